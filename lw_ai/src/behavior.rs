@@ -0,0 +1,237 @@
+//! AI behavior packs - world-wide overlays on AI utility weights
+//!
+//! A pack scales the drives behind AI decisions (going to war, raiding,
+//! expanding, seeking allies and trade partners, preying on the weak) for
+//! every nation in a world. The pack is chosen at world creation and saved
+//! with the world, so two runs of the same seed under different packs can be
+//! compared. The game replaces built-in packs, and adds new ones, from its
+//! own and its mods' configuration through [`BehaviorPacks::insert`].
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Pack a world uses unless another is chosen
+pub const DEFAULT_BEHAVIOR_PACK: &str = "historical";
+
+/// Drives behind AI decisions that a pack can weigh
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AiDrive {
+    /// Declaring wars and raiding neighbors
+    Aggression,
+    /// Settling and annexing new land
+    Expansion,
+    /// Seeking alliances
+    Diplomacy,
+    /// Seeking trade pacts
+    Trade,
+    /// Preferring the weakest neighbor as a target
+    Predation,
+}
+
+/// Multipliers on each drive; 1.0 leaves the AI as designed
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BehaviorWeights {
+    pub aggression: f32,
+    pub expansion: f32,
+    pub diplomacy: f32,
+    pub trade: f32,
+    /// Exponent on a target's weakness: above 1.0 only the weakest neighbors
+    /// appeal, below 1.0 strength hardly matters
+    pub predation: f32,
+}
+
+impl Default for BehaviorWeights {
+    fn default() -> Self {
+        Self {
+            aggression: 1.0,
+            expansion: 1.0,
+            diplomacy: 1.0,
+            trade: 1.0,
+            predation: 1.0,
+        }
+    }
+}
+
+impl BehaviorWeights {
+    pub fn get(&self, drive: AiDrive) -> f32 {
+        match drive {
+            AiDrive::Aggression => self.aggression,
+            AiDrive::Expansion => self.expansion,
+            AiDrive::Diplomacy => self.diplomacy,
+            AiDrive::Trade => self.trade,
+            AiDrive::Predation => self.predation,
+        }
+    }
+}
+
+/// A named set of weights offered at world creation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BehaviorPack {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub weights: BehaviorWeights,
+}
+
+impl BehaviorPack {
+    fn builtin(name: &str, description: &str, weights: BehaviorWeights) -> Self {
+        Self {
+            name: name.to_string(),
+            description: description.to_string(),
+            weights,
+        }
+    }
+
+    /// Packs shipped with the game, by id, in the order they are offered
+    pub fn builtins() -> Vec<(String, BehaviorPack)> {
+        vec![
+            (
+                DEFAULT_BEHAVIOR_PACK.to_string(),
+                Self::builtin(
+                    "Historical",
+                    "Nations follow their personalities as designed.",
+                    BehaviorWeights::default(),
+                ),
+            ),
+            (
+                "chaotic".to_string(),
+                Self::builtin(
+                    "Chaotic",
+                    "Quick to war and careless of whom they fight.",
+                    BehaviorWeights {
+                        aggression: 1.5,
+                        expansion: 1.2,
+                        diplomacy: 0.7,
+                        trade: 1.0,
+                        predation: 0.4,
+                    },
+                ),
+            ),
+            (
+                "peaceful".to_string(),
+                Self::builtin(
+                    "Peaceful",
+                    "Wars are rare; alliances and trade flourish.",
+                    BehaviorWeights {
+                        aggression: 0.5,
+                        expansion: 0.7,
+                        diplomacy: 1.4,
+                        trade: 1.3,
+                        predation: 1.0,
+                    },
+                ),
+            ),
+            (
+                "darwinian".to_string(),
+                Self::builtin(
+                    "Darwinian",
+                    "The strong devour the weak and expand without pause.",
+                    BehaviorWeights {
+                        aggression: 1.3,
+                        expansion: 1.4,
+                        diplomacy: 0.6,
+                        trade: 0.8,
+                        predation: 2.5,
+                    },
+                ),
+            ),
+        ]
+    }
+}
+
+/// Every pack on offer: built-ins first, then configured packs by id
+#[derive(Resource, Debug, Clone)]
+pub struct BehaviorPacks {
+    pub packs: Vec<(String, BehaviorPack)>,
+}
+
+impl Default for BehaviorPacks {
+    fn default() -> Self {
+        Self {
+            packs: BehaviorPack::builtins(),
+        }
+    }
+}
+
+impl BehaviorPacks {
+    pub fn get(&self, id: &str) -> Option<&BehaviorPack> {
+        self.packs
+            .iter()
+            .find(|(pack_id, _)| pack_id == id)
+            .map(|(_, pack)| pack)
+    }
+
+    /// Replace the pack with the same id, or offer a new one
+    pub fn insert(&mut self, id: String, pack: BehaviorPack) {
+        match self.packs.iter_mut().find(|(pack_id, _)| *pack_id == id) {
+            Some((_, existing)) => *existing = pack,
+            None => self.packs.push((id, pack)),
+        }
+    }
+}
+
+/// Pack the current world runs under, saved with it
+///
+/// The weights are saved alongside the pack id so a world keeps its
+/// character when loaded without the mod that defined its pack.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AiBehavior {
+    pub pack: String,
+    pub weights: BehaviorWeights,
+}
+
+impl Default for AiBehavior {
+    fn default() -> Self {
+        Self {
+            pack: DEFAULT_BEHAVIOR_PACK.to_string(),
+            weights: BehaviorWeights::default(),
+        }
+    }
+}
+
+impl AiBehavior {
+    /// Behavior of the pack `id`, the historical default if it is not on offer
+    pub fn from_pack(packs: &BehaviorPacks, id: &str) -> Self {
+        match packs.get(id) {
+            Some(pack) => Self {
+                pack: id.to_string(),
+                weights: pack.weights,
+            },
+            None => {
+                warn!("Unknown AI behavior pack '{}', using {}", id, DEFAULT_BEHAVIOR_PACK);
+                Self::default()
+            }
+        }
+    }
+
+    /// Scale a drive's utility or desire by the pack's weight
+    pub fn weigh(&self, drive: AiDrive, value: f32) -> f32 {
+        value * self.weights.get(drive)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn configured_packs_replace_builtins_and_unknown_packs_fall_back() {
+        let mut packs = BehaviorPacks::default();
+        let builtin_count = packs.packs.len();
+        assert!(packs.get("darwinian").is_some_and(|pack| pack.weights.predation > 1.0));
+
+        let pack: BehaviorPack = ron::from_str("(name: \"Meek\", weights: (aggression: 0.1))")
+            .unwrap_or_else(|e| panic!("pack failed to parse: {}", e));
+        assert_eq!(pack.weights.trade, 1.0);
+        packs.insert("chaotic".to_string(), pack.clone());
+        packs.insert("meek".to_string(), pack);
+        assert_eq!(packs.packs.len(), builtin_count + 1);
+
+        let chaotic = AiBehavior::from_pack(&packs, "chaotic");
+        assert_eq!(chaotic.weigh(AiDrive::Aggression, 0.8), 0.8 * 0.1);
+        assert_eq!(AiBehavior::from_pack(&packs, "missing"), AiBehavior::default());
+    }
+}
@@ -0,0 +1,93 @@
+//! Goals and plans shared by the AI systems
+//!
+//! A goal says what an actor wants (and how badly); a plan is the ordered
+//! list of actions it intends to take to get there. Plans are generic over
+//! the action type so each AI keeps its own vocabulary of actions.
+
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+/// What an actor is working toward
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum GoalKind {
+    /// Gain territory from a rival
+    Expand,
+    /// Recover lost core provinces
+    Reconquer,
+    /// Deter or survive a stronger threat
+    Secure,
+    /// Restore internal order
+    Stabilize,
+    /// Repair finances
+    Enrich,
+    /// Change laws or institutions
+    Reform,
+}
+
+/// A goal with its urgency
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct AiGoal {
+    pub kind: GoalKind,
+    /// Entity the goal is aimed at, if any (a rival nation, a province)
+    pub target: Option<Entity>,
+    /// Urgency as a utility score (0.0 to 1.0)
+    pub priority: f32,
+    /// Year the goal was adopted
+    pub adopted_year: u32,
+}
+
+impl AiGoal {
+    pub fn new(kind: GoalKind, priority: f32, adopted_year: u32) -> Self {
+        Self {
+            kind,
+            target: None,
+            priority: priority.clamp(0.0, 1.0),
+            adopted_year,
+        }
+    }
+
+    pub fn with_target(mut self, target: Entity) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Whether a new goal is pressing enough to replace this one
+    pub fn is_superseded_by(&self, other: &AiGoal) -> bool {
+        other.priority > self.priority
+    }
+}
+
+/// Ordered actions toward a goal
+#[derive(Debug, Clone, PartialEq)]
+pub struct Plan<A> {
+    pub goal: AiGoal,
+    steps: VecDeque<A>,
+}
+
+impl<A> Plan<A> {
+    pub fn new(goal: AiGoal, steps: impl IntoIterator<Item = A>) -> Self {
+        Self {
+            goal,
+            steps: steps.into_iter().collect(),
+        }
+    }
+
+    /// Action to take next
+    pub fn current_step(&self) -> Option<&A> {
+        self.steps.front()
+    }
+
+    /// Mark the current step done and return it
+    pub fn advance(&mut self) -> Option<A> {
+        self.steps.pop_front()
+    }
+
+    /// Remaining steps, next first
+    pub fn remaining(&self) -> impl Iterator<Item = &A> {
+        self.steps.iter()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
@@ -0,0 +1,220 @@
+//! Influence maps over the province graph
+//!
+//! Each layer spreads values from their sources (armies, rich land, settled
+//! cultures) outward across province adjacency, losing a fixed share per hop.
+//! Every source's footprint is stored separately so a single nation or
+//! culture can be recomputed when its inputs change without touching the
+//! rest of the map.
+
+use bevy::prelude::*;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+/// Influence below this is dropped rather than spread further
+const MIN_INFLUENCE: f32 = 0.01;
+
+/// Kinds of influence tracked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum InfluenceLayer {
+    /// Where a nation's armed strength can reach
+    MilitaryThreat,
+    /// Valuable land and who holds it
+    EconomicOpportunity,
+    /// Reach of each culture's settled population
+    CulturalPressure,
+}
+
+impl InfluenceLayer {
+    /// Share of influence kept per hop
+    pub fn decay(&self) -> f32 {
+        match self {
+            Self::MilitaryThreat => 0.7,
+            Self::EconomicOpportunity => 0.5,
+            Self::CulturalPressure => 0.6,
+        }
+    }
+}
+
+/// Spread seed values across the province graph with per-hop decay
+///
+/// Each province keeps the strongest value that reaches it (not the sum), so
+/// a large territory does not pile up influence on its own interior.
+/// Returns the footprint as `(province index, value)` pairs.
+pub fn spread<I>(
+    seeds: &[(usize, f32)],
+    neighbors: impl Fn(usize) -> I,
+    decay: f32,
+) -> Vec<(u32, f32)>
+where
+    I: IntoIterator<Item = usize>,
+{
+    let mut best: HashMap<usize, f32> = HashMap::new();
+    // Positive finite f32 bit patterns sort like the values themselves
+    let mut frontier: BinaryHeap<(u32, usize)> = BinaryHeap::new();
+    for &(index, value) in seeds {
+        if value < MIN_INFLUENCE {
+            continue;
+        }
+        if best.get(&index).is_none_or(|&known| value > known) {
+            best.insert(index, value);
+            frontier.push((value.to_bits(), index));
+        }
+    }
+
+    while let Some((bits, index)) = frontier.pop() {
+        let value = f32::from_bits(bits);
+        if best.get(&index).is_some_and(|&known| value < known) {
+            continue;
+        }
+        let next = value * decay;
+        if next < MIN_INFLUENCE {
+            continue;
+        }
+        for neighbor in neighbors(index) {
+            if best.get(&neighbor).is_none_or(|&known| next > known) {
+                best.insert(neighbor, next);
+                frontier.push((next.to_bits(), neighbor));
+            }
+        }
+    }
+
+    let mut footprint: Vec<(u32, f32)> = best.into_iter().map(|(index, value)| (index as u32, value)).collect();
+    footprint.sort_unstable_by_key(|&(index, _)| index);
+    footprint
+}
+
+/// One influence layer: every source's footprint, indexed both ways
+///
+/// `S` names where influence comes from - a nation, a culture, whatever the
+/// caller spreads.
+#[derive(Debug)]
+pub struct InfluenceField<S> {
+    by_source: HashMap<S, Vec<(u32, f32)>>,
+    by_province: Vec<Vec<(S, f32)>>,
+}
+
+impl<S> Default for InfluenceField<S> {
+    fn default() -> Self {
+        Self {
+            by_source: HashMap::new(),
+            by_province: Vec::new(),
+        }
+    }
+}
+
+impl<S: Copy + Eq + Hash> InfluenceField<S> {
+    /// Replace a source's footprint
+    pub fn set_source(&mut self, source: S, footprint: Vec<(u32, f32)>) {
+        self.remove_source(source);
+        for &(index, value) in &footprint {
+            let index = index as usize;
+            if index >= self.by_province.len() {
+                self.by_province.resize_with(index + 1, Vec::new);
+            }
+            self.by_province[index].push((source, value));
+        }
+        self.by_source.insert(source, footprint);
+    }
+
+    /// Drop a source's footprint entirely
+    pub fn remove_source(&mut self, source: S) {
+        let Some(old) = self.by_source.remove(&source) else {
+            return;
+        };
+        for (index, _) in old {
+            if let Some(entries) = self.by_province.get_mut(index as usize) {
+                entries.retain(|(entry, _)| *entry != source);
+            }
+        }
+    }
+
+    /// Sources currently in the field
+    pub fn sources(&self) -> impl Iterator<Item = &S> {
+        self.by_source.keys()
+    }
+
+    /// All influence reaching a province
+    pub fn total(&self, province: usize) -> f32 {
+        self.entries(province).iter().map(|(_, value)| value).sum()
+    }
+
+    /// Influence of one source at a province
+    pub fn from_source(&self, province: usize, source: S) -> f32 {
+        self.entries(province)
+            .iter()
+            .find(|(entry, _)| *entry == source)
+            .map_or(0.0, |(_, value)| *value)
+    }
+
+    /// Influence of everyone except `source` at a province
+    pub fn excluding(&self, province: usize, source: S) -> f32 {
+        self.entries(province)
+            .iter()
+            .filter(|(entry, _)| *entry != source)
+            .map(|(_, value)| value)
+            .sum()
+    }
+
+    /// Strongest source at a province
+    pub fn dominant(&self, province: usize) -> Option<(S, f32)> {
+        self.entries(province)
+            .iter()
+            .copied()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    /// Largest total in the field (for normalizing overlays)
+    pub fn max_total(&self) -> f32 {
+        (0..self.by_province.len()).map(|index| self.total(index)).fold(0.0, f32::max)
+    }
+
+    pub fn clear(&mut self) {
+        self.by_source.clear();
+        self.by_province.clear();
+    }
+
+    fn entries(&self, province: usize) -> &[(S, f32)] {
+        self.by_province.get(province).map_or(&[], Vec::as_slice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Provinces in a line: 0 - 1 - 2 - 3 - 4
+    fn line(index: usize) -> Vec<usize> {
+        [index.checked_sub(1), (index < 4).then_some(index + 1)]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    #[test]
+    fn influence_decays_with_distance() {
+        let footprint = spread(&[(0, 1.0)], line, 0.5);
+        let value = |index: u32| footprint.iter().find(|(i, _)| *i == index).map_or(0.0, |(_, v)| *v);
+
+        assert_eq!(value(0), 1.0);
+        assert_eq!(value(1), 0.5);
+        assert_eq!(value(2), 0.25);
+        assert!(value(4) < value(3));
+    }
+
+    #[test]
+    fn replacing_a_source_updates_province_totals() {
+        let (nation, culture) = ("nation", "culture");
+        let mut field = InfluenceField::default();
+
+        field.set_source(nation, spread(&[(0, 1.0)], line, 0.5));
+        field.set_source(culture, spread(&[(4, 1.0)], line, 0.5));
+        assert_eq!(field.excluding(0, nation), field.from_source(0, culture));
+
+        field.set_source(nation, spread(&[(4, 1.0)], line, 0.5));
+        assert_eq!(field.from_source(0, nation), field.from_source(0, culture));
+        assert_eq!(field.dominant(4).map(|(_, value)| value), Some(1.0));
+
+        field.remove_source(nation);
+        assert_eq!(field.total(4), 1.0);
+    }
+}
@@ -0,0 +1,28 @@
+//! Shared AI building blocks for Living Worlds
+//!
+//! The governance, military, and economic decision code scores, plans, and
+//! rolls dice with these, so every AI decides the same way:
+//! - Utility evaluation (response curves and considerations)
+//! - Goal and plan types
+//! - Deterministic decision sampling seeded from the world seed
+//! - Influence maps spread over a province graph
+//! - Behavior packs scaling AI drives world-wide
+//!
+//! Nothing here depends on the game. The game's `ai` module runs the Bevy
+//! systems that feed these types from the running world.
+
+mod behavior;
+mod goals;
+mod influence;
+mod sampling;
+mod utility;
+
+pub use behavior::{
+    AiBehavior, AiDrive, BehaviorPack, BehaviorPacks, BehaviorWeights, DEFAULT_BEHAVIOR_PACK,
+};
+pub use goals::{AiGoal, GoalKind, Plan};
+pub use influence::{spread, InfluenceField, InfluenceLayer};
+pub use sampling::{
+    decision_rng, decision_seed, sample_by_utility, sample_weighted, DecisionDomain, DecisionRng,
+};
+pub use utility::{best_choice, score_considerations, Consideration, ResponseCurve, UtilityChoice};
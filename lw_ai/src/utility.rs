@@ -0,0 +1,129 @@
+//! Utility evaluation framework
+//!
+//! A decision is scored by passing each relevant input through a response
+//! curve and combining the resulting considerations. Scores are in 0.0..=1.0
+//! so options from different systems can be compared directly.
+
+/// Maps a normalized input (0.0 to 1.0) to a utility (0.0 to 1.0)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResponseCurve {
+    /// `slope * x + offset`
+    Linear { slope: f32, offset: f32 },
+    /// `x ^ exponent` - exponents above 1 reward only high inputs
+    Polynomial { exponent: f32 },
+    /// S-curve rising through 0.5 at `midpoint`
+    Logistic { midpoint: f32, steepness: f32 },
+    /// `1 - x` - utility falls as the input rises
+    Inverse,
+    /// 1.0 above `threshold`, otherwise 0.0
+    Step { threshold: f32 },
+}
+
+impl ResponseCurve {
+    /// Utility of an input; the input is clamped to 0.0..=1.0 first
+    pub fn evaluate(&self, input: f32) -> f32 {
+        let x = input.clamp(0.0, 1.0);
+        let y = match *self {
+            Self::Linear { slope, offset } => slope * x + offset,
+            Self::Polynomial { exponent } => x.powf(exponent),
+            Self::Logistic { midpoint, steepness } => 1.0 / (1.0 + (-steepness * (x - midpoint)).exp()),
+            Self::Inverse => 1.0 - x,
+            Self::Step { threshold } => {
+                if x >= threshold {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        };
+        y.clamp(0.0, 1.0)
+    }
+}
+
+/// One factor in a decision
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Consideration {
+    pub input: f32,
+    pub curve: ResponseCurve,
+}
+
+impl Consideration {
+    pub fn new(input: f32, curve: ResponseCurve) -> Self {
+        Self { input, curve }
+    }
+
+    pub fn score(&self) -> f32 {
+        self.curve.evaluate(self.input)
+    }
+}
+
+/// Combine considerations into a single utility
+///
+/// Considerations multiply, so any one of them can veto a decision by
+/// scoring 0.0. Because multiplying many factors drags every score down,
+/// each factor is compensated for the number of considerations.
+pub fn score_considerations(considerations: &[Consideration]) -> f32 {
+    if considerations.is_empty() {
+        return 0.0;
+    }
+
+    let modification = 1.0 - 1.0 / considerations.len() as f32;
+    considerations
+        .iter()
+        .map(|consideration| {
+            let score = consideration.score();
+            score + (1.0 - score) * modification * score
+        })
+        .product::<f32>()
+        .clamp(0.0, 1.0)
+}
+
+/// An option paired with its utility
+#[derive(Debug, Clone, PartialEq)]
+pub struct UtilityChoice<T> {
+    pub option: T,
+    pub score: f32,
+}
+
+/// Highest scoring option, if any scores above zero
+pub fn best_choice<T>(choices: impl IntoIterator<Item = UtilityChoice<T>>) -> Option<UtilityChoice<T>> {
+    choices
+        .into_iter()
+        .filter(|choice| choice.score > 0.0)
+        .fold(None, |best: Option<UtilityChoice<T>>, choice| match best {
+            Some(best) if best.score >= choice.score => Some(best),
+            _ => Some(choice),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_zero_consideration_vetoes_the_decision() {
+        let eager = [
+            Consideration::new(0.9, ResponseCurve::Linear { slope: 1.0, offset: 0.0 }),
+            Consideration::new(0.8, ResponseCurve::Logistic { midpoint: 0.5, steepness: 10.0 }),
+        ];
+        let vetoed = [
+            Consideration::new(0.9, ResponseCurve::Linear { slope: 1.0, offset: 0.0 }),
+            Consideration::new(0.2, ResponseCurve::Step { threshold: 0.5 }),
+        ];
+
+        assert!(score_considerations(&eager) > 0.7);
+        assert_eq!(score_considerations(&vetoed), 0.0);
+    }
+
+    #[test]
+    fn best_choice_ignores_worthless_options() {
+        let choices = vec![
+            UtilityChoice { option: 'a', score: 0.0 },
+            UtilityChoice { option: 'b', score: 0.3 },
+            UtilityChoice { option: 'c', score: 0.6 },
+        ];
+
+        assert_eq!(best_choice(choices).map(|c| c.option), Some('c'));
+        assert!(best_choice(vec![UtilityChoice { option: 'a', score: 0.0 }]).is_none());
+    }
+}
@@ -0,0 +1,49 @@
+//! Errors from reading a save
+
+use std::fmt;
+use std::io;
+
+/// Why a save could not be read
+#[derive(Debug)]
+pub enum Error {
+    /// The file could not be read
+    Io(io::Error),
+    /// The file is not a save, or is damaged
+    Decompress(io::Error),
+    /// The save is from a version of the game this crate does not read
+    UnsupportedVersion(u32),
+    /// The save's contents did not match the format of its version
+    Format(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "Failed to read save: {}", e),
+            Error::Decompress(e) => write!(f, "Failed to decompress save: {}", e),
+            Error::UnsupportedVersion(version) => write!(
+                f,
+                "Save version {} is not supported (this crate reads versions {} to {})",
+                version,
+                crate::MIN_SAVE_VERSION,
+                crate::SAVE_VERSION
+            ),
+            Error::Format(e) => write!(f, "Failed to parse save: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) | Error::Decompress(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
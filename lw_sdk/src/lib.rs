@@ -0,0 +1,34 @@
+//! Read-only access to Living Worlds saves for external tools
+//!
+//! Map renderers, statistics sites, and other community tools can load a
+//! save, walk its nations, provinces, and recorded history with plain Rust
+//! types, without Bevy or the game itself.
+//!
+//! ```no_run
+//! let save = lw_sdk::load_save("saves/my_world.lws")?;
+//! for nation in save.nations() {
+//!     let provinces = save.provinces_of(nation.id).count();
+//!     let _line = format!("{} holds {} provinces in {}", nation.name, provinces, save.year);
+//! }
+//! # Ok::<(), lw_sdk::Error>(())
+//! ```
+//!
+//! # Versioning
+//!
+//! The crate reads saves of versions [`MIN_SAVE_VERSION`] to [`SAVE_VERSION`],
+//! matching the game it is released with. Its minor version is bumped each
+//! time the save format changes; the game's tests read their own saves back
+//! through this crate, so the two never drift apart.
+//!
+//! Loading a full autosave gives the world as of that snapshot - the deltas
+//! written after it are not applied.
+
+mod error;
+mod save;
+mod types;
+
+pub use error::Error;
+pub use save::{load_save, read_save, Save, MIN_SAVE_VERSION, SAVE_VERSION};
+pub use types::{
+    ChronicleCategory, ChronicleEntry, Culture, Minerals, Nation, NationId, Personality, Province, ProvinceId, Terrain,
+};
@@ -0,0 +1,418 @@
+//! Loading a save and walking what it holds
+//!
+//! Saves are RON compressed with zstd or lz4 behind a short header naming
+//! the codec (`b"LWSV"`, header version, codec tag, codec level); files
+//! without the header are raw zstd from older versions of the game. The
+//! `Raw*` types below mirror the parts of the game's save data this crate
+//! reads - everything else in the file is skipped.
+
+use serde::Deserialize;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+use crate::error::Error;
+use crate::types::{ChronicleEntry, Culture, Minerals, Nation, NationId, Personality, Province, ProvinceId, Terrain};
+
+/// Newest save version this crate reads, the game's `SAVE_VERSION`
+pub const SAVE_VERSION: u32 = 3;
+/// Oldest save version this crate reads, the first to record nations by stable id
+pub const MIN_SAVE_VERSION: u32 = 2;
+
+const HEADER_MAGIC: [u8; 4] = *b"LWSV";
+const HEADER_LEN: usize = 7;
+const CODEC_TAG_LZ4: u8 = 2;
+
+/// The game's type-safe wrappers (`Elevation`, `Abundance`, ...) as written
+#[derive(Deserialize)]
+struct Wrapped<T>(T);
+
+/// The game's `Money` as written: 48.16 fixed-point bits, or a plain amount
+/// in saves from before treasuries were fixed-point
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawMoney {
+    Bits(i64),
+    Amount(f64),
+}
+
+impl RawMoney {
+    fn amount(&self) -> f32 {
+        match *self {
+            RawMoney::Bits(bits) => (bits as f64 / 65536.0) as f32,
+            RawMoney::Amount(amount) => amount as f32,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct RawProvince {
+    id: ProvinceId,
+    position: [f32; 2],
+    culture: Option<Culture>,
+    population: u32,
+    max_population: u32,
+    terrain: Terrain,
+    elevation: Wrapped<f32>,
+    agriculture: Wrapped<f32>,
+    fresh_water_distance: Wrapped<f32>,
+    iron: Wrapped<u8>,
+    copper: Wrapped<u8>,
+    tin: Wrapped<u8>,
+    gold: Wrapped<u8>,
+    coal: Wrapped<u8>,
+    stone: Wrapped<u8>,
+    gems: Wrapped<u8>,
+    neighbors: [Option<ProvinceId>; 6],
+}
+
+#[derive(Deserialize)]
+struct RawRgb {
+    red: f32,
+    green: f32,
+    blue: f32,
+}
+
+/// Nation colors are written as Bevy colors; the game creates them in sRGB
+#[derive(Deserialize)]
+enum RawColor {
+    Srgba(RawRgb),
+    LinearRgba(RawRgb),
+}
+
+impl RawColor {
+    fn to_srgb(&self) -> [f32; 3] {
+        let encode = |linear: f32| {
+            if linear <= 0.003_130_8 {
+                linear * 12.92
+            } else {
+                1.055 * linear.powf(1.0 / 2.4) - 0.055
+            }
+        };
+        match self {
+            RawColor::Srgba(rgb) => [rgb.red, rgb.green, rgb.blue],
+            RawColor::LinearRgba(rgb) => [encode(rgb.red), encode(rgb.green), encode(rgb.blue)],
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct RawNation {
+    name: String,
+    adjective: String,
+    color: RawColor,
+    capital_province: ProvinceId,
+    treasury: RawMoney,
+    tax_rate: f32,
+    military_strength: f32,
+    stability: f32,
+    culture: Culture,
+    technology_level: u32,
+    personality: Personality,
+}
+
+#[derive(Deserialize)]
+struct RawGameTime {
+    cached_year: u32,
+    cached_day_of_year: u32,
+}
+
+#[derive(Deserialize)]
+struct RawArchive {
+    file: String,
+}
+
+#[derive(Default, Deserialize)]
+struct RawChronicle {
+    entries: Vec<ChronicleEntry>,
+    #[serde(default)]
+    archives: Vec<RawArchive>,
+}
+
+#[derive(Deserialize)]
+struct RawAiBehavior {
+    pack: String,
+}
+
+/// Saves from before behavior packs ran under the historical pack
+impl Default for RawAiBehavior {
+    fn default() -> Self {
+        Self {
+            pack: "historical".to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct RawSave {
+    version: u32,
+    world_name: String,
+    world_seed: u32,
+    game_time: RawGameTime,
+    provinces: Vec<RawProvince>,
+    #[serde(default)]
+    nations: Vec<(NationId, RawNation)>,
+    #[serde(default)]
+    province_owners: Vec<Option<NationId>>,
+    #[serde(default)]
+    play_time_secs: f64,
+    #[serde(default)]
+    chronicle: RawChronicle,
+    #[serde(default)]
+    ai_behavior: RawAiBehavior,
+}
+
+/// Just the version, to explain a save that fails to parse
+#[derive(Deserialize)]
+struct RawVersion {
+    version: u32,
+}
+
+/// A world as recorded in one save
+#[derive(Debug, Clone)]
+pub struct Save {
+    pub version: u32,
+    pub world_name: String,
+    pub world_seed: u32,
+    pub year: u32,
+    pub day_of_year: u32,
+    /// Real seconds spent in game across every session of this world
+    pub play_time_secs: f64,
+    /// Id of the AI behavior pack the world ran under (`historical`, `darwinian`, a mod's pack, ...)
+    pub behavior_pack: String,
+    provinces: Vec<Province>,
+    /// Sorted by id
+    nations: Vec<Nation>,
+    history: Vec<ChronicleEntry>,
+    archive_files: Vec<String>,
+}
+
+impl Save {
+    /// Every province, in id order
+    pub fn provinces(&self) -> &[Province] {
+        &self.provinces
+    }
+
+    pub fn province(&self, id: ProvinceId) -> Option<&Province> {
+        match self.provinces.get(id.0 as usize) {
+            Some(province) if province.id == id => Some(province),
+            _ => self.provinces.iter().find(|province| province.id == id),
+        }
+    }
+
+    /// Every nation alive when the save was written, in id order
+    pub fn nations(&self) -> &[Nation] {
+        &self.nations
+    }
+
+    pub fn nation(&self, id: NationId) -> Option<&Nation> {
+        self.nations
+            .binary_search_by_key(&id, |nation| nation.id)
+            .ok()
+            .map(|index| &self.nations[index])
+    }
+
+    /// Provinces held by a nation
+    pub fn provinces_of(&self, nation: NationId) -> impl Iterator<Item = &Province> + '_ {
+        self.provinces
+            .iter()
+            .filter(move |province| province.owner == Some(nation))
+    }
+
+    /// Recent history, oldest first
+    ///
+    /// Over long games the game moves old entries (other than milestones)
+    /// out of the save into archive files; see [`Save::full_history`].
+    pub fn history(&self) -> &[ChronicleEntry] {
+        &self.history
+    }
+
+    /// History entries about a nation, oldest first
+    pub fn history_of(&self, nation: NationId) -> impl Iterator<Item = &ChronicleEntry> + '_ {
+        self.history.iter().filter(move |entry| entry.nations.contains(&nation))
+    }
+
+    /// The whole history, archived entries read from `archive_directory`
+    ///
+    /// The game keeps archives in `chronicle_archive` beside its `saves`
+    /// directory. Archives that can no longer be read are skipped, as the
+    /// game does.
+    pub fn full_history(&self, archive_directory: impl AsRef<Path>) -> Vec<ChronicleEntry> {
+        let mut entries: Vec<ChronicleEntry> = self
+            .archive_files
+            .iter()
+            .filter_map(|file| fs::read_to_string(archive_directory.as_ref().join(file)).ok())
+            .filter_map(|text| ron::from_str::<Vec<ChronicleEntry>>(&text).ok())
+            .flatten()
+            .collect();
+        entries.extend(self.history.iter().cloned());
+        entries.sort_by_key(|entry| (entry.year, entry.day_of_year));
+        entries
+    }
+}
+
+/// Load a save file (`.lws`)
+///
+/// # Errors
+///
+/// Fails if the file can't be read, isn't a save, or is from a save version
+/// outside [`MIN_SAVE_VERSION`]..=[`SAVE_VERSION`].
+pub fn load_save(path: impl AsRef<Path>) -> Result<Save, Error> {
+    read_save(&fs::read(path)?)
+}
+
+/// Read a save from its bytes as written to disk
+///
+/// # Errors
+///
+/// See [`load_save`].
+pub fn read_save(bytes: &[u8]) -> Result<Save, Error> {
+    let text = decompress(bytes).map_err(Error::Decompress)?;
+    let text = String::from_utf8_lossy(&text);
+
+    let raw: RawSave = match ron::from_str(&text) {
+        Ok(raw) => raw,
+        Err(e) => {
+            return Err(match ron::from_str::<RawVersion>(&text) {
+                Ok(RawVersion { version }) if !(MIN_SAVE_VERSION..=SAVE_VERSION).contains(&version) => {
+                    Error::UnsupportedVersion(version)
+                }
+                _ => Error::Format(e.to_string()),
+            });
+        }
+    };
+    if !(MIN_SAVE_VERSION..=SAVE_VERSION).contains(&raw.version) {
+        return Err(Error::UnsupportedVersion(raw.version));
+    }
+
+    let provinces = raw
+        .provinces
+        .into_iter()
+        .enumerate()
+        .map(|(index, province)| Province {
+            id: province.id,
+            position: province.position,
+            owner: raw.province_owners.get(index).copied().flatten(),
+            culture: province.culture,
+            population: province.population,
+            max_population: province.max_population,
+            terrain: province.terrain,
+            elevation: province.elevation.0,
+            agriculture: province.agriculture.0,
+            fresh_water_distance: Some(province.fresh_water_distance.0).filter(|distance| distance.is_finite()),
+            minerals: Minerals {
+                iron: province.iron.0,
+                copper: province.copper.0,
+                tin: province.tin.0,
+                gold: province.gold.0,
+                coal: province.coal.0,
+                stone: province.stone.0,
+                gems: province.gems.0,
+            },
+            neighbors: province.neighbors,
+        })
+        .collect();
+
+    let mut nations: Vec<Nation> = raw
+        .nations
+        .into_iter()
+        .map(|(id, nation)| Nation {
+            id,
+            color: nation.color.to_srgb(),
+            name: nation.name,
+            adjective: nation.adjective,
+            capital_province: nation.capital_province,
+            treasury: nation.treasury.amount(),
+            tax_rate: nation.tax_rate,
+            military_strength: nation.military_strength,
+            stability: nation.stability,
+            culture: nation.culture,
+            technology_level: nation.technology_level,
+            personality: nation.personality,
+        })
+        .collect();
+    nations.sort_by_key(|nation| nation.id);
+
+    Ok(Save {
+        version: raw.version,
+        world_name: raw.world_name,
+        world_seed: raw.world_seed,
+        year: raw.game_time.cached_year,
+        day_of_year: raw.game_time.cached_day_of_year,
+        play_time_secs: raw.play_time_secs,
+        behavior_pack: raw.ai_behavior.pack,
+        provinces,
+        nations,
+        history: raw.chronicle.entries,
+        archive_files: raw.chronicle.archives.into_iter().map(|archive| archive.file).collect(),
+    })
+}
+
+fn decompress(bytes: &[u8]) -> io::Result<Vec<u8>> {
+    if bytes.len() < HEADER_LEN || bytes[..4] != HEADER_MAGIC {
+        return zstd::stream::decode_all(bytes);
+    }
+    let payload = &bytes[HEADER_LEN..];
+    if bytes[5] == CODEC_TAG_LZ4 {
+        let mut decompressed = Vec::new();
+        lz4_flex::frame::FrameDecoder::new(payload).read_to_end(&mut decompressed)?;
+        Ok(decompressed)
+    } else {
+        zstd::stream::decode_all(payload)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    const SAVE: &str = "(version:2,timestamp:\"2026-01-01T00:00:00+00:00\",world_name:\"Aldmere\",world_seed:7,\
+        world_size:Small,game_time:(current_tick:(0),starting_year:1000,cached_year:1012,cached_day_of_year:40,\
+        cached_total_days:4420),provinces:[(id:(0),position:(1.5,-2.0),culture:Some(Western),population:900,\
+        max_population:5000,terrain:TemperateGrassland,elevation:(0.3),agriculture:(2.0),\
+        fresh_water_distance:(inf),iron:(10),copper:(0),tin:(0),gold:(80),coal:(0),stone:(50),gems:(0),\
+        neighbors:[Some((1)),None,None,None,None,None],neighbor_indices:[Some(1),None,None,None,None,None],\
+        version:3,dirty:false),(id:(1),position:(3.0,-2.0),culture:None,population:0,max_population:0,\
+        terrain:Ocean,elevation:(0.0),agriculture:(0.0),fresh_water_distance:(2.0),iron:(0),copper:(0),\
+        tin:(0),gold:(0),coal:(0),stone:(0),gems:(0),neighbors:[None,None,None,None,Some((0)),None],\
+        neighbor_indices:[None,None,None,None,Some(0),None],version:0,dirty:false)],\
+        nations:[((3),(name:\"Velm\",adjective:\"Velmish\",color:Srgba((red:0.5,green:0.25,blue:1.0,alpha:1.0)),\
+        capital_province:(0),treasury:7864320,tax_rate:0.2,military_strength:40.0,stability:0.8,culture:Western,\
+        technology_level:2,personality:(aggression:0.1,expansionism:0.2,diplomacy:0.3,mercantilism:0.4)))],\
+        province_owners:[Some((3)),None],play_time_secs:60.0,chronicle:(entries:[(year:1010,day_of_year:3,\
+        category:War,text:\"Velm marches\",nations:[(3)])],archives:[]))";
+
+    #[test]
+    fn reads_a_save_as_the_game_writes_it() {
+        let bytes = zstd::stream::encode_all(SAVE.as_bytes(), 3).unwrap();
+        let save = read_save(&bytes).unwrap();
+
+        assert_eq!(
+            (save.world_name.as_str(), save.year, save.day_of_year),
+            ("Aldmere", 1012, 40)
+        );
+        let velm = save.nation(NationId(3)).unwrap();
+        assert_eq!(velm.color, [0.5, 0.25, 1.0]);
+        assert_eq!(velm.treasury, 120.0);
+        let held: Vec<_> = save.provinces_of(velm.id).map(|province| province.id).collect();
+        assert_eq!(held, vec![ProvinceId(0)]);
+
+        let capital = save.province(ProvinceId(0)).unwrap();
+        assert_eq!(capital.minerals.gold, 80);
+        assert_eq!(capital.fresh_water_distance, None);
+        assert!(save.province(ProvinceId(1)).unwrap().terrain.is_ocean());
+        assert_eq!(save.history_of(velm.id).count(), 1);
+        // Written before behavior packs
+        assert_eq!(save.behavior_pack, "historical");
+
+        // Written before treasuries were fixed-point
+        let plain = SAVE.replacen("treasury:7864320", "treasury:120.0", 1);
+        let bytes = zstd::stream::encode_all(plain.as_bytes(), 3).unwrap();
+        assert_eq!(read_save(&bytes).unwrap().nations()[0].treasury, 120.0);
+
+        let future = SAVE.replacen("version:2", "version:99", 1);
+        let bytes = zstd::stream::encode_all(future.as_bytes(), 3).unwrap();
+        assert!(matches!(read_save(&bytes), Err(Error::UnsupportedVersion(99))));
+    }
+}
@@ -0,0 +1,147 @@
+//! Plain types for what a save records
+//!
+//! Field names and enum variants follow the game's own, so a tool's output
+//! reads the same as the game's interface.
+
+use serde::{Deserialize, Serialize};
+
+/// Stable identifier of a nation, unchanged across saves of the same world
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct NationId(pub u32);
+
+/// Identifier of a province, also its index in [`Save::provinces`](crate::Save::provinces)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ProvinceId(pub u32);
+
+/// Biome of a province
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Terrain {
+    Ocean,
+    Beach,
+    River,
+    PolarDesert,
+    Tundra,
+    Taiga,
+    BorealForest,
+    TemperateRainforest,
+    TemperateDeciduousForest,
+    TemperateGrassland,
+    ColdDesert,
+    MediterraneanForest,
+    Chaparral,
+    SubtropicalDesert,
+    TropicalRainforest,
+    TropicalSeasonalForest,
+    Savanna,
+    TropicalDesert,
+    Alpine,
+    Wetlands,
+    Mangrove,
+}
+
+impl Terrain {
+    /// Whether the province is open sea, which no nation can hold
+    pub fn is_ocean(&self) -> bool {
+        *self == Terrain::Ocean
+    }
+}
+
+/// Cultural style of a province or nation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Culture {
+    Western,
+    Eastern,
+    Northern,
+    Southern,
+    Desert,
+    Island,
+    Ancient,
+    Mystical,
+}
+
+/// Mineral abundance of a province, each from 0 (none) to 100
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Minerals {
+    pub iron: u8,
+    pub copper: u8,
+    pub tin: u8,
+    pub gold: u8,
+    pub coal: u8,
+    pub stone: u8,
+    pub gems: u8,
+}
+
+/// One hexagon of the world map
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Province {
+    pub id: ProvinceId,
+    /// Center of the hexagon in world units
+    pub position: [f32; 2],
+    /// Nation holding the province, if any
+    pub owner: Option<NationId>,
+    pub culture: Option<Culture>,
+    pub population: u32,
+    pub max_population: u32,
+    pub terrain: Terrain,
+    /// From 0.0 (sea level) to 1.0 (highest peaks)
+    pub elevation: f32,
+    /// Food production capacity, from 0.0 to 3.0
+    pub agriculture: f32,
+    /// Distance to the nearest river in hexagons, `None` if there is none
+    pub fresh_water_distance: Option<f32>,
+    pub minerals: Minerals,
+    /// Neighboring hexagons (NE, E, SE, SW, W, NW), `None` off the map's edge
+    pub neighbors: [Option<ProvinceId>; 6],
+}
+
+/// Temperament that drives a nation's decisions, each from -1.0 to 1.0
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Personality {
+    pub aggression: f32,
+    pub expansionism: f32,
+    pub diplomacy: f32,
+    pub mercantilism: f32,
+}
+
+/// A nation as of the save
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Nation {
+    pub id: NationId,
+    pub name: String,
+    /// "French" for "France"
+    pub adjective: String,
+    /// Map color as sRGB, each channel from 0.0 to 1.0
+    pub color: [f32; 3],
+    pub capital_province: ProvinceId,
+    pub treasury: f32,
+    pub tax_rate: f32,
+    pub military_strength: f32,
+    /// From 0.0 to 1.0
+    pub stability: f32,
+    pub culture: Culture,
+    pub technology_level: u32,
+    pub personality: Personality,
+}
+
+/// Broad kind of a chronicle entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChronicleCategory {
+    Milestone,
+    War,
+    Politics,
+    Dynasty,
+    Catastrophe,
+    Trade,
+    Director,
+}
+
+/// One dated line of world history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChronicleEntry {
+    pub year: u32,
+    pub day_of_year: u32,
+    pub category: ChronicleCategory,
+    pub text: String,
+    /// Nations the entry is about
+    pub nations: Vec<NationId>,
+}
@@ -0,0 +1,287 @@
+// World Field Compute Shader for Living Worlds
+//
+// The heavy per-province field passes of world generation: terrain elevation,
+// temperature and prevailing winds, and moisture diffusion. Each entry point
+// mirrors its CPU counterpart (src/world/gpu/integration.rs and
+// src/world/terrain/climate.rs) in f32, so results agree within validation
+// tolerance but are not bit-exact across drivers.
+
+// ============================================================================
+// ELEVATION PASS
+// ============================================================================
+
+struct ElevationParams {
+    seed: u32,
+    count: u32,
+    continent_count: u32,
+    hex_size: f32,
+    world_width: f32,
+    world_height: f32,
+    _padding0: f32,
+    _padding1: f32,
+}
+
+@group(0) @binding(0) var<storage, read> positions: array<vec2<f32>>;
+// xy = center, z = strength, w = radius
+@group(0) @binding(1) var<storage, read> continents: array<vec4<f32>>;
+@group(0) @binding(2) var<uniform> elevation_params: ElevationParams;
+@group(0) @binding(3) var<storage, read_write> elevations: array<f32>;
+
+// Lattice hash constants - must match src/math/lattice.rs
+const HASH_SEED_OFFSET: u32 = 0x9E3779B9u;
+const HASH_X_PRIME: u32 = 0x85EBCA6Bu;
+const HASH_Y_PRIME: u32 = 0xC2B2AE35u;
+
+fn mix32(x: u32) -> u32 {
+    var h = x;
+    h = h ^ (h >> 16u);
+    h = h * 0x7FEB352Du;
+    h = h ^ (h >> 15u);
+    h = h * 0x846CA68Bu;
+    h = h ^ (h >> 16u);
+    return h;
+}
+
+fn hash2(x: i32, y: i32, seed: u32) -> u32 {
+    var h = seed ^ HASH_SEED_OFFSET;
+    h = mix32(h ^ (u32(x) * HASH_X_PRIME));
+    h = mix32(h ^ (u32(y) * HASH_Y_PRIME));
+    return h;
+}
+
+fn fade(t: f32) -> f32 {
+    return t * t * t * (t * (t * 6.0 - 15.0) + 10.0);
+}
+
+fn gradient2(hash: u32) -> vec2<f32> {
+    var gradients = array<vec2<f32>, 8>(
+        vec2<f32>(1.0, 0.0),
+        vec2<f32>(-1.0, 0.0),
+        vec2<f32>(0.0, 1.0),
+        vec2<f32>(0.0, -1.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(-1.0, 1.0),
+        vec2<f32>(1.0, -1.0),
+        vec2<f32>(-1.0, -1.0)
+    );
+    return gradients[hash & 7u];
+}
+
+// Raw gradient noise in [-1, 1], as LatticeNoise::get
+fn lattice(p: vec2<f32>, seed: u32) -> f32 {
+    let cell = floor(p);
+    let pi = vec2<i32>(cell);
+    let pf = p - cell;
+    let w = vec2<f32>(fade(pf.x), fade(pf.y));
+
+    let d00 = dot(gradient2(hash2(pi.x, pi.y, seed)), pf);
+    let d10 = dot(gradient2(hash2(pi.x + 1, pi.y, seed)), pf - vec2<f32>(1.0, 0.0));
+    let d01 = dot(gradient2(hash2(pi.x, pi.y + 1, seed)), pf - vec2<f32>(0.0, 1.0));
+    let d11 = dot(gradient2(hash2(pi.x + 1, pi.y + 1, seed)), pf - vec2<f32>(1.0, 1.0));
+
+    let x0 = mix(d00, d10, w.x);
+    let x1 = mix(d01, d11, w.x);
+    return clamp(mix(x0, x1, w.y), -1.0, 1.0);
+}
+
+// PerlinNoise::sample_fbm - every octave shares the seed, as on the CPU
+fn fbm(p: vec2<f32>, seed: u32, octaves: u32, frequency: f32, persistence: f32, lacunarity: f32) -> f32 {
+    var value = 0.0;
+    var amplitude = 1.0;
+    var freq = frequency;
+    var max_amplitude = 0.0;
+
+    for (var i = 0u; i < octaves; i = i + 1u) {
+        value += lattice(p * freq, seed) * amplitude;
+        max_amplitude += amplitude;
+        amplitude *= persistence;
+        freq *= lacunarity;
+    }
+
+    return (value / max_amplitude) * 0.5 + 0.5;
+}
+
+// PerlinNoise::sample_terrain
+fn sample_terrain(p: vec2<f32>, seed: u32) -> f32 {
+    let continental = fbm(p, seed, 4u, 0.001, 0.4, 2.0) * 0.47;
+    let landmass = fbm(p, seed, 6u, 0.005, 0.5, 2.1) * 0.35;
+    let islands = fbm(p, seed, 6u, 0.02, 0.45, 2.2) * 0.08;
+    let coastal = fbm(p, seed, 6u, 0.08, 0.4, 2.3) * 0.05;
+    let ridge_base = 1.0 - abs(lattice(p * 0.025, seed));
+    let ridge = clamp(ridge_base * ridge_base, 0.0, 1.0) * 0.05;
+    return clamp(continental + landmass + islands + coastal + ridge, 0.0, 1.0);
+}
+
+// PerlinNoise::sample_scaled
+fn sample_scaled(p: vec2<f32>, seed: u32, frequency: f32) -> f32 {
+    return (lattice(p * frequency, seed) + 1.0) * 0.5;
+}
+
+// math::smooth_falloff
+fn smooth_falloff(distance: f32, inner_radius: f32, outer_radius: f32) -> f32 {
+    if (distance <= inner_radius) {
+        return 1.0;
+    }
+    if (distance >= outer_radius) {
+        return 0.0;
+    }
+    let t = (distance - inner_radius) / (outer_radius - inner_radius);
+    return 1.0 - t * t * (3.0 - 2.0 * t);
+}
+
+fn edge_falloff(position: vec2<f32>) -> f32 {
+    let half_size = vec2<f32>(elevation_params.world_width, elevation_params.world_height) * 0.5;
+    let outside = max(abs(position) - half_size, vec2<f32>(0.0));
+    let distance_to_edge = length(outside);
+
+    let falloff_start = elevation_params.hex_size * 10.0;
+    let falloff_end = elevation_params.hex_size * 50.0;
+    if (distance_to_edge <= falloff_start) {
+        return 1.0;
+    }
+    if (distance_to_edge >= falloff_end) {
+        return 0.0;
+    }
+    let t = 1.0 - (distance_to_edge - falloff_start) / (falloff_end - falloff_start);
+    return t * t * (3.0 - 2.0 * t);
+}
+
+@compute @workgroup_size(256)
+fn elevation(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if (index >= elevation_params.count) {
+        return;
+    }
+
+    let position = positions[index];
+    let seed = elevation_params.seed;
+    let base = sample_terrain(position / elevation_params.hex_size, seed);
+
+    // Domain warp for organic continent shapes
+    let warp_x = sample_scaled(position * 0.005, seed, 0.01);
+    let warp_y = sample_scaled(position * 0.005 + vec2<f32>(100.0), seed, 0.01);
+
+    var continent_influence = 0.0;
+    for (var i = 0u; i < elevation_params.continent_count; i = i + 1u) {
+        let continent = continents[i];
+        let radius = continent.w;
+        let warped_distance = distance(position, continent.xy) + (warp_x + warp_y) * radius * 0.3;
+        let influence = smooth_falloff(warped_distance, radius * 0.4, radius * 1.2) * continent.z;
+        continent_influence = max(continent_influence, influence);
+    }
+
+    elevations[index] = (base + continent_influence) * edge_falloff(position);
+}
+
+// ============================================================================
+// TEMPERATURE PASS
+// ============================================================================
+
+struct ClimateParams {
+    count: u32,
+    equator_temp: f32,
+    pole_temp: f32,
+    y_min: f32,
+    y_max: f32,
+    _padding0: f32,
+    _padding1: f32,
+    _padding2: f32,
+}
+
+// x = position y, y = elevation, z = ocean distance (km), w = 1.0 for ocean
+@group(0) @binding(4) var<storage, read> province_climate: array<vec4<f32>>;
+@group(0) @binding(5) var<uniform> climate_params: ClimateParams;
+// x = temperature, y = wind direction, z = wind strength
+@group(0) @binding(6) var<storage, read_write> temperatures: array<vec4<f32>>;
+
+// Must match src/world/terrain/climate.rs
+const LAPSE_RATE: f32 = 0.0065;
+const OCEAN_TEMP_MODERATION: f32 = 10.0;
+const OCEAN_INFLUENCE_DISTANCE: f32 = 500.0;
+const TRADE_WIND_ZONE: f32 = 0.3;
+const WESTERLIES_ZONE: f32 = 0.6;
+const PI: f32 = 3.14159265358979;
+
+@compute @workgroup_size(256)
+fn temperature(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if (index >= climate_params.count) {
+        return;
+    }
+
+    let province = province_climate[index];
+    let latitude = (province.x - climate_params.y_min) / (climate_params.y_max - climate_params.y_min);
+    let base_temp = mix(climate_params.equator_temp, climate_params.pole_temp, abs(latitude - 0.5) * 2.0);
+    let elevation_cooling = province.y * 5000.0 * LAPSE_RATE;
+
+    var ocean_moderation = 0.0;
+    if (province.z < OCEAN_INFLUENCE_DISTANCE) {
+        ocean_moderation = OCEAN_TEMP_MODERATION * (1.0 - province.z / OCEAN_INFLUENCE_DISTANCE);
+    }
+
+    // Trade winds and polar easterlies blow west, westerlies east
+    let wind_latitude = abs(latitude - 0.5);
+    var wind_direction = PI;
+    var wind_strength = 0.6;
+    if (wind_latitude < TRADE_WIND_ZONE) {
+        wind_strength = 0.8;
+    } else if (wind_latitude < WESTERLIES_ZONE) {
+        wind_direction = 0.0;
+        wind_strength = 1.0;
+    }
+    if (province.w < 0.5) {
+        wind_strength *= 0.7;
+    }
+
+    temperatures[index] = vec4<f32>(
+        base_temp - elevation_cooling + ocean_moderation,
+        wind_direction,
+        wind_strength,
+        0.0
+    );
+}
+
+// ============================================================================
+// MOISTURE DIFFUSION PASS
+// ============================================================================
+
+// Six neighbor indices per province, -1 where there is none
+@group(0) @binding(7) var<storage, read> neighbors: array<i32>;
+@group(0) @binding(8) var<storage, read> winds: array<vec4<f32>>;
+@group(0) @binding(9) var<storage, read> rainfall_in: array<f32>;
+@group(0) @binding(10) var<storage, read_write> rainfall_out: array<f32>;
+@group(0) @binding(11) var<storage, read> moisture_province: array<vec4<f32>>;
+
+// Must match src/world/terrain/climate.rs
+const OCEAN_RAINFALL: f32 = 1500.0;
+const RAINFALL_DECAY_INLAND: f32 = 0.995;
+const MAX_RAINFALL: f32 = 3000.0;
+const NEIGHBOR_TRANSFER: f32 = 0.05;
+const RAINFALL_BLEND: f32 = 0.3;
+
+// One Jacobi step: every province reads the previous step's rainfall
+@compute @workgroup_size(256)
+fn moisture(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if (index >= arrayLength(&rainfall_out)) {
+        return;
+    }
+
+    let province = moisture_province[index];
+    var rainfall = OCEAN_RAINFALL;
+    if (province.w < 0.5) {
+        rainfall = OCEAN_RAINFALL * pow(RAINFALL_DECAY_INLAND, min(province.z, 500.0));
+    }
+
+    let wind_strength = winds[index].z;
+    for (var i = 0u; i < 6u; i = i + 1u) {
+        let neighbor = neighbors[index * 6u + i];
+        if (neighbor >= 0) {
+            rainfall += rainfall_in[u32(neighbor)] * NEIGHBOR_TRANSFER * wind_strength;
+        }
+    }
+    rainfall = min(rainfall, MAX_RAINFALL);
+
+    let current = rainfall_in[index];
+    rainfall_out[index] = current * (1.0 - RAINFALL_BLEND) + rainfall * RAINFALL_BLEND;
+}
@@ -24,34 +24,34 @@ struct NoiseParams {
     offset_y: f32,
 }
 
-// Constants for hash functions
-const HASH_MULTIPLIER: u32 = 1664525u;
-const HASH_INCREMENT: u32 = 1013904223u;
-
-// Hash function for pseudo-random number generation
-fn hash(x: u32) -> u32 {
+// Lattice hash constants - must match src/math/lattice.rs so GPU and CPU
+// generation agree on every gradient
+const HASH_SEED_OFFSET: u32 = 0x9E3779B9u;
+const HASH_X_PRIME: u32 = 0x85EBCA6Bu;
+const HASH_Y_PRIME: u32 = 0xC2B2AE35u;
+
+// Avalanching integer mix
+fn mix32(x: u32) -> u32 {
     var h = x;
-    h = h * HASH_MULTIPLIER + HASH_INCREMENT;
     h = h ^ (h >> 16u);
-    h = h * HASH_MULTIPLIER + HASH_INCREMENT;
+    h = h * 0x7FEB352Du;
+    h = h ^ (h >> 15u);
+    h = h * 0x846CA68Bu;
     h = h ^ (h >> 16u);
     return h;
 }
 
-// Convert hash to float in range [0, 1]
-fn hash_to_float(h: u32) -> f32 {
-    return f32(h) / 4294967295.0;
-}
-
-// 2D hash function
+// 2D lattice hash
 fn hash2(x: i32, y: i32, seed: u32) -> u32 {
-    let h = hash(u32(x) ^ hash(u32(y) ^ seed));
+    var h = seed ^ HASH_SEED_OFFSET;
+    h = mix32(h ^ (u32(x) * HASH_X_PRIME));
+    h = mix32(h ^ (u32(y) * HASH_Y_PRIME));
     return h;
 }
 
-// Smooth interpolation curve
-fn smoothstep(t: f32) -> f32 {
-    return t * t * (3.0 - 2.0 * t);
+// Quintic fade curve 6t^5 - 15t^4 + 10t^3
+fn fade(t: f32) -> f32 {
+    return t * t * t * (t * (t * 6.0 - 15.0) + 10.0);
 }
 
 // Linear interpolation
@@ -59,14 +59,19 @@ fn lerp(a: f32, b: f32, t: f32) -> f32 {
     return a + (b - a) * t;
 }
 
-// 2D gradient vectors for Perlin noise
+// 2D gradient vectors for Perlin noise: the four axes and four diagonals
 fn gradient2(hash: u32) -> vec2<f32> {
-    let h = hash & 7u;
-    let u = select(0.0, 1.0, (h & 1u) == 0u);
-    let v = select(0.0, 1.0, (h & 2u) == 0u);
-    let x = select(-u, u, (h & 4u) == 0u);
-    let y = select(-v, v, h >= 4u);
-    return normalize(vec2<f32>(x, y));
+    var gradients = array<vec2<f32>, 8>(
+        vec2<f32>(1.0, 0.0),
+        vec2<f32>(-1.0, 0.0),
+        vec2<f32>(0.0, 1.0),
+        vec2<f32>(0.0, -1.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(-1.0, 1.0),
+        vec2<f32>(1.0, -1.0),
+        vec2<f32>(-1.0, -1.0)
+    );
+    return gradients[hash & 7u];
 }
 
 // 2D Perlin noise function
@@ -75,7 +80,7 @@ fn perlin2d(p: vec2<f32>, seed: u32) -> f32 {
     let pf = fract(p);
 
     // Smooth interpolation weights
-    let w = vec2<f32>(smoothstep(pf.x), smoothstep(pf.y));
+    let w = vec2<f32>(fade(pf.x), fade(pf.y));
 
     // Get gradients at four corners
     let g00 = gradient2(hash2(pi.x, pi.y, seed));
@@ -92,7 +97,7 @@ fn perlin2d(p: vec2<f32>, seed: u32) -> f32 {
     // Interpolate
     let x0 = lerp(d00, d10, w.x);
     let x1 = lerp(d01, d11, w.x);
-    return lerp(x0, x1, w.y);
+    return clamp(lerp(x0, x1, w.y), -1.0, 1.0);
 }
 
 // Fractal Brownian Motion (FBM) - combines multiple octaves of noise
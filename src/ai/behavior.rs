@@ -0,0 +1,40 @@
+//! AI behavior packs - choosing the pack a world runs under
+//!
+//! The packs themselves live in `lw_ai`. The built-in packs can be replaced,
+//! and new packs added, by the base game's `config/base/behavior_packs.ron`
+//! and by mods through their own `config/behavior_packs.ron`.
+
+use bevy::prelude::*;
+use lw_ai::{AiBehavior, BehaviorPacks, DEFAULT_BEHAVIOR_PACK};
+
+use crate::modding::ModManager;
+use crate::world::WorldGenerationSettings;
+
+/// Rebuild the packs on offer from the merged mod configuration
+pub fn rebuild_behavior_packs(mut packs: ResMut<BehaviorPacks>, mods: Option<Res<ModManager>>) {
+    if !packs.is_added() && !mods.as_ref().is_some_and(|mods| mods.is_changed()) {
+        return;
+    }
+
+    *packs = BehaviorPacks::default();
+    if let Some(mods) = mods {
+        let mut configured: Vec<_> = mods.get_config().behavior_packs.clone().into_iter().collect();
+        configured.sort_by(|(a, _), (b, _)| a.cmp(b));
+        for (id, pack) in configured {
+            packs.insert(id, pack);
+        }
+    }
+}
+
+/// A freshly generated world takes the behavior pack chosen for it
+///
+/// Loading a save re-inserts the saved behavior after this runs.
+pub fn configure_ai_behavior(
+    mut behavior: ResMut<AiBehavior>,
+    packs: Res<BehaviorPacks>,
+    settings: Option<Res<WorldGenerationSettings>>,
+) {
+    let id = settings.map_or_else(|| DEFAULT_BEHAVIOR_PACK.to_string(), |s| s.behavior_pack.clone());
+    *behavior = AiBehavior::from_pack(&packs, &id);
+    info!("AI behavior pack: {}", behavior.pack);
+}
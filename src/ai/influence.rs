@@ -0,0 +1,254 @@
+//! Influence maps - the game's threat, opportunity, and cultural layers
+//!
+//! Footprints are spread with `lw_ai::spread` from the nations, armies,
+//! provinces, and cultures of the running world, and recomputed a few
+//! nations at a time as their inputs change.
+
+use bevy::prelude::*;
+use lw_ai::{spread, InfluenceField, InfluenceLayer};
+use std::collections::{HashMap, HashSet};
+use crate::name_generator::Culture;
+use crate::nations::{Nation, TerritoryOwnershipChanged};
+use crate::relationships::{Army, ArmyMovedEvent, Controls, StationedIn};
+use crate::simulation::NewYearEvent;
+use crate::world::{MapMode, MilitaryOverlayFilter, ProvinceEntityOrder, ProvinceGraph, ProvinceStorage};
+
+/// Nations recomputed per frame, so a flood of changes never stalls a frame
+const NATIONS_PER_FRAME: usize = 8;
+
+/// Where influence comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InfluenceSource {
+    Nation(Entity),
+    Culture(Culture),
+    /// Land nobody holds (economic opportunity only)
+    Unclaimed,
+}
+
+/// Influence layers for AI queries and debug overlays
+#[derive(Resource, Debug, Default)]
+pub struct InfluenceMaps {
+    pub military_threat: InfluenceField<InfluenceSource>,
+    pub economic_opportunity: InfluenceField<InfluenceSource>,
+    pub cultural_pressure: InfluenceField<InfluenceSource>,
+    /// Nations whose military and economic footprints need recomputing
+    dirty_nations: HashSet<Entity>,
+    /// Cultural pressure needs recomputing
+    cultures_dirty: bool,
+    /// Every nation needs recomputing (set when a world loads)
+    needs_rebuild: bool,
+}
+
+impl InfluenceMaps {
+    pub fn field(&self, layer: InfluenceLayer) -> &InfluenceField<InfluenceSource> {
+        match layer {
+            InfluenceLayer::MilitaryThreat => &self.military_threat,
+            InfluenceLayer::EconomicOpportunity => &self.economic_opportunity,
+            InfluenceLayer::CulturalPressure => &self.cultural_pressure,
+        }
+    }
+
+    /// Armed strength of other nations that can reach a province
+    pub fn threat_to(&self, nation: Entity, province: usize) -> f32 {
+        self.military_threat.excluding(province, InfluenceSource::Nation(nation))
+    }
+
+    /// Value of land near a province that belongs to someone else (or no one)
+    pub fn opportunity_for(&self, nation: Entity, province: usize) -> f32 {
+        self.economic_opportunity.excluding(province, InfluenceSource::Nation(nation))
+    }
+
+    /// Pull of foreign cultures on a province whose people share `culture`
+    pub fn cultural_pressure_on(&self, province: usize, culture: Culture) -> f32 {
+        self.cultural_pressure.excluding(province, InfluenceSource::Culture(culture))
+    }
+
+    /// Queue a nation's footprints for recomputation
+    pub fn mark_nation_dirty(&mut self, nation: Entity) {
+        self.dirty_nations.insert(nation);
+    }
+
+    /// Queue every layer for recomputation
+    pub fn mark_all_dirty(&mut self, nations: impl IntoIterator<Item = Entity>) {
+        self.dirty_nations.extend(nations);
+        self.cultures_dirty = true;
+    }
+
+    /// Whether recomputation is still pending
+    pub fn is_dirty(&self) -> bool {
+        self.cultures_dirty || !self.dirty_nations.is_empty()
+    }
+
+    /// Forget all influence and rebuild from scratch on the next update
+    pub fn clear(&mut self) {
+        self.military_threat.clear();
+        self.economic_opportunity.clear();
+        self.cultural_pressure.clear();
+        self.dirty_nations.clear();
+        self.cultures_dirty = false;
+        self.needs_rebuild = true;
+    }
+}
+
+/// Economic worth of a province (0.0 to roughly 1.0)
+fn province_value(storage: &ProvinceStorage, index: usize) -> f32 {
+    storage.provinces.get(index).map_or(0.0, |province| {
+        let people = (province.population as f32 / 100_000.0).min(1.0);
+        let land = province.agriculture.value() / 3.0;
+        0.6 * people + 0.4 * land
+    })
+}
+
+/// Forget the previous world's influence once a new world has loaded
+pub fn clear_influence_maps(mut maps: ResMut<InfluenceMaps>) {
+    maps.clear();
+}
+
+/// Queue influence recomputation when its inputs change
+pub fn mark_influence_dirty(
+    mut maps: ResMut<InfluenceMaps>,
+    mut ownership_events: MessageReader<TerritoryOwnershipChanged>,
+    mut army_events: MessageReader<ArmyMovedEvent>,
+    mut year_events: MessageReader<NewYearEvent>,
+    armies_query: Query<&Army>,
+    nations_query: Query<Entity, With<Nation>>,
+) {
+    // Strength and population drift every year, so refresh everything then
+    if year_events.read().count() > 0 || maps.needs_rebuild {
+        maps.needs_rebuild = false;
+        maps.mark_all_dirty(&nations_query);
+    }
+    for event in ownership_events.read() {
+        maps.mark_nation_dirty(event.nation_entity);
+    }
+    for event in army_events.read() {
+        if let Ok(army) = armies_query.get(event.army) {
+            maps.mark_nation_dirty(army.owner_nation);
+        }
+    }
+}
+
+/// Recompute queued footprints, a few nations per frame
+pub fn update_influence_maps(
+    mut maps: ResMut<InfluenceMaps>,
+    province_storage: Option<Res<ProvinceStorage>>,
+    province_entity_order: Option<Res<ProvinceEntityOrder>>,
+    province_graph: Option<Res<ProvinceGraph>>,
+    nations_query: Query<(&Nation, Option<&Controls>)>,
+    armies_query: Query<(&Army, &StationedIn)>,
+) {
+    if !maps.is_dirty() {
+        return;
+    }
+    let (Some(storage), Some(entity_order), Some(graph)) = (province_storage, province_entity_order, province_graph)
+    else {
+        return;
+    };
+    let index_by_entity: HashMap<Entity, usize> = entity_order
+        .entities
+        .iter()
+        .enumerate()
+        .map(|(index, &entity)| (entity, index))
+        .collect();
+
+    let batch: Vec<Entity> = maps.dirty_nations.iter().copied().take(NATIONS_PER_FRAME).collect();
+    for nation_entity in batch {
+        maps.dirty_nations.remove(&nation_entity);
+        let source = InfluenceSource::Nation(nation_entity);
+
+        let Ok((nation, controls)) = nations_query.get(nation_entity) else {
+            // The nation is gone; so is its influence
+            maps.military_threat.remove_source(source);
+            maps.economic_opportunity.remove_source(source);
+            continue;
+        };
+        let owned: Vec<usize> = controls
+            .map(|controls| controls.provinces())
+            .unwrap_or(&[])
+            .iter()
+            .filter_map(|province| index_by_entity.get(province).copied())
+            .collect();
+
+        // Standing strength garrisons the territory; field armies add to where they stand
+        let garrison = nation.military_strength.max(0.0) / owned.len().max(1) as f32;
+        let mut military_seeds: HashMap<usize, f32> = owned.iter().map(|&index| (index, garrison)).collect();
+        for (army, stationed_in) in &armies_query {
+            if army.owner_nation != nation_entity {
+                continue;
+            }
+            if let Some(&index) = index_by_entity.get(&stationed_in.0) {
+                *military_seeds.entry(index).or_default() += army.size.to_f32() / 1000.0;
+            }
+        }
+        let military_seeds: Vec<(usize, f32)> = military_seeds.into_iter().collect();
+        let economic_seeds: Vec<(usize, f32)> = owned.iter().map(|&index| (index, province_value(&storage, index))).collect();
+
+        let neighbors = |index: usize| graph.neighbors(index).iter().map(|&neighbor| neighbor as usize);
+        maps.military_threat.set_source(
+            source,
+            spread(&military_seeds, neighbors, InfluenceLayer::MilitaryThreat.decay()),
+        );
+        maps.economic_opportunity.set_source(
+            source,
+            spread(&economic_seeds, neighbors, InfluenceLayer::EconomicOpportunity.decay()),
+        );
+    }
+
+    if maps.cultures_dirty {
+        maps.cultures_dirty = false;
+
+        let owned: HashSet<usize> = nations_query
+            .iter()
+            .filter_map(|(_, controls)| controls)
+            .flat_map(|controls| controls.provinces())
+            .filter_map(|province| index_by_entity.get(province).copied())
+            .collect();
+
+        let mut culture_seeds: HashMap<Culture, Vec<(usize, f32)>> = HashMap::new();
+        let mut unclaimed_seeds = Vec::new();
+        for (index, province) in storage.provinces.iter().enumerate() {
+            if let Some(culture) = province.culture {
+                let weight = (province.population as f32 / 50_000.0).min(1.0);
+                culture_seeds.entry(culture).or_default().push((index, weight));
+            }
+            if !owned.contains(&index) && province.population > 0 {
+                unclaimed_seeds.push((index, province_value(&storage, index)));
+            }
+        }
+
+        let neighbors = |index: usize| graph.neighbors(index).iter().map(|&neighbor| neighbor as usize);
+        let stale: Vec<InfluenceSource> = maps
+            .cultural_pressure
+            .sources()
+            .copied()
+            .filter(|source| !matches!(source, InfluenceSource::Culture(culture) if culture_seeds.contains_key(culture)))
+            .collect();
+        for source in stale {
+            maps.cultural_pressure.remove_source(source);
+        }
+        for (culture, seeds) in culture_seeds {
+            maps.cultural_pressure.set_source(
+                InfluenceSource::Culture(culture),
+                spread(&seeds, neighbors, InfluenceLayer::CulturalPressure.decay()),
+            );
+        }
+        maps.economic_opportunity.set_source(
+            InfluenceSource::Unclaimed,
+            spread(&unclaimed_seeds, neighbors, InfluenceLayer::EconomicOpportunity.decay()),
+        );
+    }
+}
+
+/// Redraw an open influence overlay once a round of updates has finished
+pub fn refresh_influence_overlay(
+    maps: Res<InfluenceMaps>,
+    filter: Res<MilitaryOverlayFilter>,
+    mut map_mode: ResMut<MapMode>,
+) {
+    if !map_mode.is_influence_mode() || maps.is_dirty() {
+        return;
+    }
+    if maps.is_changed() || filter.is_changed() {
+        map_mode.set_changed();
+    }
+}
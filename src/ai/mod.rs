@@ -0,0 +1,24 @@
+//! Shared AI infrastructure gateway
+//!
+//! The building blocks live in the `lw_ai` crate and are re-exported here:
+//! - Utility evaluation (response curves and considerations)
+//! - Goal and plan types
+//! - Deterministic decision sampling seeded from the world seed
+//! - Influence maps (threat, opportunity, cultural pressure) over the province graph
+//! - Behavior packs scaling AI drives world-wide, chosen at world creation
+//!
+//! This module keeps only what needs the running game: the influence maps
+//! fed from its nations and provinces, and picking a world's behavior pack.
+
+// PRIVATE MODULES
+mod behavior;
+mod influence;
+mod plugin;
+
+// PUBLIC EXPORTS
+pub use influence::InfluenceMaps;
+pub use lw_ai::{
+    best_choice, decision_rng, sample_weighted, score_considerations, AiBehavior, AiDrive, BehaviorPack,
+    BehaviorPacks, Consideration, DecisionDomain, ResponseCurve, UtilityChoice, DEFAULT_BEHAVIOR_PACK,
+};
+pub use plugin::AiPlugin;
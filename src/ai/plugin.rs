@@ -0,0 +1,30 @@
+//! AI plugin - keeps shared AI state such as influence maps and behavior packs current
+
+use super::behavior::{configure_ai_behavior, rebuild_behavior_packs};
+use super::influence::{
+    clear_influence_maps, mark_influence_dirty, refresh_influence_overlay, update_influence_maps,
+    InfluenceMaps,
+};
+use crate::states::GameState;
+use bevy::prelude::*;
+use lw_ai::{AiBehavior, BehaviorPacks};
+use bevy_plugin_builder::define_plugin;
+
+define_plugin!(AiPlugin {
+    resources: [InfluenceMaps, BehaviorPacks, AiBehavior],
+
+    update: [
+        rebuild_behavior_packs,
+        (mark_influence_dirty, update_influence_maps, refresh_influence_overlay)
+            .chain()
+            .run_if(in_state(GameState::InGame))
+    ],
+
+    on_enter: {
+        GameState::LoadingWorld => [configure_ai_behavior]
+    },
+
+    on_exit: {
+        GameState::LoadingWorld => [clear_influence_maps]
+    }
+});
@@ -1,5 +1,6 @@
 //! Application Builder
 
+use bevy::app::{TaskPoolOptions, TaskPoolPlugin};
 use bevy::diagnostic::FrameTimeDiagnosticsPlugin;
 use bevy::ecs::schedule::common_conditions::resource_exists;
 use bevy::prelude::*;
@@ -9,7 +10,7 @@ use crate::config::{AppConfig, DiagnosticsConfig};
 
 // Import from sibling modules
 use super::initialization;
-use super::plugins::GamePlugins;
+use super::plugins::{GamePlugins, HeadlessGamePlugins};
 
 // === Constants ===
 /// Application name used for storage and identification
@@ -97,6 +98,26 @@ pub fn build_app_with_config(config: AppConfig) -> Result<App, AppBuildError> {
         debug!("Diagnostics disabled in configuration");
     }
 
+    // Metrics export for long headless runs
+    #[cfg(feature = "metrics")]
+    if config.metrics.is_enabled() {
+        app.insert_resource(config.metrics.clone())
+            .add_plugins(crate::diagnostics::MetricsPlugin);
+        info!("Metrics export enabled: {:?}", config.metrics.exporter);
+    }
+
+    #[cfg(not(feature = "metrics"))]
+    if config.metrics.is_enabled() {
+        warn!("Metrics export requested but the `metrics` feature is not compiled in");
+    }
+
+    // Development runs reload balance values as the tuning file is edited
+    if config.hot_reload_tuning {
+        app.insert_resource(crate::simulation::TuningWatcher::watch(std::path::Path::new(
+            crate::simulation::TUNING_PATH,
+        )));
+    }
+
     // Initialize storage
     app.insert_resource(PkvStore::new(APP_NAME, APP_NAME));
 
@@ -106,3 +127,40 @@ pub fn build_app_with_config(config: AppConfig) -> Result<App, AppBuildError> {
 
     Ok(app)
 }
+
+/// Builds a windowless app that simulates a world in the background
+///
+/// Only the simulation plugins are added. Systems needing what a window
+/// provides (cameras, UI, input) find their resources missing and are
+/// skipped instead of failing the app.
+pub fn build_headless_app() -> App {
+    headless_app(TaskPoolPlugin::default())
+}
+
+/// Builds a windowless app whose task pools share `threads` workers
+///
+/// Bevy creates its task pools once per process, so the worker count only
+/// takes effect if this is the first app the process builds.
+pub fn build_headless_app_on_threads(threads: usize) -> App {
+    headless_app(TaskPoolPlugin {
+        task_pool_options: TaskPoolOptions::with_num_threads(threads),
+    })
+}
+
+fn headless_app(task_pools: TaskPoolPlugin) -> App {
+    let mut app = App::new();
+    app.set_error_handler(bevy::ecs::error::debug);
+    app.add_plugins((MinimalPlugins.set(task_pools), bevy::state::app::StatesPlugin, AssetPlugin::default()))
+        .init_asset::<Mesh>()
+        .init_asset::<Image>()
+        .init_asset::<ColorMaterial>()
+        .init_asset::<Font>()
+        .init_resource::<crate::settings::GameSettings>()
+        // Simulation systems report through these even with nobody watching
+        .add_message::<crate::ui::ShowNotification>()
+        .add_message::<crate::audio::AudioEvent>()
+        .add_plugins(HeadlessGamePlugins);
+    app.finish();
+    app.cleanup();
+    app
+}
@@ -29,5 +29,5 @@ mod builder;
 mod initialization;
 mod plugins;
 
-pub use builder::{build_app, build_app_with_config};
+pub use builder::{build_app, build_app_with_config, build_headless_app, build_headless_app_on_threads};
 pub use builder::AppBuildError;
@@ -4,12 +4,19 @@ use bevy_plugin_builder::define_plugin;
 
 // Import all game plugins
 use crate::{
+    ai::AiPlugin,
+    audio::AudioEventPlugin,
     camera::CameraPlugin,
+    chronicle::ChroniclePlugin,
     content_creation::ContentCreationPlugin,
     diagnostics::DiagnosticsPlugin,
+    ids::IdPlugin,
+    input::InputPlugin,
     loading::LoadingScreenPlugin,
     menus::MenusPlugin,
+    milestones::MilestonePlugin,
     modding::ModdingPlugin,
+    narration::NarrationPlugin,
     nations::{DramaEnginePlugin, NationPlugin},
     parallel::ParallelPlugin,
     performance::PerformanceMonitoringPlugin,
@@ -20,6 +27,8 @@ use crate::{
     states::StatesPlugin,
     ui::UIPlugin,
     world::{NoiseComputePlugin, ProvinceEventsPlugin, WorldPlugin},
+    world_report::WorldReportPlugin,
+    worlds::WorldsPlugin,
 };
 
 define_plugin!(GamePlugins {
@@ -89,6 +98,12 @@ define_plugin!(GamePlugins {
         // PROVIDES: GameSettings resource (graphics, audio, controls)
         SettingsUIPlugin,
 
+        // InputPlugin: Touchpad and gamepad input abstraction
+        // DEPENDENCIES: SettingsUIPlugin (deadzone and touchpad settings)
+        // DEPENDENTS: CameraPlugin (pan/zoom), UIPlugin (gamepad shortcuts)
+        // PROVIDES: InputActions resource, rebuilt in PreUpdate each frame
+        InputPlugin,
+
         // ========================================================================
         // === WORLD AND SIMULATION ===
         // ========================================================================
@@ -126,12 +141,60 @@ define_plugin!(GamePlugins {
         // ARCHITECTURE: Central simulation loop that drives game progression
         SimulationPlugin,
 
+        // AiPlugin: Shared AI state (influence maps over the province graph)
+        // DEPENDENCIES: WorldPlugin (province graph), NationPlugin, SimulationPlugin
+        // DEPENDENTS: NationPlugin (fortifications.rs, warfare/field_armies.rs), OverlayPlugin (influence map modes)
+        // PROVIDES: InfluenceMaps resource
+        AiPlugin,
+
+        // IdPlugin: Central ID allocation for nations, cities, armies, laws, characters
+        // DEPENDENCIES: NationPlugin (reserves spawned nation and registered law IDs)
+        // DEPENDENTS: SaveLoadPlugin (persists the allocator), mods and editor tools
+        // PROVIDES: IdAllocator resource
+        IdPlugin,
+
+        // ChroniclePlugin: Dated per-world record of history
+        // DEPENDENCIES: SimulationPlugin (dates entries)
+        // DEPENDENTS: MilestonePlugin, SaveLoadPlugin (persists the chronicle)
+        // PROVIDES: WorldChronicle resource, ChronicleEvent message
+        ChroniclePlugin,
+
+        // MilestonePlugin: Emergent observer goals, celebrated once per world
+        // DEPENDENCIES: NationPlugin, ChroniclePlugin, UIPlugin (notifications)
+        // DEPENDENTS: SteamPlugin (maps milestones to achievements)
+        // PROVIDES: WorldMilestones resource, MilestoneAchieved message
+        MilestonePlugin,
+
+        // WorldReportPlugin: World statistics and the end-of-world summary report
+        // DEPENDENCIES: NationPlugin, ChroniclePlugin, UIPlugin (panel and notifications)
+        // DEPENDENTS: SaveLoadPlugin (persists the statistics)
+        // PROVIDES: WorldStatistics resource, OpenWorldReport message
+        WorldReportPlugin,
+
+        // NarrationPlugin: Plain-sentence narration feed of major events
+        // DEPENDENCIES: NationPlugin, ChroniclePlugin, SettingsUIPlugin, UIPlugin (shortcut)
+        // DEPENDENTS: None
+        // PROVIDES: NarrationFeed and NarrationTemplates resources, NarrationEvent message
+        NarrationPlugin,
+
+        // AudioEventPlugin: Maps semantic audio cues to tones or sound files
+        // DEPENDENCIES: ModdingPlugin (sound remaps), SettingsUIPlugin (volumes)
+        // DEPENDENTS: Any plugin that writes AudioEvent
+        // PROVIDES: SoundBank resource, AudioEvent message
+        AudioEventPlugin,
+
         // SaveLoadPlugin: Save/load system, auto-save, file browser
         // DEPENDENCIES: All gameplay plugins (saves their state)
         // DEPENDENTS: MenusPlugin (save/load UI)
         // PROVIDES: Save/load functionality for entire game state
         SaveLoadPlugin,
 
+        // WorldsPlugin: Several worlds open at once, the rest simulating headless
+        // DEPENDENCIES: SaveLoadPlugin (worlds switch through save slots)
+        // DEPENDENTS: MenusPlugin (Worlds button)
+        // PROVIDES: WorldRoster and WorldBudget resources, world switcher panel
+        WorldsPlugin,
+
         // ========================================================================
         // === INTERFACE AND CONTROLS ===
         // ========================================================================
@@ -183,4 +246,28 @@ define_plugin!(GamePlugins {
             app.add_plugins(crate::safety::ParallelSafetyPlugin);
         }
     }
-});
\ No newline at end of file
+});
+
+/// The simulation half of [`GamePlugins`], for worlds running without a window
+///
+/// Background worlds load and save through `SaveLoadPlugin` and simulate
+/// through the same plugins as the active world; menus, input, camera, and
+/// UI are left out.
+define_plugin!(HeadlessGamePlugins {
+    plugins: [
+        StatesPlugin,
+        RelationshipsPlugin,
+        ModdingPlugin,
+        ProvinceEventsPlugin,
+        WorldPlugin,
+        NationPlugin,
+        DramaEnginePlugin,
+        SimulationPlugin,
+        AiPlugin,
+        IdPlugin,
+        ChroniclePlugin,
+        MilestonePlugin,
+        WorldReportPlugin,
+        SaveLoadPlugin
+    ]
+});
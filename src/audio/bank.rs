@@ -0,0 +1,91 @@
+//! Cue-to-sound mappings
+//!
+//! Every built-in cue has a generated tone. The base game's
+//! `config/base/sounds.ron` and active mods (through their own
+//! `config/sounds.ron`) can remap any cue to a sound file, a different tone,
+//! or silence.
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use super::types::SoundDefinition;
+use crate::modding::ModManager;
+
+const DEFAULT_TONES: &[(&str, f32, f32)] = &[
+    ("ui_click", 880.0, 0.05),
+    ("ui_open", 660.0, 0.08),
+    ("ui_close", 520.0, 0.08),
+    ("notification", 740.0, 0.15),
+    ("war_declared", 98.0, 0.6),
+    ("battle_started", 110.0, 0.4),
+    ("treaty_signed", 392.0, 0.5),
+    ("nation_formed", 523.0, 0.3),
+    ("nation_collapsed", 82.0, 0.8),
+    ("milestone_reached", 587.0, 0.5),
+    ("new_year", 262.0, 1.2),
+];
+
+/// How each cue sounds, by cue id
+#[derive(Resource, Debug, Clone)]
+pub struct SoundBank {
+    pub sounds: HashMap<String, SoundDefinition>,
+}
+
+impl Default for SoundBank {
+    fn default() -> Self {
+        Self {
+            sounds: DEFAULT_TONES
+                .iter()
+                .map(|&(id, frequency, duration)| {
+                    (id.to_string(), SoundDefinition::Tone { frequency, duration, volume: 1.0 })
+                })
+                .collect(),
+        }
+    }
+}
+
+impl SoundBank {
+    pub fn get(&self, cue_id: &str) -> Option<&SoundDefinition> {
+        self.sounds.get(cue_id)
+    }
+}
+
+/// Rebuild the bank from the built-in tones and the merged mod configuration
+pub fn rebuild_sound_bank(mut bank: ResMut<SoundBank>, mods: Option<Res<ModManager>>) {
+    if !bank.is_added() && !mods.as_ref().is_some_and(|mods| mods.is_changed()) {
+        return;
+    }
+
+    let mut rebuilt = SoundBank::default();
+    if let Some(mods) = &mods {
+        rebuilt.sounds.extend(mods.get_config().sounds.clone());
+    }
+    *bank = rebuilt;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::AudioCue;
+
+    #[test]
+    fn every_cue_has_a_default_sound() {
+        let bank = SoundBank::default();
+        let cues = [
+            AudioCue::UiClick,
+            AudioCue::UiOpen,
+            AudioCue::UiClose,
+            AudioCue::Notification,
+            AudioCue::WarDeclared,
+            AudioCue::BattleStarted,
+            AudioCue::TreatySigned,
+            AudioCue::NationFormed,
+            AudioCue::NationCollapsed,
+            AudioCue::MilestoneReached,
+            AudioCue::NewYear,
+        ];
+        for cue in cues {
+            assert!(bank.get(cue.id()).is_some(), "{} has no sound", cue.id());
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! Audio module - Sound event bus
+//!
+//! Gameplay and UI systems write semantic [`AudioEvent`]s ("a battle
+//! started", "a button was clicked") and never touch sound assets. The
+//! audio plugin looks each cue up in the [`SoundBank`], which maps it to a
+//! generated tone, a sound file, or silence, and plays it at the volume of
+//! its category. Mods remap cues with a `config/sounds.ron` of cue id to
+//! [`SoundDefinition`].
+
+// PRIVATE MODULES
+mod bank;
+mod plugin;
+mod systems;
+mod tone;
+mod types;
+
+// PUBLIC EXPORTS
+pub use bank::SoundBank;
+pub use plugin::AudioEventPlugin;
+pub use types::{AudioCue, AudioEvent, SoundCategory, SoundDefinition};
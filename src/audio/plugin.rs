@@ -0,0 +1,32 @@
+//! Audio plugin implementation
+
+use bevy::audio::AddAudioSource;
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+use super::bank::{rebuild_sound_bank, SoundBank};
+use super::systems::{audio_output_available, emit_new_year, emit_ui_clicks, play_audio_events};
+use super::tone::ToneAudio;
+use super::types::AudioEvent;
+
+define_plugin!(AudioEventPlugin {
+    resources: [SoundBank],
+
+    messages: [AudioEvent],
+
+    update: [
+        (
+            rebuild_sound_bank,
+            emit_ui_clicks,
+            emit_new_year,
+            play_audio_events.run_if(audio_output_available),
+        ).chain()
+    ],
+
+    custom_init: |app: &mut App| {
+        // Tones need an audio output; headless and muted builds skip them
+        if app.is_plugin_added::<bevy::audio::AudioPlugin>() {
+            app.add_audio_source::<ToneAudio>();
+        }
+    }
+});
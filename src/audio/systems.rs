@@ -0,0 +1,88 @@
+//! Audio event systems
+
+use bevy::audio::Volume;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use super::bank::SoundBank;
+use super::tone::ToneAudio;
+use super::types::{AudioCue, AudioEvent, SoundCategory, SoundDefinition};
+use crate::settings::GameSettings;
+use crate::simulation::NewYearEvent;
+
+/// Play the sound mapped to each audio event
+///
+/// Only runs when audio output exists, see [`audio_output_available`].
+pub fn play_audio_events(
+    mut commands: Commands,
+    mut events: MessageReader<AudioEvent>,
+    bank: Res<SoundBank>,
+    settings: Option<Res<GameSettings>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    asset_server: Res<AssetServer>,
+    mut tones: ResMut<Assets<ToneAudio>>,
+) {
+    let audio = settings.map(|settings| settings.audio.clone()).unwrap_or_default();
+    let unfocused = windows.single().is_ok_and(|window| !window.focused);
+    let muted = audio.master_volume <= 0.0 || (audio.mute_when_unfocused && unfocused);
+
+    for event in events.read() {
+        if muted {
+            continue;
+        }
+        let Some(sound) = bank.get(event.cue.id()) else {
+            debug!("No sound mapped for audio cue '{}'", event.cue.id());
+            continue;
+        };
+
+        let category_volume = |category: SoundCategory| match category {
+            SoundCategory::Ui => audio.ui_volume,
+            SoundCategory::Gameplay => audio.sfx_volume,
+            SoundCategory::Ambient => audio.ambient_volume,
+        };
+
+        match sound {
+            SoundDefinition::Asset { path, volume, category } => {
+                let gain = audio.master_volume * category_volume(category.unwrap_or(event.cue.category())) * volume;
+                commands.spawn((
+                    AudioPlayer::new(asset_server.load::<AudioSource>(path.clone())),
+                    PlaybackSettings::DESPAWN.with_volume(Volume::Linear(gain)),
+                ));
+            }
+            SoundDefinition::Tone { frequency, duration, volume } => {
+                let gain = audio.master_volume * category_volume(event.cue.category()) * volume;
+                let handle = tones.add(ToneAudio {
+                    frequency: *frequency,
+                    duration: *duration,
+                });
+                commands.spawn((
+                    AudioPlayer(handle),
+                    PlaybackSettings::DESPAWN.with_volume(Volume::Linear(gain)),
+                ));
+            }
+            SoundDefinition::Silent => {}
+        }
+    }
+}
+
+/// Emit a click cue whenever any button is pressed
+pub fn emit_ui_clicks(
+    buttons: Query<&Interaction, (Changed<Interaction>, With<Button>)>,
+    mut audio: MessageWriter<AudioEvent>,
+) {
+    if buttons.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        audio.write(AudioEvent::new(AudioCue::UiClick));
+    }
+}
+
+/// Mark the turn of each year with an ambient cue
+pub fn emit_new_year(mut years: MessageReader<NewYearEvent>, mut audio: MessageWriter<AudioEvent>) {
+    if years.read().last().is_some() {
+        audio.write(AudioEvent::new(AudioCue::NewYear));
+    }
+}
+
+/// Whether the audio plugin is running, so sounds have somewhere to play
+pub fn audio_output_available(tones: Option<Res<Assets<ToneAudio>>>) -> bool {
+    tones.is_some()
+}
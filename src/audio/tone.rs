@@ -0,0 +1,71 @@
+//! Procedurally generated tones
+//!
+//! Until recorded sounds land, every cue can be heard as a short sine tone.
+
+use bevy::audio::{Decodable, Source};
+use bevy::prelude::*;
+use bevy::reflect::TypePath;
+use std::f32::consts::TAU;
+use std::time::Duration;
+
+const SAMPLE_RATE: u32 = 44_100;
+
+/// A sine tone of fixed pitch and length
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct ToneAudio {
+    pub frequency: f32,
+    /// Seconds
+    pub duration: f32,
+}
+
+/// Sample stream for a [`ToneAudio`], fading out linearly to silence
+pub struct ToneDecoder {
+    frequency: f32,
+    sample: u32,
+    total_samples: u32,
+}
+
+impl Iterator for ToneDecoder {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.sample >= self.total_samples {
+            return None;
+        }
+        let t = self.sample as f32 / SAMPLE_RATE as f32;
+        let envelope = 1.0 - self.sample as f32 / self.total_samples as f32;
+        self.sample += 1;
+        Some((TAU * self.frequency * t).sin() * envelope * 0.5)
+    }
+}
+
+impl Source for ToneDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        Some((self.total_samples - self.sample) as usize)
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        Some(Duration::from_secs_f32(self.total_samples as f32 / SAMPLE_RATE as f32))
+    }
+}
+
+impl Decodable for ToneAudio {
+    type DecoderItem = <ToneDecoder as Iterator>::Item;
+    type Decoder = ToneDecoder;
+
+    fn decoder(&self) -> Self::Decoder {
+        ToneDecoder {
+            frequency: self.frequency,
+            sample: 0,
+            total_samples: (self.duration.max(0.0) * SAMPLE_RATE as f32) as u32,
+        }
+    }
+}
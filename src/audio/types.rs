@@ -0,0 +1,97 @@
+//! Audio event types
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Mixer channel a sound plays on, each with its own volume setting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SoundCategory {
+    Ui,
+    Gameplay,
+    Ambient,
+}
+
+/// Something that should be heard, named by what happened rather than how it sounds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AudioCue {
+    UiClick,
+    UiOpen,
+    UiClose,
+    Notification,
+    WarDeclared,
+    BattleStarted,
+    TreatySigned,
+    NationFormed,
+    NationCollapsed,
+    MilestoneReached,
+    NewYear,
+}
+
+impl AudioCue {
+    /// Stable name used by sound mappings
+    pub fn id(&self) -> &'static str {
+        match self {
+            AudioCue::UiClick => "ui_click",
+            AudioCue::UiOpen => "ui_open",
+            AudioCue::UiClose => "ui_close",
+            AudioCue::Notification => "notification",
+            AudioCue::WarDeclared => "war_declared",
+            AudioCue::BattleStarted => "battle_started",
+            AudioCue::TreatySigned => "treaty_signed",
+            AudioCue::NationFormed => "nation_formed",
+            AudioCue::NationCollapsed => "nation_collapsed",
+            AudioCue::MilestoneReached => "milestone_reached",
+            AudioCue::NewYear => "new_year",
+        }
+    }
+
+    pub fn category(&self) -> SoundCategory {
+        match self {
+            AudioCue::UiClick | AudioCue::UiOpen | AudioCue::UiClose | AudioCue::Notification => SoundCategory::Ui,
+            AudioCue::NewYear => SoundCategory::Ambient,
+            _ => SoundCategory::Gameplay,
+        }
+    }
+}
+
+/// Request to play the sound mapped to a cue
+///
+/// Gameplay and UI systems write these without knowing whether, or how,
+/// the cue is heard.
+#[derive(Message, Debug, Clone)]
+pub struct AudioEvent {
+    pub cue: AudioCue,
+}
+
+impl AudioEvent {
+    pub fn new(cue: AudioCue) -> Self {
+        Self { cue }
+    }
+}
+
+/// How a cue sounds
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SoundDefinition {
+    /// A sound file loaded through the asset server
+    Asset {
+        path: String,
+        #[serde(default = "full_volume")]
+        volume: f32,
+        #[serde(default)]
+        category: Option<SoundCategory>,
+    },
+    /// A generated tone that fades out over its duration
+    Tone {
+        frequency: f32,
+        /// Seconds
+        duration: f32,
+        #[serde(default = "full_volume")]
+        volume: f32,
+    },
+    /// The cue is not heard
+    Silent,
+}
+
+fn full_volume() -> f32 {
+    1.0
+}
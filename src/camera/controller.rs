@@ -21,6 +21,9 @@ pub struct CameraController {
     pub is_dragging: bool,
     pub last_cursor_pos: Option<Vec2>,
 
+    /// Edge scrolling paused from the keyboard for this session
+    pub edge_scroll_suspended: bool,
+
     /// Movement speed multipliers
     pub pan_speed_base: f32,
     pub edge_pan_speed_base: f32,
@@ -36,6 +39,7 @@ impl Default for CameraController {
             zoom_smoothing: 10.0,    // Slightly faster zoom response
             is_dragging: false,
             last_cursor_pos: None,
+            edge_scroll_suspended: false,
             pan_speed_base: CAMERA_PAN_SPEED_BASE,
             edge_pan_speed_base: CAMERA_EDGE_PAN_SPEED_BASE,
         }
@@ -0,0 +1,102 @@
+//! Follow mode - locking the camera onto something moving across the map
+//!
+//! Anything with a `Transform` and a [`Followable`] can be followed: the
+//! camera's target tracks it every frame and the usual smoothing eases the
+//! view along behind. Zooming keeps the lock; panning by any means (keys,
+//! drag, edge scrolling, stick) lets go, as does the followed entity
+//! disappearing.
+
+use bevy::prelude::*;
+
+use crate::camera::CameraController;
+
+/// What kind of thing is being followed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FollowKind {
+    Army,
+    Migration,
+    Convoy,
+}
+
+impl FollowKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            FollowKind::Army => "Army",
+            FollowKind::Migration => "Migration",
+            FollowKind::Convoy => "Convoy",
+        }
+    }
+}
+
+/// Marks an entity the camera can follow
+#[derive(Component, Debug, Clone)]
+pub struct Followable {
+    pub kind: FollowKind,
+    pub label: String,
+    /// Nation it belongs to, if any
+    pub owner: Option<Entity>,
+}
+
+/// Entity the camera is locked onto
+#[derive(Resource, Debug, Default)]
+pub struct CameraFollow {
+    target: Option<Entity>,
+    /// Camera target and zoom as follow mode last left them
+    last_set: Option<(Vec2, f32)>,
+}
+
+impl CameraFollow {
+    pub fn target(&self) -> Option<Entity> {
+        self.target
+    }
+
+    /// Lock the camera onto an entity
+    pub fn follow(&mut self, entity: Entity) {
+        self.target = Some(entity);
+        self.last_set = None;
+    }
+
+    /// Let go of the followed entity, leaving the camera where it is
+    pub fn stop(&mut self) {
+        self.target = None;
+        self.last_set = None;
+    }
+}
+
+/// Keep the camera's target on the followed entity
+///
+/// Runs after camera input: if the target was moved since follow mode last
+/// set it, without the zoom changing too, the player has panned away.
+pub fn follow_camera_target(
+    mut follow: ResMut<CameraFollow>,
+    mut cameras: Query<&mut CameraController>,
+    targets: Query<&Transform, With<Followable>>,
+) {
+    let Some(target) = follow.target else {
+        return;
+    };
+    let Ok(mut controller) = cameras.single_mut() else {
+        return;
+    };
+    let Ok(transform) = targets.get(target) else {
+        follow.stop();
+        return;
+    };
+
+    if let Some((position, zoom)) = follow.last_set {
+        let panned = controller.target_position.truncate() != position;
+        if panned && controller.target_zoom == zoom {
+            follow.stop();
+            return;
+        }
+    }
+
+    controller.target_position.x = transform.translation.x;
+    controller.target_position.y = transform.translation.y;
+    follow.bypass_change_detection().last_set = Some((controller.target_position.truncate(), controller.target_zoom));
+}
+
+/// Stop following when leaving the game
+pub fn release_camera_follow(mut follow: ResMut<CameraFollow>) {
+    follow.stop();
+}
@@ -0,0 +1,41 @@
+//! Analog camera control from gamepads and touchpads
+
+use crate::camera::movement::CameraBounds;
+use crate::camera::CameraController;
+use crate::input::InputActions;
+use crate::settings::GameSettings;
+use bevy::prelude::*;
+
+/// Zoom rate at a fully pulled trigger, as a natural-log change per second
+const TRIGGER_ZOOM_RATE: f32 = 1.5;
+
+/// Pan with the gamepad stick or two-finger touchpad scrolling, and zoom with the triggers
+pub fn handle_analog_camera(
+    mut query: Query<&mut CameraController>,
+    actions: Res<InputActions>,
+    settings: Res<GameSettings>,
+    bounds: Res<CameraBounds>,
+    time: Res<Time>,
+) {
+    if actions.pan_axis == Vec2::ZERO && actions.zoom_axis == 0.0 && actions.scroll_pan == Vec2::ZERO {
+        return;
+    }
+    let Ok(mut controller) = query.single_mut() else {
+        return;
+    };
+
+    // The stick moves like held arrow keys, scaled by how far it is pushed
+    let pan_speed =
+        controller.pan_speed_base * settings.controls.camera_speed * controller.current_zoom * time.delta_secs();
+    controller.target_position += (actions.pan_axis * pan_speed).extend(0.0);
+
+    // Two fingers drag the map like a middle-mouse grab
+    let world_delta = actions.scroll_pan * controller.current_zoom;
+    controller.target_position.x -= world_delta.x;
+    controller.target_position.y += world_delta.y;
+
+    if actions.zoom_axis != 0.0 {
+        let rate = TRIGGER_ZOOM_RATE * settings.controls.zoom_speed * actions.zoom_axis * time.delta_secs();
+        controller.target_zoom = (controller.target_zoom * rate.exp()).clamp(bounds.min_zoom, bounds.max_zoom);
+    }
+}
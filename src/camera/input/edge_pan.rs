@@ -1,14 +1,29 @@
 //! Edge panning for RTS-style camera movement
 
 use crate::camera::CameraController;
-use crate::constants::CAMERA_EDGE_PAN_THRESHOLD;
+use crate::settings::GameSettings;
 use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
 
+/// Direction and strength of edge panning for a cursor position
+///
+/// Each axis ramps from zero at the inner edge of the scroll zone to full
+/// speed at the screen edge, so brushing the zone nudges the camera and
+/// pressing against the edge scrolls at full speed. Y is world-up.
+fn edge_pan_direction(cursor: Vec2, window_size: Vec2, zone: f32) -> Vec2 {
+    let zone = zone.max(1.0);
+    let depth = |distance: f32| ((zone - distance) / zone).clamp(0.0, 1.0);
+    Vec2::new(
+        depth(window_size.x - cursor.x) - depth(cursor.x),
+        depth(cursor.y) - depth(window_size.y - cursor.y),
+    )
+}
+
 /// Handle edge panning when cursor is near screen edges
 pub fn handle_edge_panning(
     mut query: Query<&mut CameraController>,
     windows: Query<&Window, With<PrimaryWindow>>,
+    settings: Res<GameSettings>,
     time: Res<Time>,
 ) {
     let Ok(window) = windows.single() else {
@@ -18,7 +33,10 @@ pub fn handle_edge_panning(
         return;
     };
 
-    // Only edge pan if not dragging
+    // Only edge pan if enabled, focused, and not dragging
+    if !settings.controls.edge_scrolling || controller.edge_scroll_suspended || !window.focused {
+        return;
+    }
     if controller.is_dragging {
         return;
     }
@@ -27,19 +45,43 @@ pub fn handle_edge_panning(
         return;
     };
 
-    let edge_threshold = CAMERA_EDGE_PAN_THRESHOLD;
-    let edge_speed = controller.edge_pan_speed_base * controller.current_zoom * time.delta_secs();
-
-    if cursor_pos.x <= edge_threshold {
-        controller.target_position.x -= edge_speed;
-    }
-    if cursor_pos.x >= window.width() - edge_threshold {
-        controller.target_position.x += edge_speed;
-    }
-    if cursor_pos.y <= edge_threshold {
-        controller.target_position.y += edge_speed;
+    let direction = edge_pan_direction(
+        cursor_pos,
+        Vec2::new(window.width(), window.height()),
+        settings.controls.edge_pan_zone,
+    );
+    if direction == Vec2::ZERO {
+        return;
     }
-    if cursor_pos.y >= window.height() - edge_threshold {
-        controller.target_position.y -= edge_speed;
+
+    let edge_speed =
+        controller.edge_pan_speed_base * settings.controls.edge_pan_speed * controller.current_zoom * time.delta_secs();
+    controller.target_position += (direction * edge_speed).extend(0.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edge_zone_ramps_to_full_speed_at_the_edge() {
+        let window = Vec2::new(1920.0, 1080.0);
+
+        assert_eq!(edge_pan_direction(Vec2::new(960.0, 540.0), window, 10.0), Vec2::ZERO);
+        assert_eq!(
+            edge_pan_direction(Vec2::new(0.0, 540.0), window, 10.0),
+            Vec2::new(-1.0, 0.0)
+        );
+        // Top-right corner pans right and up (world y is up)
+        assert_eq!(
+            edge_pan_direction(Vec2::new(1920.0, 0.0), window, 10.0),
+            Vec2::new(1.0, 1.0)
+        );
+
+        let brushing = edge_pan_direction(Vec2::new(1915.0, 540.0), window, 10.0);
+        assert!(brushing.x > 0.0 && brushing.x < 1.0);
+        // A wider zone starts scrolling further from the edge
+        assert_eq!(edge_pan_direction(Vec2::new(1900.0, 540.0), window, 10.0), Vec2::ZERO);
+        assert!(edge_pan_direction(Vec2::new(1900.0, 540.0), window, 40.0).x > 0.0);
     }
 }
@@ -3,6 +3,7 @@
 use crate::camera::movement::CameraBounds;
 use crate::camera::CameraController;
 use crate::constants::*;
+use crate::settings::GameSettings;
 use crate::ui::{ShortcutRegistry, ShortcutId, ShortcutEvent};
 use bevy::prelude::*;
 
@@ -26,6 +27,7 @@ pub fn handle_keyboard_movement(
     mut query: Query<&mut CameraController>,
     keyboard: Res<ButtonInput<KeyCode>>,
     registry: Res<ShortcutRegistry>,
+    settings: Res<GameSettings>,
     time: Res<Time>,
 ) {
     let Ok(mut controller) = query.single_mut() else {
@@ -55,8 +57,11 @@ pub fn handle_keyboard_movement(
             1.0
         };
 
-    let pan_speed =
-        controller.pan_speed_base * controller.current_zoom * time.delta_secs() * speed_multiplier;
+    let pan_speed = controller.pan_speed_base
+        * settings.controls.camera_speed
+        * controller.current_zoom
+        * time.delta_secs()
+        * speed_multiplier;
 
     // Handle movement based on registered keys or arrow fallbacks
     if is_key_pressed(&keyboard, up_key, KeyCode::ArrowUp) {
@@ -73,10 +78,11 @@ pub fn handle_keyboard_movement(
     }
 }
 
-/// Handle camera reset and zoom shortcuts via events
+/// Handle camera reset, zoom, and edge scrolling shortcuts via events
 pub fn handle_camera_shortcuts(
     mut query: Query<&mut CameraController>,
     mut shortcut_events: MessageReader<ShortcutEvent>,
+    settings: Res<GameSettings>,
     bounds: Res<CameraBounds>,
 ) {
     let Ok(mut controller) = query.single_mut() else {
@@ -90,12 +96,15 @@ pub fn handle_camera_shortcuts(
                 controller.target_zoom = 1.0;
             }
             ShortcutId::CameraZoomIn => {
-                controller.target_zoom =
-                    (controller.target_zoom * KEYBOARD_ZOOM_IN_FACTOR).clamp(bounds.min_zoom, bounds.max_zoom);
+                let factor = KEYBOARD_ZOOM_IN_FACTOR.powf(settings.controls.zoom_speed);
+                controller.target_zoom = (controller.target_zoom * factor).clamp(bounds.min_zoom, bounds.max_zoom);
             }
             ShortcutId::CameraZoomOut => {
-                controller.target_zoom =
-                    (controller.target_zoom * KEYBOARD_ZOOM_OUT_FACTOR).clamp(bounds.min_zoom, bounds.max_zoom);
+                let factor = KEYBOARD_ZOOM_OUT_FACTOR.powf(settings.controls.zoom_speed);
+                controller.target_zoom = (controller.target_zoom * factor).clamp(bounds.min_zoom, bounds.max_zoom);
+            }
+            ShortcutId::ToggleEdgeScrolling => {
+                controller.edge_scroll_suspended = !controller.edge_scroll_suspended;
             }
             _ => {}
         }
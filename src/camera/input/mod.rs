@@ -1,13 +1,16 @@
 //! Camera input handling gateway
 //!
-//! Manages keyboard, mouse, and edge panning input for camera control.
+//! Manages keyboard, mouse, and edge panning input for camera control, plus
+//! analog pan and zoom from gamepads and touchpads via the input layer.
 
 // Private modules
+mod analog;
 mod edge_pan;
 mod keyboard;
 mod mouse;
 
 // Public exports for use by camera plugin
+pub use analog::handle_analog_camera;
 pub use edge_pan::handle_edge_panning;
 pub use keyboard::{handle_camera_shortcuts, handle_keyboard_movement};
 pub use mouse::{handle_mouse_drag, handle_mouse_wheel_zoom};
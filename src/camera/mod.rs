@@ -4,6 +4,7 @@
 //! All external access to camera systems must go through this gateway module.
 
 mod controller;
+mod follow;
 mod input;
 mod movement;
 mod plugin;
@@ -11,6 +12,7 @@ mod setup;
 mod window;
 
 pub use controller::CameraController;
+pub use follow::{CameraFollow, FollowKind, Followable};
 pub use plugin::CameraPlugin;
 
 // Note: Input, movement, and window subsystems are intentionally kept private.
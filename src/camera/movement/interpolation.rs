@@ -2,14 +2,27 @@
 
 use crate::camera::CameraController;
 use crate::math::{lerp_exp, lerp_exp_vec3};
+use crate::settings::GameSettings;
 use bevy::prelude::*;
 
 /// Apply smooth interpolation to camera movement and zoom
+///
+/// With reduced motion enabled the camera jumps straight to its target.
 pub fn apply_smooth_movement(
     mut query: Query<(&mut Transform, &mut Projection, &mut CameraController)>,
     time: Res<Time>,
+    settings: Res<GameSettings>,
 ) {
     for (mut transform, mut projection, mut controller) in query.iter_mut() {
+        if settings.interface.reduced_motion {
+            transform.translation = controller.target_position;
+            if let Projection::Orthographic(ortho) = projection.as_mut() {
+                ortho.scale = controller.target_zoom;
+                controller.current_zoom = ortho.scale;
+            }
+            continue;
+        }
+
         // Smooth position interpolation using centralized function
         transform.translation = lerp_exp_vec3(
             transform.translation,
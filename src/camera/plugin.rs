@@ -1,5 +1,6 @@
 //! Camera plugin implementation
 
+use super::follow;
 use super::input;
 use super::movement;
 use super::setup::setup_camera;
@@ -10,18 +11,20 @@ use bevy_plugin_builder::define_plugin;
 
 /// Camera control plugin for managing viewport and camera movement using declarative syntax
 define_plugin!(CameraPlugin {
-    resources: [movement::CameraBounds, window::WindowFocusState],
+    resources: [movement::CameraBounds, window::WindowFocusState, follow::CameraFollow],
 
     startup: [setup_camera],
 
     update: [
         (
-            input::handle_keyboard_movement,
+            input::handle_keyboard_movement.run_if(crate::ui::console_closed),
             input::handle_mouse_wheel_zoom,
             input::handle_mouse_drag,
+            input::handle_analog_camera,
             input::handle_edge_panning,
             input::handle_camera_shortcuts,
             window::handle_window_focus,
+            follow::follow_camera_target,
             movement::apply_smooth_movement,
             movement::apply_camera_bounds,
         ).chain().run_if(in_state(GameState::InGame))
@@ -35,6 +38,6 @@ define_plugin!(CameraPlugin {
     },
 
     on_exit: {
-        GameState::InGame => [window::release_cursor_confinement]
+        GameState::InGame => [window::release_cursor_confinement, follow::release_camera_follow]
     }
 });
\ No newline at end of file
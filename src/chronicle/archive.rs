@@ -0,0 +1,134 @@
+//! Chronicle archive - moving old history out of memory
+//!
+//! Over centuries the chronicle grows without bound. Once enough entries
+//! are older than a century they are written to a file under
+//! `CHRONICLE_ARCHIVE_DIRECTORY` and dropped from memory, leaving a small
+//! record of where they went. Milestones stay in memory for reports. The
+//! written histories read the archive back in when opened.
+//!
+//! Archive files are named after their contents, so worlds branching from
+//! the same save never overwrite one another's history.
+
+use bevy::prelude::*;
+use std::fs;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::io;
+use std::path::PathBuf;
+
+use super::types::{ChronicleArchive, ChronicleEntry, WorldChronicle};
+use crate::simulation::NewYearEvent;
+
+/// Where archived chronicle entries are written
+pub const CHRONICLE_ARCHIVE_DIRECTORY: &str = "chronicle_archive";
+
+/// Entries this recent always stay in memory
+const RECENT_YEARS: u32 = 100;
+/// Fewest old entries worth writing out at once
+const ARCHIVE_BATCH: usize = 500;
+
+/// Write entries to a new archive file
+fn write_archive(entries: &[ChronicleEntry]) -> io::Result<ChronicleArchive> {
+    let text = ron::to_string(entries).map_err(io::Error::other)?;
+    let mut hasher = DefaultHasher::new();
+    text.hash(&mut hasher);
+
+    let first_year = entries.first().map_or(0, |entry| entry.year);
+    let last_year = entries.last().map_or(0, |entry| entry.year);
+    let file = format!("{}-{}_{:016x}.ron", first_year, last_year, hasher.finish());
+    fs::create_dir_all(CHRONICLE_ARCHIVE_DIRECTORY)?;
+    fs::write(PathBuf::from(CHRONICLE_ARCHIVE_DIRECTORY).join(&file), text)?;
+
+    Ok(ChronicleArchive {
+        first_year,
+        last_year,
+        entry_count: entries.len(),
+        file,
+    })
+}
+
+fn read_archive(archive: &ChronicleArchive) -> io::Result<Vec<ChronicleEntry>> {
+    let text = fs::read_to_string(PathBuf::from(CHRONICLE_ARCHIVE_DIRECTORY).join(&archive.file))?;
+    ron::from_str(&text).map_err(io::Error::other)
+}
+
+/// The whole chronicle, archived entries read back from disk, oldest first
+///
+/// Archives that can no longer be read are skipped.
+pub fn load_full_history(chronicle: &WorldChronicle) -> Vec<ChronicleEntry> {
+    let mut entries = Vec::new();
+    for archive in chronicle.archives() {
+        match read_archive(archive) {
+            Ok(archived) => entries.extend(archived),
+            Err(e) => warn!(
+                "Chronicle archive {} ({}-{}) unreadable: {}",
+                archive.file, archive.first_year, archive.last_year, e
+            ),
+        }
+    }
+    entries.extend(chronicle.entries().iter().cloned());
+    entries.sort_by_key(|entry| (entry.year, entry.day_of_year));
+    entries
+}
+
+/// Once a year, move a batch of century-old entries to disk
+pub fn archive_old_chronicle(mut year_events: MessageReader<NewYearEvent>, mut chronicle: ResMut<WorldChronicle>) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+    let cutoff = year.saturating_sub(RECENT_YEARS);
+    if chronicle.archivable_before(cutoff) < ARCHIVE_BATCH {
+        return;
+    }
+
+    let entries = chronicle.take_archivable_before(cutoff);
+    match write_archive(&entries) {
+        Ok(archive) => {
+            info!(
+                "Archived {} chronicle entries from {} to {}",
+                archive.entry_count, archive.first_year, archive.last_year
+            );
+            chronicle.add_archive(archive);
+        }
+        Err(e) => {
+            warn!("Failed to archive chronicle entries: {}", e);
+            chronicle.restore(entries);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::types::ChronicleCategory;
+    use super::*;
+
+    fn entry(year: u32, category: ChronicleCategory) -> ChronicleEntry {
+        ChronicleEntry {
+            year,
+            day_of_year: 0,
+            category,
+            text: format!("{:?} of {}", category, year),
+            nations: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn archiving_keeps_milestones_and_restores_in_order() {
+        let mut chronicle = WorldChronicle::default();
+        chronicle.record(entry(1000, ChronicleCategory::War));
+        chronicle.record(entry(1001, ChronicleCategory::Milestone));
+        chronicle.record(entry(1050, ChronicleCategory::Politics));
+        chronicle.record(entry(1200, ChronicleCategory::War));
+
+        assert_eq!(chronicle.archivable_before(1100), 2);
+        let taken = chronicle.take_archivable_before(1100);
+        assert_eq!(taken.iter().map(|entry| entry.year).collect::<Vec<_>>(), [1000, 1050]);
+        assert_eq!(
+            chronicle.entries().iter().map(|entry| entry.year).collect::<Vec<_>>(),
+            [1001, 1200]
+        );
+
+        chronicle.restore(taken);
+        let years: Vec<u32> = chronicle.entries().iter().map(|entry| entry.year).collect();
+        assert_eq!(years, [1000, 1001, 1050, 1200]);
+    }
+}
@@ -0,0 +1,113 @@
+//! Written-history panel
+
+use bevy::prelude::*;
+
+use super::prose::ProseChapter;
+use super::types::{
+    CloseHistoryButton, ExportHistoryButton, HistoryPanel, NextHistoryButton, PreviousHistoryButton,
+};
+use crate::ui::*;
+
+/// Spawn the panel showing one nation's written history
+pub fn spawn_history_panel(commands: &mut Commands, nation: &str, chapters: &[ProseChapter]) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(20.0),
+                top: Val::Px(60.0),
+                width: Val::Percent(60.0),
+                height: Val::Percent(85.0),
+                padding: UiRect::all(Val::Px(20.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(UI_BACKGROUND_COLOR),
+            BorderColor::all(UI_BORDER_COLOR),
+            ThemedAccent::Border(UI_BORDER_COLOR),
+            GlobalZIndex(layers::MODAL_OVERLAY),
+            HistoryPanel,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!("A History of {}", nation)),
+                TextFont {
+                    font_size: TEXT_SIZE_TITLE,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR_HEADER),
+            ));
+
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    ButtonBuilder::new("< Previous Nation")
+                        .size(ButtonSize::Small)
+                        .with_marker(PreviousHistoryButton)
+                        .build(parent);
+                    ButtonBuilder::new("Next Nation >")
+                        .size(ButtonSize::Small)
+                        .with_marker(NextHistoryButton)
+                        .build(parent);
+                    ButtonBuilder::new("Export All Histories")
+                        .size(ButtonSize::Small)
+                        .with_marker(ExportHistoryButton)
+                        .build(parent);
+                    ButtonBuilder::new("Close")
+                        .size(ButtonSize::Small)
+                        .style(ButtonStyle::Secondary)
+                        .with_marker(CloseHistoryButton)
+                        .build(parent);
+                });
+
+            parent
+                .spawn(Node {
+                    width: Val::Percent(100.0),
+                    flex_grow: 1.0,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(6.0),
+                    overflow: Overflow::scroll_y(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    if chapters.is_empty() {
+                        parent.spawn((
+                            Text::new("Nothing of note has been recorded yet."),
+                            TextFont {
+                                font_size: TEXT_SIZE_NORMAL,
+                                ..default()
+                            },
+                            TextColor(TEXT_COLOR_SECONDARY),
+                        ));
+                    }
+                    for chapter in chapters {
+                        parent.spawn((
+                            Text::new(chapter.heading.clone()),
+                            TextFont {
+                                font_size: TEXT_SIZE_LARGE,
+                                ..default()
+                            },
+                            TextColor(TEXT_COLOR_HEADER),
+                            Node {
+                                margin: UiRect::top(Val::Px(12.0)),
+                                ..default()
+                            },
+                        ));
+                        parent.spawn((
+                            Text::new(chapter.paragraph.clone()),
+                            TextFont {
+                                font_size: TEXT_SIZE_NORMAL,
+                                ..default()
+                            },
+                            TextColor(TEXT_COLOR_PRIMARY),
+                        ));
+                    }
+                });
+        });
+}
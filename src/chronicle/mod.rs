@@ -0,0 +1,29 @@
+//! World chronicle gateway
+//!
+//! The chronicle is the per-world record of what happened and when. Any
+//! system can add to it by writing a [`ChronicleEvent`]; the chronicle stamps
+//! the date and keeps the entry for the encyclopedia, reports, and saves.
+//!
+//! The chronicle and each nation's event log are also written up as prose,
+//! a paragraph per decade in the voice of the nation's culture, readable in
+//! the history panel (J) and exportable as plain text.
+//!
+//! Century-old entries are archived to disk to keep long runs lean, and read
+//! back in when the written histories are opened.
+//!
+//! # Gateway Pattern
+//!
+//! This is a PURE gateway - no implementations, only module declarations
+//! and controlled exports.
+
+// PRIVATE MODULES
+mod archive;
+mod history_ui;
+mod plugin;
+mod prose;
+mod systems;
+mod types;
+
+// CONTROLLED EXPORTS
+pub use plugin::ChroniclePlugin;
+pub use types::{ChronicleCategory, ChronicleEntry, ChronicleEvent, OpenNationHistory, WorldChronicle};
@@ -0,0 +1,42 @@
+//! World chronicle plugin
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+use super::archive::archive_old_chronicle;
+use super::systems::{
+    close_history_panel, handle_history_buttons, open_nation_history, record_chronicle_events, reset_chronicle,
+    toggle_history_panel,
+};
+use super::types::{
+    ChronicleArchive, ChronicleCategory, ChronicleEvent, HistoryView, OpenNationHistory, WorldChronicle,
+};
+use crate::states::GameState;
+
+define_plugin!(ChroniclePlugin {
+    resources: [WorldChronicle, HistoryView],
+
+    messages: [ChronicleEvent, OpenNationHistory],
+
+    reflect: [WorldChronicle, ChronicleCategory, ChronicleArchive],
+
+    update: [
+        (
+            record_chronicle_events,
+            archive_old_chronicle,
+            toggle_history_panel,
+            open_nation_history,
+            handle_history_buttons,
+        )
+            .chain()
+            .run_if(in_state(GameState::InGame))
+    ],
+
+    on_enter: {
+        GameState::LoadingWorld => [reset_chronicle]
+    },
+
+    on_exit: {
+        GameState::InGame => [close_history_panel]
+    }
+});
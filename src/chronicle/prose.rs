@@ -0,0 +1,250 @@
+//! Written histories generated from the record
+//!
+//! A nation's logged events and its chronicle entries are woven into one
+//! paragraph per decade, dated and joined in the voice of the nation's
+//! culture. Generation is deterministic: the same record always reads the
+//! same way.
+
+use crate::name_generator::{prose_style, Culture, ProseStyle};
+use crate::nations::{
+    AcquisitionMethod, HistoricalEvent, LossReason, NationHistory, ReformType, SuccessionType, WarResult,
+};
+
+use super::types::ChronicleEntry;
+
+/// One decade of a nation's written history
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProseChapter {
+    pub decade: u32,
+    pub heading: String,
+    pub paragraph: String,
+}
+
+/// A dated happening, phrased as a clause without its date
+struct Happening {
+    year: u32,
+    day: Option<u32>,
+    clause: String,
+}
+
+fn season(style: &ProseStyle, day_of_year: u32) -> &'static str {
+    match day_of_year {
+        59..=150 => style.seasons[0],
+        151..=242 => style.seasons[1],
+        243..=333 => style.seasons[2],
+        _ => style.seasons[3],
+    }
+}
+
+/// Pick a phrasing by position so repeated openings vary without randomness
+fn pick(options: &[&'static str], salt: usize) -> &'static str {
+    options.get(salt % options.len().max(1)).copied().unwrap_or("")
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn clause(event: &HistoricalEvent, nation: &str) -> (u32, String) {
+    match event {
+        HistoricalEvent::Founded { year, culture } => {
+            (*year, format!("{} was founded by the {} people", nation, culture.to_lowercase()))
+        }
+        HistoricalEvent::WarDeclared { year, enemy, aggressor: true } => {
+            (*year, format!("{} declared war on {}", nation, enemy))
+        }
+        HistoricalEvent::WarDeclared { year, enemy, aggressor: false } => {
+            (*year, format!("{} declared war on {}", enemy, nation))
+        }
+        HistoricalEvent::WarEnded { year, enemy, result } => (
+            *year,
+            match result {
+                WarResult::Victory => format!("{} emerged victorious from its war with {}", nation, enemy),
+                WarResult::Defeat => format!("{} was defeated by {}", nation, enemy),
+                WarResult::WhitePeace | WarResult::Stalemate => {
+                    format!("{} and {} made peace, neither side the victor", nation, enemy)
+                }
+            },
+        ),
+        HistoricalEvent::RulerChanged { year, old_ruler, new_ruler, reason } => (
+            *year,
+            match reason {
+                SuccessionType::Natural => format!("{} passed the crown to {}", old_ruler, new_ruler),
+                SuccessionType::Death => format!("{} died, and {} took the throne", old_ruler, new_ruler),
+                SuccessionType::Coup => format!("{} seized power from {} in a coup", new_ruler, old_ruler),
+                SuccessionType::Revolution => {
+                    format!("revolution cast down {} and raised up {}", old_ruler, new_ruler)
+                }
+                SuccessionType::Abdication => format!("{} abdicated in favour of {}", old_ruler, new_ruler),
+            },
+        ),
+        HistoricalEvent::ProvinceGained { year, province_name, method } => (
+            *year,
+            match method {
+                AcquisitionMethod::Conquest => format!("{} conquered {}", nation, province_name),
+                AcquisitionMethod::Diplomatic => format!("{} acquired {} by treaty", nation, province_name),
+                AcquisitionMethod::Settlement => format!("settlers from {} claimed {}", nation, province_name),
+                AcquisitionMethod::Inheritance => format!("{} inherited {}", nation, province_name),
+            },
+        ),
+        HistoricalEvent::ProvinceLost { year, province_name, reason } => (
+            *year,
+            match reason {
+                LossReason::Conquered => format!("{} fell to foreign conquest", province_name),
+                LossReason::Rebellion => format!("{} broke away from {} in rebellion", province_name, nation),
+                LossReason::Diplomatic => format!("{} ceded {}", nation, province_name),
+                LossReason::Economic => format!("{} could no longer hold {}", nation, province_name),
+            },
+        ),
+        HistoricalEvent::TerritorialExpansion { year, provinces_gained, .. } => {
+            (*year, format!("{} expanded across {} provinces", nation, provinces_gained))
+        }
+        HistoricalEvent::EconomicCrisis { year, .. } => (*year, format!("an economic crisis struck {}", nation)),
+        HistoricalEvent::GoldenAge { year, .. } => (*year, format!("{} entered a golden age", nation)),
+        HistoricalEvent::ReformEnacted { year, reform_type } => {
+            let kind = match reform_type {
+                ReformType::Military => "military",
+                ReformType::Economic => "economic",
+                ReformType::Administrative => "administrative",
+                ReformType::Cultural => "cultural",
+                ReformType::Religious => "religious",
+            };
+            (*year, format!("{} enacted {} reforms", nation, kind))
+        }
+        HistoricalEvent::RebellionFaced { year, suppressed: true } => {
+            (*year, format!("{} crushed a rebellion", nation))
+        }
+        HistoricalEvent::RebellionFaced { year, suppressed: false } => {
+            (*year, format!("a rebellion shook {} and could not be put down", nation))
+        }
+        HistoricalEvent::CongressAttended { year, congress, .. } => {
+            (*year, format!("{} sent envoys to the {}", nation, congress))
+        }
+        HistoricalEvent::NationUnified { year, former_name, nations_absorbed } => (
+            *year,
+            format!("{} absorbed {} neighbours and became {}", former_name, nations_absorbed, nation),
+        ),
+        HistoricalEvent::NationRenamed { year, old_name, new_name } => {
+            (*year, format!("{} took the name {}", old_name, new_name))
+        }
+    }
+}
+
+/// Write a nation's history as one paragraph per decade, oldest first
+pub fn write_nation_history(
+    nation: &str,
+    culture: Culture,
+    history: &NationHistory,
+    entries: &[&ChronicleEntry],
+) -> Vec<ProseChapter> {
+    let style = prose_style(culture);
+
+    let mut happenings: Vec<Happening> = history
+        .events
+        .iter()
+        .map(|event| {
+            let (year, clause) = clause(event, nation);
+            Happening { year, day: None, clause }
+        })
+        .chain(entries.iter().map(|entry| Happening {
+            year: entry.year,
+            day: Some(entry.day_of_year),
+            clause: entry.text.trim_end_matches('.').to_string(),
+        }))
+        .collect();
+    happenings.sort_by_key(|happening| (happening.year, happening.day.unwrap_or(0)));
+
+    let mut chapters: Vec<ProseChapter> = Vec::new();
+    let mut previous_year = None;
+    for (index, happening) in happenings.iter().enumerate() {
+        let decade = happening.year / 10 * 10;
+        if chapters.last().is_none_or(|chapter| chapter.decade != decade) {
+            chapters.push(ProseChapter {
+                decade,
+                heading: style.decade_heading.replace("{decade}", &decade.to_string()),
+                paragraph: String::new(),
+            });
+            previous_year = None;
+        }
+
+        let opening = if previous_year == Some(happening.year) {
+            pick(style.same_year, index).to_string()
+        } else if let Some(day) = happening.day {
+            style
+                .season_opening
+                .replace("{season}", season(style, day))
+                .replace("{year}", &happening.year.to_string())
+        } else {
+            pick(style.year_openings, happening.year as usize).replace("{year}", &happening.year.to_string())
+        };
+        previous_year = Some(happening.year);
+
+        if let Some(chapter) = chapters.last_mut() {
+            if !chapter.paragraph.is_empty() {
+                chapter.paragraph.push(' ');
+            }
+            chapter.paragraph.push_str(&format!("{}, {}.", capitalize(&opening), happening.clause));
+        }
+    }
+
+    chapters
+}
+
+/// Plain-text rendering of written histories, nation by nation
+pub fn histories_to_text(world_name: &str, histories: &[(String, Vec<ProseChapter>)]) -> String {
+    let mut text = format!("A History of {}\n\n", world_name);
+    for (nation, chapters) in histories {
+        text.push_str(&format!("== {} ==\n\n", nation));
+        if chapters.is_empty() {
+            text.push_str("Nothing of note has been recorded.\n\n");
+        }
+        for chapter in chapters {
+            text.push_str(&format!("{}\n{}\n\n", chapter.heading, chapter.paragraph));
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chronicle::ChronicleCategory;
+
+    #[test]
+    fn events_become_dated_paragraphs_per_decade() {
+        let mut history = NationHistory::default();
+        history.record_event(HistoricalEvent::WarDeclared {
+            year: 347,
+            enemy: "Brennmark".to_string(),
+            aggressor: true,
+        });
+        history.record_event(HistoricalEvent::RulerChanged {
+            year: 347,
+            old_ruler: "Aldric".to_string(),
+            new_ruler: "Maud".to_string(),
+            reason: SuccessionType::Death,
+        });
+        history.record_event(HistoricalEvent::GoldenAge { year: 352, prosperity: 0.9 });
+        let entry = ChronicleEntry {
+            year: 345,
+            day_of_year: 100,
+            category: ChronicleCategory::Milestone,
+            text: "Aldoria founded its first university.".to_string(),
+            nations: Vec::new(),
+        };
+
+        let chapters = write_nation_history("Aldoria", Culture::Western, &history, &[&entry]);
+
+        assert_eq!(chapters.len(), 2);
+        assert_eq!(chapters[0].heading, "The 340s");
+        assert!(chapters[0]
+            .paragraph
+            .starts_with("In the spring of 345, Aldoria founded its first university."));
+        assert!(chapters[0].paragraph.contains("Aldric died, and Maud took the throne."));
+        assert!(chapters[1].paragraph.contains("Aldoria entered a golden age."));
+    }
+}
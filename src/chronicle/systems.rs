@@ -0,0 +1,226 @@
+//! Recording chronicle entries and showing the written histories
+
+use bevy::prelude::*;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use super::archive::load_full_history;
+use super::history_ui::spawn_history_panel;
+use super::prose::{histories_to_text, write_nation_history, ProseChapter};
+use super::types::*;
+use crate::audio::{AudioCue, AudioEvent};
+use crate::nations::{Nation, NationHistory, NationId};
+use crate::simulation::GameTime;
+use crate::ui::{NotificationPosition, NotificationType, ShortcutEvent, ShortcutId, ShowNotification};
+use crate::world::WorldName;
+
+/// Date and store every chronicle request written this frame
+pub fn record_chronicle_events(
+    mut events: MessageReader<ChronicleEvent>,
+    mut chronicle: ResMut<WorldChronicle>,
+    game_time: Option<Res<GameTime>>,
+) {
+    for event in events.read() {
+        chronicle.record(ChronicleEntry {
+            year: game_time.as_ref().map_or(0, |time| time.current_year()),
+            day_of_year: game_time.as_ref().map_or(0, |time| time.day_of_year()),
+            category: event.category,
+            text: event.text.clone(),
+            nations: event.nations.clone(),
+        });
+    }
+}
+
+/// A freshly generated world starts with an empty chronicle
+///
+/// Loading a save re-inserts the saved chronicle after this runs.
+pub fn reset_chronicle(mut chronicle: ResMut<WorldChronicle>) {
+    *chronicle = WorldChronicle::default();
+}
+
+/// Where exported written histories go
+pub const HISTORY_DIRECTORY: &str = "histories";
+
+/// Every nation's written history, in name order, archived chronicle included
+fn nation_histories(
+    nations: &Query<(&Nation, &NationId, &NationHistory)>,
+    chronicle: &WorldChronicle,
+) -> Vec<(String, Vec<ProseChapter>)> {
+    let chronicle = load_full_history(chronicle);
+    let mut histories: Vec<(String, Vec<ProseChapter>)> = nations
+        .iter()
+        .map(|(nation, id, history)| {
+            let entries: Vec<&ChronicleEntry> = chronicle
+                .iter()
+                .filter(|entry| entry.nations.contains(id))
+                .collect();
+            (
+                nation.name.clone(),
+                write_nation_history(&nation.name, nation.culture, history, &entries),
+            )
+        })
+        .collect();
+    histories.sort_by(|a, b| a.0.cmp(&b.0));
+    histories
+}
+
+/// Show the viewed nation's history, from the decade of `from_year` if given
+fn show_history(
+    commands: &mut Commands,
+    view: &mut HistoryView,
+    histories: &[(String, Vec<ProseChapter>)],
+    from_year: Option<u32>,
+    panels: &Query<Entity, With<HistoryPanel>>,
+) {
+    for panel in panels {
+        commands.entity(panel).despawn();
+    }
+    if histories.is_empty() {
+        spawn_history_panel(commands, "the World", &[]);
+        return;
+    }
+    view.nation_index %= histories.len();
+    let (nation, chapters) = &histories[view.nation_index];
+    let start = from_year.map_or(0, |year| {
+        chapters
+            .iter()
+            .position(|chapter| chapter.decade >= year / 10 * 10)
+            .unwrap_or(chapters.len())
+    });
+    spawn_history_panel(commands, nation, &chapters[start..]);
+}
+
+fn export_histories(world_name: &str, year: u32, histories: &[(String, Vec<ProseChapter>)]) -> io::Result<PathBuf> {
+    fs::create_dir_all(HISTORY_DIRECTORY)?;
+    let stem: String = world_name
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    let path = PathBuf::from(HISTORY_DIRECTORY).join(format!("{}_{}.txt", stem, year));
+    fs::write(&path, histories_to_text(world_name, histories))?;
+    Ok(path)
+}
+
+/// J opens and closes the written history
+pub fn toggle_history_panel(
+    mut commands: Commands,
+    mut shortcuts: MessageReader<ShortcutEvent>,
+    mut view: ResMut<HistoryView>,
+    nations: Query<(&Nation, &NationId, &NationHistory)>,
+    chronicle: Res<WorldChronicle>,
+    panels: Query<Entity, With<HistoryPanel>>,
+    mut audio: MessageWriter<AudioEvent>,
+) {
+    if !shortcuts
+        .read()
+        .any(|event| event.shortcut_id == ShortcutId::ToggleHistory)
+    {
+        return;
+    }
+
+    if panels.is_empty() {
+        show_history(&mut commands, &mut view, &nation_histories(&nations, &chronicle), None, &panels);
+        audio.write(AudioEvent::new(AudioCue::UiOpen));
+    } else {
+        for panel in &panels {
+            commands.entity(panel).despawn();
+        }
+        audio.write(AudioEvent::new(AudioCue::UiClose));
+    }
+}
+
+/// Open a nation's history where another panel linked to
+pub fn open_nation_history(
+    mut commands: Commands,
+    mut requests: MessageReader<OpenNationHistory>,
+    mut view: ResMut<HistoryView>,
+    nations: Query<(&Nation, &NationId, &NationHistory)>,
+    chronicle: Res<WorldChronicle>,
+    panels: Query<Entity, With<HistoryPanel>>,
+    mut audio: MessageWriter<AudioEvent>,
+) {
+    let Some(request) = requests.read().last().copied() else {
+        return;
+    };
+    let Some(name) = nations
+        .iter()
+        .find(|(_, id, _)| **id == request.nation)
+        .map(|(nation, ..)| nation.name.clone())
+    else {
+        return;
+    };
+
+    let histories = nation_histories(&nations, &chronicle);
+    if let Some(index) = histories.iter().position(|(nation, _)| *nation == name) {
+        view.nation_index = index;
+    }
+    show_history(&mut commands, &mut view, &histories, request.from_year, &panels);
+    audio.write(AudioEvent::new(AudioCue::UiOpen));
+}
+
+/// Browse nations, export, and close from the history panel
+pub fn handle_history_buttons(
+    mut commands: Commands,
+    previous: Query<&Interaction, (Changed<Interaction>, With<PreviousHistoryButton>)>,
+    next: Query<&Interaction, (Changed<Interaction>, With<NextHistoryButton>)>,
+    export: Query<&Interaction, (Changed<Interaction>, With<ExportHistoryButton>)>,
+    close: Query<&Interaction, (Changed<Interaction>, With<CloseHistoryButton>)>,
+    mut view: ResMut<HistoryView>,
+    nations: Query<(&Nation, &NationId, &NationHistory)>,
+    chronicle: Res<WorldChronicle>,
+    game_time: Option<Res<GameTime>>,
+    world_name: Option<Res<WorldName>>,
+    mut notifications: MessageWriter<ShowNotification>,
+    panels: Query<Entity, With<HistoryPanel>>,
+) {
+    let pressed = |interaction: &Interaction| *interaction == Interaction::Pressed;
+
+    if close.iter().any(pressed) {
+        for panel in &panels {
+            commands.entity(panel).despawn();
+        }
+        return;
+    }
+
+    let step_back = previous.iter().any(pressed);
+    let step_forward = next.iter().any(pressed);
+    let exporting = export.iter().any(pressed);
+    if !step_back && !step_forward && !exporting {
+        return;
+    }
+
+    let histories = nation_histories(&nations, &chronicle);
+    let count = histories.len().max(1);
+    if step_back {
+        view.nation_index = (view.nation_index + count - 1) % count;
+    }
+    if step_forward {
+        view.nation_index = (view.nation_index + 1) % count;
+    }
+    if step_back || step_forward {
+        show_history(&mut commands, &mut view, &histories, None, &panels);
+    }
+
+    if exporting {
+        let name = world_name.as_ref().map_or("Unnamed World", |name| name.0.as_str());
+        let year = game_time.as_ref().map_or(0, |time| time.current_year());
+        let (message, notification_type) = match export_histories(name, year, &histories) {
+            Ok(path) => (format!("Histories exported to {}", path.display()), NotificationType::Success),
+            Err(e) => (format!("Could not export histories: {e}"), NotificationType::Error),
+        };
+        notifications.write(ShowNotification {
+            message,
+            notification_type,
+            duration: None,
+            position: NotificationPosition::TopCenter,
+        });
+    }
+}
+
+/// The history panel closes with the world
+pub fn close_history_panel(mut commands: Commands, panels: Query<Entity, With<HistoryPanel>>) {
+    for panel in &panels {
+        commands.entity(panel).despawn();
+    }
+}
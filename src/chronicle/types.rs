@@ -0,0 +1,151 @@
+//! Chronicle entries and the per-world record that holds them
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::nations::NationId;
+
+/// Broad kind of a chronicle entry, for filtering and presentation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+pub enum ChronicleCategory {
+    /// Emergent world milestones
+    Milestone,
+    War,
+    Politics,
+    Dynasty,
+    Catastrophe,
+    /// Merchant leagues and other actors of commerce
+    Trade,
+    /// Catalysts the world director introduced, recorded for transparency
+    Director,
+}
+
+/// One dated line of world history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Reflect)]
+pub struct ChronicleEntry {
+    pub year: u32,
+    pub day_of_year: u32,
+    pub category: ChronicleCategory,
+    pub text: String,
+    /// Nations the entry is about, by stable id
+    pub nations: Vec<NationId>,
+}
+
+/// A run of old entries moved out of memory into a file on disk
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Reflect)]
+pub struct ChronicleArchive {
+    pub first_year: u32,
+    pub last_year: u32,
+    pub entry_count: usize,
+    /// File name within the archive directory
+    pub file: String,
+}
+
+/// Everything recorded about the current world, oldest first
+///
+/// Old entries other than milestones are moved to disk as the world ages;
+/// `entries` holds what is still in memory and `archives` where the rest went.
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize, Reflect)]
+#[reflect(Resource)]
+pub struct WorldChronicle {
+    entries: Vec<ChronicleEntry>,
+    #[serde(default)]
+    archives: Vec<ChronicleArchive>,
+}
+
+impl WorldChronicle {
+    pub fn record(&mut self, entry: ChronicleEntry) {
+        self.entries.push(entry);
+    }
+
+    /// Entries still held in memory, including every milestone
+    pub fn entries(&self) -> &[ChronicleEntry] {
+        &self.entries
+    }
+
+    /// Archived runs of entries, oldest first
+    pub fn archives(&self) -> &[ChronicleArchive] {
+        &self.archives
+    }
+
+    /// Entries before `year` that may move to disk (everything but milestones)
+    pub fn archivable_before(&self, year: u32) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.year < year && entry.category != ChronicleCategory::Milestone)
+            .count()
+    }
+
+    /// Remove the entries before `year` that may move to disk, oldest first
+    pub fn take_archivable_before(&mut self, year: u32) -> Vec<ChronicleEntry> {
+        let (taken, kept) = std::mem::take(&mut self.entries)
+            .into_iter()
+            .partition(|entry| entry.year < year && entry.category != ChronicleCategory::Milestone);
+        self.entries = kept;
+        taken
+    }
+
+    /// Put entries back whose archive could not be written
+    pub fn restore(&mut self, mut entries: Vec<ChronicleEntry>) {
+        entries.append(&mut self.entries);
+        entries.sort_by_key(|entry| (entry.year, entry.day_of_year));
+        self.entries = entries;
+    }
+
+    pub fn add_archive(&mut self, archive: ChronicleArchive) {
+        self.archives.push(archive);
+    }
+
+    /// Rough heap and inline size of the in-memory entries
+    pub fn approx_bytes(&self) -> usize {
+        self.entries
+            .iter()
+            .map(|entry| {
+                std::mem::size_of::<ChronicleEntry>()
+                    + entry.text.capacity()
+                    + entry.nations.capacity() * std::mem::size_of::<NationId>()
+            })
+            .sum()
+    }
+}
+
+/// Request to add an entry to the chronicle, dated when it is recorded
+#[derive(Message, Debug, Clone)]
+pub struct ChronicleEvent {
+    pub category: ChronicleCategory,
+    pub text: String,
+    pub nations: Vec<NationId>,
+}
+
+/// Request to open a nation's written history, optionally from a given year on
+///
+/// Other panels use this to link into the history, e.g. from a ruler in a
+/// family tree to the decade they came to the throne.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct OpenNationHistory {
+    pub nation: NationId,
+    /// Start at the decade holding this year instead of the beginning
+    pub from_year: Option<u32>,
+}
+
+/// Which nation the written-history panel is showing, by position in name order
+#[derive(Resource, Debug, Default)]
+pub struct HistoryView {
+    pub nation_index: usize,
+}
+
+/// Root of the written-history panel
+#[derive(Component)]
+pub struct HistoryPanel;
+
+#[derive(Component)]
+pub struct PreviousHistoryButton;
+
+#[derive(Component)]
+pub struct NextHistoryButton;
+
+#[derive(Component)]
+pub struct ExportHistoryButton;
+
+#[derive(Component)]
+pub struct CloseHistoryButton;
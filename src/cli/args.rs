@@ -5,7 +5,8 @@
 //! parameters and development options.
 
 use crate::resources::WorldSize;
-use clap::Parser;
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
 
 /// Living Worlds - Command line arguments
 ///
@@ -52,6 +53,110 @@ pub struct Args {
 
     #[arg(long, help = "Display FPS counter")]
     pub show_fps: bool,
+
+    #[arg(
+        long,
+        value_name = "ADDR",
+        conflicts_with = "metrics_otlp",
+        help = "Serve Prometheus metrics on this address, e.g. 0.0.0.0:9464 (requires the metrics feature)"
+    )]
+    pub metrics_prometheus: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "URL",
+        help = "Push OTLP/HTTP metrics to this collector endpoint (requires the metrics feature)"
+    )]
+    pub metrics_otlp: Option<String>,
+
+    #[arg(
+        long,
+        help = "Run a seed twice headlessly with different thread counts and compare yearly hashes, then exit"
+    )]
+    pub verify_determinism: bool,
+
+    #[arg(
+        long,
+        default_value = "42",
+        requires = "verify_determinism",
+        help = "Seed for the determinism check"
+    )]
+    pub determinism_seed: u32,
+
+    #[arg(
+        long,
+        default_value = "10",
+        requires = "verify_determinism",
+        help = "Years to simulate in each determinism run"
+    )]
+    pub determinism_years: u32,
+
+    #[arg(
+        long,
+        hide = true,
+        requires = "verify_determinism",
+        help = "Run one side of a determinism check on --threads workers and print its yearly hashes"
+    )]
+    pub determinism_side: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Tools that run without starting the game
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Inspect and validate save files
+    Save {
+        #[command(subcommand)]
+        action: SaveCommand,
+    },
+    /// Generate a world without starting the game, loadable later from the main menu
+    Gen(GenArgs),
+}
+
+/// `gen` options
+#[derive(clap::Args, Debug)]
+pub struct GenArgs {
+    #[arg(long, help = "World seed (random if omitted)")]
+    pub seed: Option<u32>,
+
+    #[arg(
+        long,
+        default_value = "medium",
+        value_parser = parse_world_size,
+        help = "World size: small, medium, or large"
+    )]
+    pub size: WorldSize,
+
+    #[arg(long, help = "World name (generated from the seed if omitted)")]
+    pub name: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Where to write the world (default: saves/world_<seed>.lwworld)"
+    )]
+    pub out: Option<PathBuf>,
+
+    #[arg(
+        long,
+        default_value = "historical",
+        help = "AI behavior pack: historical, chaotic, peaceful, or darwinian"
+    )]
+    pub behavior: String,
+
+    #[arg(long, help = "Also write elevation, biome, and political PNG previews beside the world")]
+    pub previews: bool,
+}
+
+/// `save` subcommands
+#[derive(Subcommand, Debug)]
+pub enum SaveCommand {
+    /// Print a save's metadata, mods, nations, and integrity check results
+    Inspect { file: PathBuf },
+    /// Check a save for corruption, reporting which section failed; exits with an error if any check fails
+    Validate { file: PathBuf },
 }
 
 /// Parse and validate world size from string
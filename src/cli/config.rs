@@ -4,7 +4,7 @@
 //! command-line arguments.
 
 use super::args::Args;
-use crate::{AppConfig, DiagnosticsConfig};
+use crate::{AppConfig, DiagnosticsConfig, MetricsConfig, MetricsExporter};
 
 /// FPS counter update interval in seconds
 const DEFAULT_FPS_UPDATE_INTERVAL_SECS: f32 = 1.0;
@@ -13,7 +13,19 @@ const DEFAULT_FPS_UPDATE_INTERVAL_SECS: f32 = 1.0;
 ///
 /// Constructs application configuration with CLI-driven overrides.
 /// FPS display is enabled when `--show-fps` or `--debug` flags are set.
+/// Metrics export is enabled by `--metrics-prometheus` or `--metrics-otlp`.
+/// Tuning hot-reload is on in development runs (`--debug` or `--dev-quick-start`).
 pub fn build_app_config(args: &Args) -> AppConfig {
+    let exporter = match (&args.metrics_prometheus, &args.metrics_otlp) {
+        (Some(bind_address), _) => Some(MetricsExporter::Prometheus {
+            bind_address: bind_address.clone(),
+        }),
+        (None, Some(endpoint)) => Some(MetricsExporter::Otlp {
+            endpoint: endpoint.clone(),
+        }),
+        (None, None) => None,
+    };
+
     AppConfig {
         window: Default::default(),
         diagnostics: DiagnosticsConfig {
@@ -21,6 +33,11 @@ pub fn build_app_config(args: &Args) -> AppConfig {
             fps_interval: DEFAULT_FPS_UPDATE_INTERVAL_SECS,
             ..Default::default()
         },
+        metrics: MetricsConfig {
+            exporter,
+            ..Default::default()
+        },
+        hot_reload_tuning: args.debug || args.dev_quick_start,
         ..Default::default()
     }
 }
@@ -0,0 +1,83 @@
+//! Determinism Verification Mode for Living Worlds
+//!
+//! Backs the `--verify-determinism` flag: runs the chosen seed twice, once
+//! single-threaded and once on the configured worker count, and fails if any
+//! year's simulation hash differs. Each run is a child process of this
+//! executable started with `--determinism-side`, so Bevy's task pools as well
+//! as rayon's are sized to that run's thread count.
+
+use super::args::Args;
+use crate::simulation::{DeterminismCheck, DeterminismError};
+use bevy::log::{error, info};
+use std::io::{self, Write};
+
+/// Worker count used for the second run when `--threads` is left on auto
+const FALLBACK_COMPARISON_THREADS: usize = 4;
+
+/// Run the determinism check described by the command line arguments
+///
+/// # Returns
+/// * `Ok(())` - Every simulated year hashed identically in both runs
+/// * `Err(DeterminismError)` - A run failed or the runs diverged
+pub fn run_determinism_check(args: &Args) -> Result<(), DeterminismError> {
+    if args.determinism_side {
+        return run_determinism_side(args);
+    }
+
+    let comparison_threads = if args.threads > 0 {
+        args.threads
+    } else {
+        std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(FALLBACK_COMPARISON_THREADS)
+    }
+    .max(2);
+
+    info!(
+        "Verifying determinism: seed {}, {} years, 1 vs {} threads",
+        args.determinism_seed, args.determinism_years, comparison_threads
+    );
+
+    let executable = std::env::current_exe().map_err(|e| DeterminismError::Process {
+        threads: comparison_threads,
+        reason: format!("cannot find this executable to rerun: {}", e),
+    })?;
+    match DeterminismCheck::new(args.determinism_seed)
+        .years(args.determinism_years)
+        .thread_counts(1, comparison_threads)
+        .in_child_processes(executable)
+        .run()
+    {
+        Ok(report) => {
+            info!(
+                "Determinism verified: {} years identical, final hash {:016x}",
+                report.years_compared, report.final_hash
+            );
+            Ok(())
+        }
+        Err(e) => {
+            error!("Determinism check failed: {}", e);
+            Err(e)
+        }
+    }
+}
+
+/// One side of a determinism check, started by the parent check
+///
+/// Runs on `--threads` workers in this fresh process and writes each year's
+/// hash to stdout for the parent to compare.
+fn run_determinism_side(args: &Args) -> Result<(), DeterminismError> {
+    let threads = args.threads.max(1);
+    let hashes = DeterminismCheck::new(args.determinism_seed)
+        .years(args.determinism_years)
+        .run_with_threads(threads)?;
+
+    let mut out = io::stdout().lock();
+    for hash in &hashes {
+        writeln!(out, "{}", hash.to_line()).map_err(|e| DeterminismError::Process {
+            threads,
+            reason: e.to_string(),
+        })?;
+    }
+    Ok(())
+}
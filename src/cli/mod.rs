@@ -5,6 +5,9 @@
 //! - Command-line argument parsing and validation
 //! - Application configuration building from CLI inputs
 //! - Development mode parameter processing
+//! - Headless determinism verification (`--verify-determinism`)
+//! - Save file inspection and validation (`save inspect`, `save validate`)
+//! - Headless world generation to a loadable file (`gen`)
 //! - Error handling for invalid command-line inputs
 //!
 //! # Gateway Architecture
@@ -26,8 +29,15 @@
 // Private module declarations - implementation details hidden from external code
 mod args;
 mod config;
+mod determinism;
+mod save_tool;
+mod world_gen;
 
 // Public exports - controlled API surface following gateway pattern
-pub use args::Args;
+pub use args::{Args, Command, GenArgs, SaveCommand};
 pub use clap::Parser;
 pub use config::build_app_config;
+pub use determinism::run_determinism_check;
+pub use save_tool::run_save_command;
+pub(crate) use world_gen::generate_world_save;
+pub use world_gen::run_world_generation;
@@ -0,0 +1,146 @@
+//! Save File Tools for Living Worlds
+//!
+//! Backs the `save inspect` and `save validate` subcommands, which read a
+//! save without starting the game - for bug reports, and for checking saves
+//! before and after a format migration.
+
+use super::args::SaveCommand;
+use crate::nations::NationId;
+use crate::save_load::{format_file_size, inspect_save_file, SaveGameData, SaveInspection};
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+/// Nations listed by `save inspect`, largest first
+const LISTED_NATIONS: usize = 15;
+
+/// Run a `save` subcommand, writing its report to stdout
+///
+/// # Errors
+/// Fails if the report can't be written, or if any integrity check fails.
+pub fn run_save_command(command: &SaveCommand) -> Result<(), Box<dyn std::error::Error>> {
+    let mut out = io::stdout().lock();
+    let inspection = match command {
+        SaveCommand::Inspect { file } => {
+            let inspection = inspect_save_file(file);
+            write_metadata(&mut out, &inspection)?;
+            if let Some(data) = &inspection.data {
+                write_nations(&mut out, data)?;
+            }
+            inspection
+        }
+        SaveCommand::Validate { file } => inspect_save_file(file),
+    };
+    write_checks(&mut out, &inspection)?;
+
+    let failed = inspection.checks.iter().filter(|check| check.outcome.is_err()).count();
+    if failed > 0 {
+        return Err(format!("{} failed {} integrity checks", inspection.path.display(), failed).into());
+    }
+    Ok(())
+}
+
+fn write_metadata(out: &mut impl Write, inspection: &SaveInspection) -> io::Result<()> {
+    writeln!(out, "Save:        {}", inspection.path.display())?;
+    writeln!(
+        out,
+        "File:        {} ({}), {} uncompressed",
+        format_file_size(inspection.file_size),
+        inspection
+            .codec
+            .map_or_else(|| "legacy zstd".to_string(), |codec| codec.name()),
+        format_file_size(inspection.uncompressed_size as u64)
+    )?;
+
+    let Some(data) = &inspection.data else {
+        return Ok(());
+    };
+    writeln!(
+        out,
+        "Version:     {} (this game writes {})",
+        data.version,
+        crate::save_load::SAVE_VERSION
+    )?;
+    writeln!(
+        out,
+        "World:       \"{}\", seed {}, {:?}, generator v{}",
+        data.world_name, data.world_seed, data.world_size, data.generation_version
+    )?;
+    writeln!(out, "Written:     {}", data.timestamp.format("%Y-%m-%d %H:%M:%S"))?;
+    writeln!(
+        out,
+        "Game date:   year {}, day {}",
+        data.game_time.current_year(),
+        data.game_time.day_of_year()
+    )?;
+    let minutes = (data.play_time_secs / 60.0) as u64;
+    writeln!(out, "Play time:   {}h {:02}m", minutes / 60, minutes % 60)?;
+    if data.mods.is_empty() {
+        writeln!(out, "Mods:        none")?;
+    } else {
+        let mods: Vec<String> = data
+            .mods
+            .iter()
+            .map(|saved| format!("{} {}", saved.id, saved.version))
+            .collect();
+        writeln!(out, "Mods:        {}", mods.join(", "))?;
+    }
+    writeln!(out, "AI behavior: {}", data.ai_behavior.pack)?;
+    writeln!(
+        out,
+        "History:     {} chronicle entries in the save",
+        data.chronicle.entries().len()
+    )
+}
+
+fn write_nations(out: &mut impl Write, data: &SaveGameData) -> io::Result<()> {
+    let mut holdings: HashMap<NationId, (usize, u64)> = HashMap::new();
+    for (province, owner) in data.provinces.iter().zip(&data.province_owners) {
+        if let Some(owner) = owner {
+            let held = holdings.entry(*owner).or_default();
+            held.0 += 1;
+            held.1 += u64::from(province.population);
+        }
+    }
+
+    let mut nations: Vec<_> = data.nations.iter().collect();
+    nations.sort_by_key(|(id, _)| std::cmp::Reverse(holdings.get(id).map_or(0, |held| held.0)));
+
+    writeln!(out)?;
+    writeln!(out, "Nations ({}):", nations.len())?;
+    writeln!(
+        out,
+        "  {:<6} {:<28} {:<20} {:>9} {:>12} {:>10}",
+        "id", "name", "government", "provinces", "population", "treasury"
+    )?;
+    for (id, nation) in nations.iter().take(LISTED_NATIONS) {
+        let (provinces, population) = holdings.get(id).copied().unwrap_or_default();
+        let government = data.nation_governance.get(id).map_or_else(
+            || "-".to_string(),
+            |governance| format!("{:?}", governance.government_type),
+        );
+        writeln!(
+            out,
+            "  {:<6} {:<28} {:<20} {:>9} {:>12} {:>10.0}",
+            id, nation.name, government, provinces, population, nation.treasury.to_f32()
+        )?;
+    }
+    if nations.len() > LISTED_NATIONS {
+        writeln!(out, "  ... and {} more", nations.len() - LISTED_NATIONS)?;
+    }
+    Ok(())
+}
+
+fn write_checks(out: &mut impl Write, inspection: &SaveInspection) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "Integrity:")?;
+    for check in &inspection.checks {
+        match &check.outcome {
+            Ok(found) => writeln!(out, "  [ok]   {:<12} {}", check.section, found)?,
+            Err(problem) => writeln!(out, "  [FAIL] {:<12} {}", check.section, problem)?,
+        }
+    }
+    if inspection.is_valid() {
+        writeln!(out, "Save is sound.")?;
+    }
+    Ok(())
+}
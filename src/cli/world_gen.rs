@@ -0,0 +1,187 @@
+//! Headless World Generation for Living Worlds
+//!
+//! Backs the `gen` subcommand: runs the world builder without a window,
+//! spawns the starting nations, and writes the result as a `.lwworld` file
+//! that the main menu's load browser lists beside regular saves. Map
+//! previews can be written alongside it as PNG images.
+
+use super::args::GenArgs;
+use crate::ai::{AiBehavior, BehaviorPacks};
+use crate::name_generator::{NameGenerator, NameType};
+use crate::nations::{spawn_nations, Governance, NationGenerationSettings};
+use crate::save_load::{
+    format_file_size, write_save_data, SaveGameData, SAVE_DIRECTORY, SAVE_VERSION, WORLD_EXTENSION,
+};
+use crate::simulation::GameTime;
+use crate::world::{
+    assign_cultures_to_province_storage, GenerationPreview, MapDimensions, ProvinceGraph, WorldBuilder,
+    WorldGenerationError, WorldGenerationSettings, WorldSize,
+};
+use bevy::prelude::Color;
+use chrono::Local;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// Generate a world from the `gen` arguments and write it to disk
+///
+/// # Errors
+/// Fails if generation fails or the world or its previews can't be written.
+pub fn run_world_generation(args: &GenArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let mut out = io::stdout().lock();
+
+    // Same defaults as the world configuration screen
+    let defaults = WorldGenerationSettings::default();
+    let seed = args.seed.unwrap_or(defaults.seed);
+    let world_name = args
+        .name
+        .clone()
+        .unwrap_or_else(|| NameGenerator::with_seed(u64::from(seed)).generate(NameType::World));
+    let path = args
+        .out
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(SAVE_DIRECTORY).join(format!("world_{}.{}", seed, WORLD_EXTENSION)));
+
+    writeln!(out, "Generating \"{}\": seed {}, {:?}", world_name, seed, args.size)?;
+    let dimensions = MapDimensions::from_world_size(&args.size);
+    let ai_behavior = AiBehavior::from_pack(&BehaviorPacks::default(), &args.behavior);
+    let save_data = generate_world_save(world_name, seed, args.size, dimensions, ai_behavior)?;
+
+    if args.previews {
+        let provinces = &save_data.provinces;
+        let nation_colors: HashMap<_, _> = save_data
+            .nations
+            .iter()
+            .map(|(id, nation)| (*id, nation.color))
+            .collect();
+        let province_colors: HashMap<u32, Color> = save_data
+            .province_owners
+            .iter()
+            .enumerate()
+            .filter_map(|(index, owner)| Some((index as u32, *nation_colors.get(owner.as_ref()?)?)))
+            .collect();
+        for (suffix, preview) in [
+            ("elevation", GenerationPreview::elevation(provinces, dimensions)),
+            ("biome", GenerationPreview::climate(provinces, dimensions, seed)),
+            (
+                "political",
+                GenerationPreview::nations(provinces, dimensions, seed, &province_colors),
+            ),
+        ] {
+            let preview_path = preview_path(&path, suffix);
+            preview.to_image().try_into_dynamic()?.save(&preview_path)?;
+            writeln!(out, "Preview:     {}", preview_path.display())?;
+        }
+    }
+
+    let province_count = save_data.provinces.len();
+    let nation_count = save_data.nations.len();
+    let size = write_save_data(&save_data, &path)?;
+    writeln!(
+        out,
+        "World:       {} ({} provinces, {} nations, {})",
+        path.display(),
+        province_count,
+        nation_count,
+        format_file_size(size)
+    )?;
+    Ok(())
+}
+
+/// Generate a world with its starting nations as save data, ready to write or load
+///
+/// Everything but the seed, size and map grid uses the world configuration
+/// screen's defaults.
+///
+/// # Errors
+/// Fails if world generation fails.
+pub(crate) fn generate_world_save(
+    world_name: String,
+    seed: u32,
+    size: WorldSize,
+    dimensions: MapDimensions,
+    ai_behavior: AiBehavior,
+) -> Result<SaveGameData, WorldGenerationError> {
+    let defaults = WorldGenerationSettings::default();
+    let world = WorldBuilder::new(
+        seed,
+        size,
+        defaults.continent_count,
+        defaults.ocean_coverage,
+        defaults.river_density,
+        defaults.climate_type,
+    )
+    .with_dimensions(dimensions)
+    .with_erosion(defaults.erosion_iterations, defaults.erosion_droplets.multiplier())
+    .build()?;
+
+    let mut provinces = world.provinces;
+    assign_cultures_to_province_storage(&mut provinces, Some(u64::from(seed)));
+    let (nations, houses, governments, ownership) =
+        spawn_nations(&NationGenerationSettings::default(), &mut provinces, seed);
+
+    let mut province_owners = vec![None; provinces.len()];
+    for (nation_id, owned) in &ownership {
+        for &index in owned {
+            if let Some(owner) = province_owners.get_mut(index as usize) {
+                *owner = Some(*nation_id);
+            }
+        }
+    }
+
+    // Houses come one per nation, in nation order, as the GUI spawns them
+    let houses = nations.iter().map(|(id, _)| *id).zip(houses).collect();
+
+    let province_graph = ProvinceGraph::build(&provinces);
+    Ok(SaveGameData {
+        version: SAVE_VERSION,
+        timestamp: Local::now(),
+        world_name,
+        world_seed: seed,
+        world_size: size,
+        generation_version: defaults.generation_version,
+        map_dimensions: dimensions,
+        game_time: GameTime::new(defaults.starting_year),
+        world_tension: Default::default(),
+        map_mode: Default::default(),
+        map_heatmap: None,
+        provinces,
+        nation_laws: nations.iter().map(|(id, _)| (*id, Default::default())).collect(),
+        nation_governance: nations
+            .iter()
+            .zip(&governments)
+            .map(|((id, _), government)| (*id, Governance::founded(*government)))
+            .collect(),
+        economic_focus: nations.iter().map(|(id, _)| (*id, Default::default())).collect(),
+        nations,
+        province_owners,
+        play_time_secs: 0.0,
+        mods: Vec::new(),
+        id_allocator: Default::default(),
+        chronicle: Default::default(),
+        milestones: Default::default(),
+        director: Default::default(),
+        statistics: Default::default(),
+        ai_behavior,
+        scripted_events: Default::default(),
+        sea_level: Default::default(),
+        mod_settings: Default::default(),
+        province_graph,
+        climate: world.climate_storage,
+        treaties: Vec::new(),
+        cores: Vec::new(),
+        houses,
+        devastation: Default::default(),
+        nation_state: Vec::new(),
+        forts: Vec::new(),
+        supply_depots: Vec::new(),
+        trade_leagues: Vec::new(),
+        refugees: Default::default(),
+    })
+}
+
+/// `world.lwworld` previews as `world_elevation.png`, beside the world file
+fn preview_path(world_path: &Path, suffix: &str) -> PathBuf {
+    let stem = world_path.file_stem().and_then(|stem| stem.to_str()).unwrap_or("world");
+    world_path.with_file_name(format!("{}_{}.png", stem, suffix))
+}
@@ -4,14 +4,17 @@
 //! all other configuration types for a unified configuration interface.
 
 // Import sibling configuration modules
-use super::{DiagnosticsConfig, WindowConfig};
+use super::{DiagnosticsConfig, MetricsConfig, WindowConfig};
 
 /// Complete application configuration
 #[derive(Debug, Clone)]
 pub struct AppConfig {
     pub window: WindowConfig,
     pub diagnostics: DiagnosticsConfig,
+    pub metrics: MetricsConfig,
     pub enable_audio: bool,
+    /// Reload `config/base/tuning.ron` whenever it is saved (development runs)
+    pub hot_reload_tuning: bool,
 }
 
 impl Default for AppConfig {
@@ -19,7 +22,9 @@ impl Default for AppConfig {
         Self {
             window: WindowConfig::default(),
             diagnostics: DiagnosticsConfig::default(),
+            metrics: MetricsConfig::default(),
             enable_audio: false,
+            hot_reload_tuning: false,
         }
     }
 }
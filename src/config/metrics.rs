@@ -0,0 +1,42 @@
+//! Metrics Configuration - Telemetry Export Settings
+//!
+//! This module provides configuration for exporting simulation metrics during
+//! long unattended runs. The exporter itself is only compiled with the
+//! `metrics` feature; without it these settings are accepted and ignored.
+
+use bevy::prelude::*;
+
+/// Default interval between metric samples, in seconds
+const DEFAULT_SAMPLE_INTERVAL_SECS: f32 = 5.0;
+
+/// Where collected metrics are published
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetricsExporter {
+    /// Serve the Prometheus text format for scraping, e.g. "0.0.0.0:9464"
+    Prometheus { bind_address: String },
+    /// Push OTLP/HTTP JSON to a collector, e.g. "http://localhost:4318/v1/metrics"
+    Otlp { endpoint: String },
+}
+
+/// Configuration for metrics export
+#[derive(Debug, Clone, Resource)]
+pub struct MetricsConfig {
+    /// `None` disables collection entirely
+    pub exporter: Option<MetricsExporter>,
+    pub sample_interval_secs: f32,
+}
+
+impl MetricsConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.exporter.is_some()
+    }
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            exporter: None,
+            sample_interval_secs: DEFAULT_SAMPLE_INTERVAL_SECS,
+        }
+    }
+}
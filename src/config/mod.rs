@@ -5,6 +5,7 @@
 //! - Application-wide configuration settings
 //! - Window configuration and display settings
 //! - Diagnostics and performance monitoring configuration
+//! - Metrics export configuration for long headless runs
 //! - Default value management and validation
 //!
 //! # Gateway Architecture
@@ -31,9 +32,11 @@
 // Private module declarations - implementation details hidden from external code
 mod app;
 mod diagnostics;
+mod metrics;
 mod window;
 
 // Public exports - controlled API surface following gateway pattern
 pub use app::AppConfig;
 pub use diagnostics::DiagnosticsConfig;
+pub use metrics::{MetricsConfig, MetricsExporter};
 pub use window::WindowConfig;
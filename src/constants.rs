@@ -61,7 +61,7 @@ pub const CAMERA_PAN_SPEED_BASE: f32 = 500.0;
 /// Speed multiplier when holding Shift
 pub const CAMERA_SPEED_MULTIPLIER: f32 = 3.0;
 
-/// Distance from window edge to trigger edge panning (pixels)
+/// Default distance from window edge to trigger edge panning (pixels)
 pub const CAMERA_EDGE_PAN_THRESHOLD: f32 = 10.0;
 
 /// Base speed for edge panning (pixels per second)
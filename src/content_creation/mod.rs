@@ -11,6 +11,7 @@ mod detection;
 mod recording;
 mod export;
 mod highlights;
+mod watch;
 
 // CONTROLLED PUBLIC EXPORTS
 
@@ -13,6 +13,7 @@ use super::export::{handle_export_requests, ExportPipeline};
 use super::highlights::{track_highlights, update_highlight_reel, HighlightReel};
 use super::recording::{handle_recording_commands, update_recorder, ContentRecorder};
 use super::types::{ExportRequest, RecordingCommand, ViralMomentDetected};
+use super::watch::{handle_watch_commands, run_watch_script, stop_watch_script, WatchPlayer};
 
 define_plugin!(ContentCreationPlugin {
     resources: [
@@ -20,6 +21,7 @@ define_plugin!(ContentCreationPlugin {
         ContentRecorder,
         HighlightReel,
         ExportPipeline,
+        WatchPlayer,
     ],
 
     messages: [
@@ -42,5 +44,14 @@ define_plugin!(ContentCreationPlugin {
 
         // Export pipeline
         handle_export_requests,
+
+        // Watch mode scripts
+        (handle_watch_commands, run_watch_script)
+            .chain()
+            .run_if(in_state(GameState::InGame)),
     ],
+
+    on_enter: {
+        GameState::MainMenu => [stop_watch_script]
+    },
 });
\ No newline at end of file
@@ -0,0 +1,9 @@
+//! Watch mode - scripted camera, overlay and speed sequences
+//!
+//! Scripts are played from the developer console (`watch play <name>`) so
+//! the same world history can be filmed the same way every time.
+
+mod player;
+mod script;
+
+pub use player::{handle_watch_commands, run_watch_script, stop_watch_script, WatchPlayer};
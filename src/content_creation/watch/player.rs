@@ -0,0 +1,191 @@
+//! Watch script playback
+//!
+//! Steps fire when the game date reaches them. A `Hold` pauses the
+//! simulation for real seconds so captions and camera glides can land
+//! before time moves on.
+
+use bevy::prelude::*;
+use std::time::Duration;
+
+use super::script::{list_watch_scripts, WatchAction, WatchScript};
+use crate::camera::CameraController;
+use crate::nations::Nation;
+use crate::resources::{GameTime, MapMode};
+use crate::simulation::SimulationSpeedChanged;
+use crate::ui::{ConsoleCommand, ConsoleOutput, NotificationPosition, NotificationType, ShowNotification};
+use crate::world::ProvinceStorage;
+
+/// The script being played, if any
+#[derive(Resource, Default)]
+pub struct WatchPlayer {
+    script: Option<WatchScript>,
+    next_step: usize,
+    hold: Option<Timer>,
+}
+
+impl WatchPlayer {
+    pub fn start(&mut self, script: WatchScript) {
+        *self = Self {
+            script: Some(script),
+            ..Default::default()
+        };
+    }
+
+    pub fn stop(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// `watch list`, `watch play <script>` and `watch stop` from the console
+pub fn handle_watch_commands(
+    mut commands_in: MessageReader<ConsoleCommand>,
+    mut output: MessageWriter<ConsoleOutput>,
+    mut player: ResMut<WatchPlayer>,
+    mut game_time: ResMut<GameTime>,
+) {
+    for command in commands_in.read().filter(|command| command.name == "watch") {
+        let reply = match command.args.first().map(String::as_str) {
+            Some("list") => match list_watch_scripts() {
+                names if names.is_empty() => "No scripts in watch_scripts/".to_string(),
+                names => names.join(", "),
+            },
+            Some("play") => match command.args.get(1).map(|name| WatchScript::load(name)) {
+                Some(Ok(script)) => {
+                    let reply = format!("Playing '{}' ({} steps)", script.name, script.steps.len());
+                    player.start(script);
+                    reply
+                }
+                Some(Err(e)) => e,
+                None => "Usage: watch play <script>".to_string(),
+            },
+            Some("stop") => {
+                if player.hold.is_some() {
+                    game_time.resume();
+                }
+                player.stop();
+                "Watch script stopped".to_string()
+            }
+            _ => "Usage: watch list | play <script> | stop".to_string(),
+        };
+        output.write(ConsoleOutput(reply));
+    }
+}
+
+/// Run every step whose date has arrived
+pub fn run_watch_script(
+    time: Res<Time>,
+    mut player: ResMut<WatchPlayer>,
+    mut game_time: ResMut<GameTime>,
+    mut map_mode: ResMut<MapMode>,
+    mut cameras: Query<&mut CameraController>,
+    nations: Query<&Nation>,
+    province_storage: Option<Res<ProvinceStorage>>,
+    mut speed_events: MessageWriter<SimulationSpeedChanged>,
+    mut notifications: MessageWriter<ShowNotification>,
+    mut output: MessageWriter<ConsoleOutput>,
+) {
+    let player = &mut *player;
+    let Some(script) = &player.script else {
+        return;
+    };
+
+    if let Some(hold) = &mut player.hold {
+        if !hold.tick(time.delta()).just_finished() {
+            return;
+        }
+        player.hold = None;
+        game_time.resume();
+        speed_events.write(SimulationSpeedChanged {
+            new_speed: game_time.get_speed().multiplier(),
+            is_paused: game_time.is_paused(),
+        });
+    }
+
+    let (year, day) = (game_time.current_year(), game_time.day_of_year());
+    while let Some(step) = script.steps.get(player.next_step) {
+        if !step.is_due(year, day) {
+            break;
+        }
+        player.next_step += 1;
+
+        match &step.action {
+            WatchAction::Camera { x, y, zoom } => {
+                for mut controller in &mut cameras {
+                    controller.target_position.x = *x;
+                    controller.target_position.y = *y;
+                    if let Some(zoom) = zoom {
+                        controller.target_zoom = *zoom;
+                    }
+                }
+            }
+            WatchAction::FocusNation { name, zoom } => {
+                let capital = nations
+                    .iter()
+                    .find(|nation| nation.name.eq_ignore_ascii_case(name))
+                    .and_then(|nation| {
+                        province_storage
+                            .as_ref()?
+                            .provinces
+                            .get(nation.capital_province.value() as usize)
+                            .map(|province| province.position)
+                    });
+                match capital {
+                    Some(position) => {
+                        for mut controller in &mut cameras {
+                            controller.target_position.x = position.x;
+                            controller.target_position.y = position.y;
+                            if let Some(zoom) = zoom {
+                                controller.target_zoom = *zoom;
+                            }
+                        }
+                    }
+                    None => {
+                        output.write(ConsoleOutput(format!("Watch: no nation named '{}'", name)));
+                    }
+                }
+            }
+            WatchAction::MapMode(mode) => *map_mode = *mode,
+            WatchAction::Speed(speed) => {
+                game_time.set_speed(*speed);
+                speed_events.write(SimulationSpeedChanged {
+                    new_speed: speed.multiplier(),
+                    is_paused: game_time.is_paused(),
+                });
+            }
+            WatchAction::Pause => {
+                game_time.pause();
+                speed_events.write(SimulationSpeedChanged {
+                    new_speed: 0.0,
+                    is_paused: true,
+                });
+            }
+            WatchAction::Hold { seconds } => {
+                game_time.pause();
+                speed_events.write(SimulationSpeedChanged {
+                    new_speed: 0.0,
+                    is_paused: true,
+                });
+                player.hold = Some(Timer::new(Duration::from_secs_f32(seconds.max(0.0)), TimerMode::Once));
+                return;
+            }
+            WatchAction::Caption { text, seconds } => {
+                notifications.write(ShowNotification {
+                    message: text.clone(),
+                    notification_type: NotificationType::Info,
+                    duration: Some(Duration::from_secs_f32(seconds.max(0.5))),
+                    position: NotificationPosition::Banner,
+                });
+            }
+        }
+    }
+
+    if player.next_step >= script.steps.len() {
+        output.write(ConsoleOutput(format!("Watch script '{}' finished", script.name)));
+        player.stop();
+    }
+}
+
+/// Leaving the world ends any script
+pub fn stop_watch_script(mut player: ResMut<WatchPlayer>) {
+    player.stop();
+}
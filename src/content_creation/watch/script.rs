@@ -0,0 +1,145 @@
+//! Watch script format
+//!
+//! A watch script is a RON file in `watch_scripts/` listing actions at game
+//! dates. Steps run in date order as the simulation reaches them:
+//!
+//! ```ron
+//! WatchScript(
+//!     name: "Rise of the east",
+//!     steps: [
+//!         (year: 1000, action: Speed(Fast)),
+//!         (year: 1000, action: FocusNation(name: "Valoria", zoom: Some(1.5))),
+//!         (year: 1040, day: 180, action: MapMode(Cores)),
+//!         (year: 1040, day: 180, action: Caption(text: "The old cores fracture", seconds: 4.0)),
+//!         (year: 1041, action: Hold(seconds: 6.0)),
+//!         (year: 1100, action: Camera(x: 0.0, y: 0.0, zoom: Some(3.0))),
+//!         (year: 1200, action: Pause),
+//!     ],
+//! )
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::resources::MapMode;
+use crate::simulation::SimulationSpeed;
+
+/// Directory scanned for `*.ron` watch scripts
+pub const WATCH_SCRIPT_DIRECTORY: &str = "watch_scripts";
+
+/// One scripted action
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum WatchAction {
+    /// Glide the camera to a world position, optionally changing zoom
+    Camera { x: f32, y: f32, zoom: Option<f32> },
+    /// Glide the camera to a nation's capital
+    FocusNation { name: String, zoom: Option<f32> },
+    /// Switch the map overlay
+    MapMode(MapMode),
+    /// Change simulation speed
+    Speed(SimulationSpeed),
+    /// Pause for some real seconds, then resume at the previous speed
+    Hold { seconds: f32 },
+    /// Pause until the viewer resumes
+    Pause,
+    /// Show a caption banner
+    Caption { text: String, seconds: f32 },
+}
+
+/// An action and the game date it runs at
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WatchStep {
+    pub year: u32,
+    /// Day of the year, 0 for its first day
+    #[serde(default)]
+    pub day: u32,
+    pub action: WatchAction,
+}
+
+impl WatchStep {
+    pub fn is_due(&self, year: u32, day: u32) -> bool {
+        (self.year, self.day) <= (year, day)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WatchScript {
+    pub name: String,
+    pub steps: Vec<WatchStep>,
+}
+
+impl WatchScript {
+    /// Parse a script and put its steps in date order
+    ///
+    /// The sort is stable, so steps sharing a date keep their written order.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut script: WatchScript = ron::from_str(text).map_err(|e| format!("Invalid watch script: {}", e))?;
+        script.steps.sort_by_key(|step| (step.year, step.day));
+        Ok(script)
+    }
+
+    pub fn load(name: &str) -> Result<Self, String> {
+        let path = script_path(name);
+        let text = fs::read_to_string(&path).map_err(|e| format!("Cannot read {:?}: {}", path, e))?;
+        Self::parse(&text)
+    }
+}
+
+fn script_path(name: &str) -> PathBuf {
+    let file = if name.ends_with(".ron") {
+        name.to_string()
+    } else {
+        format!("{}.ron", name)
+    };
+    PathBuf::from(WATCH_SCRIPT_DIRECTORY).join(file)
+}
+
+/// Names of the scripts available to play
+pub fn list_watch_scripts() -> Vec<String> {
+    let Ok(entries) = fs::read_dir(WATCH_SCRIPT_DIRECTORY) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "ron"))
+        .filter_map(|path| path.file_stem().and_then(|s| s.to_str()).map(str::to_string))
+        .collect();
+    names.sort();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_orders_steps_by_date() {
+        let script = WatchScript::parse(
+            r#"WatchScript(
+                name: "test",
+                steps: [
+                    (year: 1010, action: Pause),
+                    (year: 1000, day: 200, action: MapMode(Terrain)),
+                    (year: 1000, action: Speed(Fast)),
+                    (year: 1000, action: Hold(seconds: 2.0)),
+                ],
+            )"#,
+        )
+        .expect("script parses");
+
+        let actions: Vec<_> = script.steps.iter().map(|step| &step.action).collect();
+        assert_eq!(
+            actions,
+            vec![
+                &WatchAction::Speed(SimulationSpeed::Fast),
+                &WatchAction::Hold { seconds: 2.0 },
+                &WatchAction::MapMode(MapMode::Terrain),
+                &WatchAction::Pause,
+            ]
+        );
+        assert!(script.steps[2].is_due(1000, 200));
+        assert!(!script.steps[2].is_due(1000, 199));
+    }
+}
@@ -0,0 +1,149 @@
+//! Memory Report - Per-Subsystem Memory Use Over Long Runs
+//!
+//! Every decade of simulated time, the memory held by the subsystems that
+//! grow with history is estimated and kept as a sample, so the debug
+//! dashboard can show whether compaction keeps multi-century runs flat.
+
+use bevy::ecs::entity::Entities;
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+use crate::chronicle::WorldChronicle;
+use crate::nations::{Character, DeceasedCharacters};
+use crate::simulation::NewYearEvent;
+use crate::world::ProvinceStorage;
+
+/// Years of simulated time between memory samples
+const MEMORY_SAMPLE_YEARS: u32 = 10;
+/// Samples kept, oldest dropped first
+const MAX_MEMORY_SAMPLES: usize = 100;
+
+/// Estimated memory of one subsystem
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubsystemMemory {
+    pub name: &'static str,
+    pub bytes: usize,
+}
+
+/// Memory use at one point in the run
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemorySample {
+    pub year: u32,
+    /// Entities alive in the world
+    pub entities: u32,
+    pub subsystems: Vec<SubsystemMemory>,
+}
+
+/// Memory samples over the run, oldest first
+#[derive(Resource, Debug, Default)]
+pub struct MemoryReport {
+    samples: VecDeque<MemorySample>,
+}
+
+impl MemoryReport {
+    pub fn record(&mut self, sample: MemorySample) {
+        self.samples.push_back(sample);
+        while self.samples.len() > MAX_MEMORY_SAMPLES {
+            self.samples.pop_front();
+        }
+    }
+
+    pub fn latest(&self) -> Option<&MemorySample> {
+        self.samples.back()
+    }
+
+    /// Change in a subsystem's memory since the previous sample
+    pub fn growth(&self, name: &str) -> Option<i64> {
+        let bytes = |sample: &MemorySample| {
+            sample
+                .subsystems
+                .iter()
+                .find(|subsystem| subsystem.name == name)
+                .map(|subsystem| subsystem.bytes as i64)
+        };
+        let mut recent = self.samples.iter().rev();
+        let latest = bytes(recent.next()?)?;
+        let previous = bytes(recent.next()?)?;
+        Some(latest - previous)
+    }
+}
+
+/// Estimate memory use once a decade
+pub fn sample_memory_usage(
+    mut year_events: MessageReader<NewYearEvent>,
+    chronicle: Option<Res<WorldChronicle>>,
+    deceased: Option<Res<DeceasedCharacters>>,
+    provinces: Option<Res<ProvinceStorage>>,
+    characters: Query<(), With<Character>>,
+    entities: &Entities,
+    mut report: ResMut<MemoryReport>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+    if year % MEMORY_SAMPLE_YEARS != 0 {
+        return;
+    }
+
+    let subsystems = vec![
+        SubsystemMemory {
+            name: "Provinces",
+            bytes: provinces.map_or(0, |storage| {
+                storage.provinces.capacity() * std::mem::size_of::<crate::world::Province>()
+            }),
+        },
+        SubsystemMemory {
+            name: "Chronicle",
+            bytes: chronicle.map_or(0, |chronicle| chronicle.approx_bytes()),
+        },
+        SubsystemMemory {
+            name: "Characters",
+            bytes: characters.iter().count() * std::mem::size_of::<Character>(),
+        },
+        SubsystemMemory {
+            name: "Character records",
+            bytes: deceased.map_or(0, |deceased| deceased.approx_bytes()),
+        },
+    ];
+    for subsystem in &subsystems {
+        super::log_memory_usage(subsystem.name, subsystem.bytes);
+    }
+    report.record(MemorySample {
+        year,
+        entities: entities.len(),
+        subsystems,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(year: u32, chronicle_bytes: usize) -> MemorySample {
+        MemorySample {
+            year,
+            entities: 0,
+            subsystems: vec![SubsystemMemory {
+                name: "Chronicle",
+                bytes: chronicle_bytes,
+            }],
+        }
+    }
+
+    #[test]
+    fn report_tracks_growth_and_drops_oldest_samples() {
+        let mut report = MemoryReport::default();
+        report.record(sample(1000, 4096));
+        assert_eq!(report.growth("Chronicle"), None);
+
+        report.record(sample(1010, 1024));
+        assert_eq!(report.growth("Chronicle"), Some(-3072));
+        assert_eq!(report.growth("Characters"), None);
+
+        for year in 0..MAX_MEMORY_SAMPLES as u32 {
+            report.record(sample(1020 + year * 10, 0));
+        }
+        assert_eq!(report.samples.len(), MAX_MEMORY_SAMPLES);
+        assert_eq!(report.samples.front().map(|sample| sample.year), Some(1020));
+    }
+}
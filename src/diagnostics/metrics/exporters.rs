@@ -0,0 +1,237 @@
+//! Background exporters for collected metrics
+//!
+//! Both exporters run on their own OS thread so a slow scraper or collector
+//! never stalls a frame. Prometheus is pull-based: the latest rendering is
+//! shared behind a mutex and served to whoever connects. OTLP is push-based:
+//! rendered payloads are queued and POSTed in order.
+
+use bevy::prelude::*;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::config::MetricsExporter;
+
+/// Collector connections give up after this long
+const OTLP_TIMEOUT: Duration = Duration::from_secs(5);
+/// Payloads waiting for the collector; beyond this the oldest are dropped
+const OTLP_QUEUE_LEN: usize = 64;
+/// Scrapers that stall longer are dropped so the next one is served
+const SCRAPE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Handle the sampling systems publish through
+#[derive(Resource, Clone)]
+pub enum MetricsSink {
+    Prometheus(Arc<Mutex<String>>),
+    Otlp(OtlpQueue),
+}
+
+/// Bounded queue of payloads for the OTLP thread
+///
+/// A dead collector must not grow memory for the rest of an overnight run,
+/// so once the queue is full the oldest payload makes room for the newest.
+#[derive(Clone)]
+pub struct OtlpQueue {
+    sender: SyncSender<String>,
+    receiver: Arc<Mutex<Receiver<String>>>,
+}
+
+impl OtlpQueue {
+    fn new(capacity: usize) -> Self {
+        let (sender, receiver) = mpsc::sync_channel(capacity);
+        Self {
+            sender,
+            receiver: Arc::new(Mutex::new(receiver)),
+        }
+    }
+
+    /// Queue a payload without blocking, dropping the oldest if the queue is full
+    pub fn push(&self, payload: String) {
+        let Err(TrySendError::Full(payload)) = self.sender.try_send(payload) else {
+            return;
+        };
+        // The exporter thread holds the lock only while it waits on an empty queue
+        if let Ok(receiver) = self.receiver.try_lock() {
+            let _oldest = receiver.try_recv();
+        }
+        if self.sender.try_send(payload).is_err() {
+            debug!("OTLP queue still full; dropped the newest metrics payload");
+        }
+    }
+}
+
+/// Start the configured exporter thread
+pub fn start_exporter(exporter: &MetricsExporter) -> Result<MetricsSink, String> {
+    match exporter {
+        MetricsExporter::Prometheus { bind_address } => start_prometheus(bind_address),
+        MetricsExporter::Otlp { endpoint } => start_otlp(endpoint),
+    }
+}
+
+fn start_prometheus(bind_address: &str) -> Result<MetricsSink, String> {
+    let listener =
+        TcpListener::bind(bind_address).map_err(|e| format!("Cannot bind metrics endpoint {bind_address}: {e}"))?;
+    let latest = Arc::new(Mutex::new(String::new()));
+    let shared = Arc::clone(&latest);
+
+    thread::Builder::new()
+        .name("metrics-prometheus".into())
+        .spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let body = shared.lock().map(|text| text.clone()).unwrap_or_default();
+                if let Err(e) = serve_scrape(stream, &body) {
+                    debug!("Metrics scrape failed: {}", e);
+                }
+            }
+        })
+        .map_err(|e| format!("Cannot start metrics thread: {e}"))?;
+
+    info!("Serving Prometheus metrics on http://{}/metrics", bind_address);
+    Ok(MetricsSink::Prometheus(latest))
+}
+
+fn serve_scrape(mut stream: TcpStream, body: &str) -> std::io::Result<()> {
+    // Scrapes are served one at a time, so a silent client must not hold the thread
+    stream.set_read_timeout(Some(SCRAPE_TIMEOUT))?;
+    stream.set_write_timeout(Some(SCRAPE_TIMEOUT))?;
+    // Any path answers with the metrics; only the request line is consumed
+    let mut request_line = String::new();
+    BufReader::new(&stream).read_line(&mut request_line)?;
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    )
+}
+
+/// Split a plain `http://host:port/path` URL into address and path
+fn parse_http_endpoint(endpoint: &str) -> Result<(String, String), String> {
+    let rest = endpoint
+        .strip_prefix("http://")
+        .ok_or_else(|| format!("OTLP endpoint must be a plain http:// URL, got {endpoint}"))?;
+    let (authority, path) = match rest.find('/') {
+        Some(idx) => (&rest[..idx], &rest[idx..]),
+        None => (rest, "/v1/metrics"),
+    };
+    let address = if authority.contains(':') {
+        authority.to_string()
+    } else {
+        format!("{authority}:80")
+    };
+    Ok((address, path.to_string()))
+}
+
+fn start_otlp(endpoint: &str) -> Result<MetricsSink, String> {
+    let (address, path) = parse_http_endpoint(endpoint)?;
+    let queue = OtlpQueue::new(OTLP_QUEUE_LEN);
+    let receiver = Arc::clone(&queue.receiver);
+
+    thread::Builder::new()
+        .name("metrics-otlp".into())
+        .spawn(move || {
+            // Ends once the game drops its end of the queue
+            while let Some(payload) = receiver.lock().ok().and_then(|receiver| receiver.recv().ok()) {
+                if let Err(e) = post_json(&address, &path, &payload) {
+                    warn!("Failed to push metrics to {}{}: {}", address, path, e);
+                }
+            }
+        })
+        .map_err(|e| format!("Cannot start metrics thread: {e}"))?;
+
+    info!("Pushing OTLP metrics to {}", endpoint);
+    Ok(MetricsSink::Otlp(queue))
+}
+
+/// Connect to the first of an address's resolutions that answers in time
+fn connect(address: &str) -> std::io::Result<TcpStream> {
+    let mut last_error = std::io::Error::other(format!("{address} did not resolve"));
+    for socket_address in address.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&socket_address, OTLP_TIMEOUT) {
+            Ok(stream) => return Ok(stream),
+            Err(e) => last_error = e,
+        }
+    }
+    Err(last_error)
+}
+
+fn post_json(address: &str, path: &str, payload: &str) -> std::io::Result<()> {
+    let mut stream = connect(address)?;
+    stream.set_read_timeout(Some(OTLP_TIMEOUT))?;
+    stream.set_write_timeout(Some(OTLP_TIMEOUT))?;
+    write!(
+        stream,
+        "POST {path} HTTP/1.1\r\nHost: {address}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{payload}",
+        payload.len()
+    )?;
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+    let status = response.split_whitespace().nth(1).unwrap_or("");
+    if status.starts_with('2') {
+        Ok(())
+    } else {
+        Err(std::io::Error::other(format!("collector answered {status}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn otlp_endpoints_split_into_address_and_path() {
+        assert_eq!(
+            parse_http_endpoint("http://collector:4318/v1/metrics"),
+            Ok(("collector:4318".to_string(), "/v1/metrics".to_string()))
+        );
+        assert_eq!(
+            parse_http_endpoint("http://collector:4318"),
+            Ok(("collector:4318".to_string(), "/v1/metrics".to_string()))
+        );
+        assert_eq!(
+            parse_http_endpoint("http://collector/custom"),
+            Ok(("collector:80".to_string(), "/custom".to_string()))
+        );
+        assert!(parse_http_endpoint("https://collector:4318").is_err());
+    }
+
+    #[test]
+    fn full_otlp_queue_drops_the_oldest_payload() {
+        let queue = OtlpQueue::new(2);
+        for payload in ["first", "second", "third"] {
+            queue.push(payload.to_string());
+        }
+
+        let receiver = queue.receiver.lock().expect("queue lock is free");
+        let queued: Vec<String> = receiver.try_iter().collect();
+        assert_eq!(queued, ["second", "third"]);
+    }
+
+    #[test]
+    fn scrapes_get_the_latest_metrics_as_plain_text() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("loopback port is free");
+        let address = listener.local_addr().expect("listener has an address");
+        let scraper = thread::spawn(move || -> std::io::Result<String> {
+            let mut stream = TcpStream::connect(address)?;
+            stream.write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")?;
+            let mut response = String::new();
+            stream.read_to_string(&mut response)?;
+            Ok(response)
+        });
+
+        let body = "livingworlds_game_year 1200\n";
+        let (stream, _) = listener.accept().expect("scraper connects");
+        serve_scrape(stream, body).expect("scrape is served");
+        let response = scraper.join().expect("scraper finishes").expect("scraper reads the response");
+
+        let (head, served) = response.split_once("\r\n\r\n").expect("response has a body");
+        assert!(head.starts_with("HTTP/1.1 200 OK"));
+        assert!(head.contains("Content-Type: text/plain; version=0.0.4"));
+        assert!(head.contains(&format!("Content-Length: {}", body.len())));
+        assert_eq!(served, body);
+    }
+}
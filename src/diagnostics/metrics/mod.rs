@@ -0,0 +1,17 @@
+//! Metrics export for long headless runs (gateway module)
+//!
+//! Compiled only with the `metrics` feature. When `AppConfig::metrics` names
+//! an exporter, frame phase and simulation stage timings, entity counts, and
+//! key simulation indicators are sampled on an interval and published to Prometheus or an
+//! OTLP collector so multi-hour balancing runs can be watched in Grafana.
+
+// Private modules - gateway architecture
+mod exporters;
+mod plugin;
+mod registry;
+mod systems;
+
+pub use exporters::MetricsSink;
+pub use plugin::MetricsPlugin;
+pub use registry::{MetricsRegistry, METRIC_PREFIX};
+pub use systems::PhaseTimings;
@@ -0,0 +1,60 @@
+//! Metrics plugin registration
+
+use bevy::app::RunFixedMainLoopSystems;
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+use super::exporters::MetricsSink;
+use super::registry::MetricsRegistry;
+use super::systems::{
+    mark_fixed_end, mark_fixed_start, mark_frame_end, mark_frame_start, mark_stage_end, mark_stage_start,
+    sample_and_publish_metrics, start_metrics_exporter, PhaseTimings,
+};
+use crate::nations::{
+    allocate_national_output, check_war_resolution, process_battle_events, process_war_declarations,
+    resolve_nation_actions,
+};
+use crate::simulation::run_pressure_systems_on_timer;
+
+// Collects metrics and publishes them through the configured exporter.
+// Expects `MetricsConfig` as a resource; without a working exporter the
+// sampling system never runs.
+define_plugin!(MetricsPlugin {
+    resources: [MetricsRegistry, PhaseTimings],
+
+    startup: [start_metrics_exporter],
+
+    custom_init: |app: &mut App| {
+        app.add_systems(First, mark_frame_start)
+            .add_systems(
+                RunFixedMainLoop,
+                (
+                    mark_fixed_start.in_set(RunFixedMainLoopSystems::BeforeFixedMainLoop),
+                    mark_fixed_end.in_set(RunFixedMainLoopSystems::AfterFixedMainLoop),
+                ),
+            )
+            .add_systems(
+                Last,
+                (
+                    sample_and_publish_metrics.run_if(resource_exists::<MetricsSink>),
+                    mark_frame_end,
+                )
+                    .chain(),
+            );
+
+        time_stage(app, "pressures", run_pressure_systems_on_timer);
+        time_stage(app, "nation_actions", resolve_nation_actions);
+        time_stage(app, "economy", allocate_national_output);
+        time_stage(app, "war_declarations", process_war_declarations);
+        time_stage(app, "battles", process_battle_events);
+        time_stage(app, "war_resolution", check_war_resolution);
+    }
+});
+
+/// Time one simulation stage by stamping either side of the system doing its work
+fn time_stage<M>(app: &mut App, stage: &'static str, work: impl IntoSystemSet<M> + Copy) {
+    app.add_systems(
+        Update,
+        (mark_stage_start(stage).before(work), mark_stage_end(stage).after(work)),
+    );
+}
@@ -0,0 +1,135 @@
+//! Metric registry and wire formats
+//!
+//! Holds the latest value of every gauge and renders them either as the
+//! Prometheus text exposition format or as an OTLP/HTTP JSON payload.
+
+use bevy::prelude::*;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// Prefix shared by every exported metric name
+pub const METRIC_PREFIX: &str = "livingworlds_";
+
+/// One gauge with its samples, keyed by an optional single label
+#[derive(Debug, Clone)]
+struct Gauge {
+    help: &'static str,
+    samples: BTreeMap<Option<(&'static str, String)>, f64>,
+}
+
+/// Latest value of every exported metric
+#[derive(Resource, Debug, Default)]
+pub struct MetricsRegistry {
+    gauges: BTreeMap<&'static str, Gauge>,
+}
+
+impl MetricsRegistry {
+    /// Set an unlabeled gauge
+    pub fn set(&mut self, name: &'static str, help: &'static str, value: f64) {
+        self.gauge(name, help).samples.insert(None, value);
+    }
+
+    /// Set one labeled sample of a gauge, e.g. `phase="fixed_update"`
+    pub fn set_labeled(&mut self, name: &'static str, help: &'static str, label: (&'static str, &str), value: f64) {
+        self.gauge(name, help)
+            .samples
+            .insert(Some((label.0, label.1.to_string())), value);
+    }
+
+    fn gauge(&mut self, name: &'static str, help: &'static str) -> &mut Gauge {
+        self.gauges.entry(name).or_insert_with(|| Gauge {
+            help,
+            samples: BTreeMap::new(),
+        })
+    }
+
+    /// Prometheus text exposition format (version 0.0.4)
+    pub fn render_prometheus(&self) -> String {
+        let mut out = String::new();
+        for (name, gauge) in &self.gauges {
+            let _ = writeln!(out, "# HELP {METRIC_PREFIX}{name} {}", gauge.help);
+            let _ = writeln!(out, "# TYPE {METRIC_PREFIX}{name} gauge");
+            for (label, value) in &gauge.samples {
+                match label {
+                    Some((key, label_value)) => {
+                        let _ = writeln!(out, "{METRIC_PREFIX}{name}{{{key}=\"{label_value}\"}} {value}");
+                    }
+                    None => {
+                        let _ = writeln!(out, "{METRIC_PREFIX}{name} {value}");
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    /// OTLP/HTTP JSON `ExportMetricsServiceRequest` with every gauge
+    pub fn render_otlp_json(&self, time_unix_nano: u128) -> String {
+        let metrics: Vec<serde_json::Value> = self
+            .gauges
+            .iter()
+            .map(|(name, gauge)| {
+                let data_points: Vec<serde_json::Value> = gauge
+                    .samples
+                    .iter()
+                    .map(|(label, value)| {
+                        let attributes: Vec<serde_json::Value> = label
+                            .iter()
+                            .map(|(key, label_value)| {
+                                serde_json::json!({
+                                    "key": key,
+                                    "value": { "stringValue": label_value }
+                                })
+                            })
+                            .collect();
+                        serde_json::json!({
+                            "attributes": attributes,
+                            "timeUnixNano": time_unix_nano.to_string(),
+                            "asDouble": value
+                        })
+                    })
+                    .collect();
+                serde_json::json!({
+                    "name": format!("{METRIC_PREFIX}{name}"),
+                    "description": gauge.help,
+                    "gauge": { "dataPoints": data_points }
+                })
+            })
+            .collect();
+
+        serde_json::json!({
+            "resourceMetrics": [{
+                "resource": {
+                    "attributes": [{
+                        "key": "service.name",
+                        "value": { "stringValue": "living-worlds" }
+                    }]
+                },
+                "scopeMetrics": [{
+                    "scope": { "name": "living_worlds" },
+                    "metrics": metrics
+                }]
+            }]
+        })
+        .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prometheus_output_groups_labeled_samples() {
+        let mut registry = MetricsRegistry::default();
+        registry.set("game_year", "Current in-game year", 1042.0);
+        registry.set_labeled("entities", "Entity counts", ("kind", "nations"), 12.0);
+        registry.set_labeled("entities", "Entity counts", ("kind", "wars"), 3.0);
+
+        let text = registry.render_prometheus();
+        assert!(text.contains("# TYPE livingworlds_entities gauge"));
+        assert!(text.contains("livingworlds_entities{kind=\"nations\"} 12"));
+        assert!(text.contains("livingworlds_game_year 1042"));
+        assert_eq!(text.matches("# HELP livingworlds_entities").count(), 1);
+    }
+}
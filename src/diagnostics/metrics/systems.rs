@@ -0,0 +1,172 @@
+//! Metric collection systems
+//!
+//! Phase timings are measured every frame with timestamps at schedule
+//! boundaries, and simulation stages with timestamps either side of the
+//! system doing their work; everything else is sampled on the configured interval, when
+//! the registry is also handed to the exporter.
+
+use bevy::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use super::exporters::{start_exporter, MetricsSink};
+use super::registry::MetricsRegistry;
+use crate::config::MetricsConfig;
+use crate::nations::{Nation, War};
+use crate::nations::relationships::AttackedBy;
+use crate::simulation::{GameTime, WorldTension};
+use crate::world::{ProvinceData, ProvinceStorage};
+
+/// Frame phase start times and most recent durations
+#[derive(Resource, Debug, Default)]
+pub struct PhaseTimings {
+    frame_start: Option<Instant>,
+    fixed_start: Option<Instant>,
+    /// Milliseconds spent in the fixed simulation loop last frame
+    pub fixed_update_ms: f64,
+    /// Milliseconds for the whole of last frame's main schedule
+    pub frame_ms: f64,
+    /// Slowest frame since the previous sample
+    pub worst_frame_ms: f64,
+    stage_starts: HashMap<&'static str, Instant>,
+    /// Slowest run of each simulation stage since the previous sample
+    ///
+    /// Wall-clock milliseconds, so systems the scheduler runs alongside a
+    /// stage count towards it.
+    pub worst_stage_ms: BTreeMap<&'static str, f64>,
+}
+
+/// Paces sampling to `MetricsConfig::sample_interval_secs`
+#[derive(Resource, Debug)]
+pub struct MetricsSampleTimer(pub Timer);
+
+/// Start the exporter thread named in the configuration
+pub fn start_metrics_exporter(mut commands: Commands, config: Res<MetricsConfig>) {
+    let Some(exporter) = config.exporter.as_ref() else {
+        return;
+    };
+
+    match start_exporter(exporter) {
+        Ok(sink) => {
+            commands.insert_resource(sink);
+            commands.insert_resource(MetricsSampleTimer(Timer::from_seconds(
+                config.sample_interval_secs.max(0.1),
+                TimerMode::Repeating,
+            )));
+        }
+        Err(e) => error!("Metrics export disabled: {}", e),
+    }
+}
+
+pub fn mark_frame_start(mut timings: ResMut<PhaseTimings>) {
+    timings.frame_start = Some(Instant::now());
+}
+
+pub fn mark_fixed_start(mut timings: ResMut<PhaseTimings>) {
+    timings.fixed_start = Some(Instant::now());
+}
+
+pub fn mark_fixed_end(mut timings: ResMut<PhaseTimings>) {
+    if let Some(start) = timings.fixed_start.take() {
+        timings.fixed_update_ms = start.elapsed().as_secs_f64() * 1000.0;
+    }
+}
+
+pub fn mark_frame_end(mut timings: ResMut<PhaseTimings>) {
+    if let Some(start) = timings.frame_start.take() {
+        let frame_ms = start.elapsed().as_secs_f64() * 1000.0;
+        timings.frame_ms = frame_ms;
+        timings.worst_frame_ms = timings.worst_frame_ms.max(frame_ms);
+    }
+}
+
+/// System stamping the start of a simulation stage
+pub fn mark_stage_start(stage: &'static str) -> impl FnMut(ResMut<PhaseTimings>) {
+    move |mut timings: ResMut<PhaseTimings>| {
+        timings.stage_starts.insert(stage, Instant::now());
+    }
+}
+
+/// System recording how long a simulation stage took since its start stamp
+pub fn mark_stage_end(stage: &'static str) -> impl FnMut(ResMut<PhaseTimings>) {
+    move |mut timings: ResMut<PhaseTimings>| {
+        if let Some(start) = timings.stage_starts.remove(stage) {
+            let stage_ms = start.elapsed().as_secs_f64() * 1000.0;
+            let worst = timings.worst_stage_ms.entry(stage).or_default();
+            *worst = worst.max(stage_ms);
+        }
+    }
+}
+
+/// Refresh every gauge and publish the registry
+pub fn sample_and_publish_metrics(
+    time: Res<Time>,
+    mut timer: ResMut<MetricsSampleTimer>,
+    mut timings: ResMut<PhaseTimings>,
+    mut registry: ResMut<MetricsRegistry>,
+    sink: Res<MetricsSink>,
+    entities: Query<Entity>,
+    nations: Query<(&Nation, Option<&AttackedBy>)>,
+    provinces: Query<(), With<ProvinceData>>,
+    wars: Query<(), With<War>>,
+    game_time: Option<Res<GameTime>>,
+    tension: Option<Res<WorldTension>>,
+    province_storage: Option<Res<ProvinceStorage>>,
+) {
+    if !timer.0.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    const PHASE_HELP: &str = "Time spent per frame phase in milliseconds";
+    registry.set_labeled("phase_duration_ms", PHASE_HELP, ("phase", "fixed_update"), timings.fixed_update_ms);
+    registry.set_labeled("phase_duration_ms", PHASE_HELP, ("phase", "frame"), timings.frame_ms);
+    registry.set_labeled("phase_duration_ms", PHASE_HELP, ("phase", "worst_frame"), timings.worst_frame_ms);
+    timings.worst_frame_ms = 0.0;
+
+    const STAGE_HELP: &str = "Slowest run of each simulation stage since the last sample, in milliseconds";
+    for (&stage, &stage_ms) in &timings.worst_stage_ms {
+        registry.set_labeled("stage_duration_ms", STAGE_HELP, ("stage", stage), stage_ms);
+    }
+    timings.worst_stage_ms.values_mut().for_each(|stage_ms| *stage_ms = 0.0);
+
+    const ENTITY_HELP: &str = "Live entity counts by kind";
+    registry.set_labeled("entities", ENTITY_HELP, ("kind", "total"), entities.iter().count() as f64);
+    registry.set_labeled("entities", ENTITY_HELP, ("kind", "nations"), nations.iter().count() as f64);
+    registry.set_labeled("entities", ENTITY_HELP, ("kind", "provinces"), provinces.iter().count() as f64);
+    registry.set_labeled("entities", ENTITY_HELP, ("kind", "wars"), wars.iter().count() as f64);
+
+    let (treasury, at_war) = nations
+        .iter()
+        .fold((0.0f64, 0u32), |(treasury, at_war), (nation, attacked_by)| {
+            let under_attack = attacked_by.is_some_and(|a| a.is_under_attack());
+            (treasury + nation.treasury.to_f32() as f64, at_war + under_attack as u32)
+        });
+    registry.set("total_treasury", "Sum of all nation treasuries", treasury);
+    registry.set("nations_at_war", "Nations currently under attack", at_war as f64);
+
+    if let Some(game_time) = game_time {
+        registry.set("game_year", "Current in-game year", game_time.current_year() as f64);
+    }
+    if let Some(tension) = tension {
+        registry.set("world_tension", "Global tension from 0 to 1", tension.current as f64);
+    }
+    if let Some(storage) = province_storage {
+        let population: u64 = storage.provinces.iter().map(|p| p.population as u64).sum();
+        registry.set("world_population", "Total population across all provinces", population as f64);
+    }
+
+    match sink.as_ref() {
+        MetricsSink::Prometheus(latest) => {
+            if let Ok(mut text) = latest.lock() {
+                *text = registry.render_prometheus();
+            }
+        }
+        MetricsSink::Otlp(queue) => {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_nanos())
+                .unwrap_or_default();
+            queue.push(registry.render_otlp_json(now));
+        }
+    }
+}
@@ -6,6 +6,9 @@
 //! - Performance metrics collection and reporting
 //! - Diagnostic plugin registration and management
 //! - Integration with Bevy's diagnostic systems
+//! - Per-subsystem memory report sampled over long runs
+//! - Synthetic stress load spawned from the developer console
+//! - Prometheus/OTLP metrics export (`metrics` feature)
 //!
 //! # Gateway Architecture
 //! This module controls access to diagnostic components through a clean API.
@@ -27,7 +30,11 @@
 mod error_context;
 mod fps;
 mod logging;
+mod memory;
+#[cfg(feature = "metrics")]
+mod metrics;
 mod plugin;
+mod stress;
 mod types;
 
 // Public exports - controlled API surface following gateway pattern
@@ -44,4 +51,8 @@ pub use logging::{
     log_nation_state_change,
     log_memory_usage, debug_context,
 };
+pub use memory::MemoryReport;
+#[cfg(feature = "metrics")]
+pub use metrics::{MetricsPlugin, MetricsRegistry, MetricsSink, PhaseTimings, METRIC_PREFIX};
 pub use plugin::DiagnosticsPlugin;
+pub use stress::{StressLoad, StressReport};
@@ -10,8 +10,14 @@ use bevy_plugin_builder::define_plugin;
 
 // Import from sibling modules
 use super::fps::display_fps;
+use super::memory::{sample_memory_usage, MemoryReport};
+use super::stress::{
+    clear_stress_load, draw_stress_load, handle_stress_commands, measure_stress_load, move_stress_journeys,
+    StressReport,
+};
 
 // Import configuration type
+use crate::states::GameState;
 use crate::DiagnosticsConfig;
 
 // Plugin for diagnostics and performance monitoring systems
@@ -23,6 +29,24 @@ define_plugin!(DiagnosticsPlugin {
     // Core diagnostic infrastructure
     plugins: [FrameTimeDiagnosticsPlugin::default()],
 
+    // Memory use of growing subsystems, sampled every decade, and the
+    // frame time under synthetic stress load
+    resources: [MemoryReport, StressReport],
+
     // FPS monitoring system (conditional on config presence)
-    update: [display_fps.run_if(resource_exists::<DiagnosticsConfig>)]
+    update: [
+        display_fps.run_if(resource_exists::<DiagnosticsConfig>),
+        sample_memory_usage.run_if(in_state(GameState::InGame)),
+        (
+            handle_stress_commands,
+            move_stress_journeys,
+            draw_stress_load,
+            measure_stress_load,
+        ).chain().run_if(in_state(GameState::InGame))
+    ],
+
+    // Synthetic load does not outlive the world it was spawned in
+    on_exit: {
+        GameState::InGame => [clear_stress_load]
+    }
 });
@@ -0,0 +1,606 @@
+//! Stress Test - Synthetic Load for Profiling Worst Cases
+//!
+//! The `stress` console command spawns armies, trade routes, and migrations
+//! far beyond what a game reaches on its own, so simulation and rendering
+//! can be profiled at that scale before the content gets there. Synthetic
+//! armies march with the real field armies, convoys shuttle along their
+//! trade routes, and migrations walk to their new homes, all drawn on the
+//! map and all followable. Convoys and migrations outside the region in view
+//! and the regions around it are simulated coarsely, catching up a week of
+//! travel at a time. `stress clear` tears the load down again. Frame time
+//! before the load arrived and under it shows in the performance dashboard.
+
+use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::camera::{CameraController, FollowKind, Followable};
+use crate::constants::SIMULATION_DAYS_PER_YEAR;
+use crate::math::{Manpower, HEX_SIZE};
+use crate::nations::{ArmyOrder, FieldArmy};
+use crate::relationships::{Army, ArmyType, MigrationFlow, MigrationType, StationedIn, TradeRoute, TradeRouteType};
+use crate::simulation::GameTime;
+use crate::ui::{ConsoleCommand, ConsoleOutput};
+use crate::world::{
+    ProvinceEntityOrder, ProvinceGraph, ProvinceStorage, ProvincesSpatialIndex, RegionHierarchy, TerrainType,
+};
+
+/// Most entities one command may spawn
+const MAX_STRESS_SPAWN: usize = 100_000;
+/// Provinces a synthetic army is sent marching across
+const ARMY_MARCH_PROVINCES: usize = 12;
+/// Provinces a synthetic migration wanders before settling
+const MIGRATION_PROVINCES: usize = 8;
+/// Distance convoys and migrations cover in a day
+const JOURNEY_PER_DAY: f32 = HEX_SIZE * 0.2;
+/// Most days of travel made up at once after a pause or a fast-forward
+const MAX_JOURNEY_DAYS: u32 = 30;
+/// Days of travel a journey far from view makes up in one step
+const COARSE_STEP_DAYS: u32 = 7;
+/// Real seconds between frame time measurements
+const MEASURE_SECONDS: f32 = 1.0;
+/// Height of the drawn load above the map
+const LOAD_Z: f32 = 2.0;
+
+const ARMY_COLOR: Color = Color::srgb(0.95, 0.3, 0.25);
+const ROUTE_COLOR: Color = Color::srgba(0.95, 0.8, 0.3, 0.15);
+const CONVOY_COLOR: Color = Color::srgb(0.95, 0.8, 0.3);
+const MIGRATION_COLOR: Color = Color::srgb(0.4, 0.75, 0.95);
+
+/// A kind of synthetic load
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StressKind {
+    Armies,
+    TradeRoutes,
+    Migrations,
+}
+
+impl StressKind {
+    const ALL: [StressKind; 3] = [StressKind::Armies, StressKind::TradeRoutes, StressKind::Migrations];
+
+    fn parse(word: &str) -> Option<Self> {
+        match word {
+            "armies" => Some(StressKind::Armies),
+            "routes" => Some(StressKind::TradeRoutes),
+            "migrations" => Some(StressKind::Migrations),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            StressKind::Armies => "armies",
+            StressKind::TradeRoutes => "trade routes",
+            StressKind::Migrations => "migrations",
+        }
+    }
+
+    /// Spawned when the command gives no count
+    fn default_count(self) -> usize {
+        match self {
+            StressKind::Armies => 1_000,
+            StressKind::TradeRoutes => 10_000,
+            StressKind::Migrations => 1_000,
+        }
+    }
+}
+
+/// Marks an entity spawned as synthetic load, so it can be torn down again
+#[derive(Component, Debug, Clone, Copy)]
+pub struct StressLoad(pub StressKind);
+
+/// A convoy or migration on the move between two points
+#[derive(Component, Debug, Clone)]
+struct StressJourney {
+    from: Vec2,
+    to: Vec2,
+    /// Share of the way from `from` to `to` covered
+    progress: f32,
+    /// Convoys turn back at the end of the road; migrations stay
+    round_trip: bool,
+    /// Region it was in when it last moved
+    region: Option<usize>,
+    /// Days of travel owed while it is simulated coarsely
+    owed_days: u32,
+}
+
+impl StressJourney {
+    fn position(&self) -> Vec2 {
+        self.from.lerp(self.to, self.progress)
+    }
+
+    /// Travel `distance` along the way, turning back at the end of a round trip
+    fn advance(&mut self, distance: f32) {
+        let length = self.from.distance(self.to);
+        if length <= 0.0 {
+            self.progress = 1.0;
+            return;
+        }
+        self.progress += distance / length;
+        while self.round_trip && self.progress >= 1.0 {
+            std::mem::swap(&mut self.from, &mut self.to);
+            self.progress -= 1.0;
+        }
+        self.progress = self.progress.min(1.0);
+    }
+}
+
+/// Synthetic load in the world and how the frame time took it
+#[derive(Resource, Debug, Default)]
+pub struct StressReport {
+    pub armies: usize,
+    pub trade_routes: usize,
+    pub migrations: usize,
+    /// Smoothed frame time just before load was first added, in milliseconds
+    pub baseline_ms: Option<f32>,
+    /// Smoothed frame time under the current load
+    pub loaded_ms: Option<f32>,
+    /// Worst smoothed frame time seen under load this run
+    pub peak_ms: f32,
+    /// Convoys and migrations simulated coarsely, far from the regions in view
+    pub coarse_journeys: usize,
+}
+
+impl StressReport {
+    pub fn total(&self) -> usize {
+        self.armies + self.trade_routes + self.migrations
+    }
+
+    /// Lines for the console and the performance dashboard
+    pub fn summary(&self) -> Vec<String> {
+        let baseline = self.baseline_ms.map_or("?".to_string(), |ms| format!("{:.1} ms", ms));
+        if self.total() == 0 {
+            let mut lines = vec!["No synthetic load".to_string()];
+            if self.peak_ms > 0.0 {
+                lines.push(format!(
+                    "Last run peaked at {:.1} ms over a {} baseline",
+                    self.peak_ms, baseline
+                ));
+            }
+            return lines;
+        }
+        let loaded = self.loaded_ms.map_or("?".to_string(), |ms| format!("{:.1} ms", ms));
+        let mut lines = vec![
+            format!(
+                "{} armies, {} trade routes, {} migrations",
+                self.armies, self.trade_routes, self.migrations
+            ),
+            format!("Frame {} -> {} (peak {:.1} ms)", baseline, loaded, self.peak_ms),
+        ];
+        if self.coarse_journeys > 0 {
+            lines.push(format!("{} journeys far from view moving weekly", self.coarse_journeys));
+        }
+        lines
+    }
+}
+
+fn frame_time_ms(diagnostics: &DiagnosticsStore) -> Option<f32> {
+    diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+        .and_then(|frame_time| frame_time.smoothed())
+        .map(|ms| ms as f32)
+}
+
+/// A wandering path of up to `steps` provinces from `start` that never crosses itself
+fn wander(start: usize, steps: usize, neighbors: impl Fn(usize) -> Vec<usize>, rng: &mut impl Rng) -> Vec<usize> {
+    let mut path = vec![start];
+    for _ in 0..steps {
+        let Some(&current) = path.last() else {
+            break;
+        };
+        let unvisited: Vec<usize> = neighbors(current)
+            .into_iter()
+            .filter(|next| !path.contains(next))
+            .collect();
+        match unvisited.choose(rng) {
+            Some(&next) => path.push(next),
+            None => break,
+        }
+    }
+    path
+}
+
+/// Spawn `count` of one kind of load across the nations' land
+fn spawn_stress_load(
+    commands: &mut Commands,
+    kind: StressKind,
+    count: usize,
+    storage: &ProvinceStorage,
+    entity_order: &ProvinceEntityOrder,
+    graph: &ProvinceGraph,
+    rng: &mut StdRng,
+) -> usize {
+    let is_land = |index: usize| {
+        storage
+            .provinces
+            .get(index)
+            .is_some_and(|province| province.terrain != TerrainType::Ocean)
+    };
+    let land_neighbors = |index: usize| {
+        graph
+            .neighbors(index)
+            .iter()
+            .map(|&neighbor| neighbor as usize)
+            .filter(|&neighbor| is_land(neighbor))
+            .collect::<Vec<_>>()
+    };
+    let owned: Vec<usize> = (0..storage.provinces.len())
+        .filter(|&index| is_land(index) && storage.provinces[index].owner_entity.is_some())
+        .collect();
+    let position_of = |index: usize| storage.provinces[index].position;
+
+    let mut spawned = 0;
+    for number in 0..count {
+        let Some(&start) = owned.choose(rng) else {
+            break;
+        };
+        let Some(owner) = storage.provinces[start].owner_entity else {
+            continue;
+        };
+        let position = position_of(start);
+        match kind {
+            StressKind::Armies => {
+                let Some(province) = entity_order.get(start) else {
+                    continue;
+                };
+                let route = wander(start, ARMY_MARCH_PROVINCES, land_neighbors, rng);
+                let name = format!("Stress Army {}", number + 1);
+                commands.spawn((
+                    Followable {
+                        kind: FollowKind::Army,
+                        label: name.clone(),
+                        owner: Some(owner),
+                    },
+                    Transform::from_translation(position.extend(0.0)),
+                    Army {
+                        name,
+                        size: Manpower::new(rng.gen_range(1_000..20_000)),
+                        morale: 1.0,
+                        experience: 0.0,
+                        equipment_quality: 1.0,
+                        army_type: ArmyType::Infantry,
+                        owner_nation: owner,
+                    },
+                    FieldArmy {
+                        theater: None,
+                        commander: None,
+                        order: ArmyOrder::Hold,
+                        objective: route.last().copied().unwrap_or(start),
+                        route,
+                        progress: 0,
+                        position,
+                        heading: 0.0,
+                        supply: 1.0,
+                    },
+                    StationedIn(province),
+                    StressLoad(kind),
+                ));
+            }
+            StressKind::TradeRoutes => {
+                // Any two provinces, however far apart, for the longest roads to draw
+                let Some(&end) = owned.choose(rng) else {
+                    continue;
+                };
+                let route_type = if storage.provinces[end].owner_entity == Some(owner) {
+                    TradeRouteType::Regional
+                } else {
+                    TradeRouteType::International
+                };
+                let name = format!("Stress Route {}", number + 1);
+                let journey = StressJourney {
+                    from: position,
+                    to: position_of(end),
+                    progress: rng.gen_range(0.0..1.0),
+                    round_trip: true,
+                    region: None,
+                    owed_days: 0,
+                };
+                commands.spawn((
+                    Followable {
+                        kind: FollowKind::Convoy,
+                        label: name.clone(),
+                        owner: Some(owner),
+                    },
+                    Transform::from_translation(journey.position().extend(0.0)),
+                    TradeRoute {
+                        name,
+                        route_type,
+                        volume: rng.gen_range(10.0..1_000.0),
+                        profit_margin: 0.1,
+                        security: 0.5,
+                    },
+                    journey,
+                    StressLoad(kind),
+                ));
+            }
+            StressKind::Migrations => {
+                let path = wander(start, MIGRATION_PROVINCES, land_neighbors, rng);
+                let end = path.last().copied().unwrap_or(start);
+                let (Some(origin), Some(destination)) = (entity_order.get(start), entity_order.get(end)) else {
+                    continue;
+                };
+                commands.spawn((
+                    Followable {
+                        kind: FollowKind::Migration,
+                        label: format!("Stress Migration {}", number + 1),
+                        owner: Some(owner),
+                    },
+                    Transform::from_translation(position.extend(0.0)),
+                    MigrationFlow {
+                        origin,
+                        destination,
+                        population_size: rng.gen_range(100..5_000),
+                        migration_type: MigrationType::Economic,
+                        push_factors: Vec::new(),
+                        pull_factors: Vec::new(),
+                    },
+                    StressJourney {
+                        from: position,
+                        to: position_of(end),
+                        progress: 0.0,
+                        round_trip: false,
+                        region: None,
+                        owed_days: 0,
+                    },
+                    StressLoad(kind),
+                ));
+            }
+        }
+        spawned += 1;
+    }
+    spawned
+}
+
+/// `stress armies|routes|migrations [count]`, `stress clear [kind]`, and `stress status` from the console
+pub fn handle_stress_commands(
+    mut commands: Commands,
+    mut commands_in: MessageReader<ConsoleCommand>,
+    mut output: MessageWriter<ConsoleOutput>,
+    province_storage: Option<Res<ProvinceStorage>>,
+    entity_order: Option<Res<ProvinceEntityOrder>>,
+    graph: Res<ProvinceGraph>,
+    diagnostics: Res<DiagnosticsStore>,
+    mut report: ResMut<StressReport>,
+    load: Query<(Entity, &StressLoad)>,
+    mut runs: Local<u64>,
+) {
+    let usage = "Usage: stress armies|routes|migrations [count] | clear [kind] | status";
+    for command in commands_in.read().filter(|command| command.name == "stress") {
+        let first = command.args.first().map(|arg| arg.to_lowercase());
+        let replies = match first.as_deref() {
+            None | Some("status") => report.summary(),
+            Some("clear") => {
+                let kind = command
+                    .args
+                    .get(1)
+                    .and_then(|arg| StressKind::parse(&arg.to_lowercase()));
+                let mut cleared = 0;
+                for (entity, stress) in &load {
+                    if kind.is_none_or(|kind| kind == stress.0) {
+                        commands.entity(entity).despawn();
+                        cleared += 1;
+                    }
+                }
+                let what = kind.map_or("synthetic entities", StressKind::label);
+                vec![format!("Cleared {} {}", cleared, what)]
+            }
+            Some(word) => match StressKind::parse(word) {
+                Some(kind) => {
+                    let count = match command.args.get(1).map(|arg| arg.parse::<usize>()) {
+                        Some(Ok(count)) => count.min(MAX_STRESS_SPAWN),
+                        Some(Err(_)) => {
+                            output.write(ConsoleOutput(usage.to_string()));
+                            continue;
+                        }
+                        None => kind.default_count(),
+                    };
+                    let (Some(storage), Some(entity_order)) = (province_storage.as_deref(), entity_order.as_deref())
+                    else {
+                        output.write(ConsoleOutput("No world loaded".to_string()));
+                        continue;
+                    };
+                    // The first load of a run measures against the frame time without any
+                    if load.is_empty() {
+                        report.baseline_ms = frame_time_ms(&diagnostics);
+                        report.peak_ms = 0.0;
+                    }
+                    *runs += 1;
+                    let mut rng = StdRng::seed_from_u64(*runs);
+                    let spawned =
+                        spawn_stress_load(&mut commands, kind, count, storage, entity_order, &graph, &mut rng);
+                    match spawned {
+                        0 => vec!["No nation holds any land to load".to_string()],
+                        _ => vec![format!("Spawned {} synthetic {}", spawned, kind.label())],
+                    }
+                }
+                None => vec![usage.to_string()],
+            },
+        };
+        for reply in replies {
+            output.write(ConsoleOutput(reply));
+        }
+    }
+}
+
+/// Daily travel of convoys and migrations, weekly for those far from view
+///
+/// A journey moves every day while it is in the region under the camera or
+/// one bordering it, or at sea; elsewhere it owes its days and makes them up
+/// a week at a time.
+pub fn move_stress_journeys(
+    time: Res<GameTime>,
+    hierarchy: Res<RegionHierarchy>,
+    spatial: Res<ProvincesSpatialIndex>,
+    cameras: Query<&CameraController>,
+    mut report: ResMut<StressReport>,
+    mut last_day: Local<Option<u32>>,
+    mut journeys: Query<(&mut StressJourney, &mut Transform)>,
+) {
+    let today = time.current_year() * SIMULATION_DAYS_PER_YEAR as u32 + time.day_of_year();
+    let Some(previous) = last_day.replace(today) else {
+        return;
+    };
+    let days = today.saturating_sub(previous).min(MAX_JOURNEY_DAYS);
+    if days == 0 {
+        return;
+    }
+
+    let viewed = cameras
+        .single()
+        .ok()
+        .and_then(|camera| hierarchy.region_at(&spatial, camera.target_position.truncate()));
+    let in_view = |region: Option<usize>| match (viewed, region) {
+        (Some(viewed), Some(region)) => {
+            region == viewed
+                || hierarchy
+                    .regions()
+                    .get(viewed)
+                    .is_some_and(|viewed| viewed.neighbors.contains(&(region as u32)))
+        }
+        _ => true,
+    };
+
+    let mut coarse = 0;
+    for (mut journey, mut transform) in &mut journeys {
+        journey.owed_days += days;
+        if !in_view(journey.region) {
+            coarse += 1;
+            if journey.owed_days < COARSE_STEP_DAYS {
+                continue;
+            }
+        }
+        let owed = std::mem::take(&mut journey.owed_days);
+        journey.advance(JOURNEY_PER_DAY * owed as f32);
+        journey.region = hierarchy.region_at(&spatial, journey.position());
+        let z = transform.translation.z;
+        transform.translation = journey.position().extend(z);
+    }
+    if report.coarse_journeys != coarse {
+        report.coarse_journeys = coarse;
+    }
+}
+
+/// Draw every road, convoy, migration, and army of the synthetic load
+pub fn draw_stress_load(
+    mut gizmos: Gizmos,
+    report: Res<StressReport>,
+    journeys: Query<(&StressJourney, &StressLoad)>,
+    armies: Query<&FieldArmy, With<StressLoad>>,
+) {
+    if report.total() == 0 {
+        return;
+    }
+    let radius = HEX_SIZE * 0.2;
+    for (journey, stress) in &journeys {
+        let at = Isometry3d::from_translation(journey.position().extend(LOAD_Z));
+        match stress.0 {
+            StressKind::TradeRoutes => {
+                gizmos.line(journey.from.extend(LOAD_Z), journey.to.extend(LOAD_Z), ROUTE_COLOR);
+                gizmos.circle(at, radius, CONVOY_COLOR);
+            }
+            _ => gizmos.circle(at, radius, MIGRATION_COLOR),
+        }
+    }
+    for field in &armies {
+        gizmos.circle(
+            Isometry3d::from_translation(field.position.extend(LOAD_Z)),
+            radius,
+            ARMY_COLOR,
+        );
+    }
+}
+
+/// Count the load and sample the frame time under it, once a second
+pub fn measure_stress_load(
+    time: Res<Time>,
+    diagnostics: Res<DiagnosticsStore>,
+    load: Query<&StressLoad>,
+    mut report: ResMut<StressReport>,
+    mut since: Local<f32>,
+) {
+    *since += time.delta_secs();
+    if *since < MEASURE_SECONDS {
+        return;
+    }
+    *since = 0.0;
+
+    let count = |kind: StressKind| load.iter().filter(|stress| stress.0 == kind).count();
+    let [armies, trade_routes, migrations] = StressKind::ALL.map(count);
+    let total = armies + trade_routes + migrations;
+    // Left untouched while idle, so the dashboard keeps the last run and only redraws on change
+    if total == 0 && report.total() == 0 {
+        return;
+    }
+    report.armies = armies;
+    report.trade_routes = trade_routes;
+    report.migrations = migrations;
+    report.loaded_ms = if total > 0 { frame_time_ms(&diagnostics) } else { None };
+    report.peak_ms = report.peak_ms.max(report.loaded_ms.unwrap_or(0.0));
+}
+
+/// Tear the load down when leaving the game
+pub fn clear_stress_load(
+    mut commands: Commands,
+    load: Query<Entity, With<StressLoad>>,
+    mut report: ResMut<StressReport>,
+) {
+    for entity in &load {
+        commands.entity(entity).despawn();
+    }
+    *report = StressReport::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wandering_never_crosses_itself_and_convoys_turn_back() {
+        // A 3x3 grid, numbered row by row
+        let neighbors = |index: usize| {
+            let (row, column) = (index / 3, index % 3);
+            let mut around = Vec::new();
+            if row > 0 {
+                around.push(index - 3);
+            }
+            if row < 2 {
+                around.push(index + 3);
+            }
+            if column > 0 {
+                around.push(index - 1);
+            }
+            if column < 2 {
+                around.push(index + 1);
+            }
+            around
+        };
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..20 {
+            let path = wander(4, 12, neighbors, &mut rng);
+            assert!(path.len() <= 9);
+            assert!(path.windows(2).all(|step| neighbors(step[0]).contains(&step[1])));
+            assert!(path.iter().enumerate().all(|(i, index)| !path[..i].contains(index)));
+        }
+
+        let mut convoy = StressJourney {
+            from: Vec2::ZERO,
+            to: Vec2::new(10.0, 0.0),
+            progress: 0.0,
+            round_trip: true,
+            region: None,
+            owed_days: 0,
+        };
+        convoy.advance(13.0);
+        assert_eq!(convoy.from, Vec2::new(10.0, 0.0));
+        assert!((convoy.position().x - 7.0).abs() < 0.001);
+
+        let mut migration = StressJourney {
+            round_trip: false,
+            ..convoy
+        };
+        migration.advance(100.0);
+        assert_eq!(migration.position(), Vec2::ZERO);
+    }
+}
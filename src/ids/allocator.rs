@@ -0,0 +1,218 @@
+//! Typed ID spaces and the allocator that mints them
+//!
+//! Each [`IdSpace`] counts upward independently. IDs seen from any other
+//! source - generated nations, data-file laws, a loaded save - are reserved
+//! so the allocator never hands them out again. Whether a released ID may be
+//! reused is a property of its space: anything history or genealogy can refer
+//! to is never recycled.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use thiserror::Error;
+
+use crate::nations::{CharacterId, LawId, NationId};
+
+/// Independent ranges of IDs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Reflect)]
+pub enum IdSpace {
+    Nation,
+    Army,
+    Law,
+    Character,
+}
+
+/// What happens to an ID after its owner is gone
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecyclePolicy {
+    /// Released IDs stay retired - history and saves may still name them
+    Never,
+    /// Released IDs are handed out again, lowest first
+    Reuse,
+}
+
+impl IdSpace {
+    pub fn recycle_policy(self) -> RecyclePolicy {
+        match self {
+            // Armies are transient and nothing outlives them by ID
+            IdSpace::Army => RecyclePolicy::Reuse,
+            IdSpace::Nation | IdSpace::Law | IdSpace::Character => RecyclePolicy::Never,
+        }
+    }
+
+    /// Largest raw value the space's ID type can hold
+    pub fn max_raw(self) -> u32 {
+        match self {
+            IdSpace::Law => u16::MAX as u32,
+            _ => u32::MAX,
+        }
+    }
+}
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum IdError {
+    #[error("No IDs left in the {0:?} space")]
+    Exhausted(IdSpace),
+}
+
+/// An ID type minted by the allocator
+pub trait AllocatedId: Copy {
+    const SPACE: IdSpace;
+    fn from_raw(raw: u32) -> Option<Self>;
+    fn raw(self) -> u32;
+}
+
+/// Identifier of an army
+///
+/// Given back to the [`IdAllocator`] when the army is disbanded, so the
+/// next army mustered can take it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+#[reflect(Component)]
+pub struct ArmyId(pub u32);
+
+// Manual Component implementation to register lifecycle hooks
+impl Component for ArmyId {
+    const STORAGE_TYPE: bevy::ecs::component::StorageType = bevy::ecs::component::StorageType::Table;
+    type Mutability = bevy::ecs::component::Mutable;
+
+    fn on_replace() -> Option<bevy::ecs::lifecycle::ComponentHook> {
+        Some(|mut world, bevy::ecs::lifecycle::HookContext { entity, .. }| {
+            let Some(id) = world.get::<ArmyId>(entity).copied() else {
+                return;
+            };
+            if let Some(mut ids) = world.get_resource_mut::<IdAllocator>() {
+                ids.release(id);
+            }
+        })
+    }
+}
+
+impl AllocatedId for NationId {
+    const SPACE: IdSpace = IdSpace::Nation;
+    fn from_raw(raw: u32) -> Option<Self> {
+        Some(NationId::new(raw))
+    }
+    fn raw(self) -> u32 {
+        self.value()
+    }
+}
+
+impl AllocatedId for ArmyId {
+    const SPACE: IdSpace = IdSpace::Army;
+    fn from_raw(raw: u32) -> Option<Self> {
+        Some(ArmyId(raw))
+    }
+    fn raw(self) -> u32 {
+        self.0
+    }
+}
+
+impl AllocatedId for LawId {
+    const SPACE: IdSpace = IdSpace::Law;
+    fn from_raw(raw: u32) -> Option<Self> {
+        u16::try_from(raw).ok().map(LawId::new)
+    }
+    fn raw(self) -> u32 {
+        self.0 as u32
+    }
+}
+
+impl AllocatedId for CharacterId {
+    const SPACE: IdSpace = IdSpace::Character;
+    fn from_raw(raw: u32) -> Option<Self> {
+        Some(CharacterId(raw))
+    }
+    fn raw(self) -> u32 {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct SpaceState {
+    /// Lowest never-issued raw value (u64 so the last u32 can be issued)
+    next: u64,
+    /// Released IDs waiting to be reused
+    free: BTreeSet<u32>,
+}
+
+/// Central source of collision-free IDs, persisted in saves
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IdAllocator {
+    spaces: BTreeMap<IdSpace, SpaceState>,
+}
+
+impl IdAllocator {
+    /// Mint a new ID
+    pub fn allocate<T: AllocatedId>(&mut self) -> Result<T, IdError> {
+        let raw = self.allocate_raw(T::SPACE)?;
+        T::from_raw(raw).ok_or(IdError::Exhausted(T::SPACE))
+    }
+
+    pub fn allocate_raw(&mut self, space: IdSpace) -> Result<u32, IdError> {
+        let state = self.spaces.entry(space).or_default();
+        if let Some(raw) = state.free.pop_first() {
+            return Ok(raw);
+        }
+        if state.next > space.max_raw() as u64 {
+            return Err(IdError::Exhausted(space));
+        }
+        let raw = state.next as u32;
+        state.next += 1;
+        Ok(raw)
+    }
+
+    /// Mark an ID minted elsewhere as taken
+    pub fn reserve<T: AllocatedId>(&mut self, id: T) {
+        self.reserve_raw(T::SPACE, id.raw());
+    }
+
+    pub fn reserve_raw(&mut self, space: IdSpace, raw: u32) {
+        let state = self.spaces.entry(space).or_default();
+        state.free.remove(&raw);
+        state.next = state.next.max(raw as u64 + 1);
+    }
+
+    /// Give an ID back; only spaces that allow it will issue it again
+    pub fn release<T: AllocatedId>(&mut self, id: T) {
+        if T::SPACE.recycle_policy() == RecyclePolicy::Never {
+            return;
+        }
+        let state = self.spaces.entry(T::SPACE).or_default();
+        if (id.raw() as u64) < state.next {
+            state.free.insert(id.raw());
+        }
+    }
+
+    /// Number of distinct IDs ever issued or reserved in a space
+    pub fn issued(&self, space: IdSpace) -> u64 {
+        self.spaces.get(&space).map_or(0, |state| state.next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reserved_ids_are_skipped_and_only_reusable_spaces_recycle() {
+        let mut ids = IdAllocator::default();
+        ids.reserve(NationId::new(4));
+        assert_eq!(ids.allocate::<NationId>(), Ok(NationId::new(5)));
+
+        // Nations are never recycled
+        ids.release(NationId::new(5));
+        assert_eq!(ids.allocate::<NationId>(), Ok(NationId::new(6)));
+
+        // Armies are, lowest first, and reserving takes one back off the free list
+        let armies: Vec<ArmyId> = (0..4).filter_map(|_| ids.allocate().ok()).collect();
+        ids.release(armies[2]);
+        ids.release(armies[1]);
+        ids.reserve(ArmyId(1));
+        assert_eq!(ids.allocate::<ArmyId>(), Ok(ArmyId(2)));
+        assert_eq!(ids.allocate::<ArmyId>(), Ok(ArmyId(4)));
+
+        // Law IDs are u16
+        ids.reserve_raw(IdSpace::Law, u16::MAX as u32);
+        assert_eq!(ids.allocate::<LawId>(), Err(IdError::Exhausted(IdSpace::Law)));
+    }
+}
@@ -0,0 +1,19 @@
+//! Unified ID allocation gateway
+//!
+//! Every subsystem that mints identifiers - nations, armies, laws, characters,
+//! and the mods and editor tools that add more of them - draws from one
+//! [`IdAllocator`] so IDs never collide and survive save/load.
+//!
+//! # Gateway Pattern
+//!
+//! This is a PURE gateway - no implementations, only module declarations
+//! and controlled exports.
+
+// PRIVATE MODULES
+mod allocator;
+mod plugin;
+mod systems;
+
+// CONTROLLED EXPORTS
+pub use allocator::{AllocatedId, ArmyId, IdAllocator, IdError, IdSpace, RecyclePolicy};
+pub use plugin::IdPlugin;
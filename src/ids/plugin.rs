@@ -0,0 +1,19 @@
+//! ID allocation plugin
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+use super::allocator::{ArmyId, IdAllocator, IdSpace};
+use super::systems::{reserve_registered_law_ids, reserve_spawned_nation_ids};
+use crate::nations::LawRegistry;
+
+define_plugin!(IdPlugin {
+    resources: [IdAllocator],
+
+    reflect: [IdSpace, ArmyId],
+
+    update: [
+        reserve_spawned_nation_ids,
+        reserve_registered_law_ids.run_if(resource_exists_and_changed::<LawRegistry>)
+    ]
+});
@@ -0,0 +1,20 @@
+//! Keeping the allocator in step with IDs minted elsewhere
+
+use bevy::prelude::*;
+
+use super::allocator::IdAllocator;
+use crate::nations::{LawRegistry, NationId};
+
+/// Reserve the IDs of nations spawned by world generation or loading
+pub fn reserve_spawned_nation_ids(mut ids: ResMut<IdAllocator>, nations: Query<&NationId, Added<NationId>>) {
+    for &nation_id in &nations {
+        ids.reserve(nation_id);
+    }
+}
+
+/// Reserve every registered law so mods and the editor mint IDs above them
+pub fn reserve_registered_law_ids(mut ids: ResMut<IdAllocator>, registry: Res<LawRegistry>) {
+    for law in registry.all_laws() {
+        ids.reserve(law.id);
+    }
+}
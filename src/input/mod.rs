@@ -0,0 +1,18 @@
+//! Input abstraction layer - Gateway module
+//!
+//! Turns devices other than mouse and keyboard into device-independent
+//! actions. Each frame the layer gathers mouse-wheel scrolling, touchpad
+//! pinches and two-finger scrolls, and gamepad sticks, triggers and buttons
+//! into [`InputActions`]. The camera reads pan and zoom from it, and the
+//! shortcuts registry turns its gamepad button presses into the same
+//! shortcut events the keyboard produces, so the d-pad navigates menus and
+//! the face buttons pause, change speed and switch map modes.
+
+// PRIVATE modules
+mod plugin;
+mod systems;
+mod types;
+
+// PUBLIC exports
+pub use plugin::InputPlugin;
+pub use types::InputActions;
@@ -0,0 +1,17 @@
+//! Input abstraction plugin
+
+use bevy::input::InputSystems;
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+use super::systems::gather_input_actions;
+use super::types::InputActions;
+
+define_plugin!(InputPlugin {
+    resources: [InputActions],
+
+    custom_init: |app: &mut App| {
+        // Gather once per frame, after Bevy has processed raw device input
+        app.add_systems(PreUpdate, gather_input_actions.after(InputSystems));
+    }
+});
@@ -0,0 +1,56 @@
+//! Input abstraction systems
+
+use bevy::input::gestures::PinchGesture;
+use bevy::input::mouse::MouseWheel;
+use bevy::prelude::*;
+
+use super::types::{apply_deadzone, classify_scroll, InputActions, ScrollIntent};
+use crate::settings::GameSettings;
+
+/// Gather this frame's scrolling, pinching and gamepad input into actions
+pub fn gather_input_actions(
+    mut actions: ResMut<InputActions>,
+    mut mouse_wheel: MessageReader<MouseWheel>,
+    mut pinches: MessageReader<PinchGesture>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    settings: Res<GameSettings>,
+) {
+    let mut next = InputActions::default();
+
+    let ctrl = keyboard.pressed(KeyCode::ControlLeft) || keyboard.pressed(KeyCode::ControlRight);
+    for event in mouse_wheel.read() {
+        match classify_scroll(
+            event.unit,
+            Vec2::new(event.x, event.y),
+            ctrl,
+            settings.controls.touchpad_pan,
+        ) {
+            ScrollIntent::Zoom(lines) => next.zoom_steps += lines,
+            ScrollIntent::Pan(pixels) => next.scroll_pan += pixels,
+        }
+    }
+    for pinch in pinches.read() {
+        next.pinch += pinch.0;
+    }
+
+    // Every connected pad drives the same camera; the strongest input wins per axis
+    let deadzone = settings.controls.gamepad_deadzone;
+    for gamepad in &gamepads {
+        let stick = apply_deadzone(gamepad.left_stick(), deadzone);
+        if stick.length() > next.pan_axis.length() {
+            next.pan_axis = stick;
+        }
+
+        let zoom_in = gamepad.get(GamepadButton::RightTrigger2).unwrap_or(0.0);
+        let zoom_out = gamepad.get(GamepadButton::LeftTrigger2).unwrap_or(0.0);
+        let zoom = zoom_out - zoom_in;
+        if zoom.abs() > deadzone && zoom.abs() > next.zoom_axis.abs() {
+            next.zoom_axis = zoom;
+        }
+
+        next.buttons_pressed.extend(gamepad.get_just_pressed().copied());
+    }
+
+    *actions = next;
+}
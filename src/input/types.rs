@@ -0,0 +1,88 @@
+//! Input abstraction types
+
+use bevy::input::mouse::MouseScrollUnit;
+use bevy::prelude::*;
+
+/// Wheel lines per pixel of high-resolution scrolling
+pub const PIXEL_SCROLL_FACTOR: f32 = 0.01;
+
+/// Device-independent input gathered this frame
+#[derive(Resource, Debug, Default, Clone)]
+pub struct InputActions {
+    /// Camera pan from the gamepad left stick, each axis -1..1 (y up)
+    pub pan_axis: Vec2,
+    /// Continuous zoom from the gamepad triggers, -1 (zoom in) to 1 (zoom out)
+    pub zoom_axis: f32,
+    /// Two-finger touchpad scrolling this frame, in screen pixels as reported
+    pub scroll_pan: Vec2,
+    /// Mouse-wheel zoom this frame, in wheel lines (positive scrolls away from the user)
+    pub zoom_steps: f32,
+    /// Touchpad pinch this frame (positive spreads the fingers to zoom in)
+    pub pinch: f32,
+    /// Gamepad buttons pressed this frame, on any connected pad
+    pub buttons_pressed: Vec<GamepadButton>,
+}
+
+/// What one scroll event asks for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScrollIntent {
+    /// Zoom by this many wheel lines
+    Zoom(f32),
+    /// Pan by this many pixels
+    Pan(Vec2),
+}
+
+/// Classify a scroll event as zooming or panning
+///
+/// Mouse wheels scroll in lines and always zoom. Touchpads scroll in pixels:
+/// with two-finger panning enabled those pan the map, except while Ctrl is
+/// held, which is how precision touchpads report a pinch.
+pub fn classify_scroll(unit: MouseScrollUnit, delta: Vec2, ctrl: bool, touchpad_pan: bool) -> ScrollIntent {
+    match unit {
+        MouseScrollUnit::Line => ScrollIntent::Zoom(delta.y),
+        MouseScrollUnit::Pixel if touchpad_pan && !ctrl => ScrollIntent::Pan(delta),
+        MouseScrollUnit::Pixel => ScrollIntent::Zoom(delta.y * PIXEL_SCROLL_FACTOR),
+    }
+}
+
+/// Apply a radial dead zone to a stick, rescaling what is left to 0..1
+pub fn apply_deadzone(stick: Vec2, deadzone: f32) -> Vec2 {
+    let magnitude = stick.length();
+    if magnitude <= deadzone || magnitude == 0.0 {
+        return Vec2::ZERO;
+    }
+    let scaled = ((magnitude - deadzone) / (1.0 - deadzone).max(f32::EPSILON)).min(1.0);
+    stick / magnitude * scaled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wheels_zoom_touchpads_pan_and_sticks_rest_in_the_deadzone() {
+        let swipe = Vec2::new(12.0, -30.0);
+        assert_eq!(
+            classify_scroll(MouseScrollUnit::Line, Vec2::Y, false, true),
+            ScrollIntent::Zoom(1.0)
+        );
+        assert_eq!(
+            classify_scroll(MouseScrollUnit::Pixel, swipe, false, true),
+            ScrollIntent::Pan(swipe)
+        );
+        // Ctrl+scroll is a precision-touchpad pinch; without touchpad panning pixels zoom
+        assert!(matches!(
+            classify_scroll(MouseScrollUnit::Pixel, swipe, true, true),
+            ScrollIntent::Zoom(_)
+        ));
+        assert!(matches!(
+            classify_scroll(MouseScrollUnit::Pixel, swipe, false, false),
+            ScrollIntent::Zoom(_)
+        ));
+
+        assert_eq!(apply_deadzone(Vec2::new(0.1, 0.05), 0.15), Vec2::ZERO);
+        assert!((apply_deadzone(Vec2::X, 0.15).x - 1.0).abs() < 1e-6);
+        let half = apply_deadzone(Vec2::new(0.0, -0.575), 0.15);
+        assert!((half.y + 0.5).abs() < 1e-4);
+    }
+}
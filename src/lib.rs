@@ -15,18 +15,25 @@ pub mod infrastructure; // System-level configuration and resource management
 pub mod states; // Game state management
 
 // Modules accessed through gateway re-exports below
+mod ai; // Shared AI decision infrastructure
 mod app; // Application building and plugin management
+mod audio; // Semantic sound event bus
 mod camera;
+mod chronicle; // Dated record of world history
 mod components;
 mod config; // Configuration management and settings
 mod constants;
 mod content_creation;
 mod diagnostics; // Performance monitoring and FPS display
+mod ids; // Collision-free ID allocation shared by all subsystems
+mod input; // Touchpad and gamepad input abstraction
 mod loading;
 mod math; // Single source of truth for spatial math and noise
 mod menus;
+mod milestones; // Emergent per-world observer goals
 mod modding;
 mod name_generator;
+mod narration; // Screen-reader-friendly event narration
 mod nations;
 mod parallel; // Parallel processing infrastructure
 mod performance; // Rayon performance monitoring
@@ -39,6 +46,8 @@ mod simulation;
 mod ui;
 mod version;
 mod world; // World representation and rendering
+mod world_report; // End-of-world summary reports
+mod worlds; // Several worlds open at once, one on screen
 
 // Steam integration (only when feature is enabled)
 #[cfg(feature = "steam")]
@@ -48,6 +57,10 @@ pub mod steam;
 #[cfg(test)]
 pub mod test_utils;
 
+// Headless miniature-world harness for behavioral tests
+#[cfg(test)]
+pub mod sim_harness;
+
 // === Configuration Constants ===
 /// Default window width in pixels
 pub const DEFAULT_WINDOW_WIDTH: f32 = 1920.0;
@@ -92,7 +105,7 @@ pub mod prelude {
 pub use app::{build_app, build_app_with_config, AppBuildError};
 
 // Configuration
-pub use config::{AppConfig, DiagnosticsConfig, WindowConfig};
+pub use config::{AppConfig, DiagnosticsConfig, MetricsConfig, MetricsExporter, WindowConfig};
 
 // Performance monitoring
 pub use diagnostics::{display_fps, DiagnosticsPlugin};
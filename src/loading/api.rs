@@ -1,6 +1,7 @@
 //! Public API functions for external loading system integration
 
 use super::state::{LoadingDetails, LoadingOperation, LoadingState};
+use bevy::prelude::*;
 
 /// Update the loading state from external systems
 ///
@@ -20,6 +21,20 @@ pub fn set_loading_progress(
     loading_state.current_step = message.into();
 }
 
+/// Show the map as generated so far
+///
+/// Each finished generation stage replaces the previous preview, so the
+/// loading screen reveals the world while the rest is still being built.
+///
+/// # Arguments
+/// * `loading_state` - Mutable reference to the LoadingState resource
+/// * `image` - Preview image of the map
+/// * `stage` - Name of the stage the preview shows
+pub fn set_loading_preview(loading_state: &mut LoadingState, image: Handle<Image>, stage: impl Into<String>) {
+    loading_state.details.preview = Some(image);
+    loading_state.details.preview_stage = Some(stage.into());
+}
+
 /// Start a world generation loading operation
 ///
 /// Initializes the loading state for world generation with the
@@ -37,6 +52,8 @@ pub fn start_world_generation_loading(loading_state: &mut LoadingState, seed: u3
     loading_state.details = LoadingDetails {
         world_seed: Some(seed),
         world_size: Some(size),
+        preview: None,
+        preview_stage: None,
         save_name: None,
         game_days: None,
         file_size: None,
@@ -52,12 +69,12 @@ pub fn start_world_generation_loading(loading_state: &mut LoadingState, seed: u3
 /// # Arguments
 /// * `loading_state` - Mutable reference to the LoadingState resource
 /// * `save_name` - Name of the save file being loaded
-/// * `game_days` - Age of the world in game days
+/// * `game_days` - Age of the world in game days, if already known
 /// * `file_size` - Human-readable file size description
 pub fn start_save_loading(
     loading_state: &mut LoadingState,
     save_name: String,
-    game_days: f32,
+    game_days: Option<f32>,
     file_size: String,
 ) {
     loading_state.operation = LoadingOperation::LoadingSave;
@@ -66,8 +83,10 @@ pub fn start_save_loading(
     loading_state.details = LoadingDetails {
         world_seed: None,
         world_size: None,
+        preview: None,
+        preview_stage: None,
         save_name: Some(save_name),
-        game_days: Some(game_days),
+        game_days,
         file_size: Some(file_size),
     };
 }
@@ -87,6 +106,8 @@ pub fn start_mod_application_loading(loading_state: &mut LoadingState) {
     loading_state.details = LoadingDetails {
         world_seed: None,
         world_size: None,
+        preview: None,
+        preview_stage: None,
         save_name: None,
         game_days: None,
         file_size: None,
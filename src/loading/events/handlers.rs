@@ -1,7 +1,7 @@
 //! Event handling systems for loading interactions
 
-use super::types::CancelWorldGeneration;
-use crate::loading::ui::CancelGenerationButton;
+use super::types::{CancelSaveLoading, CancelWorldGeneration};
+use crate::loading::ui::{CancelGenerationButton, CancelLoadButton};
 use crate::states::{GameState, RequestStateTransition};
 use bevy::prelude::*;
 
@@ -18,6 +18,19 @@ pub fn handle_cancel_button(
     }
 }
 
+/// Handle the cancel button shown while a save loads
+pub fn handle_cancel_load_button(
+    interactions: Query<&Interaction, (Changed<Interaction>, With<CancelLoadButton>)>,
+    mut cancel_events: MessageWriter<CancelSaveLoading>,
+) {
+    for interaction in &interactions {
+        if *interaction == Interaction::Pressed {
+            info!("Cancel Loading button pressed");
+            cancel_events.write(CancelSaveLoading);
+        }
+    }
+}
+
 /// Handle cancel world generation events
 pub fn handle_cancel_generation(
     mut cancel_events: MessageReader<CancelWorldGeneration>,
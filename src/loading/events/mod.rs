@@ -1,7 +1,7 @@
 //! Events subsystem for loading screen interactions
 //!
 //! This module handles all event-driven functionality:
-//! - Cancel generation events
+//! - Cancel generation and save loading events
 //! - Button interaction handling
 //! - State transition logic
 
@@ -10,5 +10,5 @@ mod handlers;
 mod types;
 
 // Controlled exports
-pub use handlers::{handle_cancel_button, handle_cancel_generation};
-pub use types::CancelWorldGeneration;
+pub use handlers::{handle_cancel_button, handle_cancel_generation, handle_cancel_load_button};
+pub use types::{CancelSaveLoading, CancelWorldGeneration};
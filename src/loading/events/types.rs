@@ -9,3 +9,11 @@ use bevy::prelude::*;
 /// generation resources and return to the world configuration screen.
 #[derive(Message)]
 pub struct CancelWorldGeneration;
+
+/// Event to cancel loading a save
+///
+/// Triggered by the cancel button while a save loads. The save/load system
+/// stops the background read, despawns anything already restored, and
+/// returns to the main menu.
+#[derive(Message)]
+pub struct CancelSaveLoading;
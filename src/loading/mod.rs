@@ -38,9 +38,9 @@ mod ui;
 
 // Controlled public exports - gateway interface
 pub use api::{
-    set_loading_progress, start_mod_application_loading, start_save_loading,
+    set_loading_preview, set_loading_progress, start_mod_application_loading, start_save_loading,
     start_world_generation_loading,
 };
-pub use events::CancelWorldGeneration;
+pub use events::{CancelSaveLoading, CancelWorldGeneration};
 pub use plugin::LoadingScreenPlugin;
 pub use state::LoadingState;
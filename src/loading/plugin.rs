@@ -1,7 +1,7 @@
 //! Loading screen plugin - Bevy integration for the loading system
 
 use super::{
-    CancelWorldGeneration,
+    CancelSaveLoading, CancelWorldGeneration,
     state::LoadingState,
 };
 use crate::states::GameState;
@@ -18,12 +18,14 @@ use bevy_plugin_builder::define_plugin;
 define_plugin!(LoadingScreenPlugin {
     resources: [LoadingState],
 
-    messages: [CancelWorldGeneration],
+    messages: [CancelWorldGeneration, CancelSaveLoading],
 
     update: [
         super::progress::update_loading_progress,
         super::progress::update_loading_text,
+        super::progress::update_loading_preview,
         super::events::handle_cancel_button,
+        super::events::handle_cancel_load_button,
         super::events::handle_cancel_generation
     ],
 
@@ -3,12 +3,15 @@
 //! This module handles all progress-related functionality:
 //! - Progress bar value updates
 //! - Status text updates
+//! - Map preview reveal during world generation
 //! - Loading state synchronization
 
 // Private module declarations
+mod preview;
 mod text;
 mod tracking;
 
 // Controlled exports
+pub use preview::update_loading_preview;
 pub use text::update_loading_text;
 pub use tracking::update_loading_progress;
@@ -0,0 +1,40 @@
+//! Map preview reveal during world generation
+
+use crate::loading::state::LoadingState;
+use crate::loading::ui::{LoadingPreviewCaption, LoadingPreviewImage};
+use bevy::prelude::*;
+
+/// Show the latest generation preview and name its stage
+///
+/// The preview stays hidden until the first stage has finished, so the
+/// panel doesn't reserve space for a blank map.
+pub fn update_loading_preview(
+    loading_state: Res<LoadingState>,
+    mut images: Query<(&mut ImageNode, &mut Node), With<LoadingPreviewImage>>,
+    mut captions: Query<&mut Text, With<LoadingPreviewCaption>>,
+) {
+    if !loading_state.is_changed() {
+        return;
+    }
+    let Some(preview) = &loading_state.details.preview else {
+        return;
+    };
+
+    for (mut image, mut node) in &mut images {
+        if image.image != *preview {
+            image.image = preview.clone();
+        }
+        if node.display == Display::None {
+            node.display = Display::Flex;
+        }
+    }
+
+    if let Some(stage) = &loading_state.details.preview_stage {
+        let caption = format!("Revealed: {}", stage);
+        for mut text in &mut captions {
+            if text.0 != caption {
+                text.0 = caption.clone();
+            }
+        }
+    }
+}
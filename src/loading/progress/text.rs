@@ -1,7 +1,7 @@
 //! Status text tracking and update system
 
 use crate::loading::state::LoadingState;
-use crate::loading::ui::LoadingStatusText;
+use crate::loading::ui::{world_age_text, LoadingStatusText, LoadingWorldAgeText};
 use bevy::prelude::*;
 
 /// Update the status text
@@ -12,7 +12,16 @@ use bevy::prelude::*;
 pub fn update_loading_text(
     loading_state: Res<LoadingState>,
     mut query: Query<&mut Text, With<LoadingStatusText>>,
+    mut age_query: Query<&mut Text, (With<LoadingWorldAgeText>, Without<LoadingStatusText>)>,
 ) {
+    // A save's age appears once the background read has finished
+    let age = world_age_text(loading_state.details.game_days);
+    for mut text in &mut age_query {
+        if text.0 != age {
+            text.0 = age.clone();
+        }
+    }
+
     // Temporarily removed change detection to debug the issue
     // if loading_state.is_changed() {
         for mut text in &mut query {
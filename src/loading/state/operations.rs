@@ -1,5 +1,7 @@
 //! Loading operation types and details
 
+use bevy::prelude::*;
+
 /// The type of loading operation currently in progress
 #[derive(Debug, Clone, PartialEq, Default)]
 pub enum LoadingOperation {
@@ -28,6 +30,10 @@ pub struct LoadingDetails {
     pub world_seed: Option<u32>,
     /// Size category of the world (Small, Medium, Large)
     pub world_size: Option<String>,
+    /// Map as generated so far, revealed stage by stage
+    pub preview: Option<Handle<Image>>,
+    /// Name of the last stage shown in the preview
+    pub preview_stage: Option<String>,
 
     // Save loading specific
     /// Name of the save file being loaded
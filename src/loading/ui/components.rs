@@ -10,6 +10,22 @@ pub struct LoadingProgressBar;
 #[derive(Component)]
 pub struct LoadingStatusText;
 
+/// Marker component for a loading save's world age
+#[derive(Component)]
+pub struct LoadingWorldAgeText;
+
+/// Marker component for the map preview revealed during world generation
+#[derive(Component)]
+pub struct LoadingPreviewImage;
+
+/// Marker component for the caption naming the preview's stage
+#[derive(Component)]
+pub struct LoadingPreviewCaption;
+
 /// Marker component for the cancel generation button
 #[derive(Component)]
 pub struct CancelGenerationButton;
+
+/// Marker component for the cancel button shown while a save loads
+#[derive(Component)]
+pub struct CancelLoadButton;
@@ -12,6 +12,8 @@ mod sections;
 
 // Controlled exports
 pub use components::{
-    CancelGenerationButton, LoadingProgressBar, LoadingStatusText,
+    CancelGenerationButton, CancelLoadButton, LoadingPreviewCaption, LoadingPreviewImage, LoadingProgressBar,
+    LoadingStatusText, LoadingWorldAgeText,
 };
 pub use layout::setup_loading_screen;
+pub use sections::world_age_text;
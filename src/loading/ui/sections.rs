@@ -1,6 +1,9 @@
 //! UI section builders for different parts of the loading screen
 
-use super::components::{CancelGenerationButton, LoadingProgressBar, LoadingStatusText};
+use super::components::{
+    CancelGenerationButton, CancelLoadButton, LoadingPreviewCaption, LoadingPreviewImage, LoadingProgressBar,
+    LoadingStatusText, LoadingWorldAgeText,
+};
 use crate::loading::state::{LoadingOperation, LoadingState};
 use crate::ui::{
     colors, dimensions, get_random_tip, ButtonBuilder, ButtonStyle, LabelBuilder, LabelStyle,
@@ -80,6 +83,8 @@ fn spawn_operation_details(parent: &mut ChildSpawnerCommands, loading_state: &Lo
                     .color(colors::TEXT_PRIMARY)
                     .build(parent);
             }
+
+            spawn_generation_preview(parent);
         }
         LoadingOperation::ApplyingMods => {
             LabelBuilder::new("Reloading game systems with new mod configuration")
@@ -96,17 +101,46 @@ fn spawn_operation_details(parent: &mut ChildSpawnerCommands, loading_state: &Lo
                     .build(parent);
             }
 
-            if let Some(days) = loading_state.details.game_days {
-                LabelBuilder::new(&format!("World Age: {:.0} days", days))
-                    .font_size(dimensions::FONT_SIZE_NORMAL)
-                    .color(colors::TEXT_PRIMARY)
-                    .build(parent);
-            }
+            // The age is only known once the save has been read
+            let age_entity = LabelBuilder::new(&world_age_text(loading_state.details.game_days))
+                .font_size(dimensions::FONT_SIZE_NORMAL)
+                .color(colors::TEXT_PRIMARY)
+                .build(parent);
+            parent.commands().entity(age_entity).insert(LoadingWorldAgeText);
         }
         _ => {}
     }
 }
 
+/// Spawn the map preview, hidden until the first generation stage finishes
+fn spawn_generation_preview(parent: &mut ChildSpawnerCommands) {
+    parent.spawn((
+        LoadingPreviewImage,
+        ImageNode::default(),
+        Node {
+            display: Display::None,
+            width: Val::Percent(100.0),
+            margin: UiRect::top(Val::Px(20.0)),
+            ..default()
+        },
+    ));
+
+    let caption = LabelBuilder::new("")
+        .font_size(dimensions::FONT_SIZE_SMALL)
+        .color(colors::TEXT_MUTED)
+        .margin(UiRect::top(Val::Px(8.0)))
+        .build(parent);
+    parent.commands().entity(caption).insert(LoadingPreviewCaption);
+}
+
+/// Label for a loading save's world age
+pub fn world_age_text(game_days: Option<f32>) -> String {
+    match game_days {
+        Some(days) => format!("World Age: {:.0} days", days),
+        None => "World Age: reading...".to_string(),
+    }
+}
+
 /// Spawn the bottom section with progress bar and tips
 pub fn spawn_bottom_section(parent: &mut ChildSpawnerCommands, loading_state: &LoadingState) {
     parent
@@ -151,13 +185,23 @@ pub fn spawn_bottom_section(parent: &mut ChildSpawnerCommands, loading_state: &L
                 .margin(UiRect::top(Val::Px(20.0)))
                 .build(bottom);
 
-            // Cancel button - only show during world generation
-            if loading_state.operation == LoadingOperation::GeneratingWorld {
-                ButtonBuilder::new("Cancel Generation")
-                    .style(ButtonStyle::Danger)
-                    .margin(UiRect::top(Val::Px(30.0)))
-                    .with_marker(CancelGenerationButton)
-                    .build(bottom);
+            // Cancel button - world generation and save loading can be abandoned
+            match loading_state.operation {
+                LoadingOperation::GeneratingWorld => {
+                    ButtonBuilder::new("Cancel Generation")
+                        .style(ButtonStyle::Danger)
+                        .margin(UiRect::top(Val::Px(30.0)))
+                        .with_marker(CancelGenerationButton)
+                        .build(bottom);
+                }
+                LoadingOperation::LoadingSave => {
+                    ButtonBuilder::new("Cancel Loading")
+                        .style(ButtonStyle::Danger)
+                        .margin(UiRect::top(Val::Px(30.0)))
+                        .with_marker(CancelLoadButton)
+                        .build(bottom);
+                }
+                _ => {}
             }
         });
 }
@@ -25,7 +25,8 @@ use living_worlds::{build_app_with_config, cli::{self, Parser}, infrastructure,
 ///
 /// Orchestrates the application startup through gateway modules:
 /// 1. Parse command-line arguments through CLI gateway
-/// 2. Initialize system infrastructure (logging, thread pools)
+/// 2. Initialize system infrastructure (logging, thread pools), or run the
+///    save tools or headless determinism check when asked for
 /// 3. Build application configuration from CLI inputs
 /// 4. Create Bevy application with all Living Worlds systems
 /// 5. Optionally setup development mode for quick-start workflows
@@ -36,6 +37,20 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Initialize infrastructure through gateway modules
     infrastructure::LoggingConfig::initialize(args.debug);
+
+    // Save tools and world generation work on files and exit without starting the game
+    match &args.command {
+        Some(cli::Command::Save { action }) => return cli::run_save_command(action),
+        Some(cli::Command::Gen(gen_args)) => return cli::run_world_generation(gen_args),
+        None => {}
+    }
+
+    // Determinism verification runs headless on its own thread pools and exits
+    if args.verify_determinism {
+        cli::run_determinism_check(&args)?;
+        return Ok(());
+    }
+
     infrastructure::ThreadPoolManager::initialize(args.threads)?;
 
     // Build application configuration through CLI gateway
@@ -0,0 +1,441 @@
+//! Deterministic 16.16 fixed-point numbers for simulation state
+//!
+//! `f32` arithmetic can round differently across CPUs and compiler settings,
+//! so values that feed the simulation (and therefore replays and saves) use
+//! [`Fixed32`] instead. Rendering code converts to floats at the edge with the
+//! `Vec2`/`Vec3` helpers.
+//!
+//! Serialization writes the raw `i32` bits, never a float, so a save round-trips
+//! bit-for-bit on every platform.
+//!
+//! Square roots and trigonometry are computed with integer arithmetic only, so
+//! movement and falloff come out the same everywhere:
+//!
+//! | Function | Method | Error |
+//! |----------|--------|-------|
+//! | [`Fixed32::sqrt`], [`Fixed32::hypot`] | integer square root | exact, rounded down |
+//! | [`Fixed32::sin`], [`Fixed32::cos`] | Taylor series to x¹¹ in 2.30 | under 1/65536 |
+//! | [`Fixed32::atan2`] | 31-step CORDIC in 2.30 | under 1/65536 radians |
+//!
+//! Angles are reduced modulo 2π in 2.30 precision, so the error does not grow
+//! for angles many turns from zero.
+//!
+//! The type is written here rather than wrapping the `fixed` crate so it can
+//! derive `Reflect` and so every operation, trigonometry included, is visible
+//! in one place when checking determinism.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Signed 16.16 fixed-point number (range about ±32768, resolution 1/65536)
+///
+/// Plain operators follow integer semantics: they panic on overflow in debug
+/// builds. Use the `saturating_*` and `checked_*` variants where inputs are
+/// unbounded.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Fixed32(i32);
+
+impl Fixed32 {
+    /// Number of fractional bits
+    pub const FRAC_BITS: u32 = 16;
+
+    pub const ZERO: Self = Self(0);
+    pub const ONE: Self = Self(1 << Self::FRAC_BITS);
+    pub const HALF: Self = Self(1 << (Self::FRAC_BITS - 1));
+    pub const MIN: Self = Self(i32::MIN);
+    pub const MAX: Self = Self(i32::MAX);
+    /// Smallest positive value
+    pub const EPSILON: Self = Self(1);
+    pub const PI: Self = Self(205_887);
+    pub const FRAC_PI_2: Self = Self(102_944);
+    pub const TAU: Self = Self(411_775);
+
+    /// Wrap raw bits (as stored in saves)
+    pub const fn from_bits(bits: i32) -> Self {
+        Self(bits)
+    }
+
+    /// Raw bits (as stored in saves)
+    pub const fn to_bits(self) -> i32 {
+        self.0
+    }
+
+    /// Whole number, saturating outside the representable range
+    pub const fn from_int(value: i32) -> Self {
+        let shifted = (value as i64) << Self::FRAC_BITS;
+        Self(clamp_i64(shifted))
+    }
+
+    /// `numerator / denominator` computed without floats, for constants
+    ///
+    /// A zero denominator yields zero.
+    pub const fn from_ratio(numerator: i32, denominator: i32) -> Self {
+        if denominator == 0 {
+            return Self::ZERO;
+        }
+        Self(clamp_i64(((numerator as i64) << Self::FRAC_BITS) / denominator as i64))
+    }
+
+    /// Nearest fixed-point value to a float (saturating, NaN becomes zero)
+    pub fn from_f32(value: f32) -> Self {
+        // `as` casts from float saturate and map NaN to zero
+        Self((value * Self::ONE.0 as f32).round() as i32)
+    }
+
+    pub fn to_f32(self) -> f32 {
+        self.0 as f32 / Self::ONE.0 as f32
+    }
+
+    /// Integer part, rounded toward negative infinity
+    pub const fn floor_int(self) -> i32 {
+        self.0 >> Self::FRAC_BITS
+    }
+
+    pub const fn abs(self) -> Self {
+        Self(self.0.saturating_abs())
+    }
+
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Ord::clamp(self, min, max)
+    }
+
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        Self(clamp_i64((self.0 as i64 * rhs.0 as i64) >> Self::FRAC_BITS))
+    }
+
+    /// Division saturating on overflow; dividing by zero saturates toward the sign of `self`
+    pub const fn saturating_div(self, rhs: Self) -> Self {
+        if rhs.0 == 0 {
+            return if self.0 >= 0 { Self::MAX } else { Self::MIN };
+        }
+        Self(clamp_i64(((self.0 as i64) << Self::FRAC_BITS) / rhs.0 as i64))
+    }
+
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(bits) => Some(Self(bits)),
+            None => None,
+        }
+    }
+
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(bits) => Some(Self(bits)),
+            None => None,
+        }
+    }
+
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        narrow_i64((self.0 as i64 * rhs.0 as i64) >> Self::FRAC_BITS)
+    }
+
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.0 == 0 {
+            return None;
+        }
+        narrow_i64(((self.0 as i64) << Self::FRAC_BITS) / rhs.0 as i64)
+    }
+
+    /// Square root, rounded down to the nearest representable value; zero for negative numbers
+    pub fn sqrt(self) -> Self {
+        if self.0 <= 0 {
+            return Self::ZERO;
+        }
+        Self(((self.0 as u64) << Self::FRAC_BITS).isqrt() as i32)
+    }
+
+    /// Length of the vector `(x, y)`, rounded down, without overflowing on the squares
+    ///
+    /// Saturates at [`Fixed32::MAX`].
+    pub fn hypot(x: Self, y: Self) -> Self {
+        let (x, y) = (x.0.unsigned_abs() as u64, y.0.unsigned_abs() as u64);
+        Self(clamp_i64((x * x + y * y).isqrt() as i64))
+    }
+
+    /// Sine of an angle in radians
+    pub fn sin(self) -> Self {
+        from_q30(sin_q30(to_q30(self)))
+    }
+
+    /// Cosine of an angle in radians
+    pub fn cos(self) -> Self {
+        from_q30(sin_q30(to_q30(self) + Q30_FRAC_PI_2))
+    }
+
+    /// Angle of the vector `(x, y)` in radians, in `[-π, π]`, counterclockwise from the x axis
+    ///
+    /// Follows `f32::atan2`: the negative x axis is `π`, and the zero vector is `0`.
+    pub fn atan2(y: Self, x: Self) -> Self {
+        if x.0 == 0 && y.0 == 0 {
+            return Self::ZERO;
+        }
+        // Scaled up for precision; the CORDIC gain of about 1.65 still fits easily
+        let (mut x, mut y) = ((x.0 as i64) << 16, (y.0 as i64) << 16);
+        let mut angle = 0;
+        // CORDIC only converges in the right half-plane, so turn the left half around first
+        if x < 0 {
+            angle = if y >= 0 { Q30_PI } else { -Q30_PI };
+            (x, y) = (-x, -y);
+        }
+        // Rotate the vector onto the x axis, adding up the angles turned through
+        for (shift, step) in ATAN_STEPS.iter().enumerate() {
+            let (dx, dy) = (y >> shift, x >> shift);
+            if y > 0 {
+                x += dx;
+                y -= dy;
+                angle += step;
+            } else {
+                x -= dx;
+                y += dy;
+                angle -= step;
+            }
+        }
+        from_q30(angle)
+    }
+
+    /// Float vector for rendering from fixed-point coordinates
+    pub fn to_vec2(x: Self, y: Self) -> Vec2 {
+        Vec2::new(x.to_f32(), y.to_f32())
+    }
+
+    /// Fixed-point coordinates from a float vector
+    pub fn from_vec2(v: Vec2) -> (Self, Self) {
+        (Self::from_f32(v.x), Self::from_f32(v.y))
+    }
+
+    /// Float vector for rendering from fixed-point coordinates
+    pub fn to_vec3(x: Self, y: Self, z: Self) -> Vec3 {
+        Vec3::new(x.to_f32(), y.to_f32(), z.to_f32())
+    }
+
+    /// Fixed-point coordinates from a float vector
+    pub fn from_vec3(v: Vec3) -> (Self, Self, Self) {
+        (Self::from_f32(v.x), Self::from_f32(v.y), Self::from_f32(v.z))
+    }
+}
+
+/// One in the 2.30 format used inside the trigonometric functions
+const Q30_ONE: i64 = 1 << 30;
+const Q30_PI: i64 = 3_373_259_426;
+const Q30_FRAC_PI_2: i64 = 1_686_629_713;
+const Q30_TAU: i64 = 6_746_518_852;
+/// atan(2⁻ⁱ) in 2.30, the angle CORDIC turns through at step i
+const ATAN_STEPS: [i64; 31] = [
+    843_314_857, 497_837_829, 263_043_837, 133_525_159, 67_021_687, 33_543_516, 16_775_851, 8_388_437, 4_194_283,
+    2_097_149, 1_048_576, 524_288, 262_144, 131_072, 65_536, 32_768, 16_384, 8_192, 4_096, 2_048, 1_024, 512, 256, 128,
+    64, 32, 16, 8, 4, 2, 1,
+];
+
+fn to_q30(value: Fixed32) -> i64 {
+    (value.0 as i64) << (30 - Fixed32::FRAC_BITS)
+}
+
+/// Round a 2.30 value to the nearest 16.16
+fn from_q30(value: i64) -> Fixed32 {
+    let shift = 30 - Fixed32::FRAC_BITS;
+    Fixed32(clamp_i64((value + (1 << (shift - 1))) >> shift))
+}
+
+fn q30_mul(a: i64, b: i64) -> i64 {
+    (a * b) >> 30
+}
+
+/// Sine of any angle, both in 2.30
+fn sin_q30(angle: i64) -> i64 {
+    let angle = angle.rem_euclid(Q30_TAU);
+    let quadrant = angle / Q30_FRAC_PI_2;
+    let offset = angle - quadrant * Q30_FRAC_PI_2;
+    match quadrant {
+        0 => sin_first_quadrant(offset),
+        1 => sin_first_quadrant(Q30_FRAC_PI_2 - offset),
+        2 => -sin_first_quadrant(offset),
+        _ => -sin_first_quadrant(Q30_FRAC_PI_2 - offset),
+    }
+}
+
+/// Sine of an angle in `[0, π/2]`: x - x³/3! + x⁵/5! - ... + x¹¹/11! in Horner form
+fn sin_first_quadrant(x: i64) -> i64 {
+    let square = q30_mul(x, x);
+    let mut series = Q30_ONE;
+    // (2k)(2k + 1) for k = 5 down to 1
+    for divisor in [110, 72, 42, 20, 6] {
+        series = Q30_ONE - q30_mul(square, series) / divisor;
+    }
+    q30_mul(x, series)
+}
+
+const fn clamp_i64(value: i64) -> i32 {
+    if value > i32::MAX as i64 {
+        i32::MAX
+    } else if value < i32::MIN as i64 {
+        i32::MIN
+    } else {
+        value as i32
+    }
+}
+
+const fn narrow_i64(value: i64) -> Option<Fixed32> {
+    if value > i32::MAX as i64 || value < i32::MIN as i64 {
+        None
+    } else {
+        Some(Fixed32(value as i32))
+    }
+}
+
+impl From<i32> for Fixed32 {
+    fn from(value: i32) -> Self {
+        Self::from_int(value)
+    }
+}
+
+impl From<Fixed32> for f32 {
+    fn from(value: Fixed32) -> Self {
+        value.to_f32()
+    }
+}
+
+impl fmt::Display for Fixed32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f32(), f)
+    }
+}
+
+impl Add for Fixed32 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Fixed32 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Mul for Fixed32 {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let product = (self.0 as i64 * rhs.0 as i64) >> Self::FRAC_BITS;
+        debug_assert!(narrow_i64(product).is_some(), "Fixed32 multiplication overflow");
+        Self(product as i32)
+    }
+}
+
+impl Div for Fixed32 {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        let quotient = ((self.0 as i64) << Self::FRAC_BITS) / rhs.0 as i64;
+        debug_assert!(narrow_i64(quotient).is_some(), "Fixed32 division overflow");
+        Self(quotient as i32)
+    }
+}
+
+impl Neg for Fixed32 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl AddAssign for Fixed32 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Fixed32 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Fixed32 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Fixed32 {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_as_raw_bits() {
+        let value = Fixed32::from_ratio(1, 3);
+        let json = serde_json::to_string(&value).unwrap_or_default();
+        assert_eq!(json, value.to_bits().to_string());
+
+        let restored: Option<Fixed32> = serde_json::from_str(&json).ok();
+        assert_eq!(restored, Some(value));
+    }
+
+    #[test]
+    fn arithmetic_matches_integer_math() {
+        let three = Fixed32::from_int(3);
+        let half = Fixed32::HALF;
+        assert_eq!(three * half, Fixed32::from_ratio(3, 2));
+        assert_eq!(three / Fixed32::from_int(2), Fixed32::from_ratio(3, 2));
+        assert_eq!((three - Fixed32::from_int(5)).floor_int(), -2);
+
+        assert_eq!(Fixed32::MAX.saturating_add(Fixed32::ONE), Fixed32::MAX);
+        assert_eq!(Fixed32::MAX.checked_mul(three), None);
+        assert_eq!(Fixed32::ONE.checked_div(Fixed32::ZERO), None);
+    }
+
+    #[test]
+    fn roots_and_trigonometry_stay_within_documented_error() {
+        let tolerance: f32 = 1.0 / 65536.0;
+        let precise = f64::from(tolerance);
+
+        assert_eq!(Fixed32::from_int(9).sqrt(), Fixed32::from_int(3));
+        assert_eq!(Fixed32::from_int(-4).sqrt(), Fixed32::ZERO);
+        assert_eq!(Fixed32::hypot(Fixed32::from_int(-3), Fixed32::from_int(4)), Fixed32::from_int(5));
+        // The squares of these would overflow a Fixed32
+        let far = Fixed32::from_int(20_000);
+        assert!((Fixed32::hypot(far, far).to_f32() - 28_284.271).abs() < 0.001);
+        assert!((Fixed32::from_ratio(1, 2).sqrt().to_f32() - 0.5_f32.sqrt()).abs() < tolerance);
+
+        for step in -2_000..=2_000 {
+            let angle = Fixed32::from_ratio(step, 100);
+            let radians = angle.to_f32() as f64;
+            assert!((angle.sin().to_f32() as f64 - radians.sin()).abs() <= precise, "sin {}", radians);
+            assert!((angle.cos().to_f32() as f64 - radians.cos()).abs() <= precise, "cos {}", radians);
+        }
+
+        for step in 0..360 {
+            let turn = (step as f64).to_radians();
+            for radius in [0.01, 1.0, 250.0, 20_000.0] {
+                let x = Fixed32::from_f32((radius * turn.cos()) as f32);
+                let y = Fixed32::from_f32((radius * turn.sin()) as f32);
+                let expected = (y.to_f32() as f64).atan2(x.to_f32() as f64);
+                let error = (Fixed32::atan2(y, x).to_f32() as f64 - expected).abs();
+                // Either end of the branch cut is the same direction
+                let error = error.min((error - std::f64::consts::TAU).abs());
+                assert!(error <= precise, "atan2 at {} degrees, radius {}", step, radius);
+            }
+        }
+        assert_eq!(Fixed32::atan2(Fixed32::ZERO, -Fixed32::ONE), Fixed32::PI);
+        assert_eq!(Fixed32::atan2(Fixed32::ZERO, Fixed32::ZERO), Fixed32::ZERO);
+    }
+}
@@ -0,0 +1,144 @@
+//! Deterministic gradient noise evaluated in fixed-point
+//!
+//! The classic Perlin construction (hashed gradients at lattice corners,
+//! quintic fade, bilinear blend) with every step after the lattice lookup done
+//! in [`Fixed32`]. Gradients come from an integer hash of the seed and cell
+//! coordinates rather than a shuffled permutation table, so no RNG or float
+//! transcendental is involved and the same seed produces the same world on
+//! every platform. `shaders/noise_compute.wgsl` mirrors the hash and gradient
+//! table so GPU generation stays within validation tolerance.
+
+use super::fixed::Fixed32;
+
+/// Gradient directions: the four axes and four diagonals
+const GRADIENTS: [(i32, i32); 8] = [
+    (1, 0),
+    (-1, 0),
+    (0, 1),
+    (0, -1),
+    (1, 1),
+    (-1, 1),
+    (1, -1),
+    (-1, -1),
+];
+
+/// Fractional resolution of sample coordinates (matches [`Fixed32::FRAC_BITS`])
+const FRAC_SCALE: f64 = (1u32 << Fixed32::FRAC_BITS) as f64;
+
+/// Seeded 2D gradient noise with output in [-1, 1]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatticeNoise {
+    seed: u32,
+}
+
+impl LatticeNoise {
+    pub fn new(seed: u32) -> Self {
+        Self { seed }
+    }
+
+    /// Noise value at a position, in [-1, 1]
+    ///
+    /// Splitting a float into cell and fraction only uses `floor`, a
+    /// subtraction, and a power-of-two scale, all of which are exact in IEEE
+    /// arithmetic, so the fixed-point evaluation sees identical inputs everywhere.
+    pub fn get(&self, x: f64, y: f64) -> f64 {
+        let (cell_x, frac_x) = split(x);
+        let (cell_y, frac_y) = split(y);
+        let one = Fixed32::ONE;
+
+        let n00 = self.corner(cell_x, cell_y, frac_x, frac_y);
+        let n10 = self.corner(cell_x.wrapping_add(1), cell_y, frac_x - one, frac_y);
+        let n01 = self.corner(cell_x, cell_y.wrapping_add(1), frac_x, frac_y - one);
+        let n11 = self.corner(cell_x.wrapping_add(1), cell_y.wrapping_add(1), frac_x - one, frac_y - one);
+
+        let u = fade(frac_x);
+        let v = fade(frac_y);
+        let value = lerp(lerp(n00, n10, u), lerp(n01, n11, u), v);
+
+        value.clamp(-one, one).to_bits() as f64 / FRAC_SCALE
+    }
+
+    /// Gradient contribution of one lattice corner
+    fn corner(&self, cell_x: i32, cell_y: i32, dx: Fixed32, dy: Fixed32) -> Fixed32 {
+        let (gx, gy) = GRADIENTS[(hash(self.seed, cell_x, cell_y) & 7) as usize];
+        let mut dot = Fixed32::ZERO;
+        dot += match gx {
+            1 => dx,
+            -1 => -dx,
+            _ => Fixed32::ZERO,
+        };
+        dot += match gy {
+            1 => dy,
+            -1 => -dy,
+            _ => Fixed32::ZERO,
+        };
+        dot
+    }
+}
+
+/// Split a coordinate into its lattice cell and the fixed-point offset within it
+fn split(coordinate: f64) -> (i32, Fixed32) {
+    let cell = coordinate.floor();
+    // `as` casts saturate, so far-away or non-finite inputs still land on a cell
+    let frac = ((coordinate - cell) * FRAC_SCALE) as i32;
+    (cell as i64 as i32, Fixed32::from_bits(frac.clamp(0, Fixed32::ONE.to_bits() - 1)))
+}
+
+/// Quintic fade curve 6t^5 - 15t^4 + 10t^3
+fn fade(t: Fixed32) -> Fixed32 {
+    let six = Fixed32::from_int(6);
+    let fifteen = Fixed32::from_int(15);
+    let ten = Fixed32::from_int(10);
+    t * t * t * (t * (t * six - fifteen) + ten)
+}
+
+fn lerp(a: Fixed32, b: Fixed32, t: Fixed32) -> Fixed32 {
+    a + (b - a) * t
+}
+
+/// Integer hash of a lattice cell (avalanching, so neighbors are uncorrelated)
+fn hash(seed: u32, x: i32, y: i32) -> u32 {
+    let mut h = seed ^ 0x9E37_79B9;
+    h = mix(h ^ (x as u32).wrapping_mul(0x85EB_CA6B));
+    h = mix(h ^ (y as u32).wrapping_mul(0xC2B2_AE35));
+    h
+}
+
+fn mix(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x7FEB_352D);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x846C_A68B);
+    h ^= h >> 16;
+    h
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lattice_points_are_zero_and_output_is_bounded() {
+        let noise = LatticeNoise::new(42);
+        assert_eq!(noise.get(3.0, -7.0), 0.0);
+
+        let mut distinct = std::collections::HashSet::new();
+        for i in 0..400 {
+            let value = noise.get(i as f64 * 0.37, i as f64 * 0.21 - 40.0);
+            assert!((-1.0..=1.0).contains(&value));
+            distinct.insert(value.to_bits());
+        }
+        assert!(distinct.len() > 300, "noise is nearly constant");
+    }
+
+    #[test]
+    fn seeds_give_repeatable_distinct_fields() {
+        let a = LatticeNoise::new(1);
+        let b = LatticeNoise::new(2);
+        let samples = |noise: &LatticeNoise| -> Vec<u64> {
+            (0..64).map(|i| noise.get(i as f64 * 0.5 + 0.25, 10.75).to_bits()).collect()
+        };
+        assert_eq!(samples(&a), samples(&LatticeNoise::new(1)));
+        assert_ne!(samples(&a), samples(&b));
+    }
+}
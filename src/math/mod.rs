@@ -6,7 +6,7 @@
 //!
 //! - **NO parallel implementations** - All math MUST come from this module
 //! - **NO local copies** - Import from `crate::math`, never reimplement
-//! - **NO direct external crate usage** - Only this module uses `noise` crate, etc.
+//! - **NO direct external crate usage** - Noise comes from this module's own lattice noise
 //!
 //! ## Module Structure
 //!
@@ -15,6 +15,8 @@
 //! - [`interpolation`] - Game-specific interpolation, smoothing, and blending functions
 //! - [`distance`] - Game-specific distance functions (hex distance, falloff, influence)
 //! - [`angles`] - Game-specific angle calculations and utilities
+//! - [`fixed`] - Deterministic fixed-point numbers, roots, and trigonometry for simulation state
+//! - [`units`] - Typed quantities (money, food, manpower, area, distance)
 //! ---
 //!
 //! ## Hexagon Geometry (`hexagon` module)
@@ -78,8 +80,12 @@
 //! generation, cloud patterns, and any other procedural noise needs. This is the ONLY
 //! source for noise generation.
 //!
+//! Samples are evaluated in fixed-point on a hashed gradient lattice, so a world
+//! seed produces the same terrain on every platform. The GPU noise shader mirrors
+//! the same hash and gradients.
+//!
 //! ### ⛔ FORBIDDEN
-//! - Using `noise` crate directly anywhere else
+//! - Pulling in an external noise crate
 //! - Implementing custom noise functions
 //! - Duplicating noise constants or parameters
 //! - Any form of noise generation outside this module
@@ -291,9 +297,12 @@
 // this single gateway, maintaining our "single source of truth" principle.
 mod angles;
 mod distance;
+mod fixed;
 mod hexagon;
 mod interpolation;
+mod lattice;
 mod perlin;
+mod units;
 
 // Only these carefully selected exports are available to external code.
 // This enforces our "single source of truth" principle - all math operations
@@ -322,3 +331,7 @@ pub use distance::{
 
 // Game-specific angle calculation exports
 pub use angles::fast_sin;
+
+// Deterministic fixed-point exports
+pub use fixed::Fixed32;
+pub use units::{FoodUnits, Hectares, Kilometers, Manpower, Money};
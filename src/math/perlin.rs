@@ -38,7 +38,7 @@
 //! let mountain = noise.sample_ridged(x, y, 0.02);
 //! ```
 
-use noise::{NoiseFn, Perlin};
+use super::lattice::LatticeNoise;
 
 // NOISE CONSTANTS - Centralized parameters for all noise generation
 
@@ -72,12 +72,13 @@ const NORMALIZATION_MIN: f64 = -1.0;
 
 /// Main Perlin noise generator - your one-stop shop for all noise needs
 ///
-/// This struct wraps the underlying noise library and provides convenient
-/// sampling methods for different use cases. Thread-safe and efficient.
+/// This struct wraps the deterministic lattice noise and provides convenient
+/// sampling methods for different use cases. Thread-safe and efficient, and
+/// a given seed produces identical worlds on every platform.
 #[derive(Clone)]
 pub struct PerlinNoise {
-    /// The underlying Perlin noise generator
-    perlin: Perlin,
+    /// The underlying fixed-point gradient noise
+    perlin: LatticeNoise,
     /// Seed used for reproducibility
     seed: u32,
     /// Default octaves for FBM
@@ -100,7 +101,7 @@ impl PerlinNoise {
     /// ```
     pub fn new(seed: u32) -> Self {
         Self {
-            perlin: Perlin::new(seed),
+            perlin: LatticeNoise::new(seed),
             seed,
             default_octaves: DEFAULT_OCTAVES,
             default_frequency: TERRAIN_FREQUENCY,
@@ -129,7 +130,7 @@ impl PerlinNoise {
     ///
     /// Use this when you need the raw noise values without normalization.
     pub fn sample_raw(&self, x: f64, y: f64) -> f64 {
-        self.perlin.get([x, y])
+        self.perlin.get(x, y)
     }
 
     /// Sample normalized Perlin noise at a position (returns 0.0 to 1.0)
@@ -141,7 +142,7 @@ impl PerlinNoise {
 
     /// Sample with custom frequency (returns 0.0 to 1.0)
     pub fn sample_scaled(&self, x: f64, y: f64, frequency: f64) -> f64 {
-        Self::normalize_to_01(self.perlin.get([x * frequency, y * frequency]))
+        Self::normalize_to_01(self.perlin.get(x * frequency, y * frequency))
     }
 
     // ========================================================================
@@ -187,7 +188,7 @@ impl PerlinNoise {
         let mut max_amplitude = 0.0;
 
         for _ in 0..settings.octaves {
-            value += self.perlin.get([x * frequency, y * frequency]) * amplitude;
+            value += self.perlin.get(x * frequency, y * frequency) * amplitude;
             max_amplitude += amplitude;
             amplitude *= settings.persistence;
             frequency *= settings.lacunarity;
@@ -327,7 +328,7 @@ impl PerlinNoise {
     /// Ridge noise creates sharp mountain ridges by taking the absolute value
     /// of noise and inverting it. Perfect for realistic mountain ranges.
     pub fn sample_ridged(&self, x: f64, y: f64, frequency: f64) -> f64 {
-        let noise = self.perlin.get([x * frequency, y * frequency]);
+        let noise = self.perlin.get(x * frequency, y * frequency);
         // Ridge function: 1 - |noise|
         let ridge = 1.0 - noise.abs();
         // Square it for sharper peaks
@@ -391,7 +392,7 @@ impl PerlinNoise {
 
         for _ in 0..4 {
             // Use absolute value for billow effect
-            value += self.perlin.get([x * freq, y * freq]).abs() * amplitude;
+            value += self.perlin.get(x * freq, y * freq).abs() * amplitude;
             max_amplitude += amplitude;
             amplitude *= 0.5;
             freq *= 2.0;
@@ -409,8 +410,8 @@ impl PerlinNoise {
     /// Domain warping distorts the input coordinates before sampling,
     /// creating more organic-looking features.
     pub fn sample_warped(&self, x: f64, y: f64, warp_strength: f64) -> f64 {
-        let warp_x = self.perlin.get([x * 0.01, y * 0.01]) * warp_strength;
-        let warp_y = self.perlin.get([x * 0.01 + 100.0, y * 0.01 + 100.0]) * warp_strength;
+        let warp_x = self.perlin.get(x * 0.01, y * 0.01) * warp_strength;
+        let warp_y = self.perlin.get(x * 0.01 + 100.0, y * 0.01 + 100.0) * warp_strength;
 
         // Sample with warped coordinates
         self.sample_terrain(x + warp_x, y + warp_y)
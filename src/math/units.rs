@@ -0,0 +1,386 @@
+//! Typed simulation quantities
+//!
+//! Bare numbers say nothing about what they measure, so nothing stops a
+//! treasury from being topped up with grain. Each quantity here is its own
+//! type: values of the same unit add and subtract, any unit scales by a
+//! dimensionless [`Fixed32`], and crossing units goes through a named
+//! conversion. Arithmetic saturates so runaway economies clamp instead of
+//! wrapping.
+
+use super::fixed::Fixed32;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// How a ledger quantity appears in a save
+///
+/// Current saves hold the raw bits; saves from before the quantity was typed
+/// hold a plain amount.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum LedgerRepr {
+    Bits(i64),
+    Amount(f64),
+}
+
+/// Defines a 48.16 fixed-point quantity for amounts that outgrow [`Fixed32`]
+macro_rules! ledger_quantity {
+    ($(#[$meta:meta])* $name:ident, $suffix:literal) => {
+        $(#[$meta])*
+        #[derive(
+            Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect,
+            Serialize, Deserialize,
+        )]
+        #[serde(into = "i64", from = "LedgerRepr")]
+        pub struct $name(i64);
+
+        impl $name {
+            pub const ZERO: Self = Self(0);
+            pub const MAX: Self = Self(i64::MAX);
+
+            pub const fn from_int(value: i64) -> Self {
+                Self(value.saturating_mul(1 << Fixed32::FRAC_BITS))
+            }
+
+            /// Nearest amount to a float (saturating, NaN becomes zero)
+            pub fn from_f32(value: f32) -> Self {
+                Self::from_f64(value as f64)
+            }
+
+            fn from_f64(value: f64) -> Self {
+                // `as` casts from float saturate and map NaN to zero
+                Self((value * (1i64 << Fixed32::FRAC_BITS) as f64).round() as i64)
+            }
+
+            pub fn to_f32(self) -> f32 {
+                (self.0 as f64 / (1i64 << Fixed32::FRAC_BITS) as f64) as f32
+            }
+
+            pub fn abs(self) -> Self {
+                Self(self.0.saturating_abs())
+            }
+
+            /// Dimensionless ratio of two amounts (zero when `other` is zero)
+            pub fn ratio(self, other: Self) -> Fixed32 {
+                if other.0 == 0 {
+                    return Fixed32::ZERO;
+                }
+                let bits = ((self.0 as i128) << Fixed32::FRAC_BITS) / other.0 as i128;
+                Fixed32::from_bits(bits.clamp(i32::MIN as i128, i32::MAX as i128) as i32)
+            }
+        }
+
+        impl From<$name> for i64 {
+            fn from(value: $name) -> i64 {
+                value.0
+            }
+        }
+
+        impl From<LedgerRepr> for $name {
+            fn from(repr: LedgerRepr) -> Self {
+                match repr {
+                    LedgerRepr::Bits(bits) => Self(bits),
+                    LedgerRepr::Amount(amount) => Self::from_f64(amount),
+                }
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0.saturating_add(rhs.0))
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0.saturating_sub(rhs.0))
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self(self.0.saturating_neg())
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl Mul<Fixed32> for $name {
+            type Output = Self;
+            fn mul(self, rhs: Fixed32) -> Self {
+                let scaled = (self.0 as i128 * rhs.to_bits() as i128) >> Fixed32::FRAC_BITS;
+                Self(scaled.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+            }
+        }
+
+        impl MulAssign<Fixed32> for $name {
+            fn mul_assign(&mut self, rhs: Fixed32) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl std::iter::Sum for $name {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, Add::add)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{:.0}{}", self.to_f32(), $suffix)
+            }
+        }
+    };
+}
+
+/// Defines a [`Fixed32`]-backed quantity with same-unit arithmetic
+macro_rules! fixed_quantity {
+    ($(#[$meta:meta])* $name:ident, $suffix:literal) => {
+        $(#[$meta])*
+        #[derive(
+            Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect,
+            Serialize, Deserialize,
+        )]
+        #[serde(transparent)]
+        pub struct $name(Fixed32);
+
+        impl $name {
+            pub const ZERO: Self = Self(Fixed32::ZERO);
+            pub const MAX: Self = Self(Fixed32::MAX);
+
+            pub const fn new(value: Fixed32) -> Self {
+                Self(value)
+            }
+
+            pub const fn from_int(value: i32) -> Self {
+                Self(Fixed32::from_int(value))
+            }
+
+            pub fn from_f32(value: f32) -> Self {
+                Self(Fixed32::from_f32(value))
+            }
+
+            /// Underlying dimensionless value
+            pub const fn value(self) -> Fixed32 {
+                self.0
+            }
+
+            pub fn to_f32(self) -> f32 {
+                self.0.to_f32()
+            }
+
+            /// Dimensionless ratio of two amounts (zero when `other` is zero)
+            pub fn ratio(self, other: Self) -> Fixed32 {
+                self.0.checked_div(other.0).unwrap_or(Fixed32::ZERO)
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0.saturating_add(rhs.0))
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0.saturating_sub(rhs.0))
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl Mul<Fixed32> for $name {
+            type Output = Self;
+            fn mul(self, rhs: Fixed32) -> Self {
+                Self(self.0.saturating_mul(rhs))
+            }
+        }
+
+        impl Div<Fixed32> for $name {
+            type Output = Self;
+            fn div(self, rhs: Fixed32) -> Self {
+                Self(self.0.saturating_div(rhs))
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}{}", self.0, $suffix)
+            }
+        }
+    };
+}
+
+ledger_quantity!(
+    /// Currency in a nation's treasury or ledger
+    ///
+    /// Treasuries run into the millions, far past the ±32768 of [`Fixed32`],
+    /// so money is a 48.16 fixed-point number with the same fractional
+    /// precision.
+    Money,
+    " gold"
+);
+
+ledger_quantity!(
+    /// Food grown, stored, or eaten
+    ///
+    /// A large realm's harvest outgrows [`Fixed32`] just as its treasury does.
+    FoodUnits,
+    " food"
+);
+
+fixed_quantity!(
+    /// Land area
+    Hectares,
+    " ha"
+);
+
+fixed_quantity!(
+    /// Distance along the map surface
+    Kilometers,
+    " km"
+);
+
+impl Kilometers {
+    /// Area of a square with this side length (1 km² = 100 ha)
+    pub fn squared(self) -> Hectares {
+        Hectares(self.0.saturating_mul(self.0).saturating_mul(Fixed32::from_int(100)))
+    }
+}
+
+impl Hectares {
+    /// Area in square kilometres
+    pub fn to_square_km(self) -> Fixed32 {
+        self.0 / Fixed32::from_int(100)
+    }
+}
+
+/// Number of people able to serve (soldiers, garrison slots, levies)
+///
+/// Headcounts outgrow the ±32768 range of [`Fixed32`], so manpower is a whole
+/// number of people instead.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Manpower(u32);
+
+impl Manpower {
+    pub const ZERO: Self = Self(0);
+
+    pub const fn new(people: u32) -> Self {
+        Self(people)
+    }
+
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+
+    pub fn to_f32(self) -> f32 {
+        self.0 as f32
+    }
+
+    /// Scale by a dimensionless factor (losses, recruitment rates), rounding down
+    pub fn scale(self, factor: Fixed32) -> Self {
+        let scaled = (self.0 as i64 * factor.to_bits() as i64) >> Fixed32::FRAC_BITS;
+        Self(scaled.clamp(0, u32::MAX as i64) as u32)
+    }
+}
+
+impl Add for Manpower {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl Sub for Manpower {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl AddAssign for Manpower {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Manpower {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl fmt::Display for Manpower {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} men", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quantities_saturate_and_convert() {
+        let mut treasury = Money::from_int(100);
+        treasury += Money::from_int(50);
+        assert_eq!(treasury * Fixed32::HALF, Money::from_int(75));
+        assert_eq!(Money::MAX + Money::from_int(1), Money::MAX);
+        assert_eq!(Money::from_int(30).ratio(Money::from_int(60)), Fixed32::HALF);
+        assert_eq!(Money::from_int(1).ratio(Money::ZERO), Fixed32::ZERO);
+        assert_eq!((-Money::from_int(40)).abs(), Money::from_int(40));
+
+        // Treasuries far beyond the range of Fixed32
+        let hoard = Money::from_int(5_000_000);
+        assert_eq!(hoard.to_f32(), 5_000_000.0);
+        assert_eq!(hoard * Fixed32::from_ratio(1, 4), Money::from_int(1_250_000));
+        assert_eq!(Money::from_f32(2.5e6), Money::from_int(2_500_000));
+
+        let harvest: FoodUnits = [FoodUnits::from_int(40_000), FoodUnits::from_int(2_000)].into_iter().sum();
+        assert_eq!(harvest, FoodUnits::from_int(42_000));
+
+        assert_eq!(Kilometers::from_int(2).squared(), Hectares::from_int(400));
+        assert_eq!(Hectares::from_int(400).to_square_km(), Fixed32::from_int(4));
+
+        let army = Manpower::new(10_000);
+        assert_eq!(army.scale(Fixed32::from_ratio(1, 4)), Manpower::new(2_500));
+        assert_eq!(Manpower::new(5) - army, Manpower::ZERO);
+    }
+
+    #[test]
+    fn ledger_quantities_read_plain_amounts_from_older_saves() {
+        let typed = Money::from_int(1_000);
+        let text = ron::to_string(&typed).unwrap_or_default();
+        assert_eq!(ron::from_str::<Money>(&text).ok(), Some(typed));
+        assert_eq!(ron::from_str::<Money>("1000.0").ok(), Some(typed));
+    }
+}
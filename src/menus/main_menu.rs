@@ -109,6 +109,7 @@ fn spawn_main_menu(mut commands: Commands, mut save_list: ResMut<SaveGameList>)
                     create_button("Load Game", MenuAction::LoadGame, has_saves);
                     create_button("Settings", MenuAction::Settings, true);
                     create_button("Mods", MenuAction::Mods, true);
+                    create_button("Worlds", MenuAction::Worlds, true);
                     create_button("Exit", MenuAction::Exit, true);
                 });
 
@@ -137,6 +138,7 @@ fn handle_button_interactions(
     mut settings_events: MessageWriter<SpawnSettingsMenuEvent>,
     mut save_browser_events: MessageWriter<SpawnSaveBrowserEvent>,
     mut mod_browser_events: MessageWriter<crate::modding::OpenModBrowserEvent>,
+    mut world_switcher_events: MessageWriter<crate::worlds::ToggleWorldSwitcher>,
     current_state: Res<State<GameState>>,
     mut commands: Commands,
 ) {
@@ -167,6 +169,10 @@ fn handle_button_interactions(
                     debug!("Opening Mods Browser");
                     mod_browser_events.write(crate::modding::OpenModBrowserEvent);
                 }
+                MenuAction::Worlds => {
+                    debug!("Opening world switcher");
+                    world_switcher_events.write(crate::worlds::ToggleWorldSwitcher);
+                }
                 MenuAction::Exit => {
                     debug!("Exit button pressed - showing confirmation dialog");
                     use crate::ui::dialog_presets;
@@ -107,6 +107,7 @@ fn spawn_pause_menu(mut commands: Commands, mut save_list: ResMut<SaveGameList>)
                     create_button("Settings", MenuAction::Settings, true);
                     create_button("Save Game", MenuAction::SaveGame, true);
                     create_button("Load Game", MenuAction::LoadGame, has_saves);
+                    create_button("Worlds", MenuAction::Worlds, true);
                     create_button("Main Menu", MenuAction::BackToMainMenu, true);
                     create_button("Exit Game", MenuAction::Exit, true);
                 });
@@ -198,6 +199,7 @@ fn handle_pause_button_interactions(
     mut save_events: MessageWriter<SaveGameEvent>,
     mut settings_events: MessageWriter<SpawnSettingsMenuEvent>,
     mut save_browser_events: MessageWriter<SpawnSaveBrowserEvent>,
+    mut world_switcher_events: MessageWriter<crate::worlds::ToggleWorldSwitcher>,
     mut commands: Commands,
     pause_menu_query: Query<Entity, With<PauseMenuRoot>>,
 ) {
@@ -252,6 +254,10 @@ fn handle_pause_button_interactions(
                     }
                     save_browser_events.write(SpawnSaveBrowserEvent);
                 }
+                MenuAction::Worlds => {
+                    info!("Worlds button pressed from pause menu - opening world switcher");
+                    world_switcher_events.write(crate::worlds::ToggleWorldSwitcher);
+                }
                 _ => {}
             }
         }
@@ -25,6 +25,7 @@ pub enum MenuAction {
     LoadGame,
     Settings,
     Mods,
+    Worlds,
     Exit,
 
     // Pause menu actions
@@ -0,0 +1,42 @@
+//! Celebrating milestones as they are reached
+
+use bevy::prelude::*;
+use std::time::Duration;
+
+use super::types::{MilestoneAchieved, WorldMilestones};
+use crate::audio::{AudioCue, AudioEvent};
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::ui::{NotificationPosition, NotificationType, ShowNotification};
+
+/// How long a milestone celebration stays on screen
+const CELEBRATION_SECS: u64 = 8;
+
+/// Announce each milestone and write it into the chronicle
+pub fn celebrate_milestones(
+    mut achieved_events: MessageReader<MilestoneAchieved>,
+    mut notifications: MessageWriter<ShowNotification>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    mut audio: MessageWriter<AudioEvent>,
+) {
+    for MilestoneAchieved { record } in achieved_events.read() {
+        notifications.write(ShowNotification {
+            message: format!("Milestone: {}\n{}", record.milestone.title(), record.description),
+            notification_type: NotificationType::Success,
+            duration: Some(Duration::from_secs(CELEBRATION_SECS)),
+            position: NotificationPosition::TopCenter,
+        });
+        chronicle.write(ChronicleEvent {
+            category: ChronicleCategory::Milestone,
+            text: format!("{}: {}", record.milestone.title(), record.description),
+            nations: record.nations.clone(),
+        });
+        audio.write(AudioEvent::new(AudioCue::MilestoneReached));
+    }
+}
+
+/// A freshly generated world has reached nothing yet
+///
+/// Loading a save re-inserts the saved milestones after this runs.
+pub fn reset_milestones(mut milestones: ResMut<WorldMilestones>) {
+    *milestones = WorldMilestones::default();
+}
@@ -0,0 +1,97 @@
+//! Yearly checks for milestones the world has not reached yet
+
+use bevy::prelude::*;
+
+use super::types::{
+    is_world_war, Milestone, MilestoneAchieved, MilestoneRecord, WorldMilestones,
+    ANCIENT_DYNASTY_YEARS, GREAT_EMPIRE_PROVINCES,
+};
+use crate::nations::{House, Nation, NationId, ParticipatesInWar};
+use crate::relationships::{Controls, RulesOver};
+use crate::simulation::NewYearEvent;
+
+/// Look for newly reached milestones once per simulated year
+pub fn detect_milestones(
+    mut year_events: MessageReader<NewYearEvent>,
+    mut milestones: ResMut<WorldMilestones>,
+    mut achieved_events: MessageWriter<MilestoneAchieved>,
+    nations_query: Query<(&Nation, &NationId, Option<&Controls>, Option<&ParticipatesInWar>)>,
+    houses_query: Query<(&House, &RulesOver)>,
+) {
+    let Some(year) = year_events.read().last().map(|event| event.year) else {
+        return;
+    };
+    if Milestone::ALL.iter().all(|&milestone| milestones.is_achieved(milestone)) {
+        return;
+    }
+
+    let mut found = Vec::new();
+
+    let at_war: Vec<(&Nation, NationId)> = nations_query
+        .iter()
+        .filter(|(_, _, _, war)| war.is_some())
+        .map(|(nation, id, _, _)| (nation, *id))
+        .collect();
+
+    if let Some((first, _)) = at_war.first() {
+        found.push((
+            Milestone::FirstWar,
+            format!("The {} marched to war, and peace was never certain again", first.name),
+            at_war.iter().map(|(_, id)| *id).collect(),
+        ));
+    }
+
+    let living_nations = nations_query
+        .iter()
+        .filter(|(_, _, controls, _)| controls.is_some_and(|c| c.province_count() > 0))
+        .count();
+    if is_world_war(at_war.len(), living_nations) {
+        found.push((
+            Milestone::WorldWar,
+            format!("{} of {} nations were at war at once", at_war.len(), living_nations),
+            at_war.iter().map(|(_, id)| *id).collect(),
+        ));
+    }
+
+    let largest = nations_query
+        .iter()
+        .filter_map(|(nation, id, controls, _)| controls.map(|c| (nation, *id, c.province_count())))
+        .max_by_key(|(_, _, provinces)| *provinces);
+    if let Some((nation, id, provinces)) = largest.filter(|(_, _, provinces)| *provinces >= GREAT_EMPIRE_PROVINCES) {
+        found.push((
+            Milestone::GreatEmpire,
+            format!("The {} became the first realm to rule {} provinces", nation.name, provinces),
+            vec![id],
+        ));
+    }
+
+    let oldest = houses_query
+        .iter()
+        .max_by_key(|(house, _)| house.years_in_power)
+        .filter(|(house, _)| house.years_in_power >= ANCIENT_DYNASTY_YEARS);
+    if let Some((house, rules_over)) = oldest {
+        if let Ok((nation, id, _, _)) = nations_query.get(rules_over.0) {
+            found.push((
+                Milestone::AncientDynasty,
+                format!(
+                    "{} has ruled the {} for {} years",
+                    house.full_name, nation.name, house.years_in_power
+                ),
+                vec![*id],
+            ));
+        }
+    }
+
+    for (milestone, description, nations) in found {
+        let record = MilestoneRecord {
+            milestone,
+            year,
+            description,
+            nations,
+        };
+        if milestones.record(record.clone()) {
+            info!("Milestone reached in year {}: {}", year, milestone.title());
+            achieved_events.write(MilestoneAchieved { record });
+        }
+    }
+}
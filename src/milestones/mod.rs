@@ -0,0 +1,22 @@
+//! Emergent world milestones gateway
+//!
+//! Milestones are the observer's goals: moments no script plans but every
+//! world eventually earns - the first empire of a hundred provinces, the
+//! first world war, a dynasty that outlasts five centuries. Each is detected
+//! once per world, celebrated with a notification, and written into the
+//! chronicle. Steam builds map a subset of them to achievements.
+//!
+//! # Gateway Pattern
+//!
+//! This is a PURE gateway - no implementations, only module declarations
+//! and controlled exports.
+
+// PRIVATE MODULES
+mod celebration;
+mod detection;
+mod plugin;
+mod types;
+
+// CONTROLLED EXPORTS
+pub use plugin::MilestonePlugin;
+pub use types::{Milestone, MilestoneAchieved, MilestoneRecord, WorldMilestones};
@@ -0,0 +1,27 @@
+//! Milestone plugin
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+use super::celebration::{celebrate_milestones, reset_milestones};
+use super::detection::detect_milestones;
+use super::types::{Milestone, MilestoneAchieved, WorldMilestones};
+use crate::states::GameState;
+
+define_plugin!(MilestonePlugin {
+    resources: [WorldMilestones],
+
+    messages: [MilestoneAchieved],
+
+    reflect: [WorldMilestones, Milestone],
+
+    update: [
+        (detect_milestones, celebrate_milestones)
+            .chain()
+            .run_if(in_state(GameState::InGame))
+    ],
+
+    on_enter: {
+        GameState::LoadingWorld => [reset_milestones]
+    }
+});
@@ -0,0 +1,126 @@
+//! Milestone kinds and the per-world record of those reached
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::nations::NationId;
+
+/// Provinces an empire must rule to count as a great empire
+pub const GREAT_EMPIRE_PROVINCES: usize = 100;
+
+/// Fewest nations at war at once for a world war
+pub const WORLD_WAR_MIN_NATIONS: usize = 6;
+
+/// Share of living nations that must be at war at once for a world war
+pub const WORLD_WAR_SHARE: f32 = 0.5;
+
+/// Years one house must hold a throne to count as an ancient dynasty
+pub const ANCIENT_DYNASTY_YEARS: u32 = 500;
+
+/// Emergent moments worth celebrating, each reached at most once per world
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+pub enum Milestone {
+    /// Any two nations go to war
+    FirstWar,
+    /// A nation rules at least [`GREAT_EMPIRE_PROVINCES`] provinces
+    GreatEmpire,
+    /// Much of the world is at war at the same time
+    WorldWar,
+    /// A house rules for at least [`ANCIENT_DYNASTY_YEARS`] years
+    AncientDynasty,
+}
+
+impl Milestone {
+    pub const ALL: [Milestone; 4] = [
+        Milestone::FirstWar,
+        Milestone::GreatEmpire,
+        Milestone::WorldWar,
+        Milestone::AncientDynasty,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Milestone::FirstWar => "The First War",
+            Milestone::GreatEmpire => "A Great Empire",
+            Milestone::WorldWar => "The First World War",
+            Milestone::AncientDynasty => "An Ancient Dynasty",
+        }
+    }
+}
+
+/// A milestone as it happened in this world
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Reflect)]
+pub struct MilestoneRecord {
+    pub milestone: Milestone,
+    pub year: u32,
+    pub description: String,
+    pub nations: Vec<NationId>,
+}
+
+/// Milestones this world has reached, in the order it reached them
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize, Reflect)]
+#[reflect(Resource)]
+pub struct WorldMilestones {
+    achieved: Vec<MilestoneRecord>,
+}
+
+impl WorldMilestones {
+    pub fn is_achieved(&self, milestone: Milestone) -> bool {
+        self.achieved.iter().any(|record| record.milestone == milestone)
+    }
+
+    pub fn achieved(&self) -> &[MilestoneRecord] {
+        &self.achieved
+    }
+
+    /// Record a milestone, returning false if the world already reached it
+    pub fn record(&mut self, record: MilestoneRecord) -> bool {
+        if self.is_achieved(record.milestone) {
+            return false;
+        }
+        self.achieved.push(record);
+        true
+    }
+}
+
+/// A milestone was reached for the first time in this world
+#[derive(Message, Debug, Clone)]
+pub struct MilestoneAchieved {
+    pub record: MilestoneRecord,
+}
+
+/// Whether enough of the world is fighting to call it a world war
+pub fn is_world_war(nations_at_war: usize, living_nations: usize) -> bool {
+    nations_at_war >= WORLD_WAR_MIN_NATIONS
+        && nations_at_war as f32 >= living_nations as f32 * WORLD_WAR_SHARE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn world_war_needs_many_nations_and_a_large_share() {
+        assert!(!is_world_war(4, 6));
+        assert!(!is_world_war(8, 40));
+        assert!(is_world_war(6, 12));
+        assert!(is_world_war(20, 30));
+    }
+
+    #[test]
+    fn milestones_record_once_per_world() {
+        let mut milestones = WorldMilestones::default();
+        let record = MilestoneRecord {
+            milestone: Milestone::FirstWar,
+            year: 12,
+            description: String::new(),
+            nations: Vec::new(),
+        };
+
+        assert!(milestones.record(record.clone()));
+        assert!(!milestones.record(MilestoneRecord { year: 40, ..record }));
+        assert_eq!(milestones.achieved().len(), 1);
+        assert!(milestones.is_achieved(Milestone::FirstWar));
+        assert!(!milestones.is_achieved(Milestone::WorldWar));
+    }
+}
@@ -0,0 +1,129 @@
+//! Localized strings for mod-defined text
+//!
+//! String tables live in `localization/<language>.ron` files - the base
+//! game's under `config/base/localization/`, each mod's under its own
+//! `config/localization/` - as maps from key to text. Later mods override
+//! earlier ones key by key. Text is looked up in the interface language,
+//! then in English, and a key found in neither is shown as written, so mods
+//! may use plain text where they have no translations.
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use super::manager::ModManager;
+use crate::settings::GameSettings;
+
+/// Language used when a string is missing from the interface language
+pub const FALLBACK_LANGUAGE: &str = "en";
+
+/// String tables by language, each a map from key to text
+pub type StringTables = HashMap<String, HashMap<String, String>>;
+
+/// Strings in the interface language, with the fallback language behind them
+#[derive(Resource, Debug, Clone, Default)]
+pub struct Localization {
+    pub language: String,
+    strings: HashMap<String, String>,
+    fallback: HashMap<String, String>,
+}
+
+impl Localization {
+    pub fn new(language: &str, tables: &StringTables) -> Self {
+        Self {
+            language: language.to_string(),
+            strings: tables.get(language).cloned().unwrap_or_default(),
+            fallback: tables.get(FALLBACK_LANGUAGE).cloned().unwrap_or_default(),
+        }
+    }
+
+    /// Text for a key, or the key itself when no table has it
+    pub fn text<'a>(&'a self, key: &'a str) -> &'a str {
+        self.strings
+            .get(key)
+            .or_else(|| self.fallback.get(key))
+            .map_or(key, String::as_str)
+    }
+
+    /// Text for a key with each `{name}` placeholder replaced by its value
+    pub fn format(&self, key: &str, args: &[(&str, &str)]) -> String {
+        args.iter()
+            .fold(self.text(key).to_string(), |text, (name, value)| {
+                text.replace(&format!("{{{}}}", name), value)
+            })
+    }
+}
+
+/// Read every `<language>.ron` string table in a directory
+///
+/// Tables that fail to parse are reported and skipped.
+pub fn load_string_tables(directory: &Path) -> StringTables {
+    let mut tables = StringTables::new();
+    let Ok(entries) = fs::read_dir(directory) else {
+        return tables;
+    };
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if path.extension().is_none_or(|ext| ext != "ron") {
+            continue;
+        }
+        let Some(language) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        match fs::read_to_string(&path).map_err(|e| e.to_string()).and_then(|contents| {
+            ron::from_str::<HashMap<String, String>>(&contents).map_err(|e| e.to_string())
+        }) {
+            Ok(strings) => {
+                tables.entry(language.to_string()).or_default().extend(strings);
+            }
+            Err(e) => warn!("Failed to load string table {:?}: {}", path, e),
+        }
+    }
+    tables
+}
+
+/// Rebuild the strings when the mods or the interface language change
+pub fn rebuild_localization(
+    mut localization: ResMut<Localization>,
+    mods: Option<Res<ModManager>>,
+    settings: Option<Res<GameSettings>>,
+) {
+    let mods_changed = mods.as_ref().is_some_and(|mods| mods.is_changed());
+    let settings_changed = settings.as_ref().is_some_and(|settings| settings.is_changed());
+    if !localization.is_added() && !mods_changed && !settings_changed {
+        return;
+    }
+
+    let language = settings.map_or_else(
+        || FALLBACK_LANGUAGE.to_string(),
+        |settings| settings.interface.language.clone(),
+    );
+    let tables = mods.map(|mods| mods.get_config().localization.clone()).unwrap_or_default();
+    *localization = Localization::new(&language, &tables);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strings_fall_back_to_english_then_the_key() {
+        let mut tables = StringTables::new();
+        tables.insert(
+            "en".to_string(),
+            HashMap::from([
+                ("riots.title".to_string(), "Riots in {nation}".to_string()),
+                ("riots.text".to_string(), "Bread is scarce.".to_string()),
+            ]),
+        );
+        tables.insert(
+            "de".to_string(),
+            HashMap::from([("riots.title".to_string(), "Unruhen in {nation}".to_string())]),
+        );
+
+        let german = Localization::new("de", &tables);
+        assert_eq!(german.format("riots.title", &[("nation", "Velm")]), "Unruhen in Velm");
+        assert_eq!(german.text("riots.text"), "Bread is scarce.");
+        assert_eq!(german.text("A plain sentence"), "A plain sentence");
+    }
+}
@@ -2,8 +2,15 @@
 //!
 //! This module handles mod discovery, loading, validation, and merging.
 
+use super::localization::load_string_tables;
+use super::permissions::{mod_checksum, ApprovalStatus, ModApproval, ModApprovals, ModCapability, ModSandbox};
 use super::types::*;
+use crate::ai::BehaviorPack;
+use crate::audio::SoundDefinition;
+use crate::nations::EventDefinition;
+use crate::world::{AttritionProfile, TerrainType};
 use bevy::prelude::*;
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
@@ -28,6 +35,13 @@ pub struct ModManager {
 
     /// Paths to mod directories
     pub mod_paths: ModPaths,
+
+    /// Capabilities the user has approved, by mod ID
+    pub approvals: ModApprovals,
+
+    /// Sandbox of each scripted event an active mod defines, by event ID;
+    /// base game events have none and run unrestricted
+    pub event_sandboxes: HashMap<String, ModSandbox>,
 }
 
 /// Standard mod directory locations
@@ -36,6 +50,7 @@ pub struct ModPaths {
     pub base_config: PathBuf,
     pub local_mods: PathBuf,
     pub workshop_mods: PathBuf,
+    pub approvals: PathBuf,
 }
 
 impl Default for ModPaths {
@@ -44,6 +59,7 @@ impl Default for ModPaths {
             base_config: PathBuf::from("config/base"),
             local_mods: PathBuf::from("mods"),
             workshop_mods: PathBuf::from("mods/workshop"),
+            approvals: PathBuf::from("mods/approvals.ron"),
         }
     }
 }
@@ -59,6 +75,8 @@ impl ModManager {
             active_mods: Vec::new(),
             merged_config: base_config,
             mod_paths: ModPaths::default(),
+            approvals: ModApprovals::default(),
+            event_sandboxes: HashMap::new(),
         }
     }
 
@@ -70,6 +88,8 @@ impl ModManager {
             error!("Failed to load base configuration: {}", e);
         }
 
+        self.approvals = ModApprovals::load(&self.mod_paths.approvals);
+
         // Discover available mods
         self.discover_mods();
 
@@ -107,6 +127,56 @@ impl ModManager {
             info!("Loaded colors configuration");
         }
 
+        let sounds_path = base_path.join("sounds.ron");
+        if sounds_path.exists() {
+            let contents = fs::read_to_string(&sounds_path)?;
+            match ron::from_str::<HashMap<String, SoundDefinition>>(&contents) {
+                Ok(sounds) => {
+                    self.base_config.sounds = sounds;
+                    info!("Loaded sound mappings");
+                }
+                Err(e) => warn!("Failed to parse {:?}: {}", sounds_path, e),
+            }
+        }
+
+        let attrition_path = base_path.join("attrition.ron");
+        if attrition_path.exists() {
+            let contents = fs::read_to_string(&attrition_path)?;
+            match ron::from_str::<HashMap<TerrainType, AttritionProfile>>(&contents) {
+                Ok(attrition) => {
+                    self.base_config.attrition = attrition;
+                    info!("Loaded terrain attrition profiles");
+                }
+                Err(e) => warn!("Failed to parse {:?}: {}", attrition_path, e),
+            }
+        }
+
+        let behavior_packs_path = base_path.join("behavior_packs.ron");
+        if behavior_packs_path.exists() {
+            let contents = fs::read_to_string(&behavior_packs_path)?;
+            match ron::from_str::<HashMap<String, BehaviorPack>>(&contents) {
+                Ok(packs) => {
+                    self.base_config.behavior_packs = packs;
+                    info!("Loaded AI behavior packs");
+                }
+                Err(e) => warn!("Failed to parse {:?}: {}", behavior_packs_path, e),
+            }
+        }
+
+        let events_path = base_path.join("events.ron");
+        if events_path.exists() {
+            let contents = fs::read_to_string(&events_path)?;
+            match ron::from_str::<HashMap<String, EventDefinition>>(&contents) {
+                Ok(events) => {
+                    info!("Loaded {} scripted events", events.len());
+                    self.base_config.events = events;
+                }
+                Err(e) => warn!("Failed to parse {:?}: {}", events_path, e),
+            }
+        }
+
+        self.base_config.localization = load_string_tables(&base_path.join("localization"));
+
         info!("Base configuration loaded");
         Ok(())
     }
@@ -136,11 +206,15 @@ impl ModManager {
                                 dependencies: Vec::new(),
                                 compatible_game_version: "*".to_string(),
                                 load_order: 100,
+                                capabilities: Vec::new(),
+                                settings: Vec::new(),
+                                heatmaps: Vec::new(),
                             },
                             path: entry.path(),
                             config_overrides: ModConfigOverrides::default(),
                             source: ModSource::Local(entry.path()),
                             enabled: false,
+                            checksum: 0,
                         });
                     }
                 }
@@ -164,11 +238,15 @@ impl ModManager {
                                     dependencies: Vec::new(),
                                     compatible_game_version: "*".to_string(),
                                     load_order: 200,
+                                    capabilities: Vec::new(),
+                                    settings: Vec::new(),
+                                    heatmaps: Vec::new(),
                                 },
                                 path: entry.path(),
                                 config_overrides: ModConfigOverrides::default(),
                                 source: ModSource::Workshop(id),
                                 enabled: false,
+                                checksum: 0,
                             });
                         }
                     }
@@ -203,6 +281,7 @@ impl ModManager {
             }
 
             Self::load_mod_config_overrides(loaded_mod);
+            loaded_mod.checksum = mod_checksum(&loaded_mod.path);
         }
     }
 
@@ -222,6 +301,48 @@ impl ModManager {
             }
         }
 
+        let sounds_path = config_dir.join("sounds.ron");
+        if sounds_path.exists() {
+            if let Ok(contents) = fs::read_to_string(&sounds_path) {
+                match ron::from_str::<HashMap<String, SoundDefinition>>(&contents) {
+                    Ok(sounds) => loaded_mod.config_overrides.sounds = Some(sounds),
+                    Err(e) => warn!("Failed to parse {:?}: {}", sounds_path, e),
+                }
+            }
+        }
+
+        let attrition_path = config_dir.join("attrition.ron");
+        if attrition_path.exists() {
+            if let Ok(contents) = fs::read_to_string(&attrition_path) {
+                match ron::from_str::<HashMap<TerrainType, AttritionProfile>>(&contents) {
+                    Ok(attrition) => loaded_mod.config_overrides.attrition = Some(attrition),
+                    Err(e) => warn!("Failed to parse {:?}: {}", attrition_path, e),
+                }
+            }
+        }
+
+        let behavior_packs_path = config_dir.join("behavior_packs.ron");
+        if behavior_packs_path.exists() {
+            if let Ok(contents) = fs::read_to_string(&behavior_packs_path) {
+                match ron::from_str::<HashMap<String, BehaviorPack>>(&contents) {
+                    Ok(packs) => loaded_mod.config_overrides.behavior_packs = Some(packs),
+                    Err(e) => warn!("Failed to parse {:?}: {}", behavior_packs_path, e),
+                }
+            }
+        }
+
+        let events_path = config_dir.join("events.ron");
+        if events_path.exists() {
+            if let Ok(contents) = fs::read_to_string(&events_path) {
+                match ron::from_str::<HashMap<String, EventDefinition>>(&contents) {
+                    Ok(events) => loaded_mod.config_overrides.events = Some(events),
+                    Err(e) => warn!("Failed to parse {:?}: {}", events_path, e),
+                }
+            }
+        }
+
+        loaded_mod.config_overrides.localization = load_string_tables(&config_dir.join("localization"));
+
         // (colors, generation, simulation, audio)
     }
 
@@ -234,6 +355,7 @@ impl ModManager {
     pub fn apply_active_mods(&mut self) {
         // Start with base config
         self.merged_config = self.base_config.clone();
+        self.event_sandboxes.clear();
 
         // Clone active_mods to avoid borrowing conflicts
         let active_mods = self.active_mods.clone();
@@ -254,6 +376,47 @@ impl ModManager {
                     self.merged_config.balance = balance;
                 }
 
+                // Sound remaps stack, so later mods win per cue
+                if let Some(sounds) = &loaded_mod.config_overrides.sounds {
+                    self.merged_config
+                        .sounds
+                        .extend(sounds.iter().map(|(id, sound)| (id.clone(), sound.clone())));
+                }
+
+                // Attrition profiles stack the same way, per terrain
+                if let Some(attrition) = &loaded_mod.config_overrides.attrition {
+                    self.merged_config.attrition.extend(attrition.iter().map(|(terrain, profile)| (*terrain, *profile)));
+                }
+
+                // Behavior packs stack per pack id
+                if let Some(packs) = &loaded_mod.config_overrides.behavior_packs {
+                    self.merged_config
+                        .behavior_packs
+                        .extend(packs.iter().map(|(id, pack)| (id.clone(), pack.clone())));
+                }
+
+                // Events stack per event id, strings per language and key
+                if let Some(events) = &loaded_mod.config_overrides.events {
+                    self.merged_config
+                        .events
+                        .extend(events.iter().map(|(id, event)| (id.clone(), event.clone())));
+
+                    // Each event runs with what its mod was granted
+                    let sandbox = ModSandbox {
+                        mod_id: loaded_mod.manifest.id.clone(),
+                        granted: self.approvals.granted(&loaded_mod.manifest.id, loaded_mod.checksum).to_vec(),
+                    };
+                    self.event_sandboxes
+                        .extend(events.keys().map(|id| (id.clone(), sandbox.clone())));
+                }
+                for (language, strings) in &loaded_mod.config_overrides.localization {
+                    self.merged_config
+                        .localization
+                        .entry(language.clone())
+                        .or_default()
+                        .extend(strings.iter().map(|(key, text)| (key.clone(), text.clone())));
+                }
+
                 // Apply other overrides...
                 // (colors, generation, simulation, audio)
 
@@ -264,8 +427,54 @@ impl ModManager {
         info!("Applied {} active mods to configuration", active_mods.len());
     }
 
+    /// Whether a mod's declared capabilities are approved for its current files
+    pub fn approval_status(&self, mod_id: &str) -> ApprovalStatus {
+        self.mod_index
+            .get(mod_id)
+            .and_then(|&index| self.available_mods.get(index))
+            .map_or(ApprovalStatus::NotNeeded, |loaded_mod| {
+                self.approvals
+                    .status(mod_id, loaded_mod.checksum, &loaded_mod.manifest.capabilities)
+            })
+    }
+
+    /// Capabilities a mod declares in its manifest
+    pub fn declared_capabilities(&self, mod_id: &str) -> &[ModCapability] {
+        self.mod_index
+            .get(mod_id)
+            .and_then(|&index| self.available_mods.get(index))
+            .map_or(&[], |loaded_mod| loaded_mod.manifest.capabilities.as_slice())
+    }
+
+    /// Grant a mod what its manifest declares, for its current files, and remember it
+    pub fn approve_mod(&mut self, mod_id: &str) {
+        let Some(loaded_mod) = self
+            .mod_index
+            .get(mod_id)
+            .and_then(|&index| self.available_mods.get(index))
+        else {
+            return;
+        };
+        self.approvals.mods.insert(
+            mod_id.to_string(),
+            ModApproval {
+                checksum: loaded_mod.checksum,
+                capabilities: loaded_mod.manifest.capabilities.clone(),
+            },
+        );
+        self.approvals.save(&self.mod_paths.approvals);
+        info!("Approved permissions for mod: {}", mod_id);
+    }
+
     /// Enable a mod by ID
+    ///
+    /// A mod whose permissions haven't been approved for its current files
+    /// stays disabled.
     pub fn enable_mod(&mut self, mod_id: &str) {
+        if !self.approval_status(mod_id).is_cleared() {
+            warn!("Mod {} needs its permissions approved before it can be enabled", mod_id);
+            return;
+        }
         if !self.active_mods.contains(&mod_id.to_string()) {
             self.active_mods.push(mod_id.to_string());
 
@@ -298,6 +507,30 @@ impl ModManager {
         info!("Disabled mod: {}", mod_id);
     }
 
+    /// Id and version of each active mod, in load order
+    pub fn active_mod_versions(&self) -> Vec<(String, String)> {
+        self.active_mods
+            .iter()
+            .map(|id| {
+                let version = self
+                    .mod_index
+                    .get(id)
+                    .and_then(|&index| self.available_mods.get(index))
+                    .map(|loaded_mod| loaded_mod.manifest.version.clone())
+                    .unwrap_or_default();
+                (id.clone(), version)
+            })
+            .collect()
+    }
+
+    /// Version of an installed mod, whether or not it is active
+    pub fn installed_version(&self, mod_id: &str) -> Option<&str> {
+        self.mod_index
+            .get(mod_id)
+            .and_then(|&index| self.available_mods.get(index))
+            .map(|loaded_mod| loaded_mod.manifest.version.as_str())
+    }
+
     pub fn get_config(&self) -> &GameConfig {
         &self.merged_config
     }
@@ -359,11 +592,15 @@ impl ModManager {
                                     dependencies: Vec::new(),
                                     compatible_game_version: "*".to_string(),
                                     load_order: 200,
+                                    capabilities: Vec::new(),
+                                    settings: Vec::new(),
+                                    heatmaps: Vec::new(),
                                 },
                                 path: entry.path(),
                                 config_overrides: ModConfigOverrides::default(),
                                 source: ModSource::Workshop(id),
                                 enabled: false,
+                                checksum: 0,
                             };
 
                             // Try to load manifest if it exists
@@ -378,6 +615,7 @@ impl ModManager {
 
                             // Load config overrides
                             Self::load_mod_config_overrides(&mut loaded_mod);
+                            loaded_mod.checksum = mod_checksum(&loaded_mod.path);
 
                             self.available_mods.push(loaded_mod);
                         }
@@ -422,11 +660,15 @@ impl ModManager {
                 dependencies: Vec::new(),
                 compatible_game_version: "*".to_string(),
                 load_order: 200,
+                capabilities: Vec::new(),
+                settings: Vec::new(),
+                heatmaps: Vec::new(),
             },
             path: workshop_path.clone(),
             config_overrides: ModConfigOverrides::default(),
             source: ModSource::Workshop(workshop_id),
             enabled: false,
+            checksum: 0,
         };
 
         // Try to load manifest if it exists
@@ -445,6 +687,7 @@ impl ModManager {
 
         // Load config overrides
         Self::load_mod_config_overrides(&mut loaded_mod);
+        loaded_mod.checksum = mod_checksum(&loaded_mod.path);
 
         self.available_mods.push(loaded_mod);
 
@@ -17,7 +17,10 @@
 mod examples;
 mod handlers;
 mod loader;
+mod localization;
 mod manager;
+mod mod_settings;
+mod permissions;
 mod plugin;
 mod types;
 mod ui;  // Now a subfolder with gateway architecture!
@@ -34,9 +37,22 @@ pub use ui::OpenModBrowserEvent;
 
 // Utility functions that external code may need
 
+// Localized text for mod-defined content
+pub use localization::{Localization, FALLBACK_LANGUAGE};
+
 // Types that external systems need to understand
 
+// Capabilities scripts run with, and reports of what they were stopped from doing
+pub use permissions::{ModCapability, ModSandbox, ModViolation};
+
+// Settings mods declare, their values in effect, and the per-save values worlds keep
+pub use mod_settings::{
+    ModSettingControl, ModSettingDefinition, ModSettingScope, ModSettingValue, ModSettingValues, ModSettings,
+    WorldModSettings,
+};
+
 // Manager access for systems that need to query mod state
+pub use manager::ModManager;
 
 // PURE GATEWAY - Zero Implementation Logic
 //
@@ -47,6 +63,8 @@ pub use ui::OpenModBrowserEvent;
 // - Plugin orchestration logic is in plugin.rs
 // - Event handling logic is in handlers.rs
 // - Mod management logic is in manager.rs
+// - Capabilities, approvals, and violations are in permissions.rs
+// - Mod-declared settings are in mod_settings.rs
 // - Config loading logic is in loader.rs
 // - UI logic is in ui.rs
 // - Type definitions are in types.rs
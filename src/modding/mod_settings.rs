@@ -0,0 +1,317 @@
+//! Settings mods declare for players to adjust
+//!
+//! A mod lists its settings in its manifest: sliders, toggles, and
+//! dropdowns, each kept either in the player's profile or with each save.
+//! The settings menu shows a page for every enabled mod that has any, and
+//! the values in effect are published in [`ModSettings`] for game systems
+//! and scripted events to read.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::manager::ModManager;
+use crate::settings::GameSettings;
+
+/// Where a setting's value is kept
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ModSettingScope {
+    /// With the player, shared by every world
+    #[default]
+    Profile,
+    /// With each save, so worlds can differ
+    Save,
+}
+
+/// How a setting is shown and what values it takes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ModSettingControl {
+    Slider {
+        min: f32,
+        max: f32,
+        default: f32,
+    },
+    Toggle {
+        default: bool,
+    },
+    /// One of several named options; the default is an index into them
+    Dropdown {
+        options: Vec<String>,
+        default: usize,
+    },
+}
+
+/// A setting as declared in a mod's manifest
+///
+/// ```ron
+/// settings: [
+///     (key: "famine_severity", label: "Famine Severity", section: "Disasters",
+///      control: Slider(min: 0.0, max: 2.0, default: 1.0), scope: Save),
+///     (key: "show_omens", label: "Show Omens", control: Toggle(default: true)),
+/// ],
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModSettingDefinition {
+    pub key: String,
+    pub label: String,
+    /// Heading the setting is grouped under on the mod's page
+    #[serde(default = "default_section")]
+    pub section: String,
+    pub control: ModSettingControl,
+    #[serde(default)]
+    pub scope: ModSettingScope,
+}
+
+fn default_section() -> String {
+    "General".to_string()
+}
+
+impl ModSettingDefinition {
+    pub fn default_value(&self) -> ModSettingValue {
+        match &self.control {
+            ModSettingControl::Slider { min, max, default } => ModSettingValue::Number(default.clamp(*min, *max)),
+            ModSettingControl::Toggle { default } => ModSettingValue::Toggle(*default),
+            ModSettingControl::Dropdown { options, default } => {
+                ModSettingValue::Choice(options.get(*default).or(options.first()).cloned().unwrap_or_default())
+            }
+        }
+    }
+
+    /// A stored value made valid for this setting, or its default when it can't be
+    pub fn resolve(&self, value: Option<&ModSettingValue>) -> ModSettingValue {
+        match (&self.control, value) {
+            (ModSettingControl::Slider { min, max, .. }, Some(ModSettingValue::Number(value))) => {
+                ModSettingValue::Number(value.clamp(*min, *max))
+            }
+            (ModSettingControl::Toggle { .. }, Some(ModSettingValue::Toggle(value))) => ModSettingValue::Toggle(*value),
+            (ModSettingControl::Dropdown { options, .. }, Some(ModSettingValue::Choice(choice)))
+                if options.contains(choice) =>
+            {
+                ModSettingValue::Choice(choice.clone())
+            }
+            _ => self.default_value(),
+        }
+    }
+}
+
+/// The value of one setting
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ModSettingValue {
+    Number(f32),
+    Toggle(bool),
+    Choice(String),
+}
+
+/// Setting values by mod id, then setting key
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModSettingValues(pub HashMap<String, HashMap<String, ModSettingValue>>);
+
+impl ModSettingValues {
+    pub fn get(&self, mod_id: &str, key: &str) -> Option<&ModSettingValue> {
+        self.0.get(mod_id)?.get(key)
+    }
+
+    pub fn set(&mut self, mod_id: &str, key: &str, value: ModSettingValue) {
+        self.0
+            .entry(mod_id.to_string())
+            .or_default()
+            .insert(key.to_string(), value);
+    }
+}
+
+/// One enabled mod's settings, in the order it declares them
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModSettingsPage {
+    pub mod_id: String,
+    pub name: String,
+    pub settings: Vec<ModSettingDefinition>,
+}
+
+/// Settings pages of the enabled mods, and every value in effect
+#[derive(Resource, Debug, Default)]
+pub struct ModSettings {
+    pub pages: Vec<ModSettingsPage>,
+    pub values: ModSettingValues,
+}
+
+impl ModSettings {
+    pub fn get(&self, mod_id: &str, key: &str) -> Option<&ModSettingValue> {
+        self.values.get(mod_id, key)
+    }
+
+    pub fn definition(&self, mod_id: &str, key: &str) -> Option<&ModSettingDefinition> {
+        let page = self.pages.iter().find(|page| page.mod_id == mod_id)?;
+        page.settings.iter().find(|setting| setting.key == key)
+    }
+
+    /// A setting as a number: sliders as set, toggles as 1 or 0, dropdowns as the option's index
+    pub fn number(&self, mod_id: &str, key: &str) -> Option<f32> {
+        let value = self.get(mod_id, key)?;
+        Some(match value {
+            ModSettingValue::Number(number) => *number,
+            ModSettingValue::Toggle(enabled) => f32::from(u8::from(*enabled)),
+            ModSettingValue::Choice(choice) => match &self.definition(mod_id, key)?.control {
+                ModSettingControl::Dropdown { options, .. } => {
+                    options.iter().position(|option| option == choice)? as f32
+                }
+                _ => return None,
+            },
+        })
+    }
+}
+
+/// Values of per-save settings for the world being played, saved with it
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorldModSettings(pub ModSettingValues);
+
+/// Every declared setting's value: per-save settings from the world, the rest
+/// from the profile, and defaults where neither has a valid value
+pub fn resolve_values(
+    pages: &[ModSettingsPage],
+    profile: &ModSettingValues,
+    world: &ModSettingValues,
+) -> ModSettingValues {
+    let mut values = ModSettingValues::default();
+    for page in pages {
+        for setting in &page.settings {
+            let profile_value = profile.get(&page.mod_id, &setting.key);
+            let stored = match setting.scope {
+                ModSettingScope::Save => world.get(&page.mod_id, &setting.key).or(profile_value),
+                ModSettingScope::Profile => profile_value,
+            };
+            values.set(&page.mod_id, &setting.key, setting.resolve(stored));
+        }
+    }
+    values
+}
+
+/// Rebuild the pages and values in effect when mods, the profile, or the world change
+pub fn rebuild_mod_settings(
+    mod_manager: Res<ModManager>,
+    game_settings: Res<GameSettings>,
+    world_settings: Res<WorldModSettings>,
+    mut mod_settings: ResMut<ModSettings>,
+) {
+    if !mod_manager.is_changed()
+        && !game_settings.is_changed()
+        && !world_settings.is_changed()
+        && !mod_settings.is_added()
+    {
+        return;
+    }
+    let pages: Vec<ModSettingsPage> = mod_manager
+        .available_mods
+        .iter()
+        .filter(|loaded_mod| loaded_mod.enabled && !loaded_mod.manifest.settings.is_empty())
+        .map(|loaded_mod| ModSettingsPage {
+            mod_id: loaded_mod.manifest.id.clone(),
+            name: loaded_mod.manifest.name.clone(),
+            settings: loaded_mod.manifest.settings.clone(),
+        })
+        .collect();
+    let values = resolve_values(&pages, &game_settings.mods, &world_settings.0);
+    if mod_settings.pages != pages || mod_settings.values != values {
+        mod_settings.pages = pages;
+        mod_settings.values = values;
+    }
+}
+
+/// Keep per-save settings in step between the world and the settings menu
+///
+/// A loaded world's values are shown in the menu, and values applied from
+/// the menu are kept with the world. Only differing values are written, so
+/// the two never chase each other.
+pub fn sync_world_mod_settings(
+    mod_settings: Res<ModSettings>,
+    mut game_settings: ResMut<GameSettings>,
+    mut world_settings: ResMut<WorldModSettings>,
+) {
+    let from_world = world_settings.is_changed();
+    if !from_world && !game_settings.is_changed() && !mod_settings.is_changed() {
+        return;
+    }
+    for page in &mod_settings.pages {
+        for setting in page
+            .settings
+            .iter()
+            .filter(|setting| setting.scope == ModSettingScope::Save)
+        {
+            let Some(value) = mod_settings.get(&page.mod_id, &setting.key) else {
+                continue;
+            };
+            if from_world {
+                // A new world keeps the values it started with, whatever the profile does later
+                if world_settings.0.get(&page.mod_id, &setting.key).is_none() {
+                    world_settings.0.set(&page.mod_id, &setting.key, value.clone());
+                }
+                if game_settings.mods.get(&page.mod_id, &setting.key) != Some(value) {
+                    game_settings.mods.set(&page.mod_id, &setting.key, value.clone());
+                }
+            } else {
+                let applied = setting.resolve(game_settings.mods.get(&page.mod_id, &setting.key));
+                if world_settings.0.get(&page.mod_id, &setting.key) != Some(&applied) {
+                    world_settings.0.set(&page.mod_id, &setting.key, applied);
+                }
+            }
+        }
+    }
+}
+
+/// A new world starts from the profile's per-save values
+///
+/// Loading a save re-inserts the saved values after this runs.
+pub fn reset_world_mod_settings(mut world_settings: ResMut<WorldModSettings>) {
+    *world_settings = WorldModSettings::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_come_from_the_right_scope_and_are_made_valid() {
+        let settings: Vec<ModSettingDefinition> = ron::from_str(
+            "[(key: \"severity\", label: \"Severity\", control: Slider(min: 0.0, max: 2.0, default: 1.0), \
+               scope: Save),
+              (key: \"omens\", label: \"Omens\", control: Toggle(default: true)),
+              (key: \"calendar\", label: \"Calendar\", control: Dropdown(options: [\"Solar\", \"Lunar\"], \
+               default: 1))]",
+        )
+        .unwrap_or_else(|e| panic!("settings failed to parse: {}", e));
+        assert_eq!(settings[1].section, "General");
+        let pages = vec![ModSettingsPage {
+            mod_id: "famine".to_string(),
+            name: "Famine".to_string(),
+            settings,
+        }];
+
+        let mut profile = ModSettingValues::default();
+        profile.set("famine", "severity", ModSettingValue::Number(1.5));
+        profile.set("famine", "omens", ModSettingValue::Number(3.0));
+        profile.set("famine", "calendar", ModSettingValue::Choice("Stellar".to_string()));
+        let mut world = ModSettingValues::default();
+        world.set("famine", "severity", ModSettingValue::Number(9.0));
+        world.set("famine", "omens", ModSettingValue::Toggle(false));
+
+        let values = resolve_values(&pages, &profile, &world);
+        // Per-save comes from the world, clamped to the slider's range
+        assert_eq!(values.get("famine", "severity"), Some(&ModSettingValue::Number(2.0)));
+        // Profile settings ignore the world, and a mistyped value falls back to the default
+        assert_eq!(values.get("famine", "omens"), Some(&ModSettingValue::Toggle(true)));
+        assert_eq!(
+            values.get("famine", "calendar"),
+            Some(&ModSettingValue::Choice("Lunar".to_string()))
+        );
+
+        let without_world = resolve_values(&pages, &profile, &ModSettingValues::default());
+        assert_eq!(
+            without_world.get("famine", "severity"),
+            Some(&ModSettingValue::Number(1.5))
+        );
+
+        let mod_settings = ModSettings { pages, values };
+        assert_eq!(mod_settings.number("famine", "calendar"), Some(1.0));
+        assert_eq!(mod_settings.number("famine", "omens"), Some(1.0));
+        assert_eq!(mod_settings.number("famine", "missing"), None);
+    }
+}
@@ -0,0 +1,305 @@
+//! Mod capabilities, user approvals, and the record of blocked script actions
+//!
+//! A mod declares in its manifest what its scripts need to do. The user
+//! approves that list when enabling the mod, and the approval is tied to a
+//! checksum of the mod's files, so an update that changes anything asks
+//! again. Scripts from a mod may only do what was approved; anything else
+//! is blocked and recorded here for the mod browser.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Something a mod's scripts may do
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ModCapability {
+    /// Test treasuries and tax rates
+    ReadEconomy,
+    /// Change treasuries, tax rates, and technology
+    ModifyEconomy,
+    /// Change stability and the legitimacy of ruling houses
+    ModifyPolitics,
+    /// Change military strength
+    ModifyMilitary,
+    /// Change how nations approach war, expansion, diplomacy, and trade
+    ModifyDiplomacy,
+    /// Grow or shrink province populations
+    ModifyPopulation,
+    /// Define events that happen to nations, and queue them
+    SpawnEvents,
+}
+
+impl ModCapability {
+    pub fn label(self) -> &'static str {
+        match self {
+            ModCapability::ReadEconomy => "Read economy",
+            ModCapability::ModifyEconomy => "Modify economy",
+            ModCapability::ModifyPolitics => "Modify politics",
+            ModCapability::ModifyMilitary => "Modify military",
+            ModCapability::ModifyDiplomacy => "Modify diplomacy",
+            ModCapability::ModifyPopulation => "Modify population",
+            ModCapability::SpawnEvents => "Spawn events",
+        }
+    }
+}
+
+/// Where a mod stands with the user
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApprovalStatus {
+    /// Declares no capabilities, so there is nothing to approve
+    NotNeeded,
+    /// Approved for its current files
+    Approved,
+    /// Never approved
+    Pending,
+    /// Approved once, but its files have changed since
+    Changed,
+}
+
+impl ApprovalStatus {
+    /// Whether the mod can be enabled without asking the user
+    pub fn is_cleared(self) -> bool {
+        matches!(self, ApprovalStatus::NotNeeded | ApprovalStatus::Approved)
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ApprovalStatus::NotNeeded => "No permissions needed",
+            ApprovalStatus::Approved => "Permissions approved",
+            ApprovalStatus::Pending => "Permissions need approval",
+            ApprovalStatus::Changed => "Changed since approval",
+        }
+    }
+}
+
+/// Capabilities the user granted a mod, and the files they were granted for
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModApproval {
+    pub checksum: u64,
+    pub capabilities: Vec<ModCapability>,
+}
+
+/// Every approval the user has given, kept on disk between sessions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModApprovals {
+    pub mods: HashMap<String, ModApproval>,
+}
+
+impl ModApprovals {
+    /// Read approvals, starting empty when there are none yet
+    pub fn load(path: &Path) -> Self {
+        let Ok(contents) = fs::read_to_string(path) else {
+            return Self::default();
+        };
+        ron::from_str(&contents).unwrap_or_else(|e| {
+            warn!("Failed to parse {:?}, mods will ask for approval again: {}", path, e);
+            Self::default()
+        })
+    }
+
+    pub fn save(&self, path: &Path) {
+        let written = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| e.to_string())
+            .and_then(|contents| fs::write(path, contents).map_err(|e| e.to_string()));
+        if let Err(e) = written {
+            error!("Failed to write mod approvals to {:?}: {}", path, e);
+        }
+    }
+
+    /// Capabilities granted to a mod, provided its files still match the approval
+    pub fn granted(&self, mod_id: &str, checksum: u64) -> &[ModCapability] {
+        self.mods
+            .get(mod_id)
+            .filter(|approval| approval.checksum == checksum)
+            .map_or(&[], |approval| approval.capabilities.as_slice())
+    }
+
+    pub fn status(&self, mod_id: &str, checksum: u64, declared: &[ModCapability]) -> ApprovalStatus {
+        match self.mods.get(mod_id) {
+            _ if declared.is_empty() => ApprovalStatus::NotNeeded,
+            Some(approval) if approval.checksum == checksum => ApprovalStatus::Approved,
+            Some(_) => ApprovalStatus::Changed,
+            None => ApprovalStatus::Pending,
+        }
+    }
+}
+
+/// Files under a directory, in a stable order
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_files(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
+}
+
+/// FNV-1a over every file in a mod's directory, names and contents both
+///
+/// Renaming, adding, removing, or editing any file changes the checksum.
+pub fn mod_checksum(dir: &Path) -> u64 {
+    let mut files = Vec::new();
+    collect_files(dir, &mut files);
+    files.sort();
+
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut feed = |bytes: &[u8]| {
+        for &byte in bytes {
+            hash = (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    };
+    for file in files {
+        let name = file.strip_prefix(dir).unwrap_or(&file);
+        feed(name.to_string_lossy().as_bytes());
+        feed(&[0]);
+        if let Ok(contents) = fs::read(&file) {
+            feed(&contents);
+        }
+        feed(&[0]);
+    }
+    hash
+}
+
+/// What a mod's scripts were granted, attached to each thing they define
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModSandbox {
+    pub mod_id: String,
+    pub granted: Vec<ModCapability>,
+}
+
+impl ModSandbox {
+    pub fn allows(&self, capability: ModCapability) -> bool {
+        self.granted.contains(&capability)
+    }
+}
+
+/// Sent when a mod's script was stopped from doing something it wasn't granted
+#[derive(Message, Debug, Clone)]
+pub struct ModViolation {
+    pub mod_id: String,
+    /// The script that overstepped, such as an event id
+    pub script: String,
+    pub capability: ModCapability,
+    pub year: u32,
+}
+
+/// One kind of blocked action, however often it was tried
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViolationRecord {
+    pub mod_id: String,
+    pub script: String,
+    pub capability: ModCapability,
+    pub first_year: u32,
+    pub last_year: u32,
+    pub count: u32,
+}
+
+/// Blocked actions this session, shown in the mod browser
+#[derive(Resource, Debug, Default)]
+pub struct ModViolationLog {
+    pub records: Vec<ViolationRecord>,
+}
+
+impl ModViolationLog {
+    /// Count a violation, returning whether it is the first of its kind
+    pub fn record(&mut self, violation: &ModViolation) -> bool {
+        let existing = self.records.iter_mut().find(|record| {
+            record.mod_id == violation.mod_id
+                && record.script == violation.script
+                && record.capability == violation.capability
+        });
+        if let Some(record) = existing {
+            record.count += 1;
+            record.last_year = violation.year;
+            return false;
+        }
+        self.records.push(ViolationRecord {
+            mod_id: violation.mod_id.clone(),
+            script: violation.script.clone(),
+            capability: violation.capability,
+            first_year: violation.year,
+            last_year: violation.year,
+            count: 1,
+        });
+        true
+    }
+
+    pub fn for_mod<'a>(&'a self, mod_id: &'a str) -> impl Iterator<Item = &'a ViolationRecord> + 'a {
+        self.records.iter().filter(move |record| record.mod_id == mod_id)
+    }
+}
+
+/// Log blocked actions, warning the first time each kind is seen
+pub fn record_mod_violations(mut violations: MessageReader<ModViolation>, mut log: ResMut<ModViolationLog>) {
+    for violation in violations.read() {
+        if log.record(violation) {
+            warn!(
+                "Mod '{}' tried to {} in '{}' without permission; blocked",
+                violation.mod_id,
+                violation.capability.label().to_lowercase(),
+                violation.script
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn approvals_follow_the_checksum_and_violations_are_counted_once_per_kind() {
+        let dir = std::env::temp_dir().join(format!("lw_mod_checksum_{}", std::process::id()));
+        fs::create_dir_all(dir.join("config")).unwrap_or_else(|e| panic!("temp dir: {}", e));
+        fs::write(dir.join("manifest.ron"), "ModManifest()").unwrap_or_else(|e| panic!("write: {}", e));
+        fs::write(dir.join("config/events.ron"), "{}").unwrap_or_else(|e| panic!("write: {}", e));
+        let before = mod_checksum(&dir);
+        assert_eq!(before, mod_checksum(&dir));
+        fs::write(dir.join("config/events.ron"), "{ }").unwrap_or_else(|e| panic!("write: {}", e));
+        let after = mod_checksum(&dir);
+        assert_ne!(before, after);
+        fs::remove_dir_all(&dir).ok();
+
+        let declared = [ModCapability::SpawnEvents, ModCapability::ModifyEconomy];
+        let mut approvals = ModApprovals::default();
+        assert_eq!(approvals.status("riots", before, &declared), ApprovalStatus::Pending);
+        assert_eq!(approvals.status("riots", before, &[]), ApprovalStatus::NotNeeded);
+        approvals.mods.insert(
+            "riots".to_string(),
+            ModApproval {
+                checksum: before,
+                capabilities: declared.to_vec(),
+            },
+        );
+        assert_eq!(approvals.status("riots", before, &declared), ApprovalStatus::Approved);
+        assert_eq!(approvals.granted("riots", before).len(), 2);
+        assert_eq!(approvals.status("riots", after, &declared), ApprovalStatus::Changed);
+        assert!(approvals.granted("riots", after).is_empty());
+
+        let mut log = ModViolationLog::default();
+        let violation = ModViolation {
+            mod_id: "riots".to_string(),
+            script: "bread_riots".to_string(),
+            capability: ModCapability::ModifyDiplomacy,
+            year: 1100,
+        };
+        assert!(log.record(&violation));
+        assert!(!log.record(&ModViolation {
+            year: 1101,
+            ..violation
+        }));
+        let records: Vec<_> = log.for_mod("riots").collect();
+        assert_eq!(records.len(), 1);
+        assert_eq!(
+            (records[0].count, records[0].first_year, records[0].last_year),
+            (2, 1100, 1101)
+        );
+    }
+}
@@ -13,6 +13,7 @@ use super::handlers::{
 };
 use super::loader::ConfigReloadEvent;
 use super::manager::ModManager;
+use crate::states::GameState;
 
 // The modding plugin using ADVANCED custom initialization automation!
 ///
@@ -24,11 +25,19 @@ define_plugin!(ModdingPlugin {
         super::handlers::ModDisabledEvent,
         super::handlers::WorkshopSubscribeEvent,
         super::handlers::WorkshopUnsubscribeEvent,
-        super::handlers::RefreshWorkshopDataEvent
+        super::handlers::RefreshWorkshopDataEvent,
+        super::permissions::ModViolation
     ],
 
     plugins: [super::ui::ModBrowserUIPlugin],
 
+    resources: [
+        super::localization::Localization,
+        super::permissions::ModViolationLog,
+        super::mod_settings::ModSettings,
+        super::mod_settings::WorldModSettings
+    ],
+
     startup: [super::loader::setup_config_watching],
 
     update: [
@@ -41,10 +50,21 @@ define_plugin!(ModdingPlugin {
             handle_workshop_unsubscribe_events,
             handle_refresh_workshop_data_events,
             sync_workshop_installations
+        )
+            .chain(),
+        super::localization::rebuild_localization,
+        super::permissions::record_mod_violations,
+        (
+            super::mod_settings::rebuild_mod_settings,
+            super::mod_settings::sync_world_mod_settings
         )
             .chain()
     ],
 
+    on_enter: {
+        GameState::LoadingWorld => [super::mod_settings::reset_world_mod_settings]
+    },
+
     custom_init: |app: &mut App| {
         // Custom mod manager initialization with logging
         let mut mod_manager = ModManager::new();
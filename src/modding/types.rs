@@ -8,6 +8,15 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+use crate::ai::BehaviorPack;
+use crate::audio::SoundDefinition;
+use crate::nations::EventDefinition;
+use crate::world::{AttritionProfile, HeatmapDefinition, TerrainType};
+
+use super::localization::StringTables;
+use super::mod_settings::ModSettingDefinition;
+use super::permissions::ModCapability;
+
 /// Metadata for a mod
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModManifest {
@@ -19,6 +28,15 @@ pub struct ModManifest {
     pub dependencies: Vec<ModDependency>,
     pub compatible_game_version: String,
     pub load_order: i32,
+    /// What the mod's scripts need to do, approved by the user on enable
+    #[serde(default)]
+    pub capabilities: Vec<ModCapability>,
+    /// Settings the mod offers players, shown on its own page in the settings menu
+    #[serde(default)]
+    pub settings: Vec<ModSettingDefinition>,
+    /// Province statistics the mod adds to the map mode picker as heatmaps
+    #[serde(default)]
+    pub heatmaps: Vec<HeatmapDefinition>,
 }
 
 /// Dependency specification for a mod
@@ -38,6 +56,8 @@ pub struct LoadedMod {
     pub config_overrides: ModConfigOverrides,
     pub source: ModSource,
     pub enabled: bool,
+    /// Checksum of the mod's files, which approvals are tied to
+    pub checksum: u64,
 }
 
 /// Where a mod came from
@@ -55,6 +75,21 @@ pub struct GameConfig {
     pub generation: GenerationConfig,
     pub simulation: SimulationConfig,
     pub audio: AudioConfig,
+    /// Sound played for each audio cue id, layered over the built-in sounds
+    #[serde(default)]
+    pub sounds: HashMap<String, SoundDefinition>,
+    /// Attrition profile of each terrain, overriding the built-in profiles
+    #[serde(default)]
+    pub attrition: HashMap<TerrainType, AttritionProfile>,
+    /// AI behavior packs by id, replacing built-in packs of the same id
+    #[serde(default)]
+    pub behavior_packs: HashMap<String, BehaviorPack>,
+    /// Scripted events by id
+    #[serde(default)]
+    pub events: HashMap<String, EventDefinition>,
+    /// String tables by language
+    #[serde(default)]
+    pub localization: StringTables,
 }
 
 impl Default for GameConfig {
@@ -65,6 +100,11 @@ impl Default for GameConfig {
             generation: GenerationConfig::default(),
             simulation: SimulationConfig::default(),
             audio: AudioConfig::default(),
+            sounds: HashMap::new(),
+            attrition: HashMap::new(),
+            behavior_packs: HashMap::new(),
+            events: HashMap::new(),
+            localization: StringTables::new(),
         }
     }
 }
@@ -77,6 +117,16 @@ pub struct ModConfigOverrides {
     pub generation: Option<GenerationConfig>,
     pub simulation: Option<SimulationConfig>,
     pub audio: Option<AudioConfig>,
+    #[serde(default)]
+    pub sounds: Option<HashMap<String, SoundDefinition>>,
+    #[serde(default)]
+    pub attrition: Option<HashMap<TerrainType, AttritionProfile>>,
+    #[serde(default)]
+    pub behavior_packs: Option<HashMap<String, BehaviorPack>>,
+    #[serde(default)]
+    pub events: Option<HashMap<String, EventDefinition>>,
+    #[serde(default)]
+    pub localization: StringTables,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -3,6 +3,7 @@
 //! This module handles the events for opening and closing the mod browser UI.
 
 use crate::modding::manager::ModManager;
+use crate::modding::permissions::ModViolationLog;
 use crate::modding::ui::spawning::spawn_mod_browser;
 use crate::modding::ui::state::ModBrowserState;
 use crate::modding::ui::types::{
@@ -16,6 +17,7 @@ pub fn handle_open_mod_browser(
     mut open_events: MessageReader<OpenModBrowserEvent>,
     existing_browser: Query<Entity, With<ModBrowserRoot>>,
     mod_manager: Res<ModManager>,
+    violations: Res<ModViolationLog>,
     browser_state: Res<ModBrowserState>,
 ) {
     for _ in open_events.read() {
@@ -25,7 +27,7 @@ pub fn handle_open_mod_browser(
         }
 
         debug!("Opening mod browser UI");
-        spawn_mod_browser(&mut commands, &mod_manager, &browser_state);
+        spawn_mod_browser(&mut commands, &mod_manager, &violations, &browser_state);
     }
 }
 
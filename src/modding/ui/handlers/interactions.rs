@@ -5,8 +5,13 @@
 
 use crate::loading::{start_mod_application_loading, LoadingState};
 use crate::modding::manager::ModManager;
+use crate::modding::permissions::ModViolationLog;
+use crate::modding::ui::spawning::spawn_permission_dialog;
 use crate::modding::ui::state::ModBrowserState;
-use crate::modding::ui::types::{ApplyModChangesEvent, ConfirmModsetButton, ModToggle};
+use crate::modding::ui::tabs::spawn_tab_content;
+use crate::modding::ui::types::{
+    ApplyModChangesEvent, ConfirmModsetButton, ContentArea, ModPermissionButton, ModPermissionDialog, ModToggle,
+};
 use crate::states::{GameState, RequestStateTransition};
 use bevy::prelude::*;
 
@@ -23,7 +28,12 @@ pub fn handle_confirm_modset_clicks(
 }
 
 /// Handles mod toggle interactions
+///
+/// Enabling a mod whose permissions aren't approved opens the approval
+/// dialog instead.
 pub fn handle_mod_toggles(
+    mut commands: Commands,
+    dialog_query: Query<(), With<ModPermissionDialog>>,
     mut interaction_query: Query<
         (&Interaction, &ModToggle, &Children),
         (Changed<Interaction>, With<ModToggle>),
@@ -34,6 +44,27 @@ pub fn handle_mod_toggles(
 ) {
     for (interaction, toggle, children) in &mut interaction_query {
         if *interaction == Interaction::Pressed {
+            let status = mod_manager.approval_status(&toggle.mod_id);
+            if !status.is_cleared() {
+                let asking = mod_manager
+                    .available_mods
+                    .iter()
+                    .find(|m| m.manifest.id == toggle.mod_id && !m.enabled);
+                if let Some(loaded_mod) = asking {
+                    if dialog_query.is_empty() {
+                        spawn_permission_dialog(
+                            &mut commands,
+                            &toggle.mod_id,
+                            &loaded_mod.manifest.name,
+                            status,
+                            &loaded_mod.manifest.capabilities,
+                            loaded_mod.checksum,
+                        );
+                    }
+                    continue;
+                }
+            }
+
             // Toggle the mod's enabled state
             if let Some(loaded_mod) = mod_manager
                 .available_mods
@@ -70,6 +101,45 @@ pub fn handle_mod_toggles(
     }
 }
 
+/// Handles the permission dialog: approving grants what the mod declares and enables it
+pub fn handle_permission_dialog_buttons(
+    mut commands: Commands,
+    interaction_query: Query<(&Interaction, &ModPermissionButton), Changed<Interaction>>,
+    dialog_query: Query<Entity, With<ModPermissionDialog>>,
+    content_query: Query<Entity, With<ContentArea>>,
+    mut mod_manager: ResMut<ModManager>,
+    mut browser_state: ResMut<ModBrowserState>,
+    violations: Res<ModViolationLog>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let ModPermissionButton::Approve(mod_id) = button {
+            mod_manager.approve_mod(mod_id);
+            mod_manager.enable_mod(mod_id);
+            browser_state.active_mods.insert(mod_id.clone());
+
+            // Redraw the cards so the approval shows
+            for entity in &content_query {
+                commands.entity(entity).despawn_related::<Children>();
+                commands.entity(entity).with_children(|parent| {
+                    spawn_tab_content(
+                        parent,
+                        browser_state.current_tab,
+                        &mod_manager,
+                        &violations,
+                        &browser_state.search_query,
+                    );
+                });
+            }
+        }
+        for dialog in &dialog_query {
+            commands.entity(dialog).despawn();
+        }
+    }
+}
+
 /// Handles applying mod changes with a soft reset
 pub fn handle_apply_changes(
     mut apply_events: MessageReader<ApplyModChangesEvent>,
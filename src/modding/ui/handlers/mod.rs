@@ -11,6 +11,8 @@ mod tabs;
 
 // Re-export public handler functions
 pub use browser::{handle_close_mod_browser, handle_close_button_clicks, handle_open_mod_browser};
-pub use interactions::{handle_apply_changes, handle_confirm_modset_clicks, handle_mod_toggles};
+pub use interactions::{
+    handle_apply_changes, handle_confirm_modset_clicks, handle_mod_toggles, handle_permission_dialog_buttons,
+};
 pub use search::handle_search_input_changes;
 pub use tabs::{handle_tab_button_clicks, handle_tab_switching, update_tab_buttons};
\ No newline at end of file
@@ -4,6 +4,7 @@
 //! for filtering mods in the browser.
 
 use crate::modding::manager::ModManager;
+use crate::modding::permissions::ModViolationLog;
 use crate::modding::ui::state::ModBrowserState;
 use crate::modding::ui::tabs::spawn_tab_content;
 use crate::modding::ui::types::{ContentArea, SearchInputMarker};
@@ -17,6 +18,7 @@ pub fn handle_search_input_changes(
     search_inputs: Query<&TextBuffer, (Changed<TextBuffer>, With<SearchInputMarker>)>,
     content_query: Query<Entity, With<ContentArea>>,
     mod_manager: Res<ModManager>,
+    violations: Res<ModViolationLog>,
 ) {
     for text_value in &search_inputs {
         let new_query = text_value.content.clone();
@@ -34,6 +36,7 @@ pub fn handle_search_input_changes(
                         parent,
                         browser_state.current_tab,
                         &mod_manager,
+                        &violations,
                         &browser_state.search_query,
                     );
                 });
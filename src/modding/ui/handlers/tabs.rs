@@ -4,6 +4,7 @@
 //! in the mod browser UI.
 
 use crate::modding::manager::ModManager;
+use crate::modding::permissions::ModViolationLog;
 use crate::modding::ui::state::ModBrowserState;
 use crate::modding::ui::tabs::spawn_tab_content;
 use crate::modding::ui::types::{
@@ -36,6 +37,7 @@ pub fn handle_tab_switching(
     mut browser_state: ResMut<ModBrowserState>,
     content_query: Query<Entity, With<ContentArea>>,
     mod_manager: Res<ModManager>,
+    violations: Res<ModViolationLog>,
 ) {
     for event in switch_events.read() {
         if browser_state.current_tab == event.tab {
@@ -53,6 +55,7 @@ pub fn handle_tab_switching(
                     parent,
                     browser_state.current_tab,
                     &mod_manager,
+                    &violations,
                     &browser_state.search_query,
                 );
             });
@@ -33,6 +33,7 @@ define_plugin!(ModBrowserUIPlugin {
 
         // Interactions
         handlers::handle_mod_toggles,
+        handlers::handle_permission_dialog_buttons,
         handlers::handle_confirm_modset_clicks,
         handlers::handle_apply_changes,
 
@@ -4,6 +4,7 @@
 //! including the header, content area, and action bar.
 
 use crate::modding::manager::ModManager;
+use crate::modding::permissions::ModViolationLog;
 use crate::modding::ui::spawning::search::spawn_search_bar;
 use crate::modding::ui::state::ModBrowserState;
 use crate::modding::ui::tabs::spawn_tab_content;
@@ -21,6 +22,7 @@ use bevy::prelude::*;
 pub fn spawn_mod_browser(
     commands: &mut Commands,
     mod_manager: &ModManager,
+    violations: &ModViolationLog,
     browser_state: &ModBrowserState,
 ) {
     // Create modal overlay that blocks clicks
@@ -47,7 +49,7 @@ pub fn spawn_mod_browser(
     // Add content to overlay
     commands.entity(overlay_entity).with_children(|parent| {
         spawn_header(parent, browser_state);
-        spawn_main_content(parent, browser_state, mod_manager);
+        spawn_main_content(parent, browser_state, mod_manager, violations);
         spawn_action_bar(parent);
     });
 }
@@ -144,6 +146,7 @@ fn spawn_main_content(
     parent: &mut ChildSpawnerCommands,
     browser_state: &ModBrowserState,
     mod_manager: &ModManager,
+    violations: &ModViolationLog,
 ) {
     parent
         .spawn((
@@ -157,7 +160,7 @@ fn spawn_main_content(
         ))
         .with_children(|content| {
             spawn_sidebar(content);
-            spawn_content_area(content, browser_state, mod_manager);
+            spawn_content_area(content, browser_state, mod_manager, violations);
         });
 }
 
@@ -277,6 +280,7 @@ fn spawn_content_area(
     content: &mut ChildSpawnerCommands,
     browser_state: &ModBrowserState,
     mod_manager: &ModManager,
+    violations: &ModViolationLog,
 ) {
     content
         .spawn((
@@ -293,6 +297,7 @@ fn spawn_content_area(
                 main,
                 browser_state.current_tab,
                 mod_manager,
+                violations,
                 &browser_state.search_query,
             );
         });
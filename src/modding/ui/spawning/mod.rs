@@ -5,7 +5,9 @@
 
 // Internal modules - all private
 mod browser;
+mod permissions;
 mod search;
 
 // Re-export public spawning functions
 pub use browser::spawn_mod_browser;
+pub use permissions::spawn_permission_dialog;
@@ -0,0 +1,80 @@
+//! Permission approval dialog spawning
+//!
+//! This module spawns the dialog shown when a mod that declares
+//! capabilities is enabled before the user has approved them.
+
+use crate::modding::permissions::{ApprovalStatus, ModCapability};
+use crate::modding::ui::types::{ModPermissionButton, ModPermissionDialog};
+use crate::ui::{
+    colors, dimensions, helpers, layers, ButtonBuilder, ButtonSize, ButtonStyle, LabelBuilder, LabelStyle,
+    PanelBuilder, PanelStyle,
+};
+use bevy::prelude::*;
+
+/// Spawns the dialog listing what a mod asks to do
+pub fn spawn_permission_dialog(
+    commands: &mut Commands,
+    mod_id: &str,
+    mod_name: &str,
+    status: ApprovalStatus,
+    capabilities: &[ModCapability],
+    checksum: u64,
+) {
+    let overlay = helpers::spawn_modal_overlay(commands, colors::OVERLAY_MEDIUM, ZIndex(layers::MODAL_CONTENT));
+    commands.entity(overlay).insert(ModPermissionDialog);
+    commands.entity(overlay).with_children(|parent| {
+        PanelBuilder::new()
+            .style(PanelStyle::Card)
+            .width(Val::Px(dimensions::DIALOG_WIDTH_MEDIUM))
+            .padding(UiRect::all(Val::Px(dimensions::DIALOG_PADDING)))
+            .flex_direction(FlexDirection::Column)
+            .row_gap(Val::Px(10.0))
+            .build_with_children(parent, |dialog| {
+                LabelBuilder::new(format!("{} asks for permission", mod_name))
+                    .style(LabelStyle::Heading)
+                    .build(dialog);
+
+                if status == ApprovalStatus::Changed {
+                    LabelBuilder::new("This mod's files changed since you last approved it.")
+                        .style(LabelStyle::Warning)
+                        .build(dialog);
+                }
+
+                LabelBuilder::new("Its scripts will be allowed to:")
+                    .style(LabelStyle::Body)
+                    .build(dialog);
+                for capability in capabilities {
+                    LabelBuilder::new(format!("- {}", capability.label()))
+                        .style(LabelStyle::Body)
+                        .build(dialog);
+                }
+                LabelBuilder::new(format!(
+                    "Anything else they try is blocked and listed here. Checksum {:016x}",
+                    checksum
+                ))
+                .style(LabelStyle::Caption)
+                .build(dialog);
+
+                dialog
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        justify_content: JustifyContent::End,
+                        column_gap: Val::Px(10.0),
+                        margin: UiRect::top(Val::Px(10.0)),
+                        ..default()
+                    })
+                    .with_children(|buttons| {
+                        ButtonBuilder::new("Cancel")
+                            .style(ButtonStyle::Secondary)
+                            .size(ButtonSize::Medium)
+                            .with_marker(ModPermissionButton::Cancel)
+                            .build(buttons);
+                        ButtonBuilder::new("Approve and Enable")
+                            .style(ButtonStyle::Primary)
+                            .size(ButtonSize::Medium)
+                            .with_marker(ModPermissionButton::Approve(mod_id.to_string()))
+                            .build(buttons);
+                    });
+            });
+    });
+}
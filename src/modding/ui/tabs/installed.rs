@@ -4,6 +4,7 @@
 //! including mod cards with metadata and enable/disable toggles.
 
 use crate::modding::manager::ModManager;
+use crate::modding::permissions::{ApprovalStatus, ModViolationLog};
 use crate::modding::ui::types::{ModCard, ModToggle};
 use crate::ui::{
     colors, ButtonBuilder, ButtonSize, ButtonStyle, CheckboxBuilder, LabelBuilder, LabelStyle,
@@ -15,6 +16,7 @@ use bevy::prelude::*;
 pub fn spawn_installed_tab(
     parent: &mut ChildSpawnerCommands,
     mod_manager: &ModManager,
+    violations: &ModViolationLog,
     search_query: &str,
 ) {
     // Wrap the grid in a ScrollView for scrolling support
@@ -43,7 +45,8 @@ pub fn spawn_installed_tab(
 
                     // Generate mod cards for each filtered mod
                     for loaded_mod in filtered_mods {
-                        spawn_mod_card(grid, loaded_mod);
+                        let status = mod_manager.approval_status(&loaded_mod.manifest.id);
+                        spawn_mod_card(grid, loaded_mod, status, violations);
                     }
                 });
         });
@@ -65,7 +68,12 @@ fn filter_mod(loaded_mod: &&crate::modding::types::LoadedMod, search_query: &str
 }
 
 /// Spawns a single mod card in the grid
-fn spawn_mod_card(grid: &mut ChildSpawnerCommands, loaded_mod: &crate::modding::types::LoadedMod) {
+fn spawn_mod_card(
+    grid: &mut ChildSpawnerCommands,
+    loaded_mod: &crate::modding::types::LoadedMod,
+    status: ApprovalStatus,
+    violations: &ModViolationLog,
+) {
     // Mod card container
     let panel = PanelBuilder::new()
         .style(PanelStyle::Card)
@@ -74,6 +82,7 @@ fn spawn_mod_card(grid: &mut ChildSpawnerCommands, loaded_mod: &crate::modding::
         .build_with_children(grid, |card| {
             spawn_mod_thumbnail(card);
             spawn_mod_info(card, loaded_mod);
+            spawn_mod_permissions(card, loaded_mod, status, violations);
             spawn_mod_toggle(card, loaded_mod);
         });
         
@@ -145,6 +154,46 @@ fn spawn_mod_info(card: &mut ChildSpawnerCommands, loaded_mod: &crate::modding::
         .build(card);
 }
 
+/// Spawns the mod's declared permissions, its approval, and anything it was blocked from doing
+fn spawn_mod_permissions(
+    card: &mut ChildSpawnerCommands,
+    loaded_mod: &crate::modding::types::LoadedMod,
+    status: ApprovalStatus,
+    violations: &ModViolationLog,
+) {
+    let capabilities = &loaded_mod.manifest.capabilities;
+    if !capabilities.is_empty() {
+        let labels: Vec<_> = capabilities.iter().map(|capability| capability.label()).collect();
+        LabelBuilder::new(format!("Permissions: {}", labels.join(", ")))
+            .style(LabelStyle::Caption)
+            .margin(UiRect::bottom(Val::Px(5.0)))
+            .build(card);
+    }
+
+    LabelBuilder::new(format!("{} | checksum {:016x}", status.label(), loaded_mod.checksum))
+        .style(if status.is_cleared() {
+            LabelStyle::Caption
+        } else {
+            LabelStyle::Warning
+        })
+        .margin(UiRect::bottom(Val::Px(5.0)))
+        .build(card);
+
+    for record in violations.for_mod(&loaded_mod.manifest.id) {
+        LabelBuilder::new(format!(
+            "Blocked: '{}' tried to {} ({}x, years {}-{})",
+            record.script,
+            record.capability.label().to_lowercase(),
+            record.count,
+            record.first_year,
+            record.last_year
+        ))
+        .style(LabelStyle::Error)
+        .margin(UiRect::bottom(Val::Px(5.0)))
+        .build(card);
+    }
+}
+
 /// Spawns the enable/disable toggle for a mod
 fn spawn_mod_toggle(card: &mut ChildSpawnerCommands, loaded_mod: &crate::modding::types::LoadedMod) {
     // Checkbox button using CheckboxBuilder
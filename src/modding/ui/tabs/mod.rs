@@ -14,6 +14,7 @@ pub use installed::spawn_installed_tab;
 pub use workshop::spawn_workshop_tab;
 
 use crate::modding::manager::ModManager;
+use crate::modding::permissions::ModViolationLog;
 use crate::modding::ui::types::ModBrowserTab;
 use bevy::prelude::*;
 
@@ -22,10 +23,11 @@ pub fn spawn_tab_content(
     parent: &mut ChildSpawnerCommands,
     tab: ModBrowserTab,
     mod_manager: &ModManager,
+    violations: &ModViolationLog,
     search_query: &str,
 ) {
     match tab {
-        ModBrowserTab::Installed => spawn_installed_tab(parent, mod_manager, search_query),
+        ModBrowserTab::Installed => spawn_installed_tab(parent, mod_manager, violations, search_query),
         ModBrowserTab::Workshop => spawn_workshop_tab(parent),
         ModBrowserTab::ActiveModset => spawn_active_modset_tab(parent, mod_manager),
     }
@@ -78,6 +78,18 @@ pub struct ModToggle {
     pub mod_id: String,
 }
 
+/// Root of the dialog asking the user to approve a mod's permissions
+#[derive(Component)]
+pub struct ModPermissionDialog;
+
+/// Buttons on the permission dialog
+#[derive(Component, Debug, Clone)]
+pub enum ModPermissionButton {
+    /// Grant the mod's declared permissions and enable it
+    Approve(String),
+    Cancel,
+}
+
 /// Component for mod list items in Active Modset
 #[derive(Component)]
 pub struct ModListItem {
@@ -71,6 +71,14 @@ impl NameGenerator {
             NameType::Ocean => super::geographic::generate_ocean_name(self),
             NameType::Desert => super::geographic::generate_desert_name(self),
             NameType::Forest => super::geographic::generate_forest_name(self),
+            NameType::Waterfall => super::geographic::generate_waterfall_name(self),
+            NameType::Canyon => super::geographic::generate_canyon_name(self),
+            NameType::AncientForest => super::geographic::generate_ancient_forest_name(self),
+            NameType::Continent => super::geographic::generate_continent_name(self),
+            NameType::Region { region, bearing } => {
+                super::geographic::generate_region_name(self, region, bearing)
+            }
+            NameType::Area { region } => super::geographic::generate_area_name(self, region),
         };
 
         // Ensure uniqueness by appending Roman numerals if needed
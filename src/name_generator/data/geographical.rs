@@ -263,6 +263,30 @@ pub const FOREST_ROOTS: &[&str] = &[
     "Twilight",
 ];
 
+/// Continent name roots, taking suffixes like "ia" or "heim"
+pub const CONTINENT_ROOTS: &[&str] = &[
+    "Aster",
+    "Valen",
+    "Thal",
+    "Esper",
+    "Myr",
+    "Oros",
+    "Cald",
+    "Ilar",
+    "Nor",
+    "Sered",
+    "Avel",
+    "Dran",
+    "Ost",
+    "Umbr",
+    "Zar",
+    "Quel",
+    "Bel",
+    "Tyr",
+    "Ery",
+    "Hesper",
+];
+
 /// Suffixes for geographic features
 pub const GEOGRAPHIC_SUFFIXES: &[&str] = &[
     // River suffixes
@@ -1,9 +1,12 @@
 //! Geographic feature name generation
 //!
 //! This module handles name generation for natural geographic features
-//! including rivers, mountains, oceans, deserts, and forests.
+//! including rivers, mountains, oceans, deserts, and forests, the natural
+//! wonders among them, and the continents, regions, and areas the land is
+//! divided into.
 
 use super::core::NameGenerator;
+use super::types::{Bearing, Region};
 
 /// Generate a river name with appropriate suffixes
 pub fn generate_river_name(generator: &mut NameGenerator) -> String {
@@ -45,3 +48,83 @@ pub fn generate_forest_name(generator: &mut NameGenerator) -> String {
     let suffix = generator.random_choice(&[" Forest", " Woods", " Grove", " Wildwood", " Thicket"]);
     format!("{}{}", root, suffix)
 }
+
+/// Generate a great waterfall's name
+pub fn generate_waterfall_name(generator: &mut NameGenerator) -> String {
+    use super::data::*;
+    let root = generator.random_choice(RIVER_ROOTS);
+    let suffix = generator.random_choice(&[" Falls", " Cataract", " Cascades", " Veil"]);
+    format!("{}{}", root, suffix)
+}
+
+/// Generate a canyon or gorge name
+pub fn generate_canyon_name(generator: &mut NameGenerator) -> String {
+    use super::data::*;
+    let root = generator.random_choice(MOUNTAIN_ROOTS);
+    let suffix = generator.random_choice(&[" Canyon", " Gorge", " Chasm", " Rift"]);
+    format!("{}{}", root, suffix)
+}
+
+/// Generate an ancient forest's name
+pub fn generate_ancient_forest_name(generator: &mut NameGenerator) -> String {
+    use super::data::*;
+    let root = generator.random_choice(FOREST_ROOTS);
+    let prefix = generator.random_choice(&["The Elder ", "The Old ", "The Deep ", ""]);
+    let suffix = generator.random_choice(&["wood", " Grove", " Weald", " Elderwood"]);
+    format!("{}{}{}", prefix, root, suffix)
+}
+
+/// Generate a continent's name
+pub fn generate_continent_name(generator: &mut NameGenerator) -> String {
+    use super::data::*;
+    let root = generator.random_choice(CONTINENT_ROOTS);
+    let suffix = generator.random_choice(&["ia", "ea", "os", "and", "heim", "ara"]);
+    format!("{}{}", root, suffix)
+}
+
+/// A root word fitting the lie of the land
+fn landform_root(generator: &mut NameGenerator, region: Region) -> &'static str {
+    use super::data::*;
+    let roots = match region {
+        Region::Mountain | Region::Arctic => MOUNTAIN_ROOTS,
+        Region::Desert => DESERT_ROOTS,
+        Region::Forest | Region::Tropical => FOREST_ROOTS,
+        Region::Coastal | Region::Island => OCEAN_ROOTS,
+        Region::Plains | Region::River | Region::Valley => RIVER_ROOTS,
+    };
+    *generator.random_choice(roots)
+}
+
+/// Generate a region's name, e.g. "Northern Vales" or "Silver Highlands"
+pub fn generate_region_name(
+    generator: &mut NameGenerator,
+    region: Region,
+    bearing: Option<Bearing>,
+) -> String {
+    let landform = match region {
+        Region::Coastal => generator.random_choice(&["Shores", "Coast", "Strand"]),
+        Region::Mountain => generator.random_choice(&["Highlands", "Peaks", "Heights"]),
+        Region::Desert => generator.random_choice(&["Barrens", "Sands", "Wastes"]),
+        Region::Forest => generator.random_choice(&["Woodlands", "Weald", "Forests"]),
+        Region::Plains => generator.random_choice(&["Downs", "Plains", "Steppes"]),
+        Region::River => generator.random_choice(&["Riverlands", "Lowlands", "Fens"]),
+        Region::Arctic => generator.random_choice(&["Frostlands", "Tundra", "Snows"]),
+        Region::Tropical => generator.random_choice(&["Jungles", "Tropics", "Greenlands"]),
+        Region::Valley => generator.random_choice(&["Vales", "Dales", "Glens"]),
+        Region::Island => generator.random_choice(&["Isles", "Archipelago", "Atolls"]),
+    };
+    let prefix = match bearing {
+        Some(bearing) if generator.random_bool() => bearing.adjective(),
+        _ => landform_root(generator, region),
+    };
+    format!("{} {}", prefix, landform)
+}
+
+/// Generate the name of an area within a region
+pub fn generate_area_name(generator: &mut NameGenerator, region: Region) -> String {
+    let root = landform_root(generator, region);
+    let suffix = generator.random_choice(&[
+        " Reach", " March", "fold", " Hollow", "dale", " Moor", " Heath",
+    ]);
+    format!("{}{}", root, suffix)
+}
@@ -55,6 +55,7 @@ mod data; // Name databases
 mod geographic; // Natural feature names
 mod people; // Person names with titles
 mod places; // Province and city name generation
+mod prose; // Culture-specific phrasing for written histories
 mod types; // Type definitions
 mod utils;
 mod world; // World name generation patterns // Utility functions
@@ -62,9 +63,11 @@ mod world; // World name generation patterns // Utility functions
 // CONTROLLED PUBLIC API - This is the ONLY way in/out of name_generator
 // Re-export only what external code needs
 pub use core::NameGenerator;
-pub use types::{Culture, Gender, NameType, PersonRole};
+pub use types::{Bearing, CitySize, Culture, Gender, NameType, PersonRole, Region};
 
 // Selectively expose utility functions
+pub use places::adapt_place_name;
+pub use prose::{prose_style, ProseStyle};
 
 // Module documentation for key features
 /// The name generator supports 8 distinct cultural styles
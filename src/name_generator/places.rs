@@ -112,3 +112,71 @@ pub fn get_cultural_place_style(generator: &mut NameGenerator, culture: Culture)
         }
     }
 }
+
+/// Size words added by [`generate_city_name`] that don't survive adaptation
+const CITY_PREFIXES: &[&str] = &["Great ", "Grand ", "Imperial ", "Royal ", "Market ", "Old ", "New "];
+
+/// Endings stripped to recover a place name's stem, longest first
+const PLACE_ENDINGS: &[&str] = &[
+    " City", "polis", "thorpe", "stead", "abad", "burg", "grad", "heim", "gard", "holm", "ford",
+    "bury", "jing", "ium", "wyn", "eth", "iel", "ham", "cot", "ton", "vik", "shi", "ona", "ano",
+    "os", "on", "ia", "ua", "oa", "an",
+];
+
+/// Adapt an existing place name to another culture's naming patterns
+///
+/// Deterministic: the same name always adapts to the same form, so a city
+/// conquered twice by one culture does not get two different names. The
+/// stem of the old name is kept, so "Ashford" might become "Ashheim" under
+/// Northern rule or "Al-Ashabad" under Desert rule.
+pub fn adapt_place_name(name: &str, culture: Culture) -> String {
+    let mut core = name.trim();
+    for prefix in CITY_PREFIXES {
+        core = core.strip_prefix(prefix).unwrap_or(core);
+    }
+    core = core.strip_prefix("Al-").unwrap_or(core);
+
+    let mut stem = core.to_lowercase();
+    if let Some(ending) = PLACE_ENDINGS.iter().find(|ending| stem.ends_with(&ending.to_lowercase())) {
+        if stem.len() > ending.len() + 2 {
+            stem.truncate(stem.len() - ending.len());
+        }
+    }
+    // Drop spaces and any trailing vowels so endings attach cleanly
+    stem.retain(|c| c.is_alphabetic());
+    while stem.len() > 3 && stem.ends_with(|c: char| "aeiouy".contains(c)) {
+        stem.pop();
+    }
+
+    let pick = |options: &[&'static str]| {
+        let hash = stem.bytes().fold(0usize, |acc, byte| acc.wrapping_mul(31).wrapping_add(byte as usize));
+        options[hash % options.len()]
+    };
+
+    let adapted = match culture {
+        Culture::Western => format!("{}{}", stem, pick(&["ford", "ton", "bury", "ham"])),
+        Culture::Eastern => format!("{}{}", open_syllables(&stem), pick(&["jing", "shi", "kai", "an"])),
+        Culture::Northern => format!("{}{}", stem, pick(&["heim", "gard", "vik", "holm"])),
+        Culture::Southern => format!("{}{}", stem, pick(&["ia", "ona", "ano"])),
+        Culture::Desert => return format!("Al-{}{}", capitalize_first(&stem), pick(&["abad", "an", "ar"])),
+        Culture::Island => format!("{}{}", open_syllables(&stem), pick(&["ua", "oa", "iki"])),
+        Culture::Ancient => format!("{}{}", stem, pick(&["os", "on", "ium"])),
+        Culture::Mystical => format!("{}{}", stem, pick(&["eth", "wyn", "iel"])),
+    };
+    capitalize_first(&adapted)
+}
+
+/// Break consonant clusters with vowels, as syllabic scripts transliterate them
+fn open_syllables(stem: &str) -> String {
+    let is_vowel = |c: char| "aeiou".contains(c);
+    let chars: Vec<char> = stem.chars().collect();
+    let mut result = String::with_capacity(stem.len() * 2);
+    for (i, &c) in chars.iter().enumerate() {
+        result.push(if c == 'l' { 'r' } else { c });
+        let next_is_consonant = chars.get(i + 1).is_some_and(|&next| !is_vowel(next));
+        if !is_vowel(c) && c != 'n' && next_is_consonant {
+            result.push('u');
+        }
+    }
+    result
+}
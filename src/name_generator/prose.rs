@@ -0,0 +1,100 @@
+//! Culture-specific phrasing for written histories
+//!
+//! Each culture dates and joins events in its own voice: how a year is
+//! introduced, what the seasons are called, and how one event leads to the
+//! next. Openings use `{year}` and `{season}` placeholders.
+
+use super::types::Culture;
+
+/// How a culture's chroniclers phrase their histories
+#[derive(Debug, Clone, Copy)]
+pub struct ProseStyle {
+    /// Introductions for an event dated only by year
+    pub year_openings: &'static [&'static str],
+    /// Introduction for an event dated by season and year
+    pub season_opening: &'static str,
+    /// Spring, summer, autumn, winter
+    pub seasons: [&'static str; 4],
+    /// Joins for further events in the same year
+    pub same_year: &'static [&'static str],
+    /// Title of a decade's chapter, with `{decade}`
+    pub decade_heading: &'static str,
+}
+
+const WESTERN: ProseStyle = ProseStyle {
+    year_openings: &["In the year {year}", "In {year}", "In the year of our reckoning {year}"],
+    season_opening: "In the {season} of {year}",
+    seasons: ["spring", "summer", "autumn", "winter"],
+    same_year: &["That same year", "Soon thereafter", "Before the year was out"],
+    decade_heading: "The {decade}s",
+};
+
+const EASTERN: ProseStyle = ProseStyle {
+    year_openings: &["In the year {year}", "When the calendar turned to {year}", "In {year}"],
+    season_opening: "In the {season} of {year}",
+    seasons: ["spring", "summer", "autumn", "winter"],
+    same_year: &["In that same year", "Following this", "While the year still turned"],
+    decade_heading: "The Decade Beginning {decade}",
+};
+
+const NORTHERN: ProseStyle = ProseStyle {
+    year_openings: &["The skalds tell that in {year}", "In {year}", "When the year {year} came"],
+    season_opening: "In the {season} of {year}",
+    seasons: ["thaw", "midsummer", "harvest", "long winter"],
+    same_year: &["Before the snows returned", "That same year", "Not long after"],
+    decade_heading: "The Winters of the {decade}s",
+};
+
+const SOUTHERN: ProseStyle = ProseStyle {
+    year_openings: &["In the year {year}", "Under the sun of {year}", "In {year}"],
+    season_opening: "In the {season} of {year}",
+    seasons: ["planting season", "high summer", "harvest", "rains"],
+    same_year: &["That same year", "Soon after", "Before the harvest was in"],
+    decade_heading: "The {decade}s",
+};
+
+const DESERT: ProseStyle = ProseStyle {
+    year_openings: &["In the year {year}", "When {year} came upon the sands", "In {year}"],
+    season_opening: "In the {season} of {year}",
+    seasons: ["blooming days", "season of heat", "cooling days", "cold nights"],
+    same_year: &["In that same year", "And it came to pass that", "Before the year had turned"],
+    decade_heading: "The Years from {decade}",
+};
+
+const ISLAND: ProseStyle = ProseStyle {
+    year_openings: &["In the year {year}", "When the tides brought {year}", "In {year}"],
+    season_opening: "In the {season} of {year}",
+    seasons: ["calm season", "trade-wind season", "storm season", "dry season"],
+    same_year: &["Within the same turning of the tides", "That same year", "Soon after"],
+    decade_heading: "The Tides of the {decade}s",
+};
+
+const ANCIENT: ProseStyle = ProseStyle {
+    year_openings: &["It is inscribed that in {year}", "In the year {year} of the old count", "In {year}"],
+    season_opening: "In the {season} of {year}",
+    seasons: ["spring", "summer", "autumn", "winter"],
+    same_year: &["In that same year", "Thereafter", "It is further inscribed that"],
+    decade_heading: "Tablet of the {decade}s",
+};
+
+const MYSTICAL: ProseStyle = ProseStyle {
+    year_openings: &["When the stars marked {year}", "In {year}", "In the turning of {year}"],
+    season_opening: "In the {season} of {year}",
+    seasons: ["season of blossoms", "season of light", "season of falling leaves", "season of frost"],
+    same_year: &["Beneath the same stars", "That same year", "As the omens foretold"],
+    decade_heading: "The Portents of the {decade}s",
+};
+
+/// Phrasing used for histories written by `culture`
+pub fn prose_style(culture: Culture) -> &'static ProseStyle {
+    match culture {
+        Culture::Western => &WESTERN,
+        Culture::Eastern => &EASTERN,
+        Culture::Northern => &NORTHERN,
+        Culture::Southern => &SOUTHERN,
+        Culture::Desert => &DESERT,
+        Culture::Island => &ISLAND,
+        Culture::Ancient => &ANCIENT,
+        Culture::Mystical => &MYSTICAL,
+    }
+}
@@ -34,6 +34,20 @@ pub enum NameType {
     Ocean,
     Desert,
     Forest,
+    Waterfall,
+    Canyon,
+    AncientForest,
+    Continent,
+    /// A land's broad division, e.g. "Northern Vales"
+    Region {
+        region: Region,
+        /// Where it lies within its continent, if it lies off to one side
+        bearing: Option<Bearing>,
+    },
+    /// A handful of provinces within a region, e.g. "Thornfold"
+    Area {
+        region: Region,
+    },
 }
 
 /// Cultural/linguistic styles for name generation
@@ -113,6 +127,19 @@ pub enum Region {
     Island,
 }
 
+/// Compass bearing of a place within something larger
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bearing {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
 /// City size categories affecting name generation
 ///
 /// Larger settlements tend to have grander names
@@ -177,6 +204,37 @@ impl Culture {
     }
 }
 
+impl Bearing {
+    /// The nearest of the eight bearings to an angle, counterclockwise from east in radians
+    pub fn from_angle(radians: f32) -> Self {
+        let octant = (radians / std::f32::consts::FRAC_PI_4).round() as i32;
+        match octant.rem_euclid(8) {
+            0 => Bearing::East,
+            1 => Bearing::NorthEast,
+            2 => Bearing::North,
+            3 => Bearing::NorthWest,
+            4 => Bearing::West,
+            5 => Bearing::SouthWest,
+            6 => Bearing::South,
+            _ => Bearing::SouthEast,
+        }
+    }
+
+    /// As it goes before a name, e.g. "Northern"
+    pub fn adjective(self) -> &'static str {
+        match self {
+            Bearing::North => "Northern",
+            Bearing::NorthEast => "Northeastern",
+            Bearing::East => "Eastern",
+            Bearing::SouthEast => "Southeastern",
+            Bearing::South => "Southern",
+            Bearing::SouthWest => "Southwestern",
+            Bearing::West => "Western",
+            Bearing::NorthWest => "Northwestern",
+        }
+    }
+}
+
 impl Default for Culture {
     fn default() -> Self {
         Culture::Western
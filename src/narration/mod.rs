@@ -0,0 +1,21 @@
+//! Narration feed gateway
+//!
+//! An optional, screen-reader-friendly account of the world: major events
+//! become plain dated sentences built from named templates, listed in a
+//! panel (N) and optionally appended to a text file per world. Templates
+//! can be reworded or translated through `NARRATION_TEMPLATES_FILE`.
+//!
+//! # Gateway Pattern
+//!
+//! This is a PURE gateway - no implementations, only module declarations
+//! and controlled exports.
+
+// PRIVATE MODULES
+mod panel;
+mod plugin;
+mod systems;
+mod templates;
+mod types;
+
+// CONTROLLED EXPORTS
+pub use plugin::NarrationPlugin;
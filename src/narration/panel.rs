@@ -0,0 +1,104 @@
+//! Narration panel layout
+
+use bevy::prelude::*;
+use bevy::ui::widget::Label;
+
+use super::types::*;
+use crate::ui::*;
+
+/// Spawn the narration panel along the right edge of the screen
+pub fn spawn_narration_panel(commands: &mut Commands, feed: &NarrationFeed, enabled: bool) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(10.0),
+                top: Val::Px(60.0),
+                width: Val::Px(380.0),
+                max_height: Val::Percent(70.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(UI_BACKGROUND_COLOR),
+            BorderColor::all(UI_BORDER_COLOR),
+            ThemedAccent::Border(UI_BORDER_COLOR),
+            GlobalZIndex(layers::GAME_UI),
+            NarrationPanel,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::SpaceBetween,
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|header| {
+                    header.spawn((
+                        Text::new("Narration"),
+                        TextFont {
+                            font_size: TEXT_SIZE_LARGE,
+                            ..default()
+                        },
+                        TextColor(TEXT_COLOR_HEADER),
+                        Label,
+                    ));
+                    ButtonBuilder::new("Close")
+                        .size(ButtonSize::Small)
+                        .style(ButtonStyle::Secondary)
+                        .with_marker(CloseNarrationButton)
+                        .build(header);
+                });
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(4.0),
+                        overflow: Overflow::scroll_y(),
+                        ..default()
+                    },
+                    NarrationLog,
+                ))
+                .with_children(|log| spawn_narration_lines(log, feed, enabled));
+        });
+}
+
+/// The latest feed lines as labelled text, or a hint when there are none
+pub fn spawn_narration_lines(log: &mut ChildSpawnerCommands, feed: &NarrationFeed, enabled: bool) {
+    let hint = if !enabled {
+        Some("Narration is off. Turn on Narration Feed under Settings > Interface.")
+    } else if feed.lines.is_empty() {
+        Some("Nothing has been narrated yet.")
+    } else {
+        None
+    };
+    if let Some(hint) = hint {
+        log.spawn((
+            Text::new(hint),
+            TextFont {
+                font_size: TEXT_SIZE_NORMAL,
+                ..default()
+            },
+            TextColor(TEXT_COLOR_SECONDARY),
+            Label,
+        ));
+    }
+
+    let skip = feed.lines.len().saturating_sub(NARRATION_PANEL_LINES);
+    for line in feed.lines.iter().skip(skip) {
+        log.spawn((
+            Text::new(line.clone()),
+            TextFont {
+                font_size: TEXT_SIZE_NORMAL,
+                ..default()
+            },
+            TextColor(TEXT_COLOR_PRIMARY),
+            Label,
+        ));
+    }
+}
@@ -0,0 +1,37 @@
+//! Narration plugin
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+use super::systems::*;
+use super::templates::{load_narration_templates, NarrationTemplates};
+use super::types::{narration_enabled, NarrationEvent, NarrationFeed};
+use crate::states::GameState;
+
+define_plugin!(NarrationPlugin {
+    resources: [NarrationFeed, NarrationTemplates],
+
+    messages: [NarrationEvent],
+
+    startup: [load_narration_templates],
+
+    update: [
+        (
+            narrate_world_events.run_if(narration_enabled),
+            record_narration.run_if(narration_enabled),
+            toggle_narration_panel,
+            handle_narration_close,
+            update_narration_panel,
+        )
+            .chain()
+            .run_if(in_state(GameState::InGame))
+    ],
+
+    on_enter: {
+        GameState::LoadingWorld => [reset_narration]
+    },
+
+    on_exit: {
+        GameState::InGame => [reset_narration]
+    }
+});
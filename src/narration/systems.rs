@@ -0,0 +1,238 @@
+//! Turning world events into narration and showing it
+
+use bevy::prelude::*;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use super::panel::{spawn_narration_lines, spawn_narration_panel};
+use super::templates::NarrationTemplates;
+use super::types::*;
+use crate::audio::{AudioCue, AudioEvent};
+use crate::chronicle::ChronicleEvent;
+use crate::nations::{
+    get_structure_name, DeclareWarEvent, GovernmentTransition, Nation, NationFormedEvent, NationRenamed,
+    SignTreatyEvent, TransitionType, TreatyKind,
+};
+use crate::settings::GameSettings;
+use crate::simulation::GameTime;
+use crate::ui::{ShortcutEvent, ShortcutId};
+use crate::world::WorldName;
+
+fn nation_name(nations: &Query<&Nation>, entity: Entity) -> String {
+    nations.get(entity).map_or_else(|_| "an unknown nation".to_string(), |nation| nation.name.clone())
+}
+
+fn transition_template(transition: TransitionType) -> &'static str {
+    match transition {
+        TransitionType::Revolution => "transition_revolution",
+        TransitionType::Reform => "transition_reform",
+        TransitionType::Coup => "transition_coup",
+        TransitionType::Collapse => "transition_collapse",
+        TransitionType::Election => "transition_election",
+        TransitionType::Succession => "transition_succession",
+        TransitionType::ForeignImposed => "transition_foreign_imposed",
+        TransitionType::PopularUprising => "transition_popular_uprising",
+        TransitionType::EliteConspiracy => "transition_elite_conspiracy",
+    }
+}
+
+/// Narrate wars, treaties, and changes of government, name, and nationhood
+pub fn narrate_world_events(
+    mut wars: MessageReader<DeclareWarEvent>,
+    mut treaties: MessageReader<SignTreatyEvent>,
+    mut governments: MessageReader<GovernmentTransition>,
+    mut renames: MessageReader<NationRenamed>,
+    mut formations: MessageReader<NationFormedEvent>,
+    mut chronicle: MessageReader<ChronicleEvent>,
+    nations: Query<&Nation>,
+    templates: Res<NarrationTemplates>,
+    mut narration: MessageWriter<NarrationEvent>,
+) {
+    for event in wars.read() {
+        narration.write(
+            NarrationEvent::new("war_declared")
+                .with("attacker", nation_name(&nations, event.attacker))
+                .with("defender", nation_name(&nations, event.defender)),
+        );
+    }
+
+    for event in treaties.read() {
+        let template = match event.kind {
+            TreatyKind::Peace => "peace_signed",
+            TreatyKind::Alliance => "alliance_signed",
+            TreatyKind::TradePact => "trade_pact_signed",
+            TreatyKind::Tributary => "tributary_signed",
+        };
+        let [first, second] = event.signatories;
+        narration.write(
+            NarrationEvent::new(template)
+                .with("first", nation_name(&nations, first))
+                .with("second", nation_name(&nations, second)),
+        );
+    }
+
+    for event in governments.read() {
+        narration.write(
+            NarrationEvent::new("government_changed")
+                .with("nation", nation_name(&nations, event.nation_entity))
+                .with("government", get_structure_name(&event.to_government))
+                .with("transition", templates.word(transition_template(event.transition_type))),
+        );
+    }
+
+    for event in renames.read() {
+        narration.write(
+            NarrationEvent::new("nation_renamed")
+                .with("old_name", event.old_name.clone())
+                .with("new_name", event.new_name.clone()),
+        );
+    }
+
+    for event in formations.read() {
+        let narrated = if event.absorbed.is_empty() {
+            NarrationEvent::new("nation_formed")
+        } else {
+            NarrationEvent::new("nation_formed_absorbing").with("absorbed", event.absorbed.join(", "))
+        };
+        narration.write(
+            narrated
+                .with("old_name", event.old_name.clone())
+                .with("new_name", event.new_name.clone()),
+        );
+    }
+
+    for event in chronicle.read() {
+        narration.write(NarrationEvent::new("chronicle").with("text", event.text.clone()));
+    }
+}
+
+fn narration_path(name: Option<&WorldName>) -> PathBuf {
+    let stem: String = name
+        .map_or("Unnamed World", |name| name.0.as_str())
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    PathBuf::from(NARRATION_DIRECTORY).join(format!("{}.txt", stem))
+}
+
+fn append_lines(path: &PathBuf, lines: &[String]) -> std::io::Result<()> {
+    fs::create_dir_all(NARRATION_DIRECTORY)?;
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    for line in lines {
+        writeln!(file, "{}", line)?;
+    }
+    Ok(())
+}
+
+/// Date each narration request, add it to the feed, and write it to file
+pub fn record_narration(
+    mut events: MessageReader<NarrationEvent>,
+    mut feed: ResMut<NarrationFeed>,
+    templates: Res<NarrationTemplates>,
+    settings: Res<GameSettings>,
+    game_time: Option<Res<GameTime>>,
+    name: Option<Res<WorldName>>,
+) {
+    let year = game_time.as_ref().map_or(0, |time| time.current_year());
+    let day = game_time.as_ref().map_or(0, |time| time.day_of_year());
+
+    let lines: Vec<String> = events
+        .read()
+        .map(|event| {
+            let sentence = templates.render(&event.template, &event.args);
+            templates.render(
+                "dated",
+                &[
+                    ("year".to_string(), year.to_string()),
+                    ("day".to_string(), day.to_string()),
+                    ("sentence".to_string(), sentence),
+                ],
+            )
+        })
+        .collect();
+    if lines.is_empty() {
+        return;
+    }
+
+    if settings.interface.narration_file {
+        if let Err(e) = append_lines(&narration_path(name.as_deref()), &lines) {
+            if !feed.file_error_reported {
+                warn!("Failed to write narration file: {}", e);
+                feed.file_error_reported = true;
+            }
+        }
+    }
+    for line in lines {
+        feed.push(line);
+    }
+}
+
+/// N opens and closes the narration panel
+pub fn toggle_narration_panel(
+    mut commands: Commands,
+    mut shortcuts: MessageReader<ShortcutEvent>,
+    feed: Res<NarrationFeed>,
+    settings: Res<GameSettings>,
+    panels: Query<Entity, With<NarrationPanel>>,
+    mut audio: MessageWriter<AudioEvent>,
+) {
+    if !shortcuts
+        .read()
+        .any(|event| event.shortcut_id == ShortcutId::ToggleNarration)
+    {
+        return;
+    }
+
+    if panels.is_empty() {
+        spawn_narration_panel(&mut commands, &feed, settings.interface.narration_feed);
+        audio.write(AudioEvent::new(AudioCue::UiOpen));
+    } else {
+        for panel in &panels {
+            commands.entity(panel).despawn();
+        }
+        audio.write(AudioEvent::new(AudioCue::UiClose));
+    }
+}
+
+pub fn handle_narration_close(
+    mut commands: Commands,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<CloseNarrationButton>)>,
+    panels: Query<Entity, With<NarrationPanel>>,
+) {
+    if buttons.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        for panel in &panels {
+            commands.entity(panel).despawn();
+        }
+    }
+}
+
+/// Keep an open panel in step with the feed
+pub fn update_narration_panel(
+    mut commands: Commands,
+    feed: Res<NarrationFeed>,
+    settings: Res<GameSettings>,
+    logs: Query<Entity, With<NarrationLog>>,
+) {
+    if !feed.is_changed() && !settings.is_changed() {
+        return;
+    }
+    for log in &logs {
+        commands.entity(log).despawn_related::<Children>();
+        commands
+            .entity(log)
+            .with_children(|log| spawn_narration_lines(log, &feed, settings.interface.narration_feed));
+    }
+}
+
+/// Each world narrates from a clean feed; the panel closes with the world
+pub fn reset_narration(
+    mut commands: Commands,
+    mut feed: ResMut<NarrationFeed>,
+    panels: Query<Entity, With<NarrationPanel>>,
+) {
+    *feed = NarrationFeed::default();
+    for panel in &panels {
+        commands.entity(panel).despawn();
+    }
+}
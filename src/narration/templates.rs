@@ -0,0 +1,115 @@
+//! Sentence templates for narration
+//!
+//! Every narrated sentence comes from a named template with `{placeholder}`
+//! slots, so the whole feed can be translated or reworded by dropping a RON
+//! map of template names to text in `NARRATION_TEMPLATES_FILE`. Templates
+//! missing from the file keep their English defaults.
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::fs;
+
+/// Optional overrides for the default templates
+pub const NARRATION_TEMPLATES_FILE: &str = "narration/templates.ron";
+
+const DEFAULT_TEMPLATES: &[(&str, &str)] = &[
+    ("dated", "Year {year}, day {day}. {sentence}"),
+    ("war_declared", "{attacker} declared war on {defender}."),
+    ("peace_signed", "{first} and {second} made peace."),
+    ("alliance_signed", "{first} and {second} formed an alliance."),
+    ("trade_pact_signed", "{first} and {second} signed a trade pact."),
+    ("tributary_signed", "{first} agreed to pay tribute to {second}."),
+    ("government_changed", "{nation} became a {government} through {transition}."),
+    ("nation_renamed", "{old_name} is now known as {new_name}."),
+    ("nation_formed", "{old_name} united its people as {new_name}."),
+    ("nation_formed_absorbing", "{old_name} united its people as {new_name}, absorbing {absorbed}."),
+    ("chronicle", "{text}"),
+    ("transition_revolution", "revolution"),
+    ("transition_reform", "peaceful reform"),
+    ("transition_coup", "a military coup"),
+    ("transition_collapse", "collapse"),
+    ("transition_election", "an election"),
+    ("transition_succession", "succession"),
+    ("transition_foreign_imposed", "foreign imposition"),
+    ("transition_popular_uprising", "a popular uprising"),
+    ("transition_elite_conspiracy", "a palace conspiracy"),
+];
+
+/// Named sentence templates used by the narration feed
+#[derive(Resource, Debug, Clone)]
+pub struct NarrationTemplates {
+    templates: HashMap<String, String>,
+}
+
+impl Default for NarrationTemplates {
+    fn default() -> Self {
+        Self {
+            templates: DEFAULT_TEMPLATES
+                .iter()
+                .map(|(name, text)| (name.to_string(), text.to_string()))
+                .collect(),
+        }
+    }
+}
+
+impl NarrationTemplates {
+    /// Fill the named template, falling back to the name when it is unknown
+    pub fn render(&self, name: &str, args: &[(String, String)]) -> String {
+        match self.templates.get(name) {
+            Some(template) => fill(template, args),
+            None => name.to_string(),
+        }
+    }
+
+    /// Text of a template used as a word inside another one
+    pub fn word(&self, name: &str) -> String {
+        self.render(name, &[])
+    }
+}
+
+/// Replace each `{key}` in `template` with its value
+pub fn fill(template: &str, args: &[(String, String)]) -> String {
+    args.iter().fold(template.to_string(), |text, (key, value)| {
+        text.replace(&format!("{{{}}}", key), value)
+    })
+}
+
+/// Apply template overrides from `NARRATION_TEMPLATES_FILE`, if present
+pub fn load_narration_templates(mut templates: ResMut<NarrationTemplates>) {
+    let Ok(text) = fs::read_to_string(NARRATION_TEMPLATES_FILE) else {
+        return;
+    };
+    match ron::from_str::<HashMap<String, String>>(&text) {
+        Ok(overrides) => {
+            info!("Loaded {} narration templates from {}", overrides.len(), NARRATION_TEMPLATES_FILE);
+            templates.templates.extend(overrides);
+        }
+        Err(e) => warn!("Failed to parse {}: {}", NARRATION_TEMPLATES_FILE, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn render_fills_placeholders_and_nests() {
+        let templates = NarrationTemplates::default();
+        let sentence = templates.render(
+            "war_declared",
+            &args(&[("attacker", "Aldoria"), ("defender", "Brennmark")]),
+        );
+        assert_eq!(sentence, "Aldoria declared war on Brennmark.");
+
+        let dated = templates.render(
+            "dated",
+            &args(&[("year", "1204"), ("day", "31"), ("sentence", &sentence)]),
+        );
+        assert_eq!(dated, "Year 1204, day 31. Aldoria declared war on Brennmark.");
+        assert_eq!(templates.render("no_such_template", &[]), "no_such_template");
+    }
+}
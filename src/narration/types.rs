@@ -0,0 +1,69 @@
+//! Narration feed types
+
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+use crate::settings::GameSettings;
+
+/// Lines kept in the feed; older ones remain only in the narration file
+pub const NARRATION_HISTORY: usize = 200;
+
+/// Lines shown in the narration panel, newest last
+pub const NARRATION_PANEL_LINES: usize = 40;
+
+/// Where narration files are written, one per world
+pub const NARRATION_DIRECTORY: &str = "narration";
+
+/// Request to narrate one sentence from a named template
+#[derive(Message, Debug, Clone)]
+pub struct NarrationEvent {
+    pub template: String,
+    pub args: Vec<(String, String)>,
+}
+
+impl NarrationEvent {
+    pub fn new(template: impl Into<String>) -> Self {
+        Self {
+            template: template.into(),
+            args: Vec::new(),
+        }
+    }
+
+    pub fn with(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.args.push((key.into(), value.into()));
+        self
+    }
+}
+
+/// Dated narration sentences for the current world, oldest first
+#[derive(Resource, Debug, Default)]
+pub struct NarrationFeed {
+    pub lines: VecDeque<String>,
+    /// A failed file write has been reported; stay quiet until the next world
+    pub file_error_reported: bool,
+}
+
+impl NarrationFeed {
+    pub fn push(&mut self, line: String) {
+        if self.lines.len() == NARRATION_HISTORY {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(line);
+    }
+}
+
+/// Root of the narration panel
+#[derive(Component)]
+pub struct NarrationPanel;
+
+/// Container the narration lines are listed in
+#[derive(Component)]
+pub struct NarrationLog;
+
+#[derive(Component)]
+pub struct CloseNarrationButton;
+
+/// Run condition: the narration feed is enabled in the interface settings
+pub fn narration_enabled(settings: Res<GameSettings>) -> bool {
+    settings.interface.narration_feed
+}
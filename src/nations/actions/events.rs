@@ -19,9 +19,14 @@ pub enum OwnershipChangeType {
 
 /// Territory ownership has changed (expansion, conquest, etc.)
 /// Triggers relationship rebuilds for neighbor detection and other systems
+///
+/// Only written by the ownership service once a transfer has been applied;
+/// request changes through `OwnershipService` instead of writing this directly.
 #[derive(Debug, Clone, Message)]
 pub struct TerritoryOwnershipChanged {
     pub nation_entity: Entity,
     pub provinces_changed: u32,
     pub change_type: OwnershipChangeType,
+    /// Provinces gained (or, for `Loss`, lost) by `nation_entity`
+    pub provinces: Vec<Entity>,
 }
@@ -7,24 +7,23 @@
 
 use bevy::prelude::*;
 use super::resolution::NationActionEvent;
-use crate::nations::{Nation, NationHistory};
+use crate::math::Money;
+use crate::nations::{Nation, NationHistory, OwnershipService};
 use crate::relationships::ControlledBy;
-use crate::world::{ProvinceEntityOrder, MapMode, CachedOverlayColors};
+use crate::world::ProvinceEntityOrder;
 use crate::simulation::GameTime;
 
 /// Execute expansion events - THIS IS WHERE PROVINCES ACTUALLY CHANGE HANDS
 ///
-/// Uses ControlledBy relationships for ownership and reactive cache invalidation
+/// Claims go through the ownership service, which updates relationships,
+/// storage, overlays, and province statistics together
 pub fn execute_expansion_events(
-    mut commands: Commands,
     mut messages: MessageReader<NationActionEvent>,
-    mut ownership_events: MessageWriter<super::TerritoryOwnershipChanged>,
+    mut ownership: OwnershipService,
     province_entity_order: Option<Res<ProvinceEntityOrder>>,
     controlled_by_query: Query<&ControlledBy>,
-    mut overlay_colors: ResMut<CachedOverlayColors>,
     mut nations_query: Query<(&mut Nation, &mut NationHistory)>,
     game_time: Res<GameTime>,
-    current_mode: Res<MapMode>,
 ) {
     let Some(entity_order) = province_entity_order else {
         return;
@@ -43,42 +42,26 @@ pub fn execute_expansion_events(
                 continue;
             };
 
-            // Take ownership of target provinces using ControlledBy relationship
-            let mut provinces_claimed = 0;
-
+            // Only claim unclaimed provinces (no ControlledBy component)
+            let mut claimed = Vec::new();
             for province_id in target_provinces {
                 let province_idx = province_id.value() as usize;
                 if let Some(province_entity) = entity_order.get(province_idx) {
-                    // Only claim unclaimed provinces (no ControlledBy component)
                     if controlled_by_query.get(province_entity).is_err() {
-                        // Insert ControlledBy relationship - Bevy auto-updates Controls on nation!
-                        commands.entity(province_entity).insert(ControlledBy(*nation_entity));
-                        provinces_claimed += 1;
+                        claimed.push(province_entity);
 
                         debug!("{} claims province {} (entity {:?})",
                                nation_name, province_id.value(), province_entity);
                     }
                 }
             }
+            let provinces_claimed = claimed.len() as u32;
 
             if provinces_claimed > 0 {
                 info!("{} claims {} new provinces through expansion (pressure: {:.1})",
                       nation_name, provinces_claimed, pressure_level);
 
-                // REACTIVE CACHE INVALIDATION: Directly invalidate overlay cache
-                overlay_colors.cache.remove(&MapMode::Political);
-                info!("Ownership changed, invalidated Political map cache");
-
-                if *current_mode == MapMode::Political {
-                    debug!("Currently viewing Political mode - will recalculate on next frame");
-                }
-
-                // Fire territory ownership changed event for neighbor relationship rebuild
-                ownership_events.write(super::TerritoryOwnershipChanged {
-                    nation_entity: *nation_entity,
-                    provinces_changed: provinces_claimed,
-                    change_type: super::OwnershipChangeType::Expansion,
-                });
+                ownership.transfer(claimed, *nation_entity, super::OwnershipChangeType::Expansion);
 
                 // Record historical event
                 use crate::nations::history::{HistoricalEvent, AcquisitionMethod};
@@ -89,12 +72,11 @@ pub fn execute_expansion_events(
                     method: AcquisitionMethod::Settlement,
                 });
 
-                // Update expansion statistics
-                history.provinces_gained += provinces_claimed;
+                // Update expansion statistics (provinces gained is tallied by the service)
                 history.expansion_attempts += 1;
 
                 // Small treasury cost for expansion administration
-                nation.treasury -= provinces_claimed as f32 * 50.0;
+                nation.treasury -= Money::from_int(provinces_claimed as i64 * 50);
 
                 // Small stability boost from successful expansion
                 nation.stability = (nation.stability + 0.02).min(1.0);
@@ -108,7 +90,7 @@ pub fn execute_expansion_events(
 // REMOVED: force_overlay_refresh_on_expansion
 //
 // This polling system ran EVERY frame checking for ownership changes.
-// Replaced with reactive cache invalidation in execute_expansion_events()
+// Replaced with reactive cache invalidation in apply_province_transfers()
 // that invalidates immediately when we KNOW ownership changed.
 //
 // Performance improvement: No longer wastes CPU cycles polling every frame!
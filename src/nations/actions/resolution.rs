@@ -4,7 +4,9 @@
 //! including expansion, taxation, military recruitment, and reforms.
 
 use bevy::prelude::*;
+use crate::ai::{score_considerations, AiBehavior, AiDrive, Consideration, ResponseCurve};
 use crate::diagnostics::{log_nation_decision, log_nation_state_change};
+use crate::math::{Fixed32, Money};
 use crate::simulation::{PressureType, PressureLevel};
 use crate::world::{ProvinceId, ProvinceStorage};
 use crate::nations::{Nation, NationHistory};
@@ -119,6 +121,7 @@ pub fn resolve_nation_actions(
         Entity
     )>,
     province_storage: Res<ProvinceStorage>,
+    behavior: Res<AiBehavior>,
     mut messages: MessageWriter<NationActionEvent>,
     time: Res<Time>,
 ) {
@@ -144,6 +147,7 @@ pub fn resolve_nation_actions(
                         &history,
                         level,
                         &province_storage,
+                        &behavior,
                         &mut messages,
                     );
                 }
@@ -156,6 +160,7 @@ pub fn resolve_nation_actions(
                         &history,
                         level,
                         None, // No raid target available in this context
+                        &behavior,
                         &mut messages,
                     );
                 }
@@ -196,6 +201,7 @@ pub fn handle_population_pressure(
     history: &NationHistory,
     pressure: PressureLevel,
     province_storage: &ProvinceStorage,
+    behavior: &AiBehavior,
     messages: &mut MessageWriter<NationActionEvent>,
 ) {
     info!(
@@ -204,7 +210,7 @@ pub fn handle_population_pressure(
     );
 
     // Determine expansion aggressiveness based on history and personality
-    let expansion_desire = calculate_expansion_desire(nation, history, pressure);
+    let expansion_desire = calculate_expansion_desire(nation, history, pressure, behavior);
 
     log_nation_decision(
         nation_entity.index(),
@@ -268,6 +274,7 @@ pub fn handle_economic_pressure(
     history: &NationHistory,
     pressure: PressureLevel,
     raid_target: Option<Entity>,
+    behavior: &AiBehavior,
     messages: &mut MessageWriter<NationActionEvent>,
 ) {
     info!(
@@ -276,19 +283,28 @@ pub fn handle_economic_pressure(
     );
 
     // Decide between raising taxes or raiding based on personality and history
-    let is_aggressive = nation.personality.aggression > 0.3;
-    let has_military_strength = nation.military_strength > 0.6;
     let recent_defeats = history.has_recent_defeats();
+    let raid_utility = behavior.weigh(AiDrive::Aggression, score_considerations(&[
+        Consideration::new(nation.personality.aggression, ResponseCurve::Logistic { midpoint: 0.3, steepness: 12.0 }),
+        Consideration::new(nation.military_strength, ResponseCurve::Logistic { midpoint: 0.6, steepness: 12.0 }),
+        // Recently beaten nations do not go raiding
+        Consideration::new(if recent_defeats { 0.0 } else { 1.0 }, ResponseCurve::Step { threshold: 0.5 }),
+    ]));
+    // Taxes are attractive while there is headroom below the 50% cap
+    let tax_utility = score_considerations(&[Consideration::new(
+        nation.tax_rate / 0.5,
+        ResponseCurve::Linear { slope: -0.6, offset: 0.8 },
+    )]);
 
     log_nation_decision(
         nation_entity.index(),
         &nation.name,
         "Economic Pressure Analysis",
-        &format!("Aggression: {:.2}, Military: {:.2}, Recent defeats: {}",
-                 nation.personality.aggression, nation.military_strength, recent_defeats)
+        &format!("Aggression: {:.2}, Military: {:.2}, Recent defeats: {}, Raid utility: {:.2}, Tax utility: {:.2}",
+                 nation.personality.aggression, nation.military_strength, recent_defeats, raid_utility, tax_utility)
     );
 
-    if is_aggressive && has_military_strength && !recent_defeats {
+    if raid_utility > tax_utility {
         // Attempt to raid neighbors - use provided target
         if let Some(target_entity) = raid_target {
             log_nation_decision(
@@ -344,7 +360,7 @@ pub fn handle_economic_pressure(
 
         // Actually update the tax rate and treasury
         nation.tax_rate = new_rate;
-        nation.treasury *= 1.1; // Temporary treasury boost from increased taxation
+        nation.treasury *= Fixed32::from_ratio(11, 10); // Temporary treasury boost from increased taxation
         info!("{} raises taxes from {:.1}% to {:.1}%", nation.name, old_rate * 100.0, new_rate * 100.0);
     }
 }
@@ -367,7 +383,7 @@ pub fn handle_military_pressure(
     );
 
     // Decide between building army or seeking alliance
-    let can_afford_army = nation.treasury > 5000.0;
+    let can_afford_army = nation.treasury > Money::from_int(5000);
     let is_diplomatic = nation.personality.diplomacy > 0.3;
 
     if can_afford_army && !is_diplomatic {
@@ -382,7 +398,7 @@ pub fn handle_military_pressure(
         });
 
         nation.military_strength += 0.1;
-        nation.treasury -= units_to_recruit as f32 * 100.0;
+        nation.treasury -= Money::from_int(units_to_recruit as i64 * 100);
 
         info!("{} recruits {} new military units", nation.name, units_to_recruit);
     } else {
@@ -417,7 +433,7 @@ pub fn handle_legitimacy_pressure(
 
     // Decide between reforms and public works based on ruler personality
     let is_reformist = history.ruler.personality.administrative > 0.3;
-    let can_afford_projects = nation.treasury > 3000.0;
+    let can_afford_projects = nation.treasury > Money::from_int(3000);
 
     if is_reformist {
         // Implement reforms
@@ -443,17 +459,18 @@ pub fn handle_legitimacy_pressure(
             pressure_level: pressure.value(),
         });
 
-        nation.treasury -= 1000.0;
+        nation.treasury -= Money::from_int(1000);
         nation.stability += 0.03;
         info!("{} builds {:?} to boost public support", nation.name, project);
     }
 }
 
-/// Calculate how much a nation wants to expand
+/// Calculate how much a nation wants to expand, scaled by the world's behavior pack
 fn calculate_expansion_desire(
     nation: &Nation,
     history: &NationHistory,
     pressure: PressureLevel,
+    behavior: &AiBehavior,
 ) -> f32 {
     let mut desire = pressure.value();
 
@@ -476,7 +493,7 @@ fn calculate_expansion_desire(
     desire += history.ruler.personality.ambitious * 0.2;
     desire += history.ruler.personality.martial * 0.1;
 
-    desire.clamp(0.0, 1.0)
+    behavior.weigh(AiDrive::Expansion, desire).clamp(0.0, 1.0)
 }
 
 /// Find suitable provinces for expansion
@@ -540,7 +557,7 @@ fn choose_reform_type(nation: &Nation, history: &NationHistory) -> ReformType {
     // Choose reform based on what's most needed
     if nation.military_strength < 0.3 {
         ReformType::MilitaryReform
-    } else if nation.treasury < 1000.0 {
+    } else if nation.treasury < Money::from_int(1000) {
         ReformType::TaxReform
     } else if history.ruler.personality.administrative > 0.5 {
         ReformType::AdministrativeReform
@@ -553,7 +570,7 @@ fn choose_reform_type(nation: &Nation, history: &NationHistory) -> ReformType {
 fn choose_public_work(nation: &Nation, history: &NationHistory) -> PublicWorkType {
     if nation.military_strength < 0.4 {
         PublicWorkType::Fortification
-    } else if nation.treasury < 2000.0 {
+    } else if nation.treasury < Money::from_int(2000) {
         PublicWorkType::Market
     } else if history.ruler.legitimacy < 0.5 {
         PublicWorkType::Monument
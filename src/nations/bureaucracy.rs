@@ -0,0 +1,167 @@
+//! Bureaucratic capacity - how much territory a state can actually govern
+//!
+//! Each nation's bureaucracy can administer a limited number of provinces.
+//! Capacity grows with literacy (clerks to staff it) and institutions
+//! (procedures that outlive the people running them). A state that holds more
+//! provinces than it can administer is overextended: taxes leak before
+//! reaching the treasury, distant officials slip into corruption, reforms
+//! take longer to reach the provinces, and stability drains until the state
+//! shrinks back to what it can hold or falls apart.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::governance::Governance;
+use super::types::Nation;
+use crate::relationships::Controls;
+use crate::simulation::NewYearEvent;
+
+/// Provinces any state can administer through personal rule alone
+const BASE_CAPACITY: f32 = 6.0;
+/// Capacity added by full literacy
+const LITERACY_CAPACITY: f32 = 40.0;
+/// Capacity added by fully developed institutions
+const INSTITUTION_CAPACITY: f32 = 30.0;
+/// Literacy reached through institutions alone
+const INSTITUTION_LITERACY: f32 = 0.4;
+/// Literacy added per level of technology
+const TECHNOLOGY_LITERACY: f32 = 0.08;
+/// Share of the gap to target literacy closed each year
+const LITERACY_DRIFT: f32 = 0.05;
+/// Yearly stability lost per unit of overextension
+const OVEREXTENSION_UNREST: f32 = 0.05;
+/// Revenue lost per unit of overextension
+const OVEREXTENSION_LEAKAGE: f32 = 0.3;
+/// Most of its revenue a state can lose to leakage
+const MAX_TAX_LEAKAGE: f32 = 0.8;
+
+/// A nation's administrative reach
+#[derive(Component, Debug, Clone, Reflect, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct Bureaucracy {
+    /// Share of officials and subjects who can read (0.0-1.0)
+    pub literacy: f32,
+    /// Provinces the bureaucracy can administer
+    pub capacity: f32,
+    /// Provinces the state holds
+    pub provinces: usize,
+    /// How far holdings exceed capacity (0.0 = within capacity, 1.0 = double)
+    pub overextension: f32,
+    /// Share of collected taxes that never reaches the treasury
+    pub tax_leakage: f32,
+    /// How quickly policy reaches the provinces (1.0 = full speed)
+    pub implementation_speed: f32,
+}
+
+impl Default for Bureaucracy {
+    fn default() -> Self {
+        Self {
+            literacy: 0.1,
+            capacity: BASE_CAPACITY,
+            provinces: 0,
+            overextension: 0.0,
+            tax_leakage: 0.0,
+            implementation_speed: 1.0,
+        }
+    }
+}
+
+impl Bureaucracy {
+    /// Whether the state holds more than it can govern
+    pub fn is_overextended(&self) -> bool {
+        self.overextension > 0.0
+    }
+}
+
+/// Provinces a bureaucracy can administer
+///
+/// `efficiency` scales the result; laws and administrative reforms move it
+/// around its default of 0.6.
+pub fn administrative_capacity(literacy: f32, institution_strength: f32, efficiency: f32) -> f32 {
+    let staffed = BASE_CAPACITY + literacy * LITERACY_CAPACITY + institution_strength * INSTITUTION_CAPACITY;
+    staffed * (0.5 + efficiency.clamp(0.0, 2.0) * 0.8)
+}
+
+/// How far holdings exceed capacity
+pub fn overextension(provinces: usize, capacity: f32) -> f32 {
+    if capacity <= 0.0 {
+        return if provinces > 0 { 1.0 } else { 0.0 };
+    }
+    (provinces as f32 / capacity - 1.0).max(0.0)
+}
+
+/// Share of taxes lost between the provinces and the treasury
+pub fn tax_leakage(overextension: f32) -> f32 {
+    (overextension * OVEREXTENSION_LEAKAGE).min(MAX_TAX_LEAKAGE)
+}
+
+/// Yearly reassessment of every nation's bureaucracy against its holdings
+pub fn assess_bureaucratic_capacity(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut nations_query: Query<(
+        Entity,
+        &mut Nation,
+        &Governance,
+        Option<&Controls>,
+        Option<&mut Bureaucracy>,
+    )>,
+) {
+    if year_events.read().count() == 0 {
+        return;
+    }
+
+    for (entity, mut nation, governance, controls, bureaucracy) in &mut nations_query {
+        let mut assessed = bureaucracy.as_deref().cloned().unwrap_or_default();
+
+        let target_literacy = (governance.institution_strength * INSTITUTION_LITERACY
+            + nation.technology_level as f32 * TECHNOLOGY_LITERACY)
+            .clamp(0.05, 1.0);
+        assessed.literacy += (target_literacy - assessed.literacy) * LITERACY_DRIFT;
+
+        assessed.capacity = administrative_capacity(
+            assessed.literacy,
+            governance.institution_strength,
+            governance.legitimacy_factors.administrative_efficiency,
+        );
+        assessed.provinces = controls.map_or(0, |controls| controls.provinces().len());
+        assessed.overextension = overextension(assessed.provinces, assessed.capacity);
+        assessed.tax_leakage = tax_leakage(assessed.overextension);
+        assessed.implementation_speed = 1.0 / (1.0 + assessed.overextension);
+
+        nation.stability = (nation.stability - assessed.overextension * OVEREXTENSION_UNREST).clamp(0.0, 1.0);
+
+        match bureaucracy {
+            Some(mut bureaucracy) => *bureaucracy = assessed,
+            None => {
+                commands.entity(entity).insert(assessed);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literacy_and_institutions_raise_capacity() {
+        let illiterate = administrative_capacity(0.1, 0.2, 0.6);
+        let lettered = administrative_capacity(0.8, 0.2, 0.6);
+        let institutional = administrative_capacity(0.1, 0.9, 0.6);
+
+        assert!(lettered > illiterate);
+        assert!(institutional > illiterate);
+    }
+
+    #[test]
+    fn holding_more_than_capacity_leaks_taxes() {
+        assert_eq!(overextension(10, 20.0), 0.0);
+        assert_eq!(tax_leakage(overextension(10, 20.0)), 0.0);
+
+        let overextended = overextension(40, 20.0);
+        assert!((overextended - 1.0).abs() < 1e-6);
+        assert!(tax_leakage(overextended) > 0.0);
+        assert!(tax_leakage(10.0) <= MAX_TAX_LEAKAGE);
+    }
+}
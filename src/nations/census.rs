@@ -0,0 +1,235 @@
+//! Censuses - what governments believe about their own provinces
+//!
+//! Governments never see their provinces directly. Every few years the
+//! bureaucracy counts them, and what it counts is only as good as the clerks
+//! doing the counting: literate, honest, well-staffed bureaucracies come close
+//! to the truth, while overextended or corrupt ones return figures that are
+//! far off. Between counts the figures go stale, and provinces gained since
+//! the last count are guessed at from the national average.
+//!
+//! AI pressure evaluation works from these believed figures, so nations
+//! misjudge their own overcrowding and food supply. The Census Error map mode
+//! shows where belief and truth have drifted apart.
+
+use bevy::prelude::*;
+use rand::Rng;
+use std::collections::HashMap;
+
+use super::bureaucracy::Bureaucracy;
+use super::corruption::Corruption;
+use super::types::NationId;
+use crate::ai::{decision_rng, DecisionDomain};
+use crate::simulation::NewYearEvent;
+use crate::world::{Agriculture, MapMode, Province, ProvinceStorage, WorldSeed};
+
+/// Years between censuses for the least capable bureaucracy
+const MAX_CENSUS_INTERVAL: u32 = 10;
+/// Years between censuses for a fully literate bureaucracy
+const MIN_CENSUS_INTERVAL: u32 = 4;
+/// Largest error a census can make in either direction, at zero accuracy
+const MAX_COUNT_ERROR: f32 = 0.5;
+
+/// What a census recorded for one province
+#[derive(Debug, Clone, Copy, Reflect)]
+pub struct CensusRecord {
+    pub population: u32,
+    pub agriculture: f32,
+}
+
+/// A nation's last count of its provinces
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct Census {
+    /// Year of the last census
+    pub year: Option<u32>,
+    /// How close the last census came to the truth (0.0-1.0)
+    pub accuracy: f32,
+    /// Recorded figures, by province id
+    pub records: HashMap<u32, CensusRecord>,
+}
+
+impl Census {
+    /// Whether a census is due, given how often the bureaucracy can manage one
+    pub fn is_due(&self, year: u32, interval: u32) -> bool {
+        self.year.is_none_or(|last| year.saturating_sub(last) >= interval)
+    }
+
+    /// Average recorded population, used for provinces never counted
+    fn average_population(&self) -> u32 {
+        let count = self.records.len().max(1) as u64;
+        (self.records.values().map(|record| record.population as u64).sum::<u64>() / count) as u32
+    }
+
+    /// A province as the government believes it to be
+    pub fn believed(&self, province: &Province) -> Province {
+        let mut believed = province.clone();
+        match self.records.get(&province.id.value()) {
+            Some(record) => {
+                believed.population = record.population;
+                believed.agriculture = Agriculture::new(record.agriculture);
+            }
+            None if !self.records.is_empty() => {
+                believed.population = self.average_population();
+            }
+            // Before any census the government takes what it sees at face value
+            None => {}
+        }
+        believed
+    }
+
+    /// How far the believed population is off, as a share of the truth
+    pub fn population_error(&self, province: &Province) -> Option<f32> {
+        let record = self.records.get(&province.id.value())?;
+        let truth = province.population.max(1) as f32;
+        Some((record.population as f32 - truth) / truth)
+    }
+}
+
+/// How accurately a bureaucracy counts
+pub fn census_accuracy(literacy: f32, overextension: f32, corruption: f32) -> f32 {
+    (0.3 + literacy * 0.7 - overextension * 0.3 - corruption * 0.4).clamp(0.0, 1.0)
+}
+
+/// Years between censuses for a bureaucracy
+pub fn census_interval(literacy: f32) -> u32 {
+    let span = (MAX_CENSUS_INTERVAL - MIN_CENSUS_INTERVAL) as f32;
+    MAX_CENSUS_INTERVAL - (literacy.clamp(0.0, 1.0) * span).round() as u32
+}
+
+/// Count one province; the errors are draws in -1.0..=1.0
+pub fn count_province(province: &Province, accuracy: f32, population_error: f32, agriculture_error: f32) -> CensusRecord {
+    let spread = (1.0 - accuracy.clamp(0.0, 1.0)) * MAX_COUNT_ERROR;
+    CensusRecord {
+        population: (province.population as f32 * (1.0 + population_error * spread)).max(0.0) as u32,
+        agriculture: province.agriculture.value() * (1.0 + agriculture_error * spread),
+    }
+}
+
+/// Believed-versus-true population error per province, for the Census Error map mode
+#[derive(Resource, Debug, Default)]
+pub struct CensusDiscrepancy {
+    /// Signed relative error by province index; `None` where nobody has counted
+    pub by_province: Vec<Option<f32>>,
+}
+
+/// Take censuses in nations whose last count has come due
+pub fn take_censuses(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    province_storage: Option<Res<ProvinceStorage>>,
+    world_seed: Option<Res<WorldSeed>>,
+    mut discrepancy: ResMut<CensusDiscrepancy>,
+    mut map_mode: ResMut<MapMode>,
+    mut nations_query: Query<(
+        Entity,
+        &NationId,
+        Option<&Bureaucracy>,
+        Option<&Corruption>,
+        Option<&mut Census>,
+    )>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+    let Some(storage) = province_storage else {
+        return;
+    };
+    let seed = world_seed.map_or(0, |seed| seed.0);
+
+    let mut provinces_by_owner: HashMap<Entity, Vec<&Province>> = HashMap::new();
+    for province in &storage.provinces {
+        if let Some(owner) = province.owner_entity {
+            provinces_by_owner.entry(owner).or_default().push(province);
+        }
+    }
+
+    let mut counted: HashMap<Entity, Census> = HashMap::new();
+    for (entity, nation_id, bureaucracy, corruption, census) in &mut nations_query {
+        let literacy = bureaucracy.map_or(0.1, |bureaucracy| bureaucracy.literacy);
+        let due = census
+            .as_deref()
+            .is_none_or(|census| census.is_due(year, census_interval(literacy)));
+        if !due {
+            if let Some(census) = census {
+                counted.insert(entity, census.clone());
+            }
+            continue;
+        }
+
+        let accuracy = census_accuracy(
+            literacy,
+            bureaucracy.map_or(0.0, |bureaucracy| bureaucracy.overextension),
+            corruption.map_or(0.0, |corruption| corruption.level),
+        );
+        let mut rng = decision_rng(seed, DecisionDomain::Governance, nation_id.value(), 5, year);
+        let records = provinces_by_owner
+            .get(&entity)
+            .map(|provinces| {
+                provinces
+                    .iter()
+                    .map(|province| {
+                        let record = count_province(
+                            province,
+                            accuracy,
+                            rng.gen_range(-1.0..=1.0),
+                            rng.gen_range(-1.0..=1.0),
+                        );
+                        (province.id.value(), record)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let updated = Census {
+            year: Some(year),
+            accuracy,
+            records,
+        };
+        counted.insert(entity, updated.clone());
+
+        match census {
+            Some(mut census) => *census = updated,
+            None => {
+                commands.entity(entity).insert(updated);
+            }
+        }
+    }
+
+    discrepancy.by_province = storage
+        .provinces
+        .iter()
+        .map(|province| {
+            let census = counted.get(&province.owner_entity?)?;
+            census.population_error(province)
+        })
+        .collect();
+    if *map_mode == MapMode::CensusError {
+        map_mode.set_changed();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capable_bureaucracies_count_closer_and_more_often() {
+        let capable = census_accuracy(0.9, 0.0, 0.1);
+        let overstretched = census_accuracy(0.2, 1.0, 0.6);
+        assert!(capable > overstretched);
+        assert!(census_interval(0.9) < census_interval(0.1));
+    }
+
+    #[test]
+    fn believed_figures_come_from_the_last_count() {
+        let province = Province {
+            population: 10_000,
+            ..Default::default()
+        };
+        assert_eq!(count_province(&province, 1.0, 1.0, -1.0).population, 10_000);
+
+        let mut census = Census::default();
+        census.records.insert(province.id.value(), count_province(&province, 0.0, 1.0, 0.0));
+        assert_eq!(census.believed(&province).population, 15_000);
+        assert!(census.population_error(&province).is_some_and(|error| (error - 0.5).abs() < 1e-4));
+    }
+}
@@ -0,0 +1,210 @@
+//! City names that drift with the culture ruling them
+//!
+//! Every settled province has a city named in its native culture. When a
+//! ruler of another culture holds the province for [`CITY_RENAME_YEARS`],
+//! the city takes an adapted form of its name in the ruler's naming style.
+//! Older names are kept as aliases so the city can still be found by them.
+
+use bevy::prelude::*;
+use crate::name_generator::{adapt_place_name, CitySize, Culture, NameGenerator, NameType};
+use crate::nations::{Nation, ProvinceCores};
+use crate::simulation::NewYearEvent;
+use crate::world::{ProvinceStorage, WorldSeed};
+
+/// Years of continuous foreign-culture rule before a city is renamed (about three generations)
+pub const CITY_RENAME_YEARS: u16 = 75;
+
+/// Provinces below this population have no city worth naming
+const MIN_CITY_POPULATION: u32 = 100;
+
+/// A name a city used to go by
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CityAlias {
+    pub name: String,
+    pub culture: Culture,
+    /// Year the name fell out of use
+    pub until_year: u32,
+}
+
+/// The current and former names of one city
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CityName {
+    pub name: String,
+    /// Culture whose naming patterns produced the current name
+    pub culture: Culture,
+    /// Former names, oldest first
+    pub aliases: Vec<CityAlias>,
+}
+
+impl CityName {
+    /// Adopt the form of the name used by `culture`, keeping the old one as an alias
+    pub fn adopt_culture(&mut self, culture: Culture, year: u32) {
+        let adapted = adapt_place_name(&self.name, culture);
+        let previous = std::mem::replace(&mut self.name, adapted);
+        self.aliases.push(CityAlias {
+            name: previous,
+            culture: self.culture,
+            until_year: year,
+        });
+        self.culture = culture;
+    }
+
+    /// Whether the city is or was known by `query` (case-insensitive)
+    pub fn is_known_as(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        self.name.to_lowercase().contains(&query)
+            || self.aliases.iter().any(|alias| alias.name.to_lowercase().contains(&query))
+    }
+}
+
+/// City names for every province, indexed by province order
+#[derive(Resource, Debug, Default)]
+pub struct CityNames {
+    cities: Vec<Option<CityName>>,
+}
+
+impl CityNames {
+    /// City in a province, if it has one
+    pub fn get(&self, index: usize) -> Option<&CityName> {
+        self.cities.get(index).and_then(Option::as_ref)
+    }
+
+    /// Province indices of cities matching a current or former name
+    pub fn search<'a>(&'a self, query: &'a str) -> impl Iterator<Item = usize> + 'a {
+        self.cities
+            .iter()
+            .enumerate()
+            .filter(move |(_, city)| city.as_ref().is_some_and(|city| city.is_known_as(query)))
+            .map(|(index, _)| index)
+    }
+
+    /// Check if cities have not been named for this world
+    pub fn is_empty(&self) -> bool {
+        self.cities.is_empty()
+    }
+
+    /// Drop all city names (new world or loaded save)
+    pub fn clear(&mut self) {
+        self.cities.clear();
+    }
+}
+
+/// City size for a province population
+fn city_size(population: u32) -> CitySize {
+    match population {
+        0..=999 => CitySize::Hamlet,
+        1_000..=9_999 => CitySize::Village,
+        10_000..=49_999 => CitySize::Town,
+        50_000..=249_999 => CitySize::City,
+        _ => CitySize::Metropolis,
+    }
+}
+
+/// Forget the previous world's city names once a new world has loaded
+pub fn clear_city_names(mut city_names: ResMut<CityNames>) {
+    city_names.clear();
+}
+
+/// Name every settled province's city in its native culture
+pub fn initialize_city_names(
+    mut city_names: ResMut<CityNames>,
+    province_storage: Res<ProvinceStorage>,
+    world_seed: Option<Res<WorldSeed>>,
+    nations_query: Query<&Nation>,
+) {
+    if !city_names.is_empty() {
+        return;
+    }
+
+    let mut name_gen = NameGenerator::with_seed(world_seed.map_or(0, |seed| seed.0 as u64));
+
+    city_names.cities = province_storage
+        .provinces
+        .iter()
+        .map(|province| {
+            if province.population < MIN_CITY_POPULATION {
+                return None;
+            }
+            let owner_culture = || {
+                let owner = province.owner_entity?;
+                nations_query.get(owner).ok().map(|nation| nation.culture)
+            };
+            let culture = province.culture.or_else(owner_culture)?;
+            Some(CityName {
+                name: name_gen.generate(NameType::City {
+                    size: city_size(province.population),
+                    culture,
+                }),
+                culture,
+                aliases: Vec::new(),
+            })
+        })
+        .collect();
+
+    info!(
+        "Named {} cities",
+        city_names.cities.iter().filter(|city| city.is_some()).count()
+    );
+}
+
+/// Rename cities held long enough by a ruler of another culture
+pub fn update_city_names(
+    mut year_events: MessageReader<NewYearEvent>,
+    mut city_names: ResMut<CityNames>,
+    cores: Res<ProvinceCores>,
+    nations_query: Query<&Nation>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+
+    let mut renamed = 0;
+    for (index, city) in city_names.cities.iter_mut().enumerate() {
+        let (Some(city), Some(core)) = (city.as_mut(), cores.get(index)) else {
+            continue;
+        };
+        // Checked on the exact anniversary so each conquest renames at most once
+        if core.years_ruled != CITY_RENAME_YEARS {
+            continue;
+        }
+        let Some(ruler_culture) = core
+            .ruler
+            .and_then(|ruler| nations_query.get(ruler).ok())
+            .map(|nation| nation.culture)
+        else {
+            continue;
+        };
+        if ruler_culture != city.culture {
+            let old_name = city.name.clone();
+            city.adopt_culture(ruler_culture, year);
+            debug!("{} is now known as {}", old_name, city.name);
+            renamed += 1;
+        }
+    }
+
+    if renamed > 0 {
+        info!("{} cities took new names under foreign rule", renamed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adopted_names_keep_the_old_name_as_alias() {
+        let mut city = CityName {
+            name: "Ashford".to_string(),
+            culture: Culture::Western,
+            aliases: Vec::new(),
+        };
+
+        city.adopt_culture(Culture::Northern, 1200);
+
+        assert_ne!(city.name, "Ashford");
+        assert_eq!(city.name, adapt_place_name("Ashford", Culture::Northern));
+        assert_eq!(city.culture, Culture::Northern);
+        assert_eq!(city.aliases[0].name, "Ashford");
+        assert!(city.is_known_as("ashford"));
+    }
+}
@@ -0,0 +1,231 @@
+//! Contact policy - how open a nation is to the outside world
+//!
+//! A nation may restrict foreign trade to a few licensed ports, or seal its
+//! borders and harbors entirely. Closing up keeps out foreign goods,
+//! migrants, and ideas alike: trade revenue falls and trade pacts go
+//! unsigned, refugees are turned away (and with them the cultures they would
+//! have settled), ideas from more advanced neighbors arrive slowly, and a
+//! sealed nation sends no ships across the ocean.
+//!
+//! AI courts close up under inward-looking ideologies or after traumatic
+//! contact - plague from abroad, foreign colonies on their shores - and
+//! reopen as the memory fades. A trading power can make forced opening its
+//! war goal; the defeated nation must then keep its ports open for as long
+//! as the peace lasts.
+
+use bevy::prelude::*;
+
+use super::diplomacy::{Treaty, TreatyClause};
+use super::governance::{Governance, GovernmentType};
+use super::index::NationIndex;
+use super::new_world::NewWorlds;
+use super::pandemic::Pandemics;
+use super::types::Nation;
+use crate::ai::{score_considerations, Consideration, ResponseCurve};
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::simulation::NewYearEvent;
+
+/// Years a policy holds before the court reconsiders it
+const MIN_POLICY_YEARS: u32 = 15;
+/// Isolation utility at which a court restricts or seals its ports
+const RESTRICT_THRESHOLD: f32 = 0.35;
+const SEAL_THRESHOLD: f32 = 0.6;
+/// Trauma from a year of foreign plague, and from foreign colonies on home shores
+const PLAGUE_TRAUMA: f32 = 0.3;
+const COLONY_TRAUMA: f32 = 0.1;
+/// Share of trauma remembered from one year to the next
+const TRAUMA_MEMORY: f32 = 0.9;
+
+/// How far a nation lets foreigners in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Reflect)]
+pub enum ContactPolicy {
+    #[default]
+    Open,
+    /// Foreign trade only through licensed ports; borders closed to settlers
+    Restricted,
+    /// Borders and ports closed to foreigners
+    Sealed,
+}
+
+impl ContactPolicy {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ContactPolicy::Open => "Open",
+            ContactPolicy::Restricted => "Restricted Ports",
+            ContactPolicy::Sealed => "Sealed",
+        }
+    }
+
+    /// Share of foreign trade let through
+    pub fn trade_share(&self) -> f32 {
+        match self {
+            ContactPolicy::Open => 1.0,
+            ContactPolicy::Restricted => 0.4,
+            ContactPolicy::Sealed => 0.05,
+        }
+    }
+
+    /// Share of foreign ideas let through
+    pub fn diffusion_share(&self) -> f32 {
+        match self {
+            ContactPolicy::Open => 1.0,
+            ContactPolicy::Restricted => 0.5,
+            ContactPolicy::Sealed => 0.1,
+        }
+    }
+
+    /// Whether migrants and refugees may settle
+    pub fn admits_migrants(&self) -> bool {
+        *self == ContactPolicy::Open
+    }
+}
+
+/// A nation's contact policy and what drives it
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct ContactStance {
+    pub policy: ContactPolicy,
+    /// Year the current policy was adopted
+    pub since: u32,
+    /// Memory of traumatic contact (0.0-1.0), fading over the years
+    pub trauma: f32,
+    /// Whether a peace treaty holds its ports open
+    pub forced_open: bool,
+}
+
+/// How inward-looking a government's ideology is (0.0-1.0)
+fn inward_looking(government: GovernmentType) -> f32 {
+    use GovernmentType::*;
+
+    match government {
+        Theocracy | FundamentalistState | CultState | MonasticState | DivineManadate => 0.8,
+        AnarchoPrimitivism | CasteSystem | TotalitarianRegime | PoliceState | Autocracy => 0.6,
+        AbsoluteMonarchy | Feudalism | Gerontocracy => 0.3,
+        MerchantRepublic | Plutocracy | CorporateState | Bankocracy | GuildState | PirateRepublic => 0.0,
+        _ => 0.2,
+    }
+}
+
+/// How strongly a court wants to close itself off (0.0-1.0)
+///
+/// `xenophobia` is the stronger of its ideology's inwardness and its
+/// trauma from contact; trading nations resist closing up.
+pub fn isolation_utility(xenophobia: f32, mercantilism: f32) -> f32 {
+    score_considerations(&[
+        Consideration::new(
+            xenophobia,
+            ResponseCurve::Logistic {
+                midpoint: 0.5,
+                steepness: 8.0,
+            },
+        ),
+        Consideration::new((mercantilism + 1.0) / 2.0, ResponseCurve::Inverse),
+    ])
+}
+
+/// The policy a court adopts at a given isolation utility
+pub fn policy_for(utility: f32) -> ContactPolicy {
+    if utility >= SEAL_THRESHOLD {
+        ContactPolicy::Sealed
+    } else if utility >= RESTRICT_THRESHOLD {
+        ContactPolicy::Restricted
+    } else {
+        ContactPolicy::Open
+    }
+}
+
+/// Yearly trauma from contact, and courts opening or closing their borders
+pub fn choose_contact_policies(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    pandemics: Res<Pandemics>,
+    new_worlds: Res<NewWorlds>,
+    nation_index: Res<NationIndex>,
+    treaties_query: Query<&Treaty>,
+    mut nations_query: Query<(Entity, &Nation, &Governance, Option<&mut ContactStance>)>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+
+    for (entity, nation, governance, stance) in &mut nations_query {
+        let mut updated = stance.as_deref().cloned().unwrap_or(ContactStance {
+            since: year,
+            ..default()
+        });
+
+        // Plague from abroad and foreign colonies on home shores are not forgotten quickly
+        let home = new_worlds.world_of(nation.capital_province.value() as usize);
+        let mut trauma = updated.trauma * TRAUMA_MEMORY;
+        let plague_raging = pandemics
+            .active
+            .as_ref()
+            .is_some_and(|pandemic| pandemic.stricken.contains(&entity));
+        if plague_raging || home.is_some_and(|home| new_worlds.is_epidemic_raging(home, year)) {
+            trauma += PLAGUE_TRAUMA;
+        }
+        let foreign_colonies = home
+            .and_then(|home| new_worlds.colonizers.get(&home))
+            .is_some_and(|colonizers| colonizers.iter().any(|&colonizer| colonizer != entity));
+        if foreign_colonies {
+            trauma += COLONY_TRAUMA;
+        }
+        updated.trauma = trauma.min(1.0);
+
+        updated.forced_open = treaties_query.iter().any(|treaty| {
+            treaty.involves(entity)
+                && treaty
+                    .clauses
+                    .iter()
+                    .any(|clause| *clause == TreatyClause::OpenPorts { nation: entity })
+        });
+
+        let xenophobia = inward_looking(governance.government_type).max(updated.trauma);
+        let wanted = if updated.forced_open {
+            ContactPolicy::Open
+        } else {
+            policy_for(isolation_utility(xenophobia, nation.personality.mercantilism))
+        };
+        let settled = year.saturating_sub(updated.since) >= MIN_POLICY_YEARS;
+        if wanted != updated.policy && (settled || updated.forced_open) {
+            let text = match wanted {
+                ContactPolicy::Open if updated.forced_open => {
+                    format!("{} is forced to open its ports to foreign ships", nation.name)
+                }
+                ContactPolicy::Open => format!("{} reopens its borders to the outside world", nation.name),
+                ContactPolicy::Restricted => format!("{} restricts foreign trade to licensed ports", nation.name),
+                ContactPolicy::Sealed => format!("{} seals its borders and ports against foreigners", nation.name),
+            };
+            chronicle.write(ChronicleEvent {
+                category: ChronicleCategory::Politics,
+                text,
+                nations: nation_index.id(entity).into_iter().collect(),
+            });
+            updated.policy = wanted;
+            updated.since = year;
+        }
+
+        match stance {
+            Some(mut stance) => *stance = updated,
+            None => {
+                commands.entity(entity).insert(updated);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inward_traumatized_courts_close_and_traders_stay_open() {
+        assert_eq!(policy_for(isolation_utility(0.9, -0.8)), ContactPolicy::Sealed);
+        assert_eq!(policy_for(isolation_utility(0.0, 0.8)), ContactPolicy::Open);
+        assert!(isolation_utility(0.8, 0.0) > isolation_utility(0.2, 0.0));
+
+        assert!(ContactPolicy::Sealed.trade_share() < ContactPolicy::Restricted.trade_share());
+        assert!(!ContactPolicy::Restricted.admits_migrants());
+    }
+}
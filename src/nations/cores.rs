@@ -0,0 +1,388 @@
+//! Core territory tracking - the provinces a nation considers rightfully its own
+//!
+//! A province becomes a core of the nation that has ruled it for
+//! [`CORE_FORMATION_YEARS`]. When a core is lost the former owner keeps its
+//! claim (and its culture is remembered) until [`CORE_DECAY_YEARS`] of foreign
+//! rule have passed, giving revanchist nations a Reconquest casus belli and a
+//! strong reason to pick that particular neighbor as a war target.
+//!
+//! Core state is saved by stable nation id. A loaded save parks it in
+//! [`RestoredProvinceCores`] until the world is in play, since the cores of the
+//! previous world are cleared on the way out of loading.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use crate::name_generator::Culture;
+use crate::nations::{Nation, NationId};
+use crate::relationships::Controls;
+use crate::simulation::NewYearEvent;
+use crate::world::{MapMode, ProvinceEntityOrder};
+
+/// Years of continuous rule before a province becomes a core
+pub const CORE_FORMATION_YEARS: u16 = 50;
+
+/// Years of foreign rule before a lost core is forgotten (roughly four generations)
+pub const CORE_DECAY_YEARS: u16 = 100;
+
+/// Core state of a single province
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProvinceCore {
+    /// Nation that considers this province a core
+    pub core_owner: Option<Entity>,
+    /// Culture of the core owner when the core formed
+    pub core_culture: Option<Culture>,
+    /// Nation currently ruling the province
+    pub ruler: Option<Entity>,
+    /// Years the current ruler has held the province without interruption
+    pub years_ruled: u16,
+    /// Years the core has been under foreign (or no) rule
+    pub years_foreign: u16,
+}
+
+impl ProvinceCore {
+    /// Advance the province by one simulated year under `ruler`
+    pub fn advance_year(&mut self, ruler: Option<Entity>, ruler_culture: Option<Culture>) {
+        if ruler == self.ruler {
+            self.years_ruled = self.years_ruled.saturating_add(1);
+        } else {
+            self.ruler = ruler;
+            self.years_ruled = 1;
+        }
+
+        match self.core_owner {
+            Some(core_owner) if Some(core_owner) == ruler => {
+                self.years_foreign = 0;
+            }
+            Some(_) => {
+                self.years_foreign = self.years_foreign.saturating_add(1);
+                if self.years_foreign >= CORE_DECAY_YEARS {
+                    self.core_owner = None;
+                    self.core_culture = None;
+                    self.years_foreign = 0;
+                }
+            }
+            None => {}
+        }
+
+        // The old claim has to fade before a new ruler can make the land its own
+        if self.core_owner.is_none() && ruler.is_some() && self.years_ruled >= CORE_FORMATION_YEARS {
+            self.core_owner = ruler;
+            self.core_culture = ruler_culture;
+        }
+    }
+
+    /// Whether the core owner no longer rules this province
+    pub fn is_lost(&self) -> bool {
+        self.core_owner.is_some() && self.core_owner != self.ruler
+    }
+
+    /// How far a lost core has decayed (0.0 = just lost, 1.0 = about to be forgotten)
+    pub fn decay_progress(&self) -> f32 {
+        if self.is_lost() {
+            self.years_foreign as f32 / CORE_DECAY_YEARS as f32
+        } else {
+            0.0
+        }
+    }
+}
+
+/// A province's core state as written to a save, naming nations by stable id
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedProvinceCore {
+    pub core_owner: Option<NationId>,
+    pub core_culture: Option<Culture>,
+    pub ruler: Option<NationId>,
+    pub years_ruled: u16,
+    pub years_foreign: u16,
+}
+
+/// Core state for every province, indexed by province order
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ProvinceCores {
+    cores: Vec<ProvinceCore>,
+}
+
+impl ProvinceCores {
+    /// Core state for a province index
+    pub fn get(&self, index: usize) -> Option<&ProvinceCore> {
+        self.cores.get(index)
+    }
+
+    /// Number of tracked provinces
+    pub fn len(&self) -> usize {
+        self.cores.len()
+    }
+
+    /// Check if cores have not been initialized for this world
+    pub fn is_empty(&self) -> bool {
+        self.cores.is_empty()
+    }
+
+    /// Drop all core data (new world or loaded save)
+    pub fn clear(&mut self) {
+        self.cores.clear();
+    }
+
+    /// Stable-id form of every province's core state, for saving
+    pub fn to_saved(&self, id_of: impl Fn(Entity) -> Option<NationId>) -> Vec<SavedProvinceCore> {
+        self.cores
+            .iter()
+            .map(|core| SavedProvinceCore {
+                core_owner: core.core_owner.and_then(&id_of),
+                core_culture: core.core_culture,
+                ruler: core.ruler.and_then(&id_of),
+                years_ruled: core.years_ruled,
+                years_foreign: core.years_foreign,
+            })
+            .collect()
+    }
+
+    /// Rebuild core state from a save
+    ///
+    /// A claim whose nation no longer exists is dropped with its culture.
+    pub fn restore(saved: &[SavedProvinceCore], entity_of: impl Fn(NationId) -> Option<Entity>) -> Self {
+        let cores = saved
+            .iter()
+            .map(|core| {
+                let core_owner = core.core_owner.and_then(&entity_of);
+                ProvinceCore {
+                    core_owner,
+                    core_culture: core_owner.and(core.core_culture),
+                    ruler: core.ruler.and_then(&entity_of),
+                    years_ruled: core.years_ruled,
+                    years_foreign: if core_owner.is_some() { core.years_foreign } else { 0 },
+                }
+            })
+            .collect();
+        Self { cores }
+    }
+}
+
+/// Core state read from a save, waiting for the world to come into play
+#[derive(Resource, Debug)]
+pub struct RestoredProvinceCores(pub ProvinceCores);
+
+/// Cores a nation has lost, grouped by the nation now holding them
+///
+/// Only present on nations with at least one lost core.
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct LostCores {
+    /// Province storage indices grouped by current holder
+    pub indices_by_holder: HashMap<Entity, Vec<u32>>,
+}
+
+impl LostCores {
+    /// Storage indices of the core provinces held by a specific nation
+    pub fn held_by(&self, holder: Entity) -> &[u32] {
+        self.indices_by_holder.get(&holder).map_or(&[], Vec::as_slice)
+    }
+
+    /// Total number of lost core provinces
+    pub fn total(&self) -> usize {
+        self.indices_by_holder.values().map(Vec::len).sum()
+    }
+}
+
+/// Current ruler and ruler culture per province index
+fn current_rulers(
+    entity_order: &ProvinceEntityOrder,
+    nations_query: &Query<(Entity, &Nation, Option<&Controls>)>,
+) -> Vec<Option<(Entity, Culture)>> {
+    let index_by_entity: HashMap<Entity, usize> = entity_order
+        .entities
+        .iter()
+        .enumerate()
+        .map(|(idx, &entity)| (entity, idx))
+        .collect();
+
+    let mut rulers = vec![None; entity_order.len()];
+    for (nation_entity, nation, controls) in nations_query.iter() {
+        let Some(controls) = controls else {
+            continue;
+        };
+        for province_entity in controls.provinces() {
+            if let Some(&idx) = index_by_entity.get(province_entity) {
+                rulers[idx] = Some((nation_entity, nation.culture));
+            }
+        }
+    }
+    rulers
+}
+
+/// Forget the previous world's cores once a new world has loaded
+pub fn clear_province_cores(mut cores: ResMut<ProvinceCores>) {
+    cores.clear();
+}
+
+/// Starting territory counts as core territory of its founding nation
+///
+/// A loaded save brings its own core state, which is used instead when it
+/// covers every province.
+pub fn initialize_province_cores(
+    mut commands: Commands,
+    mut cores: ResMut<ProvinceCores>,
+    restored: Option<Res<RestoredProvinceCores>>,
+    province_entity_order: Option<Res<ProvinceEntityOrder>>,
+    nations_query: Query<(Entity, &Nation, Option<&Controls>)>,
+) {
+    let Some(entity_order) = province_entity_order else {
+        return;
+    };
+    if let Some(restored) = restored {
+        commands.remove_resource::<RestoredProvinceCores>();
+        if restored.0.len() == entity_order.len() {
+            *cores = restored.0.clone();
+            info!("Restored core territory for {} provinces", cores.len());
+            return;
+        }
+    }
+    if cores.len() == entity_order.len() {
+        return;
+    }
+
+    cores.cores = current_rulers(&entity_order, &nations_query)
+        .into_iter()
+        .map(|ruler| ProvinceCore {
+            core_owner: ruler.map(|(entity, _)| entity),
+            core_culture: ruler.map(|(_, culture)| culture),
+            ruler: ruler.map(|(entity, _)| entity),
+            years_ruled: CORE_FORMATION_YEARS,
+            years_foreign: 0,
+        })
+        .collect();
+
+    info!("Initialized core territory for {} provinces", cores.len());
+}
+
+/// Yearly core formation, decay, and lost-core bookkeeping
+pub fn update_province_cores(
+    mut year_events: MessageReader<NewYearEvent>,
+    mut cores: ResMut<ProvinceCores>,
+    mut map_mode: ResMut<MapMode>,
+    province_entity_order: Option<Res<ProvinceEntityOrder>>,
+    nations_query: Query<(Entity, &Nation, Option<&Controls>)>,
+    lost_cores_query: Query<Entity, With<LostCores>>,
+    mut commands: Commands,
+) {
+    let years = year_events.read().count();
+    if years == 0 || cores.is_empty() {
+        return;
+    }
+    let Some(entity_order) = province_entity_order else {
+        return;
+    };
+
+    let rulers = current_rulers(&entity_order, &nations_query);
+    let mut lost: HashMap<Entity, LostCores> = HashMap::new();
+
+    for (idx, (core, ruler)) in cores.cores.iter_mut().zip(rulers).enumerate() {
+        for _ in 0..years {
+            core.advance_year(ruler.map(|(entity, _)| entity), ruler.map(|(_, culture)| culture));
+        }
+
+        if let (true, Some(core_owner)) = (core.is_lost(), core.core_owner) {
+            // Unowned land is reclaimed through expansion, not war
+            if let Some(holder) = core.ruler {
+                lost.entry(core_owner)
+                    .or_default()
+                    .indices_by_holder
+                    .entry(holder)
+                    .or_default()
+                    .push(idx as u32);
+            }
+        }
+    }
+
+    for nation_entity in &lost_cores_query {
+        if !lost.contains_key(&nation_entity) {
+            commands.entity(nation_entity).remove::<LostCores>();
+        }
+    }
+    let revanchist_count = lost.len();
+    for (nation_entity, lost_cores) in lost {
+        // The nation may have been destroyed while its claim lives on
+        if nations_query.contains(nation_entity) {
+            commands.entity(nation_entity).insert(lost_cores);
+        }
+    }
+
+    debug!("Core territory updated: {} nations have lost cores", revanchist_count);
+
+    if *map_mode == MapMode::Cores {
+        map_mode.set_changed();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_rule_forms_a_core() {
+        let ruler = Entity::PLACEHOLDER;
+        let mut core = ProvinceCore::default();
+
+        for _ in 0..CORE_FORMATION_YEARS {
+            core.advance_year(Some(ruler), Some(Culture::Western));
+        }
+
+        assert_eq!(core.core_owner, Some(ruler));
+        assert_eq!(core.core_culture, Some(Culture::Western));
+        assert!(!core.is_lost());
+    }
+
+    #[test]
+    fn lost_core_decays_after_foreign_rule() {
+        let mut core = ProvinceCore {
+            core_owner: Some(Entity::PLACEHOLDER),
+            core_culture: Some(Culture::Northern),
+            ruler: Some(Entity::PLACEHOLDER),
+            years_ruled: CORE_FORMATION_YEARS,
+            years_foreign: 0,
+        };
+
+        core.advance_year(None, None);
+        assert!(core.is_lost());
+
+        for _ in 1..CORE_DECAY_YEARS {
+            core.advance_year(None, None);
+        }
+        assert_eq!(core.core_owner, None);
+        assert!(!core.is_lost());
+    }
+
+    #[test]
+    fn saved_cores_drop_claims_of_vanished_nations() {
+        let mut world = World::new();
+        let [survivor, vanished] = [(); 2].map(|_| world.spawn_empty().id());
+        let cores = ProvinceCores {
+            cores: vec![
+                ProvinceCore {
+                    core_owner: Some(survivor),
+                    core_culture: Some(Culture::Western),
+                    ruler: Some(vanished),
+                    years_ruled: 12,
+                    years_foreign: 12,
+                },
+                ProvinceCore {
+                    core_owner: Some(vanished),
+                    core_culture: Some(Culture::Northern),
+                    ruler: Some(survivor),
+                    years_ruled: 30,
+                    years_foreign: 30,
+                },
+            ],
+        };
+        let ids = [(survivor, NationId::new(1)), (vanished, NationId::new(2))];
+        let saved = cores.to_saved(|entity| ids.iter().find(|(e, _)| *e == entity).map(|(_, id)| *id));
+
+        let restored = ProvinceCores::restore(&saved, |id| (id == NationId::new(1)).then_some(survivor));
+
+        assert_eq!(restored.get(0).map(|core| core.core_owner), Some(Some(survivor)));
+        assert_eq!(restored.get(0).map(|core| core.ruler), Some(None));
+        let orphaned = ProvinceCore { ruler: Some(survivor), years_ruled: 30, ..Default::default() };
+        assert_eq!(restored.get(1).copied(), Some(orphaned));
+    }
+}
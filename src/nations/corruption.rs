@@ -0,0 +1,250 @@
+//! Corruption - officials skimming what passes through their hands
+//!
+//! Corruption grows where officials are poorly paid, courts are too weak to
+//! punish them, the state has outgrown its bureaucracy, or a war floods
+//! contractors with money. It skims taxes before they reach the treasury,
+//! inflates procurement, and leaves armies fighting on short rations. Now and
+//! then it surfaces as a scandal that costs the government legitimacy.
+//!
+//! Governments that let it get out of hand can launch an anti-corruption
+//! drive: years of costly purges that bring it down far faster than it rose.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use rand::Rng;
+
+use super::bureaucracy::Bureaucracy;
+use super::governance::{CorruptionScandal, Governance};
+use super::laws::NationLaws;
+use super::relationships::ParticipatesInWar;
+use super::types::{Nation, NationId};
+use crate::ai::{decision_rng, DecisionDomain};
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::math::{Fixed32, Money};
+use crate::simulation::NewYearEvent;
+use crate::world::WorldSeed;
+
+/// Treasury per province below which officials go underpaid
+const OFFICIAL_PAY_RESERVE: f32 = 20.0;
+/// Corruption added by underpaid officials
+const LOW_PAY_CORRUPTION: f32 = 0.15;
+/// Corruption added by courts with no institutions behind them
+const WEAK_JUDICIARY_CORRUPTION: f32 = 0.3;
+/// Corruption added by war contracts
+const WAR_PROFITEERING: f32 = 0.15;
+/// Corruption added per unit of bureaucratic overextension
+const OVEREXTENSION_CORRUPTION: f32 = 0.4;
+/// Share of the gap to target corruption closed each year
+const CORRUPTION_DRIFT: f32 = 0.2;
+/// Share of taxes skimmed at total corruption
+const TAX_SKIM: f32 = 0.4;
+/// Treasury lost to inflated procurement per point of military strength at total corruption
+const PROCUREMENT_SKIM: f32 = 0.5;
+/// Supply quality lost at total corruption
+const SUPPLY_LOSS: f32 = 0.4;
+/// Yearly chance of a scandal at total corruption
+const SCANDAL_CHANCE: f32 = 0.3;
+/// Legitimacy lost to a scandal of full severity
+const SCANDAL_LEGITIMACY: f32 = 0.2;
+/// Days before a scandal is forgotten
+const SCANDAL_MEMORY_DAYS: u32 = 3 * 365;
+/// Corruption at which a government considers a drive
+const DRIVE_THRESHOLD: f32 = 0.4;
+/// Years an anti-corruption drive runs
+const DRIVE_YEARS: u32 = 5;
+/// Yearly treasury cost of a drive
+const DRIVE_COST: Money = Money::from_int(100);
+/// Yearly stability cost of purges
+const DRIVE_UNREST: f32 = 0.02;
+/// Share of target corruption left while a drive runs
+const DRIVE_TARGET: f32 = 0.3;
+/// Share of the gap to target closed each year while a drive runs
+const DRIVE_DRIFT: f32 = 0.4;
+
+/// A nation's corruption and what it costs
+#[derive(Component, Debug, Clone, Reflect, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct Corruption {
+    /// Share of officials on the take (0.0-1.0)
+    pub level: f32,
+    /// Treasury lost to inflated procurement last year
+    pub procurement_losses: f32,
+    /// Year the current anti-corruption drive ends, if one is running
+    pub drive_until: Option<u32>,
+}
+
+impl Default for Corruption {
+    fn default() -> Self {
+        Self {
+            level: 0.2,
+            procurement_losses: 0.0,
+            drive_until: None,
+        }
+    }
+}
+
+impl Corruption {
+    /// Share of collected taxes officials keep
+    pub fn tax_skim(&self) -> f32 {
+        self.level * TAX_SKIM
+    }
+
+    /// How much of what is bought for the army reaches it (1.0 = all of it)
+    pub fn supply_quality(&self) -> f32 {
+        1.0 - self.level * SUPPLY_LOSS
+    }
+
+    pub fn drive_active(&self, year: u32) -> bool {
+        self.drive_until.is_some_and(|until| year < until)
+    }
+
+    /// Start a drive of purges and audits against corrupt officials
+    pub fn launch_drive(&mut self, year: u32) {
+        self.drive_until = Some(year + DRIVE_YEARS);
+    }
+}
+
+/// What drives corruption up in a nation
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CorruptionPressures {
+    /// The government type's baseline, after laws
+    pub baseline: f32,
+    pub underpaid_officials: bool,
+    /// 0.0 = no institutions, 1.0 = fully independent courts
+    pub judiciary: f32,
+    pub at_war: bool,
+    pub overextension: f32,
+}
+
+/// The corruption a nation settles toward under its pressures
+pub fn target_corruption(pressures: CorruptionPressures, drive: bool) -> f32 {
+    let target = pressures.baseline
+        + if pressures.underpaid_officials { LOW_PAY_CORRUPTION } else { 0.0 }
+        + (1.0 - pressures.judiciary.clamp(0.0, 1.0)) * WEAK_JUDICIARY_CORRUPTION
+        + if pressures.at_war { WAR_PROFITEERING } else { 0.0 }
+        + pressures.overextension * OVEREXTENSION_CORRUPTION;
+    let target = if drive { target * DRIVE_TARGET } else { target };
+    target.clamp(0.0, 1.0)
+}
+
+/// Yearly growth of corruption, its costs, scandals, and drives against it
+pub fn spread_corruption(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    world_seed: Option<Res<WorldSeed>>,
+    mut nations_query: Query<(
+        Entity,
+        &mut Nation,
+        &NationId,
+        &mut Governance,
+        Option<&Bureaucracy>,
+        Option<&NationLaws>,
+        Option<&ParticipatesInWar>,
+        Option<&mut Corruption>,
+    )>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+    let seed = world_seed.map_or(0, |seed| seed.0);
+
+    for (entity, mut nation, nation_id, mut governance, bureaucracy, laws, at_war, corruption) in &mut nations_query {
+        let mut updated = corruption.as_deref().cloned().unwrap_or_default();
+        let provinces = bureaucracy.map_or(1, |bureaucracy| bureaucracy.provinces.max(1));
+
+        // Governments deep in corruption with money to spare go after it
+        if !updated.drive_active(year)
+            && updated.level > DRIVE_THRESHOLD
+            && nation.treasury > DRIVE_COST * Fixed32::from_int(DRIVE_YEARS as i32)
+        {
+            updated.launch_drive(year);
+            chronicle.write(ChronicleEvent {
+                category: ChronicleCategory::Politics,
+                text: format!("{} launches a drive against corrupt officials", nation.name),
+                nations: vec![*nation_id],
+            });
+        }
+        let drive = updated.drive_active(year);
+
+        let pressures = CorruptionPressures {
+            baseline: governance.government_type.mechanics().corruption
+                + laws.map_or(0.0, |laws| laws.combined_effects.corruption_change),
+            underpaid_officials: nation.treasury.to_f32() / (provinces as f32) < OFFICIAL_PAY_RESERVE,
+            judiciary: governance.institution_strength,
+            at_war: at_war.is_some(),
+            overextension: bureaucracy.map_or(0.0, |bureaucracy| bureaucracy.overextension),
+        };
+        let drift = if drive { DRIVE_DRIFT } else { CORRUPTION_DRIFT };
+        updated.level += (target_corruption(pressures, drive) - updated.level) * drift;
+
+        updated.procurement_losses = nation.military_strength * updated.level * PROCUREMENT_SKIM;
+        nation.treasury -= Money::from_f32(updated.procurement_losses);
+        if drive {
+            nation.treasury -= DRIVE_COST;
+            nation.stability = (nation.stability - DRIVE_UNREST).max(0.0);
+        }
+
+        // Old scandals fade from memory
+        if let Some(scandal) = governance.legitimacy_factors.corruption_scandal.as_mut() {
+            scandal.days_since += 365;
+            if scandal.days_since >= SCANDAL_MEMORY_DAYS {
+                governance.legitimacy_factors.corruption_scandal = None;
+            }
+        }
+
+        let mut rng = decision_rng(seed, DecisionDomain::Governance, nation_id.value(), 3, year);
+        if rng.r#gen::<f32>() < updated.level * SCANDAL_CHANCE {
+            let severity = updated.level;
+            let officials_implicated = 1 + (severity * rng.gen_range(2.0..20.0)) as u32;
+            governance.legitimacy = (governance.legitimacy - severity * SCANDAL_LEGITIMACY).max(0.0);
+            governance.legitimacy_factors.public_approval_rating =
+                (governance.legitimacy_factors.public_approval_rating - severity * SCANDAL_LEGITIMACY).max(0.0);
+            let scandal_name = format!("{} {} Affair", nation.adjective, year);
+            chronicle.write(ChronicleEvent {
+                category: ChronicleCategory::Politics,
+                text: format!(
+                    "The {} exposes {} corrupt officials in {}",
+                    scandal_name, officials_implicated, nation.name
+                ),
+                nations: vec![*nation_id],
+            });
+            governance.legitimacy_factors.corruption_scandal = Some(CorruptionScandal {
+                scandal_name,
+                severity,
+                days_since: 0,
+                officials_implicated,
+            });
+        }
+
+        match corruption {
+            Some(mut corruption) => *corruption = updated,
+            None => {
+                commands.entity(entity).insert(updated);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn poor_pay_weak_courts_and_war_breed_corruption() {
+        let clean = CorruptionPressures {
+            baseline: 0.1,
+            judiciary: 0.9,
+            ..Default::default()
+        };
+        let rotten = CorruptionPressures {
+            baseline: 0.1,
+            underpaid_officials: true,
+            judiciary: 0.2,
+            at_war: true,
+            overextension: 0.5,
+        };
+        assert!(target_corruption(rotten, false) > target_corruption(clean, false) + 0.3);
+        assert!(target_corruption(rotten, true) < target_corruption(rotten, false));
+    }
+}
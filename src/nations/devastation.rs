@@ -0,0 +1,424 @@
+//! Devastation - the scars war leaves on the land
+//!
+//! Battles ravage the provinces they are fought over, every year of war
+//! wears down the provinces along the front, and besieged fort provinces
+//! suffer worst of all. A ruthless defender that is losing may burn its own
+//! borderlands so the invader cannot live off them. Devastation kills part of
+//! the population, drives more of it to flee as refugees, and when heavy
+//! knocks a province's development back a level.
+//!
+//! The land recovers over decades: devastation fades, the population
+//! regrows, and roads and towns are rebuilt. Provinces ravaged again and
+//! again before they can recover become depopulated marches, where people
+//! only trickle back until long years of peace have passed.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use super::city_names::CityNames;
+use super::index::NationIndex;
+use super::refugees::PopulationDisplaced;
+use super::relationships::{Attacking, ParticipatesInWar};
+use super::types::Nation;
+use super::warfare::{BattleEvent, War};
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::relationships::{Fortification, StationedIn};
+use crate::simulation::{GameTime, NewYearEvent};
+use crate::world::{InfrastructureStorage, MapMode, Province, ProvinceEntityOrder, ProvinceStorage};
+
+/// Devastation from a battle at its site
+const BATTLE_DEVASTATION: f32 = 0.25;
+/// Yearly devastation of every province along an active front
+const FRONT_DEVASTATION: f32 = 0.05;
+/// Extra yearly devastation of a front province with a fort under siege
+const SIEGE_DEVASTATION: f32 = 0.1;
+/// Devastation a defender inflicts on its own borderlands by scorching them
+const SCORCHED_EARTH_DEVASTATION: f32 = 0.3;
+/// War score (attacker winning) at which a ruthless defender scorches the earth
+const SCORCHED_EARTH_WAR_SCORE: f32 = 30.0;
+/// Aggression at which a defender is ruthless enough to burn its own land
+const SCORCHED_EARTH_AGGRESSION: f32 = 0.4;
+/// Share of its strength an invader loses each year to scorched earth
+const SCORCHED_EARTH_FORAGE_LOSS: f32 = 0.05;
+/// Share of the population killed or scattered per point of devastation
+const POPULATION_LOSS: f32 = 0.5;
+/// Share of the people lost to devastation who flee as refugees rather than die
+const FLIGHT_SHARE: f32 = 0.5;
+/// Devastation at which a province loses a level of development
+const DEVELOPMENT_LOSS_LEVEL: f32 = 0.5;
+/// Devastation below which lost development is rebuilt
+const REBUILD_LEVEL: f32 = 0.2;
+/// Share of devastation that fades each year
+const RECOVERY: f32 = 0.05;
+/// Share of the lost population that returns each year
+const RESETTLEMENT: f32 = 0.05;
+/// Resettlement in a march, as a share of normal resettlement
+const MARCH_RESETTLEMENT: f32 = 0.1;
+/// Devastation at which a province counts as ravaged
+const RAVAGED_LEVEL: f32 = 0.6;
+/// Years between ravagings for them to count separately
+const RAVAGING_GAP_YEARS: u32 = 10;
+/// Ravagings that turn a province into a march
+const MARCH_RAVAGINGS: u8 = 3;
+/// Years of peace after which ravagings are forgotten and a march resettles
+const MARCH_MEMORY_YEARS: u32 = 50;
+/// Population a city needs for its fall to be remembered as a sack
+const SACK_POPULATION: u32 = 5_000;
+
+/// The scars of war on one province
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProvinceDevastation {
+    /// How ravaged the province is (0.0-1.0)
+    pub level: f32,
+    /// Population killed that has yet to regrow
+    pub lost_population: u32,
+    /// Development levels lost that have yet to be rebuilt
+    pub lost_development: u8,
+    /// Times the province has been ravaged within memory
+    pub ravagings: u8,
+    /// Year the province was last ravaged
+    pub last_ravaged: u32,
+    /// Whether the province has become a depopulated march
+    pub march: bool,
+}
+
+impl ProvinceDevastation {
+    /// Ravage the province; returns the population lost, and how many of them fled
+    pub fn ravage(&mut self, amount: f32, province: &mut Province, year: u32) -> (u32, u32) {
+        let before = self.level;
+        self.level = (self.level + amount).min(1.0);
+        let added = self.level - before;
+
+        let lost = (province.population as f32 * added * POPULATION_LOSS) as u32;
+        let fled = (lost as f32 * FLIGHT_SHARE) as u32;
+        province.set_population(province.population - lost);
+        self.lost_population += lost - fled;
+
+        if self.level >= RAVAGED_LEVEL && year >= self.last_ravaged + RAVAGING_GAP_YEARS {
+            self.ravagings = self.ravagings.saturating_add(1);
+            self.last_ravaged = year;
+        }
+        (lost, fled)
+    }
+
+    /// One year of recovery; returns the population regained
+    pub fn recover(&mut self, province: &mut Province, year: u32) -> u32 {
+        self.level *= 1.0 - RECOVERY;
+        if year >= self.last_ravaged + MARCH_MEMORY_YEARS {
+            self.ravagings = 0;
+            self.march = false;
+        }
+
+        let rate = if self.march {
+            RESETTLEMENT * MARCH_RESETTLEMENT
+        } else {
+            RESETTLEMENT
+        };
+        let returning = ((self.lost_population as f32 * rate).ceil() as u32).min(self.lost_population);
+        self.lost_population -= returning;
+        let before = province.population;
+        province.set_population(province.population.saturating_add(returning));
+        province.population - before
+    }
+}
+
+/// Devastation of every province, indexed by province storage index
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Devastation {
+    pub provinces: Vec<ProvinceDevastation>,
+    /// Defenders currently burning their own borderlands; rebuilt each year
+    #[serde(skip)]
+    scorching: BTreeSet<Entity>,
+}
+
+impl Devastation {
+    /// Devastation level by province index, for the Devastation map mode
+    pub fn level(&self, index: usize) -> f32 {
+        self.provinces.get(index).map_or(0.0, |province| province.level)
+    }
+
+    pub fn is_march(&self, index: usize) -> bool {
+        self.provinces.get(index).is_some_and(|province| province.march)
+    }
+}
+
+/// Battles, fronts, sieges, and scorched earth ravage provinces; peace lets them recover
+pub fn devastate_provinces(
+    mut devastation: ResMut<Devastation>,
+    mut battle_events: MessageReader<BattleEvent>,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    mut displaced: MessageWriter<PopulationDisplaced>,
+    mut map_mode: ResMut<MapMode>,
+    province_storage: Option<ResMut<ProvinceStorage>>,
+    mut infrastructure: Option<ResMut<InfrastructureStorage>>,
+    entity_order: Option<Res<ProvinceEntityOrder>>,
+    city_names: Res<CityNames>,
+    nation_index: Res<NationIndex>,
+    game_time: Res<GameTime>,
+    mut nations_query: Query<(Entity, &mut Nation, Option<&ParticipatesInWar>)>,
+    wars_query: Query<&War>,
+    attackers_query: Query<(Entity, &Attacking)>,
+    forts_query: Query<&StationedIn, With<Fortification>>,
+) {
+    let battles: Vec<(Entity, Entity)> = battle_events
+        .read()
+        .map(|event| (event.attacker, event.defender))
+        .collect();
+    let year = year_events.read().map(|event| event.year).last();
+    let Some(mut storage) = province_storage else {
+        return;
+    };
+    if battles.is_empty() && year.is_none() {
+        return;
+    }
+    let province_count = storage.provinces.len();
+    devastation
+        .provinces
+        .resize(province_count, ProvinceDevastation::default());
+    let current_year = year.unwrap_or_else(|| game_time.current_year());
+
+    // Provinces of `nation` bordering `enemy`
+    let front_of = |storage: &ProvinceStorage, nation: Entity, enemy: Entity| -> Vec<usize> {
+        storage
+            .provinces
+            .iter()
+            .enumerate()
+            .filter(|(_, province)| province.owner_entity == Some(nation))
+            .filter(|(_, province)| {
+                province.neighbors.iter().flatten().any(|neighbor| {
+                    storage
+                        .provinces
+                        .get(neighbor.value() as usize)
+                        .and_then(|p| p.owner_entity)
+                        == Some(enemy)
+                })
+            })
+            .map(|(index, _)| index)
+            .collect()
+    };
+    let names: HashMap<Entity, String> = nations_query
+        .iter()
+        .map(|(entity, nation, _)| (entity, nation.name.clone()))
+        .collect();
+
+    // Each battle is fought over the defender's most populous border province
+    let mut ravaged: Vec<(usize, f32, Option<Entity>)> = Vec::new();
+    for &(attacker, defender) in &battles {
+        let site = front_of(&*storage, defender, attacker)
+            .into_iter()
+            .max_by_key(|&index| storage.provinces[index].population);
+        if let Some(site) = site {
+            ravaged.push((site, BATTLE_DEVASTATION, Some(attacker)));
+        }
+    }
+
+    let mut forage_losses: HashMap<Entity, f32> = HashMap::new();
+    if year.is_some() {
+        let enemies: BTreeSet<(Entity, Entity)> = attackers_query
+            .iter()
+            .flat_map(|(attacker, attacking)| [(attacker, attacking.0), (attacking.0, attacker)])
+            .collect();
+        let fort_sites: HashSet<usize> = entity_order.as_ref().map_or_else(HashSet::new, |order| {
+            let index_by_entity: HashMap<Entity, usize> = order
+                .entities
+                .iter()
+                .enumerate()
+                .map(|(index, &entity)| (entity, index))
+                .collect();
+            forts_query
+                .iter()
+                .filter_map(|stationed_in| index_by_entity.get(&stationed_in.0).copied())
+                .collect()
+        });
+
+        // The fronts grind down, and forts along them draw sieges
+        for &(nation, enemy) in &enemies {
+            for index in front_of(&*storage, nation, enemy) {
+                let siege = if fort_sites.contains(&index) {
+                    SIEGE_DEVASTATION
+                } else {
+                    0.0
+                };
+                ravaged.push((index, FRONT_DEVASTATION + siege, None));
+            }
+        }
+
+        // Ruthless defenders who are losing burn their borderlands ahead of the invader
+        let mut scorching = BTreeSet::new();
+        for (defender, nation, war) in &nations_query {
+            let losing = war
+                .and_then(|war| wars_query.get(war.0).ok())
+                .is_some_and(|war| war.war_score >= SCORCHED_EARTH_WAR_SCORE);
+            if !losing || nation.personality.aggression < SCORCHED_EARTH_AGGRESSION {
+                continue;
+            }
+            let invaders: Vec<Entity> = attackers_query
+                .iter()
+                .filter(|(_, attacking)| attacking.0 == defender)
+                .map(|(attacker, _)| attacker)
+                .collect();
+            for &invader in &invaders {
+                for index in front_of(&*storage, defender, invader) {
+                    ravaged.push((index, SCORCHED_EARTH_DEVASTATION, None));
+                }
+                *forage_losses.entry(invader).or_default() += SCORCHED_EARTH_FORAGE_LOSS;
+            }
+            if !invaders.is_empty() {
+                scorching.insert(defender);
+                if !devastation.scorching.contains(&defender) {
+                    chronicle.write(ChronicleEvent {
+                        category: ChronicleCategory::War,
+                        text: format!("{} burns its own borderlands before the invaders", nation.name),
+                        nations: nation_index.id(defender).into_iter().collect(),
+                    });
+                }
+            }
+        }
+        devastation.scorching = scorching;
+    }
+
+    for (index, amount, sacked_by) in ravaged {
+        let (Some(record), Some(province)) = (devastation.provinces.get_mut(index), storage.provinces.get_mut(index))
+        else {
+            continue;
+        };
+        let population = province.population;
+        let before = *record;
+        let (lost, fled) = record.ravage(amount, province, current_year);
+        if fled > 0 {
+            displaced.write(PopulationDisplaced {
+                province: index,
+                people: fled,
+            });
+        }
+
+        if record.level >= DEVELOPMENT_LOSS_LEVEL && before.level < DEVELOPMENT_LOSS_LEVEL {
+            if let Some(infra) = infrastructure
+                .as_mut()
+                .and_then(|infra| infra.infrastructure.get_mut(&province.id))
+            {
+                if infra.development_level > 0 {
+                    infra.development_level -= 1;
+                    record.lost_development += 1;
+                }
+            }
+        }
+
+        let owner = province.owner_entity;
+        let city = city_names.get(index).map(|city| city.name.clone());
+        if let (Some(attacker), Some(city)) = (sacked_by, city.as_ref()) {
+            if population >= SACK_POPULATION && before.level < RAVAGED_LEVEL && record.level >= RAVAGED_LEVEL {
+                chronicle.write(ChronicleEvent {
+                    category: ChronicleCategory::War,
+                    text: format!(
+                        "{} sacks {}, leaving {} dead or scattered",
+                        names.get(&attacker).map_or("An army", String::as_str),
+                        city,
+                        lost
+                    ),
+                    nations: [Some(attacker), owner]
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|n| nation_index.id(n))
+                        .collect(),
+                });
+            }
+        }
+        if record.ravagings >= MARCH_RAVAGINGS && !record.march {
+            record.march = true;
+            let place = city.unwrap_or_else(|| "the borderlands".to_string());
+            chronicle.write(ChronicleEvent {
+                category: ChronicleCategory::Catastrophe,
+                text: format!(
+                    "The lands around {} lie empty, a depopulated march ravaged by war after war",
+                    place
+                ),
+                nations: owner.and_then(|owner| nation_index.id(owner)).into_iter().collect(),
+            });
+        }
+    }
+
+    // Peace lets the land heal over decades
+    if let Some(year) = year {
+        for (index, record) in devastation.provinces.iter_mut().enumerate() {
+            if record.level <= 0.0 && record.lost_population == 0 && record.lost_development == 0 {
+                continue;
+            }
+            let Some(province) = storage.provinces.get_mut(index) else {
+                continue;
+            };
+            record.recover(province, year);
+            if record.level < REBUILD_LEVEL && record.lost_development > 0 {
+                if let Some(infra) = infrastructure
+                    .as_mut()
+                    .and_then(|infra| infra.infrastructure.get_mut(&province.id))
+                {
+                    infra.development_level += 1;
+                }
+                record.lost_development -= 1;
+            }
+        }
+    }
+
+    for (entity, mut nation, _) in &mut nations_query {
+        if let Some(loss) = forage_losses.get(&entity) {
+            nation.military_strength *= 1.0 - loss.min(1.0);
+        }
+    }
+
+    if *map_mode == MapMode::Devastation {
+        map_mode.set_changed();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ravaged_provinces_recover_slowly_and_marches_slower() {
+        let mut province = Province {
+            population: 10_000,
+            max_population: 20_000,
+            ..Default::default()
+        };
+        let mut record = ProvinceDevastation::default();
+        let (lost, fled) = record.ravage(0.8, &mut province, 100);
+        assert_eq!(lost, 4_000);
+        assert_eq!(record.lost_population, lost - fled);
+        assert_eq!(record.ravagings, 1);
+
+        let mut march = record;
+        march.march = true;
+        let mut march_province = province.clone();
+        let returned = record.recover(&mut province, 101);
+        let march_returned = march.recover(&mut march_province, 101);
+        assert!(returned > march_returned && march_returned > 0);
+        assert!(record.level < 0.8);
+    }
+
+    #[test]
+    fn devastation_survives_a_save_round_trip() {
+        let mut devastation = Devastation::default();
+        devastation.provinces = vec![
+            ProvinceDevastation::default(),
+            ProvinceDevastation {
+                level: 0.7,
+                lost_population: 1_200,
+                lost_development: 1,
+                ravagings: 3,
+                last_ravaged: 140,
+                march: true,
+            },
+        ];
+        devastation.scorching.insert(Entity::from_raw_u32(5).unwrap());
+
+        let text = ron::to_string(&devastation).unwrap();
+        let restored: Devastation = ron::from_str(&text).unwrap();
+        assert_eq!(restored.provinces, devastation.provinces);
+        assert!(restored.is_march(1));
+        assert!(restored.scorching.is_empty());
+    }
+}
@@ -8,7 +8,8 @@ use crate::nations::{Nation, Governance};
 use crate::nations::warfare::CasusBelli;
 
 /// CB fabrication state (attached as component to nation entity)
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct FabricatingClaim {
     pub target_nation: Entity,
     pub progress: f32,       // 0.0 to 1.0
@@ -49,6 +50,7 @@ impl CasusBelliExt for CasusBelli {
                 aggressor_gov.government_type.category() != target_gov.government_type.category()
             }
             CasusBelli::NoCasusBelli => true, // Always available but costly
+            // Reconquest depends on core territory - see LostCores
             // Others require historical/relationship data
             _ => false, // TODO: Implement when history tracking exists
         }
@@ -58,6 +60,7 @@ impl CasusBelliExt for CasusBelli {
         match self {
             CasusBelli::DefensivePact => 0.0,      // Defensive wars have no penalty
             CasusBelli::Reconquest => 0.25,        // Reclaiming own land is justified
+            CasusBelli::BrokenTreaty => 0.25,      // Enforcing agreed terms
             CasusBelli::BorderDispute => 0.5,
             CasusBelli::HistoricalClaim => 0.5,
             CasusBelli::IdeologicalConflict => 0.75,
@@ -70,6 +73,7 @@ impl CasusBelliExt for CasusBelli {
         match self {
             CasusBelli::DefensivePact => 0.0,
             CasusBelli::Reconquest => 0.05,
+            CasusBelli::BrokenTreaty => 0.05,
             CasusBelli::BorderDispute => 0.1,
             CasusBelli::HistoricalClaim => 0.1,
             CasusBelli::IdeologicalConflict => 0.15,
@@ -0,0 +1,474 @@
+//! International congresses convened after great-power wars
+//!
+//! When a long war involving a great power ends, the belligerents and the
+//! remaining great powers meet to negotiate a multilateral settlement. The
+//! victor opens with territorial demands scaled by war score; mediators push
+//! back in proportion to their leverage to preserve the balance of power.
+//! The outcome can cede border provinces, carve out a neutral buffer state,
+//! and restore a deposed monarchy. Every congress is kept as a landmark
+//! record and written into each delegate's history.
+
+use bevy::prelude::*;
+use std::collections::{HashSet, VecDeque};
+use crate::ids::IdAllocator;
+use crate::nations::{
+    spawn_breakaway_nation, Attacking, Governance, GovernmentCategory, GovernmentHistory,
+    GovernmentTransition, GovernmentType, HistoricalEvent, Nation, NationHistory, NationId,
+    NationPersonality, OwnershipChangeType, OwnershipService, TransitionType, WarParticipants,
+};
+use crate::nations::warfare::{War, WarEndEvent, WarOutcome};
+use crate::relationships::{ControlledBy, Controls};
+use crate::simulation::GameTime;
+use crate::world::{ProvinceData, ProvinceId, ProvinceNeighbors};
+
+/// Number of largest nations counted as great powers
+pub const GREAT_POWER_COUNT: usize = 8;
+
+/// Battles a war must last before it warrants a congress
+const MIN_CONGRESS_BATTLES: u32 = 8;
+
+/// Most mediators invited besides the belligerents
+const MAX_MEDIATORS: usize = 4;
+
+/// Largest share of the vanquished's land the victor can demand
+const MAX_CESSION_SHARE: f32 = 0.3;
+
+/// Negotiation rounds before the congress settles on the current terms
+const NEGOTIATION_ROUNDS: u32 = 5;
+
+/// Minimum ceded provinces before a buffer state is worth creating
+const MIN_BUFFER_STATE_PROVINCES: usize = 6;
+
+/// Which side of the table a delegate sits on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DelegateRole {
+    Victor,
+    Vanquished,
+    Mediator,
+}
+
+/// A nation's seat at the congress
+#[derive(Debug, Clone, Copy)]
+pub struct Delegate {
+    pub entity: Entity,
+    pub role: DelegateRole,
+    /// Bargaining weight (military strength and territory)
+    pub leverage: f32,
+    /// Diplomacy personality (-1.0 hostile to 1.0 conciliatory)
+    pub diplomacy: f32,
+    /// Whether the delegate's government is a monarchy
+    pub monarchic: bool,
+}
+
+/// Terms the congress agreed on
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settlement {
+    /// Share of the vanquished's provinces that change hands
+    pub cession_share: f32,
+    /// Whether ceded land forms a neutral buffer state instead of going to the victor
+    pub buffer_state: bool,
+    /// Whether the vanquished's former monarchy is restored
+    pub restore_monarchy: bool,
+    /// Rounds it took to reach agreement
+    pub rounds: u32,
+}
+
+/// Run the scripted negotiation between delegates
+///
+/// `war_score` is from the victor's perspective (0-100). `vanquished_deposed_monarchy`
+/// says whether the vanquished overthrew a monarchy that could be restored.
+pub fn negotiate_settlement(
+    delegates: &[Delegate],
+    war_score: f32,
+    vanquished_deposed_monarchy: bool,
+) -> Settlement {
+    let leverage_of = |role: DelegateRole| -> f32 {
+        delegates.iter().filter(|d| d.role == role).map(|d| d.leverage).sum()
+    };
+    let victor = leverage_of(DelegateRole::Victor).max(1.0);
+    let vanquished = leverage_of(DelegateRole::Vanquished);
+    let mediators = leverage_of(DelegateRole::Mediator);
+    let total = victor + vanquished + mediators;
+
+    // Opening demand scales with how decisive the war was
+    let mut demand = (war_score.abs() / 100.0).clamp(0.0, 1.0) * MAX_CESSION_SHARE;
+
+    // Mediators and the vanquished whittle the demand down each round;
+    // conciliatory mediators object less strongly
+    let objection: f32 = delegates
+        .iter()
+        .filter(|d| d.role != DelegateRole::Victor)
+        .map(|d| d.leverage * (1.0 - d.diplomacy.clamp(-1.0, 1.0) * 0.5))
+        .sum::<f32>()
+        / total;
+    let acceptable = MAX_CESSION_SHARE * (victor / total);
+
+    let mut rounds = 0;
+    while rounds < NEGOTIATION_ROUNDS && demand > acceptable {
+        demand *= 1.0 - objection * 0.25;
+        rounds += 1;
+    }
+
+    // Strong mediators prefer a neutral buffer to a stronger victor
+    let buffer_state = mediators > victor * 0.5 && demand > MAX_CESSION_SHARE * 0.3;
+
+    // Monarchies at the table back a restoration if they outweigh the rest
+    let monarchic_leverage: f32 = delegates
+        .iter()
+        .filter(|d| d.role != DelegateRole::Vanquished && d.monarchic)
+        .map(|d| d.leverage)
+        .sum();
+    let restore_monarchy = vanquished_deposed_monarchy && monarchic_leverage > (total - vanquished) * 0.5;
+
+    Settlement {
+        cession_share: demand,
+        buffer_state,
+        restore_monarchy,
+        rounds,
+    }
+}
+
+/// A congress remembered as a landmark event
+#[derive(Debug, Clone)]
+pub struct CongressRecord {
+    pub year: u32,
+    pub name: String,
+    pub delegates: Vec<String>,
+    pub provinces_ceded: u32,
+    pub buffer_state: Option<String>,
+    pub restored_monarchy: Option<String>,
+}
+
+impl CongressRecord {
+    /// One-line summary of the settlement
+    pub fn summary(&self) -> String {
+        let mut parts = vec![format!("{} provinces ceded", self.provinces_ceded)];
+        if let Some(buffer) = &self.buffer_state {
+            parts.push(format!("{} created as a buffer state", buffer));
+        }
+        if let Some(restored) = &self.restored_monarchy {
+            parts.push(format!("monarchy restored in {}", restored));
+        }
+        format!("{} ({}): {}", self.name, self.year, parts.join(", "))
+    }
+}
+
+/// Every congress held this game, in order
+#[derive(Resource, Debug, Default)]
+pub struct CongressHistory {
+    pub congresses: Vec<CongressRecord>,
+}
+
+/// Message: a congress has redrawn the map
+#[derive(Message, Debug, Clone)]
+pub struct CongressConcludedEvent {
+    pub name: String,
+    pub year: u32,
+}
+
+/// Forget the previous world's congresses
+pub fn clear_congress_history(mut history: ResMut<CongressHistory>) {
+    history.congresses.clear();
+}
+
+/// The [`GREAT_POWER_COUNT`] nations holding the most provinces
+pub fn rank_great_powers(province_counts: impl IntoIterator<Item = (Entity, usize)>) -> HashSet<Entity> {
+    let mut ranked: Vec<(Entity, usize)> = province_counts.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1));
+    ranked.into_iter().take(GREAT_POWER_COUNT).map(|(entity, _)| entity).collect()
+}
+
+/// Rank nations by territory and return the great powers
+fn great_powers(nations: &Query<(Entity, &Nation, Option<&Controls>, &Governance, &NationId)>) -> HashSet<Entity> {
+    rank_great_powers(
+        nations
+            .iter()
+            .map(|(entity, _, controls, _, _)| (entity, controls.map_or(0, Controls::province_count))),
+    )
+}
+
+/// Pick provinces for cession, spreading outward from the victor's border
+fn select_ceded_provinces(
+    victor: Entity,
+    vanquished_provinces: &[Entity],
+    count: usize,
+    neighbors_query: &Query<&ProvinceNeighbors>,
+    controlled_by_query: &Query<&ControlledBy>,
+) -> Vec<Entity> {
+    let owned: HashSet<Entity> = vanquished_provinces.iter().copied().collect();
+    let mut queue: VecDeque<Entity> = vanquished_provinces
+        .iter()
+        .copied()
+        .filter(|&province| {
+            neighbors_query.get(province).is_ok_and(|n| {
+                n.iter_valid().any(|neighbor| controlled_by_query.get(neighbor).is_ok_and(|c| c.0 == victor))
+            })
+        })
+        .collect();
+
+    let mut selected = Vec::with_capacity(count);
+    let mut visited: HashSet<Entity> = queue.iter().copied().collect();
+    while let Some(province) = queue.pop_front() {
+        if selected.len() >= count {
+            break;
+        }
+        selected.push(province);
+        if let Ok(neighbors) = neighbors_query.get(province) {
+            for neighbor in neighbors.iter_valid() {
+                if owned.contains(&neighbor) && visited.insert(neighbor) {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+    }
+    selected
+}
+
+/// Convene a congress when a long great-power war ends
+pub fn convene_congress_on_great_war_end(
+    mut commands: Commands,
+    mut war_end_events: MessageReader<WarEndEvent>,
+    mut ownership: OwnershipService,
+    mut transition_events: MessageWriter<GovernmentTransition>,
+    mut concluded_events: MessageWriter<CongressConcludedEvent>,
+    mut congress_history: ResMut<CongressHistory>,
+    wars_query: Query<(&War, Option<&WarParticipants>)>,
+    attackers_query: Query<(Entity, &Attacking)>,
+    nations_query: Query<(Entity, &Nation, Option<&Controls>, &Governance, &NationId)>,
+    government_history_query: Query<&GovernmentHistory>,
+    mut histories_query: Query<&mut NationHistory>,
+    neighbors_query: Query<&ProvinceNeighbors>,
+    controlled_by_query: Query<&ControlledBy>,
+    province_data_query: Query<&ProvinceData>,
+    game_time: Res<GameTime>,
+    mut ids: ResMut<IdAllocator>,
+) {
+    let mut convened = HashSet::new();
+    let mut great_power_cache: Option<HashSet<Entity>> = None;
+
+    for event in war_end_events.read() {
+        if matches!(event.outcome, WarOutcome::WhitePeace) || !convened.insert(event.war_id) {
+            continue;
+        }
+        let Some((war, participants)) = wars_query.iter().find(|(war, _)| war.war_id == event.war_id) else {
+            continue;
+        };
+        if war.battles_fought < MIN_CONGRESS_BATTLES {
+            continue;
+        }
+        let participants = participants.map(|p| p.participants()).unwrap_or(&[]);
+        let Some((attacker, defender)) = attackers_query
+            .iter()
+            .find(|(entity, attacking)| participants.contains(entity) && participants.contains(&attacking.0))
+            .map(|(entity, attacking)| (entity, attacking.0))
+        else {
+            continue;
+        };
+
+        let powers = great_power_cache.get_or_insert_with(|| great_powers(&nations_query));
+        if !powers.contains(&attacker) && !powers.contains(&defender) {
+            continue;
+        }
+
+        let (victor, vanquished) = match event.outcome {
+            WarOutcome::AttackerVictory => (attacker, defender),
+            _ => (defender, attacker),
+        };
+        let (Ok(victor_data), Ok(vanquished_data)) = (nations_query.get(victor), nations_query.get(vanquished)) else {
+            continue;
+        };
+
+        // Seat the delegates
+        let delegate_for = |entity: Entity, role: DelegateRole| {
+            nations_query.get(entity).ok().map(|(_, nation, controls, governance, _)| Delegate {
+                entity,
+                role,
+                leverage: nation.military_strength.max(0.0)
+                    + controls.map_or(0.0, |c| c.province_count() as f32),
+                diplomacy: nation.personality.diplomacy,
+                monarchic: governance.government_type.category() == GovernmentCategory::Monarchic,
+            })
+        };
+        let mut delegates: Vec<Delegate> = [
+            delegate_for(victor, DelegateRole::Victor),
+            delegate_for(vanquished, DelegateRole::Vanquished),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        delegates.extend(
+            powers
+                .iter()
+                .filter(|&&power| power != victor && power != vanquished)
+                .take(MAX_MEDIATORS)
+                .filter_map(|&power| delegate_for(power, DelegateRole::Mediator)),
+        );
+
+        // A monarchy overthrown at home can be put back on the throne
+        let deposed_monarchy = (vanquished_data.3.government_type.category() != GovernmentCategory::Monarchic)
+            .then(|| government_history_query.get(vanquished).ok())
+            .flatten()
+            .and_then(|history| {
+                std::iter::once(history.founding_government)
+                    .flatten()
+                    .chain(history.changes.iter().map(|change| change.to))
+                    .filter(|government| government.category() == GovernmentCategory::Monarchic)
+                    .last()
+            });
+
+        let settlement = negotiate_settlement(&delegates, war.war_score.abs(), deposed_monarchy.is_some());
+        let year = game_time.current_year();
+        let host_name = &victor_data.1.name;
+        let congress_name = format!("Congress of {}", host_name);
+
+        // Redraw the map
+        let vanquished_provinces = vanquished_data.2.map(|c| c.provinces()).unwrap_or(&[]);
+        let cede_count = (vanquished_provinces.len() as f32 * settlement.cession_share).round() as usize;
+        // The vanquished always keeps its heartland
+        let cede_count = cede_count.min(vanquished_provinces.len().saturating_sub(1));
+        let ceded = select_ceded_provinces(
+            victor,
+            vanquished_provinces,
+            cede_count,
+            &neighbors_query,
+            &controlled_by_query,
+        );
+
+        let mut buffer_state_name = None;
+        let buffer_id = if settlement.buffer_state && ceded.len() >= MIN_BUFFER_STATE_PROVINCES {
+            ids.allocate::<NationId>().ok()
+        } else {
+            None
+        };
+        let (to_victor, to_buffer) = if buffer_id.is_some() {
+            // Land furthest from the victor's border forms the buffer
+            let split = ceded.len() / 2;
+            (ceded[..split].to_vec(), ceded[split..].to_vec())
+        } else {
+            (ceded.clone(), Vec::new())
+        };
+
+        if let (Some(&buffer_capital), Some(buffer_id)) = (to_buffer.first(), buffer_id) {
+            let (buffer_entity, buffer_name) = spawn_breakaway_nation(
+                &mut commands,
+                buffer_id,
+                vanquished_data.1,
+                province_data_query.get(buffer_capital).map_or(ProvinceId::default(), |data| data.id),
+                year,
+                GovernmentType::ConstitutionalMonarchy,
+                // Buffer states exist to keep the peace
+                NationPersonality {
+                    aggression: -0.5,
+                    expansionism: -0.5,
+                    diplomacy: 0.8,
+                    mercantilism: 0.3,
+                },
+                "Regency Council",
+            );
+            // The buffer state flies a variation of its former ruler's flag
+            commands.entity(buffer_entity).insert(crate::nations::HeraldicParent(vanquished));
+            ownership.transfer(to_buffer, buffer_entity, OwnershipChangeType::Diplomatic);
+            buffer_state_name = Some(buffer_name);
+        }
+
+        ownership.transfer(to_victor.clone(), victor, OwnershipChangeType::Diplomatic);
+
+        let mut restored_monarchy = None;
+        if let (true, Some(monarchy)) = (settlement.restore_monarchy, deposed_monarchy) {
+            transition_events.write(GovernmentTransition {
+                nation_entity: vanquished,
+                from_government: vanquished_data.3.government_type,
+                to_government: monarchy,
+                transition_type: TransitionType::ForeignImposed,
+                peaceful: true,
+            });
+            restored_monarchy = Some(vanquished_data.1.name.clone());
+        }
+
+        // Record the landmark
+        let record = CongressRecord {
+            year,
+            name: congress_name.clone(),
+            delegates: delegates
+                .iter()
+                .filter_map(|d| nations_query.get(d.entity).ok().map(|(_, n, _, _, _)| n.name.clone()))
+                .collect(),
+            provinces_ceded: ceded.len() as u32,
+            buffer_state: buffer_state_name,
+            restored_monarchy,
+        };
+        info!("{} concluded after {} rounds - {}", congress_name, settlement.rounds, record.summary());
+
+        for delegate in &delegates {
+            if let Ok(mut history) = histories_query.get_mut(delegate.entity) {
+                let provinces_changed = match delegate.role {
+                    DelegateRole::Victor => to_victor.len() as i32,
+                    DelegateRole::Vanquished => -(ceded.len() as i32),
+                    DelegateRole::Mediator => 0,
+                };
+                history.record_event(HistoricalEvent::CongressAttended {
+                    year,
+                    congress: congress_name.clone(),
+                    provinces_changed,
+                });
+            }
+        }
+
+        congress_history.congresses.push(record);
+        concluded_events.write(CongressConcludedEvent {
+            name: congress_name,
+            year,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn delegate(role: DelegateRole, leverage: f32, monarchic: bool) -> Delegate {
+        Delegate {
+            entity: Entity::PLACEHOLDER,
+            role,
+            leverage,
+            diplomacy: 0.0,
+            monarchic,
+        }
+    }
+
+    #[test]
+    fn mediators_reduce_victor_demands() {
+        let alone = negotiate_settlement(
+            &[delegate(DelegateRole::Victor, 100.0, false), delegate(DelegateRole::Vanquished, 50.0, false)],
+            100.0,
+            false,
+        );
+        let mediated = negotiate_settlement(
+            &[
+                delegate(DelegateRole::Victor, 100.0, false),
+                delegate(DelegateRole::Vanquished, 50.0, false),
+                delegate(DelegateRole::Mediator, 150.0, false),
+            ],
+            100.0,
+            false,
+        );
+
+        assert!(mediated.cession_share < alone.cession_share);
+        assert!(alone.cession_share <= MAX_CESSION_SHARE);
+    }
+
+    #[test]
+    fn monarchies_restore_deposed_monarchy() {
+        let settlement = negotiate_settlement(
+            &[
+                delegate(DelegateRole::Victor, 100.0, true),
+                delegate(DelegateRole::Vanquished, 80.0, false),
+                delegate(DelegateRole::Mediator, 40.0, false),
+            ],
+            60.0,
+            true,
+        );
+
+        assert!(settlement.restore_monarchy);
+    }
+}
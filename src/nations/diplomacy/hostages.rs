@@ -0,0 +1,597 @@
+//! Hostages, tribute, and ransom - the diplomacy of pre-modern courts
+//!
+//! Before nationalism, diplomacy runs through rulers and their households.
+//! A strong, aggressive ruler demands tribute from a weak neighbor, and the
+//! neighbor weighs the threat against the demander's reputation: paying only
+//! buys peace from someone known to keep their word. Tribute agreed becomes a
+//! tributary pact of yearly payments and a truce; tribute refused is an
+//! insult remembered, and the demander starts laying in stores for war.
+//!
+//! Decisive battles can leave a ruler, an heir, or a general in enemy hands.
+//! The captive's nation offers ransom if it trusts the captor to honor the
+//! bargain. An honorable captor frees the captive on payment; a faithless
+//! one pockets the gold and keeps the prisoner, ruining its reputation.
+//!
+//! Treaties between pre-modern courts are sealed with hostages, children of
+//! the ruling house raised at the other court. A nation will not march on a
+//! court holding its hostages, and one that breaks such a treaty anyway
+//! condemns them and is remembered as an oathbreaker.
+
+use bevy::prelude::*;
+use rand::Rng;
+use std::collections::HashSet;
+
+use super::treaties::{SignTreatyEvent, Treaty, TreatyClause, TreatyCompliance, TreatyKind, TreatyViolatedEvent};
+use crate::ai::{decision_rng, score_considerations, Consideration, DecisionDomain, ResponseCurve};
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::math::{Fixed32, Money};
+use crate::nations::warfare::BattleResolvedEvent;
+use crate::nations::{
+    is_nationalism_era, Character, CharacterRole, House, LandNeighbors, Logistics, Nation, NationIndex,
+    ParticipatesInWar,
+};
+use crate::relationships::RulesOver;
+use crate::simulation::{GameTime, NewYearEvent};
+use crate::world::WorldSeed;
+
+/// Aggression at which a ruler demands tribute from weaker neighbors
+const TRIBUTE_AGGRESSION: f32 = 0.5;
+/// Yearly chance an aggressive ruler makes a demand
+const TRIBUTE_DEMAND_CHANCE: f32 = 0.1;
+/// Strength a demander needs, as a multiple of its target's, to be taken seriously
+const TRIBUTE_STRENGTH_RATIO: f32 = 2.0;
+/// Yearly tribute, as a share of the tributary's treasury when it submits
+const TRIBUTE_SHARE: Fixed32 = Fixed32::from_ratio(2, 25);
+/// Length of a tributary pact
+const TRIBUTARY_YEARS: u32 = 20;
+/// Utility at which a threatened nation submits
+const SUBMIT_UTILITY: f32 = 0.5;
+/// Opinion the demander loses of a nation that refuses it
+const REFUSAL_OPINION: f32 = -0.3;
+/// Opinion a nation loses of a ruler who threatens it
+const THREAT_OPINION: f32 = -0.15;
+/// Battle magnitude at which the loser may lose commanders to capture
+const CAPTURE_MAGNITUDE: f32 = 0.5;
+/// Chances of capturing the ruler, the heir, or a general in a decisive battle
+const RULER_CAPTURE_CHANCE: f32 = 0.03;
+const HEIR_CAPTURE_CHANCE: f32 = 0.06;
+const GENERAL_CAPTURE_CHANCE: f32 = 0.2;
+/// Utility at which a nation pays ransom
+const RANSOM_UTILITY: f32 = 0.5;
+/// Honor and reputation a captor needs to free a captive once paid
+const HONORABLE_CAPTOR: f32 = 0.4;
+/// Trust a captor gains by honoring a ransom
+const RANSOM_HONORED_TRUST: f32 = 0.05;
+/// Trust a captor loses by keeping both gold and captive
+const RANSOM_BETRAYED_TRUST: f32 = 0.25;
+/// Opinion the captive's nation loses of a captor who cheats it
+const RANSOM_BETRAYED_OPINION: f32 = -0.5;
+/// Stability a nation loses each year its ruler is a prisoner
+const CAPTIVE_RULER_STABILITY: f32 = 0.03;
+/// Years a captive survives in prison
+const CAPTIVITY_YEARS: u32 = 15;
+/// Trust an oathbreaker loses on top of the broken treaty when its hostages die for it
+const HOSTAGE_EXECUTION_TRUST: f32 = 0.1;
+
+/// Who was taken in battle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum CaptiveRank {
+    Ruler,
+    Heir,
+    General,
+}
+
+impl CaptiveRank {
+    /// Ransom demanded, as a share of the captive nation's treasury at capture
+    pub fn ransom_share(&self) -> Fixed32 {
+        match self {
+            CaptiveRank::Ruler => Fixed32::from_ratio(3, 10),
+            CaptiveRank::Heir => Fixed32::from_ratio(1, 5),
+            CaptiveRank::General => Fixed32::from_ratio(1, 20),
+        }
+    }
+
+    /// How much a nation cares to have the captive back (0.0-1.0)
+    fn worth(&self) -> f32 {
+        match self {
+            CaptiveRank::Ruler => 1.0,
+            CaptiveRank::Heir => 0.8,
+            CaptiveRank::General => 0.5,
+        }
+    }
+}
+
+/// A prisoner held for ransom
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct Captive {
+    /// Nation the captive belongs to
+    pub nation: Entity,
+    pub rank: CaptiveRank,
+    pub name: String,
+    pub ransom: Money,
+    pub since: u32,
+    /// Whether the captor already took a ransom and kept the captive
+    pub ransom_betrayed: bool,
+}
+
+/// Prisoners a nation holds for ransom
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct Captives(pub Vec<Captive>);
+
+/// How willing a nation is to submit to a tribute demand (0.0-1.0)
+///
+/// `strength_ratio` is the demander's strength over the target's, `trust`
+/// the demander's reputation for keeping its word, and `pride` the target's
+/// aggression.
+pub fn submission_utility(strength_ratio: f32, trust: f32, pride: f32) -> f32 {
+    score_considerations(&[
+        Consideration::new(
+            (strength_ratio - 1.0) / 4.0,
+            ResponseCurve::Logistic {
+                midpoint: 0.4,
+                steepness: 8.0,
+            },
+        ),
+        Consideration::new(
+            trust,
+            ResponseCurve::Logistic {
+                midpoint: 0.4,
+                steepness: 10.0,
+            },
+        ),
+        Consideration::new(pride, ResponseCurve::Inverse),
+    ])
+}
+
+/// How willing a nation is to pay a captor's ransom (0.0-1.0)
+pub fn ransom_utility(rank: CaptiveRank, captor_trust: f32) -> f32 {
+    score_considerations(&[
+        Consideration::new(
+            rank.worth(),
+            ResponseCurve::Linear {
+                slope: 1.0,
+                offset: 0.0,
+            },
+        ),
+        Consideration::new(
+            captor_trust,
+            ResponseCurve::Logistic {
+                midpoint: 0.3,
+                steepness: 10.0,
+            },
+        ),
+    ])
+}
+
+/// Hostage clauses sealing a treaty between two pre-modern courts
+///
+/// A tributary or defeated payer sends hostages to the nation it pays;
+/// otherwise the signatories exchange them.
+pub fn hostage_clauses(signatories: [Entity; 2], clauses: &[TreatyClause]) -> Vec<TreatyClause> {
+    if !clauses
+        .iter()
+        .any(|clause| matches!(clause, TreatyClause::Truce | TreatyClause::MutualDefense))
+    {
+        return Vec::new();
+    }
+    let tribute = clauses.iter().find_map(|clause| match clause {
+        TreatyClause::Tribute { payer, recipient, .. } => Some((*payer, *recipient)),
+        _ => None,
+    });
+    match tribute {
+        Some((payer, recipient)) => vec![TreatyClause::Hostages {
+            giver: payer,
+            holder: recipient,
+        }],
+        None => {
+            let [a, b] = signatories;
+            vec![
+                TreatyClause::Hostages { giver: a, holder: b },
+                TreatyClause::Hostages { giver: b, holder: a },
+            ]
+        }
+    }
+}
+
+/// Whether `holder` keeps hostages from `giver` under any treaty
+pub fn holds_hostages<'a>(treaties: impl IntoIterator<Item = &'a Treaty>, holder: Entity, giver: Entity) -> bool {
+    treaties.into_iter().any(|treaty| {
+        treaty
+            .clauses
+            .iter()
+            .any(|clause| *clause == TreatyClause::Hostages { giver, holder })
+    })
+}
+
+/// Aggressive pre-modern rulers demand tribute from weaker neighbors
+pub fn demand_tribute(
+    mut year_events: MessageReader<NewYearEvent>,
+    mut sign_events: MessageWriter<SignTreatyEvent>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    world_seed: Option<Res<WorldSeed>>,
+    nation_index: Res<NationIndex>,
+    mut nations_query: Query<(
+        Entity,
+        &Nation,
+        Option<&LandNeighbors>,
+        Option<&ParticipatesInWar>,
+        Option<&mut TreatyCompliance>,
+    )>,
+    mut logistics_query: Query<&mut Logistics>,
+    treaties_query: Query<&Treaty>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+    let seed = world_seed.map_or(0, |seed| seed.0);
+
+    let mut demanders: Vec<(Entity, Vec<Entity>)> = nations_query
+        .iter()
+        .filter(|(_, nation, _, war, _)| {
+            war.is_none() && nation.personality.aggression >= TRIBUTE_AGGRESSION && !is_nationalism_era(nation, year)
+        })
+        .filter_map(|(entity, _, neighbors, _, _)| Some((entity, neighbors?.neighbors().to_vec())))
+        .collect();
+    // Query order is not stable between runs; sort so the seeded rolls are
+    demanders.sort();
+
+    let mut threatened: HashSet<Entity> = HashSet::new();
+    for (demander, neighbors) in demanders {
+        let Some(actor) = nation_index.id(demander).map(|id| id.value()) else {
+            continue;
+        };
+        if decision_rng(seed, DecisionDomain::Diplomacy, actor, 4, year).r#gen::<f32>() >= TRIBUTE_DEMAND_CHANCE {
+            continue;
+        }
+        let Ok((_, demander_nation, _, _, demander_compliance)) = nations_query.get(demander) else {
+            continue;
+        };
+        let strength = demander_nation.military_strength;
+        let trust = demander_compliance.map_or(0.5, |compliance| compliance.trust);
+        let demander_name = demander_nation.name.clone();
+
+        // The weakest neighbor at peace that does not already pay or hold our hostages
+        let target = neighbors
+            .iter()
+            .copied()
+            .filter(|target| !threatened.contains(target))
+            .filter_map(|target| {
+                let (_, nation, _, war, _) = nations_query.get(target).ok()?;
+                let ratio = strength / nation.military_strength.max(1.0);
+                let eligible = war.is_none()
+                    && ratio >= TRIBUTE_STRENGTH_RATIO
+                    && !is_nationalism_era(nation, year)
+                    && !treaties_query
+                        .iter()
+                        .any(|treaty| treaty.binds(demander, target) && treaty.kind == TreatyKind::Tributary)
+                    && !holds_hostages(&treaties_query, target, demander);
+                eligible.then_some((target, ratio))
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.cmp(&a.0)));
+        let Some((target, ratio)) = target else {
+            continue;
+        };
+        threatened.insert(target);
+
+        let Ok((_, target_nation, _, _, target_compliance)) = nations_query.get_mut(target) else {
+            continue;
+        };
+        let target_name = target_nation.name.clone();
+        let amount = (target_nation.treasury * TRIBUTE_SHARE).max(Money::ZERO);
+        let nations = [target, demander]
+            .into_iter()
+            .filter_map(|n| nation_index.id(n))
+            .collect();
+
+        if submission_utility(ratio, trust, target_nation.personality.aggression) >= SUBMIT_UTILITY {
+            sign_events.write(SignTreatyEvent {
+                kind: TreatyKind::Tributary,
+                signatories: [target, demander],
+                clauses: vec![
+                    TreatyClause::Truce,
+                    TreatyClause::Tribute {
+                        payer: target,
+                        recipient: demander,
+                        amount,
+                    },
+                ],
+                duration_years: Some(TRIBUTARY_YEARS),
+            });
+            chronicle.write(ChronicleEvent {
+                category: ChronicleCategory::Politics,
+                text: format!(
+                    "{} bows to the threats of {} and pays {:.0} gold a year in tribute",
+                    target_name, demander_name, amount
+                ),
+                nations,
+            });
+            continue;
+        }
+
+        if let Some(mut compliance) = target_compliance {
+            compliance.adjust_opinion(demander, THREAT_OPINION);
+        }
+        if let Ok((_, _, _, _, Some(mut compliance))) = nations_query.get_mut(demander) {
+            compliance.adjust_opinion(target, REFUSAL_OPINION);
+        }
+        // The threat was not idle: the demander lays in stores for war
+        if let Ok(mut logistics) = logistics_query.get_mut(demander) {
+            logistics.prepare_for(target);
+        }
+        chronicle.write(ChronicleEvent {
+            category: ChronicleCategory::Politics,
+            text: format!("{} refuses to pay tribute to {}", target_name, demander_name),
+            nations,
+        });
+    }
+}
+
+/// Decisive battles in pre-modern wars leave rulers, heirs, and generals in enemy hands
+pub fn take_captives(
+    mut commands: Commands,
+    mut resolved_events: MessageReader<BattleResolvedEvent>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    world_seed: Option<Res<WorldSeed>>,
+    nation_index: Res<NationIndex>,
+    game_time: Res<GameTime>,
+    nations_query: Query<&Nation>,
+    mut captives_query: Query<&mut Captives>,
+    houses_query: Query<(Entity, &House, &RulesOver)>,
+    characters_query: Query<&Character>,
+) {
+    let seed = world_seed.map_or(0, |seed| seed.0);
+    let year = game_time.current_year();
+    let mut taken: Vec<(Entity, Captive)> = Vec::new();
+
+    for event in resolved_events.read() {
+        if event.magnitude < CAPTURE_MAGNITUDE {
+            continue;
+        }
+        let (Ok(winner), Ok(loser)) = (nations_query.get(event.winner), nations_query.get(event.loser)) else {
+            continue;
+        };
+        if is_nationalism_era(loser, year) {
+            continue;
+        }
+        let Some(actor) = nation_index.id(event.loser).map(|id| id.value()) else {
+            continue;
+        };
+        let subject = 5 | ((event.war_id as u64) << 8);
+        let roll = decision_rng(seed, DecisionDomain::Military, actor, subject, game_time.current_day()).r#gen::<f32>();
+        let rank = if roll < RULER_CAPTURE_CHANCE {
+            CaptiveRank::Ruler
+        } else if roll < HEIR_CAPTURE_CHANCE {
+            CaptiveRank::Heir
+        } else if roll < GENERAL_CAPTURE_CHANCE {
+            CaptiveRank::General
+        } else {
+            continue;
+        };
+
+        let already_held = |captives: &[Captive]| {
+            captives
+                .iter()
+                .any(|captive| captive.nation == event.loser && captive.rank == rank && rank != CaptiveRank::General)
+        };
+        let held = captives_query.iter().any(|captives| already_held(&captives.0))
+            || taken
+                .iter()
+                .any(|(_, captive)| already_held(std::slice::from_ref(captive)));
+        if held {
+            continue;
+        }
+
+        let house = houses_query.iter().find(|(_, _, rules)| rules.0 == event.loser);
+        let name = match rank {
+            CaptiveRank::Ruler => house.map(|(_, house, _)| format!("{} {}", house.ruler.title, house.ruler.name)),
+            CaptiveRank::Heir => house.and_then(|(house_entity, _, _)| {
+                characters_query
+                    .iter()
+                    .find(|character| character.house_id == house_entity && character.role == CharacterRole::Heir)
+                    .map(|character| format!("the heir {}", character.name))
+            }),
+            CaptiveRank::General => Some("a general".to_string()),
+        };
+        let Some(name) = name.map(|name| format!("{} of {}", name, loser.name)) else {
+            continue;
+        };
+
+        chronicle.write(ChronicleEvent {
+            category: ChronicleCategory::War,
+            text: format!(
+                "{} is taken prisoner by {} after a crushing defeat",
+                capitalize(&name),
+                winner.name
+            ),
+            nations: [event.loser, event.winner]
+                .into_iter()
+                .filter_map(|n| nation_index.id(n))
+                .collect(),
+        });
+        taken.push((
+            event.winner,
+            Captive {
+                nation: event.loser,
+                rank,
+                name,
+                ransom: (loser.treasury * rank.ransom_share()).max(Money::ZERO),
+                since: year,
+                ransom_betrayed: false,
+            },
+        ));
+    }
+
+    for (captor, captive) in taken {
+        match captives_query.get_mut(captor) {
+            Ok(mut captives) => captives.0.push(captive),
+            Err(_) => {
+                commands.entity(captor).insert(Captives(vec![captive]));
+            }
+        }
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .map_or_else(String::new, |first| first.to_uppercase().chain(chars).collect())
+}
+
+/// Yearly ransoms: trusted captors are paid and free their prisoners, faithless ones cheat
+pub fn settle_ransoms(
+    mut year_events: MessageReader<NewYearEvent>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    nation_index: Res<NationIndex>,
+    mut captors_query: Query<(Entity, &mut Captives)>,
+    mut nations_query: Query<(&mut Nation, Option<&mut TreatyCompliance>)>,
+    houses_query: Query<(&House, &RulesOver)>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+
+    for (captor, mut captives) in &mut captors_query {
+        let Ok((captor_nation, captor_compliance)) = nations_query.get(captor) else {
+            continue;
+        };
+        let captor_name = captor_nation.name.clone();
+        let captor_trust = captor_compliance.map_or(0.5, |compliance| compliance.trust);
+        let captor_honor = houses_query
+            .iter()
+            .find(|(_, rules)| rules.0 == captor)
+            .map_or(0.5, |(house, _)| house.ruler.personality.honor);
+        let honorable = (captor_honor + captor_trust) / 2.0 >= HONORABLE_CAPTOR;
+        let mut received = Money::ZERO;
+        let mut honored = 0;
+        let mut betrayed = false;
+
+        captives.0.retain_mut(|captive| {
+            let nations = [captive.nation, captor]
+                .into_iter()
+                .filter_map(|n| nation_index.id(n))
+                .collect();
+            let Ok((mut nation, compliance)) = nations_query.get_mut(captive.nation) else {
+                return false;
+            };
+
+            if year >= captive.since + CAPTIVITY_YEARS {
+                chronicle.write(ChronicleEvent {
+                    category: ChronicleCategory::Dynasty,
+                    text: format!("{} dies a prisoner of {}", capitalize(&captive.name), captor_name),
+                    nations,
+                });
+                return false;
+            }
+
+            let pays = !captive.ransom_betrayed
+                && nation.treasury >= captive.ransom
+                && ransom_utility(captive.rank, captor_trust) >= RANSOM_UTILITY;
+            if !pays {
+                if captive.rank == CaptiveRank::Ruler {
+                    nation.stability = (nation.stability - CAPTIVE_RULER_STABILITY).max(0.0);
+                }
+                return true;
+            }
+
+            nation.treasury -= captive.ransom;
+            received += captive.ransom;
+            if honorable {
+                honored += 1;
+                chronicle.write(ChronicleEvent {
+                    category: ChronicleCategory::Politics,
+                    text: format!(
+                        "{} is ransomed from {} for {}",
+                        capitalize(&captive.name),
+                        captor_name,
+                        captive.ransom
+                    ),
+                    nations,
+                });
+                return false;
+            }
+
+            if let Some(mut compliance) = compliance {
+                compliance.adjust_opinion(captor, RANSOM_BETRAYED_OPINION);
+            }
+            captive.ransom_betrayed = true;
+            betrayed = true;
+            chronicle.write(ChronicleEvent {
+                category: ChronicleCategory::Politics,
+                text: format!(
+                    "{} takes {} in ransom for {} and keeps the prisoner",
+                    captor_name, captive.ransom, captive.name
+                ),
+                nations,
+            });
+            true
+        });
+
+        // The captor banks the gold, and is known for keeping or breaking its bargains
+        let Ok((mut captor_nation, captor_compliance)) = nations_query.get_mut(captor) else {
+            continue;
+        };
+        captor_nation.treasury += received;
+        if let Some(mut compliance) = captor_compliance {
+            let change = honored as f32 * RANSOM_HONORED_TRUST - if betrayed { RANSOM_BETRAYED_TRUST } else { 0.0 };
+            compliance.trust = (compliance.trust + change).clamp(0.0, 1.0);
+        }
+    }
+}
+
+/// Hostages held under a treaty die when their own nation breaks it
+pub fn execute_hostages(
+    mut violation_events: MessageReader<TreatyViolatedEvent>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    nation_index: Res<NationIndex>,
+    treaties_query: Query<&Treaty>,
+    mut nations_query: Query<(&Nation, Option<&mut TreatyCompliance>)>,
+) {
+    let mut condemned: HashSet<Entity> = HashSet::new();
+    for event in violation_events.read() {
+        let Ok(treaty) = treaties_query.get(event.treaty) else {
+            continue;
+        };
+        if !condemned.insert(event.treaty) || !holds_hostages([treaty], event.wronged, event.violator) {
+            continue;
+        }
+        let (Ok((violator, _)), Ok((wronged, _))) =
+            (nations_query.get(event.violator), nations_query.get(event.wronged))
+        else {
+            continue;
+        };
+        chronicle.write(ChronicleEvent {
+            category: ChronicleCategory::Dynasty,
+            text: format!(
+                "{} puts to death the hostages of {}, whose rulers broke their oath",
+                wronged.name, violator.name
+            ),
+            nations: [event.wronged, event.violator]
+                .into_iter()
+                .filter_map(|n| nation_index.id(n))
+                .collect(),
+        });
+        if let Ok((_, Some(mut compliance))) = nations_query.get_mut(event.violator) {
+            compliance.trust = (compliance.trust - HOSTAGE_EXECUTION_TRUST).max(0.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_trusted_and_overwhelming_threats_extract_tribute_and_ransom() {
+        let submits = submission_utility(5.0, 0.8, 0.1);
+        assert!(submits >= SUBMIT_UTILITY);
+        assert!(submission_utility(5.0, 0.1, 0.1) < SUBMIT_UTILITY);
+        assert!(submission_utility(2.0, 0.8, 0.1) < submits);
+        assert!(submission_utility(5.0, 0.8, 0.9) < SUBMIT_UTILITY);
+
+        assert!(ransom_utility(CaptiveRank::Ruler, 0.7) >= RANSOM_UTILITY);
+        assert!(ransom_utility(CaptiveRank::Ruler, 0.05) < RANSOM_UTILITY);
+        assert!(ransom_utility(CaptiveRank::General, 0.7) < ransom_utility(CaptiveRank::Ruler, 0.7));
+    }
+}
@@ -5,11 +5,34 @@
 //! - CB validation and cost calculation
 //! - Pressure-triggered war declarations
 //! - Available CB evaluation for AI decision making
+//! - Treaties with enforceable clauses and compliance tracking
+//! - International congresses that settle great-power wars
+//! - Tribute demands, ransomed captives, and hostages in pre-modern eras
+//! - Trade agreements with tariff cuts, route priority, and navigation rights
 
 mod casus_belli;
+mod congress;
+mod hostages;
 mod systems;
+mod trade_agreements;
+mod treaties;
 mod war_triggers;
 
 pub use casus_belli::{CasusBelliExt, FabricatingClaim};
+pub use congress::{
+    clear_congress_history, convene_congress_on_great_war_end, negotiate_settlement,
+    rank_great_powers, CongressConcludedEvent, CongressHistory, CongressRecord, Delegate, DelegateRole,
+    Settlement, GREAT_POWER_COUNT,
+};
+pub use hostages::{
+    demand_tribute, execute_hostages, settle_ransoms, take_captives, Captive, CaptiveRank, Captives,
+};
 pub use systems::evaluate_available_casus_belli;
+pub use trade_agreements::{link_trade_partners, settle_trade_agreements, TradeFlow, TradeTerms, TARIFF_RATE};
 pub use war_triggers::evaluate_war_triggers_from_pressure;
+pub use treaties::{
+    check_treaty_compliance, detect_war_declaration_violations, handle_treaty_violations,
+    initialize_treaty_compliance, process_treaty_signings, propose_ai_treaties,
+    sign_peace_on_war_end, SavedClause, SavedTreaty, SignTreatyEvent, Treaty, TreatyClause, TreatyCompliance, TreatyKind,
+    TreatyViolatedEvent,
+};
@@ -3,7 +3,7 @@
 //! Systems for evaluating available casus belli options.
 
 use bevy::prelude::*;
-use crate::nations::{Nation, Governance};
+use crate::nations::{Nation, Governance, LostCores};
 use crate::nations::warfare::CasusBelli;
 use super::casus_belli::CasusBelliExt;
 
@@ -16,9 +16,16 @@ pub fn evaluate_available_casus_belli(
     target: &Nation,
     target_governance: &Governance,
     is_neighbor: bool,
+    lost_cores: Option<&LostCores>,
+    target_entity: Entity,
 ) -> Vec<CasusBelli> {
     let mut available = Vec::new();
 
+    // Target holds provinces that are still our cores
+    if lost_cores.is_some_and(|lost| !lost.held_by(target_entity).is_empty()) {
+        available.push(CasusBelli::Reconquest);
+    }
+
     // Check each CB type
     if CasusBelli::can_justify(
         CasusBelli::BorderDispute,
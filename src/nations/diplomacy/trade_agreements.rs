@@ -0,0 +1,287 @@
+//! Trade agreements - what a trade pact actually changes
+//!
+//! A trade pact's clauses set its terms: tariffs cut between the
+//! signatories, priority for their merchants on each other's routes, and
+//! navigation rights that open each other's harbors to shipping. Each year
+//! the trade between the signatories is weighed twice - as it flows under the
+//! agreement, and as it would flow without it. Each side earns the taxable
+//! margin on the extra trade and gives up the customs it no longer levies on
+//! the other's goods. The flows are kept on the treaty entity (`TradeFlow`)
+//! for the statistics record, and each pact links its signatories as
+//! `TradePartners` (see `link_trade_partners`). Trade on a route shrinks with merchants'
+//! confidence in it, which falls where no one will insure their cargoes
+//! (see `nations::insurance`).
+//!
+//! Trading courts propose agreements where they project the largest gain,
+//! and their partners sign only if they project a gain too.
+
+use bevy::prelude::*;
+
+use super::treaties::{Treaty, TreatyClause};
+use crate::math::Money;
+use crate::nations::{ContactStance, EconomicLedger, LandNeighbors, Nation, RouteInsurance};
+use crate::relationships::{TradeLink, TradesWithNation};
+use crate::simulation::NewYearEvent;
+
+/// Customs levied on trade between nations without an agreement
+pub const TARIFF_RATE: f32 = 0.2;
+/// Trade between two economies, per unit of their combined output
+const TRADE_INTENSITY: f32 = 0.1;
+/// Growth in trade per unit of tariff cut
+const TARIFF_ELASTICITY: f32 = 3.0;
+/// Extra trade when merchants have priority on each other's routes
+const ROUTE_PRIORITY_BOOST: f32 = 0.15;
+/// Share of seaborne trade that gets through without navigation rights
+const SEA_TRADE_WITHOUT_RIGHTS: f32 = 0.5;
+/// Taxable margin on each unit of trade
+const TRADE_MARGIN: f32 = 0.4;
+/// Smallest tariff cut a trading court offers
+const MIN_TARIFF_CUT: f32 = 0.25;
+/// Smallest yearly gain worth negotiating for
+pub const MIN_PROJECTED_GAIN: f32 = 1.0;
+
+/// What a trade agreement grants its signatories
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TradeTerms {
+    /// Share of tariffs between the signatories removed (0.0-1.0)
+    pub tariff_cut: f32,
+    pub route_priority: bool,
+    pub navigation_rights: bool,
+}
+
+impl TradeTerms {
+    /// Terms granted by a treaty's clauses
+    pub fn of(clauses: &[TreatyClause]) -> Self {
+        clauses.iter().fold(Self::default(), |mut terms, clause| {
+            match clause {
+                TreatyClause::TariffReduction { share } => terms.tariff_cut = terms.tariff_cut.max(*share),
+                TreatyClause::RoutePriority => terms.route_priority = true,
+                TreatyClause::NavigationRights => terms.navigation_rights = true,
+                _ => {}
+            }
+            terms
+        })
+    }
+
+    /// Terms two courts would offer each other, from how much they care for trade
+    pub fn proposed(mercantilism_a: f32, mercantilism_b: f32, by_sea: bool) -> Self {
+        Self {
+            tariff_cut: ((mercantilism_a + mercantilism_b) / 2.0).clamp(MIN_TARIFF_CUT, 1.0),
+            route_priority: true,
+            navigation_rights: by_sea,
+        }
+    }
+
+    /// Treaty clauses granting these terms
+    pub fn clauses(&self) -> Vec<TreatyClause> {
+        let mut clauses = vec![
+            TreatyClause::TradePact,
+            TreatyClause::TariffReduction { share: self.tariff_cut },
+        ];
+        if self.route_priority {
+            clauses.push(TreatyClause::RoutePriority);
+        }
+        if self.navigation_rights {
+            clauses.push(TreatyClause::NavigationRights);
+        }
+        clauses
+    }
+}
+
+/// Trade under a treaty this year, against what it would be without the treaty
+#[derive(Component, Debug, Clone, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct TradeFlow {
+    pub volume: f32,
+    pub baseline: f32,
+    /// Trade without the treaty in its first year, to measure it against
+    pub first_baseline: f32,
+    /// Each signatory's yearly gain, in signatory order
+    pub gains: [f32; 2],
+}
+
+/// Yearly trade between two economies
+///
+/// `openness` is the share of foreign trade both courts let through, and
+/// `by_sea` whether their goods travel by ship rather than overland.
+pub fn trade_volume(output_a: f32, output_b: f32, openness: f32, by_sea: bool, terms: &TradeTerms) -> f32 {
+    let gravity = (output_a.max(0.0) * output_b.max(0.0)).sqrt() * TRADE_INTENSITY * openness;
+    let reach = if by_sea && !terms.navigation_rights {
+        SEA_TRADE_WITHOUT_RIGHTS
+    } else {
+        1.0
+    };
+    let tariffs = 1.0 + TARIFF_ELASTICITY * TARIFF_RATE * terms.tariff_cut.clamp(0.0, 1.0);
+    let priority = if terms.route_priority {
+        1.0 + ROUTE_PRIORITY_BOOST
+    } else {
+        1.0
+    };
+    gravity * reach * tariffs * priority
+}
+
+/// Each side's yearly gain from an agreement: half the margin on the extra
+/// trade, less the customs it no longer levies on the other's goods
+///
+/// Customs are levied on imports, which follow the partner's share of the
+/// combined output, so the smaller economy gives up more.
+pub fn agreement_gains(output_a: f32, output_b: f32, baseline: f32, volume: f32, tariff_cut: f32) -> [f32; 2] {
+    let margin = (volume - baseline) * TRADE_MARGIN / 2.0;
+    let customs = baseline * TARIFF_RATE * tariff_cut.clamp(0.0, 1.0);
+    let total = (output_a + output_b).max(f32::EPSILON);
+    [
+        margin - customs * output_b.max(0.0) / total,
+        margin - customs * output_a.max(0.0) / total,
+    ]
+}
+
+/// Trade and each side's gain for a pair of nations under `terms`
+pub fn project_agreement(
+    output_a: f32,
+    output_b: f32,
+    openness: f32,
+    by_sea: bool,
+    terms: &TradeTerms,
+) -> (f32, f32, [f32; 2]) {
+    let baseline = trade_volume(output_a, output_b, openness, by_sea, &TradeTerms::default());
+    let volume = trade_volume(output_a, output_b, openness, by_sea, terms);
+    let gains = agreement_gains(output_a, output_b, baseline, volume, terms.tariff_cut);
+    (baseline, volume, gains)
+}
+
+/// Link the signatories of each new trade pact as trade partners
+///
+/// Each side gets a `TradeLink` child of the treaty, so the links go when the
+/// treaty does. Loaded treaties are new too, and are linked the same way.
+pub fn link_trade_partners(mut commands: Commands, treaties_query: Query<(Entity, &Treaty), Added<Treaty>>) {
+    for (pact, treaty) in &treaties_query {
+        if !treaty.clauses.contains(&TreatyClause::TradePact) {
+            continue;
+        }
+        let [a, b] = treaty.signatories;
+        for (trader, partner) in [(a, b), (b, a)] {
+            commands.spawn((TradeLink { pact, trader }, TradesWithNation(partner), ChildOf(pact)));
+        }
+    }
+}
+
+/// Settle a year of trade under every trade agreement
+pub fn settle_trade_agreements(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut treaties_query: Query<(Entity, &Treaty, Option<&mut TradeFlow>, Option<&RouteInsurance>)>,
+    mut nations_query: Query<(&mut Nation, Option<&EconomicLedger>, Option<&ContactStance>)>,
+    land_neighbors_query: Query<&LandNeighbors>,
+) {
+    if year_events.read().last().is_none() {
+        return;
+    }
+
+    for (treaty_entity, treaty, flow, insurance) in &mut treaties_query {
+        if !treaty.clauses.contains(&TreatyClause::TradePact) {
+            continue;
+        }
+        let [a, b] = treaty.signatories;
+        let economy = |nations: &Query<(&mut Nation, Option<&EconomicLedger>, Option<&ContactStance>)>,
+                       nation: Entity| {
+            nations.get(nation).ok().map(|(_, ledger, stance)| {
+                (
+                    ledger.map_or(0.0, |ledger| ledger.output),
+                    stance.map_or(1.0, |stance| stance.policy.trade_share()),
+                )
+            })
+        };
+        let (Some((output_a, open_a)), Some((output_b, open_b))) =
+            (economy(&nations_query, a), economy(&nations_query, b))
+        else {
+            continue;
+        };
+        let by_sea = !land_neighbors_query
+            .get(a)
+            .is_ok_and(|neighbors| neighbors.neighbors().contains(&b));
+        let terms = TradeTerms::of(&treaty.clauses);
+        // Merchants who can't insure a route stop running it
+        let confidence = insurance.map_or(1.0, |insurance| insurance.confidence);
+        let openness = open_a * open_b * confidence;
+        let (baseline, volume, gains) = project_agreement(output_a, output_b, openness, by_sea, &terms);
+
+        for (nation, gain) in [a, b].into_iter().zip(gains) {
+            if let Ok((mut nation, ..)) = nations_query.get_mut(nation) {
+                nation.treasury += Money::from_f32(gain);
+            }
+        }
+
+        match flow {
+            Some(mut flow) => {
+                flow.volume = volume;
+                flow.baseline = baseline;
+                flow.gains = gains;
+            }
+            None => {
+                commands.entity(treaty_entity).insert(TradeFlow {
+                    volume,
+                    baseline,
+                    first_baseline: baseline,
+                    gains,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nations::TreatyKind;
+    use crate::relationships::TradePartners;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn agreements_grow_trade_and_pay_both_sides() {
+        let terms = TradeTerms::proposed(0.7, 0.7, true);
+        assert_eq!(TradeTerms::of(&terms.clauses()), terms);
+
+        let (baseline, volume, [big, small]) = project_agreement(9000.0, 1000.0, 1.0, true, &terms);
+        assert!(volume > baseline * 2.0, "navigation rights double seaborne trade");
+        assert!(big > 0.0 && small > 0.0);
+        assert!(big > small, "the smaller economy gives up more customs");
+
+        // Free trade overland with a much larger neighbor costs the small side its customs
+        let free_trade = TradeTerms {
+            tariff_cut: 1.0,
+            ..TradeTerms::default()
+        };
+        let (_, _, [_, small]) = project_agreement(100_000.0, 100.0, 1.0, false, &free_trade);
+        assert!(small < 0.0);
+    }
+
+    #[test]
+    fn trade_pacts_link_their_signatories_until_they_end() {
+        let mut world = World::new();
+        let [first, second, outsider] = [(); 3].map(|_| world.spawn_empty().id());
+        let pact = world
+            .spawn(Treaty {
+                kind: TreatyKind::TradePact,
+                signatories: [first, second],
+                clauses: TradeTerms::proposed(0.7, 0.7, false).clauses(),
+                signed_year: 1000,
+                expires_year: Some(1020),
+            })
+            .id();
+        world
+            .run_system_once(link_trade_partners)
+            .expect("link_trade_partners runs");
+
+        let mut links = world.query::<&TradeLink>();
+        let links = links.query(&world);
+        let partners = |nation: Entity| world.get::<TradePartners>(nation);
+        let first_partners = partners(first).expect("first has a trade partner");
+        assert!(first_partners.trades_with(second, &links));
+        assert!(!first_partners.trades_with(outsider, &links));
+        assert_eq!(partners(second).map(|partners| partners.partner_count()), Some(1));
+
+        world.despawn(pact);
+        let first_partners = world.get::<TradePartners>(first);
+        assert!(first_partners.is_none_or(|partners| !partners.has_trade_partners()));
+    }
+}
@@ -0,0 +1,823 @@
+//! Treaty system - enforceable agreements between nations
+//!
+//! Treaties are standalone entities carrying a set of clauses and a duration.
+//! Peace treaties are signed automatically when a war ends, diplomatic AIs
+//! propose alliances, trading AIs negotiate trade pacts by projected gains
+//! (see `trade_agreements`), and a yearly compliance check catches
+//! unpaid tribute and broken demilitarization terms. Treaties between
+//! pre-modern courts are sealed with hostages. Violations cost the
+//! violator trust and opinion, and the wronged party may go to war to
+//! enforce the terms.
+
+use bevy::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use crate::nations::{is_nationalism_era, Nation, NationId, ParticipatesInWar, WarParticipants, Attacking, LandNeighbors};
+use crate::nations::warfare::{CasusBelli, DeclareWarEvent, War, WarEndEvent, WarGoal, WarOutcome};
+use crate::ai::{decision_rng, AiBehavior, AiDrive, DecisionDomain};
+use crate::audio::{AudioCue, AudioEvent};
+use crate::math::{Fixed32, Money};
+use crate::simulation::{GameTime, NewYearEvent};
+use crate::world::WorldSeed;
+use super::hostages::hostage_clauses;
+use crate::nations::{ContactPolicy, ContactStance, EconomicLedger, NavalNeighbors};
+use super::trade_agreements::{project_agreement, TradeTerms, MIN_PROJECTED_GAIN};
+
+/// Truce length after any war
+const PEACE_TRUCE_YEARS: u32 = 10;
+
+/// Tribute paid by the loser each year, as a share of its treasury when peace was signed
+const WAR_TRIBUTE_SHARE: Fixed32 = Fixed32::from_ratio(1, 20);
+
+/// Military cap imposed on a subjugated nation, as a share of its strength at peace
+const DEMILITARIZATION_SHARE: f32 = 0.5;
+
+/// Length of AI-proposed alliances and trade pacts
+const ALLIANCE_YEARS: u32 = 25;
+const TRADE_PACT_YEARS: u32 = 15;
+
+/// Yearly chance a diplomatic nation proposes a new treaty
+const PROPOSAL_CHANCE: f64 = 0.1;
+
+/// Minimum trust before a nation will sign voluntary treaties
+const MIN_TRUST_TO_SIGN: f32 = 0.3;
+
+/// Trust and opinion changes when a treaty is broken
+const VIOLATION_TRUST_PENALTY: f32 = 0.2;
+const VIOLATION_OPINION_PENALTY_WRONGED: f32 = 0.5;
+const VIOLATION_OPINION_PENALTY_OTHERS: f32 = 0.1;
+
+/// Trust regained per year of keeping every treaty
+const YEARLY_TRUST_RECOVERY: f32 = 0.02;
+
+/// A single enforceable term of a treaty
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub enum TreatyClause {
+    /// Signatories may not declare war on each other
+    Truce,
+    /// Signatories defend each other and may not declare war on each other
+    MutualDefense,
+    /// Signatories trade freely; war between them breaks the pact
+    TradePact,
+    /// `payer` sends `amount` gold to `recipient` every year
+    Tribute {
+        payer: Entity,
+        recipient: Entity,
+        amount: Money,
+    },
+    /// `nation` keeps its military strength at or below `max_strength`
+    Demilitarization {
+        nation: Entity,
+        max_strength: f32,
+    },
+    /// Children of `giver`'s ruling house live at `holder`'s court as surety
+    Hostages {
+        giver: Entity,
+        holder: Entity,
+    },
+    /// `nation` keeps its borders and ports open to foreign trade
+    OpenPorts {
+        nation: Entity,
+    },
+    /// Signatories remove `share` of the tariffs on each other's goods
+    TariffReduction {
+        share: f32,
+    },
+    /// Signatories' merchants have priority on each other's trade routes
+    RoutePriority,
+    /// Signatories' ships may use each other's harbors and coastal waters
+    NavigationRights,
+}
+
+impl TreatyClause {
+    /// Whether declaring war on a co-signatory breaks this clause
+    pub fn forbids_war(&self) -> bool {
+        matches!(self, TreatyClause::Truce | TreatyClause::MutualDefense | TreatyClause::TradePact)
+    }
+
+    /// Short description for UI display
+    pub fn describe(&self, names: &HashMap<Entity, String>) -> String {
+        let name = |entity: &Entity| names.get(entity).cloned().unwrap_or_else(|| "Unknown".to_string());
+        match self {
+            TreatyClause::Truce => "Truce".to_string(),
+            TreatyClause::MutualDefense => "Mutual defense".to_string(),
+            TreatyClause::TradePact => "Trade pact".to_string(),
+            TreatyClause::Tribute { payer, recipient, amount } => {
+                format!("{} pays {}/year to {}", name(payer), amount, name(recipient))
+            }
+            TreatyClause::Demilitarization { nation, max_strength } => {
+                format!("{} army capped at {:.0}", name(nation), max_strength)
+            }
+            TreatyClause::Hostages { giver, holder } => {
+                format!("{} holds hostages from {}", name(holder), name(giver))
+            }
+            TreatyClause::OpenPorts { nation } => format!("{} keeps its ports open", name(nation)),
+            TreatyClause::TariffReduction { share } => format!("Tariffs cut by {:.0}%", share * 100.0),
+            TreatyClause::RoutePriority => "Route priority for merchants".to_string(),
+            TreatyClause::NavigationRights => "Navigation rights".to_string(),
+        }
+    }
+}
+
+/// A treaty clause as written to a save, naming nations by stable id
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SavedClause {
+    Truce,
+    MutualDefense,
+    TradePact,
+    Tribute { payer: NationId, recipient: NationId, amount: Money },
+    Demilitarization { nation: NationId, max_strength: f32 },
+    Hostages { giver: NationId, holder: NationId },
+    OpenPorts { nation: NationId },
+    TariffReduction { share: f32 },
+    RoutePriority,
+    NavigationRights,
+}
+
+impl TreatyClause {
+    /// Stable-id form for saving (None if a named nation has no id)
+    fn to_saved(&self, id_of: &impl Fn(Entity) -> Option<NationId>) -> Option<SavedClause> {
+        Some(match self {
+            TreatyClause::Truce => SavedClause::Truce,
+            TreatyClause::MutualDefense => SavedClause::MutualDefense,
+            TreatyClause::TradePact => SavedClause::TradePact,
+            TreatyClause::Tribute { payer, recipient, amount } => {
+                SavedClause::Tribute { payer: id_of(*payer)?, recipient: id_of(*recipient)?, amount: *amount }
+            }
+            TreatyClause::Demilitarization { nation, max_strength } => {
+                SavedClause::Demilitarization { nation: id_of(*nation)?, max_strength: *max_strength }
+            }
+            TreatyClause::Hostages { giver, holder } => {
+                SavedClause::Hostages { giver: id_of(*giver)?, holder: id_of(*holder)? }
+            }
+            TreatyClause::OpenPorts { nation } => SavedClause::OpenPorts { nation: id_of(*nation)? },
+            TreatyClause::TariffReduction { share } => SavedClause::TariffReduction { share: *share },
+            TreatyClause::RoutePriority => SavedClause::RoutePriority,
+            TreatyClause::NavigationRights => SavedClause::NavigationRights,
+        })
+    }
+}
+
+impl SavedClause {
+    /// Live form after loading (None if a named nation no longer exists)
+    fn restore(&self, entity_of: &impl Fn(NationId) -> Option<Entity>) -> Option<TreatyClause> {
+        Some(match self {
+            SavedClause::Truce => TreatyClause::Truce,
+            SavedClause::MutualDefense => TreatyClause::MutualDefense,
+            SavedClause::TradePact => TreatyClause::TradePact,
+            SavedClause::Tribute { payer, recipient, amount } => {
+                TreatyClause::Tribute { payer: entity_of(*payer)?, recipient: entity_of(*recipient)?, amount: *amount }
+            }
+            SavedClause::Demilitarization { nation, max_strength } => {
+                TreatyClause::Demilitarization { nation: entity_of(*nation)?, max_strength: *max_strength }
+            }
+            SavedClause::Hostages { giver, holder } => {
+                TreatyClause::Hostages { giver: entity_of(*giver)?, holder: entity_of(*holder)? }
+            }
+            SavedClause::OpenPorts { nation } => TreatyClause::OpenPorts { nation: entity_of(*nation)? },
+            SavedClause::TariffReduction { share } => TreatyClause::TariffReduction { share: *share },
+            SavedClause::RoutePriority => TreatyClause::RoutePriority,
+            SavedClause::NavigationRights => TreatyClause::NavigationRights,
+        })
+    }
+}
+
+/// What kind of agreement a treaty is (drives its display name)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+pub enum TreatyKind {
+    Peace,
+    Alliance,
+    TradePact,
+    /// The first signatory pays tribute to the second for peace
+    Tributary,
+}
+
+impl TreatyKind {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            TreatyKind::Peace => "Peace Treaty",
+            TreatyKind::Alliance => "Alliance",
+            TreatyKind::TradePact => "Trade Pact",
+            TreatyKind::Tributary => "Tributary Pact",
+        }
+    }
+}
+
+/// An active treaty between two nations
+///
+/// Nations can be party to any number of treaties, so signatories are stored
+/// on the treaty entity rather than as a relationship component.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct Treaty {
+    pub kind: TreatyKind,
+    pub signatories: [Entity; 2],
+    pub clauses: Vec<TreatyClause>,
+    pub signed_year: u32,
+    /// Year the treaty lapses (None = permanent until broken)
+    pub expires_year: Option<u32>,
+}
+
+impl Treaty {
+    /// Check if a nation signed this treaty
+    pub fn involves(&self, nation: Entity) -> bool {
+        self.signatories.contains(&nation)
+    }
+
+    /// Check if this treaty binds both nations
+    pub fn binds(&self, a: Entity, b: Entity) -> bool {
+        self.involves(a) && self.involves(b)
+    }
+
+    /// The other signatory
+    pub fn counterpart(&self, nation: Entity) -> Option<Entity> {
+        match self.signatories {
+            [a, b] if a == nation => Some(b),
+            [a, b] if b == nation => Some(a),
+            _ => None,
+        }
+    }
+
+    /// Whether a war between the signatories would break this treaty
+    pub fn forbids_war(&self) -> bool {
+        self.clauses.iter().any(TreatyClause::forbids_war)
+    }
+
+    /// Years until expiry (None for permanent treaties)
+    pub fn years_remaining(&self, current_year: u32) -> Option<u32> {
+        self.expires_year.map(|year| year.saturating_sub(current_year))
+    }
+
+    /// Stable-id form for saving (None if a party has no id)
+    pub fn to_saved(&self, id_of: impl Fn(Entity) -> Option<NationId>) -> Option<SavedTreaty> {
+        let [a, b] = self.signatories;
+        Some(SavedTreaty {
+            kind: self.kind,
+            signatories: [id_of(a)?, id_of(b)?],
+            clauses: self.clauses.iter().map(|clause| clause.to_saved(&id_of)).collect::<Option<_>>()?,
+            signed_year: self.signed_year,
+            expires_year: self.expires_year,
+        })
+    }
+}
+
+/// A treaty as written to a save, with its parties by stable id
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedTreaty {
+    pub kind: TreatyKind,
+    pub signatories: [NationId; 2],
+    pub clauses: Vec<SavedClause>,
+    pub signed_year: u32,
+    pub expires_year: Option<u32>,
+}
+
+impl SavedTreaty {
+    /// Live treaty after loading (None if a party no longer exists)
+    pub fn restore(&self, entity_of: impl Fn(NationId) -> Option<Entity>) -> Option<Treaty> {
+        let [a, b] = self.signatories;
+        Some(Treaty {
+            kind: self.kind,
+            signatories: [entity_of(a)?, entity_of(b)?],
+            clauses: self.clauses.iter().map(|clause| clause.restore(&entity_of)).collect::<Option<_>>()?,
+            signed_year: self.signed_year,
+            expires_year: self.expires_year,
+        })
+    }
+}
+
+/// Whether a treaty in force forbids war between `a` and `b`
+pub fn forbids_war_between<'a>(treaties: impl IntoIterator<Item = &'a Treaty>, a: Entity, b: Entity) -> bool {
+    treaties.into_iter().any(|treaty| treaty.binds(a, b) && treaty.forbids_war())
+}
+
+/// How reliably a nation keeps its word, and what it thinks of others
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct TreatyCompliance {
+    /// Reputation for honoring treaties (0.0 = oathbreaker, 1.0 = impeccable)
+    pub trust: f32,
+    /// Opinion of other nations (-1.0 = hated, 1.0 = beloved)
+    pub opinions: HashMap<Entity, f32>,
+    /// Total treaties broken
+    pub violations: u32,
+}
+
+impl Default for TreatyCompliance {
+    fn default() -> Self {
+        Self {
+            trust: 0.7,
+            opinions: HashMap::new(),
+            violations: 0,
+        }
+    }
+}
+
+impl TreatyCompliance {
+    /// Opinion of another nation (neutral if never interacted)
+    pub fn opinion_of(&self, nation: Entity) -> f32 {
+        self.opinions.get(&nation).copied().unwrap_or(0.0)
+    }
+
+    /// Shift opinion of another nation
+    pub fn adjust_opinion(&mut self, nation: Entity, delta: f32) {
+        let opinion = self.opinions.entry(nation).or_insert(0.0);
+        *opinion = (*opinion + delta).clamp(-1.0, 1.0);
+    }
+}
+
+/// Message: two nations sign a treaty
+#[derive(Message, Debug, Clone)]
+pub struct SignTreatyEvent {
+    pub kind: TreatyKind,
+    pub signatories: [Entity; 2],
+    pub clauses: Vec<TreatyClause>,
+    pub duration_years: Option<u32>,
+}
+
+/// Message: a treaty clause was broken
+#[derive(Message, Debug, Clone)]
+pub struct TreatyViolatedEvent {
+    pub treaty: Entity,
+    pub violator: Entity,
+    pub wronged: Entity,
+    pub clause: TreatyClause,
+}
+
+/// Give every nation a compliance record
+pub fn initialize_treaty_compliance(
+    mut commands: Commands,
+    nations_query: Query<Entity, (With<Nation>, Without<TreatyCompliance>)>,
+) {
+    for entity in &nations_query {
+        commands.entity(entity).insert(TreatyCompliance::default());
+    }
+}
+
+/// Spawn treaty entities for signed agreements
+pub fn process_treaty_signings(
+    mut commands: Commands,
+    mut sign_events: MessageReader<SignTreatyEvent>,
+    game_time: Res<GameTime>,
+    nations_query: Query<&Nation>,
+    mut audio: MessageWriter<AudioEvent>,
+) {
+    let year = game_time.current_year();
+    for event in sign_events.read() {
+        let [a, b] = event.signatories;
+        let (Ok(nation_a), Ok(nation_b)) = (nations_query.get(a), nations_query.get(b)) else {
+            continue;
+        };
+
+        // Pre-modern courts seal their treaties with hostages
+        let mut clauses = event.clauses.clone();
+        if !is_nationalism_era(nation_a, year) && !is_nationalism_era(nation_b, year) {
+            clauses.extend(hostage_clauses(event.signatories, &event.clauses));
+        }
+
+        commands.spawn(Treaty {
+            kind: event.kind,
+            signatories: event.signatories,
+            clauses,
+            signed_year: year,
+            expires_year: event.duration_years.map(|years| year + years),
+        });
+
+        info!(
+            "{} signed between {} and {}",
+            event.kind.display_name(),
+            nation_a.name,
+            nation_b.name
+        );
+        audio.write(AudioEvent::new(AudioCue::TreatySigned));
+    }
+}
+
+/// Turn finished wars into peace treaties and dissolve the war
+pub fn sign_peace_on_war_end(
+    mut commands: Commands,
+    mut war_end_events: MessageReader<WarEndEvent>,
+    mut sign_events: MessageWriter<SignTreatyEvent>,
+    wars_query: Query<(Entity, &War, Option<&WarParticipants>)>,
+    attackers_query: Query<(Entity, &Attacking)>,
+    nations_query: Query<&Nation>,
+) {
+    let mut concluded = HashSet::new();
+    for event in war_end_events.read() {
+        if !concluded.insert(event.war_id) {
+            continue;
+        }
+        let Some((war_entity, war, participants)) = wars_query.iter().find(|(_, war, _)| war.war_id == event.war_id) else {
+            continue;
+        };
+        let participants = participants.map(|p| p.participants()).unwrap_or(&[]);
+
+        // The attacker is the participant pointing at another participant
+        let Some((attacker, defender)) = attackers_query
+            .iter()
+            .find(|(entity, attacking)| participants.contains(entity) && participants.contains(&attacking.0))
+            .map(|(entity, attacking)| (entity, attacking.0))
+        else {
+            continue;
+        };
+
+        let mut clauses = vec![TreatyClause::Truce];
+        let (winner, loser) = match event.outcome {
+            WarOutcome::AttackerVictory => (Some(attacker), Some(defender)),
+            WarOutcome::DefenderVictory => (Some(defender), Some(attacker)),
+            WarOutcome::WhitePeace => (None, None),
+        };
+        if let (Some(winner), Some(loser)) = (winner, loser) {
+            if let Ok(loser_nation) = nations_query.get(loser) {
+                clauses.push(TreatyClause::Tribute {
+                    payer: loser,
+                    recipient: winner,
+                    amount: (loser_nation.treasury * WAR_TRIBUTE_SHARE).max(Money::ZERO),
+                });
+                if matches!(war.war_goal, WarGoal::Subjugation | WarGoal::Humiliation) && winner == attacker {
+                    clauses.push(TreatyClause::Demilitarization {
+                        nation: loser,
+                        max_strength: loser_nation.military_strength * DEMILITARIZATION_SHARE,
+                    });
+                }
+                if war.war_goal == WarGoal::OpenPorts && winner == attacker {
+                    clauses.push(TreatyClause::OpenPorts { nation: loser });
+                }
+            }
+        }
+
+        sign_events.write(SignTreatyEvent {
+            kind: TreatyKind::Peace,
+            signatories: [attacker, defender],
+            clauses,
+            duration_years: Some(PEACE_TRUCE_YEARS),
+        });
+
+        // Detach participants before despawning - WarParticipants is linked_spawn
+        for &participant in participants {
+            commands.entity(participant).remove::<ParticipatesInWar>();
+        }
+        commands.entity(attacker).remove::<Attacking>();
+        commands.entity(war_entity).despawn();
+    }
+}
+
+/// Diplomatic AIs propose alliances to friendly neighbors; trading AIs propose
+/// trade pacts to the neighbor they project the largest gain from
+pub fn propose_ai_treaties(
+    mut year_events: MessageReader<NewYearEvent>,
+    mut sign_events: MessageWriter<SignTreatyEvent>,
+    nations_query: Query<(
+        Entity,
+        &Nation,
+        &TreatyCompliance,
+        Option<&LandNeighbors>,
+        Option<&NavalNeighbors>,
+        Option<&EconomicLedger>,
+        &NationId,
+    )>,
+    treaties_query: Query<&Treaty>,
+    attackers_query: Query<&Attacking>,
+    stances_query: Query<&ContactStance>,
+    behavior: Res<AiBehavior>,
+    world_seed: Option<Res<WorldSeed>>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+    let seed = world_seed.map_or(0, |seed| seed.0);
+
+    let mut proposed: HashSet<(Entity, Entity)> = HashSet::new();
+
+    for (entity, nation, compliance, land_neighbors, naval_neighbors, ledger, nation_id) in &nations_query {
+        let wants_alliance = behavior.weigh(AiDrive::Diplomacy, nation.personality.diplomacy) > 0.6;
+        let wants_trade = behavior.weigh(AiDrive::Trade, nation.personality.mercantilism) > 0.6;
+        if !(wants_alliance || wants_trade) {
+            continue;
+        }
+        let mut rng = decision_rng(seed, DecisionDomain::Diplomacy, nation_id.value(), 5, year);
+        if !rng.gen_bool(PROPOSAL_CHANCE) {
+            continue;
+        }
+        let land = land_neighbors.map_or(&[][..], |neighbors| neighbors.neighbors());
+        let naval = naval_neighbors.map_or(&[][..], |neighbors| neighbors.neighbors());
+
+        let kind = if wants_alliance { TreatyKind::Alliance } else { TreatyKind::TradePact };
+        // Closed courts sign no trade pacts
+        let closed = |nation: Entity| {
+            stances_query
+                .get(nation)
+                .is_ok_and(|stance| stance.policy != ContactPolicy::Open)
+        };
+        if kind == TreatyKind::TradePact && closed(entity) {
+            continue;
+        }
+        let willing = |neighbor: Entity| {
+            let Ok((_, _, partner_compliance, ..)) = nations_query.get(neighbor) else {
+                return false;
+            };
+            let at_war = attackers_query.get(entity).is_ok_and(|a| a.0 == neighbor)
+                || attackers_query.get(neighbor).is_ok_and(|a| a.0 == entity);
+            let already_bound = treaties_query.iter().any(|t| t.kind == kind && t.binds(entity, neighbor));
+            let pair = (entity.min(neighbor), entity.max(neighbor));
+
+            !at_war
+                && !(kind == TreatyKind::TradePact && closed(neighbor))
+                && !already_bound
+                && !proposed.contains(&pair)
+                && partner_compliance.trust >= MIN_TRUST_TO_SIGN
+                && compliance.opinion_of(neighbor) >= 0.0
+                && partner_compliance.opinion_of(entity) >= 0.0
+        };
+
+        let partner = match kind {
+            TreatyKind::Alliance => land
+                .iter()
+                .copied()
+                .find(|&neighbor| willing(neighbor))
+                .map(|partner| (partner, vec![TreatyClause::MutualDefense], ALLIANCE_YEARS)),
+            _ => {
+                // Both sides must project a gain; the proposer picks its best
+                let output = ledger.map_or(0.0, |ledger| ledger.output);
+                let mut best: Option<(Entity, TradeTerms, f32)> = None;
+                for &neighbor in land.iter().chain(naval) {
+                    if !willing(neighbor) {
+                        continue;
+                    }
+                    let Ok((_, partner_nation, _, _, _, partner_ledger, _)) = nations_query.get(neighbor) else {
+                        continue;
+                    };
+                    let by_sea = !land.contains(&neighbor);
+                    let terms = TradeTerms::proposed(
+                        nation.personality.mercantilism,
+                        partner_nation.personality.mercantilism,
+                        by_sea,
+                    );
+                    let partner_output = partner_ledger.map_or(0.0, |ledger| ledger.output);
+                    let (_, _, [gain, partner_gain]) = project_agreement(output, partner_output, 1.0, by_sea, &terms);
+                    let beats_best = best.as_ref().is_none_or(|(_, _, best_gain)| gain > *best_gain);
+                    if gain >= MIN_PROJECTED_GAIN && partner_gain >= MIN_PROJECTED_GAIN && beats_best {
+                        best = Some((neighbor, terms, gain));
+                    }
+                }
+                best.map(|(partner, terms, _)| (partner, terms.clauses(), TRADE_PACT_YEARS))
+            }
+        };
+
+        if let Some((partner, clauses, years)) = partner {
+            proposed.insert((entity.min(partner), entity.max(partner)));
+            sign_events.write(SignTreatyEvent {
+                kind,
+                signatories: [entity, partner],
+                clauses,
+                duration_years: Some(years),
+            });
+        }
+    }
+}
+
+/// Declaring war on a co-signatory breaks every treaty forbidding it
+pub fn detect_war_declaration_violations(
+    mut war_events: MessageReader<DeclareWarEvent>,
+    mut violation_events: MessageWriter<TreatyViolatedEvent>,
+    treaties_query: Query<(Entity, &Treaty)>,
+) {
+    for event in war_events.read() {
+        for (treaty_entity, treaty) in &treaties_query {
+            if !treaty.binds(event.attacker, event.defender) {
+                continue;
+            }
+            if let Some(clause) = treaty.clauses.iter().find(|c| c.forbids_war()) {
+                violation_events.write(TreatyViolatedEvent {
+                    treaty: treaty_entity,
+                    violator: event.attacker,
+                    wronged: event.defender,
+                    clause: clause.clone(),
+                });
+            }
+        }
+    }
+}
+
+/// Yearly compliance pass: collect tribute, check demilitarization, expire treaties
+pub fn check_treaty_compliance(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut violation_events: MessageWriter<TreatyViolatedEvent>,
+    treaties_query: Query<(Entity, &Treaty)>,
+    mut nations_query: Query<(&mut Nation, Option<&mut TreatyCompliance>)>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+
+    let mut kept_faith: HashSet<Entity> = HashSet::new();
+    let mut broke_faith: HashSet<Entity> = HashSet::new();
+
+    for (treaty_entity, treaty) in &treaties_query {
+        // Treaties with a vanished signatory lapse
+        if treaty.signatories.iter().any(|&s| !nations_query.contains(s)) {
+            commands.entity(treaty_entity).despawn();
+            continue;
+        }
+
+        for clause in &treaty.clauses {
+            let violation = match clause {
+                TreatyClause::Tribute { payer, recipient, amount } => {
+                    let paid = match nations_query.get_mut(*payer) {
+                        Ok((mut payer_nation, _)) if payer_nation.treasury >= *amount => {
+                            payer_nation.treasury -= *amount;
+                            true
+                        }
+                        _ => false,
+                    };
+                    if paid {
+                        if let Ok((mut recipient_nation, _)) = nations_query.get_mut(*recipient) {
+                            recipient_nation.treasury += *amount;
+                        }
+                        None
+                    } else {
+                        Some((*payer, *recipient))
+                    }
+                }
+                TreatyClause::Demilitarization { nation, max_strength } => nations_query
+                    .get(*nation)
+                    .ok()
+                    .filter(|(n, _)| n.military_strength > *max_strength)
+                    .and_then(|_| treaty.counterpart(*nation).map(|wronged| (*nation, wronged))),
+                _ => None,
+            };
+
+            match violation {
+                Some((violator, wronged)) => {
+                    broke_faith.insert(violator);
+                    violation_events.write(TreatyViolatedEvent {
+                        treaty: treaty_entity,
+                        violator,
+                        wronged,
+                        clause: clause.clone(),
+                    });
+                }
+                None => {
+                    kept_faith.extend(treaty.signatories);
+                }
+            }
+        }
+
+        if treaty.expires_year.is_some_and(|expiry| year >= expiry) {
+            debug!("{} expired in year {}", treaty.kind.display_name(), year);
+            commands.entity(treaty_entity).despawn();
+        }
+    }
+
+    // Honoring treaties slowly rebuilds a damaged reputation
+    for nation in kept_faith.difference(&broke_faith) {
+        if let Ok((_, Some(mut compliance))) = nations_query.get_mut(*nation) {
+            compliance.trust = (compliance.trust + YEARLY_TRUST_RECOVERY).min(1.0);
+        }
+    }
+}
+
+/// Apply reputation damage for broken treaties and let the wronged party enforce them
+pub fn handle_treaty_violations(
+    mut commands: Commands,
+    mut violation_events: MessageReader<TreatyViolatedEvent>,
+    mut war_events: MessageWriter<DeclareWarEvent>,
+    treaties_query: Query<&Treaty>,
+    mut compliance_query: Query<(Entity, &Nation, &mut TreatyCompliance)>,
+    attackers_query: Query<&Attacking>,
+) {
+    let mut broken: HashSet<Entity> = HashSet::new();
+
+    for event in violation_events.read() {
+        if !broken.insert(event.treaty) {
+            continue;
+        }
+        let Ok(treaty) = treaties_query.get(event.treaty) else {
+            continue;
+        };
+
+        for (entity, _, mut compliance) in &mut compliance_query {
+            if entity == event.violator {
+                compliance.trust = (compliance.trust - VIOLATION_TRUST_PENALTY).max(0.0);
+                compliance.violations += 1;
+            } else if entity == event.wronged {
+                compliance.adjust_opinion(event.violator, -VIOLATION_OPINION_PENALTY_WRONGED);
+            } else {
+                compliance.adjust_opinion(event.violator, -VIOLATION_OPINION_PENALTY_OTHERS);
+            }
+        }
+
+        let names = compliance_query
+            .get(event.violator)
+            .ok()
+            .map(|(_, n, _)| n.name.clone())
+            .zip(compliance_query.get(event.wronged).ok().map(|(_, n, _)| n.name.clone()));
+        if let Some((violator_name, wronged_name)) = &names {
+            warn!(
+                "{} broke the {} with {} ({:?})",
+                violator_name,
+                treaty.kind.display_name(),
+                wronged_name,
+                event.clause
+            );
+        }
+
+        commands.entity(event.treaty).despawn();
+
+        // War-based violations already mean war; unpaid tribute and rearmament invite enforcement
+        if event.clause.forbids_war() {
+            continue;
+        }
+        let already_at_war = attackers_query.get(event.wronged).is_ok()
+            || attackers_query.get(event.violator).is_ok_and(|a| a.0 == event.wronged);
+        let Ok((_, wronged_nation, _)) = compliance_query.get(event.wronged) else {
+            continue;
+        };
+        let Ok((_, violator_nation, _)) = compliance_query.get(event.violator) else {
+            continue;
+        };
+        if !already_at_war && wronged_nation.military_strength > violator_nation.military_strength {
+            war_events.write(DeclareWarEvent {
+                attacker: event.wronged,
+                defender: event.violator,
+                war_goal: WarGoal::Humiliation,
+                casus_belli: CasusBelli::BrokenTreaty,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn treaty_finds_counterpart() {
+        let treaty = Treaty {
+            kind: TreatyKind::Alliance,
+            signatories: [Entity::PLACEHOLDER, Entity::PLACEHOLDER],
+            clauses: vec![TreatyClause::MutualDefense],
+            signed_year: 1000,
+            expires_year: Some(1025),
+        };
+
+        assert!(treaty.forbids_war());
+        assert_eq!(treaty.counterpart(Entity::PLACEHOLDER), Some(Entity::PLACEHOLDER));
+        assert_eq!(treaty.years_remaining(1010), Some(15));
+        assert_eq!(treaty.years_remaining(1030), Some(0));
+    }
+
+    #[test]
+    fn saved_treaty_round_trips_through_stable_ids() {
+        let mut world = World::new();
+        let first = world.spawn_empty().id();
+        let second = world.spawn_empty().id();
+        let ids = [(first, NationId::new(1)), (second, NationId::new(2))];
+        let treaty = Treaty {
+            kind: TreatyKind::Tributary,
+            signatories: [first, second],
+            clauses: vec![
+                TreatyClause::Truce,
+                TreatyClause::Tribute { payer: first, recipient: second, amount: Money::from_int(12) },
+            ],
+            signed_year: 1200,
+            expires_year: Some(1210),
+        };
+
+        let id_of = |entity: Entity| ids.iter().find(|(e, _)| *e == entity).map(|(_, id)| *id);
+        let entity_of = |id: NationId| ids.iter().find(|(_, i)| *i == id).map(|(e, _)| *e);
+        let saved = treaty.to_saved(id_of).expect("both signatories have ids");
+        let restored = saved.restore(entity_of).expect("both signatories exist");
+
+        assert_eq!(restored.signatories, treaty.signatories);
+        assert_eq!(restored.clauses, treaty.clauses);
+        assert_eq!(restored.expires_year, Some(1210));
+        assert!(saved.restore(|_| None).is_none());
+    }
+
+    #[test]
+    fn truces_forbid_war_only_between_their_signatories() {
+        let mut world = World::new();
+        let [first, second, outsider] = [(); 3].map(|_| world.spawn_empty().id());
+        let truce = Treaty {
+            kind: TreatyKind::Peace,
+            signatories: [first, second],
+            clauses: vec![TreatyClause::Truce],
+            signed_year: 1000,
+            expires_year: Some(1010),
+        };
+
+        assert!(forbids_war_between([&truce], second, first));
+        assert!(!forbids_war_between([&truce], first, outsider));
+    }
+
+    #[test]
+    fn opinion_is_clamped() {
+        let mut compliance = TreatyCompliance::default();
+        compliance.adjust_opinion(Entity::PLACEHOLDER, -0.8);
+        compliance.adjust_opinion(Entity::PLACEHOLDER, -0.8);
+
+        assert_eq!(compliance.opinion_of(Entity::PLACEHOLDER), -1.0);
+    }
+}
@@ -2,14 +2,47 @@
 //!
 //! This module connects the pressure system to war declarations,
 //! making AI nations declare wars when military pressure is critical.
+//! Nations that have lost core territory are quicker to fight and aim
+//! their wars at whoever holds those cores.
+//!
+//! A nation that has picked its target does not march until its stockpile
+//! for the war is laid in, so the build-up gives watchers warning. Nor will
+//! it march on a court that holds its hostages, nor on a nation it is bound
+//! to by a truce, alliance, or trade pact. Trading powers fight closed
+//! neighbors to force their ports open rather than to take land.
+//!
+//! Targets are weighed partly on the best casus belli the attacker can
+//! press against them (see `evaluate_available_casus_belli`), and the war is
+//! declared under that claim. A nation with no claim fabricates one.
+//!
+//! Nations claiming the fields of a resource rush (see `ResourceRushes`)
+//! are as quick to fight as revanchists, favor whoever holds the fields,
+//! and go to war to take them.
 
 use bevy::prelude::*;
 use crate::simulation::{PressureVector, PressureType};
-use crate::nations::{Nation, NationHistory, Governance};
+use crate::nations::{
+    ContactPolicy, ContactStance, Nation, NationHistory, Governance, Logistics, LostCores, ResourceRushes,
+};
 use crate::nations::warfare::{DeclareWarEvent, WarGoal, CasusBelli};
 use super::casus_belli::CasusBelliExt;
+use super::systems::evaluate_available_casus_belli;
+use super::hostages::holds_hostages;
+use super::treaties::{forbids_war_between, Treaty};
+use crate::world::{Province, ProvinceStorage};
+use crate::math::Money;
+use crate::ai::{best_choice, score_considerations, AiBehavior, AiDrive, Consideration, ResponseCurve, UtilityChoice};
+
+/// Lost core provinces needed before a nation turns revanchist
+const REVANCHISM_MIN_LOST_CORES: usize = 3;
+
+/// Mercantilism at which a nation fights to open a closed neighbor's ports
+const FORCED_OPENING_MERCANTILISM: f32 = 0.3;
+
+/// Aggression needed to declare war (revanchists and rush claimants need less)
+const AGGRESSION_THRESHOLD: f32 = 0.6;
+const REVANCHIST_AGGRESSION_THRESHOLD: f32 = 0.4;
 
-/// System to check if high military pressure should trigger war
 /// System to check if high military pressure should trigger war
 pub fn evaluate_war_triggers_from_pressure(
     nations_query: Query<(
@@ -21,10 +54,18 @@ pub fn evaluate_war_triggers_from_pressure(
         &Governance,
         Option<&crate::nations::relationships::LandNeighbors>,
         Option<&crate::nations::relationships::NavalNeighbors>,
+        Option<&LostCores>,
     )>,
+    mut logistics_query: Query<&mut Logistics>,
+    treaties_query: Query<&Treaty>,
+    stances_query: Query<&ContactStance>,
+    rushes: Res<ResourceRushes>,
+    province_storage: Option<Res<ProvinceStorage>>,
+    behavior: Res<AiBehavior>,
     mut war_events: MessageWriter<DeclareWarEvent>,
 ) {
-    for (entity, nation_id, nation, pressures, history, _governance, land_neighbors, naval_neighbors) in &nations_query {
+    let provinces: &[Province] = province_storage.as_ref().map_or(&[], |storage| &storage.provinces);
+    for (entity, nation_id, nation, pressures, history, governance, land_neighbors, naval_neighbors, lost_cores) in &nations_query {
         // Check if military pressure is critical
         let Some(&mil_pressure) = pressures.pressures.get(&PressureType::MilitaryVulnerability) else {
             continue;
@@ -36,32 +77,90 @@ pub fn evaluate_war_triggers_from_pressure(
 
         // Determine if nation should declare war or seek alliance
         // Aggressive nations declare war, diplomatic nations seek allies
-        let is_aggressive = nation.personality.aggression > 0.6;
-        let can_afford = nation.treasury > 10000.0;
+        let is_revanchist = lost_cores.is_some_and(|lost| lost.total() >= REVANCHISM_MIN_LOST_CORES);
+        let is_rush_claimant = rushes.is_claimant(entity);
+        let aggression_threshold = if is_revanchist || is_rush_claimant {
+            REVANCHIST_AGGRESSION_THRESHOLD
+        } else {
+            AGGRESSION_THRESHOLD
+        };
+        let is_aggressive = behavior.weigh(AiDrive::Aggression, nation.personality.aggression) > aggression_threshold;
+        let can_afford = nation.treasury > Money::from_int(10_000);
         let has_recent_defeats = history.calculate_weighted_recent_defeats() > 1.0;
 
         if is_aggressive && can_afford && !has_recent_defeats {
+            // Reclaiming lost cores takes priority over opportunistic conquest
+            if let Some((target, provinces, target_name)) = lost_cores.and_then(|lost| {
+                find_reconquest_target(entity, lost, land_neighbors, naval_neighbors, &nations_query, &treaties_query)
+            }) {
+                if holds_hostages(&treaties_query, target, entity) {
+                    continue;
+                }
+                if let Ok(mut logistics) = logistics_query.get_mut(entity) {
+                    if !logistics.ready_for_war(target, nation.military_strength) {
+                        logistics.prepare_for(target);
+                        continue;
+                    }
+                }
+
+                info!(
+                    "{} declares war on {} to reconquer {} core provinces",
+                    nation.name, target_name, provinces.len()
+                );
+
+                war_events.write(DeclareWarEvent {
+                    attacker: entity,
+                    defender: target,
+                    war_goal: WarGoal::Liberation {
+                        provinces_to_liberate: provinces,
+                    },
+                    casus_belli: CasusBelli::Reconquest,
+                });
+                continue;
+            }
+
             // Look for weak neighbor to attack
-            if let Some(target) = find_weakest_neighbor(
+            if let Some(target) = find_war_target(
+                entity,
+                (*nation_id, nation, governance, lost_cores),
                 land_neighbors,
                 naval_neighbors,
-                &nations_query
+                &nations_query,
+                &treaties_query,
+                &rushes,
+                provinces,
+                &behavior,
             ) {
+                if holds_hostages(&treaties_query, target.0, entity) {
+                    continue;
+                }
+                if let Ok(mut logistics) = logistics_query.get_mut(entity) {
+                    if !logistics.ready_for_war(target.0, nation.military_strength) {
+                        logistics.prepare_for(target.0);
+                        continue;
+                    }
+                }
+
                 // Determine war goal and CB
-                let war_goal = WarGoal::Conquest {
-                    target_provinces: vec![], // TODO: Select specific provinces
-                };
-                
-                // Check if it's a land neighbor for Border Dispute CB
-                let is_land_neighbor = land_neighbors
-                    .map(|n| n.neighbors().contains(&target.0))
-                    .unwrap_or(false);
-                    
-                let casus_belli = if is_land_neighbor {
-                    CasusBelli::BorderDispute
+                let target_closed = stances_query
+                    .get(target.0)
+                    .is_ok_and(|stance| stance.policy != ContactPolicy::Open);
+                let rush_claims = rushes.claims_against(entity, target.0, provinces);
+                let claims_rush = !rush_claims.is_empty();
+                let war_goal = if claims_rush {
+                    WarGoal::Conquest {
+                        target_provinces: rush_claims,
+                    }
+                } else if target_closed && nation.personality.mercantilism >= FORCED_OPENING_MERCANTILISM {
+                    WarGoal::OpenPorts
                 } else {
-                    CasusBelli::FabricatedClaim
+                    WarGoal::Conquest {
+                        target_provinces: vec![], // TODO: Select specific provinces
+                    }
                 };
+                
+                // Rush fields are disputed land; otherwise press the claim the target was chosen on
+                let casus_belli = if claims_rush { CasusBelli::BorderDispute } else { target.3 };
 
                 war_events.write(DeclareWarEvent {
                     attacker: entity,
@@ -79,8 +178,42 @@ pub fn evaluate_war_triggers_from_pressure(
     }
 }
 
-/// Find weakest neighboring nation
-fn find_weakest_neighbor(
+/// Land neighbors make better targets than overseas ones
+const LAND_BORDER_APPEAL: f32 = 1.0;
+const NAVAL_BORDER_APPEAL: f32 = 0.6;
+/// Appeal of a neighbor holding no rush fields we claim, against one that does
+const UNCLAIMED_APPEAL: f32 = 0.5;
+/// Appeal of a neighbor we could only fight on a fabricated claim, against one we have full right to fight
+const UNJUSTIFIED_APPEAL: f32 = 0.5;
+
+/// The least damaging claim among those available
+///
+/// Fighting with no claim at all is left to players; an AI fabricates one instead.
+fn best_casus_belli(available: &[CasusBelli]) -> CasusBelli {
+    available
+        .iter()
+        .copied()
+        .filter(|cb| *cb != CasusBelli::NoCasusBelli)
+        .min_by(|a, b| a.aggression_penalty().total_cmp(&b.aggression_penalty()))
+        .unwrap_or(CasusBelli::FabricatedClaim)
+}
+
+/// Find the neighbor most worth attacking
+///
+/// Scores each neighbor on how weak it is relative to us, how easily our
+/// armies can reach it, and whether it holds rush fields we claim, and picks
+/// the best. Neighbors a treaty forbids us to fight are never chosen. How
+/// justified a war on each would be also counts, and the claim it would be
+/// fought under is returned with the target. The behavior pack's predation
+/// sharpens or flattens the weight given to weakness.
+fn find_war_target(
+    attacker: Entity,
+    (attacker_id, attacker_nation, attacker_governance, lost_cores): (
+        crate::nations::NationId,
+        &Nation,
+        &Governance,
+        Option<&LostCores>,
+    ),
     land_neighbors: Option<&crate::nations::relationships::LandNeighbors>,
     naval_neighbors: Option<&crate::nations::relationships::NavalNeighbors>,
     nations_query: &Query<(
@@ -92,30 +225,111 @@ fn find_weakest_neighbor(
         &Governance,
         Option<&crate::nations::relationships::LandNeighbors>,
         Option<&crate::nations::relationships::NavalNeighbors>,
+        Option<&LostCores>,
     )>,
-) -> Option<(Entity, crate::nations::NationId, Nation)> {
-    let mut best_target: Option<(Entity, crate::nations::NationId, Nation)> = None;
-    let mut min_strength = f32::MAX;
-
-    // Helper to process a list of neighbor entities
-    let mut process_neighbors = |entities: &[Entity]| {
-        for &neighbor_entity in entities {
-            if let Ok((_, neighbor_id, neighbor_nation, _, _, _, _, _)) = nations_query.get(neighbor_entity) {
-                if neighbor_nation.military_strength < min_strength {
-                    min_strength = neighbor_nation.military_strength;
-                    best_target = Some((neighbor_entity, *neighbor_id, neighbor_nation.clone()));
-                }
-            }
+    treaties: &Query<&Treaty>,
+    rushes: &ResourceRushes,
+    provinces: &[Province],
+    behavior: &AiBehavior,
+) -> Option<(Entity, crate::nations::NationId, Nation, CasusBelli)> {
+    let own_strength = attacker_nation.military_strength;
+    let land = land_neighbors
+        .map(|land| land.neighbors())
+        .unwrap_or(&[])
+        .iter()
+        .map(|&entity| (entity, LAND_BORDER_APPEAL));
+    let naval = naval_neighbors
+        .map(|naval| naval.neighbors())
+        .unwrap_or(&[])
+        .iter()
+        .map(|&entity| (entity, NAVAL_BORDER_APPEAL));
+
+    let choices = land.chain(naval).filter_map(|(neighbor_entity, reach)| {
+        if forbids_war_between(treaties, attacker, neighbor_entity) {
+            return None;
         }
-    };
+        let (_, neighbor_id, neighbor_nation, _, _, neighbor_governance, ..) = nations_query.get(neighbor_entity).ok()?;
+        let casus_belli = best_casus_belli(&evaluate_available_casus_belli(
+            attacker_id,
+            attacker_nation,
+            attacker_governance,
+            *neighbor_id,
+            neighbor_nation,
+            neighbor_governance,
+            land_neighbors.is_some_and(|land| land.neighbors().contains(&neighbor_entity)),
+            lost_cores,
+            neighbor_entity,
+        ));
+        let total_strength = (own_strength + neighbor_nation.military_strength).max(f32::EPSILON);
+        let claimed = !rushes.claims_against(attacker, neighbor_entity, provinces).is_empty();
+        let score = score_considerations(&[
+            // Share of the combined strength the target lacks
+            Consideration::new(
+                1.0 - neighbor_nation.military_strength / total_strength,
+                ResponseCurve::Polynomial { exponent: behavior.weights.get(AiDrive::Predation) },
+            ),
+            Consideration::new(reach, ResponseCurve::Linear { slope: 1.0, offset: 0.0 }),
+            Consideration::new(
+                if claimed { 1.0 } else { 0.0 },
+                ResponseCurve::Linear { slope: 1.0 - UNCLAIMED_APPEAL, offset: UNCLAIMED_APPEAL },
+            ),
+            // How little the claim would cost us abroad, from fabricated (0) to fully justified (1)
+            Consideration::new(
+                1.0 - casus_belli.aggression_penalty() / CasusBelli::FabricatedClaim.aggression_penalty(),
+                ResponseCurve::Linear { slope: 1.0 - UNJUSTIFIED_APPEAL, offset: UNJUSTIFIED_APPEAL },
+            ),
+        ]);
+        Some(UtilityChoice { option: (neighbor_entity, casus_belli), score })
+    });
 
-    if let Some(land) = land_neighbors {
-        process_neighbors(land.neighbors());
-    }
-    
-    if let Some(naval) = naval_neighbors {
-        process_neighbors(naval.neighbors());
+    let (target, casus_belli) = best_choice(choices)?.option;
+    let (_, target_id, target_nation, ..) = nations_query.get(target).ok()?;
+    Some((target, *target_id, target_nation.clone(), casus_belli))
+}
+
+/// Find the neighbor most worth attacking to recover lost cores
+///
+/// Weighs the number of our cores each neighbor holds against its strength,
+/// passing over neighbors a treaty forbids us to fight.
+fn find_reconquest_target(
+    claimant: Entity,
+    lost_cores: &LostCores,
+    land_neighbors: Option<&crate::nations::relationships::LandNeighbors>,
+    naval_neighbors: Option<&crate::nations::relationships::NavalNeighbors>,
+    nations_query: &Query<(
+        Entity,
+        &crate::nations::NationId,
+        &Nation,
+        &PressureVector,
+        &NationHistory,
+        &Governance,
+        Option<&crate::nations::relationships::LandNeighbors>,
+        Option<&crate::nations::relationships::NavalNeighbors>,
+        Option<&LostCores>,
+    )>,
+    treaties: &Query<&Treaty>,
+) -> Option<(Entity, Vec<u32>, String)> {
+    let neighbors = land_neighbors
+        .map(|land| land.neighbors())
+        .into_iter()
+        .chain(naval_neighbors.map(|naval| naval.neighbors()))
+        .flatten();
+
+    let mut best: Option<(Entity, f32, String)> = None;
+    for &neighbor_entity in neighbors {
+        let held = lost_cores.held_by(neighbor_entity).len();
+        if held == 0 || forbids_war_between(treaties, claimant, neighbor_entity) {
+            continue;
+        }
+        let Ok((_, _, neighbor_nation, _, _, _, _, _, _)) = nations_query.get(neighbor_entity) else {
+            continue;
+        };
+
+        let score = held as f32 / neighbor_nation.military_strength.max(1.0);
+        if best.as_ref().is_none_or(|(_, best_score, _)| score > *best_score) {
+            best = Some((neighbor_entity, score, neighbor_nation.name.clone()));
+        }
     }
 
-    best_target
+    best.map(|(entity, _, name)| (entity, lost_cores.held_by(entity).to_vec(), name))
 }
@@ -0,0 +1,18 @@
+//! Disasters - natural catastrophes and the chains they set off
+//!
+//! Earthquakes, droughts, and wildfires arise on their own; each can set
+//! off others by chain rules with a chance and a delay. An earthquake may
+//! send a tsunami onto nearby coasts, a drought may spread, burn, and starve,
+//! and plague follows famine. Every link remembers its cause, so the
+//! chronicle can trace a compound catastrophe back to where it began.
+//!
+//! This is a pure gateway module - all implementation lives in submodules.
+
+mod systems;
+mod types;
+
+pub use systems::{clear_disasters, run_disasters};
+pub use types::{
+    ChainRule, Disaster, DisasterChains, DisasterKind, DisasterReach, DisasterSite, DisasterStruck, Disasters,
+    PendingDisaster,
+};
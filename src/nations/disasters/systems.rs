@@ -0,0 +1,307 @@
+//! Striking provinces and setting off the disasters that follow
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use super::types::{
+    Disaster, DisasterChains, DisasterKind, DisasterReach, DisasterSite, DisasterStruck, Disasters, PendingDisaster,
+};
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::math::HEX_SIZE;
+use crate::nations::{CityNames, Nation, NationIndex, PopulationDisplaced};
+use crate::simulation::NewYearEvent;
+use crate::world::{CoastalProvinceCache, ProvinceStorage, TerrainType, WorldSeed};
+
+/// Links a chain can grow before it exhausts itself
+const MAX_CHAIN_DEPTH: u32 = 4;
+/// Years a province is spared a second disaster of the same kind
+const RESPITE_YEARS: u32 = 5;
+/// Years disasters are remembered, so later links can name the chain they belong to
+const MEMORY_YEARS: u32 = 30;
+/// Places named in one chronicle entry before the rest are counted
+const NAMED_PLACES: usize = 3;
+
+/// A disaster about to strike this year
+struct Strike {
+    kind: DisasterKind,
+    province: usize,
+    cause: Option<DisasterSite>,
+    depth: u32,
+}
+
+/// A fresh world starts with no disasters under way
+pub fn clear_disasters(mut disasters: ResMut<Disasters>) {
+    *disasters = Disasters::default();
+}
+
+/// Provinces a rule's consequence can strike from a stricken province
+fn within_reach(
+    reach: DisasterReach,
+    origin: usize,
+    storage: &ProvinceStorage,
+    coastal_cache: Option<&CoastalProvinceCache>,
+) -> Vec<usize> {
+    let Some(origin_province) = storage.provinces.get(origin) else {
+        return Vec::new();
+    };
+    match reach {
+        DisasterReach::Same => vec![origin],
+        DisasterReach::Neighbors => origin_province
+            .neighbors
+            .iter()
+            .flatten()
+            .map(|neighbor| neighbor.value() as usize)
+            .filter(|&index| {
+                storage
+                    .provinces
+                    .get(index)
+                    .is_some_and(|province| province.terrain != TerrainType::Ocean && province.population > 0)
+            })
+            .collect(),
+        DisasterReach::Coast { radius_hexes } => {
+            let Some(coastal_cache) = coastal_cache.filter(|cache| cache.initialized) else {
+                return Vec::new();
+            };
+            let radius = radius_hexes * HEX_SIZE;
+            storage
+                .provinces
+                .iter()
+                .enumerate()
+                .filter(|(_, province)| {
+                    province.population > 0
+                        && coastal_cache.is_coastal(province.id)
+                        && province.position.distance(origin_province.position) <= radius
+                })
+                .map(|(index, _)| index)
+                .collect()
+        }
+    }
+}
+
+fn with_article(kind: DisasterKind) -> String {
+    let name = kind.name();
+    let article = if name.starts_with(['a', 'e', 'i', 'o', 'u']) {
+        "An"
+    } else {
+        "A"
+    };
+    format!("{} {}", article, name)
+}
+
+/// Yearly disasters: those arising on their own, those due from earlier
+/// chains, and the consequences each sets off
+pub fn run_disasters(
+    mut year_events: MessageReader<NewYearEvent>,
+    chains: Res<DisasterChains>,
+    mut disasters: ResMut<Disasters>,
+    province_storage: Option<ResMut<ProvinceStorage>>,
+    coastal_cache: Option<Res<CoastalProvinceCache>>,
+    world_seed: Option<Res<WorldSeed>>,
+    city_names: Res<CityNames>,
+    nation_index: Res<NationIndex>,
+    mut nations_query: Query<&mut Nation>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    mut struck: MessageWriter<DisasterStruck>,
+    mut displaced: MessageWriter<PopulationDisplaced>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+    let Some(mut storage) = province_storage else {
+        return;
+    };
+
+    // Seeded by world and year so identical runs suffer identical catastrophes
+    let seed = world_seed.map_or(0, |seed| seed.0) as u64;
+    let mut rng = StdRng::seed_from_u64((seed << 32) ^ year as u64 ^ 0x2545_f491_4f6c_dd1d);
+    disasters
+        .recent
+        .retain(|disaster| disaster.site.year + MEMORY_YEARS > year);
+
+    let (due, waiting): (Vec<PendingDisaster>, Vec<PendingDisaster>) = std::mem::take(&mut disasters.pending)
+        .into_iter()
+        .partition(|pending| pending.due_year <= year);
+    disasters.pending = waiting;
+    let mut queue: VecDeque<Strike> = due
+        .into_iter()
+        .map(|pending| Strike {
+            kind: pending.kind,
+            province: pending.province,
+            cause: Some(pending.cause),
+            depth: pending.depth,
+        })
+        .collect();
+
+    for kind in DisasterKind::ALL {
+        if rng.r#gen::<f32>() >= kind.spontaneous_chance() {
+            continue;
+        }
+        let candidates: Vec<usize> = storage
+            .provinces
+            .iter()
+            .enumerate()
+            .filter(|(_, province)| kind.can_arise_in(province))
+            .map(|(index, _)| index)
+            .collect();
+        if let Some(&province) = candidates.choose(&mut rng) {
+            queue.push_back(Strike {
+                kind,
+                province,
+                cause: None,
+                depth: 0,
+            });
+        }
+    }
+
+    // Consequences due this same year join the queue behind their cause
+    let mut this_year: Vec<(Disaster, Option<Entity>)> = Vec::new();
+    while let Some(strike) = queue.pop_front() {
+        if disasters.struck_recently(strike.kind, strike.province, year, RESPITE_YEARS) {
+            continue;
+        }
+        let Some(province) = storage.provinces.get_mut(strike.province) else {
+            continue;
+        };
+        if province.population == 0 {
+            continue;
+        }
+
+        let (mortality, flight) = strike.kind.toll();
+        let severity = rng.gen_range(0.5..=1.5);
+        let killed = (province.population as f32 * (mortality * severity).min(1.0)) as u32;
+        province.set_population(province.population - killed);
+        let fled = (province.population as f32 * (flight * severity).min(1.0)) as u32;
+        if fled > 0 {
+            province.set_population(province.population - fled);
+            displaced.write(PopulationDisplaced {
+                province: strike.province,
+                people: fled,
+            });
+        }
+        let owner = province.owner_entity;
+
+        let site = DisasterSite {
+            kind: strike.kind,
+            province: strike.province,
+            year,
+        };
+        let disaster = Disaster {
+            site,
+            cause: strike.cause,
+            depth: strike.depth,
+            deaths: killed,
+        };
+        disasters.recent.push(disaster.clone());
+        struck.write(DisasterStruck {
+            disaster: disaster.clone(),
+            nation: owner,
+        });
+        this_year.push((disaster, owner));
+
+        if strike.depth >= MAX_CHAIN_DEPTH {
+            continue;
+        }
+        for rule in chains.following(strike.kind) {
+            for target in within_reach(rule.reach, strike.province, &storage, coastal_cache.as_deref()) {
+                if rng.r#gen::<f32>() >= rule.chance {
+                    continue;
+                }
+                let delay = rng.gen_range(rule.delay_years.0..=rule.delay_years.1);
+                if delay == 0 {
+                    queue.push_back(Strike {
+                        kind: rule.consequence,
+                        province: target,
+                        cause: Some(site),
+                        depth: strike.depth + 1,
+                    });
+                } else {
+                    disasters.pending.push(PendingDisaster {
+                        kind: rule.consequence,
+                        province: target,
+                        due_year: year + delay,
+                        cause: site,
+                        depth: strike.depth + 1,
+                    });
+                }
+            }
+        }
+    }
+
+    // One chronicle entry for each kind of disaster and the cause it shares
+    let mut grouped: BTreeMap<(DisasterKind, Option<DisasterSite>), Vec<(Disaster, Option<Entity>)>> = BTreeMap::new();
+    for (disaster, owner) in this_year {
+        grouped
+            .entry((disaster.site.kind, disaster.cause))
+            .or_default()
+            .push((disaster, owner));
+    }
+    let place_name = |index: usize| {
+        city_names
+            .get(index)
+            .map_or_else(|| "the wilds".to_string(), |city| city.name.clone())
+    };
+    for ((kind, cause), stricken) in grouped {
+        let mut places: Vec<String> = stricken
+            .iter()
+            .take(NAMED_PLACES)
+            .map(|(disaster, _)| place_name(disaster.site.province))
+            .collect();
+        if stricken.len() > NAMED_PLACES {
+            places.push(format!("{} more places", stricken.len() - NAMED_PLACES));
+        }
+        let deaths: u64 = stricken.iter().map(|(disaster, _)| disaster.deaths as u64).sum();
+        let owners: BTreeSet<Entity> = stricken.iter().filter_map(|(_, owner)| *owner).collect();
+
+        let mut text = format!(
+            "{} {} {}, killing {}",
+            with_article(kind),
+            kind.verb(),
+            join_places(&places),
+            deaths
+        );
+        if let Some(cause) = cause {
+            text.push_str(&format!(
+                ", in the wake of the {} at {} ({})",
+                cause.kind.name(),
+                place_name(cause.province),
+                cause.year
+            ));
+            let root = stricken
+                .first()
+                .and_then(|(disaster, _)| disasters.causes_of(disaster).last().copied())
+                .filter(|root| *root != cause);
+            if let Some(root) = root {
+                text.push_str(&format!(
+                    " - a chain of calamity that began with the {} at {} ({})",
+                    root.kind.name(),
+                    place_name(root.province),
+                    root.year
+                ));
+            }
+        }
+
+        for &owner in &owners {
+            if let Ok(mut nation) = nations_query.get_mut(owner) {
+                nation.stability = (nation.stability - kind.unrest()).max(0.0);
+            }
+        }
+        chronicle.write(ChronicleEvent {
+            category: ChronicleCategory::Catastrophe,
+            text,
+            nations: owners.iter().filter_map(|&owner| nation_index.id(owner)).collect(),
+        });
+    }
+}
+
+/// "A", "A and B", "A, B, and C"
+fn join_places(places: &[String]) -> String {
+    match places {
+        [] => String::new(),
+        [only] => only.clone(),
+        [first, second] => format!("{} and {}", first, second),
+        [rest @ .., last] => format!("{}, and {}", rest.join(", "), last),
+    }
+}
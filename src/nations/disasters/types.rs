@@ -0,0 +1,315 @@
+//! Disaster kinds, the chain rules linking them, and the disasters under way
+
+use bevy::prelude::*;
+
+use crate::world::{Province, TerrainType};
+
+/// Elevation above which the ground is restless enough for earthquakes
+const FAULT_ELEVATION: f32 = 0.6;
+
+/// A natural catastrophe striking one province
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DisasterKind {
+    Earthquake,
+    Tsunami,
+    Drought,
+    Famine,
+    Wildfire,
+    /// A local outbreak, not one of the great pandemics
+    Plague,
+}
+
+impl DisasterKind {
+    pub const ALL: [DisasterKind; 6] = [
+        DisasterKind::Earthquake,
+        DisasterKind::Tsunami,
+        DisasterKind::Drought,
+        DisasterKind::Famine,
+        DisasterKind::Wildfire,
+        DisasterKind::Plague,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            DisasterKind::Earthquake => "earthquake",
+            DisasterKind::Tsunami => "tsunami",
+            DisasterKind::Drought => "drought",
+            DisasterKind::Famine => "famine",
+            DisasterKind::Wildfire => "wildfire",
+            DisasterKind::Plague => "plague",
+        }
+    }
+
+    /// What the chronicle says it did
+    pub fn verb(self) -> &'static str {
+        match self {
+            DisasterKind::Earthquake => "shook",
+            DisasterKind::Tsunami => "swept over",
+            DisasterKind::Drought => "parched",
+            DisasterKind::Famine => "starved",
+            DisasterKind::Wildfire => "burned through",
+            DisasterKind::Plague => "ravaged",
+        }
+    }
+
+    /// Yearly chance the world sees one arise on its own; zero for those that only follow others
+    pub fn spontaneous_chance(self) -> f32 {
+        match self {
+            DisasterKind::Earthquake => 0.08,
+            DisasterKind::Drought => 0.1,
+            DisasterKind::Wildfire => 0.06,
+            DisasterKind::Tsunami | DisasterKind::Famine | DisasterKind::Plague => 0.0,
+        }
+    }
+
+    /// Whether it can arise on its own in a province
+    pub fn can_arise_in(self, province: &Province) -> bool {
+        if province.population == 0 {
+            return false;
+        }
+        match self {
+            DisasterKind::Earthquake => province.elevation.value() >= FAULT_ELEVATION,
+            DisasterKind::Drought => matches!(
+                province.terrain,
+                TerrainType::TemperateGrassland
+                    | TerrainType::Savanna
+                    | TerrainType::Chaparral
+                    | TerrainType::MediterraneanForest
+                    | TerrainType::TropicalSeasonalForest
+            ),
+            DisasterKind::Wildfire => matches!(
+                province.terrain,
+                TerrainType::Taiga
+                    | TerrainType::BorealForest
+                    | TerrainType::TemperateDeciduousForest
+                    | TerrainType::MediterraneanForest
+                    | TerrainType::Chaparral
+            ),
+            DisasterKind::Tsunami | DisasterKind::Famine | DisasterKind::Plague => true,
+        }
+    }
+
+    /// Shares of the people it kills, and of the survivors who flee
+    pub fn toll(self) -> (f32, f32) {
+        match self {
+            DisasterKind::Earthquake => (0.05, 0.05),
+            DisasterKind::Tsunami => (0.08, 0.1),
+            DisasterKind::Drought => (0.01, 0.05),
+            DisasterKind::Famine => (0.06, 0.08),
+            DisasterKind::Wildfire => (0.02, 0.1),
+            DisasterKind::Plague => (0.1, 0.05),
+        }
+    }
+
+    /// Stability a stricken nation loses
+    pub fn unrest(self) -> f32 {
+        match self {
+            DisasterKind::Earthquake => 0.05,
+            DisasterKind::Tsunami => 0.04,
+            DisasterKind::Drought => 0.03,
+            DisasterKind::Famine => 0.06,
+            DisasterKind::Wildfire => 0.02,
+            DisasterKind::Plague => 0.05,
+        }
+    }
+}
+
+/// Provinces a disaster's consequence can reach
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DisasterReach {
+    /// The stricken province itself
+    Same,
+    /// Populated land provinces bordering it
+    Neighbors,
+    /// Coastal provinces within this many hexes
+    Coast { radius_hexes: f32 },
+}
+
+/// One disaster making another more likely
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainRule {
+    pub cause: DisasterKind,
+    pub consequence: DisasterKind,
+    pub reach: DisasterReach,
+    /// Chance for each province within reach
+    pub chance: f32,
+    /// Years until the consequence strikes, inclusive; 0 is the same year
+    pub delay_years: (u32, u32),
+}
+
+impl ChainRule {
+    fn new(
+        cause: DisasterKind,
+        consequence: DisasterKind,
+        reach: DisasterReach,
+        chance: f32,
+        delay_years: (u32, u32),
+    ) -> Self {
+        Self {
+            cause,
+            consequence,
+            reach,
+            chance,
+            delay_years,
+        }
+    }
+}
+
+/// The rules by which disasters follow one another
+#[derive(Resource, Debug, Clone)]
+pub struct DisasterChains {
+    pub rules: Vec<ChainRule>,
+}
+
+impl Default for DisasterChains {
+    fn default() -> Self {
+        use DisasterKind::*;
+        Self {
+            rules: vec![
+                ChainRule::new(
+                    Earthquake,
+                    Tsunami,
+                    DisasterReach::Coast { radius_hexes: 8.0 },
+                    0.5,
+                    (0, 0),
+                ),
+                ChainRule::new(Earthquake, Wildfire, DisasterReach::Same, 0.15, (0, 0)),
+                ChainRule::new(Earthquake, Plague, DisasterReach::Same, 0.1, (1, 2)),
+                ChainRule::new(Tsunami, Famine, DisasterReach::Same, 0.2, (1, 1)),
+                ChainRule::new(Drought, Famine, DisasterReach::Same, 0.6, (1, 2)),
+                ChainRule::new(Drought, Wildfire, DisasterReach::Neighbors, 0.15, (0, 1)),
+                ChainRule::new(Drought, Drought, DisasterReach::Neighbors, 0.2, (1, 1)),
+                ChainRule::new(Famine, Plague, DisasterReach::Same, 0.35, (1, 2)),
+                ChainRule::new(Plague, Plague, DisasterReach::Neighbors, 0.1, (1, 1)),
+                ChainRule::new(Plague, Famine, DisasterReach::Same, 0.15, (1, 1)),
+            ],
+        }
+    }
+}
+
+impl DisasterChains {
+    /// Rules set off by a disaster of this kind
+    pub fn following(&self, cause: DisasterKind) -> impl Iterator<Item = &ChainRule> {
+        self.rules.iter().filter(move |rule| rule.cause == cause)
+    }
+}
+
+/// Where and when a disaster struck
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DisasterSite {
+    pub kind: DisasterKind,
+    pub province: usize,
+    pub year: u32,
+}
+
+/// A disaster that has struck
+#[derive(Debug, Clone, PartialEq)]
+pub struct Disaster {
+    pub site: DisasterSite,
+    /// The disaster that set this one off, if any
+    pub cause: Option<DisasterSite>,
+    /// Links back to the disaster that began the chain
+    pub depth: u32,
+    pub deaths: u32,
+}
+
+/// A consequence waiting for its year
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingDisaster {
+    pub kind: DisasterKind,
+    pub province: usize,
+    pub due_year: u32,
+    pub cause: DisasterSite,
+    pub depth: u32,
+}
+
+/// Disasters of recent years and the consequences still to come
+#[derive(Resource, Debug, Default)]
+pub struct Disasters {
+    pub recent: Vec<Disaster>,
+    pub pending: Vec<PendingDisaster>,
+}
+
+impl Disasters {
+    /// Whether a disaster of this kind struck the province within `years` of `year`
+    pub fn struck_recently(&self, kind: DisasterKind, province: usize, year: u32, years: u32) -> bool {
+        self.recent.iter().any(|disaster| {
+            disaster.site.kind == kind && disaster.site.province == province && disaster.site.year + years > year
+        })
+    }
+
+    /// The chain of causes behind a disaster, most recent first
+    pub fn causes_of(&self, disaster: &Disaster) -> Vec<DisasterSite> {
+        let mut causes = Vec::new();
+        let mut next = disaster.cause;
+        while let Some(site) = next {
+            causes.push(site);
+            next = self
+                .recent
+                .iter()
+                .find(|earlier| earlier.site == site)
+                .and_then(|earlier| earlier.cause);
+        }
+        causes
+    }
+}
+
+/// Sent when a disaster strikes a province
+#[derive(Message, Debug, Clone)]
+pub struct DisasterStruck {
+    pub disaster: Disaster,
+    pub nation: Option<Entity>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chains_lead_from_drought_to_plague_and_can_be_traced_back() {
+        let chains = DisasterChains::default();
+        let after_drought: Vec<_> = chains.following(DisasterKind::Drought).map(|r| r.consequence).collect();
+        assert!(after_drought.contains(&DisasterKind::Famine));
+        assert!(chains
+            .following(DisasterKind::Famine)
+            .any(|rule| rule.consequence == DisasterKind::Plague));
+        assert!(chains.rules.iter().all(|rule| rule.delay_years.0 <= rule.delay_years.1));
+
+        let drought = DisasterSite {
+            kind: DisasterKind::Drought,
+            province: 4,
+            year: 1200,
+        };
+        let famine = DisasterSite {
+            kind: DisasterKind::Famine,
+            province: 4,
+            year: 1201,
+        };
+        let mut disasters = Disasters::default();
+        disasters.recent.push(Disaster {
+            site: drought,
+            cause: None,
+            depth: 0,
+            deaths: 10,
+        });
+        disasters.recent.push(Disaster {
+            site: famine,
+            cause: Some(drought),
+            depth: 1,
+            deaths: 50,
+        });
+        let plague = Disaster {
+            site: DisasterSite {
+                kind: DisasterKind::Plague,
+                province: 4,
+                year: 1203,
+            },
+            cause: Some(famine),
+            depth: 2,
+            deaths: 80,
+        };
+        assert_eq!(disasters.causes_of(&plague), vec![famine, drought]);
+        assert!(disasters.struck_recently(DisasterKind::Famine, 4, 1203, 5));
+        assert!(!disasters.struck_recently(DisasterKind::Famine, 4, 1210, 5));
+    }
+}
@@ -0,0 +1,240 @@
+//! Border disputes - where nations' claims overlap on land another holds
+//!
+//! Claims come from lost cores (strongest while the loss is fresh), from the
+//! fields of a resource rush, and from declared claims (`HasClaimOn`). A
+//! province claimed by nations other than its holder is contested; it is an
+//! active dispute once a claimant is at war with the holder. Disputes are
+//! charted as the "Border Disputes" heatmap, hotter for each claimant and
+//! hotter still where a dispute is active, with active disputes hatched, so
+//! wars can be seen coming before they start.
+
+use bevy::prelude::*;
+use std::collections::BTreeSet;
+
+use super::cores::ProvinceCores;
+use super::relationships::{Attacking, HasClaimOn};
+use super::resource_rush::ResourceRushes;
+use crate::simulation::NewYearEvent;
+use crate::world::{HeatmapRegistry, HeatmapSource, ProvinceEntityOrder, ProvinceStorage};
+
+/// Strength of a claim on the fields of a resource rush
+const RUSH_CLAIM_STRENGTH: f32 = 0.6;
+/// Strength of a declared claim
+const DECLARED_CLAIM_STRENGTH: f32 = 0.5;
+/// Name of the heatmap disputes are published to
+const DISPUTES_HEATMAP: &str = "Border Disputes";
+/// Legend entry for the hatched provinces
+const ACTIVE_DISPUTE_LABEL: &str = "Striped: a claimant is at war with the holder";
+
+/// What a claim rests on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClaimBasis {
+    LostCore,
+    ResourceRush,
+    Declared,
+}
+
+impl ClaimBasis {
+    pub fn label(self) -> &'static str {
+        match self {
+            ClaimBasis::LostCore => "lost core",
+            ClaimBasis::ResourceRush => "rush fields",
+            ClaimBasis::Declared => "declared claim",
+        }
+    }
+}
+
+/// One nation's claim on a province it does not hold
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProvinceClaim {
+    pub claimant: Entity,
+    /// 0.0 (barely remembered) to 1.0 (pressed in earnest)
+    pub strength: f32,
+    pub basis: ClaimBasis,
+}
+
+/// Claims on a single province
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProvinceDispute {
+    /// Strongest claim of each claimant, strongest first
+    pub claims: Vec<ProvinceClaim>,
+    /// A claimant is at war with the holder
+    pub active: bool,
+}
+
+impl ProvinceDispute {
+    /// Record a claim, keeping only the strongest basis of each claimant
+    pub fn add_claim(&mut self, claim: ProvinceClaim) {
+        match self
+            .claims
+            .iter_mut()
+            .find(|existing| existing.claimant == claim.claimant)
+        {
+            Some(existing) if existing.strength < claim.strength => *existing = claim,
+            Some(_) => {}
+            None => self.claims.push(claim),
+        }
+        self.claims.sort_by(|a, b| b.strength.total_cmp(&a.strength));
+    }
+
+    /// Heatmap value: one per claimant, and one more if the dispute is active
+    pub fn heat(&self) -> f32 {
+        self.claims.len() as f32 + if self.active { 1.0 } else { 0.0 }
+    }
+}
+
+/// Claims and disputes for every province, indexed by province order
+#[derive(Resource, Debug, Default)]
+pub struct BorderDisputes {
+    provinces: Vec<ProvinceDispute>,
+}
+
+impl BorderDisputes {
+    pub fn get(&self, index: usize) -> Option<&ProvinceDispute> {
+        self.provinces.get(index)
+    }
+
+    /// Provinces with at least one claimant
+    pub fn contested(&self) -> usize {
+        self.provinces
+            .iter()
+            .filter(|dispute| !dispute.claims.is_empty())
+            .count()
+    }
+}
+
+/// Register the disputes heatmap so it is listed before the first year is out
+pub fn register_disputes_heatmap(mut heatmaps: ResMut<HeatmapRegistry>) {
+    heatmaps.register(DISPUTES_HEATMAP, HeatmapSource::Published(Vec::new()));
+}
+
+/// Forget disputes from a previous world
+pub fn clear_border_disputes(mut disputes: ResMut<BorderDisputes>) {
+    *disputes = BorderDisputes::default();
+}
+
+/// Gather every claim once a year, and again whenever a war starts or ends
+pub fn update_border_disputes(
+    mut disputes: ResMut<BorderDisputes>,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut heatmaps: ResMut<HeatmapRegistry>,
+    storage: Option<Res<ProvinceStorage>>,
+    entity_order: Option<Res<ProvinceEntityOrder>>,
+    cores: Res<ProvinceCores>,
+    rushes: Res<ResourceRushes>,
+    claims_query: Query<(Entity, &HasClaimOn)>,
+    attackers_query: Query<(Entity, &Attacking)>,
+    changed_wars: Query<(), Changed<Attacking>>,
+    mut ended_wars: RemovedComponents<Attacking>,
+) {
+    let new_year = year_events.read().last().is_some();
+    let wars_changed = !changed_wars.is_empty() || ended_wars.read().count() > 0;
+    if !new_year && !wars_changed {
+        return;
+    }
+    let Some(storage) = storage else {
+        return;
+    };
+
+    let holder = |index: usize| storage.provinces.get(index).and_then(|province| province.owner_entity);
+    let mut provinces = vec![ProvinceDispute::default(); storage.provinces.len()];
+    let mut claim = |index: usize, claimant: Entity, strength: f32, basis: ClaimBasis| {
+        if holder(index) == Some(claimant) {
+            return;
+        }
+        if let Some(dispute) = provinces.get_mut(index) {
+            dispute.add_claim(ProvinceClaim {
+                claimant,
+                strength,
+                basis,
+            });
+        }
+    };
+
+    for index in 0..cores.len() {
+        if let Some(core) = cores.get(index) {
+            if let Some(core_owner) = core.core_owner.filter(|_| core.is_lost()) {
+                claim(index, core_owner, 1.0 - core.decay_progress(), ClaimBasis::LostCore);
+            }
+        }
+    }
+    for rush in &rushes.active {
+        for &claimant in &rush.claimants {
+            claim(rush.province, claimant, RUSH_CLAIM_STRENGTH, ClaimBasis::ResourceRush);
+        }
+    }
+    if let Some(order) = entity_order.as_ref() {
+        for (claimant, has_claim) in &claims_query {
+            if let Some(index) = order.index_of(has_claim.0) {
+                claim(index, claimant, DECLARED_CLAIM_STRENGTH, ClaimBasis::Declared);
+            }
+        }
+    }
+
+    let enemies: BTreeSet<(Entity, Entity)> = attackers_query
+        .iter()
+        .flat_map(|(attacker, attacking)| [(attacker, attacking.0), (attacking.0, attacker)])
+        .collect();
+    for (index, dispute) in provinces.iter_mut().enumerate() {
+        if let Some(holder) = holder(index) {
+            dispute.active = dispute
+                .claims
+                .iter()
+                .any(|claim| enemies.contains(&(claim.claimant, holder)));
+        }
+    }
+
+    // Province order is entity order, as published heatmaps expect
+    if let Some(id) = heatmaps.id_of(DISPUTES_HEATMAP) {
+        heatmaps.publish(id, provinces.iter().map(ProvinceDispute::heat).collect());
+        heatmaps.publish_hatching(
+            id,
+            ACTIVE_DISPUTE_LABEL,
+            provinces.iter().map(|dispute| dispute.active).collect(),
+        );
+    }
+    disputes.provinces = provinces;
+    debug!("Border disputes updated: {} contested provinces", disputes.contested());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_claimant_counts_once_at_its_strongest() {
+        let first = Entity::from_raw_u32(1).unwrap_or(Entity::PLACEHOLDER);
+        let second = Entity::from_raw_u32(2).unwrap_or(Entity::PLACEHOLDER);
+        let mut dispute = ProvinceDispute::default();
+        dispute.add_claim(ProvinceClaim {
+            claimant: first,
+            strength: DECLARED_CLAIM_STRENGTH,
+            basis: ClaimBasis::Declared,
+        });
+        dispute.add_claim(ProvinceClaim {
+            claimant: second,
+            strength: RUSH_CLAIM_STRENGTH,
+            basis: ClaimBasis::ResourceRush,
+        });
+        dispute.add_claim(ProvinceClaim {
+            claimant: first,
+            strength: 0.9,
+            basis: ClaimBasis::LostCore,
+        });
+        dispute.add_claim(ProvinceClaim {
+            claimant: second,
+            strength: 0.1,
+            basis: ClaimBasis::LostCore,
+        });
+
+        assert_eq!(dispute.claims.len(), 2);
+        assert_eq!(dispute.claims[0].claimant, first);
+        assert_eq!(dispute.claims[0].basis, ClaimBasis::LostCore);
+        assert_eq!(dispute.claims[1].basis, ClaimBasis::ResourceRush);
+
+        // An active dispute reads one claimant hotter on the heatmap
+        assert_eq!(dispute.heat(), 2.0);
+        dispute.active = true;
+        assert_eq!(dispute.heat(), 3.0);
+    }
+}
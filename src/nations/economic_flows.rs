@@ -0,0 +1,257 @@
+//! Economic flows - where a nation's wealth comes from and where it goes
+//!
+//! Once a month every nation's economy is summed up as a set of flows: what
+//! its provinces produce feeds its output, output is consumed at home, sold
+//! abroad, wasted, or taxed, and the treasury pays the army and loses to
+//! corruption. Whatever else moved the treasury over the last year (tribute,
+//! wonders, ransoms, construction) shows as other income or spending, and
+//! the year's surplus or deficit as reserves saved or drawn. The flows always
+//! balance, so a diagram of them shows at a glance why a nation is getting
+//! rich or going bankrupt.
+
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+use super::corruption::Corruption;
+use super::economic_system::EconomicLedger;
+use super::types::Nation;
+use super::warfare::MilitaryDoctrine;
+use crate::constants::SIMULATION_DAYS_PER_YEAR;
+use crate::math::Money;
+use crate::simulation::{CalendarRegistry, GameTime};
+
+/// Months of treasury kept to measure the change over a year
+const TREASURY_MONTHS: usize = 13;
+/// Flows smaller than this are left out
+const MIN_FLOW: f32 = 0.01;
+
+/// A stage money passes through
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlowNode {
+    Labor,
+    Food,
+    Goods,
+    Trade,
+    Output,
+    Consumption,
+    Exports,
+    Waste,
+    Taxes,
+    OtherIncome,
+    ReservesDrawn,
+    Treasury,
+    ArmyUpkeep,
+    Corruption,
+    OtherSpending,
+    ReservesSaved,
+}
+
+impl FlowNode {
+    pub fn label(self) -> &'static str {
+        match self {
+            FlowNode::Labor => "Labor",
+            FlowNode::Food => "Food",
+            FlowNode::Goods => "Goods",
+            FlowNode::Trade => "Trade",
+            FlowNode::Output => "Output",
+            FlowNode::Consumption => "Consumption",
+            FlowNode::Exports => "Exports",
+            FlowNode::Waste => "Waste",
+            FlowNode::Taxes => "Taxes",
+            FlowNode::OtherIncome => "Other Income",
+            FlowNode::ReservesDrawn => "Drawn from Reserves",
+            FlowNode::Treasury => "Treasury",
+            FlowNode::ArmyUpkeep => "Army Upkeep",
+            FlowNode::Corruption => "Corruption",
+            FlowNode::OtherSpending => "Other Spending",
+            FlowNode::ReservesSaved => "Saved to Reserves",
+        }
+    }
+
+    /// Stage from left (production) to right (spending)
+    pub fn column(self) -> usize {
+        match self {
+            FlowNode::Labor | FlowNode::Food | FlowNode::Goods | FlowNode::Trade => 0,
+            FlowNode::Output => 1,
+            FlowNode::Consumption
+            | FlowNode::Exports
+            | FlowNode::Waste
+            | FlowNode::Taxes
+            | FlowNode::OtherIncome
+            | FlowNode::ReservesDrawn => 2,
+            FlowNode::Treasury => 3,
+            FlowNode::ArmyUpkeep | FlowNode::Corruption | FlowNode::OtherSpending | FlowNode::ReservesSaved => 4,
+        }
+    }
+}
+
+/// Yearly amount passing from one stage to the next
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EconomicFlow {
+    pub from: FlowNode,
+    pub to: FlowNode,
+    pub amount: f32,
+}
+
+/// A nation's economic flows as of the last month
+#[derive(Component, Debug, Clone, Default)]
+pub struct EconomicFlows {
+    pub flows: Vec<EconomicFlow>,
+    /// Treasury change over the last year
+    pub treasury_change: f32,
+    /// Treasury at the start of each recent month, oldest first
+    treasury_history: VecDeque<Money>,
+}
+
+impl EconomicFlows {
+    /// Record this month's treasury and return the change over the last year
+    ///
+    /// Until a year has been seen, the change so far is scaled up to a year.
+    fn record_treasury(&mut self, treasury: Money) -> f32 {
+        self.treasury_history.push_back(treasury);
+        while self.treasury_history.len() > TREASURY_MONTHS {
+            self.treasury_history.pop_front();
+        }
+        match (self.treasury_history.front(), self.treasury_history.back()) {
+            (Some(&first), Some(&last)) if self.treasury_history.len() > 1 => {
+                let months = (self.treasury_history.len() - 1) as f32;
+                (last - first).to_f32() * (TREASURY_MONTHS - 1) as f32 / months
+            }
+            _ => 0.0,
+        }
+    }
+}
+
+/// A year of a nation's economy as balanced flows
+///
+/// `treasury_change` is what the treasury actually did over the year; the
+/// part not explained by taxes, army upkeep, and corruption becomes other
+/// income or spending.
+pub fn economic_flows(
+    ledger: &EconomicLedger,
+    army_upkeep: f32,
+    corruption_losses: f32,
+    treasury_change: f32,
+) -> Vec<EconomicFlow> {
+    let flow = |from, to, amount: f32| EconomicFlow { from, to, amount };
+    let production = &ledger.production;
+
+    let taxes = ledger.revenue.max(0.0);
+    let waste = ledger.waste.max(0.0);
+    let kept = (production.total() - taxes - waste).max(0.0);
+    let exports = ledger.exports.clamp(0.0, kept);
+
+    let other = treasury_change - taxes + army_upkeep + corruption_losses;
+
+    let mut flows = vec![
+        flow(FlowNode::Labor, FlowNode::Output, production.labor),
+        flow(FlowNode::Food, FlowNode::Output, production.food.to_f32()),
+        flow(FlowNode::Goods, FlowNode::Output, production.goods),
+        flow(FlowNode::Trade, FlowNode::Output, production.trade),
+        flow(FlowNode::Output, FlowNode::Consumption, kept - exports),
+        flow(FlowNode::Output, FlowNode::Exports, exports),
+        flow(FlowNode::Output, FlowNode::Waste, waste),
+        flow(FlowNode::Output, FlowNode::Taxes, taxes),
+        flow(FlowNode::Taxes, FlowNode::Treasury, taxes),
+        flow(FlowNode::OtherIncome, FlowNode::Treasury, other.max(0.0)),
+        flow(FlowNode::ReservesDrawn, FlowNode::Treasury, (-treasury_change).max(0.0)),
+        flow(FlowNode::Treasury, FlowNode::ArmyUpkeep, army_upkeep),
+        flow(FlowNode::Treasury, FlowNode::Corruption, corruption_losses),
+        flow(FlowNode::Treasury, FlowNode::OtherSpending, (-other).max(0.0)),
+        flow(FlowNode::Treasury, FlowNode::ReservesSaved, treasury_change.max(0.0)),
+    ];
+    flows.retain(|flow| flow.amount > MIN_FLOW);
+    flows
+}
+
+/// Sum up every nation's economy at the start of each month
+pub fn update_economic_flows(
+    mut commands: Commands,
+    time: Res<GameTime>,
+    calendars: Option<Res<CalendarRegistry>>,
+    mut last_month: Local<Option<(u32, usize)>>,
+    mut nations_query: Query<(
+        Entity,
+        &Nation,
+        Option<&EconomicLedger>,
+        Option<&MilitaryDoctrine>,
+        Option<&Corruption>,
+        Option<&mut EconomicFlows>,
+    )>,
+) {
+    let day = time.day_of_year();
+    let month = calendars
+        .as_ref()
+        .and_then(|calendars| calendars.default_calendar())
+        .map_or(day * 12 / SIMULATION_DAYS_PER_YEAR as u32, |calendar| {
+            calendar.day_to_period_and_day(day).0 as u32
+        }) as usize;
+    let now = Some((time.current_year(), month));
+    if *last_month == now {
+        return;
+    }
+    *last_month = now;
+
+    for (entity, nation, ledger, doctrine, corruption, flows) in &mut nations_query {
+        let mut updated = flows.as_deref().cloned().unwrap_or_default();
+        updated.treasury_change = updated.record_treasury(nation.treasury);
+        updated.flows = match ledger {
+            Some(ledger) => economic_flows(
+                ledger,
+                nation.military_strength.max(0.0) * doctrine.map_or(0.0, MilitaryDoctrine::upkeep),
+                corruption.map_or(0.0, |corruption| corruption.procurement_losses),
+                updated.treasury_change,
+            ),
+            // Nothing has been produced and allocated yet
+            None => Vec::new(),
+        };
+
+        match flows {
+            Some(mut flows) => *flows = updated,
+            None => {
+                commands.entity(entity).insert(updated);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::economic_system::Production;
+    use super::*;
+    use crate::math::FoodUnits;
+
+    fn total(flows: &[EconomicFlow], node: FlowNode, into: bool) -> f32 {
+        flows
+            .iter()
+            .filter(|flow| if into { flow.to == node } else { flow.from == node })
+            .map(|flow| flow.amount)
+            .sum()
+    }
+
+    #[test]
+    fn flows_balance_at_every_stage() {
+        let ledger = EconomicLedger {
+            production: Production {
+                labor: 300.0,
+                food: FoodUnits::from_int(400),
+                goods: 200.0,
+                trade: 100.0,
+            },
+            output: 1000.0,
+            exports: 80.0,
+            waste: 50.0,
+            revenue: 200.0,
+            ..default()
+        };
+
+        // A treasury that fell although taxes covered the army: something else cost money
+        let flows = economic_flows(&ledger, 120.0, 30.0, -40.0);
+        for node in [FlowNode::Output, FlowNode::Taxes, FlowNode::Treasury] {
+            assert!((total(&flows, node, true) - total(&flows, node, false)).abs() < 0.001);
+        }
+        assert!((total(&flows, FlowNode::OtherSpending, true) - 90.0).abs() < 0.001);
+        assert!((total(&flows, FlowNode::ReservesDrawn, false) - 40.0).abs() < 0.001);
+        assert_eq!(total(&flows, FlowNode::ReservesSaved, true), 0.0);
+    }
+}
@@ -0,0 +1,374 @@
+//! Economic systems - how each nation allocates what its provinces produce
+//!
+//! Every nation runs one of three allocation systems, chosen from its
+//! government:
+//!
+//! - **Market** economies tax what prices clear; trade compounds slowly.
+//! - **Command** economies collect most output and allocate it by plan, but
+//!   the planners only see last year's figures through a noisy lens. Plans
+//!   that overshoot leave shortages (and unrest), plans that undershoot waste
+//!   the surplus.
+//! - **Tribal** economies share output without prices. Little reaches the
+//!   treasury and nothing is hoarded, but sharing keeps people content.
+//!
+//! Run side by side for a few decades, the three drift apart in treasury,
+//! stability, and productivity.
+//!
+//! Closing a nation's borders and ports (see `ContactPolicy`) forgoes
+//! foreign trade, costing revenue under every system.
+//!
+//! Under every system, a workforce thinned by pandemic bargains for higher
+//! wages: each worker produces more, less of the output reaches the
+//! treasury, and commoners are more content until the population regrows.
+
+use bevy::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::bureaucracy::Bureaucracy;
+use super::corruption::Corruption;
+use super::governance::{Governance, GovernmentType, UniqueMechanic};
+use super::contact_policy::ContactStance;
+use super::pandemic::{labor_scarcity, Pandemics};
+use super::types::{Economy, Nation, NationId};
+use crate::ai::{decision_rng, DecisionDomain};
+use crate::math::{FoodUnits, Money};
+use crate::simulation::NewYearEvent;
+use crate::world::{Province, ProvinceStorage, WorldSeed};
+
+/// Share of market output that reaches the treasury before tax rate
+const MARKET_COLLECTION: f32 = 1.0;
+/// Yearly growth of trade in market economies
+const MARKET_TRADE_GROWTH: f32 = 0.01;
+/// Share of output a command economy takes into the plan
+const COMMAND_COLLECTION: f32 = 0.6;
+/// How much of last year's plan the planners keep (the rest follows observed output)
+const COMMAND_PLAN_INERTIA: f32 = 0.5;
+/// How far the planners' reading of the economy can be off, either way
+const COMMAND_INFORMATION_ERROR: f32 = 0.15;
+/// Share of unplanned surplus that spoils or goes unused
+const COMMAND_WASTE: f32 = 0.5;
+/// Yearly growth of industry from central investment
+const COMMAND_INDUSTRY_GROWTH: f32 = 0.02;
+/// Stability lost per unit of shortage
+const COMMAND_SHORTAGE_UNREST: f32 = 0.1;
+/// Share of tribal output given to the chief's stores
+const TRIBAL_TRIBUTE: f32 = 0.05;
+/// Treasury a tribal economy can hold; gifts keep anything above circulating
+const TRIBAL_TREASURY_CAP: Money = Money::from_int(2000);
+/// Yearly stability from sharing
+const TRIBAL_SHARING_STABILITY: f32 = 0.02;
+/// Share of revenue that depends on foreign trade
+const FOREIGN_TRADE_REVENUE: f32 = 0.25;
+/// Wage rise per share of the workforce missing
+const WAGE_ELASTICITY: f32 = 2.0;
+/// Share of the wage rise that comes out of taxable surplus
+const WAGE_REVENUE_LOSS: f32 = 0.5;
+/// Yearly stability per unit of wage rise
+const WAGE_CONTENTMENT: f32 = 0.05;
+
+/// How a nation allocates its output
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+pub enum EconomicSystem {
+    /// Prices clear supply and demand; the state taxes the result
+    #[default]
+    Market,
+    /// A central plan allocates output, working from lagging, noisy figures
+    Command,
+    /// Output is shared by custom and kinship, without prices
+    Tribal,
+}
+
+impl EconomicSystem {
+    /// The allocation system a government runs
+    pub fn for_government(government: GovernmentType) -> Self {
+        let mechanics = government.mechanics();
+        if mechanics.unique_mechanics.contains(&UniqueMechanic::PlannedEconomy) {
+            EconomicSystem::Command
+        } else if mechanics.unique_mechanics.contains(&UniqueMechanic::GiftEconomy)
+            // The tribal category also holds feudal realms and empires, which trade with coin
+            || matches!(government, GovernmentType::TribalFederation | GovernmentType::NomadicKhanate)
+        {
+            EconomicSystem::Tribal
+        } else {
+            EconomicSystem::Market
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            EconomicSystem::Market => "Market",
+            EconomicSystem::Command => "Command",
+            EconomicSystem::Tribal => "Tribal",
+        }
+    }
+}
+
+/// What a nation's provinces produced, by kind
+#[derive(Debug, Clone, Copy, Default, PartialEq, Reflect)]
+pub struct Production {
+    /// Work done by the population
+    pub labor: f32,
+    pub food: FoodUnits,
+    /// Iron, copper, and stone worked by industry
+    pub goods: f32,
+    /// Gold and gems traded
+    pub trade: f32,
+}
+
+impl Production {
+    pub fn total(&self) -> f32 {
+        self.labor + self.food.to_f32() + self.goods + self.trade
+    }
+}
+
+impl std::ops::AddAssign for Production {
+    fn add_assign(&mut self, other: Self) {
+        self.labor += other.labor;
+        self.food += other.food;
+        self.goods += other.goods;
+        self.trade += other.trade;
+    }
+}
+
+/// A nation's economic system and how last year's allocation went
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct EconomicLedger {
+    pub system: EconomicSystem,
+    /// What the provinces produced last year
+    pub output: f32,
+    /// Last year's output by kind
+    pub production: Production,
+    /// Trade output sold abroad, as far as the borders let it through
+    pub exports: f32,
+    /// What the plan expected (command economies only)
+    pub planned_output: f32,
+    /// Share of planned demand that went unmet (0.0-1.0)
+    pub shortage: f32,
+    /// Output lost to misallocation
+    pub waste: f32,
+    /// What reached the treasury
+    pub revenue: f32,
+    /// Wages against normal times (1.0); scarce labor after a pandemic raises them
+    pub wages: f32,
+}
+
+/// Result of one year's allocation
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Allocation {
+    pub revenue: f32,
+    pub planned_output: f32,
+    pub shortage: f32,
+    pub waste: f32,
+    pub stability_change: f32,
+}
+
+/// Allocate one year's output under an economic system
+///
+/// `misreading` is the planners' error in reading the economy, a factor
+/// around 1.0; only command economies use it.
+pub fn allocate(
+    system: EconomicSystem,
+    output: f32,
+    tax_rate: f32,
+    tax_efficiency: f32,
+    previous_plan: f32,
+    misreading: f32,
+) -> Allocation {
+    match system {
+        EconomicSystem::Market => Allocation {
+            revenue: output * MARKET_COLLECTION * tax_rate * tax_efficiency,
+            planned_output: output,
+            ..default()
+        },
+        EconomicSystem::Command => {
+            // A first plan starts from what is visible today
+            let previous_plan = if previous_plan > 0.0 { previous_plan } else { output };
+            let planned_output = (previous_plan * COMMAND_PLAN_INERTIA
+                + output * (1.0 - COMMAND_PLAN_INERTIA))
+                * misreading;
+            let shortage = if planned_output > 0.0 {
+                ((planned_output - output) / planned_output).max(0.0)
+            } else {
+                0.0
+            };
+            let waste = (output - planned_output).max(0.0) * COMMAND_WASTE;
+            Allocation {
+                revenue: (output - waste) * COMMAND_COLLECTION * tax_efficiency,
+                planned_output,
+                shortage,
+                waste,
+                stability_change: -shortage * COMMAND_SHORTAGE_UNREST,
+            }
+        }
+        EconomicSystem::Tribal => Allocation {
+            revenue: output * TRIBAL_TRIBUTE,
+            planned_output: output,
+            stability_change: TRIBAL_SHARING_STABILITY,
+            ..default()
+        },
+    }
+}
+
+/// Wage level for a workforce missing a share of its workers
+pub fn wage_level(scarcity: f32) -> f32 {
+    1.0 + scarcity.clamp(0.0, 1.0) * WAGE_ELASTICITY
+}
+
+/// What one province produces in a year, before allocation
+///
+/// Higher wages mean fewer hands working more land, so each produces more.
+fn province_production(province: &Province, economy: &Economy, wages: f32) -> Production {
+    Production {
+        labor: province.population as f32 * 0.01 * wages,
+        food: FoodUnits::from_f32(province.agriculture.value() * 10.0 * economy.agricultural_multiplier),
+        goods: (province.iron.value() as f32 * 0.5
+            + province.copper.value() as f32 * 0.3
+            + province.stone.value() as f32 * 0.1)
+            * economy.industrial_multiplier,
+        trade: (province.gold.value() as f32 * 2.0 + province.gems.value() as f32 * 5.0)
+            * economy.trade_multiplier,
+    }
+}
+
+/// Yearly allocation of every nation's output under its economic system
+pub fn allocate_national_output(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    province_storage: Option<Res<ProvinceStorage>>,
+    pandemics: Option<Res<Pandemics>>,
+    stances_query: Query<&ContactStance>,
+    world_seed: Option<Res<WorldSeed>>,
+    mut nations_query: Query<(
+        Entity,
+        &mut Nation,
+        &NationId,
+        &Governance,
+        Option<&mut Economy>,
+        Option<&mut EconomicLedger>,
+        Option<&Bureaucracy>,
+        Option<&Corruption>,
+    )>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+    let Some(storage) = province_storage else {
+        return;
+    };
+    let seed = world_seed.map_or(0, |seed| seed.0);
+
+    let mut provinces_by_owner: HashMap<Entity, Vec<&Province>> = HashMap::new();
+    let mut workforce_by_owner: HashMap<Entity, (u32, u32)> = HashMap::new();
+    for (index, province) in storage.provinces.iter().enumerate() {
+        if let Some(owner) = province.owner_entity {
+            provinces_by_owner.entry(owner).or_default().push(province);
+            let (living, dead) = workforce_by_owner.entry(owner).or_default();
+            *living = living.saturating_add(province.population);
+            *dead = dead.saturating_add(pandemics.as_ref().map_or(0, |pandemics| pandemics.dead(index)));
+        }
+    }
+
+    for (entity, mut nation, nation_id, governance, economy, ledger, bureaucracy, corruption) in &mut nations_query {
+        let system = EconomicSystem::for_government(governance.government_type);
+        let default_economy = Economy::default();
+        let wages = workforce_by_owner
+            .get(&entity)
+            .map_or(1.0, |&(living, dead)| wage_level(labor_scarcity(living, dead)));
+        let mut production = Production::default();
+        if let Some(provinces) = provinces_by_owner.get(&entity) {
+            let economy = economy.as_deref().unwrap_or(&default_economy);
+            for province in provinces {
+                production += province_production(province, economy, wages);
+            }
+        }
+        let output = production.total();
+
+        // A new system throws out the old plan
+        let previous_plan = ledger
+            .as_ref()
+            .filter(|ledger| ledger.system == system)
+            .map_or(0.0, |ledger| ledger.planned_output);
+        let mut rng = decision_rng(seed, DecisionDomain::Economy, nation_id.value(), 0, year);
+        let misreading = 1.0 + rng.gen_range(-COMMAND_INFORMATION_ERROR..=COMMAND_INFORMATION_ERROR);
+        let tax_efficiency = economy.as_ref().map_or(1.0, |economy| economy.tax_efficiency);
+
+        let mut allocation = allocate(system, output, nation.tax_rate, tax_efficiency, previous_plan, misreading);
+        // Overextended and corrupt administrations lose revenue on the way to the capital
+        allocation.revenue *= 1.0 - bureaucracy.map_or(0.0, |bureaucracy| bureaucracy.tax_leakage);
+        allocation.revenue *= 1.0 - corruption.map_or(0.0, |corruption| corruption.tax_skim());
+        // Closed ports let little foreign trade through
+        let trade_share = stances_query.get(entity).map_or(1.0, |stance| stance.policy.trade_share());
+        allocation.revenue *= 1.0 - FOREIGN_TRADE_REVENUE * (1.0 - trade_share);
+        // Scarce workers keep more of what they make
+        allocation.revenue *= (1.0 - (wages - 1.0) * WAGE_REVENUE_LOSS).max(0.0);
+        allocation.stability_change += (wages - 1.0) * WAGE_CONTENTMENT;
+
+        nation.treasury += Money::from_f32(allocation.revenue);
+        if system == EconomicSystem::Tribal {
+            nation.treasury = nation.treasury.min(TRIBAL_TREASURY_CAP);
+        }
+        nation.stability = (nation.stability + allocation.stability_change).clamp(0.0, 1.0);
+
+        if let Some(mut economy) = economy {
+            match system {
+                EconomicSystem::Market => {
+                    economy.trade_multiplier = (economy.trade_multiplier * (1.0 + MARKET_TRADE_GROWTH)).min(3.0);
+                }
+                EconomicSystem::Command => {
+                    economy.industrial_multiplier =
+                        (economy.industrial_multiplier * (1.0 + COMMAND_INDUSTRY_GROWTH)).min(3.0);
+                }
+                EconomicSystem::Tribal => {}
+            }
+        }
+
+        let updated = EconomicLedger {
+            system,
+            output,
+            production,
+            exports: production.trade * trade_share,
+            planned_output: allocation.planned_output,
+            shortage: allocation.shortage,
+            waste: allocation.waste,
+            revenue: allocation.revenue,
+            wages,
+        };
+        match ledger {
+            Some(mut ledger) => *ledger = updated,
+            None => {
+                commands.entity(entity).insert(updated);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn systems_diverge_on_the_same_output() {
+        let market = allocate(EconomicSystem::Market, 1000.0, 0.2, 1.0, 0.0, 1.0);
+        let command = allocate(EconomicSystem::Command, 1000.0, 0.2, 1.0, 1000.0, 1.0);
+        let tribal = allocate(EconomicSystem::Tribal, 1000.0, 0.2, 1.0, 0.0, 1.0);
+
+        assert!(command.revenue > market.revenue);
+        assert!(market.revenue > tribal.revenue);
+        assert!(tribal.stability_change > 0.0);
+    }
+
+    #[test]
+    fn overshooting_plans_cause_shortages_and_undershooting_plans_waste() {
+        let overshoot = allocate(EconomicSystem::Command, 1000.0, 0.2, 1.0, 1000.0, 1.15);
+        assert!(overshoot.shortage > 0.0);
+        assert_eq!(overshoot.waste, 0.0);
+        assert!(overshoot.stability_change < 0.0);
+
+        let undershoot = allocate(EconomicSystem::Command, 1000.0, 0.2, 1.0, 1000.0, 0.85);
+        assert_eq!(undershoot.shortage, 0.0);
+        assert!(undershoot.waste > 0.0);
+    }
+}
@@ -9,6 +9,7 @@ use bevy::prelude::Entity;
 // NationId deleted - now using Entity directly
 use super::governance::{GovernmentType, GovernmentCategory};
 use super::laws::{LawId, LawPrerequisite};
+use crate::math::Money;
 use crate::nations::NationId;
 
 
@@ -193,8 +194,8 @@ pub enum DiplomaticError {
 #[derive(Error, Debug)]
 pub enum EconomicError {
     /// Insufficient treasury
-    #[error("Insufficient treasury ({available:.0}) for cost ({required:.0})")]
-    InsufficientFunds { available: f32, required: f32 },
+    #[error("Insufficient treasury ({available}) for cost ({required})")]
+    InsufficientFunds { available: Money, required: Money },
 
     /// Trade route blocked
     #[error("Trade route to {0:?} is blocked")]
@@ -368,8 +369,8 @@ mod tests {
 
         let funds_error = NationError::Economic(
             EconomicError::InsufficientFunds {
-                available: 100.0,
-                required: 200.0,
+                available: Money::from_int(100),
+                required: Money::from_int(200),
             }
         );
         assert_eq!(funds_error.severity(), ErrorSeverity::Info);
@@ -0,0 +1,519 @@
+//! Fortifications - forts raised on threatened borders
+//!
+//! Each year a nation looks along its borders for the province where foreign
+//! armies most outweigh its own garrison and, if it can afford to, starts a
+//! fort there. Forts take years of payments to build, and the kind a nation
+//! can build depends on its technology. A finished fort projects a zone of
+//! control over its province and the neighbouring provinces it holds;
+//! invaders make slower progress against nations whose borders are covered,
+//! unless they bring the siege guns of a later doctrine. A home army that
+//! stands guard in a province with one of its nation's finished forts
+//! garrisons it, if the walls can hold it.
+//!
+//! Forts need upkeep. Forts on borders that are no longer threatened, or that
+//! the nation's technology has left behind, are let go and crumble until
+//! they are abandoned.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use super::city_names::CityNames;
+use super::index::NationIndex;
+use super::types::{Nation, NationId};
+use super::warfare::FieldArmy;
+use crate::ai::InfluenceMaps;
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::math::{Fixed32, Manpower, Money};
+use crate::relationships::{Army, ControlledBy, Fortification, FortificationType, GarrisonedIn, StationedIn};
+use crate::simulation::NewYearEvent;
+use crate::world::{ProvinceEntityOrder, ProvinceStorage};
+
+/// Foreign threat, as a multiple of the local garrison, that justifies a fort
+const FORT_THREAT_RATIO: f32 = 1.5;
+/// Threat ratio below which a fort is no longer worth its upkeep
+const UNTHREATENED_RATIO: f32 = 0.5;
+/// Share of its treasury a nation will commit to one fort
+const FORT_BUDGET_SHARE: Fixed32 = Fixed32::HALF;
+/// Owned provinces per fort a nation will maintain
+const PROVINCES_PER_FORT: usize = 8;
+/// Yearly upkeep as a share of a fort's yearly construction cost
+const UPKEEP_SHARE: Fixed32 = Fixed32::from_ratio(1, 10);
+/// Share of defensive strength lost each year without upkeep
+const NEGLECT_DECAY: f32 = 0.15;
+/// Share of full strength below which a neglected fort is abandoned
+const ABANDON_THRESHOLD: f32 = 0.25;
+/// Share of an invader's progress lost against a fully covered border
+const ZONE_OF_CONTROL_SLOWDOWN: f32 = 0.5;
+
+/// Cost, duration, and strength of one kind of fort
+struct FortSpec {
+    kind: FortificationType,
+    years: u32,
+    yearly_cost: Money,
+    strength: f32,
+    garrison: u32,
+}
+
+const FORT_SPECS: [FortSpec; 4] = [
+    FortSpec {
+        kind: FortificationType::Palisade,
+        years: 2,
+        yearly_cost: Money::from_int(40),
+        strength: 20.0,
+        garrison: 1_000,
+    },
+    FortSpec {
+        kind: FortificationType::StoneWall,
+        years: 4,
+        yearly_cost: Money::from_int(80),
+        strength: 50.0,
+        garrison: 3_000,
+    },
+    FortSpec {
+        kind: FortificationType::Fortress,
+        years: 6,
+        yearly_cost: Money::from_int(150),
+        strength: 100.0,
+        garrison: 8_000,
+    },
+    FortSpec {
+        kind: FortificationType::Citadel,
+        years: 8,
+        yearly_cost: Money::from_int(250),
+        strength: 200.0,
+        garrison: 15_000,
+    },
+];
+
+fn spec(kind: FortificationType) -> &'static FortSpec {
+    match kind {
+        FortificationType::Palisade => &FORT_SPECS[0],
+        FortificationType::StoneWall => &FORT_SPECS[1],
+        FortificationType::Fortress => &FORT_SPECS[2],
+        FortificationType::Citadel => &FORT_SPECS[3],
+    }
+}
+
+/// Most advanced kind of fort a nation's engineers can build
+pub fn best_fort_tier(technology_level: u32) -> usize {
+    (technology_level.div_ceil(2) as usize).min(FORT_SPECS.len() - 1)
+}
+
+/// Whether a nation's technology has left a kind of fort behind
+pub fn is_obsolete(kind: FortificationType, technology_level: u32) -> bool {
+    FORT_SPECS.iter().position(|spec| spec.kind == kind).unwrap_or(0) + 1 < best_fort_tier(technology_level)
+}
+
+/// A fort still under construction
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct FortConstruction {
+    /// Nation paying for the work
+    pub builder: Entity,
+    pub years_remaining: u32,
+    pub yearly_cost: Money,
+}
+
+/// A nation's forts and how much of its border they cover
+#[derive(Component, Debug, Clone, Default, Reflect, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct FortNetwork {
+    pub forts: u32,
+    pub under_construction: u32,
+    /// Share of border provinces inside a fort's zone of control
+    pub zone_of_control: f32,
+}
+
+impl FortNetwork {
+    /// Share of normal progress an invader makes against this nation, given
+    /// how much of a fort's advantage the invader's siege train negates
+    pub fn invasion_pace(&self, siege_power: f32) -> f32 {
+        1.0 - self.zone_of_control.clamp(0.0, 1.0) * ZONE_OF_CONTROL_SLOWDOWN * (1.0 - siege_power.clamp(0.0, 1.0))
+    }
+}
+
+/// A fort as written to a save, by province index, naming its builder by stable id
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedFort {
+    pub province: usize,
+    pub fort: Fortification,
+    /// Work still to do, if the fort is unfinished
+    pub construction: Option<SavedFortConstruction>,
+}
+
+/// Unfinished fort work as written to a save
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SavedFortConstruction {
+    pub builder: NationId,
+    pub years_remaining: u32,
+    pub yearly_cost: Money,
+}
+
+impl SavedFort {
+    /// Stable-id form of a fort standing in a province, for saving
+    pub fn new(
+        province: usize,
+        fort: &Fortification,
+        construction: Option<&FortConstruction>,
+        id_of: impl Fn(Entity) -> Option<NationId>,
+    ) -> Option<Self> {
+        let construction = match construction {
+            Some(construction) => Some(SavedFortConstruction {
+                builder: id_of(construction.builder)?,
+                years_remaining: construction.years_remaining,
+                yearly_cost: construction.yearly_cost,
+            }),
+            None => None,
+        };
+        Some(Self {
+            province,
+            fort: fort.clone(),
+            construction,
+        })
+    }
+
+    /// Rebuild the fort; unfinished work whose builder is gone is abandoned
+    pub fn restore(
+        &self,
+        entity_of: impl Fn(NationId) -> Option<Entity>,
+    ) -> Option<(Fortification, Option<FortConstruction>)> {
+        let construction = match self.construction {
+            Some(saved) => Some(FortConstruction {
+                builder: entity_of(saved.builder)?,
+                years_remaining: saved.years_remaining,
+                yearly_cost: saved.yearly_cost,
+            }),
+            None => None,
+        };
+        Some((self.fort.clone(), construction))
+    }
+}
+
+/// Forts read from a save, waiting for the provinces they stand in to exist
+///
+/// Saving before then writes them back out as they were read.
+#[derive(Resource, Debug, Default)]
+pub struct RestoredForts(pub Vec<SavedFort>);
+
+/// Raise the forts a loaded save recorded once the world is in play
+pub fn spawn_restored_forts(
+    mut commands: Commands,
+    restored: Option<Res<RestoredForts>>,
+    province_entity_order: Option<Res<ProvinceEntityOrder>>,
+    nation_index: Res<NationIndex>,
+) {
+    let (Some(restored), Some(entity_order)) = (restored, province_entity_order) else {
+        return;
+    };
+    commands.remove_resource::<RestoredForts>();
+    for saved in &restored.0 {
+        let (Some(province_entity), Some((fort, construction))) =
+            (entity_order.get(saved.province), saved.restore(|id| nation_index.entity(id)))
+        else {
+            continue;
+        };
+        let mut fort = commands.spawn((fort, StationedIn(province_entity)));
+        if let Some(construction) = construction {
+            fort.insert(construction);
+        }
+    }
+    info!("Restored {} forts", restored.0.len());
+}
+
+/// Yearly fort construction, upkeep, decay, and new projects on threatened borders
+pub fn build_fortifications(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    province_storage: Option<Res<ProvinceStorage>>,
+    entity_order: Option<Res<ProvinceEntityOrder>>,
+    influence_maps: Option<Res<InfluenceMaps>>,
+    city_names: Res<CityNames>,
+    mut nations_query: Query<(Entity, &mut Nation, &NationId, Option<&mut FortNetwork>)>,
+    mut forts_query: Query<(Entity, &mut Fortification, &StationedIn, Option<&mut FortConstruction>)>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+    let (Some(storage), Some(entity_order)) = (province_storage, entity_order) else {
+        return;
+    };
+    let index_by_entity: HashMap<Entity, usize> = entity_order
+        .entities
+        .iter()
+        .enumerate()
+        .map(|(index, &entity)| (entity, index))
+        .collect();
+
+    let mut owned: HashMap<Entity, Vec<usize>> = HashMap::new();
+    for (index, province) in storage.provinces.iter().enumerate() {
+        if let Some(owner) = province.owner_entity {
+            owned.entry(owner).or_default().push(index);
+        }
+    }
+    let owner_at = |index: usize| storage.provinces.get(index).and_then(|province| province.owner_entity);
+    let neighbors_of = |index: usize| {
+        storage
+            .provinces
+            .get(index)
+            .into_iter()
+            .flat_map(|province| province.neighbors.iter().flatten())
+            .map(|neighbor| neighbor.value() as usize)
+    };
+
+    let mut treasuries: HashMap<Entity, Money> = HashMap::new();
+    let mut technology: HashMap<Entity, u32> = HashMap::new();
+    let mut garrisons: HashMap<Entity, f32> = HashMap::new();
+    for (entity, nation, _, _) in &nations_query {
+        treasuries.insert(entity, nation.treasury);
+        technology.insert(entity, nation.technology_level);
+        let provinces = owned.get(&entity).map_or(1, |provinces| provinces.len().max(1));
+        garrisons.insert(entity, nation.military_strength.max(1.0) / provinces as f32);
+    }
+    // Foreign strength able to reach a province, against the owner's own garrison there
+    let threat_ratio = |owner: Entity, index: usize| {
+        let threat = influence_maps.as_ref().map_or(0.0, |maps| maps.threat_to(owner, index));
+        threat / garrisons.get(&owner).copied().unwrap_or(1.0)
+    };
+
+    let mut fortified: HashSet<usize> = HashSet::new();
+    let mut covered: HashMap<Entity, HashSet<usize>> = HashMap::new();
+    let mut builders: HashSet<Entity> = HashSet::new();
+    let mut fort_counts: HashMap<Entity, u32> = HashMap::new();
+
+    for (fort_entity, mut fort, stationed_in, construction) in &mut forts_query {
+        let Some(&index) = index_by_entity.get(&stationed_in.0) else {
+            continue;
+        };
+        fortified.insert(index);
+        let full = spec(fort.fortification_type);
+
+        if let Some(mut construction) = construction {
+            let builder = construction.builder;
+            builders.insert(builder);
+            // Unfinished works are abandoned with the province; they wait out empty treasuries
+            if owner_at(index) != Some(builder) {
+                commands.entity(fort_entity).despawn();
+                continue;
+            }
+            let Some(treasury) = treasuries.get_mut(&builder) else {
+                continue;
+            };
+            if *treasury < construction.yearly_cost {
+                continue;
+            }
+            *treasury -= construction.yearly_cost;
+            construction.years_remaining = construction.years_remaining.saturating_sub(1);
+            if construction.years_remaining == 0 {
+                fort.defensive_strength = full.strength;
+                fort.construction_year = year;
+                commands.entity(fort_entity).remove::<FortConstruction>();
+                if fort.fortification_type == FortificationType::Fortress
+                    || fort.fortification_type == FortificationType::Citadel
+                {
+                    let nation_id = nations_query.get(builder).ok().map(|(_, _, nation_id, _)| *nation_id);
+                    chronicle.write(ChronicleEvent {
+                        category: ChronicleCategory::War,
+                        text: format!("Work is completed on {}", fort.name),
+                        nations: nation_id.into_iter().collect(),
+                    });
+                }
+            }
+            continue;
+        }
+
+        let Some(owner) = owner_at(index) else {
+            fort.defensive_strength *= 1.0 - NEGLECT_DECAY;
+            continue;
+        };
+        *fort_counts.entry(owner).or_default() += 1;
+
+        let upkeep = full.yearly_cost * UPKEEP_SHARE;
+        let worth_keeping = threat_ratio(owner, index) >= UNTHREATENED_RATIO
+            && !is_obsolete(fort.fortification_type, technology.get(&owner).copied().unwrap_or(1));
+        let treasury = treasuries.entry(owner).or_default();
+        if worth_keeping && *treasury >= upkeep {
+            *treasury -= upkeep;
+            fort.defensive_strength = full.strength;
+        } else {
+            fort.defensive_strength *= 1.0 - NEGLECT_DECAY;
+            if fort.defensive_strength < full.strength * ABANDON_THRESHOLD {
+                debug!("{} is abandoned", fort.name);
+                commands.entity(fort_entity).despawn();
+                continue;
+            }
+        }
+
+        let zone = covered.entry(owner).or_default();
+        zone.insert(index);
+        zone.extend(neighbors_of(index).filter(|&neighbor| owner_at(neighbor) == Some(owner)));
+    }
+
+    for (nation_entity, mut nation, _, network) in &mut nations_query {
+        let provinces = owned.get(&nation_entity).map_or(&[][..], Vec::as_slice);
+        let border: Vec<usize> = provinces
+            .iter()
+            .copied()
+            .filter(|&index| {
+                neighbors_of(index).any(|neighbor| owner_at(neighbor).is_some_and(|owner| owner != nation_entity))
+            })
+            .collect();
+        let treasury = treasuries.get(&nation_entity).copied().unwrap_or(nation.treasury);
+        let forts = fort_counts.get(&nation_entity).copied().unwrap_or(0);
+        let mut building = builders.contains(&nation_entity);
+
+        // One project at a time, on the most threatened unfortified border province
+        let max_forts = (provinces.len() / PROVINCES_PER_FORT).max(1) as u32;
+        if !building && forts < max_forts {
+            let target = border
+                .iter()
+                .copied()
+                .filter(|index| !fortified.contains(index))
+                .map(|index| (index, threat_ratio(nation_entity, index)))
+                .filter(|&(_, ratio)| ratio >= FORT_THREAT_RATIO)
+                .max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.cmp(&a.0)));
+            let budget = treasury * FORT_BUDGET_SHARE;
+            let affordable = FORT_SPECS[..=best_fort_tier(nation.technology_level)]
+                .iter()
+                .rev()
+                .find(|spec| spec.yearly_cost * Fixed32::from_int(spec.years as i32) <= budget);
+            let site = target.and_then(|(index, _)| Some((index, entity_order.get(index)?)));
+            if let (Some((index, province_entity)), Some(fort_spec)) = (site, affordable) {
+                let place = city_names
+                    .get(index)
+                    .map_or_else(|| format!("the {} frontier", nation.adjective), |city| city.name.clone());
+                commands.spawn((
+                    Fortification {
+                        name: format!("the {} of {}", fort_spec.kind.label(), place),
+                        fortification_type: fort_spec.kind,
+                        defensive_strength: 0.0,
+                        garrison_capacity: Manpower::new(fort_spec.garrison),
+                        construction_year: year,
+                    },
+                    StationedIn(province_entity),
+                    FortConstruction {
+                        builder: nation_entity,
+                        years_remaining: fort_spec.years,
+                        yearly_cost: fort_spec.yearly_cost,
+                    },
+                ));
+                building = true;
+            }
+        }
+
+        let zone = covered.get(&nation_entity);
+        let updated = FortNetwork {
+            forts,
+            under_construction: u32::from(building),
+            zone_of_control: if border.is_empty() {
+                0.0
+            } else {
+                border.iter().filter(|index| zone.is_some_and(|zone| zone.contains(index))).count() as f32
+                    / border.len() as f32
+            },
+        };
+
+        nation.treasury = treasury;
+        match network {
+            Some(mut network) => *network = updated,
+            None => {
+                commands.entity(nation_entity).insert(updated);
+            }
+        }
+    }
+}
+
+/// Yearly posting of home armies into the finished forts where they stand guard
+pub fn garrison_forts(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    provinces_query: Query<&ControlledBy>,
+    forts_query: Query<(Entity, &Fortification, &StationedIn), Without<FortConstruction>>,
+    armies_query: Query<(Entity, &Army, &FieldArmy, &StationedIn, Option<&GarrisonedIn>)>,
+) {
+    if year_events.read().last().is_none() {
+        return;
+    }
+    let forts: HashMap<Entity, (Entity, Manpower)> = forts_query
+        .iter()
+        .map(|(fort_entity, fort, stationed_in)| (stationed_in.0, (fort_entity, fort.garrison_capacity)))
+        .collect();
+
+    for (army_entity, army, field, stationed_in, garrisoned_in) in &armies_query {
+        // Front armies and armies still on the march stay in the field
+        let fort = forts
+            .get(&stationed_in.0)
+            .filter(|_| field.theater.is_none() && field.arrived())
+            .filter(|_| provinces_query.get(stationed_in.0).is_ok_and(|owner| owner.0 == army.owner_nation))
+            .filter(|(_, capacity)| army.size <= *capacity)
+            .map(|&(fort_entity, _)| fort_entity);
+        match (fort, garrisoned_in) {
+            (Some(fort_entity), Some(garrisoned_in)) if garrisoned_in.0 == fort_entity => {}
+            (Some(fort_entity), _) => {
+                commands.entity(army_entity).insert(GarrisonedIn(fort_entity));
+            }
+            (None, Some(_)) => {
+                commands.entity(army_entity).remove::<GarrisonedIn>();
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advancing_technology_unlocks_and_outdates_forts() {
+        assert_eq!(FORT_SPECS[best_fort_tier(1)].kind, FortificationType::StoneWall);
+        assert_eq!(FORT_SPECS[best_fort_tier(9)].kind, FortificationType::Citadel);
+        assert!(!is_obsolete(FortificationType::Palisade, 1));
+        assert!(is_obsolete(FortificationType::Palisade, 5));
+    }
+
+    #[test]
+    fn covered_borders_slow_invaders() {
+        let open = FortNetwork::default();
+        let covered = FortNetwork {
+            zone_of_control: 1.0,
+            ..Default::default()
+        };
+        assert_eq!(open.invasion_pace(0.0), 1.0);
+        assert!(covered.invasion_pace(0.0) < open.invasion_pace(0.0));
+        assert!(covered.invasion_pace(0.8) > covered.invasion_pace(0.0));
+    }
+
+    #[test]
+    fn saved_forts_keep_their_work_and_lose_it_with_their_builder() {
+        let builder = Entity::from_raw_u32(4).unwrap();
+        let fort = Fortification {
+            name: "the Fortress of Velm".to_string(),
+            fortification_type: FortificationType::Fortress,
+            defensive_strength: 0.0,
+            garrison_capacity: Manpower::new(2_000),
+            construction_year: 120,
+        };
+        let construction = FortConstruction {
+            builder,
+            years_remaining: 3,
+            yearly_cost: Money::from_int(40),
+        };
+        let id_of = |entity: Entity| (entity == builder).then_some(NationId(7));
+        let saved = SavedFort::new(12, &fort, Some(&construction), id_of).unwrap();
+        assert_eq!(saved.province, 12);
+
+        let rebuilder = Entity::from_raw_u32(9).unwrap();
+        let (restored, work) = saved.restore(|id| (id == NationId(7)).then_some(rebuilder)).unwrap();
+        assert_eq!(restored.name, fort.name);
+        let work = work.unwrap();
+        assert_eq!(work.builder, rebuilder);
+        assert_eq!(work.years_remaining, 3);
+        assert_eq!(work.yearly_cost, Money::from_int(40));
+
+        assert!(saved.restore(|_| None).is_none());
+        let finished = SavedFort::new(12, &fort, None, id_of).unwrap();
+        assert!(finished.restore(|_| None).is_some_and(|(_, work)| work.is_none()));
+    }
+}
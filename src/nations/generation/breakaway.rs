@@ -0,0 +1,84 @@
+//! Nations carved out of an existing one (buffer states, civil war factions)
+
+use bevy::prelude::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng as StdRng;
+
+use crate::math::Money;
+use crate::name_generator::NameGenerator;
+use crate::nations::{
+    Economy, Governance, GovernmentHistory, GovernmentType, LegitimacyFactors, Nation,
+    NationBundle, NationHistory, NationId, NationLaws, NationPersonality, OwnsTerritory,
+    PoliticalPressure,
+};
+use crate::simulation::PressureVector;
+use crate::world::ProvinceId;
+
+/// Spawn a nation that splits from `parent`, sharing its culture and technology
+///
+/// The new nation owns no provinces yet; the caller transfers them.
+pub fn spawn_breakaway_nation(
+    commands: &mut Commands,
+    nation_id: NationId,
+    parent: &Nation,
+    capital_province: ProvinceId,
+    year: u32,
+    government: GovernmentType,
+    personality: NationPersonality,
+    founder: &str,
+) -> (Entity, String) {
+    let mut name_gen = NameGenerator::new();
+    let (name, _ruler_title) =
+        crate::nations::generate_governance_aware_name(&mut name_gen, parent.culture, &government);
+    let mut rng = StdRng::seed_from_u64(((nation_id.value() as u64) << 32) | year as u64);
+
+    let nation = Nation {
+        name: name.clone(),
+        adjective: crate::nations::generate_adjective(&name),
+        color: crate::nations::generate_nation_color(nation_id.value(), &mut rng),
+        capital_province,
+        treasury: Money::from_int(500),
+        tax_rate: 0.2,
+        military_strength: parent.military_strength * 0.1,
+        stability: 0.6,
+        culture: parent.culture,
+        technology_level: parent.technology_level,
+        personality,
+    };
+
+    let entity = commands
+        .spawn((
+            NationBundle {
+                nation,
+                economy: Economy::default(),
+                transform: Transform::default(),
+                visibility: Visibility::default(),
+                pressure_vector: PressureVector::default(),
+                history: NationHistory::new(
+                    year,
+                    crate::nations::culture_to_display_name(parent.culture).to_string(),
+                    founder.to_string(),
+                ),
+                laws: NationLaws::default(),
+            },
+            OwnsTerritory::default(),
+            Governance {
+                government_type: government,
+                stability: 0.6,
+                reform_pressure: 0.0,
+                tradition_strength: government.mechanics().reform_resistance,
+                institution_strength: 0.5,
+                last_transition: None,
+                days_in_power: 0,
+                legitimacy: 0.6,
+                legitimacy_trend: 0.0,
+                legitimacy_factors: LegitimacyFactors::for_government_type(government),
+            },
+            PoliticalPressure::default(),
+            GovernmentHistory::new(government),
+            nation_id,
+        ))
+        .id();
+
+    (entity, name)
+}
@@ -13,9 +13,11 @@ use crate::world::{Province, TerrainType};
 /// Select suitable provinces to be nation capitals using parallel evaluation
 ///
 /// Uses a spatial distribution algorithm to ensure capitals are well-spaced across the map.
+/// `reserved` capitals (custom nations) are kept clear of and never returned.
 pub fn select_capital_provinces(
     provinces: &[Province],
     nation_count: u32,
+    reserved: &[usize],
     rng: &mut StdRng,
 ) -> Vec<usize> {
     // Parallel filter to find all land provinces that could be capitals
@@ -26,7 +28,8 @@ pub fn select_capital_provinces(
             if !matches!(
                 p.terrain,
                 TerrainType::Ocean | TerrainType::River | TerrainType::Alpine
-            ) {
+            ) && !reserved.contains(&idx)
+            {
                 Some(idx)
             } else {
                 None
@@ -42,7 +45,7 @@ pub fn select_capital_provinces(
     let min_distance_squared = calculate_min_capital_distance(provinces.len(), nation_count);
 
     // Use spatial partitioning with parallel evaluation for capital selection
-    let mut selected_capitals = Vec::new();
+    let mut selected_capitals = reserved.to_vec();
     let mut remaining_candidates = suitable_provinces.clone();
 
     for _ in 0..nation_count {
@@ -109,7 +112,7 @@ pub fn select_capital_provinces(
         remaining_candidates.retain(|&x| x != selected);
     }
 
-    selected_capitals
+    selected_capitals.split_off(reserved.len())
 }
 
 /// Calculate minimum distance between capitals based on world size
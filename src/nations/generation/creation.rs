@@ -8,8 +8,9 @@ use rand_chacha::ChaCha8Rng as StdRng;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
+use crate::math::Money;
 use crate::name_generator::{Culture, NameGenerator, NameType};
-use crate::world::Province;
+use crate::world::{Province, ProvinceId};
 use super::super::house::{generate_motto, House, HouseTraits, Ruler, RulerPersonality};
 use super::super::types::*;
 use super::colors::generate_nation_color;
@@ -73,8 +74,8 @@ pub fn create_nation_with_house_parallel(
         name: nation_name.clone(),
         adjective,
         color,
-        capital_province: capital_idx as u32,
-        treasury: 1000.0,
+        capital_province: ProvinceId::new(capital_idx as u32),
+        treasury: Money::from_int(1000),
         tax_rate: rng.r#gen_range(0.15..0.35), // Start with 15%-35% tax rate
         military_strength: 100.0,
         stability: 0.75,
@@ -255,8 +256,8 @@ pub fn create_nation_with_house(
         name: nation_name.clone(),
         adjective,
         color,
-        capital_province: capital_idx as u32,
-        treasury: 1000.0,
+        capital_province: ProvinceId::new(capital_idx as u32),
+        treasury: Money::from_int(1000),
         tax_rate: rng.r#gen_range(0.15..0.35), // Start with 15%-35% tax rate
         military_strength: 100.0,
         stability: 0.75,
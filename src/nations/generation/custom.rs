@@ -0,0 +1,325 @@
+//! Designer-defined nations
+//!
+//! Custom nations are placed before the generated ones: each gets the best
+//! capital for its region preference and a small core around it that the
+//! territory growth cannot take away.
+
+use bevy::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng as StdRng;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
+
+use crate::math::Money;
+use crate::name_generator::{Culture, Gender, NameGenerator, NameType};
+use crate::world::{Province, ProvinceId, TerrainType};
+use super::super::governance::{generate_governance_aware_name, get_ruler_title, GovernmentType};
+use super::super::house::{generate_motto, House, HouseTraits, Ruler, RulerPersonality};
+use super::super::types::*;
+use super::capitals::calculate_min_capital_distance;
+use super::creation::generate_adjective;
+
+/// Most custom nations a world can start with
+pub const MAX_CUSTOM_NATIONS: usize = 8;
+
+/// Rings of provinces around a custom capital reserved before territory growth
+const CORE_RINGS: usize = 2;
+
+/// Cultures offered by the designer, in cycling order
+pub const DESIGNER_CULTURES: [Culture; 8] = [
+    Culture::Western,
+    Culture::Eastern,
+    Culture::Northern,
+    Culture::Southern,
+    Culture::Desert,
+    Culture::Island,
+    Culture::Ancient,
+    Culture::Mystical,
+];
+
+/// Colors offered by the designer, in cycling order
+pub const DESIGNER_COLORS: [Color; 8] = [
+    Color::srgb(0.75, 0.15, 0.15),
+    Color::srgb(0.15, 0.35, 0.75),
+    Color::srgb(0.85, 0.65, 0.1),
+    Color::srgb(0.2, 0.6, 0.25),
+    Color::srgb(0.55, 0.2, 0.65),
+    Color::srgb(0.9, 0.45, 0.1),
+    Color::srgb(0.1, 0.6, 0.65),
+    Color::srgb(0.45, 0.3, 0.2),
+];
+
+/// Where a custom nation would like its capital
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RegionPreference {
+    #[default]
+    Anywhere,
+    Coastal,
+    Inland,
+    Highlands,
+    Fertile,
+    North,
+    South,
+}
+
+impl RegionPreference {
+    pub const ALL: [RegionPreference; 7] = [
+        RegionPreference::Anywhere,
+        RegionPreference::Coastal,
+        RegionPreference::Inland,
+        RegionPreference::Highlands,
+        RegionPreference::Fertile,
+        RegionPreference::North,
+        RegionPreference::South,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            RegionPreference::Anywhere => "Anywhere",
+            RegionPreference::Coastal => "Coastal",
+            RegionPreference::Inland => "Inland",
+            RegionPreference::Highlands => "Highlands",
+            RegionPreference::Fertile => "Fertile Plains",
+            RegionPreference::North => "Far North",
+            RegionPreference::South => "Far South",
+        }
+    }
+
+    /// How well a province suits this preference, 0.0 to 1.0
+    fn score(&self, province: &Province, provinces: &[Province], y_range: (f32, f32)) -> f32 {
+        let coastal = province
+            .neighbor_indices
+            .iter()
+            .flatten()
+            .any(|&idx| provinces.get(idx).is_some_and(|n| n.terrain == TerrainType::Ocean));
+        let height = (y_range.1 - y_range.0).max(1.0);
+        let northness = (province.position.y - y_range.0) / height;
+
+        match self {
+            RegionPreference::Anywhere => 0.5,
+            RegionPreference::Coastal => if coastal { 1.0 } else { 0.0 },
+            RegionPreference::Inland => if coastal { 0.0 } else { 1.0 },
+            RegionPreference::Highlands => province.elevation.value(),
+            RegionPreference::Fertile => province.agriculture.value() / 3.0,
+            RegionPreference::North => northness,
+            RegionPreference::South => 1.0 - northness,
+        }
+    }
+}
+
+/// A nation defined in the world configuration designer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomNationSpec {
+    pub name: String,
+    pub culture: Culture,
+    pub color: Color,
+    pub government: GovernmentType,
+    #[serde(default)]
+    pub region: RegionPreference,
+}
+
+impl CustomNationSpec {
+    /// A fresh design for the designer's `index`-th slot
+    pub fn new(index: usize) -> Self {
+        Self {
+            name: format!("Custom Nation {}", index + 1),
+            culture: DESIGNER_CULTURES[index % DESIGNER_CULTURES.len()],
+            color: DESIGNER_COLORS[index % DESIGNER_COLORS.len()],
+            government: GovernmentType::AbsoluteMonarchy,
+            region: RegionPreference::Anywhere,
+        }
+    }
+}
+
+fn is_capital_terrain(province: &Province) -> bool {
+    !matches!(
+        province.terrain,
+        TerrainType::Ocean | TerrainType::River | TerrainType::Alpine
+    )
+}
+
+/// Pick a capital for each custom nation, best region match first
+///
+/// Capitals keep the usual spacing from each other when the map allows it.
+/// Consumes no randomness when there are no custom nations, so generated
+/// worlds without designs stay identical for a seed.
+pub fn reserve_custom_capitals(
+    provinces: &[Province],
+    specs: &[CustomNationSpec],
+    total_nations: u32,
+    rng: &mut StdRng,
+) -> Vec<usize> {
+    if specs.is_empty() || provinces.is_empty() {
+        return Vec::new();
+    }
+
+    let y_range = provinces.iter().fold((f32::MAX, f32::MIN), |(lo, hi), p| {
+        (lo.min(p.position.y), hi.max(p.position.y))
+    });
+    let min_distance_squared = calculate_min_capital_distance(provinces.len(), total_nations.max(1));
+    let candidates: Vec<usize> = (0..provinces.len())
+        .filter(|&idx| is_capital_terrain(&provinces[idx]))
+        .collect();
+
+    let mut chosen: Vec<usize> = Vec::new();
+    for spec in specs.iter().take(MAX_CUSTOM_NATIONS) {
+        let spaced = |idx: &usize| {
+            chosen.iter().all(|&other| {
+                provinces[*idx].position.distance_squared(provinces[other].position) >= min_distance_squared
+            })
+        };
+        let open: Vec<usize> = candidates.iter().copied().filter(|idx| !chosen.contains(idx)).collect();
+        let pool: Vec<usize> = {
+            let spaced_pool: Vec<usize> = open.iter().copied().filter(spaced).collect();
+            if spaced_pool.is_empty() { open } else { spaced_pool }
+        };
+
+        let best = pool
+            .into_iter()
+            .map(|idx| {
+                let jitter = rng.r#gen::<f32>() * 0.1;
+                (idx, spec.region.score(&provinces[idx], provinces, y_range) + jitter)
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(idx, _)| idx);
+
+        match best {
+            Some(idx) => chosen.push(idx),
+            None => {
+                warn!("No land left for custom nation '{}'", spec.name);
+                break;
+            }
+        }
+    }
+
+    chosen
+}
+
+/// Land provinces within a few rings of a custom capital, excluding other capitals
+pub fn reserve_core_territory(provinces: &[Province], capital: usize, capitals: &HashSet<usize>) -> Vec<u32> {
+    let mut core = Vec::new();
+    let mut visited = HashSet::from([capital]);
+    let mut queue = VecDeque::from([(capital, 0usize)]);
+
+    while let Some((idx, ring)) = queue.pop_front() {
+        if ring == CORE_RINGS {
+            continue;
+        }
+        for &neighbor in provinces[idx].neighbor_indices.iter().flatten() {
+            if neighbor >= provinces.len() || !visited.insert(neighbor) || capitals.contains(&neighbor) {
+                continue;
+            }
+            if provinces[neighbor].terrain == TerrainType::Ocean {
+                continue;
+            }
+            core.push(provinces[neighbor].id.value());
+            queue.push_back((neighbor, ring + 1));
+        }
+    }
+
+    core
+}
+
+/// Create a designer-defined nation with a generated ruling house
+pub fn create_custom_nation(
+    registry: &NationRegistry,
+    spec: &CustomNationSpec,
+    capital_idx: usize,
+    seed: u64,
+    settings: &NationGenerationSettings,
+) -> (NationId, Nation, House, GovernmentType) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut name_gen = NameGenerator::with_seed(seed);
+    let nation_id = registry.create_nation_id();
+    let culture = spec.culture;
+    let government = spec.government;
+
+    let nation_name = match spec.name.trim() {
+        "" => generate_governance_aware_name(&mut name_gen, culture, &government).0,
+        name => name.to_string(),
+    };
+    let ruler_title = get_ruler_title(&government, Gender::Male).to_string();
+
+    let mut personality = NationPersonality::random(&mut rng);
+    personality.aggression *= settings.aggression_level;
+
+    let nation = Nation {
+        name: nation_name.clone(),
+        adjective: generate_adjective(&nation_name),
+        color: spec.color,
+        capital_province: ProvinceId::new(capital_idx as u32),
+        treasury: Money::from_int(1000),
+        tax_rate: rng.r#gen_range(0.15..0.35),
+        military_strength: 100.0,
+        stability: 0.75,
+        culture,
+        technology_level: 1,
+        personality,
+    };
+
+    let house_name = name_gen.generate(NameType::House { culture });
+    let house_traits = HouseTraits::random(&mut rng);
+    let house = House {
+        name: house_name.clone(),
+        full_name: format!("House {} of {}", house_name, nation_name),
+        ruler: Ruler {
+            name: name_gen.generate(NameType::Person {
+                gender: Gender::Male,
+                culture,
+                role: crate::name_generator::PersonRole::Noble,
+            }),
+            title: ruler_title,
+            age: rng.r#gen_range(25..65),
+            years_ruling: rng.r#gen_range(1..15),
+            personality: RulerPersonality::random(&mut rng),
+        },
+        motto: generate_motto(&house_traits, &culture),
+        traits: house_traits,
+        years_in_power: rng.r#gen_range(10..200),
+        legitimacy: 0.75 + rng.r#gen_range(-0.2..0.2),
+        prestige: 0.5 + rng.r#gen_range(-0.3..0.3),
+    };
+
+    (nation_id, nation, house, government)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::Elevation;
+
+    fn strip(count: u32) -> Vec<Province> {
+        (0..count)
+            .map(|i| {
+                let mut province = Province::new(ProvinceId::new(i), Vec2::new(0.0, i as f32 * 10.0));
+                province.neighbor_indices[0] = (i > 0).then(|| i as usize - 1);
+                province.neighbor_indices[1] = (i + 1 < count).then(|| i as usize + 1);
+                province
+            })
+            .collect()
+    }
+
+    #[test]
+    fn region_preference_steers_capital() {
+        let mut provinces = strip(20);
+        provinces[3].elevation = Elevation::new(0.9);
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let north = CustomNationSpec { region: RegionPreference::North, ..CustomNationSpec::new(0) };
+        let highlands = CustomNationSpec { region: RegionPreference::Highlands, ..CustomNationSpec::new(1) };
+        let capitals = reserve_custom_capitals(&provinces, &[north, highlands], 4, &mut rng);
+
+        assert_eq!(capitals.len(), 2);
+        assert!(capitals[0] >= 18, "north capital at {}", capitals[0]);
+        assert_eq!(capitals[1], 3);
+    }
+
+    #[test]
+    fn core_territory_stops_at_other_capitals() {
+        let provinces = strip(10);
+        let capitals = HashSet::from([5, 6]);
+        let mut core = reserve_core_territory(&provinces, 5, &capitals);
+        core.sort();
+        assert_eq!(core, vec![3, 4]);
+    }
+}
@@ -5,21 +5,25 @@
 //!
 //! ## Module Structure
 //!
+//! - `breakaway` - Nations split from an existing nation
 //! - `capitals` - Capital province selection with spatial distribution
 //! - `colors` - Nation color generation using HSL color space
 //! - `creation` - Nation and house creation with governance
+//! - `custom` - Designer-defined nations with reserved capitals and cores
 //! - `territory` - Territory assignment using parallel growth algorithms
 
+mod breakaway;
 mod capitals;
 mod colors;
 mod creation;
+mod custom;
 mod territory;
 
 use bevy::prelude::*;
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng as StdRng;
 use rayon::prelude::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use crate::diagnostics::{TimedOperation, log_memory_usage};
@@ -28,12 +32,16 @@ use super::house::House;
 use super::types::*;
 
 // Re-export public items
+pub use breakaway::spawn_breakaway_nation;
 pub use capitals::{select_capital_provinces, calculate_min_capital_distance};
 pub use colors::{generate_nation_color, hsl_to_rgb};
 pub use creation::{
     create_nation_with_house_parallel, create_nation_with_house,
     ensure_unique_nation_names, pick_culture_for_location, generate_adjective,
 };
+pub use custom::{
+    CustomNationSpec, RegionPreference, DESIGNER_COLORS, DESIGNER_CULTURES, MAX_CUSTOM_NATIONS,
+};
 pub use territory::{assign_territory_to_nations, build_territories_from_provinces};
 
 /// Spawn nations into the world with territory, ruling houses, and governance
@@ -57,12 +65,22 @@ pub fn spawn_nations(
     let mut houses = Vec::new();
     let nation_registry = NationRegistry::default();
 
+    // Designer-defined nations claim their capitals first
+    let custom_capitals = custom::reserve_custom_capitals(
+        provinces,
+        &settings.custom_nations,
+        settings.nation_count,
+        &mut rng,
+    );
+    let generated_count = settings.nation_count.saturating_sub(custom_capitals.len() as u32);
+
     // Find suitable capital locations
     let capital_timer = TimedOperation::start("Capital Selection");
-    let capital_provinces = capitals::select_capital_provinces(provinces, settings.nation_count, &mut rng);
+    let capital_provinces =
+        capitals::select_capital_provinces(provinces, generated_count, &custom_capitals, &mut rng);
     let _capital_time = capital_timer.complete_with_context(format!("{} capitals selected", capital_provinces.len()));
 
-    if capital_provinces.is_empty() {
+    if capital_provinces.is_empty() && custom_capitals.is_empty() {
         // Early exit if no provinces
         if provinces.is_empty() {
             return (nations, houses, Vec::new(), std::collections::HashMap::new());
@@ -76,11 +94,20 @@ pub fn spawn_nations(
 
     // Pre-generate seeds for parallel nation creation (avoids RNG contention)
     let nation_seeds: Vec<u64> = (0..capital_provinces.len()).map(|_| rng.r#gen()).collect();
+    let custom_seeds: Vec<u64> = (0..custom_capitals.len()).map(|_| rng.r#gen()).collect();
 
     // Create nations with ruling houses and governance in parallel
     let nation_creation_timer = TimedOperation::start("Nation Creation");
     let nation_registry_arc = Arc::new(nation_registry);
-    let mut nation_data: Vec<(NationId, Nation, House, super::governance::GovernmentType)> = capital_provinces
+    let mut nation_data: Vec<(NationId, Nation, House, super::governance::GovernmentType)> = custom_capitals
+        .iter()
+        .zip(&settings.custom_nations)
+        .zip(&custom_seeds)
+        .map(|((&capital_idx, spec), &seed)| {
+            custom::create_custom_nation(&nation_registry_arc, spec, capital_idx, seed, settings)
+        })
+        .collect();
+    let generated_data: Vec<(NationId, Nation, House, super::governance::GovernmentType)> = capital_provinces
         .par_iter()
         .zip(nation_seeds.par_iter())
         .map(|(&capital_idx, &nation_seed)| {
@@ -93,10 +120,22 @@ pub fn spawn_nations(
             )
         })
         .collect();
+    nation_data.extend(generated_data);
     let _creation_time = nation_creation_timer.complete_with_context(format!("{} nations created", nation_data.len()));
 
-    // Ensure all nation names are unique
-    creation::ensure_unique_nation_names(&mut nation_data, &capital_provinces, provinces, settings);
+    // Ensure all nation names are unique; custom nations come first and keep theirs
+    let all_capitals: Vec<usize> = custom_capitals.iter().chain(&capital_provinces).copied().collect();
+    creation::ensure_unique_nation_names(&mut nation_data, &all_capitals, provinces, settings);
+
+    // Custom nations keep a core around their capital regardless of growth order
+    let capital_set: HashSet<usize> = all_capitals.iter().copied().collect();
+    let reserved_cores: HashMap<NationId, Vec<u32>> = nation_data
+        .iter()
+        .zip(&custom_capitals)
+        .map(|((nation_id, ..), &capital)| {
+            (*nation_id, custom::reserve_core_territory(provinces, capital, &capital_set))
+        })
+        .collect();
 
     let mut governments = Vec::new();
     for (idx, (nation_id, nation, house, government)) in nation_data.into_iter().enumerate() {
@@ -109,7 +148,7 @@ pub fn spawn_nations(
 
     // Assign territory using growth algorithm
     let territory_timer = TimedOperation::start("Territory Assignment");
-    let province_ownership = territory::assign_territory_to_nations(&mut nations, provinces, settings.nation_density);
+    let province_ownership = territory::assign_territory_to_nations(&mut nations, provinces, settings.nation_density, &reserved_cores);
     let total_provinces_assigned: usize = province_ownership.values().map(|v| v.len()).sum();
     let _territory_time = territory_timer.complete_with_context(
         format!("{} provinces assigned to {} nations", total_provinces_assigned, province_ownership.len())
@@ -4,22 +4,24 @@
 //! and building Territory entities from contiguous province regions.
 
 use bevy::prelude::*;
-use noise::{NoiseFn, Perlin};
 use rayon::prelude::*;
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
 
+use crate::math::PerlinNoise;
 use crate::world::{Province, TerrainType};
 use super::super::types::*;
 
 /// Assign territory to nations using parallel growth algorithm with atomic operations
 ///
 /// Uses a Dijkstra-based expansion from capitals with terrain costs for organic borders.
+/// Provinces in `reserved` are owned by their nation before any growth starts.
 pub fn assign_territory_to_nations(
     nations: &mut Vec<(NationId, Nation)>,
     provinces: &mut [Province],
     density: crate::nations::NationDensity,
+    reserved: &HashMap<NationId, Vec<u32>>,
 ) -> HashMap<NationId, Vec<u32>> {
     if nations.is_empty() {
         return HashMap::new();
@@ -40,24 +42,42 @@ pub fn assign_territory_to_nations(
 
     // Initialize capitals with atomic ownership
     for (nation_idx, (_, nation)) in nations.iter().enumerate() {
-        let capital_idx = nation.capital_province as usize;
+        let capital_idx = nation.capital_province.value() as usize;
         atomic_owners[capital_idx].store((nation_idx as u32) + 1, Ordering::SeqCst);
     }
 
+    // Reserved cores are claimed up front so no neighbor can grow into them
+    let mut reserved_claims: Vec<Vec<u32>> = vec![Vec::new(); nations.len()];
+    for (nation_idx, (nation_id, _)) in nations.iter().enumerate() {
+        for &province_id in reserved.get(nation_id).into_iter().flatten() {
+            let owner = &atomic_owners[province_id as usize];
+            if owner
+                .compare_exchange(0, (nation_idx as u32) + 1, Ordering::SeqCst, Ordering::SeqCst)
+                .is_ok()
+            {
+                reserved_claims[nation_idx].push(province_id);
+            }
+        }
+    }
+
     // Initialize Perlin noise for organic borders
-    let perlin = Perlin::new(12345); // Fixed seed for consistent noise field
+    let perlin = PerlinNoise::new(12345); // Fixed seed for consistent noise field
 
     // Parallel territory expansion for all nations using Dijkstra's algorithm
     let nation_claims: Vec<Vec<u32>> = nations
         .par_iter()
         .enumerate()
         .map(|(nation_idx, (_, nation))| {
-            let mut claimed_provinces = vec![nation.capital_province];
+            let mut claimed_provinces = vec![nation.capital_province.value()];
 
             // Use BinaryHeap for Dijkstra-based expansion (min-heap via Reverse)
             // Tuple: (Reverse(accumulated_cost), province_id)
             let mut frontier = std::collections::BinaryHeap::new();
-            frontier.push((std::cmp::Reverse(0u32), nation.capital_province));
+            frontier.push((std::cmp::Reverse(0u32), nation.capital_province.value()));
+            for &province_id in &reserved_claims[nation_idx] {
+                claimed_provinces.push(province_id);
+                frontier.push((std::cmp::Reverse(0u32), province_id));
+            }
 
             let nation_id_atomic = (nation_idx as u32) + 1; // +1 because 0 means unclaimed
 
@@ -114,10 +134,10 @@ pub fn assign_territory_to_nations(
 
                             // Use Perlin noise for organic borders
                             let scale = 0.05;
-                            let noise_val = perlin.get([
+                            let noise_val = perlin.sample_raw(
                                 neighbor_province.position.x as f64 * scale,
-                                neighbor_province.position.y as f64 * scale
-                            ]);
+                                neighbor_province.position.y as f64 * scale,
+                            );
 
                             // Map noise [-1, 1] to cost modifier [0, 20]
                             let noise_cost = ((noise_val + 1.0) * 10.0) as u32;
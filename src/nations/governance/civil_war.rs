@@ -0,0 +1,128 @@
+//! Civil wars after violent government transitions
+//!
+//! When a government falls by force, part of the country may stay loyal to
+//! the old regime. The loyalists hold the provinces furthest from the capital,
+//! keep the fallen government, and go to war to restore it.
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use super::transitions::{GovernmentTransition, TransitionType};
+use crate::ai::{decision_rng, DecisionDomain};
+use crate::ids::IdAllocator;
+use crate::nations::{
+    spawn_breakaway_nation, CasusBelli, DeclareWarEvent, HeraldicParent, Nation, NationId,
+    NationPersonality, OwnershipChangeType, OwnershipService, WarGoal,
+};
+use crate::relationships::Controls;
+use crate::simulation::GameTime;
+use crate::world::{ProvinceData, WorldSeed};
+
+/// Provinces a nation needs before it can split in a civil war
+const MIN_CIVIL_WAR_PROVINCES: usize = 6;
+
+/// Chance that a violent transition splits the country
+const CIVIL_WAR_CHANCE: f32 = 0.5;
+
+/// Share of the country that stays loyal to the old regime
+const LOYALIST_SHARE: f32 = 0.35;
+
+/// Whether a transition is violent enough to leave loyalists fighting
+fn can_cause_civil_war(event: &GovernmentTransition) -> bool {
+    !event.peaceful
+        && event.from_government != event.to_government
+        && matches!(
+            event.transition_type,
+            TransitionType::Revolution
+                | TransitionType::Coup
+                | TransitionType::PopularUprising
+                | TransitionType::EliteConspiracy
+        )
+}
+
+/// Split loyalists of the fallen regime into their own nation and declare war
+pub fn break_out_civil_wars(
+    mut commands: Commands,
+    mut transitions: MessageReader<GovernmentTransition>,
+    mut ownership: OwnershipService,
+    mut war_events: MessageWriter<DeclareWarEvent>,
+    mut ids: ResMut<IdAllocator>,
+    nations_query: Query<(&Nation, &NationId, Option<&Controls>)>,
+    province_data_query: Query<&ProvinceData>,
+    game_time: Res<GameTime>,
+    world_seed: Option<Res<WorldSeed>>,
+) {
+    let seed = world_seed.map_or(0, |seed| seed.0);
+
+    for event in transitions.read() {
+        if !can_cause_civil_war(event) {
+            continue;
+        }
+        let Ok((nation, nation_id, Some(controls))) = nations_query.get(event.nation_entity) else {
+            continue;
+        };
+        if controls.provinces().len() < MIN_CIVIL_WAR_PROVINCES {
+            continue;
+        }
+        let mut rng = decision_rng(seed, DecisionDomain::Governance, nation_id.value(), 1, game_time.current_day());
+        if rng.r#gen::<f32>() >= CIVIL_WAR_CHANCE {
+            continue;
+        }
+
+        // Loyalists hold out in the provinces furthest from the new regime's capital
+        let mut provinces: Vec<(Entity, &ProvinceData)> = controls
+            .provinces()
+            .iter()
+            .filter_map(|&entity| province_data_query.get(entity).ok().map(|data| (entity, data)))
+            .collect();
+        let capital = provinces
+            .iter()
+            .find(|(_, data)| data.id == nation.capital_province)
+            .map(|(_, data)| data.position)
+            .unwrap_or_else(|| {
+                provinces.iter().map(|(_, data)| data.position).sum::<Vec2>() / provinces.len().max(1) as f32
+            });
+        provinces.sort_by(|(_, a), (_, b)| {
+            b.position.distance_squared(capital).total_cmp(&a.position.distance_squared(capital))
+        });
+        let loyalist_count = ((provinces.len() as f32 * LOYALIST_SHARE) as usize).max(1);
+        let loyalist_provinces: Vec<Entity> = provinces.iter().take(loyalist_count).map(|(entity, _)| *entity).collect();
+        let Some(&(_, loyalist_capital)) = provinces.first() else {
+            continue;
+        };
+
+        let Ok(loyalist_id) = ids.allocate::<NationId>() else {
+            continue;
+        };
+        let (loyalists, loyalist_name) = spawn_breakaway_nation(
+            &mut commands,
+            loyalist_id,
+            nation,
+            loyalist_capital.id,
+            game_time.current_year(),
+            event.from_government,
+            // Loyalists exist to take the country back
+            NationPersonality {
+                aggression: 0.7,
+                expansionism: nation.personality.expansionism,
+                diplomacy: nation.personality.diplomacy - 0.3,
+                mercantilism: nation.personality.mercantilism,
+            },
+            "Loyalist Council",
+        );
+        commands.entity(loyalists).insert(HeraldicParent(event.nation_entity));
+        ownership.transfer(loyalist_provinces, loyalists, OwnershipChangeType::Conquest);
+
+        war_events.write(DeclareWarEvent {
+            attacker: loyalists,
+            defender: event.nation_entity,
+            war_goal: WarGoal::Subjugation,
+            casus_belli: CasusBelli::IdeologicalConflict,
+        });
+
+        info!(
+            "Civil war in {}: loyalists of the {:?} regime form {} with {} provinces",
+            nation.name, event.from_government, loyalist_name, loyalist_count
+        );
+    }
+}
@@ -0,0 +1,396 @@
+//! Elections in democracies and republics
+//!
+//! Every few years each electing nation goes to the polls. Four parties
+//! campaign for the votes of four social classes; each class leans toward the
+//! parties that serve its interests, and the whole electorate punishes the
+//! ruling party for hard times or lost wars and rallies to nationalists while
+//! the country fights. The winner nudges policy in its direction and carries a
+//! mandate as strong as its share of the vote. Where institutions are weak the
+//! ruling party may rig a result it would have lost, at the cost of a
+//! contested mandate and spreading revolutionary ideas.
+
+use bevy::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::types::{ElectoralMandate, Governance, GovernmentCategory, GovernmentType, PoliticalPressure};
+use crate::ai::{decision_rng, DecisionDomain};
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::nations::{Nation, NationId, ParticipatesInWar};
+use crate::simulation::NewYearEvent;
+use crate::world::WorldSeed;
+
+/// Years between elections
+pub const ELECTION_TERM_YEARS: u32 = 4;
+
+/// Institution strength below which a losing government may rig the vote
+const RIGGING_INSTITUTIONS: f32 = 0.35;
+
+/// Chance that a losing government with weak institutions rigs the result
+const RIGGING_CHANCE: f32 = 0.6;
+
+/// Share of the vote a rigged result hands the ruling party
+const RIGGED_SHARE: f32 = 0.55;
+
+/// How much campaigns can swing a party's vote, either way
+const CAMPAIGN_SWING: f32 = 0.15;
+
+/// Political party contesting elections
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+pub enum Party {
+    Traditionalist,
+    Liberal,
+    Socialist,
+    Nationalist,
+}
+
+impl Party {
+    pub const ALL: [Party; 4] = [Party::Traditionalist, Party::Liberal, Party::Socialist, Party::Nationalist];
+
+    /// Position in [`Party::ALL`] and in vote tallies
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Party::Traditionalist => "Traditionalists",
+            Party::Liberal => "Liberals",
+            Party::Socialist => "Socialists",
+            Party::Nationalist => "Nationalists",
+        }
+    }
+}
+
+/// Social class voting by its interests
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VoterClass {
+    Landowners,
+    Merchants,
+    Workers,
+    Peasants,
+}
+
+impl VoterClass {
+    const ALL: [VoterClass; 4] = [
+        VoterClass::Landowners,
+        VoterClass::Merchants,
+        VoterClass::Workers,
+        VoterClass::Peasants,
+    ];
+
+    /// How strongly the class favors each party, in [`Party::ALL`] order
+    fn affinity(&self) -> [f32; 4] {
+        match self {
+            VoterClass::Landowners => [0.6, 0.2, 0.0, 0.2],
+            VoterClass::Merchants => [0.15, 0.6, 0.05, 0.2],
+            VoterClass::Workers => [0.05, 0.15, 0.6, 0.2],
+            VoterClass::Peasants => [0.45, 0.1, 0.25, 0.2],
+        }
+    }
+}
+
+/// Share of the electorate in each class, in [`VoterClass::ALL`] order
+///
+/// Unequal societies have more landowners and peasants; industrial ones more
+/// workers and merchants.
+fn class_shares(inequality: f32, industrial_output: f32) -> [f32; 4] {
+    let industry = (industrial_output - 0.5).clamp(0.0, 1.0);
+    let shares = [
+        0.05 + inequality * 0.1,
+        0.15 + industry * 0.1,
+        0.2 + industry * 0.3,
+        0.6 - industry * 0.4,
+    ];
+    let total: f32 = shares.iter().sum();
+    shares.map(|share| share / total)
+}
+
+/// Conditions the electorate judges the government on
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ElectionConditions {
+    /// 0.0 = prosperous, 1.0 = crisis
+    pub economic_hardship: f32,
+    /// 0.0 = undefeated, 1.0 = humiliated
+    pub military_defeat: f32,
+    pub at_war: bool,
+}
+
+/// Vote share for each party, in [`Party::ALL`] order, summing to 1.0
+///
+/// `campaigns` is how well each party campaigned, around 1.0.
+pub fn tally_votes(
+    inequality: f32,
+    industrial_output: f32,
+    ruling_party: Option<Party>,
+    conditions: ElectionConditions,
+    campaigns: [f32; 4],
+) -> [f32; 4] {
+    let shares = class_shares(inequality, industrial_output);
+    let mut votes = [0.0; 4];
+    for (class, share) in VoterClass::ALL.iter().zip(shares) {
+        for (vote, affinity) in votes.iter_mut().zip(class.affinity()) {
+            *vote += share * affinity;
+        }
+    }
+
+    let blame = conditions.economic_hardship * 0.5 + conditions.military_defeat * 0.5;
+    for (party, vote) in Party::ALL.iter().zip(votes.iter_mut()) {
+        if Some(*party) == ruling_party {
+            *vote *= 1.0 - blame * 0.6;
+        }
+        if *party == Party::Nationalist && conditions.at_war {
+            *vote *= 1.5;
+        }
+        if *party == Party::Socialist {
+            *vote *= 1.0 + conditions.economic_hardship * 0.5;
+        }
+    }
+
+    for (vote, campaign) in votes.iter_mut().zip(campaigns) {
+        *vote *= campaign.max(0.0);
+    }
+    let total: f32 = votes.iter().sum();
+    if total <= 0.0 {
+        return [0.25; 4];
+    }
+    votes.map(|vote| vote / total)
+}
+
+/// Whether a government chooses its leaders at the ballot box
+pub fn holds_elections(government: GovernmentType) -> bool {
+    government.category() == GovernmentCategory::Democratic
+        || matches!(
+            government,
+            GovernmentType::ConstitutionalMonarchy
+                | GovernmentType::MerchantRepublic
+                | GovernmentType::DemocraticSocialism
+        )
+}
+
+/// A nation's party politics
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct Electorate {
+    pub ruling_party: Option<Party>,
+    pub last_election_year: Option<u32>,
+    /// Vote shares at the last election, in [`Party::ALL`] order
+    pub last_result: [f32; 4],
+    pub last_election_rigged: bool,
+}
+
+impl Electorate {
+    fn new() -> Self {
+        Self {
+            ruling_party: None,
+            last_election_year: None,
+            last_result: [0.0; 4],
+            last_election_rigged: false,
+        }
+    }
+
+    pub fn election_due(&self, year: u32) -> bool {
+        self.last_election_year
+            .is_none_or(|last| year.saturating_sub(last) >= ELECTION_TERM_YEARS)
+    }
+}
+
+/// The winning party steers the country its way
+fn apply_policy_direction(party: Party, nation: &mut Nation, governance: &mut Governance) {
+    match party {
+        Party::Traditionalist => {
+            governance.reform_pressure = (governance.reform_pressure - 0.1).max(0.0);
+            nation.stability = (nation.stability + 0.03).min(1.0);
+        }
+        Party::Liberal => {
+            nation.tax_rate = (nation.tax_rate - 0.02).max(0.05);
+            nation.personality.mercantilism = (nation.personality.mercantilism + 0.05).min(1.0);
+        }
+        Party::Socialist => {
+            nation.tax_rate = (nation.tax_rate + 0.03).min(0.5);
+            nation.personality.aggression = (nation.personality.aggression - 0.05).max(-1.0);
+        }
+        Party::Nationalist => {
+            nation.personality.aggression = (nation.personality.aggression + 0.05).min(1.0);
+            nation.personality.expansionism = (nation.personality.expansionism + 0.05).min(1.0);
+        }
+    }
+}
+
+/// Hold elections in every electing nation whose term has run out
+pub fn hold_elections(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    world_seed: Option<Res<WorldSeed>>,
+    mut nations_query: Query<(
+        Entity,
+        &mut Nation,
+        &NationId,
+        &mut Governance,
+        &mut PoliticalPressure,
+        Option<&mut Electorate>,
+        Option<&ParticipatesInWar>,
+    )>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+    let seed = world_seed.map_or(0, |seed| seed.0);
+
+    for (entity, mut nation, nation_id, mut governance, mut pressure, electorate, at_war) in &mut nations_query {
+        if !holds_elections(governance.government_type) {
+            if electorate.is_some() {
+                commands.entity(entity).remove::<Electorate>();
+            }
+            continue;
+        }
+
+        let is_new = electorate.is_none();
+        let mut fresh = Electorate::new();
+        let electorate: &mut Electorate = match electorate {
+            Some(electorate) => electorate.into_inner(),
+            None => &mut fresh,
+        };
+        if !electorate.election_due(year) {
+            continue;
+        }
+
+        let mut rng = decision_rng(seed, DecisionDomain::Governance, nation_id.value(), 2, year);
+        let campaigns = [(); 4].map(|_| 1.0 + rng.gen_range(-CAMPAIGN_SWING..=CAMPAIGN_SWING));
+        let mechanics = governance.government_type.mechanics();
+        let conditions = ElectionConditions {
+            economic_hardship: pressure.economic_crisis,
+            military_defeat: pressure.military_defeat,
+            at_war: at_war.is_some(),
+        };
+        let mut result = tally_votes(
+            mechanics.inequality,
+            mechanics.industrial_output,
+            electorate.ruling_party,
+            conditions,
+            campaigns,
+        );
+
+        let (winner_index, _) = result
+            .iter()
+            .enumerate()
+            .fold((0, f32::MIN), |best, (index, &share)| if share > best.1 { (index, share) } else { best });
+        let mut winner = Party::ALL[winner_index];
+
+        // A ruling party facing defeat with nobody to stop it keeps power anyway
+        let rigged = match electorate.ruling_party {
+            Some(incumbent) if incumbent != winner && governance.institution_strength < RIGGING_INSTITUTIONS => {
+                rng.r#gen::<f32>() < RIGGING_CHANCE
+            }
+            _ => false,
+        };
+        if let (true, Some(incumbent)) = (rigged, electorate.ruling_party) {
+            let incumbent_index = incumbent.index();
+            let others: f32 = result.iter().enumerate().filter(|&(i, _)| i != incumbent_index).map(|(_, s)| s).sum();
+            for (index, share) in result.iter_mut().enumerate() {
+                *share = if index == incumbent_index {
+                    RIGGED_SHARE
+                } else if others > 0.0 {
+                    *share / others * (1.0 - RIGGED_SHARE)
+                } else {
+                    0.0
+                };
+            }
+            winner = incumbent;
+        }
+        let winning_share = result[winner.index()];
+
+        governance.legitimacy_factors.electoral_mandate = Some(ElectoralMandate {
+            vote_percentage: winning_share,
+            // Short of a majority, the winner governs through a coalition
+            coalition_strength: if winning_share >= 0.5 { 1.0 } else { winning_share * 1.5 },
+            days_until_election: ELECTION_TERM_YEARS * 365,
+            election_was_contested: rigged,
+        });
+        if rigged {
+            governance.legitimacy = (governance.legitimacy - 0.15).max(0.0);
+            pressure.revolutionary_ideas = (pressure.revolutionary_ideas + 0.2).min(1.0);
+        } else {
+            governance.legitimacy = (governance.legitimacy * 0.5 + (0.4 + winning_share * 0.6) * 0.5).min(1.0);
+        }
+        apply_policy_direction(winner, &mut nation, &mut governance);
+
+        let previous = electorate.ruling_party;
+        electorate.ruling_party = Some(winner);
+        electorate.last_election_year = Some(year);
+        electorate.last_result = result;
+        electorate.last_election_rigged = rigged;
+
+        let text = match (rigged, previous) {
+            (true, _) => format!(
+                "The {} keep power in {} with a claimed {:.0}% of the vote amid accusations of fraud",
+                winner.name(),
+                nation.name,
+                winning_share * 100.0
+            ),
+            (false, Some(previous)) if previous == winner => format!(
+                "The {} are re-elected in {} with {:.0}% of the vote",
+                winner.name(),
+                nation.name,
+                winning_share * 100.0
+            ),
+            (false, _) => format!(
+                "The {} win the election in {} with {:.0}% of the vote",
+                winner.name(),
+                nation.name,
+                winning_share * 100.0
+            ),
+        };
+        chronicle.write(ChronicleEvent {
+            category: ChronicleCategory::Politics,
+            text,
+            nations: vec![*nation_id],
+        });
+
+        if is_new {
+            commands.entity(entity).insert(fresh);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EVEN_CAMPAIGNS: [f32; 4] = [1.0; 4];
+
+    #[test]
+    fn hard_times_cost_the_ruling_party_votes() {
+        let calm = tally_votes(0.5, 1.0, Some(Party::Liberal), ElectionConditions::default(), EVEN_CAMPAIGNS);
+        let crisis = tally_votes(
+            0.5,
+            1.0,
+            Some(Party::Liberal),
+            ElectionConditions {
+                economic_hardship: 1.0,
+                military_defeat: 0.5,
+                at_war: false,
+            },
+            EVEN_CAMPAIGNS,
+        );
+        assert!(crisis[1] < calm[1]);
+        assert!((crisis.iter().sum::<f32>() - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn war_rallies_voters_to_nationalists() {
+        let peace = tally_votes(0.5, 1.0, None, ElectionConditions::default(), EVEN_CAMPAIGNS);
+        let war = tally_votes(
+            0.5,
+            1.0,
+            None,
+            ElectionConditions {
+                at_war: true,
+                ..Default::default()
+            },
+            EVEN_CAMPAIGNS,
+        );
+        assert!(war[3] > peace[3]);
+    }
+}
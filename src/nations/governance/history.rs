@@ -11,6 +11,7 @@ use super::transitions::TransitionType;
 
 /// Component that tracks a nation's government history
 #[derive(Component, Debug, Clone, Default, Serialize, Deserialize, Reflect)]
+#[reflect(Component)]
 pub struct GovernmentHistory {
     pub founding_government: Option<GovernmentType>,
     pub changes: Vec<GovernmentChange>,
@@ -6,6 +6,7 @@
 use bevy::prelude::*;
 
 use super::types::{Governance, GovernmentType, PoliticalPressure};
+use crate::math::Money;
 
 /// Factors that affect government legitimacy
 #[derive(Debug, Clone, Default)]
@@ -18,6 +19,7 @@ pub struct LegitimacyFactors {
     pub democratic_mandate: f32,     // For elected governments
     pub revolutionary_fervor: f32,   // For new revolutionary governments
     pub foreign_recognition: f32,    // International acceptance
+    pub scandal: f32,                // Corruption exposed, fading with time
 }
 
 /// Update government legitimacy based on various factors
@@ -65,11 +67,11 @@ fn calculate_legitimacy_factors(
     let mut factors = LegitimacyFactors::default();
 
     // Economic prosperity
-    factors.economic_prosperity = if nation.treasury > 1000.0 {
+    factors.economic_prosperity = if nation.treasury > Money::from_int(1000) {
         1.0
-    } else if nation.treasury > 500.0 {
+    } else if nation.treasury > Money::from_int(500) {
         0.7
-    } else if nation.treasury > 100.0 {
+    } else if nation.treasury > Money::from_int(100) {
         0.4
     } else {
         0.2
@@ -104,7 +106,15 @@ fn calculate_legitimacy_factors(
             factors.divine_mandate = 0.8;
         },
         super::types::GovernmentCategory::Democratic => {
-            factors.democratic_mandate = 0.9;
+            // The last election's result, once one has been held
+            factors.democratic_mandate = governance
+                .legitimacy_factors
+                .electoral_mandate
+                .as_ref()
+                .map_or(0.9, |mandate| {
+                    let contested = if mandate.election_was_contested { 0.5 } else { 1.0 };
+                    (0.5 + mandate.vote_percentage).min(1.0) * contested
+                });
         },
         super::types::GovernmentCategory::Socialist | super::types::GovernmentCategory::Anarchist => {
             factors.revolutionary_fervor = if days_in_power < 365 { 0.8 } else { 0.4 };
@@ -115,6 +125,12 @@ fn calculate_legitimacy_factors(
     // House legitimacy affects overall legitimacy
     factors.foreign_recognition = house.legitimacy;
 
+    factors.scandal = governance
+        .legitimacy_factors
+        .corruption_scandal
+        .as_ref()
+        .map_or(0.0, |scandal| scandal.severity * (1.0 - scandal.days_since as f32 / (3.0 * 365.0)).max(0.0));
+
     factors
 }
 
@@ -160,7 +176,8 @@ pub fn calculate_legitimacy(governance: &Governance, factors: &LegitimacyFactors
         factors.divine_mandate * divine_weight * 0.15 +
         factors.democratic_mandate * democratic_weight * 0.15 +
         factors.revolutionary_fervor * 0.05 +
-        factors.foreign_recognition * 0.05;
+        factors.foreign_recognition * 0.05 -
+        factors.scandal * 0.2;
 
     // Normalize and combine with base
     (base + weighted_sum).clamp(0.0, 1.0)
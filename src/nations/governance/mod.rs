@@ -4,11 +4,14 @@
 //! enabling dynamic government types, political transitions, and governance-aware naming.
 
 // Private submodules (gateway architecture)
+mod civil_war;
+mod elections;
 mod history;
 mod legitimacy;
 mod naming;
 mod plugin;
 mod pressure;
+mod public_opinion;
 mod transitions;
 mod types;
 
@@ -16,16 +19,20 @@ mod types;
 pub use plugin::GovernancePlugin;
 
 pub use types::{
-    BrokenPromise, CorruptionScandal, CrisisFactors, DivineApproval, ElectoralMandate, Governance, GovernanceSettings, GovernmentCategory, GovernmentMechanics, GovernmentType, InstitutionalControl,
+    BrokenPromise, CorruptionScandal, CrisisFactors, DivineApproval, ElectoralMandate, Governance, GovernanceSettings, GovernmentCategory, GovernmentMechanics, GovernmentType, InstitutionalControl, UniqueMechanic,
     LegitimacyEvent, LegitimacyEventType, LegitimacyFactors, LegitimacyWeights,
     MilitaryVictory, PoliticalPressure, RevolutionaryFervor, SeparatistMovement,
 };
 
 pub use naming::{
-    generate_governance_aware_name, get_ruler_title, get_structure_name,
+    era_nation_name, generate_governance_aware_name, get_ruler_title, get_structure_name, rename_for_government,
     suggest_government_for_culture, DevelopmentLevel, build_nation_name,
 };
 
-pub use transitions::GovernmentTransition;
+pub use elections::{Electorate, Party};
+
+pub use public_opinion::PublicOpinion;
+
+pub use transitions::{GovernmentTransition, NationRenamed, TransitionType};
 
 pub use history::{GovernmentChange, GovernmentHistory};
\ No newline at end of file
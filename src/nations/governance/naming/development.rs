@@ -4,7 +4,9 @@
 //! government types for different eras and cultures.
 
 /// Development level for determining appropriate government types
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Also serves as the presentation era that picks map titles and sprites.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum DevelopmentLevel {
     Primitive,
     Medieval,
@@ -12,6 +14,18 @@ pub enum DevelopmentLevel {
     Modern,
 }
 
+impl DevelopmentLevel {
+    /// Name of the age, as chroniclers write it
+    pub fn age_name(&self) -> &'static str {
+        match self {
+            DevelopmentLevel::Primitive => "Tribal Age",
+            DevelopmentLevel::Medieval => "Medieval Age",
+            DevelopmentLevel::Renaissance => "Renaissance",
+            DevelopmentLevel::Modern => "Modern Age",
+        }
+    }
+}
+
 impl From<crate::nations::types::StartingDevelopment> for DevelopmentLevel {
     fn from(starting: crate::nations::types::StartingDevelopment) -> Self {
         match starting {
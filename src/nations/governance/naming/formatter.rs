@@ -4,6 +4,7 @@
 //! government type and cultural context.
 
 use crate::name_generator::Culture;
+use super::development::DevelopmentLevel;
 use crate::nations::governance::types::GovernmentType;
 
 /// Format the final nation name based on government type
@@ -104,4 +105,30 @@ pub fn format_nation_name(
         CyborgCollective => format!("Cybernetic Union of {}", base_name),
         ChronocraticCouncil => format!("Temporal Authority of {}", base_name),
     }
-}
\ No newline at end of file
+}
+
+/// Title an era gives a government in place of its usual one, if any
+///
+/// Before states are organised, kings are chiefs and republics are tribal
+/// councils; tribal federations that survive into the modern age become
+/// confederations. Other eras use the government's own title.
+pub fn format_era_nation_name(
+    government: &GovernmentType,
+    era: DevelopmentLevel,
+    base_name: &str,
+) -> Option<String> {
+    use GovernmentType::*;
+
+    match (era, government) {
+        (DevelopmentLevel::Primitive, AbsoluteMonarchy | Feudalism | ConstitutionalMonarchy) => {
+            Some(format!("Chiefdom of {}", base_name))
+        }
+        (DevelopmentLevel::Primitive, Empire | DivineManadate) => Some(format!("High Chiefdom of {}", base_name)),
+        (
+            DevelopmentLevel::Primitive,
+            PresidentialRepublic | ParliamentaryDemocracy | FederalRepublic | MerchantRepublic | DirectDemocracy,
+        ) => Some(format!("{} Tribal Council", base_name)),
+        (DevelopmentLevel::Modern, TribalFederation) => Some(format!("{} Confederation", base_name)),
+        _ => None,
+    }
+}
@@ -3,6 +3,7 @@
 //! This module provides the main entry point for generating nation names
 //! that match their government type.
 
+use super::development::DevelopmentLevel;
 use crate::name_generator::{Culture, NameGenerator};
 use crate::nations::governance::types::{Gender, GovernmentType};
 
@@ -19,6 +20,49 @@ pub fn generate_governance_aware_name(
     super::builder::build_nation_name(generator, culture, *government)
 }
 
+/// Rename a nation for a new government while keeping its place name
+///
+/// "Kingdom of Britannia" becoming a presidential republic yields
+/// "Republic of Britannia" rather than an unrelated random name.
+pub fn rename_for_government(
+    current_name: &str,
+    culture: Culture,
+    from: &GovernmentType,
+    to: &GovernmentType,
+) -> String {
+    let base_name = extract_place_name(current_name, culture, from);
+    super::formatter::format_nation_name(to, get_structure_name(to), &base_name, culture)
+}
+
+/// A nation's name with the title of its presentation era
+///
+/// "Kingdom of Britannia" reads "Chiefdom of Britannia" until its people
+/// leave the tribal age; names without an era title are returned unchanged.
+pub fn era_nation_name(name: &str, culture: Culture, government: &GovernmentType, era: DevelopmentLevel) -> String {
+    let base_name = extract_place_name(name, culture, government);
+    super::formatter::format_era_nation_name(government, era, &base_name).unwrap_or_else(|| name.to_string())
+}
+
+/// Recover the place name from a name formatted for `government`
+pub fn extract_place_name(name: &str, culture: Culture, government: &GovernmentType) -> String {
+    const PLACEHOLDER: &str = "\u{0}";
+
+    let pattern = super::formatter::format_nation_name(government, "", PLACEHOLDER, culture);
+    if let Some((prefix, suffix)) = pattern.split_once(PLACEHOLDER) {
+        let has_structure = !prefix.is_empty() || !suffix.is_empty();
+        if has_structure
+            && name.len() > prefix.len() + suffix.len()
+            && name.starts_with(prefix)
+            && name.ends_with(suffix)
+        {
+            return name[prefix.len()..name.len() - suffix.len()].to_string();
+        }
+    }
+
+    // Names from older generators or other formats
+    super::utils::strip_government_structures(name)
+}
+
 /// Get the appropriate ruler title for a government type
 pub fn get_ruler_title(government: &GovernmentType, _gender: Gender) -> &'static str {
     use GovernmentType::*;
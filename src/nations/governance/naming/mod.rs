@@ -21,5 +21,8 @@ mod builder_test;
 // Public exports (controlled API surface)
 pub use builder::build_nation_name;
 pub use development::DevelopmentLevel;
-pub use generator::{generate_governance_aware_name, get_ruler_title, get_structure_name};
+pub use generator::{
+    era_nation_name, extract_place_name, generate_governance_aware_name, get_ruler_title, get_structure_name,
+    rename_for_government,
+};
 pub use selection::suggest_government_for_culture;
\ No newline at end of file
@@ -185,4 +185,38 @@ mod tests {
             "Emperor"
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_rename_keeps_place_name() {
+        assert_eq!(
+            rename_for_government(
+                "Kingdom of Britannia",
+                Culture::Western,
+                &GovernmentType::AbsoluteMonarchy,
+                &GovernmentType::PresidentialRepublic,
+            ),
+            "Republic of Britannia"
+        );
+
+        assert_eq!(
+            rename_for_government(
+                "Greater Prussia Empire",
+                Culture::Western,
+                &GovernmentType::Empire,
+                &GovernmentType::VanguardCommunism,
+            ),
+            "People's Republic of Greater Prussia"
+        );
+
+        // Bare names without a structure still gain one
+        assert_eq!(
+            rename_for_government(
+                "Nordheim",
+                Culture::Northern,
+                &GovernmentType::Plutocracy,
+                &GovernmentType::AbsoluteMonarchy,
+            ),
+            "Kingdom of Nordheim"
+        );
+    }
+}
@@ -7,23 +7,46 @@ use bevy::prelude::*;
 use bevy_plugin_builder::define_plugin;
 
 use super::types::GovernanceSettings;
+use super::civil_war::break_out_civil_wars;
+use super::elections::{hold_elections, Electorate};
 use super::transitions::{check_for_transitions, process_government_transitions};
 use super::legitimacy::update_government_legitimacy;
-use super::pressure::update_political_pressure;
+use super::public_opinion::{shape_public_opinion, PublicOpinion};
+use super::pressure::{accumulate_yearly_pressures, apply_lost_war_pressure, update_political_pressure};
 
 define_plugin!(GovernancePlugin {
     resources: [
         GovernanceSettings,
     ],
 
+    reflect: [
+        Electorate,
+        PublicOpinion,
+    ],
+
     messages: [
         super::transitions::GovernmentTransition,
+        super::transitions::NationRenamed,
     ],
 
     update: [
         update_political_pressure.run_if(in_state(crate::states::GameState::InGame)),
+        accumulate_yearly_pressures.run_if(in_state(crate::states::GameState::InGame)),
+        // Peace despawns the war, so the loser must be found first
+        apply_lost_war_pressure
+            .before(crate::nations::diplomacy::sign_peace_on_war_end)
+            .run_if(in_state(crate::states::GameState::InGame)),
+        // Elections set the mandate that legitimacy is then measured against
+        hold_elections
+            .before(update_government_legitimacy)
+            .run_if(in_state(crate::states::GameState::InGame)),
+        shape_public_opinion
+            .before(update_government_legitimacy)
+            .run_if(in_state(crate::states::GameState::InGame)),
         update_government_legitimacy.run_if(in_state(crate::states::GameState::InGame)),
-        check_for_transitions.run_if(in_state(crate::states::GameState::InGame)),
-        process_government_transitions.run_if(in_state(crate::states::GameState::InGame)),
+        (check_for_transitions, process_government_transitions, break_out_civil_wars)
+            .chain()
+            .before(crate::nations::process_war_declarations)
+            .run_if(in_state(crate::states::GameState::InGame)),
     ],
-});
\ No newline at end of file
+});
@@ -5,6 +5,26 @@
 
 use bevy::prelude::*;
 use super::types::{PoliticalPressure, Governance, GovernmentCategory};
+use crate::nations::{Attacking, War, WarEndEvent, WarOutcome, WarParticipants};
+use crate::simulation::NewYearEvent;
+
+/// Stability below which a year counts toward prolonged instability
+const UNSTABLE_BELOW: f32 = 0.35;
+
+/// Prolonged instability gained per unstable year
+const INSTABILITY_PER_YEAR: f32 = 0.12;
+
+/// Treasury at which an unequal government's wealth counts as half concentrated
+const CONCENTRATED_TREASURY: f32 = 5000.0;
+
+/// Technology level from which enlightenment ideas spread
+pub const ENLIGHTENMENT_TECH_LEVEL: u32 = 3;
+
+/// Enlightenment gained per year above the threshold, per technology level
+const ENLIGHTENMENT_PER_LEVEL: f32 = 0.03;
+
+/// Military defeat pressure from losing a war
+const LOST_WAR_DEFEAT: f32 = 0.5;
 
 /// Update political pressure based on various factors
 pub fn update_political_pressure(
@@ -16,10 +36,11 @@ pub fn update_political_pressure(
     )>,
 ) {
     for (nation, mut pressure, mut governance) in &mut nations {
+        let treasury = nation.treasury.to_f32();
         // Economic pressure from low treasury
-        if nation.treasury < 100.0 {
+        if treasury < 100.0 {
             // Clamp to [0.0, 1.0] range to prevent excessive pressure from negative treasury
-            pressure.economic_crisis = ((100.0 - nation.treasury) / 100.0).min(1.0).max(0.0);
+            pressure.economic_crisis = ((100.0 - treasury) / 100.0).min(1.0).max(0.0);
         } else {
             pressure.economic_crisis *= 0.95; // Decay if economy good
         }
@@ -47,7 +68,7 @@ pub fn update_political_pressure(
 
         // INSTITUTIONAL RECOVERY - Stable nations slowly rebuild state apparatus
         // This natural recovery prevents permanent failed states unless conditions remain terrible
-        if governance.stability > 0.5 && nation.treasury > 200.0 {
+        if governance.stability > 0.5 && treasury > 200.0 {
             // Good conditions = faster recovery
             governance.institution_strength = (governance.institution_strength + 0.005).min(1.0);
         } else if governance.stability > 0.3 {
@@ -62,15 +83,88 @@ pub fn update_political_pressure(
         if let Some(last_transition) = governance.last_transition {
             let days_since_transition = (time.current_day() as f32 - last_transition as f32).max(0.0);
             if days_since_transition < 180.0 {  // First 6 months critical
-                if nation.treasury > 500.0 && nation.military_strength > 50.0 {
+                if treasury > 500.0 && nation.military_strength > 50.0 {
                     // Strong position = rapid consolidation
                     governance.stability = (governance.stability + 0.01).min(1.0);
                     governance.institution_strength = (governance.institution_strength + 0.003).min(1.0);
-                } else if nation.treasury < 100.0 || nation.military_strength < 20.0 {
+                } else if treasury < 100.0 || nation.military_strength < 20.0 {
                     // Weak position = continued instability
                     governance.stability = (governance.stability * 0.99).max(0.1);
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// Yearly pressures that build slowly: instability, wealth, and new ideas
+pub fn accumulate_yearly_pressures(
+    mut year_events: MessageReader<NewYearEvent>,
+    mut nations: Query<(&crate::nations::Nation, &Governance, &mut PoliticalPressure)>,
+) {
+    let years = year_events.read().count();
+    if years == 0 {
+        return;
+    }
+
+    for (nation, governance, mut pressure) in &mut nations {
+        for _ in 0..years {
+            // Each unstable year weighs more than the last; a stable one lets it fade
+            if nation.stability < UNSTABLE_BELOW {
+                pressure.prolonged_instability =
+                    (pressure.prolonged_instability * 1.1 + INSTABILITY_PER_YEAR).min(1.0);
+            } else {
+                pressure.prolonged_instability *= 0.7;
+            }
+
+            let treasury = nation.treasury.to_f32().max(0.0);
+            let hoard = treasury / (treasury + CONCENTRATED_TREASURY);
+            let concentration = (governance.government_type.mechanics().inequality * hoard * 2.0).min(1.0);
+            pressure.wealth_concentration = pressure.wealth_concentration * 0.8 + concentration * 0.2;
+
+            let rules_by_tradition = matches!(
+                governance.government_type.category(),
+                GovernmentCategory::Monarchic | GovernmentCategory::Theocratic | GovernmentCategory::Autocratic
+            );
+            if rules_by_tradition && nation.technology_level >= ENLIGHTENMENT_TECH_LEVEL {
+                let levels = (nation.technology_level - ENLIGHTENMENT_TECH_LEVEL + 1) as f32;
+                pressure.enlightenment = (pressure.enlightenment + levels * ENLIGHTENMENT_PER_LEVEL).min(1.0);
+            } else {
+                pressure.enlightenment *= 0.9;
+            }
+        }
+    }
+}
+
+/// Losing a war discredits the government that fought it
+///
+/// Runs before peace dissolves the war, while its participants are still known.
+pub fn apply_lost_war_pressure(
+    mut war_end_events: MessageReader<WarEndEvent>,
+    wars_query: Query<(&War, Option<&WarParticipants>)>,
+    attackers_query: Query<(Entity, &Attacking)>,
+    mut pressures: Query<&mut PoliticalPressure>,
+) {
+    for event in war_end_events.read() {
+        let Some((_, participants)) = wars_query.iter().find(|(war, _)| war.war_id == event.war_id) else {
+            continue;
+        };
+        let participants = participants.map(|p| p.participants()).unwrap_or(&[]);
+        let Some((attacker, defender)) = attackers_query
+            .iter()
+            .find(|(entity, attacking)| participants.contains(entity) && participants.contains(&attacking.0))
+            .map(|(entity, attacking)| (entity, attacking.0))
+        else {
+            continue;
+        };
+
+        let loser = match event.outcome {
+            WarOutcome::AttackerVictory => defender,
+            WarOutcome::DefenderVictory => attacker,
+            WarOutcome::WhitePeace => continue,
+        };
+        if let Ok(mut pressure) = pressures.get_mut(loser) {
+            pressure.military_defeat = (pressure.military_defeat + LOST_WAR_DEFEAT).min(1.0);
+            pressure.prolonged_instability = (pressure.prolonged_instability + INSTABILITY_PER_YEAR).min(1.0);
+        }
+    }
+}
@@ -0,0 +1,209 @@
+//! Public opinion, the press, and propaganda
+//!
+//! What people think of their rulers and their wars depends on what reaches
+//! them. A nation's media reach grows with literacy; its credibility follows
+//! how free the press is. Free presses carry bad news — famines, defeats —
+//! to the public at once, so stability takes the hit immediately. Controlled
+//! presses sit on it, but suppressed news piles up and leaks out later.
+//!
+//! Governments with money to spare and approval to win can fund propaganda.
+//! It lifts approval and war support as far as the media reaches and is
+//! believed, but if exposed it costs legitimacy and the media's credibility.
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use super::types::{Governance, GovernmentCategory, PoliticalPressure, UniqueMechanic};
+use crate::ai::{decision_rng, DecisionDomain};
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::math::{Fixed32, Money};
+use crate::nations::{Bureaucracy, LawId, Nation, NationId, NationLaws, ParticipatesInWar};
+use crate::simulation::NewYearEvent;
+use crate::world::WorldSeed;
+
+/// The "Free Press" law
+const FREE_PRESS_LAW: LawId = LawId::new(10004);
+/// The "State Media" law
+const STATE_MEDIA_LAW: LawId = LawId::new(10005);
+
+/// Share of bad news reaching the public each year under total censorship
+const CENSORED_NEWS_SPEED: f32 = 0.15;
+/// Stability lost per unit of bad news reaching the public
+const BAD_NEWS_UNREST: f32 = 0.1;
+/// Share of the gap to credibility target closed each year
+const CREDIBILITY_DRIFT: f32 = 0.1;
+/// Share of the gap to approval target closed each year
+const APPROVAL_DRIFT: f32 = 0.3;
+/// Approval below which a government considers propaganda
+const PROPAGANDA_THRESHOLD: f32 = 0.45;
+/// Yearly treasury cost of a propaganda campaign
+const PROPAGANDA_COST: Money = Money::from_int(50);
+/// Approval a fully believed campaign with full reach adds
+const PROPAGANDA_BOOST: f32 = 0.25;
+/// Yearly chance a campaign is exposed under a fully free press
+const EXPOSURE_CHANCE: f32 = 0.4;
+/// Legitimacy lost when a campaign is exposed
+const EXPOSURE_LEGITIMACY: f32 = 0.15;
+/// Stability lost per year at war with no support for it
+const WAR_WEARINESS_UNREST: f32 = 0.05;
+
+/// How a nation's public sees its rulers and its wars
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct PublicOpinion {
+    /// Approval of the government (0.0-1.0)
+    pub ruler_approval: f32,
+    /// Support for the nation's current wars (0.0-1.0, 1.0 in peace)
+    pub war_support: f32,
+    /// How freely the press reports (0.0 = fully controlled)
+    pub press_freedom: f32,
+    /// Share of the population the media reaches
+    pub media_reach: f32,
+    /// How far the public believes what it reads
+    pub credibility: f32,
+    /// Bad news not yet reported
+    pub suppressed_news: f32,
+    /// Whether the government is funding propaganda
+    pub propaganda: bool,
+}
+
+impl Default for PublicOpinion {
+    fn default() -> Self {
+        Self {
+            ruler_approval: 0.5,
+            war_support: 1.0,
+            press_freedom: 0.5,
+            media_reach: 0.2,
+            credibility: 0.5,
+            suppressed_news: 0.0,
+            propaganda: false,
+        }
+    }
+}
+
+/// How freely a nation's press reports, from its laws and government
+pub fn press_freedom(governance: &Governance, laws: Option<&NationLaws>) -> f32 {
+    if laws.is_some_and(|laws| laws.is_active(FREE_PRESS_LAW)) {
+        return 1.0;
+    }
+    if laws.is_some_and(|laws| laws.is_active(STATE_MEDIA_LAW)) {
+        return 0.1;
+    }
+    let mechanics = governance.government_type.mechanics();
+    if mechanics.unique_mechanics.contains(&UniqueMechanic::FreePress) {
+        return 0.9;
+    }
+    match governance.government_type.category() {
+        GovernmentCategory::Democratic | GovernmentCategory::Anarchist => 0.7,
+        GovernmentCategory::Autocratic | GovernmentCategory::Theocratic => 0.2,
+        _ => 0.4,
+    }
+}
+
+/// Bad news reported this year out of what has piled up, and what stays suppressed
+pub fn report_news(backlog: f32, press_freedom: f32) -> (f32, f32) {
+    let speed = CENSORED_NEWS_SPEED + (1.0 - CENSORED_NEWS_SPEED) * press_freedom.clamp(0.0, 1.0);
+    let reported = backlog * speed;
+    (reported, backlog - reported)
+}
+
+/// Yearly update of public opinion, news, and propaganda campaigns
+pub fn shape_public_opinion(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    world_seed: Option<Res<WorldSeed>>,
+    mut nations_query: Query<(
+        Entity,
+        &mut Nation,
+        &NationId,
+        &mut Governance,
+        &PoliticalPressure,
+        Option<&NationLaws>,
+        Option<&Bureaucracy>,
+        Option<&ParticipatesInWar>,
+        Option<&mut PublicOpinion>,
+    )>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+    let seed = world_seed.map_or(0, |seed| seed.0);
+
+    for (entity, mut nation, nation_id, mut governance, pressure, laws, bureaucracy, at_war, opinion) in
+        &mut nations_query
+    {
+        let mut updated = opinion.as_deref().cloned().unwrap_or_default();
+
+        updated.press_freedom = press_freedom(&governance, laws);
+        updated.media_reach = 0.2 + bureaucracy.map_or(0.1, |bureaucracy| bureaucracy.literacy) * 0.8;
+        // A free press is believed; a controlled one slowly stops being
+        let credibility_target = 0.3 + updated.press_freedom * 0.6;
+        updated.credibility += (credibility_target - updated.credibility) * CREDIBILITY_DRIFT;
+
+        // Famines and defeats reach the public as fast as the press is allowed to carry them
+        let bad_news = pressure.economic_crisis + pressure.military_defeat;
+        let (reported, suppressed) = report_news(updated.suppressed_news + bad_news, updated.press_freedom);
+        updated.suppressed_news = suppressed;
+        nation.stability = (nation.stability - reported * BAD_NEWS_UNREST).clamp(0.0, 1.0);
+
+        updated.propaganda = updated.ruler_approval < PROPAGANDA_THRESHOLD
+            && updated.press_freedom < 1.0
+            && nation.treasury > PROPAGANDA_COST * Fixed32::from_int(4);
+        let persuasion = if updated.propaganda {
+            nation.treasury -= PROPAGANDA_COST;
+            updated.media_reach * updated.credibility * PROPAGANDA_BOOST
+        } else {
+            0.0
+        };
+
+        let approval_target =
+            (nation.stability * 0.6 + governance.legitimacy * 0.4 - reported * 0.5 + persuasion).clamp(0.0, 1.0);
+        updated.ruler_approval += (approval_target - updated.ruler_approval) * APPROVAL_DRIFT;
+        governance.legitimacy_factors.public_approval_rating = updated.ruler_approval;
+        governance.legitimacy_factors.institutional_control.media_control = 1.0 - updated.press_freedom;
+
+        updated.war_support = if at_war.is_some() {
+            (0.7 - pressure.military_defeat * updated.media_reach - reported * 0.3 + persuasion).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        nation.stability = (nation.stability - (1.0 - updated.war_support) * WAR_WEARINESS_UNREST).max(0.0);
+
+        // Journalists, rivals, or leaks may expose the campaign
+        let mut rng = decision_rng(seed, DecisionDomain::Governance, nation_id.value(), 4, year);
+        if updated.propaganda && rng.r#gen::<f32>() < updated.press_freedom * EXPOSURE_CHANCE {
+            governance.legitimacy = (governance.legitimacy - EXPOSURE_LEGITIMACY).max(0.0);
+            updated.credibility *= 0.5;
+            updated.propaganda = false;
+            chronicle.write(ChronicleEvent {
+                category: ChronicleCategory::Politics,
+                text: format!("A propaganda campaign by the government of {} is exposed", nation.name),
+                nations: vec![*nation_id],
+            });
+        }
+
+        match opinion {
+            Some(mut opinion) => *opinion = updated,
+            None => {
+                commands.entity(entity).insert(updated);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn censorship_delays_bad_news_without_erasing_it() {
+        let (free_now, free_left) = report_news(1.0, 1.0);
+        let (censored_now, censored_left) = report_news(1.0, 0.0);
+
+        assert!((free_now - 1.0).abs() < 1e-6);
+        assert_eq!(free_left, 0.0);
+        assert!(censored_now < 0.2);
+        assert!((censored_now + censored_left - 1.0).abs() < 1e-6);
+    }
+}
@@ -6,7 +6,14 @@
 use bevy::prelude::*;
 use rand::Rng;
 
-use super::types::{Governance, GovernmentType, GovernmentCategory, PoliticalPressure, GovernanceSettings};
+use super::types::{
+    Governance, GovernmentType, GovernmentCategory, LegitimacyFactors, PoliticalPressure, GovernanceSettings,
+};
+use crate::ai::{decision_rng, DecisionDomain};
+use crate::math::Fixed32;
+use crate::nations::NationId;
+use crate::simulation::GameTime;
+use crate::world::WorldSeed;
 
 /// Event for government transitions
 #[derive(Message, Debug, Clone)]
@@ -18,6 +25,15 @@ pub struct GovernmentTransition {
     pub peaceful: bool,
 }
 
+/// Event fired when a nation takes a new name
+#[derive(Message, Debug, Clone)]
+pub struct NationRenamed {
+    pub nation_entity: Entity,
+    pub old_name: String,
+    pub new_name: String,
+    pub year: u32,
+}
+
 /// How the government transition occurs
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, Reflect)]
 pub enum TransitionType {
@@ -38,16 +54,20 @@ pub fn check_for_transitions(
     mut nations: Query<(
         Entity,
         &crate::nations::Nation,
+        &NationId,
         &mut Governance,
         &PoliticalPressure,
     )>,
     mut messages: MessageWriter<GovernmentTransition>,
+    game_time: Res<GameTime>,
+    world_seed: Option<Res<WorldSeed>>,
 ) {
     if !settings.allow_revolutions {
         return;
     }
+    let seed = world_seed.map_or(0, |seed| seed.0);
 
-    for (entity, nation, mut governance, pressure) in &mut nations {
+    for (entity, nation, nation_id, mut governance, pressure) in &mut nations {
         // Calculate total pressure
         let total_pressure = calculate_total_pressure(pressure, &governance);
 
@@ -56,18 +76,21 @@ pub fn check_for_transitions(
 
         // Check if pressure exceeds threshold
         if total_pressure > settings.revolution_threshold {
+            let mut rng = decision_rng(seed, DecisionDomain::Governance, nation_id.value(), 6, game_time.current_day());
+
             // Determine transition type
-            let transition_type = determine_transition_type(&governance, pressure);
+            let transition_type = determine_transition_type(&governance, pressure, &mut rng);
 
             // Determine new government
             let new_government = determine_new_government(
                 governance.government_type,
                 transition_type,
                 pressure,
+                &mut rng,
             );
 
             // Check if transition is peaceful
-            let peaceful = rand::thread_rng().r#gen::<f32>() < settings.peaceful_transition_chance
+            let peaceful = rng.r#gen::<f32>() < settings.peaceful_transition_chance
                 || matches!(transition_type, TransitionType::Reform | TransitionType::Election);
 
             // Send transition event
@@ -95,9 +118,13 @@ fn calculate_total_pressure(pressure: &PoliticalPressure, governance: &Governanc
     let tech = pressure.technological_change * 0.1;     // Was 0.8 - tech changes are gradual
     let religious = pressure.religious_fervor * 0.15;   // Was 1.0 - religious pressure builds slowly
     let revolutionary = pressure.revolutionary_ideas * 0.25; // Was 1.5 - ideas alone don't topple
+    let instability = pressure.prolonged_instability * 0.3;  // Years of disorder exhaust patience
+    let wealth = pressure.wealth_concentration * 0.2;        // Oligarchs want the state for themselves
+    let enlightenment = pressure.enlightenment * 0.2;        // New ideas question old authority
 
     // Sum and apply resistance
-    let raw_pressure = economic + military + cultural + external + tech + religious + revolutionary;
+    let raw_pressure = economic + military + cultural + external + tech + religious + revolutionary
+        + instability + wealth + enlightenment;
 
     // Apply both tradition AND institutional strength as resistance
     // Strong institutions (1.0) make transitions very hard
@@ -110,12 +137,26 @@ fn calculate_total_pressure(pressure: &PoliticalPressure, governance: &Governanc
 fn determine_transition_type(
     governance: &Governance,
     pressure: &PoliticalPressure,
+    rng: &mut impl Rng,
 ) -> TransitionType {
-    let mut rng = rand::thread_rng();
-
     // Check dominant pressure source
+    let category = governance.government_type.category();
     if pressure.military_defeat > 0.6 {
         TransitionType::Coup
+    } else if category == GovernmentCategory::Democratic && pressure.prolonged_instability > 0.7 {
+        // Democracies that cannot keep order invite a strongman
+        TransitionType::Coup
+    } else if category == GovernmentCategory::Democratic && pressure.wealth_concentration > 0.6 {
+        TransitionType::EliteConspiracy
+    } else if matches!(category, GovernmentCategory::Monarchic | GovernmentCategory::Theocratic)
+        && pressure.enlightenment > 0.6
+    {
+        // Rigid traditions break; flexible ones bend
+        if governance.tradition_strength > 0.5 {
+            TransitionType::Revolution
+        } else {
+            TransitionType::Reform
+        }
     } else if pressure.revolutionary_ideas > 0.7 {
         TransitionType::Revolution
     } else if pressure.economic_crisis > 0.8 {
@@ -136,9 +177,9 @@ fn determine_new_government(
     current: GovernmentType,
     transition_type: TransitionType,
     pressure: &PoliticalPressure,
+    rng: &mut impl Rng,
 ) -> GovernmentType {
     use GovernmentType::*;
-    let mut rng = rand::thread_rng();
 
     match transition_type {
         TransitionType::Revolution => {
@@ -266,18 +307,19 @@ fn determine_new_government(
 /// Process government transitions that have been triggered
 pub fn process_government_transitions(
     mut messages: MessageReader<GovernmentTransition>,
-    mut nations: Query<(&mut crate::nations::Nation, &mut Governance, &mut super::history::GovernmentHistory, &mut PoliticalPressure)>,
-    mut name_generator: Local<Option<crate::name_generator::NameGenerator>>,
+    mut renamed_messages: MessageWriter<NationRenamed>,
+    mut audio: MessageWriter<crate::audio::AudioEvent>,
+    mut nations: Query<(
+        &mut crate::nations::Nation,
+        &mut Governance,
+        &mut super::history::GovernmentHistory,
+        &mut PoliticalPressure,
+        Option<&mut crate::nations::NationHistory>,
+    )>,
     time: Res<crate::simulation::GameTime>,
 ) {
-    // Initialize name generator if needed
-    if name_generator.is_none() {
-        *name_generator = Some(crate::name_generator::NameGenerator::new());
-    }
-    let name_gen = name_generator.as_mut().unwrap();
-
     for event in messages.read() {
-        if let Ok((mut nation, mut governance, mut history, mut pressure)) = nations.get_mut(event.nation_entity) {
+        if let Ok((mut nation, mut governance, mut history, mut pressure, nation_history)) = nations.get_mut(event.nation_entity) {
             // Record the change in history
             history.changes.push(super::history::GovernmentChange {
                 from: event.from_government,
@@ -293,42 +335,43 @@ pub fn process_government_transitions(
                 TransitionType::Coup => {
                     // Military coups cost military loyalty and treasury
                     nation.military_strength *= 0.5;  // Half the military is purged/divided
-                    nation.treasury *= 0.7;            // Bribes and restructuring costs
+                    nation.treasury *= Fixed32::from_ratio(7, 10); // Bribes and restructuring costs
                     governance.stability = 0.25;       // Very unstable after coup
                 }
                 TransitionType::Revolution => {
                     // Revolutions devastate everything
                     nation.military_strength *= 0.3;   // Army shattered
-                    nation.treasury *= 0.4;            // Economy in chaos
+                    nation.treasury *= Fixed32::from_ratio(4, 10); // Economy in chaos
                     governance.stability = 0.2;        // Near collapse
                 }
                 TransitionType::Collapse => {
                     // Total state failure
+                    audio.write(crate::audio::AudioEvent::new(crate::audio::AudioCue::NationCollapsed));
                     nation.military_strength *= 0.2;   // Military dissolved
-                    nation.treasury *= 0.2;            // Economic devastation
+                    nation.treasury *= Fixed32::from_ratio(2, 10); // Economic devastation
                     governance.stability = 0.1;        // Failed state
                 }
                 TransitionType::Reform | TransitionType::Election => {
                     // Peaceful transitions have minimal cost
-                    nation.treasury *= 0.9;            // Election/reform costs
+                    nation.treasury *= Fixed32::from_ratio(9, 10); // Election/reform costs
                     governance.stability = if event.peaceful { 0.6 } else { 0.4 };
                 }
                 TransitionType::PopularUprising => {
                     // Popular movements disrupt economy
                     nation.military_strength *= 0.6;   // Some military defects
-                    nation.treasury *= 0.5;            // General strikes, disruption
+                    nation.treasury *= Fixed32::from_ratio(5, 10); // General strikes, disruption
                     governance.stability = 0.3;
                 }
                 TransitionType::ForeignImposed => {
                     // Puppet government has foreign backing
                     nation.military_strength *= 0.8;   // Some resistance
-                    nation.treasury *= 0.9;            // Foreign aid offsets costs
+                    nation.treasury *= Fixed32::from_ratio(9, 10); // Foreign aid offsets costs
                     governance.stability = 0.4;
                 }
                 TransitionType::EliteConspiracy => {
                     // Palace coups preserve most structures
                     nation.military_strength *= 0.7;   // Some purges
-                    nation.treasury *= 0.8;            // Elite wealth preserved
+                    nation.treasury *= Fixed32::from_ratio(8, 10); // Elite wealth preserved
                     governance.stability = 0.35;
                 }
                 TransitionType::Succession => {
@@ -339,6 +382,11 @@ pub fn process_government_transitions(
 
             // Update governance
             governance.government_type = event.to_government;
+            // The new regime draws legitimacy from its own sources, starting over
+            governance.legitimacy_factors = LegitimacyFactors::for_government_type(event.to_government);
+            governance.legitimacy = if event.peaceful { 0.6 } else { 0.35 };
+            governance.legitimacy_trend = 0.0;
+            governance.days_in_power = 0;
             governance.last_transition = Some(time.current_day());
             governance.reform_pressure = 0.0;
 
@@ -408,17 +456,46 @@ pub fn process_government_transitions(
                 }
             }
 
-            // Generate new nation name based on new government
-            // Note: In actual integration, we'd need to get the culture from somewhere
-            let culture = crate::name_generator::Culture::Western; // Placeholder
-            let (new_nation_name, new_ruler_title) = super::naming::generate_governance_aware_name(
-                name_gen,
-                culture,
+            let year = time.current_year();
+            let mut nation_history = nation_history;
+            if let Some(nation_history) = nation_history.as_mut() {
+                match event.transition_type {
+                    TransitionType::Reform => nation_history.record_event(crate::nations::HistoricalEvent::ReformEnacted {
+                        year,
+                        reform_type: crate::nations::ReformType::Administrative,
+                    }),
+                    _ if !event.peaceful => nation_history.record_event(crate::nations::HistoricalEvent::RebellionFaced {
+                        year,
+                        suppressed: false,
+                    }),
+                    _ => {}
+                }
+            }
+
+            // Rename for the new government, keeping the nation's place name
+            let new_nation_name = super::naming::rename_for_government(
+                &nation.name,
+                nation.culture,
+                &event.from_government,
                 &event.to_government,
             );
-
-            // Update nation name
-            nation.name = new_nation_name;
+            if new_nation_name != nation.name {
+                let old_name = std::mem::replace(&mut nation.name, new_nation_name.clone());
+                nation.adjective = crate::nations::generate_adjective(&super::naming::extract_place_name(
+                    &new_nation_name,
+                    nation.culture,
+                    &event.to_government,
+                ));
+                if let Some(nation_history) = nation_history.as_mut() {
+                    nation_history.record_rename(old_name.clone(), new_nation_name.clone(), year);
+                }
+                renamed_messages.write(NationRenamed {
+                    nation_entity: event.nation_entity,
+                    old_name,
+                    new_name: new_nation_name,
+                    year,
+                });
+            }
 
             // Log the transition
             info!(
@@ -468,4 +545,47 @@ pub fn transition_government(
     } else {
         0.4
     };
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn governance(government_type: GovernmentType, tradition_strength: f32) -> Governance {
+        Governance {
+            government_type,
+            stability: 0.3,
+            reform_pressure: 0.0,
+            tradition_strength,
+            institution_strength: 0.5,
+            last_transition: None,
+            days_in_power: 0,
+            legitimacy: 0.5,
+            legitimacy_trend: 0.0,
+            legitimacy_factors: LegitimacyFactors::for_government_type(government_type),
+        }
+    }
+
+    #[test]
+    fn unstable_democracies_fall_to_coups() {
+        let pressure = PoliticalPressure {
+            prolonged_instability: 0.8,
+            ..Default::default()
+        };
+        let democracy = governance(GovernmentType::ParliamentaryDemocracy, 0.2);
+        let mut rng = decision_rng(0, DecisionDomain::Governance, 0, 6, 0);
+        assert_eq!(determine_transition_type(&democracy, &pressure, &mut rng), TransitionType::Coup);
+    }
+
+    #[test]
+    fn enlightenment_reforms_flexible_monarchies_and_topples_rigid_ones() {
+        let pressure = PoliticalPressure {
+            enlightenment: 0.8,
+            ..Default::default()
+        };
+        let flexible = governance(GovernmentType::AbsoluteMonarchy, 0.3);
+        let rigid = governance(GovernmentType::AbsoluteMonarchy, 0.9);
+        let mut rng = decision_rng(0, DecisionDomain::Governance, 0, 6, 0);
+        assert_eq!(determine_transition_type(&flexible, &pressure, &mut rng), TransitionType::Reform);
+        assert_eq!(determine_transition_type(&rigid, &pressure, &mut rng), TransitionType::Revolution);
+    }
+}
@@ -11,6 +11,7 @@ use super::legitimacy::LegitimacyFactors;
 
 /// Component that tracks a nation's governance
 #[derive(Component, Debug, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Component)]
 pub struct Governance {
     pub government_type: GovernmentType,
     pub stability: f32,                        // 0.0-1.0
@@ -22,4 +23,21 @@ pub struct Governance {
     pub legitimacy: f32,                       // Cached legitimacy value (0.0-1.0)
     pub legitimacy_trend: f32,                 // Rate of change (-1.0 to 1.0)
     pub legitimacy_factors: LegitimacyFactors, // Comprehensive legitimacy tracking
-}
\ No newline at end of file
+}
+impl Governance {
+    /// A freshly founded government, as nations start a new world
+    pub fn founded(government_type: GovernmentType) -> Self {
+        Self {
+            government_type,
+            stability: 0.75,
+            reform_pressure: 0.0,
+            tradition_strength: government_type.mechanics().reform_resistance,
+            institution_strength: 1.0,
+            last_transition: None,
+            days_in_power: 0,
+            legitimacy: 0.75,
+            legitimacy_trend: 0.0,
+            legitimacy_factors: LegitimacyFactors::for_government_type(government_type),
+        }
+    }
+}
@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 
 /// Tracks various crises that impact government legitimacy
 #[derive(Component, Debug, Clone, Default, Serialize, Deserialize, Reflect)]
+#[reflect(Component)]
 pub struct CrisisFactors {
     pub famine: bool,
     pub plague: bool,
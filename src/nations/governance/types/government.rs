@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 
 /// All government types in Living Worlds with distinct mechanics
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+#[reflect(Component)]
 pub enum GovernmentType {
     // ==================== ANARCHIST VARIANTS ====================
     /// CNT-FAI style - Trade unions run everything
@@ -48,6 +48,11 @@ impl LegitimacyFactors {
         total += self.public_approval_rating * weights.popularity;
         weight_sum += weights.popularity;
 
+        // Scandals pull legitimacy down, harder where clean government is expected
+        if let Some(scandal) = &self.corruption_scandal {
+            total -= scandal.severity * weights.corruption;
+        }
+
         // Normalize
         if weight_sum > 0.0 {
             (total / weight_sum).clamp(0.0, 1.0)
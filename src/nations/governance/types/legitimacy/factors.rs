@@ -10,6 +10,7 @@ use crate::nations::governance::{CrisisFactors, GovernmentCategory, GovernmentTy
 
 /// Comprehensive legitimacy tracking with positive and negative factors
 #[derive(Component, Debug, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Component)]
 pub struct LegitimacyFactors {
     /// Base legitimacy value
     pub base_legitimacy: f32,
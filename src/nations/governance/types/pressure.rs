@@ -8,6 +8,8 @@ use serde::{Deserialize, Serialize};
 
 /// Component tracking political pressure sources
 #[derive(Component, Debug, Clone, Default, Serialize, Deserialize, Reflect)]
+#[reflect(Component)]
+#[serde(default)]
 pub struct PoliticalPressure {
     pub economic_crisis: f32,
     pub military_defeat: f32,
@@ -16,4 +18,10 @@ pub struct PoliticalPressure {
     pub technological_change: f32,
     pub religious_fervor: f32,
     pub revolutionary_ideas: f32,
+    /// Years of low stability, building faster the longer they last
+    pub prolonged_instability: f32,
+    /// Wealth pooling in the hands of few under an unequal government
+    pub wealth_concentration: f32,
+    /// Enlightenment ideas eroding the case for hereditary or divine rule
+    pub enlightenment: f32,
 }
\ No newline at end of file
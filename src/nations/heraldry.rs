@@ -0,0 +1,448 @@
+//! Procedural heraldry - flags for nations and coats of arms for houses
+//!
+//! Arms are composed from a field division and a central charge. The field
+//! takes the nation's map color, the division favors its culture's traditions,
+//! and the charge reflects its government (crosses and crescents for
+//! theocracies, crowns for monarchies, stars for republics). Vassals, successor
+//! states, and ruling houses derive variations of their parent's arms, so
+//! related realms read as related at a glance.
+//!
+//! Arms are rasterized into small textures shown in the nation panel and next
+//! to map labels at close zoom.
+
+use bevy::asset::RenderAssetUsages;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use crate::name_generator::Culture;
+use crate::nations::{Governance, GovernmentCategory, House, Nation, NationId, NationLabel, VassalOf};
+use crate::relationships::RulesOver;
+
+/// Heraldry texture width in pixels (3:2 flag proportions)
+pub const HERALDRY_WIDTH: u32 = 48;
+
+/// Heraldry texture height in pixels
+pub const HERALDRY_HEIGHT: u32 = 32;
+
+/// Camera distance below which map labels show their nation's flag
+const LABEL_FLAG_MAX_ZOOM: f32 = 1500.0;
+
+/// Metals and tinctures used for secondary fields and charges
+const GOLD: Color = Color::srgb(0.93, 0.76, 0.2);
+const SILVER: Color = Color::srgb(0.92, 0.92, 0.9);
+const SABLE: Color = Color::srgb(0.1, 0.1, 0.12);
+const GULES: Color = Color::srgb(0.72, 0.1, 0.12);
+const AZURE: Color = Color::srgb(0.12, 0.3, 0.68);
+const VERT: Color = Color::srgb(0.1, 0.5, 0.25);
+
+/// How the field is divided between the primary and secondary colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum FieldDivision {
+    Plain,
+    PerPale,
+    PerFess,
+    Quarterly,
+    Bend,
+    Saltire,
+    NordicCross,
+    Chevron,
+    TricolorVertical,
+    TricolorHorizontal,
+    Bordure,
+}
+
+/// The central emblem
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum Charge {
+    /// Field only, no emblem
+    Empty,
+    Star,
+    Sun,
+    Crescent,
+    Cross,
+    Lozenge,
+    Tower,
+    Wave,
+    Mountain,
+    Crown,
+}
+
+/// A complete heraldic design
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct CoatOfArms {
+    pub field: Color,
+    pub secondary: Color,
+    pub charge_color: Color,
+    pub division: FieldDivision,
+    pub charge: Charge,
+}
+
+/// Heraldry of a nation or house, with its rendered texture
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct Heraldry {
+    pub arms: CoatOfArms,
+    pub texture: Handle<Image>,
+}
+
+/// The realm a successor state split from, whose arms it varies
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct HeraldicParent(pub Entity);
+
+/// Flag sprite attached to a primary nation label
+#[derive(Component)]
+pub struct NationLabelFlag;
+
+/// Perceived brightness, used to keep charges legible against the field
+fn luminance(color: Color) -> f32 {
+    let srgba = color.to_srgba();
+    0.299 * srgba.red + 0.587 * srgba.green + 0.114 * srgba.blue
+}
+
+/// A tincture that contrasts with `background`
+fn contrasting(background: Color, rng: &mut StdRng) -> Color {
+    if luminance(background) > 0.55 {
+        [SABLE, GULES, AZURE, VERT][rng.gen_range(0..4)]
+    } else {
+        [GOLD, SILVER][rng.gen_range(0..2)]
+    }
+}
+
+/// Field divisions customary for a culture
+fn culture_divisions(culture: Culture) -> &'static [FieldDivision] {
+    use FieldDivision::*;
+    match culture {
+        Culture::Western => &[PerPale, Quarterly, Bend, Chevron, Plain],
+        Culture::Eastern => &[Plain, Bordure, PerFess],
+        Culture::Northern => &[NordicCross, Saltire, PerFess],
+        Culture::Southern => &[TricolorVertical, TricolorHorizontal, PerPale],
+        Culture::Desert => &[PerFess, TricolorHorizontal, Plain],
+        Culture::Island => &[Bend, Chevron, TricolorHorizontal],
+        Culture::Ancient => &[Bordure, Quarterly, Plain],
+        Culture::Mystical => &[Saltire, Bordure, Chevron],
+    }
+}
+
+/// Charges suited to a government, with the culture's devotional symbol for theocracies
+fn government_charges(category: GovernmentCategory, culture: Culture) -> &'static [Charge] {
+    use Charge::*;
+    match category {
+        GovernmentCategory::Theocratic => match culture {
+            Culture::Desert => &[Crescent],
+            Culture::Eastern | Culture::Island => &[Sun],
+            _ => &[Cross],
+        },
+        GovernmentCategory::Monarchic => &[Crown, Tower, Lozenge],
+        GovernmentCategory::Democratic | GovernmentCategory::Socialist => &[Star],
+        GovernmentCategory::Tribal => &[Mountain, Sun],
+        GovernmentCategory::Corporate => &[Lozenge, Wave],
+        GovernmentCategory::Anarchist => &[Empty, Star],
+        GovernmentCategory::Autocratic | GovernmentCategory::Technocratic => &[Tower, Star, Empty],
+    }
+}
+
+impl CoatOfArms {
+    /// Compose fresh arms for a realm
+    pub fn generate(seed: u64, field: Color, culture: Culture, category: GovernmentCategory) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let divisions = culture_divisions(culture);
+        let charges = government_charges(category, culture);
+
+        let secondary = contrasting(field, &mut rng);
+        let charge_color = contrasting(field, &mut rng);
+        Self {
+            field,
+            secondary,
+            charge_color,
+            division: divisions[rng.gen_range(0..divisions.len())],
+            charge: charges[rng.gen_range(0..charges.len())],
+        }
+    }
+
+    /// A variation of these arms for a vassal, successor, or cadet house
+    ///
+    /// The charge is kept so the lineage stays recognizable; the child's own
+    /// color takes the field and the parent's field becomes the secondary.
+    pub fn derive(&self, seed: u64, field: Color) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let division = match self.division {
+            FieldDivision::Plain => FieldDivision::Bordure,
+            FieldDivision::PerPale => FieldDivision::PerFess,
+            FieldDivision::PerFess => FieldDivision::PerPale,
+            FieldDivision::TricolorVertical => FieldDivision::TricolorHorizontal,
+            FieldDivision::TricolorHorizontal => FieldDivision::TricolorVertical,
+            _ if rng.gen_bool(0.5) => FieldDivision::Quarterly,
+            other => other,
+        };
+        Self {
+            field,
+            secondary: self.field,
+            charge_color: if luminance(field) > 0.55 { SABLE } else { self.charge_color },
+            division,
+            charge: self.charge,
+        }
+    }
+
+    /// Whether a normalized point falls on the secondary color of the field
+    fn on_secondary(&self, u: f32, v: f32) -> bool {
+        use FieldDivision::*;
+        match self.division {
+            Plain => false,
+            PerPale => u > 0.5,
+            PerFess => v > 0.5,
+            Quarterly => (u > 0.5) != (v > 0.5),
+            Bend => (u - v).abs() < 0.15,
+            Saltire => (u - v).abs() < 0.1 || (u + v - 1.0).abs() < 0.1,
+            NordicCross => (u - 0.36).abs() < 0.08 || (v - 0.5).abs() < 0.12,
+            Chevron => {
+                let apex = 0.3 + (u - 0.5).abs() * 0.9;
+                v > apex && v < apex + 0.22
+            }
+            TricolorVertical => (u * 3.0) as u32 == 1,
+            TricolorHorizontal => (v * 3.0) as u32 == 1,
+            Bordure => u < 0.08 || u > 0.92 || v < 0.12 || v > 0.88,
+        }
+    }
+
+    /// Whether an offset from the center (in units of the charge radius) falls on the charge
+    fn on_charge(&self, dx: f32, dy: f32) -> bool {
+        use std::f32::consts::TAU;
+        let r = (dx * dx + dy * dy).sqrt();
+        match self.charge {
+            Charge::Empty => false,
+            Charge::Star => {
+                // Point up: rotate so a tip sits at -y
+                let angle = dy.atan2(dx) + TAU / 4.0;
+                let t = (angle * 5.0 / TAU).rem_euclid(1.0);
+                let tip = (t - 0.5).abs() * 2.0;
+                r < 0.4 + 0.6 * tip
+            }
+            Charge::Sun => r < 0.55 || (r < 1.0 && (dy.atan2(dx) * 12.0).cos() > 0.5),
+            Charge::Crescent => r < 0.8 && ((dx - 0.3).powi(2) + dy * dy).sqrt() > 0.65,
+            Charge::Cross => {
+                (dx.abs() < 0.16 && dy.abs() < 1.0) || ((dy + 0.3).abs() < 0.16 && dx.abs() < 0.65)
+            }
+            Charge::Lozenge => dx.abs() / 0.6 + dy.abs() < 1.0,
+            Charge::Tower => {
+                let body = dx.abs() < 0.45 && dy > -0.6 && dy < 1.0;
+                let merlon = dx.abs() < 0.45 && dy > -1.0 && dy <= -0.6 && ((dx + 0.45) / 0.3) as u32 % 2 == 0;
+                body || merlon
+            }
+            Charge::Wave => dx.abs() < 1.0 && (dy - 0.25 * (dx * TAU).sin()).abs() < 0.18,
+            Charge::Mountain => dy < 0.9 && dy > -1.0 + 1.9 * dx.abs(),
+            Charge::Crown => {
+                let band = dx.abs() < 0.7 && dy > 0.2 && dy < 0.5;
+                let point = [-0.55f32, 0.0, 0.55]
+                    .iter()
+                    .any(|&px| dy <= 0.2 && dy > -0.5 + 0.7 * ((dx - px).abs() / 0.18));
+                band || point
+            }
+        }
+    }
+
+    /// Rasterize the arms into RGBA8 pixels, row by row
+    pub fn rasterize(&self, width: u32, height: u32) -> Vec<u8> {
+        let to_bytes = |color: Color| {
+            let srgba = color.to_srgba();
+            [
+                (srgba.red * 255.0) as u8,
+                (srgba.green * 255.0) as u8,
+                (srgba.blue * 255.0) as u8,
+                255,
+            ]
+        };
+        let field = to_bytes(self.field);
+        let secondary = to_bytes(self.secondary);
+        let charge = to_bytes(self.charge_color);
+
+        // The charge sits centered, sized to the shorter side
+        let charge_radius = width.min(height) as f32 * 0.32;
+        let (cx, cy) = (width as f32 / 2.0, height as f32 / 2.0);
+
+        let mut pixels = Vec::with_capacity((width * height * 4) as usize);
+        for y in 0..height {
+            for x in 0..width {
+                let (px, py) = (x as f32 + 0.5, y as f32 + 0.5);
+                let rgba = if self.on_charge((px - cx) / charge_radius, (py - cy) / charge_radius) {
+                    charge
+                } else if self.on_secondary(px / width as f32, py / height as f32) {
+                    secondary
+                } else {
+                    field
+                };
+                pixels.extend_from_slice(&rgba);
+            }
+        }
+        pixels
+    }
+
+    /// Render the arms to a texture
+    pub fn to_image(&self) -> Image {
+        Image::new(
+            Extent3d {
+                width: HERALDRY_WIDTH,
+                height: HERALDRY_HEIGHT,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            self.rasterize(HERALDRY_WIDTH, HERALDRY_HEIGHT),
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        )
+    }
+}
+
+/// Stable per-nation seed for heraldry
+fn nation_seed(nation_id: &NationId) -> u64 {
+    0x4845_5241_4c44_0000 ^ nation_id.value() as u64
+}
+
+/// Stable per-house seed derived from the house name
+fn house_seed(house: &House) -> u64 {
+    house
+        .name
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+/// Give every nation without heraldry a flag, deriving from its overlord or parent realm
+pub fn assign_nation_heraldry(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    nations_query: Query<
+        (Entity, &Nation, &NationId, Option<&Governance>, Option<&VassalOf>, Option<&HeraldicParent>),
+        Without<Heraldry>,
+    >,
+    heraldry_query: Query<&Heraldry>,
+) {
+    for (entity, nation, nation_id, governance, vassal_of, heraldic_parent) in &nations_query {
+        let parent_arms = vassal_of
+            .map(|v| v.0)
+            .or(heraldic_parent.map(|p| p.0))
+            .and_then(|parent| heraldry_query.get(parent).ok());
+
+        let arms = match parent_arms {
+            Some(parent) => parent.arms.derive(nation_seed(nation_id), nation.color),
+            None => CoatOfArms::generate(
+                nation_seed(nation_id),
+                nation.color,
+                nation.culture,
+                governance.map_or(GovernmentCategory::Monarchic, |g| g.government_type.category()),
+            ),
+        };
+        let texture = images.add(arms.to_image());
+        commands.entity(entity).insert(Heraldry { arms, texture });
+    }
+}
+
+/// Give every ruling house arms derived from the nation it rules
+pub fn assign_house_heraldry(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    houses_query: Query<(Entity, &House, &RulesOver), Without<Heraldry>>,
+    heraldry_query: Query<&Heraldry, With<Nation>>,
+) {
+    for (entity, house, rules_over) in &houses_query {
+        // Wait until the realm has its own arms
+        let Ok(realm) = heraldry_query.get(rules_over.0) else {
+            continue;
+        };
+        let mut rng = StdRng::seed_from_u64(house_seed(house));
+        let field = contrasting(realm.arms.field, &mut rng);
+        let arms = realm.arms.derive(house_seed(house), field);
+        let texture = images.add(arms.to_image());
+        commands.entity(entity).insert(Heraldry { arms, texture });
+    }
+}
+
+/// Redraw a nation's flag when its map color changes (unification, etc.)
+pub fn refresh_recolored_heraldry(
+    mut images: ResMut<Assets<Image>>,
+    mut nations_query: Query<(&Nation, &NationId, &mut Heraldry), Changed<Nation>>,
+) {
+    for (nation, nation_id, mut heraldry) in &mut nations_query {
+        if heraldry.arms.field == nation.color {
+            continue;
+        }
+        let mut rng = StdRng::seed_from_u64(nation_seed(nation_id));
+        heraldry.arms.field = nation.color;
+        heraldry.arms.charge_color = contrasting(nation.color, &mut rng);
+        heraldry.texture = images.add(heraldry.arms.to_image());
+    }
+}
+
+/// Place a flag above each new primary nation label
+pub fn attach_label_flags(
+    mut commands: Commands,
+    labels_query: Query<(Entity, &NationLabel), Added<NationLabel>>,
+    heraldry_query: Query<&Heraldry>,
+) {
+    for (label_entity, label) in &labels_query {
+        if !label.is_primary {
+            continue;
+        }
+        let Ok(heraldry) = heraldry_query.get(label.nation_id) else {
+            continue;
+        };
+        commands.entity(label_entity).with_child((
+            Sprite {
+                image: heraldry.texture.clone(),
+                custom_size: Some(Vec2::new(HERALDRY_WIDTH as f32, HERALDRY_HEIGHT as f32)),
+                ..default()
+            },
+            Transform::from_translation(Vec3::new(0.0, label.base_font_size + HERALDRY_HEIGHT as f32, 0.5)),
+            Visibility::Hidden,
+            NationLabelFlag,
+        ));
+    }
+}
+
+/// Show label flags only when the camera is close enough to read them
+pub fn update_label_flag_visibility(
+    camera_query: Query<&Transform, (With<Camera>, Changed<Transform>)>,
+    mut flags_query: Query<&mut Visibility, With<NationLabelFlag>>,
+) {
+    let Ok(camera_transform) = camera_query.single() else {
+        return;
+    };
+
+    let visibility = if camera_transform.translation.z.abs() < LABEL_FLAG_MAX_ZOOM {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut flag_visibility in &mut flags_query {
+        flag_visibility.set_if_neq(visibility);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arms_are_deterministic_per_seed() {
+        let field = Color::srgb(0.2, 0.4, 0.7);
+        let a = CoatOfArms::generate(7, field, Culture::Northern, GovernmentCategory::Monarchic);
+        let b = CoatOfArms::generate(7, field, Culture::Northern, GovernmentCategory::Monarchic);
+
+        assert_eq!(a, b);
+        assert_eq!(
+            a.rasterize(HERALDRY_WIDTH, HERALDRY_HEIGHT).len(),
+            (HERALDRY_WIDTH * HERALDRY_HEIGHT * 4) as usize
+        );
+    }
+
+    #[test]
+    fn derived_arms_keep_the_parent_charge() {
+        let parent = CoatOfArms::generate(3, GULES, Culture::Desert, GovernmentCategory::Theocratic);
+        let child = parent.derive(11, AZURE);
+
+        assert_eq!(parent.charge, Charge::Crescent);
+        assert_eq!(child.charge, parent.charge);
+        assert_eq!(child.secondary, parent.field);
+        assert_eq!(child.field, AZURE);
+    }
+}
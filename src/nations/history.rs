@@ -6,12 +6,14 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
+use crate::math::Money;
 
 /// Maximum number of historical events to keep in memory per nation
 const MAX_HISTORICAL_EVENTS: usize = 100;
 
 /// Tracks the complete history of a nation
 #[derive(Component, Debug, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Component)]
 pub struct NationHistory {
     /// Current ruler information
     pub ruler: RulerInfo,
@@ -31,8 +33,8 @@ pub struct NationHistory {
     pub events: VecDeque<HistoricalEvent>,
 
     /// Economic history
-    pub peak_treasury: f32,
-    pub lowest_treasury: f32,
+    pub peak_treasury: Money,
+    pub lowest_treasury: Money,
     pub tax_changes: u32,
 
     /// Expansion history
@@ -47,6 +49,18 @@ pub struct NationHistory {
     /// Founding information
     pub founded_year: u32,
     pub founding_culture: String,
+
+    /// Names the nation has gone by, oldest first (kept in full, unlike the event log)
+    #[serde(default)]
+    pub former_names: Vec<FormerName>,
+}
+
+/// A name the nation used before a rename
+#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+pub struct FormerName {
+    pub name: String,
+    /// Year the name was abandoned
+    pub until_year: u32,
 }
 
 impl Default for NationHistory {
@@ -60,8 +74,8 @@ impl Default for NationHistory {
             total_defeats: 0,
             recent_battles: VecDeque::with_capacity(10),
             events: VecDeque::with_capacity(MAX_HISTORICAL_EVENTS),
-            peak_treasury: 1000.0,
-            lowest_treasury: 1000.0,
+            peak_treasury: Money::from_int(1000),
+            lowest_treasury: Money::from_int(1000),
             tax_changes: 0,
             provinces_gained: 0,
             provinces_lost: 0,
@@ -70,6 +84,7 @@ impl Default for NationHistory {
             rebellions_faced: 0,
             founded_year: 0,
             founding_culture: String::from("Unknown"),
+            former_names: Vec::new(),
         }
     }
 }
@@ -101,6 +116,19 @@ impl NationHistory {
         }
     }
 
+    /// Remember the nation's previous name and log the rename
+    pub fn record_rename(&mut self, old_name: String, new_name: String, year: u32) {
+        self.former_names.push(FormerName {
+            name: old_name.clone(),
+            until_year: year,
+        });
+        self.record_event(HistoricalEvent::NationRenamed {
+            year,
+            old_name,
+            new_name,
+        });
+    }
+
     /// Record a battle outcome
     pub fn record_battle(&mut self, outcome: BattleOutcome) {
         match outcome {
@@ -344,6 +372,21 @@ pub enum HistoricalEvent {
         year: u32,
         suppressed: bool,
     },
+    CongressAttended {
+        year: u32,
+        congress: String,
+        provinces_changed: i32,
+    },
+    NationUnified {
+        year: u32,
+        former_name: String,
+        nations_absorbed: u32,
+    },
+    NationRenamed {
+        year: u32,
+        old_name: String,
+        new_name: String,
+    },
 }
 
 /// Result of a war
@@ -16,6 +16,7 @@ pub use crate::relationships::{
 
 /// A full character with personality, relationships, and drama potential
 #[derive(Debug, Clone, Component, Serialize, Deserialize, Reflect)]
+#[reflect(Component)]
 pub struct Character {
     pub id: CharacterId,
     pub house_id: Entity, // The house this character belongs to
@@ -52,6 +53,7 @@ pub struct Character {
 
 /// Unique identifier for a character
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Component, Reflect)]
+#[reflect(Component)]
 pub struct CharacterId(pub u32);
 
 /// Role within a house/dynasty
@@ -229,7 +231,8 @@ pub enum Scandal {
 // They use Bevy's #[relationship] attribute for automatic bidirectional tracking.
 
 /// Component tracking family tree membership
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct FamilyMember {
     pub house: Entity,
     pub generation: u32,
@@ -249,6 +252,7 @@ pub enum FamilyBranch {
 impl Character {
     /// Generate a new character with randomized traits
     pub fn generate(
+        id: CharacterId,
         house_id: Entity,
         culture: Culture,
         role: CharacterRole,
@@ -297,7 +301,7 @@ impl Character {
         };
 
         Self {
-            id: CharacterId(rng.r#gen()),
+            id,
             house_id,
             name,
             title: None,
@@ -0,0 +1,133 @@
+//! Compaction of long-dead characters
+//!
+//! A character who dies stays in the world, marked [`Deceased`], so the
+//! family tree can still show them to the generations that knew them. Half
+//! a century on they are despawned, and what is worth remembering - name,
+//! title, house, role, and dates - is kept as a compact [`CharacterRecord`].
+
+use bevy::prelude::*;
+use std::collections::BTreeMap;
+
+use super::characters::{Character, CharacterRole};
+use super::events::DeathCause;
+use crate::name_generator::Culture;
+use crate::simulation::NewYearEvent;
+
+/// Years a dead character stays in the world before being compacted
+const LONG_DEAD_YEARS: u32 = 50;
+
+/// Marks a character who has died
+#[derive(Component, Debug, Clone)]
+pub struct Deceased {
+    pub year: u32,
+    pub cause: DeathCause,
+}
+
+/// What is remembered of a compacted character
+#[derive(Debug, Clone)]
+pub struct CharacterRecord {
+    pub name: String,
+    pub title: Option<String>,
+    pub house: Entity,
+    pub culture: Culture,
+    pub role: CharacterRole,
+    pub age_at_death: u32,
+    pub died: u32,
+}
+
+/// Records of every compacted character, by character id
+#[derive(Resource, Debug, Default)]
+pub struct DeceasedCharacters {
+    records: BTreeMap<u32, CharacterRecord>,
+}
+
+impl DeceasedCharacters {
+    pub fn record_count(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Rough heap and inline size of the records
+    pub fn approx_bytes(&self) -> usize {
+        self.records
+            .values()
+            .map(|record| {
+                std::mem::size_of::<(u32, CharacterRecord)>()
+                    + record.name.capacity()
+                    + record.title.as_ref().map_or(0, String::capacity)
+            })
+            .sum()
+    }
+}
+
+/// Whether a character who died in `died` has been dead long enough to compact
+fn is_long_dead(died: u32, year: u32) -> bool {
+    year.saturating_sub(died) >= LONG_DEAD_YEARS
+}
+
+/// Mark characters who died this frame
+pub fn mark_deceased(
+    mut commands: Commands,
+    mut death_events: MessageReader<super::events::CharacterDeathEvent>,
+    time: Res<crate::simulation::GameTime>,
+) {
+    for death in death_events.read() {
+        commands.entity(death.character).try_insert(Deceased {
+            year: time.current_year(),
+            cause: death.cause.clone(),
+        });
+    }
+}
+
+/// Once a year, despawn long-dead characters into compact records
+pub fn compact_dead_characters(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut deceased: ResMut<DeceasedCharacters>,
+    characters: Query<(Entity, &Character, &Deceased)>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+
+    let mut compacted = 0;
+    for (entity, character, death) in &characters {
+        if !is_long_dead(death.year, year) {
+            continue;
+        }
+        deceased.records.insert(
+            character.id.0,
+            CharacterRecord {
+                name: character.name.clone(),
+                title: character.title.clone(),
+                house: character.house_id,
+                culture: character.culture,
+                role: character.role.clone(),
+                age_at_death: character.age,
+                died: death.year,
+            },
+        );
+        commands.entity(entity).despawn();
+        compacted += 1;
+    }
+    if compacted > 0 {
+        debug!("Compacted {} long-dead characters into records", compacted);
+    }
+}
+
+/// A fresh world starts with no dead
+pub fn reset_deceased_characters(mut deceased: ResMut<DeceasedCharacters>) {
+    *deceased = DeceasedCharacters::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn characters_are_compacted_half_a_century_after_death() {
+        assert!(!is_long_dead(1200, 1200));
+        assert!(!is_long_dead(1200, 1249));
+        assert!(is_long_dead(1200, 1250));
+        assert!(!is_long_dead(1300, 1250));
+    }
+}
@@ -29,6 +29,7 @@ pub struct DramaEvent {
 
 /// Unique identifier for drama events
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Component, Reflect)]
+#[reflect(Component)]
 pub struct DramaEventId(pub u32);
 
 /// How important/shareable an event is
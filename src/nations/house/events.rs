@@ -3,20 +3,14 @@
 //! Events for character lifecycle and relationship changes.
 
 use bevy::prelude::*;
-use super::characters::{CharacterId, RelationshipType};
+use super::characters::RelationshipType;
 
 /// Registry of all characters in the game
+///
+/// Character IDs come from [`IdAllocator`](crate::ids::IdAllocator).
 #[derive(Resource, Default)]
 pub struct CharacterRegistry {
     pub characters: Vec<Entity>,
-    pub id_counter: u32,
-}
-
-impl CharacterRegistry {
-    pub fn next_id(&mut self) -> CharacterId {
-        self.id_counter += 1;
-        CharacterId(self.id_counter)
-    }
 }
 
 /// Event when a new character is born
@@ -46,6 +40,21 @@ pub enum DeathCause {
     Mystery,
 }
 
+impl DeathCause {
+    /// How the death reads on a family tree, e.g. "fell in battle"
+    pub fn describe(&self) -> String {
+        match self {
+            DeathCause::Natural => "died of old age".to_string(),
+            DeathCause::Battle => "fell in battle".to_string(),
+            DeathCause::Assassination => "assassinated".to_string(),
+            DeathCause::Accident(what) => format!("died in an accident ({})", what),
+            DeathCause::Disease => "died of disease".to_string(),
+            DeathCause::Heartbreak => "died of a broken heart".to_string(),
+            DeathCause::Mystery => "died in mysterious circumstances".to_string(),
+        }
+    }
+}
+
 /// Event when relationships change
 #[derive(Message)]
 pub struct RelationshipChangedEvent {
@@ -0,0 +1,202 @@
+//! Lineage and reigns - who descends from whom, and who ruled when
+//!
+//! Characters hold a single `HasRelationship` link, which is not enough to
+//! draw a family: [`Lineage`] keeps each character's parents and spouse
+//! alongside it. [`Reign`] dates a ruler's time on the throne; when a ruler
+//! dies the next in line takes the throne and the house's ruler follows.
+
+use bevy::prelude::*;
+use std::cmp::Reverse;
+use std::collections::HashSet;
+
+use super::characters::{Character, CharacterRole};
+use super::compaction::Deceased;
+use super::events::CharacterDeathEvent;
+use super::systems::spawn_house_family;
+use super::types::House;
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::ids::IdAllocator;
+use crate::name_generator::NameGenerator;
+use crate::nations::{Nation, NationId};
+use crate::relationships::RulesOver;
+use crate::simulation::GameTime;
+
+/// A character's parents and spouse
+#[derive(Component, Debug, Clone, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Lineage {
+    pub parents: Vec<Entity>,
+    pub spouse: Option<Entity>,
+}
+
+impl Lineage {
+    pub fn child_of(parents: impl IntoIterator<Item = Entity>) -> Self {
+        Self {
+            parents: parents.into_iter().collect(),
+            spouse: None,
+        }
+    }
+
+    pub fn married_to(spouse: Entity) -> Self {
+        Self {
+            parents: Vec::new(),
+            spouse: Some(spouse),
+        }
+    }
+}
+
+/// A ruler's time on the throne
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct Reign {
+    pub start_year: u32,
+    /// `None` while the ruler still reigns
+    pub end_year: Option<u32>,
+}
+
+/// Place in the line of succession, lowest first: heirs, then children,
+/// siblings, cousins, and recognised bastards; the designated order and then
+/// age break ties. Spouses, advisors, and courtiers cannot inherit.
+fn succession_rank(role: &CharacterRole, order: Option<u32>, age: u32) -> Option<(u8, u32, Reverse<u32>)> {
+    let tier = match role {
+        CharacterRole::Heir => 0,
+        CharacterRole::Child => 1,
+        CharacterRole::Sibling => 2,
+        CharacterRole::Cousin => 3,
+        CharacterRole::Bastard => 4,
+        _ => return None,
+    };
+    Some((tier, order.unwrap_or(u32::MAX), Reverse(age)))
+}
+
+/// Give each house a family once it rules a nation
+pub fn spawn_ruling_families(
+    mut commands: Commands,
+    houses: Query<(Entity, &House, &RulesOver), Added<RulesOver>>,
+    nations: Query<&Nation>,
+    mut ids: ResMut<IdAllocator>,
+    time: Res<GameTime>,
+) {
+    let mut name_gen = NameGenerator::new();
+    let mut spawned = 0;
+    for (entity, house, rules_over) in &houses {
+        let Ok(nation) = nations.get(rules_over.0) else {
+            continue;
+        };
+        spawned += spawn_house_family(
+            &mut commands,
+            entity,
+            &house.ruler,
+            nation.culture,
+            &mut name_gen,
+            &mut ids,
+            time.current_year(),
+        )
+        .len();
+    }
+    if spawned > 0 {
+        debug!("Spawned {} members of ruling families", spawned);
+    }
+}
+
+/// When a ruler dies the first in line takes the throne, and the next becomes heir
+pub fn succeed_dead_rulers(
+    mut commands: Commands,
+    mut deaths: MessageReader<CharacterDeathEvent>,
+    mut characters: Query<(Entity, &mut Character, Option<&mut Reign>), Without<Deceased>>,
+    mut houses: Query<(&mut House, Option<&RulesOver>)>,
+    nation_ids: Query<&NationId>,
+    time: Res<GameTime>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+) {
+    let dying: HashSet<Entity> = deaths.read().map(|death| death.character).collect();
+    let year = time.current_year();
+
+    for &dead in &dying {
+        let (house_entity, old_name, title) = match characters.get_mut(dead) {
+            Ok((_, character, reign)) if character.role == CharacterRole::Ruler => {
+                if let Some(mut reign) = reign {
+                    reign.end_year = Some(year);
+                }
+                (character.house_id, character.name.clone(), character.title.clone())
+            }
+            _ => continue,
+        };
+
+        let mut line: Vec<(Entity, (u8, u32, Reverse<u32>))> = characters
+            .iter()
+            .filter(|(entity, character, _)| character.house_id == house_entity && !dying.contains(entity))
+            .filter_map(|(entity, character, _)| {
+                succession_rank(&character.role, character.succession_order, character.age).map(|rank| (entity, rank))
+            })
+            .collect();
+        line.sort_by_key(|&(_, rank)| rank);
+        let mut line = line.into_iter().map(|(entity, _)| entity);
+
+        let Ok((mut house, rules_over)) = houses.get_mut(house_entity) else {
+            continue;
+        };
+        let nations: Vec<NationId> = rules_over
+            .and_then(|rules_over| nation_ids.get(rules_over.0).ok())
+            .copied()
+            .into_iter()
+            .collect();
+
+        let successor = line.next().and_then(|entity| {
+            let (_, mut character, _) = characters.get_mut(entity).ok()?;
+            character.role = CharacterRole::Ruler;
+            character.succession_order = None;
+            character.title = title.clone();
+            Some((entity, character.name.clone(), character.age))
+        });
+        let Some((successor, new_name, age)) = successor else {
+            chronicle.write(ChronicleEvent {
+                category: ChronicleCategory::Dynasty,
+                text: format!(
+                    "The main line of House {} ends with the death of {}",
+                    house.name, old_name
+                ),
+                nations,
+            });
+            continue;
+        };
+        commands.entity(successor).insert(Reign {
+            start_year: year,
+            end_year: None,
+        });
+        if let Some(next) = line.next() {
+            if let Ok((_, mut character, _)) = characters.get_mut(next) {
+                character.role = CharacterRole::Heir;
+            }
+        }
+
+        house.ruler.name = new_name.clone();
+        house.ruler.age = age;
+        house.ruler.years_ruling = 0;
+        chronicle.write(ChronicleEvent {
+            category: ChronicleCategory::Dynasty,
+            text: format!("{} succeeds {} at the head of House {}", new_name, old_name, house.name),
+            nations,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heirs_come_first_then_the_eldest_child() {
+        let heir = succession_rank(&CharacterRole::Heir, None, 17);
+        let elder_child = succession_rank(&CharacterRole::Child, None, 15);
+        let younger_child = succession_rank(&CharacterRole::Child, None, 9);
+        let sibling = succession_rank(&CharacterRole::Sibling, None, 40);
+
+        assert!(heir < elder_child);
+        assert!(elder_child < younger_child);
+        assert!(younger_child < sibling);
+        assert!(succession_rank(&CharacterRole::Child, Some(1), 3) < elder_child);
+        assert_eq!(succession_rank(&CharacterRole::Spouse, None, 30), None);
+        assert_eq!(succession_rank(&CharacterRole::Advisor, None, 50), None);
+    }
+}
@@ -13,9 +13,12 @@ mod types;
 
 // New drama engine modules
 mod characters;
+mod compaction;
 mod drama;
 mod events;
+mod lineage;
 mod plugin;
+mod portraits;
 mod systems;
 
 // Public re-exports - carefully controlled API surface
@@ -37,10 +40,20 @@ pub use characters::{
 
 pub use drama::{
     DramaEvent, DramaEventId, DramaEventType, EventImportance,
-    EventVisibility, EventConsequence
+    EventVisibility, EventConsequence, SuccessionCrisisCause
 };
 
+// Portrait exports
+pub use portraits::{Portrait, PortraitFeatures, PORTRAIT_SIZE};
+
 // Plugin exports
 pub use plugin::DramaEnginePlugin;
 
+// Long-dead characters compacted into records
+pub use compaction::{Deceased, DeceasedCharacters};
+pub use events::DeathCause;
+
+// Parents, spouses, and reigns for family trees
+pub use lineage::{Lineage, Reign};
+
 // Event and system exports
@@ -6,9 +6,12 @@
 use bevy::prelude::*;
 use bevy_plugin_builder::define_plugin;
 
+use super::compaction::{compact_dead_characters, mark_deceased, reset_deceased_characters, DeceasedCharacters};
 use super::drama::{generate_drama_events, GlobalRng};
 use super::events::{CharacterBornEvent, CharacterDeathEvent, CharacterRegistry, RelationshipChangedEvent};
-use super::systems::{age_characters, process_character_events, update_relationships};
+use super::lineage::{spawn_ruling_families, succeed_dead_rulers};
+use super::portraits::{update_character_portraits, update_ruler_portraits};
+use super::systems::{age_characters, age_house_rulers, process_character_events, update_relationships};
 use crate::simulation::GameTime;
 
 define_plugin!(DramaEnginePlugin {
@@ -16,6 +19,17 @@ define_plugin!(DramaEnginePlugin {
         GameTime,
         GlobalRng,
         CharacterRegistry,
+        DeceasedCharacters,
+    ],
+
+    reflect: [
+        super::characters::Character,
+        super::characters::CharacterId,
+        super::characters::FamilyMember,
+        super::lineage::Lineage,
+        super::lineage::Reign,
+        super::drama::DramaEventId,
+        super::portraits::Portrait
     ],
 
     messages: [
@@ -30,18 +44,23 @@ define_plugin!(DramaEnginePlugin {
         age_characters.run_if(in_state(crate::states::GameState::InGame)),
         update_relationships.run_if(in_state(crate::states::GameState::InGame)),
         process_character_events.run_if(in_state(crate::states::GameState::InGame)),
+        spawn_ruling_families.run_if(in_state(crate::states::GameState::InGame)),
+        // Heirs succeed dead rulers; the dead are marked, then compacted into records after half a century
+        (succeed_dead_rulers, mark_deceased, compact_dead_characters)
+            .chain()
+            .after(age_characters)
+            .run_if(in_state(crate::states::GameState::InGame)),
+        age_house_rulers.run_if(in_state(crate::states::GameState::InGame)),
+        // Portraits redraw after aging so they never lag a bracket behind
+        update_ruler_portraits
+            .after(age_house_rulers)
+            .run_if(in_state(crate::states::GameState::InGame)),
+        update_character_portraits
+            .after(age_characters)
+            .run_if(in_state(crate::states::GameState::InGame)),
     ],
 
-    custom_init: |app: &mut bevy::app::App| {
-        // Register relationship components and metadata
-        // NOTE: Bevy 0.17 auto-registers all #[derive(Reflect)] types!
-        // Manual registration no longer needed:
-        // app.register_type::<HasRelationship>()
-        // app.register_type::<RelatedTo>()
-        // app.register_type::<RelationshipMetadata>()
-        // app.register_type::<RelationshipType>()
-
-        // custom_init kept for potential future use
-        let _ = app;
+    on_enter: {
+        crate::states::GameState::LoadingWorld => [reset_deceased_characters]
     }
 });
\ No newline at end of file
@@ -0,0 +1,294 @@
+//! Procedural portraits for rulers and notable characters
+//!
+//! A portrait is a stack of simple layers (clothing, neck, face, hair, beard,
+//! eyes, brows, mouth, and a crown for reigning rulers) drawn into a small
+//! texture. The fixed features come from the character's seed and culture, so
+//! the same character always has the same face; age and mood are applied on
+//! top, graying hair and adding lines as the years pass.
+
+use bevy::asset::RenderAssetUsages;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use crate::name_generator::{Culture, Gender};
+use crate::relationships::RulesOver;
+use super::characters::{Character, CharacterRole};
+use super::types::House;
+use crate::nations::Nation;
+
+/// Portrait texture size in pixels (square)
+pub const PORTRAIT_SIZE: u32 = 64;
+
+/// Portraits are redrawn when the character crosses into a new age bracket
+const AGE_BRACKET_YEARS: u32 = 5;
+
+/// Features fixed at birth
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct PortraitFeatures {
+    pub skin: Color,
+    pub hair: Color,
+    pub eyes: Color,
+    pub clothing: Color,
+    pub background: Color,
+    /// Half-width of the face (as a fraction of the portrait)
+    pub face_width: f32,
+    pub long_hair: bool,
+    pub bearded: bool,
+    /// Whether the hairline recedes with age
+    pub balding: bool,
+}
+
+/// A character's portrait and the state it was drawn for
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct Portrait {
+    pub features: PortraitFeatures,
+    /// Name of the character drawn, so a new ruler gets a new face
+    pub subject: String,
+    /// Age bracket the texture was drawn for
+    pub age_bracket: u32,
+    pub texture: Handle<Image>,
+}
+
+/// Skin tones typical of a culture
+fn culture_skin_tones(culture: Culture) -> &'static [(f32, f32, f32)] {
+    match culture {
+        Culture::Western | Culture::Northern => &[(0.96, 0.84, 0.74), (0.92, 0.76, 0.64), (0.98, 0.88, 0.8)],
+        Culture::Eastern => &[(0.95, 0.83, 0.68), (0.9, 0.76, 0.6)],
+        Culture::Southern => &[(0.72, 0.52, 0.38), (0.55, 0.38, 0.26), (0.82, 0.64, 0.48)],
+        Culture::Desert => &[(0.82, 0.64, 0.46), (0.7, 0.52, 0.36)],
+        Culture::Island => &[(0.66, 0.46, 0.32), (0.78, 0.58, 0.42)],
+        Culture::Ancient | Culture::Mystical => &[(0.86, 0.72, 0.58), (0.62, 0.44, 0.3), (0.94, 0.84, 0.76)],
+    }
+}
+
+/// Hair colors typical of a culture
+fn culture_hair_colors(culture: Culture) -> &'static [(f32, f32, f32)] {
+    match culture {
+        Culture::Northern => &[(0.86, 0.74, 0.48), (0.72, 0.42, 0.2), (0.5, 0.36, 0.22)],
+        Culture::Western => &[(0.36, 0.24, 0.14), (0.6, 0.44, 0.26), (0.14, 0.1, 0.08), (0.72, 0.42, 0.2)],
+        Culture::Mystical => &[(0.9, 0.9, 0.94), (0.14, 0.1, 0.08), (0.5, 0.3, 0.6)],
+        _ => &[(0.1, 0.08, 0.07), (0.2, 0.14, 0.1)],
+    }
+}
+
+impl PortraitFeatures {
+    /// Roll features from a character seed
+    pub fn from_seed(seed: u64, culture: Culture, gender: Gender) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let pick = |palette: &[(f32, f32, f32)], rng: &mut StdRng| {
+            let (r, g, b) = palette[rng.gen_range(0..palette.len())];
+            Color::srgb(r, g, b)
+        };
+
+        let skin = pick(culture_skin_tones(culture), &mut rng);
+        let hair = pick(culture_hair_colors(culture), &mut rng);
+        let eyes = pick(&[(0.25, 0.16, 0.1), (0.2, 0.36, 0.55), (0.3, 0.42, 0.26)], &mut rng);
+        let clothing = Color::hsl(rng.gen_range(0.0..360.0), 0.45, 0.3);
+        let background = Color::hsl(rng.gen_range(0.0..360.0), 0.2, 0.18);
+
+        let (long_hair, bearded, balding) = match gender {
+            Gender::Female => (rng.gen_bool(0.85), false, false),
+            Gender::Male => (rng.gen_bool(0.2), rng.gen_bool(0.6), rng.gen_bool(0.4)),
+            Gender::Neutral => (rng.gen_bool(0.5), rng.gen_bool(0.3), rng.gen_bool(0.2)),
+        };
+
+        Self {
+            skin,
+            hair,
+            eyes,
+            clothing,
+            background,
+            face_width: rng.gen_range(0.19..0.25),
+            long_hair,
+            bearded,
+            balding,
+        }
+    }
+
+    /// Draw the portrait at an age, with mood from -1 (grim) to 1 (cheerful)
+    pub fn rasterize(&self, size: u32, age: u32, mood: f32, crowned: bool) -> Vec<u8> {
+        let aged = ((age as f32 - 35.0) / 40.0).clamp(0.0, 1.0);
+        let hair = self.hair.mix(&Color::srgb(0.82, 0.82, 0.82), aged);
+        let line_color = self.skin.mix(&Color::BLACK, 0.25);
+        let hairline = if self.balding { 0.2 + 0.1 * aged } else { 0.2 };
+
+        let (cx, face_cy, face_ry) = (0.5, 0.46, 0.27);
+        let in_ellipse = |u: f32, v: f32, cy: f32, rx: f32, ry: f32| {
+            ((u - cx) / rx).powi(2) + ((v - cy) / ry).powi(2) < 1.0
+        };
+
+        let mut pixels = Vec::with_capacity((size * size * 4) as usize);
+        for y in 0..size {
+            for x in 0..size {
+                let u = (x as f32 + 0.5) / size as f32;
+                let v = (y as f32 + 0.5) / size as f32;
+                let dx = (u - cx).abs();
+                let in_face = in_ellipse(u, v, face_cy, self.face_width, face_ry);
+                let in_hair = in_ellipse(u, v, face_cy - 0.05, self.face_width + 0.05, face_ry + 0.04);
+
+                let mut color = self.background;
+                if in_ellipse(u, v, 1.08, 0.46, 0.32) {
+                    color = self.clothing;
+                } else if dx < 0.08 && v > 0.6 && v < 0.84 {
+                    color = self.skin;
+                }
+                if self.long_hair && in_hair && v > face_cy && v < 0.72 {
+                    color = hair;
+                }
+                if in_face {
+                    color = self.skin;
+                }
+                // Crown of the head; the hairline recedes on balding characters
+                if in_hair && v < hairline + 0.02 && !(in_face && v > hairline) {
+                    color = hair;
+                }
+                if self.long_hair && in_hair && !in_face && v >= hairline {
+                    color = hair;
+                }
+                if self.bearded && in_face && v > 0.58 && !(dx < 0.05 && v < 0.63) {
+                    color = hair;
+                }
+
+                if in_face {
+                    // Eyes
+                    let eye_dx = (dx - 0.075).abs();
+                    if eye_dx < 0.022 && (v - 0.44).abs() < 0.018 {
+                        color = self.eyes;
+                    }
+                    // Brows slant down toward the nose when grim
+                    let brow_v = 0.405 - mood.min(0.0) * 0.03 * (1.0 - dx / 0.12);
+                    if (0.045..0.11).contains(&dx) && (v - brow_v).abs() < 0.009 {
+                        color = hair;
+                    }
+                    // Mouth curves up at the corners when cheerful
+                    let mouth_v = 0.6 - mood * 3.0 * dx * dx;
+                    if dx < 0.055 && (v - mouth_v).abs() < 0.008 {
+                        color = line_color;
+                    }
+                    // Forehead lines with age
+                    if aged > 0.4 && dx < 0.09 && ((v - 0.35).abs() < 0.004 || (v - 0.37).abs() < 0.004 * aged) {
+                        color = line_color;
+                    }
+                }
+
+                if crowned && dx < 0.17 && v > 0.1 && v < 0.17 {
+                    let tooth = (dx * 18.0).fract() < 0.5 || v > 0.14;
+                    if tooth {
+                        color = Color::srgb(0.93, 0.76, 0.2);
+                    }
+                }
+
+                let srgba = color.to_srgba();
+                pixels.extend_from_slice(&[
+                    (srgba.red * 255.0) as u8,
+                    (srgba.green * 255.0) as u8,
+                    (srgba.blue * 255.0) as u8,
+                    255,
+                ]);
+            }
+        }
+        pixels
+    }
+
+    /// Render the portrait to a texture
+    pub fn to_image(&self, age: u32, mood: f32, crowned: bool) -> Image {
+        Image::new(
+            Extent3d {
+                width: PORTRAIT_SIZE,
+                height: PORTRAIT_SIZE,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            self.rasterize(PORTRAIT_SIZE, age, mood, crowned),
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        )
+    }
+}
+
+/// Stable seed from a name, for rulers who aren't full characters
+fn name_seed(name: &str) -> u64 {
+    name.bytes()
+        .fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+/// Draw (or redraw) each house's ruler when a new ruler takes over or ages into a new bracket
+pub fn update_ruler_portraits(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    houses_query: Query<(Entity, &House, Option<&RulesOver>, Option<&Portrait>), Changed<House>>,
+    nations_query: Query<&Nation>,
+) {
+    for (entity, house, rules_over, portrait) in &houses_query {
+        let ruler = &house.ruler;
+        let age_bracket = ruler.age / AGE_BRACKET_YEARS;
+        if portrait.is_some_and(|p| p.subject == ruler.name && p.age_bracket == age_bracket) {
+            continue;
+        }
+
+        let features = match portrait {
+            Some(portrait) if portrait.subject == ruler.name => portrait.features.clone(),
+            _ => {
+                let culture = rules_over
+                    .and_then(|rules| nations_query.get(rules.0).ok())
+                    .map_or(Culture::Western, |nation| nation.culture);
+                // Rulers are generated as men by nation creation
+                PortraitFeatures::from_seed(name_seed(&ruler.name), culture, Gender::Male)
+            }
+        };
+        // Temperament runs from calm to volatile; competence shows as confidence
+        let mood = (ruler.personality.competence - 0.5 - ruler.personality.temperament * 0.5).clamp(-1.0, 1.0);
+        let texture = images.add(features.to_image(ruler.age, mood, true));
+        commands.entity(entity).insert(Portrait {
+            features,
+            subject: ruler.name.clone(),
+            age_bracket,
+            texture,
+        });
+    }
+}
+
+/// Draw (or redraw) notable characters as they age
+pub fn update_character_portraits(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    characters_query: Query<(Entity, &Character, Option<&Portrait>), Changed<Character>>,
+) {
+    for (entity, character, portrait) in &characters_query {
+        let age_bracket = character.age / AGE_BRACKET_YEARS;
+        if portrait.is_some_and(|p| p.age_bracket == age_bracket) {
+            continue;
+        }
+
+        let features = portrait.map_or_else(
+            || PortraitFeatures::from_seed(character.id.0 as u64, character.culture, character.gender),
+            |portrait| portrait.features.clone(),
+        );
+        let mood = (character.happiness - character.stress).clamp(-1.0, 1.0);
+        let crowned = character.role == CharacterRole::Ruler;
+        let texture = images.add(features.to_image(character.age, mood, crowned));
+        commands.entity(entity).insert(Portrait {
+            features,
+            subject: character.name.clone(),
+            age_bracket,
+            texture,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn portraits_are_deterministic_and_age() {
+        let features = PortraitFeatures::from_seed(42, Culture::Northern, Gender::Male);
+
+        assert_eq!(features, PortraitFeatures::from_seed(42, Culture::Northern, Gender::Male));
+        let young = features.rasterize(PORTRAIT_SIZE, 25, 0.0, false);
+        assert_eq!(young, features.rasterize(PORTRAIT_SIZE, 25, 0.0, false));
+        assert_ne!(young, features.rasterize(PORTRAIT_SIZE, 75, 0.0, false));
+    }
+}
@@ -5,15 +5,34 @@
 use bevy::prelude::*;
 use rand::Rng;
 use super::characters::{
-    Character, CharacterRole, CharacterRelationshipBundle, FamilyBranch, FamilyMember,
+    Character, CharacterId, CharacterRole, CharacterRelationshipBundle, FamilyBranch, FamilyMember,
     HasRelationship, RelationshipType,
 };
+use super::compaction::Deceased;
 use super::drama::{DramaEvent, EventConsequence};
 use super::events::{CharacterBornEvent, CharacterDeathEvent, DeathCause, RelationshipChangedEvent};
+use super::lineage::{Lineage, Reign};
+
+/// Age ruling houses' rulers once a year
+pub fn age_house_rulers(
+    mut year_events: MessageReader<crate::simulation::NewYearEvent>,
+    mut houses: Query<&mut super::types::House>,
+) {
+    let years = year_events.read().count() as u32;
+    if years == 0 {
+        return;
+    }
+
+    for mut house in &mut houses {
+        house.ruler.age += years;
+        house.ruler.years_ruling += years;
+        house.years_in_power += years;
+    }
+}
 
 /// System to age characters over time
 pub fn age_characters(
-    mut characters: Query<(Entity, &mut Character)>,
+    mut characters: Query<(Entity, &mut Character), Without<Deceased>>,
     time: Res<crate::simulation::GameTime>,
     mut death_events: MessageWriter<CharacterDeathEvent>,
 ) {
@@ -136,23 +155,37 @@ pub fn process_character_events(
 }
 
 /// Helper function to spawn a character family for a house
+///
+/// The ruling character is the house's [`Ruler`](super::types::Ruler), so the
+/// family tree, portraits, and nation panel agree on who reigns.
 pub fn spawn_house_family(
     commands: &mut Commands,
     house_entity: Entity,
+    house_ruler: &super::types::Ruler,
     culture: crate::name_generator::Culture,
     name_gen: &mut crate::name_generator::NameGenerator,
+    ids: &mut crate::ids::IdAllocator,
+    year: u32,
 ) -> Vec<Entity> {
     let mut rng = rand::thread_rng();
     let mut family_entities = Vec::new();
+    // Four billion characters exhaust the space; past that, IDs saturate
+    let mut next_id = || ids.allocate().unwrap_or(CharacterId(u32::MAX));
 
     // Create ruler
-    let ruler = Character::generate(
+    let mut ruler = Character::generate(
+        next_id(),
         house_entity,
         culture,
         CharacterRole::Ruler,
         name_gen,
         &mut rng,
     );
+    ruler.name = house_ruler.name.clone();
+    ruler.title = Some(house_ruler.title.clone());
+    ruler.age = house_ruler.age;
+    // Rulers are generated as men by nation creation
+    ruler.gender = crate::name_generator::Gender::Male;
 
     let ruler_entity = commands.spawn((
         ruler.clone(),
@@ -161,12 +194,18 @@ pub fn spawn_house_family(
             generation: 0,
             branch: FamilyBranch::MainLine,
         },
+        Reign {
+            start_year: year.saturating_sub(house_ruler.years_ruling),
+            end_year: None,
+        },
     )).id();
     family_entities.push(ruler_entity);
+    let mut spouse_entity = None;
 
     // Create spouse (50% chance)
     if rng.gen_bool(0.5) {
         let spouse = Character::generate(
+            next_id(),
             house_entity,
             culture,
             CharacterRole::Spouse,
@@ -174,20 +213,23 @@ pub fn spawn_house_family(
             &mut rng,
         );
 
-        let spouse_entity = commands.spawn((
+        let spouse = commands.spawn((
             spouse,
             FamilyMember {
                 house: house_entity,
                 generation: 0,
                 branch: FamilyBranch::MarriedIn,
             },
+            Lineage::married_to(ruler_entity),
         )).id();
-        family_entities.push(spouse_entity);
+        family_entities.push(spouse);
+        spouse_entity = Some(spouse);
 
         // Create marriage relationship using the bundle (automatic bidirectional tracking)
-        commands.entity(ruler_entity).insert(
-            CharacterRelationshipBundle::spouse(spouse_entity, rng.gen_range(0.3..1.0))
-        );
+        commands.entity(ruler_entity).insert((
+            CharacterRelationshipBundle::spouse(spouse, rng.gen_range(0.3..1.0)),
+            Lineage::married_to(spouse),
+        ));
     }
 
     // Create 1-4 children
@@ -200,6 +242,7 @@ pub fn spawn_house_family(
         };
 
         let child = Character::generate(
+            next_id(),
             house_entity,
             culture,
             role,
@@ -214,6 +257,7 @@ pub fn spawn_house_family(
                 generation: 1,
                 branch: FamilyBranch::MainLine,
             },
+            Lineage::child_of(std::iter::once(ruler_entity).chain(spouse_entity)),
         )).id();
         family_entities.push(child_entity);
 
@@ -226,6 +270,7 @@ pub fn spawn_house_family(
     // Maybe add an advisor (30% chance)
     if rng.gen_bool(0.3) {
         let advisor = Character::generate(
+            next_id(),
             house_entity,
             culture,
             CharacterRole::Advisor,
@@ -247,6 +292,7 @@ pub fn spawn_house_family(
     // Maybe add a bastard for drama (10% chance)
     if rng.gen_bool(0.1) {
         let bastard = Character::generate(
+            next_id(),
             house_entity,
             culture,
             CharacterRole::Bastard,
@@ -261,6 +307,7 @@ pub fn spawn_house_family(
                 generation: 1,
                 branch: FamilyBranch::BastardLine,
             },
+            Lineage::child_of([ruler_entity]),
         )).id();
         family_entities.push(bastard_entity);
     }
@@ -0,0 +1,60 @@
+//! Central NationId ↔ Entity index
+//!
+//! Saves, generation, and history refer to nations by their stable [`NationId`];
+//! the ECS refers to them by `Entity`. Rather than every caller building its
+//! own lookup table or scanning all nations, the `NationId` component hooks keep
+//! this resource current as nations are spawned and despawned.
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use super::types::NationId;
+
+/// Two-way lookup between stable nation ids and live nation entities
+#[derive(Resource, Debug, Default)]
+pub struct NationIndex {
+    by_id: HashMap<NationId, Entity>,
+    by_entity: HashMap<Entity, NationId>,
+}
+
+impl NationIndex {
+    /// Live entity for a nation id
+    pub fn entity(&self, id: NationId) -> Option<Entity> {
+        self.by_id.get(&id).copied()
+    }
+
+    /// Stable id of a nation entity
+    pub fn id(&self, entity: Entity) -> Option<NationId> {
+        self.by_entity.get(&entity).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.by_id.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_id.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (NationId, Entity)> + '_ {
+        self.by_id.iter().map(|(&id, &entity)| (id, entity))
+    }
+
+    pub(super) fn insert(&mut self, id: NationId, entity: Entity) {
+        if let Some(previous) = self.by_id.insert(id, entity) {
+            if previous != entity {
+                warn!("NationId {} moved from {:?} to {:?}", id, previous, entity);
+                self.by_entity.remove(&previous);
+            }
+        }
+        self.by_entity.insert(entity, id);
+    }
+
+    pub(super) fn remove(&mut self, entity: Entity) {
+        if let Some(id) = self.by_entity.remove(&entity) {
+            if self.by_id.get(&id) == Some(&entity) {
+                self.by_id.remove(&id);
+            }
+        }
+    }
+}
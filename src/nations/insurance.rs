@@ -0,0 +1,388 @@
+//! Merchant insurance - underwriters who share the risks of the trade routes
+//!
+//! Once a trading nation's merchants run enough routes, its banks and guilds
+//! begin to underwrite them: an insurance house takes premiums on every
+//! cargo its merchants send along a trade agreement's route, caravan or
+//! ship, and pays out when one is lost to war or pirates.
+//!
+//! Underwriters price by risk. Each route carries a hazard - higher at sea,
+//! far higher while either end is at war, and higher again where pirate
+//! republics sail - and a memory of the losses actually suffered on it.
+//! Premiums follow the two. A route too dangerous to price is uninsurable,
+//! and merchants who can't insure stop sailing: trade on it collapses until
+//! the danger passes. A house whose reserves can't cover a bad year's claims
+//! fails, and its merchants trade uninsured until another is founded.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use rand::Rng;
+use std::collections::{BTreeMap, HashSet};
+
+use super::diplomacy::{TradeFlow, Treaty};
+use super::governance::{Governance, GovernmentType};
+use super::index::NationIndex;
+use super::relationships::{LandNeighbors, NavalNeighbors, ParticipatesInWar};
+use super::types::{Nation, NationId};
+use crate::ai::{decision_rng, DecisionDomain};
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::math::Money;
+use crate::relationships::{TradeLink, TradePartners};
+use crate::simulation::{InsuranceTuning, NewYearEvent, TuningChanged, TuningConfig};
+use crate::world::WorldSeed;
+
+/// Value of the cargo a nation's merchants carry, per unit of its side of the route's trade
+const CARGO_VALUE: f32 = 0.4;
+/// Trade agreement routes a nation's merchants need before an insurance house forms
+const MIN_ROUTES: usize = 2;
+/// Mercantilism at which merchants look for underwriters
+const INSURING_MERCANTILISM: f32 = 0.5;
+/// Yearly chance an insurance house forms where merchants want one
+const FOUNDING_CHANCE: f32 = 0.1;
+/// Capital an insurance house starts with
+const FOUNDING_RESERVES: f32 = 200.0;
+/// Share of reserves above this paid out to the house's owners each year
+const RESERVE_TARGET: f32 = 2000.0;
+const DIVIDEND_SHARE: f32 = 0.5;
+/// Yearly fall in merchants' confidence on a route they can't insure, and after an uninsured loss
+const UNINSURED_FLIGHT: f32 = 0.35;
+const UNINSURED_LOSS_FLIGHT: f32 = 0.15;
+/// Yearly recovery of confidence on an insured route
+const CONFIDENCE_RECOVERY: f32 = 0.1;
+/// Lowest confidence a route falls to before merchants give it up altogether
+const COLLAPSED_CONFIDENCE: f32 = 0.1;
+
+/// A nation's insurance house, run by its banks and guilds
+#[derive(Component, Debug, Clone, Reflect, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct InsuranceHouse {
+    pub founded_year: u32,
+    pub reserves: f32,
+    /// Premiums taken and claims paid last year
+    pub premiums: f32,
+    pub claims: f32,
+    /// Routes the house underwrote last year
+    pub routes_covered: u32,
+}
+
+/// Risk and insurance on one trade agreement's route, kept on the treaty entity
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct RouteInsurance {
+    pub by_sea: bool,
+    /// This year's chance of losing a cargo
+    pub hazard: f32,
+    /// Loss rate underwriters have seen on the route
+    pub loss_record: f32,
+    /// Premium as a share of cargo value; above the tuned uninsurable premium no one will write it
+    pub premium_rate: f32,
+    /// Whether each signatory's merchants are insured, in signatory order
+    pub insured: [bool; 2],
+    /// Share of the route's trade merchants still dare to run (0.0-1.0)
+    pub confidence: f32,
+}
+
+impl RouteInsurance {
+    fn new(by_sea: bool, tuning: &InsuranceTuning) -> Self {
+        let hazard = route_hazard(by_sea, false, false, tuning);
+        Self {
+            by_sea,
+            hazard,
+            loss_record: hazard,
+            premium_rate: premium_rate(hazard, hazard, tuning),
+            insured: [false; 2],
+            confidence: 1.0,
+        }
+    }
+
+    pub fn is_insurable(&self, tuning: &InsuranceTuning) -> bool {
+        self.premium_rate <= tuning.uninsurable_premium
+    }
+
+    /// Whether merchants have all but abandoned the route
+    pub fn has_collapsed(&self) -> bool {
+        self.confidence <= COLLAPSED_CONFIDENCE
+    }
+}
+
+/// Yearly chance of losing a cargo on a route
+pub fn route_hazard(by_sea: bool, at_war: bool, pirates: bool, tuning: &InsuranceTuning) -> f32 {
+    let mut hazard = if by_sea { tuning.maritime_hazard } else { tuning.caravan_hazard };
+    if at_war {
+        hazard += tuning.war_hazard;
+    }
+    if by_sea && pirates {
+        hazard += tuning.piracy_hazard;
+    }
+    hazard.min(1.0)
+}
+
+/// Premium underwriters ask, as a share of cargo value, from the route's hazard and loss record
+pub fn premium_rate(hazard: f32, loss_record: f32, tuning: &InsuranceTuning) -> f32 {
+    hazard.max(loss_record) * tuning.loss_share * (1.0 + tuning.premium_loading)
+}
+
+/// Whether merchants with this government answer to banks and guilds
+fn is_mercantile_government(government: GovernmentType) -> bool {
+    matches!(
+        government,
+        GovernmentType::MerchantRepublic
+            | GovernmentType::Bankocracy
+            | GovernmentType::GuildState
+            | GovernmentType::Plutocracy
+    )
+}
+
+/// Found insurance houses, price every route, take premiums, and pay claims
+pub fn underwrite_trade_routes(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    world_seed: Option<Res<WorldSeed>>,
+    tuning: Res<TuningConfig>,
+    nation_index: Res<NationIndex>,
+    mut treaties_query: Query<(Entity, &Treaty, Option<&TradeFlow>, Option<&mut RouteInsurance>)>,
+    mut nations_query: Query<(
+        Entity,
+        &mut Nation,
+        Option<&Governance>,
+        Option<&mut InsuranceHouse>,
+        Option<&TradePartners>,
+        Option<&LandNeighbors>,
+        Option<&NavalNeighbors>,
+    )>,
+    partners_query: Query<(&NationId, &TradePartners)>,
+    links_query: Query<&TradeLink>,
+    at_war_query: Query<(), With<ParticipatesInWar>>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+    let seed = world_seed.map_or(0, |seed| seed.0);
+    let tuning = &tuning.insurance;
+    let pirates: HashSet<Entity> = nations_query
+        .iter()
+        .filter(|(_, _, governance, ..)| {
+            governance.is_some_and(|governance| governance.government_type == GovernmentType::PirateRepublic)
+        })
+        .map(|(entity, ..)| entity)
+        .collect();
+    let ids = |entities: &[Entity]| -> Vec<_> { entities.iter().filter_map(|&e| nation_index.id(e)).collect() };
+
+    // Each pact's route once, from its lower-id end, in a stable order so
+    // identical runs roll identical losses
+    let mut routes: BTreeMap<(u32, u32), Entity> = BTreeMap::new();
+    for (nation_id, partners) in &partners_query {
+        for link in links_query.iter_many(partners.links()) {
+            let Some(partner_id) = nation_index.id(link.trader) else {
+                continue;
+            };
+            if nation_id.value() < partner_id.value() {
+                routes.insert((nation_id.value(), partner_id.value()), link.pact);
+            }
+        }
+    }
+
+    let mut ledgers: BTreeMap<Entity, (f32, f32, u32)> = BTreeMap::new();
+    let mut failed: HashSet<Entity> = HashSet::new();
+
+    for (&(id_a, id_b), &treaty_entity) in &routes {
+        let Ok((_, treaty, flow, insurance)) = treaties_query.get_mut(treaty_entity) else {
+            continue;
+        };
+        let signatories = treaty.signatories;
+        let [a, b] = signatories;
+        let by_sea = !nations_query
+            .get(a)
+            .ok()
+            .and_then(|(.., land, _)| land)
+            .is_some_and(|land| land.neighbors().contains(&b));
+        let near_pirates = signatories.iter().any(|&end| {
+            nations_query
+                .get(end)
+                .ok()
+                .and_then(|(.., naval)| naval)
+                .is_some_and(|naval| naval.neighbors().iter().any(|n| pirates.contains(n)))
+        });
+        let at_war = signatories.iter().any(|&end| at_war_query.contains(end));
+        let volume = flow.map_or(0.0, |flow| flow.volume);
+
+        let mut route = match insurance {
+            Some(insurance) => insurance.clone(),
+            None => RouteInsurance::new(by_sea, tuning),
+        };
+        route.by_sea = by_sea;
+        route.hazard = route_hazard(by_sea, at_war, near_pirates, tuning);
+        route.premium_rate = premium_rate(route.hazard, route.loss_record, tuning);
+        let insurable = route.is_insurable(tuning);
+
+        let mut rng = decision_rng(seed, DecisionDomain::Economy, id_a, id_b as u64, year);
+        let struck = rng.r#gen::<f32>() < route.hazard;
+        let mut uninsured_loss = false;
+
+        for (side, &merchant_nation) in signatories.iter().enumerate() {
+            let cargo = volume / 2.0 * CARGO_VALUE * route.confidence;
+            let Ok((_, mut nation, _, house, ..)) = nations_query.get_mut(merchant_nation) else {
+                continue;
+            };
+            let insured = insurable && house.is_some() && !failed.contains(&merchant_nation);
+            route.insured[side] = insured;
+            let loss = if struck { cargo * tuning.loss_share } else { 0.0 };
+
+            if !insured {
+                nation.treasury -= Money::from_f32(loss);
+                uninsured_loss |= loss > 0.0;
+                continue;
+            }
+            let premium = cargo * route.premium_rate;
+            nation.treasury -= Money::from_f32(premium);
+            let Some(mut house) = house else {
+                continue;
+            };
+            house.reserves += premium;
+            let ledger = ledgers.entry(merchant_nation).or_default();
+            ledger.0 += premium;
+            ledger.2 += 1;
+            if loss > 0.0 {
+                let paid = loss.min(house.reserves.max(0.0));
+                house.reserves -= paid;
+                nation.treasury += Money::from_f32(paid - loss);
+                ledger.1 += paid;
+                if paid < loss {
+                    failed.insert(merchant_nation);
+                }
+            }
+        }
+
+        // Underwriters learn from losses; merchants flee routes no one will cover
+        let lost = if struck { 1.0 } else { 0.0 };
+        route.loss_record += (lost - route.loss_record) * tuning.loss_memory;
+        let was_collapsed = route.has_collapsed();
+        if !insurable {
+            route.confidence -= UNINSURED_FLIGHT;
+        } else if uninsured_loss {
+            route.confidence -= UNINSURED_LOSS_FLIGHT;
+        } else if route.insured.iter().any(|&insured| insured) {
+            route.confidence += CONFIDENCE_RECOVERY;
+        }
+        route.confidence = route.confidence.clamp(COLLAPSED_CONFIDENCE, 1.0);
+
+        if route.has_collapsed() && !was_collapsed {
+            let names: Vec<String> = signatories
+                .iter()
+                .filter_map(|&end| nations_query.get(end).ok().map(|(_, nation, ..)| nation.name.clone()))
+                .collect();
+            chronicle.write(ChronicleEvent {
+                category: ChronicleCategory::Trade,
+                text: format!(
+                    "No underwriter will cover the {} between {}, and the trade collapses",
+                    if by_sea { "sea lanes" } else { "caravan roads" },
+                    names.join(" and ")
+                ),
+                nations: ids(&signatories),
+            });
+        }
+
+        match treaties_query.get_mut(treaty_entity) {
+            Ok((.., Some(mut existing))) => *existing = route,
+            _ => {
+                commands.entity(treaty_entity).insert(route);
+            }
+        }
+    }
+
+    for (entity, mut nation, governance, house, partners, ..) in &mut nations_query {
+        match house {
+            Some(mut house) => {
+                if failed.contains(&entity) {
+                    commands.entity(entity).remove::<InsuranceHouse>();
+                    chronicle.write(ChronicleEvent {
+                        category: ChronicleCategory::Trade,
+                        text: format!(
+                            "The underwriters of {} cannot meet their claims and the insurance house fails",
+                            nation.name
+                        ),
+                        nations: ids(&[entity]),
+                    });
+                    continue;
+                }
+                let (premiums, claims, covered) = ledgers.get(&entity).copied().unwrap_or_default();
+                house.premiums = premiums;
+                house.claims = claims;
+                house.routes_covered = covered;
+                // Profits beyond a prudent reserve go to the houses' owners, and through them the crown
+                if house.reserves > RESERVE_TARGET {
+                    let dividend = (house.reserves - RESERVE_TARGET) * DIVIDEND_SHARE;
+                    house.reserves -= dividend;
+                    nation.treasury += Money::from_f32(dividend);
+                }
+            }
+            None => {
+                let routes = partners.map_or(0, TradePartners::partner_count);
+                let mercantile = nation.personality.mercantilism >= INSURING_MERCANTILISM
+                    || governance.is_some_and(|governance| is_mercantile_government(governance.government_type));
+                let Some(id) = nation_index.id(entity) else {
+                    continue;
+                };
+                let mut rng = decision_rng(seed, DecisionDomain::Economy, id.value(), u64::MAX, year);
+                if routes < MIN_ROUTES || !mercantile || rng.r#gen::<f32>() >= FOUNDING_CHANCE {
+                    continue;
+                }
+                commands.entity(entity).insert(InsuranceHouse {
+                    founded_year: year,
+                    reserves: FOUNDING_RESERVES,
+                    premiums: 0.0,
+                    claims: 0.0,
+                    routes_covered: 0,
+                });
+                chronicle.write(ChronicleEvent {
+                    category: ChronicleCategory::Trade,
+                    text: format!(
+                        "The bankers and guilds of {} begin to underwrite their merchants' cargoes",
+                        nation.name
+                    ),
+                    nations: ids(&[entity]),
+                });
+            }
+        }
+    }
+}
+
+/// Reprice every route as soon as the insurance tuning is reloaded
+///
+/// Premiums are otherwise set at the new year; repricing straight away shows
+/// a balance change on the trade routes without waiting for one.
+pub fn reprice_routes_on_tuning_change(
+    mut changes: MessageReader<TuningChanged>,
+    tuning: Res<TuningConfig>,
+    mut routes_query: Query<&mut RouteInsurance>,
+) {
+    if !changes.read().any(|change| change.touches("insurance")) {
+        return;
+    }
+    for mut route in &mut routes_query {
+        route.premium_rate = premium_rate(route.hazard, route.loss_record, &tuning.insurance);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn war_and_pirates_price_routes_out_of_insurance() {
+        let tuning = InsuranceTuning::default();
+        let (sea, road) = (tuning.maritime_hazard, tuning.caravan_hazard);
+        let calm_sea = premium_rate(route_hazard(true, false, false, &tuning), sea, &tuning);
+        let pirate_sea = premium_rate(route_hazard(true, false, true, &tuning), sea, &tuning);
+        let war_road = premium_rate(route_hazard(false, true, false, &tuning), road, &tuning);
+        assert!(calm_sea < pirate_sea);
+        assert!(calm_sea <= tuning.uninsurable_premium);
+        assert!(war_road > tuning.uninsurable_premium);
+
+        // Pirates have no reach overland
+        assert_eq!(route_hazard(false, false, true, &tuning), road);
+
+        // A record of losses keeps premiums up after the danger passes
+        assert!(premium_rate(road, 0.5, &tuning) > premium_rate(road, road, &tuning));
+    }
+}
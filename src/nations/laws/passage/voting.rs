@@ -16,6 +16,7 @@ pub fn trigger_law_vote(
     nation: &Nation,
     governance: &Governance,
     registry: &LawRegistry,
+    rng: &mut impl Rng,
 ) -> LawVoteResult {
     let law = match registry.get_law(proposed_law.law_id) {
         Some(law) => law,
@@ -37,7 +38,6 @@ pub fn trigger_law_vote(
     }
 
     // Add some randomness
-    let mut rng = rand::thread_rng();
     final_support += rng.gen_range(-0.1..0.1);
 
     // Determine threshold based on government type and law complexity
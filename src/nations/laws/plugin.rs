@@ -30,6 +30,10 @@ define_plugin!(LawPlugin {
         LawHistory
     ],
 
+    reflect: [
+        super::registry::NationLaws
+    ],
+
     messages: [
         LawEnactmentEvent,
         LawRepealEvent
@@ -50,10 +54,6 @@ define_plugin!(LawPlugin {
     ],
 
     custom_init: |app: &mut bevy::app::App| {
-        // Register law components for reflection
-        // NOTE: NationLaws needs Reflect derive to be registered
-        // app.register_type::<NationLaws>();
-
         // Add debug-only validation systems
         #[cfg(debug_assertions)]
         {
@@ -14,6 +14,7 @@ mod tests {
         LawPrerequisite, NationLaws, LawRegistry,
         calculate_law_effects, apply_diminishing_returns,
     };
+    use crate::math::{Fixed32, Money};
     use crate::nations::{Nation, NationId, GovernmentCategory};
     use crate::test_utils::*;
 
@@ -106,11 +107,12 @@ mod tests {
                 let effects = calculate_law_effects(&[law.clone()]);
 
                 // Apply to nation
-                nation.treasury = (nation.treasury * (1.0 + effects.tax_efficiency_modifier)).max(0.0);
+                let efficiency = Fixed32::from_f32(1.0 + effects.tax_efficiency_modifier);
+                nation.treasury = (nation.treasury * efficiency).max(Money::ZERO);
                 nation.stability = (nation.stability + effects.stability_change).clamp(0.0, 1.0);
 
                 // Invariants that must hold
-                prop_assert!(nation.treasury >= 0.0, "Treasury went negative");
+                prop_assert!(nation.treasury >= Money::ZERO, "Treasury went negative");
                 prop_assert!(nation.stability >= 0.0, "Stability went negative");
                 prop_assert!(nation.stability <= 1.0, "Stability exceeded maximum");
             }
@@ -306,7 +308,7 @@ mod tests {
             name: "Test Nation".to_string(),
             adjective: "Test".to_string(),
             color: Color::srgb(0.5, 0.5, 0.5),
-            capital_province: 0,
+            capital_province: crate::world::ProvinceId::new(0),
             stability: 0.8,
             culture: crate::name_generator::Culture::Western,
             technology_level: 1,
@@ -11,7 +11,7 @@ use crate::simulation::PressureType;
 use super::types::{ProposedLaw, LawChange, LawChangeType};
 
 /// Active law with its original effects at enactment time
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
 pub struct ActiveLaw {
     /// The law identifier
     pub law_id: LawId,
@@ -23,7 +23,8 @@ pub struct ActiveLaw {
 }
 
 /// Tracks laws for an individual nation
-#[derive(Component, Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Component, Debug, Clone, Default, Serialize, Deserialize, Reflect)]
+#[reflect(Component)]
 pub struct NationLaws {
     /// Currently active laws (for quick lookup)
     pub active_laws: HashSet<LawId>,
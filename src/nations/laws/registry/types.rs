@@ -2,12 +2,13 @@
 //!
 //! Types used across registry modules for law proposals and changes.
 
+use bevy::reflect::Reflect;
 use serde::{Deserialize, Serialize};
 use crate::nations::laws::types::LawId;
 use crate::simulation::PressureType;
 
 /// A proposed law being debated
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
 pub struct ProposedLaw {
     pub law_id: LawId,
     pub initial_support: f32,
@@ -18,7 +19,7 @@ pub struct ProposedLaw {
 }
 
 /// Record of a law change
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
 pub struct LawChange {
     pub law_id: LawId,
     pub change_type: LawChangeType,
@@ -26,7 +27,7 @@ pub struct LawChange {
 }
 
 /// Type of law change
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Reflect)]
 pub enum LawChangeType {
     Enacted,
     Repealed,
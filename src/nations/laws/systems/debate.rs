@@ -7,17 +7,20 @@ use rand::random;
 
 use crate::nations::laws::registry::NationLaws;
 use crate::nations::laws::types::LawStatus;
-use crate::nations::Nation;
+use crate::nations::{Bureaucracy, Nation};
 use crate::simulation::GameTime;
 
 /// System to update ongoing law debates
 pub fn update_law_debates_system(
-    mut nations: Query<(&Nation, &mut NationLaws)>,
+    mut nations: Query<(&Nation, &mut NationLaws, Option<&Bureaucracy>)>,
     time: Res<GameTime>,
 ) {
     let delta_days = 1.0; // Assuming 1 day per update
 
-    for (nation, mut nation_laws) in &mut nations {
+    for (nation, mut nation_laws, bureaucracy) in &mut nations {
+        // Overextended bureaucracies take longer to carry reforms through
+        let delta_days = delta_days * bureaucracy.map_or(1.0, |bureaucracy| bureaucracy.implementation_speed);
+
         // Collect status updates to apply after retain_mut
         let mut status_updates = Vec::new();
 
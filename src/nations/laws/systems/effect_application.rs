@@ -3,6 +3,7 @@
 //! Applies the combined effects of enacted laws to nation attributes every frame.
 
 use bevy::prelude::*;
+use crate::math::{Fixed32, Money};
 use crate::nations::{Economy, Nation, Governance};
 use crate::nations::laws::{NationLaws, LawEffects};
 use crate::relationships::{EnactedLaws, LawEntity};
@@ -89,8 +90,8 @@ pub fn apply_law_effects_to_nations(
             // Maintenance cost modifier (negative is good)
             if effects.maintenance_cost_modifier.abs() > 0.001 {
                 econ.maintenance_cost = (econ.maintenance_cost
-                    * (1.0 + effects.maintenance_cost_modifier))
-                    .clamp(0.0, 10000.0);
+                    * Fixed32::from_f32(1.0 + effects.maintenance_cost_modifier))
+                    .clamp(Money::ZERO, Money::from_int(10_000));
             }
         }
 
@@ -106,10 +107,7 @@ pub fn apply_law_effects_to_nations(
             // Would track revolt risk if we had rebellion system
         }
 
-        // Apply corruption changes
-        if effects.corruption_change.abs() > 0.001 {
-            // Would track corruption if we had corruption system
-        }
+        // Corruption changes feed the yearly corruption target (see nations::corruption)
 
         // Apply technology rate modifier
         if effects.technology_rate_modifier.abs() > 0.001 {
@@ -7,18 +7,23 @@ use bevy::prelude::*;
 use crate::nations::laws::passage::{trigger_law_vote, LawVoteResult};
 use crate::nations::laws::registry::{LawRegistry, NationLaws};
 use crate::nations::laws::types::{LawEnactmentEvent, LawRepealEvent, LawStatus};
-use crate::nations::{Nation, Governance};
+use crate::ai::{decision_rng, DecisionDomain};
+use crate::nations::{Nation, NationId, Governance};
 use crate::simulation::GameTime;
+use crate::world::WorldSeed;
 
 /// System to process law votes when debate ends
 pub fn process_law_votes_system(
-    mut nations: Query<(Entity, &Nation, &Governance, &mut NationLaws)>,
+    mut nations: Query<(Entity, &Nation, Option<&NationId>, &Governance, &mut NationLaws)>,
     registry: Res<LawRegistry>,
     time: Res<GameTime>,
+    world_seed: Option<Res<WorldSeed>>,
     mut enactment_events: MessageWriter<LawEnactmentEvent>,
     repeal_events: MessageWriter<LawRepealEvent>,
 ) {
-    for (entity, nation, governance, mut nation_laws) in &mut nations {
+    let seed = world_seed.map_or(0, |seed| seed.0);
+    for (entity, nation, nation_id, governance, mut nation_laws) in &mut nations {
+        let actor = nation_id.map_or(entity.index(), |id| id.value());
         // Process completed debates
         let mut completed_proposals = Vec::new();
         for (i, proposed) in nation_laws.proposed_laws.iter().enumerate() {
@@ -30,7 +35,14 @@ pub fn process_law_votes_system(
         // Vote on completed proposals (in reverse to maintain indices)
         for &idx in completed_proposals.iter().rev() {
             let proposed = nation_laws.proposed_laws.remove(idx);
-            let vote_result = trigger_law_vote(&proposed, nation, governance, &registry);
+            let mut rng = decision_rng(
+                seed,
+                DecisionDomain::Governance,
+                actor,
+                proposed.law_id.0 as u64,
+                time.current_day(),
+            );
+            let vote_result = trigger_law_vote(&proposed, nation, governance, &registry, &mut rng);
 
             match vote_result {
                 LawVoteResult::Passed { final_support, margin } => {
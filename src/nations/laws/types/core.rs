@@ -12,7 +12,7 @@ use super::status::LawComplexity;
 use crate::nations::GovernmentCategory;
 
 /// Unique identifier for a law
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
 pub struct LawId(pub u16);
 
 impl LawId {
@@ -3,13 +3,14 @@
 //! Contains types related to the mechanical effects that laws
 //! have on nations when enacted.
 
+use bevy::reflect::Reflect;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use crate::simulation::PressureType;
 
 /// Mechanical effects that laws have on nations
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
 pub struct LawEffects {
     // Economic modifiers
     pub tax_efficiency_modifier: f32,
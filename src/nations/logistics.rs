@@ -0,0 +1,445 @@
+//! Logistics - strategic stockpiles and forward supply depots
+//!
+//! Nations keep a stockpile of food and ammunition, bought from the treasury
+//! and slowly spoiling. A nation planning a war lays in several years' worth
+//! before it marches and pushes depots up to the border it means to cross;
+//! the build-up is visible to anyone watching. At war, armies eat through
+//! the stockpile, and an army whose stores run out fights hungry.
+//!
+//! Depots extend supply beyond what the capital alone can reach. They sit in
+//! border provinces, where enemy raiders can burn them and advancing armies
+//! can capture them. How well all of this is planned depends on the
+//! bureaucracy's literacy and the ruler's skill as a commander.
+
+use bevy::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use super::bureaucracy::Bureaucracy;
+use super::history::NationHistory;
+use super::index::NationIndex;
+use super::relationships::{Attacking, ParticipatesInWar};
+use super::types::{Nation, NationId};
+use crate::ai::{decision_rng, DecisionDomain};
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::math::Money;
+use crate::relationships::StationedIn;
+use crate::simulation::NewYearEvent;
+use crate::world::{ProvinceEntityOrder, ProvinceStorage, WorldSeed};
+
+/// Stockpile kept in peacetime, in years of wartime consumption
+const PEACE_RESERVE_YEARS: f32 = 0.5;
+/// Stockpile laid in before a planned war, in years of wartime consumption
+const WAR_RESERVE_YEARS: f32 = 2.0;
+/// Share of the war reserve needed before the armies march
+const READY_SHARE: f32 = 0.8;
+/// Stores eaten per point of military strength per year of war
+const WAR_CONSUMPTION: f32 = 0.5;
+/// Share of its treasury a nation spends on stores in a year
+const PURCHASE_SHARE: f32 = 0.1;
+/// Share of the stockpile lost to spoilage each year under the worst planning
+const SPOILAGE: f32 = 0.15;
+/// Years a war plan is kept before it is abandoned
+const PLAN_PATIENCE_YEARS: u32 = 8;
+/// Most depots a perfectly planned army maintains
+const MAX_DEPOTS: usize = 5;
+/// Stores moved into a new depot
+const DEPOT_STOCK: f32 = 50.0;
+/// Yearly chance an exposed depot is raided, under the worst planning
+const RAID_CHANCE: f32 = 0.4;
+/// Share of a raided depot's stores lost
+const RAID_LOSS: f32 = 0.5;
+/// Battle strength left to an army with no stores at all
+const UNSUPPLIED_STRENGTH: f32 = 0.6;
+
+/// A forward store of food and ammunition
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct SupplyDepot {
+    pub owner: Entity,
+    pub stock: f32,
+}
+
+/// A nation's stockpile, depots, and war preparations
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct Logistics {
+    /// Food and ammunition held in the national stockpile
+    pub stockpile: f32,
+    /// Stockpile the nation is aiming for
+    pub target: f32,
+    /// Quality of supply planning (0.0-1.0)
+    pub planning: f32,
+    /// Share of last year's wartime needs that were met
+    pub supplied: f32,
+    pub depots: u32,
+    /// Nation a war is being prepared against
+    pub preparing_for: Option<Entity>,
+    /// Year preparations began
+    pub preparing_since: Option<u32>,
+}
+
+impl Logistics {
+    /// Start laying in stores for a war against a target
+    pub fn prepare_for(&mut self, target: Entity) {
+        if self.preparing_for != Some(target) {
+            self.preparing_for = Some(target);
+            self.preparing_since = None;
+        }
+    }
+
+    /// Whether the stores for a planned war against a target are in place
+    pub fn ready_for_war(&self, target: Entity, military_strength: f32) -> bool {
+        self.preparing_for == Some(target)
+            && self.stockpile >= military_strength * WAR_CONSUMPTION * WAR_RESERVE_YEARS * READY_SHARE
+    }
+
+    /// Battle strength multiplier from how well the army is supplied
+    pub fn supply_factor(&self) -> f32 {
+        UNSUPPLIED_STRENGTH + (1.0 - UNSUPPLIED_STRENGTH) * self.supplied.clamp(0.0, 1.0)
+    }
+
+    /// Stable-id form of the nation's logistics, for saving
+    pub fn to_saved(&self, id_of: impl Fn(Entity) -> Option<NationId>) -> SavedLogistics {
+        let preparing_for = self.preparing_for.and_then(id_of);
+        SavedLogistics {
+            stockpile: self.stockpile,
+            target: self.target,
+            planning: self.planning,
+            supplied: self.supplied,
+            depots: self.depots,
+            preparing_for,
+            preparing_since: self.preparing_since.filter(|_| preparing_for.is_some()),
+        }
+    }
+}
+
+/// A nation's logistics as written to a save, naming its war target by stable id
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SavedLogistics {
+    pub stockpile: f32,
+    pub target: f32,
+    pub planning: f32,
+    pub supplied: f32,
+    pub depots: u32,
+    pub preparing_for: Option<NationId>,
+    pub preparing_since: Option<u32>,
+}
+
+impl SavedLogistics {
+    /// Rebuild the nation's logistics; a war plan against a fallen nation is dropped
+    pub fn restore(&self, entity_of: impl Fn(NationId) -> Option<Entity>) -> Logistics {
+        let preparing_for = self.preparing_for.and_then(entity_of);
+        Logistics {
+            stockpile: self.stockpile,
+            target: self.target,
+            planning: self.planning,
+            supplied: self.supplied,
+            depots: self.depots,
+            preparing_for,
+            preparing_since: self.preparing_since.filter(|_| preparing_for.is_some()),
+        }
+    }
+}
+
+/// A supply depot as written to a save, by province index and owner stable id
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedSupplyDepot {
+    pub province: usize,
+    pub owner: NationId,
+    pub stock: f32,
+}
+
+impl SupplyDepot {
+    /// Stable-id form of a depot standing in a province, for saving
+    pub fn to_saved(&self, province: usize, id_of: impl Fn(Entity) -> Option<NationId>) -> Option<SavedSupplyDepot> {
+        Some(SavedSupplyDepot {
+            province,
+            owner: id_of(self.owner)?,
+            stock: self.stock,
+        })
+    }
+}
+
+impl SavedSupplyDepot {
+    /// Rebuild the depot; one whose owner is gone is lost with it
+    pub fn restore(&self, entity_of: impl Fn(NationId) -> Option<Entity>) -> Option<SupplyDepot> {
+        Some(SupplyDepot {
+            owner: entity_of(self.owner)?,
+            stock: self.stock,
+        })
+    }
+}
+
+/// Supply depots read from a save, waiting for the provinces they stand in to exist
+///
+/// Saving before then writes them back out as they were read.
+#[derive(Resource, Debug, Default)]
+pub struct RestoredSupplyDepots(pub Vec<SavedSupplyDepot>);
+
+/// Restock the depots a loaded save recorded once the world is in play
+pub fn spawn_restored_depots(
+    mut commands: Commands,
+    restored: Option<Res<RestoredSupplyDepots>>,
+    province_entity_order: Option<Res<ProvinceEntityOrder>>,
+    nation_index: Res<NationIndex>,
+) {
+    let (Some(restored), Some(entity_order)) = (restored, province_entity_order) else {
+        return;
+    };
+    commands.remove_resource::<RestoredSupplyDepots>();
+    for saved in &restored.0 {
+        let (Some(province_entity), Some(depot)) =
+            (entity_order.get(saved.province), saved.restore(|id| nation_index.entity(id)))
+        else {
+            continue;
+        };
+        commands.spawn((depot, StationedIn(province_entity)));
+    }
+}
+
+/// How well a nation plans its supply, from its clerks and its commander
+pub fn supply_planning(literacy: f32, commander_martial: f32) -> f32 {
+    (literacy.clamp(0.0, 1.0) * 0.5 + (commander_martial.clamp(-1.0, 1.0) + 1.0) * 0.25).clamp(0.0, 1.0)
+}
+
+/// Yearly stockpiling, war preparations, depot placement, raids, and captures
+pub fn manage_logistics(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    province_storage: Option<Res<ProvinceStorage>>,
+    entity_order: Option<Res<ProvinceEntityOrder>>,
+    world_seed: Option<Res<WorldSeed>>,
+    mut nations_query: Query<(
+        Entity,
+        &mut Nation,
+        &NationId,
+        Option<&Bureaucracy>,
+        Option<&NationHistory>,
+        Option<&ParticipatesInWar>,
+        Option<&mut Logistics>,
+    )>,
+    attackers_query: Query<(Entity, &Attacking)>,
+    mut depots_query: Query<(Entity, &mut SupplyDepot, &StationedIn)>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+    let (Some(storage), Some(entity_order)) = (province_storage, entity_order) else {
+        return;
+    };
+    let seed = world_seed.map_or(0, |seed| seed.0);
+    let index_by_entity: HashMap<Entity, usize> = entity_order
+        .entities
+        .iter()
+        .enumerate()
+        .map(|(index, &entity)| (entity, index))
+        .collect();
+    let owner_at = |index: usize| storage.provinces.get(index).and_then(|province| province.owner_entity);
+    let neighbors_of = |index: usize| {
+        storage
+            .provinces
+            .get(index)
+            .into_iter()
+            .flat_map(|province| province.neighbors.iter().flatten())
+            .map(|neighbor| neighbor.value() as usize)
+    };
+    let enemies: BTreeSet<(Entity, Entity)> = attackers_query
+        .iter()
+        .flat_map(|(attacker, attacking)| [(attacker, attacking.0), (attacking.0, attacker)])
+        .collect();
+    let names: HashMap<Entity, (String, NationId)> = nations_query
+        .iter()
+        .map(|(entity, nation, nation_id, ..)| (entity, (nation.name.clone(), *nation_id)))
+        .collect();
+    let planning: HashMap<Entity, f32> = nations_query
+        .iter()
+        .map(|(entity, _, _, bureaucracy, history, ..)| {
+            let literacy = bureaucracy.map_or(0.1, |bureaucracy| bureaucracy.literacy);
+            let martial = history.map_or(0.0, |history| history.ruler.personality.martial);
+            (entity, supply_planning(literacy, martial))
+        })
+        .collect();
+
+    // Depots change hands with their provinces, and exposed ones get raided
+    let mut depot_stock: HashMap<Entity, f32> = HashMap::new();
+    let mut depot_sites: HashMap<Entity, HashSet<usize>> = HashMap::new();
+    for (depot_entity, mut depot, stationed_in) in &mut depots_query {
+        let Some(&index) = index_by_entity.get(&stationed_in.0) else {
+            continue;
+        };
+        let Some(holder) = owner_at(index) else {
+            commands.entity(depot_entity).despawn();
+            continue;
+        };
+        if holder != depot.owner {
+            if let (true, Some((captor, captor_id)), Some((loser, loser_id))) = (
+                enemies.contains(&(holder, depot.owner)),
+                names.get(&holder),
+                names.get(&depot.owner),
+            ) {
+                chronicle.write(ChronicleEvent {
+                    category: ChronicleCategory::War,
+                    text: format!("{} captures a supply depot from {}", captor, loser),
+                    nations: vec![*captor_id, *loser_id],
+                });
+            }
+            depot.owner = holder;
+        }
+
+        let exposed = neighbors_of(index).any(|neighbor| {
+            owner_at(neighbor).is_some_and(|neighbor_owner| enemies.contains(&(depot.owner, neighbor_owner)))
+        });
+        let care = planning.get(&depot.owner).copied().unwrap_or(0.0);
+        let mut rng = decision_rng(seed, DecisionDomain::Military, index as u32, 1, year);
+        if exposed && rng.r#gen::<f32>() < RAID_CHANCE * (1.0 - care) {
+            depot.stock *= 1.0 - RAID_LOSS;
+            debug!("Raiders burn stores at a depot in province {}", index);
+        }
+
+        if depot.stock < 1.0 {
+            commands.entity(depot_entity).despawn();
+            continue;
+        }
+        *depot_stock.entry(depot.owner).or_default() += depot.stock;
+        depot_sites.entry(depot.owner).or_default().insert(index);
+    }
+
+    let mut owned: HashMap<Entity, Vec<usize>> = HashMap::new();
+    for (index, province) in storage.provinces.iter().enumerate() {
+        if let Some(owner) = province.owner_entity {
+            owned.entry(owner).or_default().push(index);
+        }
+    }
+
+    for (entity, mut nation, nation_id, _, _, at_war, logistics) in &mut nations_query {
+        let mut updated = logistics.as_deref().cloned().unwrap_or_default();
+        updated.planning = planning.get(&entity).copied().unwrap_or(0.0);
+        let yearly_need = nation.military_strength.max(0.0) * WAR_CONSUMPTION;
+
+        // Plans end when the war starts or when the nation loses patience
+        if at_war.is_some() {
+            updated.preparing_for = None;
+            updated.preparing_since = None;
+        } else if updated.preparing_for.is_some() {
+            let since = *updated.preparing_since.get_or_insert(year);
+            if year.saturating_sub(since) > PLAN_PATIENCE_YEARS {
+                updated.preparing_for = None;
+                updated.preparing_since = None;
+            }
+        }
+
+        let reserve_years = if updated.preparing_for.is_some() || at_war.is_some() {
+            WAR_RESERVE_YEARS
+        } else {
+            PEACE_RESERVE_YEARS
+        };
+        updated.target = yearly_need * reserve_years;
+        if updated.stockpile < updated.target && nation.treasury > Money::ZERO {
+            let purchase = (updated.target - updated.stockpile).min(nation.treasury.to_f32() * PURCHASE_SHARE);
+            nation.treasury -= Money::from_f32(purchase);
+            updated.stockpile += purchase;
+        }
+        updated.stockpile *= 1.0 - SPOILAGE * (1.0 - updated.planning);
+
+        // War eats the stockpile first, then the forward depots
+        updated.supplied = 1.0;
+        if at_war.is_some() && yearly_need > 0.0 {
+            let from_stockpile = updated.stockpile.min(yearly_need);
+            updated.stockpile -= from_stockpile;
+            let from_depots = depot_stock
+                .get(&entity)
+                .copied()
+                .unwrap_or(0.0)
+                .min(yearly_need - from_stockpile);
+            let mut owed = from_depots;
+            for (_, mut depot, _) in depots_query.iter_mut().filter(|(_, depot, _)| depot.owner == entity) {
+                let taken = depot.stock.min(owed);
+                depot.stock -= taken;
+                owed -= taken;
+            }
+            updated.supplied = (from_stockpile + from_depots) / yearly_need;
+            if updated.supplied < 0.5 {
+                debug!("{} armies are running short of supplies", nation.name);
+            }
+        }
+
+        // Depots go up along the border with the planned target, or the enemy
+        let front_with = updated.preparing_for.or_else(|| {
+            enemies
+                .iter()
+                .find(|(nation, _)| *nation == entity)
+                .map(|(_, enemy)| *enemy)
+        });
+        let sites = depot_sites.entry(entity).or_default();
+        let max_depots = (updated.planning * MAX_DEPOTS as f32).ceil() as usize;
+        if let Some(front_with) = front_with {
+            let front: Vec<usize> = owned
+                .get(&entity)
+                .map_or(&[][..], Vec::as_slice)
+                .iter()
+                .copied()
+                .filter(|&index| !sites.contains(&index))
+                .filter(|&index| neighbors_of(index).any(|neighbor| owner_at(neighbor) == Some(front_with)))
+                .collect();
+            for index in front {
+                if sites.len() >= max_depots || updated.stockpile < DEPOT_STOCK * 2.0 {
+                    break;
+                }
+                let Some(province_entity) = entity_order.get(index) else {
+                    continue;
+                };
+                updated.stockpile -= DEPOT_STOCK;
+                sites.insert(index);
+                commands.spawn((
+                    SupplyDepot {
+                        owner: entity,
+                        stock: DEPOT_STOCK,
+                    },
+                    StationedIn(province_entity),
+                ));
+            }
+            if updated.preparing_for.is_some()
+                && logistics.as_ref().is_some_and(|old| old.depots == 0)
+                && !sites.is_empty()
+            {
+                let target_name = names.get(&front_with).map_or("a neighbour", |(name, _)| name.as_str());
+                chronicle.write(ChronicleEvent {
+                    category: ChronicleCategory::War,
+                    text: format!("{} stockpiles supplies on its border with {}", nation.name, target_name),
+                    nations: vec![*nation_id],
+                });
+            }
+        }
+        updated.depots = sites.len() as u32;
+
+        match logistics {
+            Some(mut logistics) => *logistics = updated,
+            None => {
+                commands.entity(entity).insert(updated);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn armies_march_once_the_stores_are_laid_in() {
+        let target = Entity::from_raw_u32(7).unwrap_or(Entity::PLACEHOLDER);
+        let mut logistics = Logistics::default();
+        logistics.prepare_for(target);
+        assert!(!logistics.ready_for_war(target, 100.0));
+
+        logistics.stockpile = 100.0 * WAR_CONSUMPTION * WAR_RESERVE_YEARS;
+        assert!(logistics.ready_for_war(target, 100.0));
+
+        logistics.supplied = 0.0;
+        assert_eq!(logistics.supply_factor(), UNSUPPLIED_STRENGTH);
+        assert!(supply_planning(0.9, 0.8) > supply_planning(0.1, -0.5));
+    }
+}
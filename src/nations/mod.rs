@@ -5,20 +5,48 @@
 
 // PRIVATE MODULES - Gateway architecture compliance
 mod actions;
+mod bureaucracy;
+mod census;
+mod city_names;
+mod contact_policy;
+mod cores;
+mod corruption;
+mod devastation;
+mod disputes;
 mod diplomacy;
+mod disasters;
+mod economic_flows;
+mod economic_system;
 mod errors;
+mod fortifications;
 mod generation;
 mod governance;
+mod heraldry;
 mod history;
 mod house;
+mod index;
+mod insurance;
 mod laws;
+mod logistics;
 mod neighbors;
+mod new_world;
 mod ownership;
+mod ownership_service;
+mod pandemic;
+mod personality;
 mod plugin;
+mod presentation;
+mod refugees;
 pub mod relationships;  // Public for relationship component access
 mod rendering;
+mod resource_rush;
+mod saved_state;
+mod scripted_events;
+mod technology;
 mod territory_analysis;
+mod trade_league;
 mod types;
+mod unification;
 mod warfare;
 
 pub use actions::{
@@ -28,20 +56,50 @@ pub use actions::{
     // Event types
     NationActionEvent, TerritoryOwnershipChanged, OwnershipChangeType,
 };
-pub use generation::{spawn_nations, build_territories_from_provinces};
+pub use bureaucracy::Bureaucracy;
+pub use census::{Census, CensusDiscrepancy};
+pub use city_names::{initialize_city_names, CityAlias, CityName, CityNames, CITY_RENAME_YEARS};
+pub use contact_policy::{ContactPolicy, ContactStance};
+pub use cores::{
+    LostCores, ProvinceCore, ProvinceCores, RestoredProvinceCores, SavedProvinceCore, CORE_DECAY_YEARS,
+    CORE_FORMATION_YEARS,
+};
+pub use corruption::Corruption;
+pub use devastation::{Devastation, ProvinceDevastation};
+pub use disputes::{BorderDisputes, ClaimBasis, ProvinceClaim, ProvinceDispute};
+pub use disasters::{
+    ChainRule, Disaster, DisasterChains, DisasterKind, DisasterReach, DisasterSite, DisasterStruck, Disasters,
+    PendingDisaster,
+};
+pub use economic_flows::{EconomicFlow, EconomicFlows, FlowNode};
+pub use economic_system::{EconomicLedger, EconomicSystem, Production};
+// Metrics time the yearly economy as one simulation stage
+#[cfg(feature = "metrics")]
+pub use economic_system::allocate_national_output;
+pub use fortifications::{FortConstruction, FortNetwork, RestoredForts, SavedFort};
+pub use generation::{
+    spawn_nations, spawn_breakaway_nation, build_territories_from_provinces, generate_adjective, generate_nation_color,
+    CustomNationSpec, RegionPreference, DESIGNER_COLORS, DESIGNER_CULTURES, MAX_CUSTOM_NATIONS,
+};
 pub use governance::{
     Governance, GovernmentCategory, GovernmentType,
     GovernmentTransition, GovernmentHistory, LegitimacyFactors, PoliticalPressure, get_structure_name,
+    generate_governance_aware_name, rename_for_government, NationRenamed, PublicOpinion, TransitionType,
+    DevelopmentLevel,
+};
+pub use heraldry::{
+    Charge, CoatOfArms, FieldDivision, HeraldicParent, Heraldry, HERALDRY_HEIGHT, HERALDRY_WIDTH,
 };
 pub use history::{
-    BattleOutcome, HistoricalEvent, NationHistory, RulerTraits, SuccessionType,
-    WarResult, create_initial_history,
+    AcquisitionMethod, BattleOutcome, FormerName, HistoricalEvent, LossReason, NationHistory,
+    ReformType, RulerTraits, SuccessionType, WarResult, create_initial_history,
 };
 pub use house::{
-    House, HouseTraits, Ruler, RulerPersonality,
+    House, HouseTraits, Portrait, PortraitFeatures, Ruler, RulerPersonality, PORTRAIT_SIZE,
     // Drama engine exports
-    DramaEnginePlugin, Character, CharacterId, CharacterRole,
-    DramaEvent, DramaEventType, DramaEventId, EventImportance, EventVisibility,
+    DramaEnginePlugin, Character, CharacterId, CharacterRole, DeathCause, Deceased, DeceasedCharacters,
+    Lineage, Reign,
+    DramaEvent, DramaEventType, DramaEventId, EventImportance, EventVisibility, SuccessionCrisisCause,
     // Relationship system exports
     HasRelationship, RelationshipMetadata, RelationshipType,
 };
@@ -53,8 +111,9 @@ pub use neighbors::{
     get_neighbor_strengths, rebuild_neighbor_relationships_on_ownership_change,
 };
 pub use warfare::{
-    War, WarGoal, Battle, BattleConfig, BattleResult, WarOutcome, CasusBelli,
-    DeclareWarEvent, BattleEvent, WarEndEvent,
+    War, WarGoal, Battle, BattleConfig, BattleResult, WarOutcome, CasusBelli, ArmyComposition, ArmyTemplate, Doctrine, MilitaryDoctrine, CampaignAttrition,
+    ArmyOrder, FieldArmy,
+    DeclareWarEvent, BattleEvent, BattleResolvedEvent, WarEndEvent,
     process_war_declarations, process_battle_events, check_war_resolution,
     record_battle_outcome,
 };
@@ -62,13 +121,38 @@ pub use diplomacy::{
     CasusBelliExt, FabricatingClaim,
     evaluate_available_casus_belli,
     evaluate_war_triggers_from_pressure,
+    CongressConcludedEvent, CongressHistory, CongressRecord, rank_great_powers,
+    Captive, CaptiveRank, Captives,
+    SavedClause, SavedTreaty, SignTreatyEvent, Treaty, TreatyClause, TreatyCompliance, TreatyKind, TreatyViolatedEvent,
+    TradeFlow, TradeTerms, TARIFF_RATE,
 };
 pub use ownership::{
     // O(1) ECS-based ownership queries using Controls/ControlledBy relationships
     get_nation_provinces, get_nation_province_count, nation_has_territory,
     nation_owns_province, get_province_owner, get_nation_bounds, get_nation_centroid,
 };
+pub use index::NationIndex;
+pub use insurance::{InsuranceHouse, RouteInsurance};
+pub use logistics::{Logistics, RestoredSupplyDepots, SavedSupplyDepot, SupplyDepot};
+pub use ownership_service::{apply_province_transfers, OwnershipService, ProvinceTransferRequest};
+pub use refugees::{PopulationDisplaced, RefugeeFlow, Refugees, SavedRefugees};
+pub use resource_rush::{ResourceRush, ResourceRushes, RushMineral};
+pub use saved_state::{NationStateData, SavedNationState};
+pub use scripted_events::{
+    EventDefinition, EventModifier, EventOption, EventScope, PendingEvent, ScriptedEventFired, ScriptedEventState,
+    ScriptedEvents,
+};
+pub use new_world::{ColonialEmpire, NewWorlds};
+pub use pandemic::{Pandemic, Pandemics};
+pub use presentation::{presented_name, PresentationEra, PresentationEras};
+pub use personality::{EconomicFocus, PersonalityArchetype, EDITABLE_GOVERNMENTS};
+pub use technology::Research;
 pub use territory_analysis::TerritoryMetrics;
+pub use trade_league::{SavedTradeLeague, TradeLeague};
+pub use unification::{
+    is_nationalism_era, NationFormedEvent, UnificationMovement, UnifiedInto,
+    NATIONALISM_ERA_YEARS, NATIONALISM_TECH_LEVEL,
+};
 pub use rendering::{NationLabel, NationLabelShadow};
 pub use plugin::NationPlugin;
 pub use relationships::*;
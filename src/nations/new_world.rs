@@ -0,0 +1,502 @@
+//! New World - discovery and colonization across the oceans
+//!
+//! The map is charted into worlds: landmasses joined by short sea crossings
+//! that coastal ships can make. Until a nation builds ships that cross open
+//! ocean, the other worlds are out of reach, and a nation that has sealed its
+//! ports sends no ships however good they are. Once it has them, its sailors
+//! may reach a world nobody at home has seen, and contact has consequences:
+//!
+//! - **Disease**: the less populous world has been isolated from the other's
+//!   plagues, and old-world diseases kill half its people over a decade. The
+//!   dead leave labor scarce, as after any pandemic.
+//! - **Colonization**: seafaring nations with money to spare race to plant
+//!   colonies on unclaimed coasts; settlement spreads inland from there
+//!   through ordinary expansion.
+//! - **Colonial trade**: overseas provinces ship their goods home each year,
+//!   enriching mercantile metropoles most.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use rand::Rng;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use super::city_names::CityNames;
+use super::contact_policy::{ContactPolicy, ContactStance};
+use super::index::NationIndex;
+use super::pandemic::Pandemics;
+use super::types::Nation;
+use super::{OwnershipChangeType, OwnershipService};
+use crate::ai::{decision_rng, score_considerations, Consideration, DecisionDomain, ResponseCurve};
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::math::{Fixed32, Money};
+use crate::simulation::NewYearEvent;
+use crate::world::{Province, ProvinceEntityOrder, ProvinceStorage, TerrainType, WorldSeed};
+
+/// Technology level at which ships can cross open ocean
+const OCEAN_CROSSING_TECH: u32 = 4;
+/// Ocean hexes coastal ships cross, joining landmasses into one world
+const COASTAL_CROSSING: u32 = 3;
+/// Provinces a world needs to be worth an ocean voyage
+const MIN_WORLD_PROVINCES: usize = 20;
+/// Yearly chance a seafaring nation's sailors reach an unknown world
+const DISCOVERY_CHANCE: f32 = 0.08;
+/// Share of an isolated world's people killed by old-world diseases
+const CONTACT_MORTALITY: f32 = 0.5;
+/// Years the old-world diseases take to run their course
+const EPIDEMIC_YEARS: u32 = 10;
+/// Yearly stability lost by nations of a stricken world
+const EPIDEMIC_UNREST: f32 = 0.03;
+/// Treasury needed to outfit a colonial expedition, and what it costs
+const COLONY_COST: Money = Money::from_int(2000);
+/// Yearly chance an eager seafaring nation plants a colony
+const COLONY_CHANCE: f32 = 0.3;
+/// Yearly treasury from each overseas province
+const COLONIAL_TRADE: f32 = 25.0;
+
+/// A nation's holdings beyond the ocean
+#[derive(Component, Debug, Clone, Default, Reflect, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct ColonialEmpire {
+    /// Provinces held in worlds other than the home world
+    pub overseas: u32,
+    /// Treasury the colonies shipped home last year
+    pub trade: f32,
+}
+
+/// The worlds of the map and the contacts between them
+#[derive(Resource, Debug, Default)]
+pub struct NewWorlds {
+    /// World of each land province, by province index
+    worlds: Vec<Option<u32>>,
+    /// Land provinces in each world
+    sizes: Vec<usize>,
+    /// Pairs of worlds in contact, lower first
+    pub contacts: BTreeSet<(u32, u32)>,
+    /// Worlds suffering old-world diseases, and the year contact brought them
+    pub epidemics: BTreeMap<u32, u32>,
+    /// Nations that have planted colonies in each world
+    pub colonizers: BTreeMap<u32, BTreeSet<Entity>>,
+}
+
+impl NewWorlds {
+    /// World of a province, if it is land
+    pub fn world_of(&self, index: usize) -> Option<u32> {
+        self.worlds.get(index).copied().flatten()
+    }
+
+    /// Whether the peoples of two worlds know of each other
+    pub fn in_contact(&self, a: u32, b: u32) -> bool {
+        a == b || self.contacts.contains(&(a.min(b), a.max(b)))
+    }
+
+    /// Whether old-world diseases are still running through a world
+    pub fn is_epidemic_raging(&self, world: u32, year: u32) -> bool {
+        self.epidemics
+            .get(&world)
+            .is_some_and(|&since| year < since + EPIDEMIC_YEARS)
+    }
+
+    /// Number of worlds on the map
+    pub fn world_count(&self) -> usize {
+        self.sizes.len()
+    }
+}
+
+/// Chart the worlds of a map: landmasses joined by short sea crossings
+///
+/// Returns the world of each land province, numbered in order of each
+/// world's lowest province index; ocean provinces have none.
+pub fn chart_worlds(provinces: &[Province], crossing: u32) -> Vec<Option<u32>> {
+    let is_land = |index: usize| {
+        provinces
+            .get(index)
+            .is_some_and(|province| province.terrain != TerrainType::Ocean)
+    };
+    let neighbors = |index: usize| {
+        provinces[index]
+            .neighbors
+            .iter()
+            .flatten()
+            .map(|neighbor| neighbor.value() as usize)
+            .filter(|&neighbor| neighbor < provinces.len())
+    };
+
+    // Landmasses are connected land
+    let mut landmass: Vec<Option<usize>> = vec![None; provinces.len()];
+    let mut landmass_count = 0;
+    for start in 0..provinces.len() {
+        if !is_land(start) || landmass[start].is_some() {
+            continue;
+        }
+        landmass[start] = Some(landmass_count);
+        let mut queue = VecDeque::from([start]);
+        while let Some(current) = queue.pop_front() {
+            for next in neighbors(current) {
+                if is_land(next) && landmass[next].is_none() {
+                    landmass[next] = Some(landmass_count);
+                    queue.push_back(next);
+                }
+            }
+        }
+        landmass_count += 1;
+    }
+
+    // Landmasses within a short crossing of each other share a world
+    let mut parent: Vec<usize> = (0..landmass_count).collect();
+    fn root(parent: &mut [usize], mut node: usize) -> usize {
+        while parent[node] != node {
+            parent[node] = parent[parent[node]];
+            node = parent[node];
+        }
+        node
+    }
+    for start in 0..provinces.len() {
+        let Some(home) = landmass[start] else {
+            continue;
+        };
+        if !neighbors(start).any(|next| !is_land(next)) {
+            continue;
+        }
+        let mut visited: BTreeSet<usize> = BTreeSet::from([start]);
+        let mut queue = VecDeque::from([(start, 0)]);
+        while let Some((current, distance)) = queue.pop_front() {
+            for next in neighbors(current) {
+                if !visited.insert(next) {
+                    continue;
+                }
+                match landmass[next] {
+                    Some(other) if other != home => {
+                        let (a, b) = (root(&mut parent, home), root(&mut parent, other));
+                        parent[a.max(b)] = a.min(b);
+                    }
+                    Some(_) => {}
+                    None if distance < crossing => queue.push_back((next, distance + 1)),
+                    None => {}
+                }
+            }
+        }
+    }
+
+    // Number worlds in order of first appearance
+    let mut numbering: BTreeMap<usize, u32> = BTreeMap::new();
+    landmass
+        .iter()
+        .map(|landmass| {
+            let world = root(&mut parent, (*landmass)?);
+            let next = numbering.len() as u32;
+            Some(*numbering.entry(world).or_insert(next))
+        })
+        .collect()
+}
+
+/// How eager a nation is to outfit a colonial expedition (0.0-1.0)
+pub fn colonial_utility(expansionism: f32, mercantilism: f32, treasury: Money) -> f32 {
+    score_considerations(&[
+        Consideration::new(
+            (expansionism + 1.0) / 2.0,
+            ResponseCurve::Linear {
+                slope: 0.7,
+                offset: 0.3,
+            },
+        ),
+        Consideration::new(
+            (mercantilism + 1.0) / 2.0,
+            ResponseCurve::Linear {
+                slope: 0.6,
+                offset: 0.4,
+            },
+        ),
+        Consideration::new(
+            treasury.ratio(COLONY_COST * Fixed32::from_int(5)).to_f32(),
+            ResponseCurve::Logistic {
+                midpoint: 0.4,
+                steepness: 8.0,
+            },
+        ),
+    ])
+}
+
+fn is_coastal(provinces: &[Province], province: &Province) -> bool {
+    province.neighbors.iter().flatten().any(|neighbor| {
+        provinces
+            .get(neighbor.value() as usize)
+            .is_some_and(|neighbor| neighbor.terrain == TerrainType::Ocean)
+    })
+}
+
+/// Yearly ocean discovery, contact epidemics, colonization, and colonial trade
+pub fn discover_new_worlds(
+    mut commands: Commands,
+    mut new_worlds: ResMut<NewWorlds>,
+    mut pandemics: ResMut<Pandemics>,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    mut ownership: OwnershipService,
+    province_storage: Option<ResMut<ProvinceStorage>>,
+    province_entity_order: Option<Res<ProvinceEntityOrder>>,
+    world_seed: Option<Res<WorldSeed>>,
+    city_names: Res<CityNames>,
+    nation_index: Res<NationIndex>,
+    mut nations_query: Query<(Entity, &mut Nation, Option<&mut ColonialEmpire>)>,
+    stances_query: Query<&ContactStance>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+    let (Some(mut storage), Some(entity_order)) = (province_storage, province_entity_order) else {
+        return;
+    };
+    if new_worlds.worlds.len() != storage.provinces.len() {
+        new_worlds.worlds = chart_worlds(&storage.provinces, COASTAL_CROSSING);
+        let count = new_worlds
+            .worlds
+            .iter()
+            .flatten()
+            .max()
+            .map_or(0, |&max| max as usize + 1);
+        let mut sizes = vec![0; count];
+        for &world in new_worlds.worlds.iter().flatten() {
+            sizes[world as usize] += 1;
+        }
+        new_worlds.sizes = sizes;
+    }
+    if new_worlds.world_count() < 2 {
+        return;
+    }
+    let seed = world_seed.map_or(0, |seed| seed.0);
+
+    // Who can cross the ocean: advanced nations holding a coast, with ports not sealed
+    let mut coastal_owners: BTreeSet<Entity> = BTreeSet::new();
+    let mut population_of_world = vec![0u64; new_worlds.world_count()];
+    for (index, province) in storage.provinces.iter().enumerate() {
+        if let Some(world) = new_worlds.world_of(index) {
+            population_of_world[world as usize] += province.population as u64;
+        }
+        if let Some(owner) = province.owner_entity {
+            if is_coastal(&storage.provinces, province) {
+                coastal_owners.insert(owner);
+            }
+        }
+    }
+    let mut seafarers: Vec<(u32, Entity, u32)> = nations_query
+        .iter()
+        .filter(|(entity, nation, _)| {
+            nation.technology_level >= OCEAN_CROSSING_TECH
+                && coastal_owners.contains(entity)
+                && stances_query
+                    .get(*entity)
+                    .map_or(true, |stance| stance.policy != ContactPolicy::Sealed)
+        })
+        .filter_map(|(entity, nation, _)| {
+            let id = nation_index.id(entity)?.value();
+            let home = new_worlds.world_of(nation.capital_province.value() as usize)?;
+            Some((id, entity, home))
+        })
+        .collect();
+    // Query order is not stable between runs; sort so the seeded rolls are
+    seafarers.sort();
+
+    // Discovery: sailors reach a world their own has never seen
+    for &(actor, entity, home) in &seafarers {
+        if decision_rng(seed, DecisionDomain::Economy, actor, 7, year).r#gen::<f32>() >= DISCOVERY_CHANCE {
+            continue;
+        }
+        let unknown: Vec<u32> = (0..new_worlds.world_count() as u32)
+            .filter(|&world| {
+                new_worlds.sizes[world as usize] >= MIN_WORLD_PROVINCES && !new_worlds.in_contact(home, world)
+            })
+            .collect();
+        if unknown.is_empty() {
+            continue;
+        }
+        let pick = decision_rng(seed, DecisionDomain::Economy, actor, 8, year).gen_range(0..unknown.len());
+        let found = unknown[pick];
+        new_worlds.contacts.insert((home.min(found), home.max(found)));
+
+        let Ok((_, nation, _)) = nations_query.get(entity) else {
+            continue;
+        };
+        chronicle.write(ChronicleEvent {
+            category: ChronicleCategory::Milestone,
+            text: format!(
+                "Sailors of {} cross the ocean and reach a new world of {} lands",
+                nation.name, new_worlds.sizes[found as usize]
+            ),
+            nations: nation_index.id(entity).into_iter().collect(),
+        });
+
+        // The world that lived apart has no defence against the other's plagues
+        let (home_people, found_people) = (population_of_world[home as usize], population_of_world[found as usize]);
+        let isolated = if found_people <= home_people { found } else { home };
+        if population_of_world[isolated as usize] > 0 && !new_worlds.epidemics.contains_key(&isolated) {
+            new_worlds.epidemics.insert(isolated, year);
+            chronicle.write(ChronicleEvent {
+                category: ChronicleCategory::Catastrophe,
+                text: format!(
+                    "Strange sicknesses arrive with the ships of {}; the peoples beyond the sea begin to die",
+                    nation.name
+                ),
+                nations: nation_index.id(entity).into_iter().collect(),
+            });
+        }
+    }
+
+    // Old-world diseases run through isolated worlds
+    let mut stricken: BTreeSet<Entity> = BTreeSet::new();
+    let raging: Vec<u32> = new_worlds
+        .epidemics
+        .iter()
+        .filter(|&(_, &since)| year < since + EPIDEMIC_YEARS)
+        .map(|(&world, _)| world)
+        .collect();
+    for (index, province) in storage.provinces.iter_mut().enumerate() {
+        if !new_worlds.world_of(index).is_some_and(|world| raging.contains(&world)) {
+            continue;
+        }
+        let killed = (province.population as f32 * CONTACT_MORTALITY / EPIDEMIC_YEARS as f32) as u32;
+        province.set_population(province.population - killed);
+        pandemics.record_dead(index, killed);
+        if let Some(owner) = province.owner_entity {
+            stricken.insert(owner);
+        }
+    }
+
+    // Colonization: seafarers race to plant colonies on unclaimed coasts
+    let mut claimed: BTreeSet<usize> = BTreeSet::new();
+    for &(actor, entity, home) in &seafarers {
+        let Ok((_, nation, _)) = nations_query.get(entity) else {
+            continue;
+        };
+        if nation.treasury < COLONY_COST {
+            continue;
+        }
+        let eagerness = colonial_utility(
+            nation.personality.expansionism,
+            nation.personality.mercantilism,
+            nation.treasury,
+        );
+        if decision_rng(seed, DecisionDomain::Economy, actor, 9, year).r#gen::<f32>() >= COLONY_CHANCE * eagerness {
+            continue;
+        }
+        // The most fertile unclaimed coast in a world we know of beyond the sea
+        let site = storage
+            .provinces
+            .iter()
+            .enumerate()
+            .filter(|(index, province)| {
+                province.owner_entity.is_none()
+                    && province.terrain != TerrainType::Ocean
+                    && !claimed.contains(index)
+                    && new_worlds
+                        .world_of(*index)
+                        .is_some_and(|world| world != home && new_worlds.in_contact(home, world))
+                    && is_coastal(&storage.provinces, province)
+            })
+            .max_by(|(a_index, a), (b_index, b)| {
+                a.agriculture
+                    .value()
+                    .total_cmp(&b.agriculture.value())
+                    .then(b_index.cmp(a_index))
+            })
+            .map(|(index, _)| index);
+        let (Some(site), Some(world)) = (site, site.and_then(|site| new_worlds.world_of(site))) else {
+            continue;
+        };
+        let Some(province_entity) = entity_order.get(site) else {
+            continue;
+        };
+        claimed.insert(site);
+        ownership.transfer(vec![province_entity], entity, OwnershipChangeType::Expansion);
+
+        let first_in_world = new_worlds.colonizers.get(&world).is_none_or(BTreeSet::is_empty);
+        let first_for_nation = new_worlds.colonizers.entry(world).or_default().insert(entity);
+        if first_for_nation {
+            let place = city_names
+                .get(site)
+                .map_or_else(|| "a distant shore".to_string(), |city| city.name.clone());
+            let text = if first_in_world {
+                format!("{} plants the first colony beyond the ocean at {}", nation.name, place)
+            } else {
+                format!(
+                    "{} joins the race for the new world with a colony at {}",
+                    nation.name, place
+                )
+            };
+            chronicle.write(ChronicleEvent {
+                category: ChronicleCategory::Milestone,
+                text,
+                nations: nation_index.id(entity).into_iter().collect(),
+            });
+        }
+        if let Ok((_, mut nation, _)) = nations_query.get_mut(entity) {
+            nation.treasury -= COLONY_COST;
+        }
+    }
+
+    // Colonial trade: overseas provinces ship their goods home
+    let mut overseas: BTreeMap<Entity, u32> = BTreeMap::new();
+    let homes: BTreeMap<Entity, Option<u32>> = nations_query
+        .iter()
+        .map(|(entity, nation, _)| (entity, new_worlds.world_of(nation.capital_province.value() as usize)))
+        .collect();
+    for (index, province) in storage.provinces.iter().enumerate() {
+        let Some(owner) = province.owner_entity else {
+            continue;
+        };
+        let home = homes.get(&owner).copied().flatten();
+        if home.is_some() && new_worlds.world_of(index).is_some_and(|world| Some(world) != home) {
+            *overseas.entry(owner).or_default() += 1;
+        }
+    }
+    for (entity, mut nation, empire) in &mut nations_query {
+        if stricken.contains(&entity) {
+            nation.stability = (nation.stability - EPIDEMIC_UNREST).max(0.0);
+        }
+        let count = overseas.get(&entity).copied().unwrap_or(0);
+        if count == 0 && empire.is_none() {
+            continue;
+        }
+        let trade = count as f32 * COLONIAL_TRADE * (1.0 + nation.personality.mercantilism.max(0.0));
+        nation.treasury += Money::from_f32(trade);
+        let updated = ColonialEmpire { overseas: count, trade };
+        match empire {
+            Some(mut empire) => *empire = updated,
+            None => {
+                commands.entity(entity).insert(updated);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::ProvinceId;
+
+    #[test]
+    fn short_crossings_join_landmasses_and_open_ocean_divides_worlds() {
+        // A line of ten hexes: land, one ocean, land, four ocean, land x2, ocean
+        let terrain = |index: usize| match index {
+            1 | 3 | 4 | 5 | 6 | 9 => TerrainType::Ocean,
+            _ => TerrainType::TemperateGrassland,
+        };
+        let mut provinces: Vec<Province> = (0..10)
+            .map(|id| Province {
+                id: ProvinceId::new(id),
+                terrain: terrain(id as usize),
+                ..Default::default()
+            })
+            .collect();
+        for a in 0..9 {
+            provinces[a].neighbors[0] = Some(ProvinceId::new(a as u32 + 1));
+            provinces[a + 1].neighbors[1] = Some(ProvinceId::new(a as u32));
+        }
+
+        let worlds = chart_worlds(&provinces, 3);
+        assert_eq!(worlds[0], Some(0));
+        assert_eq!(worlds[2], Some(0), "a one-hex strait is a coastal crossing");
+        assert_eq!(worlds[7], Some(1), "four hexes of ocean need ocean-going ships");
+        assert_eq!(worlds[7], worlds[8]);
+        assert_eq!(worlds[1], None);
+    }
+}
@@ -9,6 +9,9 @@
 //! - `Controls` on nation entities (auto-maintained by Bevy)
 //!
 //! All queries are O(1) via the Controls component.
+//!
+//! These are read-only. To change ownership, go through `OwnershipService`
+//! so storage, overlays, and statistics stay in step with the relationships.
 
 use bevy::prelude::*;
 use crate::relationships::{Controls, ControlledBy};
@@ -0,0 +1,146 @@
+//! Single authoritative path for province ownership changes
+//!
+//! Ownership lives in several places at once: the `ControlledBy` relationship,
+//! `Province::owner_entity` in `ProvinceStorage` (borders, saves), the cached
+//! Political overlay, and nation statistics. Systems that move provinces
+//! (expansion, congresses, unification) request a transfer through
+//! [`OwnershipService`]; [`apply_province_transfers`] is the only system that
+//! mutates ownership, updating every representation together and announcing
+//! the result with [`TerritoryOwnershipChanged`].
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+
+use super::actions::{OwnershipChangeType, TerritoryOwnershipChanged};
+use super::NationHistory;
+use crate::relationships::ControlledBy;
+use crate::world::{CachedOverlayColors, MapMode, ProvinceData, ProvinceStorage};
+
+/// Request to move provinces to a new owner (`None` releases them)
+#[derive(Message, Debug, Clone)]
+pub struct ProvinceTransferRequest {
+    pub provinces: Vec<Entity>,
+    pub new_owner: Option<Entity>,
+    pub change_type: OwnershipChangeType,
+}
+
+/// System parameter for requesting ownership changes
+#[derive(SystemParam)]
+pub struct OwnershipService<'w> {
+    requests: MessageWriter<'w, ProvinceTransferRequest>,
+}
+
+impl OwnershipService<'_> {
+    /// Hand provinces to a nation
+    pub fn transfer(&mut self, provinces: Vec<Entity>, new_owner: Entity, change_type: OwnershipChangeType) {
+        if provinces.is_empty() {
+            return;
+        }
+        self.requests.write(ProvinceTransferRequest {
+            provinces,
+            new_owner: Some(new_owner),
+            change_type,
+        });
+    }
+
+    /// Leave provinces unowned
+    pub fn release(&mut self, provinces: Vec<Entity>) {
+        if provinces.is_empty() {
+            return;
+        }
+        self.requests.write(ProvinceTransferRequest {
+            provinces,
+            new_owner: None,
+            change_type: OwnershipChangeType::Loss,
+        });
+    }
+}
+
+/// Apply all pending transfers and keep every ownership representation in sync
+pub fn apply_province_transfers(
+    mut commands: Commands,
+    mut requests: MessageReader<ProvinceTransferRequest>,
+    mut ownership_events: MessageWriter<TerritoryOwnershipChanged>,
+    mut province_storage: Option<ResMut<ProvinceStorage>>,
+    mut overlay_colors: ResMut<CachedOverlayColors>,
+    mut map_mode: ResMut<MapMode>,
+    controlled_by_query: Query<&ControlledBy>,
+    province_data_query: Query<&ProvinceData>,
+    mut histories_query: Query<&mut NationHistory>,
+) {
+    // Owners as of this frame, including transfers not yet applied by commands
+    let mut pending: HashMap<Entity, Option<Entity>> = HashMap::new();
+    let mut announcements = Vec::new();
+
+    for request in requests.read() {
+        let mut gained = Vec::new();
+        // Ordered so announcements come out the same way every run
+        let mut lost: BTreeMap<Entity, Vec<Entity>> = BTreeMap::new();
+
+        for &province in &request.provinces {
+            let previous = pending
+                .get(&province)
+                .copied()
+                .unwrap_or_else(|| controlled_by_query.get(province).ok().map(|c| c.0));
+            if previous == request.new_owner {
+                continue;
+            }
+
+            match request.new_owner {
+                Some(owner) => {
+                    commands.entity(province).insert(ControlledBy(owner));
+                    gained.push(province);
+                }
+                None => {
+                    commands.entity(province).remove::<ControlledBy>();
+                }
+            }
+            if let Some(previous) = previous {
+                lost.entry(previous).or_default().push(province);
+            }
+
+            if let (Some(storage), Ok(data)) = (province_storage.as_mut(), province_data_query.get(province)) {
+                if let Some(stored) = storage.provinces.get_mut(data.id.value() as usize) {
+                    stored.owner_entity = request.new_owner;
+                }
+            }
+            pending.insert(province, request.new_owner);
+        }
+
+        if let Some(owner) = request.new_owner.filter(|_| !gained.is_empty()) {
+            if let Ok(mut history) = histories_query.get_mut(owner) {
+                history.provinces_gained += gained.len() as u32;
+            }
+            announcements.push(TerritoryOwnershipChanged {
+                nation_entity: owner,
+                provinces_changed: gained.len() as u32,
+                change_type: request.change_type,
+                provinces: gained,
+            });
+        }
+        for (previous, provinces) in lost {
+            if let Ok(mut history) = histories_query.get_mut(previous) {
+                history.provinces_lost += provinces.len() as u32;
+            }
+            announcements.push(TerritoryOwnershipChanged {
+                nation_entity: previous,
+                provinces_changed: provinces.len() as u32,
+                change_type: OwnershipChangeType::Loss,
+                provinces,
+            });
+        }
+    }
+
+    if announcements.is_empty() {
+        return;
+    }
+
+    // Every overlay may color by owner, so drop them all and redraw the current one
+    overlay_colors.clear_cache();
+    map_mode.set_changed();
+    debug!("Applied {} ownership changes", announcements.len());
+    for announcement in announcements {
+        ownership_events.write(announcement);
+    }
+}
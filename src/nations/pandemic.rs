@@ -0,0 +1,340 @@
+//! Pandemics - rare pestilences with world-historical reach
+//!
+//! Once in centuries a lethal new strain emerges where the land breeds
+//! disease: fever swamps, rainforests, and the rodent-ridden steppe and
+//! savanna. It spreads along the trade network, from province to neighboring
+//! province along the roads and by leaps between distant trade hubs, raging
+//! for a few years wherever it arrives and killing a fifth to two fifths of
+//! the people. It burns out when it runs out of places to go.
+//!
+//! The dead leave fields untilled and workshops empty. The survivors are
+//! few enough to bargain, so wages rise (see `EconomicLedger::wages`): each
+//! worker produces more, landlords and tax collectors take less, and
+//! commoners are more content. Scarcity fades over generations as the
+//! population regrows.
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::city_names::CityNames;
+use super::index::NationIndex;
+use super::types::Nation;
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::math::HEX_SIZE;
+use crate::simulation::NewYearEvent;
+use crate::world::{InfrastructureStorage, ProvinceStorage, TerrainType, WorldSeed};
+
+/// Yearly chance a pandemic emerges while none is raging
+const EMERGENCE_CHANCE: f32 = 0.004;
+/// Population a breeding ground needs for a strain to take hold among people
+const ORIGIN_POPULATION: u32 = 2_000;
+/// Range of a strain's lethality
+const MIN_LETHALITY: f32 = 0.2;
+const MAX_LETHALITY: f32 = 0.4;
+/// Years a pandemic rages in a province, spreading onward
+const RAGING_YEARS: u32 = 2;
+/// Yearly chance of spreading to a neighbor with no trade at all
+const BASE_SPREAD: f32 = 0.15;
+/// Extra spread to a neighbor at full trade connectivity
+const TRADE_SPREAD: f32 = 0.6;
+/// Yearly chance a raging trade hub infects each other hub within reach
+const HUB_SPREAD: f32 = 0.35;
+/// Reach of the long-distance trade between hubs
+const HUB_REACH: f32 = HEX_SIZE * 40.0;
+/// Stability lost per share of a nation's people killed in a year
+const DEATH_UNREST: f32 = 0.5;
+/// Share of pandemic dead replaced each year by a regrowing population
+const REGROWTH: f32 = 0.02;
+
+/// Names chroniclers give the great pestilences
+const PANDEMIC_NAMES: &[&str] = &[
+    "the Great Mortality",
+    "the Black Death",
+    "the Red Fever",
+    "the Sweating Sickness",
+    "the Grey Pestilence",
+    "the Pale Plague",
+    "the Weeping Death",
+];
+
+/// Whether a strain can emerge among the wildlife of a terrain
+fn breeds_pestilence(terrain: TerrainType) -> bool {
+    matches!(
+        terrain,
+        TerrainType::TropicalRainforest
+            | TerrainType::Wetlands
+            | TerrainType::Mangrove
+            | TerrainType::Savanna
+            | TerrainType::TemperateGrassland
+    )
+}
+
+/// Yearly chance a raging province infects a neighbor with the given trade connectivity
+pub fn spread_chance(connectivity: f32) -> f32 {
+    BASE_SPREAD + TRADE_SPREAD * connectivity.clamp(0.0, 1.0)
+}
+
+/// Share of the working population missing (0.0-1.0) given the living and the unreplaced dead
+pub fn labor_scarcity(population: u32, dead: u32) -> f32 {
+    let total = population as f32 + dead as f32;
+    if total > 0.0 {
+        dead as f32 / total
+    } else {
+        0.0
+    }
+}
+
+/// A pandemic that has emerged and is raging across the world
+#[derive(Debug, Clone)]
+pub struct Pandemic {
+    pub name: String,
+    /// Share of the people it kills where it rages
+    pub lethality: f32,
+    pub emerged: u32,
+    /// Provinces where it rages, and the year it arrived
+    pub raging: BTreeMap<usize, u32>,
+    /// Provinces it has passed through
+    pub reached: BTreeSet<usize>,
+    /// Nations it has reached
+    pub stricken: BTreeSet<Entity>,
+    pub deaths: u64,
+}
+
+/// The raging pandemic, if any, and the dead each province has yet to replace
+#[derive(Resource, Debug, Default)]
+pub struct Pandemics {
+    pub active: Option<Pandemic>,
+    /// Pandemic dead not yet replaced, by province index
+    pub dead: Vec<u32>,
+}
+
+impl Pandemics {
+    /// Unreplaced pandemic dead in a province
+    pub fn dead(&self, index: usize) -> u32 {
+        self.dead.get(index).copied().unwrap_or(0)
+    }
+
+    /// Count people killed by disease in a province toward its labor scarcity
+    pub fn record_dead(&mut self, index: usize, killed: u32) {
+        if self.dead.len() <= index {
+            self.dead.resize(index + 1, 0);
+        }
+        self.dead[index] = self.dead[index].saturating_add(killed);
+    }
+}
+
+/// Yearly emergence, spread, and mortality of pandemics, and regrowth after them
+pub fn spread_pandemics(
+    mut pandemics: ResMut<Pandemics>,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    province_storage: Option<ResMut<ProvinceStorage>>,
+    infrastructure: Option<Res<InfrastructureStorage>>,
+    world_seed: Option<Res<WorldSeed>>,
+    city_names: Res<CityNames>,
+    nation_index: Res<NationIndex>,
+    mut nations_query: Query<(Entity, &mut Nation)>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+    let Some(mut storage) = province_storage else {
+        return;
+    };
+    let province_count = storage.provinces.len();
+    pandemics.dead.resize(province_count, 0);
+
+    // Seeded by world and year so identical runs suffer identical pestilences
+    let seed = world_seed.map_or(0, |seed| seed.0) as u64;
+    let mut rng = StdRng::seed_from_u64((seed << 32) ^ year as u64 ^ 0x9e37_79b9_7f4a_7c15);
+    let connectivity = |storage: &ProvinceStorage, index: usize| -> (f32, bool) {
+        storage
+            .provinces
+            .get(index)
+            .and_then(|province| infrastructure.as_ref()?.infrastructure.get(&province.id))
+            .map_or((0.0, false), |infra| (infra.connectivity, infra.is_hub))
+    };
+    let place_name = |index: usize| {
+        city_names
+            .get(index)
+            .map_or_else(|| "the wilds".to_string(), |city| city.name.clone())
+    };
+
+    // The dead are slowly replaced
+    for (index, dead) in pandemics.dead.iter_mut().enumerate() {
+        if *dead == 0 {
+            continue;
+        }
+        let regrown = ((*dead as f32 * REGROWTH).ceil() as u32).min(*dead);
+        *dead -= regrown;
+        if let Some(province) = storage.provinces.get_mut(index) {
+            province.set_population(province.population.saturating_add(regrown));
+        }
+    }
+
+    if pandemics.active.is_none() && rng.r#gen::<f32>() < EMERGENCE_CHANCE {
+        let mut breeding_grounds: Vec<usize> = storage
+            .provinces
+            .iter()
+            .enumerate()
+            .filter(|(_, province)| breeds_pestilence(province.terrain) && province.population >= ORIGIN_POPULATION)
+            .map(|(index, _)| index)
+            .collect();
+        breeding_grounds.sort_unstable();
+        if let Some(&origin) = breeding_grounds.choose(&mut rng) {
+            let name = PANDEMIC_NAMES
+                .choose(&mut rng)
+                .copied()
+                .unwrap_or("the Great Mortality");
+            chronicle.write(ChronicleEvent {
+                category: ChronicleCategory::Catastrophe,
+                text: format!(
+                    "A new pestilence stirs near {}; it will be remembered as {}",
+                    place_name(origin),
+                    name
+                ),
+                nations: storage.provinces[origin]
+                    .owner_entity
+                    .and_then(|owner| nation_index.id(owner))
+                    .into_iter()
+                    .collect(),
+            });
+            pandemics.active = Some(Pandemic {
+                name: name.to_string(),
+                lethality: rng.gen_range(MIN_LETHALITY..=MAX_LETHALITY),
+                emerged: year,
+                raging: BTreeMap::from([(origin, year)]),
+                reached: BTreeSet::from([origin]),
+                stricken: BTreeSet::new(),
+                deaths: 0,
+            });
+        }
+    }
+
+    let Some(mut pandemic) = pandemics.active.take() else {
+        return;
+    };
+
+    // Spread along the roads to neighbors, and between distant trade hubs
+    let hubs: Vec<usize> = (0..province_count)
+        .filter(|&index| connectivity(&storage, index).1)
+        .collect();
+    let mut newly_infected: BTreeSet<usize> = BTreeSet::new();
+    for (&index, &since) in &pandemic.raging {
+        if since == year {
+            continue;
+        }
+        let Some(province) = storage.provinces.get(index) else {
+            continue;
+        };
+        for neighbor in province.neighbors.iter().flatten() {
+            let next = neighbor.value() as usize;
+            let populated = storage
+                .provinces
+                .get(next)
+                .is_some_and(|province| province.terrain != TerrainType::Ocean && province.population > 0);
+            if populated
+                && !pandemic.reached.contains(&next)
+                && rng.r#gen::<f32>() < spread_chance(connectivity(&storage, next).0)
+            {
+                newly_infected.insert(next);
+            }
+        }
+        if connectivity(&storage, index).1 {
+            for &hub in &hubs {
+                let in_reach = storage.provinces[hub].position.distance(province.position) <= HUB_REACH;
+                if in_reach && !pandemic.reached.contains(&hub) && rng.r#gen::<f32>() < HUB_SPREAD {
+                    newly_infected.insert(hub);
+                }
+            }
+        }
+    }
+    for &index in &newly_infected {
+        pandemic.raging.insert(index, year);
+        pandemic.reached.insert(index);
+    }
+
+    // Where it rages, it kills; half its toll in each of its two years
+    let mut deaths_by_nation: BTreeMap<Entity, u32> = BTreeMap::new();
+    for &index in pandemic.raging.keys() {
+        let Some(province) = storage.provinces.get_mut(index) else {
+            continue;
+        };
+        let toll = pandemic.lethality / RAGING_YEARS as f32 * rng.gen_range(0.75..=1.25);
+        let killed = (province.population as f32 * toll.min(1.0)) as u32;
+        province.set_population(province.population - killed);
+        pandemics.dead[index] += killed;
+        pandemic.deaths += killed as u64;
+        if let Some(owner) = province.owner_entity {
+            *deaths_by_nation.entry(owner).or_default() += killed;
+        }
+    }
+    pandemic.raging.retain(|_, &mut since| year < since + RAGING_YEARS - 1);
+
+    // Stricken nations reel from the dying
+    let mut population_of: BTreeMap<Entity, u64> = BTreeMap::new();
+    for province in &storage.provinces {
+        if let Some(owner) = province.owner_entity {
+            *population_of.entry(owner).or_default() += province.population as u64;
+        }
+    }
+    for (entity, mut nation) in &mut nations_query {
+        let Some(&killed) = deaths_by_nation.get(&entity) else {
+            continue;
+        };
+        let before = population_of.get(&entity).copied().unwrap_or(0) + killed as u64;
+        let share = killed as f32 / before.max(1) as f32;
+        nation.stability = (nation.stability - share * DEATH_UNREST).max(0.0);
+        if killed > 0 && pandemic.stricken.insert(entity) {
+            chronicle.write(ChronicleEvent {
+                category: ChronicleCategory::Catastrophe,
+                text: format!("{} reaches {}", capitalize_first(&pandemic.name), nation.name),
+                nations: nation_index.id(entity).into_iter().collect(),
+            });
+        }
+    }
+
+    if pandemic.raging.is_empty() {
+        chronicle.write(ChronicleEvent {
+            category: ChronicleCategory::Catastrophe,
+            text: format!(
+                "{} burns itself out after {} years, leaving {} dead across {} nations",
+                capitalize_first(&pandemic.name),
+                year - pandemic.emerged + 1,
+                pandemic.deaths,
+                pandemic.stricken.len()
+            ),
+            nations: pandemic
+                .stricken
+                .iter()
+                .filter_map(|&nation| nation_index.id(nation))
+                .collect(),
+        });
+    } else {
+        pandemics.active = Some(pandemic);
+    }
+}
+
+fn capitalize_first(text: &str) -> String {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .map_or_else(String::new, |first| first.to_uppercase().chain(chars).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trade_speeds_the_spread_and_the_dead_leave_labor_scarce() {
+        assert!(spread_chance(1.0) > spread_chance(0.2));
+        assert!(spread_chance(0.0) > 0.0);
+
+        assert_eq!(labor_scarcity(7_000, 3_000), 0.3);
+        assert_eq!(labor_scarcity(0, 0), 0.0);
+    }
+}
@@ -0,0 +1,192 @@
+//! Editable AI personality: economic focus and historic archetype presets
+//!
+//! `NationPersonality` drives AI decisions; this module adds the pieces the
+//! world-creation editor lets observers pick alongside it.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::governance::GovernmentType;
+use super::types::{Economy, NationPersonality};
+
+/// Governments offered in the personality editor, in cycling order
+pub const EDITABLE_GOVERNMENTS: [GovernmentType; 14] = [
+    GovernmentType::AbsoluteMonarchy,
+    GovernmentType::Feudalism,
+    GovernmentType::Empire,
+    GovernmentType::TribalFederation,
+    GovernmentType::NomadicKhanate,
+    GovernmentType::Theocracy,
+    GovernmentType::Caliphate,
+    GovernmentType::MerchantRepublic,
+    GovernmentType::Oligarchy,
+    GovernmentType::CityState,
+    GovernmentType::PresidentialRepublic,
+    GovernmentType::ParliamentaryDemocracy,
+    GovernmentType::MilitaryJunta,
+    GovernmentType::Autocracy,
+];
+
+/// What a nation's economy leans on, setting its starting multipliers
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+#[reflect(Component)]
+pub enum EconomicFocus {
+    #[default]
+    Balanced,
+    Agrarian,
+    Mercantile,
+    Industrial,
+}
+
+impl EconomicFocus {
+    pub const ALL: [EconomicFocus; 4] = [
+        EconomicFocus::Balanced,
+        EconomicFocus::Agrarian,
+        EconomicFocus::Mercantile,
+        EconomicFocus::Industrial,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            EconomicFocus::Balanced => "Balanced",
+            EconomicFocus::Agrarian => "Agrarian",
+            EconomicFocus::Mercantile => "Mercantile",
+            EconomicFocus::Industrial => "Industrial",
+        }
+    }
+
+    /// Starting economy for this focus; laws adjust it from here
+    pub fn economy(&self) -> Economy {
+        let (agricultural, trade, industrial) = match self {
+            EconomicFocus::Balanced => (1.0, 1.0, 1.0),
+            EconomicFocus::Agrarian => (1.3, 0.85, 0.85),
+            EconomicFocus::Mercantile => (0.9, 1.35, 0.9),
+            EconomicFocus::Industrial => (0.85, 0.9, 1.3),
+        };
+        Economy {
+            agricultural_multiplier: agricultural,
+            trade_multiplier: trade,
+            industrial_multiplier: industrial,
+            ..Economy::default()
+        }
+    }
+}
+
+/// Historic character types an observer can stamp onto a nation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PersonalityArchetype {
+    Conqueror,
+    MerchantPrinces,
+    HermitKingdom,
+    Peacemaker,
+    Horde,
+    Theocrats,
+}
+
+impl PersonalityArchetype {
+    pub const ALL: [PersonalityArchetype; 6] = [
+        PersonalityArchetype::Conqueror,
+        PersonalityArchetype::MerchantPrinces,
+        PersonalityArchetype::HermitKingdom,
+        PersonalityArchetype::Peacemaker,
+        PersonalityArchetype::Horde,
+        PersonalityArchetype::Theocrats,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PersonalityArchetype::Conqueror => "Conqueror",
+            PersonalityArchetype::MerchantPrinces => "Merchant Princes",
+            PersonalityArchetype::HermitKingdom => "Hermit Kingdom",
+            PersonalityArchetype::Peacemaker => "Peacemaker",
+            PersonalityArchetype::Horde => "Horde",
+            PersonalityArchetype::Theocrats => "Theocrats",
+        }
+    }
+
+    pub fn personality(&self) -> NationPersonality {
+        let (aggression, expansionism, diplomacy, mercantilism) = match self {
+            PersonalityArchetype::Conqueror => (0.9, 0.9, -0.4, -0.2),
+            PersonalityArchetype::MerchantPrinces => (-0.3, 0.2, 0.6, 0.9),
+            PersonalityArchetype::HermitKingdom => (-0.5, -0.9, -0.6, -0.9),
+            PersonalityArchetype::Peacemaker => (-0.9, -0.3, 0.9, 0.4),
+            PersonalityArchetype::Horde => (1.0, 0.7, -0.8, -0.5),
+            PersonalityArchetype::Theocrats => (0.3, 0.4, -0.2, -0.3),
+        };
+        NationPersonality {
+            aggression,
+            expansionism,
+            diplomacy,
+            mercantilism,
+        }
+    }
+
+    pub fn government(&self) -> GovernmentType {
+        match self {
+            PersonalityArchetype::Conqueror => GovernmentType::Empire,
+            PersonalityArchetype::MerchantPrinces => GovernmentType::MerchantRepublic,
+            PersonalityArchetype::HermitKingdom => GovernmentType::AbsoluteMonarchy,
+            PersonalityArchetype::Peacemaker => GovernmentType::ParliamentaryDemocracy,
+            PersonalityArchetype::Horde => GovernmentType::NomadicKhanate,
+            PersonalityArchetype::Theocrats => GovernmentType::Theocracy,
+        }
+    }
+
+    pub fn economic_focus(&self) -> EconomicFocus {
+        match self {
+            PersonalityArchetype::Conqueror => EconomicFocus::Industrial,
+            PersonalityArchetype::MerchantPrinces => EconomicFocus::Mercantile,
+            PersonalityArchetype::HermitKingdom => EconomicFocus::Agrarian,
+            PersonalityArchetype::Peacemaker => EconomicFocus::Balanced,
+            PersonalityArchetype::Horde => EconomicFocus::Agrarian,
+            PersonalityArchetype::Theocrats => EconomicFocus::Balanced,
+        }
+    }
+}
+
+impl NationPersonality {
+    /// Short description of the strongest leanings, for the nation panel
+    pub fn summary(&self) -> String {
+        let traits = [
+            (self.aggression, "Warlike", "Pacifist"),
+            (self.expansionism, "Expansionist", "Isolationist"),
+            (self.diplomacy, "Friendly", "Hostile"),
+            (self.mercantilism, "Free-trading", "Protectionist"),
+        ];
+        let notable: Vec<&str> = traits
+            .iter()
+            .filter(|(value, _, _)| value.abs() >= 0.4)
+            .map(|&(value, high, low)| if value > 0.0 { high } else { low })
+            .collect();
+        if notable.is_empty() {
+            "Moderate".to_string()
+        } else {
+            notable.join(", ")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn archetype_personalities_stay_in_range() {
+        for archetype in PersonalityArchetype::ALL {
+            let p = archetype.personality();
+            for value in [p.aggression, p.expansionism, p.diplomacy, p.mercantilism] {
+                assert!((-1.0..=1.0).contains(&value), "{:?} out of range", archetype);
+            }
+            assert!(EDITABLE_GOVERNMENTS.contains(&archetype.government()));
+        }
+    }
+
+    #[test]
+    fn summary_names_only_strong_traits() {
+        assert_eq!(NationPersonality::balanced().summary(), "Moderate");
+        assert_eq!(
+            PersonalityArchetype::Conqueror.personality().summary(),
+            "Warlike, Expansionist, Hostile"
+        );
+    }
+}
@@ -16,31 +16,100 @@ define_plugin!(NationPlugin {
     ],
 
     resources: [
-        NationRegistry
+        NationRegistry,
+        super::index::NationIndex,
+        super::cores::ProvinceCores,
+        super::disputes::BorderDisputes,
+        super::census::CensusDiscrepancy,
+        super::devastation::Devastation,
+        super::refugees::Refugees,
+        super::pandemic::Pandemics,
+        super::disasters::DisasterChains,
+        super::disasters::Disasters,
+        super::resource_rush::ResourceRushes,
+        super::new_world::NewWorlds,
+        super::presentation::PresentationEras,
+        super::city_names::CityNames,
+        super::diplomacy::CongressHistory,
+        super::scripted_events::ScriptedEvents,
+        super::scripted_events::ScriptedEventState
     ],
 
     messages: [
         super::actions::NationActionEvent,
         super::actions::TerritoryOwnershipChanged,
+        super::ownership_service::ProvinceTransferRequest,
         super::warfare::DeclareWarEvent,
         super::warfare::BattleEvent,
-        super::warfare::WarEndEvent
+        super::warfare::BattleResolvedEvent,
+        super::refugees::PopulationDisplaced,
+        super::disasters::DisasterStruck,
+        super::warfare::WarEndEvent,
+        super::diplomacy::SignTreatyEvent,
+        super::diplomacy::TreatyViolatedEvent,
+        super::diplomacy::CongressConcludedEvent,
+        super::unification::NationFormedEvent,
+        super::scripted_events::ScriptedEventFired
     ],
 
     reflect: [
         super::types::Nation,
+        super::types::NationId,
+        super::types::Economy,
+        super::bureaucracy::Bureaucracy,
+        super::corruption::Corruption,
+        super::census::Census,
+        super::fortifications::FortConstruction,
+        super::fortifications::FortNetwork,
+        super::logistics::Logistics,
+        super::logistics::SupplyDepot,
+        super::economic_system::EconomicLedger,
+        super::economic_system::EconomicSystem,
+        super::new_world::ColonialEmpire,
+        super::contact_policy::ContactStance,
+        super::types::Territory,
+        super::types::OwnedBy,
+        super::types::OwnsTerritory,
+        super::history::NationHistory,
+        super::cores::LostCores,
+        super::territory_analysis::TerritoryMetrics,
         super::house::House,
         super::house::Ruler,
         super::house::RulerPersonality,
         super::house::HouseTraits,
         super::types::NationPersonality,
+        super::personality::EconomicFocus,
         super::governance::GovernmentType,
         super::governance::Governance,
         super::governance::PoliticalPressure,
         super::governance::GovernmentHistory,
+        super::governance::LegitimacyFactors,
+        super::governance::CrisisFactors,
         super::warfare::War,
         super::warfare::CasusBelli,
         super::warfare::WarGoal,
+        super::warfare::ArmyComposition,
+        super::warfare::ArmyTemplate,
+        super::warfare::Doctrine,
+        super::warfare::MilitaryDoctrine,
+        super::warfare::CampaignAttrition,
+        super::warfare::FieldArmy,
+        super::warfare::ArmyOrder,
+        super::technology::Research,
+        super::diplomacy::FabricatingClaim,
+        super::diplomacy::Treaty,
+        super::diplomacy::TreatyClause,
+        super::diplomacy::TreatyKind,
+        super::diplomacy::TreatyCompliance,
+        super::diplomacy::TradeFlow,
+        super::insurance::InsuranceHouse,
+        super::insurance::RouteInsurance,
+        super::diplomacy::Captives,
+        super::trade_league::TradeLeague,
+        super::unification::UnificationMovement,
+        super::unification::UnifiedInto,
+        super::heraldry::Heraldry,
+        super::heraldry::HeraldicParent,
         // Relationship components (Bevy 0.17)
         super::relationships::LandNeighborOf,
         super::relationships::LandNeighbors,
@@ -49,27 +118,229 @@ define_plugin!(NationPlugin {
         super::relationships::ParticipatesInWar,
         super::relationships::WarParticipants,
         super::relationships::Attacking,
-        super::relationships::AttackedBy
+        super::relationships::AttackedBy,
+        super::relationships::RivalOf,
+        super::relationships::Rivals,
+        super::relationships::VassalOf,
+        super::relationships::Overlord,
+        super::relationships::PaysTributeTo,
+        super::relationships::TributeLord,
+        super::relationships::HasClaimOn,
+        super::relationships::ClaimedBy,
+        super::relationships::FormerlyRuled,
+        super::relationships::FormerRulers,
+        super::relationships::HistoricalTerritory,
+        super::relationships::FormerlyOwnedBy
     ],
 
+    startup: [super::disputes::register_disputes_heatmap],
+
     update: [
         // ACTION EXECUTION - This is where nations actually DO things!
         // Uses reactive cache invalidation - no more polling every frame!
         super::actions::execute_expansion_events.run_if(in_state(GameState::InGame)),
 
+        // OWNERSHIP - The single path through which provinces change hands
+        super::ownership_service::apply_province_transfers
+            .after(super::actions::execute_expansion_events)
+            .after(super::diplomacy::convene_congress_on_great_war_end)
+            .after(super::unification::complete_unifications)
+            .before(super::neighbors::rebuild_neighbor_relationships_on_ownership_change)
+            .run_if(in_state(GameState::InGame)),
+
         // NEIGHBOR RELATIONSHIPS - Event-driven rebuild when territory ownership changes
         super::neighbors::rebuild_neighbor_relationships_on_ownership_change.run_if(in_state(GameState::InGame)),
 
         // WAR SYSTEMS - War declaration, battles, and resolution
         super::warfare::process_war_declarations.run_if(in_state(GameState::InGame)),
         super::warfare::process_battle_events.run_if(in_state(GameState::InGame)),
+        // TECHNOLOGY & DOCTRINE - Research unlocks doctrines; armies retrain over years
+        (super::technology::advance_technology,
+         super::warfare::adopt_doctrines)
+            .chain()
+            .before(super::warfare::manage_army_composition)
+            .before(super::economic_system::allocate_national_output)
+            .run_if(in_state(GameState::InGame)),
+        // ARMY COMPOSITION - Yearly template choice; recruits fill out the template
+        super::warfare::manage_army_composition
+            .before(super::warfare::process_battle_events)
+            .run_if(in_state(GameState::InGame)),
         super::warfare::check_war_resolution.run_if(in_state(GameState::InGame)),
 
+        // FORTIFICATIONS - Yearly fort building on threatened borders; zones of control slow invaders
+        super::fortifications::build_fortifications
+            .before(super::warfare::process_battle_events)
+            .run_if(in_state(GameState::InGame)),
+
+        // LOGISTICS - Yearly stockpiling and depots; war plans wait on the stores, battles on supply
+        super::logistics::manage_logistics
+            .after(super::warfare::adopt_doctrines)
+            .before(super::warfare::process_battle_events)
+            .before(super::diplomacy::evaluate_war_triggers_from_pressure)
+            .before(super::economic_system::allocate_national_output)
+            .run_if(in_state(GameState::InGame)),
+
+        // CAMPAIGN ATTRITION - Yearly losses to desert thirst, winter cold, and jungle fever on the fronts
+        super::warfare::apply_campaign_attrition
+            .after(super::logistics::manage_logistics)
+            .before(super::warfare::process_battle_events)
+            .run_if(in_state(GameState::InGame)),
+
+        // FIELD ARMIES - Yearly muster onto the fronts and a home reserve; home armies garrison their forts;
+        // armies march daily on their objectives
+        (super::warfare::muster_field_armies,
+         super::fortifications::garrison_forts,
+         super::warfare::march_field_armies)
+            .chain()
+            .after(super::warfare::apply_campaign_attrition)
+            .after(super::fortifications::build_fortifications)
+            .run_if(in_state(GameState::InGame)),
+
+        // DEVASTATION - Battles, fronts, sieges, and scorched earth scar provinces; peace heals them over decades
+        super::devastation::devastate_provinces
+            .after(super::warfare::process_battle_events)
+            .before(super::census::take_censuses)
+            .run_if(in_state(GameState::InGame)),
+
+        // REFUGEES - The displaced flee to safety; hosts feed them until they go home or settle
+        super::refugees::shelter_refugees
+            .after(super::devastation::devastate_provinces)
+            .before(super::census::take_censuses)
+            .run_if(in_state(GameState::InGame)),
+
+        // CORE TERRITORY - Yearly core formation and decay, read by war triggers
+        super::cores::update_province_cores
+            .before(super::diplomacy::evaluate_war_triggers_from_pressure)
+            .run_if(in_state(GameState::InGame)),
+
+        // BORDER DISPUTES - Overlapping claims on held land, active once a claimant goes to war
+        super::disputes::update_border_disputes
+            .after(super::cores::update_province_cores)
+            .after(super::resource_rush::run_resource_rushes)
+            .run_if(in_state(GameState::InGame)),
+
+        // BUREAUCRACY - Yearly capacity review; leakage applies to the same year's revenue
+        super::bureaucracy::assess_bureaucratic_capacity
+            .before(super::corruption::spread_corruption)
+            .run_if(in_state(GameState::InGame)),
+
+        // CORRUPTION - Skims the same year's revenue, so it settles before allocation
+        super::corruption::spread_corruption
+            .before(super::economic_system::allocate_national_output)
+            .run_if(in_state(GameState::InGame)),
+
+        // CENSUS - Counts use this year's bureaucracy and corruption
+        super::census::take_censuses
+            .after(super::corruption::spread_corruption)
+            .run_if(in_state(GameState::InGame)),
+
+        // PANDEMICS - Rare pestilences spread along trade; their dead leave labor scarce for generations
+        super::pandemic::spread_pandemics
+            .before(super::economic_system::allocate_national_output)
+            .before(super::census::take_censuses)
+            .run_if(in_state(GameState::InGame)),
+
+        // DISASTERS - Earthquakes, droughts, and fires, and the tsunamis, famines, and plagues that follow them
+        super::disasters::run_disasters
+            .before(super::refugees::shelter_refugees)
+            .before(super::economic_system::allocate_national_output)
+            .before(super::census::take_censuses)
+            .run_if(in_state(GameState::InGame)),
+
+        // RESOURCE RUSHES - Gold and gem strikes on weak frontiers draw migrants and claimants, then go bust
+        super::resource_rush::run_resource_rushes
+            .after(super::cores::update_province_cores)
+            .before(super::refugees::shelter_refugees)
+            .before(super::economic_system::allocate_national_output)
+            .before(super::diplomacy::evaluate_war_triggers_from_pressure)
+            .run_if(in_state(GameState::InGame)),
+
+        // NEW WORLD - Ocean-going nations discover far worlds, carry disease there, and race to colonize
+        super::new_world::discover_new_worlds
+            .after(super::pandemic::spread_pandemics)
+            .before(super::economic_system::allocate_national_output)
+            .before(super::census::take_censuses)
+            .run_if(in_state(GameState::InGame)),
+
+        // CONTACT POLICY - Courts close or reopen their borders by ideology and memory of contact
+        super::contact_policy::choose_contact_policies
+            .after(super::new_world::discover_new_worlds)
+            .before(super::economic_system::allocate_national_output)
+            .run_if(in_state(GameState::InGame)),
+
+        // ECONOMY - Yearly allocation of output under each nation's economic system
+        super::economic_system::allocate_national_output.run_if(in_state(GameState::InGame)),
+
+        // ECONOMIC FLOWS - Monthly summary of where each nation's wealth comes from and goes
+        super::economic_flows::update_economic_flows
+            .after(super::economic_system::allocate_national_output)
+            .run_if(in_state(GameState::InGame)),
+
+        // CITY NAMES - Long foreign rule renames cities in the ruler's naming style
+        super::city_names::update_city_names
+            .after(super::cores::update_province_cores)
+            .run_if(in_state(GameState::InGame)),
+
         // DIPLOMACY - Pressure-triggered war declarations
         super::diplomacy::evaluate_war_triggers_from_pressure.run_if(in_state(GameState::InGame)),
 
+        // TREATIES - Congresses, peace terms, voluntary pacts, and compliance enforcement
+        (super::diplomacy::initialize_treaty_compliance,
+         // Congress reads the war before peace dissolves it
+         super::diplomacy::convene_congress_on_great_war_end,
+         super::diplomacy::sign_peace_on_war_end,
+         super::diplomacy::propose_ai_treaties,
+         super::diplomacy::demand_tribute,
+         super::diplomacy::process_treaty_signings,
+         super::diplomacy::link_trade_partners,
+         super::diplomacy::detect_war_declaration_violations,
+         super::diplomacy::check_treaty_compliance,
+         // Trade pacts settle the year's trade before violations dissolve any
+         super::diplomacy::settle_trade_agreements,
+         // Underwriters price next year's confidence from this year's losses
+         super::insurance::underwrite_trade_routes,
+         // Hostages die while the broken treaty still stands
+         super::diplomacy::execute_hostages,
+         super::diplomacy::handle_treaty_violations)
+            .chain()
+            .after(super::warfare::check_war_resolution)
+            .before(super::warfare::process_war_declarations)
+            .run_if(in_state(GameState::InGame)),
+
+        // CAPTIVES - Decisive battles take prisoners; ransoms are paid, honored, or betrayed each year
+        (super::diplomacy::take_captives.after(super::warfare::process_battle_events),
+         super::diplomacy::settle_ransoms)
+            .run_if(in_state(GameState::InGame)),
+
+        // Balance edits to the insurance tuning reprice routes immediately
+        super::insurance::reprice_routes_on_tuning_change.run_if(in_state(GameState::InGame)),
+
+        // MERCHANT LEAGUES - Trade cities band together, embargo aggressors, and fund defenders
+        super::trade_league::run_trade_leagues
+            .before(super::economic_system::allocate_national_output)
+            .run_if(in_state(GameState::InGame)),
+
+        // UNIFICATION - Nationalist movements merging culture-mates into nation-states
+        (super::unification::update_unification_movements,
+         super::unification::complete_unifications)
+            .chain()
+            .after(super::cores::update_province_cores)
+            .run_if(in_state(GameState::InGame)),
+
+        // HERALDRY - Flags for new nations and houses, redrawn when a nation is recolored
+        (super::heraldry::assign_nation_heraldry,
+         super::heraldry::assign_house_heraldry,
+         super::heraldry::refresh_recolored_heraldry)
+            .chain()
+            .run_if(in_state(GameState::InGame)),
+
         // Rendering systems
         super::rendering::render_nation_borders.run_if(in_state(GameState::InGame)),
+        // Presentation eras follow technology; renamed or re-titled nations update their labels in place
+        (super::presentation::advance_presentation_eras,
+         super::rendering::refresh_renamed_nation_labels)
+            .chain()
+            .run_if(in_state(GameState::InGame)),
         // Label updates (size/visibility) run every frame in Political mode
         (super::rendering::update_nation_label_sizes,
          super::rendering::update_label_visibility)
@@ -79,9 +350,40 @@ define_plugin!(NationPlugin {
         super::rendering::spawn_nation_labels_on_mode_enter
             .run_if(in_state(GameState::InGame))
             .run_if(resource_changed::<crate::world::MapMode>),
+        // Label flags appear beside primary labels at close zoom
+        (super::heraldry::attach_label_flags,
+         super::heraldry::update_label_flag_visibility)
+            .run_if(in_state(GameState::InGame)),
         // Cleanup labels when MapMode changes AWAY FROM Political
         super::rendering::cleanup_labels_on_mode_exit
             .run_if(in_state(GameState::InGame))
-            .run_if(resource_changed::<crate::world::MapMode>)
-    ]
+            .run_if(resource_changed::<crate::world::MapMode>),
+        // Mod-defined events, reloaded whenever the active mods change
+        super::scripted_events::rebuild_scripted_events,
+        super::scripted_events::run_scripted_events
+            .run_if(in_state(GameState::InGame))
+    ],
+
+    on_exit: {
+        GameState::LoadingWorld => [
+            super::cores::clear_province_cores,
+            super::disputes::clear_border_disputes,
+            super::city_names::clear_city_names,
+            super::resource_rush::clear_resource_rushes,
+            super::disasters::clear_disasters,
+            super::diplomacy::clear_congress_history
+        ]
+    },
+
+    on_enter: {
+        GameState::LoadingWorld => [
+            super::scripted_events::reset_scripted_event_state
+        ],
+        GameState::InGame => [
+            super::cores::initialize_province_cores,
+            super::city_names::initialize_city_names,
+            super::fortifications::spawn_restored_forts,
+            super::logistics::spawn_restored_depots
+        ]
+    }
 });
@@ -0,0 +1,107 @@
+//! Presentation eras - how the map shows a nation's progress
+//!
+//! Technology levels map onto four presentation eras: tribal, medieval,
+//! renaissance, and modern. A nation's era picks the title on its map label
+//! (a kingdom still in the tribal age reads as a chiefdom), the look of its
+//! cities, and the icons of its armies, so an observer can read the world's
+//! progress at a glance. The technology-to-era mapping is a resource, so
+//! scenarios and mods can move the thresholds.
+
+use bevy::prelude::*;
+
+use super::governance::{era_nation_name, DevelopmentLevel, Governance};
+use super::index::NationIndex;
+use super::types::Nation;
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+
+/// Technology levels at which the medieval, renaissance, and modern eras begin
+#[derive(Resource, Debug, Clone)]
+pub struct PresentationEras {
+    pub thresholds: [u32; 3],
+}
+
+impl Default for PresentationEras {
+    fn default() -> Self {
+        Self { thresholds: [2, 4, 7] }
+    }
+}
+
+impl PresentationEras {
+    /// Era a nation at this technology level is presented in
+    pub fn era_for(&self, technology_level: u32) -> DevelopmentLevel {
+        let [medieval, renaissance, modern] = self.thresholds;
+        if technology_level >= modern {
+            DevelopmentLevel::Modern
+        } else if technology_level >= renaissance {
+            DevelopmentLevel::Renaissance
+        } else if technology_level >= medieval {
+            DevelopmentLevel::Medieval
+        } else {
+            DevelopmentLevel::Primitive
+        }
+    }
+}
+
+/// The era a nation is presented in on the map
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PresentationEra(pub DevelopmentLevel);
+
+/// The name shown on a nation's map label, titled for its era
+pub fn presented_name(nation: &Nation, governance: Option<&Governance>, era: Option<&PresentationEra>) -> String {
+    match (governance, era) {
+        (Some(governance), Some(era)) => {
+            era_nation_name(&nation.name, nation.culture, &governance.government_type, era.0)
+        }
+        _ => nation.name.clone(),
+    }
+}
+
+/// Keep each nation's presentation era in step with its technology
+pub fn advance_presentation_eras(
+    mut commands: Commands,
+    eras: Res<PresentationEras>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    nation_index: Res<NationIndex>,
+    mut nations_query: Query<(Entity, &Nation, Option<&mut PresentationEra>)>,
+) {
+    for (entity, nation, current) in &mut nations_query {
+        let era = eras.era_for(nation.technology_level);
+        match current {
+            Some(mut current) => {
+                if current.0 == era {
+                    continue;
+                }
+                if era > current.0 {
+                    chronicle.write(ChronicleEvent {
+                        category: ChronicleCategory::Milestone,
+                        text: format!("{} enters the {}", nation.name, era.age_name()),
+                        nations: nation_index.id(entity).into_iter().collect(),
+                    });
+                }
+                current.0 = era;
+            }
+            None => {
+                commands.entity(entity).insert(PresentationEra(era));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn technology_maps_onto_eras_in_order() {
+        let eras = PresentationEras::default();
+        assert_eq!(eras.era_for(1), DevelopmentLevel::Primitive);
+        assert_eq!(eras.era_for(2), DevelopmentLevel::Medieval);
+        assert_eq!(eras.era_for(5), DevelopmentLevel::Renaissance);
+        assert_eq!(eras.era_for(30), DevelopmentLevel::Modern);
+
+        let late_bloomers = PresentationEras {
+            thresholds: [5, 10, 20],
+        };
+        assert_eq!(late_bloomers.era_for(4), DevelopmentLevel::Primitive);
+    }
+}
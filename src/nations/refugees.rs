@@ -0,0 +1,493 @@
+//! Refugees - people fleeing war and disaster
+//!
+//! Ravaged provinces and plague-stricken lands send part of their people
+//! fleeing (`PopulationDisplaced`). Refugees make for the nearest safe
+//! province, one that is neither ravaged nor on a front, staying within
+//! their own nation if they can. Otherwise they cross the border into a
+//! nation at peace, unless it has closed its borders with the No Sanctuary
+//! law or a closed contact policy. People with nowhere to go are scattered
+//! and lost.
+//!
+//! Hosts feed their refugees from the treasury, and a nation crowded with
+//! them grows restless. Refugees drift home once their homeland is at peace
+//! and recovering; those still abroad after a generation settle for good,
+//! and settlers who outnumber the locals make the province their own
+//! culture. A flood across a border is a diplomatic incident unless the
+//! host grants the right of asylum.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+use super::city_names::CityNames;
+use super::contact_policy::ContactStance;
+use super::devastation::Devastation;
+use super::diplomacy::TreatyCompliance;
+use super::index::NationIndex;
+use super::laws::{LawId, NationLaws};
+use super::relationships::{Attacking, ParticipatesInWar};
+use super::types::{Nation, NationId};
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::math::Money;
+use crate::name_generator::Culture;
+use crate::simulation::{GameTime, NewYearEvent};
+use crate::world::{MapMode, Province, ProvinceStorage, TerrainType};
+
+/// Law that shelters refugees without complaint
+const RIGHT_OF_ASYLUM_LAW: LawId = LawId::new(7006);
+/// Law that turns all refugees away at the border
+const NO_SANCTUARY_LAW: LawId = LawId::new(7007);
+/// Furthest refugees travel looking for safety, in provinces
+const MAX_FLIGHT_PROVINCES: usize = 6;
+/// Devastation above which a province is no refuge
+const SAFE_DEVASTATION: f32 = 0.3;
+/// Treasury cost of feeding a thousand refugees for a year
+const FOOD_COST_PER_THOUSAND: f32 = 2.0;
+/// Refugees, as a share of the host's population, it takes in without strain
+const STRAIN_SHARE: f32 = 0.03;
+/// Stability lost per share of population above the strain threshold
+const STRAIN_STABILITY: f32 = 0.5;
+/// Most stability a host loses to refugees in a year
+const MAX_STRAIN_STABILITY: f32 = 0.05;
+/// Share of refugees who go home each year once their homeland is safe
+const RETURN_SHARE: f32 = 0.2;
+/// Years abroad after which refugees settle for good
+const SETTLE_YEARS: u32 = 20;
+/// Settlers, as a share of the province's people, who carry their culture with them
+const CULTURE_SHIFT_SHARE: f32 = 0.5;
+/// Refugees crossing one border in a year that make a diplomatic incident
+const INCIDENT_REFUGEES: u32 = 10_000;
+/// Opinion a host loses of the nation whose people flood across its border
+const INCIDENT_OPINION: f32 = -0.1;
+
+/// Message: people have fled a province
+#[derive(Message, Debug, Clone)]
+pub struct PopulationDisplaced {
+    /// Province storage index they fled
+    pub province: usize,
+    /// People who fled, already taken from the province's population
+    pub people: u32,
+}
+
+/// Refugees from one province sheltering in another
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RefugeeFlow {
+    pub origin: usize,
+    pub destination: usize,
+    pub people: u32,
+    /// Culture of the province they fled
+    pub culture: Option<Culture>,
+    /// Year they first arrived
+    pub since: u32,
+}
+
+/// Every refugee flow in the world, and how many each province hosts and has lost
+#[derive(Resource, Debug, Default)]
+pub struct Refugees {
+    pub flows: Vec<RefugeeFlow>,
+    /// Refugees sheltering in each province, by province index
+    hosted: Vec<u32>,
+    /// Refugees abroad from each province, by province index
+    fled: Vec<u32>,
+    /// Refugees who crossed from one nation (second) into another (first) this year
+    arrivals: BTreeMap<(Entity, Entity), u32>,
+}
+
+/// Refugees as written to a save, naming the nations of this year's arrivals by stable id
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SavedRefugees {
+    pub flows: Vec<RefugeeFlow>,
+    /// Refugees who crossed into a nation (first) from another (second) this year
+    pub arrivals: Vec<(NationId, NationId, u32)>,
+}
+
+impl Refugees {
+    pub fn hosted(&self, index: usize) -> u32 {
+        self.hosted.get(index).copied().unwrap_or(0)
+    }
+
+    pub fn fled(&self, index: usize) -> u32 {
+        self.fled.get(index).copied().unwrap_or(0)
+    }
+
+    fn shelter(&mut self, origin: usize, destination: usize, people: u32, culture: Option<Culture>, year: u32) {
+        match self
+            .flows
+            .iter_mut()
+            .find(|flow| flow.origin == origin && flow.destination == destination)
+        {
+            Some(flow) => flow.people += people,
+            None => self.flows.push(RefugeeFlow {
+                origin,
+                destination,
+                people,
+                culture,
+                since: year,
+            }),
+        }
+    }
+
+    /// Stable-id form of every flow and this year's arrivals, for saving
+    pub fn to_saved(&self, id_of: impl Fn(Entity) -> Option<NationId>) -> SavedRefugees {
+        SavedRefugees {
+            flows: self.flows.clone(),
+            arrivals: self
+                .arrivals
+                .iter()
+                .filter_map(|(&(host, home), &people)| Some((id_of(host)?, id_of(home)?, people)))
+                .collect(),
+        }
+    }
+
+    /// Rebuild refugees from a save; arrivals between nations that are gone are forgotten
+    pub fn restore(
+        saved: &SavedRefugees,
+        province_count: usize,
+        entity_of: impl Fn(NationId) -> Option<Entity>,
+    ) -> Self {
+        let mut refugees = Self {
+            flows: saved.flows.clone(),
+            arrivals: saved
+                .arrivals
+                .iter()
+                .filter_map(|&(host, home, people)| Some(((entity_of(host)?, entity_of(home)?), people)))
+                .collect(),
+            ..Default::default()
+        };
+        refugees.tally(province_count);
+        refugees
+    }
+
+    fn tally(&mut self, province_count: usize) {
+        self.hosted = vec![0; province_count];
+        self.fled = vec![0; province_count];
+        for flow in &self.flows {
+            if let Some(hosted) = self.hosted.get_mut(flow.destination) {
+                *hosted += flow.people;
+            }
+            if let Some(fled) = self.fled.get_mut(flow.origin) {
+                *fled += flow.people;
+            }
+        }
+    }
+}
+
+/// Nearest land province to `origin` that `is_refuge` accepts, searching outward
+pub fn find_refuge(provinces: &[Province], origin: usize, is_refuge: impl Fn(usize) -> bool) -> Option<usize> {
+    let mut visited: HashSet<usize> = HashSet::from([origin]);
+    let mut frontier: VecDeque<(usize, usize)> = VecDeque::from([(origin, 0)]);
+    while let Some((index, distance)) = frontier.pop_front() {
+        if distance >= MAX_FLIGHT_PROVINCES {
+            continue;
+        }
+        let Some(province) = provinces.get(index) else {
+            continue;
+        };
+        for neighbor in province.neighbors.iter().flatten() {
+            let next = neighbor.value() as usize;
+            let is_land = provinces
+                .get(next)
+                .is_some_and(|province| province.terrain != TerrainType::Ocean);
+            if !is_land || !visited.insert(next) {
+                continue;
+            }
+            if is_refuge(next) {
+                return Some(next);
+            }
+            frontier.push_back((next, distance + 1));
+        }
+    }
+    None
+}
+
+/// Displaced people find refuge; each year refugees cost their hosts, go home, or settle
+pub fn shelter_refugees(
+    mut refugees: ResMut<Refugees>,
+    mut displaced_events: MessageReader<PopulationDisplaced>,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    mut map_mode: ResMut<MapMode>,
+    province_storage: Option<ResMut<ProvinceStorage>>,
+    devastation: Res<Devastation>,
+    city_names: Res<CityNames>,
+    nation_index: Res<NationIndex>,
+    game_time: Res<GameTime>,
+    mut nations_query: Query<(
+        Entity,
+        &mut Nation,
+        Option<&ParticipatesInWar>,
+        Option<&NationLaws>,
+        Option<&mut TreatyCompliance>,
+    )>,
+    attackers_query: Query<(Entity, &Attacking)>,
+    stances_query: Query<&ContactStance>,
+) {
+    let displaced: Vec<PopulationDisplaced> = displaced_events.read().cloned().collect();
+    let year = year_events.read().map(|event| event.year).last();
+    let Some(mut storage) = province_storage else {
+        return;
+    };
+    if displaced.is_empty() && year.is_none() {
+        return;
+    }
+    let current_year = year.unwrap_or_else(|| game_time.current_year());
+
+    let at_war: HashSet<Entity> = nations_query
+        .iter()
+        .filter(|(_, _, war, _, _)| war.is_some())
+        .map(|(entity, ..)| entity)
+        .collect();
+    let closed: HashSet<Entity> = nations_query
+        .iter()
+        .filter(|(entity, .., laws, _)| {
+            laws.is_some_and(|laws| laws.is_active(NO_SANCTUARY_LAW))
+                || stances_query
+                    .get(*entity)
+                    .is_ok_and(|stance| !stance.policy.admits_migrants())
+        })
+        .map(|(entity, ..)| entity)
+        .collect();
+    let enemies: BTreeSet<(Entity, Entity)> = attackers_query
+        .iter()
+        .flat_map(|(attacker, attacking)| [(attacker, attacking.0), (attacking.0, attacker)])
+        .collect();
+    let on_front = |storage: &ProvinceStorage, index: usize| -> bool {
+        let Some(owner) = storage.provinces.get(index).and_then(|province| province.owner_entity) else {
+            return false;
+        };
+        storage.provinces[index].neighbors.iter().flatten().any(|neighbor| {
+            storage
+                .provinces
+                .get(neighbor.value() as usize)
+                .and_then(|province| province.owner_entity)
+                .is_some_and(|neighbor_owner| enemies.contains(&(owner, neighbor_owner)))
+        })
+    };
+    // Safe from the fighting, and not ravaged
+    let is_safe = |storage: &ProvinceStorage, index: usize| -> bool {
+        devastation.level(index) < SAFE_DEVASTATION && !on_front(storage, index)
+    };
+
+    for event in displaced {
+        let Some(origin) = storage.provinces.get(event.province) else {
+            continue;
+        };
+        let home = origin.owner_entity;
+        let culture = origin.culture;
+        let owner_of = |index: usize| storage.provinces.get(index).and_then(|province| province.owner_entity);
+
+        // Within their own nation if they can, across the border if they must
+        let refuge = find_refuge(&storage.provinces, event.province, |index| {
+            home.is_some() && owner_of(index) == home && is_safe(&*storage, index)
+        })
+        .or_else(|| {
+            find_refuge(&storage.provinces, event.province, |index| {
+                owner_of(index).is_some_and(|host| !at_war.contains(&host) && !closed.contains(&host))
+                    && is_safe(&*storage, index)
+            })
+        });
+        let Some(refuge) = refuge else {
+            continue;
+        };
+
+        let host = owner_of(refuge);
+        if let (Some(host), Some(home)) = (host, home) {
+            if host != home {
+                *refugees.arrivals.entry((host, home)).or_default() += event.people;
+            }
+        }
+        let province = &mut storage.provinces[refuge];
+        province.population = province.population.saturating_add(event.people);
+        province.mark_dirty();
+        refugees.shelter(event.province, refuge, event.people, culture, current_year);
+    }
+
+    if year.is_some() {
+        let names: HashMap<Entity, String> = nations_query
+            .iter()
+            .map(|(entity, nation, ..)| (entity, nation.name.clone()))
+            .collect();
+
+        // Refugees drift home once it is safe, and settle if they stay abroad long enough
+        let mut flows = std::mem::take(&mut refugees.flows);
+        flows.retain_mut(|flow| {
+            let home_is_safe = storage
+                .provinces
+                .get(flow.origin)
+                .is_some_and(|origin| origin.owner_entity.is_some_and(|home| !at_war.contains(&home)))
+                && is_safe(&*storage, flow.origin);
+
+            if home_is_safe {
+                let Some(host) = storage.provinces.get_mut(flow.destination) else {
+                    return false;
+                };
+                let returning = ((flow.people as f32 * RETURN_SHARE).ceil() as u32)
+                    .min(flow.people)
+                    .min(host.population);
+                host.population -= returning;
+                host.mark_dirty();
+                flow.people -= returning;
+                if let Some(origin) = storage.provinces.get_mut(flow.origin) {
+                    origin.population = origin.population.saturating_add(returning);
+                    origin.mark_dirty();
+                }
+                return flow.people > 0;
+            }
+
+            if current_year < flow.since + SETTLE_YEARS {
+                return true;
+            }
+            let Some(host) = storage.provinces.get_mut(flow.destination) else {
+                return false;
+            };
+            let outnumber = flow.people as f32 >= host.population as f32 * CULTURE_SHIFT_SHARE;
+            if let Some(culture) = flow.culture.filter(|_| outnumber) {
+                if host.culture != Some(culture) {
+                    host.culture = Some(culture);
+                    host.mark_dirty();
+                    let place = city_names
+                        .get(flow.destination)
+                        .map_or_else(|| "a border province".to_string(), |city| city.name.clone());
+                    chronicle.write(ChronicleEvent {
+                        category: ChronicleCategory::Politics,
+                        text: format!(
+                            "Refugees who never went home have made {} a {} land",
+                            place,
+                            culture.name()
+                        ),
+                        nations: host
+                            .owner_entity
+                            .and_then(|owner| nation_index.id(owner))
+                            .into_iter()
+                            .collect(),
+                    });
+                }
+            }
+            false
+        });
+        refugees.flows = flows;
+
+        // Hosts feed their refugees, and crowded hosts grow restless
+        let mut hosted_by: HashMap<Entity, u32> = HashMap::new();
+        for flow in &refugees.flows {
+            if let Some(host) = storage.provinces.get(flow.destination).and_then(|p| p.owner_entity) {
+                *hosted_by.entry(host).or_default() += flow.people;
+            }
+        }
+        let mut population_of: HashMap<Entity, u64> = HashMap::new();
+        for province in &storage.provinces {
+            if let Some(owner) = province.owner_entity {
+                *population_of.entry(owner).or_default() += province.population as u64;
+            }
+        }
+        for (entity, mut nation, ..) in &mut nations_query {
+            let Some(&hosted) = hosted_by.get(&entity) else {
+                continue;
+            };
+            let food = hosted as f32 / 1000.0 * FOOD_COST_PER_THOUSAND;
+            nation.treasury -= Money::from_f32(food).min(nation.treasury.max(Money::ZERO));
+
+            let population = population_of.get(&entity).copied().unwrap_or(0).max(1);
+            let share = hosted as f32 / population as f32;
+            if share > STRAIN_SHARE {
+                let strain = ((share - STRAIN_SHARE) * STRAIN_STABILITY).min(MAX_STRAIN_STABILITY);
+                nation.stability = (nation.stability - strain).max(0.0);
+            }
+        }
+
+        // A flood across the border is an incident, unless the host grants asylum
+        let arrivals = std::mem::take(&mut refugees.arrivals);
+        for ((host, home), people) in arrivals {
+            if people < INCIDENT_REFUGEES {
+                continue;
+            }
+            let Ok((_, _, _, laws, compliance)) = nations_query.get_mut(host) else {
+                continue;
+            };
+            let (Some(host_name), Some(home_name)) = (names.get(&host), names.get(&home)) else {
+                continue;
+            };
+            let nations = [host, home].into_iter().filter_map(|n| nation_index.id(n)).collect();
+            if laws.is_some_and(|laws| laws.is_active(RIGHT_OF_ASYLUM_LAW)) {
+                chronicle.write(ChronicleEvent {
+                    category: ChronicleCategory::Politics,
+                    text: format!(
+                        "{} grants asylum to {} refugees fleeing {}",
+                        host_name, people, home_name
+                    ),
+                    nations,
+                });
+                continue;
+            }
+            if let Some(mut compliance) = compliance {
+                compliance.adjust_opinion(home, INCIDENT_OPINION);
+            }
+            chronicle.write(ChronicleEvent {
+                category: ChronicleCategory::Politics,
+                text: format!(
+                    "{} protests to {} as {} of its refugees pour across the border",
+                    host_name, home_name, people
+                ),
+                nations,
+            });
+        }
+    }
+
+    let province_count = storage.provinces.len();
+    refugees.tally(province_count);
+
+    if *map_mode == MapMode::RefugeeFlows {
+        map_mode.set_changed();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::ProvinceId;
+
+    #[test]
+    fn refugees_flee_to_the_nearest_refuge_over_land() {
+        // Four provinces in a line, and an ocean off the first one
+        let mut provinces: Vec<Province> = (0..5)
+            .map(|id| Province {
+                id: ProvinceId::new(id),
+                terrain: TerrainType::TemperateGrassland,
+                ..Default::default()
+            })
+            .collect();
+        let links = [(0, 1), (1, 2), (2, 3), (0, 4)];
+        for (a, b) in links {
+            let slot = provinces[a].neighbors.iter().position(Option::is_none).unwrap();
+            provinces[a].neighbors[slot] = Some(ProvinceId::new(b as u32));
+            let slot = provinces[b].neighbors.iter().position(Option::is_none).unwrap();
+            provinces[b].neighbors[slot] = Some(ProvinceId::new(a as u32));
+        }
+        provinces[4].terrain = TerrainType::Ocean;
+
+        assert_eq!(find_refuge(&provinces, 0, |index| index >= 2), Some(2));
+        assert_eq!(find_refuge(&provinces, 0, |index| index == 4), None);
+        assert_eq!(find_refuge(&provinces, 0, |_| false), None);
+    }
+
+    #[test]
+    fn refugees_are_tallied_again_after_a_save() {
+        let host = Entity::from_raw_u32(1).unwrap();
+        let home = Entity::from_raw_u32(2).unwrap();
+        let mut refugees = Refugees::default();
+        refugees.shelter(0, 2, 300, None, 50);
+        refugees.shelter(1, 2, 200, None, 51);
+        refugees.arrivals.insert((host, home), 500);
+
+        let saved = refugees.to_saved(|entity| Some(NationId(entity.index())));
+        let restored = Refugees::restore(&saved, 3, |id| Some(Entity::from_raw_u32(id.0).unwrap()));
+        assert_eq!(restored.flows, refugees.flows);
+        assert_eq!(restored.hosted(2), 500);
+        assert_eq!(restored.fled(0), 300);
+        assert_eq!(restored.arrivals.get(&(host, home)), Some(&500));
+
+        let forgotten = Refugees::restore(&saved, 3, |_| None);
+        assert!(forgotten.arrivals.is_empty());
+        assert_eq!(forgotten.hosted(2), 500);
+    }
+}
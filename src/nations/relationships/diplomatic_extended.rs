@@ -8,6 +8,7 @@ use bevy::prelude::*;
 ///
 /// Creates symmetric rivalry between two nations.
 #[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = Rivals)]
 pub struct RivalOf(pub Entity);
 
@@ -15,6 +16,7 @@ pub struct RivalOf(pub Entity);
 ///
 /// Automatically maintained by Bevy's relationship system.
 #[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = RivalOf, linked_spawn)]
 pub struct Rivals(Vec<Entity>);
 
@@ -36,6 +38,7 @@ impl Rivals {
 ///
 /// Creates hierarchical vassalage relationship.
 #[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = Overlord)]
 pub struct VassalOf(pub Entity);
 
@@ -43,6 +46,7 @@ pub struct VassalOf(pub Entity);
 ///
 /// Automatically maintained by Bevy's relationship system.
 #[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = VassalOf, linked_spawn)]
 pub struct Overlord(Vec<Entity>);
 
@@ -64,6 +68,7 @@ impl Overlord {
 ///
 /// Creates tributary relationship where one nation provides resources.
 #[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = TributeLord)]
 pub struct PaysTributeTo(pub Entity);
 
@@ -71,6 +76,7 @@ pub struct PaysTributeTo(pub Entity);
 ///
 /// Automatically maintained by Bevy's relationship system.
 #[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = PaysTributeTo, linked_spawn)]
 pub struct TributeLord(Vec<Entity>);
 
@@ -92,6 +98,7 @@ impl TributeLord {
 ///
 /// Points to the Province or Territory entity being claimed.
 #[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = ClaimedBy)]
 pub struct HasClaimOn(pub Entity);
 
@@ -99,6 +106,7 @@ pub struct HasClaimOn(pub Entity);
 ///
 /// Automatically maintained by Bevy's relationship system.
 #[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = HasClaimOn, linked_spawn)]
 pub struct ClaimedBy(Vec<Entity>);
 
@@ -11,6 +11,7 @@ use bevy::prelude::*;
 /// Points to the Province/Territory entity that was historically controlled.
 /// Used for historical claims and narrative context.
 #[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = FormerRulers)]
 pub struct FormerlyRuled(pub Entity);
 
@@ -19,6 +20,7 @@ pub struct FormerlyRuled(pub Entity);
 /// Automatically maintained by Bevy's relationship system.
 /// Preserves historical context even after territory changes hands.
 #[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = FormerlyRuled, linked_spawn)]
 pub struct FormerRulers(Vec<Entity>);
 
@@ -41,6 +43,7 @@ impl FormerRulers {
 /// Points to the Province/Territory entity. Stronger than HasClaimOn,
 /// represents deep cultural/historical connection.
 #[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = FormerlyOwnedBy)]
 pub struct HistoricalTerritory(pub Entity);
 
@@ -49,6 +52,7 @@ pub struct HistoricalTerritory(pub Entity);
 /// Automatically maintained by Bevy's relationship system.
 /// Used for casus belli generation and cultural identity.
 #[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = HistoricalTerritory, linked_spawn)]
 pub struct FormerlyOwnedBy(Vec<Entity>);
 
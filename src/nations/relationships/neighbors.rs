@@ -9,6 +9,7 @@ use bevy::prelude::*;
 ///
 /// Created when nations have provinces that are directly adjacent.
 #[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = LandNeighbors)]
 pub struct LandNeighborOf(pub Entity);
 
@@ -16,6 +17,7 @@ pub struct LandNeighborOf(pub Entity);
 ///
 /// Automatically maintained by Bevy's relationship system.
 #[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = LandNeighborOf, linked_spawn)]
 pub struct LandNeighbors(Vec<Entity>);
 
@@ -37,6 +39,7 @@ impl LandNeighbors {
 ///
 /// Created when nations have coastal provinces projecting power across water.
 #[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = NavalNeighbors)]
 pub struct NavalNeighborOf(pub Entity);
 
@@ -44,6 +47,7 @@ pub struct NavalNeighborOf(pub Entity);
 ///
 /// Automatically maintained by Bevy's relationship system.
 #[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = NavalNeighborOf, linked_spawn)]
 pub struct NavalNeighbors(Vec<Entity>);
 
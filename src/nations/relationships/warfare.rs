@@ -8,6 +8,7 @@ use bevy::prelude::*;
 ///
 /// Points to the War entity that this nation is involved in.
 #[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = WarParticipants)]
 pub struct ParticipatesInWar(pub Entity);
 
@@ -15,6 +16,7 @@ pub struct ParticipatesInWar(pub Entity);
 ///
 /// Automatically maintained by Bevy's relationship system.
 #[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = ParticipatesInWar, linked_spawn)]
 pub struct WarParticipants(Vec<Entity>);
 
@@ -32,6 +34,7 @@ impl WarParticipants {
 ///
 /// Creates asymmetric attack relationship between nations.
 #[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = AttackedBy)]
 pub struct Attacking(pub Entity);
 
@@ -39,6 +42,7 @@ pub struct Attacking(pub Entity);
 ///
 /// Tracks all nations that are attacking this nation.
 #[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = Attacking, linked_spawn)]
 pub struct AttackedBy(Vec<Entity>);
 
@@ -5,10 +5,15 @@
 
 use bevy::prelude::*;
 use bevy::sprite::Text2d;  // Moved from bevy::text in Bevy 0.17
+use std::collections::HashMap;
 
+use super::governance::Governance;
+use super::presentation::{presented_name, PresentationEra};
+use super::territory_analysis::TerritoryMetrics;
 use super::types::Nation;
 use crate::math::HEX_SIZE;
 use crate::resources::MapMode;
+use crate::settings::{GameSettings, QualityLevel};
 use crate::ui::ShortcutRegistry;
 use crate::world::ProvinceStorage;
 
@@ -133,11 +138,24 @@ fn find_non_colliding_position(
     (centroid, 0.7)
 }
 
+/// Nations with what their labels need: territory, and government and era for the title
+type LabeledNationQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Nation,
+        Option<&'static TerritoryMetrics>,
+        Option<&'static Governance>,
+        Option<&'static PresentationEra>,
+    ),
+>;
+
 /// Spawn nation labels when entering Political mode
 /// This wrapper ensures labels only spawn when MapMode changes TO Political
 pub fn spawn_nation_labels_on_mode_enter(
     commands: Commands,
-    nations: Query<(Entity, &Nation, Option<&super::territory_analysis::TerritoryMetrics>)>,
+    nations: LabeledNationQuery,
     province_storage: Res<ProvinceStorage>,
     spatial_index: Res<crate::world::ProvincesSpatialIndex>,
     existing_labels: Query<Entity, With<NationLabel>>,
@@ -178,6 +196,17 @@ pub fn cleanup_labels_on_mode_exit(
     }
 }
 
+/// Farthest camera zoom at which borders are drawn, and whether edges
+/// against unclaimed land and sea are outlined too
+fn border_detail(quality: &QualityLevel) -> (f32, bool) {
+    match quality {
+        QualityLevel::Low => (1000.0, false),
+        QualityLevel::Medium => (2000.0, true),
+        QualityLevel::High => (5000.0, true),
+        QualityLevel::Ultra => (f32::INFINITY, true),
+    }
+}
+
 /// System to render nation borders (thicker lines between different nations)
 /// Always renders in Terrain mode, or when B key is pressed in other modes
 pub fn render_nation_borders(
@@ -187,6 +216,7 @@ pub fn render_nation_borders(
     keyboard: Res<ButtonInput<KeyCode>>,
     registry: Res<ShortcutRegistry>,
     current_map_mode: Res<crate::world::MapMode>,
+    settings: Res<GameSettings>,
 ) {
     // Get the border toggle key from the shortcuts registry (defaults to B)
     let border_key = registry
@@ -210,8 +240,9 @@ pub fn render_nation_borders(
     let camera_pos = camera_transform.translation;
     let zoom_level = camera_pos.z.abs();
 
-    // Don't render borders when too far zoomed out
-    if zoom_level > 2000.0 {
+    // Don't render borders when too far zoomed out for the chosen quality
+    let (max_zoom, outline_unclaimed) = border_detail(&settings.graphics.border_quality);
+    if zoom_level > max_zoom {
         return;
     }
 
@@ -227,8 +258,8 @@ pub fn render_nation_borders(
                 let neighbor = &province_storage.provinces[neighbor_id.value() as usize];
                 let neighbor_owner = neighbor.owner_entity;
 
-                // Draw border if different owner or no owner
-                if neighbor_owner.map_or(true, |n| n != owner) {
+                // Draw border if different owner, or no owner at higher quality
+                if neighbor_owner.map_or(outline_unclaimed, |n| n != owner) {
                     // Use centralized edge calculation from hexagon module
                     let (corner1, corner2) = crate::math::get_edge_positions_for_neighbor(
                         province.position,
@@ -249,7 +280,7 @@ pub fn render_nation_borders(
 /// System to spawn territory-spanning nation labels with dynamic sizing
 pub fn spawn_nation_labels(
     mut commands: Commands,
-    nations: Query<(Entity, &Nation, Option<&super::territory_analysis::TerritoryMetrics>)>,
+    nations: LabeledNationQuery,
     province_storage: Res<ProvinceStorage>,
     spatial_index: Res<crate::world::ProvincesSpatialIndex>,
     existing_labels: Query<Entity, With<NationLabel>>,
@@ -264,12 +295,14 @@ pub fn spawn_nation_labels(
     }
 
     // Create labels for each nation based on territory analysis
-    for (nation_entity, nation, metrics_opt) in nations.iter() {
+    for (nation_entity, nation, metrics_opt, governance, era) in nations.iter() {
+        let name = presented_name(nation, governance, era);
+
         // Use existing metrics or calculate new ones
         let metrics = if let Some(m) = metrics_opt {
             m.clone()
         } else {
-            if let Some(m) = super::territory_analysis::TerritoryMetrics::calculate(
+            if let Some(m) = TerritoryMetrics::calculate(
                 nation_entity,
                 &province_storage,
             ) {
@@ -297,12 +330,7 @@ pub fn spawn_nation_labels(
             for (idx, cluster) in metrics.clusters.iter().enumerate() {
                 if cluster.relative_size >= 0.2 {
                     let cluster_font_size = base_font_size * cluster.relative_size;
-                    let label_text = if idx == 0 {
-                        nation.name.clone()
-                    } else {
-                        // Secondary clusters get abbreviated names
-                        format!("{} (Colony)", &nation.name[..nation.name.len().min(10)])
-                    };
+                    let label_text = label_text(&name, idx == 0);
                     let base_opacity = if idx == 0 { 1.0 } else { 0.7 };
 
                     // Find non-colliding position for this cluster label
@@ -330,7 +358,10 @@ pub fn spawn_nation_labels(
                             label_position.y - 2.0,
                             149.0, // Just behind main text
                         )),
-                        NationLabelShadow, // Marker for cleanup
+                        NationLabelShadow {
+                            nation_id: nation_entity,
+                            is_primary: idx == 0,
+                        },
                     ));
 
                     // Spawn main text label
@@ -360,7 +391,7 @@ pub fn spawn_nation_labels(
         } else {
             // Single contiguous territory - find non-colliding position
             let (label_position, collision_opacity) = find_non_colliding_position(
-                &nation.name,
+                &name,
                 base_font_size,
                 metrics.centroid,
                 metrics.bounds,
@@ -371,7 +402,7 @@ pub fn spawn_nation_labels(
 
             // Spawn drop shadow first
             commands.spawn((
-                Text2d::new(&nation.name),
+                Text2d::new(&name),
                 TextFont {
                     font_size: base_font_size,
                     ..default()
@@ -382,12 +413,15 @@ pub fn spawn_nation_labels(
                     label_position.y - 2.0,
                     149.0, // Just behind main text
                 )),
-                NationLabelShadow, // Marker for cleanup
+                NationLabelShadow {
+                    nation_id: nation_entity,
+                    is_primary: true,
+                },
             ));
 
             // Spawn main text label
             commands.spawn((
-                Text2d::new(&nation.name),
+                Text2d::new(name),
                 TextFont {
                     font_size: base_font_size,
                     ..default()
@@ -411,6 +445,55 @@ pub fn spawn_nation_labels(
     }
 }
 
+/// Text shown on a nation's main or secondary (colony) label
+fn label_text(nation_name: &str, is_primary: bool) -> String {
+    if is_primary {
+        nation_name.to_string()
+    } else {
+        // Secondary clusters get abbreviated names
+        let abbreviated: String = nation_name.chars().take(10).collect();
+        format!("{} (Colony)", abbreviated)
+    }
+}
+
+/// Rewrite existing labels when a nation is renamed or enters a new era
+pub fn refresh_renamed_nation_labels(
+    mut renamed: MessageReader<super::governance::NationRenamed>,
+    nations: Query<(&Nation, Option<&Governance>, Option<&PresentationEra>)>,
+    new_eras: Query<Entity, Changed<PresentationEra>>,
+    mut label_query: Query<(&NationLabel, &mut Text2d), Without<NationLabelShadow>>,
+    mut shadow_query: Query<(&NationLabelShadow, &mut Text2d), Without<NationLabel>>,
+) {
+    let mut retitled: HashMap<Entity, String> = HashMap::new();
+    for event in renamed.read() {
+        let name = nations
+            .get(event.nation_entity)
+            .map_or_else(|_| event.new_name.clone(), |(nation, governance, era)| {
+                presented_name(nation, governance, era)
+            });
+        retitled.insert(event.nation_entity, name);
+    }
+    for entity in &new_eras {
+        if let Ok((nation, governance, era)) = nations.get(entity) {
+            retitled.insert(entity, presented_name(nation, governance, era));
+        }
+    }
+    if retitled.is_empty() {
+        return;
+    }
+
+    for (label, mut text) in &mut label_query {
+        if let Some(name) = retitled.get(&label.nation_id) {
+            text.0 = label_text(name, label.is_primary);
+        }
+    }
+    for (shadow, mut text) in &mut shadow_query {
+        if let Some(name) = retitled.get(&shadow.nation_id) {
+            text.0 = label_text(name, shadow.is_primary);
+        }
+    }
+}
+
 /// System to dynamically update nation label sizes based on camera zoom
 pub fn update_nation_label_sizes(
     camera_query: Query<(&Camera, &Transform), Changed<Transform>>,
@@ -472,15 +555,23 @@ pub fn update_nation_label_sizes(
 }
 
 /// System to hide/show labels based on zoom level (LOD)
+///
+/// Label density stretches or shrinks every zoom threshold, so denser maps
+/// keep smaller nations labeled further out.
 pub fn update_label_visibility(
-    camera_query: Query<(&Camera, &Transform), Changed<Transform>>,
+    camera_query: Query<(&Camera, Ref<Transform>)>,
+    settings: Res<GameSettings>,
     mut label_query: Query<(&NationLabel, &mut Visibility)>,
 ) {
     let Ok((_, camera_transform)) = camera_query.single() else {
         return;
     };
+    if !camera_transform.is_changed() && !settings.is_changed() {
+        return;
+    }
 
-    let zoom_level = camera_transform.translation.z.abs();
+    // Thresholds below are for the default density
+    let zoom_level = camera_transform.translation.z.abs() / settings.graphics.label_density.max(0.1);
 
     for (label, mut visibility) in label_query.iter_mut() {
         // Visibility thresholds based on nation size
@@ -524,4 +615,9 @@ pub struct NationLabel {
 /// Marker component for nation label shadow entities
 /// Used for cleanup to prevent memory leaks
 #[derive(Component)]
-pub struct NationLabelShadow;
+pub struct NationLabelShadow {
+    /// The nation whose label this shadows
+    pub nation_id: Entity,
+    /// Whether this shadows a main label or a secondary one
+    pub is_primary: bool,
+}
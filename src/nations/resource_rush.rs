@@ -0,0 +1,411 @@
+//! Resource rushes - gold and gem strikes on weakly held frontiers
+//!
+//! Prospectors work every settled province with a rich seam of gold or gems,
+//! and each year may strike it. A strike in a province its ruler holds
+//! firmly is simply worked. A strike where the hold is weak - a restless
+//! nation, or land it has not held long enough to call its own - sets off a
+//! rush: people pour in from the surrounding provinces, the boomtown swells
+//! past what the land could feed, and every nation with land nearby lays
+//! claim to the fields. Claimants weigh the fields when choosing whom to
+//! fight (see `war_triggers`), and hungry ones fight sooner.
+//!
+//! Seams run out. When a rush's reserves are spent the deposit is left
+//! poor, the boomtown's extra capacity goes, and the people it can no longer
+//! hold leave as displaced (`PopulationDisplaced`).
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
+use super::city_names::CityNames;
+use super::cores::ProvinceCores;
+use super::index::NationIndex;
+use super::refugees::PopulationDisplaced;
+use super::types::Nation;
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::math::Money;
+use crate::simulation::NewYearEvent;
+use crate::world::{Abundance, Province, ProvinceStorage, TerrainType, WorldSeed};
+
+/// Abundance of gold or gems that makes a seam worth a rush
+const RICH_DEPOSIT: u8 = 60;
+/// People a province needs before anyone goes prospecting
+const PROSPECTOR_POPULATION: u32 = 500;
+/// Yearly chance a rich seam in a settled province is struck
+const STRIKE_CHANCE: f32 = 0.01;
+/// Holder stability below which its grip on a province is weak
+const WEAK_HOLD_STABILITY: f32 = 0.5;
+/// Years a province must be held before the holder's grip is firm
+const FIRM_HOLD_YEARS: u16 = 50;
+/// Boom years a rush lasts at the richest seam; poorer seams run out sooner
+const MAX_BOOM_YEARS: u32 = 30;
+const MIN_BOOM_YEARS: u32 = 10;
+/// How far past its usual capacity a boomtown can swell
+const BOOMTOWN_CAPACITY: u32 = 4;
+/// Provinces around a strike that send people, in provinces
+const MIGRATION_RADIUS: usize = 4;
+/// Share of each nearby province's people who join the rush each boom year
+const MIGRATION_SHARE: f32 = 0.03;
+/// Provinces around a strike whose owners lay claim to it
+const CLAIM_RADIUS: usize = 3;
+/// Treasury the holder takes each boom year per point of abundance
+const RUSH_INCOME_PER_ABUNDANCE: f32 = 4.0;
+/// Treasury a firmly held strike yields once, per point of abundance
+const STRIKE_INCOME_PER_ABUNDANCE: f32 = 20.0;
+/// Share of the seam left once the rush has worked it out
+const DEPLETED_SHARE: f32 = 0.2;
+
+/// Mineral a rush is for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RushMineral {
+    Gold,
+    Gems,
+}
+
+impl RushMineral {
+    fn label(self) -> &'static str {
+        match self {
+            RushMineral::Gold => "gold",
+            RushMineral::Gems => "gems",
+        }
+    }
+
+    /// Adjective for the fields and strikes of this mineral
+    fn adjective(self) -> &'static str {
+        match self {
+            RushMineral::Gold => "gold",
+            RushMineral::Gems => "gem",
+        }
+    }
+
+    fn abundance(self, province: &Province) -> Abundance {
+        match self {
+            RushMineral::Gold => province.gold,
+            RushMineral::Gems => province.gems,
+        }
+    }
+
+    /// The richer of a province's gold and gems, if rich enough to rush for
+    fn of(province: &Province) -> Option<(RushMineral, Abundance)> {
+        let (mineral, abundance) = if province.gems >= province.gold {
+            (RushMineral::Gems, province.gems)
+        } else {
+            (RushMineral::Gold, province.gold)
+        };
+        (abundance.value() >= RICH_DEPOSIT).then_some((mineral, abundance))
+    }
+
+    fn deplete(self, province: &mut Province) {
+        let depleted = Abundance::new((self.abundance(province).value() as f32 * DEPLETED_SHARE) as u8);
+        match self {
+            RushMineral::Gold => province.gold = depleted,
+            RushMineral::Gems => province.gems = depleted,
+        }
+    }
+}
+
+/// A rush underway at one province
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResourceRush {
+    /// Province storage index of the strike
+    pub province: usize,
+    pub mineral: RushMineral,
+    /// Year the seam was struck
+    pub struck: u32,
+    /// Boom years left before the seam is worked out
+    pub reserves: u32,
+    /// Capacity of the province before the boom
+    pub base_capacity: u32,
+    /// People who have come for the rush
+    pub migrants: u32,
+    /// Nations with land nearby that claim the fields
+    pub claimants: BTreeSet<Entity>,
+}
+
+/// Rushes underway, and every seam already struck
+#[derive(Resource, Debug, Default)]
+pub struct ResourceRushes {
+    pub active: Vec<ResourceRush>,
+    /// Province indices whose rich seams have been struck
+    struck: HashSet<usize>,
+}
+
+impl ResourceRushes {
+    /// Rush provinces held by `holder` that `claimant` claims, as province ids
+    pub fn claims_against(&self, claimant: Entity, holder: Entity, provinces: &[Province]) -> Vec<u32> {
+        self.active
+            .iter()
+            .filter(|rush| rush.claimants.contains(&claimant))
+            .filter_map(|rush| provinces.get(rush.province))
+            .filter(|province| province.owner_entity == Some(holder))
+            .map(|province| province.id.value())
+            .collect()
+    }
+
+    /// Whether `nation` claims any rush it doesn't hold
+    pub fn is_claimant(&self, nation: Entity) -> bool {
+        self.active.iter().any(|rush| rush.claimants.contains(&nation))
+    }
+}
+
+/// Forget rushes from a previous world
+pub fn clear_resource_rushes(mut rushes: ResMut<ResourceRushes>) {
+    *rushes = ResourceRushes::default();
+}
+
+/// Boom years a seam of the given abundance supports
+pub fn boom_years(abundance: Abundance) -> u32 {
+    let richness = (abundance.value().saturating_sub(RICH_DEPOSIT)) as f32 / (100 - RICH_DEPOSIT) as f32;
+    MIN_BOOM_YEARS + ((MAX_BOOM_YEARS - MIN_BOOM_YEARS) as f32 * richness).round() as u32
+}
+
+/// Land provinces within `radius` steps of `origin`, nearest first, excluding it
+fn provinces_around(provinces: &[Province], origin: usize, radius: usize) -> Vec<usize> {
+    let mut visited: HashSet<usize> = HashSet::from([origin]);
+    let mut frontier: VecDeque<(usize, usize)> = VecDeque::from([(origin, 0)]);
+    let mut around = Vec::new();
+    while let Some((index, distance)) = frontier.pop_front() {
+        if distance >= radius {
+            continue;
+        }
+        let Some(province) = provinces.get(index) else {
+            continue;
+        };
+        for &next in province.neighbor_indices.iter().flatten() {
+            let is_land = provinces
+                .get(next)
+                .is_some_and(|province| province.terrain != TerrainType::Ocean);
+            if is_land && visited.insert(next) {
+                around.push(next);
+                frontier.push_back((next, distance + 1));
+            }
+        }
+    }
+    around
+}
+
+/// Prospectors strike rich seams; rushes draw people, pay their holders, and go bust
+pub fn run_resource_rushes(
+    mut rushes: ResMut<ResourceRushes>,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    mut displaced_events: MessageWriter<PopulationDisplaced>,
+    province_storage: Option<ResMut<ProvinceStorage>>,
+    world_seed: Option<Res<WorldSeed>>,
+    cores: Res<ProvinceCores>,
+    city_names: Res<CityNames>,
+    nation_index: Res<NationIndex>,
+    mut nations_query: Query<&mut Nation>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+    let Some(mut storage) = province_storage else {
+        return;
+    };
+
+    // Seeded by world and year so identical runs strike identical seams
+    let seed = world_seed.map_or(0, |seed| seed.0) as u64;
+    let mut rng = StdRng::seed_from_u64((seed << 32) ^ year as u64 ^ 0x6f6c_645f_7275_7368);
+    let place_name = |index: usize| {
+        city_names
+            .get(index)
+            .map_or_else(|| "the hills".to_string(), |city| city.name.clone())
+    };
+    let nation_name = |nations: &Query<&mut Nation>, entity: Entity| {
+        nations
+            .get(entity)
+            .map_or_else(|_| "a fallen realm".to_string(), |nation| nation.name.clone())
+    };
+    let ids =
+        |entities: &[Entity]| -> Vec<_> { entities.iter().filter_map(|&entity| nation_index.id(entity)).collect() };
+
+    // Prospecting: every settled, unstruck rich seam has a chance each year
+    let mut strikes = Vec::new();
+    for (index, province) in storage.provinces.iter().enumerate() {
+        if province.population < PROSPECTOR_POPULATION || province.owner_entity.is_none() {
+            continue;
+        }
+        let Some((mineral, abundance)) = RushMineral::of(province) else {
+            continue;
+        };
+        if rushes.struck.contains(&index) {
+            continue;
+        }
+        if rng.r#gen::<f32>() < STRIKE_CHANCE {
+            strikes.push((index, mineral, abundance));
+        }
+    }
+
+    for (index, mineral, abundance) in strikes {
+        rushes.struck.insert(index);
+        let Some(holder) = storage.provinces[index].owner_entity else {
+            continue;
+        };
+        let Ok(mut nation) = nations_query.get_mut(holder) else {
+            continue;
+        };
+        let held_firmly = cores
+            .get(index)
+            .is_some_and(|core| core.ruler == Some(holder) && core.years_ruled >= FIRM_HOLD_YEARS);
+
+        if held_firmly && nation.stability >= WEAK_HOLD_STABILITY {
+            nation.treasury += Money::from_f32(abundance.value() as f32 * STRIKE_INCOME_PER_ABUNDANCE);
+            chronicle.write(ChronicleEvent {
+                category: ChronicleCategory::Trade,
+                text: format!(
+                    "{} strikes {} near {} and works the seam under royal licence",
+                    nation.name,
+                    mineral.label(),
+                    place_name(index)
+                ),
+                nations: ids(&[holder]),
+            });
+            continue;
+        }
+        let holder_name = nation.name.clone();
+
+        // A weak hold: the rush is on, and the neighbors want the fields
+        let claimants: BTreeSet<Entity> = provinces_around(&storage.provinces, index, CLAIM_RADIUS)
+            .into_iter()
+            .filter_map(|nearby| storage.provinces[nearby].owner_entity)
+            .filter(|&owner| owner != holder)
+            .collect();
+        let province = &mut storage.provinces[index];
+        let base_capacity = province.max_population;
+        province.max_population = base_capacity.saturating_mul(BOOMTOWN_CAPACITY);
+        province.mark_dirty();
+
+        chronicle.write(ChronicleEvent {
+            category: ChronicleCategory::Trade,
+            text: format!(
+                "A {} strike at {} on {}'s weakly held frontier sets off a rush",
+                mineral.adjective(),
+                place_name(index),
+                holder_name
+            ),
+            nations: ids(&[holder]),
+        });
+        if !claimants.is_empty() {
+            let disputants: Vec<Entity> = std::iter::once(holder).chain(claimants.iter().copied()).collect();
+            let names: Vec<String> = claimants
+                .iter()
+                .map(|&claimant| nation_name(&nations_query, claimant))
+                .collect();
+            chronicle.write(ChronicleEvent {
+                category: ChronicleCategory::Politics,
+                text: format!(
+                    "{} dispute {}'s claim to the {} fields of {}",
+                    names.join(", "),
+                    holder_name,
+                    mineral.adjective(),
+                    place_name(index)
+                ),
+                nations: ids(&disputants),
+            });
+        }
+
+        rushes.active.push(ResourceRush {
+            province: index,
+            mineral,
+            struck: year,
+            reserves: boom_years(abundance),
+            base_capacity,
+            migrants: 0,
+            claimants,
+        });
+    }
+
+    // Boom: people pour in and the holder takes its cut, until the seam runs out
+    let mut active = std::mem::take(&mut rushes.active);
+    active.retain_mut(|rush| {
+        let holder = storage
+            .provinces
+            .get(rush.province)
+            .and_then(|province| province.owner_entity);
+        if let Some(holder) = holder {
+            rush.claimants.remove(&holder);
+        }
+
+        if rush.reserves > 0 {
+            rush.reserves -= 1;
+            let room = {
+                let boomtown = &storage.provinces[rush.province];
+                boomtown.max_population.saturating_sub(boomtown.population)
+            };
+            let mut arrivals = 0u32;
+            for nearby in provinces_around(&storage.provinces, rush.province, MIGRATION_RADIUS) {
+                if arrivals >= room {
+                    break;
+                }
+                let source = &mut storage.provinces[nearby];
+                let leaving = ((source.population as f32 * MIGRATION_SHARE) as u32).min(room - arrivals);
+                if leaving == 0 {
+                    continue;
+                }
+                source.set_population(source.population - leaving);
+                arrivals += leaving;
+            }
+            let boomtown = &mut storage.provinces[rush.province];
+            boomtown.set_population(boomtown.population.saturating_add(arrivals));
+            rush.migrants = rush.migrants.saturating_add(arrivals);
+
+            let abundance = rush.mineral.abundance(boomtown).value();
+            if let Some(mut nation) = holder.and_then(|holder| nations_query.get_mut(holder).ok()) {
+                nation.treasury += Money::from_f32(abundance as f32 * RUSH_INCOME_PER_ABUNDANCE);
+            }
+            return true;
+        }
+
+        // Bust: the seam is spent and the boomtown can't hold its people
+        let boomtown = &mut storage.provinces[rush.province];
+        rush.mineral.deplete(boomtown);
+        boomtown.max_population = rush.base_capacity;
+        let excess = boomtown.population.saturating_sub(rush.base_capacity);
+        boomtown.set_population(rush.base_capacity.min(boomtown.population));
+        boomtown.mark_dirty();
+        if excess > 0 {
+            displaced_events.write(PopulationDisplaced {
+                province: rush.province,
+                people: excess,
+            });
+        }
+        chronicle.write(ChronicleEvent {
+            category: ChronicleCategory::Trade,
+            text: format!(
+                "The {} fields of {} are worked out after {} years; {} people leave the emptying boomtown",
+                rush.mineral.adjective(),
+                place_name(rush.province),
+                year.saturating_sub(rush.struck),
+                excess
+            ),
+            nations: ids(holder.as_slice()),
+        });
+        false
+    });
+    rushes.active = active;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn richer_seams_boom_longer() {
+        assert_eq!(boom_years(Abundance::new(RICH_DEPOSIT)), MIN_BOOM_YEARS);
+        assert_eq!(boom_years(Abundance::new(100)), MAX_BOOM_YEARS);
+        assert!(boom_years(Abundance::new(80)) > boom_years(Abundance::new(70)));
+
+        let mut province = Province {
+            gold: Abundance::new(90),
+            gems: Abundance::new(40),
+            ..Province::default()
+        };
+        let (mineral, abundance) = RushMineral::of(&province).map_or((RushMineral::Gems, 0), |(m, a)| (m, a.value()));
+        assert_eq!((mineral, abundance), (RushMineral::Gold, 90));
+        mineral.deplete(&mut province);
+        assert_eq!(province.gold.value(), 18);
+        assert!(RushMineral::of(&province).is_none());
+    }
+}
@@ -0,0 +1,131 @@
+//! Saved nation state - the simulation components nations pick up over the years
+//!
+//! Corruption, forts, logistics, doctrine and the rest live on components the
+//! yearly systems add to nations as they need them. A save records them by
+//! stable id and loading puts them back on the rebuilt nation entities; a
+//! component a save does not carry is added afresh by the system that owns it.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::bureaucracy::Bureaucracy;
+use super::corruption::Corruption;
+use super::fortifications::FortNetwork;
+use super::insurance::InsuranceHouse;
+use super::logistics::{Logistics, SavedLogistics};
+use super::new_world::ColonialEmpire;
+use super::technology::Research;
+use super::types::NationId;
+use super::warfare::{ArmyComposition, CampaignAttrition, MilitaryDoctrine};
+
+/// Components read from every nation when saving
+pub type NationStateData = (
+    &'static NationId,
+    Option<&'static Corruption>,
+    Option<&'static FortNetwork>,
+    Option<&'static Logistics>,
+    Option<&'static MilitaryDoctrine>,
+    Option<&'static CampaignAttrition>,
+    Option<&'static ArmyComposition>,
+    Option<&'static Bureaucracy>,
+    Option<&'static Research>,
+    Option<&'static InsuranceHouse>,
+    Option<&'static ColonialEmpire>,
+);
+
+/// A nation's simulation components as written to a save, by stable id
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedNationState {
+    pub nation: NationId,
+    #[serde(default)]
+    pub corruption: Option<Corruption>,
+    #[serde(default)]
+    pub forts: Option<FortNetwork>,
+    #[serde(default)]
+    pub logistics: Option<SavedLogistics>,
+    #[serde(default)]
+    pub doctrine: Option<MilitaryDoctrine>,
+    #[serde(default)]
+    pub attrition: Option<CampaignAttrition>,
+    #[serde(default)]
+    pub composition: Option<ArmyComposition>,
+    #[serde(default)]
+    pub bureaucracy: Option<Bureaucracy>,
+    #[serde(default)]
+    pub research: Option<Research>,
+    #[serde(default)]
+    pub insurance: Option<InsuranceHouse>,
+    #[serde(default)]
+    pub colonies: Option<ColonialEmpire>,
+}
+
+impl SavedNationState {
+    /// Stable-id form of every nation's components, for saving
+    pub fn collect(nations_query: &Query<NationStateData>, id_of: impl Fn(Entity) -> Option<NationId>) -> Vec<Self> {
+        nations_query
+            .iter()
+            .map(|state| {
+                let (
+                    nation_id,
+                    corruption,
+                    forts,
+                    logistics,
+                    doctrine,
+                    attrition,
+                    composition,
+                    bureaucracy,
+                    research,
+                    insurance,
+                    colonies,
+                ) = state;
+                Self {
+                    nation: *nation_id,
+                    corruption: corruption.cloned(),
+                    forts: forts.cloned(),
+                    logistics: logistics.map(|logistics| logistics.to_saved(&id_of)),
+                    doctrine: doctrine.cloned(),
+                    attrition: attrition.cloned(),
+                    composition: composition.cloned(),
+                    bureaucracy: bureaucracy.cloned(),
+                    research: research.cloned(),
+                    insurance: insurance.cloned(),
+                    colonies: colonies.cloned(),
+                }
+            })
+            .collect()
+    }
+
+    /// Put the saved components back on a restored nation
+    pub fn restore(&self, nation: &mut EntityCommands, entity_of: impl Fn(NationId) -> Option<Entity>) {
+        if let Some(corruption) = &self.corruption {
+            nation.insert(corruption.clone());
+        }
+        if let Some(forts) = &self.forts {
+            nation.insert(forts.clone());
+        }
+        if let Some(logistics) = &self.logistics {
+            nation.insert(logistics.restore(entity_of));
+        }
+        if let Some(doctrine) = &self.doctrine {
+            nation.insert(doctrine.clone());
+        }
+        if let Some(attrition) = &self.attrition {
+            nation.insert(attrition.clone());
+        }
+        if let Some(composition) = &self.composition {
+            nation.insert(composition.clone());
+        }
+        if let Some(bureaucracy) = &self.bureaucracy {
+            nation.insert(bureaucracy.clone());
+        }
+        if let Some(research) = &self.research {
+            nation.insert(research.clone());
+        }
+        if let Some(insurance) = &self.insurance {
+            nation.insert(insurance.clone());
+        }
+        if let Some(colonies) = &self.colonies {
+            nation.insert(colonies.clone());
+        }
+    }
+}
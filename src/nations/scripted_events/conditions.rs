@@ -0,0 +1,176 @@
+//! Evaluating triggers and weights against a nation, province, or ruler
+
+use super::types::{
+    Condition, EventDefinition, NationStat, ProvinceStat, RulerStat, ScriptedEventState, WeightModifier,
+};
+use crate::modding::ModSettings;
+use crate::nations::{House, Nation, NationId};
+use crate::world::Province;
+
+/// What an event is evaluated against
+#[derive(Clone, Copy)]
+pub struct EventContext<'a> {
+    pub nation_id: NationId,
+    pub nation: &'a Nation,
+    /// Provinces held and the people living in them
+    pub holdings: (u32, u64),
+    pub at_war: bool,
+    pub house: Option<&'a House>,
+    pub province: Option<&'a Province>,
+    pub year: u32,
+    pub state: &'a ScriptedEventState,
+    pub mod_settings: &'a ModSettings,
+}
+
+impl EventContext<'_> {
+    pub fn nation_stat(&self, stat: NationStat) -> f32 {
+        let nation = self.nation;
+        match stat {
+            NationStat::Treasury => nation.treasury.to_f32(),
+            NationStat::TaxRate => nation.tax_rate,
+            NationStat::MilitaryStrength => nation.military_strength,
+            NationStat::Stability => nation.stability,
+            NationStat::TechnologyLevel => nation.technology_level as f32,
+            NationStat::Aggression => nation.personality.aggression,
+            NationStat::Expansionism => nation.personality.expansionism,
+            NationStat::Diplomacy => nation.personality.diplomacy,
+            NationStat::Mercantilism => nation.personality.mercantilism,
+            NationStat::Provinces => self.holdings.0 as f32,
+            NationStat::Population => self.holdings.1 as f32,
+        }
+    }
+
+    fn province_stat(&self, stat: ProvinceStat) -> Option<f32> {
+        let province = self.province?;
+        Some(match stat {
+            ProvinceStat::Population => province.population as f32,
+            ProvinceStat::Agriculture => province.agriculture.value(),
+            ProvinceStat::Elevation => province.elevation.value(),
+        })
+    }
+
+    fn ruler_stat(&self, stat: RulerStat) -> Option<f32> {
+        let house = self.house?;
+        let ruler = &house.ruler;
+        Some(match stat {
+            RulerStat::Age => ruler.age as f32,
+            RulerStat::YearsRuling => ruler.years_ruling as f32,
+            RulerStat::Competence => ruler.personality.competence,
+            RulerStat::Ambition => ruler.personality.ambition,
+            RulerStat::Temperament => ruler.personality.temperament,
+            RulerStat::Honor => ruler.personality.honor,
+            RulerStat::Legitimacy => house.legitimacy,
+            RulerStat::Prestige => house.prestige,
+        })
+    }
+
+    /// Whether a condition holds here
+    pub fn holds(&self, condition: &Condition) -> bool {
+        match condition {
+            Condition::All(conditions) => conditions.iter().all(|c| self.holds(c)),
+            Condition::Any(conditions) => conditions.iter().any(|c| self.holds(c)),
+            Condition::Not(condition) => !self.holds(condition),
+            Condition::Nation(stat, compare) => compare.holds(self.nation_stat(*stat)),
+            Condition::Province(stat, compare) => self.province_stat(*stat).is_some_and(|v| compare.holds(v)),
+            Condition::Ruler(stat, compare) => self.ruler_stat(*stat).is_some_and(|v| compare.holds(v)),
+            Condition::Year(compare) => compare.holds(self.year as f32),
+            Condition::AtWar => self.at_war,
+            Condition::Culture(culture) => self.nation.culture == *culture,
+            Condition::Terrain(terrain) => self.province.is_some_and(|p| p.terrain == *terrain),
+            Condition::Happened(event) => self.state.last_fired(event, self.nation_id).is_some(),
+            Condition::Setting(mod_id, key, compare) => {
+                self.mod_settings.number(mod_id, key).is_some_and(|v| compare.holds(v))
+            }
+        }
+    }
+
+    /// `base` scaled by every modifier whose condition holds
+    pub fn weigh(&self, base: f32, modifiers: &[WeightModifier]) -> f32 {
+        modifiers
+            .iter()
+            .filter(|modifier| self.holds(&modifier.when))
+            .fold(base, |weight, modifier| weight * modifier.factor)
+            .max(0.0)
+    }
+
+    /// Whether the event's trigger holds here
+    pub fn triggers(&self, event: &EventDefinition) -> bool {
+        event.trigger.as_ref().is_none_or(|trigger| self.holds(trigger))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::types::Compare;
+    use super::*;
+    use crate::math::Money;
+    use crate::name_generator::Culture;
+    use crate::nations::NationPersonality;
+    use crate::world::ProvinceId;
+    use bevy::prelude::*;
+
+    #[test]
+    fn triggers_read_the_scope_they_are_given() {
+        let nation = Nation {
+            name: "Velm".to_string(),
+            adjective: "Velmish".to_string(),
+            color: Color::WHITE,
+            capital_province: ProvinceId::new(0),
+            treasury: Money::from_int(500),
+            tax_rate: 0.2,
+            military_strength: 40.0,
+            stability: 0.3,
+            culture: Culture::Western,
+            technology_level: 2,
+            personality: NationPersonality::balanced(),
+        };
+        let mut state = ScriptedEventState::default();
+        state
+            .last_fired
+            .entry("famine".to_string())
+            .or_default()
+            .insert(NationId::new(1), 1010);
+        let mut mod_settings = ModSettings::default();
+        mod_settings
+            .values
+            .set("famine", "severity", crate::modding::ModSettingValue::Number(1.5));
+        let context = EventContext {
+            nation_id: NationId::new(1),
+            nation: &nation,
+            holdings: (12, 90_000),
+            at_war: false,
+            house: None,
+            province: None,
+            year: 1020,
+            state: &state,
+            mod_settings: &mod_settings,
+        };
+
+        let trigger: Condition = ron::from_str(
+            "All([Nation(Stability, Below(0.4)), Nation(Provinces, Between(10.0, 20.0)), Not(AtWar), \
+             Happened(\"famine\"), Setting(\"famine\", \"severity\", Above(1.0))])",
+        )
+        .unwrap_or_else(|e| panic!("trigger failed to parse: {}", e));
+        assert!(context.holds(&trigger));
+        // Settings of mods that aren't enabled never hold
+        let unset: Condition = ron::from_str("Setting(\"floods\", \"severity\", Above(0.0))")
+            .unwrap_or_else(|e| panic!("condition failed to parse: {}", e));
+        assert!(!context.holds(&unset));
+        // Conditions on a scope the event lacks are false
+        assert!(!context.holds(&Condition::Province(ProvinceStat::Population, Compare::Above(0.0))));
+        assert!(context.holds(&Condition::Not(Box::new(Condition::Ruler(
+            RulerStat::Age,
+            Compare::Above(0.0)
+        )))));
+
+        let doubled = WeightModifier {
+            factor: 2.0,
+            when: Condition::Culture(Culture::Western),
+        };
+        let ignored = WeightModifier {
+            factor: 10.0,
+            when: Condition::AtWar,
+        };
+        assert_eq!(context.weigh(0.1, &[doubled, ignored]), 0.2);
+    }
+}
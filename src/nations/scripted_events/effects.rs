@@ -0,0 +1,145 @@
+//! Applying the effects of a chosen option
+
+use super::types::{Effect, EventModifier, NationStat, PendingEvent, ScriptedEventState};
+use crate::math::{Fixed32, Money};
+use crate::nations::{House, Nation, NationId};
+use crate::world::ProvinceStorage;
+
+/// What an option's effects act on
+pub struct EffectTarget<'a> {
+    pub nation_id: NationId,
+    pub nation: &'a mut Nation,
+    pub house: Option<&'a mut House>,
+    pub storage: Option<&'a mut ProvinceStorage>,
+    /// Provinces a population effect reaches, as indices into `storage`
+    pub provinces: &'a [usize],
+}
+
+/// Change a nation stat, keeping it within its range
+///
+/// Provinces and population are counted from the map and cannot be changed
+/// this way.
+pub fn adjust_stat(nation: &mut Nation, stat: NationStat, amount: f32) {
+    match stat {
+        NationStat::Treasury => nation.treasury += Money::from_f32(amount),
+        NationStat::TaxRate => nation.tax_rate = (nation.tax_rate + amount).clamp(0.0, 1.0),
+        NationStat::MilitaryStrength => nation.military_strength = (nation.military_strength + amount).max(0.0),
+        NationStat::Stability => nation.stability = (nation.stability + amount).clamp(0.0, 1.0),
+        NationStat::TechnologyLevel => {
+            nation.technology_level = (nation.technology_level as f32 + amount).round().max(0.0) as u32;
+        }
+        NationStat::Aggression => {
+            nation.personality.aggression = (nation.personality.aggression + amount).clamp(-1.0, 1.0);
+        }
+        NationStat::Expansionism => {
+            nation.personality.expansionism = (nation.personality.expansionism + amount).clamp(-1.0, 1.0);
+        }
+        NationStat::Diplomacy => {
+            nation.personality.diplomacy = (nation.personality.diplomacy + amount).clamp(-1.0, 1.0);
+        }
+        NationStat::Mercantilism => {
+            nation.personality.mercantilism = (nation.personality.mercantilism + amount).clamp(-1.0, 1.0);
+        }
+        NationStat::Provinces | NationStat::Population => {}
+    }
+}
+
+/// Apply an option's effects in order
+pub fn apply_effects(
+    effects: &[Effect],
+    target: &mut EffectTarget,
+    state: &mut ScriptedEventState,
+    source: &str,
+    year: u32,
+) {
+    for effect in effects {
+        match effect {
+            Effect::Treasury(amount) => adjust_stat(target.nation, NationStat::Treasury, *amount),
+            Effect::TreasuryShare(share) => {
+                target.nation.treasury += target.nation.treasury.abs() * Fixed32::from_f32(*share);
+            }
+            Effect::Stability(amount) => adjust_stat(target.nation, NationStat::Stability, *amount),
+            Effect::TaxRate(amount) => adjust_stat(target.nation, NationStat::TaxRate, *amount),
+            Effect::MilitaryStrength(amount) => adjust_stat(target.nation, NationStat::MilitaryStrength, *amount),
+            Effect::Legitimacy(amount) => {
+                if let Some(house) = target.house.as_deref_mut() {
+                    house.legitimacy = (house.legitimacy + amount).clamp(0.0, 1.0);
+                }
+            }
+            Effect::Population(share) => {
+                if let Some(storage) = target.storage.as_deref_mut() {
+                    for &index in target.provinces {
+                        if let Some(province) = storage.provinces.get_mut(index) {
+                            let population = province.population as f32 * (1.0 + share).max(0.0);
+                            province.set_population(population.round() as u32);
+                        }
+                    }
+                }
+            }
+            Effect::Modifier { stat, amount, years } => {
+                adjust_stat(target.nation, *stat, *amount);
+                state.modifiers.push(EventModifier {
+                    nation: target.nation_id,
+                    source: source.to_string(),
+                    stat: *stat,
+                    amount: *amount,
+                    expires_year: year + years,
+                });
+            }
+            Effect::TriggerEvent { event, delay_years } => state.pending.push(PendingEvent {
+                event: event.clone(),
+                nation: target.nation_id,
+                due_year: year + delay_years,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::name_generator::Culture;
+    use crate::nations::NationPersonality;
+    use crate::world::ProvinceId;
+    use bevy::prelude::*;
+
+    #[test]
+    fn effects_clamp_stats_and_record_modifiers_and_chains() {
+        let mut nation = Nation {
+            name: "Velm".to_string(),
+            adjective: "Velmish".to_string(),
+            color: Color::WHITE,
+            capital_province: ProvinceId::new(0),
+            treasury: Money::from_int(200),
+            tax_rate: 0.2,
+            military_strength: 40.0,
+            stability: 0.9,
+            culture: Culture::Western,
+            technology_level: 2,
+            personality: NationPersonality::balanced(),
+        };
+        let mut state = ScriptedEventState::default();
+        let effects: Vec<Effect> = ron::from_str(
+            "[TreasuryShare(-0.5), Stability(0.5), Modifier(stat: MilitaryStrength, amount: -50.0, years: 5), \
+             TriggerEvent(event: \"aftermath\", delay_years: 2)]",
+        )
+        .unwrap_or_else(|e| panic!("effects failed to parse: {}", e));
+
+        let mut target = EffectTarget {
+            nation_id: NationId::new(3),
+            nation: &mut nation,
+            house: None,
+            storage: None,
+            provinces: &[],
+        };
+        apply_effects(&effects, &mut target, &mut state, "riots", 1100);
+
+        assert_eq!(nation.treasury, Money::from_int(100));
+        assert_eq!(nation.stability, 1.0);
+        assert_eq!(nation.military_strength, 0.0);
+        assert_eq!(state.modifiers.len(), 1);
+        assert_eq!(state.modifiers[0].expires_year, 1105);
+        assert_eq!(state.pending[0].due_year, 1102);
+        assert_eq!(state.pending[0].nation, NationId::new(3));
+    }
+}
@@ -0,0 +1,24 @@
+//! Scripted events - Gateway Module
+//!
+//! Modders define events in RON data files: a trigger and weights deciding
+//! when an event happens, and options whose effects change the nation, a
+//! province, or the ruler. Effects can impose timed modifiers and queue
+//! follow-up events. Each year every event is considered for every nation,
+//! and a nation that meets one chooses among its options by their weights.
+//! Text is looked up in the localization tables. Events from mods run in
+//! their mod's sandbox: an event needing a capability the mod wasn't
+//! granted never happens, and the attempt is reported.
+//!
+//! This is a pure gateway module - all implementation lives in submodules.
+
+mod conditions;
+mod effects;
+mod sandbox;
+mod systems;
+mod types;
+
+pub use systems::{rebuild_scripted_events, reset_scripted_event_state, run_scripted_events};
+pub use types::{
+    EventDefinition, EventModifier, EventOption, EventScope, PendingEvent, ScriptedEventFired, ScriptedEventState,
+    ScriptedEvents,
+};
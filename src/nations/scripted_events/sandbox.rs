@@ -0,0 +1,113 @@
+//! Capabilities an event needs, checked against what its mod was granted
+
+use std::collections::BTreeSet;
+
+use super::types::{Condition, Effect, EventDefinition, NationStat};
+use crate::modding::{ModCapability, ModSandbox};
+
+/// Capability needed to change a nation stat, if any
+fn modify_capability(stat: NationStat) -> Option<ModCapability> {
+    match stat {
+        NationStat::Treasury | NationStat::TaxRate | NationStat::TechnologyLevel => Some(ModCapability::ModifyEconomy),
+        NationStat::Stability => Some(ModCapability::ModifyPolitics),
+        NationStat::MilitaryStrength => Some(ModCapability::ModifyMilitary),
+        NationStat::Aggression | NationStat::Expansionism | NationStat::Diplomacy | NationStat::Mercantilism => {
+            Some(ModCapability::ModifyDiplomacy)
+        }
+        // Counted from the map; modifiers on them do nothing
+        NationStat::Provinces | NationStat::Population => None,
+    }
+}
+
+fn condition_needs(condition: &Condition, needs: &mut BTreeSet<ModCapability>) {
+    match condition {
+        Condition::All(conditions) | Condition::Any(conditions) => {
+            for condition in conditions {
+                condition_needs(condition, needs);
+            }
+        }
+        Condition::Not(condition) => condition_needs(condition, needs),
+        Condition::Nation(NationStat::Treasury | NationStat::TaxRate, _) => {
+            needs.insert(ModCapability::ReadEconomy);
+        }
+        _ => {}
+    }
+}
+
+fn effect_needs(effect: &Effect, needs: &mut BTreeSet<ModCapability>) {
+    let capability = match effect {
+        Effect::Treasury(_) | Effect::TreasuryShare(_) | Effect::TaxRate(_) => Some(ModCapability::ModifyEconomy),
+        Effect::Stability(_) | Effect::Legitimacy(_) => Some(ModCapability::ModifyPolitics),
+        Effect::MilitaryStrength(_) => Some(ModCapability::ModifyMilitary),
+        Effect::Population(_) => Some(ModCapability::ModifyPopulation),
+        Effect::Modifier { stat, .. } => modify_capability(*stat),
+        Effect::TriggerEvent { .. } => Some(ModCapability::SpawnEvents),
+    };
+    needs.extend(capability);
+}
+
+/// Everything an event reads or changes that a mod must be granted
+///
+/// Defining an event at all spawns events, so every event needs that.
+pub fn required_capabilities(event: &EventDefinition) -> BTreeSet<ModCapability> {
+    let mut needs = BTreeSet::from([ModCapability::SpawnEvents]);
+    let conditions = event
+        .trigger
+        .iter()
+        .chain(event.weight.iter().map(|modifier| &modifier.when))
+        .chain(
+            event
+                .options
+                .iter()
+                .flat_map(|option| option.ai_weight_modifiers.iter().map(|modifier| &modifier.when)),
+        );
+    for condition in conditions {
+        condition_needs(condition, &mut needs);
+    }
+    for effect in event.options.iter().flat_map(|option| &option.effects) {
+        effect_needs(effect, &mut needs);
+    }
+    needs
+}
+
+/// Capabilities an event needs that its sandbox doesn't grant
+pub fn missing_capabilities(event: &EventDefinition, sandbox: &ModSandbox) -> Vec<ModCapability> {
+    required_capabilities(event)
+        .into_iter()
+        .filter(|&capability| !sandbox.allows(capability))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_need_what_their_triggers_read_and_effects_change() {
+        let event: EventDefinition = ron::from_str(
+            "(title: \"t\", text: \"t\", trigger: Some(Not(Nation(Treasury, Below(10.0)))), \
+             options: [(text: \"o\", effects: [Stability(0.1), Modifier(stat: Diplomacy, amount: 0.2, years: 3)])])",
+        )
+        .unwrap_or_else(|e| panic!("event failed to parse: {}", e));
+
+        let needs: Vec<_> = required_capabilities(&event).into_iter().collect();
+        assert_eq!(
+            needs,
+            vec![
+                ModCapability::ReadEconomy,
+                ModCapability::ModifyPolitics,
+                ModCapability::ModifyDiplomacy,
+                ModCapability::SpawnEvents,
+            ]
+        );
+
+        let sandbox = ModSandbox {
+            mod_id: "intrigue".to_string(),
+            granted: vec![ModCapability::SpawnEvents, ModCapability::ModifyPolitics],
+        };
+        assert_eq!(
+            missing_capabilities(&event, &sandbox),
+            vec![ModCapability::ReadEconomy, ModCapability::ModifyDiplomacy]
+        );
+    }
+}
@@ -0,0 +1,328 @@
+//! Loading event definitions and running them once a year
+
+use bevy::prelude::*;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+
+use super::conditions::EventContext;
+use super::effects::{adjust_stat, apply_effects, EffectTarget};
+use super::sandbox::missing_capabilities;
+use super::types::{EventDefinition, EventScope, ScriptedEventFired, ScriptedEventState, ScriptedEvents};
+use crate::ai::{decision_rng, sample_weighted, DecisionDomain};
+use crate::chronicle::ChronicleEvent;
+use crate::modding::{Localization, ModManager, ModSettings, ModViolation};
+use crate::nations::{CityNames, House, Nation, NationId, NationIndex, ParticipatesInWar};
+use crate::relationships::RulesOver;
+use crate::resources::WorldSeed;
+use crate::simulation::NewYearEvent;
+use crate::world::ProvinceStorage;
+
+/// An event chosen to happen this year, with the option the nation takes
+struct Firing {
+    event: String,
+    nation: Entity,
+    nation_id: NationId,
+    province: Option<usize>,
+    option: usize,
+}
+
+/// Rebuild the event list from the merged mod configuration
+pub fn rebuild_scripted_events(mut events: ResMut<ScriptedEvents>, mods: Option<Res<ModManager>>) {
+    if !events.is_added() && !mods.as_ref().is_some_and(|mods| mods.is_changed()) {
+        return;
+    }
+
+    let mut configured: Vec<_> = mods
+        .as_ref()
+        .map(|mods| mods.get_config().events.clone().into_iter().collect())
+        .unwrap_or_default();
+    configured.sort_by(|(a, _), (b, _)| a.cmp(b));
+    info!("Loaded {} scripted events", configured.len());
+    events.events = configured;
+    events.sandboxes = mods.map(|mods| mods.event_sandboxes.clone()).unwrap_or_default();
+}
+
+/// A new world starts with no event history
+///
+/// Loading a save re-inserts the saved state after this runs.
+pub fn reset_scripted_event_state(mut state: ResMut<ScriptedEventState>) {
+    *state = ScriptedEventState::default();
+}
+
+/// Stable number for an event id, so each event rolls its own dice
+fn event_subject(id: &str) -> u64 {
+    id.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Decide whether an event happens in this context, and which option is taken
+///
+/// Queued events skip the cooldown and the chance roll; their trigger must
+/// still hold.
+fn decide(
+    id: &str,
+    event: &EventDefinition,
+    context: EventContext,
+    owned: &[usize],
+    storage: Option<&ProvinceStorage>,
+    queued: bool,
+    seed: u32,
+) -> Option<(Option<usize>, usize)> {
+    if !queued && !context.state.may_fire(id, event, context.nation_id, context.year) {
+        return None;
+    }
+    let mut rng = decision_rng(
+        seed,
+        DecisionDomain::Governance,
+        context.nation_id.value(),
+        event_subject(id),
+        context.year,
+    );
+
+    let (context, province) = match event.scope {
+        EventScope::Province => {
+            let storage = storage?;
+            let candidates: Vec<usize> = owned
+                .iter()
+                .copied()
+                .filter(|&index| {
+                    storage.provinces.get(index).is_some_and(|province| {
+                        EventContext {
+                            province: Some(province),
+                            ..context
+                        }
+                        .triggers(event)
+                    })
+                })
+                .collect();
+            let index = *candidates.choose(&mut rng)?;
+            let context = EventContext {
+                province: storage.provinces.get(index),
+                ..context
+            };
+            (context, Some(index))
+        }
+        EventScope::Character if context.house.is_none() => return None,
+        EventScope::Nation | EventScope::Character => {
+            if !context.triggers(event) {
+                return None;
+            }
+            (context, None)
+        }
+    };
+
+    if !queued && rng.r#gen::<f32>() >= context.weigh(event.chance, &event.weight) {
+        return None;
+    }
+
+    // An event whose options all weigh nothing does not happen
+    let options: Vec<(usize, f32)> = event
+        .options
+        .iter()
+        .enumerate()
+        .map(|(index, option)| (index, context.weigh(option.ai_weight, &option.ai_weight_modifiers)))
+        .collect();
+    let option = *sample_weighted(&mut rng, &options)?;
+    Some((province, option))
+}
+
+/// Once a year, expire modifiers, then let due and scheduled events happen
+pub fn run_scripted_events(
+    mut year_events: MessageReader<NewYearEvent>,
+    events: Res<ScriptedEvents>,
+    mut state: ResMut<ScriptedEventState>,
+    localization: Res<Localization>,
+    world_seed: Option<Res<WorldSeed>>,
+    city_names: Option<Res<CityNames>>,
+    mut province_storage: Option<ResMut<ProvinceStorage>>,
+    nation_index: Res<NationIndex>,
+    mut nations_query: Query<(Entity, &NationId, &mut Nation, Option<&ParticipatesInWar>)>,
+    mut houses_query: Query<(Entity, &mut House, &RulesOver)>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    mut fired: MessageWriter<ScriptedEventFired>,
+    mut violations: MessageWriter<ModViolation>,
+    mod_settings: Res<ModSettings>,
+) {
+    let Some(year) = year_events.read().last().map(|event| event.year) else {
+        return;
+    };
+
+    let (expired, running) = std::mem::take(&mut state.modifiers)
+        .into_iter()
+        .partition::<Vec<_>, _>(|modifier| modifier.expires_year <= year);
+    state.modifiers = running;
+    for modifier in expired {
+        let nation = nation_index
+            .entity(modifier.nation)
+            .and_then(|entity| nations_query.get_mut(entity).ok());
+        if let Some((_, _, mut nation, _)) = nation {
+            adjust_stat(&mut nation, modifier.stat, -modifier.amount);
+        }
+    }
+
+    let (due, waiting) = std::mem::take(&mut state.pending)
+        .into_iter()
+        .partition::<Vec<_>, _>(|pending| pending.due_year <= year);
+    state.pending = waiting;
+    if events.events.is_empty() {
+        return;
+    }
+
+    // Mod events needing more than their mod was granted are held back, and reported each year they would run
+    let mut blocked: HashSet<&str> = HashSet::new();
+    for (id, event) in &events.events {
+        let Some(sandbox) = events.sandboxes.get(id) else {
+            continue;
+        };
+        for capability in missing_capabilities(event, sandbox) {
+            blocked.insert(id.as_str());
+            violations.write(ModViolation {
+                mod_id: sandbox.mod_id.clone(),
+                script: id.clone(),
+                capability,
+                year,
+            });
+        }
+    }
+
+    let mut owned: HashMap<Entity, Vec<usize>> = HashMap::new();
+    let mut holdings: HashMap<Entity, (u32, u64)> = HashMap::new();
+    if let Some(storage) = province_storage.as_deref() {
+        for (index, province) in storage.provinces.iter().enumerate() {
+            if let Some(owner) = province.owner_entity {
+                owned.entry(owner).or_default().push(index);
+                let held = holdings.entry(owner).or_default();
+                held.0 += 1;
+                held.1 += province.population as u64;
+            }
+        }
+    }
+    let rulers: HashMap<Entity, Entity> = houses_query
+        .iter()
+        .map(|(house_entity, _, rules_over)| (rules_over.0, house_entity))
+        .collect();
+
+    let seed = world_seed.map_or(0, |s| s.0);
+    let firings: Vec<Firing> = {
+        let mut nations: Vec<(Entity, NationId)> = nations_query.iter().map(|(entity, id, ..)| (entity, *id)).collect();
+        // Query order is not stable between runs; sort so events fire in the same order
+        nations.sort_by_key(|(_, id)| id.value());
+
+        let queued = due.iter().filter_map(|pending| {
+            let entity = nation_index.entity(pending.nation)?;
+            Some((pending.event.as_str(), entity, pending.nation, true))
+        });
+        let nations = &nations;
+        let scheduled = events
+            .events
+            .iter()
+            .filter(|(_, event)| !event.triggered_only)
+            .flat_map(move |(id, _)| {
+                nations
+                    .iter()
+                    .map(move |&(entity, nation_id)| (id.as_str(), entity, nation_id, false))
+            });
+
+        let state = &*state;
+        let storage = province_storage.as_deref();
+        queued
+            .chain(scheduled)
+            .filter(|(id, ..)| !blocked.contains(id))
+            .filter_map(|(id, entity, nation_id, queued)| {
+                let event = events.get(id)?;
+                let (_, _, nation, war) = nations_query.get(entity).ok()?;
+                let house = rulers
+                    .get(&entity)
+                    .and_then(|&house_entity| houses_query.get(house_entity).ok())
+                    .map(|(_, house, _)| house);
+                let context = EventContext {
+                    nation_id,
+                    nation,
+                    holdings: holdings.get(&entity).copied().unwrap_or_default(),
+                    at_war: war.is_some(),
+                    house,
+                    province: None,
+                    year,
+                    state,
+                    mod_settings: &mod_settings,
+                };
+                let provinces = owned.get(&entity).map_or(&[][..], Vec::as_slice);
+                let (province, option) = decide(id, event, context, provinces, storage, queued, seed)?;
+                Some(Firing {
+                    event: id.to_string(),
+                    nation: entity,
+                    nation_id,
+                    province,
+                    option,
+                })
+            })
+            .collect()
+    };
+
+    for firing in firings {
+        let Some(event) = events.get(&firing.event) else {
+            continue;
+        };
+        let Ok((_, _, nation, _)) = nations_query.get_mut(firing.nation) else {
+            continue;
+        };
+        let nation = nation.into_inner();
+        let house = rulers
+            .get(&firing.nation)
+            .and_then(|&house_entity| houses_query.get_mut(house_entity).ok())
+            .map(|(_, house, _)| house.into_inner());
+        let ruler = house.as_ref().map(|house| house.ruler.name.clone()).unwrap_or_default();
+        let province = firing.province.map(|index| {
+            city_names
+                .as_ref()
+                .and_then(|names| names.get(index))
+                .map_or_else(|| format!("province {}", index), |city| city.name.clone())
+        });
+
+        let reached = match firing.province {
+            Some(index) => vec![index],
+            None => owned.get(&firing.nation).cloned().unwrap_or_default(),
+        };
+        let option = &event.options[firing.option];
+        let mut target = EffectTarget {
+            nation_id: firing.nation_id,
+            nation,
+            house,
+            storage: province_storage.as_deref_mut(),
+            provinces: &reached,
+        };
+        apply_effects(&option.effects, &mut target, &mut state, &firing.event, year);
+        state
+            .last_fired
+            .entry(firing.event.clone())
+            .or_default()
+            .insert(firing.nation_id, year);
+
+        let nation = target.nation;
+        let args = [
+            ("nation", nation.name.as_str()),
+            ("adjective", nation.adjective.as_str()),
+            ("ruler", ruler.as_str()),
+            ("province", province.as_deref().unwrap_or_default()),
+        ];
+        let body: Vec<String> = [&event.text, &option.text]
+            .into_iter()
+            .map(|key| localization.format(key, &args))
+            .filter(|text| !text.is_empty())
+            .collect();
+        let text = format!("{}: {}", localization.format(&event.title, &args), body.join(" "));
+        debug!("Scripted event '{}' in year {}: {}", firing.event, year, text);
+        chronicle.write(ChronicleEvent {
+            category: event.category,
+            text,
+            nations: vec![firing.nation_id],
+        });
+        fired.write(ScriptedEventFired {
+            event: firing.event,
+            nation: firing.nation,
+            option: firing.option,
+        });
+    }
+}
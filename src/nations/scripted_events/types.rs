@@ -0,0 +1,327 @@
+//! The event language - definitions as modders write them in RON
+//!
+//! ```ron
+//! "bread_riots": (
+//!     title: "event.bread_riots.title",
+//!     text: "event.bread_riots.text",
+//!     scope: Province,
+//!     chance: 0.05,
+//!     trigger: All([Nation(Stability, Below(0.4)), Province(Population, Above(20000))]),
+//!     weight: [(factor: 3.0, when: AtWar)],
+//!     cooldown_years: 25,
+//!     options: [
+//!         (text: "event.bread_riots.crush", ai_weight: 1.0,
+//!          effects: [Stability(-0.05), Modifier(stat: MilitaryStrength, amount: -5.0, years: 5)]),
+//!         (text: "event.bread_riots.feed", ai_weight: 2.0,
+//!          effects: [TreasuryShare(-0.1), Stability(0.05)]),
+//!     ],
+//! ),
+//! ```
+//!
+//! `title`, `text`, and option `text` are localization keys; text with no
+//! entry in the string tables is shown as written.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::chronicle::ChronicleCategory;
+use crate::modding::ModSandbox;
+use crate::name_generator::Culture;
+use crate::nations::NationId;
+use crate::world::TerrainType;
+
+/// Yearly chance of an event whose definition gives none
+const DEFAULT_CHANCE: f32 = 0.1;
+
+/// What an event is about, beyond the nation it happens to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EventScope {
+    /// The nation as a whole
+    #[default]
+    Nation,
+    /// One of the nation's provinces, chosen among those meeting the trigger
+    Province,
+    /// The nation's ruler
+    Character,
+}
+
+/// A test on a number
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Compare {
+    Above(f32),
+    Below(f32),
+    /// Inclusive on both ends
+    Between(f32, f32),
+}
+
+impl Compare {
+    pub fn holds(&self, value: f32) -> bool {
+        match *self {
+            Compare::Above(threshold) => value > threshold,
+            Compare::Below(threshold) => value < threshold,
+            Compare::Between(low, high) => (low..=high).contains(&value),
+        }
+    }
+}
+
+/// Numbers a trigger can test about the nation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NationStat {
+    Treasury,
+    TaxRate,
+    MilitaryStrength,
+    Stability,
+    TechnologyLevel,
+    Aggression,
+    Expansionism,
+    Diplomacy,
+    Mercantilism,
+    /// Provinces held
+    Provinces,
+    /// People living in the provinces held
+    Population,
+}
+
+/// Numbers a trigger can test about the province in scope
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProvinceStat {
+    Population,
+    Agriculture,
+    Elevation,
+}
+
+/// Numbers a trigger can test about the ruler and their house
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RulerStat {
+    Age,
+    YearsRuling,
+    Competence,
+    Ambition,
+    Temperament,
+    Honor,
+    Legitimacy,
+    Prestige,
+}
+
+/// When an event may happen
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Condition {
+    All(Vec<Condition>),
+    Any(Vec<Condition>),
+    Not(Box<Condition>),
+    Nation(NationStat, Compare),
+    /// False outside province-scoped events
+    Province(ProvinceStat, Compare),
+    /// False for nations without a ruling house
+    Ruler(RulerStat, Compare),
+    Year(Compare),
+    AtWar,
+    Culture(Culture),
+    /// Terrain of the province in scope; false outside province-scoped events
+    Terrain(TerrainType),
+    /// The event with this id has happened to the nation before
+    Happened(String),
+    /// A mod setting by mod id and key, as a number: toggles are 1 or 0,
+    /// dropdowns the index of the chosen option; false if the mod isn't enabled
+    Setting(String, String, Compare),
+}
+
+/// Scales an event's chance while a condition holds
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeightModifier {
+    pub factor: f32,
+    pub when: Condition,
+}
+
+/// What an option does when it is taken
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Effect {
+    /// Add to the treasury (negative to spend)
+    Treasury(f32),
+    /// Change the treasury by a share of itself
+    TreasuryShare(f32),
+    Stability(f32),
+    TaxRate(f32),
+    MilitaryStrength(f32),
+    /// Legitimacy of the ruling house
+    Legitimacy(f32),
+    /// Change population by a share: the province in scope, or every province held
+    Population(f32),
+    /// Change a nation stat for a number of years, then take the change back
+    Modifier {
+        stat: NationStat,
+        amount: f32,
+        years: u32,
+    },
+    /// Queue another event for the same nation after a delay; its trigger is
+    /// checked when it comes due, its chance is not
+    TriggerEvent {
+        event: String,
+        delay_years: u32,
+    },
+}
+
+/// One response to an event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventOption {
+    pub text: String,
+    /// How much nations favor this option over the others
+    #[serde(default = "default_ai_weight")]
+    pub ai_weight: f32,
+    #[serde(default)]
+    pub ai_weight_modifiers: Vec<WeightModifier>,
+    #[serde(default)]
+    pub effects: Vec<Effect>,
+}
+
+fn default_ai_weight() -> f32 {
+    1.0
+}
+
+fn default_chance() -> f32 {
+    DEFAULT_CHANCE
+}
+
+/// An event as defined in a data file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventDefinition {
+    pub title: String,
+    pub text: String,
+    #[serde(default)]
+    pub scope: EventScope,
+    /// Chronicle section the event is recorded under
+    #[serde(default = "default_category")]
+    pub category: ChronicleCategory,
+    /// Yearly chance for each nation meeting the trigger, before weight modifiers
+    #[serde(default = "default_chance")]
+    pub chance: f32,
+    #[serde(default)]
+    pub trigger: Option<Condition>,
+    #[serde(default)]
+    pub weight: Vec<WeightModifier>,
+    /// Years before the event can happen to the same nation again
+    #[serde(default)]
+    pub cooldown_years: u32,
+    /// Happens at most once to each nation
+    #[serde(default)]
+    pub once: bool,
+    /// Only happens when queued by another event's `TriggerEvent`
+    #[serde(default)]
+    pub triggered_only: bool,
+    pub options: Vec<EventOption>,
+}
+
+fn default_category() -> ChronicleCategory {
+    ChronicleCategory::Politics
+}
+
+/// Events from the base game and active mods, in id order
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ScriptedEvents {
+    pub events: Vec<(String, EventDefinition)>,
+    /// Sandbox of each event a mod defines; base game events have none
+    pub sandboxes: HashMap<String, ModSandbox>,
+}
+
+impl ScriptedEvents {
+    pub fn get(&self, id: &str) -> Option<&EventDefinition> {
+        self.events
+            .binary_search_by(|(event_id, _)| event_id.as_str().cmp(id))
+            .ok()
+            .map(|index| &self.events[index].1)
+    }
+}
+
+/// A timed change to a nation stat, taken back when it expires
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventModifier {
+    pub nation: NationId,
+    /// Event that applied it
+    pub source: String,
+    pub stat: NationStat,
+    pub amount: f32,
+    pub expires_year: u32,
+}
+
+/// An event queued by another, waiting for its year
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingEvent {
+    pub event: String,
+    pub nation: NationId,
+    pub due_year: u32,
+}
+
+/// Event history, queued events, and running modifiers, saved with the world
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScriptedEventState {
+    /// Year each event last happened to each nation
+    pub last_fired: HashMap<String, HashMap<NationId, u32>>,
+    pub pending: Vec<PendingEvent>,
+    pub modifiers: Vec<EventModifier>,
+}
+
+impl ScriptedEventState {
+    pub fn last_fired(&self, event: &str, nation: NationId) -> Option<u32> {
+        self.last_fired.get(event)?.get(&nation).copied()
+    }
+
+    /// Whether cooldown and `once` let the event happen to the nation this year
+    pub fn may_fire(&self, id: &str, event: &EventDefinition, nation: NationId, year: u32) -> bool {
+        match self.last_fired(id, nation) {
+            Some(_) if event.once => false,
+            Some(last) => year >= last + event.cooldown_years.max(1),
+            None => true,
+        }
+    }
+}
+
+/// Sent when a scripted event happens to a nation
+#[derive(Message, Debug, Clone)]
+pub struct ScriptedEventFired {
+    pub event: String,
+    pub nation: Entity,
+    pub option: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shipped_events_parse_and_chain_to_defined_events() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/config/base/events.ron");
+        let text = std::fs::read_to_string(path).unwrap_or_else(|e| panic!("events file ships with the game: {}", e));
+        let shipped: HashMap<String, EventDefinition> =
+            ron::from_str(&text).unwrap_or_else(|e| panic!("events failed to parse: {}", e));
+
+        let mut events: Vec<_> = shipped.into_iter().collect();
+        events.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let events = ScriptedEvents {
+            events,
+            sandboxes: HashMap::new(),
+        };
+        for (id, event) in &events.events {
+            assert!(!event.options.is_empty(), "{} has no options", id);
+            for effect in event.options.iter().flat_map(|option| &option.effects) {
+                if let Effect::TriggerEvent { event, .. } = effect {
+                    assert!(events.get(event).is_some(), "{} queues unknown event {}", id, event);
+                }
+            }
+        }
+
+        let mut state = ScriptedEventState::default();
+        let Some(riots) = events.get("bread_riots") else {
+            panic!("bread_riots is shipped");
+        };
+        state
+            .last_fired
+            .entry("bread_riots".to_string())
+            .or_default()
+            .insert(NationId::new(0), 1000);
+        assert!(!state.may_fire("bread_riots", riots, NationId::new(0), 1010));
+        assert!(state.may_fire("bread_riots", riots, NationId::new(0), 1025));
+        assert!(state.may_fire("bread_riots", riots, NationId::new(1), 1010));
+    }
+}
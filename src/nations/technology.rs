@@ -0,0 +1,129 @@
+//! Technology - how nations advance from one level to the next
+//!
+//! Research accumulates each year at a pace set by the nation's literacy and
+//! laws, and by the world's tech progression setting. Each level takes longer
+//! than the last. Nations bordering more advanced neighbours pick up their
+//! ideas and close the gap faster, so knowledge spreads outward from leading
+//! nations unevenly rather than arriving everywhere at once. Nations that
+//! close their borders shut those ideas out along with the foreigners.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::bureaucracy::Bureaucracy;
+use super::contact_policy::ContactStance;
+use super::laws::NationLaws;
+use super::relationships::LandNeighbors;
+use super::types::Nation;
+use crate::simulation::NewYearEvent;
+use crate::world::WorldGenerationSettings;
+
+/// Research per year for a half-literate nation at level one
+const BASE_RESEARCH: f32 = 0.02;
+/// Extra research per year for each level a neighbour is ahead
+const DIFFUSION_PER_LEVEL: f32 = 0.005;
+/// Most levels of lead diffusion draws on
+const MAX_DIFFUSION_GAP: u32 = 3;
+
+/// Progress toward a nation's next technology level
+#[derive(Component, Debug, Clone, Default, Reflect, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct Research {
+    /// Progress toward the next level (0.0-1.0)
+    pub progress: f32,
+    /// Progress made last year
+    pub rate: f32,
+}
+
+/// Yearly research for a nation
+///
+/// `openness` is the share of foreign ideas its contact policy lets through.
+pub fn research_rate(
+    literacy: f32,
+    law_modifier: f32,
+    speed: f32,
+    technology_level: u32,
+    neighbor_lead: u32,
+    openness: f32,
+) -> f32 {
+    let own = BASE_RESEARCH * (0.5 + literacy.clamp(0.0, 1.0)) * (1.0 + law_modifier).max(0.0)
+        / (technology_level.max(1) as f32).sqrt();
+    let diffusion =
+        neighbor_lead.min(MAX_DIFFUSION_GAP) as f32 * DIFFUSION_PER_LEVEL * openness.clamp(0.0, 1.0);
+    (own + diffusion) * speed.max(0.0)
+}
+
+/// Yearly research, with ideas spreading across land borders
+pub fn advance_technology(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    settings: Option<Res<WorldGenerationSettings>>,
+    mut nations_query: Query<(
+        Entity,
+        &mut Nation,
+        Option<&Bureaucracy>,
+        Option<&NationLaws>,
+        Option<&LandNeighbors>,
+        Option<&ContactStance>,
+        Option<&mut Research>,
+    )>,
+) {
+    if year_events.read().last().is_none() {
+        return;
+    }
+    let speed = settings.map_or(1.0, |settings| settings.tech_progression_speed);
+    let levels: HashMap<Entity, u32> = nations_query
+        .iter()
+        .map(|(entity, nation, ..)| (entity, nation.technology_level))
+        .collect();
+
+    for (entity, mut nation, bureaucracy, laws, neighbors, stance, research) in &mut nations_query {
+        let neighbor_lead = neighbors
+            .map(|neighbors| neighbors.neighbors())
+            .unwrap_or(&[])
+            .iter()
+            .filter_map(|neighbor| levels.get(neighbor))
+            .max()
+            .map_or(0, |&best| best.saturating_sub(nation.technology_level));
+        let rate = research_rate(
+            bureaucracy.map_or(0.1, |bureaucracy| bureaucracy.literacy),
+            laws.map_or(0.0, |laws| laws.combined_effects.technology_rate_modifier),
+            speed,
+            nation.technology_level,
+            neighbor_lead,
+            stance.map_or(1.0, |stance| stance.policy.diffusion_share()),
+        );
+
+        let mut updated = research.as_deref().cloned().unwrap_or_default();
+        updated.rate = rate;
+        updated.progress += rate;
+        if updated.progress >= 1.0 {
+            updated.progress -= 1.0;
+            nation.technology_level += 1;
+            info!("{} reaches technology level {}", nation.name, nation.technology_level);
+        }
+
+        match research {
+            Some(mut research) => *research = updated,
+            None => {
+                commands.entity(entity).insert(updated);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literacy_and_advanced_neighbours_speed_research() {
+        let isolated = research_rate(0.1, 0.0, 1.0, 2, 0, 1.0);
+        assert!(research_rate(0.9, 0.0, 1.0, 2, 0, 1.0) > isolated);
+        assert!(research_rate(0.1, 0.0, 1.0, 2, 2, 1.0) > isolated);
+        assert!(research_rate(0.1, 0.0, 1.0, 6, 0, 1.0) < isolated);
+        assert_eq!(research_rate(0.5, 0.0, 0.0, 1, 3, 1.0), 0.0);
+        assert!(research_rate(0.1, 0.0, 1.0, 2, 2, 0.1) < research_rate(0.1, 0.0, 1.0, 2, 2, 1.0));
+    }
+}
@@ -0,0 +1,401 @@
+//! Merchant leagues - coalitions of trade cities that answer to no crown
+//!
+//! Wealthy trading cities near one another band together across borders.
+//! A league collects dues from its members' commerce, buys its members
+//! better terms than any one city could get alone, and keeps a small fleet
+//! to protect the trade. It also takes sides: it embargoes nations that
+//! attack its cities and pays for the defence of the nations that hold them.
+//!
+//! Leagues are their own entities rather than nations. They own no provinces
+//! and fight no battles; they are recorded in the chronicle as they are
+//! founded, act, and fall apart.
+
+use bevy::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use super::city_names::CityNames;
+use super::relationships::Attacking;
+use super::types::{Economy, Nation, NationId};
+use crate::ai::{decision_rng, DecisionDomain};
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::math::{Fixed32, Money, HEX_SIZE};
+use crate::simulation::NewYearEvent;
+use crate::world::{Province, ProvinceStorage, TerrainType, WorldSeed};
+
+/// Commerce a city needs to count as a trade city
+const TRADE_CITY_COMMERCE: f32 = 40.0;
+/// Share of the threshold a member can fall to before the league drops it
+const MEMBER_RETENTION: f32 = 0.5;
+/// Commerce multiplier for cities with a harbour
+const HARBOUR_BONUS: f32 = 1.5;
+/// Distance within which cities can join the same league
+const LEAGUE_REACH: f32 = HEX_SIZE * 12.0;
+/// Cities needed to found a league
+const MIN_FOUNDING_CITIES: usize = 3;
+/// Yearly chance a qualifying group of cities founds a league
+const FOUNDING_CHANCE: f32 = 0.2;
+/// Most cities a league admits
+const MAX_LEAGUE_CITIES: usize = 12;
+/// Share of member commerce paid to the league as dues
+const DUES_RATE: f32 = 0.1;
+/// Share of member commerce returned as better terms by a large, well-protected league
+const BARGAINING_SHARE: f32 = 0.25;
+/// Fleet strength needed per member city for full protection
+const FLEET_PER_CITY: f32 = 10.0;
+/// Share of the league treasury spent on ships each year
+const FLEET_SPENDING: Fixed32 = Fixed32::from_ratio(3, 10);
+/// Share of the fleet lost to wear each year
+const FLEET_WEAR: f32 = 0.1;
+/// Share of league commerce an embargoed nation loses each year
+const EMBARGO_LOSS: f32 = 0.5;
+/// League treasury kept back before it funds any war
+const WAR_CHEST: Money = Money::from_int(100);
+/// Share of the league treasury paid to a defender each year
+const WAR_FUNDING_SHARE: Fixed32 = Fixed32::from_ratio(1, 4);
+
+/// A merchant league of trade cities
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct TradeLeague {
+    pub name: String,
+    pub founded_year: u32,
+    /// Member cities, by province id
+    pub cities: Vec<u32>,
+    /// Nations holding member cities
+    pub nations: Vec<Entity>,
+    pub treasury: Money,
+    /// Strength of the league's protective fleet
+    pub fleet: f32,
+    /// Nations the league refuses to trade with
+    pub embargoes: Vec<Entity>,
+    /// Nations whose defence the league is paying for
+    pub funding: Vec<Entity>,
+    pub dissolved_year: Option<u32>,
+}
+
+impl TradeLeague {
+    pub fn is_active(&self) -> bool {
+        self.dissolved_year.is_none()
+    }
+
+    /// Stable-id form of the league, for saving
+    pub fn to_saved(&self, id_of: impl Fn(Entity) -> Option<NationId>) -> SavedTradeLeague {
+        let ids = |entities: &[Entity]| -> Vec<NationId> { entities.iter().filter_map(|&e| id_of(e)).collect() };
+        SavedTradeLeague {
+            name: self.name.clone(),
+            founded_year: self.founded_year,
+            cities: self.cities.clone(),
+            nations: ids(&self.nations),
+            treasury: self.treasury,
+            fleet: self.fleet,
+            embargoes: ids(&self.embargoes),
+            funding: ids(&self.funding),
+            dissolved_year: self.dissolved_year,
+        }
+    }
+}
+
+/// A merchant league as written to a save, naming nations by stable id
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedTradeLeague {
+    pub name: String,
+    pub founded_year: u32,
+    pub cities: Vec<u32>,
+    pub nations: Vec<NationId>,
+    pub treasury: Money,
+    pub fleet: f32,
+    pub embargoes: Vec<NationId>,
+    pub funding: Vec<NationId>,
+    pub dissolved_year: Option<u32>,
+}
+
+impl SavedTradeLeague {
+    /// Rebuild the league; nations that no longer exist drop out of it
+    pub fn restore(&self, entity_of: impl Fn(NationId) -> Option<Entity>) -> TradeLeague {
+        let entities = |ids: &[NationId]| -> Vec<Entity> { ids.iter().filter_map(|&id| entity_of(id)).collect() };
+        TradeLeague {
+            name: self.name.clone(),
+            founded_year: self.founded_year,
+            cities: self.cities.clone(),
+            nations: entities(&self.nations),
+            treasury: self.treasury,
+            fleet: self.fleet,
+            embargoes: entities(&self.embargoes),
+            funding: entities(&self.funding),
+            dissolved_year: self.dissolved_year,
+        }
+    }
+}
+
+/// A city's yearly commerce, before its ruler's trade policy
+pub fn city_commerce(province: &Province, harbour: bool) -> f32 {
+    let commerce = province.population as f32 * 0.001
+        + province.gold.normalized() * 20.0
+        + province.gems.normalized() * 50.0;
+    if harbour {
+        commerce * HARBOUR_BONUS
+    } else {
+        commerce
+    }
+}
+
+/// Share of a member's commerce the league wins back through collective negotiation
+pub fn bargaining_bonus(cities: usize, fleet: f32) -> f32 {
+    if cities < 2 {
+        return 0.0;
+    }
+    let leverage = 1.0 - 1.0 / cities as f32;
+    let protection = fleet / (fleet + FLEET_PER_CITY * cities as f32);
+    BARGAINING_SHARE * leverage * (0.5 + 0.5 * protection)
+}
+
+/// A city wealthy enough to trade on its own account
+struct TradeCity {
+    id: u32,
+    owner: Entity,
+    commerce: f32,
+    position: Vec2,
+}
+
+/// Yearly founding, growth, dues, and politics of merchant leagues
+pub fn run_trade_leagues(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    province_storage: Option<Res<ProvinceStorage>>,
+    city_names: Res<CityNames>,
+    world_seed: Option<Res<WorldSeed>>,
+    mut nations_query: Query<(Entity, &mut Nation, &NationId, Option<&Economy>)>,
+    attackers_query: Query<(Entity, &Attacking)>,
+    mut leagues_query: Query<&mut TradeLeague>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+    let Some(storage) = province_storage else {
+        return;
+    };
+    let seed = world_seed.map_or(0, |seed| seed.0);
+
+    let trade_multipliers: HashMap<Entity, f32> = nations_query
+        .iter()
+        .map(|(entity, _, _, economy)| (entity, economy.map_or(1.0, |economy| economy.trade_multiplier)))
+        .collect();
+    let nation_ids: HashMap<Entity, NationId> =
+        nations_query.iter().map(|(entity, _, nation_id, _)| (entity, *nation_id)).collect();
+    let nation_names: HashMap<Entity, String> =
+        nations_query.iter().map(|(entity, nation, _, _)| (entity, nation.name.clone())).collect();
+
+    let cities: BTreeMap<u32, TradeCity> = storage
+        .provinces
+        .iter()
+        .enumerate()
+        .filter_map(|(index, province)| {
+            city_names.get(index)?;
+            let owner = province.owner_entity?;
+            let harbour = province.neighbors.iter().flatten().any(|neighbor| {
+                storage
+                    .provinces
+                    .get(neighbor.value() as usize)
+                    .is_some_and(|neighbor| neighbor.terrain == TerrainType::Ocean)
+            });
+            let commerce =
+                city_commerce(province, harbour) * trade_multipliers.get(&owner).copied().unwrap_or(1.0);
+            (commerce >= TRADE_CITY_COMMERCE * MEMBER_RETENTION).then_some((
+                province.id.value(),
+                TradeCity {
+                    id: province.id.value(),
+                    owner,
+                    commerce,
+                    position: province.position,
+                },
+            ))
+        })
+        .collect();
+    let qualifies = |city: &TradeCity| city.commerce >= TRADE_CITY_COMMERCE;
+    let in_reach = |a: &TradeCity, b: &TradeCity| a.position.distance(b.position) <= LEAGUE_REACH;
+
+    let mut affiliated: HashSet<u32> = leagues_query
+        .iter()
+        .filter(|league| league.is_active())
+        .flat_map(|league| league.cities.iter().copied())
+        .collect();
+
+    let mut payments: HashMap<Entity, Money> = HashMap::new();
+    let mut military_aid: HashMap<Entity, f32> = HashMap::new();
+
+    for mut league in leagues_query.iter_mut().filter(|league| league.is_active()) {
+        // Cities that have lost their wealth drift out of the league
+        let lapsed: Vec<u32> = league.cities.iter().copied().filter(|id| !cities.contains_key(id)).collect();
+        league.cities.retain(|id| cities.contains_key(id));
+        for id in lapsed {
+            affiliated.remove(&id);
+        }
+
+        // Nearby trade cities ask to join
+        let recruits: Vec<u32> = cities
+            .values()
+            .filter(|city| qualifies(city) && !affiliated.contains(&city.id))
+            .filter(|city| league.cities.iter().any(|id| cities.get(id).is_some_and(|member| in_reach(member, city))))
+            .map(|city| city.id)
+            .take(MAX_LEAGUE_CITIES.saturating_sub(league.cities.len()))
+            .collect();
+        for id in recruits {
+            affiliated.insert(id);
+            league.cities.push(id);
+        }
+
+        let members: Vec<&TradeCity> = league.cities.iter().filter_map(|id| cities.get(id)).collect();
+        let member_nations: BTreeSet<Entity> = members.iter().map(|city| city.owner).collect();
+        league.nations = member_nations.iter().copied().collect();
+        if members.len() < 2 {
+            league.dissolved_year = Some(year);
+            for id in &league.cities {
+                affiliated.remove(id);
+            }
+            chronicle.write(ChronicleEvent {
+                category: ChronicleCategory::Trade,
+                text: format!("{} dissolves as its cities go their own way", league.name),
+                nations: member_nations.iter().filter_map(|owner| nation_ids.get(owner).copied()).collect(),
+            });
+            continue;
+        }
+
+        // Dues fund the league; collective negotiation repays the members
+        let commerce: f32 = members.iter().map(|city| city.commerce).sum();
+        let bonus = bargaining_bonus(members.len(), league.fleet);
+        for city in &members {
+            *payments.entry(city.owner).or_default() += Money::from_f32(city.commerce * bonus);
+        }
+        league.treasury += Money::from_f32(commerce * DUES_RATE);
+        let shipbuilding = league.treasury * FLEET_SPENDING;
+        league.treasury -= shipbuilding;
+        league.fleet = league.fleet * (1.0 - FLEET_WEAR) + shipbuilding.to_f32();
+
+        // Nations that attack the league's cities lose its trade
+        let aggressors: BTreeSet<Entity> = attackers_query
+            .iter()
+            .filter(|(aggressor, attacking)| {
+                member_nations.contains(&attacking.0) && !member_nations.contains(aggressor)
+            })
+            .map(|(aggressor, _)| aggressor)
+            .collect();
+        for aggressor in &aggressors {
+            if !league.embargoes.contains(aggressor) {
+                league.embargoes.push(*aggressor);
+                let aggressor_name = nation_names.get(aggressor).map_or("Unknown", String::as_str);
+                chronicle.write(ChronicleEvent {
+                    category: ChronicleCategory::Trade,
+                    text: format!("{} embargoes {} for attacking its cities", league.name, aggressor_name),
+                    nations: nation_ids.get(aggressor).copied().into_iter().collect(),
+                });
+            }
+        }
+        league.embargoes.retain(|nation| aggressors.contains(nation));
+        for aggressor in &league.embargoes {
+            *payments.entry(*aggressor).or_default() -= Money::from_f32(commerce * EMBARGO_LOSS);
+        }
+
+        // Members under attack get the league's money and ships
+        let defenders: BTreeSet<Entity> = attackers_query
+            .iter()
+            .filter(|(aggressor, attacking)| {
+                member_nations.contains(&attacking.0) && !member_nations.contains(aggressor)
+            })
+            .map(|(_, attacking)| attacking.0)
+            .collect();
+        league.funding.retain(|nation| defenders.contains(nation));
+        for defender in &defenders {
+            if league.treasury <= WAR_CHEST {
+                break;
+            }
+            let grant = league.treasury * WAR_FUNDING_SHARE;
+            league.treasury -= grant;
+            *payments.entry(*defender).or_default() += grant;
+            if !league.funding.contains(defender) {
+                league.funding.push(*defender);
+                *military_aid.entry(*defender).or_default() += league.fleet / members.len() as f32;
+                let defender_name = nation_names.get(defender).map_or("Unknown", String::as_str);
+                chronicle.write(ChronicleEvent {
+                    category: ChronicleCategory::Trade,
+                    text: format!("{} pays for the defence of {}", league.name, defender_name),
+                    nations: nation_ids.get(defender).copied().into_iter().collect(),
+                });
+            }
+        }
+    }
+
+    // The wealthiest unaffiliated cities gather their neighbours into new leagues
+    let mut candidates: Vec<&TradeCity> = cities.values().filter(|city| qualifies(city)).collect();
+    candidates.sort_by(|a, b| b.commerce.total_cmp(&a.commerce).then(a.id.cmp(&b.id)));
+    for founder in &candidates {
+        if affiliated.contains(&founder.id) {
+            continue;
+        }
+        let founding: Vec<&TradeCity> = candidates
+            .iter()
+            .copied()
+            .filter(|city| !affiliated.contains(&city.id) && in_reach(founder, city))
+            .take(MAX_LEAGUE_CITIES)
+            .collect();
+        let founding_nations: BTreeSet<Entity> = founding.iter().map(|city| city.owner).collect();
+        if founding.len() < MIN_FOUNDING_CITIES || founding_nations.len() < 2 {
+            continue;
+        }
+        let mut rng = decision_rng(seed, DecisionDomain::Economy, founder.id, 1, year);
+        if rng.r#gen::<f32>() >= FOUNDING_CHANCE {
+            continue;
+        }
+
+        let Some(city_name) = city_names.get(founder.id as usize) else {
+            continue;
+        };
+        let name = format!("The {} League", city_name.name);
+        chronicle.write(ChronicleEvent {
+            category: ChronicleCategory::Trade,
+            text: format!(
+                "Merchants of {} cities found {} across {} nations",
+                founding.len(),
+                name,
+                founding_nations.len()
+            ),
+            nations: founding_nations.iter().filter_map(|owner| nation_ids.get(owner).copied()).collect(),
+        });
+        affiliated.extend(founding.iter().map(|city| city.id));
+        commands.spawn(TradeLeague {
+            name,
+            founded_year: year,
+            cities: founding.iter().map(|city| city.id).collect(),
+            nations: founding_nations.into_iter().collect(),
+            treasury: Money::ZERO,
+            fleet: 0.0,
+            embargoes: Vec::new(),
+            funding: Vec::new(),
+            dissolved_year: None,
+        });
+    }
+
+    for (entity, mut nation, _, _) in &mut nations_query {
+        if let Some(payment) = payments.get(&entity) {
+            nation.treasury += *payment;
+        }
+        if let Some(aid) = military_aid.get(&entity) {
+            nation.military_strength += aid;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn larger_protected_leagues_negotiate_better_terms() {
+        assert_eq!(bargaining_bonus(1, 100.0), 0.0);
+        assert!(bargaining_bonus(6, 0.0) > bargaining_bonus(2, 0.0));
+        assert!(bargaining_bonus(6, 60.0) > bargaining_bonus(6, 0.0));
+        assert!(bargaining_bonus(12, 1_000.0) <= BARGAINING_SHARE);
+    }
+}
@@ -8,10 +8,42 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::fmt;
 
+use crate::math::Money;
+use crate::world::ProvinceId;
+
 /// Unique identifier for a nation
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect, Component)]
+///
+/// Stable across saves, unlike the nation's `Entity`. Hooks keep
+/// [`NationIndex`](super::index::NationIndex) in sync with the entities carrying it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+#[reflect(Component)]
 pub struct NationId(pub u32);
 
+// Manual Component implementation to register lifecycle hooks
+impl Component for NationId {
+    const STORAGE_TYPE: bevy::ecs::component::StorageType = bevy::ecs::component::StorageType::Table;
+    type Mutability = bevy::ecs::component::Mutable;
+
+    fn on_insert() -> Option<bevy::ecs::lifecycle::ComponentHook> {
+        Some(|mut world, bevy::ecs::lifecycle::HookContext { entity, .. }| {
+            let Some(id) = world.get::<NationId>(entity).copied() else {
+                return;
+            };
+            if let Some(mut index) = world.get_resource_mut::<super::index::NationIndex>() {
+                index.insert(id, entity);
+            }
+        })
+    }
+
+    fn on_replace() -> Option<bevy::ecs::lifecycle::ComponentHook> {
+        Some(|mut world, bevy::ecs::lifecycle::HookContext { entity, .. }| {
+            if let Some(mut index) = world.get_resource_mut::<super::index::NationIndex>() {
+                index.remove(entity);
+            }
+        })
+    }
+}
+
 impl NationId {
     pub fn new(id: u32) -> Self {
         Self(id)
@@ -32,6 +64,7 @@ impl fmt::Display for NationId {
 ///
 /// Uses Bevy 0.16 Component Hooks for automatic cache cleanup when removed
 #[derive(Debug, Clone, Serialize, Deserialize, Reflect, Component)]
+#[reflect(Component)]
 /// A nation in the world with territory, government, and economy.
 ///
 /// ## Province Ownership vs Territory Grouping
@@ -54,10 +87,10 @@ pub struct Nation {
     pub name: String,
     pub adjective: String, // "French" for "France"
     pub color: Color,
-    pub capital_province: u32,
+    pub capital_province: ProvinceId,
 
     // Economic and military strength
-    pub treasury: f32,
+    pub treasury: Money,
     pub tax_rate: f32, // 0.0 to 1.0 (0% to 100%)
     pub military_strength: f32,
     pub stability: f32, // 0.0 to 1.0
@@ -87,6 +120,7 @@ pub struct NationPersonality {
 /// and trade. These values are multipliers applied to base calculations.
 /// Law effects modify these values to implement economic policies.
 #[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct Economy {
     /// Efficiency of tax collection (0.0 = no tax, 1.0 = base, 2.0 = double)
     pub tax_efficiency: f32,
@@ -97,7 +131,7 @@ pub struct Economy {
     /// Trade income multiplier
     pub trade_multiplier: f32,
     /// Base maintenance cost per turn
-    pub maintenance_cost: f32,
+    pub maintenance_cost: Money,
 }
 
 impl Default for Economy {
@@ -107,7 +141,7 @@ impl Default for Economy {
             industrial_multiplier: 1.0,
             agricultural_multiplier: 1.0,
             trade_multiplier: 1.0,
-            maintenance_cost: 100.0,
+            maintenance_cost: Money::from_int(100),
         }
     }
 }
@@ -162,6 +196,10 @@ impl Default for NationRegistry {
 
 impl NationRegistry {
     /// Thread-safe nation ID creation using atomic operations
+    ///
+    /// Only for parallel world generation; the IDs handed out here are
+    /// reserved in [`IdAllocator`](crate::ids::IdAllocator) as the nations
+    /// spawn, and everything later mints from the allocator.
     pub fn create_nation_id(&self) -> NationId {
         let id = self
             .nation_id_counter
@@ -177,6 +215,8 @@ pub struct NationGenerationSettings {
     pub nation_density: NationDensity,
     pub starting_development: StartingDevelopment,
     pub aggression_level: f32,
+    /// Designer-defined nations placed before the generated ones
+    pub custom_nations: Vec<super::CustomNationSpec>,
 }
 
 impl Default for NationGenerationSettings {
@@ -186,6 +226,7 @@ impl Default for NationGenerationSettings {
             nation_density: NationDensity::Balanced,
             starting_development: StartingDevelopment::Medieval,
             aggression_level: 0.5,
+            custom_nations: Vec::new(),
         }
     }
 }
@@ -216,7 +257,8 @@ pub enum StartingDevelopment {
 }
 
 /// A territory is a group of contiguous provinces owned by same nation
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct Territory {
     pub provinces: HashSet<u32>, // The province IDs in this territory
     pub center: Vec2,  // Geographic center
@@ -225,12 +267,14 @@ pub struct Territory {
 
 /// Entity Relationships for Territory ownership
 /// Territory is owned by a Nation - uses Bevy 0.16 automatic bidirectional tracking
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = OwnsTerritory)] // THIS enables automatic tracking!
 pub struct OwnedBy(pub Entity);
 
 /// Nation owns territories - automatically maintained by Bevy!
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = OwnedBy, linked_spawn)] // THIS creates the magic!
 pub struct OwnsTerritory(Vec<Entity>); // Private for safety - Bevy handles access
 
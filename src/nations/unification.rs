@@ -0,0 +1,329 @@
+//! Unification movements - small nations of one culture merging into a nation-state
+//!
+//! Once nationalism takes hold, a dominant nation can rally its culturally
+//! similar, land-connected smaller neighbors into a unification movement.
+//! The movement builds momentum each year while the members stay at peace
+//! with each other; when it completes, the members' territory passes to the
+//! unifier, which takes a new name and color to mark the nation-state's birth.
+
+use bevy::prelude::*;
+use rand_chacha::ChaCha8Rng as StdRng;
+use rand::SeedableRng;
+use std::collections::{HashMap, HashSet, VecDeque};
+use crate::audio::{AudioCue, AudioEvent};
+use crate::constants::SIMULATION_STARTING_YEAR;
+use crate::name_generator::{Culture, NameGenerator};
+use crate::nations::{
+    Attacking, Governance, HistoricalEvent, LandNeighborOf, LandNeighbors, NavalNeighborOf,
+    Nation, NationHistory, NationId, NationRenamed, OwnershipChangeType, OwnershipService,
+};
+use crate::relationships::Controls;
+use crate::simulation::NewYearEvent;
+use crate::world::{CachedOverlayColors, MapMode};
+
+/// Technology level at which nationalism becomes a political force
+pub const NATIONALISM_TECH_LEVEL: u32 = 3;
+
+/// Years after the simulation starts when nationalism spreads regardless of technology
+pub const NATIONALISM_ERA_YEARS: u32 = 400;
+
+/// A unifier must hold at least this many times the territory of any member
+const DOMINANCE_RATIO: f32 = 2.0;
+
+/// Minimum stability for a nation to lead a movement
+const MIN_UNIFIER_STABILITY: f32 = 0.5;
+
+/// Yearly movement progress before modifiers
+const BASE_YEARLY_PROGRESS: f32 = 0.02;
+
+/// An active unification movement, attached to the unifying nation
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct UnificationMovement {
+    /// Shared culture of the movement
+    pub culture: Culture,
+    /// Smaller nations to be absorbed
+    pub members: Vec<Entity>,
+    /// Progress toward formation (0.0 to 1.0)
+    pub progress: f32,
+    /// Year the movement began
+    pub started_year: u32,
+}
+
+/// Marker left on a nation absorbed by unification
+///
+/// The entity is kept (with its Nation component removed) rather than
+/// despawned, because neighbor and war relationship targets are linked_spawn.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct UnifiedInto {
+    pub nation: Entity,
+    pub year: u32,
+}
+
+/// Message: a new nation-state has formed
+#[derive(Message, Debug, Clone)]
+pub struct NationFormedEvent {
+    pub nation: Entity,
+    pub old_name: String,
+    pub new_name: String,
+    pub absorbed: Vec<String>,
+    pub year: u32,
+}
+
+/// Whether nationalism has reached a nation
+pub fn is_nationalism_era(nation: &Nation, year: u32) -> bool {
+    nation.technology_level >= NATIONALISM_TECH_LEVEL
+        || year.saturating_sub(SIMULATION_STARTING_YEAR) >= NATIONALISM_ERA_YEARS
+}
+
+/// Yearly progress for a movement given the unifier's stability and territorial share
+pub fn yearly_progress(unifier_stability: f32, unifier_share: f32) -> f32 {
+    BASE_YEARLY_PROGRESS * (0.5 + unifier_stability.clamp(0.0, 1.0)) * (0.5 + unifier_share.clamp(0.0, 1.0))
+}
+
+type UnificationNationQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Nation,
+        Option<&'static Controls>,
+        Option<&'static LandNeighbors>,
+        Option<&'static Attacking>,
+    ),
+>;
+
+/// Find culture-mates connected to the unifier by land that it clearly dominates
+fn gather_members(unifier: Entity, culture: Culture, nations: &UnificationNationQuery) -> Vec<Entity> {
+    let size = |entity: Entity| {
+        nations
+            .get(entity)
+            .ok()
+            .and_then(|(_, _, controls, _, _)| controls)
+            .map_or(0, Controls::province_count)
+    };
+    let unifier_size = size(unifier) as f32;
+
+    let mut members = Vec::new();
+    let mut visited = HashSet::from([unifier]);
+    let mut queue = VecDeque::from([unifier]);
+    while let Some(current) = queue.pop_front() {
+        let Ok((_, _, _, Some(neighbors), _)) = nations.get(current) else {
+            continue;
+        };
+        for &neighbor in neighbors.neighbors() {
+            if !visited.insert(neighbor) {
+                continue;
+            }
+            let Ok((_, nation, _, _, _)) = nations.get(neighbor) else {
+                continue;
+            };
+            if nation.culture == culture && size(neighbor) as f32 * DOMINANCE_RATIO <= unifier_size {
+                members.push(neighbor);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    members
+}
+
+/// Start and advance unification movements each year
+pub fn update_unification_movements(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut movements: Query<&mut UnificationMovement>,
+    nations_query: UnificationNationQuery,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+
+    // Territory per culture, for the unifier's share of its cultural sphere
+    let mut culture_provinces: HashMap<Culture, usize> = HashMap::new();
+    for (_, nation, controls, _, _) in &nations_query {
+        *culture_provinces.entry(nation.culture).or_default() += controls.map_or(0, Controls::province_count);
+    }
+
+    // Nations already leading or joining a movement
+    let mut committed: HashSet<Entity> = HashSet::new();
+    for movement in &movements {
+        committed.extend(movement.members.iter().copied());
+    }
+
+    for (entity, nation, controls, _, attacking) in &nations_query {
+        let size = controls.map_or(0, Controls::province_count);
+        let share = size as f32 / culture_provinces.get(&nation.culture).copied().unwrap_or(1).max(1) as f32;
+
+        if let Ok(mut movement) = movements.get_mut(entity) {
+            // Members that changed culture, were absorbed elsewhere, or grew too big drop out
+            let current = gather_members(entity, movement.culture, &nations_query);
+            movement.members.retain(|member| current.contains(member));
+
+            let fighting_member = attacking.is_some_and(|a| movement.members.contains(&a.0))
+                || movement.members.iter().any(|&member| {
+                    nations_query
+                        .get(member)
+                        .is_ok_and(|(_, _, _, _, a)| a.is_some_and(|a| a.0 == entity || movement.members.contains(&a.0)))
+                });
+
+            if movement.members.is_empty() || nation.stability < MIN_UNIFIER_STABILITY * 0.5 {
+                info!(
+                    "The {} unification movement led by {} has collapsed",
+                    crate::nations::culture_to_display_name(movement.culture),
+                    nation.name
+                );
+                commands.entity(entity).remove::<UnificationMovement>();
+            } else if !fighting_member {
+                // Wars between members stall the movement
+                movement.progress = (movement.progress + yearly_progress(nation.stability, share)).min(1.0);
+            }
+            continue;
+        }
+
+        if committed.contains(&entity)
+            || nation.stability < MIN_UNIFIER_STABILITY
+            || !is_nationalism_era(nation, year)
+        {
+            continue;
+        }
+
+        let members: Vec<Entity> = gather_members(entity, nation.culture, &nations_query)
+            .into_iter()
+            .filter(|&member| !committed.contains(&member) && !movements.contains(member))
+            .collect();
+        if members.is_empty() {
+            continue;
+        }
+
+        info!(
+            "{} launches a {} unification movement with {} nations",
+            nation.name,
+            crate::nations::culture_to_display_name(nation.culture),
+            members.len()
+        );
+        committed.extend(members.iter().copied());
+        commands.entity(entity).insert(UnificationMovement {
+            culture: nation.culture,
+            members,
+            progress: 0.0,
+            started_year: year,
+        });
+    }
+}
+
+/// Merge members into the unifier once a movement completes
+pub fn complete_unifications(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut ownership: OwnershipService,
+    mut formed_events: MessageWriter<NationFormedEvent>,
+    mut renamed_events: MessageWriter<NationRenamed>,
+    mut audio: MessageWriter<AudioEvent>,
+    mut overlay_colors: ResMut<CachedOverlayColors>,
+    mut map_mode: ResMut<MapMode>,
+    movements: Query<(Entity, &UnificationMovement)>,
+    mut nations_query: Query<(&mut Nation, &NationId, &Governance, Option<&Controls>, Option<&mut NationHistory>)>,
+    attackers_query: Query<(Entity, &Attacking)>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+
+    for (unifier, movement) in &movements {
+        if movement.progress < 1.0 {
+            continue;
+        }
+
+        let mut absorbed_names = Vec::new();
+        let mut absorbed_provinces = Vec::new();
+        for &member in &movement.members {
+            let Ok((member_nation, _, _, controls, _)) = nations_query.get(member) else {
+                continue;
+            };
+            absorbed_names.push(member_nation.name.clone());
+            absorbed_provinces.extend_from_slice(controls.map(|c| c.provinces()).unwrap_or(&[]));
+
+            // Wars against an absorbed nation end with it
+            for (attacker, attacking) in &attackers_query {
+                if attacking.0 == member || attacker == member {
+                    commands.entity(attacker).remove::<Attacking>();
+                }
+            }
+            commands
+                .entity(member)
+                .remove::<(Nation, LandNeighborOf, NavalNeighborOf)>()
+                .insert(UnifiedInto { nation: unifier, year });
+        }
+
+        let Ok((mut nation, nation_id, governance, _, history)) = nations_query.get_mut(unifier) else {
+            continue;
+        };
+
+        // The nation-state takes a new name and color
+        let old_name = nation.name.clone();
+        let (new_name, _ruler_title) = crate::nations::generate_governance_aware_name(
+            &mut NameGenerator::new(),
+            movement.culture,
+            &governance.government_type,
+        );
+        let mut rng = StdRng::seed_from_u64(((nation_id.value() as u64) << 32) | year as u64);
+        nation.name = new_name.clone();
+        nation.adjective = crate::nations::generate_adjective(&new_name);
+        nation.color = crate::nations::generate_nation_color(nation_id.value(), &mut rng);
+        nation.stability = (nation.stability + 0.1).min(1.0);
+
+        if let Some(mut history) = history {
+            history.record_event(HistoricalEvent::NationUnified {
+                year,
+                former_name: old_name.clone(),
+                nations_absorbed: absorbed_names.len() as u32,
+            });
+            history.record_rename(old_name.clone(), new_name.clone(), year);
+        }
+        let provinces_moved = absorbed_provinces.len();
+
+        info!(
+            "{} proclaims the unification of {} as {} ({} provinces absorbed)",
+            old_name,
+            absorbed_names.join(", "),
+            new_name,
+            provinces_moved
+        );
+
+        commands.entity(unifier).remove::<UnificationMovement>();
+        ownership.transfer(absorbed_provinces, unifier, OwnershipChangeType::Diplomatic);
+        renamed_events.write(NationRenamed {
+            nation_entity: unifier,
+            old_name: old_name.clone(),
+            new_name: new_name.clone(),
+            year,
+        });
+        audio.write(AudioEvent::new(AudioCue::NationFormed));
+        formed_events.write(NationFormedEvent {
+            nation: unifier,
+            old_name,
+            new_name,
+            absorbed: absorbed_names,
+            year,
+        });
+
+        // Recolor the map immediately
+        overlay_colors.clear_cache();
+        map_mode.set_changed();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stable_dominant_unifiers_progress_faster() {
+        let weak = yearly_progress(0.5, 0.3);
+        let strong = yearly_progress(0.9, 0.7);
+
+        assert!(strong > weak);
+        assert!(strong < 0.1);
+    }
+}
@@ -0,0 +1,280 @@
+//! Campaign attrition - what terrain and climate cost armies in the field
+//!
+//! Armies at war campaign across the provinces on either side of the front,
+//! and each terrain takes its toll (see `AttritionProfile`). Desert fronts
+//! need water carried along the supply lines, and an army whose supply
+//! fails goes thirsty. Cold fronts kill soldiers in winter unless they have
+//! winter gear, which nations make from the furs and wool of their own cold
+//! and pastoral provinces; hard winters make it worse. Jungle and swamp
+//! fronts spread disease, which medicine from later technology holds back.
+//!
+//! A poorly supplied army suffers more from all of these.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+
+use crate::ai::{decision_rng, DecisionDomain};
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::math::Money;
+use crate::nations::{Attacking, Logistics, Nation, NationId, ParticipatesInWar};
+use crate::simulation::NewYearEvent;
+use crate::world::{
+    winter_severity, AttritionProfile, AttritionProfiles, ClimateStorage, ProvinceStorage, TerrainType, WorldSeed,
+};
+
+/// Winter gear made each year by each cold or pastoral province
+const GEAR_PER_PROVINCE: f32 = 2.0;
+/// Share of winter gear worn out each year, and of gear in use on campaign
+const GEAR_WEAR: f32 = 0.2;
+/// Treasury cost of carrying water for one point of water need
+const WATER_PRICE: f32 = 0.5;
+/// Yearly chance of a hard winter across the world
+const HARD_WINTER_CHANCE: f32 = 0.15;
+/// Winter severity multiplier in a hard winter
+const HARD_WINTER_SEVERITY: f32 = 1.5;
+/// Disease losses prevented per technology level
+const MEDICINE_PER_LEVEL: f32 = 0.08;
+/// Most disease losses medicine can prevent
+const MAX_MEDICINE: f32 = 0.6;
+/// Most of its strength an army can lose to attrition in a year
+const MAX_YEARLY_LOSS: f32 = 0.5;
+/// Yearly loss worth recording in the chronicle
+const CHRONICLE_LOSS: f32 = 0.15;
+
+/// A nation's winter gear and last year's losses to terrain and climate
+#[derive(Component, Debug, Clone, Default, Reflect, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct CampaignAttrition {
+    /// Winter gear held for the army
+    pub winter_gear: f32,
+    /// Share of the army's water need that was met
+    pub water_supplied: f32,
+    /// Share of strength lost to thirst
+    pub thirst: f32,
+    /// Share of strength lost to cold
+    pub cold: f32,
+    /// Share of strength lost to disease
+    pub disease: f32,
+    /// Whether last winter was a hard one
+    pub hard_winter: bool,
+}
+
+impl CampaignAttrition {
+    pub fn total(&self) -> f32 {
+        self.thirst + self.cold + self.disease
+    }
+}
+
+/// Whether a terrain's people make winter gear
+fn makes_winter_gear(terrain: TerrainType) -> bool {
+    matches!(
+        terrain,
+        TerrainType::Tundra
+            | TerrainType::Taiga
+            | TerrainType::BorealForest
+            | TerrainType::Alpine
+            | TerrainType::TemperateGrassland
+            | TerrainType::ColdDesert
+    )
+}
+
+/// One year's losses on a front with the given average profile
+///
+/// `water_met` and `gear_met` are the shares of water and winter gear needs
+/// that were covered, and `supplied` the share of general supply needs.
+pub fn campaign_losses(
+    front: AttritionProfile,
+    water_met: f32,
+    gear_met: f32,
+    medicine: f32,
+    supplied: f32,
+) -> (f32, f32, f32) {
+    let hunger = 2.0 - supplied.clamp(0.0, 1.0);
+    let thirst = front.water * (1.0 - water_met.clamp(0.0, 1.0)) * hunger;
+    let cold = front.cold * (1.0 - gear_met.clamp(0.0, 1.0)) * hunger;
+    let disease = front.disease * (1.0 - medicine.clamp(0.0, MAX_MEDICINE)) * hunger;
+    (thirst, cold, disease)
+}
+
+/// Yearly winter gear production and attrition on every nation's fronts
+pub fn apply_campaign_attrition(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    province_storage: Option<Res<ProvinceStorage>>,
+    climate_storage: Option<Res<ClimateStorage>>,
+    profiles: Option<Res<AttritionProfiles>>,
+    world_seed: Option<Res<WorldSeed>>,
+    mut nations_query: Query<(
+        Entity,
+        &mut Nation,
+        &NationId,
+        Option<&Logistics>,
+        Option<&ParticipatesInWar>,
+        Option<&mut CampaignAttrition>,
+    )>,
+    attackers_query: Query<(Entity, &Attacking)>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+    let Some(storage) = province_storage else {
+        return;
+    };
+    let profiles = profiles.map(|profiles| profiles.clone()).unwrap_or_default();
+    let seed = world_seed.map_or(0, |seed| seed.0);
+    let hard_winter = decision_rng(seed, DecisionDomain::Military, 0, 2, year).r#gen::<f32>() < HARD_WINTER_CHANCE;
+    let winter = |index: usize| {
+        let temperature = storage
+            .provinces
+            .get(index)
+            .and_then(|province| climate_storage.as_ref()?.climates.get(&province.id))
+            .map_or(10.0, |climate| climate.temperature);
+        let severity = winter_severity(temperature);
+        if hard_winter {
+            (severity * HARD_WINTER_SEVERITY).min(1.0)
+        } else {
+            severity
+        }
+    };
+
+    let enemies: HashSet<(Entity, Entity)> = attackers_query
+        .iter()
+        .flat_map(|(attacker, attacking)| [(attacker, attacking.0), (attacking.0, attacker)])
+        .collect();
+
+    // Winter gear makers, and the profile of the fighting on each nation's fronts
+    let mut gear_makers: HashMap<Entity, usize> = HashMap::new();
+    let mut fronts: HashMap<Entity, (AttritionProfile, usize)> = HashMap::new();
+    for (index, province) in storage.provinces.iter().enumerate() {
+        let Some(owner) = province.owner_entity else {
+            continue;
+        };
+        if makes_winter_gear(province.terrain) {
+            *gear_makers.entry(owner).or_default() += 1;
+        }
+
+        let mut profile = profiles.get(province.terrain);
+        profile.cold *= winter(index);
+        let mut fought_over_by: HashSet<Entity> = HashSet::new();
+        for neighbor in province.neighbors.iter().flatten() {
+            let Some(neighbor_owner) = storage
+                .provinces
+                .get(neighbor.value() as usize)
+                .and_then(|neighbor| neighbor.owner_entity)
+            else {
+                continue;
+            };
+            if enemies.contains(&(owner, neighbor_owner)) {
+                // Both the defender and the invader campaign in a front province
+                fought_over_by.insert(owner);
+                fought_over_by.insert(neighbor_owner);
+            }
+        }
+        for nation in fought_over_by {
+            let (total, count) = fronts.entry(nation).or_default();
+            total.water += profile.water;
+            total.cold += profile.cold;
+            total.disease += profile.disease;
+            *count += 1;
+        }
+    }
+
+    for (entity, mut nation, nation_id, logistics, at_war, attrition) in &mut nations_query {
+        let mut updated = attrition.as_deref().cloned().unwrap_or_default();
+        updated.winter_gear = updated.winter_gear * (1.0 - GEAR_WEAR)
+            + gear_makers.get(&entity).copied().unwrap_or(0) as f32 * GEAR_PER_PROVINCE;
+        updated.hard_winter = hard_winter;
+        updated.water_supplied = 1.0;
+        (updated.thirst, updated.cold, updated.disease) = (0.0, 0.0, 0.0);
+
+        if let (Some(_), Some(&(total, count))) = (at_war, fronts.get(&entity)) {
+            let strength = nation.military_strength.max(0.0);
+            let front = AttritionProfile {
+                water: total.water / count as f32,
+                cold: total.cold / count as f32,
+                disease: total.disease / count as f32,
+            };
+            let supplied = logistics.map_or(1.0, |logistics| logistics.supplied);
+
+            // Water comes up the supply lines, paid for from the treasury
+            let water_need = strength * front.water;
+            let water_cost = Money::from_f32(water_need * WATER_PRICE);
+            updated.water_supplied = if nation.treasury >= water_cost {
+                supplied
+            } else {
+                supplied * 0.5
+            };
+            nation.treasury -= water_cost.min(nation.treasury.max(Money::ZERO));
+
+            let gear_need = strength * front.cold;
+            let gear_met = if gear_need > 0.0 {
+                updated.winter_gear / gear_need
+            } else {
+                1.0
+            };
+            updated.winter_gear -= updated.winter_gear.min(gear_need) * GEAR_WEAR;
+
+            let medicine = nation.technology_level as f32 * MEDICINE_PER_LEVEL;
+            (updated.thirst, updated.cold, updated.disease) =
+                campaign_losses(front, updated.water_supplied, gear_met, medicine, supplied);
+
+            let loss = updated.total().min(MAX_YEARLY_LOSS);
+            nation.military_strength *= 1.0 - loss;
+            if loss >= CHRONICLE_LOSS {
+                let cause = if updated.cold >= updated.thirst.max(updated.disease) {
+                    if hard_winter {
+                        "a hard winter"
+                    } else {
+                        "the winter"
+                    }
+                } else if updated.thirst >= updated.disease {
+                    "thirst"
+                } else {
+                    "fever"
+                };
+                chronicle.write(ChronicleEvent {
+                    category: ChronicleCategory::War,
+                    text: format!("{} loses {:.0}% of its army to {}", nation.name, loss * 100.0, cause),
+                    nations: vec![*nation_id],
+                });
+            }
+        }
+
+        match attrition {
+            Some(mut attrition) => *attrition = updated,
+            None => {
+                commands.entity(entity).insert(updated);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gear_water_and_supply_each_blunt_their_own_hazard() {
+        let tundra = AttritionProfile {
+            cold: 0.3,
+            ..Default::default()
+        };
+        let desert = AttritionProfile {
+            water: 0.3,
+            ..Default::default()
+        };
+
+        let (_, ungeared, _) = campaign_losses(tundra, 1.0, 0.0, 0.0, 1.0);
+        let (_, geared, _) = campaign_losses(tundra, 1.0, 1.0, 0.0, 1.0);
+        assert!(ungeared > 0.0 && geared == 0.0);
+
+        let (watered, ..) = campaign_losses(desert, 1.0, 0.0, 0.0, 1.0);
+        let (thirsty, ..) = campaign_losses(desert, 0.5, 0.0, 0.0, 1.0);
+        let (starving, ..) = campaign_losses(desert, 0.5, 0.0, 0.0, 0.0);
+        assert_eq!(watered, 0.0);
+        assert!(starving > thirsty && thirsty > 0.0);
+    }
+}
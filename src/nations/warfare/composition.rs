@@ -0,0 +1,262 @@
+//! Army composition - the mix of infantry, cavalry, and artillery
+//!
+//! Each nation organizes its army around a template suited to its doctrine,
+//! its land, and its temperament: steppe peoples field horse armies, small
+//! city-states in rough country stand behind dense pike blocks, and
+//! gunpowder states bring guns. Recruits each year fill out the template, so
+//! an army that changes template converts over several years.
+//!
+//! Arms counter one another - infantry holds off cavalry, cavalry rides down
+//! artillery, artillery breaks infantry - so the same two strengths produce
+//! different battles depending on what each side brings.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::doctrine::{Doctrine, MilitaryDoctrine};
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::nations::{Nation, NationId};
+use crate::simulation::NewYearEvent;
+use crate::world::{ProvinceStorage, TerrainType};
+use std::collections::HashMap;
+
+/// Share of the army replaced by template-following recruits each year
+const REINFORCEMENT_SHARE: f32 = 0.25;
+/// Strength swing from a complete counter (all cavalry against all infantry)
+const MATCHUP_WEIGHT: f32 = 0.3;
+/// Provinces at or below which a nation fights like a city-state
+const CITY_STATE_PROVINCES: usize = 8;
+
+/// How a nation organizes its army
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect, Serialize, Deserialize)]
+pub enum ArmyTemplate {
+    #[default]
+    FeudalLevy,
+    SteppeHorde,
+    PikeSquare,
+    CombinedArms,
+    GrandBattery,
+}
+
+impl ArmyTemplate {
+    pub const ALL: [ArmyTemplate; 5] = [
+        ArmyTemplate::FeudalLevy,
+        ArmyTemplate::SteppeHorde,
+        ArmyTemplate::PikeSquare,
+        ArmyTemplate::CombinedArms,
+        ArmyTemplate::GrandBattery,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ArmyTemplate::FeudalLevy => "Feudal Levy",
+            ArmyTemplate::SteppeHorde => "Steppe Horde",
+            ArmyTemplate::PikeSquare => "Pike Square",
+            ArmyTemplate::CombinedArms => "Combined Arms",
+            ArmyTemplate::GrandBattery => "Grand Battery",
+        }
+    }
+
+    /// Shares of infantry, cavalry, and artillery the template calls for
+    pub fn shares(&self) -> [f32; 3] {
+        match self {
+            ArmyTemplate::FeudalLevy => [0.6, 0.35, 0.05],
+            ArmyTemplate::SteppeHorde => [0.2, 0.75, 0.05],
+            ArmyTemplate::PikeSquare => [0.75, 0.1, 0.15],
+            ArmyTemplate::CombinedArms => [0.5, 0.25, 0.25],
+            ArmyTemplate::GrandBattery => [0.45, 0.15, 0.4],
+        }
+    }
+
+    /// Doctrine needed to field the template
+    fn min_doctrine(&self) -> Doctrine {
+        match self {
+            ArmyTemplate::FeudalLevy | ArmyTemplate::SteppeHorde | ArmyTemplate::PikeSquare => Doctrine::Levy,
+            ArmyTemplate::CombinedArms => Doctrine::Professional,
+            ArmyTemplate::GrandBattery => Doctrine::Gunpowder,
+        }
+    }
+}
+
+/// What a nation weighs when choosing a template
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TemplateFactors {
+    pub doctrine: Doctrine,
+    /// Share of the nation's land that is open grassland or desert
+    pub open_terrain: f32,
+    pub provinces: usize,
+    pub aggression: f32,
+    pub mercantilism: f32,
+}
+
+/// The template that best suits a nation
+pub fn choose_template(factors: TemplateFactors) -> ArmyTemplate {
+    let rough_terrain = 1.0 - factors.open_terrain;
+    let score = |template: &ArmyTemplate| match template {
+        ArmyTemplate::FeudalLevy => 0.3,
+        ArmyTemplate::SteppeHorde => factors.open_terrain + factors.aggression.max(0.0) * 0.3 - 0.2,
+        ArmyTemplate::PikeSquare => {
+            let city_state = if factors.provinces <= CITY_STATE_PROVINCES { 0.3 } else { 0.0 };
+            city_state + rough_terrain * 0.3 + factors.mercantilism.max(0.0) * 0.2
+                - factors.aggression.max(0.0) * 0.2
+        }
+        ArmyTemplate::CombinedArms => 0.5,
+        ArmyTemplate::GrandBattery => 0.7,
+    };
+    ArmyTemplate::ALL
+        .iter()
+        .filter(|template| template.min_doctrine() <= factors.doctrine)
+        .max_by(|a, b| score(a).total_cmp(&score(b)))
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Whether a terrain favours horsemen
+fn is_open_terrain(terrain: TerrainType) -> bool {
+    matches!(
+        terrain,
+        TerrainType::TemperateGrassland
+            | TerrainType::Savanna
+            | TerrainType::Chaparral
+            | TerrainType::ColdDesert
+            | TerrainType::SubtropicalDesert
+            | TerrainType::TropicalDesert
+            | TerrainType::Tundra
+    )
+}
+
+/// A nation's army, by arm
+#[derive(Component, Debug, Clone, Reflect, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct ArmyComposition {
+    pub template: ArmyTemplate,
+    pub infantry: f32,
+    pub cavalry: f32,
+    pub artillery: f32,
+}
+
+impl Default for ArmyComposition {
+    fn default() -> Self {
+        Self::from_template(ArmyTemplate::default())
+    }
+}
+
+impl ArmyComposition {
+    pub fn from_template(template: ArmyTemplate) -> Self {
+        let [infantry, cavalry, artillery] = template.shares();
+        Self {
+            template,
+            infantry,
+            cavalry,
+            artillery,
+        }
+    }
+
+    /// Replace a share of the army with recruits raised to the template
+    pub fn reinforce(&mut self, share: f32) {
+        let [infantry, cavalry, artillery] = self.template.shares();
+        self.infantry += (infantry - self.infantry) * share;
+        self.cavalry += (cavalry - self.cavalry) * share;
+        self.artillery += (artillery - self.artillery) * share;
+    }
+
+    /// Strength multiplier this army gets from what it counters in the other
+    pub fn matchup(&self, enemy: &ArmyComposition) -> f32 {
+        let counters = |a: &ArmyComposition, b: &ArmyComposition| {
+            a.infantry * b.cavalry + a.cavalry * b.artillery + a.artillery * b.infantry
+        };
+        1.0 + (counters(self, enemy) - counters(enemy, self)) * MATCHUP_WEIGHT
+    }
+}
+
+/// Yearly choice of army template and reinforcement toward it
+pub fn manage_army_composition(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    province_storage: Option<Res<ProvinceStorage>>,
+    mut nations_query: Query<(
+        Entity,
+        &Nation,
+        &NationId,
+        Option<&MilitaryDoctrine>,
+        Option<&mut ArmyComposition>,
+    )>,
+) {
+    if year_events.read().last().is_none() {
+        return;
+    }
+    let Some(storage) = province_storage else {
+        return;
+    };
+
+    let mut land: HashMap<Entity, (usize, usize)> = HashMap::new();
+    for province in &storage.provinces {
+        if let Some(owner) = province.owner_entity {
+            let (total, open) = land.entry(owner).or_default();
+            *total += 1;
+            if is_open_terrain(province.terrain) {
+                *open += 1;
+            }
+        }
+    }
+
+    for (entity, nation, nation_id, doctrine, composition) in &mut nations_query {
+        let (provinces, open) = land.get(&entity).copied().unwrap_or((0, 0));
+        let template = choose_template(TemplateFactors {
+            doctrine: doctrine.map_or(Doctrine::Levy, |doctrine| doctrine.current),
+            open_terrain: open as f32 / provinces.max(1) as f32,
+            provinces,
+            aggression: nation.personality.aggression,
+            mercantilism: nation.personality.mercantilism,
+        });
+
+        let Some(mut composition) = composition else {
+            commands.entity(entity).insert(ArmyComposition::from_template(template));
+            continue;
+        };
+        if composition.template != template {
+            chronicle.write(ChronicleEvent {
+                category: ChronicleCategory::War,
+                text: format!("{} reorganizes its army as a {}", nation.name, template.name()),
+                nations: vec![*nation_id],
+            });
+            composition.template = template;
+        }
+        composition.reinforce(REINFORCEMENT_SHARE);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn land_and_size_shape_the_army() {
+        let steppe = TemplateFactors {
+            doctrine: Doctrine::Levy,
+            open_terrain: 0.9,
+            provinces: 40,
+            aggression: 0.6,
+            ..Default::default()
+        };
+        let city_state = TemplateFactors {
+            doctrine: Doctrine::Levy,
+            open_terrain: 0.1,
+            provinces: 4,
+            mercantilism: 0.7,
+            ..Default::default()
+        };
+        assert_eq!(choose_template(steppe), ArmyTemplate::SteppeHorde);
+        assert_eq!(choose_template(city_state), ArmyTemplate::PikeSquare);
+    }
+
+    #[test]
+    fn pikes_beat_horse_armies() {
+        let pikes = ArmyComposition::from_template(ArmyTemplate::PikeSquare);
+        let horde = ArmyComposition::from_template(ArmyTemplate::SteppeHorde);
+        assert!(pikes.matchup(&horde) > 1.0);
+        assert!(horde.matchup(&pikes) < 1.0);
+        assert_eq!(pikes.matchup(&pikes), 1.0);
+    }
+}
@@ -0,0 +1,212 @@
+//! Military doctrine - how technology changes the way nations fight
+//!
+//! Each technology era unlocks a doctrine: feudal levies, professional
+//! standing armies, gunpowder, and disciplined line infantry. Newer doctrines
+//! hit harder and break fortifications more easily, but cost more to keep
+//! under arms. Armies take years to retrain, and richer, better-governed
+//! states retrain faster, so a nation that has just reached gunpowder still
+//! fights partly with pikes - and one that got there first can overrun
+//! neighbours still fielding levies.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::math::Money;
+use crate::nations::{Governance, Nation, NationId};
+use crate::simulation::NewYearEvent;
+
+/// Share of the transition completed each year with no institutions
+const BASE_ADOPTION: f32 = 0.1;
+/// Extra share completed each year with fully developed institutions
+const INSTITUTION_ADOPTION: f32 = 0.15;
+
+/// The way a nation's armies fight
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Reflect, Serialize, Deserialize)]
+pub enum Doctrine {
+    #[default]
+    Levy,
+    Professional,
+    Gunpowder,
+    LineInfantry,
+}
+
+impl Doctrine {
+    pub const ALL: [Doctrine; 4] = [
+        Doctrine::Levy,
+        Doctrine::Professional,
+        Doctrine::Gunpowder,
+        Doctrine::LineInfantry,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Doctrine::Levy => "Levy",
+            Doctrine::Professional => "Professional",
+            Doctrine::Gunpowder => "Gunpowder",
+            Doctrine::LineInfantry => "Line Infantry",
+        }
+    }
+
+    /// Technology level that unlocks the doctrine
+    pub fn min_technology(&self) -> u32 {
+        match self {
+            Doctrine::Levy => 0,
+            Doctrine::Professional => 2,
+            Doctrine::Gunpowder => 4,
+            Doctrine::LineInfantry => 6,
+        }
+    }
+
+    /// Battle strength multiplier
+    fn combat_power(&self) -> f32 {
+        match self {
+            Doctrine::Levy => 1.0,
+            Doctrine::Professional => 1.3,
+            Doctrine::Gunpowder => 1.8,
+            Doctrine::LineInfantry => 2.3,
+        }
+    }
+
+    /// Share of a defender's fortification advantage the doctrine negates
+    fn siege_power(&self) -> f32 {
+        match self {
+            Doctrine::Levy => 0.0,
+            Doctrine::Professional => 0.2,
+            Doctrine::Gunpowder => 0.6,
+            Doctrine::LineInfantry => 0.8,
+        }
+    }
+
+    /// Yearly treasury cost per point of military strength
+    fn upkeep(&self) -> f32 {
+        match self {
+            Doctrine::Levy => 0.02,
+            Doctrine::Professional => 0.08,
+            Doctrine::Gunpowder => 0.12,
+            Doctrine::LineInfantry => 0.15,
+        }
+    }
+
+    /// Most advanced doctrine a technology level allows
+    pub fn unlocked(technology_level: u32) -> Doctrine {
+        Doctrine::ALL
+            .iter()
+            .rev()
+            .find(|doctrine| doctrine.min_technology() <= technology_level)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn next(&self) -> Option<Doctrine> {
+        Doctrine::ALL.iter().find(|doctrine| *doctrine > self).copied()
+    }
+}
+
+/// A nation's doctrine and its progress retraining toward the next one
+#[derive(Component, Debug, Clone, Default, Reflect, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct MilitaryDoctrine {
+    pub current: Doctrine,
+    /// Doctrine the army is retraining toward
+    pub adopting: Option<Doctrine>,
+    /// Share of the army retrained (0.0-1.0)
+    pub adoption: f32,
+}
+
+impl MilitaryDoctrine {
+    /// Blend a doctrine property between the old and new ways of fighting
+    fn blend(&self, property: impl Fn(&Doctrine) -> f32) -> f32 {
+        match self.adopting {
+            Some(next) => property(&self.current) + (property(&next) - property(&self.current)) * self.adoption,
+            None => property(&self.current),
+        }
+    }
+
+    pub fn combat_power(&self) -> f32 {
+        self.blend(Doctrine::combat_power)
+    }
+
+    pub fn siege_power(&self) -> f32 {
+        self.blend(Doctrine::siege_power)
+    }
+
+    pub fn upkeep(&self) -> f32 {
+        self.blend(Doctrine::upkeep)
+    }
+
+    /// Retrain a share of the army; returns the doctrine completed, if any
+    pub fn advance(&mut self, share: f32) -> Option<Doctrine> {
+        let next = self.adopting?;
+        self.adoption += share;
+        if self.adoption < 1.0 {
+            return None;
+        }
+        self.current = next;
+        self.adopting = None;
+        self.adoption = 0.0;
+        Some(next)
+    }
+}
+
+/// Yearly doctrine adoption and army upkeep
+pub fn adopt_doctrines(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    mut nations_query: Query<(Entity, &mut Nation, &NationId, &Governance, Option<&mut MilitaryDoctrine>)>,
+) {
+    if year_events.read().last().is_none() {
+        return;
+    }
+
+    for (entity, mut nation, nation_id, governance, doctrine) in &mut nations_query {
+        let mut updated = doctrine.as_deref().cloned().unwrap_or_default();
+
+        if updated.adopting.is_none() && Doctrine::unlocked(nation.technology_level) > updated.current {
+            updated.adopting = updated.current.next();
+        }
+        // Retraining stalls while the army goes unpaid
+        let share = if nation.treasury > Money::ZERO {
+            BASE_ADOPTION + governance.institution_strength.clamp(0.0, 1.0) * INSTITUTION_ADOPTION
+        } else {
+            BASE_ADOPTION * 0.5
+        };
+        if let Some(completed) = updated.advance(share) {
+            chronicle.write(ChronicleEvent {
+                category: ChronicleCategory::War,
+                text: format!("{} completes its move to {} warfare", nation.name, completed.name()),
+                nations: vec![*nation_id],
+            });
+        }
+
+        nation.treasury -= Money::from_f32(nation.military_strength.max(0.0) * updated.upkeep());
+
+        match doctrine {
+            Some(mut doctrine) => *doctrine = updated,
+            None => {
+                commands.entity(entity).insert(updated);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transitions_blend_old_and_new_doctrines() {
+        let mut doctrine = MilitaryDoctrine {
+            current: Doctrine::Professional,
+            adopting: Some(Doctrine::Gunpowder),
+            adoption: 0.5,
+        };
+        assert!(doctrine.combat_power() > Doctrine::Professional.combat_power());
+        assert!(doctrine.combat_power() < Doctrine::Gunpowder.combat_power());
+
+        assert_eq!(doctrine.advance(0.6), Some(Doctrine::Gunpowder));
+        assert_eq!(doctrine.current, Doctrine::Gunpowder);
+        assert_eq!(Doctrine::unlocked(4), Doctrine::Gunpowder);
+    }
+}
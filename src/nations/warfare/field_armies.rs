@@ -0,0 +1,505 @@
+//! Field armies - where a nation's strength stands and where it is going
+//!
+//! Each year a nation's military strength is mustered into field armies: one
+//! for every enemy it is at war with, and a home army kept in reserve. The
+//! home army stands guard in whichever of the nation's regions lies most
+//! under threat, at the capital when none does. Front armies are led by the ruling house's generals, the ablest
+//! first, and then by the ruler. Each front army is ordered onto an objective:
+//! an enemy-held province named in the war goal, one of its own that the
+//! enemy wants, or else the enemy capital. It marches there province by
+//! province, slower when hungry, and besieges an enemy objective once it
+//! arrives. The further an army marches beyond its own borders, the less of
+//! the national stores reach it. Armies whose war has ended are disbanded
+//! back into the reserve, and their `ArmyId`s go back to the allocator.
+
+use bevy::prelude::*;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+use super::war::{War, WarGoal};
+use crate::ai::InfluenceMaps;
+use crate::camera::{FollowKind, Followable};
+use crate::constants::SIMULATION_DAYS_PER_YEAR;
+use crate::diagnostics::StressLoad;
+use crate::ids::{ArmyId, IdAllocator};
+use crate::math::{Fixed32, Manpower, HEX_SIZE};
+use crate::nations::{
+    Attacking, Character, CharacterRole, Corruption, Deceased, Logistics, Nation, NationHistory, ParticipatesInWar,
+};
+use crate::relationships::{Army, ArmyType, GarrisonedIn, RuledBy, StationedIn};
+use crate::simulation::{GameTime, NewYearEvent};
+use crate::world::{ProvinceEntityOrder, ProvinceGraph, ProvinceId, ProvinceStorage, RegionHierarchy, TerrainType};
+
+/// Soldiers per point of military strength
+const SOLDIERS_PER_STRENGTH: f32 = 100.0;
+/// Share of strength sent to the fronts at war; the rest stays in reserve
+const FRONT_SHARE: f32 = 0.8;
+/// Distance a fully supplied army marches in a day
+const MARCH_PER_DAY: f32 = HEX_SIZE * 0.15;
+/// Most days of marching caught up in one frame at high game speed
+const MAX_MARCH_DAYS: u32 = 30;
+/// Supply lost per province marched beyond the border, under the worst planning
+const OVEREXTENSION: f32 = 0.08;
+/// Experience gained by a front army for each year at war
+const EXPERIENCE_PER_YEAR: f32 = 0.1;
+
+/// What an army has been told to do
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum ArmyOrder {
+    /// Marching on an enemy-held province
+    Advance,
+    /// Encamped before an enemy-held objective
+    Besiege,
+    /// Marching to or holding a province of its own
+    Hold,
+    /// Kept back at home until needed
+    Reserve,
+}
+
+impl ArmyOrder {
+    pub fn label(self) -> &'static str {
+        match self {
+            ArmyOrder::Advance => "Advancing on",
+            ArmyOrder::Besiege => "Besieging",
+            ArmyOrder::Hold => "Holding",
+            ArmyOrder::Reserve => "In reserve at",
+        }
+    }
+
+    /// Order once the objective is reached
+    fn on_arrival(self) -> Self {
+        match self {
+            ArmyOrder::Advance => ArmyOrder::Besiege,
+            order => order,
+        }
+    }
+}
+
+/// A field army's theater, commander, orders, and march
+///
+/// Province references are indices into `ProvinceStorage`.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct FieldArmy {
+    /// Enemy this army is fighting, or `None` for the home reserve
+    pub theater: Option<Entity>,
+    pub commander: Option<String>,
+    pub order: ArmyOrder,
+    pub objective: usize,
+    /// Provinces from where the march began to the objective
+    pub route: Vec<usize>,
+    /// How far along `route` the army has come
+    pub progress: usize,
+    /// Where the army is on the map
+    pub position: Vec2,
+    /// Direction of march, in radians counterclockwise from east
+    pub heading: f32,
+    /// Share of its needs reaching the army (0.0-1.0)
+    pub supply: f32,
+}
+
+impl FieldArmy {
+    /// Province the army last reached
+    pub fn location(&self) -> Option<usize> {
+        self.route.get(self.progress).copied()
+    }
+
+    /// Provinces still to cross, starting where the army is
+    pub fn remaining_route(&self) -> &[usize] {
+        self.route.get(self.progress..).unwrap_or(&[])
+    }
+
+    /// Whether the army has reached its objective
+    pub fn arrived(&self) -> bool {
+        self.progress + 1 >= self.route.len()
+    }
+}
+
+/// Shortest march from one province to another, both ends included
+pub fn march_route<I: IntoIterator<Item = usize>>(
+    from: usize,
+    to: usize,
+    neighbors: impl Fn(usize) -> I,
+) -> Option<Vec<usize>> {
+    let mut came_from: HashMap<usize, usize> = HashMap::from([(from, from)]);
+    let mut frontier = VecDeque::from([from]);
+    while let Some(current) = frontier.pop_front() {
+        if current == to {
+            let mut route = vec![to];
+            let mut step = to;
+            while step != from {
+                step = came_from[&step];
+                route.push(step);
+            }
+            route.reverse();
+            return Some(route);
+        }
+        for next in neighbors(current) {
+            if !came_from.contains_key(&next) {
+                came_from.insert(next, current);
+                frontier.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Share of its needs reaching an army `beyond_border` provinces into foreign land
+pub fn army_supply(national_supply: f32, planning: f32, beyond_border: usize) -> f32 {
+    let loss = OVEREXTENSION * (1.0 - planning.clamp(0.0, 1.0) * 0.5) * beyond_border as f32;
+    (national_supply.clamp(0.0, 1.0) * (1.0 - loss)).clamp(0.0, 1.0)
+}
+
+/// Provinces named by a war goal
+fn war_goal_provinces(goal: &WarGoal) -> &[u32] {
+    match goal {
+        WarGoal::Conquest { target_provinces } => target_provinces,
+        WarGoal::Liberation { provinces_to_liberate } => provinces_to_liberate,
+        _ => &[],
+    }
+}
+
+/// Where each nation's home army stands guard: the most threatened of its
+/// provinces in the region where the threat to its holdings adds up highest
+fn guard_posts(storage: &ProvinceStorage, hierarchy: &RegionHierarchy, maps: &InfluenceMaps) -> HashMap<Entity, usize> {
+    // Threat summed over each nation's holdings in each region, and where it presses hardest
+    let mut holdings: BTreeMap<(Entity, usize), (f32, usize, f32)> = BTreeMap::new();
+    for (index, province) in storage.provinces.iter().enumerate() {
+        let (Some(owner), Some(region)) = (province.owner_entity, hierarchy.region_of(index)) else {
+            continue;
+        };
+        let threat = maps.threat_to(owner, index);
+        let (total, hardest, hardest_threat) = holdings.entry((owner, region)).or_insert((0.0, index, threat));
+        *total += threat;
+        if threat > *hardest_threat {
+            *hardest = index;
+            *hardest_threat = threat;
+        }
+    }
+
+    let mut posts: HashMap<Entity, (f32, usize)> = HashMap::new();
+    for ((owner, _), (total, hardest, _)) in holdings {
+        if total <= 0.0 {
+            continue;
+        }
+        let post = posts.entry(owner).or_insert((total, hardest));
+        if total > post.0 {
+            *post = (total, hardest);
+        }
+    }
+    posts.into_iter().map(|(owner, (_, province))| (owner, province)).collect()
+}
+
+/// Yearly muster of each nation's strength into front armies and a home reserve
+pub fn muster_field_armies(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    province_storage: Option<Res<ProvinceStorage>>,
+    entity_order: Option<Res<ProvinceEntityOrder>>,
+    province_graph: Option<Res<ProvinceGraph>>,
+    influence_maps: Option<Res<InfluenceMaps>>,
+    region_hierarchy: Option<Res<RegionHierarchy>>,
+    nations_query: Query<(
+        Entity,
+        &Nation,
+        Option<&NationHistory>,
+        Option<&Logistics>,
+        Option<&Corruption>,
+        Option<&RuledBy>,
+        Option<&ParticipatesInWar>,
+    )>,
+    attackers_query: Query<(Entity, &Attacking)>,
+    wars_query: Query<&War>,
+    characters_query: Query<&Character, Without<Deceased>>,
+    // Synthetic stress-test armies are left to the console that spawned them
+    mut armies_query: Query<(Entity, &mut Army, &mut FieldArmy), Without<StressLoad>>,
+    mut ids: ResMut<IdAllocator>,
+) {
+    if year_events.read().last().is_none() {
+        return;
+    }
+    let (Some(storage), Some(entity_order), Some(graph)) = (province_storage, entity_order, province_graph) else {
+        return;
+    };
+    let owner_at = |index: usize| storage.provinces.get(index).and_then(|province| province.owner_entity);
+    let index_of = |id: ProvinceId| storage.province_by_id.get(&id).copied();
+    let land_neighbors = |index: usize| {
+        graph
+            .neighbors(index)
+            .iter()
+            .map(|&neighbor| neighbor as usize)
+            .filter(|&neighbor| {
+                storage
+                    .provinces
+                    .get(neighbor)
+                    .is_some_and(|province| province.terrain != TerrainType::Ocean)
+            })
+            .collect::<Vec<_>>()
+    };
+    let home_posts = match (influence_maps.as_deref(), region_hierarchy.as_deref()) {
+        (Some(maps), Some(hierarchy)) => guard_posts(&storage, hierarchy, maps),
+        _ => HashMap::new(),
+    };
+    let enemies: BTreeSet<(Entity, Entity)> = attackers_query
+        .iter()
+        .flat_map(|(attacker, attacking)| [(attacker, attacking.0), (attacking.0, attacker)])
+        .collect();
+    let names: HashMap<Entity, &str> = nations_query
+        .iter()
+        .map(|(entity, nation, ..)| (entity, nation.name.as_str()))
+        .collect();
+
+    let mut existing: HashMap<(Entity, Option<Entity>), Entity> = armies_query
+        .iter()
+        .map(|(entity, army, field)| ((army.owner_nation, field.theater), entity))
+        .collect();
+
+    for (entity, nation, history, logistics, corruption, ruled_by, at_war) in &nations_query {
+        let Some(capital) = index_of(nation.capital_province).filter(|&index| owner_at(index) == Some(entity)) else {
+            continue;
+        };
+        let fronts: Vec<Entity> = enemies
+            .iter()
+            .filter(|(side, _)| *side == entity)
+            .map(|(_, enemy)| *enemy)
+            .collect();
+        let goal = at_war
+            .and_then(|at_war| wars_query.get(at_war.0).ok())
+            .map_or(&[][..], |war| war_goal_provinces(&war.war_goal));
+
+        // The house's generals lead the fronts, the ablest first, then the ruler
+        let house = ruled_by.and_then(RuledBy::current_ruler);
+        let mut generals: Vec<&Character> = characters_query
+            .iter()
+            .filter(|character| Some(character.house_id) == house && character.role == CharacterRole::General)
+            .collect();
+        generals.sort_by(|a, b| b.personality.competence.total_cmp(&a.personality.competence));
+        let mut commanders = generals
+            .into_iter()
+            .map(|general| general.name.clone())
+            .chain(history.map(|history| history.ruler.name.clone()));
+
+        let soldiers = nation.military_strength.max(0.0) * SOLDIERS_PER_STRENGTH;
+        let front_soldiers = if fronts.is_empty() {
+            0.0
+        } else {
+            soldiers * FRONT_SHARE / fronts.len() as f32
+        };
+        let planning = logistics.map_or(0.5, |logistics| logistics.planning);
+        let national_supply = logistics.map_or(1.0, |logistics| logistics.supplied);
+        let equipment = corruption.map_or(1.0, Corruption::supply_quality);
+
+        let theaters = fronts
+            .iter()
+            .map(|&enemy| {
+                let targets: Vec<usize> = goal.iter().filter_map(|&id| index_of(ProvinceId::new(id))).collect();
+                let (objective, order) = match targets.iter().find(|&&index| owner_at(index) == Some(enemy)) {
+                    Some(&target) => (Some(target), ArmyOrder::Advance),
+                    None => match targets.iter().find(|&&index| owner_at(index) == Some(entity)) {
+                        Some(&threatened) => (Some(threatened), ArmyOrder::Hold),
+                        None => (
+                            nations_query
+                                .get(enemy)
+                                .ok()
+                                .and_then(|(_, enemy_nation, ..)| index_of(enemy_nation.capital_province)),
+                            ArmyOrder::Advance,
+                        ),
+                    },
+                };
+                let name = format!("Army of the {} Front", names.get(&enemy).copied().unwrap_or("Foreign"));
+                (
+                    Some(enemy),
+                    name,
+                    objective.unwrap_or(capital),
+                    order,
+                    front_soldiers,
+                    commanders.next(),
+                )
+            })
+            .chain(std::iter::once((
+                None,
+                "Home Army".to_string(),
+                home_posts.get(&entity).copied().unwrap_or(capital),
+                ArmyOrder::Reserve,
+                soldiers - front_soldiers * fronts.len() as f32,
+                None,
+            )))
+            .collect::<Vec<_>>();
+
+        for (theater, name, objective, order, size, commander) in theaters {
+            let size = Manpower::new(size as u32);
+            match existing.remove(&(entity, theater)) {
+                Some(army_entity) => {
+                    let Ok((_, mut army, mut field)) = armies_query.get_mut(army_entity) else {
+                        continue;
+                    };
+                    army.size = size;
+                    army.equipment_quality = equipment;
+                    if theater.is_some() {
+                        army.experience = (army.experience + EXPERIENCE_PER_YEAR).min(1.0);
+                    }
+                    field.commander = commander;
+
+                    // New objectives are marched on from wherever the army stands
+                    if field.objective != objective {
+                        let start = field.location().unwrap_or(capital);
+                        field.route = march_route(start, objective, land_neighbors).unwrap_or_else(|| vec![start]);
+                        field.objective = field.route.last().copied().unwrap_or(start);
+                        field.progress = 0;
+                        field.order = order;
+                    }
+                    if field.arrived() {
+                        field.order = field.order.on_arrival();
+                    }
+                    let beyond = field
+                        .route
+                        .iter()
+                        .take(field.progress + 1)
+                        .filter(|&&index| owner_at(index) != Some(entity))
+                        .count();
+                    field.supply = army_supply(national_supply, planning, beyond);
+                    army.morale = 0.5 + 0.5 * field.supply;
+                }
+                None => {
+                    let (Some(province), Some(position)) = (
+                        entity_order.get(capital),
+                        storage.provinces.get(capital).map(|province| province.position),
+                    ) else {
+                        continue;
+                    };
+                    let route = march_route(capital, objective, land_neighbors).unwrap_or_else(|| vec![capital]);
+                    let mut field = FieldArmy {
+                        theater,
+                        commander,
+                        order,
+                        objective: route.last().copied().unwrap_or(capital),
+                        route,
+                        progress: 0,
+                        position,
+                        heading: 0.0,
+                        supply: national_supply.clamp(0.0, 1.0),
+                    };
+                    if field.arrived() {
+                        field.order = field.order.on_arrival();
+                    }
+                    let army_id = match ids.allocate::<ArmyId>() {
+                        Ok(army_id) => army_id,
+                        Err(e) => {
+                            warn!("{} cannot muster the {}: {}", nation.name, name, e);
+                            continue;
+                        }
+                    };
+                    debug!("{} musters the {} ({})", nation.name, name, size);
+                    commands.spawn((
+                        army_id,
+                        Followable {
+                            kind: FollowKind::Army,
+                            label: name.clone(),
+                            owner: Some(entity),
+                        },
+                        Transform::from_translation(position.extend(0.0)),
+                        Army {
+                            name,
+                            size,
+                            morale: 0.5 + 0.5 * field.supply,
+                            experience: 0.0,
+                            equipment_quality: equipment,
+                            army_type: ArmyType::Infantry,
+                            owner_nation: entity,
+                        },
+                        field,
+                        StationedIn(province),
+                    ));
+                }
+            }
+        }
+    }
+
+    // Armies of fallen nations and of wars that have ended are disbanded
+    for army_entity in existing.into_values() {
+        commands.entity(army_entity).despawn();
+    }
+}
+
+/// Daily march of field armies along their routes
+pub fn march_field_armies(
+    mut commands: Commands,
+    time: Res<GameTime>,
+    mut last_day: Local<Option<u32>>,
+    province_storage: Option<Res<ProvinceStorage>>,
+    entity_order: Option<Res<ProvinceEntityOrder>>,
+    mut armies_query: Query<(Entity, &mut FieldArmy, &mut Transform)>,
+) {
+    let today = time.current_year() * SIMULATION_DAYS_PER_YEAR as u32 + time.day_of_year();
+    let Some(previous) = last_day.replace(today) else {
+        return;
+    };
+    let days = today.saturating_sub(previous).min(MAX_MARCH_DAYS);
+    if days == 0 {
+        return;
+    }
+    let (Some(storage), Some(entity_order)) = (province_storage, entity_order) else {
+        return;
+    };
+
+    for (entity, mut field, mut transform) in &mut armies_query {
+        if field.arrived() {
+            continue;
+        }
+        // Hungry armies forage as they go and march slower
+        let mut budget = Fixed32::from_f32(MARCH_PER_DAY * days as f32 * (0.5 + 0.5 * field.supply));
+        while let Some(&next) = field.route.get(field.progress + 1) {
+            let Some(target) = storage.provinces.get(next).map(|province| province.position) else {
+                break;
+            };
+            // Distances and headings in fixed point, so every machine marches alike
+            let (dx, dy) = Fixed32::from_vec2(target - field.position);
+            let distance = Fixed32::hypot(dx, dy);
+            if distance > Fixed32::ZERO {
+                field.heading = Fixed32::atan2(dy, dx).to_f32();
+            }
+            if distance > budget {
+                let share = budget / distance;
+                field.position += Fixed32::to_vec2(dx * share, dy * share);
+                break;
+            }
+            budget -= distance;
+            field.position = target;
+            field.progress += 1;
+            // An army on the march leaves any fort it garrisoned
+            if let Some(province) = entity_order.get(next) {
+                commands.entity(entity).insert(StationedIn(province)).remove::<GarrisonedIn>();
+            }
+        }
+        // Keeps a following camera on the army
+        transform.translation = field.position.extend(transform.translation.z);
+        if field.arrived() {
+            field.order = field.order.on_arrival();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn routes_take_the_shortest_march_and_supply_thins_abroad() {
+        // A ring of eight provinces with a shortcut from 0 to 4
+        let neighbors = |index: usize| {
+            let mut next = vec![(index + 1) % 8, (index + 7) % 8];
+            match index {
+                0 => next.push(4),
+                4 => next.push(0),
+                _ => {}
+            }
+            next
+        };
+        assert_eq!(march_route(1, 5, neighbors), Some(vec![1, 0, 4, 5]));
+        assert_eq!(march_route(2, 2, neighbors), Some(vec![2]));
+        assert_eq!(march_route(0, 9, neighbors), None);
+
+        assert_eq!(army_supply(1.0, 1.0, 0), 1.0);
+        assert!(army_supply(1.0, 0.0, 5) < army_supply(1.0, 1.0, 5));
+        assert_eq!(army_supply(1.0, 0.0, 100), 0.0);
+    }
+}
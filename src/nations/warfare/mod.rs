@@ -4,14 +4,26 @@
 //! - Auto-resolve battle system with dice rolls
 //! - War state tracking (goals, participants, war score)
 //! - War declaration and resolution systems
+//! - Army templates and composition matchups
+//! - Technology-driven military doctrines
+//! - Terrain and climate attrition on campaign
+//! - Field armies mustered onto fronts, marching on their objectives
 
+mod attrition;
 mod battle;
+mod composition;
+mod doctrine;
+mod field_armies;
 mod war;
 mod systems;
 
+pub use attrition::{CampaignAttrition, apply_campaign_attrition};
 pub use battle::{Battle, BattleConfig, BattleResult, record_battle_outcome};
+pub use composition::{ArmyComposition, ArmyTemplate, manage_army_composition};
+pub use doctrine::{Doctrine, MilitaryDoctrine, adopt_doctrines};
+pub use field_armies::{ArmyOrder, FieldArmy, march_field_armies, muster_field_armies};
 pub use war::{War, WarGoal, WarOutcome, CasusBelli};
 pub use systems::{
-    DeclareWarEvent, BattleEvent, WarEndEvent, process_war_declarations, process_battle_events,
+    DeclareWarEvent, BattleEvent, BattleResolvedEvent, WarEndEvent, process_war_declarations, process_battle_events,
     check_war_resolution,
 };
@@ -3,8 +3,11 @@
 //! Systems for war declaration, battle resolution, and peace.
 
 use bevy::prelude::*;
-use rand::thread_rng;
-use crate::nations::{Nation, NationHistory, BattleOutcome, ParticipatesInWar, Attacking};
+use crate::ai::{decision_rng, DecisionDomain};
+use crate::audio::{AudioCue, AudioEvent};
+use crate::nations::{ArmyComposition, Corruption, FortNetwork, Logistics, MilitaryDoctrine, Nation, NationHistory, NationId, BattleOutcome, ParticipatesInWar, Attacking};
+use crate::simulation::GameTime;
+use crate::world::WorldSeed;
 use super::{War, WarGoal, CasusBelli, Battle, BattleConfig, record_battle_outcome, WarOutcome};
 
 /// Event: Nation declares war
@@ -24,6 +27,15 @@ pub struct BattleEvent {
     pub defender: Entity,
 }
 
+/// Event: A battle was fought and decided
+#[derive(Debug, Clone, Message)]
+pub struct BattleResolvedEvent {
+    pub war_id: u32,
+    pub winner: Entity,
+    pub loser: Entity,
+    pub magnitude: f32,
+}
+
 /// Event: War ends
 #[derive(Debug, Clone, Message)]
 pub struct WarEndEvent {
@@ -37,6 +49,7 @@ pub fn process_war_declarations(
     mut war_events: MessageReader<DeclareWarEvent>,
     nations_query: Query<&Nation>,
     game_time: Res<crate::simulation::GameTime>,
+    mut audio: MessageWriter<AudioEvent>,
     mut next_war_id: Local<u32>,
 ) {
     for event in war_events.read() {
@@ -57,6 +70,7 @@ pub fn process_war_declarations(
             start_year: game_time.current_year(),
             war_score: 0.0,
             battles_fought: 0,
+            casualties: 0.0,
         }).id();
 
         // Create relationships
@@ -71,6 +85,7 @@ pub fn process_war_declarations(
             "War declared: {} vs {} (CB: {:?})",
             attacker_nation.name, defender_nation.name, event.casus_belli
         );
+        audio.write(AudioEvent::new(AudioCue::WarDeclared));
     }
 }
 
@@ -78,10 +93,21 @@ pub fn process_war_declarations(
 pub fn process_battle_events(
     mut battle_events: MessageReader<BattleEvent>,
     mut wars_query: Query<&mut War>,
-    nations_query: Query<&Nation>,
+    nations_query: Query<(&Nation, &NationId)>,
+    corruption_query: Query<&Corruption>,
+    fort_networks: Query<&FortNetwork>,
+    compositions: Query<&ArmyComposition>,
+    doctrines: Query<&MilitaryDoctrine>,
+    logistics_query: Query<&Logistics>,
     mut histories_query: Query<&mut NationHistory>,
     attacking_query: Query<&Attacking>,
+    mut audio: MessageWriter<AudioEvent>,
+    mut resolved_events: MessageWriter<BattleResolvedEvent>,
+    game_time: Res<GameTime>,
+    world_seed: Option<Res<WorldSeed>>,
 ) {
+    let seed = world_seed.map_or(0, |seed| seed.0);
+
     for event in battle_events.read() {
         // Find the war
         let mut war_opt = None;
@@ -96,31 +122,73 @@ pub fn process_battle_events(
             continue;
         };
 
-        let Ok(attacker) = nations_query.get(event.attacker) else {
+        let Ok((attacker, attacker_id)) = nations_query.get(event.attacker) else {
             continue;
         };
-        let Ok(defender) = nations_query.get(event.defender) else {
+        let Ok((defender, _)) = nations_query.get(event.defender) else {
             continue;
         };
 
+        // Armies supplied through corrupt procurement, or whose stores have run out, fight below strength
+        let supply_quality = |nation: Entity| {
+            corruption_query.get(nation).map_or(1.0, Corruption::supply_quality)
+                * logistics_query.get(nation).map_or(1.0, Logistics::supply_factor)
+        };
+
+        // Each side gains from the arms of the other that its own arms counter
+        let (attacker_matchup, defender_matchup) =
+            match (compositions.get(event.attacker), compositions.get(event.defender)) {
+                (Ok(attacker_army), Ok(defender_army)) => {
+                    (attacker_army.matchup(defender_army), defender_army.matchup(attacker_army))
+                }
+                _ => (1.0, 1.0),
+            };
+
+        // Later doctrines fight harder
+        let combat_power = |nation: Entity| doctrines.get(nation).map_or(1.0, MilitaryDoctrine::combat_power);
+
         // Resolve battle
         let battle = Battle {
             attacker_entity: event.attacker,
             defender_entity: event.defender,
-            attacker_strength: attacker.military_strength,
-            defender_strength: defender.military_strength,
+            attacker_strength: attacker.military_strength
+                * supply_quality(event.attacker)
+                * attacker_matchup
+                * combat_power(event.attacker),
+            defender_strength: defender.military_strength
+                * supply_quality(event.defender)
+                * defender_matchup
+                * combat_power(event.defender),
             config: BattleConfig::default(),
         };
 
-        let result = battle.resolve(&mut thread_rng());
+        // Each battle of a war rolls its own dice
+        let battle_number = (u64::from(war.war_id) << 32) | u64::from(war.battles_fought);
+        let mut rng = decision_rng(
+            seed,
+            DecisionDomain::Military,
+            attacker_id.value(),
+            battle_number,
+            game_time.current_day(),
+        );
+        let result = battle.resolve(&mut rng);
+        audio.write(AudioEvent::new(AudioCue::BattleStarted));
 
         // Update war score based on which side is attacking
         // Check if attacker in battle is the attacker in war
         let is_war_attacker = attacking_query.get(event.attacker).is_ok();
         let score_change = result.magnitude * 10.0; // Max 10 points per battle
+        // Forts along the invaded nation's border slow the invader's progress
+        let (invader, invaded) = if is_war_attacker {
+            (event.attacker, event.defender)
+        } else {
+            (event.defender, event.attacker)
+        };
+        let siege_power = doctrines.get(invader).map_or(0.0, MilitaryDoctrine::siege_power);
+        let invasion_pace = fort_networks.get(invaded).map_or(1.0, |forts| forts.invasion_pace(siege_power));
         if result.winner == event.attacker {
             if is_war_attacker {
-                war.war_score += score_change;
+                war.war_score += score_change * invasion_pace;
             } else {
                 war.war_score -= score_change;
             }
@@ -128,10 +196,17 @@ pub fn process_battle_events(
             if is_war_attacker {
                 war.war_score -= score_change;
             } else {
-                war.war_score += score_change;
+                war.war_score += score_change * invasion_pace;
             }
         }
         war.battles_fought += 1;
+        war.casualties += result.attacker_casualties + result.defender_casualties;
+        resolved_events.write(BattleResolvedEvent {
+            war_id: war.war_id,
+            winner: result.winner,
+            loser: result.loser,
+            magnitude: result.magnitude,
+        });
 
         // Record in histories
         if let Ok(mut attacker_history) = histories_query.get_mut(event.attacker) {
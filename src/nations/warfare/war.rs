@@ -22,6 +22,8 @@ pub enum WarGoal {
     Humiliation,
     /// Total annexation
     Annexation,
+    /// Force a closed nation to open its borders and ports to trade
+    OpenPorts,
 }
 
 /// Active war between nations
@@ -45,6 +47,9 @@ pub struct War {
     pub war_score: f32,
     /// Battles fought
     pub battles_fought: u32,
+    /// Military strength lost by both sides across all battles
+    #[serde(default)]
+    pub casualties: f32,
 }
 
 /// Casus belli placeholder (will be defined in diplomacy module)
@@ -60,6 +65,8 @@ pub enum CasusBelli {
     DefensivePact,
     /// Reconquest (reclaim lost territory)
     Reconquest,
+    /// Broken treaty (enforcing violated terms)
+    BrokenTreaty,
     /// Fabricated claim (needs time and resources)
     FabricatedClaim,
     /// No CB (huge diplomatic penalty)
@@ -11,14 +11,16 @@ use bevy::prelude::*;
 
 /// A governor administers specific provinces
 /// Enables provincial administration and local governance mechanics
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = AdministeredBy)]
 pub struct Administers(pub Entity);
 
 /// Reverse relationship: A province is administered by governors
 /// Automatically maintained by Bevy when `Administers` is added
 /// NOTE: Should typically only contain one governor (validated by validation systems)
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = Administers, linked_spawn)]
 pub struct AdministeredBy(Vec<Entity>); // Private for safety - Bevy handles internal access
 
@@ -51,7 +53,8 @@ impl AdministeredBy {
 // ================================================================================================
 
 /// Marker component for governor entities
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct Governor {
     pub name: String,
     pub loyalty: f32,    // 0.0 = disloyal, 1.0 = completely loyal
@@ -60,7 +63,8 @@ pub struct Governor {
 }
 
 /// Administrative efficiency data for provinces
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct AdministrativeEfficiency {
     /// Overall efficiency (0.0 = chaos, 1.0 = perfect administration)
     pub efficiency: f32,
@@ -183,7 +187,7 @@ pub struct CorruptionDetectedEvent {
     pub corruption_level: f32,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum DismissalReason {
     Corruption,
     Incompetence,
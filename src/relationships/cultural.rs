@@ -14,13 +14,15 @@ use bevy::prelude::*;
 ///
 /// Cultural regions group provinces with similar cultural characteristics,
 /// affecting naming patterns, architectural styles, and political tendencies.
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = ContainsProvinces)]
 pub struct BelongsToRegion(pub Entity);
 
 /// Reverse relationship: A cultural region contains multiple provinces
 /// Automatically maintained by Bevy when `BelongsToRegion` is added
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = BelongsToRegion, linked_spawn)]
 pub struct ContainsProvinces(Vec<Entity>); // Private for safety - Bevy handles internal access
 
@@ -52,7 +54,8 @@ impl ContainsProvinces {
 
 /// Marker component for cultural region entities
 /// These entities represent distinct cultural areas in the world
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct CulturalRegion {
     /// Name of the cultural region (e.g., "Northern Highlands", "Desert Kingdoms")
     pub name: String,
@@ -66,7 +69,8 @@ pub struct CulturalRegion {
 
 /// Cultural coherence data for regions
 /// Tracks how unified a cultural region is
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct CulturalCoherence {
     /// How unified the region is (0.0 = fragmented, 1.0 = totally unified)
     pub unity: f32,
@@ -214,7 +218,7 @@ pub struct CulturalUnificationEvent {
     pub unifying_nation: Entity,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum FragmentationCause {
     PoliticalDivision,    // Multiple nations in one cultural region
     MilitaryConquest,     // Recent conquest creating instability
@@ -14,13 +14,15 @@ use bevy::prelude::*;
 ///
 /// Alliances provide mutual defense benefits and trade bonuses.
 /// They can be broken under certain conditions (betrayal mechanics).
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = Allies)]
 pub struct AlliedWith(pub Entity);
 
 /// Reverse relationship: A nation has allies
 /// Automatically maintained by Bevy when `AlliedWith` is added
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = AlliedWith, linked_spawn)]
 pub struct Allies(Vec<Entity>); // Private for safety - Bevy handles internal access
 
@@ -55,13 +57,15 @@ impl Allies {
 ///
 /// Wars block trade, enable territorial conquest, and affect stability.
 /// Wars can be resolved through victory, defeat, or peace treaties.
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = Enemies)]
 pub struct AtWarWith(pub Entity);
 
 /// Reverse relationship: A nation has enemies at war
 /// Automatically maintained by Bevy when `AtWarWith` is added
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = AtWarWith, linked_spawn)]
 pub struct Enemies(Vec<Entity>); // Private for safety - Bevy handles internal access
 
@@ -91,35 +95,55 @@ impl Enemies {
 // TRADE RELATIONSHIPS
 // ================================================================================================
 
-/// Nation A has a trade agreement with Nation B
+/// One side of a trade pact: `trader` trades with the nation this link points at
+///
+/// A nation trades under any number of pacts, and a relationship holds a single
+/// target, so each side of a pact is its own link entity, a child of the treaty.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct TradeLink {
+    /// The trade pact treaty this link belongs to
+    pub pact: Entity,
+    /// The nation trading under the pact
+    pub trader: Entity,
+}
+
+/// A trade link runs to Nation B
 /// Economic cooperation and resource exchange
 ///
 /// Trade agreements boost both nations' economies and resource availability.
 /// They can be affected by wars, alliances, and economic policies.
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = TradePartners)]
 pub struct TradesWithNation(pub Entity);
 
 /// Reverse relationship: A nation has trade partners
 /// Automatically maintained by Bevy when `TradesWithNation` is added
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = TradesWithNation, linked_spawn)]
 pub struct TradePartners(Vec<Entity>); // Private for safety - Bevy handles internal access
 
 impl TradePartners {
-    /// Get read-only access to trade partners
-    pub fn trade_partners(&self) -> &[Entity] {
+    /// Get read-only access to the trade links arriving at this nation
+    pub fn links(&self) -> &[Entity] {
         &self.0
     }
 
+    /// Get the trade partners, through their links
+    pub fn partners<'a>(&'a self, links: &'a Query<&TradeLink>) -> impl Iterator<Item = Entity> + 'a {
+        links.iter_many(&self.0).map(|link| link.trader)
+    }
+
     /// Get the number of trade partners
     pub fn partner_count(&self) -> usize {
         self.0.len()
     }
 
     /// Check if trading with a specific nation
-    pub fn trades_with(&self, nation: Entity) -> bool {
-        self.0.contains(&nation)
+    pub fn trades_with(&self, nation: Entity, links: &Query<&TradeLink>) -> bool {
+        self.partners(links).any(|partner| partner == nation)
     }
 
     /// Check if nation has any trade partners
@@ -134,7 +158,8 @@ impl TradePartners {
 
 /// Comprehensive diplomatic relationship data
 /// Attached to nation entities to track diplomatic state
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 pub struct DiplomaticState {
     /// Number of allies this nation has
     pub ally_count: u32,
@@ -170,11 +195,11 @@ pub fn query_wars<'w, 's>(
 
 /// Query all trade relationships
 pub fn query_trade_relationships<'w, 's>(
-    nations_query: &'w Query<'w, 's, (Entity, &'w TradesWithNation)>,
+    links_query: &'w Query<'w, 's, (&'w TradeLink, &'w TradesWithNation)>,
 ) -> impl Iterator<Item = (Entity, Entity)> + 'w {
-    nations_query
+    links_query
         .iter()
-        .map(|(nation_a, trades_with)| (nation_a, trades_with.0))
+        .map(|(link, trades_with)| (link.trader, trades_with.0))
 }
 
 /// Get all nations that a specific nation has diplomatic relations with
@@ -182,6 +207,7 @@ pub fn query_trade_relationships<'w, 's>(
 pub fn get_diplomatic_partners(
     nation_entity: Entity,
     diplomatic_query: &Query<(Option<&Allies>, Option<&Enemies>, Option<&TradePartners>)>,
+    links_query: &Query<&TradeLink>,
 ) -> Vec<(Entity, DiplomaticRelationType)> {
     let mut partners = Vec::new();
 
@@ -202,7 +228,7 @@ pub fn get_diplomatic_partners(
 
         // Add all trade partners
         if let Some(trade_partners) = trade_partners {
-            for &partner in trade_partners.trade_partners() {
+            for partner in trade_partners.partners(links_query) {
                 partners.push((partner, DiplomaticRelationType::Trade));
             }
         }
@@ -211,7 +237,7 @@ pub fn get_diplomatic_partners(
     partners
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum DiplomaticRelationType {
     Alliance,
     War,
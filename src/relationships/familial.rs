@@ -15,13 +15,15 @@ use serde::{Deserialize, Serialize};
 /// When applied to a Character entity, automatically creates `RelatedTo` on the target entity
 ///
 /// This replaces manual bidirectional relationship tracking with automatic Bevy relationships.
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = RelatedTo)]
 pub struct HasRelationship(pub Entity);
 
 /// Reverse relationship: A character is related to by other characters
 /// Automatically maintained by Bevy when `HasRelationship` is added
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = HasRelationship, linked_spawn)]
 pub struct RelatedTo(Vec<Entity>);
 
@@ -48,7 +50,8 @@ impl RelatedTo {
 
 /// Additional relationship metadata stored on the source character
 /// This component accompanies `HasRelationship` to provide relationship details
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct RelationshipMetadata {
     /// The target entity this metadata applies to
     pub target: Entity,
@@ -11,13 +11,15 @@ use bevy::prelude::*;
 
 /// A province is connected to another by road
 /// Infrastructure for trade, military movement, and communication
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = ConnectedRoads)]
 pub struct ConnectedByRoad(pub Entity);
 
 /// Reverse relationship: A province has road connections to other provinces
 /// Automatically maintained by Bevy when `ConnectedByRoad` is added
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = ConnectedByRoad, linked_spawn)]
 pub struct ConnectedRoads(Vec<Entity>); // Private for safety - Bevy handles internal access
 
@@ -49,13 +51,15 @@ impl ConnectedRoads {
 
 /// A province is connected to another by trade route
 /// Economic connection for goods flow and commercial activity
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = ConnectedTradeRoutes)]
 pub struct ConnectedByTrade(pub Entity);
 
 /// Reverse relationship: A province has trade route connections to other provinces
 /// Automatically maintained by Bevy when `ConnectedByTrade` is added
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = ConnectedByTrade, linked_spawn)]
 pub struct ConnectedTradeRoutes(Vec<Entity>); // Private for safety - Bevy handles internal access
 
@@ -86,7 +90,8 @@ impl ConnectedTradeRoutes {
 // ================================================================================================
 
 /// Marker component for road entities
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct Road {
     pub name: String,
     pub quality: RoadQuality,
@@ -96,7 +101,8 @@ pub struct Road {
 }
 
 /// Marker component for trade route entities
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct TradeRoute {
     pub name: String,
     pub route_type: TradeRouteType,
@@ -105,14 +111,14 @@ pub struct TradeRoute {
     pub security: f32,      // 0.0 = dangerous, 1.0 = completely safe
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum RoadQuality {
     Dirt,        // Basic dirt path
     Cobblestone, // Improved stone road
     Paved,       // Advanced paved road
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum TradeRouteType {
     Local,         // Between neighboring provinces
     Regional,      // Across cultural regions
@@ -125,7 +131,8 @@ pub enum TradeRouteType {
 // ================================================================================================
 
 /// Provincial infrastructure status
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct InfrastructureStatus {
     /// Number of roads connected to this province
     pub road_connections: u32,
@@ -272,7 +279,7 @@ pub struct InfrastructureMaintenanceEvent {
     pub urgency: MaintenanceUrgency,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum MaintenanceUrgency {
     Routine,  // Regular maintenance
     Urgent,   // Needs immediate attention
@@ -13,7 +13,8 @@ use bevy::prelude::*;
 /// When applied to a Law entity, automatically creates `EnactedLaws` on the Nation entity
 ///
 /// This replaces manual HashSet<LawId> tracking with entity relationships.
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = EnactedLaws)]
 pub struct EnactedBy(pub Entity);
 
@@ -21,7 +22,8 @@ pub struct EnactedBy(pub Entity);
 /// Automatically maintained by Bevy when `EnactedBy` is added to law entities
 ///
 /// This replaces the manual `NationLaws.active_laws: HashSet<LawId>` with automatic tracking.
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = EnactedBy, linked_spawn)]
 pub struct EnactedLaws(Vec<Entity>); // Private for safety
 
@@ -53,13 +55,15 @@ impl EnactedLaws {
 
 /// A law is being proposed for a nation
 /// Represents laws under debate but not yet enacted
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = ProposedLaws)]
 pub struct ProposedFor(pub Entity);
 
 /// Reverse relationship: A nation has laws being proposed
 /// Automatically maintained by Bevy when `ProposedFor` is added
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = ProposedFor, linked_spawn)]
 pub struct ProposedLaws(Vec<Entity>); // Private for safety
 
@@ -86,13 +90,15 @@ impl ProposedLaws {
 
 /// A law was repealed by a nation (historical tracking)
 /// Useful for tracking law history and preventing immediate re-enactment
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = RepealedLaws)]
 pub struct RepealedBy(pub Entity);
 
 /// Reverse relationship: A nation has repealed laws (historical)
 /// Tracks laws that were previously active but have been repealed
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = RepealedBy, linked_spawn)]
 pub struct RepealedLaws(Vec<Entity>); // Private for safety
 
@@ -114,13 +120,15 @@ impl RepealedLaws {
 
 /// A law conflicts with another law
 /// Used to prevent conflicting laws from being enacted simultaneously
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = ConflictedBy)]
 pub struct ConflictsWith(pub Entity);
 
 /// Reverse relationship: Laws that conflict with this one
 /// Automatically maintained bidirectional conflict tracking
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = ConflictsWith, linked_spawn)]
 pub struct ConflictedBy(Vec<Entity>); // Private for safety
 
@@ -141,7 +149,8 @@ impl ConflictedBy {
 // ================================================================================================
 
 /// Component for law entities containing law data
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct LawEntity {
     pub law_id: crate::nations::LawId,
     pub name: String,
@@ -151,7 +160,8 @@ pub struct LawEntity {
 }
 
 /// Component tracking proposal status for proposed laws
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct ProposalStatus {
     pub support_percentage: f32,
     pub debate_duration: f32,
@@ -160,7 +170,8 @@ pub struct ProposalStatus {
 }
 
 /// Component tracking repeal information
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct RepealInfo {
     pub repeal_date: i32, // Game year when repealed
     pub repeal_reason: String,
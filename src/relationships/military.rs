@@ -5,19 +5,24 @@
 
 use bevy::prelude::*;
 
+use crate::math::Manpower;
+use serde::{Deserialize, Serialize};
+
 // ================================================================================================
 // ARMY POSITIONING RELATIONSHIPS
 // ================================================================================================
 
 /// An army is stationed in a specific province
 /// Military unit positioning for strategic and tactical purposes
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = HostsArmies)]
 pub struct StationedIn(pub Entity);
 
 /// Reverse relationship: A province hosts military units
 /// Automatically maintained by Bevy when `StationedIn` is added
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = StationedIn, linked_spawn)]
 pub struct HostsArmies(Vec<Entity>); // Private for safety - Bevy handles internal access
 
@@ -43,15 +48,47 @@ impl HostsArmies {
     }
 }
 
+/// An army is garrisoned inside a fortification
+/// Garrisoned armies defend the fortification's province and count against its capacity
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+#[relationship(relationship_target = Garrisons)]
+pub struct GarrisonedIn(pub Entity);
+
+/// Reverse relationship: A fortification holds garrison armies
+/// Not `linked_spawn` - armies survive the fall of their fortress
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+#[relationship_target(relationship = GarrisonedIn)]
+pub struct Garrisons(Vec<Entity>); // Private for safety - Bevy handles internal access
+
+impl Garrisons {
+    /// Get read-only access to the garrisoned armies
+    pub fn armies(&self) -> &[Entity] {
+        &self.0
+    }
+
+    /// Get the number of garrisoned armies
+    pub fn garrison_count(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check if a specific army is part of this garrison
+    pub fn garrisons_army(&self, army: Entity) -> bool {
+        self.0.contains(&army)
+    }
+}
+
 // ================================================================================================
 // MILITARY ENTITIES
 // ================================================================================================
 
 /// Marker component for army entities
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct Army {
     pub name: String,
-    pub size: u32,              // Number of soldiers
+    pub size: Manpower,         // Number of soldiers
     pub morale: f32,            // 0.0 = broken, 1.0 = excellent
     pub experience: f32,        // 0.0 = green recruits, 1.0 = veterans
     pub equipment_quality: f32, // 0.0 = poor, 1.0 = excellent
@@ -60,16 +97,17 @@ pub struct Army {
 }
 
 /// Marker component for fortifications
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect, Serialize, Deserialize)]
+#[reflect(Component)]
 pub struct Fortification {
     pub name: String,
     pub fortification_type: FortificationType,
     pub defensive_strength: f32, // Defensive bonus
-    pub garrison_capacity: u32,  // Max army size that can be stationed
+    pub garrison_capacity: Manpower, // Max army size that can be stationed
     pub construction_year: u32,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum ArmyType {
     Infantry, // Basic foot soldiers
     Cavalry,  // Mounted units
@@ -79,7 +117,7 @@ pub enum ArmyType {
     Elite,    // Special elite units
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Serialize, Deserialize)]
 pub enum FortificationType {
     Palisade,  // Basic wooden fortification
     StoneWall, // Stone wall fortification
@@ -87,12 +125,25 @@ pub enum FortificationType {
     Citadel,   // Heavily fortified citadel
 }
 
+impl FortificationType {
+    /// Display name
+    pub fn label(&self) -> &'static str {
+        match self {
+            FortificationType::Palisade => "Palisade",
+            FortificationType::StoneWall => "Stone Wall",
+            FortificationType::Fortress => "Fortress",
+            FortificationType::Citadel => "Citadel",
+        }
+    }
+}
+
 // ================================================================================================
 // MILITARY DATA
 // ================================================================================================
 
 /// Provincial military status
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct MilitaryStatus {
     /// Total military strength in this province
     pub total_strength: f32,
@@ -104,7 +155,7 @@ pub struct MilitaryStatus {
     pub strategic_importance: StrategicImportance,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum StrategicImportance {
     Low,      // Remote location
     Medium,   // Some strategic value
@@ -175,7 +226,7 @@ pub fn find_province_armies(
 
 /// Calculate effective army strength based on all factors
 pub fn calculate_army_strength(army: &Army) -> f32 {
-    let base_strength = army.size as f32;
+    let base_strength = army.size.to_f32();
     let morale_modifier = 0.5 + (army.morale * 0.5); // 0.5x to 1.0x
     let experience_modifier = 0.7 + (army.experience * 0.3); // 0.7x to 1.0x
     let equipment_modifier = 0.8 + (army.equipment_quality * 0.2); // 0.8x to 1.0x
@@ -183,35 +234,13 @@ pub fn calculate_army_strength(army: &Army) -> f32 {
     base_strength * morale_modifier * experience_modifier * equipment_modifier
 }
 
-/// Calculate defensive strength of a province
-pub fn calculate_provincial_defense(
-    province_entity: Entity,
-    fortifications_query: &Query<(&Fortification, &StationedIn)>,
-    armies_query: &Query<&Army>,
-) -> f32 {
-    let mut total_defense = 0.0;
-
-    // Add fortification bonuses
-    let fortification_bonus: f32 = fortifications_query
+/// Total soldiers garrisoned in a fortification
+pub fn garrison_manpower(garrisons: &Garrisons, armies_query: &Query<&Army>) -> Manpower {
+    garrisons
+        .armies()
         .iter()
-        .filter(|(_, stationed_in)| stationed_in.0 == province_entity)
-        .map(|(fortification, _)| fortification.defensive_strength)
-        .sum();
-
-    total_defense += fortification_bonus;
-
-    // Add army defensive strength (reduced compared to offensive strength)
-    // TODO: This requires a Query<&HostsArmies> parameter to be passed to this function
-    // Temporarily disabled until the function signature can be updated
-    // if let Some(armies) = find_province_armies(province_entity, provinces_query) {
-    //     let army_defense: f32 = armies.iter()
-    //         .filter_map(|&army_entity| armies_query.get(army_entity).ok())
-    //         .map(|army| calculate_army_strength(army) * 0.8) // Defensive penalty
-    //         .sum();
-    //     total_defense += army_defense;
-    // }
-
-    total_defense
+        .filter_map(|&army_entity| armies_query.get(army_entity).ok())
+        .fold(Manpower::ZERO, |total, army| total + army.size)
 }
 
 // ================================================================================================
@@ -227,14 +256,16 @@ pub fn update_military_status(
     // NOTE: Bevy queries should not be manually parallelized with Rayon
     // Bevy has its own parallel scheduling system
     for (province_entity, mut military_status, hosts_armies) in &mut provinces_query {
-            // Count armies and calculate total strength
-            military_status.army_count = hosts_armies.army_count() as u32;
-            military_status.total_strength = hosts_armies
+            // Count armies and calculate total strength (fortifications share
+            // `StationedIn`, so only entities with an `Army` count)
+            let armies: Vec<&Army> = hosts_armies
                 .armies()
                 .iter()
                 .filter_map(|&army_entity| armies_query.get(army_entity).ok())
-                .map(|army| calculate_army_strength(army))
-                .sum();
+                .collect();
+            military_status.army_count = armies.len() as u32;
+            military_status.total_strength =
+                armies.iter().map(|army| calculate_army_strength(army)).sum();
 
             // Calculate defensive value from fortifications
             military_status.defensive_value = fortifications_query
@@ -297,7 +328,7 @@ pub struct BattleEvent {
     pub outcome: BattleOutcome,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum BattleOutcome {
     AttackerVictory,
     DefenderVictory,
@@ -328,6 +359,22 @@ pub fn validate_military_positions(
     }
 }
 
+/// Validates that no fortification holds more soldiers than it can house
+pub fn validate_garrison_capacity(
+    fortifications_query: Query<(Entity, &Fortification, &Garrisons)>,
+    armies_query: Query<&Army>,
+) {
+    for (fortification_entity, fortification, garrisons) in fortifications_query.iter() {
+        let garrisoned = garrison_manpower(garrisons, &armies_query);
+        if garrisoned > fortification.garrison_capacity {
+            warn!(
+                "Fortification {:?} ({}) holds {} but only has room for {}",
+                fortification_entity, fortification.name, garrisoned, fortification.garrison_capacity
+            );
+        }
+    }
+}
+
 /// Validates army ownership
 pub fn validate_army_ownership(armies_query: Query<(Entity, &Army)>, nations_query: Query<Entity>) {
     let valid_nations: std::collections::HashSet<Entity> = nations_query.iter().collect();
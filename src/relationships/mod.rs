@@ -61,11 +61,31 @@ pub use political::{
 // DIPLOMATIC RELATIONSHIPS - Inter-nation relations
 // ================================================================================================
 
+pub use diplomatic::{
+    // Diplomatic relationship components
+    AlliedWith, Allies, AtWarWith, Enemies, TradeLink, TradePartners, TradesWithNation,
+    // Diplomatic data
+    DiplomaticRelationType, DiplomaticState,
+    // Diplomatic events
+    AllianceFormedEvent, PeaceTreatyEvent, TradeAgreementEvent, WarDeclaredEvent,
+    // Query helpers
+    get_diplomatic_partners,
+};
 
 // ================================================================================================
 // CULTURAL RELATIONSHIPS - Cultural regions and identity
 // ================================================================================================
 
+pub use cultural::{
+    // Cultural relationship components
+    BelongsToRegion, ContainsProvinces,
+    // Cultural data
+    CulturalCoherence, CulturalRegion,
+    // Cultural events
+    CulturalTensionEvent, CulturalUnificationEvent, FragmentationCause,
+    // Query helpers
+    find_regions_by_culture, get_provinces_in_region,
+};
 
 // ================================================================================================
 // LEGISLATIVE RELATIONSHIPS - Laws and governance
@@ -82,26 +102,79 @@ pub use legislative::{
 // ADMINISTRATIVE RELATIONSHIPS - Governance and administration
 // ================================================================================================
 
+pub use administrative::{
+    // Administrative relationship components
+    AdministeredBy, Administers,
+    // Administrative data
+    AdministrativeEfficiency, Governor,
+    // Administrative events
+    CorruptionDetectedEvent, DismissalReason, GovernorAppointedEvent, GovernorDismissedEvent,
+    // Query helpers
+    find_province_governor, get_governor_provinces,
+};
 
 // ================================================================================================
 // INFRASTRUCTURE RELATIONSHIPS - Physical connections
 // ================================================================================================
 
+pub use infrastructure::{
+    // Infrastructure relationship components
+    ConnectedByRoad, ConnectedByTrade, ConnectedRoads, ConnectedTradeRoutes,
+    // Infrastructure entities and data
+    InfrastructureStatus, Road, RoadQuality, TradeRoute, TradeRouteType,
+    // Infrastructure events
+    InfrastructureMaintenanceEvent, MaintenanceUrgency, RoadConstructedEvent,
+    TradeRouteEstablishedEvent,
+    // Query helpers
+    calculate_trade_efficiency, find_province_roads, find_province_trade_routes,
+};
 
 // ================================================================================================
 // MILITARY RELATIONSHIPS - Army positioning and structures
 // ================================================================================================
 
+pub use military::{
+    // Army entities and positioning
+    Army, ArmyType, HostsArmies, StationedIn,
+    // Garrisons
+    Fortification, FortificationType, GarrisonedIn, Garrisons,
+    // Military data
+    MilitaryStatus, StrategicImportance,
+    // Military events
+    ArmyMovedEvent, BattleEvent, BattleOutcome, FortificationBuiltEvent,
+    // Query helpers
+    calculate_army_strength, find_nation_armies, find_province_armies, garrison_manpower,
+};
 
 // ================================================================================================
 // RELIGIOUS RELATIONSHIPS - Faith and influence
 // ================================================================================================
 
+pub use religious::{
+    // Religious relationship components
+    FaithOf, Followers, InfluencedByReligions, InfluencesProvince,
+    // Religious entities and data
+    Religion, ReligionType, ReligiousInfluence, ReligiousStatus,
+    // Religious events
+    ConflictType, ReligionFoundedEvent, ReligiousConflictEvent, ReligiousConversionEvent,
+    // Query helpers
+    find_religion_provinces, get_dominant_religion,
+};
 
 // ================================================================================================
 // POPULATION RELATIONSHIPS - Demographics and residence
 // ================================================================================================
 
+pub use population::{
+    // Population relationship components
+    HostsPopulations, ResidesIn,
+    // Population entities and data
+    Demographics, MigrationFlow, MigrationType, Occupation, PopulationGroup, SocialClass,
+    // Population events
+    DemographicShiftEvent, MigrationEvent, PopulationChangeEvent,
+    // Query helpers
+    calculate_provincial_population, find_province_populations,
+};
 
 // ================================================================================================
 // FAMILIAL RELATIONSHIPS - Character family and social bonds
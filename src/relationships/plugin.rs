@@ -45,6 +45,35 @@ define_plugin!(RelationshipsPlugin {
         LawRepealedEvent
     ],
 
+    reflect: [
+        // Political (6)
+        ControlledBy, Controls, HasCapital, CapitalOf, RulesOver, RuledBy,
+        // Cultural (4)
+        BelongsToRegion, ContainsProvinces, CulturalRegion, CulturalCoherence,
+        // Administrative (4)
+        Administers, AdministeredBy, Governor, AdministrativeEfficiency,
+        // Diplomatic (8)
+        AlliedWith, Allies, AtWarWith, Enemies, TradeLink, TradesWithNation, TradePartners,
+        DiplomaticState,
+        // Infrastructure (7)
+        ConnectedByRoad, ConnectedRoads, ConnectedByTrade, ConnectedTradeRoutes, Road, TradeRoute,
+        InfrastructureStatus,
+        // Military (7)
+        StationedIn, HostsArmies, GarrisonedIn, Garrisons, Army, Fortification, MilitaryStatus,
+        // Religious (7)
+        InfluencesProvince, InfluencedByReligions, FaithOf, Followers, Religion, ReligiousInfluence,
+        ReligiousStatus,
+        // Population (5)
+        ResidesIn, HostsPopulations, PopulationGroup, Demographics, MigrationFlow,
+        // Legislative (11)
+        EnactedBy, EnactedLaws, ProposedFor, ProposedLaws, RepealedBy, RepealedLaws,
+        ConflictsWith, ConflictedBy, LawEntity, ProposalStatus, RepealInfo,
+        // Familial (3)
+        super::HasRelationship,
+        super::RelatedTo,
+        super::RelationshipMetadata
+    ],
+
     update: [
         // Primary relationship systems (chained for order)
         (
@@ -72,6 +101,7 @@ define_plugin!(RelationshipsPlugin {
             validate_infrastructure_connections,
             validate_military_positions,
             validate_army_ownership,
+            validate_garrison_capacity,
             validate_religious_relationships,
             validate_religious_influence_consistency,
             validate_population_residence,
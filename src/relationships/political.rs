@@ -14,7 +14,8 @@ use bevy::prelude::*;
 ///
 /// This replaces the manual `Province.owner: Option<NationId>` field with automatic
 /// bidirectional relationship tracking.
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = Controls)]
 pub struct ControlledBy(pub Entity);
 
@@ -22,7 +23,8 @@ pub struct ControlledBy(pub Entity);
 /// Automatically maintained by Bevy when `ControlledBy` is added to provinces
 ///
 /// This replaces the manual `ProvinceOwnershipCache` HashMap with automatic tracking.
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = ControlledBy, linked_spawn)]
 pub struct Controls(Vec<Entity>); // Private for safety - Bevy handles internal access
 
@@ -56,14 +58,16 @@ impl Controls {
 /// One-to-one relationship from Nation to Province
 ///
 /// This replaces the manual `Nation.capital_province: u32` field with entity relationships.
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = CapitalOf)]
 pub struct HasCapital(pub Entity);
 
 /// Reverse relationship: A province is the capital of a nation
 /// Automatically maintained by Bevy when `HasCapital` is added
 /// NOTE: Should typically only contain one nation (validated by validation systems)
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = HasCapital, linked_spawn)]
 pub struct CapitalOf(Vec<Entity>); // Private for safety - Bevy handles internal access
 
@@ -94,14 +98,16 @@ impl CapitalOf {
 /// Enables house and succession mechanics
 ///
 /// This allows for implementing royal families, successions, and political intrigue.
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = RuledBy)]
 pub struct RulesOver(pub Entity);
 
 /// Reverse relationship: A nation is ruled by a person/house
 /// Automatically maintained by Bevy when `RulesOver` is added
 /// NOTE: Should typically only contain one ruler (validated by validation systems)
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = RulesOver, linked_spawn)]
 pub struct RuledBy(Vec<Entity>); // Private for safety - Bevy handles internal access
 
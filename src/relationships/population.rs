@@ -11,13 +11,15 @@ use bevy::prelude::*;
 
 /// A population group resides in a province
 /// Demographic tracking and migration mechanics
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = HostsPopulations)]
 pub struct ResidesIn(pub Entity);
 
 /// Reverse relationship: A province hosts population groups
 /// Automatically maintained by Bevy when `ResidesIn` is added
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = ResidesIn, linked_spawn)]
 pub struct HostsPopulations(Vec<Entity>); // Private for safety - Bevy handles internal access
 
@@ -48,7 +50,8 @@ impl HostsPopulations {
 // ================================================================================================
 
 /// Marker component for population group entities
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct PopulationGroup {
     pub name: String,
     pub size: u32, // Number of people in this group
@@ -60,7 +63,8 @@ pub struct PopulationGroup {
 }
 
 /// Demographics data for a province
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct Demographics {
     pub total_population: u32,
     pub population_density: f32, // People per unit area
@@ -69,7 +73,7 @@ pub struct Demographics {
     pub social_stratification: SocialStratification,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum SocialClass {
     Nobility,  // Aristocrats and rulers
     Clergy,    // Religious leaders
@@ -79,7 +83,7 @@ pub enum SocialClass {
     Slaves,    // Enslaved population
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum Occupation {
     Agriculture,    // Farming and herding
     Craftsmanship,  // Artisans and craftsmen
@@ -90,14 +94,14 @@ pub enum Occupation {
     Labor,          // General laborers
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Reflect)]
 pub struct CulturalGroup {
     pub culture: crate::name_generator::Culture,
     pub population: u32,
     pub percentage: f32,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Reflect)]
 pub struct SocialStratification {
     pub nobility_percentage: f32,
     pub middle_class_percentage: f32,
@@ -110,7 +114,8 @@ pub struct SocialStratification {
 // ================================================================================================
 
 /// Migration flow data between provinces
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct MigrationFlow {
     pub origin: Entity,       // Source province
     pub destination: Entity,  // Destination province
@@ -120,7 +125,7 @@ pub struct MigrationFlow {
     pub pull_factors: Vec<PullFactor>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum MigrationType {
     Economic,      // Seeking better opportunities
     Political,     // Fleeing persecution or conflict
@@ -129,7 +134,7 @@ pub enum MigrationType {
     Seasonal,      // Temporary seasonal migration
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum PushFactor {
     Poverty,     // Economic hardship
     War,         // Military conflict
@@ -139,7 +144,7 @@ pub enum PushFactor {
     Taxation,    // Excessive taxation
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum PullFactor {
     Opportunity, // Economic opportunities
     Safety,      // Security and peace
@@ -399,7 +404,7 @@ pub struct DemographicShiftEvent {
     pub magnitude: f32, // How significant the shift is
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum PopulationChangeType {
     NaturalGrowth,
     Immigration,
@@ -409,7 +414,7 @@ pub enum PopulationChangeType {
     Famine,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum DemographicShiftType {
     CulturalChange, // Cultural composition changed
     SocialMobility, // Social class distribution changed
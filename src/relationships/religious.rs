@@ -11,13 +11,15 @@ use bevy::prelude::*;
 
 /// A religion has influence in a province
 /// Religious spread and conversion mechanics
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 #[relationship(relationship_target = InfluencedByReligions)]
 pub struct InfluencesProvince(pub Entity);
 
 /// Reverse relationship: A province is influenced by religions
 /// Automatically maintained by Bevy when `InfluencesProvince` is added
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 #[relationship_target(relationship = InfluencesProvince, linked_spawn)]
 pub struct InfluencedByReligions(Vec<Entity>); // Private for safety - Bevy handles internal access
 
@@ -43,12 +45,44 @@ impl InfluencedByReligions {
     }
 }
 
+/// A province or population group holds a religion as its primary faith
+/// Unlike `InfluencesProvince`, each follower has exactly one faith
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+#[relationship(relationship_target = Followers)]
+pub struct FaithOf(pub Entity);
+
+/// Reverse relationship: A religion's followers
+/// Not `linked_spawn` - provinces and populations outlive a dead religion
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+#[relationship_target(relationship = FaithOf)]
+pub struct Followers(Vec<Entity>); // Private for safety - Bevy handles internal access
+
+impl Followers {
+    /// Get read-only access to the followers of this religion
+    pub fn followers(&self) -> &[Entity] {
+        &self.0
+    }
+
+    /// Get the number of followers
+    pub fn follower_count(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check if an entity follows this religion
+    pub fn has_follower(&self, follower: Entity) -> bool {
+        self.0.contains(&follower)
+    }
+}
+
 // ================================================================================================
 // RELIGIOUS ENTITIES
 // ================================================================================================
 
 /// Marker component for religion entities
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct Religion {
     pub name: String,
     pub religion_type: ReligionType,
@@ -59,7 +93,8 @@ pub struct Religion {
 }
 
 /// Religious influence data in a specific province
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct ReligiousInfluence {
     pub religion: Entity,
     pub influence_strength: f32, // 0.0 = no influence, 1.0 = complete dominance
@@ -67,7 +102,7 @@ pub struct ReligiousInfluence {
     pub religious_buildings: u32, // Temples, churches, etc.
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum ReligionType {
     Monotheistic,  // Single deity
     Polytheistic,  // Multiple deities
@@ -81,7 +116,8 @@ pub enum ReligionType {
 // ================================================================================================
 
 /// Provincial religious status
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct ReligiousStatus {
     /// Dominant religion in this province
     pub dominant_religion: Option<Entity>,
@@ -139,22 +175,22 @@ pub fn find_religion_provinces(
 }
 
 /// Get the dominant religion in a province
+///
+/// A declared primary faith wins; otherwise the strongest influence among the
+/// religions present in the province.
 pub fn get_dominant_religion(
     province_entity: Entity,
+    provinces_query: &Query<(Option<&FaithOf>, Option<&InfluencedByReligions>)>,
     religious_influences: &Query<&ReligiousInfluence>,
 ) -> Option<Entity> {
+    let (faith, influenced_by) = provinces_query.get(province_entity).ok()?;
+    if let Some(faith) = faith {
+        return Some(faith.0);
+    }
+
     religious_influences
-        .iter()
-        .filter(|influence| {
-            // Check if this influence applies to our province
-            // This would need to be cross-referenced with the relationship system
-            true // Placeholder
-        })
-        .max_by(|a, b| {
-            a.influence_strength
-                .partial_cmp(&b.influence_strength)
-                .unwrap()
-        })
+        .iter_many(influenced_by?.religions())
+        .max_by(|a, b| a.influence_strength.total_cmp(&b.influence_strength))
         .map(|influence| influence.religion)
 }
 
@@ -164,19 +200,23 @@ pub fn get_dominant_religion(
 
 /// Updates religious status for all provinces
 pub fn update_religious_status(
-    mut provinces_query: Query<(Entity, &mut ReligiousStatus, &InfluencedByReligions)>,
+    mut provinces_query: Query<(
+        &mut ReligiousStatus,
+        &InfluencedByReligions,
+        Option<&FaithOf>,
+    )>,
     religious_influences_query: Query<&ReligiousInfluence>,
 ) {
     // NOTE: Bevy queries should not be manually parallelized with Rayon
     // Bevy has its own parallel scheduling system
-    for (province_entity, mut status, influenced_by) in &mut provinces_query {
+    for (mut status, influenced_by, faith) in &mut provinces_query {
             status.religion_count = influenced_by.0.len() as u32;
 
             if status.religion_count == 0 {
-                status.dominant_religion = None;
+                status.dominant_religion = faith.map(|faith| faith.0);
                 status.diversity = 0.0;
                 status.tension = 0.0;
-                return;
+                continue;
             }
 
             // Calculate diversity based on influence distribution
@@ -194,8 +234,13 @@ pub fn update_religious_status(
                 (status.diversity * 0.5 + (status.religion_count as f32 - 1.0) * 0.1).min(1.0)
             };
 
-            // Find dominant religion (placeholder - would need proper influence data)
-            status.dominant_religion = influenced_by.0.first().copied();
+            // Declared faith first, then the strongest influence present
+            status.dominant_religion = faith.map(|faith| faith.0).or_else(|| {
+                religious_influences_query
+                    .iter_many(influenced_by.religions())
+                    .max_by(|a, b| a.influence_strength.total_cmp(&b.influence_strength))
+                    .map(|influence| influence.religion)
+            });
     }
 }
 
@@ -248,7 +293,7 @@ pub struct ReligionFoundedEvent {
     pub founder: Option<Entity>, // Person or entity who founded it
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum ConflictType {
     IdeologicalDispute,  // Theological disagreement
     ResourceCompetition, // Competing for followers/resources
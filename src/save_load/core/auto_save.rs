@@ -2,7 +2,7 @@
 //!
 //! This module handles automatic saving at regular intervals.
 
-use super::{AutoSaveTimer, SaveGameEvent};
+use super::{AutoSaveTimer, SaveGameEvent, AUTOSAVE_SLOT};
 use bevy::prelude::*;
 
 /// Handle auto-save timer
@@ -20,7 +20,7 @@ pub fn handle_auto_save(
     if timer.timer.just_finished() {
         info!("Auto-saving game...");
         save_events.write(SaveGameEvent {
-            slot_name: "autosave".to_string(),
+            slot_name: AUTOSAVE_SLOT.to_string(),
         });
     }
 }
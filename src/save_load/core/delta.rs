@@ -0,0 +1,416 @@
+//! Delta saves
+//!
+//! Frequent autosaves of a huge world are expensive when every one is a full
+//! snapshot. Between full autosaves, only what changed is written: provinces
+//! whose ownership moved (reported by `TerritoryOwnershipChanged`), whose
+//! coastline moved (reported by `CoastlineChanged`), or that were marked
+//! dirty by any other change; nations whose `Nation` or `NationLaws`
+//! components changed (Bevy change detection); and nations that died.
+//! Loading a full save applies the deltas chained to it, in order.
+
+use super::{
+    decompress_data, deserialize_save_delta, SaveChangeTracker, SaveDelta, SaveGameData, DELTA_EXTENSION,
+    SAVE_DIRECTORY, SAVE_VERSION,
+};
+use crate::nations::{Nation, NationId, NationIndex, NationLaws, TerritoryOwnershipChanged};
+use crate::resources::{GameTime, MapMode, WorldTension};
+use crate::world::{CoastlineChanged, Province, ProvinceData, ProvinceStorage};
+use bevy::prelude::*;
+use chrono::Local;
+use std::fs;
+use std::path::Path;
+
+/// Record provinces and nations that changed since the last autosave
+pub fn track_save_changes(
+    mut tracker: ResMut<SaveChangeTracker>,
+    mut ownership_events: MessageReader<TerritoryOwnershipChanged>,
+    mut coastline_events: MessageReader<CoastlineChanged>,
+    province_data: Query<&ProvinceData>,
+    changed_nations: Query<&NationId, Or<(Changed<Nation>, Changed<NationLaws>)>>,
+) {
+    for event in ownership_events.read() {
+        for &province in &event.provinces {
+            if let Ok(data) = province_data.get(province) {
+                tracker.provinces.insert(data.id.value());
+            }
+        }
+    }
+
+    for event in coastline_events.read() {
+        tracker.provinces.extend(event.provinces().map(|index| index as u32));
+    }
+
+    for nation_id in &changed_nations {
+        tracker.nations.insert(*nation_id);
+    }
+}
+
+/// A new or loaded world starts without an autosave chain
+pub fn reset_save_chain(mut tracker: ResMut<SaveChangeTracker>) {
+    *tracker = SaveChangeTracker {
+        deltas_per_snapshot: tracker.deltas_per_snapshot,
+        ..Default::default()
+    };
+}
+
+/// After an autosave, track changes afresh from what it wrote
+///
+/// Clearing the provinces' dirty flags is bookkeeping, not a change to the
+/// world, so callers should pass storage that bypasses change detection.
+pub fn mark_autosaved(
+    tracker: &mut SaveChangeTracker,
+    province_storage: Option<&mut ProvinceStorage>,
+    live_nations: impl Iterator<Item = NationId>,
+) {
+    tracker.clear_changes();
+    tracker.saved_nations = live_nations.collect();
+    if let Some(storage) = province_storage {
+        storage
+            .provinces
+            .iter_mut()
+            .filter(|province| province.dirty)
+            .for_each(Province::clear_dirty);
+    }
+}
+
+/// Snapshot everything the tracker marked as changed, as the next link of its chain
+pub fn build_save_delta(
+    tracker: &SaveChangeTracker,
+    game_time: GameTime,
+    world_tension: WorldTension,
+    map_mode: MapMode,
+    province_storage: Option<&ProvinceStorage>,
+    nation_index: &NationIndex,
+    nations: impl Iterator<Item = (NationId, Nation, NationLaws)>,
+) -> SaveDelta {
+    let provinces = province_storage
+        .map(|storage| {
+            storage
+                .provinces
+                .iter()
+                .enumerate()
+                .filter(|(idx, province)| province.dirty || tracker.provinces.contains(&(*idx as u32)))
+                .map(|(_, province)| {
+                    let owner = province.owner_entity.and_then(|owner| nation_index.id(owner));
+                    (province.clone(), owner)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let live: Vec<_> = nations.collect();
+    let removed_nations = tracker
+        .saved_nations
+        .iter()
+        .filter(|id| !live.iter().any(|(live_id, _, _)| live_id == *id))
+        .copied()
+        .collect();
+    let (nations, nation_laws) = live
+        .into_iter()
+        .filter(|(id, _, _)| tracker.nations.contains(id))
+        .map(|(id, nation, laws)| ((id, nation), (id, laws)))
+        .unzip();
+
+    SaveDelta {
+        version: SAVE_VERSION,
+        timestamp: Local::now(),
+        base_save: tracker.base_save.clone().unwrap_or_default(),
+        sequence: tracker.sequence + 1,
+        game_time,
+        world_tension,
+        map_mode,
+        map_heatmap: None,
+        provinces,
+        nations,
+        nation_laws,
+        removed_nations,
+        id_allocator: None,
+        chronicle: Default::default(),
+        milestones: Default::default(),
+        director: Default::default(),
+        statistics: Default::default(),
+        nation_governance: Default::default(),
+        economic_focus: Default::default(),
+        scripted_events: Default::default(),
+        sea_level: Default::default(),
+        mod_settings: Default::default(),
+        treaties: None,
+        cores: None,
+        houses: None,
+        devastation: None,
+        nation_state: None,
+        forts: None,
+        supply_depots: None,
+        trade_leagues: None,
+        refugees: None,
+    }
+}
+
+/// Apply one delta on top of a full save
+pub fn apply_delta(save_data: &mut SaveGameData, delta: SaveDelta) {
+    save_data.game_time = delta.game_time;
+    save_data.world_tension = delta.world_tension;
+    save_data.map_mode = delta.map_mode;
+    save_data.map_heatmap = delta.map_heatmap;
+    save_data.timestamp = delta.timestamp;
+
+    let province_count = save_data.provinces.len();
+    save_data.province_owners.resize(province_count, None);
+    for (province, owner) in delta.provinces {
+        let idx = province.id.value() as usize;
+        if idx < province_count {
+            save_data.provinces[idx] = province;
+            save_data.province_owners[idx] = owner;
+        }
+    }
+
+    for (id, nation) in delta.nations {
+        match save_data.nations.iter_mut().find(|(existing, _)| *existing == id) {
+            Some((_, existing)) => *existing = nation,
+            None => save_data.nations.push((id, nation)),
+        }
+    }
+    save_data.nation_laws.extend(delta.nation_laws);
+
+    // A dead nation leaves nothing behind, not even provinces still listed under it
+    if !delta.removed_nations.is_empty() {
+        save_data.nations.retain(|(id, _)| !delta.removed_nations.contains(id));
+        for id in &delta.removed_nations {
+            save_data.nation_laws.remove(id);
+        }
+        for owner in &mut save_data.province_owners {
+            if owner.is_some_and(|id| delta.removed_nations.contains(&id)) {
+                *owner = None;
+            }
+        }
+    }
+    if let Some(id_allocator) = delta.id_allocator {
+        save_data.id_allocator = id_allocator;
+    }
+    save_data.chronicle = delta.chronicle;
+    save_data.milestones = delta.milestones;
+    save_data.director = delta.director;
+    save_data.statistics = delta.statistics;
+    save_data.nation_governance = delta.nation_governance;
+    save_data.economic_focus = delta.economic_focus;
+    save_data.scripted_events = delta.scripted_events;
+    save_data.sea_level = delta.sea_level;
+    save_data.mod_settings = delta.mod_settings;
+    if let Some(treaties) = delta.treaties {
+        save_data.treaties = treaties;
+    }
+    if let Some(cores) = delta.cores {
+        save_data.cores = cores;
+    }
+    if let Some(houses) = delta.houses {
+        save_data.houses = houses;
+    }
+    if let Some(devastation) = delta.devastation {
+        save_data.devastation = devastation;
+    }
+    if let Some(nation_state) = delta.nation_state {
+        save_data.nation_state = nation_state;
+    }
+    if let Some(forts) = delta.forts {
+        save_data.forts = forts;
+    }
+    if let Some(supply_depots) = delta.supply_depots {
+        save_data.supply_depots = supply_depots;
+    }
+    if let Some(trade_leagues) = delta.trade_leagues {
+        save_data.trade_leagues = trade_leagues;
+    }
+    if let Some(refugees) = delta.refugees {
+        save_data.refugees = refugees;
+    }
+}
+
+/// Apply every delta chained to the full save at `base_path`
+///
+/// Deltas are applied in sequence order and the chain stops at the first
+/// gap or unreadable file, so a damaged delta never skips over changes.
+pub fn apply_delta_chain(base_path: &Path, save_data: &mut SaveGameData) -> u32 {
+    let Some(base_name) = base_path.file_name().and_then(|n| n.to_str()) else {
+        return 0;
+    };
+    let Ok(entries) = fs::read_dir(SAVE_DIRECTORY) else {
+        return 0;
+    };
+
+    let Some(stem) = base_path.file_stem().and_then(|n| n.to_str()) else {
+        return 0;
+    };
+
+    // Only this chain's files are read; their headers must name the base too
+    let prefix = format!("{}_d", stem);
+    let mut deltas: Vec<SaveDelta> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == DELTA_EXTENSION))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|name| name.starts_with(&prefix))
+        })
+        .filter_map(|path| {
+            let compressed = fs::read(&path).ok()?;
+            let decompressed = decompress_data(&compressed).ok()?;
+            deserialize_save_delta(&String::from_utf8_lossy(&decompressed)).ok()
+        })
+        .filter(|delta| delta.base_save == base_name)
+        .collect();
+    deltas.sort_by_key(|delta| delta.sequence);
+
+    let mut applied = 0;
+    for delta in deltas {
+        if delta.sequence != applied + 1 {
+            warn!(
+                "Delta chain for {} breaks after {} deltas; later deltas ignored",
+                base_name, applied
+            );
+            break;
+        }
+        apply_delta(save_data, delta);
+        applied += 1;
+    }
+
+    if applied > 0 {
+        info!("Applied {} delta saves on top of {}", applied, base_name);
+    }
+    applied
+}
+
+/// Remove the deltas chained to a full save that is being deleted
+pub fn delete_delta_chain(base_path: &Path) {
+    let Some(stem) = base_path.file_stem().and_then(|n| n.to_str()) else {
+        return;
+    };
+    let Ok(entries) = fs::read_dir(SAVE_DIRECTORY) else {
+        return;
+    };
+
+    let prefix = format!("{}_d", stem);
+    for path in entries.flatten().map(|entry| entry.path()) {
+        let is_chained = path.extension().is_some_and(|ext| ext == DELTA_EXTENSION)
+            && path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|name| name.starts_with(&prefix));
+        if is_chained {
+            if let Err(e) = fs::remove_file(&path) {
+                warn!("Failed to delete delta save {:?}: {}", path, e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::{Province, ProvinceId};
+
+    #[test]
+    fn delta_replaces_changed_provinces_and_drops_dead_nations() {
+        let provinces: Vec<Province> = (0..3)
+            .map(|i| Province::new(ProvinceId::new(i), Vec2::ZERO))
+            .collect();
+        let mut save_data = SaveGameData {
+            version: SAVE_VERSION,
+            timestamp: Local::now(),
+            world_name: "Delta".to_string(),
+            world_seed: 1,
+            world_size: crate::resources::WorldSize::Small,
+            generation_version: 1,
+            map_dimensions: Default::default(),
+            game_time: GameTime::default(),
+            world_tension: WorldTension::default(),
+            map_mode: MapMode::default(),
+            map_heatmap: None,
+            provinces: provinces.clone(),
+            nation_laws: [
+                (NationId::new(0), NationLaws::default()),
+                (NationId::new(1), NationLaws::default()),
+            ]
+            .into(),
+            nations: Vec::new(),
+            province_owners: vec![Some(NationId::new(0)), Some(NationId::new(0)), Some(NationId::new(1))],
+            play_time_secs: 0.0,
+            mods: Vec::new(),
+            id_allocator: Default::default(),
+            chronicle: Default::default(),
+            milestones: Default::default(),
+            director: Default::default(),
+            statistics: Default::default(),
+            nation_governance: Default::default(),
+            economic_focus: Default::default(),
+            ai_behavior: Default::default(),
+            scripted_events: Default::default(),
+            sea_level: Default::default(),
+            mod_settings: Default::default(),
+            province_graph: Default::default(),
+            climate: Default::default(),
+            treaties: Vec::new(),
+            cores: Vec::new(),
+            houses: Vec::new(),
+            devastation: Default::default(),
+            nation_state: Vec::new(),
+            forts: Vec::new(),
+            supply_depots: Vec::new(),
+            trade_leagues: Vec::new(),
+            refugees: Default::default(),
+        };
+
+        let mut changed = provinces[1].clone();
+        changed.population = 4242;
+        let mut game_time = GameTime::default();
+        game_time.advance_ticks(1000);
+        let mut ids = crate::ids::IdAllocator::default();
+        ids.reserve(NationId::new(9));
+        apply_delta(
+            &mut save_data,
+            SaveDelta {
+                version: SAVE_VERSION,
+                timestamp: Local::now(),
+                base_save: "autosave.lws".to_string(),
+                sequence: 1,
+                game_time: game_time.clone(),
+                world_tension: WorldTension::default(),
+                map_mode: MapMode::default(),
+                map_heatmap: None,
+                provinces: vec![(changed, Some(NationId::new(7)))],
+                nations: Vec::new(),
+                nation_laws: Vec::new(),
+                removed_nations: vec![NationId::new(0)],
+                id_allocator: Some(ids),
+                chronicle: Default::default(),
+                milestones: Default::default(),
+                director: Default::default(),
+                statistics: Default::default(),
+                nation_governance: Default::default(),
+                economic_focus: Default::default(),
+                scripted_events: Default::default(),
+                sea_level: Default::default(),
+                mod_settings: Default::default(),
+                treaties: None,
+                cores: None,
+                houses: None,
+                devastation: None,
+                nation_state: None,
+                forts: None,
+                supply_depots: None,
+                trade_leagues: None,
+                refugees: None,
+            },
+        );
+
+        assert_eq!(save_data.provinces[1].population, 4242);
+        assert_eq!(
+            save_data.province_owners,
+            vec![None, Some(NationId::new(7)), Some(NationId::new(1))]
+        );
+        assert!(!save_data.nation_laws.contains_key(&NationId::new(0)));
+        assert!(save_data.nation_laws.contains_key(&NationId::new(1)));
+        assert_eq!(save_data.id_allocator.issued(crate::ids::IdSpace::Nation), 10);
+        assert_eq!(save_data.game_time.current_day(), game_time.current_day());
+    }
+}
@@ -0,0 +1,387 @@
+//! Save inspection - reading a save outside the game to check it is sound
+//!
+//! Backs the `save inspect` and `save validate` commands. Each stage of
+//! loading is checked on its own - reading, decompressing, decoding, the
+//! version, parsing, and the consistency of what was parsed - so a damaged
+//! save reports which part failed rather than a bare parse error. When the
+//! save fails to parse, the parser's error position is traced back to the
+//! top-level section (`provinces`, `nations`, ...) it fell in.
+
+use super::{
+    decompress_data, deserialize_save_delta, header_codec, read_save_summary, SaveGameData, SaveSummary,
+    DELTA_EXTENSION, SAVE_VERSION, STABLE_OWNERSHIP_VERSION,
+};
+use crate::nations::NationId;
+use crate::save_load::SaveCodec;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Outcome of checking one part of a save: what was found, or what is wrong
+#[derive(Debug, Clone)]
+pub struct SaveCheck {
+    pub section: &'static str,
+    pub outcome: Result<String, String>,
+}
+
+impl SaveCheck {
+    fn pass(section: &'static str, found: impl Into<String>) -> Self {
+        Self {
+            section,
+            outcome: Ok(found.into()),
+        }
+    }
+
+    fn fail(section: &'static str, problem: impl Into<String>) -> Self {
+        Self {
+            section,
+            outcome: Err(problem.into()),
+        }
+    }
+}
+
+/// Everything learned about a save file
+pub struct SaveInspection {
+    pub path: PathBuf,
+    pub file_size: u64,
+    /// Codec named in the header, `None` for legacy headerless zstd
+    pub codec: Option<SaveCodec>,
+    pub uncompressed_size: usize,
+    /// The parsed save, if it parsed
+    pub data: Option<SaveGameData>,
+    /// The browser summary written beside the save, if there is one
+    pub summary: Option<SaveSummary>,
+    pub checks: Vec<SaveCheck>,
+}
+
+impl SaveInspection {
+    /// Whether every check passed
+    pub fn is_valid(&self) -> bool {
+        self.checks.iter().all(|check| check.outcome.is_ok())
+    }
+}
+
+/// Read and check a save file, stage by stage
+///
+/// Stops at the first stage that fails, since later stages depend on it;
+/// the consistency checks after parsing all run and report separately.
+pub fn inspect_save_file(path: &Path) -> SaveInspection {
+    let mut inspection = SaveInspection {
+        path: path.to_path_buf(),
+        file_size: 0,
+        codec: None,
+        uncompressed_size: 0,
+        data: None,
+        summary: read_save_summary(path),
+        checks: Vec::new(),
+    };
+    let checks = &mut inspection.checks;
+
+    let compressed = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            checks.push(SaveCheck::fail("file", format!("cannot be read: {}", e)));
+            return inspection;
+        }
+    };
+    inspection.file_size = compressed.len() as u64;
+    inspection.codec = header_codec(&compressed);
+
+    let codec_name = inspection
+        .codec
+        .map_or_else(|| "legacy zstd".to_string(), |codec| codec.name());
+    let decompressed = match decompress_data(&compressed) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            checks.push(SaveCheck::fail(
+                "compression",
+                format!("{} stream is damaged: {}", codec_name, e),
+            ));
+            return inspection;
+        }
+    };
+    inspection.uncompressed_size = decompressed.len();
+    checks.push(SaveCheck::pass("compression", codec_name));
+
+    let text = match String::from_utf8(decompressed) {
+        Ok(text) => text,
+        Err(e) => {
+            checks.push(SaveCheck::fail(
+                "encoding",
+                format!("not valid UTF-8 at byte {}", e.utf8_error().valid_up_to()),
+            ));
+            return inspection;
+        }
+    };
+
+    let data: SaveGameData = match ron::from_str(&text) {
+        Ok(data) => data,
+        Err(e) => {
+            let offset = byte_offset(&text, e.position.line, e.position.col);
+            let section = failing_section(&text, offset).unwrap_or("header");
+            checks.push(SaveCheck::fail(
+                "parse",
+                format!("section `{}` is damaged at byte {}: {}", section, offset, e.code),
+            ));
+            return inspection;
+        }
+    };
+    checks.push(SaveCheck::pass("parse", "all sections read"));
+
+    checks.push(check_version(&data));
+    checks.push(check_provinces(&data));
+    checks.push(check_ownership(&data));
+    checks.push(check_nations(&data));
+    if let Some(summary) = &inspection.summary {
+        checks.push(check_summary(&data, summary));
+    }
+    if let Some(check) = check_delta_chain(path) {
+        checks.push(check);
+    }
+
+    inspection.data = Some(data);
+    inspection
+}
+
+fn check_version(data: &SaveGameData) -> SaveCheck {
+    if data.version > SAVE_VERSION {
+        SaveCheck::fail(
+            "version",
+            format!(
+                "written by a newer game (version {}, this game reads up to {})",
+                data.version, SAVE_VERSION
+            ),
+        )
+    } else if data.version < STABLE_OWNERSHIP_VERSION {
+        SaveCheck::pass(
+            "version",
+            format!("{} (legacy - province ownership will not be restored)", data.version),
+        )
+    } else {
+        SaveCheck::pass("version", data.version.to_string())
+    }
+}
+
+fn check_provinces(data: &SaveGameData) -> SaveCheck {
+    let count = data.provinces.len();
+    if count == 0 {
+        return SaveCheck::fail("provinces", "no provinces");
+    }
+    if let Some((index, province)) = data
+        .provinces
+        .iter()
+        .enumerate()
+        .find(|(index, province)| province.id.value() as usize != *index)
+    {
+        return SaveCheck::fail(
+            "provinces",
+            format!("province at index {} has id {}", index, province.id.value()),
+        );
+    }
+    if let Some(province) = data.provinces.iter().find(|province| {
+        province
+            .neighbor_indices
+            .iter()
+            .flatten()
+            .any(|&neighbor| neighbor >= count)
+    }) {
+        return SaveCheck::fail(
+            "provinces",
+            format!("province {} has a neighbor beyond the map", province.id.value()),
+        );
+    }
+    SaveCheck::pass("provinces", format!("{} provinces", count))
+}
+
+fn check_ownership(data: &SaveGameData) -> SaveCheck {
+    if data.version < STABLE_OWNERSHIP_VERSION {
+        return SaveCheck::pass("ownership", "not recorded in this version");
+    }
+    if data.province_owners.len() != data.provinces.len() {
+        return SaveCheck::fail(
+            "ownership",
+            format!(
+                "{} owners recorded for {} provinces",
+                data.province_owners.len(),
+                data.provinces.len()
+            ),
+        );
+    }
+    let nations: HashSet<NationId> = data.nations.iter().map(|(id, _)| *id).collect();
+    let unknown = data
+        .province_owners
+        .iter()
+        .flatten()
+        .filter(|owner| !nations.contains(owner))
+        .count();
+    if unknown > 0 {
+        return SaveCheck::fail(
+            "ownership",
+            format!("{} provinces owned by nations not in the save", unknown),
+        );
+    }
+    let owned = data.province_owners.iter().flatten().count();
+    SaveCheck::pass("ownership", format!("{} provinces owned", owned))
+}
+
+fn check_nations(data: &SaveGameData) -> SaveCheck {
+    let mut seen = HashSet::new();
+    if let Some((id, _)) = data.nations.iter().find(|(id, _)| !seen.insert(*id)) {
+        return SaveCheck::fail("nations", format!("nation {} appears twice", id));
+    }
+    if let Some((_, nation)) = data
+        .nations
+        .iter()
+        .find(|(_, nation)| nation.capital_province.value() as usize >= data.provinces.len())
+    {
+        return SaveCheck::fail("nations", format!("{}'s capital is beyond the map", nation.name));
+    }
+    if let Some(id) = data.nation_laws.keys().find(|id| !seen.contains(*id)) {
+        return SaveCheck::fail("nations", format!("laws recorded for missing nation {}", id));
+    }
+    SaveCheck::pass("nations", format!("{} nations", data.nations.len()))
+}
+
+fn check_summary(data: &SaveGameData, summary: &SaveSummary) -> SaveCheck {
+    let year = data.game_time.current_year();
+    if summary.year != year || summary.nation_count as usize != data.nations.len() {
+        return SaveCheck::fail(
+            "summary",
+            format!(
+                "sidecar says year {} with {} nations, save holds year {} with {}",
+                summary.year,
+                summary.nation_count,
+                year,
+                data.nations.len()
+            ),
+        );
+    }
+    SaveCheck::pass("summary", "matches the save")
+}
+
+/// Check the autosave deltas chained to this save, if it has any
+fn check_delta_chain(path: &Path) -> Option<SaveCheck> {
+    let base_name = path.file_name().and_then(|name| name.to_str())?;
+    let directory = path
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let prefix = format!("{}_d", path.file_stem().and_then(|stem| stem.to_str())?);
+
+    let mut sequences = Vec::new();
+    for delta_path in fs::read_dir(directory).ok()?.flatten().map(|entry| entry.path()) {
+        let chained = delta_path.extension().is_some_and(|ext| ext == DELTA_EXTENSION)
+            && delta_path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&prefix));
+        if !chained {
+            continue;
+        }
+        let delta = fs::read(&delta_path)
+            .map_err(|e| e.to_string())
+            .and_then(|bytes| decompress_data(&bytes))
+            .and_then(|bytes| deserialize_save_delta(&String::from_utf8_lossy(&bytes)));
+        match delta {
+            Ok(delta) if delta.base_save == base_name => sequences.push(delta.sequence),
+            Ok(_) => {}
+            Err(e) => {
+                return Some(SaveCheck::fail(
+                    "deltas",
+                    format!("{} is damaged: {}", delta_path.display(), e),
+                ))
+            }
+        }
+    }
+    if sequences.is_empty() {
+        return None;
+    }
+
+    sequences.sort_unstable();
+    match sequences
+        .iter()
+        .zip(1..)
+        .find(|&(&sequence, expected)| sequence != expected)
+    {
+        Some((_, expected)) => Some(SaveCheck::fail(
+            "deltas",
+            format!("chain breaks at delta {}; later deltas will be ignored", expected),
+        )),
+        None => Some(SaveCheck::pass("deltas", format!("{} chained deltas", sequences.len()))),
+    }
+}
+
+/// Byte offset of a 1-based line and column, as the RON parser reports them
+fn byte_offset(text: &str, line: usize, col: usize) -> usize {
+    let line_start: usize = text
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum();
+    let column: usize = text[line_start..]
+        .chars()
+        .take(col.saturating_sub(1))
+        .map(char::len_utf8)
+        .sum();
+    line_start + column
+}
+
+/// Top-level field of the save that `offset` falls in
+fn failing_section(text: &str, offset: usize) -> Option<&str> {
+    let bytes = text.as_bytes();
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut field_start = None;
+    let mut section = None;
+
+    for (index, &byte) in bytes.iter().enumerate().take(offset.min(bytes.len())) {
+        if in_string {
+            match byte {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match byte {
+            b'"' => in_string = true,
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth = depth.saturating_sub(1),
+            b':' if depth == 1 => {
+                if let Some(start) = field_start.take() {
+                    section = text.get(start..index).map(str::trim);
+                }
+            }
+            _ => {}
+        }
+        // A top-level field name starts right after the opening bracket or a comma
+        if depth == 1 && matches!(byte, b'(' | b',') {
+            field_start = Some(index + 1);
+        } else if depth != 1 {
+            field_start = None;
+        }
+    }
+    section
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_errors_are_traced_to_their_section() {
+        let text = "(version:2,world_name:\"a:(b\",provinces:[(id:(0),neighbors:[None])],nations:[oops])";
+        let offset = text.find("oops").unwrap_or_default();
+        assert_eq!(failing_section(text, offset), Some("nations"));
+        assert_eq!(
+            failing_section(text, text.find("id:").unwrap_or_default()),
+            Some("provinces")
+        );
+        assert_eq!(failing_section(text, 3), None);
+
+        let lines = "(version:2,\n  nations:[oops])";
+        assert_eq!(byte_offset(lines, 2, 12), lines.find("oops").unwrap_or_default());
+    }
+}
@@ -1,203 +1,456 @@
 //! Core load game operations
 //!
 //! This module handles the actual loading of game state, separated from UI and I/O.
+//!
+//! Loading runs in two halves so the loading screen stays responsive. A
+//! background task reads, decompresses, and deserializes the save; the world
+//! is then rebuilt across several frames, spawning nations a frame budget at
+//! a time like world generation does. Either half can be cancelled from the
+//! loading screen, which returns to the main menu.
 
-use super::{LoadCompleteEvent, LoadGameEvent};
-use super::{PendingLoadData, SaveGameList};
-use crate::loading::{set_loading_progress, start_save_loading, LoadingState};
+use super::nation_restoration::{resolve_province_owners, restore_nation};
+use super::{LoadCompleteEvent, LoadGameEvent, LoadStage, LoadTask, LoadTaskUpdate};
+use super::{PendingLoadData, PendingModCheck, PlayTime, SaveGameData, SaveGameList};
+use crate::loading::{set_loading_progress, start_save_loading, CancelSaveLoading, LoadingState};
+use crate::modding::ModManager;
+use crate::nations::{
+    NationId, ProvinceCores, Refugees, RestoredForts, RestoredProvinceCores, RestoredSupplyDepots,
+};
+use crate::relationships::RulesOver;
 use crate::resources::{ProvincesSpatialIndex, WorldName, WorldSeed};
 use crate::states::{GameState, RequestStateTransition};
-use crate::world::{build_world_mesh, CloudBuilder, ProvinceStorage, WorldMeshHandle};
-use bevy::prelude::*;
+use crate::ui::ShowNotification;
+use crate::world::{
+    analyze_infrastructure, build_region_hierarchy, build_world_mesh, restored_map_mode, CloudBuilder, HeatmapRegistry,
+    ProvinceGraph, ProvinceStorage, WorldMeshHandle, FRAME_BUDGET_MS,
+};
 use bevy::prelude::Mesh2d;
 use bevy::prelude::MeshMaterial2d;
+use bevy::prelude::*;
+use bevy::tasks::AsyncComputeTaskPool;
 use rand::{rngs::StdRng, SeedableRng};
 use rayon::prelude::*;
 use std::collections::HashMap;
 use std::fs;
+use std::path::Path;
+use std::time::Instant;
+
+/// Where a loaded save is in being rebuilt into the world
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum RestoreStep {
+    #[default]
+    Resources,
+    Mesh,
+    Nations,
+    Provinces,
+}
 
-/// Handle load game requests with decompression and deserialization
+/// Entities rebuilt so far from a loaded save
+///
+/// Everything spawned is remembered so a cancelled load leaves nothing behind.
+#[derive(Resource, Default)]
+pub struct SaveRestore {
+    step: RestoreStep,
+    next_nation: usize,
+    nation_entities: HashMap<NationId, Entity>,
+    mesh_entity: Option<Entity>,
+}
+
+/// Start reading the requested save in the background and show the loading screen
 pub fn handle_load_game(
     mut load_events: MessageReader<LoadGameEvent>,
-    mut complete_events: MessageWriter<LoadCompleteEvent>,
     mut commands: Commands,
     mut next_state: ResMut<NextState<GameState>>,
+    load_task: Option<Res<LoadTask>>,
 ) {
-    for event in load_events.read() {
-        info!("Loading game from: {:?}", event.save_path);
-
-        match fs::read(&event.save_path) {
-            Ok(compressed_data) => {
-                // Decompress
-                match super::decompress_data(&compressed_data) {
-                    Ok(decompressed) => {
-                        // Deserialize
-                        let data_str = String::from_utf8_lossy(&decompressed);
-                        match super::deserialize_save_data(&data_str) {
-                            Ok(save_data) => {
-                                if save_data.version > super::super::types::SAVE_VERSION {
-                                    error!(
-                                        "Save file version {} is newer than game version {}",
-                                        save_data.version,
-                                        super::super::types::SAVE_VERSION
-                                    );
-                                    complete_events.write(LoadCompleteEvent {
-                                        success: false,
-                                        message: format!("Save file version {} incompatible with game version {}",
-                                            save_data.version, super::super::types::SAVE_VERSION),
-                                    });
-                                    continue;
-                                }
-
-                                info!("Successfully loaded save from {}", save_data.timestamp);
-                                info!(
-                                    "Game time: {} days, World size: {:?}",
-                                    save_data.game_time.current_day(), save_data.world_size
-                                );
-
-                                // Initialize loading screen
-                                let mut loading_state = LoadingState::default();
-                                let file_size = std::fs::metadata(&event.save_path)
-                                    .map(|m| super::format_file_size(m.len()))
-                                    .unwrap_or_else(|_| "Unknown".to_string());
-                                start_save_loading(
-                                    &mut loading_state,
-                                    event
-                                        .save_path
-                                        .file_name()
-                                        .and_then(|n| n.to_str())
-                                        .unwrap_or("Save")
-                                        .to_string(),
-                                    save_data.game_time.current_day() as f32,
-                                    file_size,
-                                );
-                                commands.insert_resource(loading_state);
-
-                                // Store data for loading
-                                commands.insert_resource(PendingLoadData(save_data));
-
-                                // Transition to loading state
-                                next_state.set(GameState::LoadingWorld);
-
-                                complete_events.write(LoadCompleteEvent {
-                                    success: true,
-                                    message: format!("Game loaded from {:?}", event.save_path),
-                                });
-                            }
-                            Err(e) => {
-                                complete_events.write(LoadCompleteEvent {
-                                    success: false,
-                                    message: e,
-                                });
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        complete_events.write(LoadCompleteEvent {
-                            success: false,
-                            message: e,
-                        });
-                    }
-                }
+    let Some(event) = load_events.read().last() else {
+        return;
+    };
+    if load_task.is_some() {
+        warn!("A save is already loading; ignoring {:?}", event.save_path);
+        return;
+    }
+    info!("Loading game from: {:?}", event.save_path);
+
+    let (sender, receiver) = async_channel::unbounded();
+    let save_path = event.save_path.clone();
+    AsyncComputeTaskPool::get()
+        .spawn(async move {
+            let result = read_save_file(&save_path, &sender);
+            let _ = sender.try_send(LoadTaskUpdate::Finished(result.map(Box::new)));
+        })
+        .detach();
+
+    let mut loading_state = LoadingState::default();
+    let file_size = fs::metadata(&event.save_path)
+        .map(|m| super::format_file_size(m.len()))
+        .unwrap_or_else(|_| "Unknown".to_string());
+    start_save_loading(
+        &mut loading_state,
+        event
+            .save_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("Save")
+            .to_string(),
+        None,
+        file_size,
+    );
+    commands.insert_resource(loading_state);
+    commands.insert_resource(LoadTask {
+        save_path: event.save_path.clone(),
+        updates: receiver,
+    });
+    next_state.set(GameState::LoadingWorld);
+}
+
+/// Read, decompress, and deserialize a save, reporting each stage
+///
+/// Stops early once nobody is listening, i.e. the load was cancelled.
+fn read_save_file(save_path: &Path, updates: &async_channel::Sender<LoadTaskUpdate>) -> Result<SaveGameData, String> {
+    let report = |stage| {
+        if updates.is_closed() {
+            return Err("Load cancelled".to_string());
+        }
+        let _ = updates.try_send(LoadTaskUpdate::Stage(stage));
+        Ok(())
+    };
+
+    report(LoadStage::Reading)?;
+    let compressed_data = fs::read(save_path).map_err(|e| format!("Failed to read save file: {}", e))?;
+
+    // Decompress with whichever codec the header names
+    report(LoadStage::Decompressing)?;
+    debug!("Save compressed with {}", super::detect_codec(&compressed_data).name());
+    let decompressed = super::decompress_data(&compressed_data)?;
+
+    report(LoadStage::Deserializing)?;
+    let mut save_data = super::deserialize_save_data(&String::from_utf8_lossy(&decompressed))?;
+    if save_data.version > super::SAVE_VERSION {
+        return Err(format!(
+            "Save file version {} incompatible with game version {}",
+            save_data.version,
+            super::SAVE_VERSION
+        ));
+    }
+
+    // Autosave chains continue in the deltas written after this snapshot
+    super::apply_delta_chain(save_path, &mut save_data);
+    info!("Successfully loaded save from {}", save_data.timestamp);
+    if save_data.generation_version != crate::world::GENERATION_VERSION {
+        info!(
+            "Save world came from generation v{} (current v{}); its seed will not regenerate the same map",
+            save_data.generation_version,
+            crate::world::GENERATION_VERSION
+        );
+    }
+    info!(
+        "Game time: {} days, World size: {:?}",
+        save_data.game_time.current_day(),
+        save_data.world_size
+    );
+    Ok(save_data)
+}
+
+/// Follow the background read, then hold for the mod check or begin restoring
+pub fn poll_load_task(
+    mut commands: Commands,
+    load_task: Res<LoadTask>,
+    mut loading_state: ResMut<LoadingState>,
+    mod_manager: Option<Res<ModManager>>,
+    mut complete_events: MessageWriter<LoadCompleteEvent>,
+    mut state_events: MessageWriter<RequestStateTransition>,
+    mut notifications: MessageWriter<ShowNotification>,
+) {
+    while let Ok(update) = load_task.updates.try_recv() {
+        let save_data = match update {
+            LoadTaskUpdate::Stage(stage) => {
+                set_loading_progress(&mut loading_state, stage.progress(), stage.label());
+                continue;
             }
-            Err(e) => {
+            LoadTaskUpdate::Finished(Ok(save_data)) => *save_data,
+            LoadTaskUpdate::Finished(Err(message)) => {
+                error!("Failed to load {:?}: {}", load_task.save_path, message);
+                notifications.write(ShowNotification::error(format!("Load failed: {}", message)));
                 complete_events.write(LoadCompleteEvent {
                     success: false,
-                    message: format!("Failed to read save file: {}", e),
+                    message,
+                });
+                commands.remove_resource::<LoadTask>();
+                state_events.write(RequestStateTransition {
+                    from: GameState::LoadingWorld,
+                    to: GameState::MainMenu,
                 });
+                return;
             }
+        };
+        commands.remove_resource::<LoadTask>();
+
+        // Mods that differ from the save's hold the load for the player
+        let issues = super::check_mod_compatibility(&save_data.mods, &super::active_mods(mod_manager.as_deref()));
+        if !issues.is_empty() {
+            warn!("Save {:?} has {} mod differences", load_task.save_path, issues.len());
+            set_loading_progress(&mut loading_state, LoadStage::Restoring.progress(), "Checking mods...");
+            commands.insert_resource(PendingModCheck {
+                save_data: Some(save_data),
+                save_path: load_task.save_path.clone(),
+                issues,
+            });
+            return;
         }
+
+        begin_loading(&mut commands, &mut loading_state, save_data);
+        return;
     }
 }
 
-/// Check if we have pending save data to load instead of generating a new world
-pub fn check_for_pending_load(
+/// Hand a read save to the restore systems
+pub fn begin_loading(commands: &mut Commands, loading_state: &mut LoadingState, save_data: SaveGameData) {
+    loading_state.details.game_days = Some(save_data.game_time.current_day() as f32);
+    set_loading_progress(
+        loading_state,
+        LoadStage::Restoring.progress(),
+        LoadStage::Restoring.label(),
+    );
+    commands.insert_resource(PendingLoadData(save_data));
+    commands.insert_resource(SaveRestore::default());
+}
+
+/// Rebuild the world from a loaded save, one step per frame
+///
+/// Nations spawn in batches that fit the frame budget.
+pub fn restore_save_data(
     mut commands: Commands,
-    pending_load: Option<Res<PendingLoadData>>,
-    mut state_events: MessageWriter<RequestStateTransition>,
+    load_data: Res<PendingLoadData>,
+    mut restore: ResMut<SaveRestore>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
     mut loading_state: ResMut<LoadingState>,
+    mut complete_events: MessageWriter<LoadCompleteEvent>,
+    mut state_events: MessageWriter<RequestStateTransition>,
+    heatmaps: Option<Res<HeatmapRegistry>>,
 ) {
-    if let Some(load_data) = pending_load {
-        info!("Restoring game state from save...");
-        set_loading_progress(&mut loading_state, 0.2, "Restoring game state...");
-
-        // Insert all the loaded resources
-        commands.insert_resource(WorldSeed(load_data.0.world_seed));
-        commands.insert_resource(WorldName(load_data.0.world_name.clone()));
-        commands.insert_resource(load_data.0.world_size);
-        commands.insert_resource(load_data.0.map_dimensions);
-        commands.insert_resource(load_data.0.game_time.clone());
-        commands.insert_resource(load_data.0.world_tension.clone());
-        commands.insert_resource(load_data.0.map_mode);
-        set_loading_progress(&mut loading_state, 0.4, "Resources restored...");
-
-        // Rebuild world mesh
-        info!(
-            "Rebuilding world mesh from {} provinces...",
-            load_data.0.provinces.len()
-        );
-        set_loading_progress(&mut loading_state, 0.5, "Rebuilding world mesh...");
-        let mesh_handle =
-            build_world_mesh(&load_data.0.provinces, &mut meshes, load_data.0.world_seed);
-        set_loading_progress(&mut loading_state, 0.8, "Creating game entities...");
-
-        commands.spawn((
-            Mesh2d(mesh_handle.clone()),
-            MeshMaterial2d(materials.add(ColorMaterial::from(Color::WHITE))),
-            Transform::from_xyz(0.0, 0.0, 0.0),
-            Name::new("World Mega-Mesh"),
-        ));
+    let save_data = &load_data.0;
+    match restore.step {
+        RestoreStep::Resources => {
+            info!("Restoring game state from save...");
+            commands.insert_resource(WorldSeed(save_data.world_seed));
+            commands.insert_resource(WorldName(save_data.world_name.clone()));
+            commands.insert_resource(save_data.world_size);
+            commands.insert_resource(save_data.map_dimensions);
+            commands.insert_resource(save_data.game_time.clone());
+            commands.insert_resource(save_data.world_tension.clone());
+            commands.insert_resource(restored_map_mode(
+                save_data.map_mode,
+                save_data.map_heatmap.as_deref(),
+                heatmaps.as_deref(),
+            ));
+            commands.insert_resource(PlayTime {
+                seconds: save_data.play_time_secs,
+            });
+            commands.insert_resource(save_data.id_allocator.clone());
+            commands.insert_resource(save_data.chronicle.clone());
+            commands.insert_resource(save_data.milestones.clone());
+            commands.insert_resource(save_data.director.clone());
+            commands.insert_resource(save_data.statistics.clone());
+            commands.insert_resource(save_data.ai_behavior.clone());
+            commands.insert_resource(save_data.scripted_events.clone());
+            commands.insert_resource(save_data.sea_level.clone());
+            commands.insert_resource(save_data.mod_settings.clone());
+            commands.insert_resource(save_data.climate.clone());
+            commands.insert_resource(save_data.devastation.clone());
+            restore.step = RestoreStep::Mesh;
+        }
+        RestoreStep::Mesh => {
+            info!("Rebuilding world mesh from {} provinces...", save_data.provinces.len());
+            let mesh_handle = build_world_mesh(&save_data.provinces, &mut meshes, save_data.world_seed);
+            let mesh_entity = commands
+                .spawn((
+                    Mesh2d(mesh_handle.clone()),
+                    MeshMaterial2d(materials.add(ColorMaterial::from(Color::WHITE))),
+                    Transform::from_xyz(0.0, 0.0, 0.0),
+                    Name::new("World Mega-Mesh"),
+                ))
+                .id();
+            restore.mesh_entity = Some(mesh_entity);
+            commands.insert_resource(WorldMeshHandle(mesh_handle));
 
-        // Store the mesh handle
-        commands.insert_resource(WorldMeshHandle(mesh_handle.clone()));
-
-        // Create province storage with parallel ID mapping
-        let province_by_id: HashMap<_, _> = load_data
-            .0
-            .provinces
-            .par_iter()
-            .enumerate()
-            .map(|(idx, province)| (province.id, idx))
-            .collect();
-
-        commands.insert_resource(ProvinceStorage {
-            provinces: load_data.0.provinces.clone(),
-            province_by_id,
-        });
+            set_loading_progress(
+                &mut loading_state,
+                LoadStage::SpawningNations.progress(),
+                LoadStage::SpawningNations.label(),
+            );
+            restore.step = RestoreStep::Nations;
+        }
+        RestoreStep::Nations => {
+            let started = Instant::now();
+            while let Some((nation_id, nation)) = save_data.nations.get(restore.next_nation) {
+                let entity = restore_nation(&mut commands, save_data, *nation_id, nation);
+                restore.nation_entities.insert(*nation_id, entity);
+                restore.next_nation += 1;
+                if started.elapsed().as_secs_f32() * 1000.0 >= FRAME_BUDGET_MS {
+                    break;
+                }
+            }
 
-        // Create spatial index with parallel insertion
-        let spatial_entries: Vec<_> = load_data
-            .0
-            .provinces
-            .par_iter()
-            .map(|province| (province.position, province.id.value()))
-            .collect();
-
-        let mut spatial_index = ProvincesSpatialIndex::default();
-        for (position, id) in spatial_entries {
-            spatial_index.insert(position, id);
+            let total = save_data.nations.len();
+            let share = restore.next_nation as f32 / total.max(1) as f32;
+            let start = LoadStage::SpawningNations.progress();
+            set_loading_progress(
+                &mut loading_state,
+                start + share * (LoadStage::Finishing.progress() - start),
+                format!("Restoring nations ({}/{})...", restore.next_nation, total),
+            );
+            if restore.next_nation >= total {
+                info!("Restored {} nations from save", restore.nation_entities.len());
+                set_loading_progress(
+                    &mut loading_state,
+                    LoadStage::Finishing.progress(),
+                    LoadStage::Finishing.label(),
+                );
+                restore.step = RestoreStep::Provinces;
+            }
         }
-        commands.insert_resource(spatial_index);
+        RestoreStep::Provinces => {
+            // Create province storage with parallel ID mapping
+            let province_by_id: HashMap<_, _> = save_data
+                .provinces
+                .par_iter()
+                .enumerate()
+                .map(|(idx, province)| (province.id, idx))
+                .collect();
+
+            // Point provinces at the new nation entities
+            let owners = resolve_province_owners(
+                save_data.version,
+                save_data.provinces.len(),
+                &save_data.province_owners,
+                &restore.nation_entities,
+            );
+            let mut provinces = save_data.provinces.clone();
+            for (province, owner) in provinces.iter_mut().zip(owners) {
+                province.owner_entity = owner;
+            }
+            let storage = ProvinceStorage {
+                provinces,
+                province_by_id,
+            };
+            // Infrastructure follows from who owns and lives where, so it is not saved
+            commands.insert_resource(analyze_infrastructure(&storage.provinces, &storage));
+            commands.insert_resource(storage);
 
-        // Generate cloud system
-        let mut rng = StdRng::seed_from_u64(load_data.0.world_seed as u64);
-        let cloud_system = CloudBuilder::new(&mut rng, &load_data.0.map_dimensions).build();
-        commands.insert_resource(cloud_system);
+            // Saves carry the province graph; older ones, or ones whose map no longer matches, rebuild it
+            let graph = if save_data.province_graph.matches(&save_data.provinces) {
+                save_data.province_graph.clone()
+            } else {
+                ProvinceGraph::build(&save_data.provinces)
+            };
+            // The region hierarchy follows from the provinces and the seed, so it is not saved
+            build_region_hierarchy(&save_data.provinces, &graph, save_data.world_seed, &mut commands);
+            commands.insert_resource(graph);
 
-        // Remove pending load data
-        commands.remove_resource::<PendingLoadData>();
-        set_loading_progress(&mut loading_state, 1.0, "Load complete!");
+            // Treaties name their parties by stable id; one whose party is gone has lapsed
+            for saved in &save_data.treaties {
+                if let Some(treaty) = saved.restore(|id| restore.nation_entities.get(&id).copied()) {
+                    commands.spawn(treaty);
+                }
+            }
+            // Houses follow the nation they rule; one whose nation is gone has fallen
+            for (nation_id, house) in &save_data.houses {
+                if let Some(&nation_entity) = restore.nation_entities.get(nation_id) {
+                    commands.spawn((house.clone(), RulesOver(nation_entity)));
+                }
+            }
+            // Older saves carry no cores and start them afresh from current rule
+            if !save_data.cores.is_empty() {
+                let cores = ProvinceCores::restore(&save_data.cores, |id| restore.nation_entities.get(&id).copied());
+                commands.insert_resource(RestoredProvinceCores(cores));
+            }
+            // Nation components, leagues, and refugees name nations by stable id
+            let nation_entities = &restore.nation_entities;
+            let entity_of = |id: NationId| nation_entities.get(&id).copied();
+            for state in &save_data.nation_state {
+                if let Some(&nation_entity) = nation_entities.get(&state.nation) {
+                    state.restore(&mut commands.entity(nation_entity), entity_of);
+                }
+            }
+            for league in &save_data.trade_leagues {
+                commands.spawn(league.restore(entity_of));
+            }
+            let refugees = Refugees::restore(&save_data.refugees, save_data.provinces.len(), entity_of);
+            commands.insert_resource(refugees);
+            // Forts and depots wait for the province entities they stand in
+            commands.insert_resource(RestoredForts(save_data.forts.clone()));
+            commands.insert_resource(RestoredSupplyDepots(save_data.supply_depots.clone()));
 
-        // Transition to game
-        state_events.write(RequestStateTransition {
-            from: GameState::LoadingWorld,
-            to: GameState::InGame,
-        });
+            // Create spatial index with parallel insertion
+            let spatial_entries: Vec<_> = save_data
+                .provinces
+                .par_iter()
+                .map(|province| (province.position, province.id.value()))
+                .collect();
+            let mut spatial_index = ProvincesSpatialIndex::default();
+            for (position, id) in spatial_entries {
+                spatial_index.insert(position, id);
+            }
+            commands.insert_resource(spatial_index);
+
+            // Generate cloud system
+            let mut rng = StdRng::seed_from_u64(save_data.world_seed as u64);
+            let cloud_system = CloudBuilder::new(&mut rng, &save_data.map_dimensions).build();
+            commands.insert_resource(cloud_system);
+
+            commands.remove_resource::<PendingLoadData>();
+            commands.remove_resource::<SaveRestore>();
+            set_loading_progress(&mut loading_state, 1.0, "Load complete!");
+            complete_events.write(LoadCompleteEvent {
+                success: true,
+                message: format!("Game loaded: {}", save_data.world_name),
+            });
+
+            // Transition to game
+            state_events.write(RequestStateTransition {
+                from: GameState::LoadingWorld,
+                to: GameState::InGame,
+            });
+        }
     }
 }
 
+/// Abandon a load from the loading screen, despawning whatever was restored
+pub fn cancel_save_loading(
+    mut commands: Commands,
+    mut cancel_events: MessageReader<CancelSaveLoading>,
+    restore: Option<Res<SaveRestore>>,
+    mut complete_events: MessageWriter<LoadCompleteEvent>,
+    mut state_events: MessageWriter<RequestStateTransition>,
+) {
+    if cancel_events.read().last().is_none() {
+        return;
+    }
+    info!("Save loading cancelled");
+
+    // Dropping the task's receiver stops it at its next stage
+    commands.remove_resource::<LoadTask>();
+    commands.remove_resource::<PendingModCheck>();
+    commands.remove_resource::<PendingLoadData>();
+    if let Some(restore) = restore {
+        for entity in restore.nation_entities.values().chain(&restore.mesh_entity) {
+            commands.entity(*entity).despawn();
+        }
+        commands.remove_resource::<SaveRestore>();
+    }
+
+    complete_events.write(LoadCompleteEvent {
+        success: false,
+        message: "Load cancelled".to_string(),
+    });
+    state_events.write(RequestStateTransition {
+        from: GameState::LoadingWorld,
+        to: GameState::MainMenu,
+    });
+}
+
 /// Load the most recent save
 pub fn load_latest_save(world: &mut World) {
     // Scan for saves
@@ -213,3 +466,16 @@ pub fn load_latest_save(world: &mut World) {
         });
     }
 }
+
+/// Restore save data already in memory, as if it had just been read from disk
+///
+/// Headless runs that generate their world in-process load it this way.
+pub fn load_save_data(world: &mut World, save_data: SaveGameData) {
+    info!("Loading in-memory world: {}", save_data.world_name);
+    world.insert_resource(LoadingState::default());
+    world.insert_resource(PendingLoadData(save_data));
+    world.insert_resource(SaveRestore::default());
+    if let Some(mut next_state) = world.get_resource_mut::<NextState<GameState>>() {
+        next_state.set(GameState::LoadingWorld);
+    }
+}
@@ -10,28 +10,45 @@
 
 // Re-export what our children need from parent gateway (for internal use only)
 pub(self) use super::{
-    AutoSaveTimer, LoadCompleteEvent, LoadGameEvent, PendingLoadData, SaveCompleteEvent,
+    AutoSaveTimer, LoadStage, LoadTask, LoadTaskUpdate, ModIssue, PendingModCheck, PendingSave, PlayTime, SaveChangeTracker, SaveDelta, SaveSummary, SavedMod, DELTA_EXTENSION,
+    THUMBNAIL_HEIGHT, THUMBNAIL_WIDTH, SaveCompressionSettings, SaveProgressEvent, SaveStage, SaveTaskUpdate,
+    SaveTasks, AUTOSAVE_SLOT, LoadCompleteEvent, LoadGameEvent, PendingLoadData, SaveCompleteEvent,
     SaveGameData, SaveGameEvent, SaveGameList, SAVE_DIRECTORY, SAVE_EXTENSION, SAVE_VERSION,
+    STABLE_OWNERSHIP_VERSION,
 };
 
 // Re-export I/O functions our children need (for internal use only)
 pub(self) use super::io::{
-    compress_data, decompress_data, deserialize_save_data, format_file_size,
-    scan_save_files_internal, serialize_save_data,
+    compress_with_progress, decompress_data, deserialize_save_data, deserialize_save_delta, detect_codec,
+    format_file_size, header_codec, read_save_summary, scan_save_files_internal, serialize_save_data,
+    serialize_save_delta, write_save_summary,
 };
 
 // PRIVATE MODULES - Core logic implementation
 mod auto_save;
+mod delta;
+mod inspect;
 mod load;
+mod mod_check;
+mod nation_restoration;
 mod save;
+mod summary;
 
 // CONTROLLED EXPORTS - Core functionality
 
 // System functions (used by plugin)
 pub(super) use auto_save::handle_auto_save;
-pub(super) use load::{check_for_pending_load, handle_load_game};
-pub(super) use save::handle_save_game;
+pub(super) use delta::{delete_delta_chain, reset_save_chain, track_save_changes};
+pub(super) use load::{begin_loading, cancel_save_loading, handle_load_game, poll_load_task, restore_save_data, SaveRestore};
+pub(super) use save::{apply_compression_settings, handle_save_game, poll_save_tasks};
+pub(super) use summary::{accumulate_play_time, reset_play_time};
+
+// Delta save helpers (used by save and load)
+pub(self) use delta::{apply_delta_chain, build_save_delta, mark_autosaved};
+pub(self) use mod_check::check_mod_compatibility;
+pub(self) use summary::{active_mods, build_save_summary};
 
 // Public utility functions
-pub use load::load_latest_save;
-pub use save::quick_save;
+pub use inspect::{inspect_save_file, SaveCheck, SaveInspection};
+pub use load::{load_latest_save, load_save_data};
+pub use save::{quick_save, write_save_data};
@@ -0,0 +1,78 @@
+//! Mod compatibility checks for loading saves
+//!
+//! A save simulated under one set of mods can silently corrupt when loaded
+//! under another, so loads compare the save's recorded mods with the active
+//! ones and hold back on any difference until the player decides.
+
+use super::{ModIssue, SavedMod};
+
+/// Differences between the mods a save used and the mods active now
+pub fn check_mod_compatibility(saved: &[SavedMod], active: &[SavedMod]) -> Vec<ModIssue> {
+    let mut issues = Vec::new();
+
+    for saved_mod in saved {
+        match active.iter().find(|m| m.id == saved_mod.id) {
+            None => issues.push(ModIssue::Missing {
+                id: saved_mod.id.clone(),
+                version: saved_mod.version.clone(),
+            }),
+            Some(active_mod) if active_mod.version != saved_mod.version => {
+                issues.push(ModIssue::VersionMismatch {
+                    id: saved_mod.id.clone(),
+                    saved: saved_mod.version.clone(),
+                    active: active_mod.version.clone(),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+
+    for active_mod in active {
+        if !saved.iter().any(|m| m.id == active_mod.id) {
+            issues.push(ModIssue::Unexpected {
+                id: active_mod.id.clone(),
+                version: active_mod.version.clone(),
+            });
+        }
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn saved_mod(id: &str, version: &str) -> SavedMod {
+        SavedMod {
+            id: id.to_string(),
+            version: version.to_string(),
+        }
+    }
+
+    #[test]
+    fn reports_missing_mismatched_and_unexpected_mods() {
+        let saved = [saved_mod("rivers", "1.0"), saved_mod("empires", "2.0"), saved_mod("same", "1.0")];
+        let active = [saved_mod("empires", "2.1"), saved_mod("same", "1.0"), saved_mod("plagues", "0.3")];
+
+        assert_eq!(
+            check_mod_compatibility(&saved, &active),
+            vec![
+                ModIssue::Missing {
+                    id: "rivers".to_string(),
+                    version: "1.0".to_string()
+                },
+                ModIssue::VersionMismatch {
+                    id: "empires".to_string(),
+                    saved: "2.0".to_string(),
+                    active: "2.1".to_string()
+                },
+                ModIssue::Unexpected {
+                    id: "plagues".to_string(),
+                    version: "0.3".to_string()
+                },
+            ]
+        );
+        assert!(check_mod_compatibility(&active, &active).is_empty());
+    }
+}
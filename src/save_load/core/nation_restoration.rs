@@ -1,67 +1,110 @@
 //! Nation restoration during save game loading
 //!
-//! Restores nation entities and their associated law data.
+//! Saves refer to nations by stable [`NationId`]; this module spawns fresh
+//! nation entities and remaps province ownership onto them. Saves from before
+//! [`STABLE_OWNERSHIP_VERSION`] stored raw entities that cannot be resolved in a
+//! new session, so their provinces load unowned rather than pointing at
+//! whatever entity happens to reuse the old bits.
 
+use super::{SaveGameData, STABLE_OWNERSHIP_VERSION};
+use crate::nations::{
+    GovernmentHistory, Nation, NationBundle, NationHistory, NationId, NationLaws, OwnsTerritory,
+    PoliticalPressure,
+};
+use crate::simulation::PressureVector;
 use bevy::prelude::*;
-use crate::nations::{Nation, NationId, laws::NationLaws};
-use crate::save_load::types::SaveGameData;
 use std::collections::HashMap;
 
-/// Restore nations and their laws from save data
-pub fn restore_nations_with_laws(
-    commands: &mut Commands,
-    save_data: &SaveGameData,
-) -> HashMap<NationId, Entity> {
-    let mut nation_entity_map = HashMap::new();
-
-    // Recreate each nation with its laws
-    for (nation_id, nation_laws) in &save_data.nation_laws {
-        // Create nation entity with laws
-        let entity = commands
-            .spawn((
-                // Note: We need the actual Nation component data
-                // This is a placeholder - in reality we'd need to save full nation data
-                Nation {
-                    id: *nation_id,
-                    name: format!("Nation {}", nation_id.value()),
-                    treasury: 1000.0,
-                    stability: 0.5,
-                    military_strength: 100.0,
-                    technology_level: 1,
-                    culture: crate::name_generator::Culture::Western,
-                    personality: crate::nations::NationPersonality::balanced(),
-                    // ... other fields would be restored from save
-                },
-                nation_laws.clone(),
-            ))
-            .id();
+/// Spawn one nation entity from save data
+///
+/// `NationIndex` picks the new entity up through the `NationId` hooks once
+/// the commands apply; the returned entity is for use until then.
+pub fn restore_nation(commands: &mut Commands, save_data: &SaveGameData, nation_id: NationId, nation: &Nation) -> Entity {
+    let laws = save_data
+        .nation_laws
+        .get(&nation_id)
+        .cloned()
+        .unwrap_or_default();
+    let focus = save_data
+        .economic_focus
+        .get(&nation_id)
+        .copied()
+        .unwrap_or_default();
+    let mut entity_commands = commands.spawn((
+        NationBundle {
+            nation: nation.clone(),
+            economy: focus.economy(),
+            transform: Transform::default(),
+            visibility: Visibility::default(),
+            pressure_vector: PressureVector::default(),
+            history: NationHistory::default(),
+            laws,
+        },
+        OwnsTerritory::default(),
+        focus,
+        nation_id,
+    ));
+    if let Some(governance) = save_data.nation_governance.get(&nation_id) {
+        entity_commands.insert((
+            governance.clone(),
+            PoliticalPressure::default(),
+            GovernmentHistory::new(governance.government_type),
+        ));
+    }
+    entity_commands.id()
+}
 
-        nation_entity_map.insert(*nation_id, entity);
+/// Resolve each province's owner to a live nation entity
+///
+/// Returns one entry per province. Owners whose nation was not restored, and
+/// every owner in a pre-versioned save, resolve to `None`.
+pub fn resolve_province_owners(
+    save_version: u32,
+    province_count: usize,
+    province_owners: &[Option<NationId>],
+    nation_entities: &HashMap<NationId, Entity>,
+) -> Vec<Option<Entity>> {
+    if save_version < STABLE_OWNERSHIP_VERSION {
+        warn!(
+            "Save version {} predates stable nation ids; provinces will load unowned",
+            save_version
+        );
+        return vec![None; province_count];
+    }
 
-        info!(
-            "Restored nation {} with {} active laws and {} proposed laws",
-            nation_id.value(),
-            nation_laws.active_laws.len(),
-            nation_laws.proposed_laws.len()
+    if province_owners.len() != province_count {
+        warn!(
+            "Save lists {} province owners for {} provinces; missing entries load unowned",
+            province_owners.len(),
+            province_count
         );
     }
 
-    nation_entity_map
+    (0..province_count)
+        .map(|idx| {
+            province_owners
+                .get(idx)
+                .copied()
+                .flatten()
+                .and_then(|id| nation_entities.get(&id).copied())
+        })
+        .collect()
 }
 
-/// Update the province ownership based on restored nations
-pub fn update_province_ownership(
-    provinces: &mut [crate::world::Province],
-    nation_entity_map: &HashMap<NationId, Entity>,
-) {
-    for province in provinces.iter_mut() {
-        if let Some(owner_id) = province.owner {
-            // Update province ownership to use new entity references
-            // This maintains the relationship between provinces and nations
-            if let Some(_entity) = nation_entity_map.get(&owner_id) {
-                // Province ownership is already stored as NationId
-                // Entity relationships would be handled by the entity system
-            }
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_saves_load_unowned_and_unknown_ids_drop() {
+        let nation = Entity::from_raw_u32(7).unwrap_or(Entity::PLACEHOLDER);
+        let entities = HashMap::from([(NationId::new(3), nation)]);
+        let owners = [Some(NationId::new(3)), None, Some(NationId::new(9))];
+
+        assert_eq!(
+            resolve_province_owners(STABLE_OWNERSHIP_VERSION, 4, &owners, &entities),
+            vec![Some(nation), None, None, None]
+        );
+        assert_eq!(resolve_province_owners(1, 2, &owners, &entities), vec![None, None]);
     }
-}
\ No newline at end of file
+}
@@ -2,37 +2,226 @@
 //!
 //! This module handles the actual saving of game state, separated from UI and I/O.
 
-use super::{SaveCompleteEvent, SaveGameEvent};
+use super::{
+    active_mods, build_save_delta, build_save_summary, mark_autosaved, write_save_summary, PendingSave, PlayTime,
+    SaveSummary, SaveChangeTracker, SaveCompleteEvent, SaveCompressionSettings, SaveDelta,
+    SaveGameEvent, SaveProgressEvent, SaveStage, SaveTaskUpdate, SaveTasks, AUTOSAVE_SLOT, DELTA_EXTENSION,
+};
 use super::{SaveGameData, SAVE_DIRECTORY, SAVE_EXTENSION, SAVE_VERSION};
+use crate::save_load::SaveCodec;
+use crate::settings::{GameSettings, SaveCompression};
 use crate::resources::{
     GameTime, MapDimensions, MapMode, WorldName, WorldSeed, WorldSize, WorldTension,
 };
-use crate::world::ProvinceStorage;
-use crate::nations::{Nation, NationLaws};
+use crate::world::{
+    saved_map_mode, ClimateStorage, HeatmapRegistry, ProvinceEntityOrder, ProvinceGraph, ProvinceStorage, SeaLevel,
+    WorldGenerationSettings, GENERATION_VERSION,
+};
+use crate::ai::AiBehavior;
+use crate::chronicle::WorldChronicle;
+use crate::ids::IdAllocator;
+use crate::milestones::WorldMilestones;
+use crate::modding::{ModManager, WorldModSettings};
+use crate::simulation::WorldDirector;
+use crate::world_report::WorldStatistics;
+use crate::nations::{
+    Devastation, EconomicFocus, FortConstruction, Governance, House, Nation, NationId, NationIndex, NationLaws,
+    NationStateData, ProvinceCores, Refugees, RestoredForts, RestoredSupplyDepots, SavedFort, SavedNationState,
+    SavedSupplyDepot, SavedTradeLeague, SavedTreaty, ScriptedEventState, SupplyDepot, TradeLeague, Treaty,
+};
+use crate::relationships::{Fortification, RulesOver, StationedIn};
 use bevy::prelude::*;
-use bevy::tasks::IoTaskPool;
+use bevy::tasks::AsyncComputeTaskPool;
 use chrono::Local;
 use rayon::prelude::*;
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::Write;
+use std::path::{Path, PathBuf};
 
-/// Handle save game requests with compression and versioning
+/// Share of save progress spent serializing before compression starts
+const SERIALIZE_SHARE: f32 = 0.3;
+/// Share of save progress reserved for writing the file
+const WRITE_SHARE: f32 = 0.05;
+
+/// What a background save task writes
+enum SavePayload {
+    Full(SaveGameData),
+    Delta(SaveDelta),
+}
+
+/// Handle save game requests, handing each snapshot to a background task
 pub fn handle_save_game(
     mut save_events: MessageReader<SaveGameEvent>,
-    mut complete_events: MessageWriter<SaveCompleteEvent>,
+    mut save_tasks: ResMut<SaveTasks>,
+    mut change_tracker: ResMut<SaveChangeTracker>,
+    compression: Res<SaveCompressionSettings>,
     world_seed: Option<Res<WorldSeed>>,
     world_name: Option<Res<WorldName>>,
     world_size: Option<Res<WorldSize>>,
     map_dims: Option<Res<MapDimensions>>,
     game_time: Option<Res<GameTime>>,
     world_tension: Option<Res<WorldTension>>,
-    map_mode: Option<Res<MapMode>>,
-    province_storage: Option<Res<ProvinceStorage>>,
+    (map_mode, heatmaps): (Option<Res<MapMode>>, Option<Res<HeatmapRegistry>>),
+    mut province_storage: Option<ResMut<ProvinceStorage>>,
+    generation_settings: Option<Res<WorldGenerationSettings>>,
+    nation_index: Res<NationIndex>,
     nations_query: Query<(Entity, &Nation, &crate::nations::NationId, &NationLaws)>,
+    (
+        play_time,
+        mod_manager,
+        ids,
+        chronicle,
+        milestones,
+        director,
+        statistics,
+        setups,
+        ai_behavior,
+        scripted_events,
+        sea_level,
+        mod_settings,
+        province_graph,
+        climate,
+        (treaties_query, houses_query),
+        (
+            province_cores,
+            devastation,
+            refugees,
+            entity_order,
+            (pending_forts, pending_depots),
+            (nation_state_query, forts_query, depots_query, leagues_query),
+        ),
+    ): (
+        Res<PlayTime>,
+        Option<Res<ModManager>>,
+        Res<IdAllocator>,
+        Res<WorldChronicle>,
+        Res<WorldMilestones>,
+        Res<WorldDirector>,
+        Res<WorldStatistics>,
+        Query<(&NationId, Option<&Governance>, Option<&EconomicFocus>)>,
+        Res<AiBehavior>,
+        Res<ScriptedEventState>,
+        Res<SeaLevel>,
+        Res<WorldModSettings>,
+        Res<ProvinceGraph>,
+        Option<Res<ClimateStorage>>,
+        (Query<&Treaty>, Query<(&House, &RulesOver)>),
+        (
+            Res<ProvinceCores>,
+            Res<Devastation>,
+            Res<Refugees>,
+            Option<Res<ProvinceEntityOrder>>,
+            (Option<Res<RestoredForts>>, Option<Res<RestoredSupplyDepots>>),
+            (
+                Query<NationStateData>,
+                Query<(&Fortification, &StationedIn, Option<&FortConstruction>)>,
+                Query<(&SupplyDepot, &StationedIn)>,
+                Query<&TradeLeague>,
+            ),
+        ),
+    ),
 ) {
     for event in save_events.read() {
         info!("Saving game to slot: {}", event.slot_name);
 
+        let nation_colors: HashMap<Entity, Color> = nations_query
+            .iter()
+            .map(|(entity, nation, _, _)| (entity, nation.color))
+            .collect();
+        let mods = active_mods(mod_manager.as_deref());
+        let summary = build_save_summary(
+            game_time.as_deref(),
+            &nation_colors,
+            &play_time,
+            mods.clone(),
+            province_storage.as_deref(),
+            map_dims.as_deref(),
+            world_seed.as_ref().map_or(0, |s| s.0),
+        );
+
+        let treaties = collect_treaties(&treaties_query, &nation_index);
+        let cores = province_cores.to_saved(|entity| nation_index.id(entity));
+        let houses = collect_houses(&houses_query, &nation_index);
+        let id_of = |entity: Entity| nation_index.id(entity);
+        let nation_state = SavedNationState::collect(&nation_state_query, id_of);
+        let province_index: HashMap<Entity, usize> = entity_order
+            .iter()
+            .flat_map(|order| order.entities.iter().enumerate().map(|(index, &entity)| (entity, index)))
+            .collect();
+        let forts = collect_forts(&forts_query, &province_index, &nation_index, pending_forts.as_deref());
+        let supply_depots = collect_depots(&depots_query, &province_index, &nation_index, pending_depots.as_deref());
+        let trade_leagues: Vec<SavedTradeLeague> = leagues_query.iter().map(|league| league.to_saved(id_of)).collect();
+        let saved_refugees = refugees.to_saved(id_of);
+        let (saved_mode, saved_heatmap) =
+            saved_map_mode(map_mode.as_deref().copied().unwrap_or_default(), heatmaps.as_deref());
+
+        let is_autosave = event.slot_name == AUTOSAVE_SLOT;
+        let codec = if is_autosave {
+            compression.autosave_codec
+        } else {
+            compression.manual_codec
+        };
+
+        // Between full autosaves, write only what changed since the previous one
+        let delta_base = change_tracker
+            .base_save
+            .clone()
+            .filter(|_| is_autosave && change_tracker.wants_delta());
+        if let Some(base_save) = delta_base {
+            let mut delta = build_save_delta(
+                &change_tracker,
+                game_time.as_deref().cloned().unwrap_or_default(),
+                world_tension.as_deref().cloned().unwrap_or_default(),
+                saved_mode,
+                province_storage.as_deref(),
+                &nation_index,
+                nations_query
+                    .iter()
+                    .map(|(_, nation, nation_id, laws)| (*nation_id, nation.clone(), laws.clone())),
+            );
+            delta.map_heatmap = saved_heatmap;
+            delta.id_allocator = Some(ids.clone());
+            delta.chronicle = chronicle.clone();
+            delta.milestones = milestones.clone();
+            delta.director = director.clone();
+            delta.statistics = statistics.clone();
+            delta.nation_governance = collect_governance(&setups);
+            delta.economic_focus = collect_economic_focus(&setups);
+            delta.scripted_events = scripted_events.clone();
+            delta.sea_level = sea_level.clone();
+            delta.mod_settings = mod_settings.clone();
+            delta.treaties = Some(treaties);
+            delta.cores = Some(cores);
+            delta.houses = Some(houses);
+            delta.devastation = Some(devastation.clone());
+            delta.nation_state = Some(nation_state);
+            delta.forts = Some(forts);
+            delta.supply_depots = Some(supply_depots);
+            delta.trade_leagues = Some(trade_leagues);
+            delta.refugees = Some(saved_refugees);
+            let base_stem = base_save.trim_end_matches(&format!(".{}", SAVE_EXTENSION));
+            let filename = format!("{}/{}_d{}.{}", SAVE_DIRECTORY, base_stem, delta.sequence, DELTA_EXTENSION);
+            change_tracker.sequence = delta.sequence;
+            mark_autosaved(
+                &mut change_tracker,
+                province_storage.as_mut().map(|storage| storage.bypass_change_detection()),
+                nations_query.iter().map(|(_, _, nation_id, _)| *nation_id),
+            );
+
+            // The browser lists the base save, so its summary follows the chain
+            let listed_save = Path::new(SAVE_DIRECTORY).join(&base_save);
+            spawn_save_task(
+                &mut save_tasks,
+                &event.slot_name,
+                filename,
+                SavePayload::Delta(delta),
+                (listed_save, summary),
+                codec,
+            );
+            continue;
+        }
+
         // Gather all game state into SaveGameData (optimized province handling)
         let save_data = SaveGameData {
             version: SAVE_VERSION,
@@ -43,10 +232,14 @@ pub fn handle_save_game(
                 .unwrap_or_else(|| "Unnamed World".to_string()),
             world_seed: world_seed.as_ref().map(|s| s.0).unwrap_or(0),
             world_size: world_size.as_deref().copied().unwrap_or(WorldSize::Medium),
+            generation_version: generation_settings
+                .as_ref()
+                .map_or(GENERATION_VERSION, |settings| settings.generation_version),
             map_dimensions: map_dims.as_deref().copied().unwrap_or_default(),
             game_time: game_time.as_deref().cloned().unwrap_or_default(),
             world_tension: world_tension.as_deref().cloned().unwrap_or_default(),
-            map_mode: map_mode.as_deref().copied().unwrap_or_default(),
+            map_mode: saved_mode,
+            map_heatmap: saved_heatmap,
             provinces: province_storage
                 .as_ref()
                 .map(|s| {
@@ -67,73 +260,277 @@ pub fn handle_save_game(
                 .iter()
                 .map(|(_, _, nation_id, laws)| (*nation_id, laws.clone()))
                 .collect(),
+            nations: nations_query
+                .iter()
+                .map(|(_, nation, nation_id, _)| (*nation_id, nation.clone()))
+                .collect(),
+            // Entities don't survive a reload, so ownership is written by stable id
+            province_owners: province_storage
+                .as_ref()
+                .map(|s| {
+                    s.provinces
+                        .iter()
+                        .map(|province| province.owner_entity.and_then(|owner| nation_index.id(owner)))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            play_time_secs: play_time.seconds,
+            mods,
+            id_allocator: ids.clone(),
+            chronicle: chronicle.clone(),
+            milestones: milestones.clone(),
+            director: director.clone(),
+            statistics: statistics.clone(),
+            nation_governance: collect_governance(&setups),
+            economic_focus: collect_economic_focus(&setups),
+            ai_behavior: ai_behavior.clone(),
+            scripted_events: scripted_events.clone(),
+            sea_level: sea_level.clone(),
+            mod_settings: mod_settings.clone(),
+            province_graph: province_graph.clone(),
+            climate: climate.as_deref().cloned().unwrap_or_default(),
+            treaties,
+            cores,
+            houses,
+            devastation: devastation.clone(),
+            nation_state,
+            forts,
+            supply_depots,
+            trade_leagues,
+            refugees: saved_refugees,
         };
 
-        // Serialize and compress
-        match super::serialize_save_data(&save_data) {
-            Ok(serialized) => {
-                match super::compress_data(serialized.as_bytes()) {
-                    Ok(compressed) => {
-                        // Generate filename with timestamp
-                        let timestamp = Local::now().format("%Y%m%d_%H%M%S");
-                        let filename = format!(
-                            "{}/{}_{}.{}",
-                            SAVE_DIRECTORY, event.slot_name, timestamp, SAVE_EXTENSION
-                        );
-
-                        let filename_clone = filename.clone();
-                        let compressed_size = compressed.len() as u64;
-
-                        // Write to file asynchronously
-                        IoTaskPool::get()
-                            .spawn(async move {
-                                match File::create(&filename_clone) {
-                                    Ok(mut file) => {
-                                        if let Err(e) = file.write_all(&compressed) {
-                                            error!("Failed to write save file: {}", e);
-                                            false
-                                        } else {
-                                            info!(
-                                                "Game saved successfully to: {} ({}KB compressed)",
-                                                filename_clone,
-                                                compressed_size / 1024
-                                            );
-                                            true
-                                        }
-                                    }
-                                    Err(e) => {
-                                        error!("Failed to create save file: {}", e);
-                                        false
-                                    }
-                                }
-                            })
-                            .detach();
-
-                        complete_events.write(SaveCompleteEvent {
-                            success: true,
-                            message: format!(
-                                "Game saved to {} ({}KB)",
-                                filename,
-                                compressed_size / 1024
-                            ),
-                        });
-                    }
-                    Err(e) => {
-                        complete_events.write(SaveCompleteEvent {
-                            success: false,
-                            message: e,
-                        });
-                    }
+        let timestamp = Local::now().format("%Y%m%d_%H%M%S");
+        let base_name = format!("{}_{}.{}", event.slot_name, timestamp, SAVE_EXTENSION);
+        if is_autosave {
+            change_tracker.begin_chain(base_name.clone());
+            mark_autosaved(
+                &mut change_tracker,
+                province_storage.as_mut().map(|storage| storage.bypass_change_detection()),
+                nations_query.iter().map(|(_, _, nation_id, _)| *nation_id),
+            );
+        }
+        let filename = format!("{}/{}", SAVE_DIRECTORY, base_name);
+        let listed_save = PathBuf::from(&filename);
+
+        spawn_save_task(
+            &mut save_tasks,
+            &event.slot_name,
+            filename,
+            SavePayload::Full(save_data),
+            (listed_save, summary),
+            codec,
+        );
+    }
+}
+
+/// Every nation's government, keyed by stable id
+fn collect_governance(
+    setups: &Query<(&NationId, Option<&Governance>, Option<&EconomicFocus>)>,
+) -> HashMap<NationId, Governance> {
+    setups
+        .iter()
+        .filter_map(|(id, governance, _)| governance.map(|g| (*id, g.clone())))
+        .collect()
+}
+
+/// Every nation's economic focus, keyed by stable id
+fn collect_economic_focus(
+    setups: &Query<(&NationId, Option<&Governance>, Option<&EconomicFocus>)>,
+) -> HashMap<NationId, EconomicFocus> {
+    setups
+        .iter()
+        .filter_map(|(id, _, focus)| focus.map(|f| (*id, *f)))
+        .collect()
+}
+
+/// Every treaty in force, with its parties by stable id
+fn collect_treaties(treaties: &Query<&Treaty>, nation_index: &NationIndex) -> Vec<SavedTreaty> {
+    treaties
+        .iter()
+        .filter_map(|treaty| treaty.to_saved(|entity| nation_index.id(entity)))
+        .collect()
+}
+
+/// Every ruling house, with the nation it rules by stable id
+fn collect_houses(
+    houses: &Query<(&House, &RulesOver)>,
+    nation_index: &NationIndex,
+) -> Vec<(NationId, House)> {
+    houses
+        .iter()
+        .filter_map(|(house, rules_over)| nation_index.id(rules_over.0).map(|id| (id, house.clone())))
+        .collect()
+}
+
+/// Every fort by the province it stands in, with its builder by stable id
+///
+/// Forts a loaded save has not yet put back on the map are written out as they were read.
+fn collect_forts(
+    forts: &Query<(&Fortification, &StationedIn, Option<&FortConstruction>)>,
+    province_index: &HashMap<Entity, usize>,
+    nation_index: &NationIndex,
+    pending: Option<&RestoredForts>,
+) -> Vec<SavedFort> {
+    forts
+        .iter()
+        .filter_map(|(fort, stationed_in, construction)| {
+            let &province = province_index.get(&stationed_in.0)?;
+            SavedFort::new(province, fort, construction, |entity| nation_index.id(entity))
+        })
+        .chain(pending.into_iter().flat_map(|pending| pending.0.iter().cloned()))
+        .collect()
+}
+
+/// Every supply depot by the province it stands in, with its owner by stable id
+///
+/// Depots a loaded save has not yet put back on the map are written out as they were read.
+fn collect_depots(
+    depots: &Query<(&SupplyDepot, &StationedIn)>,
+    province_index: &HashMap<Entity, usize>,
+    nation_index: &NationIndex,
+    pending: Option<&RestoredSupplyDepots>,
+) -> Vec<SavedSupplyDepot> {
+    depots
+        .iter()
+        .filter_map(|(depot, stationed_in)| {
+            let &province = province_index.get(&stationed_in.0)?;
+            depot.to_saved(province, |entity| nation_index.id(entity))
+        })
+        .chain(pending.into_iter().flat_map(|pending| pending.0.iter().cloned()))
+        .collect()
+}
+
+fn spawn_save_task(
+    save_tasks: &mut SaveTasks,
+    slot_name: &str,
+    filename: String,
+    payload: SavePayload,
+    (listed_save, summary): (PathBuf, SaveSummary),
+    codec: SaveCodec,
+) {
+    let (sender, receiver) = async_channel::unbounded();
+
+    // Serialization and compression of large worlds take seconds; keep them off the frame
+    AsyncComputeTaskPool::get()
+        .spawn(async move {
+            let result = write_save_file(&payload, filename, codec, &sender);
+            if result.is_ok() {
+                if let Err(e) = write_save_summary(&listed_save, &summary) {
+                    warn!("Save written without a browser summary: {}", e);
                 }
             }
-            Err(e) => {
-                complete_events.write(SaveCompleteEvent {
-                    success: false,
-                    message: e,
-                });
+            let _ = sender.try_send(SaveTaskUpdate::Finished(result));
+        })
+        .detach();
+
+    save_tasks.pending.push(PendingSave {
+        slot_name: slot_name.to_string(),
+        updates: receiver,
+    });
+}
+
+/// Serialize, compress, and write a save, reporting progress along the way
+fn write_save_file(
+    payload: &SavePayload,
+    filename: String,
+    codec: SaveCodec,
+    progress: &async_channel::Sender<SaveTaskUpdate>,
+) -> Result<(String, u64), String> {
+    let report = |stage, progress_value| {
+        let _ = progress.try_send(SaveTaskUpdate::Progress {
+            stage,
+            progress: progress_value,
+        });
+    };
+
+    report(SaveStage::Serializing, 0.0);
+    let serialized = match payload {
+        SavePayload::Full(save_data) => super::serialize_save_data(save_data)?,
+        SavePayload::Delta(delta) => super::serialize_save_delta(delta)?,
+    };
+
+    // Compression dominates, so it gets most of the progress bar
+    report(SaveStage::Compressing, SERIALIZE_SHARE);
+    let compressed = super::compress_with_progress(serialized.as_bytes(), codec, |fraction| {
+        report(
+            SaveStage::Compressing,
+            SERIALIZE_SHARE + fraction * (1.0 - SERIALIZE_SHARE - WRITE_SHARE),
+        );
+    })?;
+
+    report(SaveStage::Writing, 1.0 - WRITE_SHARE);
+    File::create(&filename)
+        .and_then(|mut file| file.write_all(&compressed))
+        .map_err(|e| format!("Failed to write save file: {}", e))?;
+
+    info!(
+        "Game saved to {} ({}KB, {})",
+        filename,
+        compressed.len() / 1024,
+        codec.name()
+    );
+    Ok((filename, compressed.len() as u64))
+}
+
+/// Follow the save compression chosen in the settings menu
+pub fn apply_compression_settings(
+    settings: Option<Res<GameSettings>>,
+    mut compression: ResMut<SaveCompressionSettings>,
+) {
+    let Some(settings) = settings.filter(|settings| settings.is_changed()) else {
+        return;
+    };
+    let codec = |level: SaveCompression| match level {
+        SaveCompression::Fast => SaveCodec::FAST,
+        SaveCompression::Balanced => SaveCodec::BALANCED,
+        SaveCompression::Smallest => SaveCodec::SMALLEST,
+    };
+    compression.autosave_codec = codec(settings.interface.autosave_compression);
+    compression.manual_codec = codec(settings.interface.manual_save_compression);
+}
+
+/// Forward background save progress and completion as messages
+pub fn poll_save_tasks(
+    mut save_tasks: ResMut<SaveTasks>,
+    mut progress_events: MessageWriter<SaveProgressEvent>,
+    mut complete_events: MessageWriter<SaveCompleteEvent>,
+) {
+    save_tasks.pending.retain(|pending| {
+        while let Ok(update) = pending.updates.try_recv() {
+            match update {
+                SaveTaskUpdate::Progress { stage, progress } => {
+                    progress_events.write(SaveProgressEvent {
+                        slot_name: pending.slot_name.clone(),
+                        stage,
+                        progress,
+                    });
+                }
+                SaveTaskUpdate::Finished(result) => {
+                    complete_events.write(match result {
+                        Ok((filename, size)) => SaveCompleteEvent {
+                            slot_name: pending.slot_name.clone(),
+                            message: format!("Game saved to {} ({}KB)", filename, size / 1024),
+                            path: Some(PathBuf::from(filename)),
+                            success: true,
+                        },
+                        Err(message) => {
+                            error!("Save to slot {} failed: {}", pending.slot_name, message);
+                            SaveCompleteEvent {
+                                slot_name: pending.slot_name.clone(),
+                                path: None,
+                                success: false,
+                                message,
+                            }
+                        }
+                    });
+                    return false;
+                }
             }
         }
-    }
+        // A task that vanished without finishing has nothing more to say
+        !pending.updates.is_closed() || !pending.updates.is_empty()
+    });
 }
 
 /// Quick save functionality
@@ -142,3 +539,21 @@ pub fn quick_save(world: &mut World) {
         slot_name: "quicksave".to_string(),
     });
 }
+
+/// Write a save built outside a running game, such as a world generated from the command line
+///
+/// Uses the default codec and creates the destination directory if needed.
+/// Returns the size written in bytes.
+pub fn write_save_data(save_data: &SaveGameData, path: &Path) -> Result<u64, String> {
+    let serialized = super::serialize_save_data(save_data)?;
+    let compressed = super::compress_with_progress(serialized.as_bytes(), SaveCodec::default(), |_| {})?;
+
+    if let Some(directory) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(directory)
+            .map_err(|e| format!("Failed to create {}: {}", directory.display(), e))?;
+    }
+    File::create(path)
+        .and_then(|mut file| file.write_all(&compressed))
+        .map_err(|e| format!("Failed to write save file: {}", e))?;
+    Ok(compressed.len() as u64)
+}
@@ -0,0 +1,103 @@
+//! Save browser summaries
+//!
+//! Each full save gets a small sidecar summary - date, nation count, play
+//! time, active mods, and a political thumbnail - so the browser can show
+//! every save without inflating any of them.
+
+use super::{PlayTime, SaveSummary, SavedMod, THUMBNAIL_HEIGHT, THUMBNAIL_WIDTH};
+use crate::modding::ModManager;
+use crate::resources::{GameTime, MapDimensions};
+use crate::world::{ProvinceStorage, TerrainType, WorldColors};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Accumulate real time spent in game
+pub fn accumulate_play_time(time: Res<Time<Real>>, mut play_time: ResMut<PlayTime>) {
+    play_time.seconds += time.delta_secs_f64();
+}
+
+/// A freshly generated world starts with no play time
+///
+/// Loading a save re-inserts the saved play time after this runs.
+pub fn reset_play_time(mut play_time: ResMut<PlayTime>) {
+    *play_time = PlayTime::default();
+}
+
+/// Mods active right now, for recording in saves
+pub fn active_mods(mod_manager: Option<&ModManager>) -> Vec<SavedMod> {
+    mod_manager
+        .map(|manager| {
+            manager
+                .active_mod_versions()
+                .into_iter()
+                .map(|(id, version)| SavedMod { id, version })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Gather the browser summary for a save being written
+pub fn build_save_summary(
+    game_time: Option<&GameTime>,
+    nation_colors: &HashMap<Entity, Color>,
+    play_time: &PlayTime,
+    mods: Vec<SavedMod>,
+    province_storage: Option<&ProvinceStorage>,
+    map_dims: Option<&MapDimensions>,
+    world_seed: u32,
+) -> SaveSummary {
+    let thumbnail = match (province_storage, map_dims) {
+        (Some(storage), Some(dims)) => render_thumbnail(storage, dims, nation_colors, world_seed),
+        _ => Vec::new(),
+    };
+
+    SaveSummary {
+        year: game_time.map_or(0, |time| time.current_year()),
+        day_of_year: game_time.map_or(0, |time| time.day_of_year()),
+        nation_count: nation_colors.len() as u32,
+        play_time_secs: play_time.seconds,
+        mods,
+        thumbnail,
+    }
+}
+
+/// Downsample the map to a political thumbnail: nation colors over terrain
+fn render_thumbnail(
+    storage: &ProvinceStorage,
+    dims: &MapDimensions,
+    nation_colors: &HashMap<Entity, Color>,
+    world_seed: u32,
+) -> Vec<u8> {
+    let world_colors = WorldColors::new(world_seed);
+    let ocean = world_colors.terrain(TerrainType::Ocean, 0.0, Vec2::ZERO).to_srgba();
+    let mut pixels = [ocean.red, ocean.green, ocean.blue]
+        .map(|channel| (channel * 255.0) as u8)
+        .repeat((THUMBNAIL_WIDTH * THUMBNAIL_HEIGHT) as usize);
+
+    let bounds = dims.bounds;
+    let span_x = (bounds.x_max - bounds.x_min).max(f32::EPSILON);
+    let span_y = (bounds.y_max - bounds.y_min).max(f32::EPSILON);
+
+    for province in &storage.provinces {
+        let u = (province.position.x - bounds.x_min) / span_x;
+        // Image rows run top to bottom, world y runs bottom to top
+        let v = 1.0 - (province.position.y - bounds.y_min) / span_y;
+        let x = ((u * THUMBNAIL_WIDTH as f32) as u32).min(THUMBNAIL_WIDTH - 1);
+        let y = ((v * THUMBNAIL_HEIGHT as f32) as u32).min(THUMBNAIL_HEIGHT - 1);
+
+        let color = province
+            .owner_entity
+            .and_then(|owner| nation_colors.get(&owner).copied())
+            .unwrap_or_else(|| {
+                world_colors.terrain(province.terrain, province.elevation.value(), province.position)
+            })
+            .to_srgba();
+
+        let idx = ((y * THUMBNAIL_WIDTH + x) * 3) as usize;
+        pixels[idx] = (color.red * 255.0) as u8;
+        pixels[idx + 1] = (color.green * 255.0) as u8;
+        pixels[idx + 2] = (color.blue * 255.0) as u8;
+    }
+
+    pixels
+}
@@ -17,9 +17,21 @@ pub struct LoadGameEvent {
     pub save_path: PathBuf,
 }
 
+/// Event sent as a background save advances
+#[derive(Message, Debug, Clone)]
+pub struct SaveProgressEvent {
+    pub slot_name: String,
+    pub stage: super::SaveStage,
+    /// Overall progress from 0.0 to 1.0
+    pub progress: f32,
+}
+
 /// Event sent when save completes
 #[derive(Message)]
 pub struct SaveCompleteEvent {
+    pub slot_name: String,
+    /// File written, when the save succeeded
+    pub path: Option<PathBuf>,
     pub success: bool,
     pub message: String,
 }
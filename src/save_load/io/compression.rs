@@ -1,15 +1,209 @@
 //! Compression operations for save files
 //!
-//! This module handles zstd compression and decompression of save data.
+//! Saves start with a small header naming the codec that compressed them, so
+//! loading is transparent whichever codec wrote the file. Files without the
+//! header are raw zstd from before codecs were selectable.
+//!
+//! Header layout: `b"LWSV"`, header version, codec tag, codec level.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use zstd::stream::{decode_all, Decoder, Encoder};
+
+const HEADER_MAGIC: [u8; 4] = *b"LWSV";
+const HEADER_VERSION: u8 = 1;
+const HEADER_LEN: usize = 7;
+
+const CODEC_TAG_ZSTD: u8 = 1;
+const CODEC_TAG_LZ4: u8 = 2;
+
+/// Input is fed to the encoder in chunks of this size so progress can be reported
+const COMPRESSION_CHUNK_SIZE: usize = 1 << 20;
+
+/// Compression codec for save files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+pub enum SaveCodec {
+    /// zstd at a level from 1 (fast) to 19 (smallest)
+    Zstd { level: i32 },
+    /// lz4 frames - several times faster than zstd, noticeably larger
+    Lz4,
+}
+
+impl SaveCodec {
+    /// Fastest option, for frequent autosaves
+    pub const FAST: SaveCodec = SaveCodec::Lz4;
+    /// Default for manual saves
+    pub const BALANCED: SaveCodec = SaveCodec::Zstd { level: 3 };
+    /// Smallest files, for archiving and sharing
+    pub const SMALLEST: SaveCodec = SaveCodec::Zstd { level: 19 };
 
-use zstd::stream::{decode_all, encode_all};
+    pub fn name(&self) -> String {
+        match self {
+            SaveCodec::Zstd { level } => format!("zstd-{}", level),
+            SaveCodec::Lz4 => "lz4".to_string(),
+        }
+    }
+
+    fn header(&self) -> [u8; HEADER_LEN] {
+        let (tag, level) = match self {
+            SaveCodec::Zstd { level } => (CODEC_TAG_ZSTD, (*level).clamp(1, 22) as u8),
+            SaveCodec::Lz4 => (CODEC_TAG_LZ4, 0),
+        };
+        let [m0, m1, m2, m3] = HEADER_MAGIC;
+        [m0, m1, m2, m3, HEADER_VERSION, tag, level]
+    }
+}
+
+impl Default for SaveCodec {
+    fn default() -> Self {
+        Self::BALANCED
+    }
+}
+
+/// Split a save into its codec and payload; `None` for legacy headerless files
+///
+/// A header from a newer version of the game, or naming a codec this build
+/// does not know, is an error rather than a guess.
+fn read_header(data: &[u8]) -> Result<Option<(SaveCodec, &[u8])>, String> {
+    if data.len() < HEADER_LEN || data[..4] != HEADER_MAGIC {
+        return Ok(None);
+    }
+    if data[4] != HEADER_VERSION {
+        return Err(format!("Unsupported save header version {}", data[4]));
+    }
+    let codec = match data[5] {
+        CODEC_TAG_ZSTD => SaveCodec::Zstd { level: data[6] as i32 },
+        CODEC_TAG_LZ4 => SaveCodec::Lz4,
+        tag => return Err(format!("Unknown save codec tag {}", tag)),
+    };
+    Ok(Some((codec, &data[HEADER_LEN..])))
+}
+
+/// Codec recorded in a save's header (legacy saves report default zstd)
+pub fn detect_codec(data: &[u8]) -> SaveCodec {
+    header_codec(data).unwrap_or(SaveCodec::BALANCED)
+}
 
-/// Compress data using zstd
-pub fn compress_data(data: &[u8]) -> Result<Vec<u8>, String> {
-    encode_all(data, 3).map_err(|e| format!("Failed to compress data: {}", e))
+/// Codec recorded in a save's header, `None` for legacy headerless files and
+/// headers this build cannot read
+pub fn header_codec(data: &[u8]) -> Option<SaveCodec> {
+    read_header(data).ok().flatten().map(|(codec, _)| codec)
 }
 
-/// Decompress data using zstd
+/// Compress data with the given codec, prefixed by the codec header
+///
+/// `progress` is called with the fraction of input consumed so far.
+pub fn compress_with_progress(
+    data: &[u8],
+    codec: SaveCodec,
+    mut progress: impl FnMut(f32),
+) -> Result<Vec<u8>, String> {
+    let mut output = Vec::with_capacity(data.len() / 4 + HEADER_LEN);
+    output.extend_from_slice(&codec.header());
+
+    let map_err = |e: std::io::Error| format!("Failed to compress data: {}", e);
+    match codec {
+        SaveCodec::Zstd { level } => {
+            let mut encoder = Encoder::new(output, level).map_err(map_err)?;
+            write_chunks(&mut encoder, data, &mut progress).map_err(map_err)?;
+            encoder.finish().map_err(map_err)
+        }
+        SaveCodec::Lz4 => {
+            let mut encoder = lz4_flex::frame::FrameEncoder::new(output);
+            write_chunks(&mut encoder, data, &mut progress).map_err(map_err)?;
+            encoder
+                .finish()
+                .map_err(|e| format!("Failed to compress data: {}", e))
+        }
+    }
+}
+
+fn write_chunks(writer: &mut impl Write, data: &[u8], progress: &mut impl FnMut(f32)) -> std::io::Result<()> {
+    let total = data.len().max(1) as f32;
+    let mut written = 0;
+    for chunk in data.chunks(COMPRESSION_CHUNK_SIZE) {
+        writer.write_all(chunk)?;
+        written += chunk.len();
+        progress(written as f32 / total);
+    }
+    Ok(())
+}
+
+/// Decompress a save written by any codec, including legacy headerless zstd
 pub fn decompress_data(compressed: &[u8]) -> Result<Vec<u8>, String> {
-    decode_all(compressed).map_err(|e| format!("Failed to decompress data: {}", e))
+    let map_err = |e: std::io::Error| format!("Failed to decompress data: {}", e);
+    match read_header(compressed)? {
+        Some((SaveCodec::Lz4, payload)) => {
+            let mut decompressed = Vec::new();
+            lz4_flex::frame::FrameDecoder::new(payload)
+                .read_to_end(&mut decompressed)
+                .map_err(map_err)?;
+            Ok(decompressed)
+        }
+        Some((SaveCodec::Zstd { .. }, payload)) => decode_all(payload).map_err(map_err),
+        None => decode_all(compressed).map_err(map_err),
+    }
+}
+
+/// Decompress at most `limit` bytes, tolerating truncated input
+///
+/// Used to peek at save metadata without inflating the whole file.
+pub fn decompress_prefix(compressed: &[u8], limit: usize) -> Vec<u8> {
+    let mut decompressed = Vec::with_capacity(limit);
+    // Unreadable headers yield nothing for the caller to parse
+    let Ok(header) = read_header(compressed) else {
+        return decompressed;
+    };
+    // Truncated input ends in an error after yielding what it could; keep that part
+    let _ = match header {
+        Some((SaveCodec::Lz4, payload)) => lz4_flex::frame::FrameDecoder::new(payload)
+            .take(limit as u64)
+            .read_to_end(&mut decompressed),
+        Some((SaveCodec::Zstd { .. }, payload)) => match Decoder::new(payload) {
+            Ok(decoder) => decoder.take(limit as u64).read_to_end(&mut decompressed),
+            Err(e) => Err(e),
+        },
+        None => match Decoder::new(compressed) {
+            Ok(decoder) => decoder.take(limit as u64).read_to_end(&mut decompressed),
+            Err(e) => Err(e),
+        },
+    };
+    decompressed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_codec_round_trips_and_legacy_files_still_load() {
+        let data = b"(version:3,world_name:\"Round Trip\")".repeat(1000);
+
+        for codec in [SaveCodec::FAST, SaveCodec::BALANCED, SaveCodec::Zstd { level: 9 }] {
+            let compressed = compress_with_progress(&data, codec, |_| {}).unwrap_or_default();
+            assert_eq!(detect_codec(&compressed), codec);
+            assert_eq!(decompress_data(&compressed).as_deref(), Ok(&data[..]));
+            assert_eq!(decompress_prefix(&compressed, 16), data[..16].to_vec());
+        }
+
+        let legacy = zstd::stream::encode_all(&data[..], 3).unwrap_or_default();
+        assert_eq!(decompress_data(&legacy).as_deref(), Ok(&data[..]));
+    }
+
+    #[test]
+    fn unknown_header_versions_and_codecs_are_rejected() {
+        let data = b"(version:3)".repeat(100);
+        let compressed = compress_with_progress(&data, SaveCodec::FAST, |_| {}).unwrap_or_default();
+
+        let mut future_version = compressed.clone();
+        future_version[4] = HEADER_VERSION + 1;
+        assert!(decompress_data(&future_version).is_err());
+        assert_eq!(header_codec(&future_version), None);
+
+        let mut unknown_codec = compressed;
+        unknown_codec[5] = 99;
+        assert!(decompress_data(&unknown_codec).is_err());
+        assert!(decompress_prefix(&unknown_codec, 16).is_empty());
+    }
 }
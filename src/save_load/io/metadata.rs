@@ -3,38 +3,34 @@
 //! This module provides efficient extraction of save metadata without
 //! fully decompressing and parsing entire save files.
 
-use std::fs::File;
+use super::{SaveSummary, SUMMARY_EXTENSION};
+use std::fs::{self, File};
 use std::io::{BufReader, Read};
-use std::path::Path;
-use zstd::stream::Decoder;
+use std::path::{Path, PathBuf};
+
+/// Decompressed bytes inspected for metadata fields
+const METADATA_PREFIX_LEN: usize = 65536;
+
+/// Below this much output the 8KB peek is considered to have failed
+const MIN_METADATA_LEN: usize = 1024;
 
 /// Extract minimal metadata from a save file efficiently
 /// Only reads the first 8KB of the compressed file to avoid performance issues
-pub fn extract_save_metadata(path: &Path) -> Option<(String, f32, u32, String, u32)> {
+pub fn extract_save_metadata(path: &Path) -> Option<(String, f32, u32, String, u32, u32)> {
     let file = File::open(path).ok()?;
     let mut reader = BufReader::new(file);
     let mut limited_reader = (&mut reader).take(8192); // 8KB should be enough for metadata
     let mut compressed_chunk = Vec::with_capacity(8192); // Pre-allocate for expected size
     limited_reader.read_to_end(&mut compressed_chunk).ok()?;
 
-    // Try to decompress what we have
-    let decompressed = match super::decompress_data(&compressed_chunk) {
-        Ok(data) => data,
-        Err(_) => {
-            // Fallback: read the full file if partial decompression fails
-            let mut file = File::open(path).ok()?;
-            let mut compressed_data = Vec::new(); // Size unknown for full file, keep as-is
-            file.read_to_end(&mut compressed_data).ok()?;
-
-            // But only decompress the first 64KB of content to keep it fast
-            let mut decoder = Decoder::new(&compressed_data[..]).ok()?;
-            let mut partial_decompressed = Vec::with_capacity(65536); // Pre-allocate for 64KB
-            let _ = (&mut decoder)
-                .take(65536)
-                .read_to_end(&mut partial_decompressed);
-            partial_decompressed
-        }
-    };
+    // Peek at the start of the save, whichever codec wrote it
+    let mut decompressed = super::decompress_prefix(&compressed_chunk, METADATA_PREFIX_LEN);
+    if decompressed.len() < MIN_METADATA_LEN {
+        // Fallback: the first 8KB did not inflate far enough, so read the full file
+        let mut compressed_data = Vec::new(); // Size unknown for full file, keep as-is
+        File::open(path).ok()?.read_to_end(&mut compressed_data).ok()?;
+        decompressed = super::decompress_prefix(&compressed_data, METADATA_PREFIX_LEN);
+    }
 
     // Convert only what we need to string (first 16KB should have all metadata)
     let check_len = decompressed.len().min(16384);
@@ -63,7 +59,27 @@ pub fn extract_save_metadata(path: &Path) -> Option<(String, f32, u32, String, u
     // Extract world_seed
     let world_seed = extract_field_u32(&data_str, "world_seed:");
 
-    Some((world_size, game_time, version, world_name, world_seed))
+    // Extract generation_version (absent, and so 0, in older saves)
+    let generation_version = extract_field_u32(&data_str, "generation_version:");
+
+    Some((world_size, game_time, version, world_name, world_seed, generation_version))
+}
+
+/// Sidecar summary file belonging to a save
+pub fn summary_path(save_path: &Path) -> PathBuf {
+    save_path.with_extension(SUMMARY_EXTENSION)
+}
+
+/// Read the browser summary written beside a save, if there is one
+pub fn read_save_summary(save_path: &Path) -> Option<SaveSummary> {
+    let text = fs::read_to_string(summary_path(save_path)).ok()?;
+    ron::from_str(&text).ok()
+}
+
+/// Write the browser summary beside a save
+pub fn write_save_summary(save_path: &Path, summary: &SaveSummary) -> Result<(), String> {
+    let text = ron::to_string(summary).map_err(|e| format!("Failed to serialize save summary: {:?}", e))?;
+    fs::write(summary_path(save_path), text).map_err(|e| format!("Failed to write save summary: {}", e))
 }
 
 fn extract_field_f32(data: &str, field_name: &str) -> f32 {
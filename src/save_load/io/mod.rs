@@ -10,7 +10,10 @@
 //! and controlled exports. Internal modules handle their own logic.
 
 // Re-export what our children need from parent gateway (for internal use only)
-pub(self) use super::{SaveGameData, SaveGameInfo, SaveGameList, SAVE_DIRECTORY, SAVE_EXTENSION};
+pub(self) use super::{
+    SaveDelta, SaveGameData, SaveGameInfo, SaveGameList, SaveSummary, SAVE_DIRECTORY, SAVE_EXTENSION,
+    SUMMARY_EXTENSION, WORLD_EXTENSION,
+};
 
 // PRIVATE MODULES - I/O implementation
 mod compression;
@@ -24,8 +27,14 @@ mod serialization;
 pub use scanner::{ensure_save_directory, scan_save_files, scan_save_files_internal};
 
 // File operations (used by core module)
-pub(super) use compression::{compress_data, decompress_data};
-pub(super) use serialization::{deserialize_save_data, serialize_save_data};
+pub(super) use compression::{
+    compress_with_progress, decompress_data, decompress_prefix, detect_codec, header_codec,
+};
+pub use compression::SaveCodec;
+pub(super) use metadata::{read_save_summary, summary_path, write_save_summary};
+pub(super) use serialization::{
+    deserialize_save_data, deserialize_save_delta, serialize_save_data, serialize_save_delta,
+};
 
 // Utility functions
 pub use scanner::format_file_size;
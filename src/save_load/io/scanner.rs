@@ -2,9 +2,9 @@
 //!
 //! This module handles directory operations and save file discovery.
 
-use super::metadata::extract_save_metadata;
+use super::metadata::{extract_save_metadata, read_save_summary};
 use super::SaveGameList;
-use super::{SaveGameInfo, SAVE_DIRECTORY, SAVE_EXTENSION};
+use super::{SaveGameInfo, SAVE_DIRECTORY, SAVE_EXTENSION, WORLD_EXTENSION};
 use bevy::prelude::*;
 use chrono::Local;
 use rayon::prelude::*;
@@ -40,7 +40,8 @@ pub fn scan_save_files_internal(save_list: &mut SaveGameList) {
                 if let Ok(metadata) = entry.metadata() {
                     if metadata.is_file() {
                         if let Some(extension) = entry.path().extension() {
-                            return extension == SAVE_EXTENSION;
+                            // Generated worlds load like saves that haven't started yet
+                            return extension == SAVE_EXTENSION || extension == WORLD_EXTENSION;
                         }
                     }
                 }
@@ -53,9 +54,8 @@ pub fn scan_save_files_internal(save_list: &mut SaveGameList) {
             .par_iter()
             .filter_map(|entry| {
                 let metadata = entry.metadata().ok()?;
-                let file_name = entry.file_name();
-                let file_name_str = file_name.to_str()?;
-                let name = file_name_str.trim_end_matches(&format!(".{}", SAVE_EXTENSION));
+                let path = entry.path();
+                let name = path.file_stem()?.to_str()?;
 
                 // Try to parse date from filename
                 let date_created = parse_date_from_filename(name).unwrap_or_else(|| {
@@ -71,7 +71,7 @@ pub fn scan_save_files_internal(save_list: &mut SaveGameList) {
                 });
 
                 // Extract metadata from the save file (parallel I/O operation)
-                let (world_size, game_time, version, world_name, world_seed) =
+                let (world_size, game_time, version, world_name, world_seed, generation_version) =
                     extract_save_metadata(&entry.path()).unwrap_or_else(|| {
                         (
                             "Unknown".to_string(),
@@ -79,6 +79,7 @@ pub fn scan_save_files_internal(save_list: &mut SaveGameList) {
                             1,
                             "Unnamed World".to_string(),
                             0,
+                            0,
                         )
                     });
 
@@ -91,7 +92,9 @@ pub fn scan_save_files_internal(save_list: &mut SaveGameList) {
                     world_size,
                     game_time,
                     version,
+                    generation_version,
                     compressed_size: metadata.len(),
+                    summary: read_save_summary(&entry.path()),
                 })
             })
             .collect();
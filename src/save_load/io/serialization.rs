@@ -2,7 +2,7 @@
 //!
 //! This module handles RON format serialization and deserialization.
 
-use super::SaveGameData;
+use super::{SaveDelta, SaveGameData};
 
 /// Serialize save data to RON format
 pub fn serialize_save_data(data: &SaveGameData) -> Result<String, String> {
@@ -13,3 +13,117 @@ pub fn serialize_save_data(data: &SaveGameData) -> Result<String, String> {
 pub fn deserialize_save_data(data: &str) -> Result<SaveGameData, String> {
     ron::from_str(data).map_err(|e| format!("Failed to deserialize save data: {:?}", e))
 }
+
+/// Serialize a delta save to RON format
+pub fn serialize_save_delta(delta: &SaveDelta) -> Result<String, String> {
+    ron::to_string(delta).map_err(|e| format!("Failed to serialize save delta: {:?}", e))
+}
+
+/// Deserialize a delta save from RON format
+pub fn deserialize_save_delta(data: &str) -> Result<SaveDelta, String> {
+    ron::from_str(data).map_err(|e| format!("Failed to deserialize save delta: {:?}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::compression::{compress_with_progress, SaveCodec};
+    use super::*;
+    use crate::chronicle::{ChronicleCategory, ChronicleEntry, WorldChronicle};
+    use crate::math::Money;
+    use crate::nations::{Nation, NationId, NationPersonality};
+    use crate::resources::{GameTime, MapMode, WorldTension};
+    use crate::save_load::SAVE_VERSION;
+    use crate::world::{Abundance, Province, ProvinceId};
+    use bevy::prelude::*;
+    use chrono::Local;
+
+    /// The external tools SDK must read every save the game writes
+    #[test]
+    fn saves_stay_readable_by_the_sdk() {
+        assert_eq!(SAVE_VERSION, lw_sdk::SAVE_VERSION);
+
+        let mut capital = Province::new(ProvinceId::new(0), Vec2::new(1.5, -2.0));
+        capital.gold = Abundance::new(80);
+        let mut chronicle = WorldChronicle::default();
+        chronicle.record(ChronicleEntry {
+            year: 1010,
+            day_of_year: 3,
+            category: ChronicleCategory::War,
+            text: "Velm marches".to_string(),
+            nations: vec![NationId::new(3)],
+        });
+        let nation = Nation {
+            name: "Velm".to_string(),
+            adjective: "Velmish".to_string(),
+            color: Color::srgb(0.5, 0.25, 1.0),
+            capital_province: ProvinceId::new(0),
+            treasury: Money::from_int(120),
+            tax_rate: 0.2,
+            military_strength: 40.0,
+            stability: 0.8,
+            culture: crate::name_generator::Culture::Western,
+            technology_level: 2,
+            personality: NationPersonality::balanced(),
+        };
+        let data = SaveGameData {
+            version: SAVE_VERSION,
+            timestamp: Local::now(),
+            world_name: "Aldmere".to_string(),
+            world_seed: 7,
+            world_size: crate::resources::WorldSize::Small,
+            generation_version: 1,
+            map_dimensions: Default::default(),
+            game_time: GameTime::default(),
+            world_tension: WorldTension::default(),
+            map_mode: MapMode::default(),
+            map_heatmap: None,
+            provinces: vec![capital, Province::new(ProvinceId::new(1), Vec2::ZERO)],
+            nation_laws: Default::default(),
+            nations: vec![(NationId::new(3), nation)],
+            province_owners: vec![Some(NationId::new(3)), None],
+            play_time_secs: 60.0,
+            mods: Vec::new(),
+            id_allocator: Default::default(),
+            chronicle,
+            milestones: Default::default(),
+            director: Default::default(),
+            statistics: Default::default(),
+            nation_governance: Default::default(),
+            economic_focus: Default::default(),
+            ai_behavior: Default::default(),
+            scripted_events: Default::default(),
+            sea_level: Default::default(),
+            mod_settings: Default::default(),
+            province_graph: Default::default(),
+            climate: Default::default(),
+            treaties: Vec::new(),
+            cores: Vec::new(),
+            houses: Vec::new(),
+            devastation: Default::default(),
+            nation_state: Vec::new(),
+            forts: Vec::new(),
+            supply_depots: Vec::new(),
+            trade_leagues: Vec::new(),
+            refugees: Default::default(),
+        };
+
+        let text = serialize_save_data(&data).unwrap_or_default();
+        for codec in [SaveCodec::FAST, SaveCodec::BALANCED] {
+            let bytes = compress_with_progress(text.as_bytes(), codec, |_| {}).unwrap_or_default();
+            let save = match lw_sdk::read_save(&bytes) {
+                Ok(save) => save,
+                Err(e) => panic!("SDK failed to read a {} save: {}", codec.name(), e),
+            };
+
+            assert_eq!(save.world_name, "Aldmere");
+            assert_eq!(save.year, data.game_time.current_year());
+            let velm = save.nation(lw_sdk::NationId(3));
+            assert_eq!(velm.map(|nation| nation.color), Some([0.5, 0.25, 1.0]));
+            let held: Vec<_> = save.provinces_of(lw_sdk::NationId(3)).map(|province| province.id).collect();
+            assert_eq!(held, vec![lw_sdk::ProvinceId(0)]);
+            assert_eq!(save.provinces()[0].minerals.gold, 80);
+            assert_eq!(save.history().len(), 1);
+            assert_eq!(save.behavior_pack, data.ai_behavior.pack);
+        }
+    }
+}
@@ -37,31 +37,56 @@ pub use plugin::SaveLoadPlugin;
 
 // Types - data structures (selective exports)
 pub use types::{
+    LoadStage,
+    ModIssue,
+    SaveDelta,
     SaveGameData,
     SaveGameInfo,
+    SaveStage,
+    SaveSummary,
+    SavedMod,
     AUTO_SAVE_INTERVAL, // Needed by resources module
+    AUTOSAVE_SLOT,
+    DELTAS_PER_SNAPSHOT,
+    DELTA_EXTENSION,
     SAVE_DIRECTORY,
     SAVE_EXTENSION,
     SAVE_VERSION, // Needed by core module
+    STABLE_OWNERSHIP_VERSION,
+    SUMMARY_EXTENSION,
+    THUMBNAIL_HEIGHT,
+    THUMBNAIL_WIDTH,
+    WORLD_EXTENSION,
 };
 
 // Events - all events are public for external triggering
 pub use events::{
     CloseSaveDialogEvent, DeleteSaveEvent, LoadCompleteEvent, LoadGameEvent, OpenSaveDialogEvent,
-    SaveCompleteEvent, SaveGameEvent,
+    SaveCompleteEvent, SaveGameEvent, SaveProgressEvent,
 };
 
+// Compression codecs - selectable per save kind
+pub use io::SaveCodec;
+
 // Resources - selective exports for external access
 pub use resources::{
     AutoSaveTimer,   // Needed for plugin
     PendingLoadData, // Needed for load system
+    PendingModCheck,
+    PlayTime,
     SaveBrowserState,
+    SaveChangeTracker,
+    SaveCompressionSettings,
     SaveDialogState,
     SaveGameList,
+    SaveSortOrder,
+    SaveTasks,
 };
+pub(crate) use resources::{LoadTask, LoadTaskUpdate, PendingSave, SaveTaskUpdate};
 
 // Public utility functions
-pub use io::scan_save_files_internal;
+pub use core::{inspect_save_file, load_save_data, write_save_data, SaveCheck, SaveInspection};
+pub use io::{format_file_size, scan_save_files_internal};
 
 // Note: We do NOT export:
 // - UI components (internal implementation)
@@ -4,9 +4,9 @@
 //! 79 lines of manual boilerplate → 35 lines of declarative paradise!
 
 use super::{
-    AutoSaveTimer, CloseSaveDialogEvent, DeleteSaveEvent, LoadCompleteEvent, LoadGameEvent,
-    OpenSaveDialogEvent, SaveBrowserState, SaveCompleteEvent, SaveDialogState, SaveGameEvent,
-    SaveGameList,
+    AutoSaveTimer, PendingModCheck, PlayTime, CloseSaveDialogEvent, DeleteSaveEvent, LoadCompleteEvent, LoadGameEvent,
+    OpenSaveDialogEvent, SaveBrowserState, SaveCompleteEvent, SaveCompressionSettings, SaveDialogState,
+    SaveChangeTracker, SaveGameEvent, SaveGameList, SaveProgressEvent, SaveTasks,
 };
 use crate::states::GameState;
 use bevy::prelude::*;
@@ -14,11 +14,21 @@ use bevy_plugin_builder::define_plugin;
 
 // Save/Load plugin using REVOLUTIONARY declarative automation
 define_plugin!(SaveLoadPlugin {
-    resources: [SaveGameList, SaveBrowserState, SaveDialogState, AutoSaveTimer],
+    resources: [
+        SaveGameList,
+        SaveBrowserState,
+        SaveDialogState,
+        AutoSaveTimer,
+        SaveCompressionSettings,
+        SaveTasks,
+        SaveChangeTracker,
+        PlayTime
+    ],
 
     messages: [
         SaveGameEvent,
         LoadGameEvent,
+        SaveProgressEvent,
         SaveCompleteEvent,
         LoadCompleteEvent,
         DeleteSaveEvent,
@@ -36,28 +46,47 @@ define_plugin!(SaveLoadPlugin {
         crate::resources::GameTime,
         crate::resources::WorldTension,
         crate::resources::MapMode,
-        crate::world::ProvinceStorage
+        crate::world::ProvinceStorage,
+        SaveCompressionSettings,
+        super::SaveCodec,
+        PlayTime
     ],
 
     startup: [super::io::ensure_save_directory],
 
     update: [
+        super::core::track_save_changes
+            .before(super::core::handle_save_game)
+            .run_if(in_state(GameState::InGame)),
+        super::core::apply_compression_settings.before(super::core::handle_save_game),
         super::core::handle_save_game,
+        super::core::poll_save_tasks,
+        super::ui::update_save_progress_indicator.after(super::core::poll_save_tasks),
         super::core::handle_load_game,
+        super::core::poll_load_task.run_if(resource_exists::<super::LoadTask>),
+        super::core::restore_save_data.run_if(resource_exists::<super::core::SaveRestore>),
+        super::core::cancel_save_loading.run_if(in_state(GameState::LoadingWorld)),
         super::core::handle_auto_save.run_if(in_state(GameState::InGame)),
+        super::core::accumulate_play_time.run_if(in_state(GameState::InGame)),
         super::handlers::handle_save_load_shortcuts.run_if(in_state(GameState::InGame)),
         super::handlers::handle_spawn_save_browser_event,
         super::ui::update_save_browser,
         super::ui::handle_save_browser_interactions,
+        super::ui::handle_save_browser_controls,
         super::ui::handle_delete_button_click,
         super::ui::handle_delete_confirmation,
         super::ui::handle_open_save_dialog,
         super::ui::handle_close_save_dialog,
-        super::ui::handle_save_dialog_interactions
+        super::ui::handle_save_dialog_interactions,
+        super::ui::spawn_mod_check_dialog.run_if(resource_added::<PendingModCheck>),
+        super::ui::handle_mod_check_dialog.run_if(resource_exists::<PendingModCheck>)
     ],
 
     on_enter: {
-        GameState::LoadingWorld => [super::core::check_for_pending_load]
+        GameState::LoadingWorld => [
+            super::core::reset_save_chain,
+            super::core::reset_play_time
+        ]
     },
 
     on_exit: {
@@ -2,7 +2,12 @@
 //!
 //! This module defines resources used for managing save/load state.
 
-use super::{SaveGameData, SaveGameInfo, AUTO_SAVE_INTERVAL};
+use super::{
+    LoadStage, ModIssue, SaveCodec, SaveGameData, SaveGameInfo, SaveStage, AUTO_SAVE_INTERVAL, DELTAS_PER_SNAPSHOT,
+};
+use crate::nations::NationId;
+use std::collections::{BTreeSet, HashSet};
+use std::path::PathBuf;
 use bevy::prelude::*;
 
 /// Tracks available save files
@@ -27,11 +32,160 @@ impl Default for AutoSaveTimer {
     }
 }
 
+/// Codecs used for each kind of save
+///
+/// Autosaves happen often and mid-game, so they favor speed; manual saves
+/// are kept and shared, so they favor size. Both follow the choices in the
+/// settings menu, which are saved with the rest of the game settings.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct SaveCompressionSettings {
+    pub autosave_codec: SaveCodec,
+    pub manual_codec: SaveCodec,
+}
+
+impl Default for SaveCompressionSettings {
+    fn default() -> Self {
+        Self {
+            autosave_codec: SaveCodec::FAST,
+            manual_codec: SaveCodec::BALANCED,
+        }
+    }
+}
+
+/// What changed since the last autosave, and where the current chain stands
+#[derive(Resource, Debug)]
+pub struct SaveChangeTracker {
+    /// Province indices touched since the last autosave
+    pub provinces: BTreeSet<u32>,
+    /// Nations whose state or laws changed since the last autosave
+    pub nations: HashSet<NationId>,
+    /// Nations alive at the last autosave, so the next delta can list those that died
+    pub saved_nations: HashSet<NationId>,
+    /// File name of the full autosave the current chain builds on
+    pub base_save: Option<String>,
+    /// Deltas written on top of `base_save` so far
+    pub sequence: u32,
+    /// Deltas between full snapshots; 0 makes every autosave a full one
+    pub deltas_per_snapshot: u32,
+}
+
+impl SaveChangeTracker {
+    /// Start a new chain from a full autosave
+    pub fn begin_chain(&mut self, base_save: String) {
+        self.base_save = Some(base_save);
+        self.sequence = 0;
+        self.clear_changes();
+    }
+
+    /// Whether the next autosave should be a delta rather than a full snapshot
+    pub fn wants_delta(&self) -> bool {
+        self.base_save.is_some() && self.sequence < self.deltas_per_snapshot
+    }
+
+    pub fn clear_changes(&mut self) {
+        self.provinces.clear();
+        self.nations.clear();
+    }
+}
+
+impl Default for SaveChangeTracker {
+    fn default() -> Self {
+        Self {
+            provinces: BTreeSet::new(),
+            nations: HashSet::new(),
+            saved_nations: HashSet::new(),
+            base_save: None,
+            sequence: 0,
+            deltas_per_snapshot: DELTAS_PER_SNAPSHOT,
+        }
+    }
+}
+
+/// Update sent from a background save task
+pub enum SaveTaskUpdate {
+    Progress { stage: SaveStage, progress: f32 },
+    /// Final path and compressed size, or the failure message
+    Finished(Result<(String, u64), String>),
+}
+
+/// A save being serialized, compressed, and written off the main thread
+pub struct PendingSave {
+    pub slot_name: String,
+    pub updates: async_channel::Receiver<SaveTaskUpdate>,
+}
+
+/// Saves currently running in the background
+#[derive(Resource, Default)]
+pub struct SaveTasks {
+    pub pending: Vec<PendingSave>,
+}
+
+/// Update sent from a background load task
+pub enum LoadTaskUpdate {
+    Stage(LoadStage),
+    /// The save as read, or the failure message
+    Finished(Result<Box<SaveGameData>, String>),
+}
+
+/// A save being read, decompressed, and deserialized off the main thread
+///
+/// Removing this resource cancels the load: the task stops at its next stage.
+#[derive(Resource)]
+pub struct LoadTask {
+    pub save_path: PathBuf,
+    pub updates: async_channel::Receiver<LoadTaskUpdate>,
+}
+
+/// Real time spent in game on the current world
+#[derive(Resource, Debug, Default, Clone, Copy, Reflect)]
+#[reflect(Resource)]
+pub struct PlayTime {
+    pub seconds: f64,
+}
+
+/// Ordering of the save browser list
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SaveSortOrder {
+    #[default]
+    Newest,
+    Oldest,
+    WorldName,
+    InGameDate,
+    PlayTime,
+}
+
+impl SaveSortOrder {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SaveSortOrder::Newest => "Newest",
+            SaveSortOrder::Oldest => "Oldest",
+            SaveSortOrder::WorldName => "World Name",
+            SaveSortOrder::InGameDate => "In-Game Date",
+            SaveSortOrder::PlayTime => "Play Time",
+        }
+    }
+
+    /// The order after this one when cycling through them
+    pub fn next(&self) -> Self {
+        match self {
+            SaveSortOrder::Newest => SaveSortOrder::Oldest,
+            SaveSortOrder::Oldest => SaveSortOrder::WorldName,
+            SaveSortOrder::WorldName => SaveSortOrder::InGameDate,
+            SaveSortOrder::InGameDate => SaveSortOrder::PlayTime,
+            SaveSortOrder::PlayTime => SaveSortOrder::Newest,
+        }
+    }
+}
+
 /// Save browser UI state
 #[derive(Resource, Default)]
 pub struct SaveBrowserState {
     pub is_open: bool,
     pub selected_save: Option<usize>,
+    pub sort_order: SaveSortOrder,
+    /// Case-insensitive text matched against save and world names
+    pub filter: String,
 }
 
 /// Resource to track save dialog state
@@ -42,6 +196,15 @@ pub struct SaveDialogState {
     pub search_filter: String,
 }
 
-/// Pending load data to be applied when LoadingWorld state is entered
+/// Save data being restored into the world during the LoadingWorld state
 #[derive(Resource)]
 pub struct PendingLoadData(pub SaveGameData);
+
+/// A load held back until the player decides about mismatched mods
+#[derive(Resource)]
+pub struct PendingModCheck {
+    /// Taken when the player chooses to load anyway
+    pub save_data: Option<SaveGameData>,
+    pub save_path: PathBuf,
+    pub issues: Vec<ModIssue>,
+}
@@ -15,12 +15,95 @@ pub const SAVE_DIRECTORY: &str = "saves";
 /// Save file extension (compressed RON)
 pub const SAVE_EXTENSION: &str = "lws"; // Living Worlds Save
 
+/// Generated world file extension (a save written before the first simulated day)
+pub const WORLD_EXTENSION: &str = "lwworld"; // Living Worlds World
+
+/// Delta save file extension (compressed RON, applied on top of a full save)
+pub const DELTA_EXTENSION: &str = "lwd"; // Living Worlds Delta
+
+/// Save summary file extension (plain RON written beside each full save)
+pub const SUMMARY_EXTENSION: &str = "lwm"; // Living Worlds Metadata
+
+/// Save browser thumbnail size in pixels
+pub const THUMBNAIL_WIDTH: u32 = 96;
+pub const THUMBNAIL_HEIGHT: u32 = 48;
+
+/// Autosaves written as deltas between two full snapshots
+pub const DELTAS_PER_SNAPSHOT: u32 = 5;
+
 /// Current save version for compatibility checking
-pub const SAVE_VERSION: u32 = 1;
+///
+/// The external tools SDK (`lw_sdk`) reads saves too; bump its `SAVE_VERSION` alongside this.
+pub const SAVE_VERSION: u32 = 3;
+
+/// First save version that records ownership by [`NationId`](crate::nations::NationId)
+///
+/// Earlier saves serialized the owner's `Entity`, which is meaningless in a new session.
+pub const STABLE_OWNERSHIP_VERSION: u32 = 2;
 
 /// Auto-save interval in seconds
 pub const AUTO_SAVE_INTERVAL: f32 = 300.0; // 5 minutes
 
+/// Slot name used by the auto-save timer
+pub const AUTOSAVE_SLOT: &str = "autosave";
+
+/// Stage of a save running in the background
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveStage {
+    Serializing,
+    Compressing,
+    Writing,
+}
+
+impl SaveStage {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SaveStage::Serializing => "Saving world state...",
+            SaveStage::Compressing => "Compressing...",
+            SaveStage::Writing => "Writing save file...",
+        }
+    }
+}
+
+/// Stage of a save being loaded
+///
+/// Reading, decompressing, and deserializing run in the background; the
+/// world is then rebuilt a frame budget at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadStage {
+    Reading,
+    Decompressing,
+    Deserializing,
+    Restoring,
+    SpawningNations,
+    Finishing,
+}
+
+impl LoadStage {
+    /// Loading screen progress when the stage begins
+    pub fn progress(&self) -> f32 {
+        match self {
+            LoadStage::Reading => 0.0,
+            LoadStage::Decompressing => 0.1,
+            LoadStage::Deserializing => 0.25,
+            LoadStage::Restoring => 0.45,
+            LoadStage::SpawningNations => 0.6,
+            LoadStage::Finishing => 0.9,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LoadStage::Reading => "Reading save file...",
+            LoadStage::Decompressing => "Decompressing...",
+            LoadStage::Deserializing => "Reading world state...",
+            LoadStage::Restoring => "Rebuilding world mesh...",
+            LoadStage::SpawningNations => "Restoring nations...",
+            LoadStage::Finishing => "Restoring provinces...",
+        }
+    }
+}
+
 /// Information about a save file
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SaveGameInfo {
@@ -32,7 +115,32 @@ pub struct SaveGameInfo {
     pub world_size: String,
     pub game_time: f32,
     pub version: u32,
+    pub generation_version: u32,
     pub compressed_size: u64,
+    /// Browser summary, absent for saves written before summaries existed
+    pub summary: Option<SaveSummary>,
+}
+
+/// A mod that was active when a save was written
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedMod {
+    pub id: String,
+    pub version: String,
+}
+
+/// Everything the save browser shows, kept small enough to read for every save
+///
+/// Written as a sidecar file so the browser never inflates the save itself.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SaveSummary {
+    pub year: u32,
+    pub day_of_year: u32,
+    pub nation_count: u32,
+    /// Real seconds spent in game across every session of this world
+    pub play_time_secs: f64,
+    pub mods: Vec<SavedMod>,
+    /// RGB8 pixels, row-major, `THUMBNAIL_WIDTH` by `THUMBNAIL_HEIGHT` (empty if no world)
+    pub thumbnail: Vec<u8>,
 }
 
 /// Complete game state for serialization
@@ -43,11 +151,192 @@ pub struct SaveGameData {
     pub world_name: String,
     pub world_seed: u32,
     pub world_size: WorldSize,
+    /// World generator revision that produced this world (0 if unknown)
+    #[serde(default)]
+    pub generation_version: u32,
     pub map_dimensions: MapDimensions,
     pub game_time: GameTime,
     pub world_tension: WorldTension,
     pub map_mode: MapMode,
+    /// Name of the heatmap on show, which `map_mode` records as the political map
+    #[serde(default)]
+    pub map_heatmap: Option<String>,
     pub provinces: Vec<crate::world::Province>,
     /// Nation laws data - entity IDs will be remapped on load
     pub nation_laws: HashMap<crate::nations::NationId, NationLaws>,
+    /// Nation state keyed by stable id (empty before version 2)
+    #[serde(default)]
+    pub nations: Vec<(crate::nations::NationId, crate::nations::Nation)>,
+    /// Owner of each province by stable id, parallel to `provinces` (empty before version 2)
+    #[serde(default)]
+    pub province_owners: Vec<Option<crate::nations::NationId>>,
+    /// Real seconds spent in game when this save was written
+    #[serde(default)]
+    pub play_time_secs: f64,
+    /// Mods active when this save was written, in load order
+    #[serde(default)]
+    pub mods: Vec<SavedMod>,
+    /// ID allocation state, so IDs minted after loading never collide
+    #[serde(default)]
+    pub id_allocator: crate::ids::IdAllocator,
+    #[serde(default)]
+    pub chronicle: crate::chronicle::WorldChronicle,
+    #[serde(default)]
+    pub milestones: crate::milestones::WorldMilestones,
+    #[serde(default)]
+    pub director: crate::simulation::WorldDirector,
+    /// Peaks, reigns, and wars the end-of-world report is built from
+    #[serde(default)]
+    pub statistics: crate::world_report::WorldStatistics,
+    /// Government of each nation (absent in older saves, which keep the spawn default)
+    #[serde(default)]
+    pub nation_governance: HashMap<crate::nations::NationId, crate::nations::Governance>,
+    #[serde(default)]
+    pub economic_focus: HashMap<crate::nations::NationId, crate::nations::EconomicFocus>,
+    /// AI behavior pack the world runs under (historical in older saves)
+    #[serde(default)]
+    pub ai_behavior: crate::ai::AiBehavior,
+    /// Scripted event history, queued events, and running modifiers
+    #[serde(default)]
+    pub scripted_events: crate::nations::ScriptedEventState,
+    /// How far the sea has moved since generation (a stable sea in older saves)
+    #[serde(default)]
+    pub sea_level: crate::world::SeaLevel,
+    /// Values of mod settings kept per save
+    #[serde(default)]
+    pub mod_settings: crate::modding::WorldModSettings,
+    /// Province adjacency, so loading skips rebuilding it (empty in older saves, which rebuild)
+    #[serde(default)]
+    pub province_graph: crate::world::ProvinceGraph,
+    /// Climate of every province at generation, which crop yields are reckoned from (empty in older saves)
+    #[serde(default)]
+    pub climate: crate::world::ClimateStorage,
+    /// Treaties in force, with their parties by stable id (none in older saves)
+    #[serde(default)]
+    pub treaties: Vec<crate::nations::SavedTreaty>,
+    /// Core state of every province, with nations by stable id (empty in older saves, which start cores afresh)
+    #[serde(default)]
+    pub cores: Vec<crate::nations::SavedProvinceCore>,
+    /// Ruling houses, with the nation each rules by stable id (none in older saves)
+    #[serde(default)]
+    pub houses: Vec<(crate::nations::NationId, crate::nations::House)>,
+    /// War devastation by province index (none in older saves)
+    #[serde(default)]
+    pub devastation: crate::nations::Devastation,
+    /// Simulation components of every nation by stable id (none before version 3)
+    #[serde(default)]
+    pub nation_state: Vec<crate::nations::SavedNationState>,
+    /// Forts by province index, with builders by stable id (none before version 3)
+    #[serde(default)]
+    pub forts: Vec<crate::nations::SavedFort>,
+    /// Supply depots by province index, with owners by stable id (none before version 3)
+    #[serde(default)]
+    pub supply_depots: Vec<crate::nations::SavedSupplyDepot>,
+    /// Merchant leagues, with nations by stable id (none before version 3)
+    #[serde(default)]
+    pub trade_leagues: Vec<crate::nations::SavedTradeLeague>,
+    /// Refugee flows by province index (none before version 3)
+    #[serde(default)]
+    pub refugees: crate::nations::SavedRefugees,
+}
+
+/// Difference between a save's mods and the mods active now
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ModIssue {
+    /// The save used a mod that is not active
+    Missing { id: String, version: String },
+    /// The mod is active at a different version than the save used
+    VersionMismatch { id: String, saved: String, active: String },
+    /// A mod is active that the save never used
+    Unexpected { id: String, version: String },
+}
+
+impl std::fmt::Display for ModIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModIssue::Missing { id, version } => write!(f, "Missing: {} {}", id, version),
+            ModIssue::VersionMismatch { id, saved, active } => {
+                write!(f, "Version mismatch: {} (saved with {}, active {})", id, saved, active)
+            }
+            ModIssue::Unexpected { id, version } => write!(f, "Not in save: {} {}", id, version),
+        }
+    }
+}
+
+/// Changes since the previous save in an autosave chain
+///
+/// A chain is one full autosave followed by deltas numbered from 1; loading
+/// the full save applies every delta of its chain in order.
+#[derive(Serialize, Deserialize)]
+pub struct SaveDelta {
+    pub version: u32,
+    pub timestamp: DateTime<Local>,
+    /// File name of the full save this chain starts from
+    pub base_save: String,
+    /// Position in the chain, starting at 1
+    pub sequence: u32,
+    pub game_time: GameTime,
+    pub world_tension: WorldTension,
+    pub map_mode: MapMode,
+    /// Name of the heatmap on show, which `map_mode` records as the political map
+    #[serde(default)]
+    pub map_heatmap: Option<String>,
+    /// Provinces that changed, with their new owners by stable id
+    pub provinces: Vec<(crate::world::Province, Option<crate::nations::NationId>)>,
+    /// Nations that changed or appeared
+    pub nations: Vec<(crate::nations::NationId, crate::nations::Nation)>,
+    pub nation_laws: Vec<(crate::nations::NationId, NationLaws)>,
+    /// Nations that died since the previous save of the chain
+    #[serde(default)]
+    pub removed_nations: Vec<crate::nations::NationId>,
+    /// ID allocation state, carried whole (absent in older deltas, which keep the base save's)
+    #[serde(default)]
+    pub id_allocator: Option<crate::ids::IdAllocator>,
+    /// Chronicle, milestones, director state, and statistics are small, so each delta carries them whole
+    #[serde(default)]
+    pub chronicle: crate::chronicle::WorldChronicle,
+    #[serde(default)]
+    pub milestones: crate::milestones::WorldMilestones,
+    #[serde(default)]
+    pub director: crate::simulation::WorldDirector,
+    #[serde(default)]
+    pub statistics: crate::world_report::WorldStatistics,
+    /// Governments and economic focus for every nation, carried whole like the chronicle
+    #[serde(default)]
+    pub nation_governance: HashMap<crate::nations::NationId, crate::nations::Governance>,
+    #[serde(default)]
+    pub economic_focus: HashMap<crate::nations::NationId, crate::nations::EconomicFocus>,
+    /// Scripted event state, carried whole like the chronicle
+    #[serde(default)]
+    pub scripted_events: crate::nations::ScriptedEventState,
+    /// Sea level, carried whole like the chronicle; reshaped provinces travel with `provinces`
+    #[serde(default)]
+    pub sea_level: crate::world::SeaLevel,
+    /// Per-save mod setting values, carried whole like the chronicle
+    #[serde(default)]
+    pub mod_settings: crate::modding::WorldModSettings,
+    /// Treaties in force, carried whole (absent in older deltas, which keep the base save's)
+    #[serde(default)]
+    pub treaties: Option<Vec<crate::nations::SavedTreaty>>,
+    /// Province core state, carried whole (absent in older deltas, which keep the base save's)
+    #[serde(default)]
+    pub cores: Option<Vec<crate::nations::SavedProvinceCore>>,
+    /// Ruling houses, carried whole (absent in older deltas, which keep the base save's)
+    #[serde(default)]
+    pub houses: Option<Vec<(crate::nations::NationId, crate::nations::House)>>,
+    /// War devastation, carried whole (absent in older deltas, which keep the base save's)
+    #[serde(default)]
+    pub devastation: Option<crate::nations::Devastation>,
+    /// Nation components, forts, depots, leagues, and refugees, each carried whole
+    /// (absent in older deltas, which keep the base save's)
+    #[serde(default)]
+    pub nation_state: Option<Vec<crate::nations::SavedNationState>>,
+    #[serde(default)]
+    pub forts: Option<Vec<crate::nations::SavedFort>>,
+    #[serde(default)]
+    pub supply_depots: Option<Vec<crate::nations::SavedSupplyDepot>>,
+    #[serde(default)]
+    pub trade_leagues: Option<Vec<crate::nations::SavedTradeLeague>>,
+    #[serde(default)]
+    pub refugees: Option<crate::nations::SavedRefugees>,
 }
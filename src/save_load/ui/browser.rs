@@ -3,10 +3,23 @@
 //! This module creates the save browser UI using our standard UI builders.
 
 use super::components::*;
-use super::{LoadGameEvent, SaveBrowserState, SaveGameList};
+use super::{
+    LoadGameEvent, SaveBrowserState, SaveGameInfo, SaveGameList, SaveSortOrder, SAVE_VERSION, THUMBNAIL_HEIGHT,
+    THUMBNAIL_WIDTH,
+};
 use crate::menus::SpawnSaveBrowserEvent;
-use crate::ui::{colors, helpers, ButtonBuilder, ButtonSize, ButtonStyle, PanelBuilder, PanelStyle};
+use crate::modding::ModManager;
+use crate::ui::{
+    colors, helpers, ButtonBuilder, ButtonSize, ButtonStyle, PanelBuilder, PanelStyle, TextBuffer,
+    TextInputBuilder,
+};
+use crate::world::GENERATION_VERSION;
+use bevy::asset::RenderAssetUsages;
 use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+/// On-screen scale of save thumbnails
+const THUMBNAIL_SCALE: f32 = 2.0;
 
 /// System to handle the SpawnSaveBrowserEvent
 pub fn spawn_save_browser(
@@ -14,10 +27,13 @@ pub fn spawn_save_browser(
     mut commands: Commands,
     mut save_list: ResMut<SaveGameList>,
     mut browser_state: ResMut<SaveBrowserState>,
+    mut images: ResMut<Assets<Image>>,
+    mod_manager: Option<Res<ModManager>>,
 ) {
     for _ in messages.read() {
-        // Mark browser as open
+        // Mark browser as open with a fresh filter; the sort order persists
         browser_state.is_open = true;
+        browser_state.filter.clear();
 
         // Scan for saves
         super::scan_save_files_internal(&mut save_list);
@@ -62,6 +78,39 @@ pub fn spawn_save_browser(
                                 ..default()
                             },
                         ));
+                        // Filter and sort controls
+                        PanelBuilder::new()
+                            .style(PanelStyle::Transparent)
+                            .flex_direction(FlexDirection::Row)
+                            .align_items(AlignItems::Center)
+                            .justify_content(JustifyContent::SpaceBetween)
+                            .width(Val::Percent(100.0))
+                            .build_with_children(parent, |controls| {
+                                TextInputBuilder::new()
+                                    .with_placeholder("Filter by save or world name...")
+                                    .with_width(Val::Px(480.0))
+                                    .with_font_size(16.0)
+                                    .retain_on_submit(true)
+                                    .with_marker(SaveFilterInput)
+                                    .build(controls);
+
+                                controls.spawn((
+                                    Text::new(format!("Sorted by {}", browser_state.sort_order.label())),
+                                    TextFont {
+                                        font_size: 14.0,
+                                        ..default()
+                                    },
+                                    TextColor(colors::TEXT_SECONDARY),
+                                    SortOrderLabel,
+                                ));
+
+                                ButtonBuilder::new("Sort")
+                                    .style(ButtonStyle::Secondary)
+                                    .size(ButtonSize::Small)
+                                    .with_marker(SortSavesButton)
+                                    .build(controls);
+                            });
+
                         // Scrollable save list
                         parent
                             .spawn((
@@ -70,16 +119,20 @@ pub fn spawn_save_browser(
                                     flex_grow: 1.0,
                                     overflow: Overflow::scroll_y(),
                                     padding: UiRect::all(Val::Px(10.0)),
-                                    margin: UiRect::bottom(Val::Px(20.0)),
+                                    margin: UiRect::vertical(Val::Px(10.0)),
                                     ..default()
                                 },
                                 BackgroundColor(colors::BACKGROUND_DARK),
+                                SaveListContainer,
                             ))
                             .with_children(|list| {
-                                // Add save slots
-                                for (index, save_info) in save_list.saves.iter().enumerate() {
-                                    spawn_save_slot(list, index, save_info.clone());
-                                }
+                                spawn_save_slots(
+                                    list,
+                                    &save_list.saves,
+                                    &browser_state,
+                                    &mut images,
+                                    mod_manager.as_deref(),
+                                );
                             });
 
                         // Bottom buttons
@@ -106,11 +159,149 @@ pub fn spawn_save_browser(
     }
 }
 
+/// Saves passing the filter, in the chosen order, as indices into `saves`
+fn visible_save_indices(saves: &[SaveGameInfo], sort_order: SaveSortOrder, filter: &str) -> Vec<usize> {
+    let filter = filter.trim().to_lowercase();
+    let mut indices: Vec<usize> = saves
+        .iter()
+        .enumerate()
+        .filter(|(_, save)| {
+            filter.is_empty()
+                || save.name.to_lowercase().contains(&filter)
+                || save.world_name.to_lowercase().contains(&filter)
+        })
+        .map(|(index, _)| index)
+        .collect();
+
+    let in_game_date = |save: &SaveGameInfo| {
+        save.summary
+            .as_ref()
+            .map_or((0, 0), |summary| (summary.year, summary.day_of_year))
+    };
+    let play_time = |save: &SaveGameInfo| save.summary.as_ref().map_or(0.0, |summary| summary.play_time_secs);
+
+    indices.sort_by(|&a, &b| {
+        let (a, b) = (&saves[a], &saves[b]);
+        match sort_order {
+            SaveSortOrder::Newest => b.date_created.cmp(&a.date_created),
+            SaveSortOrder::Oldest => a.date_created.cmp(&b.date_created),
+            SaveSortOrder::WorldName => a.world_name.to_lowercase().cmp(&b.world_name.to_lowercase()),
+            SaveSortOrder::InGameDate => in_game_date(b).cmp(&in_game_date(a)),
+            SaveSortOrder::PlayTime => play_time(b).total_cmp(&play_time(a)),
+        }
+    });
+    indices
+}
+
+/// Reasons a save may not load as it was played
+fn save_warnings(save_info: &SaveGameInfo, mod_manager: Option<&ModManager>) -> Vec<String> {
+    let mut warnings = Vec::new();
+    if save_info.version < SAVE_VERSION {
+        warnings.push(format!("Older save format (v{})", save_info.version));
+    }
+    if save_info.generation_version == 0 {
+        warnings.push("World generator version unknown".to_string());
+    } else if save_info.generation_version != GENERATION_VERSION {
+        warnings.push(format!("Generated by world generator v{}", save_info.generation_version));
+    }
+
+    let saved_mods = save_info.summary.iter().flat_map(|summary| &summary.mods);
+    for saved_mod in saved_mods {
+        match mod_manager.and_then(|manager| manager.installed_version(&saved_mod.id)) {
+            None => warnings.push(format!("Missing mod: {}", saved_mod.id)),
+            Some(version) if version != saved_mod.version => warnings.push(format!(
+                "Mod {} is v{}, save used v{}",
+                saved_mod.id, version, saved_mod.version
+            )),
+            Some(_) => {}
+        }
+    }
+    warnings
+}
+
+/// Upload a summary thumbnail as a UI image
+fn thumbnail_image(save_info: &SaveGameInfo, images: &mut Assets<Image>) -> Option<Handle<Image>> {
+    let pixels = &save_info.summary.as_ref()?.thumbnail;
+    if pixels.len() != (THUMBNAIL_WIDTH * THUMBNAIL_HEIGHT * 3) as usize {
+        return None;
+    }
+
+    let rgba = pixels
+        .chunks_exact(3)
+        .flat_map(|rgb| [rgb[0], rgb[1], rgb[2], u8::MAX])
+        .collect();
+    let image = Image::new(
+        Extent3d {
+            width: THUMBNAIL_WIDTH,
+            height: THUMBNAIL_HEIGHT,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        rgba,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    Some(images.add(image))
+}
+
+fn format_play_time(seconds: f64) -> String {
+    let minutes = (seconds / 60.0) as u64;
+    format!("{}h {:02}m", minutes / 60, minutes % 60)
+}
+
+fn spawn_save_slots(
+    list: &mut ChildSpawnerCommands,
+    saves: &[SaveGameInfo],
+    browser_state: &SaveBrowserState,
+    images: &mut Assets<Image>,
+    mod_manager: Option<&ModManager>,
+) {
+    let visible = visible_save_indices(saves, browser_state.sort_order, &browser_state.filter);
+    if visible.is_empty() {
+        list.spawn((
+            Text::new(if saves.is_empty() {
+                "No saved games"
+            } else {
+                "No saves match the filter"
+            }),
+            TextFont {
+                font_size: 16.0,
+                ..default()
+            },
+            TextColor(colors::TEXT_TERTIARY),
+        ));
+    }
+
+    for index in visible {
+        let save_info = &saves[index];
+        let thumbnail = thumbnail_image(save_info, images);
+        let warnings = save_warnings(save_info, mod_manager);
+        spawn_save_slot(list, index, save_info.clone(), thumbnail, warnings);
+    }
+}
+
 fn spawn_save_slot(
     parent: &mut ChildSpawnerCommands,
     index: usize,
-    save_info: super::super::types::SaveGameInfo,
+    save_info: SaveGameInfo,
+    thumbnail: Option<Handle<Image>>,
+    warnings: Vec<String>,
 ) {
+    let text_line = |text: String, font_size: f32, color: Color, margin: f32| {
+        (
+            Text::new(text),
+            TextFont {
+                font_size,
+                ..default()
+            },
+            TextColor(color),
+            Node {
+                margin: UiRect::top(Val::Px(margin)),
+                ..default()
+            },
+        )
+    };
+
     parent
         .spawn((
             Button,
@@ -136,6 +327,20 @@ fn spawn_save_slot(
                 .justify_content(JustifyContent::SpaceBetween)
                 .align_items(AlignItems::Start)
                 .build_with_children(slot, |row| {
+                    // Far left: political thumbnail of the world
+                    if let Some(image) = thumbnail {
+                        row.spawn((
+                            ImageNode::new(image),
+                            Node {
+                                width: Val::Px(THUMBNAIL_WIDTH as f32 * THUMBNAIL_SCALE),
+                                height: Val::Px(THUMBNAIL_HEIGHT as f32 * THUMBNAIL_SCALE),
+                                margin: UiRect::right(Val::Px(12.0)),
+                                flex_shrink: 0.0,
+                                ..default()
+                            },
+                        ));
+                    }
+
                     // Left side: Save info
                     PanelBuilder::new()
                         .style(PanelStyle::Transparent)
@@ -143,67 +348,143 @@ fn spawn_save_slot(
                         .flex_grow(1.0)
                         .build_with_children(row, |info| {
                             // Save name
-                            info.spawn((
-                                Text::new(&save_info.name),
-                                TextFont {
-                                    font_size: 20.0,
-                                    ..default()
-                                },
-                                TextColor(colors::TEXT_PRIMARY),
-                            ));
+                            info.spawn(text_line(save_info.name.clone(), 20.0, colors::TEXT_PRIMARY, 0.0));
 
                             // World info
-                            info.spawn((
-                                Text::new(format!(
-                                    "World: {} | Seed: {} | Size: {}",
+                            info.spawn(text_line(
+                                format!(
+                                    "World: {} | Seed: {} | Size: {} | Gen v{}",
                                     save_info.world_name,
                                     save_info.world_seed,
-                                    save_info.world_size
-                                )),
-                                TextFont {
-                                    font_size: 16.0,
-                                    ..default()
-                                },
-                                TextColor(colors::TEXT_SECONDARY),
-                                Node {
-                                    margin: UiRect::top(Val::Px(3.0)),
-                                    ..default()
-                                },
+                                    save_info.world_size,
+                                    save_info.generation_version
+                                ),
+                                16.0,
+                                colors::TEXT_SECONDARY,
+                                3.0,
                             ));
 
+                            // Progress through the world
+                            let progress = match &save_info.summary {
+                                Some(summary) => format!(
+                                    "Year {}, day {} | {} nations | Played {}",
+                                    summary.year,
+                                    summary.day_of_year + 1,
+                                    summary.nation_count,
+                                    format_play_time(summary.play_time_secs)
+                                ),
+                                None => format!("Game Time: {:.0} days", save_info.game_time),
+                            };
+                            info.spawn(text_line(progress, 14.0, colors::TEXT_SECONDARY, 3.0));
+
+                            if let Some(summary) = save_info.summary.as_ref().filter(|s| !s.mods.is_empty()) {
+                                let mods: Vec<String> = summary
+                                    .mods
+                                    .iter()
+                                    .map(|m| format!("{} {}", m.id, m.version))
+                                    .collect();
+                                info.spawn(text_line(
+                                    format!("Mods: {}", mods.join(", ")),
+                                    14.0,
+                                    colors::TEXT_TERTIARY,
+                                    3.0,
+                                ));
+                            }
+
                             // Date and size info
-                            info.spawn((
-                                Text::new(format!(
-                                    "Date: {} | Size: {} | Game Time: {:.0} days",
+                            info.spawn(text_line(
+                                format!(
+                                    "Saved: {} | Size: {}",
                                     save_info.date_created.format("%Y-%m-%d %H:%M"),
-                                    super::format_file_size(save_info.compressed_size),
-                                    save_info.game_time
-                                )),
-                                TextFont {
-                                    font_size: 14.0,
-                                    ..default()
-                                },
-                                TextColor(colors::TEXT_TERTIARY),
-                                Node {
-                                    margin: UiRect::top(Val::Px(5.0)),
-                                    ..default()
-                                },
+                                    super::format_file_size(save_info.compressed_size)
+                                ),
+                                14.0,
+                                colors::TEXT_TERTIARY,
+                                5.0,
                             ));
                         });
 
-                    // Right side: Delete button
-                    ButtonBuilder::new("Delete")
-                        .style(ButtonStyle::Danger)
-                        .size(ButtonSize::Small)
-                        .with_marker(DeleteSaveButton {
-                            save_path: save_info.path.clone(),
-                            save_name: save_info.name.clone(),
-                        })
-                        .build(row);
+                    // Right side: compatibility badge and delete button
+                    PanelBuilder::new()
+                        .style(PanelStyle::Transparent)
+                        .flex_direction(FlexDirection::Column)
+                        .align_items(AlignItems::End)
+                        .build_with_children(row, |side| {
+                            if !warnings.is_empty() {
+                                side.spawn((
+                                    Node {
+                                        flex_direction: FlexDirection::Column,
+                                        padding: UiRect::all(Val::Px(6.0)),
+                                        margin: UiRect::bottom(Val::Px(8.0)),
+                                        max_width: Val::Px(220.0),
+                                        ..default()
+                                    },
+                                    BackgroundColor(colors::WARNING),
+                                ))
+                                .with_children(|badge| {
+                                    badge.spawn(text_line("Compatibility".to_string(), 14.0, colors::TEXT_PRIMARY, 0.0));
+                                    for warning in &warnings {
+                                        badge.spawn(text_line(warning.clone(), 12.0, colors::TEXT_PRIMARY, 2.0));
+                                    }
+                                });
+                            }
+
+                            ButtonBuilder::new("Delete")
+                                .style(ButtonStyle::Danger)
+                                .size(ButtonSize::Small)
+                                .with_marker(DeleteSaveButton {
+                                    save_path: save_info.path.clone(),
+                                    save_name: save_info.name.clone(),
+                                })
+                                .build(side);
+                        });
                 });
         });
 }
 
+/// Re-sort or re-filter the save list when its controls change
+pub fn handle_save_browser_controls(
+    mut commands: Commands,
+    sort_buttons: Query<&Interaction, (Changed<Interaction>, With<SortSavesButton>)>,
+    filter_inputs: Query<&TextBuffer, (Changed<TextBuffer>, With<SaveFilterInput>)>,
+    mut sort_labels: Query<&mut Text, With<SortOrderLabel>>,
+    list_query: Query<Entity, With<SaveListContainer>>,
+    mut browser_state: ResMut<SaveBrowserState>,
+    save_list: Res<SaveGameList>,
+    mut images: ResMut<Assets<Image>>,
+    mod_manager: Option<Res<ModManager>>,
+) {
+    let mut changed = false;
+
+    if sort_buttons.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        browser_state.sort_order = browser_state.sort_order.next();
+        for mut label in &mut sort_labels {
+            **label = format!("Sorted by {}", browser_state.sort_order.label());
+        }
+        changed = true;
+    }
+
+    for buffer in &filter_inputs {
+        if browser_state.filter != buffer.content {
+            browser_state.filter = buffer.content.clone();
+            // A hidden save must not stay selected for loading
+            browser_state.selected_save = None;
+            changed = true;
+        }
+    }
+
+    if !changed {
+        return;
+    }
+
+    for list in &list_query {
+        commands.entity(list).despawn_related::<Children>();
+        commands.entity(list).with_children(|list| {
+            spawn_save_slots(list, &save_list.saves, &browser_state, &mut images, mod_manager.as_deref());
+        });
+    }
+}
+
 /// Handle save browser button interactions
 pub fn handle_save_browser_interactions(
     mut interactions: Query<
@@ -35,6 +35,22 @@ pub struct LoadSelectedButton;
 #[derive(Component)]
 pub struct CancelBrowserButton;
 
+/// Marker for the browser's filter text input
+#[derive(Component)]
+pub struct SaveFilterInput;
+
+/// Button cycling the browser's sort order
+#[derive(Component)]
+pub struct SortSavesButton;
+
+/// Text showing the current sort order
+#[derive(Component)]
+pub struct SortOrderLabel;
+
+/// Container the save slots are spawned into, rebuilt on sort or filter changes
+#[derive(Component)]
+pub struct SaveListContainer;
+
 // Save dialog components
 
 /// Marker for save dialog UI
@@ -82,3 +98,19 @@ pub struct ConfirmDeleteButton {
 /// Cancel delete button
 #[derive(Component)]
 pub struct CancelDeleteButton;
+
+// Mod check components
+
+/// Marker for the dialog shown when a save's mods differ from the active ones
+#[derive(Component)]
+pub struct ModCheckDialog;
+
+// Save progress components
+
+/// Corner indicator shown while a save runs in the background
+#[derive(Component)]
+pub struct SaveProgressIndicator;
+
+/// Text of the save progress indicator
+#[derive(Component)]
+pub struct SaveProgressText;
@@ -75,6 +75,9 @@ pub fn handle_delete_confirmation(
                         error!("Failed to delete save file: {}", e);
                     } else {
                         info!("Deleted save file: {:?}", pending.0);
+                        super::delete_delta_chain(&pending.0);
+                        // Older saves have no summary, so a missing one is fine
+                        let _ = fs::remove_file(super::summary_path(&pending.0));
 
                         // Refresh the save list
                         super::scan_save_files_internal(&mut save_list);
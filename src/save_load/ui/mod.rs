@@ -10,24 +10,34 @@
 
 // Re-export what our children need from parent gateway (for internal use only)
 pub(self) use super::{
-    CloseSaveDialogEvent, LoadGameEvent, OpenSaveDialogEvent, SaveBrowserState, SaveDialogState,
-    SaveGameEvent, SaveGameInfo, SaveGameList,
+    CloseSaveDialogEvent, LoadCompleteEvent, LoadGameEvent, PendingModCheck, OpenSaveDialogEvent, SaveBrowserState, SaveDialogState,
+    SaveGameEvent, SaveGameInfo, SaveGameList, SaveProgressEvent, SaveSortOrder, SaveTasks, SAVE_VERSION,
+    THUMBNAIL_HEIGHT, THUMBNAIL_WIDTH,
 };
 
 // Re-export I/O functions our children might need
-pub(self) use super::io::{format_file_size, scan_save_files_internal};
+pub(self) use super::io::{format_file_size, scan_save_files_internal, summary_path};
+
+// Deleting a full save also removes the deltas chained to it
+pub(self) use super::core::delete_delta_chain;
+
+// Loading resumes through core once the player accepts mismatched mods
+pub(self) use super::core::begin_loading;
 
 // PRIVATE MODULES - UI implementation
 mod browser;
 mod components;
 mod delete_dialog;
+mod mod_check_dialog;
+mod progress;
 mod save_dialog;
 
 // CONTROLLED EXPORTS - UI system functions for plugin
 
 // Browser systems
 pub(super) use browser::{
-    close_save_browser, handle_save_browser_interactions, spawn_save_browser, update_save_browser,
+    close_save_browser, handle_save_browser_controls, handle_save_browser_interactions, spawn_save_browser,
+    update_save_browser,
 };
 
 // Save dialog systems
@@ -38,4 +48,10 @@ pub(super) use save_dialog::{
 // Delete dialog systems
 pub(super) use delete_dialog::{handle_delete_button_click, handle_delete_confirmation};
 
+// Mod check dialog systems
+pub(super) use mod_check_dialog::{handle_mod_check_dialog, spawn_mod_check_dialog};
+
+// Save progress indicator
+pub(super) use progress::update_save_progress_indicator;
+
 // Note: Component markers remain private - they're implementation details
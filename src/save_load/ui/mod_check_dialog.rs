@@ -0,0 +1,86 @@
+//! Mod mismatch dialog implementation
+//!
+//! Shown when a save's recorded mods differ from the active ones, letting the
+//! player load anyway or back out.
+
+use super::components::ModCheckDialog;
+use super::{begin_loading, LoadCompleteEvent, PendingModCheck};
+use crate::loading::LoadingState;
+use crate::states::{GameState, RequestStateTransition};
+use crate::ui::{layers, DialogBuilder, DialogType};
+use bevy::prelude::*;
+
+/// Spawn the dialog when a load is held back by mod differences
+pub fn spawn_mod_check_dialog(mut commands: Commands, pending: Res<PendingModCheck>) {
+    let issues: Vec<String> = pending.issues.iter().map(|issue| format!("- {}", issue)).collect();
+    let save_name = pending
+        .save_path
+        .file_stem()
+        .and_then(|n| n.to_str())
+        .unwrap_or("This save");
+
+    let dialog_entity = DialogBuilder::new(DialogType::Custom)
+        .title("Mods Differ From Save")
+        .body(&format!(
+            "\"{}\" was saved with different mods:\n\n{}\n\nLoading anyway may corrupt the game state.",
+            save_name,
+            issues.join("\n")
+        ))
+        .confirm_button("Load Anyway")
+        .cancel_button("Cancel")
+        .z_index(layers::CRITICAL_DIALOG)
+        .dismissible(false)
+        .build(&mut commands);
+
+    // Shown over the loading screen, and gone with it if the load is cancelled
+    commands
+        .entity(dialog_entity)
+        .insert((ModCheckDialog, DespawnOnExit(GameState::LoadingWorld)));
+}
+
+/// Load anyway or cancel, then close the dialog
+pub fn handle_mod_check_dialog(
+    interactions: Query<
+        (
+            &Interaction,
+            AnyOf<(&bevy_ui_builders::ConfirmButton, &bevy_ui_builders::CancelButton)>,
+        ),
+        Changed<Interaction>,
+    >,
+    mut commands: Commands,
+    dialog_query: Query<Entity, With<ModCheckDialog>>,
+    mut pending: ResMut<PendingModCheck>,
+    mut loading_state: ResMut<LoadingState>,
+    mut state_events: MessageWriter<RequestStateTransition>,
+    mut complete_events: MessageWriter<LoadCompleteEvent>,
+) {
+    for (interaction, (confirm_button, cancel_button)) in &interactions {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if confirm_button.is_some() {
+            if let Some(save_data) = pending.save_data.take() {
+                info!("Loading {:?} despite {} mod differences", pending.save_path, pending.issues.len());
+                begin_loading(&mut commands, &mut loading_state, save_data);
+            }
+        } else if cancel_button.is_some() {
+            complete_events.write(LoadCompleteEvent {
+                success: false,
+                message: "Load cancelled: mods differ from the save".to_string(),
+            });
+            state_events.write(RequestStateTransition {
+                from: GameState::LoadingWorld,
+                to: GameState::MainMenu,
+            });
+        } else {
+            continue;
+        }
+
+        for entity in &dialog_query {
+            commands.entity(entity).despawn();
+        }
+        commands.remove_resource::<PendingModCheck>();
+        break;
+    }
+}
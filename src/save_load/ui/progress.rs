@@ -0,0 +1,63 @@
+//! Save progress indicator
+//!
+//! A small panel in the corner of the screen that follows background saves
+//! through their stages, and disappears once the last one has finished.
+
+use super::components::*;
+use super::{SaveProgressEvent, SaveTasks};
+use crate::ui::colors;
+use bevy::prelude::*;
+
+/// Show the stage and progress of background saves
+pub fn update_save_progress_indicator(
+    mut commands: Commands,
+    mut progress_events: MessageReader<SaveProgressEvent>,
+    save_tasks: Res<SaveTasks>,
+    indicator_query: Query<Entity, With<SaveProgressIndicator>>,
+    mut text_query: Query<&mut Text, With<SaveProgressText>>,
+) {
+    let latest = progress_events.read().last().cloned();
+    if save_tasks.pending.is_empty() {
+        for indicator in &indicator_query {
+            commands.entity(indicator).despawn();
+        }
+        return;
+    }
+    let Some(event) = latest else {
+        return;
+    };
+
+    let label = format!("{} {:.0}%", event.stage.label(), event.progress * 100.0);
+    if !indicator_query.is_empty() {
+        for mut text in &mut text_query {
+            text.0 = label.clone();
+        }
+        return;
+    }
+
+    commands
+        .spawn((
+            SaveProgressIndicator,
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(20.0),
+                bottom: Val::Px(20.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            BackgroundColor(colors::BACKGROUND_MEDIUM),
+            BorderColor::all(colors::BORDER),
+            ZIndex(150),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                SaveProgressText,
+                Text::new(label),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(colors::TEXT_SECONDARY),
+            ));
+        });
+}
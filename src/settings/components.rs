@@ -74,6 +74,13 @@ pub struct SettingsSlider {
     pub setting_type: SettingType,
 }
 
+/// Marker for a toggle, dropdown, or slider on a mod's settings page
+#[derive(Component)]
+pub struct ModSettingInput {
+    pub mod_id: String,
+    pub key: String,
+}
+
 /// Marker for master volume slider
 #[derive(Component)]
 pub struct MasterVolumeSlider;
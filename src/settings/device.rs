@@ -0,0 +1,184 @@
+//! Device presets - tuning for handhelds and low-power hardware
+//!
+//! A device profile sets the default world size, how many clouds drift over
+//! the map, the fastest simulation speed allowed, and an extra UI scale for
+//! small screens viewed from further away. The player picks a preset in the
+//! graphics settings or leaves it on auto-detect, which recognizes the Steam
+//! Deck and machines with few cores. Profile values live in
+//! `DEVICE_PRESETS_FILE`; presets missing from the file keep their defaults.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+
+use super::types::{DevicePreset, GameSettings};
+use crate::resources::WorldSize;
+use crate::simulation::SimulationSpeed;
+
+/// Optional overrides for the default device profiles
+pub const DEVICE_PRESETS_FILE: &str = "config/base/device_presets.ron";
+
+/// Machines with this many logical cores or fewer count as low-power
+const LOW_POWER_CORES: usize = 4;
+
+/// Settings tuned for one kind of hardware
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeviceProfile {
+    /// World size preselected when configuring a new world
+    pub world_size: WorldSize,
+    /// Share of clouds spawned over the map (0.0-1.0)
+    pub cloud_density: f32,
+    /// Fastest simulation speed the player can select
+    pub max_speed: SimulationSpeed,
+    /// Extra UI scale, on top of the player's own and the resolution's
+    pub ui_scale: f32,
+}
+
+impl Default for DeviceProfile {
+    fn default() -> Self {
+        Self {
+            world_size: WorldSize::Medium,
+            cloud_density: 1.0,
+            max_speed: SimulationSpeed::Fastest,
+            ui_scale: 1.0,
+        }
+    }
+}
+
+/// Profiles for every concrete device preset
+#[derive(Resource, Debug, Clone)]
+pub struct DevicePresets {
+    profiles: HashMap<DevicePreset, DeviceProfile>,
+}
+
+impl Default for DevicePresets {
+    fn default() -> Self {
+        let profiles = HashMap::from([
+            (DevicePreset::Desktop, DeviceProfile::default()),
+            (
+                DevicePreset::SteamDeck,
+                DeviceProfile {
+                    world_size: WorldSize::Small,
+                    cloud_density: 0.4,
+                    max_speed: SimulationSpeed::Faster,
+                    ui_scale: 1.25,
+                },
+            ),
+            (
+                DevicePreset::LowPower,
+                DeviceProfile {
+                    world_size: WorldSize::Small,
+                    cloud_density: 0.5,
+                    max_speed: SimulationSpeed::Faster,
+                    ui_scale: 1.0,
+                },
+            ),
+        ]);
+        Self { profiles }
+    }
+}
+
+impl DevicePresets {
+    /// Profile for a preset, resolving auto-detection to the detected hardware
+    pub fn profile(&self, preset: DevicePreset, detected: DevicePreset) -> DeviceProfile {
+        let preset = if preset == DevicePreset::Auto { detected } else { preset };
+        self.profiles.get(&preset).cloned().unwrap_or_default()
+    }
+}
+
+/// The device profile currently in effect
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ActiveDeviceProfile(pub DeviceProfile);
+
+/// Whether system identification names a Steam Deck ("Jupiter" or "Galileo" boards by Valve)
+pub fn is_steam_deck(board_vendor: &str, product_name: &str) -> bool {
+    board_vendor.trim() == "Valve" && matches!(product_name.trim(), "Jupiter" | "Galileo")
+}
+
+/// Recognize the hardware the game is running on
+pub fn detect_device() -> DevicePreset {
+    // Steam sets this in the Deck's game mode session
+    if std::env::var("SteamDeck").is_ok_and(|value| value == "1") {
+        return DevicePreset::SteamDeck;
+    }
+    let vendor = fs::read_to_string("/sys/devices/virtual/dmi/id/board_vendor").unwrap_or_default();
+    let product = fs::read_to_string("/sys/devices/virtual/dmi/id/product_name").unwrap_or_default();
+    if is_steam_deck(&vendor, &product) {
+        return DevicePreset::SteamDeck;
+    }
+
+    let cores = std::thread::available_parallelism().map_or(1, |cores| cores.get());
+    if cores <= LOW_POWER_CORES {
+        DevicePreset::LowPower
+    } else {
+        DevicePreset::Desktop
+    }
+}
+
+/// Apply profile overrides from `DEVICE_PRESETS_FILE`, if present
+pub fn load_device_presets(mut presets: ResMut<DevicePresets>) {
+    let Ok(text) = fs::read_to_string(DEVICE_PRESETS_FILE) else {
+        return;
+    };
+    match ron::from_str::<HashMap<DevicePreset, DeviceProfile>>(&text) {
+        Ok(overrides) => {
+            info!("Loaded {} device presets from {}", overrides.len(), DEVICE_PRESETS_FILE);
+            presets.profiles.extend(overrides);
+        }
+        Err(e) => warn!("Failed to parse {}: {}", DEVICE_PRESETS_FILE, e),
+    }
+}
+
+/// Keep the active profile in step with the chosen preset
+pub fn sync_device_profile(
+    settings: Res<GameSettings>,
+    presets: Res<DevicePresets>,
+    mut active: ResMut<ActiveDeviceProfile>,
+    mut detected: Local<Option<DevicePreset>>,
+) {
+    if !settings.is_changed() && !presets.is_changed() {
+        return;
+    }
+    let detected = *detected.get_or_insert_with(|| {
+        let device = detect_device();
+        info!("Detected device: {}", device.as_str());
+        device
+    });
+
+    let profile = presets.profile(settings.graphics.device_preset, detected);
+    if active.0 != profile {
+        active.0 = profile;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shipped_presets_parse_and_tune_the_deck_down() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/config/base/device_presets.ron");
+        let text = fs::read_to_string(path).expect("device presets file ships with the game");
+        let shipped: HashMap<DevicePreset, DeviceProfile> = ron::from_str(&text).expect("device presets parse");
+        assert_eq!(
+            shipped.get(&DevicePreset::SteamDeck),
+            DevicePresets::default().profiles.get(&DevicePreset::SteamDeck)
+        );
+
+        let presets = DevicePresets::default();
+        let desktop = presets.profile(DevicePreset::Desktop, DevicePreset::SteamDeck);
+        let deck = presets.profile(DevicePreset::Auto, DevicePreset::SteamDeck);
+        assert_eq!(deck.world_size, WorldSize::Small);
+        assert!(deck.cloud_density < desktop.cloud_density);
+        assert!(deck.ui_scale > desktop.ui_scale);
+        assert_eq!(
+            SimulationSpeed::Fastest.capped_at(deck.max_speed),
+            SimulationSpeed::Faster
+        );
+
+        assert!(is_steam_deck("Valve\n", "Jupiter\n"));
+        assert!(!is_steam_deck("Valve", "Index"));
+    }
+}
@@ -8,6 +8,7 @@
 
 // PRIVATE MODULES - All implementation hidden behind gateway
 mod components;
+mod device;
 mod navigation;
 mod persistence;
 mod resolution;
@@ -18,6 +19,8 @@ mod types;
 // CONTROLLED EXPORTS - Minimal public API
 
 // Essential types for external use
+pub use types::{BackgroundMode, GameSettings, QualityLevel, SaveCompression};
+pub use device::ActiveDeviceProfile;
 
 // Essential components for external queries (minimal exposure)
 
@@ -112,7 +112,7 @@ macro_rules! generate_setting_tab {
 
             // Generate event handling system
             pub fn [<handle_ $tab_name:lower _interactions>](
-                temp_settings: bevy::prelude::ResMut<crate::settings::types::TempGameSettings>,
+                mut temp_settings: bevy::prelude::ResMut<crate::settings::types::TempGameSettings>,
                 // Add standard interaction queries here
                 mut cycle_buttons: bevy::prelude::Query<
                     (&bevy::prelude::Interaction, &crate::settings::components::CycleButton, &bevy::prelude::Children),
@@ -127,7 +127,7 @@ macro_rules! generate_setting_tab {
                     bevy::prelude::Changed<crate::ui::Slider>
                 >,
                 // Add text query for updating UI
-                text_query: bevy::prelude::Query<&mut bevy::prelude::Text>,
+                mut text_query: bevy::prelude::Query<&mut bevy::prelude::Text>,
             ) {
                 $crate::generate_event_handlers!(temp_settings, cycle_buttons, toggle_buttons, sliders, text_query);
             }
@@ -199,7 +199,8 @@ macro_rules! generate_controls {
     // Slider control with range and format (simple format like Percentage)
     ($section:ident, $settings:ident, slider: $label:literal => $field:ident ($min:literal..$max:literal, $format:ident) $(, $($rest:tt)*)?) => {
         let slider = crate::ui::SliderBuilder::new($min..$max)
-
+            .label($label)
+            .value($settings.$field)
             .build_in($section);
         $section.commands().entity(slider).insert(crate::settings::components::SettingsSlider {
             setting_type: $crate::field_to_setting_type!($field)
@@ -211,7 +212,8 @@ macro_rules! generate_controls {
     // Slider control with range and format (function format like Decimal(1))
     ($section:ident, $settings:ident, slider: $label:literal => $field:ident ($min:literal..$max:literal, $format:ident($param:literal)) $(, $($rest:tt)*)?) => {
         let slider = crate::ui::SliderBuilder::new($min..$max)
-            
+            .label($label)
+            .value($settings.$field)
             .build_in($section);
         $section.commands().entity(slider).insert(crate::settings::components::SettingsSlider {
             setting_type: $crate::field_to_setting_type!($field)
@@ -258,7 +260,8 @@ macro_rules! generate_controls {
 /// Helper macro to create toggle row (replicates existing pattern)
 #[macro_export]
 macro_rules! create_toggle_row {
-    ($parent:ident, $label:literal, $enabled:expr_2021, $setting_type:expr_2021) => {
+    // Toggle carrying its own marker, for controls not keyed by SettingType
+    ($parent:ident, $label:expr_2021, $enabled:expr_2021, marker: $marker:expr_2021) => {
         $parent
             .spawn((
                 bevy::prelude::Node {
@@ -289,12 +292,21 @@ macro_rules! create_toggle_row {
                         crate::ui::ButtonStyle::Secondary
                     })
                     .build(row);
-                row.commands().entity(button).insert(crate::settings::components::ToggleButton {
-                    setting_type: $setting_type,
-                    enabled: $enabled,
-                });
+                row.commands().entity(button).insert($marker);
             });
     };
+
+    ($parent:ident, $label:literal, $enabled:expr_2021, $setting_type:expr_2021) => {
+        $crate::create_toggle_row!(
+            $parent,
+            $label,
+            $enabled,
+            marker: crate::settings::components::ToggleButton {
+                setting_type: $setting_type,
+                enabled: $enabled,
+            }
+        )
+    };
 }
 
 /// Convert field names to SettingType enum variants
@@ -315,12 +327,48 @@ macro_rules! field_to_setting_type {
     (shadow_quality) => {
         crate::settings::types::SettingType::ShadowQuality
     };
+    (day_night_cycle) => {
+        crate::settings::types::SettingType::DayNightCycle
+    };
+    (seasonal_effects) => {
+        crate::settings::types::SettingType::SeasonalEffects
+    };
+    (device_preset) => {
+        crate::settings::types::SettingType::DevicePreset
+    };
+    (msaa) => {
+        crate::settings::types::SettingType::Msaa
+    };
+    (clouds) => {
+        crate::settings::types::SettingType::Clouds
+    };
+    (border_quality) => {
+        crate::settings::types::SettingType::BorderQuality
+    };
+    (label_density) => {
+        crate::settings::types::SettingType::LabelDensity
+    };
+    (overlay_refresh_rate) => {
+        crate::settings::types::SettingType::OverlayRefreshRate
+    };
+    (background_mode) => {
+        crate::settings::types::SettingType::BackgroundMode
+    };
+    (background_fps) => {
+        crate::settings::types::SettingType::BackgroundFps
+    };
     (master_volume) => {
         crate::settings::types::SettingType::MasterVolume
     };
     (sfx_volume) => {
         crate::settings::types::SettingType::SfxVolume
     };
+    (ui_volume) => {
+        crate::settings::types::SettingType::UiVolume
+    };
+    (ambient_volume) => {
+        crate::settings::types::SettingType::AmbientVolume
+    };
     (mute_when_unfocused) => {
         crate::settings::types::SettingType::MuteWhenUnfocused
     };
@@ -339,6 +387,36 @@ macro_rules! field_to_setting_type {
     (show_tooltips) => {
         crate::settings::types::SettingType::ShowTooltips
     };
+    (font_scale) => {
+        crate::settings::types::SettingType::FontScale
+    };
+    (reduced_motion) => {
+        crate::settings::types::SettingType::ReducedMotion
+    };
+    (high_contrast) => {
+        crate::settings::types::SettingType::HighContrast
+    };
+    (narration_feed) => {
+        crate::settings::types::SettingType::NarrationFeed
+    };
+    (narration_file) => {
+        crate::settings::types::SettingType::NarrationFile
+    };
+    (safe_area) => {
+        crate::settings::types::SettingType::SafeArea
+    };
+    (ultrawide_spread) => {
+        crate::settings::types::SettingType::UltrawideSpread
+    };
+    (themed_ui) => {
+        crate::settings::types::SettingType::ThemedUi
+    };
+    (autosave_compression) => {
+        crate::settings::types::SettingType::AutosaveCompression
+    };
+    (manual_save_compression) => {
+        crate::settings::types::SettingType::ManualSaveCompression
+    };
     (camera_speed) => {
         crate::settings::types::SettingType::CameraSpeed
     };
@@ -354,6 +432,24 @@ macro_rules! field_to_setting_type {
     (zoom_sensitivity) => {
         crate::settings::types::SettingType::ZoomSensitivity
     };
+    (edge_scrolling) => {
+        crate::settings::types::SettingType::EdgeScrolling
+    };
+    (edge_pan_zone) => {
+        crate::settings::types::SettingType::EdgePanZone
+    };
+    (invert_pan) => {
+        crate::settings::types::SettingType::InvertPan
+    };
+    (zoom_to_cursor) => {
+        crate::settings::types::SettingType::ZoomToCursor
+    };
+    (touchpad_pan) => {
+        crate::settings::types::SettingType::TouchpadPan
+    };
+    (gamepad_deadzone) => {
+        crate::settings::types::SettingType::GamepadDeadzone
+    };
 }
 
 /// Generate event handlers for all control types
@@ -363,8 +459,13 @@ macro_rules! generate_event_handlers {
         // Handle cycle button interactions
         for (interaction, cycle_button, children) in &mut $cycle_buttons {
             if *interaction == bevy::prelude::Interaction::Pressed {
-                // This will be expanded with specific field updates
-                // based on the actual settings structure in each generated implementation
+                if let Some(label) = $temp_settings.0.cycle_option(cycle_button.setting_type) {
+                    for child in children.iter() {
+                        if let Ok(mut text) = $text_query.get_mut(child) {
+                            text.0 = format!("< {} >", label);
+                        }
+                    }
+                }
             }
         }
 
@@ -372,13 +473,13 @@ macro_rules! generate_event_handlers {
         for (interaction, mut toggle_button) in &mut $toggle_buttons {
             if *interaction == bevy::prelude::Interaction::Pressed {
                 toggle_button.enabled = !toggle_button.enabled;
-                // Update temp settings based on setting type
+                $temp_settings.0.set_toggle(toggle_button.setting_type, toggle_button.enabled);
             }
         }
 
         // Handle slider interactions
         for (slider, settings_slider) in &$sliders {
-            // Update temp settings based on setting type and slider value
+            $temp_settings.0.set_slider(settings_slider.setting_type, slider.value);
         }
     };
 }
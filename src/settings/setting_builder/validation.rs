@@ -264,18 +264,46 @@ pub mod runtime {
             SettingType::VSync => "vsync",
             SettingType::RenderScale => "render_scale",
             SettingType::ShadowQuality => "shadow_quality",
+            SettingType::DevicePreset => "device_preset",
+            SettingType::Msaa => "msaa",
+            SettingType::Clouds => "clouds",
+            SettingType::BorderQuality => "border_quality",
+            SettingType::LabelDensity => "label_density",
+            SettingType::OverlayRefreshRate => "overlay_refresh_rate",
+            SettingType::BackgroundMode => "background_mode",
+            SettingType::BackgroundFps => "background_fps",
+            SettingType::DayNightCycle => "day_night_cycle",
+            SettingType::SeasonalEffects => "seasonal_effects",
             SettingType::MasterVolume => "master_volume",
             SettingType::SfxVolume | SettingType::SFXVolume => "sfx_volume",
+            SettingType::UiVolume => "ui_volume",
+            SettingType::AmbientVolume => "ambient_volume",
             SettingType::UiScale | SettingType::UIScale => "ui_scale",
             SettingType::ShowFps | SettingType::ShowFPS => "show_fps",
             SettingType::ShowProvinceInfo => "show_province_info",
             SettingType::ShowTooltips => "show_tooltips",
             SettingType::TooltipDelay => "tooltip_delay",
+            SettingType::FontScale => "font_scale",
+            SettingType::ReducedMotion => "reduced_motion",
+            SettingType::HighContrast => "high_contrast",
+            SettingType::NarrationFeed => "narration_feed",
+            SettingType::NarrationFile => "narration_file",
+            SettingType::SafeArea => "safe_area",
+            SettingType::UltrawideSpread => "ultrawide_spread",
+            SettingType::ThemedUi => "themed_ui",
+            SettingType::AutosaveCompression => "autosave_compression",
+            SettingType::ManualSaveCompression => "manual_save_compression",
             SettingType::EdgePanSpeed => "edge_pan_speed",
             SettingType::ZoomSensitivity => "zoom_sensitivity",
             SettingType::InvertZoom => "invert_zoom",
             SettingType::CameraSpeed => "camera_speed",
             SettingType::ZoomSpeed => "zoom_speed",
+            SettingType::EdgeScrolling => "edge_scrolling",
+            SettingType::EdgePanZone => "edge_pan_zone",
+            SettingType::InvertPan => "invert_pan",
+            SettingType::ZoomToCursor => "zoom_to_cursor",
+            SettingType::TouchpadPan => "touchpad_pan",
+            SettingType::GamepadDeadzone => "gamepad_deadzone",
             SettingType::MuteWhenUnfocused => "mute_when_unfocused",
         };
 
@@ -7,6 +7,8 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::modding::ModSettingValues;
+
 /// Main settings structure containing all game settings
 #[derive(Resource, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GameSettings {
@@ -14,6 +16,9 @@ pub struct GameSettings {
     pub audio: AudioSettings,
     pub interface: InterfaceSettings,
     pub controls: ControlSettings,
+    /// Values for settings declared by mods, by mod id then key
+    #[serde(default)]
+    pub mods: ModSettingValues,
 }
 
 impl Default for GameSettings {
@@ -23,6 +28,7 @@ impl Default for GameSettings {
             audio: AudioSettings::default(),
             interface: InterfaceSettings::default(),
             controls: ControlSettings::default(),
+            mods: ModSettingValues::default(),
         }
     }
 }
@@ -38,12 +44,33 @@ impl Default for TempGameSettings {
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct GraphicsSettings {
     pub window_mode: WindowModeOption,
     pub resolution: ResolutionOption,
     pub vsync: bool,
     pub render_scale: f32,
     pub shadow_quality: QualityLevel,
+    /// Night band sweeping across the map with simulated time
+    pub day_night_cycle: bool,
+    /// Polar snow cover that grows and recedes with the seasons
+    pub seasonal_effects: bool,
+    /// Hardware profile tuning world size, clouds, speed and UI scale
+    pub device_preset: DevicePreset,
+    /// Multisample anti-aliasing on the map camera
+    pub msaa: bool,
+    /// Drifting cloud layers over the map
+    pub clouds: bool,
+    /// How far out nation borders are drawn, and whether coastlines are outlined
+    pub border_quality: QualityLevel,
+    /// Multiplier on the zoom distance at which nation labels stay visible
+    pub label_density: f32,
+    /// Most times per second the map overlay is recolored as the world changes
+    pub overlay_refresh_rate: f32,
+    /// What the game does while its window is in the background
+    pub background_mode: BackgroundMode,
+    /// Frames per second drawn in the background when throttling
+    pub background_fps: f32,
 }
 
 impl Default for GraphicsSettings {
@@ -54,6 +81,16 @@ impl Default for GraphicsSettings {
             vsync: true,
             render_scale: 1.0,
             shadow_quality: QualityLevel::Medium,
+            day_night_cycle: true,
+            seasonal_effects: true,
+            device_preset: DevicePreset::Auto,
+            msaa: true,
+            clouds: true,
+            border_quality: QualityLevel::Medium,
+            label_density: 1.0,
+            overlay_refresh_rate: 10.0,
+            background_mode: BackgroundMode::Throttle,
+            background_fps: 5.0,
         }
     }
 }
@@ -120,9 +157,12 @@ pub enum GraphicsPreset {
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AudioSettings {
     pub master_volume: f32,
     pub sfx_volume: f32,
+    pub ui_volume: f32,
+    pub ambient_volume: f32,
     pub mute_when_unfocused: bool,
 }
 
@@ -131,18 +171,43 @@ impl Default for AudioSettings {
         Self {
             master_volume: 1.0,
             sfx_volume: 1.0,
+            ui_volume: 1.0,
+            ambient_volume: 1.0,
             mute_when_unfocused: false,
         }
     }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct InterfaceSettings {
     pub ui_scale: f32,
     pub show_fps: bool,
     pub show_province_info: bool,
     pub tooltip_delay: f32,
     pub show_tooltips: bool,
+    /// Multiplier on every text size, on top of `ui_scale`
+    pub font_scale: f32,
+    /// Stop cloud drift and camera easing
+    pub reduced_motion: bool,
+    /// Brighten text and borders against the dark panels
+    pub high_contrast: bool,
+    /// Describe major events in plain sentences in the narration feed
+    pub narration_feed: bool,
+    /// Also append the narration to a text file as the world runs
+    pub narration_file: bool,
+    /// Margin kept clear of panels on every side, as a share of the screen
+    pub safe_area: f32,
+    /// On ultrawide screens, spread panels to the far edges instead of a centered 16:9 frame
+    pub ultrawide_spread: bool,
+    /// Tint toolbar and panel accents with the selected nation's colors and the world's era
+    pub themed_ui: bool,
+    /// Language of mod-defined text, by string table name ("en", "de", ...)
+    pub language: String,
+    /// Compression used for autosaves
+    pub autosave_compression: SaveCompression,
+    /// Compression used for saves made by the player
+    pub manual_save_compression: SaveCompression,
 }
 
 impl Default for InterfaceSettings {
@@ -153,17 +218,41 @@ impl Default for InterfaceSettings {
             show_province_info: true,
             tooltip_delay: 0.5,
             show_tooltips: true,
+            font_scale: 1.0,
+            reduced_motion: false,
+            high_contrast: false,
+            narration_feed: false,
+            narration_file: false,
+            safe_area: 0.0,
+            ultrawide_spread: true,
+            themed_ui: true,
+            language: "en".to_string(),
+            autosave_compression: SaveCompression::Fast,
+            manual_save_compression: SaveCompression::Balanced,
         }
     }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ControlSettings {
     pub edge_pan_speed: f32,
     pub zoom_sensitivity: f32,
     pub invert_zoom: bool,
     pub camera_speed: f32,
     pub zoom_speed: f32,
+    /// Pan the camera while the cursor rests against a screen edge
+    pub edge_scrolling: bool,
+    /// Width in pixels of the band along each edge that scrolls; the rest of the screen is dead zone
+    pub edge_pan_zone: f32,
+    /// Middle-mouse drag pushes the view instead of grabbing the map
+    pub invert_pan: bool,
+    /// Zooming in with the wheel closes in on the point under the cursor
+    pub zoom_to_cursor: bool,
+    /// Two-finger touchpad scrolling pans the map; pinch still zooms
+    pub touchpad_pan: bool,
+    /// Share of gamepad stick and trigger travel ignored around rest
+    pub gamepad_deadzone: f32,
 }
 
 impl Default for ControlSettings {
@@ -174,6 +263,13 @@ impl Default for ControlSettings {
             invert_zoom: false,
             camera_speed: 1.0,
             zoom_speed: 1.0,
+            edge_scrolling: true,
+            edge_pan_zone: crate::constants::CAMERA_EDGE_PAN_THRESHOLD,
+            invert_pan: false,
+            zoom_to_cursor: true,
+            // Off by default: high-resolution mouse wheels also scroll in pixels
+            touchpad_pan: false,
+            gamepad_deadzone: 0.15,
         }
     }
 }
@@ -287,6 +383,97 @@ impl QualityLevel {
     }
 }
 
+/// Hardware the game is tuned for
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DevicePreset {
+    /// Detect the hardware at startup
+    #[default]
+    Auto,
+    Desktop,
+    SteamDeck,
+    /// Laptops and older machines
+    LowPower,
+}
+
+impl DevicePreset {
+    pub fn cycle(&self) -> Self {
+        match self {
+            Self::Auto => Self::Desktop,
+            Self::Desktop => Self::SteamDeck,
+            Self::SteamDeck => Self::LowPower,
+            Self::LowPower => Self::Auto,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Auto => "Auto-Detect",
+            Self::Desktop => "Desktop",
+            Self::SteamDeck => "Steam Deck",
+            Self::LowPower => "Low Power",
+        }
+    }
+}
+
+/// Behavior while the game window is unfocused
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BackgroundMode {
+    /// Render and simulate as if focused
+    KeepRendering,
+    /// Keep simulating, but render only a trickle of frames
+    #[default]
+    Throttle,
+    /// Pause the simulation until the window is focused again
+    Pause,
+}
+
+impl BackgroundMode {
+    pub fn cycle(&self) -> Self {
+        match self {
+            Self::KeepRendering => Self::Throttle,
+            Self::Throttle => Self::Pause,
+            Self::Pause => Self::KeepRendering,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::KeepRendering => "Keep Rendering",
+            Self::Throttle => "Run in Background",
+            Self::Pause => "Pause",
+        }
+    }
+}
+
+/// Trade-off between save speed and file size
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SaveCompression {
+    /// Quickest to write, largest files
+    Fast,
+    #[default]
+    Balanced,
+    /// Slowest to write, for archiving and sharing
+    Smallest,
+}
+
+impl SaveCompression {
+    pub fn cycle(&self) -> Self {
+        match self {
+            Self::Fast => Self::Balanced,
+            Self::Balanced => Self::Smallest,
+            Self::Smallest => Self::Fast,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Fast => "Fast",
+            Self::Balanced => "Balanced",
+            Self::Smallest => "Smallest",
+        }
+    }
+}
+
 /// Types of settings that can be modified
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum SettingType {
@@ -296,9 +483,21 @@ pub enum SettingType {
     VSync,
     RenderScale,
     ShadowQuality,
+    DayNightCycle,
+    SeasonalEffects,
+    DevicePreset,
+    Msaa,
+    Clouds,
+    BorderQuality,
+    LabelDensity,
+    OverlayRefreshRate,
+    BackgroundMode,
+    BackgroundFps,
     // Audio
     MasterVolume,
     SfxVolume,
+    UiVolume,
+    AmbientVolume,
     MuteWhenUnfocused,
     // Interface
     UiScale,
@@ -306,17 +505,129 @@ pub enum SettingType {
     ShowProvinceInfo,
     TooltipDelay,
     ShowTooltips,
+    FontScale,
+    ReducedMotion,
+    HighContrast,
+    NarrationFeed,
+    NarrationFile,
+    SafeArea,
+    UltrawideSpread,
+    ThemedUi,
+    AutosaveCompression,
+    ManualSaveCompression,
     // Controls
     EdgePanSpeed,
     ZoomSensitivity,
     InvertZoom,
     CameraSpeed,
     ZoomSpeed,
+    EdgeScrolling,
+    EdgePanZone,
+    InvertPan,
+    ZoomToCursor,
+    TouchpadPan,
+    GamepadDeadzone,
     SFXVolume,
     UIScale,
     ShowFPS,
 }
 
+impl GameSettings {
+    /// Apply a toggle control's new state to the matching field
+    pub fn set_toggle(&mut self, setting_type: SettingType, enabled: bool) {
+        match setting_type {
+            SettingType::VSync => self.graphics.vsync = enabled,
+            SettingType::DayNightCycle => self.graphics.day_night_cycle = enabled,
+            SettingType::SeasonalEffects => self.graphics.seasonal_effects = enabled,
+            SettingType::Msaa => self.graphics.msaa = enabled,
+            SettingType::Clouds => self.graphics.clouds = enabled,
+            SettingType::MuteWhenUnfocused => self.audio.mute_when_unfocused = enabled,
+            SettingType::ShowFps | SettingType::ShowFPS => self.interface.show_fps = enabled,
+            SettingType::ShowProvinceInfo => self.interface.show_province_info = enabled,
+            SettingType::ShowTooltips => self.interface.show_tooltips = enabled,
+            SettingType::ReducedMotion => self.interface.reduced_motion = enabled,
+            SettingType::HighContrast => self.interface.high_contrast = enabled,
+            SettingType::NarrationFeed => self.interface.narration_feed = enabled,
+            SettingType::NarrationFile => self.interface.narration_file = enabled,
+            SettingType::UltrawideSpread => self.interface.ultrawide_spread = enabled,
+            SettingType::ThemedUi => self.interface.themed_ui = enabled,
+            SettingType::InvertZoom => self.controls.invert_zoom = enabled,
+            SettingType::EdgeScrolling => self.controls.edge_scrolling = enabled,
+            SettingType::InvertPan => self.controls.invert_pan = enabled,
+            SettingType::ZoomToCursor => self.controls.zoom_to_cursor = enabled,
+            SettingType::TouchpadPan => self.controls.touchpad_pan = enabled,
+            _ => {}
+        }
+    }
+
+    /// Advance a cycle control to its next option, returning the new option's label
+    pub fn cycle_option(&mut self, setting_type: SettingType) -> Option<String> {
+        let graphics = &mut self.graphics;
+        match setting_type {
+            SettingType::WindowMode => {
+                graphics.window_mode = graphics.window_mode.cycle();
+                Some(graphics.window_mode.as_str().to_string())
+            }
+            SettingType::Resolution => {
+                graphics.resolution = graphics.resolution.cycle();
+                Some(graphics.resolution.as_str())
+            }
+            SettingType::ShadowQuality => {
+                graphics.shadow_quality = graphics.shadow_quality.cycle();
+                Some(graphics.shadow_quality.as_str().to_string())
+            }
+            SettingType::DevicePreset => {
+                graphics.device_preset = graphics.device_preset.cycle();
+                Some(graphics.device_preset.as_str().to_string())
+            }
+            SettingType::BorderQuality => {
+                graphics.border_quality = graphics.border_quality.cycle();
+                Some(graphics.border_quality.as_str().to_string())
+            }
+            SettingType::BackgroundMode => {
+                graphics.background_mode = graphics.background_mode.cycle();
+                Some(graphics.background_mode.as_str().to_string())
+            }
+            SettingType::AutosaveCompression => {
+                let interface = &mut self.interface;
+                interface.autosave_compression = interface.autosave_compression.cycle();
+                Some(interface.autosave_compression.as_str().to_string())
+            }
+            SettingType::ManualSaveCompression => {
+                let interface = &mut self.interface;
+                interface.manual_save_compression = interface.manual_save_compression.cycle();
+                Some(interface.manual_save_compression.as_str().to_string())
+            }
+            _ => None,
+        }
+    }
+
+    /// Apply a slider control's new value to the matching field
+    pub fn set_slider(&mut self, setting_type: SettingType, value: f32) {
+        match setting_type {
+            SettingType::RenderScale => self.graphics.render_scale = value,
+            SettingType::LabelDensity => self.graphics.label_density = value,
+            SettingType::OverlayRefreshRate => self.graphics.overlay_refresh_rate = value,
+            SettingType::BackgroundFps => self.graphics.background_fps = value,
+            SettingType::MasterVolume => self.audio.master_volume = value,
+            SettingType::SfxVolume | SettingType::SFXVolume => self.audio.sfx_volume = value,
+            SettingType::UiVolume => self.audio.ui_volume = value,
+            SettingType::AmbientVolume => self.audio.ambient_volume = value,
+            SettingType::UiScale | SettingType::UIScale => self.interface.ui_scale = value,
+            SettingType::TooltipDelay => self.interface.tooltip_delay = value,
+            SettingType::FontScale => self.interface.font_scale = value,
+            SettingType::SafeArea => self.interface.safe_area = value,
+            SettingType::EdgePanSpeed => self.controls.edge_pan_speed = value,
+            SettingType::ZoomSensitivity => self.controls.zoom_sensitivity = value,
+            SettingType::CameraSpeed => self.controls.camera_speed = value,
+            SettingType::ZoomSpeed => self.controls.zoom_speed = value,
+            SettingType::EdgePanZone => self.controls.edge_pan_zone = value,
+            SettingType::GamepadDeadzone => self.controls.gamepad_deadzone = value,
+            _ => {}
+        }
+    }
+}
+
 /// Event triggered when settings are changed
 #[derive(Message)]
 pub struct SettingsChanged;
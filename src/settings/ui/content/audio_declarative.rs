@@ -10,7 +10,9 @@ define_setting_tab!(AudioTabDeclarative {
     sections: [
         Section("Volume Control") {
             slider: "Master Volume" => master_volume (0.0..1.0, Percentage),
-            slider: "SFX Volume" => sfx_volume (0.0..1.0, Percentage)
+            slider: "SFX Volume" => sfx_volume (0.0..1.0, Percentage),
+            slider: "Interface Volume" => ui_volume (0.0..1.0, Percentage),
+            slider: "Ambient Volume" => ambient_volume (0.0..1.0, Percentage)
         },
 
         Section("Audio Options") {
@@ -113,6 +115,8 @@ mod tests {
         let _test_settings = AudioSettings {
             master_volume: 0.8,        // Covered by master_volume slider
             sfx_volume: 0.6,           // Covered by sfx_volume slider
+            ui_volume: 0.5,            // Covered by ui_volume slider
+            ambient_volume: 0.4,       // Covered by ambient_volume slider
             mute_when_unfocused: true, // Covered by mute_when_unfocused toggle
         };
 
@@ -17,13 +17,22 @@ define_setting_tab!(ControlsTabDeclarative {
     sections: [
         Section("Camera Controls") {
             slider: "Camera Speed" => camera_speed (0.5..2.0, Decimal(1)),
-            slider: "Edge Pan Speed" => edge_pan_speed (0.5..2.0, Decimal(1))
+            toggle: "Edge Scrolling" => edge_scrolling,
+            slider: "Edge Pan Speed" => edge_pan_speed (0.5..2.0, Decimal(1)),
+            slider: "Edge Scroll Zone" => edge_pan_zone (2.0..60.0, Integer),
+            toggle: "Invert Drag Pan" => invert_pan
         },
 
         Section("Zoom Controls") {
             slider: "Zoom Speed" => zoom_speed (0.5..2.0, Decimal(1)),
             slider: "Zoom Sensitivity" => zoom_sensitivity (0.5..2.0, Decimal(1)),
-            toggle: "Invert Zoom" => invert_zoom
+            toggle: "Invert Zoom" => invert_zoom,
+            toggle: "Zoom to Cursor" => zoom_to_cursor
+        },
+
+        Section("Touchpad & Gamepad") {
+            toggle: "Two-Finger Scroll Pans" => touchpad_pan,
+            slider: "Stick Deadzone" => gamepad_deadzone (0.05..0.4, Percentage)
         }
     ]
 });
@@ -147,6 +156,12 @@ mod tests {
             invert_zoom: true,     // Covered by invert_zoom toggle
             camera_speed: 1.2,     // Covered by camera_speed slider
             zoom_speed: 0.9,       // Covered by zoom_speed slider
+            edge_scrolling: false, // Covered by edge_scrolling toggle
+            edge_pan_zone: 24.0,   // Covered by edge_pan_zone slider
+            invert_pan: true,      // Covered by invert_pan toggle
+            zoom_to_cursor: false, // Covered by zoom_to_cursor toggle
+            touchpad_pan: true,    // Covered by touchpad_pan toggle
+            gamepad_deadzone: 0.2, // Covered by gamepad_deadzone slider
         };
 
         // The declarative version covers ALL ControlSettings fields!
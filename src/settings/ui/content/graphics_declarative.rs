@@ -16,11 +16,30 @@ define_setting_tab!(GraphicsTabDeclarative {
 
         Section("Rendering Quality") {
             slider: "Render Scale" => render_scale (0.5..2.0, Percentage),
-            cycle: "Shadow Quality" => shadow_quality
+            cycle: "Shadow Quality" => shadow_quality,
+            toggle: "Anti-Aliasing (MSAA)" => msaa
+        },
+
+        Section("Map Effects") {
+            toggle: "Day/Night Cycle" => day_night_cycle,
+            toggle: "Seasonal Snow" => seasonal_effects,
+            toggle: "Clouds" => clouds,
+            cycle: "Border Quality" => border_quality,
+            slider: "Label Density" => label_density (0.5..2.0, Percentage),
+            slider: "Overlay Updates per Second" => overlay_refresh_rate (1.0..30.0, Integer)
+        },
+
+        Section("Background") {
+            cycle: "When Unfocused" => background_mode,
+            slider: "Background Frame Rate" => background_fps (1.0..30.0, Integer)
         },
 
         Section("Graphics Presets") {
             presets: [Low, Medium, High, Ultra]
+        },
+
+        Section("Device") {
+            cycle: "Device Preset" => device_preset
         }
     ]
 });
@@ -18,12 +18,28 @@ define_setting_tab!(InterfaceTabDeclarative {
         Section("Display Options") {
             slider: "UI Scale" => ui_scale (0.75..1.5, Percentage),
             toggle: "Show FPS" => show_fps,
-            toggle: "Show Province Info" => show_province_info
+            toggle: "Show Province Info" => show_province_info,
+            slider: "Safe Area Margin" => safe_area (0.0..0.1, Percentage),
+            toggle: "Spread Panels on Ultrawide" => ultrawide_spread,
+            toggle: "Era and Nation Theming" => themed_ui
         },
 
         Section("Tooltip Settings") {
             toggle: "Show Tooltips" => show_tooltips,
             slider: "Tooltip Delay" => tooltip_delay (0.0..2.0, Decimal(1))
+        },
+
+        Section("Accessibility") {
+            slider: "Font Size" => font_scale (0.75..2.0, Percentage),
+            toggle: "Reduced Motion" => reduced_motion,
+            toggle: "High Contrast" => high_contrast,
+            toggle: "Narration Feed" => narration_feed,
+            toggle: "Write Narration to File" => narration_file
+        },
+
+        Section("Saving") {
+            cycle: "Autosave Compression" => autosave_compression,
+            cycle: "Save Compression" => manual_save_compression
         }
     ]
 });
@@ -108,6 +124,7 @@ pub fn document_missing_features() {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::settings::types::SaveCompression;
     use bevy::prelude::info;
 
     #[test]
@@ -148,6 +165,17 @@ mod tests {
             show_province_info: false, // NEW - covered by show_province_info toggle
             tooltip_delay: 1.0,        // NEW - covered by tooltip_delay slider
             show_tooltips: true,       // Covered by show_tooltips toggle
+            font_scale: 1.25,          // Covered by font_scale slider
+            reduced_motion: true,      // Covered by reduced_motion toggle
+            high_contrast: false,      // Covered by high_contrast toggle
+            narration_feed: true,      // Covered by narration_feed toggle
+            narration_file: false,     // Covered by narration_file toggle
+            safe_area: 0.05,           // Covered by safe_area slider
+            ultrawide_spread: false,   // Covered by ultrawide_spread toggle
+            themed_ui: true,           // Covered by themed_ui toggle
+            language: "de".to_string(), // Set in the settings file; tables come from mods
+            autosave_compression: SaveCompression::Fast, // Covered by autosave_compression cycle
+            manual_save_compression: SaveCompression::Smallest, // Covered by manual_save_compression cycle
         };
 
         // The declarative version covers ALL InterfaceSettings fields!
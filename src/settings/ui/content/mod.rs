@@ -12,6 +12,7 @@ mod audio_declarative;
 mod controls_declarative;
 mod graphics_declarative;
 mod interface_declarative;
+mod mods;
 mod performance;
 
 // CONTROLLED EXPORTS - Generated spawning functions from macros
@@ -19,6 +20,7 @@ pub use audio_declarative::spawn_audiotabdeclarative_content;
 pub use controls_declarative::spawn_controlstabdeclarative_content;
 pub use graphics_declarative::spawn_graphicstabdeclarative_content;
 pub use interface_declarative::spawn_interfacetabdeclarative_content;
+pub use mods::spawn_mod_settings_content;
 pub use performance::spawn_performance_content;
 
 // CONTROLLED EXPORTS - Generated event handlers from macros
@@ -26,3 +28,4 @@ pub use audio_declarative::handle_audiotabdeclarative_interactions;
 pub use controls_declarative::handle_controlstabdeclarative_interactions;
 pub use graphics_declarative::handle_graphicstabdeclarative_interactions;
 pub use interface_declarative::handle_interfacetabdeclarative_interactions;
+pub use mods::handle_mod_setting_interactions;
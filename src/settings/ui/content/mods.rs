@@ -0,0 +1,169 @@
+//! Mod Settings Tab
+//!
+//! Pages for settings that mods declare, laid out the way define_setting_tab!
+//! lays out the built-in tabs, with controls keyed by mod id and setting key.
+
+use crate::modding::{ModSettingControl, ModSettingDefinition, ModSettingValue, ModSettings};
+use crate::settings::{components::ModSettingInput, types::TempGameSettings};
+use crate::ui::{colors, ButtonBuilder, ButtonStyle, Slider, SliderBuilder};
+use bevy::prelude::*;
+
+/// Spawns the page of the mod at `index` among the enabled mods with settings
+pub fn spawn_mod_settings_content(
+    parent: &mut ChildSpawnerCommands,
+    mod_settings: &ModSettings,
+    index: usize,
+    temp_settings: &TempGameSettings,
+) {
+    parent
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(20.0),
+                ..default()
+            },
+            BackgroundColor(Color::NONE),
+        ))
+        .with_children(|content| {
+            let Some(page) = mod_settings.pages.get(index) else {
+                content.spawn((
+                    Text::new("This mod is no longer enabled."),
+                    TextFont {
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(colors::TEXT_SECONDARY),
+                ));
+                return;
+            };
+
+            // Sections in the order the mod first names them
+            let mut sections: Vec<&str> = Vec::new();
+            for setting in &page.settings {
+                if !sections.contains(&setting.section.as_str()) {
+                    sections.push(&setting.section);
+                }
+            }
+
+            for section_name in sections {
+                content
+                    .spawn((
+                        Node {
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(15.0),
+                            margin: UiRect::bottom(Val::Px(25.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::NONE),
+                    ))
+                    .with_children(|section| {
+                        section.spawn((
+                            Text::new(section_name),
+                            TextFont {
+                                font_size: 20.0,
+                                ..default()
+                            },
+                            TextColor(colors::TEXT_PRIMARY),
+                        ));
+
+                        for setting in page.settings.iter().filter(|setting| setting.section == section_name) {
+                            let value = setting.resolve(temp_settings.0.mods.get(&page.mod_id, &setting.key));
+                            spawn_control(section, &page.mod_id, setting, value);
+                        }
+                    });
+            }
+        });
+}
+
+fn spawn_control(
+    section: &mut ChildSpawnerCommands,
+    mod_id: &str,
+    setting: &ModSettingDefinition,
+    value: ModSettingValue,
+) {
+    let input = ModSettingInput {
+        mod_id: mod_id.to_string(),
+        key: setting.key.clone(),
+    };
+    match (&setting.control, value) {
+        (ModSettingControl::Slider { min, max, .. }, ModSettingValue::Number(number)) => {
+            let slider = SliderBuilder::new(*min..*max)
+                .label(setting.label.clone())
+                .value(number)
+                .build_in(section);
+            section.commands().entity(slider).insert(input);
+        }
+        (ModSettingControl::Toggle { .. }, ModSettingValue::Toggle(enabled)) => {
+            crate::create_toggle_row!(section, setting.label.as_str(), enabled, marker: input);
+        }
+        (ModSettingControl::Dropdown { .. }, ModSettingValue::Choice(choice)) => {
+            section.spawn((
+                Text::new(setting.label.clone()),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(colors::TEXT_PRIMARY),
+            ));
+            let button = ButtonBuilder::new(format!("< {} >", choice))
+                .style(ButtonStyle::Secondary)
+                .build_in(section);
+            section.commands().entity(button).insert(input);
+        }
+        // resolve() always matches the value to the control
+        _ => {}
+    }
+}
+
+/// Edit the pending mod setting values as their controls are used
+pub fn handle_mod_setting_interactions(
+    mut temp_settings: ResMut<TempGameSettings>,
+    mod_settings: Res<ModSettings>,
+    buttons: Query<(&Interaction, &ModSettingInput, &Children), (Changed<Interaction>, With<Button>)>,
+    sliders: Query<(&Slider, &ModSettingInput), Changed<Slider>>,
+    mut text_query: Query<&mut Text>,
+) {
+    for (interaction, input, children) in &buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(setting) = mod_settings.definition(&input.mod_id, &input.key) else {
+            continue;
+        };
+        let current = setting.resolve(temp_settings.0.mods.get(&input.mod_id, &input.key));
+        let (next, label) = match (&setting.control, current) {
+            (ModSettingControl::Toggle { .. }, ModSettingValue::Toggle(enabled)) => {
+                let label = if enabled { "" } else { "✓" };
+                (ModSettingValue::Toggle(!enabled), label.to_string())
+            }
+            (ModSettingControl::Dropdown { options, .. }, ModSettingValue::Choice(choice)) => {
+                let index = options
+                    .iter()
+                    .position(|option| *option == choice)
+                    .map_or(0, |index| (index + 1) % options.len());
+                let Some(next) = options.get(index) else {
+                    continue;
+                };
+                (ModSettingValue::Choice(next.clone()), format!("< {} >", next))
+            }
+            _ => continue,
+        };
+        temp_settings.0.mods.set(&input.mod_id, &input.key, next);
+        for child in children.iter() {
+            if let Ok(mut text) = text_query.get_mut(child) {
+                text.0 = label.clone();
+            }
+        }
+    }
+
+    for (slider, input) in &sliders {
+        let Some(setting) = mod_settings.definition(&input.mod_id, &input.key) else {
+            continue;
+        };
+        // Sliders report a change when spawned; only a moved slider is an edit
+        let value = ModSettingValue::Number(slider.value);
+        if setting.resolve(temp_settings.0.mods.get(&input.mod_id, &input.key)) != value {
+            temp_settings.0.mods.set(&input.mod_id, &input.key, value);
+        }
+    }
+}
@@ -3,6 +3,7 @@
 //! Handles the event to spawn the settings menu.
 
 use crate::menus::SpawnSettingsMenuEvent;
+use crate::modding::ModSettings;
 use crate::settings::types::*;
 use crate::settings::ui::spawning::spawn_settings_menu;
 use crate::states::{CurrentSettingsTab};
@@ -16,6 +17,7 @@ pub fn handle_spawn_settings_menu(
     mut temp_settings: ResMut<TempGameSettings>,
     current_tab: Res<CurrentSettingsTab>,
     mut dirty_state: ResMut<SettingsDirtyState>,
+    mod_settings: Res<ModSettings>,
 ) {
     for _event in events.read() {
         info!("Spawning settings menu");
@@ -25,6 +27,7 @@ pub fn handle_spawn_settings_menu(
             &mut temp_settings,
             &current_tab,
             &mut dirty_state,
+            &mod_settings,
         );
     }
 }
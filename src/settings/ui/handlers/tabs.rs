@@ -4,6 +4,7 @@
 //! eliminating 20+ lines of repetitive button interaction boilerplate.
 
 use crate::settings::{components::*, ui::spawning::spawn_settings_menu, types::*};
+use crate::modding::ModSettings;
 use crate::states::CurrentSettingsTab;
 use crate::ui::define_ui_interactions;
 use bevy::prelude::*;
@@ -17,7 +18,8 @@ define_ui_interactions!(
         settings_root: Query<Entity, With<SettingsMenuRoot>>,
         settings: Res<GameSettings>,
         mut temp_settings: ResMut<TempGameSettings>,
-        mut dirty_state: ResMut<SettingsDirtyState>
+        mut dirty_state: ResMut<SettingsDirtyState>,
+        mod_settings: Res<ModSettings>
     ) => {
         tab => {
             debug!("Switching to tab: {:?}", tab);
@@ -34,6 +36,7 @@ define_ui_interactions!(
                 &mut *temp_settings,
                 &*current_tab,
                 &mut *dirty_state,
+                &*mod_settings,
             );
         }
     }
@@ -10,6 +10,7 @@ use bevy::window::{MonitorSelection, PresentMode, PrimaryWindow, VideoModeSelect
 pub fn apply_settings_changes(
     settings: Res<GameSettings>,
     mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut cameras: Query<&mut Msaa, With<Camera>>,
 ) {
     if !settings.is_changed() {
         return;
@@ -46,6 +47,14 @@ pub fn apply_settings_changes(
         };
     }
 
+    // Apply anti-aliasing to every camera
+    let msaa = if settings.graphics.msaa { Msaa::Sample4 } else { Msaa::Off };
+    for mut camera_msaa in &mut cameras {
+        if *camera_msaa != msaa {
+            *camera_msaa = msaa;
+        }
+    }
+
     // Apply audio settings
 
     // Log the audio settings for now
@@ -86,9 +95,18 @@ pub fn validate_settings(
 
     // Clamp all values to sensible ranges
     temp_settings.0.graphics.render_scale = temp_settings.0.graphics.render_scale.clamp(0.5, 2.0);
+    temp_settings.0.graphics.label_density = temp_settings.0.graphics.label_density.clamp(0.5, 2.0);
+    temp_settings.0.graphics.overlay_refresh_rate = temp_settings.0.graphics.overlay_refresh_rate.clamp(1.0, 30.0);
+    temp_settings.0.graphics.background_fps = temp_settings.0.graphics.background_fps.clamp(1.0, 30.0);
     temp_settings.0.audio.master_volume = temp_settings.0.audio.master_volume.clamp(0.0, 1.0);
     temp_settings.0.audio.sfx_volume = temp_settings.0.audio.sfx_volume.clamp(0.0, 1.0);
+    temp_settings.0.audio.ui_volume = temp_settings.0.audio.ui_volume.clamp(0.0, 1.0);
+    temp_settings.0.audio.ambient_volume = temp_settings.0.audio.ambient_volume.clamp(0.0, 1.0);
     temp_settings.0.interface.ui_scale = temp_settings.0.interface.ui_scale.clamp(0.75, 2.0);
+    temp_settings.0.interface.font_scale = temp_settings.0.interface.font_scale.clamp(0.75, 2.0);
+    temp_settings.0.interface.safe_area = temp_settings.0.interface.safe_area.clamp(0.0, 0.1);
     temp_settings.0.controls.camera_speed = temp_settings.0.controls.camera_speed.clamp(0.1, 5.0);
     temp_settings.0.controls.zoom_speed = temp_settings.0.controls.zoom_speed.clamp(0.1, 5.0);
+    temp_settings.0.controls.edge_pan_zone = temp_settings.0.controls.edge_pan_zone.clamp(2.0, 60.0);
+    temp_settings.0.controls.gamepad_deadzone = temp_settings.0.controls.gamepad_deadzone.clamp(0.05, 0.4);
 }
@@ -12,7 +12,9 @@ define_plugin!(SettingsUIPlugin {
         TempGameSettings,
         SettingsDirtyState,
         ResolutionConfirmation,
-        FocusedElement
+        FocusedElement,
+        crate::settings::device::DevicePresets,
+        crate::settings::device::ActiveDeviceProfile
     ],
 
     messages: [
@@ -24,7 +26,10 @@ define_plugin!(SettingsUIPlugin {
         crate::menus::SpawnSettingsMenuEvent
     ],
 
-    startup: [crate::settings::persistence::load_settings],
+    startup: [
+        crate::settings::persistence::load_settings,
+        crate::settings::device::load_device_presets
+    ],
 
     update: [
         // High-level handlers
@@ -49,9 +54,11 @@ define_plugin!(SettingsUIPlugin {
             super::content::handle_graphicstabdeclarative_interactions,
             super::content::handle_audiotabdeclarative_interactions,
             super::content::handle_interfacetabdeclarative_interactions,
-            super::content::handle_controlstabdeclarative_interactions
+            super::content::handle_controlstabdeclarative_interactions,
+            super::content::handle_mod_setting_interactions
         ),
         // Settings application system
-        super::handlers::apply_settings_changes
+        super::handlers::apply_settings_changes,
+        crate::settings::device::sync_device_profile
     ]
 });
\ No newline at end of file
@@ -4,6 +4,7 @@
 //! Orchestrates all components using the builder pattern.
 
 use super::{spawn_apply_cancel_buttons, spawn_tab_buttons};
+use crate::modding::ModSettings;
 use crate::settings::{components::*, types::*};
 use crate::states::{CurrentSettingsTab, SettingsTab};
 use crate::ui::{colors, ChildBuilder};
@@ -16,6 +17,7 @@ pub fn spawn_settings_menu(
     temp_settings: &mut TempGameSettings,
     current_tab: &CurrentSettingsTab,
     dirty_state: &mut SettingsDirtyState,
+    mod_settings: &ModSettings,
 ) {
     debug!("Spawning settings menu");
 
@@ -63,10 +65,10 @@ pub fn spawn_settings_menu(
                     spawn_title(panel);
 
                     // Tab buttons
-                    spawn_tab_buttons(panel, current_tab.0);
+                    spawn_tab_buttons(panel, current_tab.0, mod_settings);
 
                     // Tab content area
-                    spawn_content_area(panel, current_tab.0, &temp_settings.0);
+                    spawn_content_area(panel, current_tab.0, temp_settings, mod_settings);
 
                     // Apply/Cancel buttons
                     spawn_apply_cancel_buttons(panel);
@@ -100,7 +102,8 @@ fn spawn_title(parent: &mut ChildBuilder) {
 fn spawn_content_area(
     parent: &mut ChildBuilder,
     current_tab: SettingsTab,
-    temp_settings: &GameSettings,
+    temp_settings: &TempGameSettings,
+    mod_settings: &ModSettings,
 ) {
     parent
         .spawn((
@@ -119,19 +122,19 @@ fn spawn_content_area(
             SettingsTab::Graphics => {
                 crate::settings::ui::content::spawn_graphicstabdeclarative_content(
                     content,
-                    &temp_settings.graphics,
+                    &temp_settings.0.graphics,
                 )
             }
             SettingsTab::Audio => {
                 crate::settings::ui::content::spawn_audiotabdeclarative_content(
                     content,
-                    &temp_settings.audio,
+                    &temp_settings.0.audio,
                 )
             }
             SettingsTab::Interface => {
                 crate::settings::ui::content::spawn_interfacetabdeclarative_content(
                     content,
-                    &temp_settings.interface,
+                    &temp_settings.0.interface,
                 )
             }
             SettingsTab::Performance => {
@@ -140,8 +143,14 @@ fn spawn_content_area(
             SettingsTab::Controls => {
                 crate::settings::ui::content::spawn_controlstabdeclarative_content(
                     content,
-                    &temp_settings.controls,
+                    &temp_settings.0.controls,
                 )
             }
+            SettingsTab::Mod(index) => crate::settings::ui::content::spawn_mod_settings_content(
+                content,
+                mod_settings,
+                usize::from(index),
+                temp_settings,
+            ),
         });
 }
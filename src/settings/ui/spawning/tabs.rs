@@ -2,21 +2,24 @@
 //!
 //! Handles creation of the tab navigation buttons at the top of the settings menu.
 
+use crate::modding::ModSettings;
 use crate::settings::components::TabButton;
 use crate::states::SettingsTab;
 use crate::ui::{ButtonBuilder, ButtonSize, ButtonStyle, ChildBuilder};
 use bevy::prelude::{
-    BackgroundColor, Color, FlexDirection, Node, UiRect, Val, default,
+    BackgroundColor, Color, FlexDirection, FlexWrap, Node, UiRect, Val, default,
 };
 
-/// Spawns the tab buttons row
-pub fn spawn_tab_buttons(parent: &mut ChildBuilder, current_tab: SettingsTab) {
+/// Spawns the tab buttons row, with a tab for each enabled mod that has settings
+pub fn spawn_tab_buttons(parent: &mut ChildBuilder, current_tab: SettingsTab, mod_settings: &ModSettings) {
     parent
         .spawn((
             Node {
                 flex_direction: FlexDirection::Row,
+                flex_wrap: FlexWrap::Wrap,
                 margin: UiRect::bottom(Val::Px(20.0)),
                 column_gap: Val::Px(10.0),
+                row_gap: Val::Px(10.0),
                 ..default()
             },
             BackgroundColor(Color::NONE),
@@ -30,6 +33,11 @@ pub fn spawn_tab_buttons(parent: &mut ChildBuilder, current_tab: SettingsTab) {
             create_tab_button(tabs, "Interface", SettingsTab::Interface, current_tab);
             // Controls tab button
             create_tab_button(tabs, "Controls", SettingsTab::Controls, current_tab);
+            // Mod tab buttons
+            for (index, page) in mod_settings.pages.iter().enumerate() {
+                let tab = SettingsTab::Mod(index as u16);
+                create_tab_button(tabs, &page.name, tab, current_tab);
+            }
         });
 }
 
@@ -0,0 +1,112 @@
+//! Headless simulation driver
+//!
+//! Wraps a Bevy `App` built by [`MiniWorld`](super::MiniWorld) with helpers
+//! for wiring the systems under test, advancing ticks, and reading back state.
+
+use bevy::ecs::message::Messages;
+use bevy::ecs::system::ScheduleSystem;
+use bevy::prelude::*;
+
+use crate::relationships::{ControlledBy, Controls};
+use crate::simulation::{GameTick, GameTime};
+
+/// A miniature world running headless, advanced one tick per app update
+pub struct SimHarness {
+    app: App,
+    columns: u32,
+    provinces: Vec<Entity>,
+    nations: Vec<Entity>,
+}
+
+impl SimHarness {
+    pub(super) fn new(app: App, columns: u32, provinces: Vec<Entity>, nations: Vec<Entity>) -> Self {
+        Self {
+            app,
+            columns,
+            provinces,
+            nations,
+        }
+    }
+
+    /// Register a message type the systems under test read or write
+    pub fn with_message<M: Message>(mut self) -> Self {
+        self.app.add_message::<M>();
+        self
+    }
+
+    /// Add systems to the `Update` schedule, without the game's state run conditions
+    pub fn with_systems<M>(mut self, systems: impl IntoScheduleConfigs<ScheduleSystem, M>) -> Self {
+        self.app.add_systems(Update, systems);
+        self
+    }
+
+    /// Advance the simulation by `ticks`, one game day per tick
+    pub fn tick(&mut self, ticks: u32) {
+        for _ in 0..ticks {
+            if let Some(mut time) = self.app.world_mut().get_resource_mut::<GameTime>() {
+                time.advance_ticks(GameTick::TICKS_PER_DAY);
+            }
+            self.app.update();
+        }
+    }
+
+    /// Send a message to be read on the next tick
+    pub fn send<M: Message>(&mut self, message: M) {
+        self.app.world_mut().write_message(message);
+    }
+
+    /// Messages of a type written during the most recent tick
+    pub fn messages<M: Message + Clone>(&self) -> Vec<M> {
+        self.app
+            .world()
+            .get_resource::<Messages<M>>()
+            .map(|messages| messages.iter_current_update_messages().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn world(&self) -> &World {
+        self.app.world()
+    }
+
+    pub fn world_mut(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+
+    /// Nation spawned `index`-th (band order, left to right)
+    pub fn nation(&self, index: usize) -> Entity {
+        self.nations[index]
+    }
+
+    pub fn nations(&self) -> &[Entity] {
+        &self.nations
+    }
+
+    /// Province entity at a grid position
+    pub fn province(&self, col: u32, row: u32) -> Entity {
+        self.provinces[(row * self.columns + col) as usize]
+    }
+
+    pub fn provinces(&self) -> &[Entity] {
+        &self.provinces
+    }
+
+    /// Current owner according to the `ControlledBy` relationship
+    pub fn owner_of(&self, province: Entity) -> Option<Entity> {
+        self.world().get::<ControlledBy>(province).map(|controlled| controlled.0)
+    }
+
+    /// Number of provinces a nation controls
+    pub fn province_count(&self, nation: Entity) -> usize {
+        self.world()
+            .get::<Controls>(nation)
+            .map_or(0, |controls| controls.province_count())
+    }
+
+    /// Component on an entity, panicking with context if it is missing
+    pub fn get<T: Component>(&self, entity: Entity) -> &T {
+        match self.world().get::<T>(entity) {
+            Some(component) => component,
+            None => panic!("{:?} has no {}", entity, std::any::type_name::<T>()),
+        }
+    }
+}
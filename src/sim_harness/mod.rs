@@ -0,0 +1,150 @@
+//! Headless simulation harness for behavioral tests (gateway module)
+//!
+//! Where `test_utils` offers loose helpers, this builds a complete miniature
+//! world - provinces with neighbors and owners, nations, storage, and entity
+//! order - so a system can be run for N ticks and judged by its outcome:
+//!
+//! ```ignore
+//! let mut sim = MiniWorld::new(7)
+//!     .grid(10, 10)
+//!     .nations(2)
+//!     .build()
+//!     .with_message::<ProvinceTransferRequest>()
+//!     .with_message::<TerritoryOwnershipChanged>()
+//!     .with_systems(apply_province_transfers);
+//! sim.tick(1);
+//! ```
+
+#![cfg(test)]
+
+// Private modules - gateway architecture
+mod harness;
+mod world;
+
+pub use harness::SimHarness;
+pub use world::MiniWorld;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nations::{
+        apply_province_transfers, initialize_city_names, process_battle_events, Attacking, BattleEvent,
+        CasusBelli, CityNames, NationHistory, OwnershipChangeType, ProvinceTransferRequest,
+        TerritoryOwnershipChanged, War, WarGoal,
+    };
+    use crate::simulation::NewYearEvent;
+    use crate::world::{Agriculture, ProvinceStorage};
+    use crate::world_report::{record_market_prices, Good, WorldStatistics};
+
+    #[test]
+    fn mini_world_splits_grid_between_nations() {
+        let sim = MiniWorld::new(1).grid(10, 10).nations(2).build();
+
+        assert_eq!(sim.provinces().len(), 100);
+        assert_eq!(sim.province_count(sim.nation(0)), 50);
+        assert_eq!(sim.owner_of(sim.province(0, 0)), Some(sim.nation(0)));
+        assert_eq!(sim.owner_of(sim.province(9, 9)), Some(sim.nation(1)));
+    }
+
+    #[test]
+    fn ownership_transfer_updates_every_representation() {
+        let mut sim = MiniWorld::new(2)
+            .build()
+            .with_message::<ProvinceTransferRequest>()
+            .with_message::<TerritoryOwnershipChanged>()
+            .with_systems(apply_province_transfers);
+        let (west, east) = (sim.nation(0), sim.nation(1));
+        let border = vec![sim.province(4, 0), sim.province(4, 1)];
+
+        sim.send(ProvinceTransferRequest {
+            provinces: border.clone(),
+            new_owner: Some(east),
+            change_type: OwnershipChangeType::Conquest,
+        });
+        sim.tick(1);
+
+        assert!(border.iter().all(|&p| sim.owner_of(p) == Some(east)));
+        assert_eq!(sim.province_count(west), 48);
+        let storage = sim.world().resource::<ProvinceStorage>();
+        assert_eq!(storage.provinces[4].owner_entity, Some(east));
+        assert_eq!(sim.get::<NationHistory>(east).provinces_gained, 2);
+        assert_eq!(sim.get::<NationHistory>(west).provinces_lost, 2);
+
+        let announced = sim.messages::<TerritoryOwnershipChanged>();
+        assert_eq!(announced.len(), 2);
+        assert!(announced.iter().any(|m| m.nation_entity == west && m.provinces == border));
+    }
+
+    #[test]
+    fn battles_update_war_and_both_histories() {
+        let mut sim = MiniWorld::new(3)
+            .build()
+            .with_message::<BattleEvent>()
+            .with_systems(process_battle_events);
+        let (attacker, defender) = (sim.nation(0), sim.nation(1));
+        let war = sim
+            .world_mut()
+            .spawn(War {
+                war_id: 1,
+                war_goal: WarGoal::Subjugation,
+                casus_belli: CasusBelli::BorderDispute,
+                start_year: 0,
+                war_score: 0.0,
+                battles_fought: 0,
+                casualties: 0.0,
+            })
+            .id();
+        sim.world_mut().entity_mut(attacker).insert(Attacking(defender));
+
+        for _ in 0..5 {
+            sim.send(BattleEvent {
+                war_id: 1,
+                attacker,
+                defender,
+            });
+            sim.tick(1);
+        }
+
+        assert_eq!(sim.get::<War>(war).battles_fought, 5);
+        let attacker_history = sim.get::<NationHistory>(attacker);
+        let defender_history = sim.get::<NationHistory>(defender);
+        assert_eq!(attacker_history.total_victories + attacker_history.total_defeats, 5);
+        assert_eq!(attacker_history.total_victories, defender_history.total_defeats);
+    }
+
+    /// Set every province's fertility, run `years` of markets, and return the closing prices
+    fn run_market_years(sim: &mut SimHarness, first_year: u32, years: u32, fertility: f32) -> (f32, f32) {
+        for province in &mut sim.world_mut().resource_mut::<ProvinceStorage>().provinces {
+            province.agriculture = Agriculture::new(fertility);
+        }
+        for year in first_year..first_year + years {
+            sim.send(NewYearEvent { year });
+            sim.tick(1);
+        }
+        let statistics = sim.world().resource::<WorldStatistics>();
+        let close = |good| statistics.markets.values().find_map(|market| market.last_close(good));
+        (close(Good::Grain).unwrap_or_default(), close(Good::Iron).unwrap_or_default())
+    }
+
+    #[test]
+    fn market_prices_clear_where_supply_meets_demand() {
+        let mut sim = MiniWorld::new(4)
+            .build()
+            .with_message::<NewYearEvent>()
+            .with_systems((initialize_city_names, record_market_prices).chain());
+        sim.world_mut().init_resource::<CityNames>();
+        sim.world_mut().init_resource::<WorldStatistics>();
+        // One great city draws the whole grid into its market
+        sim.world_mut().resource_mut::<ProvinceStorage>().provinces[0].population = 20_000;
+
+        // Fields feed exactly what people eat; nobody mines iron
+        let (grain, iron) = run_market_years(&mut sim, 1, 20, 1.5);
+        assert_eq!(sim.world().resource::<WorldStatistics>().markets.len(), 1);
+        assert!((grain - Good::Grain.base_price()).abs() < Good::Grain.base_price() * 0.15);
+        assert!(iron > Good::Iron.base_price() * 5.0);
+
+        // A glut of grain drives its price below the base
+        let (grain, _) = run_market_years(&mut sim, 21, 20, 3.0);
+        assert!(grain < Good::Grain.base_price() * 0.8);
+    }
+}
@@ -0,0 +1,159 @@
+//! Miniature world construction
+//!
+//! Builds a small odd-q hex grid of grassland provinces split into vertical
+//! bands, one per nation. Everything is derived from the seed and grid size,
+//! so two harnesses built with the same parameters start identical.
+
+use bevy::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use super::harness::SimHarness;
+use crate::math::{calculate_grid_position, get_neighbor_positions, Money, HEX_SIZE};
+use crate::nations::{
+    Economy, Nation, NationBundle, NationHistory, NationId, NationIndex, NationLaws,
+    NationPersonality, OwnsTerritory,
+};
+use crate::relationships::ControlledBy;
+use crate::simulation::{GameTime, PressureVector};
+use crate::world::{
+    CachedOverlayColors, MapMode, Province, ProvinceBundle, ProvinceEntityOrder, ProvinceId,
+    ProvinceGraph, ProvinceNeighbors, ProvinceStorage,
+};
+
+/// Builder for a headless miniature world
+#[derive(Debug, Clone)]
+pub struct MiniWorld {
+    seed: u64,
+    columns: u32,
+    rows: u32,
+    nations: u32,
+}
+
+impl Default for MiniWorld {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            columns: 10,
+            rows: 10,
+            nations: 2,
+        }
+    }
+}
+
+impl MiniWorld {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            ..Default::default()
+        }
+    }
+
+    pub fn grid(mut self, columns: u32, rows: u32) -> Self {
+        self.columns = columns.max(1);
+        self.rows = rows.max(1);
+        self
+    }
+
+    pub fn nations(mut self, nations: u32) -> Self {
+        self.nations = nations;
+        self
+    }
+
+    /// Spawn the world into a fresh headless app
+    pub fn build(self) -> SimHarness {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(GameTime::default())
+            .init_resource::<NationIndex>()
+            .init_resource::<CachedOverlayColors>()
+            .init_resource::<MapMode>();
+
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed);
+        let nations: Vec<Entity> = (0..self.nations)
+            .map(|idx| {
+                // Capital sits in the top row of the nation's band
+                let capital = ProvinceId::new(idx * self.columns / self.nations);
+                spawn_nation(app.world_mut(), idx, capital, &mut rng)
+            })
+            .collect();
+
+        let index_of = |col: i32, row: i32| -> Option<usize> {
+            (col >= 0 && row >= 0 && (col as u32) < self.columns && (row as u32) < self.rows)
+                .then(|| (row as u32 * self.columns + col as u32) as usize)
+        };
+
+        let mut provinces = Vec::with_capacity((self.columns * self.rows) as usize);
+        for row in 0..self.rows {
+            for col in 0..self.columns {
+                let id = ProvinceId::new(row * self.columns + col);
+                let position = calculate_grid_position(col, row, HEX_SIZE, self.columns, self.rows);
+                let mut province = Province::new(id, position);
+                province.population = rng.gen_range(1_000..5_000);
+                let neighbor_indices =
+                    get_neighbor_positions(col as i32, row as i32, HEX_SIZE).map(|(c, r)| index_of(c, r));
+                province.neighbor_indices = neighbor_indices;
+                province.neighbors = neighbor_indices.map(|idx| idx.map(|i| ProvinceId::new(i as u32)));
+                if !nations.is_empty() {
+                    let band = (col * nations.len() as u32 / self.columns) as usize;
+                    province.owner_entity = nations.get(band).copied();
+                }
+                provinces.push(province);
+            }
+        }
+
+        let world = app.world_mut();
+        let entities: Vec<Entity> = world
+            .spawn_batch(provinces.iter().map(ProvinceBundle::from_province).collect::<Vec<_>>())
+            .collect();
+        for (province, &entity) in provinces.iter().zip(&entities) {
+            let neighbors = province
+                .neighbor_indices
+                .map(|idx| idx.and_then(|i| entities.get(i).copied()));
+            let mut entity_mut = world.entity_mut(entity);
+            entity_mut.insert(ProvinceNeighbors::new(neighbors));
+            if let Some(owner) = province.owner_entity {
+                entity_mut.insert(ControlledBy(owner));
+            }
+        }
+
+        world.insert_resource(ProvinceEntityOrder::new(entities.clone()));
+        world.insert_resource(ProvinceGraph::build(&provinces));
+        world.insert_resource(ProvinceStorage::from_provinces(provinces));
+
+        SimHarness::new(app, self.columns, entities, nations)
+    }
+}
+
+fn spawn_nation(world: &mut World, idx: u32, capital: ProvinceId, rng: &mut ChaCha8Rng) -> Entity {
+    let name = format!("Testland {}", idx + 1);
+    let nation = Nation {
+        name: name.clone(),
+        adjective: format!("{}ian", name),
+        color: Color::srgb(rng.r#gen(), rng.r#gen(), rng.r#gen()),
+        capital_province: capital,
+        treasury: Money::from_int(1000),
+        tax_rate: 0.2,
+        military_strength: 100.0,
+        stability: 0.75,
+        culture: crate::name_generator::Culture::Western,
+        technology_level: 1,
+        personality: NationPersonality::balanced(),
+    };
+
+    world
+        .spawn((
+            NationBundle {
+                nation,
+                economy: Economy::default(),
+                transform: Transform::default(),
+                visibility: Visibility::default(),
+                pressure_vector: PressureVector::default(),
+                history: NationHistory::default(),
+                laws: NationLaws::default(),
+            },
+            OwnsTerritory::default(),
+            NationId::new(idx),
+        ))
+        .id()
+}
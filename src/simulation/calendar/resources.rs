@@ -44,7 +44,8 @@ impl CalendarRegistry {
 }
 
 /// Per-nation calendar selection (for future multi-calendar support)
-#[derive(Component, Clone)]
+#[derive(Component, Clone, Reflect)]
+#[reflect(Component)]
 pub struct NationCalendar {
     /// Calendar ID this nation uses
     pub calendar_id: String,
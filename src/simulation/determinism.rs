@@ -0,0 +1,549 @@
+//! Simulation hashing and determinism verification
+//!
+//! A seed must reproduce the same world no matter how many worker threads
+//! generated and simulated it. [`SimulationHash`] fingerprints the state that
+//! matters phase by phase - terrain, nations, ownership, wars - so two runs
+//! can be compared year by year and a mismatch pinned to the first phase that
+//! drifted. [`DeterminismCheck`] runs a seed twice on differently sized
+//! thread pools and does exactly that, each run in a process of its own when
+//! given the game's executable.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use super::time::{GameTick, GameTime, NewYearEvent};
+use crate::ai::AiBehavior;
+use crate::app::build_headless_app_on_threads;
+use crate::cli::generate_world_save;
+use crate::nations::{Nation, NationId, NationIndex, War};
+use crate::resources::{MapDimensions, WorldSize};
+use crate::save_load::{load_save_data, AutoSaveTimer};
+use crate::states::GameState;
+use crate::world::{Province, ProvinceStorage};
+
+/// Frames each simulated year is spread over
+///
+/// Enough fixed-length frames that the frame-timed systems, such as the
+/// two-second pressure timer, still fire during a year; divides the year's
+/// ticks evenly.
+const FRAMES_PER_YEAR: u64 = 146;
+
+/// Frames a world may take to load before the run is abandoned
+const MAX_LOADING_FRAMES: u32 = 10_000;
+
+/// Marks the lines of a child run's output that carry a yearly hash
+const HASH_LINE_PREFIX: &str = "simulation-hash ";
+
+/// FNV-1a hasher, stable across builds and platforms unlike `DefaultHasher`
+#[derive(Debug, Clone, Copy)]
+pub struct StableHasher(u64);
+
+impl StableHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    pub fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    /// Hash a float by its exact bit pattern
+    pub fn write_f32_bits(&mut self, value: f32) {
+        self.write_u32(value.to_bits());
+    }
+}
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hasher for StableHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Slice of simulation state hashed separately so divergence can be located
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeterminismPhase {
+    Terrain,
+    Nations,
+    Ownership,
+    Wars,
+}
+
+impl DeterminismPhase {
+    pub const ALL: [DeterminismPhase; 4] = [
+        DeterminismPhase::Terrain,
+        DeterminismPhase::Nations,
+        DeterminismPhase::Ownership,
+        DeterminismPhase::Wars,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DeterminismPhase::Terrain => "terrain",
+            DeterminismPhase::Nations => "nations",
+            DeterminismPhase::Ownership => "ownership",
+            DeterminismPhase::Wars => "wars",
+        }
+    }
+}
+
+impl std::fmt::Display for DeterminismPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Per-phase fingerprint of the simulation at the start of a year
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SimulationHash {
+    pub year: u32,
+    phases: [u64; DeterminismPhase::ALL.len()],
+}
+
+impl SimulationHash {
+    /// Hash the given state; iteration order of `nations` and `wars` does not matter
+    pub fn capture<'a>(
+        year: u32,
+        provinces: &[Province],
+        owners: impl Iterator<Item = Option<NationId>>,
+        nations: impl Iterator<Item = (NationId, &'a Nation)>,
+        wars: impl Iterator<Item = &'a War>,
+    ) -> Self {
+        let mut terrain = StableHasher::new();
+        for province in provinces {
+            province.id.value().hash(&mut terrain);
+            province.terrain.hash(&mut terrain);
+            terrain.write_f32_bits(province.elevation.value());
+            province.population.hash(&mut terrain);
+            province.max_population.hash(&mut terrain);
+        }
+
+        let mut ownership = StableHasher::new();
+        for owner in owners {
+            owner.hash(&mut ownership);
+        }
+
+        let mut nations: Vec<_> = nations.collect();
+        nations.sort_by_key(|(id, _)| id.value());
+        let mut nation_hasher = StableHasher::new();
+        for (id, nation) in nations {
+            id.hash(&mut nation_hasher);
+            nation.name.hash(&mut nation_hasher);
+            nation.capital_province.value().hash(&mut nation_hasher);
+            nation.treasury.hash(&mut nation_hasher);
+            nation_hasher.write_f32_bits(nation.tax_rate);
+            nation_hasher.write_f32_bits(nation.military_strength);
+            nation_hasher.write_f32_bits(nation.stability);
+            nation.technology_level.hash(&mut nation_hasher);
+        }
+
+        let mut wars: Vec<_> = wars.collect();
+        wars.sort_by_key(|war| war.war_id);
+        let mut war_hasher = StableHasher::new();
+        for war in wars {
+            war.war_id.hash(&mut war_hasher);
+            war.start_year.hash(&mut war_hasher);
+            war_hasher.write_f32_bits(war.war_score);
+            war.battles_fought.hash(&mut war_hasher);
+        }
+
+        Self {
+            year,
+            phases: [
+                terrain.finish(),
+                nation_hasher.finish(),
+                ownership.finish(),
+                war_hasher.finish(),
+            ],
+        }
+    }
+
+    pub fn phase(&self, phase: DeterminismPhase) -> u64 {
+        self.phases[phase as usize]
+    }
+
+    /// Single value covering every phase
+    pub fn combined(&self) -> u64 {
+        let mut hasher = StableHasher::new();
+        self.year.hash(&mut hasher);
+        self.phases.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// The line a child run writes to report this hash
+    pub fn to_line(&self) -> String {
+        let [terrain, nations, ownership, wars] = self.phases;
+        format!(
+            "{}{} {:016x} {:016x} {:016x} {:016x}",
+            HASH_LINE_PREFIX, self.year, terrain, nations, ownership, wars
+        )
+    }
+
+    /// Read a line written by [`to_line`](Self::to_line); `None` for any other line
+    pub fn from_line(line: &str) -> Option<Self> {
+        let mut fields = line.strip_prefix(HASH_LINE_PREFIX)?.split_whitespace();
+        let year = fields.next()?.parse().ok()?;
+        let mut phases = [0; DeterminismPhase::ALL.len()];
+        for phase in &mut phases {
+            *phase = u64::from_str_radix(fields.next()?, 16).ok()?;
+        }
+        if fields.next().is_some() {
+            return None;
+        }
+        Some(Self { year, phases })
+    }
+
+    /// First phase (in [`DeterminismPhase::ALL`] order) whose hash differs
+    pub fn first_divergent_phase(&self, other: &SimulationHash) -> Option<DeterminismPhase> {
+        DeterminismPhase::ALL
+            .into_iter()
+            .find(|&phase| self.phase(phase) != other.phase(phase))
+    }
+}
+
+/// Yearly hashes recorded while the log resource exists
+#[derive(Resource, Debug, Clone, Default)]
+pub struct SimulationHashLog {
+    pub years: Vec<SimulationHash>,
+}
+
+/// Where two runs of the same seed first disagreed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Divergence {
+    /// A year both runs reached hashed differently
+    Phase { year: u32, phase: DeterminismPhase },
+    /// Every shared year matched, but one run recorded fewer years
+    Length { first_years: usize, second_years: usize },
+}
+
+impl std::fmt::Display for Divergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Divergence::Phase { year, phase } => write!(f, "year {} diverged first in {}", year, phase),
+            Divergence::Length {
+                first_years,
+                second_years,
+            } => write!(f, "one run recorded {} years, the other {}", first_years, second_years),
+        }
+    }
+}
+
+/// Compare two hash logs year by year, returning the number of matching years
+pub fn compare_hash_logs(first: &[SimulationHash], second: &[SimulationHash]) -> Result<usize, Divergence> {
+    for (a, b) in first.iter().zip(second) {
+        if let Some(phase) = a.first_divergent_phase(b) {
+            return Err(Divergence::Phase { year: a.year, phase });
+        }
+    }
+
+    // One run stopping early is a divergence in its own right
+    if first.len() != second.len() {
+        return Err(Divergence::Length {
+            first_years: first.len(),
+            second_years: second.len(),
+        });
+    }
+
+    Ok(first.len())
+}
+
+/// Record a [`SimulationHash`] whenever a new year begins
+pub fn record_simulation_hash(
+    mut year_events: MessageReader<NewYearEvent>,
+    province_storage: Option<Res<ProvinceStorage>>,
+    nation_index: Res<NationIndex>,
+    nations_query: Query<(&NationId, &Nation)>,
+    wars_query: Query<&War>,
+    mut log: ResMut<SimulationHashLog>,
+) {
+    let Some(event) = year_events.read().last() else {
+        return;
+    };
+    let provinces = province_storage
+        .as_ref()
+        .map_or(&[][..], |storage| storage.provinces.as_slice());
+
+    log.years.push(SimulationHash::capture(
+        event.year,
+        provinces,
+        provinces
+            .iter()
+            .map(|province| province.owner_entity.and_then(|owner| nation_index.id(owner))),
+        nations_query.iter().map(|(id, nation)| (*id, nation)),
+        wars_query.iter(),
+    ));
+}
+
+/// Errors that stop a determinism check from producing a verdict
+#[derive(Debug, thiserror::Error)]
+pub enum DeterminismError {
+    #[error("Failed to build a {threads}-thread pool: {reason}")]
+    ThreadPool { threads: usize, reason: String },
+
+    #[error("The {threads}-thread run failed: {reason}")]
+    Process { threads: usize, reason: String },
+
+    #[error("World generation failed: {0}")]
+    WorldGeneration(String),
+
+    #[error("Generated world did not finish loading within {0} frames")]
+    Loading(u32),
+
+    #[error("Runs with {first_threads} and {second_threads} threads differ: {divergence}")]
+    Diverged {
+        first_threads: usize,
+        second_threads: usize,
+        divergence: Divergence,
+    },
+}
+
+/// Outcome of a passing determinism check
+#[derive(Debug, Clone, Copy)]
+pub struct DeterminismReport {
+    pub years_compared: usize,
+    pub final_hash: u64,
+}
+
+/// Run a seed twice with different thread counts and compare each year
+///
+/// Both runs generate the world and nations on their own rayon pool, load
+/// them into a headless app with every simulation plugin, and step it on
+/// fixed-length frames, hashing every year.
+///
+/// Bevy's task pools are created once per process, so two runs in one
+/// process share the first run's pools. Only with
+/// [`in_child_processes`](Self::in_child_processes) does each run get task
+/// pools of its own size.
+#[derive(Debug, Clone)]
+pub struct DeterminismCheck {
+    seed: u32,
+    world_size: WorldSize,
+    /// Provinces per row and column, overriding the world size's grid
+    grid: Option<(u32, u32)>,
+    years: u32,
+    thread_counts: (usize, usize),
+    /// Game executable each run is started in, if not run in this process
+    executable: Option<PathBuf>,
+}
+
+impl DeterminismCheck {
+    pub fn new(seed: u32) -> Self {
+        Self {
+            seed,
+            world_size: WorldSize::Small,
+            grid: None,
+            years: 10,
+            thread_counts: (1, 4),
+            executable: None,
+        }
+    }
+
+    pub fn world_size(mut self, world_size: WorldSize) -> Self {
+        self.world_size = world_size;
+        self
+    }
+
+    /// Generate a map of this many provinces per row and column instead
+    pub fn grid(mut self, columns: u32, rows: u32) -> Self {
+        self.grid = Some((columns.max(1), rows.max(1)));
+        self
+    }
+
+    pub fn years(mut self, years: u32) -> Self {
+        self.years = years;
+        self
+    }
+
+    pub fn thread_counts(mut self, first: usize, second: usize) -> Self {
+        self.thread_counts = (first.max(1), second.max(1));
+        self
+    }
+
+    /// Start each run in a fresh process of the game's `executable`
+    ///
+    /// The child reruns this check's seed and years on the default world
+    /// with `--verify-determinism --determinism-side` and reports its hashes
+    /// on stdout; the world size and grid set here are not passed on.
+    pub fn in_child_processes(mut self, executable: PathBuf) -> Self {
+        self.executable = Some(executable);
+        self
+    }
+
+    pub fn run(&self) -> Result<DeterminismReport, DeterminismError> {
+        let (first_threads, second_threads) = self.thread_counts;
+        let first = self.run_side(first_threads)?;
+        let second = self.run_side(second_threads)?;
+
+        let years_compared = compare_hash_logs(&first, &second).map_err(|divergence| {
+            DeterminismError::Diverged {
+                first_threads,
+                second_threads,
+                divergence,
+            }
+        })?;
+
+        Ok(DeterminismReport {
+            years_compared,
+            final_hash: first.last().map_or(0, SimulationHash::combined),
+        })
+    }
+
+    fn run_side(&self, threads: usize) -> Result<Vec<SimulationHash>, DeterminismError> {
+        match &self.executable {
+            Some(executable) => self.run_in_child(executable, threads),
+            None => self.run_with_threads(threads),
+        }
+    }
+
+    /// One complete run in a child process, read back from its output
+    fn run_in_child(&self, executable: &Path, threads: usize) -> Result<Vec<SimulationHash>, DeterminismError> {
+        let failed = |reason: String| DeterminismError::Process { threads, reason };
+        let output = Command::new(executable)
+            .args(["--verify-determinism", "--determinism-side"])
+            .args(["--determinism-seed", &self.seed.to_string()])
+            .args(["--determinism-years", &self.years.to_string()])
+            .args(["--threads", &threads.to_string()])
+            .stderr(Stdio::inherit())
+            .output()
+            .map_err(|e| failed(e.to_string()))?;
+        if !output.status.success() {
+            return Err(failed(format!("exited with {}", output.status)));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(SimulationHash::from_line)
+            .collect())
+    }
+
+    /// One complete run in this process on `threads` workers
+    ///
+    /// Rayon gets a dedicated pool of that size. Bevy's task pools only take
+    /// the size if this is the first app the process builds.
+    pub fn run_with_threads(&self, threads: usize) -> Result<Vec<SimulationHash>, DeterminismError> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .map_err(|e| DeterminismError::ThreadPool {
+                threads,
+                reason: e.to_string(),
+            })?;
+
+        info!("Determinism run: seed {} on {} threads", self.seed, threads);
+        pool.install(|| self.simulate(threads))
+    }
+
+    fn simulate(&self, threads: usize) -> Result<Vec<SimulationHash>, DeterminismError> {
+        let dimensions = match self.grid {
+            Some((columns, rows)) => MapDimensions::from_grid(columns, rows),
+            None => MapDimensions::from_world_size(&self.world_size),
+        };
+        let save_data = generate_world_save(
+            format!("Determinism {}", self.seed),
+            self.seed,
+            self.world_size,
+            dimensions,
+            AiBehavior::default(),
+        )
+        .map_err(|e| DeterminismError::WorldGeneration(e.to_string()))?;
+
+        // Fixed-length frames so timers and the fixed clock advance identically every run
+        let mut app = build_headless_app_on_threads(threads);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Time::<Fixed>::default().timestep()))
+            .init_resource::<SimulationHashLog>()
+            .add_systems(PostUpdate, record_simulation_hash);
+        if let Some(mut auto_save) = app.world_mut().get_resource_mut::<AutoSaveTimer>() {
+            auto_save.enabled = false;
+        }
+        load_save_data(app.world_mut(), save_data);
+
+        let mut loading_frames = 0;
+        while app.world().get_resource::<State<GameState>>().map(|state| *state.get()) != Some(GameState::InGame) {
+            if loading_frames >= MAX_LOADING_FRAMES {
+                return Err(DeterminismError::Loading(MAX_LOADING_FRAMES));
+            }
+            app.update();
+            loading_frames += 1;
+        }
+
+        // The clock is driven by hand from here, a slice of the year each frame
+        for _ in 0..self.years {
+            for _ in 0..FRAMES_PER_YEAR {
+                if let Some(mut time) = app.world_mut().get_resource_mut::<GameTime>() {
+                    time.pause();
+                    time.advance_ticks(GameTick::TICKS_PER_YEAR / FRAMES_PER_YEAR);
+                }
+                app.update();
+            }
+        }
+
+        Ok(app
+            .world_mut()
+            .remove_resource::<SimulationHashLog>()
+            .map(|log| log.years)
+            .unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash(year: u32, phases: [u64; 4]) -> SimulationHash {
+        SimulationHash { year, phases }
+    }
+
+    #[test]
+    fn comparison_reports_first_divergent_year_and_phase() {
+        let run = [hash(1001, [1, 2, 3, 4]), hash(1002, [5, 6, 7, 8])];
+        let drifted = [hash(1001, [1, 2, 3, 4]), hash(1002, [5, 6, 0, 0])];
+
+        assert_eq!(compare_hash_logs(&run, &run), Ok(2));
+        assert_eq!(
+            compare_hash_logs(&run, &drifted),
+            Err(Divergence::Phase {
+                year: 1002,
+                phase: DeterminismPhase::Ownership
+            })
+        );
+        assert_eq!(
+            compare_hash_logs(&run, &run[..1]),
+            Err(Divergence::Length {
+                first_years: 2,
+                second_years: 1
+            })
+        );
+    }
+
+    #[test]
+    fn hash_lines_round_trip_and_other_output_is_ignored() {
+        let original = hash(1003, [u64::MAX, 0, 0xdead_beef, 42]);
+        assert_eq!(SimulationHash::from_line(&original.to_line()), Some(original));
+        assert_eq!(SimulationHash::from_line("Loading world..."), None);
+        assert_eq!(SimulationHash::from_line("simulation-hash 1003 1 2 3"), None);
+    }
+
+    #[test]
+    fn same_seed_matches_across_thread_counts() {
+        let report = DeterminismCheck::new(1234)
+            .grid(64, 40)
+            .years(2)
+            .thread_counts(1, 4)
+            .run();
+        assert!(report.is_ok(), "{:?}", report.err());
+        assert_eq!(report.map(|report| report.years_compared).ok(), Some(2));
+    }
+}
@@ -0,0 +1,235 @@
+//! Choosing and applying catalysts for a stagnant world
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use super::types::{Catalyst, DynamismReport, WorldDirector};
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::math::{Fixed32, HEX_SIZE};
+use crate::nations::{
+    DramaEvent, DramaEventId, DramaEventType, EventImportance, EventVisibility, House, Nation, NationId,
+    NationIndex, PopulationDisplaced, SuccessionCrisisCause,
+};
+use crate::relationships::RulesOver;
+use crate::resources::WorldSeed;
+use crate::simulation::{DirectorTuning, NewYearEvent, TuningConfig};
+use crate::world::{CoastalProvinceCache, ProvinceStorage, WorldGenerationSettings};
+
+/// A freshly generated world takes the director mode chosen for it
+///
+/// Loading a save re-inserts the saved director after this runs.
+pub fn configure_director(
+    mut director: ResMut<WorldDirector>,
+    settings: Option<Res<WorldGenerationSettings>>,
+) {
+    *director = WorldDirector::new(settings.map_or_else(default, |s| s.director_mode));
+}
+
+/// Once a year, nudge the world if it has grown still
+pub fn direct_world(
+    mut year_events: MessageReader<NewYearEvent>,
+    mut director: ResMut<WorldDirector>,
+    tuning: Res<TuningConfig>,
+    world_seed: Option<Res<WorldSeed>>,
+    mut province_storage: Option<ResMut<ProvinceStorage>>,
+    coastal_cache: Option<Res<CoastalProvinceCache>>,
+    mut nations_query: Query<(&mut Nation, &NationId)>,
+    mut houses_query: Query<(Entity, &mut House, &RulesOver)>,
+    nation_index: Res<NationIndex>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    mut drama_events: MessageWriter<DramaEvent>,
+    mut displaced: MessageWriter<PopulationDisplaced>,
+) {
+    let Some(year) = year_events.read().last().map(|event| event.year) else {
+        return;
+    };
+    if !director.may_intervene(year) {
+        return;
+    }
+    let Some(report) = director.report().filter(|report| director.is_stagnant(report)) else {
+        return;
+    };
+
+    // Seeded by world and year so identical runs receive identical nudges
+    let seed = world_seed.map_or(0, |s| s.0) as u64;
+    let mut rng = StdRng::seed_from_u64((seed << 32) ^ year as u64);
+
+    let mut catalysts = Catalyst::ALL;
+    catalysts.shuffle(&mut rng);
+
+    // Fall through to the next catalyst when the world has nothing for one to act on
+    let applied = catalysts.into_iter().find_map(|catalyst| {
+        let outcome = match catalyst {
+            Catalyst::SuccessionCrisis => {
+                succession_crisis(
+                    &mut rng,
+                    year,
+                    &tuning.director,
+                    &mut houses_query,
+                    &mut nations_query,
+                    &mut drama_events,
+                )
+            }
+            Catalyst::Plague => province_storage
+                .as_deref_mut()
+                .and_then(|storage| plague(&mut rng, &tuning.director, storage, &nation_index, &mut displaced)),
+            Catalyst::NewWorldDiscovery => {
+                new_world_discovery(&mut rng, &tuning.director, coastal_cache.as_deref(), &mut nations_query)
+            }
+        };
+        outcome.map(|(text, nations)| (catalyst, text, nations))
+    });
+    let Some((catalyst, text, nations)) = applied else {
+        return;
+    };
+
+    director.last_intervention = Some(year);
+    info!("Director introduced {} in year {}: {}", catalyst.name(), year, text);
+    chronicle.write(ChronicleEvent {
+        category: ChronicleCategory::Director,
+        text: format!("{} (the director introduced {} {})", text, catalyst.name(), explain(&report)),
+        nations,
+    });
+}
+
+/// Why the director judged the world stagnant, in the chronicle's words
+fn explain(report: &DynamismReport) -> String {
+    format!(
+        "after a quiet age: {:.1} wars per century, {:.0} border changes per decade",
+        report.wars_per_century, report.border_changes_per_decade
+    )
+}
+
+/// A ruling house's claim is contested, shaking its realm
+fn succession_crisis(
+    rng: &mut StdRng,
+    year: u32,
+    tuning: &DirectorTuning,
+    houses_query: &mut Query<(Entity, &mut House, &RulesOver)>,
+    nations_query: &mut Query<(&mut Nation, &NationId)>,
+    drama_events: &mut MessageWriter<DramaEvent>,
+) -> Option<(String, Vec<NationId>)> {
+    let mut ruling: Vec<(NationId, Entity, Entity)> = houses_query
+        .iter()
+        .filter_map(|(house_entity, _, rules_over)| {
+            let (_, nation_id) = nations_query.get(rules_over.0).ok()?;
+            Some((*nation_id, house_entity, rules_over.0))
+        })
+        .collect();
+    // Query order is not stable between runs; sort so the seeded pick is
+    ruling.sort_by_key(|(id, _, _)| id.value());
+    let &(_, house_entity, nation_entity) = ruling.choose(rng)?;
+
+    let (_, mut house, _) = houses_query.get_mut(house_entity).ok()?;
+    let (mut nation, nation_id) = nations_query.get_mut(nation_entity).ok()?;
+
+    house.legitimacy = (house.legitimacy - tuning.crisis_legitimacy_loss).max(0.0);
+    nation.stability = (nation.stability - tuning.crisis_stability_loss).max(0.0);
+
+    drama_events.write(DramaEvent {
+        id: DramaEventId(rng.r#gen()),
+        event_type: DramaEventType::SuccessionCrisis {
+            claimants: vec![house.ruler.name.clone()],
+            cause: SuccessionCrisisCause::DisputedLegitimacy,
+        },
+        participants: Vec::new(),
+        importance: EventImportance::Major,
+        visibility: EventVisibility::Public,
+        consequences: Vec::new(),
+        timestamp: year,
+        resolved: false,
+    });
+
+    Some((
+        format!(
+            "Rival claimants rose against {} of {}, and the {} was thrown into turmoil",
+            house.ruler.name, house.full_name, nation.name
+        ),
+        vec![*nation_id],
+    ))
+}
+
+/// Disease spreads out from one populated province, thinning with distance
+fn plague(
+    rng: &mut StdRng,
+    tuning: &DirectorTuning,
+    storage: &mut ProvinceStorage,
+    nation_index: &NationIndex,
+    displaced: &mut MessageWriter<PopulationDisplaced>,
+) -> Option<(String, Vec<NationId>)> {
+    let populated: Vec<usize> = storage
+        .provinces
+        .iter()
+        .enumerate()
+        .filter(|(_, province)| province.population > 0)
+        .map(|(idx, _)| idx)
+        .collect();
+    let epicenter = storage.provinces[*populated.choose(rng)?].position;
+    let radius = Fixed32::from_f32(tuning.plague_radius_hexes * HEX_SIZE);
+
+    let mut deaths: u64 = 0;
+    let mut stricken: Vec<NationId> = Vec::new();
+    for (index, province) in storage.provinces.iter_mut().enumerate() {
+        let (dx, dy) = Fixed32::from_vec2(province.position - epicenter);
+        let distance = Fixed32::hypot(dx, dy);
+        if distance > radius {
+            continue;
+        }
+        // A plague with no reach strikes nowhere, even at its epicenter
+        let Some(reach) = distance.checked_div(radius) else {
+            continue;
+        };
+        let closeness = (Fixed32::ONE - reach).to_f32();
+        let lost = (province.population as f32 * tuning.plague_peak_mortality * closeness) as u32;
+        province.population -= lost;
+        province.mark_dirty();
+        deaths += lost as u64;
+        let fled = (province.population as f32 * tuning.plague_peak_flight * closeness) as u32;
+        if fled > 0 {
+            province.population -= fled;
+            displaced.write(PopulationDisplaced {
+                province: index,
+                people: fled,
+            });
+        }
+        if let Some(owner) = province.owner_entity.and_then(|owner| nation_index.id(owner)) {
+            if !stricken.contains(&owner) {
+                stricken.push(owner);
+            }
+        }
+    }
+
+    Some((format!("A plague swept the land, taking {} lives", deaths), stricken))
+}
+
+/// A seafaring nation returns from unknown shores with riches and renown
+fn new_world_discovery(
+    rng: &mut StdRng,
+    tuning: &DirectorTuning,
+    coastal_cache: Option<&CoastalProvinceCache>,
+    nations_query: &mut Query<(&mut Nation, &NationId)>,
+) -> Option<(String, Vec<NationId>)> {
+    let coastal_cache = coastal_cache.filter(|cache| cache.initialized)?;
+    let mut seafarers: Vec<NationId> = nations_query
+        .iter()
+        .filter(|(nation, _)| coastal_cache.is_coastal(nation.capital_province))
+        .map(|(_, id)| *id)
+        .collect();
+    // Query order is not stable between runs; sort so the seeded pick is
+    seafarers.sort_by_key(|id| id.value());
+    let chosen = *seafarers.choose(rng)?;
+
+    let (mut nation, nation_id) = nations_query.iter_mut().find(|(_, id)| **id == chosen)?;
+    nation.treasury += nation.treasury.abs() * Fixed32::from_f32(tuning.discovery_treasury_gain);
+    nation.stability = (nation.stability + tuning.discovery_stability_gain).min(1.0);
+
+    Some((
+        format!(
+            "Explorers of the {} returned from lands beyond the sea, laden with gold and wonder",
+            nation.name
+        ),
+        vec![*nation_id],
+    ))
+}
@@ -0,0 +1,65 @@
+//! Measuring how much the world is changing
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use super::types::WorldDirector;
+use crate::nations::{DeclareWarEvent, Nation, NationId, OwnershipChangeType, TerritoryOwnershipChanged};
+use crate::simulation::NewYearEvent;
+
+/// Count wars and border changes into the year in progress
+pub fn observe_world_dynamism(
+    mut war_events: MessageReader<DeclareWarEvent>,
+    mut ownership_events: MessageReader<TerritoryOwnershipChanged>,
+    mut director: ResMut<WorldDirector>,
+) {
+    let wars = war_events.read().count() as u32;
+    // Every transfer has a gaining side; counting losses too would count it twice
+    let changes: u32 = ownership_events
+        .read()
+        .filter(|event| !matches!(event.change_type, OwnershipChangeType::Loss))
+        .map(|event| event.provinces_changed)
+        .sum();
+
+    if wars > 0 || changes > 0 {
+        director.current.wars_declared += wars;
+        director.current.provinces_changed += changes;
+    }
+}
+
+/// Close the finished year's sample with its economic churn
+pub fn close_director_year(
+    mut year_events: MessageReader<NewYearEvent>,
+    mut director: ResMut<WorldDirector>,
+    nations_query: Query<(&NationId, &Nation)>,
+) {
+    if year_events.read().last().is_none() {
+        return;
+    }
+
+    let treasuries: HashMap<NationId, f32> = nations_query
+        .iter()
+        .map(|(id, nation)| (*id, nation.treasury.to_f32()))
+        .collect();
+
+    let growth: Vec<f32> = treasuries
+        .iter()
+        .filter_map(|(id, &treasury)| {
+            let previous = *director.previous_treasuries.get(id)?;
+            Some((treasury - previous) / previous.abs().max(1.0))
+        })
+        .collect();
+
+    let mut sample = std::mem::take(&mut director.current);
+    sample.economic_variance = variance(&growth);
+    director.push_sample(sample);
+    director.previous_treasuries = treasuries;
+}
+
+fn variance(values: &[f32]) -> f32 {
+    if values.len() < 2 {
+        return 0.0;
+    }
+    let mean = values.iter().sum::<f32>() / values.len() as f32;
+    values.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / values.len() as f32
+}
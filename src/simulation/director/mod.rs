@@ -0,0 +1,19 @@
+//! World director gateway
+//!
+//! Long runs can settle into stalemate. The director watches how dynamic the
+//! world is - wars per century, border changes, economic churn - and when
+//! the world grows still it injects a plausible catalyst: a succession
+//! crisis, a plague, a discovery across the sea. Every intervention is
+//! written into the chronicle, and purist runs can switch it off entirely.
+
+// PRIVATE modules - internal implementation
+mod intervention;
+mod metrics;
+mod types;
+
+// CONTROLLED PUBLIC EXPORTS
+pub use types::{Catalyst, DirectorMode, DynamismReport, WorldDirector};
+
+// Internal exports for the simulation plugin
+pub(super) use intervention::{configure_director, direct_world};
+pub(super) use metrics::{close_director_year, observe_world_dynamism};
@@ -0,0 +1,199 @@
+//! Director configuration, dynamism samples, and catalysts
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+use crate::nations::NationId;
+
+/// Years of history the director weighs before judging a world stagnant
+pub const DYNAMISM_WINDOW_YEARS: usize = 50;
+
+/// How many of the three dynamism measures must fall short to intervene
+const STAGNANT_MEASURES: usize = 2;
+
+/// How readily the director steps in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Reflect)]
+pub enum DirectorMode {
+    /// Purist run: the world is left entirely to itself
+    Off,
+    /// Rare nudges when the world has been still for a long time
+    #[default]
+    Gentle,
+    /// Frequent nudges to keep history moving
+    Active,
+}
+
+impl DirectorMode {
+    /// Minimum years between interventions, `None` when disabled
+    pub fn cooldown_years(self) -> Option<u32> {
+        match self {
+            DirectorMode::Off => None,
+            DirectorMode::Gentle => Some(50),
+            DirectorMode::Active => Some(20),
+        }
+    }
+}
+
+/// Something the director can set in motion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+pub enum Catalyst {
+    SuccessionCrisis,
+    Plague,
+    NewWorldDiscovery,
+}
+
+impl Catalyst {
+    pub const ALL: [Catalyst; 3] = [Catalyst::SuccessionCrisis, Catalyst::Plague, Catalyst::NewWorldDiscovery];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Catalyst::SuccessionCrisis => "a succession crisis",
+            Catalyst::Plague => "a plague",
+            Catalyst::NewWorldDiscovery => "a discovery across the sea",
+        }
+    }
+}
+
+/// One simulated year as the director saw it
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub(super) struct YearSample {
+    pub wars_declared: u32,
+    pub provinces_changed: u32,
+    /// Variance of the year's relative treasury changes across nations
+    pub economic_variance: f32,
+}
+
+/// How lively the world has been over the director's window
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DynamismReport {
+    pub wars_per_century: f32,
+    pub border_changes_per_decade: f32,
+    pub economic_variance: f32,
+}
+
+/// The director's thresholds and what it has observed, saved with the world
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct WorldDirector {
+    pub mode: DirectorMode,
+    pub min_wars_per_century: f32,
+    pub min_border_changes_per_decade: f32,
+    pub min_economic_variance: f32,
+    /// Year of the most recent intervention
+    pub last_intervention: Option<u32>,
+    /// Completed years, oldest first
+    pub(super) samples: VecDeque<YearSample>,
+    /// The year in progress
+    pub(super) current: YearSample,
+    /// Treasuries at the end of the previous year
+    pub(super) previous_treasuries: HashMap<NationId, f32>,
+}
+
+impl Default for WorldDirector {
+    fn default() -> Self {
+        Self {
+            mode: DirectorMode::default(),
+            min_wars_per_century: 2.0,
+            min_border_changes_per_decade: 10.0,
+            min_economic_variance: 0.001,
+            last_intervention: None,
+            samples: VecDeque::new(),
+            current: YearSample::default(),
+            previous_treasuries: HashMap::new(),
+        }
+    }
+}
+
+impl WorldDirector {
+    pub fn new(mode: DirectorMode) -> Self {
+        Self {
+            mode,
+            ..default()
+        }
+    }
+
+    /// Dynamism over the window, once a full window has been observed
+    pub fn report(&self) -> Option<DynamismReport> {
+        if self.samples.len() < DYNAMISM_WINDOW_YEARS {
+            return None;
+        }
+        let years = self.samples.len() as f32;
+        let wars: u32 = self.samples.iter().map(|s| s.wars_declared).sum();
+        let changes: u32 = self.samples.iter().map(|s| s.provinces_changed).sum();
+        let variance: f32 = self.samples.iter().map(|s| s.economic_variance).sum();
+
+        Some(DynamismReport {
+            wars_per_century: wars as f32 * 100.0 / years,
+            border_changes_per_decade: changes as f32 * 10.0 / years,
+            economic_variance: variance / years,
+        })
+    }
+
+    /// Whether the world has grown still enough to need a push
+    pub fn is_stagnant(&self, report: &DynamismReport) -> bool {
+        let short = [
+            report.wars_per_century < self.min_wars_per_century,
+            report.border_changes_per_decade < self.min_border_changes_per_decade,
+            report.economic_variance < self.min_economic_variance,
+        ];
+        short.iter().filter(|&&below| below).count() >= STAGNANT_MEASURES
+    }
+
+    /// Whether the mode and cooldown allow an intervention this year
+    pub fn may_intervene(&self, year: u32) -> bool {
+        match (self.mode.cooldown_years(), self.last_intervention) {
+            (None, _) => false,
+            (Some(_), None) => true,
+            (Some(cooldown), Some(last)) => year.saturating_sub(last) >= cooldown,
+        }
+    }
+
+    pub(super) fn push_sample(&mut self, sample: YearSample) {
+        self.samples.push_back(sample);
+        while self.samples.len() > DYNAMISM_WINDOW_YEARS {
+            self.samples.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quiet_director(mode: DirectorMode) -> WorldDirector {
+        let mut director = WorldDirector::new(mode);
+        for _ in 0..DYNAMISM_WINDOW_YEARS {
+            director.push_sample(YearSample::default());
+        }
+        director
+    }
+
+    #[test]
+    fn quiet_world_is_stagnant_once_the_window_fills() {
+        let mut director = WorldDirector::new(DirectorMode::Gentle);
+        director.push_sample(YearSample::default());
+        assert!(director.report().is_none());
+
+        let director = quiet_director(DirectorMode::Gentle);
+        let report = director.report().unwrap();
+        assert!(director.is_stagnant(&report));
+
+        let busy = DynamismReport {
+            wars_per_century: 6.0,
+            border_changes_per_decade: 40.0,
+            economic_variance: 0.0,
+        };
+        assert!(!director.is_stagnant(&busy));
+    }
+
+    #[test]
+    fn cooldown_and_off_mode_gate_interventions() {
+        let mut director = quiet_director(DirectorMode::Gentle);
+        assert!(director.may_intervene(100));
+        director.last_intervention = Some(100);
+        assert!(!director.may_intervene(120));
+        assert!(director.may_intervene(150));
+
+        assert!(!quiet_director(DirectorMode::Off).may_intervene(1000));
+    }
+}
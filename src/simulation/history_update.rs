@@ -3,6 +3,7 @@
 //! Tracks historical events and updates ruler/war status
 
 use bevy::prelude::*;
+use crate::math::{Fixed32, Money};
 use crate::nations::{Nation, NationHistory};
 use crate::nations::relationships::AttackedBy;
 use crate::simulation::GameTime;
@@ -37,15 +38,15 @@ pub fn update_nation_histories(
         }
 
         // Check for economic events
-        if nation.treasury < 100.0 && history.lowest_treasury > 500.0 {
+        if nation.treasury < Money::from_int(100) && history.lowest_treasury > Money::from_int(500) {
             history.record_event(crate::nations::HistoricalEvent::EconomicCrisis {
                 year: current_year,
-                severity: 1.0 - (nation.treasury / 1000.0),
+                severity: 1.0 - nation.treasury.to_f32() / 1000.0,
             });
         }
 
         // Check for golden age
-        if nation.stability > 0.9 && nation.treasury > history.peak_treasury * 0.9 {
+        if nation.stability > 0.9 && nation.treasury > history.peak_treasury * Fixed32::from_ratio(9, 10) {
             history.record_event(crate::nations::HistoricalEvent::GoldenAge {
                 year: current_year,
                 prosperity: nation.stability,
@@ -1,6 +1,7 @@
 //! Time control using the shortcuts registry system
 
 use bevy::prelude::*;
+use crate::settings::ActiveDeviceProfile;
 use crate::simulation::{GameTime, SimulationSpeed, SimulationSpeedChanged};
 use crate::ui::{ShortcutEvent, ShortcutId};
 
@@ -9,6 +10,7 @@ pub fn handle_time_controls(
     mut game_time: ResMut<GameTime>,
     mut shortcut_events: MessageReader<ShortcutEvent>,
     mut speed_events: MessageWriter<SimulationSpeedChanged>,
+    device: Res<ActiveDeviceProfile>,
 ) {
     let old_speed = game_time.get_speed();
     let was_paused = game_time.is_paused();
@@ -88,6 +90,14 @@ pub fn handle_time_controls(
         }
     }
 
+    // Handhelds and low-power machines cap how fast the simulation may run
+    let capped = game_time.get_speed().capped_at(device.0.max_speed);
+    if capped != game_time.get_speed() {
+        game_time.set_speed(capped);
+        speed_changed = true;
+        info!("Speed capped at {} on this device", capped.name());
+    }
+
     // Send event if speed changed
     if speed_changed && (old_speed != game_time.get_speed() || was_paused != game_time.is_paused()) {
         speed_events.write(SimulationSpeedChanged {
@@ -10,18 +10,23 @@
 //! - `time/` - Game time management and speed control
 //! - `input/` - User input handling for simulation controls
 //! - `tension/` - World tension tracking and calculations
+//! - `determinism` - Simulation hashing and same-seed run comparison
+//! - `tuning/` - Balance values loaded from data and hot-reloaded in development
 //!
 //! Each submodule has its own gateway (mod.rs) that controls its public API.
 //! This creates a hierarchical gateway system ensuring clean module boundaries.
 
 // PRIVATE modules - internal implementation details
 mod calendar;
+mod determinism;
+mod director;
 mod history_update;
 mod input;
 mod plugin;
 mod pressures;
 mod tension;
 mod time;
+mod tuning;
 
 // CONTROLLED PUBLIC EXPORTS
 // Only expose what external code needs, nothing more
@@ -33,7 +38,7 @@ pub use plugin::SimulationPlugin;
 pub use tension::WorldTension;
 
 // Time-related exports that other systems need
-pub use time::{GameTime, GameTick, SimulationSpeed, SimulationSpeedChanged};
+pub use time::{GameTime, GameTick, NewYearEvent, SimulationSpeed, SimulationSpeedChanged};
 
 // Calendar system exports
 pub use calendar::{
@@ -46,6 +51,21 @@ pub use pressures::{
     PressureLevel,
     PressureType, PressureVector,
 };
+// Metrics time the pressure pass as one simulation stage
+#[cfg(feature = "metrics")]
+pub use pressures::run_pressure_systems_on_timer;
+
+// Determinism verification exports
+pub use determinism::{
+    compare_hash_logs, record_simulation_hash, DeterminismCheck, DeterminismError, DeterminismPhase,
+    DeterminismReport, Divergence, SimulationHash, SimulationHashLog, StableHasher,
+};
+
+// Tuning exports - balance values systems read live
+pub use tuning::{DirectorTuning, InsuranceTuning, TuningChanged, TuningConfig, TuningWatcher, TUNING_PATH};
+
+// World director exports
+pub use director::{Catalyst, DirectorMode, DynamismReport, WorldDirector};
 
 // History update system exports
 
@@ -1,10 +1,13 @@
 //! Main plugin for the simulation module - AUTOMATION POWERED!
 
+use super::director::{close_director_year, configure_director, direct_world, observe_world_dynamism, WorldDirector};
 use super::input::handle_time_controls;
 use super::pressures::{run_pressure_systems_on_timer, PressureSystemTimer};
 use super::time::{
-    advance_simulation_ticks, interpolate_visual_time, resume_from_pause_menu, track_year_changes, NewYearEvent, SimulationSpeedChanged, VisualTime,
+    advance_simulation_ticks, handle_background_focus, interpolate_visual_time, resume_from_pause_menu, track_year_changes, BackgroundState,
+    NewYearEvent, SimulationSpeedChanged, VisualTime,
 };
+use super::tuning::{load_tuning_config, reload_changed_tuning, TuningConfig, TuningWatcher};
 use crate::resources::GameTime;
 use crate::states::GameState;
 use bevy::prelude::*;
@@ -12,18 +15,26 @@ use bevy_plugin_builder::define_plugin;
 
 /// Plugin that manages the simulation time system using AUTOMATION FRAMEWORK
 define_plugin!(SimulationPlugin {
-    resources: [PressureSystemTimer, VisualTime],
+    resources: [BackgroundState, PressureSystemTimer, TuningConfig, VisualTime, WorldDirector],
+
+    reflect: [
+        super::pressures::PressureVector,
+        super::calendar::NationCalendar,
+        super::director::DirectorMode
+    ],
 
     messages: [
         SimulationSpeedChanged,
         NewYearEvent,
+        super::tuning::TuningChanged,
         super::history_update::BattleEvent,
         super::history_update::WarStatusEvent,
         crate::nations::NationActionEvent
     ],
 
     startup: [
-        super::calendar::setup_calendar_system
+        super::calendar::setup_calendar_system,
+        load_tuning_config
     ],
 
     // DETERMINISTIC SIMULATION: Time advances in FixedUpdate at consistent rate
@@ -34,8 +45,12 @@ define_plugin!(SimulationPlugin {
     ],
 
     update: [
+        // Development runs pick up edits to the tuning file live
+        reload_changed_tuning.run_if(resource_exists::<TuningWatcher>),
         // Input handling (frame-dependent is OK for input)
         handle_time_controls.run_if(in_state(GameState::InGame)),
+        // Throttle or pause while the window is in the background
+        handle_background_focus.run_if(in_state(GameState::InGame)),
         // Visual interpolation for smooth display
         interpolate_visual_time.run_if(in_state(GameState::InGame)),
         // History tracking systems
@@ -45,10 +60,15 @@ define_plugin!(SimulationPlugin {
         // PERFORMANCE: Pressure systems run periodically, not every frame!
         run_pressure_systems_on_timer.run_if(in_state(GameState::InGame)),
         // ACTION RESOLUTION: Nations analyze pressures and decide what to do
-        crate::nations::resolve_nation_actions.run_if(in_state(GameState::InGame))
+        crate::nations::resolve_nation_actions.run_if(in_state(GameState::InGame)),
+        // DIRECTOR: Watch dynamism and nudge stagnant worlds (disabled in purist runs)
+        (observe_world_dynamism, close_director_year, direct_world)
+            .chain()
+            .run_if(in_state(GameState::InGame))
     ],
 
     on_enter: {
+        GameState::LoadingWorld => [configure_director],
         GameState::InGame => [
             super::calendar::apply_world_time_settings,
             resume_from_pause_menu
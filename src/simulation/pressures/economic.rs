@@ -2,6 +2,8 @@
 //!
 //! Economic pressure drives trade, resource exploitation, and taxation policies.
 
+use bevy::prelude::*;
+
 use super::types::PressureLevel;
 use crate::nations::Nation;
 use crate::world::Province;
@@ -25,8 +27,9 @@ pub fn calculate_economic_pressure(
     controlled_provinces: &[&Province],
 ) -> EconomicPressure {
     // Treasury pressure based on current funds vs needs
+    let treasury = nation.treasury.to_f32();
     let treasury_minimum = controlled_provinces.len() as f32 * 100.0; // 100 gold per province minimum
-    let treasury_ratio = nation.treasury / treasury_minimum.max(1.0);
+    let treasury_ratio = treasury / treasury_minimum.max(1.0);
     let treasury_shortage = if treasury_ratio < 1.0 {
         PressureLevel::new(1.0 - treasury_ratio)
     } else {
@@ -34,7 +37,7 @@ pub fn calculate_economic_pressure(
     };
 
     // Trade deficit (simplified - will expand with trade routes)
-    let trade_balance = nation.treasury * 0.1; // Placeholder: 10% of treasury as trade income
+    let trade_balance = treasury * 0.1; // Placeholder: 10% of treasury as trade income
     let trade_needs = controlled_provinces.len() as f32 * 50.0;
     let trade_ratio = trade_balance / trade_needs.max(1.0);
     let trade_deficit = if trade_ratio < 1.0 {
@@ -98,8 +101,8 @@ pub enum EconomicAction {
     RaiseTaxes { rate_increase: f32 },
     /// Seek resource-rich territories
     SeekResources { target_resource: ResourceTarget },
-    /// Establish trade routes
-    EstablishTrade { priority_partners: Vec<u32> },
+    /// Establish trade routes, favouring these nations before any others
+    EstablishTrade { priority_partners: Vec<Entity> },
     /// Reduce infrastructure spending
     CutSpending { reduction_percentage: f32 },
     /// Raid neighbors for wealth
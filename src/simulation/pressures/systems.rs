@@ -28,7 +28,7 @@ fn select_raid_target(
         .filter_map(|&neighbor_entity| {
             neighbors_query.get(neighbor_entity).ok().map(|(e, nation)| {
                 let vulnerability = 30.0 * (1.0 - nation.military_strength.min(1.0));
-                let wealth = 25.0 * (nation.treasury / 1000.0).min(1.0);
+                let wealth = 25.0 * (nation.treasury.to_f32() / 1000.0).min(1.0);
                 let score = vulnerability + wealth;
                 (e, score)
             })
@@ -59,10 +59,14 @@ fn select_ally_target(
 }
 
 /// Collect all provinces controlled by a nation through its territories
+///
+/// With a census, provinces come back as the government believes them to be
+/// rather than as they are.
 fn collect_controlled_provinces(
     owns_territory: &crate::nations::OwnsTerritory,
     territories_query: &Query<&crate::nations::Territory>,
     province_storage: &ProvinceStorage,
+    census: Option<&crate::nations::Census>,
 ) -> Vec<Province> {
     let mut controlled_provinces = Vec::new();
 
@@ -71,7 +75,10 @@ fn collect_controlled_provinces(
             for &province_id in &territory.provinces {
                 if let Some(&idx) = province_storage.province_by_id.get(&ProvinceId::new(province_id)) {
                     if let Some(province) = province_storage.provinces.get(idx) {
-                        controlled_provinces.push(province.clone());
+                        controlled_provinces.push(match census {
+                            Some(census) => census.believed(province),
+                            None => province.clone(),
+                        });
                     }
                 }
             }
@@ -176,6 +183,7 @@ pub fn resolve_pressure_actions(
         Query<(Entity, &Nation)>,
     )>,
     province_storage: Res<ProvinceStorage>,
+    behavior: Res<crate::ai::AiBehavior>,
     mut messages: MessageWriter<crate::nations::NationActionEvent>,
     time: Res<Time>,
 ) {
@@ -234,6 +242,7 @@ pub fn resolve_pressure_actions(
                             history,
                             level,
                             &province_storage,
+                            &behavior,
                             &mut messages,
                         );
                     }
@@ -245,6 +254,7 @@ pub fn resolve_pressure_actions(
                             history,
                             level,
                             raid_target,
+                            &behavior,
                             &mut messages,
                         );
                     }
@@ -310,7 +320,7 @@ pub fn apply_pressure_effects(
 /// Determine ruler personality from nation/house traits
 pub fn determine_ruler_personality(nation: &Nation) -> RulerPersonality {
     // Simplified for now - would look at House traits
-    if nation.military_strength > nation.treasury {
+    if nation.military_strength > nation.treasury.to_f32() {
         RulerPersonality::Warlike
     } else if nation.stability > 0.7 {
         RulerPersonality::Peaceful
@@ -346,6 +356,7 @@ pub fn run_pressure_systems_on_timer(
             &mut PressureVector,
             &crate::nations::OwnsTerritory,
             Option<&crate::nations::NationHistory>,
+            Option<&crate::nations::Census>,
         ), Without<crate::nations::Territory>>,
         // P1: Immutable query for neighbor lookups
         Query<(&Nation, Option<&crate::nations::relationships::LandNeighbors>, Option<&crate::nations::relationships::NavalNeighbors>)>,
@@ -362,7 +373,7 @@ pub fn run_pressure_systems_on_timer(
 
     // Phase 1a: Collect entity IDs for neighbor lookup
     let mut nation_data_for_neighbors: Vec<Entity> = Vec::new();
-    for (entity, _, _, _, _) in param_set.p0().iter() {
+    for (entity, _, _, _, _, _) in param_set.p0().iter() {
         nation_data_for_neighbors.push(entity);
     }
     
@@ -378,7 +389,7 @@ pub fn run_pressure_systems_on_timer(
     // Phase 1c: Calculate all pressure updates using extracted helpers
     let mut pressure_updates = Vec::new();
 
-    for (entity, nation, _, owns_territory, history_opt) in param_set.p0().iter() {
+    for (entity, nation, _, owns_territory, history_opt, census) in param_set.p0().iter() {
         // Get neighbor strengths from pre-calculated map
         let neighbor_strengths = neighbor_strengths_map.get(&entity).cloned().unwrap_or_default();
 
@@ -386,11 +397,12 @@ pub fn run_pressure_systems_on_timer(
             .map(|h| h.calculate_weighted_recent_defeats())
             .unwrap_or(0.0);
 
-        // Gather controlled provinces using helper
+        // Gather controlled provinces as the government knows them
         let controlled_provinces = collect_controlled_provinces(
             owns_territory,
             &territories_query,
             &province_storage,
+            census,
         );
 
         if controlled_provinces.is_empty() {
@@ -418,7 +430,7 @@ pub fn run_pressure_systems_on_timer(
     
     // Phase 2: Apply all updates
     for (entity, pop_over, pop_under, econ, mil, legit) in pressure_updates {
-        if let Ok((_, _, mut pressures, _, _)) = param_set.p0().get_mut(entity) {
+        if let Ok((_, _, mut pressures, _, _, _)) = param_set.p0().get_mut(entity) {
             pressures.set_pressure(PressureType::PopulationOvercrowding, PressureLevel(pop_over));
             pressures.set_pressure(PressureType::PopulationUnderpopulation, PressureLevel(pop_under));
             pressures.set_pressure(PressureType::EconomicStrain, PressureLevel(econ));
@@ -7,7 +7,7 @@ use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
 /// Different types of pressure that nations experience
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
 pub enum PressureType {
     /// Too many people for available resources
     PopulationOvercrowding,
@@ -28,7 +28,7 @@ pub enum PressureType {
 }
 
 /// Intensity level of a pressure
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Reflect)]
 pub struct PressureLevel(pub f32);
 
 impl PressureLevel {
@@ -64,7 +64,8 @@ impl PressureLevel {
 }
 
 /// A vector of all pressures affecting a nation
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct PressureVector {
     /// Map of pressure types to their current levels
     pub pressures: std::collections::HashMap<PressureType, PressureLevel>,
@@ -0,0 +1,123 @@
+//! Background throttling - what the game does while its window is unfocused
+//!
+//! Alt-tabbed away, the game either keeps going as usual, keeps simulating
+//! while drawing only a trickle of frames, or pauses outright, depending on
+//! the graphics settings. When throttled, frames are far apart, so virtual
+//! time may take bigger steps to keep the simulation at its chosen speed.
+//! On returning, the player is told how much simulated time went by.
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy::winit::{UpdateMode, WinitSettings};
+use std::time::Duration;
+
+use super::events::SimulationSpeedChanged;
+use super::resources::GameTime;
+use crate::constants::SIMULATION_DAYS_PER_YEAR;
+use crate::settings::{BackgroundMode, GameSettings};
+use crate::ui::ShowNotification;
+
+/// Bevy's default cap on a single virtual time step
+const DEFAULT_MAX_DELTA: Duration = Duration::from_millis(250);
+
+/// Where the game stood when the window lost focus
+#[derive(Resource, Debug, Default)]
+pub struct BackgroundState {
+    /// Simulation day when focus was lost, while unfocused
+    unfocused_since: Option<u32>,
+    /// Whether losing focus paused the game, so regaining it should resume
+    paused_by_focus: bool,
+}
+
+/// Describe a span of simulated days, e.g. "2 years, 40 days"
+pub fn format_elapsed(days: u32) -> String {
+    let per_year = SIMULATION_DAYS_PER_YEAR as u32;
+    let plural = |count: u32, unit: &str| format!("{} {}{}", count, unit, if count == 1 { "" } else { "s" });
+    match (days / per_year, days % per_year) {
+        (0, days) => plural(days, "day"),
+        (years, 0) => plural(years, "year"),
+        (years, days) => format!("{}, {}", plural(years, "year"), plural(days, "day")),
+    }
+}
+
+/// Throttle, pause, and resume the game as the window loses and regains focus
+pub fn handle_background_focus(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    settings: Res<GameSettings>,
+    winit: Option<ResMut<WinitSettings>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut game_time: ResMut<GameTime>,
+    mut state: ResMut<BackgroundState>,
+    mut speed_events: MessageWriter<SimulationSpeedChanged>,
+    mut notifications: MessageWriter<ShowNotification>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let mode = settings.graphics.background_mode;
+    let frame_interval = Duration::from_secs_f32(1.0 / settings.graphics.background_fps.clamp(1.0, 30.0));
+
+    if let Some(mut winit) = winit {
+        let unfocused_mode = match mode {
+            BackgroundMode::Throttle => UpdateMode::reactive_low_power(frame_interval),
+            BackgroundMode::KeepRendering | BackgroundMode::Pause => UpdateMode::Continuous,
+        };
+        if winit.unfocused_mode != unfocused_mode {
+            winit.unfocused_mode = unfocused_mode;
+        }
+    }
+
+    let was_paused = game_time.is_paused();
+    match (window.focused, state.unfocused_since) {
+        // Focus lost
+        (false, None) => {
+            state.unfocused_since = Some(game_time.current_day());
+            if mode == BackgroundMode::Throttle {
+                virtual_time.set_max_delta(DEFAULT_MAX_DELTA.max(frame_interval * 2));
+            }
+            if mode == BackgroundMode::Pause && !was_paused {
+                game_time.pause();
+                state.paused_by_focus = true;
+                info!("Window unfocused - simulation paused");
+            }
+        }
+        // Focus regained
+        (true, Some(since)) => {
+            state.unfocused_since = None;
+            virtual_time.set_max_delta(DEFAULT_MAX_DELTA);
+            if std::mem::take(&mut state.paused_by_focus) {
+                game_time.resume();
+                info!("Window focused - simulation resumed");
+            }
+
+            let elapsed = game_time.current_day().saturating_sub(since);
+            if elapsed > 0 {
+                notifications.write(ShowNotification::info(format!(
+                    "{} passed while you were away",
+                    format_elapsed(elapsed)
+                )));
+            }
+        }
+        _ => {}
+    }
+
+    if was_paused != game_time.is_paused() {
+        speed_events.write(SimulationSpeedChanged {
+            new_speed: game_time.get_speed().multiplier(),
+            is_paused: game_time.is_paused(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elapsed_time_reads_in_years_and_days() {
+        assert_eq!(format_elapsed(1), "1 day");
+        assert_eq!(format_elapsed(40), "40 days");
+        assert_eq!(format_elapsed(365), "1 year");
+        assert_eq!(format_elapsed(2 * 365 + 40), "2 years, 40 days");
+    }
+}
@@ -4,6 +4,7 @@
 //! All time-related functionality must be accessed through this gateway.
 
 // PRIVATE modules - internal implementation
+mod background;
 mod constants;
 mod events;
 mod resources;
@@ -11,6 +12,7 @@ mod systems;
 mod types;
 
 // Re-export what parent modules need
+pub use background::{handle_background_focus, BackgroundState};
 pub use events::{NewYearEvent, SimulationSpeedChanged};
 pub use resources::GameTime; // Now defined locally in this module
 pub use systems::{advance_simulation_ticks, interpolate_visual_time, resume_from_pause_menu, track_year_changes};
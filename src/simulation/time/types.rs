@@ -129,6 +129,15 @@ impl SimulationSpeed {
         }
     }
 
+    /// This speed, or `max` if that is slower
+    pub fn capped_at(&self, max: SimulationSpeed) -> Self {
+        if self.ticks_per_second() > max.ticks_per_second() {
+            max
+        } else {
+            *self
+        }
+    }
+
     /// Get the next slower speed level
     pub fn slower(&self) -> Self {
         match self {
@@ -0,0 +1,18 @@
+//! Simulation tuning gateway
+//!
+//! Balance values live in `config/base/tuning.ron` rather than in the code,
+//! read into the [`TuningConfig`] resource at startup. In development runs
+//! the file is watched and reloaded as it is saved, so balance changes show
+//! up in a running world without a rebuild; each reload that changes
+//! anything sends a [`TuningChanged`] message naming the sections it touched.
+
+// PRIVATE modules - internal implementation
+mod reload;
+mod types;
+
+// CONTROLLED PUBLIC EXPORTS
+pub use reload::TuningWatcher;
+pub use types::{DirectorTuning, InsuranceTuning, TuningChanged, TuningConfig, TUNING_PATH};
+
+// Internal exports for the simulation plugin
+pub(super) use reload::{load_tuning_config, reload_changed_tuning};
@@ -0,0 +1,99 @@
+//! Loading the tuning file at startup and reloading it while it is edited
+
+use bevy::prelude::*;
+use crossbeam_channel::{unbounded, Receiver};
+use notify::{Event as NotifyEvent, RecursiveMode, Watcher};
+use std::path::{Path, PathBuf};
+
+use super::types::{TuningChanged, TuningConfig, TUNING_PATH};
+
+/// Watches the tuning file for edits; inserted only in development runs
+#[derive(Resource)]
+pub struct TuningWatcher {
+    path: PathBuf,
+    _watcher: Option<notify::RecommendedWatcher>,
+    receiver: Receiver<Result<NotifyEvent, notify::Error>>,
+}
+
+impl TuningWatcher {
+    /// Watch the file at `path`
+    ///
+    /// Editors often replace a file rather than write it in place, so the
+    /// file's directory is watched and events are filtered by name.
+    pub fn watch(path: &Path) -> Self {
+        let (sender, receiver) = unbounded();
+        let mut watcher = notify::recommended_watcher(move |event| {
+            let _ = sender.send(event);
+        })
+        .ok();
+
+        let directory = path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        if let Some(watcher) = watcher.as_mut() {
+            match watcher.watch(directory, RecursiveMode::NonRecursive) {
+                Ok(()) => info!("Hot-reloading tuning from {}", path.display()),
+                Err(e) => warn!("Cannot watch {} for tuning changes: {}", directory.display(), e),
+            }
+        }
+
+        Self {
+            path: path.to_path_buf(),
+            _watcher: watcher,
+            receiver,
+        }
+    }
+
+    /// Whether the file was touched since the last call
+    fn file_changed(&self) -> bool {
+        let file_name = self.path.file_name();
+        let mut changed = false;
+        while let Ok(event) = self.receiver.try_recv() {
+            if let Ok(event) = event {
+                changed |= event.paths.iter().any(|path| path.file_name() == file_name);
+            }
+        }
+        changed
+    }
+}
+
+/// Read the tuning file once at startup, keeping the defaults if it can't be read
+pub fn load_tuning_config(mut tuning: ResMut<TuningConfig>) {
+    match TuningConfig::load(Path::new(TUNING_PATH)) {
+        Ok(loaded) => {
+            *tuning = loaded;
+            info!("Loaded tuning from {}", TUNING_PATH);
+        }
+        Err(e) => warn!("Using built-in tuning: {}", e),
+    }
+}
+
+/// Re-read the tuning file when it changes and announce what changed
+///
+/// A file that fails to parse mid-edit is reported and ignored; the last
+/// good values stay in effect.
+pub fn reload_changed_tuning(
+    watcher: Res<TuningWatcher>,
+    mut tuning: ResMut<TuningConfig>,
+    mut changes: MessageWriter<TuningChanged>,
+) {
+    if !watcher.file_changed() {
+        return;
+    }
+    let reloaded = match TuningConfig::load(&watcher.path) {
+        Ok(reloaded) => reloaded,
+        Err(e) => {
+            warn!("Tuning not reloaded: {}", e);
+            return;
+        }
+    };
+
+    let sections = reloaded.changed_sections(&tuning);
+    if sections.is_empty() {
+        return;
+    }
+    info!("Tuning reloaded: {} changed", sections.join(", "));
+    *tuning = reloaded;
+    changes.write(TuningChanged { sections });
+}
@@ -0,0 +1,148 @@
+//! Tunable balance values and the message announcing a change to them
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// File the tuning values are read from, relative to the game directory
+pub const TUNING_PATH: &str = "config/base/tuning.ron";
+
+/// Balance values read from [`TUNING_PATH`] instead of compiled in
+///
+/// Systems read the resource each time they run, so a reload takes effect
+/// on their next pass. Sections missing from the file keep their defaults.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TuningConfig {
+    pub director: DirectorTuning,
+    pub insurance: InsuranceTuning,
+}
+
+/// How hard the world director's catalysts strike
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DirectorTuning {
+    /// Legitimacy a house loses to a succession crisis
+    pub crisis_legitimacy_loss: f32,
+    /// Stability a nation loses to a succession crisis
+    pub crisis_stability_loss: f32,
+    /// Plague reach from its first province, in hexes
+    pub plague_radius_hexes: f32,
+    /// Share of the population lost at the heart of a plague
+    pub plague_peak_mortality: f32,
+    /// Share of the survivors who flee the heart of a plague
+    pub plague_peak_flight: f32,
+    /// Treasury gained from a discovery, relative to the current treasury
+    pub discovery_treasury_gain: f32,
+    /// Stability gained from a discovery
+    pub discovery_stability_gain: f32,
+}
+
+impl Default for DirectorTuning {
+    fn default() -> Self {
+        Self {
+            crisis_legitimacy_loss: 0.3,
+            crisis_stability_loss: 0.25,
+            plague_radius_hexes: 12.0,
+            plague_peak_mortality: 0.3,
+            plague_peak_flight: 0.15,
+            discovery_treasury_gain: 0.25,
+            discovery_stability_gain: 0.1,
+        }
+    }
+}
+
+/// How underwriters price the trade routes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InsuranceTuning {
+    /// Yearly chance of losing a cargo on a peaceful overland route
+    pub caravan_hazard: f32,
+    /// Yearly chance of losing a cargo on a peaceful sea route
+    pub maritime_hazard: f32,
+    /// Extra hazard while either end of a route is at war
+    pub war_hazard: f32,
+    /// Extra hazard on a sea route within reach of a pirate republic
+    pub piracy_hazard: f32,
+    /// Share of a route's yearly cargo lost when disaster strikes
+    pub loss_share: f32,
+    /// Weight underwriters give this year's loss against the route's record
+    pub loss_memory: f32,
+    /// Underwriters' margin over the expected loss
+    pub premium_loading: f32,
+    /// Premium, as a share of the cargo, above which no one will underwrite a route
+    pub uninsurable_premium: f32,
+}
+
+impl Default for InsuranceTuning {
+    fn default() -> Self {
+        Self {
+            caravan_hazard: 0.03,
+            maritime_hazard: 0.05,
+            war_hazard: 0.25,
+            piracy_hazard: 0.15,
+            loss_share: 0.5,
+            loss_memory: 0.2,
+            premium_loading: 0.3,
+            uninsurable_premium: 0.2,
+        }
+    }
+}
+
+impl TuningConfig {
+    /// Read tuning values from a RON file
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path).map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        ron::from_str(&text).map_err(|e| format!("{} is malformed: {}", path.display(), e))
+    }
+
+    /// Sections whose values differ from `other`
+    pub fn changed_sections(&self, other: &TuningConfig) -> Vec<&'static str> {
+        let mut sections = Vec::new();
+        if self.director != other.director {
+            sections.push("director");
+        }
+        if self.insurance != other.insurance {
+            sections.push("insurance");
+        }
+        sections
+    }
+}
+
+/// Sent when the tuning file is reloaded with new values
+///
+/// Values are read live, so only systems holding something derived from
+/// the old values - cached prices, thresholds baked into components - need
+/// to listen.
+#[derive(Message, Debug, Clone)]
+pub struct TuningChanged {
+    pub sections: Vec<&'static str>,
+}
+
+impl TuningChanged {
+    /// Whether the reload changed `section`
+    pub fn touches(&self, section: &str) -> bool {
+        self.sections.iter().any(|&changed| changed == section)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partial_files_keep_defaults_and_report_changed_sections() {
+        let tuning: TuningConfig = ron::from_str("(insurance: (war_hazard: 0.5))").unwrap_or_default();
+        assert_eq!(tuning.insurance.war_hazard, 0.5);
+        assert_eq!(
+            tuning.insurance.caravan_hazard,
+            InsuranceTuning::default().caravan_hazard
+        );
+        assert_eq!(tuning.director, DirectorTuning::default());
+
+        assert_eq!(tuning.changed_sections(&TuningConfig::default()), vec!["insurance"]);
+        assert!(TuningConfig::default()
+            .changed_sections(&TuningConfig::default())
+            .is_empty());
+    }
+}
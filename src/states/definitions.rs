@@ -77,6 +77,8 @@ pub enum SettingsTab {
     Interface,
     Performance,
     Controls,
+    /// Settings page of the enabled mod at this index among those with settings
+    Mod(u16),
 }
 
 /// Resource for tracking the current settings tab
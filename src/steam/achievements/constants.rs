@@ -20,3 +20,5 @@ pub const APOCALYPSE: &str = "APOCALYPSE"; // Max world tension
 pub const SPEED_DEMON: &str = "SPEED_DEMON"; // Use fastest speed
 pub const PHOTOGRAPHER: &str = "PHOTOGRAPHER"; // Take 100 screenshots
 pub const MODDER: &str = "MODDER"; // Subscribe to workshop item
+pub const WORLD_WAR: &str = "WORLD_WAR"; // Witness a world war milestone
+pub const ANCIENT_DYNASTY: &str = "ANCIENT_DYNASTY"; // Witness a 500-year dynasty
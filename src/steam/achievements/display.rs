@@ -23,6 +23,8 @@ pub fn get_achievement_display_name(id: &str) -> String {
         achievements::SPEED_DEMON => "Time Lord",
         achievements::PHOTOGRAPHER => "Chronicler",
         achievements::MODDER => "Community Member",
+        achievements::WORLD_WAR => "The World Aflame",
+        achievements::ANCIENT_DYNASTY => "Blood of Ages",
         _ => "Unknown Achievement",
     }
     .to_string()
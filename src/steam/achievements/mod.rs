@@ -18,7 +18,7 @@ pub use constants::*;
 pub use display::get_achievement_display_name;
 
 // Export trigger system
-pub use triggers::{handle_achievement_triggers, unlock_achievement};
+pub use triggers::{handle_achievement_triggers, handle_milestone_achievements, unlock_achievement};
 
 // PURE GATEWAY - No Implementation Logic
 // All actual implementations are in their respective files:
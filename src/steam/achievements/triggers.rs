@@ -6,6 +6,7 @@ use bevy::prelude::*;
 use bevy_steamworks::*;
 
 use super::super::types::{AchievementUnlockedEvent, SteamClient, SteamStats};
+use crate::milestones::{Milestone, MilestoneAchieved};
 use super::{constants as achievements, display::get_achievement_display_name};
 
 /// Check and unlock achievements based on current game state
@@ -73,6 +74,32 @@ pub fn handle_achievement_triggers(
     }
 }
 
+/// Unlock the achievements tied to in-game milestones
+pub fn handle_milestone_achievements(
+    steam: Res<SteamClient>,
+    mut milestone_events: MessageReader<MilestoneAchieved>,
+    mut achievement_events: MessageWriter<AchievementUnlockedEvent>,
+) {
+    let user_stats = steam.0.user_stats();
+    for event in milestone_events.read() {
+        if let Some(achievement_id) = milestone_achievement(event.record.milestone) {
+            unlock_achievement(&user_stats, achievement_id, &mut achievement_events);
+        }
+    }
+}
+
+/// Steam achievement for a milestone, if it has one
+///
+/// Only a subset of milestones are achievements; the rest are celebrated in game only.
+fn milestone_achievement(milestone: Milestone) -> Option<&'static str> {
+    match milestone {
+        Milestone::FirstWar => Some(achievements::WITNESS_WAR),
+        Milestone::WorldWar => Some(achievements::WORLD_WAR),
+        Milestone::AncientDynasty => Some(achievements::ANCIENT_DYNASTY),
+        Milestone::GreatEmpire => None,
+    }
+}
+
 /// Attempt to unlock a specific achievement
 pub fn unlock_achievement(
     user_stats: &UserStats,
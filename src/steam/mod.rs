@@ -54,7 +54,8 @@ pub use workshop::{get_subscribed_items, subscribe_to_workshop_item};
 
 // Re-export achievements functionality for controlled access
 pub use achievements::{
-    get_achievement_display_name, handle_achievement_triggers, unlock_achievement,
+    get_achievement_display_name, handle_achievement_triggers, handle_milestone_achievements,
+    unlock_achievement,
 };
 
 // PURE GATEWAY - No Implementation Logic
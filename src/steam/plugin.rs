@@ -29,7 +29,8 @@ define_plugin!(SteamPlugin {
     update: [
         (callbacks::poll_steam_callbacks,
          rich_presence::update_rich_presence,
-         achievements::handle_achievement_triggers)
+         achievements::handle_achievement_triggers,
+         achievements::handle_milestone_achievements)
     ],
 
     on_exit: {
@@ -4,6 +4,7 @@
 
 use bevy::prelude::*;
 use crate::nations::{Nation, NationId, NationLaws, NationPersonality, GovernmentType};
+use crate::math::Money;
 use crate::name_generator::Culture;
 
 /// Spawn a test nation with configurable parameters
@@ -17,9 +18,9 @@ pub fn spawn_test_nation(
             id: NationId::new(0),
             name: name.to_string(),
             adjective: format!("{}n", name), // Simple adjective form
-            capital_province: 0,
+            capital_province: crate::world::ProvinceId::new(0),
             color: Color::srgb(1.0, 0.0, 0.0),
-            treasury: 1000.0,
+            treasury: Money::from_int(1000),
             tax_rate: 0.2,
             military_strength: 100.0,
             stability: 1.0,
@@ -0,0 +1,15 @@
+//! Accessibility - Gateway module
+//!
+//! Applies the interface accessibility settings to every UI element,
+//! whichever builder spawned it: the global UI scale (on top of the layout's
+//! resolution scale), the font size multiplier and the high-contrast theme.
+//! Reduced motion is read directly by the systems that animate the camera
+//! and clouds.
+
+// PRIVATE modules
+mod plugin;
+mod systems;
+mod types;
+
+// PUBLIC exports
+pub use plugin::AccessibilityPlugin;
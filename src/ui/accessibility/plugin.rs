@@ -0,0 +1,16 @@
+//! Accessibility plugin
+
+use bevy_plugin_builder::define_plugin;
+
+use super::systems::*;
+
+define_plugin!(AccessibilityPlugin {
+    update: [
+        (
+            apply_ui_scale,
+            apply_font_scale,
+            apply_high_contrast_text,
+            apply_high_contrast_borders,
+        )
+    ]
+});
@@ -0,0 +1,99 @@
+//! Accessibility systems
+
+use bevy::prelude::*;
+
+use super::types::Adjusted;
+use crate::settings::{ActiveDeviceProfile, GameSettings};
+use crate::ui::layout::UiLayout;
+use crate::ui::styles::contrast;
+
+/// Scale every UI dimension by the interface UI scale, on top of the scales
+/// the layout picked for the window's resolution and the device profile
+pub fn apply_ui_scale(
+    settings: Res<GameSettings>,
+    layout: Res<UiLayout>,
+    device: Res<ActiveDeviceProfile>,
+    mut ui_scale: ResMut<UiScale>,
+) {
+    let scale = settings.interface.ui_scale * layout.resolution_scale * device.0.ui_scale;
+    if (settings.is_changed() || layout.is_changed() || device.is_changed()) && ui_scale.0 != scale {
+        ui_scale.0 = scale;
+    }
+}
+
+/// Multiply UI text sizes by the font scale
+pub fn apply_font_scale(
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    mut texts: Query<(Entity, &mut TextFont, Option<&Adjusted<f32>>), With<Node>>,
+) {
+    let refresh = settings.is_changed();
+    let scale = settings.interface.font_scale;
+
+    for (entity, mut font, previous) in &mut texts {
+        if !refresh && !font.is_changed() {
+            continue;
+        }
+        let next = Adjusted::resolve(font.font_size, previous, |size| size * scale);
+        if font.font_size != next.applied {
+            font.font_size = next.applied;
+        }
+        if next.needs_record(previous) {
+            commands.entity(entity).insert(next);
+        }
+    }
+}
+
+/// Brighten UI text while high contrast is enabled
+pub fn apply_high_contrast_text(
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    mut texts: Query<(Entity, &mut TextColor, Option<&Adjusted<Color>>), With<Node>>,
+) {
+    let refresh = settings.is_changed();
+    let enabled = settings.interface.high_contrast;
+
+    for (entity, mut color, previous) in &mut texts {
+        if !refresh && !color.is_changed() {
+            continue;
+        }
+        let next = Adjusted::resolve(color.0, previous, |original| {
+            if enabled { contrast::text(original) } else { original }
+        });
+        if color.0 != next.applied {
+            color.0 = next.applied;
+        }
+        if next.needs_record(previous) {
+            commands.entity(entity).insert(next);
+        }
+    }
+}
+
+/// Draw visible borders in the high-contrast border color
+pub fn apply_high_contrast_borders(
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    mut borders: Query<(Entity, &mut BorderColor, Option<&Adjusted<BorderColor>>)>,
+) {
+    let refresh = settings.is_changed();
+    let enabled = settings.interface.high_contrast;
+
+    for (entity, mut border, previous) in &mut borders {
+        if !refresh && !border.is_changed() {
+            continue;
+        }
+        let next = Adjusted::resolve(*border, previous, |original| {
+            if enabled && original.top.alpha() > 0.0 {
+                BorderColor::all(contrast::BORDER)
+            } else {
+                original
+            }
+        });
+        if *border != next.applied {
+            *border = next.applied;
+        }
+        if next.needs_record(previous) {
+            commands.entity(entity).insert(next);
+        }
+    }
+}
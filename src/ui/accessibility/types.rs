@@ -0,0 +1,57 @@
+//! Accessibility types
+
+use bevy::prelude::*;
+
+/// A UI value adjusted for accessibility, remembering what it was before
+///
+/// Builders and game systems keep writing their own values; any value that
+/// differs from `applied` is treated as a new original and adjusted again.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Adjusted<T: Copy + PartialEq + Send + Sync + 'static> {
+    pub original: T,
+    pub applied: T,
+}
+
+impl<T: Copy + PartialEq + Send + Sync + 'static> Adjusted<T> {
+    /// Work out the original behind `current` and the value to show instead
+    pub fn resolve(current: T, previous: Option<&Self>, adjust: impl Fn(T) -> T) -> Self {
+        let original = match previous {
+            Some(previous) if previous.applied == current => previous.original,
+            _ => current,
+        };
+        Self {
+            original,
+            applied: adjust(original),
+        }
+    }
+
+    /// Whether this needs storing on the entity
+    pub fn needs_record(&self, previous: Option<&Self>) -> bool {
+        match previous {
+            Some(previous) => previous != self,
+            None => self.original != self.applied,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_keeps_original_until_overwritten() {
+        let double = |size: f32| size * 2.0;
+
+        let first = Adjusted::resolve(14.0, None, double);
+        assert_eq!(first, Adjusted { original: 14.0, applied: 28.0 });
+
+        // Our own write comes back unchanged
+        let again = Adjusted::resolve(28.0, Some(&first), double);
+        assert_eq!(again, first);
+        assert!(!again.needs_record(Some(&first)));
+
+        // A builder setting a new size starts over from it
+        let rewritten = Adjusted::resolve(18.0, Some(&first), double);
+        assert_eq!(rewritten, Adjusted { original: 18.0, applied: 36.0 });
+    }
+}
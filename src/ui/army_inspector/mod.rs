@@ -0,0 +1,16 @@
+//! Military inspector - Gateway module
+//!
+//! T opens the selected nation's order of battle: its field armies grouped
+//! by the front they fight on, with each army's strength, commander, supply,
+//! and orders. Clicking an army pans the map to it and draws the route it
+//! still has to march and the objective at its end.
+
+// PRIVATE modules
+mod plugin;
+mod systems;
+mod types;
+mod ui;
+
+// PUBLIC exports
+pub use plugin::ArmyInspectorPlugin;
+pub use types::ArmyInspectorView;
@@ -0,0 +1,22 @@
+//! Military inspector plugin
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+use super::systems::*;
+use super::types::ArmyInspectorView;
+use crate::states::GameState;
+
+define_plugin!(ArmyInspectorPlugin {
+    resources: [ArmyInspectorView],
+
+    update: [
+        (toggle_army_inspector, handle_army_inspector_buttons, refresh_army_inspector, draw_selected_army_route)
+            .chain()
+            .run_if(in_state(GameState::InGame))
+    ],
+
+    on_exit: {
+        GameState::InGame => [close_army_inspector]
+    }
+});
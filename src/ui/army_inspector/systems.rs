@@ -0,0 +1,273 @@
+//! Opening, closing, and redrawing the military inspector, and drawing the picked army's route
+
+use bevy::prelude::*;
+
+use super::types::{
+    ArmyEntry, ArmyInspectorPanel, ArmyInspectorView, ArmyRowButton, CloseArmyInspectorButton, FollowArmyButton,
+    TheaterEntry,
+};
+use super::ui::spawn_army_inspector_panel;
+use crate::audio::{AudioCue, AudioEvent};
+use crate::camera::{CameraController, CameraFollow};
+use crate::nations::{ArmyOrder, CityNames, FieldArmy, Logistics, Nation};
+use crate::relationships::Army;
+use crate::ui::{SelectedNation, ShortcutEvent, ShortcutId};
+use crate::world::ProvinceStorage;
+
+/// Height of the route above the map, clear of provinces and borders
+const ROUTE_Z: f32 = 2.0;
+/// Color of the road still to march
+const ROUTE_COLOR: Color = Color::srgb(1.0, 0.84, 0.0);
+/// Color of the road already marched
+const MARCHED_COLOR: Color = Color::srgba(1.0, 0.84, 0.0, 0.35);
+/// Marker around an objective held by the enemy
+const ENEMY_OBJECTIVE_COLOR: Color = Color::srgb(0.95, 0.3, 0.25);
+/// Marker around an objective of the army's own
+const OWN_OBJECTIVE_COLOR: Color = Color::srgb(0.35, 0.65, 0.95);
+
+/// T opens and closes the military inspector
+pub fn toggle_army_inspector(
+    mut shortcuts: MessageReader<ShortcutEvent>,
+    mut view: ResMut<ArmyInspectorView>,
+    mut audio: MessageWriter<AudioEvent>,
+) {
+    if !shortcuts
+        .read()
+        .any(|event| event.shortcut_id == ShortcutId::ToggleArmyInspector)
+    {
+        return;
+    }
+    view.open = !view.open;
+    if !view.open {
+        view.selected_army = None;
+    }
+    let cue = if view.open { AudioCue::UiOpen } else { AudioCue::UiClose };
+    audio.write(AudioEvent::new(cue));
+}
+
+/// Close button, clicking an army to pick it out and pan the map to it, and following an army
+pub fn handle_army_inspector_buttons(
+    close_buttons: Query<&Interaction, (Changed<Interaction>, With<CloseArmyInspectorButton>)>,
+    rows: Query<(&Interaction, &ArmyRowButton), Changed<Interaction>>,
+    follow_buttons: Query<(&Interaction, &FollowArmyButton), Changed<Interaction>>,
+    armies: Query<&FieldArmy>,
+    mut cameras: Query<&mut CameraController>,
+    mut follow: ResMut<CameraFollow>,
+    mut view: ResMut<ArmyInspectorView>,
+) {
+    if close_buttons
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed)
+    {
+        view.open = false;
+        view.selected_army = None;
+        return;
+    }
+
+    // Following an army picks it out too, so its route is drawn along the way
+    for (interaction, button) in &follow_buttons {
+        if *interaction == Interaction::Pressed {
+            follow.follow(button.0);
+            view.selected_army = Some(button.0);
+        }
+    }
+
+    for (interaction, row) in &rows {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        // Clicking the picked army again lets it go
+        if view.selected_army == Some(row.0) {
+            view.selected_army = None;
+            continue;
+        }
+        view.selected_army = Some(row.0);
+        if let Ok(field) = armies.get(row.0) {
+            for mut controller in &mut cameras {
+                controller.target_position.x = field.position.x;
+                controller.target_position.y = field.position.y;
+            }
+        }
+    }
+}
+
+/// Name of a province: its city if it has one
+fn place_name(city_names: &CityNames, index: usize) -> String {
+    city_names
+        .get(index)
+        .map_or_else(|| format!("province {}", index), |city| city.name.clone())
+}
+
+/// The selected nation's armies grouped by front, fronts first and the reserve last
+fn theaters_of(
+    nation: Entity,
+    armies: &Query<(Entity, &Army, &FieldArmy)>,
+    nations: &Query<(&Nation, Option<&Logistics>)>,
+    city_names: &CityNames,
+) -> Vec<TheaterEntry> {
+    let mut theaters: Vec<TheaterEntry> = Vec::new();
+    for (entity, army, field) in armies.iter().filter(|(_, army, _)| army.owner_nation == nation) {
+        let entry = ArmyEntry {
+            entity,
+            name: army.name.clone(),
+            soldiers: army.size,
+            commander: field.commander.clone(),
+            supply: field.supply,
+            morale: army.morale,
+            orders: format!("{} {}", field.order.label(), place_name(city_names, field.objective)),
+            provinces_to_go: field.remaining_route().len().saturating_sub(1),
+        };
+        match theaters.iter_mut().find(|theater| theater.enemy == field.theater) {
+            Some(theater) => theater.armies.push(entry),
+            None => {
+                let title = match field.theater {
+                    Some(enemy) => {
+                        let enemy = nations
+                            .get(enemy)
+                            .map_or("an unknown enemy", |(nation, _)| nation.name.as_str());
+                        format!("Front against {}", enemy)
+                    }
+                    None => "Home Reserve".to_string(),
+                };
+                theaters.push(TheaterEntry {
+                    enemy: field.theater,
+                    title,
+                    armies: vec![entry],
+                });
+            }
+        }
+    }
+    theaters.sort_by_key(|theater| theater.enemy.is_none());
+    theaters
+}
+
+/// Redraw when the inspector opens, the selection changes, or an army's strength or orders change
+pub fn refresh_army_inspector(
+    mut commands: Commands,
+    mut view: ResMut<ArmyInspectorView>,
+    selected: Res<SelectedNation>,
+    nations: Query<(&Nation, Option<&Logistics>)>,
+    armies: Query<(Entity, &Army, &FieldArmy)>,
+    city_names: Res<CityNames>,
+    panels: Query<Entity, With<ArmyInspectorPanel>>,
+    mut shown: Local<Option<Vec<TheaterEntry>>>,
+) {
+    // A picked army belongs to the nation it was picked from
+    if selected.is_changed() && view.selected_army.is_some() {
+        view.selected_army = None;
+    }
+    if view.selected_army.is_some_and(|army| !armies.contains(army)) {
+        view.selected_army = None;
+    }
+
+    let theaters = selected
+        .entity
+        .filter(|_| view.open)
+        .map(|nation| theaters_of(nation, &armies, &nations, &city_names));
+    // Marching moves armies every day; only a new province or new orders change the list
+    if !view.is_changed() && !selected.is_changed() && *shown == theaters {
+        return;
+    }
+    *shown = theaters.clone();
+
+    for panel in &panels {
+        commands.entity(panel).despawn();
+    }
+    if !view.open {
+        return;
+    }
+
+    let Some((nation, logistics)) = selected.entity.and_then(|entity| nations.get(entity).ok()) else {
+        let note = "Select a nation on the map to see its armies";
+        spawn_army_inspector_panel(&mut commands, "Military", Some(note), &[], None);
+        return;
+    };
+
+    let title = format!("Armies of {}", nation.name);
+    let theaters = theaters.unwrap_or_default();
+    if theaters.is_empty() {
+        let note = "Armies are mustered at the turn of the year";
+        spawn_army_inspector_panel(&mut commands, &title, Some(note), &[], None);
+        return;
+    }
+
+    let armies_count: usize = theaters.iter().map(|theater| theater.armies.len()).sum();
+    let soldiers: u32 = theaters
+        .iter()
+        .flat_map(|theater| &theater.armies)
+        .map(|army| army.soldiers.get())
+        .sum();
+    let fronts = theaters.iter().filter(|theater| theater.enemy.is_some()).count();
+    let mut summary = format!("Armies: {}, soldiers: {}, fronts: {}", armies_count, soldiers, fronts);
+    if let Some(logistics) = logistics {
+        summary.push_str(&format!(
+            ". Stockpile {:.0} of {:.0}, {:.0}% of last year's needs met",
+            logistics.stockpile,
+            logistics.target,
+            logistics.supplied * 100.0
+        ));
+    }
+    summary.push_str(". Click an army to show its march on the map");
+    spawn_army_inspector_panel(&mut commands, &title, Some(&summary), &theaters, view.selected_army);
+}
+
+/// Draw the picked army's position, route, and objective on the map
+pub fn draw_selected_army_route(
+    mut gizmos: Gizmos,
+    view: Res<ArmyInspectorView>,
+    armies: Query<(&Army, &FieldArmy)>,
+    province_storage: Option<Res<ProvinceStorage>>,
+) {
+    let (Some(army), Some(storage)) = (view.selected_army, province_storage) else {
+        return;
+    };
+    let Ok((army, field)) = armies.get(army) else {
+        return;
+    };
+    let position_of = |index: &usize| storage.provinces.get(*index).map(|province| province.position);
+
+    let marched: Vec<Vec3> = field
+        .route
+        .iter()
+        .take(field.progress + 1)
+        .filter_map(position_of)
+        .chain(std::iter::once(field.position))
+        .map(|point| point.extend(ROUTE_Z))
+        .collect();
+    gizmos.linestrip(marched, MARCHED_COLOR);
+
+    let ahead: Vec<Vec3> = std::iter::once(field.position)
+        .chain(field.remaining_route().iter().skip(1).filter_map(position_of))
+        .map(|point| point.extend(ROUTE_Z))
+        .collect();
+    gizmos.linestrip(ahead, ROUTE_COLOR);
+
+    let army_at = Isometry3d::from_translation(field.position.extend(ROUTE_Z));
+    gizmos.circle(army_at, crate::math::HEX_SIZE * 0.3, ROUTE_COLOR);
+
+    if let Some(objective) = position_of(&field.objective) {
+        let enemy_held = storage
+            .provinces
+            .get(field.objective)
+            .is_some_and(|province| province.owner_entity.is_some_and(|owner| owner != army.owner_nation));
+        let color = match field.order {
+            ArmyOrder::Advance | ArmyOrder::Besiege if enemy_held => ENEMY_OBJECTIVE_COLOR,
+            _ => OWN_OBJECTIVE_COLOR,
+        };
+        let objective_at = Isometry3d::from_translation(objective.extend(ROUTE_Z));
+        gizmos.circle(objective_at, crate::math::HEX_SIZE * 0.8, color);
+        gizmos.circle(objective_at, crate::math::HEX_SIZE * 0.6, color);
+    }
+}
+
+/// Close the inspector when leaving the game
+pub fn close_army_inspector(
+    mut view: ResMut<ArmyInspectorView>,
+    panels: Query<Entity, With<ArmyInspectorPanel>>,
+    mut commands: Commands,
+) {
+    for panel in &panels {
+        commands.entity(panel).despawn();
+    }
+    *view = ArmyInspectorView::default();
+}
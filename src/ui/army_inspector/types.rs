@@ -0,0 +1,52 @@
+//! Data types for the military inspector
+
+use bevy::prelude::*;
+
+use crate::math::Manpower;
+
+/// Whether the inspector is open, and the army picked out on the map
+#[derive(Resource, Debug, Default)]
+pub struct ArmyInspectorView {
+    pub open: bool,
+    pub selected_army: Option<Entity>,
+}
+
+/// One army as listed in the inspector
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArmyEntry {
+    pub entity: Entity,
+    pub name: String,
+    pub soldiers: Manpower,
+    pub commander: Option<String>,
+    pub supply: f32,
+    pub morale: f32,
+    /// Order and the place it names, e.g. "Besieging Varna"
+    pub orders: String,
+    /// Provinces left to march
+    pub provinces_to_go: usize,
+}
+
+/// The armies facing one enemy, or the home reserve
+#[derive(Debug, Clone, PartialEq)]
+pub struct TheaterEntry {
+    /// Enemy faced, or `None` for the reserve
+    pub enemy: Option<Entity>,
+    pub title: String,
+    pub armies: Vec<ArmyEntry>,
+}
+
+/// Marker for the inspector panel root
+#[derive(Component)]
+pub struct ArmyInspectorPanel;
+
+/// Close button
+#[derive(Component)]
+pub struct CloseArmyInspectorButton;
+
+/// An army's row; clicking it picks the army out on the map
+#[derive(Component)]
+pub struct ArmyRowButton(pub Entity);
+
+/// Locks the camera onto an army
+#[derive(Component)]
+pub struct FollowArmyButton(pub Entity);
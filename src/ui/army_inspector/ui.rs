@@ -0,0 +1,181 @@
+//! Military inspector UI rendering
+
+use bevy::prelude::*;
+
+use super::types::{
+    ArmyEntry, ArmyInspectorPanel, ArmyRowButton, CloseArmyInspectorButton, FollowArmyButton, TheaterEntry,
+};
+use crate::ui::*;
+
+/// Border of the army picked out on the map
+const SELECTED_COLOR: Color = Color::srgb(1.0, 0.84, 0.0);
+
+fn line(parent: &mut ChildBuilder, text: String, size: f32, color: Color) {
+    parent.spawn((
+        Text::new(text),
+        TextFont {
+            font_size: size,
+            ..default()
+        },
+        TextColor(color),
+    ));
+}
+
+/// Color of a supply or morale share, from red when short to green when full
+fn share_color(share: f32) -> Color {
+    if share >= 0.75 {
+        Color::srgb(0.5, 0.85, 0.45)
+    } else if share >= 0.4 {
+        Color::srgb(0.9, 0.75, 0.3)
+    } else {
+        Color::srgb(0.9, 0.35, 0.3)
+    }
+}
+
+fn spawn_army_row(parent: &mut ChildBuilder, army: &ArmyEntry, selected: bool) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                border: UiRect::all(Val::Px(if selected { 2.0 } else { 1.0 })),
+                ..default()
+            },
+            BackgroundColor(UI_BACKGROUND_COLOR),
+            BorderColor::all(if selected { SELECTED_COLOR } else { UI_BORDER_COLOR }),
+            Interaction::default(),
+            ArmyRowButton(army.entity),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(Node {
+                    width: Val::Percent(100.0),
+                    justify_content: JustifyContent::SpaceBetween,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    line(parent, army.name.clone(), TEXT_SIZE_LARGE, TEXT_COLOR_PRIMARY);
+                    line(parent, army.soldiers.to_string(), TEXT_SIZE_NORMAL, TEXT_COLOR_HEADER);
+                });
+
+            let commander = army.commander.as_deref().unwrap_or("No commander");
+            line(
+                parent,
+                format!("Led by {}", commander),
+                TEXT_SIZE_NORMAL,
+                TEXT_COLOR_SECONDARY,
+            );
+
+            parent
+                .spawn(Node {
+                    column_gap: Val::Px(12.0),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    line(
+                        parent,
+                        format!("Supply {:.0}%", army.supply * 100.0),
+                        TEXT_SIZE_NORMAL,
+                        share_color(army.supply),
+                    );
+                    line(
+                        parent,
+                        format!("Morale {:.0}%", army.morale * 100.0),
+                        TEXT_SIZE_NORMAL,
+                        share_color(army.morale),
+                    );
+                });
+
+            let orders = match army.provinces_to_go {
+                0 => army.orders.clone(),
+                1 => format!("{} (1 province away)", army.orders),
+                to_go => format!("{} ({} provinces away)", army.orders, to_go),
+            };
+            parent
+                .spawn(Node {
+                    width: Val::Percent(100.0),
+                    justify_content: JustifyContent::SpaceBetween,
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    line(parent, orders, TEXT_SIZE_NORMAL, TEXT_COLOR_PRIMARY);
+                    ButtonBuilder::new("Follow")
+                        .size(ButtonSize::Small)
+                        .style(ButtonStyle::Secondary)
+                        .with_marker(FollowArmyButton(army.entity))
+                        .build(parent);
+                });
+        });
+}
+
+/// Spawn the inspector for one nation, or a note saying why there is nothing to show
+pub fn spawn_army_inspector_panel(
+    commands: &mut Commands,
+    title: &str,
+    summary: Option<&str>,
+    theaters: &[TheaterEntry],
+    selected: Option<Entity>,
+) {
+    // A side panel, so the route of the picked army stays visible on the map
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(20.0),
+                top: Val::Px(60.0),
+                width: Val::Px(420.0),
+                height: Val::Percent(85.0),
+                padding: UiRect::all(Val::Px(20.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(UI_BACKGROUND_COLOR),
+            BorderColor::all(UI_BORDER_COLOR),
+            GlobalZIndex(layers::MODAL_OVERLAY),
+            ArmyInspectorPanel,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(Node {
+                    width: Val::Percent(100.0),
+                    justify_content: JustifyContent::SpaceBetween,
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    line(parent, title.to_uppercase(), TEXT_SIZE_TITLE, TEXT_COLOR_HEADER);
+                    ButtonBuilder::new("Close")
+                        .size(ButtonSize::Small)
+                        .style(ButtonStyle::Ghost)
+                        .with_marker(CloseArmyInspectorButton)
+                        .build(parent);
+                });
+
+            if let Some(summary) = summary {
+                line(parent, summary.to_string(), TEXT_SIZE_NORMAL, TEXT_COLOR_SECONDARY);
+            }
+
+            parent
+                .spawn(Node {
+                    width: Val::Percent(100.0),
+                    flex_grow: 1.0,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(8.0),
+                    overflow: Overflow::scroll_y(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for theater in theaters {
+                        line(parent, theater.title.clone(), TEXT_SIZE_LARGE, TEXT_COLOR_HEADER);
+                        for army in &theater.armies {
+                            spawn_army_row(parent, army, selected == Some(army.entity));
+                        }
+                    }
+                });
+        });
+}
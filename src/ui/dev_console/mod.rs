@@ -0,0 +1,14 @@
+//! Developer console - Gateway module
+//!
+//! A text console opened with the backquote key. Typed lines become
+//! `ConsoleCommand` messages that any module may handle, replying with
+//! `ConsoleOutput` lines.
+
+// PRIVATE modules
+mod plugin;
+mod systems;
+mod types;
+
+// PUBLIC exports
+pub use plugin::DevConsolePlugin;
+pub use types::{console_closed, ConsoleCommand, ConsoleOutput};
@@ -0,0 +1,23 @@
+//! Developer console plugin
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+use super::systems::*;
+use super::types::*;
+
+define_plugin!(DevConsolePlugin {
+    resources: [DevConsole],
+
+    messages: [ConsoleCommand, ConsoleOutput],
+
+    update: [
+        (
+            toggle_dev_console,
+            submit_console_line,
+            collect_console_output,
+            update_console_log,
+        )
+            .chain()
+    ]
+});
@@ -0,0 +1,130 @@
+//! Developer console systems
+
+use bevy::prelude::*;
+
+use super::types::*;
+use crate::ui::{layers, text_input, ShortcutConfig, ShortcutEvent, ShortcutId, TextBuffer};
+use crate::ui::TEXT_COLOR_SECONDARY;
+
+fn spawn_console_panel(commands: &mut Commands, console: &DevConsole) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(0.0),
+                left: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: Val::Percent(35.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::FlexEnd,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.02, 0.02, 0.04, 0.9)),
+            GlobalZIndex(layers::CRITICAL_DIALOG),
+            DevConsolePanel,
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(console.lines.iter().cloned().collect::<Vec<_>>().join("\n")),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR_SECONDARY),
+                DevConsoleLog,
+            ));
+            text_input()
+                .with_font_size(16.0)
+                .with_width(Val::Percent(100.0))
+                .with_padding(UiRect::horizontal(Val::Px(8.0)))
+                .with_max_length(200)
+                .independent()
+                .with_marker(DevConsoleInput)
+                .build(panel);
+        });
+}
+
+/// Open with the console shortcut; close with the same key or Escape
+///
+/// Shortcuts are disabled while the console is open so typing does not
+/// trigger game actions.
+pub fn toggle_dev_console(
+    mut commands: Commands,
+    mut console: ResMut<DevConsole>,
+    mut shortcut_events: MessageReader<ShortcutEvent>,
+    mut shortcut_config: ResMut<ShortcutConfig>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    panels: Query<Entity, With<DevConsolePanel>>,
+) {
+    let requested = shortcut_events
+        .read()
+        .any(|event| event.shortcut_id == ShortcutId::OpenConsole);
+
+    if !console.open {
+        if requested {
+            console.open = true;
+            shortcut_config.enabled = false;
+            spawn_console_panel(&mut commands, &console);
+        }
+        return;
+    }
+
+    if keyboard.just_pressed(KeyCode::Backquote) || keyboard.just_pressed(KeyCode::Escape) {
+        console.open = false;
+        shortcut_config.enabled = true;
+        for panel in &panels {
+            commands.entity(panel).despawn();
+        }
+    }
+}
+
+/// Turn the typed line into a `ConsoleCommand` when Enter is pressed
+pub fn submit_console_line(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut console: ResMut<DevConsole>,
+    mut inputs: Query<&mut TextBuffer, With<DevConsoleInput>>,
+    mut commands_out: MessageWriter<ConsoleCommand>,
+) {
+    if !console.open || !keyboard.just_pressed(KeyCode::Enter) {
+        return;
+    }
+
+    for mut buffer in &mut inputs {
+        let line = buffer.content.replace('`', "").trim().to_string();
+        buffer.content.clear();
+        let Some(command) = ConsoleCommand::parse(&line) else {
+            continue;
+        };
+
+        console.print(format!("> {}", line));
+        match command.name.as_str() {
+            "help" => {
+                console.print("clear - clear the console");
+                console.print("watch list | play <script> | stop - scripted camera and overlay sequences");
+                console.print("stress armies|routes|migrations [count] | clear [kind] | status - synthetic load");
+            }
+            "clear" => console.lines.clear(),
+            _ => {
+                commands_out.write(command);
+            }
+        }
+    }
+}
+
+/// Print what command handlers reported
+pub fn collect_console_output(mut console: ResMut<DevConsole>, mut output: MessageReader<ConsoleOutput>) {
+    for ConsoleOutput(line) in output.read() {
+        console.print(line.clone());
+    }
+}
+
+pub fn update_console_log(console: Res<DevConsole>, mut logs: Query<&mut Text, With<DevConsoleLog>>) {
+    if !console.is_changed() {
+        return;
+    }
+    for mut text in &mut logs {
+        **text = console.lines.iter().cloned().collect::<Vec<_>>().join("\n");
+    }
+}
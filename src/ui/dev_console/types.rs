@@ -0,0 +1,80 @@
+//! Developer console types
+
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+/// Lines of output the console keeps on screen
+pub const MAX_CONSOLE_LINES: usize = 14;
+
+/// Console state and scrollback
+#[derive(Resource, Default, Debug)]
+pub struct DevConsole {
+    pub open: bool,
+    pub lines: VecDeque<String>,
+}
+
+impl DevConsole {
+    pub fn print(&mut self, line: impl Into<String>) {
+        self.lines.push_back(line.into());
+        while self.lines.len() > MAX_CONSOLE_LINES {
+            self.lines.pop_front();
+        }
+    }
+}
+
+/// A command typed into the console, for any module that handles it
+///
+/// Modules read these messages and match on `name`; unknown commands are
+/// simply ignored by everyone.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct ConsoleCommand {
+    pub name: String,
+    pub args: Vec<String>,
+}
+
+impl ConsoleCommand {
+    /// Split a console line into a lowercase command name and its arguments
+    pub fn parse(line: &str) -> Option<Self> {
+        let mut words = line.split_whitespace();
+        let name = words.next()?.to_lowercase();
+        Some(Self {
+            name,
+            args: words.map(str::to_string).collect(),
+        })
+    }
+}
+
+/// A line for the console to print, written by command handlers
+#[derive(Message, Debug, Clone)]
+pub struct ConsoleOutput(pub String);
+
+#[derive(Component)]
+pub struct DevConsolePanel;
+
+#[derive(Component)]
+pub struct DevConsoleLog;
+
+#[derive(Component)]
+pub struct DevConsoleInput;
+
+/// Run condition: the console is not capturing the keyboard
+pub fn console_closed(console: Option<Res<DevConsole>>) -> bool {
+    console.map_or(true, |console| !console.open)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_splits_name_and_args() {
+        assert_eq!(
+            ConsoleCommand::parse("  Watch  play intro "),
+            Some(ConsoleCommand {
+                name: "watch".to_string(),
+                args: vec!["play".to_string(), "intro".to_string()],
+            })
+        );
+        assert_eq!(ConsoleCommand::parse("   "), None);
+    }
+}
@@ -0,0 +1,154 @@
+//! Sankey layout: stages stacked in columns, bands as thick as their flows
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::nations::{EconomicFlow, FlowNode};
+
+/// Width of a stage's bar
+pub const BAR_WIDTH: f32 = 14.0;
+/// Space between stages stacked in a column
+const NODE_GAP: f32 = 16.0;
+/// Thinnest band drawn, so small flows stay visible
+const MIN_BAND: f32 = 1.0;
+
+/// A stage's bar
+#[derive(Debug, Clone, PartialEq)]
+pub struct SankeyNode {
+    pub node: FlowNode,
+    /// Top-left corner of the bar
+    pub position: Vec2,
+    pub height: f32,
+    /// Larger of what flows in and out
+    pub value: f32,
+}
+
+/// A band from one bar to the next
+#[derive(Debug, Clone, PartialEq)]
+pub struct SankeyBand {
+    pub from: FlowNode,
+    /// Middle of the band where it leaves its source and enters its target
+    pub start: Vec2,
+    pub end: Vec2,
+    pub thickness: f32,
+}
+
+/// Place stages in columns `column_spacing` apart, scaled to fit `height`
+///
+/// Stages keep the order they first appear in `flows`, top to bottom. Each
+/// bar is as tall as the larger of its inflow and outflow, and bands leave
+/// and enter bars stacked in flow order, so they never overlap at a bar.
+pub fn sankey_layout(flows: &[EconomicFlow], column_spacing: f32, height: f32) -> (Vec<SankeyNode>, Vec<SankeyBand>) {
+    let mut order: Vec<FlowNode> = Vec::new();
+    let mut inflow: HashMap<FlowNode, f32> = HashMap::new();
+    let mut outflow: HashMap<FlowNode, f32> = HashMap::new();
+    for flow in flows {
+        for node in [flow.from, flow.to] {
+            if !order.contains(&node) {
+                order.push(node);
+            }
+        }
+        *outflow.entry(flow.from).or_default() += flow.amount;
+        *inflow.entry(flow.to).or_default() += flow.amount;
+    }
+    let value = |node: &FlowNode| {
+        inflow
+            .get(node)
+            .copied()
+            .unwrap_or(0.0)
+            .max(outflow.get(node).copied().unwrap_or(0.0))
+    };
+
+    // One scale for every column, set by the fullest
+    let columns = order.iter().map(|node| node.column()).max().map_or(0, |max| max + 1);
+    let mut fullest = 0.0_f32;
+    let mut free_space = height;
+    for column in 0..columns {
+        let members: Vec<&FlowNode> = order.iter().filter(|node| node.column() == column).collect();
+        let total: f32 = members.iter().map(|node| value(node)).sum();
+        let gaps = members.len().saturating_sub(1) as f32 * NODE_GAP;
+        if total > fullest {
+            fullest = total;
+            free_space = (height - gaps).max(height * 0.5);
+        }
+    }
+    let scale = if fullest > 0.0 { free_space / fullest } else { 0.0 };
+
+    let mut nodes = Vec::new();
+    let mut column_top = vec![0.0_f32; columns];
+    for &node in &order {
+        let column = node.column();
+        let node_value = value(&node);
+        let node_height = node_value * scale;
+        nodes.push(SankeyNode {
+            node,
+            position: Vec2::new(column as f32 * column_spacing, column_top[column]),
+            height: node_height,
+            value: node_value,
+        });
+        column_top[column] += node_height + NODE_GAP;
+    }
+
+    let top_of = |node: FlowNode| {
+        nodes
+            .iter()
+            .find(|placed| placed.node == node)
+            .map(|placed| placed.position)
+    };
+    let mut used_out: HashMap<FlowNode, f32> = HashMap::new();
+    let mut used_in: HashMap<FlowNode, f32> = HashMap::new();
+    let mut bands = Vec::new();
+    for flow in flows {
+        let (Some(source), Some(target)) = (top_of(flow.from), top_of(flow.to)) else {
+            continue;
+        };
+        let thickness = flow.amount * scale;
+        let out_offset = used_out.entry(flow.from).or_default();
+        let start = source + Vec2::new(BAR_WIDTH, *out_offset + thickness / 2.0);
+        *out_offset += thickness;
+        let in_offset = used_in.entry(flow.to).or_default();
+        let end = target + Vec2::new(0.0, *in_offset + thickness / 2.0);
+        *in_offset += thickness;
+        bands.push(SankeyBand {
+            from: flow.from,
+            start,
+            end,
+            thickness: thickness.max(MIN_BAND),
+        });
+    }
+
+    (nodes, bands)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bars_are_as_tall_as_their_flows_and_bands_stack() {
+        let flows = [
+            EconomicFlow {
+                from: FlowNode::Food,
+                to: FlowNode::Output,
+                amount: 60.0,
+            },
+            EconomicFlow {
+                from: FlowNode::Trade,
+                to: FlowNode::Output,
+                amount: 40.0,
+            },
+        ];
+        let (nodes, bands) = sankey_layout(&flows, 100.0, 100.0 + NODE_GAP);
+
+        // The first column holds both sources and the gap between them
+        let output = nodes.iter().find(|node| node.node == FlowNode::Output);
+        assert_eq!(output.map(|node| node.height), Some(100.0));
+        assert_eq!(output.map(|node| node.position.x), Some(100.0));
+        let trade = nodes.iter().find(|node| node.node == FlowNode::Trade);
+        assert_eq!(trade.map(|node| node.position.y), Some(60.0 + NODE_GAP));
+
+        // Bands enter the output bar one below the other
+        assert_eq!(bands[0].end, Vec2::new(100.0, 30.0));
+        assert_eq!(bands[1].end, Vec2::new(100.0, 80.0));
+    }
+}
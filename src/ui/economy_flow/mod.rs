@@ -0,0 +1,18 @@
+//! Economy flow diagram - Gateway module
+//!
+//! I opens a Sankey-style diagram of the selected nation's economy: what its
+//! provinces produce, how that output is consumed, exported, wasted, or
+//! taxed, and where the treasury's money goes. Bands are as thick as the
+//! yearly amount they carry, and the diagram follows the nation's figures
+//! as they are summed up each month.
+
+// PRIVATE modules
+mod layout;
+mod plugin;
+mod systems;
+mod types;
+mod ui;
+
+// PUBLIC exports
+pub use plugin::EconomyFlowPlugin;
+pub use types::EconomyFlowView;
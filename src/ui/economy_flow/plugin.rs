@@ -0,0 +1,22 @@
+//! Economy flow diagram plugin
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+use super::systems::*;
+use super::types::EconomyFlowView;
+use crate::states::GameState;
+
+define_plugin!(EconomyFlowPlugin {
+    resources: [EconomyFlowView],
+
+    update: [
+        (toggle_economy_flow, handle_economy_flow_buttons, refresh_economy_flow)
+            .chain()
+            .run_if(in_state(GameState::InGame))
+    ],
+
+    on_exit: {
+        GameState::InGame => [close_economy_flow]
+    }
+});
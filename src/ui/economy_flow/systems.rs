@@ -0,0 +1,107 @@
+//! Opening, closing, and redrawing the economy flow diagram
+
+use bevy::prelude::*;
+
+use super::layout::sankey_layout;
+use super::types::{CloseEconomyFlowButton, EconomyFlowPanel, EconomyFlowView};
+use super::ui::{spawn_economy_flow_panel, COLUMN_SPACING, DIAGRAM_HEIGHT};
+use crate::audio::{AudioCue, AudioEvent};
+use crate::nations::{EconomicFlows, EconomicLedger, Nation};
+use crate::ui::{SelectedNation, ShortcutEvent, ShortcutId};
+
+/// I opens and closes the economy flow diagram
+pub fn toggle_economy_flow(
+    mut shortcuts: MessageReader<ShortcutEvent>,
+    mut view: ResMut<EconomyFlowView>,
+    mut audio: MessageWriter<AudioEvent>,
+) {
+    if !shortcuts
+        .read()
+        .any(|event| event.shortcut_id == ShortcutId::ToggleEconomyFlow)
+    {
+        return;
+    }
+    view.open = !view.open;
+    let cue = if view.open { AudioCue::UiOpen } else { AudioCue::UiClose };
+    audio.write(AudioEvent::new(cue));
+}
+
+/// Close button on the diagram
+pub fn handle_economy_flow_buttons(
+    close_buttons: Query<&Interaction, (Changed<Interaction>, With<CloseEconomyFlowButton>)>,
+    mut view: ResMut<EconomyFlowView>,
+) {
+    if close_buttons
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed)
+    {
+        view.open = false;
+    }
+}
+
+/// Redraw when the diagram opens, the selection changes, or the nation's month is summed up
+pub fn refresh_economy_flow(
+    mut commands: Commands,
+    view: Res<EconomyFlowView>,
+    selected: Res<SelectedNation>,
+    nations: Query<(&Nation, Option<&EconomicLedger>, Option<&EconomicFlows>)>,
+    updated: Query<(), Changed<EconomicFlows>>,
+    panels: Query<Entity, With<EconomyFlowPanel>>,
+) {
+    let nation_updated = selected.entity.is_some_and(|entity| updated.contains(entity));
+    if !view.is_changed() && !selected.is_changed() && !(view.open && nation_updated) {
+        return;
+    }
+    for panel in &panels {
+        commands.entity(panel).despawn();
+    }
+    if !view.open {
+        return;
+    }
+
+    let Some((nation, ledger, flows)) = selected.entity.and_then(|entity| nations.get(entity).ok()) else {
+        spawn_economy_flow_panel(
+            &mut commands,
+            "Economic Flows",
+            Some("Select a nation on the map to see its economy"),
+            &[],
+            &[],
+        );
+        return;
+    };
+
+    let title = format!("Economy of {}", nation.name);
+    let Some(flows) = flows.filter(|flows| !flows.flows.is_empty()) else {
+        let note = "Flows appear once the first year's output has been allocated";
+        spawn_economy_flow_panel(&mut commands, &title, Some(note), &[], &[]);
+        return;
+    };
+
+    let trend = if flows.treasury_change >= 0.0 {
+        "growing"
+    } else {
+        "shrinking"
+    };
+    let summary = format!(
+        "Yearly amounts. {} economy producing {:.0}; treasury {:.0}, {} by {:.0} a year",
+        ledger.map_or("Unknown", |ledger| ledger.system.label()),
+        ledger.map_or(0.0, |ledger| ledger.output),
+        nation.treasury.to_f32(),
+        trend,
+        flows.treasury_change.abs()
+    );
+    let (nodes, bands) = sankey_layout(&flows.flows, COLUMN_SPACING, DIAGRAM_HEIGHT);
+    spawn_economy_flow_panel(&mut commands, &title, Some(&summary), &nodes, &bands);
+}
+
+/// Close the diagram when leaving the game
+pub fn close_economy_flow(
+    mut view: ResMut<EconomyFlowView>,
+    panels: Query<Entity, With<EconomyFlowPanel>>,
+    mut commands: Commands,
+) {
+    for panel in &panels {
+        commands.entity(panel).despawn();
+    }
+    *view = EconomyFlowView::default();
+}
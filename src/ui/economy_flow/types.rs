@@ -0,0 +1,40 @@
+//! Data types for the economy flow diagram
+
+use bevy::prelude::*;
+
+use crate::nations::FlowNode;
+
+/// Whether the diagram is open
+#[derive(Resource, Debug, Default)]
+pub struct EconomyFlowView {
+    pub open: bool,
+}
+
+/// Color of a stage and of the bands leaving it
+pub fn node_color(node: FlowNode) -> Color {
+    match node {
+        FlowNode::Labor => Color::srgb(0.75, 0.6, 0.45),
+        FlowNode::Food => Color::srgb(0.45, 0.75, 0.35),
+        FlowNode::Goods => Color::srgb(0.6, 0.6, 0.7),
+        FlowNode::Trade => Color::srgb(0.9, 0.75, 0.3),
+        FlowNode::Output => Color::srgb(0.55, 0.65, 0.8),
+        FlowNode::Consumption => Color::srgb(0.5, 0.7, 0.6),
+        FlowNode::Exports => Color::srgb(0.35, 0.65, 0.9),
+        FlowNode::Waste => Color::srgb(0.45, 0.45, 0.45),
+        FlowNode::Taxes | FlowNode::Treasury => Color::srgb(0.85, 0.7, 0.25),
+        FlowNode::OtherIncome => Color::srgb(0.6, 0.8, 0.5),
+        FlowNode::ReservesDrawn => Color::srgb(0.9, 0.35, 0.3),
+        FlowNode::ArmyUpkeep => Color::srgb(0.75, 0.35, 0.35),
+        FlowNode::Corruption => Color::srgb(0.55, 0.35, 0.6),
+        FlowNode::OtherSpending => Color::srgb(0.65, 0.55, 0.5),
+        FlowNode::ReservesSaved => Color::srgb(0.4, 0.8, 0.45),
+    }
+}
+
+/// Marker for the diagram panel root
+#[derive(Component)]
+pub struct EconomyFlowPanel;
+
+/// Close button
+#[derive(Component)]
+pub struct CloseEconomyFlowButton;
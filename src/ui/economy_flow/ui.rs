@@ -0,0 +1,133 @@
+//! Economy flow diagram UI rendering
+
+use bevy::prelude::*;
+
+use super::layout::{SankeyBand, SankeyNode, BAR_WIDTH};
+use super::types::{node_color, CloseEconomyFlowButton, EconomyFlowPanel};
+use crate::ui::graph_view::{spawn_graph_edge, spawn_graph_viewport};
+use crate::ui::*;
+
+/// Horizontal distance between stages
+pub const COLUMN_SPACING: f32 = 190.0;
+/// Height the tallest column is scaled to
+pub const DIAGRAM_HEIGHT: f32 = 460.0;
+/// Room right of the last column for its labels
+const LABEL_WIDTH: f32 = 150.0;
+
+fn line(parent: &mut ChildBuilder, text: String, color: Color) {
+    parent.spawn((
+        Text::new(text),
+        TextFont {
+            font_size: TEXT_SIZE_NORMAL,
+            ..default()
+        },
+        TextColor(color),
+    ));
+}
+
+fn spawn_bar(parent: &mut ChildBuilder, node: &SankeyNode) {
+    let color = node_color(node.node);
+    parent.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(node.position.x),
+            top: Val::Px(node.position.y),
+            width: Val::Px(BAR_WIDTH),
+            height: Val::Px(node.height.max(2.0)),
+            ..default()
+        },
+        BackgroundColor(color),
+    ));
+    parent.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(node.position.x + BAR_WIDTH + 4.0),
+            top: Val::Px(node.position.y + node.height / 2.0 - 7.0),
+            ..default()
+        },
+        Text::new(format!("{} {:.0}", node.node.label(), node.value)),
+        TextFont {
+            font_size: 11.0,
+            ..default()
+        },
+        TextColor(TEXT_COLOR_PRIMARY),
+    ));
+}
+
+/// Spawn the diagram for one nation, or a note saying why there is none
+pub fn spawn_economy_flow_panel(
+    commands: &mut Commands,
+    title: &str,
+    summary: Option<&str>,
+    nodes: &[SankeyNode],
+    bands: &[SankeyBand],
+) {
+    let columns = nodes
+        .iter()
+        .map(|node| node.node.column())
+        .max()
+        .map_or(0, |max| max + 1);
+    let width = columns.saturating_sub(1) as f32 * COLUMN_SPACING + BAR_WIDTH + LABEL_WIDTH;
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(15.0),
+                top: Val::Px(60.0),
+                width: Val::Percent(70.0),
+                height: Val::Percent(85.0),
+                padding: UiRect::all(Val::Px(20.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(UI_BACKGROUND_COLOR),
+            BorderColor::all(UI_BORDER_COLOR),
+            GlobalZIndex(layers::MODAL_OVERLAY),
+            EconomyFlowPanel,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(Node {
+                    width: Val::Percent(100.0),
+                    justify_content: JustifyContent::SpaceBetween,
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(title.to_uppercase()),
+                        TextFont {
+                            font_size: TEXT_SIZE_TITLE,
+                            ..default()
+                        },
+                        TextColor(TEXT_COLOR_HEADER),
+                    ));
+                    ButtonBuilder::new("Close")
+                        .size(ButtonSize::Small)
+                        .style(ButtonStyle::Ghost)
+                        .with_marker(CloseEconomyFlowButton)
+                        .build(parent);
+                });
+
+            if let Some(summary) = summary {
+                line(parent, summary.to_string(), TEXT_COLOR_SECONDARY);
+            }
+
+            if nodes.is_empty() {
+                return;
+            }
+            spawn_graph_viewport(parent, Vec2::new(width, DIAGRAM_HEIGHT), (), |parent| {
+                // Bands first so the bars cover their ends
+                for band in bands {
+                    let color = node_color(band.from).with_alpha(0.45);
+                    spawn_graph_edge(parent, band.start, band.end, band.thickness, color, ());
+                }
+                for node in nodes {
+                    spawn_bar(parent, node);
+                }
+            });
+        });
+}
@@ -22,6 +22,8 @@ pub fn spawn_family_browser_panel(mut commands: Commands) {
             },
             BackgroundColor(UI_BACKGROUND_COLOR),
             BorderColor::all(UI_BORDER_COLOR),
+            ThemedAccent::Border(UI_BORDER_COLOR),
+            ScreenAnchor::top_left(20.0, 100.0),
             FamilyBrowserPanel,
             Visibility::Hidden, // Start hidden
         ))
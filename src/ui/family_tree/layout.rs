@@ -3,17 +3,32 @@
 use bevy::prelude::*;
 use std::collections::{HashMap, HashSet, VecDeque};
 use crate::nations::{
-    Character, CharacterId, CharacterRole, HasRelationship,
-    RelationshipMetadata, RelationshipType,
+    Character, CharacterId, CharacterRole, Deceased, Lineage, Portrait, Reign, RelationshipType,
 };
+use crate::ui::graph_view::{layered_layout, LayeredNode};
 use super::types::*;
 
+/// Characters with everything the tree shows about them
+pub type TreeCharacterQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Character,
+        Option<&'static Lineage>,
+        Option<&'static Reign>,
+        Option<&'static Deceased>,
+        Option<&'static Portrait>,
+    ),
+>;
+
 /// Build a family tree layout for a house
+///
+/// Generations come from each character's [`Lineage`]: children sit a row
+/// below their parents and spouses share their partner's row.
 pub fn build_tree_layout(
     house_entity: Entity,
-    root_character: Option<Entity>,
-    characters: &Query<(Entity, &Character)>,
-    relationships: &Query<(&HasRelationship, &RelationshipMetadata)>,
+    characters: &TreeCharacterQuery,
     config: &TreeLayoutConfig,
 ) -> FamilyTreeLayout {
     let mut layout = FamilyTreeLayout {
@@ -21,176 +36,143 @@ pub fn build_tree_layout(
         ..default()
     };
 
-    // Find all characters in this house
-    let house_characters: Vec<(Entity, &Character)> = characters
+    // Find all characters in this house, oldest records first
+    let mut house_characters: Vec<_> = characters
         .iter()
-        .filter(|(_, c)| c.house_id == house_entity)
+        .filter(|(_, c, ..)| c.house_id == house_entity)
         .collect();
+    house_characters.sort_by_key(|(_, c, ..)| c.id.0);
 
     if house_characters.is_empty() {
         return layout;
     }
 
-    // Determine root character (founder or specified character)
-    let root_entity = root_character.unwrap_or_else(|| {
-        // Find oldest character or ruler
-        house_characters
-            .iter()
-            .max_by_key(|(_, c)| c.age)
-            .map(|(e, _)| *e)
-            .unwrap()
-    });
-
-    let root_char = house_characters
+    // The tree is centered on the living ruler, or the eldest if the line has ended
+    layout.root_character = house_characters
         .iter()
-        .find(|(e, _)| *e == root_entity)
-        .map(|(_, c)| *c);
-
-    if let Some(root) = root_char {
-        layout.root_character = Some(root.id);
+        .find(|(_, c, _, _, deceased, _)| c.role == CharacterRole::Ruler && deceased.is_none())
+        .or_else(|| house_characters.iter().max_by_key(|(_, c, ..)| c.age))
+        .map(|(_, c, ..)| c.id);
 
-        // Build relationship graph
-        let rel_graph = build_relationship_graph(&house_characters, relationships);
+    let index_of: HashMap<Entity, usize> = house_characters
+        .iter()
+        .enumerate()
+        .map(|(index, (entity, ..))| (*entity, index))
+        .collect();
+    let links: Vec<(Vec<usize>, Option<usize>)> = house_characters
+        .iter()
+        .map(|(_, _, lineage, ..)| match lineage {
+            Some(lineage) => (
+                lineage.parents.iter().filter_map(|parent| index_of.get(parent).copied()).collect(),
+                lineage.spouse.and_then(|spouse| index_of.get(&spouse).copied()),
+            ),
+            None => (Vec::new(), None),
+        })
+        .collect();
+    let generations = assign_generations(&links);
 
-        // Assign generations using BFS from root
-        let generations = assign_generations(root.id, &rel_graph);
+    let layered: Vec<LayeredNode> = links
+        .iter()
+        .zip(&generations)
+        .map(|((parents, spouse), &generation)| LayeredNode {
+            layer: generation,
+            parents: parents.clone(),
+            beside: *spouse,
+        })
+        .collect();
+    let spacing = Vec2::new(
+        config.node_width + config.horizontal_spacing,
+        config.node_height + config.vertical_spacing,
+    );
+    let positions = layered_layout(&layered, spacing);
+
+    // Create tree nodes
+    for (index, (entity, character, _, reign, deceased, portrait)) in house_characters.iter().enumerate() {
+        let reign = reign.copied();
+        layout.nodes.insert(
+            character.id,
+            TreeNode {
+                character_entity: *entity,
+                character_id: character.id,
+                name: character.name.clone(),
+                age: character.age,
+                role: character.role.clone(),
+                title: character.title.clone(),
+                is_alive: deceased.is_none() && character.health > 0.0,
+                generation: generations[index],
+                position: positions[index],
+                portrait: portrait.map(|portrait| portrait.texture.clone()),
+                reign,
+                died: deceased.map(|deceased| deceased.year),
+                cause_of_death: deceased.map(|deceased| deceased.cause.describe()),
+                on_succession_line: reign.is_some() || character.role == CharacterRole::Heir,
+            },
+        );
+    }
 
-        // Create tree nodes
-        for (entity, character) in &house_characters {
-            let generation = generations.get(&character.id).copied().unwrap_or(0);
-            layout.nodes.insert(
-                character.id,
-                TreeNode {
-                    character_entity: *entity,
-                    character_id: character.id,
-                    name: character.name.clone(),
-                    age: character.age,
-                    role: character.role.clone(),
-                    title: character.title.clone(),
-                    is_alive: character.health > 0.0,
-                    generation,
-                    position: Vec2::ZERO, // Will be calculated
-                },
-            );
+    // Parent-to-child edges, and one edge per married couple
+    let id_of = |index: usize| house_characters[index].1.id;
+    for (index, (parents, spouse)) in links.iter().enumerate() {
+        for &parent in parents {
+            layout.edges.push(TreeEdge {
+                from_id: id_of(parent),
+                to_id: id_of(index),
+                relationship: RelationshipType::Child,
+                visible: true,
+            });
         }
-
-        // Create tree edges
-        for (from_char, relationships) in &rel_graph {
-            for (to_char, rel_type) in relationships {
-                // Only add parent-child and spouse edges for now
-                match rel_type {
-                    RelationshipType::Parent
-                    | RelationshipType::Child
-                    | RelationshipType::Spouse => {
-                        layout.edges.push(TreeEdge {
-                            from_id: *from_char,
-                            to_id: *to_char,
-                            relationship: rel_type.clone(),
-                            visible: true,
-                        });
-                    }
-                    _ => {}
-                }
+        if let Some(spouse) = *spouse {
+            let mutual = links[spouse].1 == Some(index);
+            if spouse != index && (!mutual || index < spouse) {
+                layout.edges.push(TreeEdge {
+                    from_id: id_of(index),
+                    to_id: id_of(spouse),
+                    relationship: RelationshipType::Spouse,
+                    visible: true,
+                });
             }
         }
-
-        // Calculate positions
-        calculate_positions(&mut layout, config);
     }
 
+    layout.bounds = calculate_bounds(&layout);
     layout
 }
 
-/// Build a graph of relationships between characters
-fn build_relationship_graph(
-    characters: &[(Entity, &Character)],
-    relationships: &Query<(&HasRelationship, &RelationshipMetadata)>,
-) -> HashMap<CharacterId, Vec<(CharacterId, RelationshipType)>> {
-    let mut graph: HashMap<CharacterId, Vec<(CharacterId, RelationshipType)>> = HashMap::new();
-
-    for (entity, character) in characters {
-        let char_id = character.id;
-        graph.entry(char_id).or_default();
-
-        // Get all relationships for this character
-        if let Ok((has_rel, metadata)) = relationships.get(*entity) {
-            // Find the target character
-            if let Some((_, target_char)) = characters.iter().find(|(e, _)| *e == has_rel.0) {
-                graph
-                    .entry(char_id)
-                    .or_default()
-                    .push((target_char.id, metadata.relationship_type.clone()));
+/// Generation of each character, given their parents' and spouse's indices
+///
+/// A character sits one below their lowest parent, and no higher than their
+/// spouse, so people who married in share a row with their partner.
+fn assign_generations(links: &[(Vec<usize>, Option<usize>)]) -> Vec<u32> {
+    let mut generations = vec![0u32; links.len()];
+
+    // Relax until nothing moves; the pass cap guards against cyclic records
+    for _ in 0..links.len() {
+        let mut changed = false;
+        for (index, (parents, spouse)) in links.iter().enumerate() {
+            let below_parents = parents
+                .iter()
+                .filter_map(|&parent| generations.get(parent))
+                .map(|generation| generation + 1)
+                .max()
+                .unwrap_or(0);
+            let beside_spouse = spouse
+                .and_then(|spouse| generations.get(spouse))
+                .copied()
+                .unwrap_or(0);
+            let generation = below_parents.max(beside_spouse);
+            if generation > generations[index] {
+                generations[index] = generation;
+                changed = true;
             }
         }
-    }
-
-    graph
-}
-
-/// Assign generation numbers using BFS from root
-fn assign_generations(
-    root: CharacterId,
-    graph: &HashMap<CharacterId, Vec<(CharacterId, RelationshipType)>>,
-) -> HashMap<CharacterId, u32> {
-    let mut generations = HashMap::new();
-    let mut visited = HashSet::new();
-    let mut queue = VecDeque::new();
-
-    queue.push_back((root, 0u32));
-    visited.insert(root);
-
-    while let Some((char_id, generation)) = queue.pop_front() {
-        generations.insert(char_id, generation);
-
-        // Get children (relationships where type is Child)
-        if let Some(relationships) = graph.get(&char_id) {
-            for (target, rel_type) in relationships {
-                if matches!(rel_type, RelationshipType::Child) && !visited.contains(target) {
-                    visited.insert(*target);
-                    queue.push_back((*target, generation + 1));
-                }
-            }
+        if !changed {
+            break;
         }
     }
 
     generations
 }
 
-/// Calculate positions for all nodes in the tree
-fn calculate_positions(layout: &mut FamilyTreeLayout, config: &TreeLayoutConfig) {
-    let generation_count = layout.generation_count();
-
-    // For each generation, arrange characters horizontally
-    for generation_num in 0..generation_count {
-        let mut nodes_in_gen: Vec<_> = layout
-            .nodes
-            .values_mut()
-            .filter(|n| n.generation == generation_num)
-            .collect();
-
-        let node_count = nodes_in_gen.len();
-        if node_count == 0 {
-            continue;
-        }
-
-        // Calculate Y position (generation spacing)
-        let y = (generation_num as f32) * (config.node_height + config.vertical_spacing);
-
-        // Calculate X positions (spread horizontally, centered)
-        let total_width = (node_count as f32) * config.node_width
-            + ((node_count - 1) as f32) * config.horizontal_spacing;
-        let start_x = -total_width / 2.0;
-
-        for (i, node) in nodes_in_gen.iter_mut().enumerate() {
-            let x = start_x + (i as f32) * (config.node_width + config.horizontal_spacing);
-            node.position = Vec2::new(x, y);
-        }
-    }
-
-    // Calculate bounds
-    layout.bounds = calculate_bounds(layout);
-}
-
 /// Calculate the bounding box of all nodes
 fn calculate_bounds(layout: &FamilyTreeLayout) -> TreeBounds {
     let mut bounds = TreeBounds::default();
@@ -217,7 +199,7 @@ pub fn trace_bloodline(
     let mut ancestors = Vec::new();
     let mut descendants = Vec::new();
 
-    // Trace ancestors (parent relationships going backward)
+    // Trace ancestors (child edges run from parent to child, so walk them backward)
     let mut queue = VecDeque::new();
     queue.push_back(character_id);
     let mut visited = HashSet::new();
@@ -229,7 +211,7 @@ pub fn trace_bloodline(
         visited.insert(id);
 
         for edge in layout.edges_to(id) {
-            if matches!(edge.relationship, RelationshipType::Parent) {
+            if matches!(edge.relationship, RelationshipType::Child) {
                 ancestors.push(edge.from_id);
                 queue.push_back(edge.from_id);
             }
@@ -257,3 +239,21 @@ pub fn trace_bloodline(
 
     (ancestors, descendants)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn children_sit_below_parents_and_spouses_beside_partners() {
+        // Ruler and spouse, their son, his wife from another house, their grandchild
+        let links = [
+            (vec![], Some(1)),
+            (vec![], Some(0)),
+            (vec![0, 1], Some(3)),
+            (vec![], Some(2)),
+            (vec![2, 3], None),
+        ];
+        assert_eq!(assign_generations(&links), vec![0, 0, 1, 1, 2]);
+    }
+}
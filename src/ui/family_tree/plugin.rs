@@ -26,6 +26,7 @@ define_plugin!(FamilyTreePlugin {
             handle_close_tree,
             handle_close_button,
             handle_node_click,
+            handle_chronicle_links,
             apply_relationship_filters,
             update_tree_visualization,
         ).run_if(in_state(GameState::InGame))
@@ -1,7 +1,9 @@
 //! Systems for family tree interaction and management
 
 use bevy::prelude::*;
-use crate::nations::{Character, HasRelationship, RelationshipMetadata};
+use crate::chronicle::OpenNationHistory;
+use crate::nations::NationId;
+use crate::relationships::RulesOver;
 use crate::ui::family_browser::{OpenFamilyTreeEvent, CloseFamilyTreeEvent, SelectedHouseTree};
 use super::types::*;
 use super::layout::*;
@@ -18,21 +20,14 @@ pub fn handle_open_tree(
     mut selected: ResMut<SelectedHouseTree>,
     mut layout: ResMut<FamilyTreeLayout>,
     mut panel: Query<&mut Visibility, With<FamilyTreePanel>>,
-    characters: Query<(Entity, &Character)>,
-    relationships: Query<(&HasRelationship, &RelationshipMetadata)>,
+    characters: TreeCharacterQuery,
 ) {
     for event in events.read() {
         selected.house_entity = Some(event.house_entity);
 
         // Build tree layout
         let config = TreeLayoutConfig::default();
-        *layout = build_tree_layout(
-            event.house_entity,
-            None, // Use default root (oldest/ruler)
-            &characters,
-            &relationships,
-            &config,
-        );
+        *layout = build_tree_layout(event.house_entity, &characters, &config);
 
         // Show panel
         if let Ok(mut visibility) = panel.single_mut() {
@@ -100,6 +95,33 @@ pub fn handle_node_click(
     }
 }
 
+/// "Chronicle" links open the ruling nation's history at the character's year
+pub fn handle_chronicle_links(
+    links: Query<(&Interaction, &ChronicleLinkButton), Changed<Interaction>>,
+    layout: Res<FamilyTreeLayout>,
+    houses: Query<&RulesOver>,
+    nation_ids: Query<&NationId>,
+    mut history: MessageWriter<OpenNationHistory>,
+    mut close_events: MessageWriter<CloseFamilyTreeEvent>,
+) {
+    for (interaction, link) in &links {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let nation = layout
+            .house_entity
+            .and_then(|house| houses.get(house).ok())
+            .and_then(|rules_over| nation_ids.get(rules_over.0).ok());
+        if let Some(&nation) = nation {
+            history.write(OpenNationHistory {
+                nation,
+                from_year: Some(link.year),
+            });
+            close_events.write(CloseFamilyTreeEvent);
+        }
+    }
+}
+
 /// Apply relationship filters to edge visibility
 pub fn apply_relationship_filters(
     mut layout: ResMut<FamilyTreeLayout>,
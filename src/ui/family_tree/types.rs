@@ -1,7 +1,7 @@
 //! Data types for family tree visualization
 
 use bevy::prelude::*;
-use crate::nations::{Character, CharacterId, CharacterRole, RelationshipType};
+use crate::nations::{CharacterId, CharacterRole, Reign, RelationshipType};
 use std::collections::HashMap;
 
 /// A node in the family tree representing a character
@@ -16,6 +16,19 @@ pub struct TreeNode {
     pub is_alive: bool,
     pub generation: u32,
     pub position: Vec2, // Final position in tree layout
+    pub portrait: Option<Handle<Image>>,
+    pub reign: Option<Reign>,
+    pub died: Option<u32>,
+    pub cause_of_death: Option<String>,
+    /// Rulers past and present and the current heir
+    pub on_succession_line: bool,
+}
+
+impl TreeNode {
+    /// Year the chronicle link opens at: the start of a reign, or else the death
+    pub fn chronicle_year(&self) -> Option<u32> {
+        self.reign.map(|reign| reign.start_year).or(self.died)
+    }
 }
 
 /// An edge connecting two characters in the tree
@@ -108,8 +121,8 @@ pub struct TreeLayoutConfig {
 impl Default for TreeLayoutConfig {
     fn default() -> Self {
         Self {
-            node_width: 80.0,
-            node_height: 100.0,
+            node_width: 120.0,
+            node_height: 200.0,
             horizontal_spacing: 40.0,
             vertical_spacing: 60.0,
         }
@@ -173,6 +186,12 @@ pub struct RelationshipLine {
     pub relationship: RelationshipType,
 }
 
+/// "Chronicle" link on a node, opening the house's nation history at `year`
+#[derive(Component)]
+pub struct ChronicleLinkButton {
+    pub year: u32,
+}
+
 /// State for bloodline highlighting
 #[derive(Resource, Default)]
 pub struct BloodlineHighlight {
@@ -2,9 +2,15 @@
 
 use bevy::prelude::*;
 use crate::ui::*;
-use crate::nations::CharacterRole;
+use crate::ui::graph_view::{spawn_graph_edge, spawn_graph_viewport, GraphViewport};
+use crate::nations::{CharacterRole, RelationshipType};
 use super::types::*;
 
+/// Border and edge color of the line of succession
+const SUCCESSION_COLOR: Color = Color::srgb(0.85, 0.65, 0.2);
+/// Side of a node's portrait
+const PORTRAIT_PX: f32 = 48.0;
+
 /// Spawn the family tree viewer panel
 pub fn spawn_family_tree_panel(mut commands: Commands) {
     commands
@@ -55,29 +61,8 @@ pub fn spawn_family_tree_panel(mut commands: Commands) {
             // Filter controls
             spawn_tree_filters(parent);
 
-            // Tree content area (scrollable)
-            parent
-                .spawn((
-                    Node {
-                        width: Val::Percent(100.0),
-                        flex_grow: 1.0,
-                        overflow: Overflow::scroll(),
-                        ..default()
-                    },
-                    TreeContentArea,
-                ))
-                .with_children(|parent| {
-                    // Tree visualization container
-                    parent.spawn((
-                        Node {
-                            width: Val::Px(2000.0), // Large enough for tree
-                            height: Val::Px(2000.0),
-                            position_type: PositionType::Relative,
-                            ..default()
-                        },
-                        TreeVisualizationContainer,
-                    ));
-                });
+            // Tree canvas, panned by dragging and zoomed with the wheel
+            spawn_graph_viewport(parent, Vec2::splat(2000.0), TreeVisualizationContainer, |_| {});
         });
 }
 
@@ -134,6 +119,15 @@ fn spawn_tree_filters(parent: &mut ChildBuilder) {
                         ));
                     });
             }
+
+            parent.spawn((
+                Text::new("Line of succession"),
+                TextFont {
+                    font_size: TEXT_SIZE_NORMAL,
+                    ..default()
+                },
+                TextColor(SUCCESSION_COLOR),
+            ));
         });
 }
 
@@ -141,7 +135,8 @@ fn spawn_tree_filters(parent: &mut ChildBuilder) {
 pub fn update_tree_visualization(
     mut commands: Commands,
     layout: Res<FamilyTreeLayout>,
-    container: Query<Entity, With<TreeVisualizationContainer>>,
+    mut container: Query<(Entity, &mut Node, &ChildOf), With<TreeVisualizationContainer>>,
+    mut viewports: Query<&mut GraphViewport>,
     existing_nodes: Query<Entity, With<TreeNodeUI>>,
     existing_lines: Query<Entity, With<RelationshipLine>>,
     highlight: Res<BloodlineHighlight>,
@@ -150,10 +145,20 @@ pub fn update_tree_visualization(
         return;
     }
 
-    let Ok(container_entity) = container.single() else {
+    let Ok((container_entity, mut canvas, child_of)) = container.single_mut() else {
         return;
     };
 
+    // A newly opened tree starts unpanned, on a canvas just big enough for it
+    if layout.is_changed() {
+        let config = TreeLayoutConfig::default();
+        canvas.width = Val::Px(layout.bounds.max_x.max(0.0) + config.node_width);
+        canvas.height = Val::Px(layout.bounds.max_y.max(0.0) + config.node_height);
+        if let Ok(mut viewport) = viewports.get_mut(child_of.parent()) {
+            viewport.reset();
+        }
+    }
+
     // Despawn existing visualization
     for entity in existing_nodes.iter().chain(existing_lines.iter()) {
         commands.entity(entity).despawn();
@@ -182,8 +187,21 @@ pub fn update_tree_visualization(
     });
 }
 
+fn small_text(parent: &mut ChildBuilder, text: String, font_size: f32, color: Color) {
+    parent.spawn((
+        Text::new(text),
+        TextFont {
+            font_size,
+            ..default()
+        },
+        TextColor(color),
+        TextLayout::new_with_justify(Justify::Center),
+    ));
+}
+
 /// Draw a character node
 fn draw_character_node(parent: &mut ChildBuilder, node: &TreeNode, is_highlighted: bool) {
+    let config = TreeLayoutConfig::default();
     let base_color = if is_highlighted {
         Color::srgb(1.0, 0.84, 0.0) // Gold highlight
     } else if !node.is_alive {
@@ -191,6 +209,13 @@ fn draw_character_node(parent: &mut ChildBuilder, node: &TreeNode, is_highlighte
     } else {
         UI_BACKGROUND_COLOR
     };
+    let border_color = if is_highlighted {
+        Color::srgb(1.0, 0.84, 0.0)
+    } else if node.on_succession_line {
+        SUCCESSION_COLOR
+    } else {
+        UI_BORDER_COLOR
+    };
 
     parent
         .spawn((
@@ -198,47 +223,40 @@ fn draw_character_node(parent: &mut ChildBuilder, node: &TreeNode, is_highlighte
                 position_type: PositionType::Absolute,
                 left: Val::Px(node.position.x),
                 top: Val::Px(node.position.y),
-                width: Val::Px(80.0),
-                height: Val::Px(100.0),
+                width: Val::Px(config.node_width),
+                height: Val::Px(config.node_height),
                 padding: UiRect::all(Val::Px(6.0)),
                 flex_direction: FlexDirection::Column,
                 align_items: AlignItems::Center,
-                justify_content: JustifyContent::SpaceBetween,
-                border: UiRect::all(Val::Px(2.0)),
+                row_gap: Val::Px(2.0),
+                border: UiRect::all(Val::Px(if node.on_succession_line { 3.0 } else { 2.0 })),
                 ..default()
             },
             BackgroundColor(base_color),
-            BorderColor::all(if is_highlighted {
-                Color::srgb(1.0, 0.84, 0.0)
-            } else {
-                UI_BORDER_COLOR
-            }),
+            BorderColor::all(border_color),
             TreeNodeUI {
                 character_id: node.character_id,
             },
             Interaction::default(),
         ))
         .with_children(|parent| {
-            // Role icon
-            let icon = get_role_icon(&node.role);
-            parent.spawn((
-                Text::new(icon),
-                TextFont {
-                    font_size: 20.0,
-                    ..default()
-                },
-                TextColor(TEXT_COLOR_PRIMARY),
-            ));
+            // Portrait, or the role icon for characters without one
+            match &node.portrait {
+                Some(portrait) => {
+                    parent.spawn((
+                        ImageNode::new(portrait.clone()),
+                        Node {
+                            width: Val::Px(PORTRAIT_PX),
+                            height: Val::Px(PORTRAIT_PX),
+                            ..default()
+                        },
+                    ));
+                }
+                None => small_text(parent, get_role_icon(&node.role).to_string(), 20.0, TEXT_COLOR_PRIMARY),
+            }
 
             // Name
-            parent.spawn((
-                Text::new(truncate_name(&node.name, 10)),
-                TextFont {
-                    font_size: 12.0,
-                    ..default()
-                },
-                TextColor(TEXT_COLOR_PRIMARY),
-            ));
+            small_text(parent, truncate_name(&node.name, 16), 12.0, TEXT_COLOR_PRIMARY);
 
             // Age
             let age_text = if node.is_alive {
@@ -246,89 +264,79 @@ fn draw_character_node(parent: &mut ChildBuilder, node: &TreeNode, is_highlighte
             } else {
                 format!("†{}", node.age)
             };
-            parent.spawn((
-                Text::new(age_text),
-                TextFont {
-                    font_size: 10.0,
-                    ..default()
-                },
-                TextColor(TEXT_COLOR_SECONDARY),
-            ));
+            small_text(parent, age_text, 10.0, TEXT_COLOR_SECONDARY);
 
             // Title
             if let Some(title) = &node.title {
-                parent.spawn((
-                    Text::new(truncate_name(title, 8)),
-                    TextFont {
-                        font_size: 10.0,
-                        ..default()
-                    },
-                    TextColor(TEXT_COLOR_SECONDARY),
-                ));
+                small_text(parent, truncate_name(title, 14), 10.0, TEXT_COLOR_SECONDARY);
+            }
+
+            // Reign dates
+            if let Some(reign) = node.reign {
+                let end = reign.end_year.map_or(String::new(), |year| year.to_string());
+                small_text(parent, format!("r. {}–{}", reign.start_year, end), 10.0, SUCCESSION_COLOR);
+            }
+
+            // Death
+            if let (Some(year), Some(cause)) = (node.died, &node.cause_of_death) {
+                small_text(parent, format!("d. {}, {}", year, cause), 9.0, TEXT_COLOR_SECONDARY);
+            }
+
+            if let Some(year) = node.chronicle_year() {
+                ButtonBuilder::new("Chronicle")
+                    .size(ButtonSize::Small)
+                    .style(ButtonStyle::Ghost)
+                    .with_marker(ChronicleLinkButton { year })
+                    .build(parent);
             }
         });
 }
 
 /// Draw a relationship line between two nodes
+///
+/// Parents join their children from the bottom of the parent's node to the
+/// top of the child's; spouses are joined across their middles. Lines
+/// between two members of the line of succession are drawn in its color.
 fn draw_relationship_line(
     parent: &mut ChildBuilder,
     from: &TreeNode,
     to: &TreeNode,
-    relationship: &crate::nations::RelationshipType,
+    relationship: &RelationshipType,
 ) {
-    use crate::nations::RelationshipType;
+    let config = TreeLayoutConfig::default();
+    let half_width = config.node_width / 2.0;
+    let succession = from.on_succession_line && to.on_succession_line;
 
-    let color = match relationship {
-        RelationshipType::Parent | RelationshipType::Child => Color::srgb(0.8, 0.8, 0.8),
-        RelationshipType::Spouse => Color::srgb(1.0, 0.5, 0.5), // Pink
-        _ => Color::srgb(0.5, 0.5, 0.5),
-    };
-
-    // Simple vertical line for parent-child
-    if matches!(relationship, RelationshipType::Parent | RelationshipType::Child) {
-        let from_center = from.position + Vec2::new(40.0, 100.0);
-        let to_center = to.position + Vec2::new(40.0, 0.0);
-
-        // Vertical line
-        parent.spawn((
-            Node {
-                position_type: PositionType::Absolute,
-                left: Val::Px(from_center.x - 1.0),
-                top: Val::Px(from_center.y),
-                width: Val::Px(2.0),
-                height: Val::Px(to_center.y - from_center.y),
-                ..default()
-            },
-            BackgroundColor(color),
-            RelationshipLine {
-                from_id: from.character_id,
-                to_id: to.character_id,
-                relationship: relationship.clone(),
+    let (start, end, color) = match relationship {
+        RelationshipType::Parent | RelationshipType::Child => (
+            from.position + Vec2::new(half_width, config.node_height),
+            to.position + Vec2::new(half_width, 0.0),
+            if succession {
+                SUCCESSION_COLOR
+            } else {
+                Color::srgb(0.8, 0.8, 0.8)
             },
-        ));
-    }
-    // Horizontal line for spouses (same generation)
-    else if matches!(relationship, RelationshipType::Spouse) {
-        let from_center = from.position + Vec2::new(80.0, 50.0);
-        let to_center = to.position + Vec2::new(0.0, 50.0);
+        ),
+        RelationshipType::Spouse => (
+            from.position + Vec2::new(half_width, config.node_height / 2.0),
+            to.position + Vec2::new(half_width, config.node_height / 2.0),
+            Color::srgb(1.0, 0.5, 0.5), // Pink
+        ),
+        _ => return,
+    };
 
-        parent.spawn((
-            Node {
-                position_type: PositionType::Absolute,
-                left: Val::Px(from_center.x),
-                top: Val::Px(from_center.y - 1.0),
-                width: Val::Px((to_center.x - from_center.x).abs()),
-                height: Val::Px(2.0),
-                ..default()
-            },
-            BackgroundColor(color),
-            RelationshipLine {
-                from_id: from.character_id,
-                to_id: to.character_id,
-                relationship: relationship.clone(),
-            },
-        ));
-    }
+    spawn_graph_edge(
+        parent,
+        start,
+        end,
+        if succession { 3.0 } else { 2.0 },
+        color,
+        RelationshipLine {
+            from_id: from.character_id,
+            to_id: to.character_id,
+            relationship: relationship.clone(),
+        },
+    );
 }
 
 /// Get icon for character role
@@ -360,10 +368,6 @@ fn truncate_name(name: &str, max_len: usize) -> String {
 #[derive(Component)]
 pub struct CloseTreeButton;
 
-/// Marker for tree content area
-#[derive(Component)]
-pub struct TreeContentArea;
-
 /// Marker for tree visualization container
 #[derive(Component)]
 pub struct TreeVisualizationContainer;
@@ -0,0 +1,16 @@
+//! Follow breadcrumbs - Gateway module
+//!
+//! While the camera follows something across the map, a breadcrumb bar
+//! at the top of the screen shows what is being followed, whose it is, and
+//! where it is: "World › Velmar › Army: Army of the Kost Front in Northern
+//! Vales, Asteria". Clicking "World" or "Stop" leaves follow mode where the
+//! camera is; clicking the nation leaves it and selects that nation.
+
+// PRIVATE modules
+mod plugin;
+mod systems;
+mod types;
+mod ui;
+
+// PUBLIC exports
+pub use plugin::FollowBreadcrumbsPlugin;
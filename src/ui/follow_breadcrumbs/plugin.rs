@@ -0,0 +1,19 @@
+//! Follow breadcrumbs plugin
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+use super::systems::*;
+use crate::states::GameState;
+
+define_plugin!(FollowBreadcrumbsPlugin {
+    update: [
+        (handle_breadcrumb_clicks, refresh_follow_breadcrumbs)
+            .chain()
+            .run_if(in_state(GameState::InGame))
+    ],
+
+    on_exit: {
+        GameState::InGame => [despawn_follow_breadcrumbs]
+    }
+});
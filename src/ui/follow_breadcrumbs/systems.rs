@@ -0,0 +1,72 @@
+//! Showing the breadcrumb bar while following, and acting on its crumbs
+
+use bevy::prelude::*;
+
+use super::types::{Breadcrumb, FollowBreadcrumbBar};
+use super::ui::spawn_follow_breadcrumbs;
+use crate::camera::{CameraFollow, Followable};
+use crate::nations::{Nation, NationId};
+use crate::ui::SelectedNation;
+use crate::world::{ProvincesSpatialIndex, RegionHierarchy};
+
+/// "World" and "Stop" leave follow mode; the nation crumb also selects the nation
+pub fn handle_breadcrumb_clicks(
+    crumbs: Query<(&Interaction, &Breadcrumb), Changed<Interaction>>,
+    nation_ids: Query<&NationId>,
+    mut follow: ResMut<CameraFollow>,
+    mut selected: ResMut<SelectedNation>,
+) {
+    for (interaction, crumb) in &crumbs {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        follow.stop();
+        if let Breadcrumb::Nation(nation) = *crumb {
+            if let Ok(nation_id) = nation_ids.get(nation) {
+                selected.entity = Some(nation);
+                selected.nation_id = Some(*nation_id);
+            }
+        }
+    }
+}
+
+/// Rebuild the bar when follow mode starts or stops, the followed entity is renamed, or it crosses into another region
+pub fn refresh_follow_breadcrumbs(
+    mut commands: Commands,
+    follow: Res<CameraFollow>,
+    followables: Query<(&Followable, &Transform)>,
+    renamed: Query<(), Changed<Followable>>,
+    nations: Query<&Nation>,
+    hierarchy: Res<RegionHierarchy>,
+    spatial: Res<ProvincesSpatialIndex>,
+    bars: Query<Entity, With<FollowBreadcrumbBar>>,
+    mut shown_region: Local<Option<usize>>,
+) {
+    let followed = follow.target().and_then(|target| followables.get(target).ok());
+    let region = followed.and_then(|(_, transform)| hierarchy.region_at(&spatial, transform.translation.truncate()));
+    let target_renamed = follow.target().is_some_and(|target| renamed.contains(target));
+    if !follow.is_changed() && !target_renamed && *shown_region == region {
+        return;
+    }
+    *shown_region = region;
+    for bar in &bars {
+        commands.entity(bar).despawn();
+    }
+    let Some((followed, _)) = followed else {
+        return;
+    };
+
+    let nation = followed
+        .owner
+        .and_then(|owner| nations.get(owner).ok().map(|nation| (owner, nation.name.as_str())));
+    let current = format!("{}: {}", followed.kind.label(), followed.label);
+    let location = region.and_then(|region| hierarchy.describe_region(region));
+    spawn_follow_breadcrumbs(&mut commands, nation, &current, location.as_deref());
+}
+
+/// Remove the bar when leaving the game
+pub fn despawn_follow_breadcrumbs(mut commands: Commands, bars: Query<Entity, With<FollowBreadcrumbBar>>) {
+    for bar in &bars {
+        commands.entity(bar).despawn();
+    }
+}
@@ -0,0 +1,16 @@
+//! Data types for the follow breadcrumbs
+
+use bevy::prelude::*;
+
+/// Marker for the breadcrumb bar root
+#[derive(Component)]
+pub struct FollowBreadcrumbBar;
+
+/// A clickable breadcrumb
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breadcrumb {
+    /// Leave follow mode
+    World,
+    /// Leave follow mode and select the nation
+    Nation(Entity),
+}
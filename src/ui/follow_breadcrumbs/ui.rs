@@ -0,0 +1,86 @@
+//! Follow breadcrumbs UI rendering
+
+use bevy::prelude::*;
+
+use super::types::{Breadcrumb, FollowBreadcrumbBar};
+use crate::ui::*;
+
+fn separator(parent: &mut ChildBuilder) {
+    parent.spawn((
+        Text::new("›"),
+        TextFont {
+            font_size: TEXT_SIZE_NORMAL,
+            ..default()
+        },
+        TextColor(TEXT_COLOR_SECONDARY),
+    ));
+}
+
+/// Spawn the bar centered at the top of the screen
+///
+/// `nation` is the followed entity's owner, if it has one; `current` names
+/// the followed entity itself, and `location` the region it is passing through.
+pub fn spawn_follow_breadcrumbs(
+    commands: &mut Commands,
+    nation: Option<(Entity, &str)>,
+    current: &str,
+    location: Option<&str>,
+) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(50.0),
+                top: Val::Px(10.0),
+                padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                column_gap: Val::Px(8.0),
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            UiTransform::from_translation(Val2::percent(-50.0, 0.0)),
+            BackgroundColor(UI_BACKGROUND_COLOR),
+            BorderColor::all(UI_BORDER_COLOR),
+            GlobalZIndex(layers::GAME_UI),
+            FollowBreadcrumbBar,
+        ))
+        .with_children(|parent| {
+            ButtonBuilder::new("World")
+                .size(ButtonSize::Small)
+                .style(ButtonStyle::Ghost)
+                .with_marker(Breadcrumb::World)
+                .build(parent);
+            if let Some((entity, name)) = nation {
+                separator(parent);
+                ButtonBuilder::new(name)
+                    .size(ButtonSize::Small)
+                    .style(ButtonStyle::Ghost)
+                    .with_marker(Breadcrumb::Nation(entity))
+                    .build(parent);
+            }
+            separator(parent);
+            parent.spawn((
+                Text::new(current.to_string()),
+                TextFont {
+                    font_size: TEXT_SIZE_NORMAL,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR_HEADER),
+            ));
+            if let Some(location) = location {
+                parent.spawn((
+                    Text::new(format!("in {}", location)),
+                    TextFont {
+                        font_size: TEXT_SIZE_NORMAL,
+                        ..default()
+                    },
+                    TextColor(TEXT_COLOR_SECONDARY),
+                ));
+            }
+            ButtonBuilder::new("Stop")
+                .size(ButtonSize::Small)
+                .style(ButtonStyle::Secondary)
+                .with_marker(Breadcrumb::World)
+                .build(parent);
+        });
+}
@@ -0,0 +1,41 @@
+//! Drawing edges between graph nodes
+
+use bevy::prelude::*;
+
+use crate::ui::ChildBuilder;
+
+/// Spawn a straight edge from `from` to `to` on a canvas
+///
+/// The edge is a thin bar centered between the two points and rotated to
+/// join them, so it can run at any angle. Spawn edges before nodes so the
+/// nodes are drawn over their ends.
+pub fn spawn_graph_edge(
+    parent: &mut ChildBuilder,
+    from: Vec2,
+    to: Vec2,
+    thickness: f32,
+    color: Color,
+    marker: impl Bundle,
+) -> Entity {
+    let offset = to - from;
+    let length = offset.length();
+    let middle = (from + to) / 2.0;
+    parent
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(middle.x - length / 2.0),
+                top: Val::Px(middle.y - thickness / 2.0),
+                width: Val::Px(length),
+                height: Val::Px(thickness),
+                ..default()
+            },
+            UiTransform {
+                rotation: Rot2::radians(offset.y.atan2(offset.x)),
+                ..default()
+            },
+            BackgroundColor(color),
+            marker,
+        ))
+        .id()
+}
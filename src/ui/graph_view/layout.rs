@@ -0,0 +1,134 @@
+//! Layered layout: generations in rows, children under their parents
+
+use bevy::prelude::*;
+use std::collections::BTreeMap;
+
+/// A node to place in a layered layout
+#[derive(Debug, Clone, Default)]
+pub struct LayeredNode {
+    /// Row of the node, 0 at the top
+    pub layer: u32,
+    /// Nodes in earlier layers this node hangs from, by index
+    pub parents: Vec<usize>,
+    /// A node in the same layer to sit directly right of, such as a spouse
+    pub beside: Option<usize>,
+}
+
+/// Top-left position of every node, in input order
+///
+/// Each row is laid out left to right: a node goes under the middle of its
+/// parents (or next to the node it sits beside) and is pushed right only as
+/// far as it takes to keep `spacing.x` from its neighbour. Nodes with neither
+/// keep their input order at the end of the row. The result starts at the
+/// origin.
+pub fn layered_layout(nodes: &[LayeredNode], spacing: Vec2) -> Vec<Vec2> {
+    let mut positions = vec![Vec2::ZERO; nodes.len()];
+    let mut layers: BTreeMap<u32, Vec<usize>> = BTreeMap::new();
+    for (index, node) in nodes.iter().enumerate() {
+        layers.entry(node.layer).or_default().push(index);
+    }
+
+    for (&layer, members) in &layers {
+        let placed_parent_x = |index: usize| {
+            let parents: Vec<f32> = nodes[index]
+                .parents
+                .iter()
+                .filter(|&&parent| nodes.get(parent).is_some_and(|p| p.layer < layer))
+                .map(|&parent| positions[parent].x)
+                .collect();
+            (!parents.is_empty()).then(|| parents.iter().sum::<f32>() / parents.len() as f32)
+        };
+
+        // The node another sits beside; of two beside each other, the later follows
+        let follows = |index: usize| {
+            nodes[index].beside.filter(|&other| {
+                other != index && members.contains(&other) && (nodes[other].beside != Some(index) || other < index)
+            })
+        };
+
+        // Nodes with parents in parent order, the rest after; partners follow their node
+        let mut anchored: Vec<(f32, usize)> = Vec::new();
+        let mut loose = Vec::new();
+        for &index in members {
+            if follows(index).is_some() {
+                continue;
+            }
+            match placed_parent_x(index) {
+                Some(x) => anchored.push((x, index)),
+                None => loose.push(index),
+            }
+        }
+        anchored.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut row: Vec<(Option<f32>, usize)> = Vec::new();
+        for (desired, index) in anchored
+            .into_iter()
+            .map(|(x, index)| (Some(x), index))
+            .chain(loose.into_iter().map(|index| (None, index)))
+        {
+            row.push((desired, index));
+            for &partner in members {
+                if follows(partner) == Some(index) {
+                    row.push((None, partner));
+                }
+            }
+        }
+
+        let y = layer as f32 * spacing.y;
+        let mut next_free = f32::MIN;
+        for (desired, index) in row {
+            let x = match desired {
+                Some(x) if next_free == f32::MIN => x,
+                Some(x) => x.max(next_free),
+                None if next_free == f32::MIN => 0.0,
+                None => next_free,
+            };
+            positions[index] = Vec2::new(x, y);
+            next_free = x + spacing.x;
+        }
+    }
+
+    let min_x = positions.iter().map(|p| p.x).fold(f32::INFINITY, f32::min);
+    if min_x.is_finite() {
+        for position in &mut positions {
+            position.x -= min_x;
+        }
+    }
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn children_sit_under_their_parents_and_spouses_beside() {
+        let nodes = [
+            // A ruler and spouse
+            LayeredNode::default(),
+            LayeredNode {
+                beside: Some(0),
+                ..default()
+            },
+            // Their two children
+            LayeredNode {
+                layer: 1,
+                parents: vec![0, 1],
+                ..default()
+            },
+            LayeredNode {
+                layer: 1,
+                parents: vec![0, 1],
+                ..default()
+            },
+        ];
+        let spacing = Vec2::new(100.0, 150.0);
+        let positions = layered_layout(&nodes, spacing);
+
+        assert_eq!(positions[0], Vec2::ZERO);
+        assert_eq!(positions[1], Vec2::new(100.0, 0.0));
+        // The first child goes under the couple's middle, the second beside it
+        assert_eq!(positions[2], Vec2::new(50.0, 150.0));
+        assert_eq!(positions[3], Vec2::new(150.0, 150.0));
+    }
+}
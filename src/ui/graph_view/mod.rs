@@ -0,0 +1,19 @@
+//! Graph view widget - Gateway module
+//!
+//! A reusable view for node-and-edge diagrams: a clipped viewport the mouse
+//! wheel zooms and a left drag pans, a canvas inside it that callers draw
+//! nodes onto, straight edges at any angle, and a layered layout that places
+//! generations in rows with each node centered under its parents. The family
+//! tree and the diplomatic relations graph are both drawn with it.
+
+// PRIVATE modules
+mod draw;
+mod layout;
+mod plugin;
+mod viewport;
+
+// PUBLIC exports
+pub use draw::spawn_graph_edge;
+pub use layout::{layered_layout, LayeredNode};
+pub use plugin::GraphViewPlugin;
+pub use viewport::{spawn_graph_viewport, GraphCanvas, GraphViewport};
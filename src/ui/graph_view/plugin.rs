@@ -0,0 +1,10 @@
+//! Graph view plugin
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+use super::viewport::{apply_graph_viewports, pan_zoom_graph_viewports};
+
+define_plugin!(GraphViewPlugin {
+    update: [(pan_zoom_graph_viewports, apply_graph_viewports).chain()]
+});
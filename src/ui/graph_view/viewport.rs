@@ -0,0 +1,134 @@
+//! Pannable, zoomable viewport holding a graph canvas
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::input::InputActions;
+use crate::settings::GameSettings;
+use crate::ui::ChildBuilder;
+
+/// Zoom change per wheel step
+const ZOOM_STEP: f32 = 0.1;
+const MIN_ZOOM: f32 = 0.25;
+const MAX_ZOOM: f32 = 3.0;
+
+/// How far a viewport's canvas is panned and zoomed
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct GraphViewport {
+    /// Offset of the canvas from its resting place, in pixels
+    pub pan: Vec2,
+    pub zoom: f32,
+    /// Cursor position the current drag last moved from
+    drag_from: Option<Vec2>,
+}
+
+impl Default for GraphViewport {
+    fn default() -> Self {
+        Self {
+            pan: Vec2::ZERO,
+            zoom: 1.0,
+            drag_from: None,
+        }
+    }
+}
+
+impl GraphViewport {
+    /// Back to the canvas's resting place at full size, e.g. when a new graph is drawn
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// The canvas inside a viewport that nodes and edges are drawn on
+#[derive(Component)]
+pub struct GraphCanvas;
+
+/// Spawn a viewport filling the space left in `parent`, with a canvas of `size` centered in it
+///
+/// `canvas` is added to the canvas entity (a marker to find it by), and
+/// `build` draws onto it; nodes and edges are positioned absolutely in canvas
+/// pixels. Callers can resize the canvas later through its `Node`.
+pub fn spawn_graph_viewport(
+    parent: &mut ChildBuilder,
+    size: Vec2,
+    canvas: impl Bundle,
+    build: impl FnOnce(&mut ChildBuilder),
+) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                flex_grow: 1.0,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            Interaction::default(),
+            GraphViewport::default(),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(size.x),
+                        height: Val::Px(size.y),
+                        flex_shrink: 0.0,
+                        ..default()
+                    },
+                    UiTransform::default(),
+                    GraphCanvas,
+                    canvas,
+                ))
+                .with_children(build);
+        });
+}
+
+/// The mouse wheel or a pinch zooms a hovered viewport and a left drag on it pans
+pub fn pan_zoom_graph_viewports(
+    mut viewports: Query<(&Interaction, &mut GraphViewport)>,
+    actions: Res<InputActions>,
+    settings: Res<GameSettings>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+) {
+    let cursor = windows.single().ok().and_then(Window::cursor_position);
+    // Zoom follows the same wheel direction as the map camera
+    let direction = if settings.controls.invert_zoom { -1.0 } else { 1.0 };
+    let zoom_factor = (1.0 + actions.zoom_steps * direction * ZOOM_STEP) * (1.0 + actions.pinch).max(0.1);
+
+    for (interaction, mut viewport) in &mut viewports {
+        let hovered = *interaction != Interaction::None;
+        if hovered && zoom_factor != 1.0 {
+            viewport.zoom = (viewport.zoom * zoom_factor).clamp(MIN_ZOOM, MAX_ZOOM);
+        }
+
+        // A release anywhere ends the drag, as with the map's middle-button drag
+        if hovered && mouse_button.just_pressed(MouseButton::Left) {
+            viewport.bypass_change_detection().drag_from = cursor;
+        } else if !mouse_button.pressed(MouseButton::Left) {
+            viewport.bypass_change_detection().drag_from = None;
+        }
+        if let (Some(from), Some(cursor)) = (viewport.drag_from, cursor) {
+            if from != cursor {
+                viewport.pan += cursor - from;
+                viewport.drag_from = Some(cursor);
+            }
+        }
+    }
+}
+
+/// Move and scale each canvas to its viewport's pan and zoom
+pub fn apply_graph_viewports(
+    viewports: Query<(&GraphViewport, &Children), Changed<GraphViewport>>,
+    mut canvases: Query<&mut UiTransform, With<GraphCanvas>>,
+) {
+    for (viewport, children) in &viewports {
+        for &child in children {
+            if let Ok(mut transform) = canvases.get_mut(child) {
+                transform.translation = Val2::px(viewport.pan.x, viewport.pan.y);
+                transform.scale = Vec2::splat(viewport.zoom);
+            }
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Date selector for the Historical Borders map mode
+//!
+//! A compact row of step buttons under the map mode switcher. It is only
+//! visible while the Historical Borders overlay is active.
+
+use crate::resources::MapMode;
+use crate::ui::{ButtonBuilder, ButtonSize, ChildBuilder, LabelBuilder, LabelStyle};
+use crate::world::{BorderHistory, HistoricalBordersView};
+use bevy::prelude::*;
+
+/// Marker for the selector container (shown only in Historical Borders mode)
+#[derive(Component, Reflect)]
+pub struct HistorySelectorDisplay;
+
+/// Marker for the label showing the viewed year
+#[derive(Component)]
+pub struct HistoryYearText;
+
+/// Button that moves the viewed date by a number of years
+#[derive(Component, Clone, Copy)]
+pub struct HistoryStepButton {
+    pub years: i32,
+}
+
+/// Button that toggles ghosting historical borders over current ones
+#[derive(Component, Clone, Copy)]
+pub struct HistoryGhostToggleButton;
+
+/// Spawn the date selector (hidden until the mode is selected)
+pub fn spawn_history_selector(parent: &mut ChildBuilder) {
+    parent
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::End,
+                row_gap: Val::Px(4.0),
+                margin: UiRect::top(Val::Px(6.0)),
+                display: Display::None,
+                ..default()
+            },
+            HistorySelectorDisplay,
+        ))
+        .with_children(|selector| {
+            let year_entity = LabelBuilder::new("Year -")
+                .style(LabelStyle::Body)
+                .font_size(14.0)
+                .color(Color::srgba(0.9, 0.85, 0.6, 1.0))
+                .build(selector);
+            selector.commands().entity(year_entity).insert(HistoryYearText);
+
+            selector
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(4.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    for (label, years) in [("<<", -50), ("<", -5), (">", 5), (">>", 50)] {
+                        ButtonBuilder::new(label)
+                            .size(ButtonSize::Small)
+                            .with_marker(HistoryStepButton { years })
+                            .build(row);
+                    }
+                });
+
+            ButtonBuilder::new("Ghost Current Borders")
+                .size(ButtonSize::Small)
+                .with_marker(HistoryGhostToggleButton)
+                .build(selector);
+        });
+}
+
+/// Show the selector only while Historical Borders mode is active
+pub fn update_history_selector_visibility(
+    map_mode: Res<MapMode>,
+    mut query: Query<&mut Node, With<HistorySelectorDisplay>>,
+) {
+    if !map_mode.is_changed() {
+        return;
+    }
+
+    if let Ok(mut node) = query.single_mut() {
+        node.display = if *map_mode == MapMode::HistoricalBorders {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+}
+
+/// Handle step and ghost toggle buttons
+pub fn handle_history_selector_buttons(
+    step_buttons: Query<(&Interaction, &HistoryStepButton), Changed<Interaction>>,
+    ghost_buttons: Query<&Interaction, (Changed<Interaction>, With<HistoryGhostToggleButton>)>,
+    history: Res<BorderHistory>,
+    mut view: ResMut<HistoricalBordersView>,
+) {
+    for (interaction, button) in &step_buttons {
+        if *interaction == Interaction::Pressed {
+            view.step(button.years, &history);
+            debug!("Historical borders view moved to year {}", view.year);
+        }
+    }
+
+    for interaction in &ghost_buttons {
+        if *interaction == Interaction::Pressed {
+            view.ghost_current = !view.ghost_current;
+            debug!("Historical border ghosting: {}", view.ghost_current);
+        }
+    }
+}
+
+/// Keep the year label in sync with the viewed date
+pub fn update_history_year_text(
+    view: Res<HistoricalBordersView>,
+    history: Res<BorderHistory>,
+    mut query: Query<&mut Text, With<HistoryYearText>>,
+) {
+    if !view.is_changed() && !history.is_changed() {
+        return;
+    }
+
+    if let Ok(mut text) = query.single_mut() {
+        **text = match history.snapshot_at(view.year) {
+            Some(snapshot) => format!(
+                "Year {} ({} nations)",
+                snapshot.year,
+                snapshot.palette.len()
+            ),
+            None => "No history recorded yet".to_string(),
+        };
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::ui::{ChildBuilder, ButtonBuilder, ButtonStyle, LabelBuilder, LabelStyle};
 use crate::resources::MapMode;
+use crate::world::HeatmapRegistry;
 use bevy::prelude::*;
 
 /// Marker component for the map mode display
@@ -29,19 +30,6 @@ pub struct MapModeDropdownState {
     pub clicked_this_frame: bool,
 }
 
-/// Get all available map modes in display order
-fn get_all_map_modes() -> Vec<MapMode> {
-    vec![
-        MapMode::Political,
-        MapMode::Terrain,
-        MapMode::Climate,
-        MapMode::Population,
-        MapMode::Agriculture,
-        MapMode::Infrastructure,
-        MapMode::Minerals,
-    ]
-}
-
 /// Spawn the map mode display UI element
 pub fn spawn_map_mode_display(parent: &mut ChildBuilder) {
     // Main container for the entire map mode display
@@ -84,7 +72,7 @@ pub fn spawn_map_mode_display(parent: &mut ChildBuilder) {
     });
 }
 
-/// Spawn the dropdown menu (initially hidden)
+/// Spawn the dropdown menu (initially hidden), filled from the heatmap registry
 fn spawn_dropdown_menu(parent: &mut ChildBuilder) {
     parent.spawn((
         Node {
@@ -103,22 +91,51 @@ fn spawn_dropdown_menu(parent: &mut ChildBuilder) {
         BorderColor::all(Color::srgba(0.3, 0.3, 0.3, 1.0)),
         ZIndex(1000),
         MapModeDropdown,
-    )).with_children(|dropdown| {
-        // Create dropdown items for each map mode
-        for mode in get_all_map_modes() {
-            let item_entity = ButtonBuilder::new(mode.display_name())
+    ));
+}
+
+/// List every registered map mode, the game's own first, rebuilt as heatmaps come and go
+pub fn sync_map_mode_dropdown_items(
+    mut commands: Commands,
+    registry: Res<HeatmapRegistry>,
+    dropdown_query: Query<Entity, With<MapModeDropdown>>,
+    items: Query<Entity, With<MapModeDropdownItem>>,
+    mut listed: Local<Vec<(MapMode, String)>>,
+) {
+    let Ok(dropdown) = dropdown_query.single() else {
+        return;
+    };
+    let mut entries: Vec<(MapMode, String)> = registry
+        .iter()
+        .filter_map(|(id, heatmap)| registry.mode(id).map(|mode| (mode, heatmap.name.clone())))
+        .collect();
+    // Stable, so each group keeps registration order
+    entries.sort_by_key(|(mode, _)| matches!(mode, MapMode::Heatmap(_)));
+    // Publishing values changes the registry every update; only respawn when the list differs
+    // or the HUD was rebuilt without the items
+    if *listed == entries && items.iter().count() == entries.len() {
+        return;
+    }
+    for item in &items {
+        commands.entity(item).despawn();
+    }
+    commands.entity(dropdown).with_children(|dropdown| {
+        for (mode, name) in &entries {
+            let item_entity = ButtonBuilder::new(name.clone())
                 .style(ButtonStyle::Ghost)
                 .width(Val::Px(154.0))
                 .build(dropdown);
 
-            dropdown.commands().entity(item_entity).insert(MapModeDropdownItem { mode });
+            dropdown.commands().entity(item_entity).insert(MapModeDropdownItem { mode: *mode });
         }
     });
+    *listed = entries;
 }
 
 /// Update the map mode display text
 pub fn update_map_mode_display(
     current_map_mode: Res<MapMode>,
+    registry: Res<HeatmapRegistry>,
     button_query: Query<&Children, With<MapModeButton>>,
     mut text_query: Query<&mut Text>,
 ) {
@@ -128,7 +145,7 @@ pub fn update_map_mode_display(
             // Find the Text component in the button's children
             for child in children.iter() {
                 if let Ok(mut text) = text_query.get_mut(child) {
-                    **text = current_map_mode.display_name().to_string();
+                    **text = registry.mode_name(*current_map_mode).to_string();
                     break; // Found and updated the text, we're done
                 }
             }
@@ -231,7 +248,14 @@ pub fn handle_map_mode_shortcut(
     use crate::ui::ShortcutId;
 
     for event in shortcut_events.read() {
-        if event.shortcut_id == ShortcutId::MapModeToggle {
+        if event.shortcut_id == ShortcutId::MapModeCycle {
+            // Step through every overlay, including the Military supply view
+            current_map_mode.cycle();
+            dropdown_state.is_open = false;
+            dropdown_state.clicked_this_frame = false;
+
+            debug!("Map mode cycled to: {:?}", *current_map_mode);
+        } else if event.shortcut_id == ShortcutId::MapModeToggle {
             // Quick toggle between Political and Terrain (most common switch)
             *current_map_mode = match *current_map_mode {
                 MapMode::Political => MapMode::Terrain,
@@ -7,6 +7,7 @@
 
 // Submodules - all private, exposed through plugin
 mod control_hints;
+mod history_selector;
 mod map_mode_display;
 mod plugin;
 mod setup;
@@ -4,7 +4,7 @@ use crate::states::GameState;
 use bevy::prelude::*;
 use bevy_plugin_builder::define_plugin;
 
-use super::{control_hints, map_mode_display, setup, speed_display, time_display};
+use super::{control_hints, history_selector, map_mode_display, setup, speed_display, time_display};
 
 // Plugin that manages all HUD elements.
 define_plugin!(HudPlugin {
@@ -16,7 +16,8 @@ define_plugin!(HudPlugin {
         time_display::GameTimeDisplay,
         speed_display::GameSpeedDisplay,
         control_hints::ControlHintsText,
-        map_mode_display::MapModeDisplay
+        map_mode_display::MapModeDisplay,
+        history_selector::HistorySelectorDisplay
     ],
 
     update: [
@@ -24,6 +25,7 @@ define_plugin!(HudPlugin {
          speed_display::update_speed_display,
          control_hints::update_control_hints,
          // Map mode systems with explicit ordering to prevent race conditions
+         map_mode_display::sync_map_mode_dropdown_items,
          map_mode_display::handle_map_mode_button,
          map_mode_display::handle_dropdown_item_clicks
             .before(map_mode_display::handle_dropdown_close),
@@ -31,7 +33,11 @@ define_plugin!(HudPlugin {
             .before(map_mode_display::handle_map_mode_shortcut),
          map_mode_display::handle_map_mode_shortcut
             .before(map_mode_display::update_map_mode_display),
-         map_mode_display::update_map_mode_display).run_if(in_state(GameState::InGame))
+         map_mode_display::update_map_mode_display).run_if(in_state(GameState::InGame)),
+        // Historical borders date selector
+        (history_selector::update_history_selector_visibility,
+         history_selector::handle_history_selector_buttons,
+         history_selector::update_history_year_text).run_if(in_state(GameState::InGame))
     ],
 
     on_enter: {
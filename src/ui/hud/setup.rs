@@ -1,10 +1,13 @@
 //! HUD setup and cleanup systems
 
-use super::super::{PanelBuilder, PanelStyle};
-use super::{control_hints, map_mode_display, speed_display, time_display};
+use super::super::{PanelBuilder, PanelStyle, ScreenAnchor, ThemedAccent};
+use super::{control_hints, history_selector, map_mode_display, speed_display, time_display};
 use crate::states::GameState;
 use bevy::prelude::*;
 
+/// Background of the HUD toolbar, before theming
+const HUD_BACKGROUND: Color = Color::srgba(0.05, 0.05, 0.05, 0.85);
+
 /// Setup all HUD elements
 pub fn setup_hud(mut commands: Commands) {
     // Top-right HUD container using PanelBuilder
@@ -18,16 +21,17 @@ pub fn setup_hud(mut commands: Commands) {
                 right: Val::Px(10.0),
                 ..default()
             },
+            ScreenAnchor::top_right(10.0, 10.0),
             ZIndex(100),
         ))
         .with_children(|parent| {
             // Create panel with consistent styling using PanelBuilder
-            PanelBuilder::new()
+            let hud_panel = PanelBuilder::new()
                 .style(PanelStyle::Transparent)
                 .flex_direction(FlexDirection::Column)
                 .align_items(AlignItems::End)
                 .padding(UiRect::all(Val::Px(8.0)))
-                .background_color(Color::srgba(0.05, 0.05, 0.05, 0.85))
+                .background_color(HUD_BACKGROUND)
                 .build_with_children(parent, |panel| {
                     // Add family browser toggle button
                     crate::ui::family_browser::spawn_toggle_button(panel);
@@ -41,8 +45,12 @@ pub fn setup_hud(mut commands: Commands) {
                     // Add map mode display
                     map_mode_display::spawn_map_mode_display(panel);
 
+                    // Add historical borders date selector (hidden until that mode is active)
+                    history_selector::spawn_history_selector(panel);
+
                     // Add control hints
                     control_hints::spawn_control_hints(panel);
                 });
+            parent.commands().entity(hud_panel).insert(ThemedAccent::Background(HUD_BACKGROUND));
         });
 }
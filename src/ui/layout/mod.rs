@@ -0,0 +1,16 @@
+//! Responsive Layout - Gateway module
+//!
+//! Fits the interface to whatever screen it is shown on. The UI is designed
+//! against a 1920x1080 reference; at runtime the layout scales it with the
+//! window's resolution, keeps panels inside a configurable safe area, and on
+//! ultrawide screens either spreads panels out to the far edges or holds them
+//! in a centered 16:9 frame. Panels opt in with a [`ScreenAnchor`].
+
+// PRIVATE modules
+mod plugin;
+mod systems;
+mod types;
+
+// PUBLIC exports
+pub use plugin::LayoutPlugin;
+pub use types::{ScreenAnchor, UiLayout};
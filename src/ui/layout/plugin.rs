@@ -0,0 +1,12 @@
+//! Responsive layout plugin
+
+use bevy_plugin_builder::define_plugin;
+
+use super::systems::*;
+use super::types::UiLayout;
+
+define_plugin!(LayoutPlugin {
+    resources: [UiLayout],
+
+    update: [(update_ui_layout, apply_screen_anchors).chain()]
+});
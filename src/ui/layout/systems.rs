@@ -0,0 +1,50 @@
+//! Responsive layout systems
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use super::types::{ScreenAnchor, UiLayout};
+use crate::settings::GameSettings;
+
+/// Recompute the layout when the window is resized, moves to a monitor with
+/// a different scale factor, or the interface settings change
+pub fn update_ui_layout(
+    settings: Res<GameSettings>,
+    mut layout: ResMut<UiLayout>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+) {
+    let Ok(window) = window_query.single() else {
+        return;
+    };
+    let viewport = Vec2::new(window.width(), window.height());
+    if viewport.x <= 0.0 || viewport.y <= 0.0 {
+        // Minimized
+        return;
+    }
+
+    let next = UiLayout::compute(
+        viewport,
+        settings.interface.ui_scale,
+        settings.interface.safe_area,
+        settings.interface.ultrawide_spread,
+    );
+    if *layout != next {
+        *layout = next;
+    }
+}
+
+/// Position anchored panels inside the current layout bounds
+pub fn apply_screen_anchors(layout: Res<UiLayout>, mut anchored: Query<(Ref<ScreenAnchor>, &mut Node)>) {
+    for (anchor, mut node) in &mut anchored {
+        if !layout.is_changed() && !anchor.is_changed() {
+            continue;
+        }
+        let [left, right, top, bottom] = layout.place(&anchor);
+        if node.left != left || node.right != right || node.top != top || node.bottom != bottom {
+            node.left = left;
+            node.right = right;
+            node.top = top;
+            node.bottom = bottom;
+        }
+    }
+}
@@ -0,0 +1,237 @@
+//! Responsive layout types
+
+use bevy::prelude::*;
+
+/// Resolution the interface is designed against
+pub const REFERENCE_RESOLUTION: Vec2 = Vec2::new(1920.0, 1080.0);
+
+/// Narrowest interface, in reference pixels, that still fits both side columns around the map
+const MIN_UI_WIDTH: f32 = 1440.0;
+
+/// Limits on how far the interface scales with the window
+const MIN_RESOLUTION_SCALE: f32 = 0.8;
+const MAX_RESOLUTION_SCALE: f32 = 2.0;
+
+/// Aspect ratio from which a screen counts as ultrawide (21:9 is 2.33)
+const ULTRAWIDE_ASPECT: f32 = 2.2;
+
+/// Aspect ratio of the centered frame panels keep to on ultrawide screens when not spread
+const FRAMED_ASPECT: f32 = 16.0 / 9.0;
+
+/// Largest safe-area margin, as a share of each screen dimension
+pub const MAX_SAFE_AREA: f32 = 0.1;
+
+/// Overall shape of the screen
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LayoutMode {
+    #[default]
+    Standard,
+    /// Wider than 21:9-ish; panels spread to the far edges or keep to a centered frame
+    Ultrawide,
+}
+
+/// Current screen layout, in UI units (logical pixels divided by the UI scale)
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct UiLayout {
+    /// Factor the interface is scaled by for this resolution, before the player's UI scale
+    pub resolution_scale: f32,
+    pub mode: LayoutMode,
+    /// Whole window in UI units
+    pub ui_size: Vec2,
+    /// Area anchored panels are placed within (y down)
+    pub bounds: Rect,
+}
+
+impl Default for UiLayout {
+    fn default() -> Self {
+        Self::compute(REFERENCE_RESOLUTION, 1.0, 0.0, true)
+    }
+}
+
+impl UiLayout {
+    /// Lay out a window of `viewport` logical pixels
+    ///
+    /// `safe_area` is the margin kept clear on every side, as a share of the
+    /// window. On ultrawide screens `spread` sends panels to the far edges;
+    /// otherwise they keep to a centered 16:9 frame.
+    pub fn compute(viewport: Vec2, ui_scale: f32, safe_area: f32, spread: bool) -> Self {
+        let aspect = viewport.x / viewport.y.max(1.0);
+        let mode = if aspect >= ULTRAWIDE_ASPECT {
+            LayoutMode::Ultrawide
+        } else {
+            LayoutMode::Standard
+        };
+
+        // Scale with height, but shrink on narrow windows so both side columns still fit
+        let resolution_scale = (viewport.y / REFERENCE_RESOLUTION.y)
+            .min(viewport.x / MIN_UI_WIDTH)
+            .clamp(MIN_RESOLUTION_SCALE, MAX_RESOLUTION_SCALE);
+        let ui_size = viewport / (resolution_scale * ui_scale.max(0.1));
+
+        let mut frame = Rect::from_corners(Vec2::ZERO, ui_size);
+        if mode == LayoutMode::Ultrawide && !spread {
+            let width = ui_size.y * FRAMED_ASPECT;
+            let left = (ui_size.x - width) / 2.0;
+            frame = Rect::new(left, 0.0, left + width, ui_size.y);
+        }
+        let margin = ui_size * safe_area.clamp(0.0, MAX_SAFE_AREA);
+        let bounds = Rect::from_corners(frame.min + margin, frame.max - margin);
+
+        Self {
+            resolution_scale,
+            mode,
+            ui_size,
+            bounds,
+        }
+    }
+
+    /// Node insets (left, right, top, bottom) that place an anchored panel
+    pub fn place(&self, anchor: &ScreenAnchor) -> [Val; 4] {
+        let (left, right) = if anchor.corner.is_left() {
+            (Val::Px(self.bounds.min.x + anchor.offset.x), Val::Auto)
+        } else {
+            (Val::Auto, Val::Px(self.ui_size.x - self.bounds.max.x + anchor.offset.x))
+        };
+        let (top, bottom) = if anchor.corner.is_top() {
+            (Val::Px(self.bounds.min.y + anchor.offset.y), Val::Auto)
+        } else {
+            (Val::Auto, Val::Px(self.ui_size.y - self.bounds.max.y + anchor.offset.y))
+        };
+        [left, right, top, bottom]
+    }
+}
+
+/// Screen corner a panel is pinned to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScreenCorner {
+    TopLeft,
+    TopRight,
+    BottomRight,
+}
+
+impl ScreenCorner {
+    fn is_left(&self) -> bool {
+        *self == ScreenCorner::TopLeft
+    }
+
+    fn is_top(&self) -> bool {
+        matches!(self, ScreenCorner::TopLeft | ScreenCorner::TopRight)
+    }
+}
+
+/// Pins an absolutely positioned panel to a corner of the layout bounds
+///
+/// The offset is measured inward from the corner, in UI units; the panel's
+/// `left`/`right`/`top`/`bottom` insets are rewritten whenever the layout changes.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ScreenAnchor {
+    pub corner: ScreenCorner,
+    pub offset: Vec2,
+}
+
+impl ScreenAnchor {
+    pub fn top_left(x: f32, y: f32) -> Self {
+        Self {
+            corner: ScreenCorner::TopLeft,
+            offset: Vec2::new(x, y),
+        }
+    }
+
+    pub fn top_right(x: f32, y: f32) -> Self {
+        Self {
+            corner: ScreenCorner::TopRight,
+            offset: Vec2::new(x, y),
+        }
+    }
+
+    pub fn bottom_right(x: f32, y: f32) -> Self {
+        Self {
+            corner: ScreenCorner::BottomRight,
+            offset: Vec2::new(x, y),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Common window sizes the layout is checked against
+    const COMMON_RESOLUTIONS: [(&str, Vec2); 12] = [
+        ("4:3 XGA", Vec2::new(1024.0, 768.0)),
+        ("720p", Vec2::new(1280.0, 720.0)),
+        ("Steam Deck", Vec2::new(1280.0, 800.0)),
+        ("WXGA", Vec2::new(1366.0, 768.0)),
+        ("1080p", Vec2::new(1920.0, 1080.0)),
+        ("WUXGA", Vec2::new(1920.0, 1200.0)),
+        ("Ultrawide FHD", Vec2::new(2560.0, 1080.0)),
+        ("1440p", Vec2::new(2560.0, 1440.0)),
+        ("Ultrawide QHD", Vec2::new(3440.0, 1440.0)),
+        ("Ultrawide QHD+", Vec2::new(3840.0, 1600.0)),
+        ("4K", Vec2::new(3840.0, 2160.0)),
+        ("Super ultrawide", Vec2::new(5120.0, 1440.0)),
+    ];
+
+    /// Screen rectangle, in UI units, of an anchored panel of the given size
+    fn panel_rect(layout: &UiLayout, anchor: &ScreenAnchor, size: Vec2) -> Rect {
+        let x = if anchor.corner.is_left() {
+            layout.bounds.min.x + anchor.offset.x
+        } else {
+            layout.bounds.max.x - anchor.offset.x - size.x
+        };
+        let y = if anchor.corner.is_top() {
+            layout.bounds.min.y + anchor.offset.y
+        } else {
+            layout.bounds.max.y - anchor.offset.y - size.y
+        };
+        Rect::from_corners(Vec2::new(x, y), Vec2::new(x, y) + size)
+    }
+
+    /// Layout harness: the in-game panels fit and stay apart at every common resolution
+    #[test]
+    fn panels_fit_common_resolutions() {
+        let laws_panel = (ScreenAnchor::top_left(20.0, 100.0), Vec2::new(450.0, 600.0));
+        let nation_panel = (ScreenAnchor::top_right(20.0, 100.0), Vec2::new(320.0, 600.0));
+        let tile_panel = (ScreenAnchor::bottom_right(10.0, 10.0), Vec2::new(250.0, 200.0));
+
+        for (name, viewport) in COMMON_RESOLUTIONS {
+            for spread in [true, false] {
+                for safe_area in [0.0, 0.05] {
+                    let layout = UiLayout::compute(viewport, 1.0, safe_area, spread);
+                    let screen = Rect::from_corners(Vec2::ZERO, layout.ui_size);
+
+                    let rects: Vec<Rect> = [laws_panel, nation_panel, tile_panel]
+                        .iter()
+                        .map(|(anchor, size)| panel_rect(&layout, anchor, *size))
+                        .collect();
+                    for rect in &rects {
+                        assert!(
+                            screen.contains(rect.min) && screen.contains(rect.max),
+                            "{name}: panel {rect:?} leaves the screen {screen:?}"
+                        );
+                        assert!(layout.bounds.contains(rect.min) && layout.bounds.contains(rect.max));
+                    }
+                    assert!(rects[0].max.x < rects[1].min.x, "{name}: side columns overlap");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reference_screen_is_unscaled_and_ultrawide_spreads_or_frames() {
+        let reference = UiLayout::default();
+        assert_eq!(reference.resolution_scale, 1.0);
+        assert_eq!(reference.bounds, Rect::from_corners(Vec2::ZERO, REFERENCE_RESOLUTION));
+
+        let anchor = ScreenAnchor::top_right(20.0, 100.0);
+        assert_eq!(reference.place(&anchor)[1], Val::Px(20.0));
+
+        let ultrawide = Vec2::new(5120.0, 1440.0);
+        let spread = UiLayout::compute(ultrawide, 1.0, 0.0, true);
+        let framed = UiLayout::compute(ultrawide, 1.0, 0.0, false);
+        assert_eq!(spread.mode, LayoutMode::Ultrawide);
+        assert_eq!(spread.bounds.max.x, spread.ui_size.x);
+        assert!((framed.bounds.width() / framed.bounds.height() - FRAMED_ASPECT).abs() < 0.01);
+        assert!(framed.place(&anchor)[1] != spread.place(&anchor)[1]);
+    }
+}
@@ -10,15 +10,22 @@ use bevy::prelude::ChildSpawnerCommands;
 pub type ChildBuilder<'a> = ChildSpawnerCommands<'a>;
 
 // PRIVATE MODULES - All implementation hidden
+mod accessibility;     // Accessibility settings applied to all UI
 mod animation;         // Declarative animation system
+mod army_inspector;    // Military inspector (order of battle)
 mod cleanup;           // Generic cleanup utilities
+mod dev_console;       // Developer console
 mod dialogs;           // Game-specific dialogs
 mod dropdown;          // Dropdown component system
+mod economy_flow;      // Nation economy flow (Sankey) diagram
 mod family_browser;    // Family browser (prestige-ranked houses)
 mod family_tree;       // Family tree viewer
+mod follow_breadcrumbs; // Breadcrumb bar while the camera follows something
+mod graph_view;        // Reusable pan/zoom graph widget and layouts
 mod hud;               // Heads-up display
 mod interaction;       // UI interaction systems
 mod law_browser;       // Law browsing UI
+mod layout;            // Responsive layout across resolutions and aspect ratios
 mod loading;           // Loading indicators
 mod nation_laws_panel; // Nation laws display
 mod nation_info;       // Nation information panel
@@ -26,9 +33,12 @@ mod nation_selection;  // Nation selection UI
 mod notifications;     // Universal notification system (toasts, banners)
 mod overlay_display;   // Map overlay displays
 mod performance_dashboard; // Performance monitoring
+mod personality_editor;  // Nation AI personality editor
 mod plugin;            // Main UI plugin
+mod relations_graph;   // Diplomatic relations graph
 mod shortcuts;         // Keyboard shortcuts registry
 mod styles;            // Centralized styling
+mod theming;           // Era and nation accents on toolbars and panels
 mod tile_info;         // Tile information display
 mod tips;              // Game tips system
 mod toolbar;           // Main toolbar
@@ -37,6 +47,7 @@ mod toolbar;           // Main toolbar
 
 // Styles module re-exports for controlled access
 pub use styles::{colors, dimensions, helpers, layers};
+pub use styles::theme::{ThemedAccent, UiTheme};
 
 // Convenience aliases from styles module
 pub use styles::colors::{
@@ -56,9 +67,15 @@ pub use shortcuts::{
     // Core types
     ShortcutId, ShortcutContext, ShortcutEvent,
     // Registry
-    ShortcutRegistry,
+    ShortcutRegistry, ShortcutConfig,
 };
 
+// Developer console exports
+pub use dev_console::{console_closed, ConsoleCommand, ConsoleOutput};
+
+// Responsive layout exports
+pub use layout::{ScreenAnchor, UiLayout};
+
 // Dropdown system exports
 pub use dropdown::DropdownBuilder;
 
@@ -143,7 +160,7 @@ pub fn despawn_ui_entities<T: Component>(mut commands: Commands, query: Query<En
 // Nation laws panel exports
 
 // Notification system exports
-pub use notifications::ShowNotification;
+pub use notifications::{NotificationPosition, NotificationType, ShowNotification};
 
 // Main plugin (implementation in plugin.rs)
 pub use plugin::UIPlugin;
@@ -31,6 +31,21 @@ pub struct NationInfoPanel;
 #[derive(Component)]
 pub struct NationNameText;
 
+/// Marker for the selected nation's flag and its ruling house's arms
+#[derive(Component)]
+pub enum HeraldryImage {
+    Nation,
+    House,
+}
+
+/// Marker for the selected nation's ruler portrait
+#[derive(Component)]
+pub struct RulerPortraitImage;
+
+/// Marker for former nation names text
+#[derive(Component)]
+pub struct FormerNamesText;
+
 /// Marker for ruler text
 #[derive(Component)]
 pub struct RulerText;
@@ -59,10 +74,22 @@ pub struct MilitaryText;
 #[derive(Component)]
 pub struct GovernmentText;
 
+/// Marker for AI personality and economic focus text
+#[derive(Component)]
+pub struct PersonalityText;
+
 /// Marker for legitimacy text
 #[derive(Component)]
 pub struct LegitimacyText;
 
+/// Marker for trust and active treaties text
+#[derive(Component)]
+pub struct TreatiesText;
+
+/// Marker for unification movement text
+#[derive(Component)]
+pub struct UnificationText;
+
 /// Marker for view laws button
 #[derive(Component)]
 pub struct ViewLawsButton;
@@ -89,6 +116,8 @@ pub fn spawn_nation_info_panel(mut commands: Commands) {
             },
             BackgroundColor(UI_BACKGROUND_COLOR),
             BorderColor::all(UI_BORDER_COLOR),
+            ThemedAccent::Border(UI_BORDER_COLOR),
+            super::ScreenAnchor::top_right(20.0, 100.0),
             NationInfoPanel,
             Visibility::Hidden, // Start hidden, show when nation selected
         ))
@@ -114,6 +143,39 @@ pub fn spawn_nation_info_panel(mut commands: Commands) {
                 BackgroundColor(UI_BORDER_COLOR),
             ));
 
+            // Ruler portrait, flag and house arms
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        ImageNode::default(),
+                        Node {
+                            width: Val::Px(crate::nations::PORTRAIT_SIZE as f32),
+                            height: Val::Px(crate::nations::PORTRAIT_SIZE as f32),
+                            display: Display::None,
+                            ..default()
+                        },
+                        RulerPortraitImage,
+                    ));
+                    for marker in [HeraldryImage::Nation, HeraldryImage::House] {
+                        row.spawn((
+                            ImageNode::default(),
+                            Node {
+                                width: Val::Px(crate::nations::HERALDRY_WIDTH as f32),
+                                height: Val::Px(crate::nations::HERALDRY_HEIGHT as f32),
+                                display: Display::None,
+                                ..default()
+                            },
+                            marker,
+                        ));
+                    }
+                });
+
             // Nation name
             parent.spawn((
                 Text::new("No Nation Selected"),
@@ -125,6 +187,17 @@ pub fn spawn_nation_info_panel(mut commands: Commands) {
                 NationNameText,
             ));
 
+            // Former names (empty until the nation is renamed)
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: TEXT_SIZE_NORMAL,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR_SECONDARY),
+                FormerNamesText,
+            ));
+
             // House and ruler
             parent.spawn((
                 Text::new("House: Unknown"),
@@ -157,6 +230,17 @@ pub fn spawn_nation_info_panel(mut commands: Commands) {
                 GovernmentText,
             ));
 
+            // AI personality and economy
+            parent.spawn((
+                Text::new("Temperament: Unknown"),
+                TextFont {
+                    font_size: TEXT_SIZE_NORMAL,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR_SECONDARY),
+                PersonalityText,
+            ));
+
             // Separator
             parent.spawn((
                 Node {
@@ -233,6 +317,37 @@ pub fn spawn_nation_info_panel(mut commands: Commands) {
                 LegitimacyText,
             ));
 
+            // Diplomacy
+            parent.spawn((
+                Text::new("Treaties"),
+                TextFont {
+                    font_size: TEXT_SIZE_NORMAL,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR_HEADER),
+            ));
+
+            parent.spawn((
+                Text::new("No active treaties"),
+                TextFont {
+                    font_size: TEXT_SIZE_NORMAL,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR_SECONDARY),
+                TreatiesText,
+            ));
+
+            // Unification movement progress (empty when none)
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: TEXT_SIZE_NORMAL,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR_SECONDARY),
+                UnificationText,
+            ));
+
             // Separator
             parent.spawn((
                 Node {
@@ -335,27 +450,118 @@ pub fn update_panel_visibility(
     }
 }
 
-/// Update nation name display
+/// Update nation name display, including names the nation used to go by
 pub fn update_nation_basic_info(
     mut messages: MessageReader<NationSelectionChanged>,
-    nations_query: Query<&Nation>,
-    mut name_text: Query<&mut Text, With<NationNameText>>,
+    mut renamed: MessageReader<crate::nations::NationRenamed>,
+    selected_nation: Res<SelectedNation>,
+    nations_query: Query<(&Nation, Option<&crate::nations::NationHistory>)>,
+    mut name_text: Query<&mut Text, (With<NationNameText>, Without<FormerNamesText>)>,
+    mut former_names_text: Query<&mut Text, (With<FormerNamesText>, Without<NationNameText>)>,
 ) {
-    for message in messages.read() {
-        let Ok(mut text) = name_text.single_mut() else {
-            continue;
-        };
+    let selection_changed = messages.read().count() > 0;
+    let selected_renamed = renamed
+        .read()
+        .any(|event| Some(event.nation_entity) == selected_nation.entity);
+    if !selection_changed && !selected_renamed {
+        return;
+    }
 
-        if let Some(entity) = message.current {
-            if let Ok(nation) = nations_query.get(entity) {
-                text.0 = nation.name.clone();
-            } else {
-                text.0 = "Invalid Nation Data".to_string();
-                warn!("Selected nation entity {:?} missing Nation component", entity);
+    let (Ok(mut text), Ok(mut former_text)) = (name_text.single_mut(), former_names_text.single_mut()) else {
+        return;
+    };
+    former_text.0.clear();
+
+    let Some(entity) = selected_nation.entity else {
+        text.0 = "No Nation Selected".to_string();
+        return;
+    };
+
+    if let Ok((nation, history)) = nations_query.get(entity) {
+        text.0 = nation.name.clone();
+        if let Some(history) = history.filter(|history| !history.former_names.is_empty()) {
+            let former: Vec<String> = history
+                .former_names
+                .iter()
+                .rev()
+                .map(|former| format!("{} (until {})", former.name, former.until_year))
+                .collect();
+            former_text.0 = format!("Formerly: {}", former.join(", "));
+        }
+    } else {
+        text.0 = "Invalid Nation Data".to_string();
+        warn!("Selected nation entity {:?} missing Nation component", entity);
+    }
+}
+
+/// Show the selected nation's flag and its ruling house's arms
+pub fn update_heraldry_display(
+    mut messages: MessageReader<NationSelectionChanged>,
+    selected_nation: Res<SelectedNation>,
+    changed_heraldry: Query<(), Changed<crate::nations::Heraldry>>,
+    nations_query: Query<
+        (Option<&crate::nations::Heraldry>, Option<&crate::relationships::RuledBy>),
+        With<Nation>,
+    >,
+    heraldry_query: Query<&crate::nations::Heraldry>,
+    mut images_query: Query<(&HeraldryImage, &mut ImageNode, &mut Node)>,
+) {
+    let selection_changed = messages.read().count() > 0;
+    if !selection_changed && changed_heraldry.is_empty() {
+        return;
+    }
+
+    let (nation_heraldry, ruled_by) = selected_nation
+        .entity
+        .and_then(|entity| nations_query.get(entity).ok())
+        .unwrap_or((None, None));
+    let house_heraldry = ruled_by
+        .and_then(|ruled_by| ruled_by.current_ruler())
+        .and_then(|house| heraldry_query.get(house).ok());
+
+    for (marker, mut image, mut node) in &mut images_query {
+        let heraldry = match marker {
+            HeraldryImage::Nation => nation_heraldry,
+            HeraldryImage::House => house_heraldry,
+        };
+        match heraldry {
+            Some(heraldry) => {
+                image.image = heraldry.texture.clone();
+                node.display = Display::Flex;
             }
-        } else {
-            text.0 = "No Nation Selected".to_string();
+            None => node.display = Display::None,
+        }
+    }
+}
+
+/// Show the portrait of the selected nation's ruler
+pub fn update_ruler_portrait_display(
+    mut messages: MessageReader<NationSelectionChanged>,
+    selected_nation: Res<SelectedNation>,
+    changed_portraits: Query<(), Changed<crate::nations::Portrait>>,
+    nations_query: Query<&crate::relationships::RuledBy, With<Nation>>,
+    portraits_query: Query<&crate::nations::Portrait>,
+    mut image_query: Query<(&mut ImageNode, &mut Node), With<RulerPortraitImage>>,
+) {
+    let selection_changed = messages.read().count() > 0;
+    if !selection_changed && changed_portraits.is_empty() {
+        return;
+    }
+    let Ok((mut image, mut node)) = image_query.single_mut() else {
+        return;
+    };
+
+    let portrait = selected_nation
+        .entity
+        .and_then(|entity| nations_query.get(entity).ok())
+        .and_then(|ruled_by| ruled_by.current_ruler())
+        .and_then(|house| portraits_query.get(house).ok());
+    match portrait {
+        Some(portrait) => {
+            image.image = portrait.texture.clone();
+            node.display = Display::Flex;
         }
+        None => node.display = Display::None,
     }
 }
 
@@ -409,6 +615,9 @@ pub fn update_house_ruler_info(
 pub fn update_nation_statistics(
     mut messages: MessageReader<NationSelectionChanged>,
     nations_query: Query<&Nation>,
+    compositions_query: Query<&crate::nations::ArmyComposition>,
+    doctrines_query: Query<&crate::nations::MilitaryDoctrine>,
+    attrition_query: Query<&crate::nations::CampaignAttrition>,
     controls_query: Query<&crate::relationships::Controls>,
     mut province_text: Query<
         &mut Text,
@@ -457,13 +666,46 @@ pub fn update_nation_statistics(
                     text.0 = format!("Provinces: {}", province_count);
                 }
                 if let Ok(mut text) = treasury_text.single_mut() {
-                    text.0 = format!("Treasury: {:.0} gold", nation.treasury);
+                    text.0 = format!("Treasury: {}", nation.treasury);
                 }
                 if let Ok(mut text) = stability_text.single_mut() {
                     text.0 = format!("Stability: {:.0}%", nation.stability * 100.0);
                 }
                 if let Ok(mut text) = military_text.single_mut() {
-                    text.0 = format!("Military: {:.0} strength", nation.military_strength);
+                    text.0 = match compositions_query.get(entity) {
+                        Ok(army) => format!(
+                            "Military: {:.0} strength ({}: {:.0}% foot, {:.0}% horse, {:.0}% guns)",
+                            nation.military_strength,
+                            army.template.name(),
+                            army.infantry * 100.0,
+                            army.cavalry * 100.0,
+                            army.artillery * 100.0
+                        ),
+                        Err(_) => format!("Military: {:.0} strength", nation.military_strength),
+                    };
+                    if let Ok(doctrine) = doctrines_query.get(entity) {
+                        text.0.push_str(&format!(
+                            "\nDoctrine: {} (technology {})",
+                            doctrine.current.name(),
+                            nation.technology_level
+                        ));
+                        if let Some(next) = doctrine.adopting {
+                            text.0.push_str(&format!(
+                                ", retraining for {}: {:.0}%",
+                                next.name(),
+                                doctrine.adoption * 100.0
+                            ));
+                        }
+                    }
+                    if let Some(attrition) = attrition_query.get(entity).ok().filter(|attrition| attrition.total() > 0.0) {
+                        text.0.push_str(&format!(
+                            "\nAttrition: {:.0}% last year (thirst {:.0}%, cold {:.0}%, fever {:.0}%)",
+                            attrition.total() * 100.0,
+                            attrition.thirst * 100.0,
+                            attrition.cold * 100.0,
+                            attrition.disease * 100.0
+                        ));
+                    }
                 }
             } else {
                 // Invalid nation data
@@ -521,6 +763,113 @@ pub fn update_government_display(
     }
 }
 
+/// Update AI personality and economic focus display
+pub fn update_personality_display(
+    mut messages: MessageReader<NationSelectionChanged>,
+    nations_query: Query<(
+        &Nation,
+        Option<&crate::nations::EconomicFocus>,
+        Option<&crate::nations::EconomicLedger>,
+        Option<&crate::nations::Bureaucracy>,
+        Option<&crate::nations::Corruption>,
+        Option<&crate::nations::PublicOpinion>,
+        Option<&crate::nations::FortNetwork>,
+        Option<&crate::nations::Logistics>,
+        Option<&crate::nations::ContactStance>,
+    )>,
+    names_query: Query<&Nation>,
+    mut personality_text: Query<&mut Text, With<PersonalityText>>,
+    game_time: Res<crate::simulation::GameTime>,
+) {
+    for message in messages.read() {
+        let Ok(mut text) = personality_text.single_mut() else {
+            continue;
+        };
+
+        text.0 = match message.current.and_then(|entity| nations_query.get(entity).ok()) {
+            Some((nation, focus, ledger, bureaucracy, corruption, opinion, forts, logistics, stance)) => {
+                let mut summary = format!(
+                    "Temperament: {}\nEconomy: {}",
+                    nation.personality.summary(),
+                    focus.copied().unwrap_or_default().label()
+                );
+                if let Some(ledger) = ledger {
+                    summary.push_str(&format!(
+                        "\nSystem: {} (output {:.0}, revenue {:.0})",
+                        ledger.system.label(),
+                        ledger.output,
+                        ledger.revenue
+                    ));
+                    if ledger.shortage > 0.0 {
+                        summary.push_str(&format!(", {:.0}% shortage", ledger.shortage * 100.0));
+                    } else if ledger.waste > 0.0 {
+                        summary.push_str(&format!(", {:.0} wasted", ledger.waste));
+                    }
+                    if ledger.wages > 1.01 {
+                        summary.push_str(&format!("\nWages: {:.0}% above normal (scarce labor)", (ledger.wages - 1.0) * 100.0));
+                    }
+                }
+                if let Some(stance) = stance {
+                    if stance.policy != crate::nations::ContactPolicy::Open || stance.forced_open {
+                        summary.push_str(&format!("\nContact: {}", stance.policy.label()));
+                        if stance.forced_open {
+                            summary.push_str(" (forced open)");
+                        }
+                    }
+                }
+                if let Some(bureaucracy) = bureaucracy {
+                    summary.push_str(&format!(
+                        "\nAdministration: {} of {:.0} provinces",
+                        bureaucracy.provinces, bureaucracy.capacity
+                    ));
+                    if bureaucracy.is_overextended() {
+                        summary.push_str(&format!(
+                            ", overextended ({:.0}% of taxes lost)",
+                            bureaucracy.tax_leakage * 100.0
+                        ));
+                    }
+                }
+                if let Some(corruption) = corruption {
+                    summary.push_str(&format!("\nCorruption: {:.0}%", corruption.level * 100.0));
+                    if corruption.drive_active(game_time.current_year()) {
+                        summary.push_str(" (anti-corruption drive)");
+                    }
+                }
+                if let Some(opinion) = opinion {
+                    summary.push_str(&format!(
+                        "\nApproval: {:.0}%, press freedom {:.0}%",
+                        opinion.ruler_approval * 100.0,
+                        opinion.press_freedom * 100.0
+                    ));
+                    if opinion.propaganda {
+                        summary.push_str(" (propaganda campaign)");
+                    }
+                }
+                if let Some(forts) = forts.filter(|forts| forts.forts + forts.under_construction > 0) {
+                    summary.push_str(&format!(
+                        "\nForts: {} ({} building), {:.0}% of border covered",
+                        forts.forts,
+                        forts.under_construction,
+                        forts.zone_of_control * 100.0
+                    ));
+                }
+                if let Some(logistics) = logistics {
+                    summary.push_str(&format!(
+                        "\nStockpile: {:.0} of {:.0}, {} depots",
+                        logistics.stockpile, logistics.target, logistics.depots
+                    ));
+                    // The build-up before a planned war is visible to anyone watching
+                    if let Some(target) = logistics.preparing_for.and_then(|target| names_query.get(target).ok()) {
+                        summary.push_str(&format!(" (massing supplies against {})", target.name));
+                    }
+                }
+                summary
+            }
+            None => "Temperament: Unknown".to_string(),
+        };
+    }
+}
+
 /// Calculate and cache legitimacy in Governance component
 /// This runs less frequently and updates the cached value
 pub fn update_cached_legitimacy(
@@ -609,6 +958,131 @@ pub fn update_legitimacy_display(
     }
 }
 
+/// List the selected nation's treaties, merchant leagues, and its reputation for keeping them
+pub fn update_treaties_display(
+    mut messages: MessageReader<NationSelectionChanged>,
+    selected_nation: Res<SelectedNation>,
+    added_treaties: Query<(), Added<crate::nations::Treaty>>,
+    mut removed_treaties: RemovedComponents<crate::nations::Treaty>,
+    treaties_query: Query<&crate::nations::Treaty>,
+    changed_leagues: Query<(), Changed<crate::nations::TradeLeague>>,
+    leagues_query: Query<&crate::nations::TradeLeague>,
+    nations_query: Query<(Entity, &Nation)>,
+    compliance_query: Query<&crate::nations::TreatyCompliance>,
+    game_time: Res<crate::simulation::GameTime>,
+    mut treaties_text: Query<&mut Text, With<TreatiesText>>,
+) {
+    let selection_changed = messages.read().count() > 0;
+    let treaties_changed = !added_treaties.is_empty()
+        || removed_treaties.read().count() > 0
+        || !changed_leagues.is_empty();
+    if !selection_changed && !treaties_changed {
+        return;
+    }
+
+    let Ok(mut text) = treaties_text.single_mut() else {
+        return;
+    };
+    let Some(entity) = selected_nation.entity else {
+        text.0 = "No active treaties".to_string();
+        return;
+    };
+
+    let names: std::collections::HashMap<Entity, String> = nations_query
+        .iter()
+        .map(|(entity, nation)| (entity, nation.name.clone()))
+        .collect();
+    let year = game_time.current_year();
+
+    let mut lines = Vec::new();
+    if let Ok(compliance) = compliance_query.get(entity) {
+        lines.push(format!(
+            "Trust: {:.0}% ({} broken)",
+            compliance.trust * 100.0,
+            compliance.violations
+        ));
+    }
+
+    let mut treaty_count = 0;
+    for treaty in treaties_query.iter().filter(|treaty| treaty.involves(entity)) {
+        treaty_count += 1;
+        let partner = treaty
+            .counterpart(entity)
+            .and_then(|partner| names.get(&partner))
+            .map_or("Unknown", String::as_str);
+        let remaining = treaty
+            .years_remaining(year)
+            .map_or(String::new(), |years| format!(", {} years left", years));
+        lines.push(format!("{} with {}{}", treaty.kind.display_name(), partner, remaining));
+        for clause in treaty.clauses.iter().filter(|clause| !clause.forbids_war()) {
+            lines.push(format!("  - {}", clause.describe(&names)));
+        }
+    }
+    if treaty_count == 0 {
+        lines.push("No active treaties".to_string());
+    }
+
+    for league in leagues_query.iter().filter(|league| league.is_active()) {
+        if league.nations.contains(&entity) {
+            lines.push(format!(
+                "Cities in {} ({} cities, fleet {:.0})",
+                league.name,
+                league.cities.len(),
+                league.fleet
+            ));
+        }
+        if league.embargoes.contains(&entity) {
+            lines.push(format!("Embargoed by {}", league.name));
+        }
+    }
+
+    text.0 = lines.join("\n");
+}
+
+/// Show the selected nation's unification movement, or the one it is being drawn into
+pub fn update_unification_display(
+    mut messages: MessageReader<NationSelectionChanged>,
+    selected_nation: Res<SelectedNation>,
+    changed_movements: Query<(), Changed<crate::nations::UnificationMovement>>,
+    mut removed_movements: RemovedComponents<crate::nations::UnificationMovement>,
+    movements_query: Query<(Entity, &crate::nations::UnificationMovement)>,
+    nations_query: Query<&Nation>,
+    mut unification_text: Query<&mut Text, With<UnificationText>>,
+) {
+    let selection_changed = messages.read().count() > 0;
+    let movements_changed = !changed_movements.is_empty() || removed_movements.read().count() > 0;
+    if !selection_changed && !movements_changed {
+        return;
+    }
+
+    let Ok(mut text) = unification_text.single_mut() else {
+        return;
+    };
+    let Some(entity) = selected_nation.entity else {
+        text.0.clear();
+        return;
+    };
+
+    text.0 = movements_query
+        .iter()
+        .find(|(leader, movement)| *leader == entity || movement.members.contains(&entity))
+        .map_or(String::new(), |(leader, movement)| {
+            let culture = crate::nations::culture_to_display_name(movement.culture);
+            let progress = movement.progress * 100.0;
+            if leader == entity {
+                format!(
+                    "Leading {} unification: {:.0}% ({} nations)",
+                    culture,
+                    progress,
+                    movement.members.len()
+                )
+            } else {
+                let leader_name = nations_query.get(leader).map_or("Unknown", |nation| nation.name.as_str());
+                format!("{} unification under {}: {:.0}%", culture, leader_name, progress)
+            }
+        });
+}
+
 use bevy_plugin_builder::define_plugin;
 
 /// Handle View Family Tree button click
@@ -653,10 +1127,15 @@ define_plugin!(NationInfoPlugin {
         // React to selection changes
         update_panel_visibility.run_if(in_state(GameState::InGame)),
         update_nation_basic_info.run_if(in_state(GameState::InGame)),
+        update_heraldry_display.run_if(in_state(GameState::InGame)),
+        update_ruler_portrait_display.run_if(in_state(GameState::InGame)),
         update_house_ruler_info.run_if(in_state(GameState::InGame)),
         update_nation_statistics.run_if(in_state(GameState::InGame)),
         update_government_display.run_if(in_state(GameState::InGame)),
+        update_personality_display.run_if(in_state(GameState::InGame)),
         update_legitimacy_display.run_if(in_state(GameState::InGame)),
+        update_treaties_display.run_if(in_state(GameState::InGame)),
+        update_unification_display.run_if(in_state(GameState::InGame)),
 
         // Update cached legitimacy when governance changes (independent of selection)
         update_cached_legitimacy.run_if(in_state(GameState::InGame)),
@@ -4,6 +4,7 @@
 
 use bevy::prelude::*;
 use crate::ui::styles::{colors, dimensions};
+use crate::ui::ScreenAnchor;
 use super::types::*;
 
 /// Spawn the nation laws panel
@@ -25,6 +26,7 @@ pub fn spawn_nation_laws_panel(commands: &mut Commands) {
             },
             BackgroundColor(colors::BACKGROUND_DARKER),
             BorderColor::all(colors::BORDER_ACTIVE),
+            ScreenAnchor::top_left(20.0, 100.0),
             ZIndex(90), // Below law browser (100) but above other UI
             NationLawsPanel,
         ))
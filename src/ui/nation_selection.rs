@@ -22,13 +22,13 @@ pub fn handle_nation_selection(
 ) {
     // Only process selection on actual mouse click
     if mouse_button.just_pressed(MouseButton::Left) {
-        // Only select nations in political mode
-        if *map_mode != MapMode::Political {
-            // Clear selection when not in political mode
+        // Only select nations in modes that show national territory
+        if !map_mode.supports_nation_selection() {
+            // Clear selection when not in a nation-aware mode
             if selected_nation.entity.is_some() {
                 selected_nation.entity = None;
                 selected_nation.nation_id = None;
-                info!("Cleared nation selection (not in political mode)");
+                info!("Cleared nation selection (map mode does not show nations)");
             }
             return;
         }
@@ -12,6 +12,7 @@ pub fn handle_notification_events(
     mut events: MessageReader<ShowNotification>,
     container_query: Query<Entity, With<NotificationContainer>>,
     time: Res<Time>,
+    mut audio: MessageWriter<crate::audio::AudioEvent>,
 ) {
     // Get or create notification container
     let container = match container_query.iter().next() {
@@ -24,6 +25,7 @@ pub fn handle_notification_events(
 
     // Process each notification event
     for notification in events.read() {
+        audio.write(crate::audio::AudioEvent::new(crate::audio::AudioCue::Notification));
         match notification.position {
             NotificationPosition::TopCenter | NotificationPosition::BottomRight => {
                 spawn_toast(&mut commands, container, notification, &time);
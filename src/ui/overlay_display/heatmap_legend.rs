@@ -0,0 +1,148 @@
+//! Heatmap legend: the metric's name and the values its colors stand for
+
+use crate::resources::MapMode;
+use crate::ui::colors;
+use crate::ui::{ChildBuilder, LabelBuilder, PanelBuilder, PanelStyle};
+use crate::world::{heat_ramp, HeatmapRegistry};
+use bevy::prelude::*;
+
+/// Swatches drawn along the ramp
+const RAMP_STEPS: usize = 8;
+
+/// Marker component for the heatmap legend container
+#[derive(Component)]
+pub struct HeatmapLegendContainer;
+
+/// Which text of the legend a label shows
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+pub enum HeatmapLegendText {
+    Title,
+    Min,
+    Max,
+    /// What hatched provinces mean, empty when the heatmap hatches none
+    Hatching,
+}
+
+/// Spawn the heatmap legend
+pub fn spawn_heatmap_legend(parent: &mut ChildBuilder) {
+    let panel_entity = PanelBuilder::new()
+        .style(PanelStyle::Transparent)
+        .flex_direction(FlexDirection::Column)
+        .display(Display::None) // Start hidden
+        .build_with_children(parent, |container| {
+            let title = LabelBuilder::new("Heatmap")
+                .font_size(14.0)
+                .color(colors::TEXT_PRIMARY)
+                .margin(UiRect::bottom(Val::Px(4.0)))
+                .build(container);
+            container.commands().entity(title).insert(HeatmapLegendText::Title);
+
+            container
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    height: Val::Px(12.0),
+                    margin: UiRect::bottom(Val::Px(2.0)),
+                    ..default()
+                })
+                .with_children(|ramp| {
+                    for step in 0..RAMP_STEPS {
+                        ramp.spawn((
+                            Node {
+                                flex_grow: 1.0,
+                                ..default()
+                            },
+                            BackgroundColor(heat_ramp(step as f32 / (RAMP_STEPS - 1) as f32)),
+                        ));
+                    }
+                });
+
+            container
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::SpaceBetween,
+                    ..default()
+                })
+                .with_children(|labels| {
+                    for text in [HeatmapLegendText::Min, HeatmapLegendText::Max] {
+                        let label = LabelBuilder::new("")
+                            .font_size(12.0)
+                            .color(colors::TEXT_SECONDARY)
+                            .build(labels);
+                        labels.commands().entity(label).insert(text);
+                    }
+                });
+
+            let hatching = LabelBuilder::new("")
+                .font_size(12.0)
+                .color(colors::TEXT_SECONDARY)
+                .margin(UiRect::top(Val::Px(2.0)))
+                .build(container);
+            container.commands().entity(hatching).insert(HeatmapLegendText::Hatching);
+        });
+
+    parent.commands().entity(panel_entity).insert(HeatmapLegendContainer);
+}
+
+/// Short form of a legend value, e.g. 12.5k for 12,500
+fn format_legend_value(value: f32) -> String {
+    let magnitude = value.abs();
+    if magnitude >= 1_000_000.0 {
+        format!("{:.1}M", value / 1_000_000.0)
+    } else if magnitude >= 1_000.0 {
+        format!("{:.1}k", value / 1_000.0)
+    } else if magnitude >= 10.0 || value.fract() == 0.0 {
+        format!("{:.0}", value)
+    } else {
+        format!("{:.2}", value)
+    }
+}
+
+/// Show the legend for heatmaps and keep its name and scale current
+pub fn update_heatmap_legend(
+    map_mode: Res<MapMode>,
+    registry: Res<HeatmapRegistry>,
+    mut legend_query: Query<&mut Node, With<HeatmapLegendContainer>>,
+    mut text_query: Query<(&mut Text, &HeatmapLegendText)>,
+) {
+    let heatmap = match *map_mode {
+        MapMode::Heatmap(id) => registry.get(id),
+        _ => None,
+    };
+    if let Ok(mut node) = legend_query.single_mut() {
+        node.display = if heatmap.is_some() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+    let Some(heatmap) = heatmap else {
+        return;
+    };
+
+    let range = heatmap.range();
+    for (mut text, which) in &mut text_query {
+        let next = match (which, range) {
+            (HeatmapLegendText::Title, _) => heatmap.name.clone(),
+            (HeatmapLegendText::Hatching, _) => heatmap.hatch_label().unwrap_or_default().to_string(),
+            (HeatmapLegendText::Min, Some((min, _))) => format_legend_value(min),
+            (HeatmapLegendText::Max, Some((_, max))) => format_legend_value(max),
+            (_, None) => String::new(),
+        };
+        if text.0 != next {
+            text.0 = next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legend_values_stay_short() {
+        assert_eq!(format_legend_value(12_500.0), "12.5k");
+        assert_eq!(format_legend_value(2_300_000.0), "2.3M");
+        assert_eq!(format_legend_value(42.0), "42");
+        assert_eq!(format_legend_value(0.25), "0.25");
+    }
+}
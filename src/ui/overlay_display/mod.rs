@@ -1,12 +1,13 @@
 //! Overlay Display Module - Pure Gateway
 //!
 //! Manages the resource overlay display system including the current overlay
-//! indicator and mineral legend. This is a pure gateway that orchestrates
+//! indicator, mineral legend, and heatmap legend. This is a pure gateway that orchestrates
 //! submodules without implementation.
 
 use bevy::prelude::*;
 
 // Submodules - all private
+mod heatmap_legend;
 mod mineral_legend;
 mod overlay_text;
 mod plugin;
@@ -22,5 +23,6 @@ pub struct OverlayDisplayRoot;
 pub use plugin::OverlayDisplayPlugin;
 
 // Re-export marker components for external use
+pub use heatmap_legend::HeatmapLegendContainer;
 pub use mineral_legend::MineralLegendContainer;
 pub use overlay_text::MapModeText;
@@ -7,15 +7,21 @@ use crate::states::GameState;
 use bevy::prelude::*;
 use bevy_plugin_builder::define_plugin;
 
-use super::{mineral_legend, setup};
+use super::{heatmap_legend, mineral_legend, setup};
 
-// Plugin that manages overlay display UI - now focused solely on mineral legend
+// Plugin that manages overlay display UI - now focused solely on the legends
 ///
 // Map mode display is handled by HUD to avoid duplication
 define_plugin!(OverlayDisplayPlugin {
     update: [
         mineral_legend::update_mineral_legend_visibility
             .run_if(resource_changed::<crate::resources::MapMode>)
+            .run_if(in_state(GameState::InGame)),
+        heatmap_legend::update_heatmap_legend
+            .run_if(
+                resource_changed::<crate::resources::MapMode>
+                    .or(resource_changed::<crate::world::HeatmapRegistry>)
+            )
             .run_if(in_state(GameState::InGame))
     ],
 
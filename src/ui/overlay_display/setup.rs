@@ -1,7 +1,7 @@
 //! Setup and cleanup systems for overlay display
 
-use super::super::{PanelBuilder, PanelStyle};
-use super::{mineral_legend, OverlayDisplayRoot};
+use super::super::{PanelBuilder, PanelStyle, ScreenAnchor};
+use super::{heatmap_legend, mineral_legend, OverlayDisplayRoot};
 use bevy::prelude::*;
 
 /// Setup the overlay display UI
@@ -14,6 +14,7 @@ pub fn setup_overlay_display(mut commands: Commands) {
                 left: Val::Px(10.0),
                 ..default()
             },
+            ScreenAnchor::top_left(10.0, 10.0),
             ZIndex(100),
             OverlayDisplayRoot,
         ))
@@ -25,8 +26,9 @@ pub fn setup_overlay_display(mut commands: Commands) {
                 .padding(UiRect::all(Val::Px(8.0)))
                 .width(Val::Px(180.0))
                 .build_with_children(parent, |panel| {
-                    // Only add mineral legend - map mode display is handled by HUD
+                    // Only add legends - map mode display is handled by HUD
                     mineral_legend::spawn_mineral_legend(panel);
+                    heatmap_legend::spawn_heatmap_legend(panel);
                 });
         });
 }
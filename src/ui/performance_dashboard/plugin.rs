@@ -22,5 +22,7 @@ define_plugin!(PerformanceDashboardPlugin {
         update_thread_utilization,
         update_metrics_summary,
         refresh_operations_list,
+        update_memory_report,
+        update_stress_report,
     ]
 });
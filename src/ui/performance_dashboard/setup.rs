@@ -2,7 +2,7 @@
 
 use crate::ui::ChildBuilder;
 use super::types::*;
-use crate::ui::{colors, dimensions};
+use crate::ui::{colors, dimensions, ScreenAnchor};
 use bevy::prelude::*;
 
 /// Setup the performance dashboard UI
@@ -22,6 +22,7 @@ pub fn setup_performance_dashboard(mut commands: Commands) {
             },
             BackgroundColor(colors::BACKGROUND_DARK.with_alpha(0.95)),
             BorderRadius::all(Val::Px(4.0)),
+            ScreenAnchor::top_right(10.0, 60.0),
             Visibility::Hidden, // Start hidden
             PerformancePanel,
         ))
@@ -183,6 +184,76 @@ pub fn setup_performance_dashboard(mut commands: Commands) {
                         });
                 });
 
+            // Memory report section
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::all(Val::Px(dimensions::PADDING_SMALL)),
+                        row_gap: Val::Px(4.0),
+                        ..default()
+                    },
+                    BackgroundColor(colors::SURFACE),
+                ))
+                .with_children(|section| {
+                    // Section title
+                    section.spawn((
+                        Text::new("Memory (per decade)"),
+                        TextColor(colors::TEXT_SECONDARY),
+                        TextFont {
+                            font_size: dimensions::FONT_SIZE_SMALL,
+                            ..default()
+                        },
+                    ));
+
+                    // Subsystem lines, rewritten as samples arrive
+                    section.spawn((
+                        Text::new("No samples yet"),
+                        TextColor(colors::TEXT_PRIMARY),
+                        TextFont {
+                            font_size: dimensions::FONT_SIZE_SMALL,
+                            ..default()
+                        },
+                        MemoryReportDisplay,
+                    ));
+                });
+
+            // Stress test section
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::all(Val::Px(dimensions::PADDING_SMALL)),
+                        row_gap: Val::Px(4.0),
+                        ..default()
+                    },
+                    BackgroundColor(colors::SURFACE),
+                ))
+                .with_children(|section| {
+                    // Section title
+                    section.spawn((
+                        Text::new("Stress Test"),
+                        TextColor(colors::TEXT_SECONDARY),
+                        TextFont {
+                            font_size: dimensions::FONT_SIZE_SMALL,
+                            ..default()
+                        },
+                    ));
+
+                    // Load and frame time, rewritten as the load is measured
+                    section.spawn((
+                        Text::new("No synthetic load"),
+                        TextColor(colors::TEXT_PRIMARY),
+                        TextFont {
+                            font_size: dimensions::FONT_SIZE_SMALL,
+                            ..default()
+                        },
+                        StressReportDisplay,
+                    ));
+                });
+
             // Recent operations section
             parent
                 .spawn((
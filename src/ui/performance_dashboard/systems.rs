@@ -2,6 +2,7 @@
 
 use crate::ui::ChildBuilder;
 use super::types::*;
+use crate::diagnostics::{MemoryReport, StressReport};
 use crate::performance::RayonMetrics;
 use crate::ui::colors;
 use crate::ui::{ShortcutEvent, ShortcutId};
@@ -121,6 +122,52 @@ pub fn update_metrics_summary(
     }
 }
 
+/// Show the latest memory sample, with each subsystem's growth since the one before
+pub fn update_memory_report(
+    report: Res<MemoryReport>,
+    mut text_query: Query<&mut Text, With<MemoryReportDisplay>>,
+) {
+    if !report.is_changed() {
+        return;
+    }
+    let Some(sample) = report.latest() else {
+        return;
+    };
+    let Ok(mut text) = text_query.single_mut() else {
+        return;
+    };
+
+    let megabytes = |bytes: f64| bytes / (1024.0 * 1024.0);
+    let mut lines = vec![format!("Year {}: {} entities", sample.year, format_count(sample.entities as usize))];
+    for subsystem in &sample.subsystems {
+        let growth = report
+            .growth(subsystem.name)
+            .map(|bytes| format!(" ({:+.2})", megabytes(bytes as f64)))
+            .unwrap_or_default();
+        lines.push(format!(
+            "{}: {:.2} MB{}",
+            subsystem.name,
+            megabytes(subsystem.bytes as f64),
+            growth
+        ));
+    }
+    text.0 = lines.join("\n");
+}
+
+/// Show the synthetic load from the `stress` console command and the frame time under it
+pub fn update_stress_report(
+    report: Res<StressReport>,
+    mut text_query: Query<&mut Text, With<StressReportDisplay>>,
+) {
+    if !report.is_changed() {
+        return;
+    }
+    let Ok(mut text) = text_query.single_mut() else {
+        return;
+    };
+    text.0 = report.summary().join("\n");
+}
+
 /// Update recent operations list
 pub fn refresh_operations_list(
     metrics: Res<RayonMetrics>,
@@ -44,6 +44,14 @@ pub struct OperationsListDisplay;
 #[derive(Component)]
 pub struct MetricsSummaryDisplay;
 
+/// Marker for the per-subsystem memory report text
+#[derive(Component)]
+pub struct MemoryReportDisplay;
+
+/// Marker for the stress test load and frame time text
+#[derive(Component)]
+pub struct StressReportDisplay;
+
 /// Display mode for the dashboard
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DisplayMode {
@@ -0,0 +1,15 @@
+//! Nation personality editor - Gateway module
+//!
+//! When "Customize" is chosen for nation personalities during world
+//! configuration, the new world opens paused on a list of its nations with
+//! sliders for each AI trait, government and economy pickers, historic
+//! archetype presets and randomize buttons.
+
+// PRIVATE modules
+mod plugin;
+mod systems;
+mod types;
+mod ui;
+
+// PUBLIC exports
+pub use plugin::PersonalityEditorPlugin;
@@ -0,0 +1,35 @@
+//! Nation personality editor plugin
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+use super::systems::*;
+use super::types::*;
+use crate::states::GameState;
+
+define_plugin!(PersonalityEditorPlugin {
+    resources: [PersonalityEditorPending],
+
+    messages: [RefreshEditorRows],
+
+    on_exit: {
+        GameState::WorldConfiguration => [arm_personality_editor]
+    },
+
+    on_enter: {
+        GameState::MainMenu => [disarm_personality_editor],
+        GameState::InGame => [open_personality_editor]
+    },
+
+    update: [
+        (
+            hold_simulation_while_editing,
+            apply_trait_sliders,
+            handle_editor_buttons,
+            handle_begin_simulation,
+            refresh_editor_rows,
+        )
+            .chain()
+            .run_if(in_state(GameState::InGame))
+    ]
+});
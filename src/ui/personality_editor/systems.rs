@@ -0,0 +1,271 @@
+//! Nation personality editor systems
+
+use bevy::prelude::*;
+use rand::prelude::*;
+
+use super::types::*;
+use super::ui::{archetype_label, economy_label, government_label, spawn_personality_editor};
+use crate::nations::{
+    EconomicFocus, Economy, Governance, GovernmentHistory, GovernmentType, LegitimacyFactors, Nation, NationId,
+    NationPersonality, PersonalityArchetype, EDITABLE_GOVERNMENTS,
+};
+use crate::resources::GameTime;
+use crate::simulation::SimulationSpeedChanged;
+use crate::ui::Slider;
+use crate::world::WorldGenerationSettings;
+
+/// Remember whether the world being created should open the editor
+pub fn arm_personality_editor(
+    settings: Option<Res<WorldGenerationSettings>>,
+    mut pending: ResMut<PersonalityEditorPending>,
+) {
+    pending.0 = settings.is_some_and(|settings| settings.customize_nations);
+}
+
+/// Returning to the menu abandons a pending edit
+pub fn disarm_personality_editor(mut pending: ResMut<PersonalityEditorPending>) {
+    pending.0 = false;
+}
+
+/// Open the editor on the first frame of a freshly generated world
+pub fn open_personality_editor(
+    mut commands: Commands,
+    mut pending: ResMut<PersonalityEditorPending>,
+    existing: Query<(), With<PersonalityEditorPanel>>,
+    nations: Query<(Entity, &Nation, &NationId, Option<&Governance>, Option<&EconomicFocus>)>,
+) {
+    if !pending.0 || !existing.is_empty() {
+        return;
+    }
+    pending.0 = false;
+
+    let mut rows: Vec<_> = nations
+        .iter()
+        .map(|(entity, nation, id, governance, focus)| {
+            (id.value(), (entity, nation, governance, focus.copied().unwrap_or_default()))
+        })
+        .collect();
+    rows.sort_by_key(|(id, _)| *id);
+    let rows: Vec<_> = rows.into_iter().map(|(_, row)| row).collect();
+
+    info!("Opening personality editor for {} nations", rows.len());
+    spawn_personality_editor(&mut commands, &rows);
+}
+
+/// Time stays frozen while nations are being edited
+pub fn hold_simulation_while_editing(
+    panel: Query<(), With<PersonalityEditorPanel>>,
+    mut game_time: ResMut<GameTime>,
+) {
+    if !panel.is_empty() && !game_time.is_paused() {
+        game_time.pause();
+    }
+}
+
+/// Write slider movements into the nation's personality
+pub fn apply_trait_sliders(
+    sliders: Query<(&Slider, &TraitSlider), Changed<Slider>>,
+    mut nations: Query<&mut Nation>,
+) {
+    for (slider, trait_slider) in &sliders {
+        let Ok(mut nation) = nations.get_mut(trait_slider.nation) else {
+            continue;
+        };
+        let personality_trait = trait_slider.personality_trait;
+        if (personality_trait.get(&nation.personality) - slider.value).abs() > f32::EPSILON {
+            personality_trait.set(&mut nation.personality, slider.value);
+        }
+    }
+}
+
+/// Switch a nation to a new government as if it had always held it
+fn set_government(commands: &mut Commands, nation: Entity, governance: &mut Governance, government: GovernmentType) {
+    governance.government_type = government;
+    governance.tradition_strength = government.mechanics().reform_resistance;
+    governance.legitimacy_factors = LegitimacyFactors::for_government_type(government);
+    commands.entity(nation).insert(GovernmentHistory::new(government));
+}
+
+fn set_focus(commands: &mut Commands, nation: Entity, economy: Option<Mut<Economy>>, focus: EconomicFocus) {
+    if let Some(mut economy) = economy {
+        let base = focus.economy();
+        economy.agricultural_multiplier = base.agricultural_multiplier;
+        economy.trade_multiplier = base.trade_multiplier;
+        economy.industrial_multiplier = base.industrial_multiplier;
+    }
+    commands.entity(nation).insert(focus);
+}
+
+fn randomize_nation(
+    commands: &mut Commands,
+    rng: &mut impl Rng,
+    entity: Entity,
+    nation: &mut Nation,
+    governance: Option<Mut<Governance>>,
+    economy: Option<Mut<Economy>>,
+) {
+    nation.personality = NationPersonality::random(rng);
+    if let Some(focus) = EconomicFocus::ALL.choose(rng) {
+        set_focus(commands, entity, economy, *focus);
+    }
+    if let (Some(mut governance), Some(government)) = (governance, EDITABLE_GOVERNMENTS.choose(rng)) {
+        set_government(commands, entity, &mut governance, *government);
+    }
+}
+
+/// Government, economy, archetype and randomize buttons
+pub fn handle_editor_buttons(
+    mut commands: Commands,
+    government_buttons: Query<(&Interaction, &GovernmentCycleButton), Changed<Interaction>>,
+    economy_buttons: Query<(&Interaction, &EconomyCycleButton), Changed<Interaction>>,
+    mut archetype_buttons: Query<(&Interaction, &mut ArchetypeCycleButton), Changed<Interaction>>,
+    randomize_buttons: Query<(&Interaction, &RandomizeNationButton), Changed<Interaction>>,
+    randomize_all: Query<&Interaction, (Changed<Interaction>, With<RandomizeAllButton>)>,
+    mut nations: Query<(
+        Entity,
+        &mut Nation,
+        Option<&mut Governance>,
+        Option<&EconomicFocus>,
+        Option<&mut Economy>,
+    )>,
+    mut refresh: MessageWriter<RefreshEditorRows>,
+) {
+    let mut changed = false;
+
+    for (interaction, button) in &government_buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Ok((entity, _, Some(mut governance), _, _)) = nations.get_mut(button.nation) {
+            let current = EDITABLE_GOVERNMENTS
+                .iter()
+                .position(|government| *government == governance.government_type);
+            let next = current.map_or(0, |index| (index + 1) % EDITABLE_GOVERNMENTS.len());
+            set_government(&mut commands, entity, &mut governance, EDITABLE_GOVERNMENTS[next]);
+            changed = true;
+        }
+    }
+
+    for (interaction, button) in &economy_buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Ok((entity, _, _, focus, economy)) = nations.get_mut(button.nation) {
+            let current = focus.copied().unwrap_or_default();
+            let index = EconomicFocus::ALL
+                .iter()
+                .position(|f| *f == current)
+                .unwrap_or(0);
+            let next = EconomicFocus::ALL[(index + 1) % EconomicFocus::ALL.len()];
+            set_focus(&mut commands, entity, economy, next);
+            changed = true;
+        }
+    }
+
+    for (interaction, mut button) in &mut archetype_buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let archetype = PersonalityArchetype::ALL[button.next % PersonalityArchetype::ALL.len()];
+        button.next = (button.next + 1) % PersonalityArchetype::ALL.len();
+        if let Ok((entity, mut nation, governance, _, economy)) = nations.get_mut(button.nation) {
+            nation.personality = archetype.personality();
+            set_focus(&mut commands, entity, economy, archetype.economic_focus());
+            if let Some(mut governance) = governance {
+                set_government(&mut commands, entity, &mut governance, archetype.government());
+            }
+            changed = true;
+        }
+    }
+
+    let mut rng = thread_rng();
+    for (interaction, button) in &randomize_buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Ok((entity, mut nation, governance, _, economy)) = nations.get_mut(button.nation) {
+            randomize_nation(&mut commands, &mut rng, entity, &mut nation, governance, economy);
+            changed = true;
+        }
+    }
+
+    if randomize_all.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        for (entity, mut nation, governance, _, economy) in &mut nations {
+            randomize_nation(&mut commands, &mut rng, entity, &mut nation, governance, economy);
+        }
+        changed = true;
+    }
+
+    if changed {
+        refresh.write(RefreshEditorRows);
+    }
+}
+
+/// Close the editor and let time run
+pub fn handle_begin_simulation(
+    mut commands: Commands,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<BeginSimulationButton>)>,
+    panels: Query<Entity, With<PersonalityEditorPanel>>,
+    mut game_time: ResMut<GameTime>,
+    mut speed_events: MessageWriter<SimulationSpeedChanged>,
+) {
+    if !buttons.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        return;
+    }
+
+    for panel in &panels {
+        commands.entity(panel).despawn();
+    }
+    game_time.resume();
+    speed_events.write(SimulationSpeedChanged {
+        new_speed: game_time.get_speed().multiplier(),
+        is_paused: game_time.is_paused(),
+    });
+}
+
+/// Re-read sliders and button labels after a preset changed several values at once
+pub fn refresh_editor_rows(
+    mut refresh: MessageReader<RefreshEditorRows>,
+    nations: Query<(&Nation, Option<&Governance>, Option<&EconomicFocus>)>,
+    mut sliders: Query<(&mut Slider, &TraitSlider)>,
+    government_buttons: Query<(&GovernmentCycleButton, &Children)>,
+    economy_buttons: Query<(&EconomyCycleButton, &Children)>,
+    archetype_buttons: Query<(&ArchetypeCycleButton, &Children)>,
+    mut texts: Query<&mut Text>,
+) {
+    if refresh.read().count() == 0 {
+        return;
+    }
+
+    for (mut slider, trait_slider) in &mut sliders {
+        if let Ok((nation, _, _)) = nations.get(trait_slider.nation) {
+            let value = trait_slider.personality_trait.get(&nation.personality);
+            if (slider.value - value).abs() > f32::EPSILON {
+                slider.value = value;
+            }
+        }
+    }
+
+    let mut set_label = |children: &Children, label: String| {
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                **text = label;
+                break;
+            }
+        }
+    };
+
+    for (button, children) in &government_buttons {
+        if let Ok((_, governance, _)) = nations.get(button.nation) {
+            set_label(children, government_label(governance));
+        }
+    }
+    for (button, children) in &economy_buttons {
+        if let Ok((_, _, focus)) = nations.get(button.nation) {
+            set_label(children, economy_label(focus.copied().unwrap_or_default()));
+        }
+    }
+    for (button, children) in &archetype_buttons {
+        set_label(children, archetype_label(button.next));
+    }
+}
@@ -0,0 +1,104 @@
+//! Data types for the nation personality editor
+
+use bevy::prelude::*;
+
+use crate::nations::NationPersonality;
+
+/// Whether the next world to start should open the editor first
+///
+/// Armed when leaving world configuration with "Customize" chosen, so loading
+/// a save never opens it.
+#[derive(Resource, Default, Debug)]
+pub struct PersonalityEditorPending(pub bool);
+
+/// One of the four AI personality axes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PersonalityTrait {
+    Aggression,
+    Expansionism,
+    Diplomacy,
+    Mercantilism,
+}
+
+impl PersonalityTrait {
+    pub const ALL: [PersonalityTrait; 4] = [
+        PersonalityTrait::Aggression,
+        PersonalityTrait::Expansionism,
+        PersonalityTrait::Diplomacy,
+        PersonalityTrait::Mercantilism,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PersonalityTrait::Aggression => "Aggression",
+            PersonalityTrait::Expansionism => "Expansion",
+            PersonalityTrait::Diplomacy => "Diplomacy",
+            PersonalityTrait::Mercantilism => "Trade",
+        }
+    }
+
+    pub fn get(&self, personality: &NationPersonality) -> f32 {
+        match self {
+            PersonalityTrait::Aggression => personality.aggression,
+            PersonalityTrait::Expansionism => personality.expansionism,
+            PersonalityTrait::Diplomacy => personality.diplomacy,
+            PersonalityTrait::Mercantilism => personality.mercantilism,
+        }
+    }
+
+    pub fn set(&self, personality: &mut NationPersonality, value: f32) {
+        let value = value.clamp(-1.0, 1.0);
+        match self {
+            PersonalityTrait::Aggression => personality.aggression = value,
+            PersonalityTrait::Expansionism => personality.expansionism = value,
+            PersonalityTrait::Diplomacy => personality.diplomacy = value,
+            PersonalityTrait::Mercantilism => personality.mercantilism = value,
+        }
+    }
+}
+
+/// Marker for the editor panel root
+#[derive(Component)]
+pub struct PersonalityEditorPanel;
+
+/// Slider editing one trait of one nation
+#[derive(Component)]
+pub struct TraitSlider {
+    pub nation: Entity,
+    pub personality_trait: PersonalityTrait,
+}
+
+/// Cycles the nation through the editable governments
+#[derive(Component)]
+pub struct GovernmentCycleButton {
+    pub nation: Entity,
+}
+
+/// Cycles the nation through the economic focuses
+#[derive(Component)]
+pub struct EconomyCycleButton {
+    pub nation: Entity,
+}
+
+/// Applies the next historic archetype to the nation
+#[derive(Component)]
+pub struct ArchetypeCycleButton {
+    pub nation: Entity,
+    pub next: usize,
+}
+
+#[derive(Component)]
+pub struct RandomizeNationButton {
+    pub nation: Entity,
+}
+
+#[derive(Component)]
+pub struct RandomizeAllButton;
+
+/// Closes the editor and lets time run
+#[derive(Component)]
+pub struct BeginSimulationButton;
+
+/// Sliders and labels need to be re-read from the nations
+#[derive(Message, Debug, Clone, Default)]
+pub struct RefreshEditorRows;
@@ -0,0 +1,183 @@
+//! Nation personality editor rendering
+
+use bevy::prelude::*;
+
+use super::types::*;
+use crate::nations::{get_structure_name, EconomicFocus, Governance, Nation, PersonalityArchetype};
+use crate::ui::*;
+
+/// Label for a nation's government button
+pub fn government_label(governance: Option<&Governance>) -> String {
+    match governance {
+        Some(governance) => format!("Government: {}", get_structure_name(&governance.government_type)),
+        None => "Government: Unknown".to_string(),
+    }
+}
+
+pub fn economy_label(focus: EconomicFocus) -> String {
+    format!("Economy: {}", focus.label())
+}
+
+/// Label naming the archetype the button will apply next
+pub fn archetype_label(next: usize) -> String {
+    let archetype = PersonalityArchetype::ALL[next % PersonalityArchetype::ALL.len()];
+    format!("Archetype: {}", archetype.label())
+}
+
+/// Spawn the editor with one row per nation
+pub fn spawn_personality_editor(
+    commands: &mut Commands,
+    nations: &[(Entity, &Nation, Option<&Governance>, EconomicFocus)],
+) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(10.0),
+                top: Val::Percent(5.0),
+                width: Val::Percent(80.0),
+                height: Val::Percent(90.0),
+                padding: UiRect::all(Val::Px(20.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(12.0),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(UI_BACKGROUND_COLOR),
+            BorderColor::all(UI_BORDER_COLOR),
+            ThemedAccent::Border(UI_BORDER_COLOR),
+            GlobalZIndex(layers::MODAL_OVERLAY),
+            PersonalityEditorPanel,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("NATION PERSONALITIES"),
+                TextFont {
+                    font_size: TEXT_SIZE_TITLE,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR_HEADER),
+            ));
+            parent.spawn((
+                Text::new("Time is paused. Shape each nation's temperament, then begin the simulation."),
+                TextFont {
+                    font_size: TEXT_SIZE_NORMAL,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR_SECONDARY),
+            ));
+
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    ButtonBuilder::new("Randomize All")
+                        .size(ButtonSize::Small)
+                        .style(ButtonStyle::Secondary)
+                        .with_marker(RandomizeAllButton)
+                        .build(parent);
+                    ButtonBuilder::new("Begin Simulation")
+                        .size(ButtonSize::Small)
+                        .style(ButtonStyle::Success)
+                        .with_marker(BeginSimulationButton)
+                        .build(parent);
+                });
+
+            parent
+                .spawn(Node {
+                    width: Val::Percent(100.0),
+                    flex_grow: 1.0,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(10.0),
+                    overflow: Overflow::scroll_y(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for &(entity, nation, governance, focus) in nations {
+                        spawn_nation_row(parent, entity, nation, governance, focus);
+                    }
+                });
+        });
+}
+
+fn spawn_nation_row(
+    parent: &mut ChildBuilder,
+    entity: Entity,
+    nation: &Nation,
+    governance: Option<&Governance>,
+    focus: EconomicFocus,
+) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                border: UiRect::left(Val::Px(4.0)),
+                ..default()
+            },
+            BorderColor::all(nation.color),
+        ))
+        .with_children(|row| {
+            row.spawn((
+                Text::new(nation.name.clone()),
+                TextFont {
+                    font_size: TEXT_SIZE_LARGE,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR_PRIMARY),
+            ));
+
+            row.spawn(Node {
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(12.0),
+                ..default()
+            })
+            .with_children(|sliders| {
+                for personality_trait in PersonalityTrait::ALL {
+                    let slider = SliderBuilder::new(-1.0..1.0)
+                        .label(personality_trait.label())
+                        .value(personality_trait.get(&nation.personality))
+                        .step(0.05)
+                        .format(ValueFormat::Custom(|v| format!("{:+.2}", v)))
+                        .width(Val::Percent(24.0))
+                        .build(sliders);
+                    sliders.commands().entity(slider).insert(TraitSlider {
+                        nation: entity,
+                        personality_trait,
+                    });
+                }
+            });
+
+            row.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(8.0),
+                ..default()
+            })
+            .with_children(|buttons| {
+                ButtonBuilder::new(government_label(governance))
+                    .size(ButtonSize::Small)
+                    .with_marker(GovernmentCycleButton { nation: entity })
+                    .build(buttons);
+                ButtonBuilder::new(economy_label(focus))
+                    .size(ButtonSize::Small)
+                    .with_marker(EconomyCycleButton { nation: entity })
+                    .build(buttons);
+                ButtonBuilder::new(archetype_label(0))
+                    .size(ButtonSize::Small)
+                    .style(ButtonStyle::Secondary)
+                    .with_marker(ArchetypeCycleButton { nation: entity, next: 0 })
+                    .build(buttons);
+                ButtonBuilder::new("Randomize")
+                    .size(ButtonSize::Small)
+                    .style(ButtonStyle::Secondary)
+                    .with_marker(RandomizeNationButton { nation: entity })
+                    .build(buttons);
+            });
+        });
+}
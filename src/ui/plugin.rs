@@ -1,9 +1,9 @@
 //! Main UI plugin implementation
 
 use super::{
-    animation, family_browser, family_tree, hud, law_browser, loading, nation_info,
-    nation_laws_panel, notifications, overlay_display, performance_dashboard, shortcuts,
-    tile_info,
+    accessibility, animation, army_inspector, dev_console, economy_flow, family_browser, family_tree,
+    follow_breadcrumbs, graph_view, hud, law_browser, layout, loading, nation_info, nation_laws_panel, notifications,
+    overlay_display, performance_dashboard, personality_editor, relations_graph, shortcuts, theming, tile_info,
 };
 use bevy_plugin_builder::define_plugin;
 use bevy_ui_builders::UiBuilderPlugin;
@@ -18,8 +18,12 @@ define_plugin!(UIPlugin {
         // Core UI systems
         UiBuilderPlugin,
         animation::AnimationPlugin,
+        layout::LayoutPlugin,
+        accessibility::AccessibilityPlugin,
+        theming::ThemingPlugin,
         shortcuts::ShortcutPlugin,
         notifications::NotificationPlugin,
+        dev_console::DevConsolePlugin,
         // Game-specific UI plugins
         loading::LoadingIndicatorPlugin,
         hud::HudPlugin,
@@ -30,6 +34,12 @@ define_plugin!(UIPlugin {
         law_browser::LawBrowserPlugin,
         nation_laws_panel::NationLawsPanelPlugin,
         family_browser::FamilyBrowserPlugin,
-        family_tree::FamilyTreePlugin
+        graph_view::GraphViewPlugin,
+        family_tree::FamilyTreePlugin,
+        relations_graph::RelationsGraphPlugin,
+        economy_flow::EconomyFlowPlugin,
+        army_inspector::ArmyInspectorPlugin,
+        follow_breadcrumbs::FollowBreadcrumbsPlugin,
+        personality_editor::PersonalityEditorPlugin
     ]
 });
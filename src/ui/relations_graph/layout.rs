@@ -0,0 +1,76 @@
+//! Placing nations on the ring and deciding which pairs get an edge
+
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+
+use super::types::RelationKind;
+
+/// Opinions milder than this are left undrawn
+pub const OPINION_THRESHOLD: f32 = 0.3;
+
+/// The edge between two nations, if any: war over alliance over strong opinion
+pub fn classify(at_war: bool, allied: bool, opinion: f32) -> Option<RelationKind> {
+    if at_war {
+        Some(RelationKind::War)
+    } else if allied {
+        Some(RelationKind::Alliance)
+    } else if opinion.abs() >= OPINION_THRESHOLD {
+        Some(RelationKind::Opinion(opinion))
+    } else {
+        None
+    }
+}
+
+/// Evenly spaced positions on a ring, one per nation
+///
+/// Nations keep their bearing from the middle of the map, so neighbours sit
+/// side by side and the ring reads like a compass. `capitals` are map positions
+/// (y up); the result is in UI space (y down) around `center`, in input order.
+pub fn radial_layout(capitals: &[Vec2], center: Vec2, radius: f32) -> Vec<Vec2> {
+    if capitals.is_empty() {
+        return Vec::new();
+    }
+    let middle = capitals.iter().copied().sum::<Vec2>() / capitals.len() as f32;
+    let bearing = |capital: Vec2| {
+        let offset = capital - middle;
+        (-offset.y).atan2(offset.x)
+    };
+    let mut order: Vec<usize> = (0..capitals.len()).collect();
+    order.sort_by(|&a, &b| bearing(capitals[a]).total_cmp(&bearing(capitals[b])));
+
+    // Start the ring at the first nation's own bearing so the compass holds
+    let start = bearing(capitals[order[0]]);
+    let step = TAU / capitals.len() as f32;
+    let mut positions = vec![center; capitals.len()];
+    for (slot, &index) in order.iter().enumerate() {
+        let angle = start + step * slot as f32;
+        positions[index] = center + Vec2::new(angle.cos(), angle.sin()) * radius;
+    }
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ring_keeps_compass_bearings_and_edges_rank_war_first() {
+        // East, north, west, south on the map
+        let capitals = [
+            Vec2::new(10.0, 0.0),
+            Vec2::new(0.0, 10.0),
+            Vec2::new(-10.0, 0.0),
+            Vec2::new(0.0, -10.0),
+        ];
+        let positions = radial_layout(&capitals, Vec2::splat(100.0), 50.0);
+        assert!(positions[0].x > 140.0);
+        assert!(positions[1].y < 60.0, "north is drawn at the top");
+        assert!(positions[2].x < 60.0);
+        assert!(positions[3].y > 140.0);
+
+        assert_eq!(classify(true, true, 0.9), Some(RelationKind::War));
+        assert_eq!(classify(false, true, -0.9), Some(RelationKind::Alliance));
+        assert_eq!(classify(false, false, -0.5), Some(RelationKind::Opinion(-0.5)));
+        assert_eq!(classify(false, false, 0.1), None);
+    }
+}
@@ -0,0 +1,17 @@
+//! Diplomatic relations graph - Gateway module
+//!
+//! U opens a radial graph of nations, each placed on the ring by the bearing
+//! of its capital from the middle of the map. Edges show wars, alliances, and
+//! strong opinions; the graph can be narrowed to the great powers or to the
+//! selected nation's neighbourhood, and clicking a nation opens its panel.
+
+// PRIVATE modules
+mod layout;
+mod plugin;
+mod systems;
+mod types;
+mod ui;
+
+// PUBLIC exports
+pub use plugin::RelationsGraphPlugin;
+pub use types::{RelationsFilter, RelationsView};
@@ -0,0 +1,27 @@
+//! Relations graph plugin
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+use super::systems::*;
+use super::types::RelationsView;
+use crate::states::GameState;
+
+define_plugin!(RelationsGraphPlugin {
+    resources: [RelationsView],
+
+    update: [
+        (
+            toggle_relations_graph,
+            handle_relations_buttons,
+            handle_relations_node_click,
+            refresh_relations_graph
+        )
+            .chain()
+            .run_if(in_state(GameState::InGame))
+    ],
+
+    on_exit: {
+        GameState::InGame => [close_relations_graph]
+    }
+});
@@ -0,0 +1,223 @@
+//! Opening, filtering, and rebuilding the relations graph
+
+use bevy::prelude::*;
+use std::collections::{BTreeSet, HashSet};
+
+use super::layout::{classify, radial_layout};
+use super::types::{
+    CloseRelationsGraphButton, RelationsFilter, RelationsFilterButton, RelationsGraphPanel, RelationsNodeButton,
+    RelationsView,
+};
+use super::ui::{spawn_relations_graph_panel, GraphEdge, GraphNode, GRAPH_RADIUS, GRAPH_SIZE};
+use crate::audio::{AudioCue, AudioEvent};
+use crate::nations::{
+    rank_great_powers, Attacking, LandNeighbors, Nation, NationId, NavalNeighbors, Treaty, TreatyClause,
+    TreatyCompliance, TreatyKind,
+};
+use crate::relationships::Controls;
+use crate::simulation::NewYearEvent;
+use crate::ui::{SelectedNation, ShortcutEvent, ShortcutId};
+use crate::world::ProvinceStorage;
+
+/// Nations drawn at once; beyond this the ring is too crowded to read
+const MAX_NODES: usize = 40;
+
+/// U opens and closes the relations graph
+pub fn toggle_relations_graph(
+    mut shortcuts: MessageReader<ShortcutEvent>,
+    mut view: ResMut<RelationsView>,
+    mut audio: MessageWriter<AudioEvent>,
+) {
+    if !shortcuts
+        .read()
+        .any(|event| event.shortcut_id == ShortcutId::ToggleRelations)
+    {
+        return;
+    }
+    view.open = !view.open;
+    let cue = if view.open { AudioCue::UiOpen } else { AudioCue::UiClose };
+    audio.write(AudioEvent::new(cue));
+}
+
+/// Filter and close buttons on the relations graph
+pub fn handle_relations_buttons(
+    filter_buttons: Query<(&Interaction, &RelationsFilterButton), Changed<Interaction>>,
+    close_buttons: Query<&Interaction, (Changed<Interaction>, With<CloseRelationsGraphButton>)>,
+    mut view: ResMut<RelationsView>,
+) {
+    if close_buttons
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed)
+    {
+        view.open = false;
+        return;
+    }
+    for (interaction, button) in &filter_buttons {
+        if *interaction == Interaction::Pressed && view.filter != button.0 {
+            view.filter = button.0;
+        }
+    }
+}
+
+/// Clicking a nation selects it, which opens its panel, and closes the graph
+pub fn handle_relations_node_click(
+    nodes: Query<(&Interaction, &RelationsNodeButton), Changed<Interaction>>,
+    nation_ids: Query<&NationId>,
+    mut selected: ResMut<SelectedNation>,
+    mut view: ResMut<RelationsView>,
+) {
+    for (interaction, node) in &nodes {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Ok(nation_id) = nation_ids.get(node.0) {
+            selected.entity = Some(node.0);
+            selected.nation_id = Some(*nation_id);
+            view.open = false;
+        }
+    }
+}
+
+/// Rebuild the graph when it opens, its filter or the selection changes, or a year passes
+pub fn refresh_relations_graph(
+    mut commands: Commands,
+    view: Res<RelationsView>,
+    selected: Res<SelectedNation>,
+    mut year_events: MessageReader<NewYearEvent>,
+    nations: Query<(
+        Entity,
+        &Nation,
+        Option<&Controls>,
+        Option<&TreatyCompliance>,
+        Option<&LandNeighbors>,
+        Option<&NavalNeighbors>,
+    )>,
+    treaties: Query<&Treaty>,
+    wars: Query<(Entity, &Attacking)>,
+    storage: Option<Res<ProvinceStorage>>,
+    panels: Query<Entity, With<RelationsGraphPanel>>,
+) {
+    let new_year = year_events.read().last().is_some();
+    if !view.is_changed() && !selected.is_changed() && !(view.open && new_year) {
+        return;
+    }
+    for panel in &panels {
+        commands.entity(panel).despawn();
+    }
+    if !view.open {
+        return;
+    }
+
+    let size = |controls: Option<&Controls>| controls.map_or(0, Controls::province_count);
+    let great_powers = rank_great_powers(nations.iter().map(|(entity, _, controls, ..)| (entity, size(controls))));
+
+    let mut note = None;
+    let mut members: Vec<Entity> = match view.filter {
+        RelationsFilter::All => nations.iter().map(|(entity, ..)| entity).collect(),
+        RelationsFilter::GreatPowers => great_powers.iter().copied().collect(),
+        RelationsFilter::Region => match selected.entity.and_then(|entity| nations.get(entity).ok()) {
+            Some((entity, _, _, _, land, naval)) => std::iter::once(entity)
+                .chain(land.into_iter().flat_map(|land| land.neighbors().iter().copied()))
+                .chain(naval.into_iter().flat_map(|naval| naval.neighbors().iter().copied()))
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect(),
+            None => {
+                note = Some("Select a nation on the map to see its neighbourhood".to_string());
+                Vec::new()
+            }
+        },
+    };
+    members.retain(|&entity| nations.contains(entity));
+    if members.len() > MAX_NODES {
+        let total = members.len();
+        members.sort_by_key(|&entity| {
+            std::cmp::Reverse(nations.get(entity).map_or(0, |(_, _, controls, ..)| size(controls)))
+        });
+        members.truncate(MAX_NODES);
+        note = Some(format!("Showing the {} largest of {} nations", MAX_NODES, total));
+    }
+
+    let capital = |nation: &Nation| {
+        storage
+            .as_ref()
+            .and_then(|storage| {
+                let index = *storage.province_by_id.get(&nation.capital_province)?;
+                storage.provinces.get(index).map(|province| province.position)
+            })
+            .unwrap_or(Vec2::ZERO)
+    };
+    let capitals: Vec<Vec2> = members
+        .iter()
+        .map(|&entity| {
+            nations
+                .get(entity)
+                .map_or(Vec2::ZERO, |(_, nation, ..)| capital(nation))
+        })
+        .collect();
+    let positions = radial_layout(&capitals, Vec2::splat(GRAPH_SIZE / 2.0), GRAPH_RADIUS);
+
+    let at_war: HashSet<(Entity, Entity)> = wars
+        .iter()
+        .flat_map(|(attacker, attacking)| [(attacker, attacking.0), (attacking.0, attacker)])
+        .collect();
+    let allied: HashSet<(Entity, Entity)> = treaties
+        .iter()
+        .filter(|treaty| treaty.kind == TreatyKind::Alliance || treaty.clauses.contains(&TreatyClause::MutualDefense))
+        .flat_map(|treaty| {
+            let [a, b] = treaty.signatories;
+            [(a, b), (b, a)]
+        })
+        .collect();
+    let opinion = |from: Entity, of: Entity| {
+        nations
+            .get(from)
+            .ok()
+            .and_then(|(_, _, _, compliance, ..)| compliance)
+            .map_or(0.0, |compliance| compliance.opinion_of(of))
+    };
+
+    let mut edges = Vec::new();
+    for (i, &a) in members.iter().enumerate() {
+        for (j, &b) in members.iter().enumerate().skip(i + 1) {
+            let mutual = (opinion(a, b) + opinion(b, a)) / 2.0;
+            if let Some(kind) = classify(at_war.contains(&(a, b)), allied.contains(&(a, b)), mutual) {
+                edges.push(GraphEdge {
+                    from: positions[i],
+                    to: positions[j],
+                    kind,
+                });
+            }
+        }
+    }
+
+    let nodes: Vec<GraphNode> = members
+        .iter()
+        .zip(&positions)
+        .filter_map(|(&entity, &position)| {
+            let (_, nation, ..) = nations.get(entity).ok()?;
+            Some(GraphNode {
+                entity,
+                name: nation.name.clone(),
+                color: nation.color,
+                great_power: great_powers.contains(&entity),
+                selected: selected.entity == Some(entity),
+                position,
+            })
+        })
+        .collect();
+
+    spawn_relations_graph_panel(&mut commands, view.filter, &nodes, &edges, note.as_deref());
+}
+
+/// Close the graph when leaving the game
+pub fn close_relations_graph(
+    mut view: ResMut<RelationsView>,
+    panels: Query<Entity, With<RelationsGraphPanel>>,
+    mut commands: Commands,
+) {
+    for panel in &panels {
+        commands.entity(panel).despawn();
+    }
+    *view = RelationsView::default();
+}
@@ -0,0 +1,81 @@
+//! Data types for the diplomatic relations graph
+
+use bevy::prelude::*;
+
+/// Which nations the graph shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RelationsFilter {
+    #[default]
+    All,
+    /// The largest nations by territory
+    GreatPowers,
+    /// The selected nation and its land and sea neighbours
+    Region,
+}
+
+impl RelationsFilter {
+    pub const ALL: [RelationsFilter; 3] = [
+        RelationsFilter::All,
+        RelationsFilter::GreatPowers,
+        RelationsFilter::Region,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            RelationsFilter::All => "All Nations",
+            RelationsFilter::GreatPowers => "Great Powers",
+            RelationsFilter::Region => "Region",
+        }
+    }
+}
+
+/// Whether the graph is open and what it shows
+#[derive(Resource, Debug, Default)]
+pub struct RelationsView {
+    pub open: bool,
+    pub filter: RelationsFilter,
+}
+
+/// How two nations stand with each other, as drawn on an edge
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RelationKind {
+    War,
+    Alliance,
+    /// Mutual opinion, -1.0 (hated) to 1.0 (beloved)
+    Opinion(f32),
+}
+
+impl RelationKind {
+    pub fn color(self) -> Color {
+        match self {
+            RelationKind::War => Color::srgb(0.9, 0.2, 0.2),
+            RelationKind::Alliance => Color::srgb(0.3, 0.55, 0.95),
+            RelationKind::Opinion(opinion) if opinion >= 0.0 => Color::srgba(0.35, 0.8, 0.4, 0.3 + opinion * 0.6),
+            RelationKind::Opinion(opinion) => Color::srgba(0.95, 0.6, 0.2, 0.3 - opinion * 0.6),
+        }
+    }
+
+    /// Wars and alliances are drawn thicker than opinions
+    pub fn thickness(self) -> f32 {
+        match self {
+            RelationKind::War | RelationKind::Alliance => 3.0,
+            RelationKind::Opinion(_) => 1.5,
+        }
+    }
+}
+
+/// Marker for the relations graph panel root
+#[derive(Component)]
+pub struct RelationsGraphPanel;
+
+/// Filter button
+#[derive(Component, Clone, Copy)]
+pub struct RelationsFilterButton(pub RelationsFilter);
+
+/// Close button
+#[derive(Component)]
+pub struct CloseRelationsGraphButton;
+
+/// A nation's node; clicking it opens the nation's panel
+#[derive(Component, Clone, Copy)]
+pub struct RelationsNodeButton(pub Entity);
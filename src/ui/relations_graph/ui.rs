@@ -0,0 +1,190 @@
+//! Relations graph UI rendering
+
+use bevy::prelude::*;
+
+use super::types::{
+    CloseRelationsGraphButton, RelationKind, RelationsFilter, RelationsFilterButton, RelationsGraphPanel,
+    RelationsNodeButton,
+};
+use crate::ui::graph_view::{spawn_graph_edge, spawn_graph_viewport};
+use crate::ui::*;
+
+/// Side of the square the ring is drawn in
+pub const GRAPH_SIZE: f32 = 620.0;
+/// Radius of the ring of nations
+pub const GRAPH_RADIUS: f32 = 250.0;
+const NODE_SIZE: f32 = 18.0;
+const NODE_LABEL_WIDTH: f32 = 110.0;
+
+/// A nation placed on the ring
+pub struct GraphNode {
+    pub entity: Entity,
+    pub name: String,
+    pub color: Color,
+    pub great_power: bool,
+    pub selected: bool,
+    /// Center of the node within the graph area
+    pub position: Vec2,
+}
+
+/// A relation between two placed nations
+pub struct GraphEdge {
+    pub from: Vec2,
+    pub to: Vec2,
+    pub kind: RelationKind,
+}
+
+fn line(parent: &mut ChildBuilder, text: String, color: Color) {
+    parent.spawn((
+        Text::new(text),
+        TextFont {
+            font_size: TEXT_SIZE_NORMAL,
+            ..default()
+        },
+        TextColor(color),
+    ));
+}
+
+fn spawn_node(parent: &mut ChildBuilder, node: &GraphNode) {
+    let size = if node.great_power { NODE_SIZE * 1.4 } else { NODE_SIZE };
+    let border = if node.selected {
+        TEXT_COLOR_HEADER
+    } else {
+        UI_BORDER_COLOR
+    };
+    parent.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(node.position.x - size / 2.0),
+            top: Val::Px(node.position.y - size / 2.0),
+            width: Val::Px(size),
+            height: Val::Px(size),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(node.color),
+        BorderColor::all(border),
+        BorderRadius::MAX,
+        Interaction::default(),
+        RelationsNodeButton(node.entity),
+    ));
+    parent.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(node.position.x - NODE_LABEL_WIDTH / 2.0),
+            top: Val::Px(node.position.y + size / 2.0 + 2.0),
+            width: Val::Px(NODE_LABEL_WIDTH),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        Text::new(node.name.clone()),
+        TextFont {
+            font_size: 11.0,
+            ..default()
+        },
+        TextColor(if node.selected {
+            TEXT_COLOR_HEADER
+        } else {
+            TEXT_COLOR_PRIMARY
+        }),
+        TextLayout::new_with_justify(Justify::Center),
+    ));
+}
+
+/// Spawn the relations graph with its filter bar, legend, and ring of nations
+pub fn spawn_relations_graph_panel(
+    commands: &mut Commands,
+    filter: RelationsFilter,
+    nodes: &[GraphNode],
+    edges: &[GraphEdge],
+    note: Option<&str>,
+) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(15.0),
+                top: Val::Px(60.0),
+                width: Val::Percent(70.0),
+                height: Val::Percent(85.0),
+                padding: UiRect::all(Val::Px(20.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(UI_BACKGROUND_COLOR),
+            BorderColor::all(UI_BORDER_COLOR),
+            GlobalZIndex(layers::MODAL_OVERLAY),
+            RelationsGraphPanel,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("DIPLOMATIC RELATIONS"),
+                TextFont {
+                    font_size: TEXT_SIZE_TITLE,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR_HEADER),
+            ));
+
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    flex_wrap: FlexWrap::Wrap,
+                    column_gap: Val::Px(6.0),
+                    row_gap: Val::Px(6.0),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for candidate in RelationsFilter::ALL {
+                        let style = if candidate == filter {
+                            ButtonStyle::Primary
+                        } else {
+                            ButtonStyle::Secondary
+                        };
+                        ButtonBuilder::new(candidate.label())
+                            .size(ButtonSize::Small)
+                            .style(style)
+                            .with_marker(RelationsFilterButton(candidate))
+                            .build(parent);
+                    }
+                    ButtonBuilder::new("Close")
+                        .size(ButtonSize::Small)
+                        .style(ButtonStyle::Ghost)
+                        .with_marker(CloseRelationsGraphButton)
+                        .build(parent);
+                });
+
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(16.0),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for (label, kind) in [
+                        ("War", RelationKind::War),
+                        ("Alliance", RelationKind::Alliance),
+                        ("Friendly", RelationKind::Opinion(1.0)),
+                        ("Hostile", RelationKind::Opinion(-1.0)),
+                    ] {
+                        line(parent, label.to_string(), kind.color());
+                    }
+                });
+
+            if let Some(note) = note {
+                line(parent, note.to_string(), TEXT_COLOR_SECONDARY);
+            }
+
+            spawn_graph_viewport(parent, Vec2::splat(GRAPH_SIZE), (), |parent| {
+                // Edges first so nodes sit on top of them
+                for edge in edges {
+                    spawn_graph_edge(parent, edge.from, edge.to, edge.kind.thickness(), edge.kind.color(), ());
+                }
+                for node in nodes {
+                    spawn_node(parent, node);
+                }
+            });
+        });
+}
@@ -185,6 +185,16 @@ impl ShortcutSetBuilder {
                 .description("Zoom Out")
                 .in_game()
                 .group("Camera"),
+            ShortcutBuilder::new(ShortcutId::CameraReset)
+                .key(KeyCode::Home)
+                .description("Reset Camera")
+                .in_game()
+                .group("Camera"),
+            ShortcutBuilder::new(ShortcutId::ToggleEdgeScrolling)
+                .key(KeyCode::ScrollLock)
+                .description("Toggle Edge Scrolling")
+                .in_game()
+                .group("Camera"),
         ]);
         self
     }
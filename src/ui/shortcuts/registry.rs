@@ -11,6 +11,8 @@ pub struct ShortcutRegistry {
     shortcuts: HashMap<ShortcutId, ShortcutDefinition>,
     /// Map of key bindings to shortcut IDs (for conflict detection)
     bindings: HashMap<(KeyBinding, ShortcutContext), ShortcutId>,
+    /// Map of gamepad buttons to shortcut IDs, in the context of each shortcut
+    gamepad_bindings: HashMap<(GamepadButton, ShortcutContext), ShortcutId>,
     /// Grouped shortcuts for UI display
     groups: HashMap<String, ShortcutGroup>,
     /// Currently active context
@@ -105,6 +107,31 @@ impl ShortcutRegistry {
         None
     }
 
+    /// Bind a gamepad button to a registered shortcut, in that shortcut's context
+    pub fn bind_gamepad(&mut self, id: ShortcutId, button: GamepadButton) {
+        if let Some(definition) = self.shortcuts.get(&id) {
+            self.gamepad_bindings.insert((button, definition.context), id);
+        }
+    }
+
+    /// Shortcuts triggered by this frame's gamepad button presses
+    ///
+    /// A button bound in the active context wins over its global binding, so
+    /// the d-pad steps through settings rather than dropdowns while the
+    /// settings menu is open.
+    pub fn check_gamepad_shortcuts(&self, pressed: &[GamepadButton]) -> Vec<ShortcutId> {
+        pressed
+            .iter()
+            .filter_map(|button| {
+                self.gamepad_bindings
+                    .get(&(*button, self.active_context))
+                    .or_else(|| self.gamepad_bindings.get(&(*button, ShortcutContext::Global)))
+            })
+            .filter(|id| self.shortcuts.get(id).is_some_and(|definition| definition.enabled))
+            .cloned()
+            .collect()
+    }
+
     /// Set the active context
     pub fn set_context(&mut self, context: ShortcutContext) {
         self.active_context = context;
@@ -193,6 +220,7 @@ impl ShortcutRegistry {
     pub fn clear(&mut self) {
         self.shortcuts.clear();
         self.bindings.clear();
+        self.gamepad_bindings.clear();
         self.groups.clear();
     }
 
@@ -225,6 +253,8 @@ impl ShortcutRegistry {
             (CameraRight, KeyBinding::single(KeyCode::KeyD), "Move Camera Right", ShortcutContext::InGame),
             (CameraZoomIn, KeyBinding::single(KeyCode::KeyQ), "Zoom In", ShortcutContext::InGame),
             (CameraZoomOut, KeyBinding::single(KeyCode::KeyE), "Zoom Out", ShortcutContext::InGame),
+            (CameraReset, KeyBinding::single(KeyCode::Home), "Reset Camera", ShortcutContext::InGame),
+            (ToggleEdgeScrolling, KeyBinding::single(KeyCode::ScrollLock), "Toggle Edge Scrolling", ShortcutContext::InGame),
         ]);
 
         // File operations
@@ -242,11 +272,18 @@ impl ShortcutRegistry {
             (ToggleHud, KeyBinding::single(KeyCode::KeyH), "Toggle HUD", ShortcutContext::InGame),
             (ToggleFps, KeyBinding::single(KeyCode::F3), "Toggle FPS", ShortcutContext::Global),
             (ToggleFullscreen, KeyBinding::single(KeyCode::F11), "Toggle Fullscreen", ShortcutContext::Global),
+            (ToggleNarration, KeyBinding::single(KeyCode::KeyN), "Toggle Narration Feed", ShortcutContext::InGame),
+            (ToggleHistory, KeyBinding::single(KeyCode::KeyJ), "Toggle World History", ShortcutContext::InGame),
+            (ToggleWorlds, KeyBinding::single(KeyCode::F2), "World Switcher", ShortcutContext::Global),
+            (ToggleRelations, KeyBinding::single(KeyCode::KeyU), "Diplomatic Relations", ShortcutContext::InGame),
+            (ToggleEconomyFlow, KeyBinding::single(KeyCode::KeyI), "Economic Flows", ShortcutContext::InGame),
+            (ToggleArmyInspector, KeyBinding::single(KeyCode::KeyT), "Military Inspector", ShortcutContext::InGame),
         ]);
 
         // Map modes
         self.register_many(vec![
             (MapModeToggle, KeyBinding::single(KeyCode::Tab), "Toggle Map Mode", ShortcutContext::InGame),
+            (MapModeCycle, KeyBinding::single(KeyCode::KeyM), "Cycle Map Modes", ShortcutContext::InGame),
         ]);
 
         // Menus
@@ -276,6 +313,11 @@ impl ShortcutRegistry {
             (TakeScreenshot, KeyBinding::single(KeyCode::F12), "Take Screenshot", ShortcutContext::Global),
         ]);
 
+        // Developer
+        self.register_many(vec![
+            (OpenConsole, KeyBinding::single(KeyCode::Backquote), "Developer Console", ShortcutContext::Global),
+        ]);
+
         // Debug shortcuts (only in debug builds)
         #[cfg(debug_assertions)]
         self.register_many(vec![
@@ -293,6 +335,28 @@ impl ShortcutRegistry {
         self.add_to_group(CameraDown, "Camera");
         self.add_to_group(CameraLeft, "Camera");
         self.add_to_group(CameraRight, "Camera");
+        self.add_to_group(CameraReset, "Camera");
+        self.add_to_group(ToggleEdgeScrolling, "Camera");
+
+        // Gamepad: d-pad and face buttons navigate menus and drive the observer view
+        for (id, button) in [
+            (DropdownUp, GamepadButton::DPadUp),
+            (DropdownDown, GamepadButton::DPadDown),
+            (DropdownSelect, GamepadButton::South),
+            (SettingsNavigatePrevious, GamepadButton::DPadUp),
+            (SettingsNavigateNext, GamepadButton::DPadDown),
+            (SettingsActivate, GamepadButton::South),
+            (OpenMainMenu, GamepadButton::Start),
+            (OpenMainMenu, GamepadButton::East),
+            (Pause, GamepadButton::North),
+            (SlowDown, GamepadButton::LeftTrigger),
+            (SpeedUp, GamepadButton::RightTrigger),
+            (MapModeToggle, GamepadButton::West),
+            (MapModeCycle, GamepadButton::Select),
+            (CameraReset, GamepadButton::RightThumb),
+        ] {
+            self.bind_gamepad(id, button);
+        }
     }
 }
 
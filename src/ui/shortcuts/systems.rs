@@ -3,10 +3,12 @@
 use bevy::prelude::*;
 use super::registry::*;
 use super::types::*;
+use crate::input::InputActions;
 
-/// Process keyboard input and trigger shortcuts
+/// Process keyboard and gamepad input and trigger shortcuts
 pub fn process_shortcuts(
     keyboard: Res<ButtonInput<KeyCode>>,
+    actions: Res<InputActions>,
     registry: Res<ShortcutRegistry>,
     config: Res<ShortcutConfig>,
     mut messages: MessageWriter<ShortcutEvent>,
@@ -27,6 +29,19 @@ pub fn process_shortcuts(
             debug!("Shortcut triggered: {:?} ({})", shortcut_id, definition.binding.display_string());
         }
     }
+
+    // Gamepad buttons trigger the same shortcuts as their keyboard bindings
+    for shortcut_id in registry.check_gamepad_shortcuts(&actions.buttons_pressed) {
+        if let Some(definition) = registry.get(&shortcut_id) {
+            messages.write(ShortcutEvent {
+                shortcut_id: shortcut_id.clone(),
+                binding: definition.binding,
+                context: definition.context,
+            });
+
+            debug!("Shortcut triggered by gamepad: {:?}", shortcut_id);
+        }
+    }
 }
 
 /// Update shortcut context based on game state
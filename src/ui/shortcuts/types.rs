@@ -21,6 +21,7 @@ pub enum ShortcutId {
     CameraZoomIn,
     CameraZoomOut,
     CameraReset,
+    ToggleEdgeScrolling,
 
     // Time controls
     Pause,
@@ -50,6 +51,7 @@ pub enum ShortcutId {
     MapModeCultural,
     MapModeReligious,
     MapModeToggle,  // Quick toggle between Political and Terrain
+    MapModeCycle,   // Step through all map modes in order
 
     // Menus
     OpenMainMenu,
@@ -78,6 +80,24 @@ pub enum ShortcutId {
     TakeScreenshot,
     RecordVideo,
 
+    // Accessibility
+    ToggleNarration,
+
+    // History
+    ToggleHistory,
+
+    // Worlds
+    ToggleWorlds,
+
+    // Diplomacy
+    ToggleRelations,
+
+    // Economy
+    ToggleEconomyFlow,
+
+    // Military
+    ToggleArmyInspector,
+
     // Developer
     OpenConsole,
     ReloadUI,
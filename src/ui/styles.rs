@@ -163,6 +163,97 @@ pub mod animations {
     pub const DIALOG_DISMISS: Duration = Duration::from_millis(100);
 }
 
+/// Color adjustments for the high-contrast accessibility theme
+pub mod contrast {
+    use bevy::prelude::*;
+
+    /// Borders drawn in high-contrast mode
+    pub const BORDER: Color = Color::srgb(0.95, 0.95, 0.95);
+
+    /// Minimum lightness for colored text in high-contrast mode
+    const MIN_TEXT_LIGHTNESS: f32 = 0.8;
+
+    /// High-contrast version of a text color
+    ///
+    /// Gray text becomes white and colored text keeps its hue but is
+    /// lightened. Applying it twice gives the same color.
+    pub fn text(color: Color) -> Color {
+        let hsla = Hsla::from(color);
+        if hsla.saturation < 0.15 {
+            return Color::srgba(1.0, 1.0, 1.0, hsla.alpha);
+        }
+        Color::from(hsla.with_lightness(hsla.lightness.max(MIN_TEXT_LIGHTNESS)))
+    }
+}
+
+/// Accent theming that follows the watched nation and the world's era
+pub mod theme {
+    use bevy::prelude::*;
+
+    use crate::nations::DevelopmentLevel;
+
+    /// How far panel borders lean toward the accent
+    const BORDER_TINT: f32 = 0.45;
+    /// How far toolbar backgrounds lean toward the accent; kept low so text stays readable
+    const BACKGROUND_TINT: f32 = 0.12;
+    /// Share of the accent taken from the watched nation's color rather than the era
+    const NATION_SHARE: f32 = 0.5;
+
+    /// Accent color of each era's UI
+    pub fn era_accent(era: DevelopmentLevel) -> Color {
+        match era {
+            DevelopmentLevel::Primitive => Color::srgb(0.62, 0.45, 0.25),
+            DevelopmentLevel::Medieval => Color::srgb(0.58, 0.26, 0.22),
+            DevelopmentLevel::Renaissance => Color::srgb(0.70, 0.58, 0.28),
+            DevelopmentLevel::Modern => Color::srgb(0.35, 0.50, 0.65),
+        }
+    }
+
+    /// `base` moved toward `tint` by `amount`, keeping the base's alpha
+    pub fn blend(base: Color, tint: Color, amount: f32) -> Color {
+        let base = base.to_srgba();
+        let tint = tint.to_srgba();
+        Color::srgba(
+            base.red + (tint.red - base.red) * amount,
+            base.green + (tint.green - base.green) * amount,
+            base.blue + (tint.blue - base.blue) * amount,
+            base.alpha,
+        )
+    }
+
+    /// The accent the UI currently leans toward, if theming is on
+    #[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+    pub struct UiTheme {
+        pub accent: Option<Color>,
+    }
+
+    impl UiTheme {
+        /// Theme for the world's dominant era, shaded by the watched nation's color
+        pub fn new(era: Option<DevelopmentLevel>, nation: Option<Color>) -> Self {
+            let accent = match (era.map(era_accent), nation) {
+                (Some(era), Some(nation)) => Some(blend(era, nation, NATION_SHARE)),
+                (era, nation) => era.or(nation),
+            };
+            Self { accent }
+        }
+
+        pub fn border(&self, base: Color) -> Color {
+            self.accent.map_or(base, |accent| blend(base, accent, BORDER_TINT))
+        }
+
+        pub fn background(&self, base: Color) -> Color {
+            self.accent.map_or(base, |accent| blend(base, accent, BACKGROUND_TINT))
+        }
+    }
+
+    /// A toolbar or panel color that follows the theme, with its unthemed color
+    #[derive(Component, Debug, Clone, Copy)]
+    pub enum ThemedAccent {
+        Border(Color),
+        Background(Color),
+    }
+}
+
 /// Helper functions for creating styled UI elements
 pub mod helpers {
     use bevy::prelude::*;
@@ -0,0 +1,13 @@
+//! UI Theming - Gateway module
+//!
+//! Works out the UI theme from the world's dominant era and the selected
+//! nation's color, and tints every toolbar and panel marked with
+//! `ThemedAccent`. The interface setting "Era and Nation Theming" turns it
+//! off, restoring the standard palette.
+
+// PRIVATE modules
+mod plugin;
+mod systems;
+
+// PUBLIC exports
+pub use plugin::ThemingPlugin;
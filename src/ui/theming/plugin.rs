@@ -0,0 +1,12 @@
+//! Theming plugin
+
+use bevy_plugin_builder::define_plugin;
+
+use super::systems::*;
+use crate::ui::styles::theme::UiTheme;
+
+define_plugin!(ThemingPlugin {
+    resources: [UiTheme],
+
+    update: [(update_ui_theme, apply_theme_accents).chain()]
+});
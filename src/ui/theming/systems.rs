@@ -0,0 +1,107 @@
+//! Theming systems
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::nations::{DevelopmentLevel, Nation, PresentationEra};
+use crate::settings::GameSettings;
+use crate::ui::styles::theme::{ThemedAccent, UiTheme};
+use crate::ui::SelectedNation;
+
+/// The era most nations are presented in, the later era on a tie
+pub fn dominant_era(eras: impl IntoIterator<Item = DevelopmentLevel>) -> Option<DevelopmentLevel> {
+    let mut counts: HashMap<DevelopmentLevel, usize> = HashMap::new();
+    for era in eras {
+        *counts.entry(era).or_default() += 1;
+    }
+    counts
+        .into_iter()
+        .max_by_key(|&(era, count)| (count, era))
+        .map(|(era, _)| era)
+}
+
+/// Work the theme out again when the setting, the selection, or any nation's era changes
+pub fn update_ui_theme(
+    settings: Res<GameSettings>,
+    selected: Res<SelectedNation>,
+    eras: Query<&PresentationEra>,
+    changed_eras: Query<(), Changed<PresentationEra>>,
+    nations: Query<&Nation>,
+    mut theme: ResMut<UiTheme>,
+) {
+    if !settings.is_changed() && !selected.is_changed() && changed_eras.is_empty() {
+        return;
+    }
+    let next = if settings.interface.themed_ui {
+        let era = dominant_era(eras.iter().map(|era| era.0));
+        let nation = selected
+            .entity
+            .and_then(|entity| nations.get(entity).ok())
+            .map(|nation| nation.color);
+        UiTheme::new(era, nation)
+    } else {
+        UiTheme::default()
+    };
+    if *theme != next {
+        *theme = next;
+    }
+}
+
+/// Tint themed toolbars and panels, all of them when the theme changes and new ones as they appear
+pub fn apply_theme_accents(
+    theme: Res<UiTheme>,
+    mut accents: Query<(
+        Ref<ThemedAccent>,
+        Option<&mut BorderColor>,
+        Option<&mut BackgroundColor>,
+    )>,
+) {
+    for (accent, border, background) in &mut accents {
+        if !theme.is_changed() && !accent.is_added() {
+            continue;
+        }
+        match *accent {
+            ThemedAccent::Border(base) => {
+                if let Some(mut border) = border {
+                    let themed = BorderColor::all(theme.border(base));
+                    if *border != themed {
+                        *border = themed;
+                    }
+                }
+            }
+            ThemedAccent::Background(base) => {
+                if let Some(mut background) = background {
+                    let themed = theme.background(base);
+                    if background.0 != themed {
+                        background.0 = themed;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::colors;
+
+    #[test]
+    fn theme_follows_the_most_common_era_and_stays_subtle() {
+        use DevelopmentLevel::*;
+        assert_eq!(dominant_era([Medieval, Primitive, Medieval, Modern]), Some(Medieval));
+        assert_eq!(dominant_era([Medieval, Renaissance]), Some(Renaissance));
+        assert_eq!(dominant_era(std::iter::empty()), None);
+
+        // Without theming the standard palette is untouched
+        let unthemed = UiTheme::default();
+        assert_eq!(unthemed.border(colors::BORDER_DEFAULT), colors::BORDER_DEFAULT);
+
+        let themed = UiTheme::new(Some(Modern), Some(Color::srgb(1.0, 0.0, 0.0)));
+        let background = themed.background(colors::BACKGROUND_MEDIUM).to_srgba();
+        let base = colors::BACKGROUND_MEDIUM.to_srgba();
+        assert!(background.red > base.red);
+        assert!((background.red - base.red).abs() < 0.1);
+        assert_ne!(themed, UiTheme::new(Some(Modern), None));
+    }
+}
@@ -2,7 +2,7 @@
 
 use crate::ui::{ChildBuilder, LabelBuilder, PanelBuilder, PanelStyle};
 use crate::resources::SelectedProvinceInfo;
-use crate::world::{ProvinceId, ProvinceStorage};
+use crate::world::{ProvinceId, ProvinceStorage, RegionHierarchy, RegionalWeather};
 use bevy::log::{debug, error};
 use bevy::prelude::*;
 
@@ -36,6 +36,12 @@ pub fn spawn_tile_info_panel(parent: &mut ChildBuilder) {
 pub fn update_tile_info_ui(
     selected_info: Res<SelectedProvinceInfo>,
     province_storage: Res<ProvinceStorage>,
+    city_names: Res<crate::nations::CityNames>,
+    wonders: Res<crate::world::NaturalWonders>,
+    disputes: Res<crate::nations::BorderDisputes>,
+    regions: Res<RegionHierarchy>,
+    regional_weather: Res<RegionalWeather>,
+    nations: Query<&crate::nations::Nation>,
     mut text_query: Query<&mut Text, With<TileInfoText>>,
 ) {
     if let Ok(mut text) = text_query.single_mut() {
@@ -47,15 +53,68 @@ pub fn update_tile_info_ui(
             {
                 // Bounds check to prevent panic on invalid index
                 if let Some(province) = province_storage.provinces.get(idx) {
+                    let city_line = city_names.get(idx).map_or(String::new(), |city| {
+                        let mut line = format!("City: {}\n", city.name);
+                        if !city.aliases.is_empty() {
+                            let former: Vec<&str> =
+                                city.aliases.iter().rev().map(|alias| alias.name.as_str()).collect();
+                            line.push_str(&format!("Formerly: {}\n", former.join(", ")));
+                        }
+                        line
+                    });
+                    // Area, then region and continent, and the region's skies
+                    let location_line = regions.describe(idx).map_or(String::new(), |place| {
+                        let area = regions.area_of(idx).and_then(|area| regions.areas().get(area));
+                        let mut line = area.map_or(String::new(), |area| format!("Area: {}\n", area.name));
+                        line.push_str(&format!("Region: {}\n", place));
+                        let weather = regions.region_of(idx).and_then(|region| regional_weather.state(region));
+                        if let Some(weather) = weather {
+                            line.push_str(&format!("Weather: {}\n", weather.description()));
+                        }
+                        line
+                    });
+                    let wonder_line = wonders.in_province(idx).map_or(String::new(), |wonder| {
+                        format!(
+                            "Wonder: {} ({})\n  +{:.0} treasury, +{:.1}% stability per year\n",
+                            wonder.name,
+                            wonder.kind.label(),
+                            wonder.kind.yearly_income().to_f32(),
+                            wonder.kind.yearly_stability() * 100.0
+                        )
+                    });
+                    // Claims by nations other than the holder, strongest first
+                    let claims_line = disputes.get(idx).filter(|dispute| !dispute.claims.is_empty()).map_or(
+                        String::new(),
+                        |dispute| {
+                            let status = if dispute.active { "Actively disputed" } else { "Contested" };
+                            let mut line = format!("{}:\n", status);
+                            for claim in &dispute.claims {
+                                let name = nations
+                                    .get(claim.claimant)
+                                    .map_or("A fallen nation", |nation| nation.name.as_str());
+                                line.push_str(&format!(
+                                    "  {} - {:.0}% ({})\n",
+                                    name,
+                                    claim.strength * 100.0,
+                                    claim.basis.label()
+                                ));
+                            }
+                            line
+                        },
+                    );
                     *text = Text::new(format!(
                         "Province #{}
-Terrain: {:?}
+{}{}{}{}Terrain: {:?}
 Elevation: {:.2}
 Population: {:.0}
 Agriculture: {:.1}
 Water Distance: {:.1} hex
 Position: ({:.0}, {:.0})",
                         province.id,
+                        city_line,
+                        location_line,
+                        wonder_line,
+                        claims_line,
                         province.terrain,
                         province.elevation,
                         province.population,
@@ -2,6 +2,7 @@
 
 use super::panel;
 use crate::states::GameState;
+use crate::ui::ScreenAnchor;
 use bevy::prelude::*;
 
 /// Setup the tile info panel
@@ -18,6 +19,7 @@ pub fn setup_tile_info(mut commands: Commands) {
                 min_width: Val::Px(250.0),
                 ..default()
             },
+            ScreenAnchor::bottom_right(10.0, 10.0),
             super::TileInfoPanel,
             ZIndex(100),
         ))
@@ -20,7 +20,7 @@ mod weather;
 pub use types::{CloudData, CloudEntity, CloudLayer, CloudSystem};
 
 // Weather system
-pub use weather::{WeatherState, WeatherSystem};
+pub use weather::{RegionalWeather, WeatherState, WeatherSystem};
 
 // Generation
 pub use generation::CloudBuilder;
@@ -28,5 +28,5 @@ pub use generation::CloudBuilder;
 // Rendering components and systems
 pub use rendering::{
     animate_clouds, create_cloud_texture, dynamic_cloud_spawn_system, generate_cloud_formation,
-    update_weather_system, CloudFormationType, CloudPlugin, CloudSprite, CloudTextureParams,
+    update_regional_weather, update_weather_system, CloudFormationType, CloudPlugin, CloudSprite, CloudTextureParams,
 };
@@ -4,9 +4,12 @@
 //! module with layered sprites for realistic atmospheric effects.
 
 use super::types::{CloudLayer, CloudSystem};
+use super::weather::RegionalWeather;
 use crate::constants::*;
 use crate::math::{fast_sin, smoothstep, PerlinNoise};
 use crate::resources::{WeatherState, WeatherSystem};
+use crate::settings::{ActiveDeviceProfile, GameSettings};
+use crate::world::{ProvincesSpatialIndex, RegionHierarchy};
 use bevy::prelude::*;
 use rand::prelude::*;
 use rand::rngs::StdRng;
@@ -223,12 +226,17 @@ pub fn create_cloud_texture(params: CloudTextureParams) -> Image {
     )
 }
 
-/// Animate clouds with wind movement
+/// Animate clouds with wind movement, holding them still under reduced motion
 pub fn animate_clouds(
     mut clouds: Query<(&CloudSprite, &mut Transform)>,
     weather: Res<WeatherSystem>,
     time: Res<Time>,
+    settings: Res<crate::settings::GameSettings>,
 ) {
+    if settings.interface.reduced_motion {
+        return;
+    }
+
     // Use global constants for map dimensions
     const MAP_WIDTH: f32 = MAP_WIDTH_PIXELS;
     const MAP_HEIGHT: f32 = MAP_HEIGHT_PIXELS;
@@ -267,12 +275,45 @@ pub fn animate_clouds(
     }
 }
 
+/// Chance per second that a region's weather takes a new turn
+const REGIONAL_CHANGE_CHANCE: f32 = 0.02;
+
+/// Let each region's weather wander a step either side of the world's
+pub fn update_regional_weather(
+    weather: Res<WeatherSystem>,
+    hierarchy: Option<Res<RegionHierarchy>>,
+    mut regional: ResMut<RegionalWeather>,
+    time: Res<Time>,
+    mut rng: Local<Option<StdRng>>,
+) {
+    let Some(hierarchy) = hierarchy else {
+        return;
+    };
+    let rng = rng.get_or_insert_with(StdRng::from_entropy);
+
+    // A new world starts out under the same skies everywhere
+    let regions = hierarchy.regions().len();
+    if regional.states.len() != regions {
+        regional.states = vec![weather.current_state; regions];
+    }
+
+    let chance = REGIONAL_CHANGE_CHANCE * time.delta_secs();
+    for state in &mut regional.states {
+        if rng.r#gen::<f32>() < chance {
+            *state = weather.current_state.shifted(rng.gen_range(-1..=1));
+        }
+    }
+}
+
 /// System to update weather and manage cloud visibility
 pub fn update_weather_system(
     mut weather: ResMut<WeatherSystem>,
     time: Res<Time>,
     mut clouds: Query<(&CloudSprite, &mut Sprite, &mut Transform)>,
     mut rng: Local<Option<StdRng>>,
+    regional: Res<RegionalWeather>,
+    hierarchy: Option<Res<RegionHierarchy>>,
+    spatial: Option<Res<ProvincesSpatialIndex>>,
 ) {
     // Initialize RNG on first run
     if rng.is_none() {
@@ -329,8 +370,15 @@ pub fn update_weather_system(
     }
 
     for (cloud_sprite, mut sprite, mut transform) in &mut clouds {
-        // Fade clouds in/out based on weather
-        let target_alpha = cloud_sprite.base_alpha * weather.cloud_coverage;
+        // Fade clouds in/out based on the weather of the region below, or the world's out at sea
+        let coverage = match (hierarchy.as_deref(), spatial.as_deref()) {
+            (Some(hierarchy), Some(spatial)) => hierarchy
+                .region_at(spatial, transform.translation.truncate())
+                .and_then(|region| regional.state(region))
+                .map_or(weather.cloud_coverage, |state| state.coverage()),
+            _ => weather.cloud_coverage,
+        };
+        let target_alpha = cloud_sprite.base_alpha * coverage;
         let current_alpha = sprite.color.alpha();
         let new_alpha = current_alpha + (target_alpha - current_alpha) * time.delta_secs();
         sprite.color.set_alpha(new_alpha);
@@ -360,6 +408,7 @@ pub fn dynamic_cloud_spawn_system(
     camera: Query<(&Camera, &GlobalTransform)>,
     mut images: ResMut<Assets<Image>>,
     mut last_coverage: Local<f32>,
+    device: Res<ActiveDeviceProfile>,
 ) {
     let coverage_change = (weather.cloud_coverage - *last_coverage).abs();
     if coverage_change < 0.1 {
@@ -367,7 +416,7 @@ pub fn dynamic_cloud_spawn_system(
     }
     *last_coverage = weather.cloud_coverage;
 
-    let target_count = (36.0 * weather.cloud_coverage) as usize;
+    let target_count = (36.0 * weather.cloud_coverage * device.0.cloud_density) as usize;
     let current_count = clouds.iter().count();
 
     if current_count < target_count {
@@ -432,13 +481,14 @@ use bevy_plugin_builder::define_plugin;
 
 /// Bevy plugin for the cloud system
 define_plugin!(CloudPlugin {
-    resources: [WeatherSystem, CloudSystem],
+    resources: [WeatherSystem, RegionalWeather, CloudSystem],
 
     update: [
         (
+            update_regional_weather,
             update_weather_system,
-            animate_clouds,
-            dynamic_cloud_spawn_system,
+            (animate_clouds, dynamic_cloud_spawn_system).run_if(clouds_enabled),
+            show_clouds,
         ).chain().run_if(in_state(crate::states::GameState::InGame))
     ],
 
@@ -447,12 +497,40 @@ define_plugin!(CloudPlugin {
     }
 });
 
+/// Run condition: the cloud layer is switched on in the graphics settings
+fn clouds_enabled(settings: Res<GameSettings>) -> bool {
+    settings.graphics.clouds
+}
+
+/// Hide or show every cloud when the cloud layer is switched off or on
+fn show_clouds(
+    settings: Res<GameSettings>,
+    mut clouds: Query<(&mut Visibility, Ref<super::types::CloudEntity>)>,
+) {
+    let visibility = if settings.graphics.clouds {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for (mut cloud_visibility, marker) in &mut clouds {
+        if (settings.is_changed() || marker.is_added()) && *cloud_visibility != visibility {
+            *cloud_visibility = visibility;
+        }
+    }
+}
+
+/// Whether the cloud at `index` is among an evenly spread `density` share of all clouds
+fn keeps_cloud(index: usize, density: f32) -> bool {
+    (index as f32 * density).floor() != ((index + 1) as f32 * density).floor()
+}
+
 /// Spawn cloud entities from generated cloud data
 fn spawn_clouds_from_data(
     mut commands: Commands,
     mut cloud_system: ResMut<CloudSystem>,
     mut images: ResMut<Assets<Image>>,
     mut clouds_spawned: Local<bool>,
+    device: Res<ActiveDeviceProfile>,
 ) {
     // Only spawn if we haven't spawned yet
     if !*clouds_spawned {
@@ -525,7 +603,15 @@ fn spawn_clouds_from_data(
             texture_pools.push(layer_textures);
         }
 
-        for cloud_data in &cloud_system.clouds {
+        // Thin the sky evenly across layers on low-power devices
+        let density = device.0.cloud_density.clamp(0.0, 1.0);
+        let kept = cloud_system
+            .clouds
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| keeps_cloud(*index, density))
+            .map(|(_, cloud)| cloud);
+        for cloud_data in kept {
             let layer_idx = cloud_data.layer as usize;
             let texture_handle = texture_pools[layer_idx]
                 [cloud_data.texture_index % textures_per_layer as usize]
@@ -18,6 +18,28 @@ pub enum WeatherState {
 }
 
 impl WeatherState {
+    /// From clearest to stormiest
+    const SCALE: [WeatherState; 6] = [
+        WeatherState::Clear,
+        WeatherState::Fair,
+        WeatherState::Partly,
+        WeatherState::Cloudy,
+        WeatherState::Overcast,
+        WeatherState::Storm,
+    ];
+
+    /// The state `steps` clearer (negative) or stormier (positive) than this one
+    pub fn shifted(self, steps: i32) -> WeatherState {
+        let index = Self::SCALE.iter().position(|state| *state == self).unwrap_or(0) as i32;
+        Self::SCALE[(index + steps).clamp(0, Self::SCALE.len() as i32 - 1) as usize]
+    }
+
+    /// Typical cloud coverage, the middle of the range
+    pub fn coverage(&self) -> f32 {
+        let (min, max) = self.coverage_range();
+        (min + max) / 2.0
+    }
+
     pub fn coverage_range(&self) -> (f32, f32) {
         match self {
             WeatherState::Clear => (0.0, 0.1),
@@ -77,3 +99,20 @@ impl Default for WeatherSystem {
         }
     }
 }
+
+/// Weather of each region, wandering either side of the world-wide weather
+///
+/// The `WeatherSystem` sets the tone for the whole world; each region of the
+/// region hierarchy drifts a step clearer or stormier than it on its own, so
+/// a storm can sit over one region while the next lies under fair skies.
+#[derive(Resource, Debug, Default)]
+pub struct RegionalWeather {
+    /// Weather of each region, by region index
+    pub(super) states: Vec<WeatherState>,
+}
+
+impl RegionalWeather {
+    pub fn state(&self, region: usize) -> Option<WeatherState> {
+        self.states.get(region).copied()
+    }
+}
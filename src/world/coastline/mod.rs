@@ -0,0 +1,16 @@
+//! Coastline feature module gateway
+//!
+//! Over a long run the sea can drift up or down, or rise suddenly in a great
+//! flood. As it moves, low coasts drown - their people fleeing and their
+//! owners losing them - or the shallows rise as new beaches. Land whose
+//! shore moved is reclassified, so its agriculture, capacity, and harbors
+//! follow, and the map is redrawn.
+
+// PRIVATE MODULES
+mod plugin;
+mod systems;
+mod types;
+
+// PUBLIC EXPORTS
+pub use plugin::CoastlinePlugin;
+pub use types::{CoastlineChanged, SeaLevel, SeaLevelDrift, SeaLevelShift};
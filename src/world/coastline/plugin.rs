@@ -0,0 +1,23 @@
+//! Coastline plugin
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+use super::types::{CoastlineChanged, SeaLevel, SeaLevelShift};
+use crate::states::GameState;
+
+define_plugin!(CoastlinePlugin {
+    resources: [SeaLevel],
+
+    messages: [SeaLevelShift, CoastlineChanged],
+
+    update: [
+        (super::systems::shift_sea_level, super::systems::redraw_coastline)
+            .chain()
+            .run_if(in_state(GameState::InGame))
+    ],
+
+    on_enter: {
+        GameState::LoadingWorld => [super::systems::configure_sea_level]
+    }
+});
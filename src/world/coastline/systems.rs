@@ -0,0 +1,436 @@
+//! Moving the sea and redrawing the coastline it leaves behind
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use super::types::{CoastlineChanged, SeaLevel, SeaLevelDrift, SeaLevelShift, COASTLINE_STEP, GREAT_FLOOD_RISE};
+use crate::chronicle::{ChronicleCategory, ChronicleEvent};
+use crate::nations::{CityNames, NationIndex, OwnershipService, PopulationDisplaced};
+use crate::simulation::NewYearEvent;
+use crate::world::generation::population_capacity;
+use crate::world::{
+    Agriculture, CachedOverlayColors, CoastalProvinceCache, Elevation, InfrastructureStorage, MapMode, Province,
+    ProvinceData, ProvinceEntityOrder, ProvinceId, ProvinceStorage, TerrainType, WorldGenerationSettings, WorldSeed,
+};
+
+/// Elevation of land the sea takes, matching the shallows generation leaves beside coasts
+const SHALLOW_SEA_ELEVATION: f32 = 0.12;
+/// Height above the sea within which newly coastal land becomes beach, as at generation
+const BEACH_WIDTH: f32 = 0.01;
+/// Share of its trade a province keeps once its harbor is left dry
+const LANDLOCKED_TRADE_KEPT: f32 = 0.5;
+/// Places named in one chronicle entry before the rest are counted
+const NAMED_PLACES: usize = 3;
+
+/// A freshly generated world takes the sea level drift chosen for it
+///
+/// Loading a save re-inserts the saved sea level after this runs.
+pub fn configure_sea_level(mut sea_level: ResMut<SeaLevel>, settings: Option<Res<WorldGenerationSettings>>) {
+    *sea_level = SeaLevel::new(settings.map_or_else(default, |s| s.sea_level_drift));
+}
+
+/// Apply requested shifts, then the year's drift and any great flood
+pub fn shift_sea_level(
+    mut year_events: MessageReader<NewYearEvent>,
+    mut shifts: MessageReader<SeaLevelShift>,
+    mut sea_level: ResMut<SeaLevel>,
+    world_seed: Option<Res<WorldSeed>>,
+    mut chronicle: MessageWriter<ChronicleEvent>,
+) {
+    for shift in shifts.read() {
+        sea_level.offset += shift.amount;
+        if let Some(cause) = &shift.cause {
+            chronicle.write(ChronicleEvent {
+                category: ChronicleCategory::Catastrophe,
+                text: cause.clone(),
+                nations: Vec::new(),
+            });
+        }
+    }
+
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+    let drift = sea_level.drift;
+    if drift == SeaLevelDrift::Stable {
+        return;
+    }
+    sea_level.offset += drift.yearly_drift();
+
+    // Seeded by world and year so identical runs see identical floods
+    let seed = world_seed.map_or(0, |seed| seed.0) as u64;
+    let mut rng = StdRng::seed_from_u64((seed << 32) ^ year as u64 ^ 0x9e37_79b9_7f4a_7c15);
+    if rng.r#gen::<f32>() < drift.flood_chance() {
+        sea_level.offset += GREAT_FLOOD_RISE;
+        sea_level.last_flood = Some(year);
+        chronicle.write(ChronicleEvent {
+            category: ChronicleCategory::Catastrophe,
+            text: "A great flood: the seas rose across the world, and the low coasts will not be seen again"
+                .to_string(),
+            nations: Vec::new(),
+        });
+    }
+}
+
+/// Whether a province is land beside the sea
+fn is_shore(storage: &ProvinceStorage, index: usize) -> bool {
+    storage.provinces.get(index).is_some_and(|province| {
+        province.terrain != TerrainType::Ocean
+            && province.neighbors.iter().flatten().any(|neighbor| {
+                storage
+                    .provinces
+                    .get(neighbor.value() as usize)
+                    .is_some_and(|neighbor| neighbor.terrain == TerrainType::Ocean)
+            })
+    })
+}
+
+/// Give a province new terrain, carrying its fertility bonuses over and
+/// recomputing how many people it can hold
+fn retype(province: &mut Province, terrain: TerrainType) {
+    let old_base = province.terrain.properties().agriculture_base;
+    let new_base = terrain.properties().agriculture_base;
+    let agriculture = if old_base > 0.0 {
+        province.agriculture.value() * new_base / old_base
+    } else {
+        new_base
+    };
+    province.terrain = terrain;
+    province.agriculture = Agriculture::new(agriculture);
+    province.max_population = population_capacity(province);
+    province.population = province.population.min(province.max_population);
+    province.mark_dirty();
+}
+
+/// Move the coastline one ring toward the sea at `level`: flooding low shores
+/// when `step` is positive, raising the shallows when it is negative
+///
+/// Returns what changed and the people who fled the flooded land.
+fn reshape_coastline(
+    storage: &mut ProvinceStorage,
+    level: f32,
+    step: i8,
+) -> (CoastlineChanged, Vec<PopulationDisplaced>) {
+    let count = storage.provinces.len();
+    let was_shore: Vec<bool> = (0..count).map(|index| is_shore(storage, index)).collect();
+    let mut change = CoastlineChanged::default();
+    let mut fled = Vec::new();
+
+    if step > 0 {
+        change.flooded = (0..count)
+            .filter(|&index| {
+                was_shore[index]
+                    && storage
+                        .provinces
+                        .get(index)
+                        .is_some_and(|province| province.elevation.value() < level)
+            })
+            .collect();
+        for &index in &change.flooded {
+            let Some(province) = storage.provinces.get_mut(index) else {
+                continue;
+            };
+            if province.population > 0 {
+                fled.push(PopulationDisplaced {
+                    province: index,
+                    people: province.population,
+                });
+                province.set_population(0);
+            }
+            retype(province, TerrainType::Ocean);
+            province.elevation = Elevation::new(SHALLOW_SEA_ELEVATION);
+        }
+    } else if step < 0 {
+        change.emerged = (0..count)
+            .filter(|&index| {
+                storage.provinces.get(index).is_some_and(|province| {
+                    province.terrain == TerrainType::Ocean
+                        && province.elevation.value() >= SHALLOW_SEA_ELEVATION
+                        && province.neighbors.iter().flatten().any(|neighbor| {
+                            storage
+                                .provinces
+                                .get(neighbor.value() as usize)
+                                .is_some_and(|neighbor| neighbor.terrain != TerrainType::Ocean)
+                        })
+                })
+            })
+            .collect();
+        for &index in &change.emerged {
+            if let Some(province) = storage.provinces.get_mut(index) {
+                retype(province, TerrainType::Beach);
+                province.elevation = Elevation::new(level);
+            }
+        }
+    }
+
+    // Beaches left inland become farmland; low land newly on the shore becomes beach
+    let now_shore: Vec<bool> = (0..count).map(|index| is_shore(storage, index)).collect();
+    for index in 0..count {
+        let Some(province) = storage.provinces.get_mut(index) else {
+            continue;
+        };
+        if province.terrain == TerrainType::Ocean {
+            continue;
+        }
+        let terrain = if province.terrain == TerrainType::Beach && !now_shore[index] {
+            Some(TerrainType::TemperateGrassland)
+        } else if now_shore[index]
+            && !was_shore[index]
+            && !matches!(province.terrain, TerrainType::Beach | TerrainType::River)
+            && province.elevation.value() < level + BEACH_WIDTH
+        {
+            Some(TerrainType::Beach)
+        } else {
+            None
+        };
+        let emerged = change.emerged.binary_search(&index).is_ok();
+        if let Some(terrain) = terrain {
+            retype(province, terrain);
+            if !emerged {
+                change.reclassified.push(index);
+            }
+        }
+        if was_shore[index] && !now_shore[index] && !emerged {
+            change.ports_lost.push(index);
+        }
+    }
+
+    (change, fled)
+}
+
+/// "A", "A and B", "A, B, and 3 more provinces"; unnamed places are only counted
+fn describe_places(indices: &[usize], city_names: Option<&CityNames>) -> String {
+    let mut places: Vec<String> = indices
+        .iter()
+        .filter_map(|&index| city_names.and_then(|names| names.get(index)))
+        .take(NAMED_PLACES)
+        .map(|city| city.name.clone())
+        .collect();
+    let unnamed = indices.len() - places.len();
+    let provinces = |count: usize| format!("{} province{}", count, if count == 1 { "" } else { "s" });
+    if places.is_empty() {
+        return provinces(unnamed);
+    }
+    if unnamed > 0 {
+        places.push(format!("{} more", provinces(unnamed)));
+    }
+    match places.as_slice() {
+        [only] => only.clone(),
+        [first, second] => format!("{} and {}", first, second),
+        [rest @ .., last] => format!("{}, and {}", rest.join(", "), last),
+        [] => String::new(),
+    }
+}
+
+/// Redraw the coastline one ring at a time as the sea level moves away from it
+///
+/// Flooded provinces lose their people, owner, and infrastructure; land
+/// whose shore moved is reclassified, so its agriculture and capacity follow.
+/// Every copy of the province data is brought up to date, the coastal cache
+/// rebuilt, and the map redrawn.
+pub fn redraw_coastline(
+    mut sea_level: ResMut<SeaLevel>,
+    province_storage: Option<ResMut<ProvinceStorage>>,
+    mut coastal_cache: ResMut<CoastalProvinceCache>,
+    infrastructure: Option<ResMut<InfrastructureStorage>>,
+    entity_order: Option<Res<ProvinceEntityOrder>>,
+    mut province_data: Query<&mut ProvinceData>,
+    mut ownership: OwnershipService,
+    (city_names, nation_index, mut overlay_colors, mut map_mode): (
+        Option<Res<CityNames>>,
+        Res<NationIndex>,
+        ResMut<CachedOverlayColors>,
+        ResMut<MapMode>,
+    ),
+    mut chronicle: MessageWriter<ChronicleEvent>,
+    mut displaced: MessageWriter<PopulationDisplaced>,
+    mut changed: MessageWriter<CoastlineChanged>,
+) {
+    let step = sea_level.pending_step();
+    if step == 0 {
+        return;
+    }
+    let Some(mut storage) = province_storage else {
+        return;
+    };
+
+    // The generated shoreline lies at the lowest land still touching the sea
+    let reference = match sea_level.reference {
+        Some(reference) => reference,
+        None => {
+            let lowest = (0..storage.provinces.len())
+                .filter(|&index| is_shore(&storage, index))
+                .filter_map(|index| storage.provinces.get(index))
+                .map(|province| province.elevation.value())
+                .fold(f32::INFINITY, f32::min);
+            if !lowest.is_finite() {
+                return;
+            }
+            sea_level.reference = Some(lowest);
+            lowest
+        }
+    };
+    sea_level.shaped_offset += step as f32 * COASTLINE_STEP;
+    let level = reference + sea_level.shaped_offset;
+
+    let (change, fled) = reshape_coastline(&mut storage, level, step);
+    if change.provinces().next().is_none() {
+        return;
+    }
+
+    let drowned_people: u64 = fled.iter().map(|flight| flight.people as u64).sum();
+    for flight in fled {
+        displaced.write(flight);
+    }
+
+    let province_entity = |index: usize| entity_order.as_ref().and_then(|order| order.get(index));
+    // Flooded provinces keep their owner until the release below is applied
+    ownership.release(
+        change
+            .flooded
+            .iter()
+            .filter_map(|&index| province_entity(index))
+            .collect(),
+    );
+
+    if let Some(mut infrastructure) = infrastructure {
+        for &index in &change.flooded {
+            infrastructure.infrastructure.remove(&ProvinceId::new(index as u32));
+        }
+        for &index in &change.ports_lost {
+            if let Some(infra) = infrastructure.infrastructure.get_mut(&ProvinceId::new(index as u32)) {
+                infra.trade_volume *= LANDLOCKED_TRADE_KEPT;
+            }
+        }
+        infrastructure.calculate_statistics();
+    }
+
+    for index in change.provinces() {
+        let (Some(entity), Some(province)) = (province_entity(index), storage.provinces.get(index)) else {
+            continue;
+        };
+        if let Ok(mut data) = province_data.get_mut(entity) {
+            *data = ProvinceData::from_province(province);
+        }
+    }
+    coastal_cache.build(&storage);
+    overlay_colors.clear_cache();
+    map_mode.set_changed();
+
+    let city_names = city_names.as_deref();
+    let mut text = if change.flooded.is_empty() {
+        format!(
+            "The sea withdrew, and {} rose from the shallows as new shore",
+            describe_places(&change.emerged, None)
+        )
+    } else {
+        format!(
+            "The sea rose over {}, driving {} people from their homes",
+            describe_places(&change.flooded, city_names),
+            drowned_people
+        )
+    };
+    if !change.ports_lost.is_empty() {
+        text.push_str(&format!(
+            "; the harbors of {} were left high and dry",
+            describe_places(&change.ports_lost, city_names)
+        ));
+    }
+    let mut nations: Vec<Entity> = change
+        .flooded
+        .iter()
+        .chain(&change.ports_lost)
+        .filter_map(|&index| storage.provinces.get(index).and_then(|province| province.owner_entity))
+        .collect();
+    nations.sort();
+    nations.dedup();
+    info!(
+        "Coastline redrawn at sea level {:.3}: {} flooded, {} emerged, {} reclassified",
+        level,
+        change.flooded.len(),
+        change.emerged.len(),
+        change.reclassified.len()
+    );
+    chronicle.write(ChronicleEvent {
+        category: ChronicleCategory::Catastrophe,
+        text,
+        nations: nations
+            .into_iter()
+            .filter_map(|nation| nation_index.id(nation))
+            .collect(),
+    });
+    changed.write(change);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::generate_test_world;
+
+    /// Sea, beach, low coast, and inland farmland in a row
+    fn shore_strip() -> ProvinceStorage {
+        let mut storage = generate_test_world(4);
+        let shape = [
+            (TerrainType::Ocean, SHALLOW_SEA_ELEVATION),
+            (TerrainType::Beach, 0.40),
+            (TerrainType::TemperateGrassland, 0.41),
+            (TerrainType::TemperateGrassland, 0.45),
+        ];
+        for (index, (terrain, elevation)) in shape.into_iter().enumerate() {
+            let province = &mut storage.provinces[index];
+            province.terrain = terrain;
+            province.elevation = Elevation::new(elevation);
+            province.agriculture = Agriculture::new(terrain.properties().agriculture_base);
+            province.neighbors = [None; 6];
+            if index > 0 {
+                province.neighbors[0] = Some(ProvinceId::new(index as u32 - 1));
+            }
+            if index < 3 {
+                province.neighbors[1] = Some(ProvinceId::new(index as u32 + 1));
+            }
+        }
+        storage.provinces[0].population = 0;
+        storage
+    }
+
+    #[test]
+    fn rising_sea_floods_the_shore_and_falling_sea_returns_it() {
+        let mut storage = shore_strip();
+
+        let (flood, fled) = reshape_coastline(&mut storage, 0.405, 1);
+        assert_eq!(flood.flooded, vec![1]);
+        assert_eq!(fled.len(), 1);
+        assert_eq!(fled[0].people, 1000);
+        assert_eq!(storage.provinces[1].terrain, TerrainType::Ocean);
+        assert_eq!(storage.provinces[1].population, 0);
+        assert_eq!(storage.provinces[1].max_population, 0);
+        // The low farmland is now the shore
+        assert_eq!(flood.reclassified, vec![2]);
+        assert_eq!(storage.provinces[2].terrain, TerrainType::Beach);
+        assert!(storage.provinces[2].agriculture.value() < 1.0);
+
+        let (retreat, fled) = reshape_coastline(&mut storage, 0.40, -1);
+        assert!(fled.is_empty());
+        assert_eq!(retreat.emerged, vec![1]);
+        assert_eq!(storage.provinces[1].terrain, TerrainType::Beach);
+        assert!(storage.provinces[1].max_population > 0);
+        // The beach behind it is inland again, farmland without a harbor
+        assert_eq!(retreat.reclassified, vec![2]);
+        assert_eq!(retreat.ports_lost, vec![2]);
+        assert_eq!(storage.provinces[2].terrain, TerrainType::TemperateGrassland);
+    }
+
+    #[test]
+    fn sea_level_steps_follow_the_offset() {
+        let mut sea_level = SeaLevel::new(SeaLevelDrift::Rising);
+        assert_eq!(sea_level.pending_step(), 0);
+        sea_level.offset = COASTLINE_STEP * 2.5;
+        assert_eq!(sea_level.pending_step(), 1);
+        sea_level.shaped_offset = COASTLINE_STEP * 2.0;
+        assert_eq!(sea_level.pending_step(), 0);
+        sea_level.offset = -COASTLINE_STEP;
+        assert_eq!(sea_level.pending_step(), -1);
+        assert_eq!(sea_level.level(), None);
+    }
+}
@@ -0,0 +1,116 @@
+//! Sea level settings, the sea's state in a running world, and coastline messages
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Elevation the sea gains or loses each year under steady drift, about 0.004 a century
+const DRIFT_PER_YEAR: f32 = 0.000_04;
+/// Yearly chance of a great flood when floods are enabled
+const GREAT_FLOOD_CHANCE: f32 = 0.002;
+/// Elevation a great flood raises the sea by
+pub const GREAT_FLOOD_RISE: f32 = 0.01;
+/// Change in sea level that moves the coastline by one ring of provinces
+pub const COASTLINE_STEP: f32 = 0.005;
+
+/// How the sea changes over a long run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Reflect)]
+pub enum SeaLevelDrift {
+    /// Coastlines stay where the world was generated
+    #[default]
+    Stable,
+    /// The sea creeps inland, drowning low coasts over centuries
+    Rising,
+    /// The sea withdraws, leaving new land along the shallows
+    Falling,
+    /// A steady sea broken by rare great floods
+    GreatFloods,
+}
+
+impl SeaLevelDrift {
+    /// Change in sea level each year
+    pub fn yearly_drift(self) -> f32 {
+        match self {
+            SeaLevelDrift::Rising => DRIFT_PER_YEAR,
+            SeaLevelDrift::Falling => -DRIFT_PER_YEAR,
+            SeaLevelDrift::Stable | SeaLevelDrift::GreatFloods => 0.0,
+        }
+    }
+
+    /// Yearly chance of a great flood
+    pub fn flood_chance(self) -> f32 {
+        match self {
+            SeaLevelDrift::GreatFloods => GREAT_FLOOD_CHANCE,
+            _ => 0.0,
+        }
+    }
+}
+
+/// The sea's level in a running world, saved with it
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SeaLevel {
+    pub drift: SeaLevelDrift,
+    /// Elevation of the shoreline the world was generated with, found from the lowest coast
+    pub reference: Option<f32>,
+    /// How far the sea has risen (positive) or fallen since the world was generated
+    pub offset: f32,
+    /// Offset the coastline was last redrawn for
+    pub shaped_offset: f32,
+    /// Year of the most recent great flood
+    pub last_flood: Option<u32>,
+}
+
+impl SeaLevel {
+    pub fn new(drift: SeaLevelDrift) -> Self {
+        Self { drift, ..default() }
+    }
+
+    /// Elevation below which coastal land is under the sea
+    pub fn level(&self) -> Option<f32> {
+        self.reference.map(|reference| reference + self.offset)
+    }
+
+    /// Direction the coastline still has to move: 1 to flood, -1 to emerge, 0 when it is settled
+    pub fn pending_step(&self) -> i8 {
+        let behind = self.offset - self.shaped_offset;
+        if behind >= COASTLINE_STEP {
+            1
+        } else if behind <= -COASTLINE_STEP {
+            -1
+        } else {
+            0
+        }
+    }
+}
+
+/// Request to raise (positive) or lower the sea, for floods set off by events or mods
+#[derive(Message, Debug, Clone)]
+pub struct SeaLevelShift {
+    pub amount: f32,
+    /// Chronicle text describing what moved the sea, if it should be recorded
+    pub cause: Option<String>,
+}
+
+/// Sent after the coastline is redrawn, with province storage indices
+#[derive(Message, Debug, Clone, Default)]
+pub struct CoastlineChanged {
+    /// Land now under the sea
+    pub flooded: Vec<usize>,
+    /// Sea floor now dry land
+    pub emerged: Vec<usize>,
+    /// Land whose terrain changed with the new shoreline
+    pub reclassified: Vec<usize>,
+    /// Coastal land left without a shore, losing its harbor
+    pub ports_lost: Vec<usize>,
+}
+
+impl CoastlineChanged {
+    /// Every province the change touched
+    pub fn provinces(&self) -> impl Iterator<Item = usize> + '_ {
+        self.flooded
+            .iter()
+            .chain(&self.emerged)
+            .chain(&self.reclassified)
+            .chain(&self.ports_lost)
+            .copied()
+    }
+}
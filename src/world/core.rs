@@ -126,9 +126,11 @@ pub struct MapBounds {
 impl MapDimensions {
     pub fn from_world_size(size: &WorldSize) -> Self {
         let (provinces_per_row, provinces_per_col) = size.dimensions();
-        let provinces_per_row = provinces_per_row as u32;
-        let provinces_per_col = provinces_per_col as u32;
+        Self::from_grid(provinces_per_row as u32, provinces_per_col as u32)
+    }
 
+    /// Dimensions of a map with any number of provinces per row and column
+    pub fn from_grid(provinces_per_row: u32, provinces_per_col: u32) -> Self {
         use crate::math::{HEX_SIZE, SQRT_3};
         let hex_size = HEX_SIZE;
         let width_pixels = provinces_per_row as f32 * hex_size * 1.5;
@@ -0,0 +1,12 @@
+//! Visual cycle gateway - day/night and seasons on the map
+//!
+//! Cheap sprite overlays synced to `GameTime`, each toggleable from the
+//! graphics settings.
+
+// PRIVATE MODULES
+mod rendering;
+mod solar;
+
+// PUBLIC EXPORTS
+pub use rendering::{NightOverlay, SnowCap, VisualCyclePlugin};
+pub use solar::{snow_extent, sun_phase, winter_depth, Hemisphere, VISUAL_DAY_LENGTH_DAYS};
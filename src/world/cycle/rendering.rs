@@ -0,0 +1,185 @@
+//! Day/night terminator and seasonal snow overlays
+//!
+//! Both effects are single tinted sprites stretched over the map, so they
+//! cost one draw each regardless of province count. The night band wraps
+//! horizontally as the visual day advances; snow caps grow from the poles
+//! toward the equator in each hemisphere's winter.
+
+use super::solar::{snow_extent, sun_phase, Hemisphere};
+use crate::math::smoothstep;
+use crate::settings::GameSettings;
+use crate::simulation::GameTime;
+use crate::world::MapDimensions;
+use bevy::asset::RenderAssetUsages;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy_plugin_builder::define_plugin;
+
+/// Z-index for snow caps (above provinces, below detail sprites and borders)
+const SNOW_Z_INDEX: f32 = 5.0;
+
+/// Z-index for the night band (darkens everything on the map surface)
+const NIGHT_Z_INDEX: f32 = 40.0;
+
+/// Darkest the night side of the map gets
+const NIGHT_MAX_ALPHA: f32 = 0.35;
+
+/// Most opaque the snow gets at the pole
+const SNOW_MAX_ALPHA: f32 = 0.65;
+
+/// Horizontal resolution of the night band texture (two visual days wide)
+const NIGHT_TEXTURE_WIDTH: u32 = 256;
+
+/// Vertical resolution of the snow cap gradient
+const SNOW_TEXTURE_HEIGHT: u32 = 64;
+
+/// Marker for the sprite darkening the night side of the map
+#[derive(Component)]
+pub struct NightOverlay;
+
+/// Snow cap sprite over one hemisphere's polar region
+#[derive(Component)]
+pub struct SnowCap(pub Hemisphere);
+
+/// Night band covering two visual days so it can slide without a seam
+fn create_night_texture() -> Image {
+    let mut pixels = Vec::with_capacity((NIGHT_TEXTURE_WIDTH * 4) as usize);
+    for x in 0..NIGHT_TEXTURE_WIDTH {
+        let phase = (x as f32 + 0.5) / (NIGHT_TEXTURE_WIDTH as f32 / 2.0);
+        // Sun elevation: +1 at noon, -1 at midnight, with a soft twilight edge
+        let elevation = (phase * std::f32::consts::TAU).cos();
+        let darkness = smoothstep(0.15, -0.15, elevation);
+        pixels.extend_from_slice(&[5, 8, 30, (darkness * NIGHT_MAX_ALPHA * 255.0) as u8]);
+    }
+    Image::new(
+        Extent3d {
+            width: NIGHT_TEXTURE_WIDTH,
+            height: 1,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        pixels,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
+
+/// Snow fading from solid at the top (pole) to clear at the bottom (snow line)
+fn create_snow_texture() -> Image {
+    let mut pixels = Vec::with_capacity((SNOW_TEXTURE_HEIGHT * 4) as usize);
+    for y in 0..SNOW_TEXTURE_HEIGHT {
+        let toward_line = (y as f32 + 0.5) / SNOW_TEXTURE_HEIGHT as f32;
+        let cover = 1.0 - smoothstep(0.6, 1.0, toward_line);
+        pixels.extend_from_slice(&[242, 247, 255, (cover * SNOW_MAX_ALPHA * 255.0) as u8]);
+    }
+    Image::new(
+        Extent3d {
+            width: 1,
+            height: SNOW_TEXTURE_HEIGHT,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        pixels,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
+
+/// Spawn the night band and snow caps once per game
+pub fn spawn_visual_cycle(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    existing: Query<(), With<NightOverlay>>,
+) {
+    if !existing.is_empty() {
+        return;
+    }
+
+    commands.spawn((
+        Sprite::from_image(images.add(create_night_texture())),
+        Transform::from_xyz(0.0, 0.0, NIGHT_Z_INDEX),
+        Visibility::Hidden,
+        NightOverlay,
+        Name::new("Night Overlay"),
+    ));
+
+    let snow = images.add(create_snow_texture());
+    for hemisphere in [Hemisphere::North, Hemisphere::South] {
+        commands.spawn((
+            Sprite {
+                image: snow.clone(),
+                flip_y: hemisphere == Hemisphere::South,
+                ..default()
+            },
+            Transform::from_xyz(0.0, 0.0, SNOW_Z_INDEX),
+            Visibility::Hidden,
+            SnowCap(hemisphere),
+            Name::new(format!("Snow Cap {:?}", hemisphere)),
+        ));
+    }
+}
+
+/// Slide the night band across the map with the visual day
+pub fn update_day_night_cycle(
+    settings: Res<GameSettings>,
+    game_time: Res<GameTime>,
+    map_dimensions: Option<Res<MapDimensions>>,
+    mut night_query: Query<(&mut Sprite, &mut Transform, &mut Visibility), With<NightOverlay>>,
+) {
+    let Ok((mut sprite, mut transform, mut visibility)) = night_query.single_mut() else {
+        return;
+    };
+    let Some(map) = map_dimensions.filter(|_| settings.graphics.day_night_cycle) else {
+        visibility.set_if_neq(Visibility::Hidden);
+        return;
+    };
+    visibility.set_if_neq(Visibility::Inherited);
+
+    let bounds = map.bounds;
+    let width = bounds.x_max - bounds.x_min;
+    let center = Vec2::new((bounds.x_min + bounds.x_max) / 2.0, (bounds.y_min + bounds.y_max) / 2.0);
+
+    // The sun travels east to west, so the night band slides westward
+    let shift = width * (1.0 - sun_phase(&game_time));
+    sprite.custom_size = Some(Vec2::new(width * 2.0, bounds.y_max - bounds.y_min));
+    transform.translation.x = center.x - width / 2.0 + shift;
+    transform.translation.y = center.y;
+}
+
+/// Grow and shrink the polar snow caps with the seasons
+pub fn update_seasonal_snow(
+    settings: Res<GameSettings>,
+    game_time: Res<GameTime>,
+    map_dimensions: Option<Res<MapDimensions>>,
+    mut snow_query: Query<(&SnowCap, &mut Sprite, &mut Transform, &mut Visibility)>,
+) {
+    let map = map_dimensions.filter(|_| settings.graphics.seasonal_effects);
+    for (cap, mut sprite, mut transform, mut visibility) in &mut snow_query {
+        let Some(map) = map.as_ref() else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        visibility.set_if_neq(Visibility::Inherited);
+
+        let bounds = map.bounds;
+        let half_height = (bounds.y_max - bounds.y_min) / 2.0;
+        let depth = half_height * snow_extent(game_time.day_of_year(), cap.0);
+        sprite.custom_size = Some(Vec2::new(bounds.x_max - bounds.x_min, depth));
+        transform.translation.x = (bounds.x_min + bounds.x_max) / 2.0;
+        transform.translation.y = match cap.0 {
+            Hemisphere::North => bounds.y_max - depth / 2.0,
+            Hemisphere::South => bounds.y_min + depth / 2.0,
+        };
+    }
+}
+
+define_plugin!(VisualCyclePlugin {
+    update: [
+        (update_day_night_cycle, update_seasonal_snow)
+            .run_if(in_state(crate::states::GameState::InGame))
+    ],
+
+    on_enter: {
+        crate::states::GameState::InGame => [spawn_visual_cycle]
+    }
+});
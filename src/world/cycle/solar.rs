@@ -0,0 +1,68 @@
+//! Sun position and snow cover derived from simulation time
+//!
+//! Pure functions so the visual cycle stays in lockstep with `GameTime`
+//! and can be reasoned about without a running app.
+
+use crate::math::smoothstep;
+use crate::simulation::{GameTick, GameTime};
+
+/// Simulated days for the terminator to sweep the whole map once
+///
+/// A true daily cycle would flash past every second at normal speed, so the
+/// visual day is stretched to roughly a month of simulated time.
+pub const VISUAL_DAY_LENGTH_DAYS: u64 = 30;
+
+/// Fraction of a hemisphere (measured from the pole) under snow at midwinter
+const MAX_SNOW_EXTENT: f32 = 0.45;
+
+/// Fraction of a hemisphere under snow at midsummer (permanent ice)
+const MIN_SNOW_EXTENT: f32 = 0.08;
+
+/// Day of year of the northern midwinter
+const NORTHERN_MIDWINTER_DAY: f32 = 10.0;
+
+/// Hemispheres of the map, split at the equator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hemisphere {
+    North,
+    South,
+}
+
+/// Position of the sun in the visual day (0.0 = dawn at the east edge, wraps at 1.0)
+pub fn sun_phase(game_time: &GameTime) -> f32 {
+    let period = GameTick::TICKS_PER_DAY * VISUAL_DAY_LENGTH_DAYS;
+    (game_time.current_tick().0 % period) as f32 / period as f32
+}
+
+/// How deep into winter a hemisphere is (0.0 = midsummer, 1.0 = midwinter)
+pub fn winter_depth(day_of_year: u32, hemisphere: Hemisphere) -> f32 {
+    let angle = (day_of_year as f32 - NORTHERN_MIDWINTER_DAY) / 365.0 * std::f32::consts::TAU;
+    let northern = 0.5 + 0.5 * angle.cos();
+    match hemisphere {
+        Hemisphere::North => northern,
+        Hemisphere::South => 1.0 - northern,
+    }
+}
+
+/// Fraction of a hemisphere, measured from its pole, covered by snow
+pub fn snow_extent(day_of_year: u32, hemisphere: Hemisphere) -> f32 {
+    // Snow lingers near midwinter and retreats quickly in spring
+    let depth = smoothstep(0.0, 1.0, winter_depth(day_of_year, hemisphere));
+    MIN_SNOW_EXTENT + (MAX_SNOW_EXTENT - MIN_SNOW_EXTENT) * depth
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hemispheres_have_opposite_winters() {
+        let january = 15;
+        let july = 196;
+
+        assert!(snow_extent(january, Hemisphere::North) > snow_extent(july, Hemisphere::North));
+        assert!(snow_extent(july, Hemisphere::South) > snow_extent(january, Hemisphere::South));
+        assert!(snow_extent(july, Hemisphere::North) >= MIN_SNOW_EXTENT);
+        assert!(snow_extent(january, Hemisphere::North) <= MAX_SNOW_EXTENT);
+    }
+}
@@ -0,0 +1,322 @@
+//! Zoom-dependent landscape detail sprites
+//!
+//! When the camera is close, the flat province colors are dressed with small
+//! sprites: cities scaled by population, farms on fertile settled land,
+//! banners for stationed armies, and monuments for wonders. Cities and armies
+//! are drawn in the presentation era of the nation that owns them. Only details
+//! inside the camera view are spawned; everything is culled when the camera
+//! pulls back past [`DETAIL_MAX_ZOOM`].
+
+use super::textures::{setup_detail_textures, DetailKind, DetailTextures};
+use crate::camera::CameraController;
+use crate::math::HEX_SIZE;
+use crate::nations::{DevelopmentLevel, Nation, PresentationEra};
+use crate::relationships::{Army, StationedIn};
+use crate::world::{ProvinceEntityOrder, ProvinceId, ProvinceStorage};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_plugin_builder::define_plugin;
+use std::collections::HashMap;
+
+/// Camera zoom (orthographic scale) above which no detail is drawn
+pub const DETAIL_MAX_ZOOM: f32 = 1.5;
+
+/// Seconds between detail refreshes while the camera is still
+const DETAIL_REFRESH_INTERVAL_SECS: f32 = 0.5;
+
+/// Extra margin around the view, in world units, so panning reveals ready sprites
+const VIEW_MARGIN: f32 = HEX_SIZE * 4.0;
+
+/// Provinces need this many people to show a city
+const CITY_MIN_POPULATION: u32 = 5_000;
+
+/// Settled provinces with at least this agriculture show farmland
+const FARM_MIN_AGRICULTURE: f32 = 1.5;
+
+/// Z-index for detail sprites (above provinces and borders, below army badges)
+const DETAIL_Z_INDEX: f32 = 20.0;
+
+/// A landmark drawn as a monument on the detail layer
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct Wonder {
+    pub name: String,
+    pub province: ProvinceId,
+}
+
+/// Identity of a detail sprite, stable across refreshes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum DetailKey {
+    City(usize),
+    Farm(usize),
+    Army(Entity),
+    Wonder(Entity),
+}
+
+/// Marker for sprites on the detail layer
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DetailSprite {
+    pub kind: DetailKind,
+}
+
+/// Spawned detail sprites and refresh bookkeeping
+#[derive(Resource)]
+pub struct MapDetailLayer {
+    sprites: HashMap<DetailKey, Entity>,
+    refresh_timer: Timer,
+    last_view: Option<Rect>,
+}
+
+impl Default for MapDetailLayer {
+    fn default() -> Self {
+        Self {
+            sprites: HashMap::new(),
+            refresh_timer: Timer::from_seconds(DETAIL_REFRESH_INTERVAL_SECS, TimerMode::Repeating),
+            last_view: None,
+        }
+    }
+}
+
+impl MapDetailLayer {
+    /// Number of detail sprites currently spawned
+    pub fn sprite_count(&self) -> usize {
+        self.sprites.len()
+    }
+}
+
+/// What a detail sprite should look like this refresh
+struct DetailSpec {
+    kind: DetailKind,
+    position: Vec2,
+    size: f32,
+    color: Color,
+}
+
+/// City icon size in world units, growing with the log of population
+fn city_size(population: u32) -> f32 {
+    let magnitude = (population.max(1) as f32).log10() - (CITY_MIN_POPULATION as f32).log10();
+    HEX_SIZE * (0.6 + 0.25 * magnitude.clamp(0.0, 3.0))
+}
+
+/// City icon for an era: huts, then gabled houses, then domes, then towers
+fn city_kind(era: DevelopmentLevel) -> DetailKind {
+    match era {
+        DevelopmentLevel::Primitive => DetailKind::Hamlet,
+        DevelopmentLevel::Medieval => DetailKind::City,
+        DevelopmentLevel::Renaissance => DetailKind::DomedCity,
+        DevelopmentLevel::Modern => DetailKind::Metropolis,
+    }
+}
+
+/// Army icon for an era: warband spears, then banners, then regimental colors
+fn army_kind(era: DevelopmentLevel) -> DetailKind {
+    match era {
+        DevelopmentLevel::Primitive => DetailKind::Warband,
+        DevelopmentLevel::Medieval => DetailKind::Army,
+        DevelopmentLevel::Renaissance | DevelopmentLevel::Modern => DetailKind::Regiment,
+    }
+}
+
+/// Offset from the province center so farms sit beside the city
+fn farm_offset(index: usize) -> Vec2 {
+    let angle = (index as f32 * 2.399_963).rem_euclid(std::f32::consts::TAU);
+    Vec2::from_angle(angle) * HEX_SIZE * 0.45
+}
+
+/// Spawn, move, and cull detail sprites for the current camera view
+pub fn refresh_detail_layer(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut layer: ResMut<MapDetailLayer>,
+    textures: Option<Res<DetailTextures>>,
+    camera_query: Query<(&Transform, &CameraController), With<Camera>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    province_storage: Option<Res<ProvinceStorage>>,
+    province_entity_order: Option<Res<ProvinceEntityOrder>>,
+    armies_query: Query<(Entity, &Army, &StationedIn)>,
+    wonders_query: Query<(Entity, &Wonder)>,
+    nations_query: Query<(&Nation, Option<&PresentationEra>)>,
+) {
+    let (Some(textures), Some(province_storage)) = (textures, province_storage) else {
+        return;
+    };
+    let (Ok((camera_transform, controller)), Ok(window)) = (camera_query.single(), window_query.single())
+    else {
+        return;
+    };
+
+    layer.refresh_timer.tick(time.delta());
+
+    // Pulled back too far: clear the layer entirely
+    if controller.current_zoom > DETAIL_MAX_ZOOM {
+        for (_, entity) in layer.sprites.drain() {
+            commands.entity(entity).despawn();
+        }
+        layer.last_view = None;
+        return;
+    }
+
+    let half_extent = Vec2::new(window.width(), window.height()) * controller.current_zoom / 2.0;
+    let view = Rect::from_center_half_size(camera_transform.translation.truncate(), half_extent + VIEW_MARGIN);
+
+    // Armies march and wonders appear on their own, so refresh periodically even when still
+    let view_moved = layer.last_view != Some(view);
+    if !view_moved && !layer.refresh_timer.just_finished() {
+        return;
+    }
+    layer.last_view = Some(view);
+
+    let mut wanted: HashMap<DetailKey, DetailSpec> = HashMap::new();
+    // Unclaimed land stays in the tribal age
+    let era_of = |owner: Option<Entity>| {
+        owner
+            .and_then(|owner| nations_query.get(owner).ok())
+            .and_then(|(_, era)| era)
+            .map_or(DevelopmentLevel::Primitive, |era| era.0)
+    };
+
+    for (index, province) in province_storage.provinces.iter().enumerate() {
+        if !view.contains(province.position) {
+            continue;
+        }
+        if province.population >= CITY_MIN_POPULATION {
+            wanted.insert(
+                DetailKey::City(index),
+                DetailSpec {
+                    kind: city_kind(era_of(province.owner_entity)),
+                    position: province.position,
+                    size: city_size(province.population),
+                    color: Color::WHITE,
+                },
+            );
+        }
+        if province.population > 0 && province.agriculture.value() >= FARM_MIN_AGRICULTURE {
+            wanted.insert(
+                DetailKey::Farm(index),
+                DetailSpec {
+                    kind: DetailKind::Farm,
+                    position: province.position + farm_offset(index),
+                    size: HEX_SIZE * 0.5,
+                    color: Color::WHITE,
+                },
+            );
+        }
+    }
+
+    if let Some(entity_order) = province_entity_order {
+        let index_by_entity: HashMap<Entity, usize> = entity_order
+            .entities
+            .iter()
+            .enumerate()
+            .map(|(index, &entity)| (entity, index))
+            .collect();
+        for (entity, army, stationed_in) in &armies_query {
+            let Some(province) = index_by_entity
+                .get(&stationed_in.0)
+                .and_then(|&index| province_storage.provinces.get(index))
+            else {
+                continue;
+            };
+            if !view.contains(province.position) {
+                continue;
+            }
+            let color = nations_query
+                .get(army.owner_nation)
+                .map_or(Color::WHITE, |(nation, _)| nation.color);
+            wanted.insert(
+                DetailKey::Army(entity),
+                DetailSpec {
+                    kind: army_kind(era_of(Some(army.owner_nation))),
+                    position: province.position + Vec2::new(-HEX_SIZE * 0.4, HEX_SIZE * 0.3),
+                    size: HEX_SIZE * 0.55,
+                    color,
+                },
+            );
+        }
+    }
+
+    for (entity, wonder) in &wonders_query {
+        let Some(&index) = province_storage.province_by_id.get(&wonder.province) else {
+            continue;
+        };
+        let Some(province) = province_storage.provinces.get(index) else {
+            continue;
+        };
+        if !view.contains(province.position) {
+            continue;
+        }
+        wanted.insert(
+            DetailKey::Wonder(entity),
+            DetailSpec {
+                kind: DetailKind::Wonder,
+                position: province.position + Vec2::new(HEX_SIZE * 0.4, HEX_SIZE * 0.3),
+                size: HEX_SIZE * 0.7,
+                color: Color::WHITE,
+            },
+        );
+    }
+
+    // Cull sprites that left the view or whose subject is gone
+    layer.sprites.retain(|key, entity| {
+        let keep = wanted.contains_key(key);
+        if !keep {
+            commands.entity(*entity).despawn();
+        }
+        keep
+    });
+
+    for (key, spec) in wanted {
+        let transform = Transform::from_translation(spec.position.extend(DETAIL_Z_INDEX));
+        let sprite = Sprite {
+            image: textures.get(spec.kind),
+            color: spec.color,
+            custom_size: Some(Vec2::splat(spec.size)),
+            ..default()
+        };
+        match layer.sprites.get(&key) {
+            // Existing sprites are refreshed in place (armies move, cities grow, eras turn)
+            Some(&entity) => {
+                commands
+                    .entity(entity)
+                    .insert((sprite, transform, DetailSprite { kind: spec.kind }));
+            }
+            None => {
+                let entity = commands
+                    .spawn((sprite, transform, DetailSprite { kind: spec.kind }))
+                    .id();
+                layer.sprites.insert(key, entity);
+            }
+        }
+    }
+}
+
+/// Remove all detail sprites when leaving the game
+pub fn clear_detail_layer(
+    mut commands: Commands,
+    mut layer: ResMut<MapDetailLayer>,
+    sprites_query: Query<Entity, With<DetailSprite>>,
+) {
+    for entity in &sprites_query {
+        commands.entity(entity).despawn();
+    }
+    layer.sprites.clear();
+    layer.last_view = None;
+}
+
+define_plugin!(MapDetailPlugin {
+    resources: [MapDetailLayer],
+
+    reflect: [Wonder],
+
+    update: [
+        refresh_detail_layer.run_if(in_state(crate::states::GameState::InGame))
+    ],
+
+    on_enter: {
+        crate::states::GameState::InGame => [setup_detail_textures]
+    },
+
+    on_exit: {
+        crate::states::GameState::InGame => [clear_detail_layer]
+    }
+});
@@ -0,0 +1,12 @@
+//! Map detail layer gateway
+//!
+//! Zoom-dependent sprites for cities, farms, armies, and wonders that bring
+//! the close-up map to life.
+
+// PRIVATE MODULES
+mod layer;
+mod textures;
+
+// PUBLIC EXPORTS
+pub use layer::{DetailSprite, MapDetailLayer, MapDetailPlugin, Wonder, DETAIL_MAX_ZOOM};
+pub use textures::{create_detail_texture, DetailKind, DetailTextures};
@@ -0,0 +1,263 @@
+//! Procedural icon textures for the map detail layer
+//!
+//! Icons are drawn in white or neutral tones so sprites can tint them
+//! (armies take their nation's color) without needing one texture per owner.
+//! Cities and armies have one icon per presentation era, from thatched huts
+//! and warband spears to towers and regimental colors.
+
+use bevy::asset::RenderAssetUsages;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use std::collections::HashMap;
+
+/// Edge length of every detail icon in pixels
+pub const DETAIL_ICON_SIZE: u32 = 32;
+
+/// Kinds of landscape detail drawn on the map
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DetailKind {
+    /// Tribal-age settlement of thatched huts
+    Hamlet,
+    City,
+    /// Renaissance city under a domed cathedral
+    DomedCity,
+    /// Modern city of towers
+    Metropolis,
+    Farm,
+    /// Tribal-age war party under a spear pennant
+    Warband,
+    Army,
+    /// Gunpowder-age regiment under rectangular colors
+    Regiment,
+    Wonder,
+}
+
+impl DetailKind {
+    pub const ALL: [DetailKind; 9] = [
+        Self::Hamlet,
+        Self::City,
+        Self::DomedCity,
+        Self::Metropolis,
+        Self::Farm,
+        Self::Warband,
+        Self::Army,
+        Self::Regiment,
+        Self::Wonder,
+    ];
+}
+
+/// Texture handles for each detail icon, created once per game
+#[derive(Resource, Debug, Clone)]
+pub struct DetailTextures {
+    icons: HashMap<DetailKind, Handle<Image>>,
+}
+
+impl DetailTextures {
+    pub fn get(&self, kind: DetailKind) -> Handle<Image> {
+        self.icons.get(&kind).cloned().unwrap_or_default()
+    }
+}
+
+const CLEAR: [u8; 4] = [0, 0, 0, 0];
+const WALL: [u8; 4] = [226, 214, 190, 255];
+const ROOF: [u8; 4] = [168, 72, 52, 255];
+const OUTLINE: [u8; 4] = [40, 32, 28, 255];
+const WHEAT: [u8; 4] = [222, 190, 92, 230];
+const CROP: [u8; 4] = [120, 164, 72, 230];
+const BANNER: [u8; 4] = [255, 255, 255, 255];
+const POLE: [u8; 4] = [70, 54, 40, 255];
+const GOLD: [u8; 4] = [240, 200, 80, 255];
+const THATCH: [u8; 4] = [196, 160, 96, 255];
+const STEEL: [u8; 4] = [150, 160, 172, 255];
+const WINDOW: [u8; 4] = [250, 230, 150, 255];
+
+/// Color of one icon pixel at normalized coordinates (0..1, y down)
+fn icon_pixel(kind: DetailKind, u: f32, v: f32) -> [u8; 4] {
+    match kind {
+        DetailKind::City => {
+            // Three houses of different heights, the middle one tallest
+            let houses = [(0.08, 0.36, 0.55), (0.34, 0.66, 0.35), (0.64, 0.92, 0.5)];
+            for (left, right, top) in houses {
+                if u < left || u > right {
+                    continue;
+                }
+                let half = (right - left) / 2.0;
+                let peak = top - half;
+                let roof_line = peak + (u - (left + half)).abs();
+                if v >= top && v <= 0.92 {
+                    let edge = u - left < 0.03 || right - u < 0.03 || v > 0.89;
+                    return if edge { OUTLINE } else { WALL };
+                }
+                if v >= roof_line && v < top {
+                    return ROOF;
+                }
+            }
+            CLEAR
+        }
+        DetailKind::Hamlet => {
+            // Two round huts with dark doorways
+            let huts = [(0.3, 0.9, 0.24), (0.7, 0.92, 0.2)];
+            for (center, floor, radius) in huts {
+                let du = u - center;
+                let dv = v - floor;
+                if dv > 0.0 || du * du + dv * dv > radius * radius {
+                    continue;
+                }
+                let door = du.abs() < radius * 0.2 && dv > -radius * 0.5;
+                let rim = du * du + dv * dv > (radius - 0.03) * (radius - 0.03);
+                return if door || rim { OUTLINE } else { THATCH };
+            }
+            CLEAR
+        }
+        DetailKind::DomedCity => {
+            // A domed cathedral with a spire between two houses
+            if (0.47..0.53).contains(&u) && (0.06..0.3).contains(&v) {
+                return OUTLINE;
+            }
+            let du = u - 0.5;
+            let dv = v - 0.46;
+            if dv <= 0.0 && du * du + dv * dv <= 0.16 * 0.16 {
+                return GOLD;
+            }
+            if (0.34..0.66).contains(&u) && (0.46..0.92).contains(&v) {
+                let edge = u < 0.37 || u > 0.63 || v > 0.89;
+                return if edge { OUTLINE } else { WALL };
+            }
+            for (left, right) in [(0.06, 0.3), (0.7, 0.94)] {
+                if u >= left && u <= right && v >= 0.6 && v <= 0.92 {
+                    return if v < 0.66 { ROOF } else { WALL };
+                }
+            }
+            CLEAR
+        }
+        DetailKind::Metropolis => {
+            // Towers of different heights with lit windows
+            let towers = [(0.08, 0.3, 0.36), (0.36, 0.62, 0.08), (0.68, 0.92, 0.24)];
+            for (left, right, top) in towers {
+                if u < left || u > right || v < top || v > 0.94 {
+                    continue;
+                }
+                let window = (u * 16.0).fract() < 0.45 && (v * 16.0).fract() < 0.45;
+                return if window { WINDOW } else { STEEL };
+            }
+            CLEAR
+        }
+        DetailKind::Farm => {
+            // A tilted field of alternating furrows
+            let du = u - 0.5;
+            let dv = v - 0.5;
+            if du.abs() + dv.abs() > 0.48 {
+                return CLEAR;
+            }
+            let furrow = ((du + dv) * 8.0).floor() as i32;
+            if furrow % 2 == 0 { WHEAT } else { CROP }
+        }
+        DetailKind::Army => {
+            // A swallow-tailed banner on a pole
+            if (0.18..0.25).contains(&u) && (0.08..0.95).contains(&v) {
+                return POLE;
+            }
+            let fly_edge = 0.7 + (v - 0.3).abs();
+            if u >= 0.25 && u < fly_edge && (0.1..0.5).contains(&v) {
+                return BANNER;
+            }
+            CLEAR
+        }
+        DetailKind::Warband => {
+            // A spear with an iron head and a small pennant
+            if (0.45..0.54).contains(&u) && (0.2..0.95).contains(&v) {
+                return POLE;
+            }
+            if (0.06..0.2).contains(&v) && (u - 0.495).abs() < (v - 0.06) * 0.5 {
+                return STEEL;
+            }
+            if u >= 0.54 && (0.2..0.5).contains(&v) && u < 0.54 + (0.5 - v) * 1.4 {
+                return BANNER;
+            }
+            CLEAR
+        }
+        DetailKind::Regiment => {
+            // Rectangular colors with a canton, on a tall staff
+            if (0.16..0.22).contains(&u) && (0.04..0.95).contains(&v) {
+                return POLE;
+            }
+            if (0.22..0.9).contains(&u) && (0.08..0.48).contains(&v) {
+                let canton = u < 0.46 && v < 0.26;
+                return if canton { OUTLINE } else { BANNER };
+            }
+            CLEAR
+        }
+        DetailKind::Wonder => {
+            // A stepped monument
+            let steps = [(0.1, 0.9, 0.78), (0.22, 0.78, 0.56), (0.34, 0.66, 0.34), (0.44, 0.56, 0.1)];
+            for (left, right, top) in steps {
+                if u >= left && u <= right && v >= top && v <= 0.92 {
+                    return if v - top < 0.03 { OUTLINE } else { GOLD };
+                }
+            }
+            CLEAR
+        }
+    }
+}
+
+/// Rasterize a detail icon as RGBA8 pixels
+pub fn rasterize_icon(kind: DetailKind, size: u32) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity((size * size * 4) as usize);
+    for y in 0..size {
+        for x in 0..size {
+            let u = (x as f32 + 0.5) / size as f32;
+            let v = (y as f32 + 0.5) / size as f32;
+            pixels.extend_from_slice(&icon_pixel(kind, u, v));
+        }
+    }
+    pixels
+}
+
+/// Create the texture for a detail icon
+pub fn create_detail_texture(kind: DetailKind) -> Image {
+    Image::new(
+        Extent3d {
+            width: DETAIL_ICON_SIZE,
+            height: DETAIL_ICON_SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        rasterize_icon(kind, DETAIL_ICON_SIZE),
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
+
+/// Build the icon textures when a game starts
+pub fn setup_detail_textures(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    existing: Option<Res<DetailTextures>>,
+) {
+    if existing.is_some() {
+        return;
+    }
+    let icons = DetailKind::ALL
+        .into_iter()
+        .map(|kind| (kind, images.add(create_detail_texture(kind))))
+        .collect();
+    commands.insert_resource(DetailTextures { icons });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_icon_has_visible_pixels() {
+        for kind in DetailKind::ALL {
+            let pixels = rasterize_icon(kind, DETAIL_ICON_SIZE);
+            assert_eq!(pixels.len(), (DETAIL_ICON_SIZE * DETAIL_ICON_SIZE * 4) as usize);
+
+            let opaque = pixels.chunks_exact(4).filter(|p| p[3] > 0).count();
+            let total = (DETAIL_ICON_SIZE * DETAIL_ICON_SIZE) as usize;
+            assert!(opaque > total / 10, "{:?} icon is nearly empty", kind);
+            assert!(opaque < total, "{:?} icon has no transparent border", kind);
+        }
+    }
+}
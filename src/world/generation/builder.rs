@@ -7,9 +7,10 @@ use bevy::log::{debug, info};
 use rand::{rngs::StdRng, SeedableRng};
 
 use super::errors::{WorldGenerationError, WorldGenerationErrorType};
+use super::preview::GenerationPreview;
 use crate::diagnostics::{TimedOperation, log_world_gen_step, log_world_gen_progress, log_memory_usage};
 use crate::resources::{MapDimensions, WorldSize};
-use crate::world::{Province, World};
+use crate::world::{Province, TerrainType, World};
 
 // Import utilities
 
@@ -26,6 +27,8 @@ pub struct WorldBuilder {
     ocean_coverage: f32,
     river_density: f32,
     climate_type: crate::world::ClimateType,
+    erosion_iterations: u32,
+    erosion_droplets: f32,
 }
 
 impl WorldBuilder {
@@ -49,16 +52,40 @@ impl WorldBuilder {
             ocean_coverage,
             river_density,
             climate_type,
+            erosion_iterations: 1,
+            erosion_droplets: 1.0,
         }
     }
 
+    /// Rounds of erosion and the droplet count relative to the world size's baseline
+    pub fn with_erosion(mut self, iterations: u32, droplet_multiplier: f32) -> Self {
+        self.erosion_iterations = iterations;
+        self.erosion_droplets = droplet_multiplier;
+        self
+    }
+
+    /// Generate on a grid other than the world size's, such as a miniature test world
+    pub fn with_dimensions(mut self, dimensions: MapDimensions) -> Self {
+        self.dimensions = dimensions;
+        self
+    }
+
     pub fn build(self) -> Result<World, WorldGenerationError> {
         self.build_with_progress(None::<fn(&str, f32)>)
     }
 
     pub fn build_with_progress(
+        self,
+        progress_callback: Option<impl Fn(&str, f32)>,
+    ) -> Result<World, WorldGenerationError> {
+        self.build_with_preview(progress_callback, None::<fn(GenerationPreview)>)
+    }
+
+    /// Build the world, also handing out a map preview as each stage finishes
+    pub fn build_with_preview(
         mut self,
         progress_callback: Option<impl Fn(&str, f32)>,
+        preview_callback: Option<impl Fn(GenerationPreview)>,
     ) -> Result<World, WorldGenerationError> {
         let total_timer = TimedOperation::start_with_level("World Generation", crate::diagnostics::LogLevel::Info);
 
@@ -72,6 +99,11 @@ impl WorldBuilder {
                 callback(step, progress);
             }
         };
+        let report_preview = |preview: &dyn Fn() -> GenerationPreview| {
+            if let Some(ref callback) = preview_callback {
+                callback(preview());
+            }
+        };
 
         // Step 1: Generate provinces with Perlin noise elevation
         let province_count = self.dimensions.provinces_per_row * self.dimensions.provinces_per_col;
@@ -94,25 +126,28 @@ impl WorldBuilder {
         // (This is already done in ProvinceBuilder::build() now)
 
         // Step 2: Apply erosion simulation for realistic terrain
-        let erosion_iterations =
-            match self.dimensions.provinces_per_row * self.dimensions.provinces_per_col {
-                n if n < 400_000 => 3_000,
-                n if n < 700_000 => 5_000,
-                _ => 8_000,
-            };
-        report_progress(&format!("Applying erosion simulation ({} iterations)...", erosion_iterations), 0.2);
+        let erosion = crate::world::ErosionQuality::for_world(
+            province_count,
+            self.erosion_iterations,
+            self.erosion_droplets,
+        );
+        let step = format!(
+            "Applying erosion simulation ({} iterations of {} droplets)...",
+            erosion.iterations, erosion.droplets
+        );
+        report_progress(&step, 0.2);
 
         let erosion_timer = TimedOperation::start("Erosion Simulation");
-        debug!("Starting erosion with {} iterations for {} provinces",
-               erosion_iterations, provinces.len());
+        debug!("Starting erosion with {:?} for {} provinces", erosion, provinces.len());
         crate::world::apply_erosion_to_provinces(
             &mut provinces,
             self.dimensions,
             &mut self.rng,
-            erosion_iterations,
+            erosion,
         );
-        let erosion_time = erosion_timer.complete_with_context(format!("{} iterations", erosion_iterations));
-        log_world_gen_step("Erosion Simulation", erosion_iterations, erosion_time);
+        let erosion_time = erosion_timer.complete_with_context(format!("{} droplets", erosion.droplets));
+        log_world_gen_step("Erosion Simulation", erosion.droplets, erosion_time);
+        report_preview(&|| GenerationPreview::elevation(&provinces, self.dimensions));
 
         // Step 3: Calculate ocean depths
         let ocean_count = provinces.iter().filter(|p| p.elevation.value() <= 0.0).count();
@@ -127,9 +162,11 @@ impl WorldBuilder {
         report_progress(&format!("Generating climate zones across {} provinces...", provinces.len()), 0.4);
 
         let climate_timer = TimedOperation::start("Climate Generation");
-        let climate_storage = crate::world::apply_climate_to_provinces(&mut provinces, self.dimensions, self.climate_type);
+        let climate_storage =
+            crate::world::apply_climate_to_provinces(&mut provinces, self.dimensions, self.climate_type, None);
         let climate_time = climate_timer.complete_with_context(format!("{:?} climate", self.climate_type));
         log_world_gen_step("Climate Generation", provinces.len(), climate_time);
+        report_preview(&|| GenerationPreview::climate(&provinces, self.dimensions, self.seed));
 
         // Step 5: Generate river systems
         let target_rivers = (provinces.len() as f32 * self.river_density * 0.001) as usize;
@@ -147,6 +184,9 @@ impl WorldBuilder {
         let actual_rivers = river_system.river_tiles.len();
         let river_time = river_timer.complete_with_context(format!("{} rivers generated", actual_rivers));
         log_world_gen_step("River Generation", actual_rivers, river_time);
+        report_preview(&|| {
+            GenerationPreview::rivers(&provinces, self.dimensions, self.seed, &river_system.river_tiles)
+        });
 
         // Step 6: Calculate agriculture values
         report_progress(&format!("Calculating agriculture values for {} provinces...", provinces.len()), 0.6);
@@ -201,6 +241,11 @@ impl WorldBuilder {
         info!("  Rivers generated: {}", actual_rivers);
         info!("  Clouds generated: {}", cloud_system.clouds.len());
         info!("  Total time: {:.2}ms", total_time_ms);
+        info!(
+            "  Fingerprint: {} (generation v{})",
+            super::WorldFingerprint::of_provinces(&provinces),
+            super::GENERATION_VERSION
+        );
 
         // Log memory usage summary
         let world_memory = provinces.len() * std::mem::size_of::<crate::world::Province>()
@@ -226,58 +271,17 @@ impl WorldBuilder {
 /// - Agriculture potential
 /// - Fresh water access
 pub fn initialize_province_populations(provinces: &mut [Province]) {
-    use crate::world::terrain::TerrainType;
     use rayon::prelude::*;
 
     provinces.par_iter_mut().for_each(|province| {
-        // Base population depends on terrain type
-        let terrain_multiplier = match province.terrain {
-            // Ocean and water have no population
-            TerrainType::Ocean | TerrainType::River => 0.0,
-
-            // Highly habitable terrain
-            TerrainType::TemperateGrassland => 1.0,
-            TerrainType::Savanna => 0.9,
-
-            // Moderate habitability
-            TerrainType::TemperateDeciduousForest | TerrainType::MediterraneanForest => 0.7,
-            TerrainType::TropicalSeasonalForest => 0.6,
-
-            // Lower habitability
-            TerrainType::BorealForest | TerrainType::Taiga => 0.4,
-            TerrainType::TropicalRainforest | TerrainType::TemperateRainforest => 0.35,
-
-            // Harsh terrain
-            TerrainType::Wetlands | TerrainType::Mangrove => 0.25,
-            TerrainType::SubtropicalDesert | TerrainType::TropicalDesert => 0.15,
-            TerrainType::ColdDesert | TerrainType::Tundra => 0.1,
-            TerrainType::Alpine | TerrainType::PolarDesert => 0.05,
-
-            // Special terrain
-            TerrainType::Beach | TerrainType::Chaparral => 0.5,
-        };
-
+        let terrain_multiplier = terrain_habitability(province.terrain);
         if terrain_multiplier == 0.0 {
             province.population = 0;
             province.max_population = 0;
             return;
         }
 
-        // Agriculture bonus (0.0 to 3.0 agriculture value)
-        let agriculture_bonus = 1.0 + (province.agriculture.value() * 0.5);
-
-        // Water access bonus
-        let water_bonus = if province.fresh_water_distance.value() < 2.0 {
-            1.5 // Near river/lake
-        } else if province.fresh_water_distance.value() < 5.0 {
-            1.2 // Reasonable distance
-        } else {
-            1.0 // Far from water
-        };
-
-        // Calculate max population (base of 10000 for ideal conditions)
-        let base_max_pop = 10000.0;
-        let max_pop = (base_max_pop * terrain_multiplier * agriculture_bonus * water_bonus) as u32;
+        let max_pop = supported_population(province);
         province.max_population = max_pop.max(100); // Minimum 100 for habitable land
 
         // Initial population is 20-40% of max
@@ -295,3 +299,60 @@ pub fn initialize_province_populations(provinces: &mut [Province]) {
         if populated_provinces > 0 { total_pop / populated_provinces as u64 } else { 0 }
     );
 }
+
+/// Share of the ideal population a terrain can support
+fn terrain_habitability(terrain: TerrainType) -> f32 {
+    match terrain {
+        // Ocean and water have no population
+        TerrainType::Ocean | TerrainType::River => 0.0,
+
+        // Highly habitable terrain
+        TerrainType::TemperateGrassland => 1.0,
+        TerrainType::Savanna => 0.9,
+
+        // Moderate habitability
+        TerrainType::TemperateDeciduousForest | TerrainType::MediterraneanForest => 0.7,
+        TerrainType::TropicalSeasonalForest => 0.6,
+
+        // Lower habitability
+        TerrainType::BorealForest | TerrainType::Taiga => 0.4,
+        TerrainType::TropicalRainforest | TerrainType::TemperateRainforest => 0.35,
+
+        // Harsh terrain
+        TerrainType::Wetlands | TerrainType::Mangrove => 0.25,
+        TerrainType::SubtropicalDesert | TerrainType::TropicalDesert => 0.15,
+        TerrainType::ColdDesert | TerrainType::Tundra => 0.1,
+        TerrainType::Alpine | TerrainType::PolarDesert => 0.05,
+
+        // Special terrain
+        TerrainType::Beach | TerrainType::Chaparral => 0.5,
+    }
+}
+
+/// People a province's terrain, agriculture, and water could support
+fn supported_population(province: &Province) -> u32 {
+    // Agriculture bonus (0.0 to 3.0 agriculture value)
+    let agriculture_bonus = 1.0 + (province.agriculture.value() * 0.5);
+
+    // Water access bonus
+    let water_bonus = if province.fresh_water_distance.value() < 2.0 {
+        1.5 // Near river/lake
+    } else if province.fresh_water_distance.value() < 5.0 {
+        1.2 // Reasonable distance
+    } else {
+        1.0 // Far from water
+    };
+
+    // Base of 10000 for ideal conditions
+    let base_max_pop = 10000.0;
+    (base_max_pop * terrain_habitability(province.terrain) * agriculture_bonus * water_bonus) as u32
+}
+
+/// Most people a province can hold, recomputed when its terrain changes after generation
+pub fn population_capacity(province: &Province) -> u32 {
+    if terrain_habitability(province.terrain) == 0.0 {
+        0
+    } else {
+        supported_population(province).max(100)
+    }
+}
@@ -0,0 +1,132 @@
+//! Generation output fingerprint and seed-stability contract
+//!
+//! A seed is a promise: the same seed, size, and settings produce the same
+//! world. [`GENERATION_VERSION`] names the generator revision that promise is
+//! made against, and [`WorldFingerprint`] condenses a generated world into one
+//! value so golden fixtures can catch any change to the output. A change that
+//! alters an existing seed's world must bump the version and re-bless the
+//! fixtures, which keeps such changes deliberate.
+
+use std::hash::{Hash, Hasher};
+
+use crate::simulation::StableHasher;
+use crate::world::Province;
+
+/// Revision of the world generator; bump when existing seeds change output
+pub const GENERATION_VERSION: u32 = 2;
+
+/// Hash of every province's elevation and biome, in province order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WorldFingerprint(pub u64);
+
+impl WorldFingerprint {
+    pub fn of_provinces(provinces: &[Province]) -> Self {
+        let mut hasher = StableHasher::new();
+        provinces.len().hash(&mut hasher);
+        for province in provinces {
+            hasher.write_f32_bits(province.elevation.value());
+            province.terrain.hash(&mut hasher);
+        }
+        Self(hasher.finish())
+    }
+}
+
+impl std::fmt::Display for WorldFingerprint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Golden tests: `golden_small` runs with the normal suite; the larger
+    //! sizes with `cargo test golden -- --include-ignored`.
+    //!
+    //! Bless after a deliberate generator change (and version bump) with
+    //! `LW_BLESS_GOLDEN=1 cargo test golden -- --include-ignored`.
+
+    use super::*;
+    use crate::resources::WorldSize;
+    use crate::world::{ClimateType, WorldBuilder};
+    use serde::{Deserialize, Serialize};
+
+    const GOLDEN_SEED: u32 = 42;
+    const FIXTURE_PATH: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/src/world/generation/golden_fingerprints.ron"
+    );
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct GoldenFingerprint {
+        generation_version: u32,
+        world_size: WorldSize,
+        seed: u32,
+        fingerprint: u64,
+    }
+
+    fn load_fixtures() -> Vec<GoldenFingerprint> {
+        match std::fs::read_to_string(FIXTURE_PATH) {
+            Ok(text) => ron::from_str(&text).unwrap_or_else(|e| panic!("Malformed {FIXTURE_PATH}: {e}")),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    fn check_golden(world_size: WorldSize) {
+        // Defaults of the world configuration screen
+        let world = WorldBuilder::new(GOLDEN_SEED, world_size, 7, 0.6, 1.0, ClimateType::Mixed)
+            .build()
+            .unwrap_or_else(|e| panic!("Generation failed: {e:?}"));
+        let fingerprint = WorldFingerprint::of_provinces(&world.provinces);
+
+        let mut fixtures = load_fixtures();
+        let recorded = fixtures.iter().position(|f| {
+            f.generation_version == GENERATION_VERSION && f.world_size == world_size && f.seed == GOLDEN_SEED
+        });
+
+        if std::env::var_os("LW_BLESS_GOLDEN").is_some() {
+            let entry = GoldenFingerprint {
+                generation_version: GENERATION_VERSION,
+                world_size,
+                seed: GOLDEN_SEED,
+                fingerprint: fingerprint.0,
+            };
+            match recorded {
+                Some(idx) => fixtures[idx] = entry,
+                None => fixtures.push(entry),
+            }
+            let text = ron::ser::to_string_pretty(&fixtures, ron::ser::PrettyConfig::default())
+                .unwrap_or_else(|e| panic!("Cannot serialize fixtures: {e}"));
+            std::fs::write(FIXTURE_PATH, text).unwrap_or_else(|e| panic!("Cannot write {FIXTURE_PATH}: {e}"));
+            return;
+        }
+
+        let Some(idx) = recorded else {
+            panic!(
+                "No golden fingerprint for {world_size:?} at generation v{GENERATION_VERSION} in {FIXTURE_PATH}; \
+                 record it with `LW_BLESS_GOLDEN=1 cargo test golden -- --include-ignored` and commit the file"
+            );
+        };
+        assert_eq!(
+            WorldFingerprint(fixtures[idx].fingerprint),
+            fingerprint,
+            "{world_size:?} seed {GOLDEN_SEED} changed output without a GENERATION_VERSION bump"
+        );
+    }
+
+    #[test]
+    fn golden_small() {
+        check_golden(WorldSize::Small);
+    }
+
+    #[test]
+    #[ignore = "generates a full world"]
+    fn golden_medium() {
+        check_golden(WorldSize::Medium);
+    }
+
+    #[test]
+    #[ignore = "generates a full world"]
+    fn golden_large() {
+        check_golden(WorldSize::Large);
+    }
+}
@@ -9,7 +9,9 @@
 
 mod builder; // Main world builder orchestrator
 mod errors; // Error types for generation failures
+mod fingerprint; // Seed-stability contract
 mod plugin;
+mod preview; // Stage snapshots for the loading screen
 mod utils; // Shared utilities // Generation plugin
 
 // PUBLIC INTERFACE - The only way to generate worlds
@@ -17,8 +19,14 @@ mod utils; // Shared utilities // Generation plugin
 // Re-export the WorldBuilder from builder.rs
 pub use builder::WorldBuilder;
 
-// Re-export population initialization for use in GPU generation path
-pub use builder::initialize_province_populations;
+// Re-export population initialization for use in GPU generation path and changing coastlines
+pub use builder::{initialize_province_populations, population_capacity};
+
+// Re-export the seed-stability contract
+pub use fingerprint::{WorldFingerprint, GENERATION_VERSION};
+
+// Re-export stage previews for the progressive map reveal
+pub use preview::{GenerationPreview, PreviewStage};
 
 // Re-export error types for generation failures
 pub use errors::{WorldGenerationError, WorldGenerationErrorType};
@@ -0,0 +1,209 @@
+//! Progressive map reveal during world generation
+//!
+//! The builder hands out a [`GenerationPreview`] as each stage finishes, so
+//! the loading screen can show the map forming - bare elevation first, then
+//! biomes, rivers, and finally nation borders - instead of a blank panel.
+//! A preview is one pixel per province, small enough to send every stage.
+
+use std::collections::HashMap;
+
+use bevy::asset::RenderAssetUsages;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+use crate::resources::MapDimensions;
+use crate::world::{Province, WorldColors};
+
+/// Generation stage a preview was taken after
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreviewStage {
+    Elevation,
+    Climate,
+    Rivers,
+    Nations,
+}
+
+impl PreviewStage {
+    /// Caption shown under the preview
+    pub fn label(self) -> &'static str {
+        match self {
+            PreviewStage::Elevation => "Terrain",
+            PreviewStage::Climate => "Climate",
+            PreviewStage::Rivers => "Rivers",
+            PreviewStage::Nations => "Nations",
+        }
+    }
+}
+
+/// Snapshot of the map, one RGBA pixel per province, north row first
+#[derive(Debug, Clone)]
+pub struct GenerationPreview {
+    pub stage: PreviewStage,
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<[u8; 4]>,
+}
+
+/// Ocean shading from deep to shallow
+const DEEP_WATER: [f32; 3] = [0.02, 0.06, 0.18];
+const SHALLOW_WATER: [f32; 3] = [0.10, 0.30, 0.55];
+/// Land shading from lowland to peak before biomes exist
+const LOWLAND: [f32; 3] = [0.25, 0.45, 0.20];
+const HIGHLAND: [f32; 3] = [0.50, 0.40, 0.28];
+const PEAK: [f32; 3] = [0.92, 0.92, 0.95];
+const RIVER: [f32; 3] = [0.20, 0.45, 0.85];
+/// Share of a nation's color laid over its provinces
+const NATION_TINT: f32 = 0.55;
+
+impl GenerationPreview {
+    /// Bare elevation: water by depth, land by height
+    pub fn elevation(provinces: &[Province], dimensions: MapDimensions) -> Self {
+        Self::paint(PreviewStage::Elevation, provinces, dimensions, |_, province| {
+            elevation_color(province.elevation.value())
+        })
+    }
+
+    /// Biomes as the finished map will draw them
+    pub fn climate(provinces: &[Province], dimensions: MapDimensions, seed: u32) -> Self {
+        let colors = WorldColors::new(seed);
+        Self::paint(PreviewStage::Climate, provinces, dimensions, |_, province| {
+            terrain_color(&colors, province)
+        })
+    }
+
+    /// Biomes with river provinces picked out
+    pub fn rivers(provinces: &[Province], dimensions: MapDimensions, seed: u32, river_tiles: &[u32]) -> Self {
+        let colors = WorldColors::new(seed);
+        let mut preview = Self::paint(PreviewStage::Rivers, provinces, dimensions, |_, province| {
+            terrain_color(&colors, province)
+        });
+        for &tile in river_tiles {
+            preview.set(tile as usize, dimensions, RIVER);
+        }
+        preview
+    }
+
+    /// Biomes tinted by the nation owning each province
+    pub fn nations(
+        provinces: &[Province],
+        dimensions: MapDimensions,
+        seed: u32,
+        province_colors: &HashMap<u32, Color>,
+    ) -> Self {
+        let colors = WorldColors::new(seed);
+        Self::paint(PreviewStage::Nations, provinces, dimensions, |index, province| {
+            let base = terrain_color(&colors, province);
+            match province_colors.get(&(index as u32)) {
+                Some(color) => {
+                    let tint = color.to_srgba();
+                    let tint = [tint.red, tint.green, tint.blue];
+                    std::array::from_fn(|c| base[c] * (1.0 - NATION_TINT) + tint[c] * NATION_TINT)
+                }
+                None => base,
+            }
+        })
+    }
+
+    /// Upload-ready image of the preview
+    pub fn to_image(&self) -> Image {
+        Image::new(
+            Extent3d {
+                width: self.width,
+                height: self.height,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            self.pixels.iter().flatten().copied().collect(),
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        )
+    }
+
+    fn paint(
+        stage: PreviewStage,
+        provinces: &[Province],
+        dimensions: MapDimensions,
+        color: impl Fn(usize, &Province) -> [f32; 3],
+    ) -> Self {
+        let width = dimensions.provinces_per_row;
+        let height = dimensions.provinces_per_col;
+        let mut preview = Self {
+            stage,
+            width,
+            height,
+            pixels: vec![[0, 0, 0, 255]; (width * height) as usize],
+        };
+        for (index, province) in provinces.iter().enumerate() {
+            preview.set(index, dimensions, color(index, province));
+        }
+        preview
+    }
+
+    /// Color the pixel of the province at `index` (row-major from the south)
+    fn set(&mut self, index: usize, dimensions: MapDimensions, rgb: [f32; 3]) {
+        let row_len = dimensions.provinces_per_row as usize;
+        if row_len == 0 {
+            return;
+        }
+        let (col, row) = (index % row_len, index / row_len);
+        if row >= self.height as usize {
+            return;
+        }
+        // Images run top-down, the map's rows bottom-up
+        let pixel = (self.height as usize - 1 - row) * row_len + col;
+        self.pixels[pixel] = [to_byte(rgb[0]), to_byte(rgb[1]), to_byte(rgb[2]), 255];
+    }
+}
+
+fn to_byte(channel: f32) -> u8 {
+    (channel.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+fn lerp(from: [f32; 3], to: [f32; 3], t: f32) -> [f32; 3] {
+    let t = t.clamp(0.0, 1.0);
+    std::array::from_fn(|c| from[c] + (to[c] - from[c]) * t)
+}
+
+fn elevation_color(elevation: f32) -> [f32; 3] {
+    if elevation <= 0.0 {
+        lerp(DEEP_WATER, SHALLOW_WATER, 1.0 + elevation.max(-1.0))
+    } else if elevation < 0.5 {
+        lerp(LOWLAND, HIGHLAND, elevation * 2.0)
+    } else {
+        lerp(HIGHLAND, PEAK, (elevation - 0.5) * 2.0)
+    }
+}
+
+fn terrain_color(colors: &WorldColors, province: &Province) -> [f32; 3] {
+    let color = colors
+        .terrain(province.terrain, province.elevation.value(), province.position)
+        .to_srgba();
+    [color.red, color.green, color.blue]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pixels_put_the_first_row_at_the_bottom() {
+        let dimensions = MapDimensions {
+            provinces_per_row: 3,
+            provinces_per_col: 2,
+            ..MapDimensions::default()
+        };
+        let mut preview = GenerationPreview {
+            stage: PreviewStage::Rivers,
+            width: 3,
+            height: 2,
+            pixels: vec![[0, 0, 0, 255]; 6],
+        };
+        preview.set(1, dimensions, [1.0, 0.0, 0.0]);
+        preview.set(5, dimensions, [0.0, 0.0, 1.0]);
+        preview.set(6, dimensions, [0.0, 1.0, 0.0]);
+
+        assert_eq!(preview.pixels[4], [255, 0, 0, 255]);
+        assert_eq!(preview.pixels[2], [0, 0, 255, 255]);
+        assert_eq!(preview.pixels.iter().filter(|p| p[1] == 255).count(), 0);
+    }
+}
@@ -228,7 +228,11 @@ fn run_single_benchmark(
             continent_seeds,
             validation_config,
             gpu_status,
-            &GpuGenerationConfig::default(),
+            // Benchmarks measure the GPU passes whether or not generation uses them
+            &GpuGenerationConfig {
+                use_gpu: true,
+                ..Default::default()
+            },
         )
     } else {
         // If not in comparison mode, create minimal result
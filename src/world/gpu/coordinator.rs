@@ -4,7 +4,7 @@
 //! generation and GPU compute, managing the state transitions and data flow.
 
 use super::{
-    resources::GpuGenerationRequest, GpuComputeStatus, GpuElevationData,
+    fields::GpuFieldContext, resources::GpuGenerationRequest, GpuComputeStatus, GpuElevationData,
 };
 use bevy::prelude::*;
 
@@ -38,19 +38,24 @@ impl Default for GpuGenerationState {
 /// Configuration for GPU world generation
 #[derive(Resource, Debug, Clone)]
 pub struct GpuGenerationConfig {
+    /// Run the field passes on the GPU; off by default because their output
+    /// is not bit-exact across hardware, so a seed would not reproduce its world
     pub use_gpu: bool,
     pub fallback_on_failure: bool,
     pub timeout_seconds: f32,
     pub max_retries: u32,
+    /// Device to run the field passes on, handed over when generation starts
+    pub fields: Option<GpuFieldContext>,
 }
 
 impl Default for GpuGenerationConfig {
     fn default() -> Self {
         Self {
-            use_gpu: true,
+            use_gpu: false,
             fallback_on_failure: true,
             timeout_seconds: 30.0,
             max_retries: 3,
+            fields: None,
         }
     }
 }
@@ -0,0 +1,313 @@
+//! GPU world field passes
+//!
+//! Runs the heavy per-province passes of world generation - elevation noise,
+//! temperature and winds, and moisture diffusion - as compute shaders from
+//! `shaders/field_compute.wgsl`. The passes are dispatched straight on the
+//! render device and read back by blocking on the queue, so they can be used
+//! from the world generation task without going through the render graph.
+//!
+//! # Determinism
+//!
+//! CPU generation is bit-exact: the lattice noise runs in fixed point, so a
+//! seed reproduces the same world on every machine. The shader evaluates the
+//! same formulas in f32, and GPUs differ in how they round `pow`, fused
+//! multiply-adds and the like, so GPU results only agree with the CPU within
+//! validation tolerance. Worlds generated with [`GpuFieldContext`] are
+//! therefore not reproducible from their seed on other hardware.
+
+use bevy::prelude::*;
+use bevy::render::{
+    render_resource::{
+        BindGroupEntry, BindGroupLayout, Buffer, BufferDescriptor, BufferInitDescriptor, BufferUsages, CommandEncoder,
+        CommandEncoderDescriptor, ComputePassDescriptor, ComputePipeline, MapMode, PollType,
+        RawComputePipelineDescriptor, ShaderModuleDescriptor, ShaderSource,
+    },
+    renderer::{RenderDevice, RenderQueue},
+};
+use bytemuck::{Pod, Zeroable};
+
+use crate::resources::MapDimensions;
+
+/// Compute shader holding every field pass
+const FIELD_SHADER: &str = include_str!("../../../shaders/field_compute.wgsl");
+
+/// Threads per workgroup in every field pass (matches the shader)
+const WORKGROUP_SIZE: u32 = 256;
+
+/// Elevation pass parameters - must match `ElevationParams` in the shader
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable)]
+struct ElevationParams {
+    seed: u32,
+    count: u32,
+    continent_count: u32,
+    hex_size: f32,
+    world_width: f32,
+    world_height: f32,
+    _padding: [f32; 2],
+}
+
+/// Temperature pass parameters - must match `ClimateParams` in the shader
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable)]
+pub struct ClimateFieldParams {
+    pub count: u32,
+    pub equator_temp: f32,
+    pub pole_temp: f32,
+    pub y_min: f32,
+    pub y_max: f32,
+    pub _padding: [f32; 3],
+}
+
+/// Handles to the GPU that can be moved into the world generation task
+///
+/// Results are not bit-exact across hardware; see the module docs.
+#[derive(Clone)]
+pub struct GpuFieldContext {
+    device: RenderDevice,
+    queue: RenderQueue,
+}
+
+impl std::fmt::Debug for GpuFieldContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GpuFieldContext").finish_non_exhaustive()
+    }
+}
+
+impl GpuFieldContext {
+    pub fn new(device: RenderDevice, queue: RenderQueue) -> Self {
+        Self { device, queue }
+    }
+
+    /// Elevation of every province, as `GpuElevationGenerator::generate_elevations_cpu`
+    pub fn elevations(
+        &self,
+        positions: &[Vec2],
+        seed: u32,
+        continent_seeds: &[(Vec2, f32, f32)],
+        dimensions: MapDimensions,
+    ) -> Result<Vec<f32>, String> {
+        if positions.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let points: Vec<[f32; 2]> = positions.iter().map(|position| [position.x, position.y]).collect();
+        // A storage buffer can't be empty, so a world without continents gets one of no strength
+        let mut continents: Vec<[f32; 4]> = continent_seeds
+            .iter()
+            .map(|(center, strength, radius)| [center.x, center.y, *strength, *radius])
+            .collect();
+        if continents.is_empty() {
+            continents.push([0.0; 4]);
+        }
+        let params = ElevationParams {
+            seed,
+            count: positions.len() as u32,
+            continent_count: continent_seeds.len() as u32,
+            hex_size: dimensions.hex_size,
+            world_width: dimensions.provinces_per_row as f32 * dimensions.hex_size,
+            world_height: dimensions.provinces_per_col as f32 * dimensions.hex_size,
+            _padding: [0.0; 2],
+        };
+
+        let positions_buffer = self.storage("field_positions", bytemuck::cast_slice(&points));
+        let continents_buffer = self.storage("field_continents", bytemuck::cast_slice(&continents));
+        let params_buffer = self.uniform("field_elevation_params", bytemuck::bytes_of(&params));
+        let output = self.output(
+            "field_elevations",
+            (positions.len() * std::mem::size_of::<f32>()) as u64,
+        );
+
+        let mut encoder = self.encoder("field_elevation_encoder");
+        self.dispatch(
+            &mut encoder,
+            "elevation",
+            &[
+                (0, &positions_buffer),
+                (1, &continents_buffer),
+                (2, &params_buffer),
+                (3, &output),
+            ],
+            params.count,
+        );
+        self.read_back(encoder, &output)
+    }
+
+    /// Temperature, wind direction and wind strength of every province
+    ///
+    /// Each input is (position y, elevation, ocean distance in km, 1.0 for
+    /// ocean); each output is (temperature, wind direction, wind strength, 0).
+    pub fn temperatures(&self, provinces: &[[f32; 4]], params: ClimateFieldParams) -> Result<Vec<[f32; 4]>, String> {
+        if provinces.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let provinces_buffer = self.storage("field_climate_provinces", bytemuck::cast_slice(provinces));
+        let params_buffer = self.uniform("field_climate_params", bytemuck::bytes_of(&params));
+        let output = self.output("field_temperatures", std::mem::size_of_val(provinces) as u64);
+
+        let mut encoder = self.encoder("field_temperature_encoder");
+        self.dispatch(
+            &mut encoder,
+            "temperature",
+            &[(4, &provinces_buffer), (5, &params_buffer), (6, &output)],
+            provinces.len() as u32,
+        );
+        self.read_back(encoder, &output)
+    }
+
+    /// Diffuse rainfall between neighbors for `iterations` Jacobi steps
+    ///
+    /// `provinces` and `winds` are laid out as for [`Self::temperatures`];
+    /// `neighbors` holds six indices per province, -1 where there is none.
+    pub fn diffuse_moisture(
+        &self,
+        provinces: &[[f32; 4]],
+        winds: &[[f32; 4]],
+        neighbors: &[i32],
+        rainfall: &[f32],
+        iterations: u32,
+    ) -> Result<Vec<f32>, String> {
+        if rainfall.is_empty() || iterations == 0 {
+            return Ok(rainfall.to_vec());
+        }
+
+        let provinces_buffer = self.storage("field_moisture_provinces", bytemuck::cast_slice(provinces));
+        let winds_buffer = self.storage("field_winds", bytemuck::cast_slice(winds));
+        let neighbors_buffer = self.storage("field_neighbors", bytemuck::cast_slice(neighbors));
+        // Two buffers, read from one and write the other, swapping each step
+        let mut current = self.storage("field_rainfall_a", bytemuck::cast_slice(rainfall));
+        let mut next = self.output("field_rainfall_b", std::mem::size_of_val(rainfall) as u64);
+
+        let mut encoder = self.encoder("field_moisture_encoder");
+        for _ in 0..iterations {
+            self.dispatch(
+                &mut encoder,
+                "moisture",
+                &[
+                    (7, &neighbors_buffer),
+                    (8, &winds_buffer),
+                    (9, &current),
+                    (10, &next),
+                    (11, &provinces_buffer),
+                ],
+                rainfall.len() as u32,
+            );
+            std::mem::swap(&mut current, &mut next);
+        }
+        self.read_back(encoder, &current)
+    }
+
+    fn storage(&self, label: &'static str, contents: &[u8]) -> Buffer {
+        self.device.create_buffer_with_data(&BufferInitDescriptor {
+            label: Some(label),
+            contents,
+            usage: BufferUsages::STORAGE | BufferUsages::COPY_SRC,
+        })
+    }
+
+    fn uniform(&self, label: &'static str, contents: &[u8]) -> Buffer {
+        self.device.create_buffer_with_data(&BufferInitDescriptor {
+            label: Some(label),
+            contents,
+            usage: BufferUsages::UNIFORM,
+        })
+    }
+
+    fn output(&self, label: &'static str, size: u64) -> Buffer {
+        self.device.create_buffer(&BufferDescriptor {
+            label: Some(label),
+            size,
+            usage: BufferUsages::STORAGE | BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        })
+    }
+
+    fn encoder(&self, label: &'static str) -> CommandEncoder {
+        self.device
+            .create_command_encoder(&CommandEncoderDescriptor { label: Some(label) })
+    }
+
+    fn pipeline(&self, entry_point: &str) -> ComputePipeline {
+        let module = self.device.create_and_validate_shader_module(ShaderModuleDescriptor {
+            label: Some("world_field_compute"),
+            source: ShaderSource::Wgsl(FIELD_SHADER.into()),
+        });
+        self.device.create_compute_pipeline(&RawComputePipelineDescriptor {
+            label: Some(entry_point),
+            layout: None,
+            module: &module,
+            entry_point: Some(entry_point),
+            compilation_options: Default::default(),
+            cache: None,
+        })
+    }
+
+    /// Record one pass over `count` provinces, binding buffers to group 0
+    fn dispatch(&self, encoder: &mut CommandEncoder, entry_point: &str, bindings: &[(u32, &Buffer)], count: u32) {
+        let pipeline = self.pipeline(entry_point);
+        // The layout is derived from the shader, holding only what this entry point uses
+        let layout = BindGroupLayout::from(pipeline.get_bind_group_layout(0));
+        let entries: Vec<BindGroupEntry> = bindings
+            .iter()
+            .map(|(binding, buffer)| BindGroupEntry {
+                binding: *binding,
+                resource: buffer.as_entire_binding(),
+            })
+            .collect();
+        let bind_group = self.device.create_bind_group(entry_point, &layout, &entries);
+
+        let mut pass = encoder.begin_compute_pass(&ComputePassDescriptor {
+            label: Some(entry_point),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.dispatch_workgroups(count.div_ceil(WORKGROUP_SIZE), 1, 1);
+    }
+
+    /// Submit the recorded passes and wait for `source` to come back
+    fn read_back<T: Pod>(&self, mut encoder: CommandEncoder, source: &Buffer) -> Result<Vec<T>, String> {
+        let size = source.size();
+        let staging = self.device.create_buffer(&BufferDescriptor {
+            label: Some("field_readback_staging"),
+            size,
+            usage: BufferUsages::COPY_DST | BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+        encoder.copy_buffer_to_buffer(source, 0, &staging, 0, size);
+        self.queue.submit(std::iter::once(encoder.finish()));
+
+        let slice = staging.slice(..);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        slice.map_async(MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        self.device
+            .poll(PollType::Wait)
+            .map_err(|e| format!("GPU did not finish field pass: {}", e))?;
+        receiver
+            .recv()
+            .map_err(|e| format!("GPU readback never completed: {}", e))?
+            .map_err(|e| format!("GPU readback failed: {}", e))?;
+
+        let view = slice.get_mapped_range();
+        let data = bytemuck::pod_collect_to_vec::<u8, T>(&view);
+        drop(view);
+        staging.unmap();
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn params_match_shader_uniform_layout() {
+        // Both shader structs are eight 4-byte scalars, a multiple of 16 bytes
+        assert_eq!(std::mem::size_of::<ElevationParams>(), 32);
+        assert_eq!(std::mem::size_of::<ClimateFieldParams>(), 32);
+        assert!(FIELD_SHADER.contains("fn elevation") && FIELD_SHADER.contains("fn moisture"));
+    }
+}
@@ -53,7 +53,7 @@ impl GpuElevationGenerator {
                 positions.len()
             );
 
-            match self.try_gpu_generation(positions, gpu_state) {
+            match self.try_gpu_generation(positions, gpu_config, gpu_state) {
                 Some(elevations) => {
                     let gpu_time = start_time.elapsed();
                     info!(
@@ -83,22 +83,31 @@ impl GpuElevationGenerator {
         elevations
     }
 
-    /// Attempt GPU generation - integrates with Bevy's GPU coordinator systems
+    /// Attempt GPU generation - results from the coordinator systems, else the field pass
     fn try_gpu_generation(
         &self,
-        _positions: &[Vec2],
+        positions: &[Vec2],
+        gpu_config: &GpuGenerationConfig,
         gpu_state: &mut GpuGenerationState,
     ) -> Option<Vec<f32>> {
         // Check if GPU results are already available from coordinator systems
-        match gpu_state {
-            GpuGenerationState::Complete(elevations) => {
-                let results = elevations.clone();
+        if let GpuGenerationState::Complete(elevations) = gpu_state {
+            if elevations.len() == positions.len() {
+                let results = std::mem::take(elevations);
                 *gpu_state = GpuGenerationState::Ready; // Reset for next use
-                Some(results)
+                return Some(results);
+            }
+        }
+
+        // Otherwise dispatch the elevation pass directly
+        let fields = gpu_config.fields.as_ref()?;
+        match fields.elevations(positions, self.seed, &self.continent_seeds, self.dimensions) {
+            Ok(elevations) => {
+                *gpu_state = GpuGenerationState::Complete(elevations.clone());
+                Some(elevations)
             }
-            _ => {
-                // GPU not ready or failed - use CPU fallback
-                // The actual GPU coordination happens through Bevy systems in coordinator.rs
+            Err(e) => {
+                *gpu_state = GpuGenerationState::Failed(e);
                 None
             }
         }
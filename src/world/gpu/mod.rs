@@ -9,6 +9,7 @@ mod benchmark;
 mod buffers;
 mod capabilities;
 mod coordinator;
+mod fields;
 mod integration;
 mod node;
 mod plugin;
@@ -29,6 +30,9 @@ pub use resources::{
 // Public exports - Types
 pub use types::{ComputeLabel, ComputeMode, GpuComputeStatus, GpuResources};
 
+// Public exports - Field passes
+pub use fields::{ClimateFieldParams, GpuFieldContext};
+
 // Public exports - Capabilities
 pub use capabilities::check_gpu_compute_support;
 
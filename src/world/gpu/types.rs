@@ -6,6 +6,8 @@
 use bevy::prelude::*;
 use bevy::render::render_graph::RenderLabel;
 
+use super::fields::GpuFieldContext;
+
 /// Mode for compute operations - GPU accelerated or CPU fallback
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum ComputeMode {
@@ -63,4 +65,6 @@ pub struct GpuResources {
     pub use_gpu: bool,
     pub timeout_ms: u64,
     pub validation_enabled: bool,
+    /// Device for the field passes, when the render device is reachable
+    pub fields: Option<GpuFieldContext>,
 }
@@ -24,6 +24,7 @@ use crate::world::{Abundance, Province, TerrainType};
 
 /// Types of mineral resources available in the game
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[reflect(Component)]
 pub enum MineralType {
     /// Iron - Common, used for tools and weapons
     Iron,
@@ -21,9 +21,12 @@
 // PRIVATE FEATURE MODULES - Implementation details are hidden
 
 mod borders; // Border rendering
+mod coastline; // Sea level drift and changing coastlines
 mod clouds; // Cloud system (data, generation, rendering)
 mod colors; // Color system (themes, providers, calculations)
+mod detail; // Zoom-dependent map detail sprites
 mod cultural; // Geographic-cultural assignment system
+mod cycle; // Day/night and seasonal overlays
 mod gpu; // GPU compute acceleration for world generation
 mod infrastructure; // Infrastructure and development systems
 mod mesh; // World mesh rendering
@@ -32,6 +35,8 @@ mod overlay;
 mod provinces; // Province data, spatial indexing, agriculture
 mod rivers; // River systems and flow
 mod terrain; // Terrain types, climate, erosion // Overlay rendering modes
+mod wonders; // Natural wonders and landmarks
+mod yields; // Yearly crop yields from climate, water, and technology
 
 // Non-feature modules
 mod core; // Core world data structures (World)
@@ -49,15 +54,22 @@ pub use core::{MapBounds, MapDimensions, World, WorldName, WorldSeed, WorldSize}
 // === Clouds Feature ===
 pub use clouds::{
     CloudBuilder, CloudData, CloudEntity,
-    CloudPlugin, WeatherState, WeatherSystem,
+    CloudPlugin, RegionalWeather, WeatherState, WeatherSystem,
 };
 
 // === Terrain Feature ===
 pub use terrain::{
-    apply_climate_to_provinces, apply_erosion_to_provinces, Biome,
-    ClimateZone, TerrainEntity, TerrainPlugin, TerrainType,
+    apply_climate_to_provinces, apply_erosion_to_provinces, winter_severity, AttritionProfile,
+    AttritionProfiles, Biome, ClimateStorage, ClimateZone, ErosionQuality, TerrainEntity, TerrainPlugin,
+    TerrainType,
 };
 
+// === Coastline Feature ===
+pub use coastline::{CoastlineChanged, CoastlinePlugin, SeaLevel, SeaLevelDrift, SeaLevelShift};
+
+// === Crop Yields Feature ===
+pub use yields::CropYieldPlugin;
+
 // === Infrastructure Feature ===
 pub use infrastructure::{analyze_infrastructure, InfrastructureStorage};
 
@@ -65,7 +77,8 @@ pub use infrastructure::{analyze_infrastructure, InfrastructureStorage};
 pub use provinces::{
     calculate_agriculture_values, calculate_ocean_depths, Abundance, Agriculture, Distance,
     Elevation, Province, ProvinceBuilder, ProvinceEntity, ProvinceId,
-    ProvincesSpatialIndex, ProvinceEventsPlugin,
+    ProvincesSpatialIndex, ProvinceEventsPlugin, ProvinceGraph,
+    RegionHierarchy, Continent, Region, Area, build_region_hierarchy,
     CoastalProvinceCache, initialize_coastal_cache, NavalRangeCalculator, NAVAL_RANGE_HEXES,
     // ECS province components and utilities
     ProvinceMarker, ProvinceData, ProvinceNeighbors, ProvinceBundle, ProvinceEntityOrder,
@@ -81,11 +94,24 @@ pub use minerals::*; // Re-export all mineral types
 // === Borders Feature ===
 pub use borders::{BorderEntity, BorderPlugin};
 
+// === Visual Cycle Feature ===
+pub use cycle::{NightOverlay, SnowCap, VisualCyclePlugin};
+
+// === Map Detail Feature ===
+pub use detail::{DetailKind, DetailSprite, MapDetailLayer, MapDetailPlugin, Wonder, DETAIL_MAX_ZOOM};
+
+// === Natural Wonders Feature ===
+pub use wonders::{place_natural_wonders, NaturalWonder, NaturalWonders, NaturalWondersPlugin, WonderKind};
+
 // === Mesh Rendering ===
 pub use mesh::{build_world_mesh, ProvinceStorage, WorldMeshHandle};
 
 // === Overlay System ===
-pub use overlay::{CachedOverlayColors, MapMode, OverlayPlugin};
+pub use overlay::{
+    heat_ramp, restored_map_mode, saved_map_mode, BorderHistory, CachedOverlayColors, HeatmapDefinition, HeatmapId,
+    HeatmapRegistry, HeatmapSource, HistoricalBordersView, MapMode, MilitaryOverlayFilter, MilitarySupplyStorage,
+    OverlayPlugin, ProvinceMetric,
+};
 
 // === Color System ===
 pub use colors::WorldColors;
@@ -99,7 +125,10 @@ pub use ui::{
 };
 
 // === World Generation ===
-pub use generation::{WorldBuilder, WorldGenerationError, WorldGenerationErrorType};
+pub use generation::{
+    GenerationPreview, PreviewStage, WorldBuilder, WorldFingerprint, WorldGenerationError, WorldGenerationErrorType,
+    GENERATION_VERSION,
+};
 
 // === GPU Compute Acceleration ===
 pub use gpu::NoiseComputePlugin;
@@ -117,4 +146,5 @@ pub use setup::{
     AsyncWorldGeneration,
     PendingNeighborSetup,
     PendingProvinceSpawn,
+    FRAME_BUDGET_MS,
 };
@@ -3,9 +3,13 @@
 //! This module provides lazy-loaded overlay colors with Arc-based caching for
 //! zero-copy performance. Uses ECS queries for province data and ownership.
 
+use super::heatmap::{heat_ramp, Heatmap, HeatmapRegistry};
+use super::history::{BorderHistory, HistoricalBordersView};
+use super::military::{MilitaryOverlayFilter, MilitarySupplyStorage};
 use super::types::MapMode;
 use crate::math::VERTICES_PER_HEX;
-use crate::nations::Nation;
+use crate::ai::InfluenceMaps;
+use crate::nations::{CensusDiscrepancy, Devastation, Nation, ProvinceCores, Refugees};
 use crate::relationships::Controls;
 use crate::world::{ProvinceData, ProvinceEntityOrder, WorldColors};
 use bevy::log::{debug, info, warn};
@@ -57,6 +61,241 @@ struct ProvinceRenderData {
     gems: u8,
 }
 
+/// Color for a province in the Military overlay
+///
+/// Owned provinces take their nation's color darkened by missing supply;
+/// attrition zones are tinted red. Provinces outside the nation filter are
+/// greyed out so the focused nation's supply network stands out.
+fn military_color(
+    data: &ProvinceRenderData,
+    nation_color: Option<Color>,
+    military_storage: Option<&MilitarySupplyStorage>,
+    military_filter: Option<&MilitaryOverlayFilter>,
+    world_colors: &WorldColors,
+) -> Color {
+    if data.terrain == crate::world::TerrainType::Ocean {
+        return world_colors.terrain(data.terrain, data.elevation, data.position);
+    }
+
+    let Some(nation_color) = nation_color else {
+        return Color::srgb(0.15, 0.15, 0.15);
+    };
+
+    let owner = military_storage.and_then(|storage| storage.owner_at(data.index));
+    if military_filter.is_some_and(|filter| !filter.includes(owner)) {
+        return Color::srgb(0.25, 0.25, 0.25);
+    }
+
+    let supply = military_storage.map_or(1.0, |storage| storage.supply_at(data.index));
+    let rgba = nation_color.to_linear().to_f32_array();
+    let shade = 0.25 + 0.75 * supply.clamp(0.0, 1.0);
+    let shaded = [rgba[0] * shade, rgba[1] * shade, rgba[2] * shade];
+
+    if military_storage.is_some_and(|storage| storage.is_attrition_zone(data.index)) {
+        // Blend toward red so attrition zones read at a glance
+        let alpha = 0.6;
+        Color::linear_rgb(
+            shaded[0] * (1.0 - alpha) + 0.9 * alpha,
+            shaded[1] * (1.0 - alpha) + 0.1 * alpha,
+            shaded[2] * (1.0 - alpha) + 0.1 * alpha,
+        )
+    } else {
+        Color::linear_rgb(shaded[0], shaded[1], shaded[2])
+    }
+}
+
+/// Color for a province in the Historical Borders overlay
+///
+/// When ghosting is enabled the historical owner is blended over today's
+/// owner, so provinces that changed hands show a mix of both colors while
+/// stable heartlands keep a solid tone.
+fn historical_color(
+    data: &ProvinceRenderData,
+    historical: Option<Color>,
+    current: Option<Color>,
+    ghost_current: bool,
+    world_colors: &WorldColors,
+) -> Color {
+    if data.terrain == crate::world::TerrainType::Ocean {
+        return world_colors.terrain(data.terrain, data.elevation, data.position);
+    }
+
+    let unowned = Color::srgb(0.15, 0.15, 0.15);
+    let past = historical.unwrap_or(unowned);
+    if !ghost_current {
+        return past;
+    }
+
+    let past_rgba = past.to_linear().to_f32_array();
+    let now_rgba = current.unwrap_or(unowned).to_linear().to_f32_array();
+    let alpha = 0.65;
+    Color::linear_rgb(
+        past_rgba[0] * alpha + now_rgba[0] * (1.0 - alpha),
+        past_rgba[1] * alpha + now_rgba[1] * (1.0 - alpha),
+        past_rgba[2] * alpha + now_rgba[2] * (1.0 - alpha),
+    )
+}
+
+/// Color for a province in the Cores overlay
+///
+/// Cores held by their owner show the owner's color. Lost cores are striped
+/// red and fade toward grey as foreign rule wears the claim down, so
+/// revanchist flashpoints stand out from long-forgotten claims.
+fn core_color(
+    data: &ProvinceRenderData,
+    core: Option<&crate::nations::ProvinceCore>,
+    core_owner_colors: &HashMap<Entity, Color>,
+    world_colors: &WorldColors,
+) -> Color {
+    if data.terrain == crate::world::TerrainType::Ocean {
+        return world_colors.terrain(data.terrain, data.elevation, data.position);
+    }
+
+    let no_core = Color::srgb(0.15, 0.15, 0.15);
+    let Some(core) = core else {
+        return no_core;
+    };
+    let Some(owner_color) = core.core_owner.and_then(|owner| core_owner_colors.get(&owner)) else {
+        return no_core;
+    };
+    if !core.is_lost() {
+        return *owner_color;
+    }
+
+    // Alternate rows of the hex grid so lost cores read as hatched
+    let stripe = (data.position.y / 20.0).floor() as i32 % 2 == 0;
+    let owner_rgba = owner_color.to_linear().to_f32_array();
+    let accent = if stripe {
+        [0.85, 0.1, 0.1]
+    } else {
+        [owner_rgba[0], owner_rgba[1], owner_rgba[2]]
+    };
+    let fade = core.decay_progress().clamp(0.0, 1.0) * 0.7;
+    Color::linear_rgb(
+        accent[0] * (1.0 - fade) + 0.25 * fade,
+        accent[1] * (1.0 - fade) + 0.25 * fade,
+        accent[2] * (1.0 - fade) + 0.25 * fade,
+    )
+}
+
+/// Per-province influence for an influence overlay, normalized to 0..1
+///
+/// With a nation focused, threat and opportunity are shown from its point of
+/// view (foreign armies, land it does not hold). Cultural pressure is always
+/// measured against each province's own culture.
+fn influence_values(
+    mode: MapMode,
+    maps: &InfluenceMaps,
+    focus: Option<Entity>,
+    province_entity_order: &ProvinceEntityOrder,
+    province_data_query: &Query<&ProvinceData>,
+) -> Vec<f32> {
+    let values: Vec<f32> = (0..province_entity_order.len())
+        .map(|index| match (mode, focus) {
+            (MapMode::ThreatInfluence, Some(nation)) => maps.threat_to(nation, index),
+            (MapMode::ThreatInfluence, None) => maps.military_threat.total(index),
+            (MapMode::EconomicInfluence, Some(nation)) => maps.opportunity_for(nation, index),
+            (MapMode::EconomicInfluence, None) => maps.economic_opportunity.total(index),
+            _ => {
+                let culture = province_entity_order
+                    .get(index)
+                    .and_then(|entity| province_data_query.get(entity).ok())
+                    .and_then(|data| data.culture);
+                match culture {
+                    Some(culture) => maps.cultural_pressure_on(index, culture),
+                    None => maps.cultural_pressure.total(index),
+                }
+            }
+        })
+        .collect();
+
+    let max = values.iter().copied().fold(0.0, f32::max);
+    if max <= f32::EPSILON {
+        return values;
+    }
+    values.into_iter().map(|value| value / max).collect()
+}
+
+/// Color for a province in the Census Error overlay
+///
+/// Provinces whose owner believes them more populous than they are shade
+/// toward red, those believed emptier toward blue; accurate counts stay pale.
+/// Uncounted and unowned land is grey.
+fn census_error_color(data: &ProvinceRenderData, error: Option<f32>, world_colors: &WorldColors) -> Color {
+    if data.terrain == crate::world::TerrainType::Ocean {
+        return world_colors.terrain(data.terrain, data.elevation, data.position);
+    }
+    let Some(error) = error else {
+        return Color::srgb(0.15, 0.15, 0.15);
+    };
+
+    // A 50% miscount is fully saturated
+    let t = (error.abs() * 2.0).clamp(0.0, 1.0);
+    if error > 0.0 {
+        Color::srgb(0.9, 0.9 - 0.8 * t, 0.9 - 0.8 * t)
+    } else {
+        Color::srgb(0.9 - 0.8 * t, 0.9 - 0.6 * t, 0.9)
+    }
+}
+
+/// Color for a province in the Devastation overlay
+///
+/// Untouched land stays pale; devastation darkens toward charred brown.
+/// Depopulated marches are hatched so they stand out from fresh damage.
+fn devastation_color(data: &ProvinceRenderData, level: f32, march: bool, world_colors: &WorldColors) -> Color {
+    if data.terrain == crate::world::TerrainType::Ocean {
+        return world_colors.terrain(data.terrain, data.elevation, data.position);
+    }
+
+    let t = level.clamp(0.0, 1.0);
+    let stripe = march && (data.position.y / 20.0).floor() as i32 % 2 == 0;
+    if stripe {
+        return Color::srgb(0.25, 0.12, 0.08);
+    }
+    Color::srgb(0.85 - 0.5 * t, 0.82 - 0.62 * t, 0.72 - 0.62 * t)
+}
+
+/// Color for a province in the Refugee Flows overlay
+///
+/// Provinces sheltering refugees shade toward blue and provinces their people
+/// fled shade toward orange, by refugees as a share of the local people.
+fn refugee_color(data: &ProvinceRenderData, hosted: u32, fled: u32, world_colors: &WorldColors) -> Color {
+    if data.terrain == crate::world::TerrainType::Ocean {
+        return world_colors.terrain(data.terrain, data.elevation, data.position);
+    }
+
+    // Refugees a quarter of the local people are fully saturated
+    if hosted >= fled {
+        let t = (hosted as f32 / data.population.max(1) as f32 * 4.0).clamp(0.0, 1.0);
+        Color::srgb(0.88 - 0.73 * t, 0.88 - 0.48 * t, 0.85 - 0.05 * t)
+    } else {
+        let t = (fled as f32 / (data.population + fled) as f32 * 4.0).clamp(0.0, 1.0);
+        Color::srgb(0.88 + 0.07 * t, 0.88 - 0.43 * t, 0.85 - 0.75 * t)
+    }
+}
+
+/// Color for a province in an influence overlay
+///
+/// A heat ramp from cold blue (no influence) through yellow to red (the
+/// strongest influence on the map). Oceans keep their terrain color.
+fn influence_color(data: &ProvinceRenderData, value: f32, world_colors: &WorldColors) -> Color {
+    if data.terrain == crate::world::TerrainType::Ocean {
+        return world_colors.terrain(data.terrain, data.elevation, data.position);
+    }
+
+    heat_ramp(value)
+}
+
+/// Color for a province in a registered heatmap, striped where the heatmap hatches it
+fn heatmap_color(data: &ProvinceRenderData, heatmap: Option<&Heatmap>, world_colors: &WorldColors) -> Color {
+    let hatched = heatmap.is_some_and(|heatmap| heatmap.is_hatched(data.index));
+    let stripe = hatched && (data.position.y / 20.0).floor() as i32 % 2 == 0;
+    if stripe && data.terrain != crate::world::TerrainType::Ocean {
+        return Color::srgb(0.15, 0.05, 0.05);
+    }
+    influence_color(data, heatmap.map_or(0.0, |heatmap| heatmap.scaled(data.index)), world_colors)
+}
+
 impl CachedOverlayColors {
     /// Get colors with ECS queries for nation ownership
     pub fn get_or_calculate_ecs(
@@ -69,9 +308,20 @@ impl CachedOverlayColors {
         controls_query: &Query<&Controls>,
         climate_storage: Option<&crate::world::terrain::ClimateStorage>,
         infrastructure_storage: Option<&crate::world::InfrastructureStorage>,
+        military_storage: Option<&MilitarySupplyStorage>,
+        military_filter: Option<&MilitaryOverlayFilter>,
+        border_history: Option<&BorderHistory>,
+        history_view: Option<&HistoricalBordersView>,
+        province_cores: Option<&ProvinceCores>,
+        influence_maps: Option<&InfluenceMaps>,
+        census_discrepancy: Option<&CensusDiscrepancy>,
+        devastation: Option<&Devastation>,
+        refugees: Option<&Refugees>,
+        heatmaps: Option<&HeatmapRegistry>,
     ) -> Arc<Vec<[f32; 4]>> {
         // If requesting current overlay, return Arc clone (just increments refcount)
-        if mode == self.current_type {
+        // Live modes (supply, historical dates) must recalculate on every refresh
+        if mode == self.current_type && !mode.is_live() {
             return Arc::clone(&self.current);
         }
 
@@ -79,6 +329,8 @@ impl CachedOverlayColors {
         let use_cache = match mode {
             MapMode::Political => self.cache.contains_key(&mode),
             MapMode::Infrastructure if infrastructure_storage.is_some() => false,
+            // Live data changes between refreshes - never serve stale colors
+            _ if mode.is_live() => false,
             _ => self.cache.contains_key(&mode),
         };
 
@@ -106,6 +358,16 @@ impl CachedOverlayColors {
             controls_query,
             climate_storage,
             infrastructure_storage,
+            military_storage,
+            military_filter,
+            border_history,
+            history_view,
+            province_cores,
+            influence_maps,
+            census_discrepancy,
+            devastation,
+            refugees,
+            heatmaps,
         ));
 
         debug!(
@@ -148,13 +410,26 @@ impl CachedOverlayColors {
         controls_query: &Query<&Controls>,
         climate_storage: Option<&crate::world::terrain::ClimateStorage>,
         infrastructure_storage: Option<&crate::world::InfrastructureStorage>,
+        military_storage: Option<&MilitarySupplyStorage>,
+        military_filter: Option<&MilitaryOverlayFilter>,
+        border_history: Option<&BorderHistory>,
+        history_view: Option<&HistoricalBordersView>,
+        province_cores: Option<&ProvinceCores>,
+        influence_maps: Option<&InfluenceMaps>,
+        census_discrepancy: Option<&CensusDiscrepancy>,
+        devastation: Option<&Devastation>,
+        refugees: Option<&Refugees>,
+        heatmaps: Option<&HeatmapRegistry>,
     ) -> Vec<[f32; 4]> {
         let world_colors = WorldColors::new(world_seed);
         let province_count = province_entity_order.len();
 
         // Build nation colors map for political/terrain modes
         // Uses Controls relationship - O(nations) instead of O(provinces)
-        let nation_colors_map: HashMap<usize, Color> = if mode == MapMode::Political || mode == MapMode::Terrain {
+        let nation_colors_map: HashMap<usize, Color> = if matches!(
+            mode,
+            MapMode::Political | MapMode::Terrain | MapMode::Military | MapMode::HistoricalBorders
+        ) {
             let mut map = HashMap::new();
             for (nation_entity, nation) in nations_query.iter() {
                 if let Ok(controls) = controls_query.get(nation_entity) {
@@ -171,6 +446,48 @@ impl CachedOverlayColors {
             HashMap::new()
         };
 
+        // Decode the snapshot for the viewed date once, outside the parallel loop
+        let historical_colors: Vec<Option<Color>> = if mode == MapMode::HistoricalBorders {
+            match (border_history, history_view) {
+                (Some(history), Some(view)) => history
+                    .snapshot_at(view.year)
+                    .map(|snapshot| {
+                        snapshot
+                            .decode()
+                            .into_iter()
+                            .map(|owner| owner.and_then(|idx| snapshot.nation(idx)).map(|n| n.color))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                _ => Vec::new(),
+            }
+        } else {
+            Vec::new()
+        };
+        let ghost_current = history_view.is_some_and(|view| view.ghost_current);
+
+        // Core owners may have lost every province, so color by entity rather than by territory
+        let core_owner_colors: HashMap<Entity, Color> = if mode == MapMode::Cores {
+            nations_query
+                .iter()
+                .map(|(entity, nation)| (entity, nation.color))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
+        // Influence is normalized once so the parallel loop only maps values to colors
+        let influence: Vec<f32> = match influence_maps.filter(|_| mode.is_influence_mode()) {
+            Some(maps) => influence_values(
+                mode,
+                maps,
+                military_filter.and_then(|filter| filter.nation),
+                province_entity_order,
+                province_data_query,
+            ),
+            None => Vec::new(),
+        };
+
         // Extract province data for parallel processing
         let province_render_data: Vec<ProvinceRenderData> = province_entity_order
             .entities
@@ -262,6 +579,56 @@ impl CachedOverlayColors {
                                 + data.gold as u32 + data.coal as u32 + data.stone as u32 + data.gems as u32;
                             world_colors.richness(total as f32 / 100.0)
                         }
+                        MapMode::HistoricalBorders => historical_color(
+                            data,
+                            historical_colors.get(data.index).copied().flatten(),
+                            nation_colors_map.get(&data.index).copied(),
+                            ghost_current,
+                            &world_colors,
+                        ),
+                        MapMode::Cores => core_color(
+                            data,
+                            province_cores.and_then(|cores| cores.get(data.index)),
+                            &core_owner_colors,
+                            &world_colors,
+                        ),
+                        MapMode::Devastation => devastation_color(
+                            data,
+                            devastation.map_or(0.0, |devastation| devastation.level(data.index)),
+                            devastation.is_some_and(|devastation| devastation.is_march(data.index)),
+                            &world_colors,
+                        ),
+                        MapMode::RefugeeFlows => refugee_color(
+                            data,
+                            refugees.map_or(0, |refugees| refugees.hosted(data.index)),
+                            refugees.map_or(0, |refugees| refugees.fled(data.index)),
+                            &world_colors,
+                        ),
+                        MapMode::ThreatInfluence
+                        | MapMode::EconomicInfluence
+                        | MapMode::CulturalInfluence => influence_color(
+                            data,
+                            influence.get(data.index).copied().unwrap_or(0.0),
+                            &world_colors,
+                        ),
+                        MapMode::Heatmap(id) => heatmap_color(
+                            data,
+                            heatmaps.and_then(|heatmaps| heatmaps.get(id)),
+                            &world_colors,
+                        ),
+                        MapMode::CensusError => census_error_color(
+                            data,
+                            census_discrepancy
+                                .and_then(|census| census.by_province.get(data.index).copied().flatten()),
+                            &world_colors,
+                        ),
+                        MapMode::Military => military_color(
+                            data,
+                            nation_colors_map.get(&data.index).copied(),
+                            military_storage,
+                            military_filter,
+                            &world_colors,
+                        ),
                     };
 
                     let color_array = color.to_linear().to_f32_array();
@@ -0,0 +1,534 @@
+//! Heatmap overlays for arbitrary per-province metrics
+//!
+//! The [`HeatmapRegistry`] lists every map mode the player can pick. The
+//! game's own modes are registered at startup and keep their `MapMode`
+//! variant, since most of them color categorically or carry legends a single
+//! ramp cannot express. Any other system can register a named metric, which
+//! becomes a map mode of its own, `MapMode::Heatmap(id)`. Metrics either read
+//! each province's data when drawn or are published by the system that owns
+//! them, one value per province in entity order. Mods declare heatmaps over
+//! province statistics in their manifest. Values are auto-scaled between the
+//! smallest and largest on the map and drawn with the shared heat ramp; a
+//! publishing system can also hatch provinces to mark a state the ramp
+//! cannot show, such as a dispute that has turned to war.
+//!
+//! Heatmap ids only hold for the session that handed them out, so saves
+//! record an active heatmap by name.
+
+use super::types::MapMode;
+use crate::modding::ModManager;
+use crate::simulation::NewYearEvent;
+use crate::world::{ProvinceData, ProvinceEntityOrder, TerrainType};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Identifies a registered heatmap; one name keeps one id for as long as the game runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub struct HeatmapId(pub u16);
+
+/// A province statistic a mod can chart without code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProvinceMetric {
+    Population,
+    MaxPopulation,
+    Agriculture,
+    Elevation,
+    FreshWaterDistance,
+    MineralRichness,
+}
+
+impl ProvinceMetric {
+    pub fn read(self, data: &ProvinceData) -> f32 {
+        match self {
+            ProvinceMetric::Population => data.population as f32,
+            ProvinceMetric::MaxPopulation => data.max_population as f32,
+            ProvinceMetric::Agriculture => data.agriculture.value(),
+            ProvinceMetric::Elevation => data.elevation.value(),
+            ProvinceMetric::FreshWaterDistance => data.fresh_water_distance.value(),
+            ProvinceMetric::MineralRichness => [
+                data.iron,
+                data.copper,
+                data.tin,
+                data.gold,
+                data.coal,
+                data.stone,
+                data.gems,
+            ]
+            .iter()
+            .map(|abundance| abundance.value() as f32)
+            .sum(),
+        }
+    }
+}
+
+/// A heatmap declared in a mod manifest
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeatmapDefinition {
+    pub name: String,
+    pub metric: ProvinceMetric,
+}
+
+/// Where a heatmap's values come from
+pub enum HeatmapSource {
+    /// One of the game's own map modes, colored by its own rules
+    MapMode(MapMode),
+    /// Read from each province's data when the heatmap is drawn
+    Province(Box<dyn Fn(&ProvinceData) -> f32 + Send + Sync>),
+    /// Published by a system, one value per province in entity order
+    Published(Vec<f32>),
+}
+
+/// Provinces a heatmap draws striped over its heat, and what the stripes mean
+pub struct HeatmapHatching {
+    pub label: String,
+    /// One flag per province in entity order
+    pub provinces: Vec<bool>,
+}
+
+/// A registered heatmap and the values of its last draw
+pub struct Heatmap {
+    pub name: String,
+    /// Mod that declared it, `None` for the game's own
+    pub owner: Option<String>,
+    source: HeatmapSource,
+    hatching: Option<HeatmapHatching>,
+    /// Values scaled to 0..1, one per province
+    scaled: Vec<f32>,
+    /// Smallest and largest value on the map at the last draw
+    range: Option<(f32, f32)>,
+}
+
+impl Heatmap {
+    /// Smallest and largest value on the map, `None` until drawn or when nothing has a value
+    pub fn range(&self) -> Option<(f32, f32)> {
+        self.range
+    }
+
+    /// The province's value scaled to 0..1 between the map's extremes
+    pub fn scaled(&self, index: usize) -> f32 {
+        self.scaled.get(index).copied().unwrap_or(0.0)
+    }
+
+    /// Whether the province is drawn striped
+    pub fn is_hatched(&self, index: usize) -> bool {
+        self.hatching
+            .as_ref()
+            .is_some_and(|hatching| hatching.provinces.get(index).copied().unwrap_or(false))
+    }
+
+    /// What the stripes mean, for the legend
+    pub fn hatch_label(&self) -> Option<&str> {
+        self.hatching.as_ref().map(|hatching| hatching.label.as_str())
+    }
+
+    /// The game map mode this entry stands for, `None` for metric heatmaps
+    pub fn built_in_mode(&self) -> Option<MapMode> {
+        match self.source {
+            HeatmapSource::MapMode(mode) => Some(mode),
+            _ => None,
+        }
+    }
+}
+
+/// Every map mode and heatmap that can be shown, indexed by id
+///
+/// Ids are never reused: a removed heatmap leaves its slot empty, and only a
+/// heatmap registered again under the same name gets that id back. Ids
+/// already handed out, such as the current map mode, never point at a
+/// different heatmap.
+#[derive(Resource, Default)]
+pub struct HeatmapRegistry {
+    heatmaps: Vec<Option<Heatmap>>,
+    /// Name each id was handed out for, kept after removal
+    names: Vec<String>,
+}
+
+impl HeatmapRegistry {
+    /// Register a heatmap, replacing the source of one with the same name
+    pub fn register(&mut self, name: impl Into<String>, source: HeatmapSource) -> HeatmapId {
+        self.insert(name.into(), None, source)
+    }
+
+    /// Register one of the game's own map modes under its display name
+    pub fn register_map_mode(&mut self, mode: MapMode) -> HeatmapId {
+        self.insert(mode.display_name().to_string(), None, HeatmapSource::MapMode(mode))
+    }
+
+    fn insert(&mut self, name: String, owner: Option<String>, source: HeatmapSource) -> HeatmapId {
+        let slot = self.names.iter().position(|existing| *existing == name);
+        if let Some(slot) = slot {
+            let is_map_mode = self.heatmaps[slot]
+                .as_ref()
+                .is_some_and(|existing| existing.built_in_mode().is_some());
+            // A metric or mod cannot take over one of the game's own map modes
+            if is_map_mode && !matches!(source, HeatmapSource::MapMode(_)) {
+                warn!("Heatmap '{}' shares its name with a map mode and was not registered", name);
+                return HeatmapId(slot as u16);
+            }
+        }
+        let heatmap = Heatmap {
+            name,
+            owner,
+            source,
+            hatching: None,
+            scaled: Vec::new(),
+            range: None,
+        };
+        match slot {
+            Some(slot) => {
+                self.heatmaps[slot] = Some(heatmap);
+                HeatmapId(slot as u16)
+            }
+            None => {
+                self.names.push(heatmap.name.clone());
+                self.heatmaps.push(Some(heatmap));
+                HeatmapId((self.heatmaps.len() - 1) as u16)
+            }
+        }
+    }
+
+    /// Replace the values of a published heatmap
+    pub fn publish(&mut self, id: HeatmapId, values: Vec<f32>) {
+        if let Some(Some(heatmap)) = self.heatmaps.get_mut(id.0 as usize) {
+            heatmap.source = HeatmapSource::Published(values);
+        }
+    }
+
+    /// Replace which provinces a heatmap hatches, one flag per province in entity order
+    pub fn publish_hatching(&mut self, id: HeatmapId, label: impl Into<String>, provinces: Vec<bool>) {
+        if let Some(Some(heatmap)) = self.heatmaps.get_mut(id.0 as usize) {
+            heatmap.hatching = Some(HeatmapHatching {
+                label: label.into(),
+                provinces,
+            });
+        }
+    }
+
+    pub fn get(&self, id: HeatmapId) -> Option<&Heatmap> {
+        self.heatmaps.get(id.0 as usize).and_then(Option::as_ref)
+    }
+
+    pub fn id_of(&self, name: &str) -> Option<HeatmapId> {
+        self.iter().find(|(_, heatmap)| heatmap.name == name).map(|(id, _)| id)
+    }
+
+    /// Registered heatmaps in id order
+    pub fn iter(&self) -> impl Iterator<Item = (HeatmapId, &Heatmap)> {
+        self.heatmaps
+            .iter()
+            .enumerate()
+            .filter_map(|(index, heatmap)| heatmap.as_ref().map(|heatmap| (HeatmapId(index as u16), heatmap)))
+    }
+
+    /// Map mode that shows a registered entry, `None` once it is removed
+    pub fn mode(&self, id: HeatmapId) -> Option<MapMode> {
+        self.get(id)
+            .map(|heatmap| heatmap.built_in_mode().unwrap_or(MapMode::Heatmap(id)))
+    }
+
+    /// Name to show for a map mode, the heatmap's own name for heatmaps
+    pub fn mode_name(&self, mode: MapMode) -> &str {
+        match mode {
+            MapMode::Heatmap(id) => self
+                .get(id)
+                .map_or(mode.display_name(), |heatmap| heatmap.name.as_str()),
+            _ => mode.display_name(),
+        }
+    }
+
+    /// Read and scale a heatmap's values for drawing
+    fn rescale(
+        &mut self,
+        id: HeatmapId,
+        province_entity_order: &ProvinceEntityOrder,
+        province_data_query: &Query<&ProvinceData>,
+    ) {
+        let Some(Some(heatmap)) = self.heatmaps.get_mut(id.0 as usize) else {
+            return;
+        };
+        let values: Vec<f32> = (0..province_entity_order.len())
+            .map(|index| {
+                let data = province_entity_order
+                    .get(index)
+                    .and_then(|entity| province_data_query.get(entity).ok());
+                // Oceans keep their terrain color and would drag the scale down
+                match data {
+                    Some(data) if data.terrain == TerrainType::Ocean => f32::NAN,
+                    Some(data) => match &heatmap.source {
+                        HeatmapSource::MapMode(_) => f32::NAN,
+                        HeatmapSource::Province(read) => read(data),
+                        HeatmapSource::Published(values) => values.get(index).copied().unwrap_or(f32::NAN),
+                    },
+                    None => f32::NAN,
+                }
+            })
+            .collect();
+        let (scaled, range) = auto_scale(&values);
+        heatmap.scaled = scaled;
+        heatmap.range = range;
+    }
+}
+
+/// How saves record a map mode: a heatmap as the political map plus its name
+pub fn saved_map_mode(mode: MapMode, registry: Option<&HeatmapRegistry>) -> (MapMode, Option<String>) {
+    match mode {
+        MapMode::Heatmap(id) => (
+            MapMode::Political,
+            registry.and_then(|registry| registry.get(id)).map(|heatmap| heatmap.name.clone()),
+        ),
+        mode => (mode, None),
+    }
+}
+
+/// The map mode a save recorded, finding its heatmap by name
+///
+/// A heatmap that is no longer registered, or one saved by id before saves
+/// recorded names, falls back to the political map.
+pub fn restored_map_mode(mode: MapMode, heatmap: Option<&str>, registry: Option<&HeatmapRegistry>) -> MapMode {
+    let Some(name) = heatmap else {
+        return match mode {
+            MapMode::Heatmap(_) => MapMode::Political,
+            mode => mode,
+        };
+    };
+    registry
+        .and_then(|registry| registry.id_of(name).and_then(|id| registry.mode(id)))
+        .unwrap_or(MapMode::Political)
+}
+
+/// Scale values to 0..1 between the smallest and largest finite value
+///
+/// Missing (non-finite) values scale to 0. When every value is the same the
+/// whole map sits mid-ramp rather than reading as all low.
+pub fn auto_scale(values: &[f32]) -> (Vec<f32>, Option<(f32, f32)>) {
+    let range =
+        values
+            .iter()
+            .copied()
+            .filter(|value| value.is_finite())
+            .fold(None, |range: Option<(f32, f32)>, value| match range {
+                Some((min, max)) => Some((min.min(value), max.max(value))),
+                None => Some((value, value)),
+            });
+    let Some((min, max)) = range else {
+        return (vec![0.0; values.len()], None);
+    };
+    let span = max - min;
+    let scaled = values
+        .iter()
+        .map(|&value| {
+            if !value.is_finite() {
+                0.0
+            } else if span <= f32::EPSILON {
+                0.5
+            } else {
+                (value - min) / span
+            }
+        })
+        .collect();
+    (scaled, range)
+}
+
+/// Heat ramp from cold blue (0) through yellow to red (1)
+pub fn heat_ramp(value: f32) -> Color {
+    let value = value.clamp(0.0, 1.0);
+    if value < 0.5 {
+        let t = value * 2.0;
+        Color::srgb(0.1 + 0.8 * t, 0.15 + 0.7 * t, 0.35 - 0.25 * t)
+    } else {
+        let t = (value - 0.5) * 2.0;
+        Color::srgb(0.9, 0.85 - 0.7 * t, 0.1)
+    }
+}
+
+/// The game's own map modes in menu order, then its heatmaps for metrics without a mode
+pub fn register_builtin_heatmaps(mut registry: ResMut<HeatmapRegistry>) {
+    let modes = [
+        MapMode::Political,
+        MapMode::Terrain,
+        MapMode::Climate,
+        MapMode::Population,
+        MapMode::Agriculture,
+        MapMode::Infrastructure,
+        MapMode::Minerals,
+        MapMode::Military,
+        MapMode::HistoricalBorders,
+        MapMode::Cores,
+        MapMode::Devastation,
+        MapMode::RefugeeFlows,
+        MapMode::ThreatInfluence,
+        MapMode::EconomicInfluence,
+        MapMode::CulturalInfluence,
+    ];
+    for mode in modes {
+        registry.register_map_mode(mode);
+    }
+    // Comparing believed and true statistics is a development aid
+    if cfg!(debug_assertions) {
+        registry.register_map_mode(MapMode::CensusError);
+    }
+
+    let metric = ProvinceMetric::MaxPopulation;
+    registry.register(
+        "Carrying Capacity",
+        HeatmapSource::Province(Box::new(move |data| metric.read(data))),
+    );
+    let metric = ProvinceMetric::FreshWaterDistance;
+    registry.register(
+        "Distance to Fresh Water",
+        HeatmapSource::Province(Box::new(move |data| metric.read(data))),
+    );
+}
+
+/// Register the heatmaps enabled mods declare and drop those of disabled mods
+pub fn sync_mod_heatmaps(mod_manager: Res<ModManager>, mut registry: ResMut<HeatmapRegistry>) {
+    if !mod_manager.is_changed() {
+        return;
+    }
+    let enabled: Vec<_> = mod_manager
+        .available_mods
+        .iter()
+        .filter(|loaded_mod| loaded_mod.enabled)
+        .collect();
+    for slot in &mut registry.heatmaps {
+        let stale = slot.as_ref().is_some_and(|heatmap| {
+            heatmap.owner.as_ref().is_some_and(|owner| {
+                !enabled.iter().any(|loaded_mod| {
+                    loaded_mod.manifest.id == *owner
+                        && loaded_mod
+                            .manifest
+                            .heatmaps
+                            .iter()
+                            .any(|definition| definition.name == heatmap.name)
+                })
+            })
+        });
+        if stale {
+            *slot = None;
+        }
+    }
+    for loaded_mod in enabled {
+        for definition in &loaded_mod.manifest.heatmaps {
+            let metric = definition.metric;
+            registry.insert(
+                definition.name.clone(),
+                Some(loaded_mod.manifest.id.clone()),
+                HeatmapSource::Province(Box::new(move |data| metric.read(data))),
+            );
+        }
+    }
+}
+
+/// Rescale the heatmap on show when it is picked, its values are published, or a year passes
+///
+/// A heatmap that is no longer registered, such as one from a disabled mod
+/// or a save made with other mods, falls back to the political map.
+pub fn refresh_active_heatmap(
+    mut map_mode: ResMut<MapMode>,
+    mut registry: ResMut<HeatmapRegistry>,
+    mut year_events: MessageReader<NewYearEvent>,
+    province_entity_order: Option<Res<ProvinceEntityOrder>>,
+    province_data_query: Query<&ProvinceData>,
+) {
+    let new_year = year_events.read().last().is_some();
+    let MapMode::Heatmap(id) = *map_mode else {
+        return;
+    };
+    match registry.mode(id) {
+        Some(MapMode::Heatmap(_)) => {}
+        Some(mode) => {
+            *map_mode = mode;
+            return;
+        }
+        None => {
+            *map_mode = MapMode::Political;
+            return;
+        }
+    }
+    if !map_mode.is_changed() && !registry.is_changed() && !new_year {
+        return;
+    }
+    let Some(province_entity_order) = province_entity_order else {
+        return;
+    };
+    // Scaling is bookkeeping, not a new publication to react to
+    registry
+        .bypass_change_detection()
+        .rescale(id, &province_entity_order, &province_data_query);
+    map_mode.set_changed();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_scale_between_the_map_extremes() {
+        let (scaled, range) = auto_scale(&[10.0, 30.0, f32::NAN, 20.0]);
+        assert_eq!(range, Some((10.0, 30.0)));
+        assert_eq!(scaled, vec![0.0, 1.0, 0.0, 0.5]);
+
+        // A flat map sits mid-ramp, an empty one has no range
+        assert_eq!(auto_scale(&[4.0, 4.0]).0, vec![0.5, 0.5]);
+        assert_eq!(auto_scale(&[f32::INFINITY]), (vec![0.0], None));
+
+    }
+
+    #[test]
+    fn registry_never_hands_out_an_id_twice() {
+        let mut registry = HeatmapRegistry::default();
+        let political = registry.register_map_mode(MapMode::Political);
+        let first = registry.register("Wealth", HeatmapSource::Published(Vec::new()));
+        let second = registry.register("Piety", HeatmapSource::Published(Vec::new()));
+        assert_eq!(registry.mode(political), Some(MapMode::Political));
+        assert_eq!(registry.mode(second), Some(MapMode::Heatmap(second)));
+
+        // Re-registering a name keeps its id, even after removal; freed ids stay empty
+        assert_eq!(registry.register("Wealth", HeatmapSource::Published(vec![1.0])), first);
+        registry.heatmaps[first.0 as usize] = None;
+        let unrest = registry.register("Unrest", HeatmapSource::Published(Vec::new()));
+        assert_ne!(unrest, first);
+        assert_eq!(registry.mode(first), None);
+        assert_eq!(registry.register("Wealth", HeatmapSource::Published(Vec::new())), first);
+        assert_eq!(registry.mode_name(MapMode::Heatmap(second)), "Piety");
+
+        // A metric cannot take over a game map mode's entry
+        registry.register("Political Map", HeatmapSource::Published(Vec::new()));
+        assert_eq!(registry.mode(political), Some(MapMode::Political));
+    }
+
+    #[test]
+    fn published_hatching_marks_provinces() {
+        let mut registry = HeatmapRegistry::default();
+        let id = registry.register("Disputes", HeatmapSource::Published(vec![1.0, 2.0]));
+        registry.publish_hatching(id, "Striped: at war", vec![false, true]);
+        let hatched = registry
+            .get(id)
+            .map(|heatmap| (heatmap.is_hatched(0), heatmap.is_hatched(1), heatmap.is_hatched(5)));
+        assert_eq!(hatched, Some((false, true, false)));
+        assert_eq!(registry.get(id).and_then(Heatmap::hatch_label), Some("Striped: at war"));
+    }
+
+    #[test]
+    fn saves_record_heatmaps_by_name() {
+        let mut registry = HeatmapRegistry::default();
+        registry.register("Wealth", HeatmapSource::Published(Vec::new()));
+        let piety = registry.register("Piety", HeatmapSource::Published(Vec::new()));
+
+        let saved = saved_map_mode(MapMode::Heatmap(piety), Some(&registry));
+        assert_eq!(saved, (MapMode::Political, Some("Piety".to_string())));
+        assert_eq!(saved_map_mode(MapMode::Terrain, Some(&registry)), (MapMode::Terrain, None));
+
+        // A later session hands out different ids
+        let mut later = HeatmapRegistry::default();
+        let piety_later = later.register("Piety", HeatmapSource::Published(Vec::new()));
+        assert_eq!(
+            restored_map_mode(saved.0, saved.1.as_deref(), Some(&later)),
+            MapMode::Heatmap(piety_later)
+        );
+        assert_eq!(restored_map_mode(MapMode::Political, Some("Wealth"), Some(&later)), MapMode::Political);
+        // Saves from before names were recorded hold a bare id
+        assert_eq!(restored_map_mode(MapMode::Heatmap(piety), None, Some(&later)), MapMode::Political);
+        assert_eq!(restored_map_mode(MapMode::Cores, None, None), MapMode::Cores);
+    }
+}
@@ -0,0 +1,325 @@
+//! Historical border snapshots for the Historical Borders map mode
+//!
+//! A rolling buffer of yearly ownership snapshots lets observers scrub back
+//! through time and see how empires expanded and collapsed. Snapshots are
+//! run-length encoded (neighbouring provinces almost always share an owner),
+//! and each one carries its own palette so nations that have since been
+//! destroyed still render with their original name and color.
+
+use crate::nations::Nation;
+use crate::relationships::Controls;
+use crate::simulation::{GameTime, NewYearEvent};
+use crate::world::{MapMode, ProvinceEntityOrder};
+use bevy::log::debug;
+use bevy::prelude::*;
+use std::collections::{HashMap, VecDeque};
+
+/// Years between recorded snapshots
+const DEFAULT_SNAPSHOT_INTERVAL_YEARS: u32 = 5;
+
+/// Maximum snapshots kept before the oldest are dropped
+const DEFAULT_MAX_SNAPSHOTS: usize = 400;
+
+/// Palette marker for provinces without an owner
+const UNOWNED: u16 = u16::MAX;
+
+/// A nation as it existed when a snapshot was taken
+#[derive(Debug, Clone)]
+pub struct HistoricalNation {
+    pub entity: Entity,
+    pub name: String,
+    pub color: Color,
+}
+
+/// Province ownership at a single point in time
+#[derive(Debug, Clone)]
+pub struct BorderSnapshot {
+    /// Year the snapshot was taken
+    pub year: u32,
+    /// Nations that owned territory at this date
+    pub palette: Vec<HistoricalNation>,
+    /// Run-length encoded palette indices in province order
+    runs: Vec<(u16, u32)>,
+}
+
+impl BorderSnapshot {
+    /// Encode ownership (indexed by province order) into a snapshot
+    pub fn encode(year: u32, owners: &[Option<u16>], palette: Vec<HistoricalNation>) -> Self {
+        let mut runs: Vec<(u16, u32)> = Vec::new();
+        for owner in owners {
+            let value = owner.unwrap_or(UNOWNED);
+            match runs.last_mut() {
+                Some((last, count)) if *last == value => *count += 1,
+                _ => runs.push((value, 1)),
+            }
+        }
+
+        Self {
+            year,
+            palette,
+            runs,
+        }
+    }
+
+    /// Decode to one palette index per province
+    pub fn decode(&self) -> Vec<Option<u16>> {
+        let mut owners = Vec::with_capacity(self.province_count());
+        for &(value, count) in &self.runs {
+            let owner = (value != UNOWNED).then_some(value);
+            owners.extend(std::iter::repeat_n(owner, count as usize));
+        }
+        owners
+    }
+
+    /// Number of provinces covered by this snapshot
+    pub fn province_count(&self) -> usize {
+        self.runs.iter().map(|&(_, count)| count as usize).sum()
+    }
+
+    /// Look up a palette entry
+    pub fn nation(&self, index: u16) -> Option<&HistoricalNation> {
+        self.palette.get(index as usize)
+    }
+}
+
+/// Rolling buffer of border snapshots for time-lapse playback
+#[derive(Resource, Debug)]
+pub struct BorderHistory {
+    snapshots: VecDeque<BorderSnapshot>,
+    pub snapshot_interval_years: u32,
+    pub max_snapshots: usize,
+    last_recorded_year: Option<u32>,
+}
+
+impl Default for BorderHistory {
+    fn default() -> Self {
+        Self {
+            snapshots: VecDeque::new(),
+            snapshot_interval_years: DEFAULT_SNAPSHOT_INTERVAL_YEARS,
+            max_snapshots: DEFAULT_MAX_SNAPSHOTS,
+            last_recorded_year: None,
+        }
+    }
+}
+
+impl BorderHistory {
+    /// Add a snapshot, dropping the oldest when the buffer is full
+    pub fn push(&mut self, snapshot: BorderSnapshot) {
+        self.last_recorded_year = Some(snapshot.year);
+        self.snapshots.push_back(snapshot);
+        while self.snapshots.len() > self.max_snapshots {
+            self.snapshots.pop_front();
+        }
+    }
+
+    /// The latest snapshot taken at or before `year`
+    pub fn snapshot_at(&self, year: u32) -> Option<&BorderSnapshot> {
+        self.snapshots
+            .iter()
+            .rev()
+            .find(|snapshot| snapshot.year <= year)
+            .or_else(|| self.snapshots.front())
+    }
+
+    /// Earliest and latest recorded years
+    pub fn year_range(&self) -> Option<(u32, u32)> {
+        Some((self.snapshots.front()?.year, self.snapshots.back()?.year))
+    }
+
+    /// Number of stored snapshots
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    /// Check if no snapshots have been recorded yet
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    /// Whether a snapshot is due for this year
+    fn is_due(&self, year: u32) -> bool {
+        self.last_recorded_year
+            .is_none_or(|last| year >= last + self.snapshot_interval_years)
+    }
+
+    /// Drop all snapshots (new world or loaded save)
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+        self.last_recorded_year = None;
+    }
+}
+
+/// Date selection for the Historical Borders map mode
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HistoricalBordersView {
+    /// Year being viewed
+    pub year: u32,
+    /// Blend historical borders over current ones instead of replacing them
+    pub ghost_current: bool,
+}
+
+impl Default for HistoricalBordersView {
+    fn default() -> Self {
+        Self {
+            year: crate::constants::SIMULATION_STARTING_YEAR,
+            ghost_current: true,
+        }
+    }
+}
+
+impl HistoricalBordersView {
+    /// Move the viewed year, clamped to the recorded history
+    pub fn step(&mut self, years: i32, history: &BorderHistory) {
+        let Some((first, last)) = history.year_range() else {
+            return;
+        };
+        let target = (self.year as i64 + years as i64).clamp(first as i64, last as i64);
+        self.year = target as u32;
+    }
+}
+
+/// Capture current province ownership as a snapshot
+fn capture_snapshot(
+    year: u32,
+    entity_order: &ProvinceEntityOrder,
+    nations_query: &Query<(Entity, &Nation, Option<&Controls>)>,
+) -> BorderSnapshot {
+    let index_by_entity: HashMap<Entity, usize> = entity_order
+        .entities
+        .iter()
+        .enumerate()
+        .map(|(idx, &entity)| (entity, idx))
+        .collect();
+
+    let mut owners = vec![None; entity_order.len()];
+    let mut palette = Vec::new();
+
+    for (nation_entity, nation, controls) in nations_query.iter() {
+        let Some(controls) = controls.filter(|c| c.has_provinces()) else {
+            continue;
+        };
+        let Ok(palette_index) = u16::try_from(palette.len()) else {
+            break;
+        };
+        palette.push(HistoricalNation {
+            entity: nation_entity,
+            name: nation.name.clone(),
+            color: nation.color,
+        });
+        for province_entity in controls.provinces() {
+            if let Some(&idx) = index_by_entity.get(province_entity) {
+                owners[idx] = Some(palette_index);
+            }
+        }
+    }
+
+    BorderSnapshot::encode(year, &owners, palette)
+}
+
+/// Forget the previous world's borders once a new world has loaded
+pub fn clear_border_history(mut history: ResMut<BorderHistory>) {
+    history.clear();
+}
+
+/// Record the opening borders as soon as the world is running
+pub fn record_initial_border_snapshot(
+    mut history: ResMut<BorderHistory>,
+    mut view: ResMut<HistoricalBordersView>,
+    game_time: Res<GameTime>,
+    province_entity_order: Option<Res<ProvinceEntityOrder>>,
+    nations_query: Query<(Entity, &Nation, Option<&Controls>)>,
+) {
+    let Some(entity_order) = province_entity_order else {
+        return;
+    };
+
+    let year = game_time.current_year();
+    if history.year_range().is_some_and(|(_, last)| last >= year) {
+        return;
+    }
+
+    history.push(capture_snapshot(year, &entity_order, &nations_query));
+    view.year = year;
+}
+
+/// Record a snapshot every few simulated years
+pub fn record_border_snapshots(
+    mut year_events: MessageReader<NewYearEvent>,
+    mut history: ResMut<BorderHistory>,
+    province_entity_order: Option<Res<ProvinceEntityOrder>>,
+    nations_query: Query<(Entity, &Nation, Option<&Controls>)>,
+) {
+    let Some(year) = year_events.read().map(|event| event.year).last() else {
+        return;
+    };
+    if !history.is_due(year) {
+        return;
+    }
+    let Some(entity_order) = province_entity_order else {
+        return;
+    };
+
+    history.push(capture_snapshot(year, &entity_order, &nations_query));
+    debug!(
+        "Recorded border snapshot for year {} ({} stored)",
+        year,
+        history.len()
+    );
+}
+
+/// Recolor the map when the viewed date or blend mode changes
+pub fn refresh_historical_borders_on_view_change(
+    view: Res<HistoricalBordersView>,
+    mut map_mode: ResMut<MapMode>,
+) {
+    if view.is_changed() && *map_mode == MapMode::HistoricalBorders {
+        map_mode.set_changed();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nation(index: u32) -> HistoricalNation {
+        HistoricalNation {
+            entity: Entity::PLACEHOLDER,
+            name: format!("Nation {index}"),
+            color: Color::WHITE,
+        }
+    }
+
+    #[test]
+    fn snapshot_round_trips_ownership() {
+        let owners = vec![Some(0), Some(0), None, Some(1), Some(1), Some(1), None];
+        let snapshot = BorderSnapshot::encode(1000, &owners, vec![nation(1), nation(2)]);
+
+        assert_eq!(snapshot.province_count(), owners.len());
+        assert_eq!(snapshot.decode(), owners);
+    }
+
+    #[test]
+    fn history_finds_latest_snapshot_before_year() {
+        let mut history = BorderHistory::default();
+        history.push(BorderSnapshot::encode(1000, &[Some(0)], vec![nation(1)]));
+        history.push(BorderSnapshot::encode(1005, &[None], Vec::new()));
+
+        assert_eq!(history.snapshot_at(1003).map(|s| s.year), Some(1000));
+        assert_eq!(history.snapshot_at(1010).map(|s| s.year), Some(1005));
+        assert_eq!(history.snapshot_at(900).map(|s| s.year), Some(1000));
+    }
+
+    #[test]
+    fn history_drops_oldest_when_full() {
+        let mut history = BorderHistory {
+            max_snapshots: 2,
+            ..Default::default()
+        };
+        for year in [1000, 1005, 1010] {
+            history.push(BorderSnapshot::encode(year, &[None], Vec::new()));
+        }
+
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.year_range(), Some((1005, 1010)));
+    }
+}
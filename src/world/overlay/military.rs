@@ -0,0 +1,560 @@
+//! Military supply and attrition overlay data
+//!
+//! Supply flows outward from each nation's capital, and from its stocked
+//! forward depots, across the provinces it controls, losing strength with
+//! distance and rough terrain. Better supply planning carries it further. Provinces where
+//! supply runs thin, or that face an enemy across an active front, are marked
+//! as attrition zones. The overlay cache reads this storage to shade the map,
+//! and army strength, fort, and depot badges are spawned while the Military
+//! map mode is active.
+
+use crate::math::HEX_SIZE;
+use crate::nations::{Attacking, FortConstruction, Logistics, Nation, SupplyDepot};
+use crate::relationships::{Army, Controls, Fortification, StationedIn};
+use crate::world::{AttritionProfiles, MapMode, ProvinceEntityOrder, ProvinceStorage, TerrainType};
+use bevy::log::debug;
+use bevy::prelude::*;
+use bevy::sprite::Text2d;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+/// Supply budget a fully stable nation can push away from its capital
+/// (in supply cost units, where open terrain costs 10 per province)
+const BASE_SUPPLY_RANGE: u32 = 240;
+
+/// Supply below this level causes attrition for armies operating there
+pub const ATTRITION_SUPPLY_THRESHOLD: f32 = 0.2;
+
+/// Supply below this level causes attrition in harsh terrain
+const HARSH_TERRAIN_SUPPLY_THRESHOLD: f32 = 0.5;
+
+/// Combined terrain attrition at which terrain counts as harsh
+const HARSH_TERRAIN_HAZARD: f32 = 0.15;
+
+/// Seconds between supply recalculations while the overlay is visible
+const SUPPLY_REFRESH_INTERVAL_SECS: f32 = 2.0;
+
+/// Z-index for army badges (above provinces, below the selection border)
+const ARMY_BADGE_Z_INDEX: f32 = 50.0;
+
+/// Font size for army strength badges
+const ARMY_BADGE_FONT_SIZE: f32 = HEX_SIZE * 0.5;
+
+/// Font size for fort badges
+const FORT_BADGE_FONT_SIZE: f32 = HEX_SIZE * 0.35;
+
+/// Offset of fort badges below the province center, clear of army badges
+const FORT_BADGE_OFFSET: f32 = HEX_SIZE * 0.5;
+
+/// Offset of depot badges above the province center, clear of army badges
+const DEPOT_BADGE_OFFSET: f32 = HEX_SIZE * 0.5;
+
+/// Per-province supply state backing the Military map mode
+///
+/// All vectors are indexed by province storage index, which matches the
+/// mesh vertex order in `ProvinceEntityOrder`.
+#[derive(Resource)]
+pub struct MilitarySupplyStorage {
+    /// Supply level of the owning nation (0.0 = none, 1.0 = full)
+    pub supply: Vec<f32>,
+    /// Whether armies in this province suffer attrition
+    pub attrition: Vec<bool>,
+    /// Owning nation of each province
+    pub owners: Vec<Option<Entity>>,
+    /// Throttles recalculation while the overlay is open
+    refresh_timer: Timer,
+    /// Forces a recalculation on the next refresh pass
+    dirty: bool,
+}
+
+impl Default for MilitarySupplyStorage {
+    fn default() -> Self {
+        Self {
+            supply: Vec::new(),
+            attrition: Vec::new(),
+            owners: Vec::new(),
+            refresh_timer: Timer::from_seconds(SUPPLY_REFRESH_INTERVAL_SECS, TimerMode::Repeating),
+            dirty: true,
+        }
+    }
+}
+
+impl MilitarySupplyStorage {
+    /// Supply level at a province index (0.0 when unknown)
+    pub fn supply_at(&self, index: usize) -> f32 {
+        self.supply.get(index).copied().unwrap_or(0.0)
+    }
+
+    /// Whether a province index is an attrition zone
+    pub fn is_attrition_zone(&self, index: usize) -> bool {
+        self.attrition.get(index).copied().unwrap_or(false)
+    }
+
+    /// Owner of a province index
+    pub fn owner_at(&self, index: usize) -> Option<Entity> {
+        self.owners.get(index).copied().flatten()
+    }
+
+    /// Request a recalculation on the next refresh pass
+    pub fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+}
+
+/// Restricts the Military overlay to a single nation
+///
+/// Follows the selected nation: clicking a nation while in Military mode
+/// focuses the overlay on its supply network and armies.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MilitaryOverlayFilter {
+    pub nation: Option<Entity>,
+}
+
+impl MilitaryOverlayFilter {
+    /// Whether a province owner passes the filter
+    pub fn includes(&self, owner: Option<Entity>) -> bool {
+        match self.nation {
+            Some(nation) => owner == Some(nation),
+            None => true,
+        }
+    }
+}
+
+/// Marker for army strength badges on the map
+#[derive(Component)]
+pub struct ArmyBadge;
+
+/// Marker for fort badges on the map
+#[derive(Component)]
+pub struct FortBadge;
+
+/// Marker for supply depot badges on the map
+#[derive(Component)]
+pub struct DepotBadge;
+
+/// Cost of pushing supply through a province of this terrain
+fn supply_cost(terrain: TerrainType) -> u32 {
+    match terrain {
+        TerrainType::Alpine => 30,
+        TerrainType::PolarDesert
+        | TerrainType::Tundra
+        | TerrainType::ColdDesert
+        | TerrainType::SubtropicalDesert
+        | TerrainType::TropicalDesert => 25,
+        TerrainType::Wetlands | TerrainType::Mangrove | TerrainType::TropicalRainforest => 20,
+        TerrainType::Taiga
+        | TerrainType::BorealForest
+        | TerrainType::TemperateRainforest
+        | TerrainType::TemperateDeciduousForest
+        | TerrainType::TropicalSeasonalForest => 15,
+        _ => 10,
+    }
+}
+
+/// Supply budget a nation can project from its capital or a depot
+fn supply_range(nation: &Nation, logistics: Option<&Logistics>) -> u32 {
+    let stability = nation.stability.clamp(0.0, 1.0);
+    let planning = logistics.map_or(0.5, |logistics| logistics.planning);
+    (BASE_SUPPLY_RANGE as f32 * (0.5 + 0.5 * stability) * (0.75 + 0.5 * planning)) as u32
+}
+
+/// Keep the filter in sync with the selected nation
+pub fn sync_military_filter_with_selection(
+    selected_nation: Res<crate::ui::SelectedNation>,
+    mut filter: ResMut<MilitaryOverlayFilter>,
+    mut storage: ResMut<MilitarySupplyStorage>,
+) {
+    if filter.nation != selected_nation.entity {
+        filter.nation = selected_nation.entity;
+        storage.mark_dirty();
+    }
+}
+
+/// Recalculate supply reach and attrition zones while the Military overlay is open
+pub fn refresh_military_supply(
+    time: Res<Time>,
+    mut storage: ResMut<MilitarySupplyStorage>,
+    mut map_mode: ResMut<MapMode>,
+    mut was_active: Local<bool>,
+    province_storage: Option<Res<ProvinceStorage>>,
+    province_entity_order: Option<Res<ProvinceEntityOrder>>,
+    nations_query: Query<(Entity, &Nation, Option<&Controls>, Option<&Logistics>)>,
+    depots_query: Query<(&SupplyDepot, &StationedIn)>,
+    attacking_query: Query<(Entity, &Attacking)>,
+    attrition_profiles: Option<Res<AttritionProfiles>>,
+) {
+    if *map_mode != MapMode::Military {
+        *was_active = false;
+        return;
+    }
+
+    // Always recalculate when the overlay is first opened
+    if !*was_active {
+        *was_active = true;
+        storage.mark_dirty();
+    }
+
+    storage.refresh_timer.tick(time.delta());
+    if !storage.dirty && !storage.refresh_timer.just_finished() {
+        return;
+    }
+
+    let (Some(province_storage), Some(entity_order)) = (province_storage, province_entity_order)
+    else {
+        return;
+    };
+
+    let province_count = province_storage.provinces.len();
+    let index_by_entity: HashMap<Entity, usize> = entity_order
+        .entities
+        .iter()
+        .enumerate()
+        .map(|(idx, &entity)| (entity, idx))
+        .collect();
+
+    // Ownership from the Controls relationship (authoritative at runtime)
+    let mut owners = vec![None; province_count];
+    for (nation_entity, _, controls, _) in &nations_query {
+        let Some(controls) = controls else { continue };
+        for province_entity in controls.provinces() {
+            if let Some(&idx) = index_by_entity.get(province_entity) {
+                if idx < province_count {
+                    owners[idx] = Some(nation_entity);
+                }
+            }
+        }
+    }
+
+    // Stocked depots held by their owners are sources of supply
+    let mut depot_sources: HashMap<Entity, Vec<usize>> = HashMap::new();
+    for (depot, stationed_in) in &depots_query {
+        if let Some(&idx) = index_by_entity.get(&stationed_in.0) {
+            if depot.stock > 0.0 && owners.get(idx).copied().flatten() == Some(depot.owner) {
+                depot_sources.entry(depot.owner).or_default().push(idx);
+            }
+        }
+    }
+
+    // Supply spreads from each capital and depot through the nation's own provinces
+    let mut supply = vec![0.0f32; province_count];
+    for (nation_entity, nation, _, logistics) in &nations_query {
+        let mut sources = depot_sources.remove(&nation_entity).unwrap_or_default();
+        // A lost capital leaves the nation fighting on from its depots
+        if let Some(&capital_idx) = province_storage.province_by_id.get(&nation.capital_province) {
+            if owners.get(capital_idx).copied().flatten() == Some(nation_entity) {
+                sources.push(capital_idx);
+            }
+        }
+        if sources.is_empty() {
+            continue;
+        }
+
+        let range = supply_range(nation, logistics);
+        let mut best_cost: HashMap<usize, u32> = HashMap::new();
+        let mut frontier = BinaryHeap::new();
+        for idx in sources {
+            best_cost.insert(idx, 0);
+            frontier.push(Reverse((0u32, idx)));
+        }
+
+        while let Some(Reverse((cost, idx))) = frontier.pop() {
+            if best_cost.get(&idx).is_some_and(|&known| cost > known) {
+                continue;
+            }
+            supply[idx] = 1.0 - cost as f32 / range as f32;
+
+            for neighbor_id in province_storage.provinces[idx].neighbors.iter().flatten() {
+                let neighbor_idx = neighbor_id.value() as usize;
+                if neighbor_idx >= province_count || owners[neighbor_idx] != Some(nation_entity) {
+                    continue;
+                }
+                let next_cost = cost + supply_cost(province_storage.provinces[neighbor_idx].terrain);
+                if next_cost >= range {
+                    continue;
+                }
+                if best_cost.get(&neighbor_idx).is_none_or(|&known| next_cost < known) {
+                    best_cost.insert(neighbor_idx, next_cost);
+                    frontier.push(Reverse((next_cost, neighbor_idx)));
+                }
+            }
+        }
+    }
+
+    // Active fronts: provinces bordering a nation we are at war with
+    let enemy_pairs: HashSet<(Entity, Entity)> = attacking_query
+        .iter()
+        .flat_map(|(attacker, attacking)| [(attacker, attacking.0), (attacking.0, attacker)])
+        .collect();
+
+    let attrition: Vec<bool> = (0..province_count)
+        .map(|idx| {
+            let Some(owner) = owners[idx] else {
+                return false;
+            };
+            if supply[idx] < ATTRITION_SUPPLY_THRESHOLD {
+                return true;
+            }
+            // Deserts, frozen wastes, and jungles wear armies down once supply thins
+            let terrain = province_storage.provinces[idx].terrain;
+            let hazard = attrition_profiles
+                .as_ref()
+                .map_or_else(|| terrain.attrition_profile(), |profiles| profiles.get(terrain));
+            if supply[idx] < HARSH_TERRAIN_SUPPLY_THRESHOLD
+                && hazard.water + hazard.cold + hazard.disease >= HARSH_TERRAIN_HAZARD
+            {
+                return true;
+            }
+            province_storage.provinces[idx]
+                .neighbors
+                .iter()
+                .flatten()
+                .filter_map(|id| owners.get(id.value() as usize).copied().flatten())
+                .any(|neighbor_owner| enemy_pairs.contains(&(owner, neighbor_owner)))
+        })
+        .collect();
+
+    debug!(
+        "Military supply refreshed: {} attrition zones across {} provinces",
+        attrition.iter().filter(|&&a| a).count(),
+        province_count
+    );
+
+    storage.supply = supply;
+    storage.attrition = attrition;
+    storage.owners = owners;
+    storage.dirty = false;
+
+    // Trigger a recolor of the mega-mesh with the new supply data
+    map_mode.set_changed();
+}
+
+/// Spawn strength badges for armies, and badges for forts and depots, when entering the Military overlay
+pub fn spawn_army_badges_on_mode_enter(
+    mut commands: Commands,
+    current_mode: Res<MapMode>,
+    filter: Res<MilitaryOverlayFilter>,
+    existing_badges: Query<Entity, Or<(With<ArmyBadge>, With<FortBadge>, With<DepotBadge>)>>,
+    armies: Query<(&Army, &StationedIn)>,
+    forts: Query<(&Fortification, &StationedIn, Option<&FortConstruction>)>,
+    depots: Query<(&SupplyDepot, &StationedIn)>,
+    province_entity_order: Option<Res<ProvinceEntityOrder>>,
+    province_storage: Option<Res<ProvinceStorage>>,
+) {
+    if *current_mode != MapMode::Military {
+        return;
+    }
+
+    // Rebuild badges so filter changes are reflected
+    for entity in &existing_badges {
+        commands.entity(entity).despawn();
+    }
+
+    let (Some(entity_order), Some(province_storage)) = (province_entity_order, province_storage)
+    else {
+        return;
+    };
+
+    let index_by_entity: HashMap<Entity, usize> = entity_order
+        .entities
+        .iter()
+        .enumerate()
+        .map(|(idx, &entity)| (entity, idx))
+        .collect();
+
+    // Aggregate armies per province so stacked armies share one badge
+    let mut strength_by_province: HashMap<usize, u32> = HashMap::new();
+    for (army, stationed_in) in &armies {
+        if !filter.includes(Some(army.owner_nation)) {
+            continue;
+        }
+        if let Some(&idx) = index_by_entity.get(&stationed_in.0) {
+            *strength_by_province.entry(idx).or_insert(0) += army.size.get();
+        }
+    }
+
+    for (idx, strength) in strength_by_province {
+        let Some(province) = province_storage.provinces.get(idx) else {
+            continue;
+        };
+
+        commands.spawn((
+            Text2d::new(format_strength(strength)),
+            TextFont {
+                font_size: ARMY_BADGE_FONT_SIZE,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Transform::from_translation(province.position.extend(ARMY_BADGE_Z_INDEX)),
+            ArmyBadge,
+        ));
+    }
+
+    // Finished forts show their kind; works in progress are marked as such
+    for (fort, stationed_in, construction) in &forts {
+        let Some(province) = index_by_entity
+            .get(&stationed_in.0)
+            .and_then(|&idx| province_storage.provinces.get(idx))
+        else {
+            continue;
+        };
+        if !filter.includes(province.owner_entity) {
+            continue;
+        }
+
+        let label = match construction {
+            Some(construction) => format!("{} ({}y)", fort.fortification_type.label(), construction.years_remaining),
+            None => fort.fortification_type.label().to_string(),
+        };
+        let position = province.position - Vec2::new(0.0, FORT_BADGE_OFFSET);
+        commands.spawn((
+            Text2d::new(label),
+            TextFont {
+                font_size: FORT_BADGE_FONT_SIZE,
+                ..default()
+            },
+            TextColor(Color::srgb(0.85, 0.8, 0.6)),
+            Transform::from_translation(position.extend(ARMY_BADGE_Z_INDEX)),
+            FortBadge,
+        ));
+    }
+
+    for (depot, stationed_in) in &depots {
+        if !filter.includes(Some(depot.owner)) {
+            continue;
+        }
+        let Some(province) = index_by_entity
+            .get(&stationed_in.0)
+            .and_then(|&idx| province_storage.provinces.get(idx))
+        else {
+            continue;
+        };
+
+        let position = province.position + Vec2::new(0.0, DEPOT_BADGE_OFFSET);
+        commands.spawn((
+            Text2d::new(format!("Depot {:.0}", depot.stock)),
+            TextFont {
+                font_size: FORT_BADGE_FONT_SIZE,
+                ..default()
+            },
+            TextColor(Color::srgb(0.7, 0.85, 0.6)),
+            Transform::from_translation(position.extend(ARMY_BADGE_Z_INDEX)),
+            DepotBadge,
+        ));
+    }
+}
+
+/// Despawn army, fort, and depot badges when leaving the Military overlay
+pub fn cleanup_army_badges_on_mode_exit(
+    mut commands: Commands,
+    current_mode: Res<MapMode>,
+    badges: Query<Entity, Or<(With<ArmyBadge>, With<FortBadge>, With<DepotBadge>)>>,
+) {
+    if *current_mode != MapMode::Military {
+        for entity in &badges {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Compact strength label ("850", "12.4k")
+fn format_strength(strength: u32) -> String {
+    if strength >= 1000 {
+        format!("{:.1}k", strength as f32 / 1000.0)
+    } else {
+        strength.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim_harness::{MiniWorld, SimHarness};
+    use crate::world::ProvinceId;
+
+    const COLUMNS: u32 = 10;
+
+    /// Two nations, west (columns 0-4) and east (columns 5-9), with the Military overlay open
+    fn supply_world(seed: u64) -> SimHarness {
+        let mut sim = MiniWorld::new(seed)
+            .grid(COLUMNS, 10)
+            .nations(2)
+            .build()
+            .with_systems(refresh_military_supply);
+        sim.world_mut().init_resource::<MilitarySupplyStorage>();
+        *sim.world_mut().resource_mut::<MapMode>() = MapMode::Military;
+        sim
+    }
+
+    fn index(col: u32, row: u32) -> usize {
+        (row * COLUMNS + col) as usize
+    }
+
+    fn refreshed(sim: &mut SimHarness) -> &MilitarySupplyStorage {
+        sim.tick(1);
+        sim.world().resource::<MilitarySupplyStorage>()
+    }
+
+    #[test]
+    fn supply_decays_with_distance_and_terrain_cost() {
+        let mut sim = supply_world(1);
+        let storage = refreshed(&mut sim);
+        // West's capital is the top of column 0; each row down is one more province away
+        let column: Vec<f32> = (0..6).map(|row| storage.supply_at(index(0, row))).collect();
+        assert_eq!(column[0], 1.0);
+        assert!(column.windows(2).all(|pair| pair[1] < pair[0]), "supply falls off with distance: {column:?}");
+        let grassland = storage.supply_at(index(0, 3));
+
+        let mut mountains = supply_world(1);
+        mountains.world_mut().resource_mut::<ProvinceStorage>().provinces[index(0, 3)].terrain = TerrainType::Alpine;
+        let alpine = refreshed(&mut mountains).supply_at(index(0, 3));
+        assert!(alpine < grassland, "alpine {alpine} should cost more supply than grassland {grassland}");
+        assert!(alpine > 0.0);
+    }
+
+    #[test]
+    fn supply_stops_at_foreign_borders() {
+        let mut sim = supply_world(2);
+        // East's capital falls to the west, leaving it no source of its own
+        let east = sim.nation(1);
+        if let Some(mut nation) = sim.world_mut().get_mut::<Nation>(east) {
+            nation.capital_province = ProvinceId::new(0);
+        }
+        let storage = refreshed(&mut sim);
+
+        for row in 0..10 {
+            assert!(storage.supply_at(index(4, row)) > 0.0, "west supplies its own border");
+            assert_eq!(storage.supply_at(index(5, row)), 0.0, "west supply crossed into east");
+        }
+    }
+
+    #[test]
+    fn thin_supply_and_enemy_fronts_are_attrition_zones() {
+        let mut sim = supply_world(3);
+        // A collapsing west cannot supply its far provinces
+        let west = sim.nation(0);
+        if let Some(mut nation) = sim.world_mut().get_mut::<Nation>(west) {
+            nation.stability = 0.0;
+        }
+        let storage = refreshed(&mut sim);
+
+        let west_provinces: Vec<usize> = (0..10).flat_map(|row| (0..5).map(move |col| index(col, row))).collect();
+        assert!(west_provinces.iter().any(|&idx| storage.supply_at(idx) < ATTRITION_SUPPLY_THRESHOLD));
+        assert!(west_provinces.iter().any(|&idx| storage.supply_at(idx) >= ATTRITION_SUPPLY_THRESHOLD));
+        for &idx in &west_provinces {
+            let thin = storage.supply_at(idx) < ATTRITION_SUPPLY_THRESHOLD;
+            assert_eq!(storage.is_attrition_zone(idx), thin, "province {idx} at peace");
+        }
+
+        // War turns both sides of the border into a front, however well supplied
+        let east = sim.nation(1);
+        sim.world_mut().entity_mut(west).insert(Attacking(east));
+        sim.world_mut().resource_mut::<MilitarySupplyStorage>().mark_dirty();
+        let storage = refreshed(&mut sim);
+
+        assert!(storage.supply_at(index(4, 0)) >= ATTRITION_SUPPLY_THRESHOLD);
+        assert!(storage.is_attrition_zone(index(4, 0)));
+        assert_eq!(storage.supply_at(index(5, 0)), 1.0, "east's capital");
+        assert!(storage.is_attrition_zone(index(5, 0)));
+        assert!(!storage.is_attrition_zone(index(2, 0)), "behind the front");
+    }
+}
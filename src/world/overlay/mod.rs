@@ -2,10 +2,21 @@
 
 // PRIVATE MODULES
 mod cache;
+mod heatmap;
+mod history;
+mod military;
 mod rendering;
 mod types;
 
 // PUBLIC EXPORTS
 pub use cache::CachedOverlayColors;
+pub use heatmap::{
+    heat_ramp, restored_map_mode, saved_map_mode, Heatmap, HeatmapDefinition, HeatmapId, HeatmapRegistry,
+    HeatmapSource, ProvinceMetric,
+};
+pub use history::{BorderHistory, BorderSnapshot, HistoricalBordersView, HistoricalNation};
+pub use military::{
+    ArmyBadge, MilitaryOverlayFilter, MilitarySupplyStorage, ATTRITION_SUPPLY_THRESHOLD,
+};
 pub use rendering::{update_province_colors, OverlayPlugin};
 pub use types::MapMode;
@@ -16,6 +16,48 @@ use std::sync::Arc;
 /// Bytes per megabyte for memory calculations
 const BYTES_PER_MB: f32 = 1024.0 * 1024.0;
 
+/// Tracks overlay redraws requested since the last recolor
+#[derive(Debug, Default)]
+struct OverlayRedrawState {
+    /// Map mode last drawn
+    drawn_mode: Option<MapMode>,
+    /// A world change is waiting to be drawn
+    pending: bool,
+    /// Seconds since the last redraw
+    since_redraw: f32,
+}
+
+impl OverlayRedrawState {
+    /// Whether to recolor this frame
+    ///
+    /// Switching map modes redraws at once; world changes that mark the mode
+    /// changed are batched and redrawn at most `rate` times per second.
+    fn due(&mut self, mode: MapMode, mode_changed: bool, elapsed: f32, rate: f32) -> bool {
+        self.since_redraw += elapsed;
+        if mode_changed {
+            self.pending = true;
+        }
+        let switched = self.drawn_mode != Some(mode);
+        if !self.pending || (!switched && self.since_redraw < 1.0 / rate.max(0.1)) {
+            return false;
+        }
+        self.drawn_mode = Some(mode);
+        self.pending = false;
+        self.since_redraw = 0.0;
+        true
+    }
+}
+
+/// Run condition: the overlay needs recoloring, throttled by the overlay refresh rate setting
+fn overlay_redraw_due(
+    mode: Res<MapMode>,
+    settings: Res<crate::settings::GameSettings>,
+    time: Res<Time>,
+    mut state: Local<OverlayRedrawState>,
+) -> bool {
+    state.due(*mode, mode.is_changed(), time.delta_secs(), settings.graphics.overlay_refresh_rate)
+}
+
 /// System that updates province colors in the mega-mesh based on active overlay mode
 /// Now uses Arc-based zero-copy architecture for instant switching
 pub fn update_province_colors(
@@ -32,6 +74,18 @@ pub fn update_province_colors(
     controls_query: Query<&Controls>,
     climate_storage: Option<Res<crate::world::terrain::ClimateStorage>>,
     infrastructure_storage: Option<Res<crate::world::InfrastructureStorage>>,
+    military_storage: Option<Res<super::MilitarySupplyStorage>>,
+    military_filter: Option<Res<super::MilitaryOverlayFilter>>,
+    border_history: Option<Res<super::BorderHistory>>,
+    history_view: Option<Res<super::HistoricalBordersView>>,
+    province_cores: Option<Res<crate::nations::ProvinceCores>>,
+    (influence_maps, census_discrepancy, devastation, refugees, heatmaps): (
+        Option<Res<crate::ai::InfluenceMaps>>,
+        Option<Res<crate::nations::CensusDiscrepancy>>,
+        Option<Res<crate::nations::Devastation>>,
+        Option<Res<crate::nations::Refugees>>,
+        Option<Res<super::HeatmapRegistry>>,
+    ),
 ) {
     let start = std::time::Instant::now();
     trace!(
@@ -63,6 +117,16 @@ pub fn update_province_colors(
         &controls_query,
         climate_storage.as_ref().map(|r| r.as_ref()),
         infrastructure_storage.as_ref().map(|r| r.as_ref()),
+        military_storage.as_ref().map(|r| r.as_ref()),
+        military_filter.as_ref().map(|r| r.as_ref()),
+        border_history.as_ref().map(|r| r.as_ref()),
+        history_view.as_ref().map(|r| r.as_ref()),
+        province_cores.as_ref().map(|r| r.as_ref()),
+        influence_maps.as_ref().map(|r| r.as_ref()),
+        census_discrepancy.as_ref().map(|r| r.as_ref()),
+        devastation.as_ref().map(|r| r.as_ref()),
+        refugees.as_ref().map(|r| r.as_ref()),
+        heatmaps.as_ref().map(|r| r.as_ref()),
     );
 
     let _selection_time = start.elapsed() - mesh_lookup_time;
@@ -106,20 +170,58 @@ use bevy_plugin_builder::define_plugin;
 
 /// Plugin that manages map overlay rendering
 define_plugin!(OverlayPlugin {
-    resources: [MapMode, crate::resources::CachedOverlayColors],
+    resources: [
+        MapMode,
+        crate::resources::CachedOverlayColors,
+        super::MilitarySupplyStorage,
+        super::MilitaryOverlayFilter,
+        super::BorderHistory,
+        super::HistoricalBordersView,
+        super::HeatmapRegistry
+    ],
+
+    startup: [super::heatmap::register_builtin_heatmaps],
 
     update: [
+        // Military overlay data refreshes before colors are rebuilt
+        (super::military::sync_military_filter_with_selection,
+         super::military::refresh_military_supply)
+            .chain()
+            .before(update_province_colors)
+            .run_if(in_state(crate::states::GameState::InGame)),
+        // Heatmaps are rescaled before their colors are rebuilt
+        (super::heatmap::sync_mod_heatmaps, super::heatmap::refresh_active_heatmap)
+            .chain()
+            .before(update_province_colors)
+            .run_if(in_state(crate::states::GameState::InGame)),
+        super::history::refresh_historical_borders_on_view_change
+            .before(update_province_colors)
+            .run_if(in_state(crate::states::GameState::InGame)),
         update_province_colors
+            .run_if(in_state(crate::states::GameState::InGame))
+            .run_if(overlay_redraw_due),
+        // Time-lapse border history recording
+        super::history::record_border_snapshots
+            .run_if(in_state(crate::states::GameState::InGame)),
+        // Army badges follow the Military overlay
+        (super::military::spawn_army_badges_on_mode_enter,
+         super::military::cleanup_army_badges_on_mode_exit)
             .run_if(resource_changed::<MapMode>)
             .run_if(in_state(crate::states::GameState::InGame))
     ],
 
     on_exit: {
-        crate::states::GameState::LoadingWorld => [initialize_overlay_colors]
+        crate::states::GameState::LoadingWorld => [
+            initialize_overlay_colors,
+            super::history::clear_border_history
+        ]
     },
 
     on_enter: {
-        crate::states::GameState::InGame => [force_initial_overlay_update]
+        crate::states::GameState::InGame => [
+            force_initial_overlay_update,
+            super::history::record_initial_border_snapshot
+        ]
     }
 });
 
@@ -162,3 +264,22 @@ pub fn initialize_overlay_colors(
     // Note: Pre-calculation removed - overlays are now calculated on-demand
     // This is more efficient with ECS as we don't need to hold query results
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mode_switches_redraw_at_once_and_world_changes_are_throttled() {
+        let mut state = OverlayRedrawState::default();
+        assert!(state.due(MapMode::Political, true, 0.016, 4.0));
+
+        // A burst of world changes redraws once the interval has passed
+        assert!(!state.due(MapMode::Political, true, 0.1, 4.0));
+        assert!(!state.due(MapMode::Political, false, 0.1, 4.0));
+        assert!(state.due(MapMode::Political, false, 0.1, 4.0));
+        assert!(!state.due(MapMode::Political, false, 1.0, 4.0));
+
+        assert!(state.due(MapMode::Terrain, true, 0.0, 4.0));
+    }
+}
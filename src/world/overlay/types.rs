@@ -1,5 +1,6 @@
 //! Overlay system types and data structures
 
+use super::heatmap::HeatmapId;
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -18,10 +19,22 @@ pub enum MapMode {
     Agriculture,    // Agricultural productivity
     Infrastructure, // Roads, cities, and development
     Minerals,       // Combined mineral richness (compressed from 7 individual modes)
+    Military,       // Supply reach, attrition zones, and army positions
+    HistoricalBorders, // Political borders at a chosen past date
+    Cores,          // Core territory claims and lost cores
+    Devastation,    // War damage, recovery, and depopulated marches
+    RefugeeFlows,   // Where refugees fled from and where they shelter
+    ThreatInfluence,   // Reach of armed strength (AI influence map)
+    EconomicInfluence, // Value of nearby land (AI influence map)
+    CulturalInfluence, // Spread of settled cultures (AI influence map)
+    CensusError,       // Believed vs true population (development builds)
+    Heatmap(HeatmapId), // A metric registered with the HeatmapRegistry
 }
 
 impl MapMode {
     /// Cycle to the next map mode
+    ///
+    /// Heatmaps are picked from the map mode dropdown and cycle back to Political.
     pub fn cycle(&mut self) {
         *self = match self {
             MapMode::Political => MapMode::Terrain,
@@ -30,7 +43,16 @@ impl MapMode {
             MapMode::Population => MapMode::Agriculture,
             MapMode::Agriculture => MapMode::Infrastructure,
             MapMode::Infrastructure => MapMode::Minerals,
-            MapMode::Minerals => MapMode::Political,
+            MapMode::Minerals => MapMode::Military,
+            MapMode::Military => MapMode::HistoricalBorders,
+            MapMode::HistoricalBorders => MapMode::Cores,
+            MapMode::Cores => MapMode::Devastation,
+            MapMode::Devastation => MapMode::RefugeeFlows,
+            MapMode::RefugeeFlows => MapMode::ThreatInfluence,
+            MapMode::ThreatInfluence => MapMode::EconomicInfluence,
+            MapMode::EconomicInfluence => MapMode::CulturalInfluence,
+            MapMode::CulturalInfluence if cfg!(debug_assertions) => MapMode::CensusError,
+            MapMode::CulturalInfluence | MapMode::CensusError | MapMode::Heatmap(_) => MapMode::Political,
         }
     }
 
@@ -44,9 +66,52 @@ impl MapMode {
             MapMode::Agriculture => "Agriculture",
             MapMode::Infrastructure => "Infrastructure",
             MapMode::Minerals => "Minerals",
+            MapMode::Military => "Military Supply",
+            MapMode::HistoricalBorders => "Historical Borders",
+            MapMode::Cores => "Core Territories",
+            MapMode::Devastation => "Devastation",
+            MapMode::RefugeeFlows => "Refugee Flows",
+            MapMode::ThreatInfluence => "Military Threat",
+            MapMode::EconomicInfluence => "Economic Opportunity",
+            MapMode::CulturalInfluence => "Cultural Pressure",
+            MapMode::CensusError => "Census Error",
+            // The registry holds each heatmap's own name, see HeatmapRegistry::mode_name
+            MapMode::Heatmap(_) => "Heatmap",
         }
     }
 
+    /// Check if this mode shows live data that must be recalculated on every refresh
+    /// instead of being served from the overlay cache
+    pub fn is_live(&self) -> bool {
+        matches!(
+            self,
+            MapMode::Military
+                | MapMode::HistoricalBorders
+                | MapMode::Cores
+                | MapMode::Devastation
+                | MapMode::RefugeeFlows
+                | MapMode::CensusError
+                | MapMode::Heatmap(_)
+        )
+            || self.is_influence_mode()
+    }
+
+    /// Check if this mode shows one of the AI influence maps
+    pub fn is_influence_mode(&self) -> bool {
+        matches!(
+            self,
+            MapMode::ThreatInfluence | MapMode::EconomicInfluence | MapMode::CulturalInfluence
+        )
+    }
+
+    /// Check if clicking a province should select its owning nation
+    pub fn supports_nation_selection(&self) -> bool {
+        matches!(
+            self,
+            MapMode::Political | MapMode::Military | MapMode::ThreatInfluence | MapMode::EconomicInfluence
+        )
+    }
+
     /// Check if this is a mineral-specific mode
     pub fn is_mineral_mode(&self) -> bool {
         matches!(self, MapMode::Minerals)
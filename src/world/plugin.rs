@@ -3,8 +3,11 @@
 use bevy_plugin_builder::define_plugin;
 
 // Import from sibling modules through super (gateway pattern)
-use super::{BorderPlugin, CloudPlugin, OverlayPlugin, TerrainPlugin, WorldConfigPlugin};
-use super::{ProvincesSpatialIndex, CoastalProvinceCache};
+use super::{
+    BorderPlugin, CloudPlugin, CoastlinePlugin, CropYieldPlugin, MapDetailPlugin, NaturalWondersPlugin, OverlayPlugin,
+    TerrainPlugin, VisualCyclePlugin, WorldConfigPlugin,
+};
+use super::{ProvincesSpatialIndex, CoastalProvinceCache, ProvinceGraph, RegionHierarchy};
 use super::events::{WorldGeneratedEvent, ProvinceSelectedEvent};
 
 /// Main world plugin using REVOLUTIONARY plugin aggregation automation!
@@ -16,10 +19,22 @@ define_plugin!(WorldPlugin {
         TerrainPlugin,
         BorderPlugin,
         OverlayPlugin,
+        MapDetailPlugin,
+        NaturalWondersPlugin,
+        CoastlinePlugin,
+        CropYieldPlugin,
+        VisualCyclePlugin,
         WorldConfigPlugin
     ],
 
-    resources: [ProvincesSpatialIndex, CoastalProvinceCache],
+    resources: [ProvincesSpatialIndex, CoastalProvinceCache, ProvinceGraph, RegionHierarchy],
+
+    reflect: [
+        super::ProvinceEntity,
+        super::ProvinceMarker,
+        super::ProvinceData,
+        super::ProvinceNeighbors
+    ],
 
     messages: [WorldGeneratedEvent, ProvinceSelectedEvent]
 });
\ No newline at end of file
@@ -1,11 +1,32 @@
-//! Agriculture and fresh water distance calculation
+//! Agriculture - what the land yields, and how far it lies from water
+//!
+//! A province's yield comes from one formula, used at generation and again
+//! every year by the yield systems:
+//!
+//! ```text
+//! yield = soil × climate × water × tools        (clamped to 0.0-3.0)
+//! ```
+//!
+//! - **soil** is the terrain's base agriculture, which already reflects the
+//!   climate the land was generated in
+//! - **climate** is the growing season of the province's climate zone now,
+//!   relative to the zone it had at generation: a warming world opens the
+//!   taiga to the plough and dries the tropics toward desert, and a cooling
+//!   one does the reverse
+//! - **water** rewards land near a river or the sea; irrigation counts as
+//!   bringing the water closer, reaching further with more development and
+//!   technology
+//! - **tools** are the owner's technology, a few percent per level
+//!
+//! At generation climate and tools are neutral and there is no irrigation,
+//! so a new world's yields are soil and water alone.
 
 use bevy::log::info;
 use rayon::prelude::*;
 use std::collections::{HashSet, VecDeque};
 
 use super::super::rivers::RiverSystem;
-use super::super::terrain::TerrainType;
+use super::super::terrain::{StoredClimateZone, TerrainType};
 use super::{Agriculture, Distance, Province, ProvinceId};
 use crate::resources::MapDimensions;
 
@@ -29,6 +50,15 @@ const WATER_DISTANCE_BONUSES: [(f32, f32); 5] = [
     (10.0, 1.0), // Far from water (no bonus)
 ];
 
+// Irrigation reach in hexes for each development level, per technology level past the first
+const IRRIGATION_REACH_PER_LEVEL: f32 = 0.25;
+// Technology levels past the first that still extend irrigation
+const MAX_IRRIGATION_LEVELS: u32 = 4;
+
+// Yield gained per technology level past the first, and the most tools can add
+const TOOLS_PER_LEVEL: f32 = 0.04;
+const MAX_TOOLS_BONUS: f32 = 0.5;
+
 // Threshold for "high agriculture" provinces
 const HIGH_AGRICULTURE_THRESHOLD: f32 = 1.5;
 
@@ -55,6 +85,73 @@ impl std::fmt::Display for AgricultureError {
 
 impl std::error::Error for AgricultureError {}
 
+/// What a province's yield depends on besides its terrain and distance from water
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct YieldConditions {
+    /// Growing season now relative to the one the province was generated with
+    pub climate: f32,
+    /// Hexes irrigation brings water closer by
+    pub irrigation_reach: f32,
+    /// Technology level of the owner (1 for unowned land)
+    pub technology_level: u32,
+}
+
+impl Default for YieldConditions {
+    /// The conditions a world is generated in
+    fn default() -> Self {
+        Self {
+            climate: 1.0,
+            irrigation_reach: 0.0,
+            technology_level: 1,
+        }
+    }
+}
+
+/// Yield of a province from its terrain, distance from water, and conditions
+pub fn crop_yield(
+    terrain: TerrainType,
+    water_distance: f32,
+    conditions: &YieldConditions,
+) -> Agriculture {
+    let soil = terrain.properties().agriculture_base;
+    let irrigated_distance = if water_distance > 0.0 {
+        (water_distance - conditions.irrigation_reach).max(1.0)
+    } else {
+        water_distance
+    };
+    let tools = 1.0
+        + (conditions.technology_level.saturating_sub(1) as f32 * TOOLS_PER_LEVEL)
+            .min(MAX_TOOLS_BONUS);
+    Agriculture::new(soil * conditions.climate * water_bonus(irrigated_distance) * tools)
+}
+
+/// Hexes irrigation carries water, given a province's development and its owner's technology
+pub fn irrigation_reach(development_level: u8, technology_level: u32) -> f32 {
+    let levels = technology_level
+        .saturating_sub(1)
+        .min(MAX_IRRIGATION_LEVELS);
+    development_level as f32 * levels as f32 * IRRIGATION_REACH_PER_LEVEL
+}
+
+/// Length of the growing season in a climate zone, relative to temperate land
+fn growing_season(zone: StoredClimateZone) -> f32 {
+    match zone {
+        StoredClimateZone::Arctic => 0.2,
+        StoredClimateZone::Alpine => 0.3,
+        StoredClimateZone::Desert => 0.4,
+        StoredClimateZone::Subarctic => 0.6,
+        StoredClimateZone::Tropical => 0.85,
+        StoredClimateZone::Temperate | StoredClimateZone::Subtropical => 1.0,
+    }
+}
+
+/// Growing season of a province warmed by `warming` degrees, relative to its generated one
+pub fn climate_shift(temperature: f32, rainfall: f32, elevation: f32, warming: f32) -> f32 {
+    let generated = StoredClimateZone::from_climate_data(temperature, rainfall, elevation);
+    let current = StoredClimateZone::from_climate_data(temperature + warming, rainfall, elevation);
+    growing_season(current) / growing_season(generated)
+}
+
 /// Calculate agriculture and fresh water distance for all provinces
 pub fn calculate(
     provinces: &mut [Province],
@@ -90,11 +187,8 @@ pub fn calculate(
         .par_iter()
         .enumerate()
         .map(|(idx, province)| {
-            let base_agriculture = get_base_agriculture(province.terrain);
             let water_dist = water_distances[idx].unwrap_or(MAX_WATER_DISTANCE);
-            let water_bonus = calculate_water_bonus(province, &river_set, &delta_set, water_dist);
-
-            let agriculture = Agriculture::new(base_agriculture * water_bonus);
+            let agriculture = crop_yield(province.terrain, water_dist, &YieldConditions::default());
             let fresh_water_distance = Distance::new(water_dist);
 
             (idx, agriculture, fresh_water_distance)
@@ -120,35 +214,13 @@ pub fn calculate(
     Ok(())
 }
 
-/// Get base agriculture value for a terrain type - uses centralized properties
-fn get_base_agriculture(terrain: TerrainType) -> f32 {
-    terrain.properties().agriculture_base
-}
-
-/// Calculate water bonus based on proximity to water sources
-fn calculate_water_bonus(
-    province: &Province,
-    river_set: &HashSet<ProvinceId>,
-    delta_set: &HashSet<ProvinceId>,
-    water_distance: f32,
-) -> f32 {
-    // Special handling for water terrain - they don't get extra bonus
-    if province.terrain == TerrainType::River || province.terrain == TerrainType::Ocean {
-        return 1.0; // Base agriculture already accounts for water
-    }
-
-    if river_set.contains(&province.id) || delta_set.contains(&province.id) {
-        return 1.0; // Already accounted in terrain type
-    }
-
-    // Apply distance-based bonus
-    for &(max_dist, bonus) in WATER_DISTANCE_BONUSES.iter() {
-        if water_distance <= max_dist {
-            return bonus;
-        }
-    }
-
-    1.0 // No bonus if too far from water
+/// Bonus for land near water; water sources themselves get none, their
+/// terrain already accounts for it
+fn water_bonus(water_distance: f32) -> f32 {
+    WATER_DISTANCE_BONUSES
+        .iter()
+        .find(|&&(max_dist, _)| water_distance <= max_dist)
+        .map_or(1.0, |&(_, bonus)| bonus)
 }
 
 /// Calculate actual distances from water sources using BFS
@@ -198,3 +270,45 @@ fn calculate_water_distances(
 
     Ok(distances)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn climate_irrigation_and_tools_shift_yields() {
+        let grassland = TerrainType::TemperateGrassland;
+        let soil = grassland.properties().agriculture_base;
+        let generated = YieldConditions::default();
+
+        // A new world's yields are soil and water alone
+        assert_eq!(crop_yield(grassland, 8.0, &generated).value(), soil);
+        assert_eq!(crop_yield(grassland, 1.0, &generated).value(), soil * 1.3);
+
+        // Irrigation needs technology, then brings distant land near water
+        assert_eq!(irrigation_reach(4, 1), 0.0);
+        let irrigated = YieldConditions {
+            irrigation_reach: irrigation_reach(4, 3),
+            ..generated
+        };
+        assert_eq!(crop_yield(grassland, 3.0, &irrigated).value(), soil * 1.3);
+
+        // Tools add a few percent a level, up to a cap
+        let advanced = YieldConditions {
+            technology_level: 6,
+            ..generated
+        };
+        assert!(crop_yield(grassland, 8.0, &advanced).value() > soil * 1.15);
+        let far_future = YieldConditions {
+            technology_level: 100,
+            ..generated
+        };
+        assert_eq!(crop_yield(grassland, 8.0, &far_future).value(), soil * 1.5);
+
+        // Warming opens cold land and parches hot land; no warming changes nothing
+        assert_eq!(climate_shift(-5.0, 600.0, 0.3, 0.0), 1.0);
+        assert!(climate_shift(-5.0, 600.0, 0.3, 6.0) > 1.5);
+        assert!(climate_shift(27.0, 900.0, 0.3, 4.0) < 0.5);
+        assert!(climate_shift(12.0, 900.0, 0.3, -15.0) < 1.0);
+    }
+}
@@ -0,0 +1,160 @@
+//! Province graph - precomputed adjacency for fast neighbor walks
+//!
+//! Every province's neighbors are stored back to back in one array
+//! (compressed sparse rows), with the distance to each neighbor alongside,
+//! so AI, trade, and diffusion systems can walk the map without chasing
+//! `Option`s through `ProvinceStorage`. Adjacency never changes after
+//! generation, so the graph is built once and saved with the world.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+use super::types::Province;
+use crate::math::Fixed32;
+
+/// Neighbor lists and distances of every province, by province index
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProvinceGraph {
+    /// Where each province's neighbors start in `neighbors`, plus one past the last
+    offsets: Vec<u32>,
+    /// Neighbor indices of every province, back to back
+    neighbors: Vec<u32>,
+    /// Distance to each neighbor, parallel to `neighbors`
+    lengths: Vec<Fixed32>,
+}
+
+impl ProvinceGraph {
+    /// Build the graph from generated provinces
+    pub fn build(provinces: &[Province]) -> Self {
+        let mut graph = Self {
+            offsets: Vec::with_capacity(provinces.len() + 1),
+            neighbors: Vec::with_capacity(provinces.len() * 6),
+            lengths: Vec::with_capacity(provinces.len() * 6),
+        };
+        graph.offsets.push(0);
+        for province in provinces {
+            for neighbor in province.neighbors.iter().flatten() {
+                let Some(other) = provinces.get(neighbor.value() as usize) else {
+                    continue;
+                };
+                let (dx, dy) = Fixed32::from_vec2(other.position - province.position);
+                graph.neighbors.push(neighbor.value());
+                graph.lengths.push(Fixed32::hypot(dx, dy));
+            }
+            graph.offsets.push(graph.neighbors.len() as u32);
+        }
+        graph
+    }
+
+    /// Number of provinces in the graph
+    pub fn len(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether the graph was built for these provinces (a saved graph may predate them)
+    pub fn matches(&self, provinces: &[Province]) -> bool {
+        self.len() == provinces.len()
+    }
+
+    fn range(&self, index: usize) -> std::ops::Range<usize> {
+        match (self.offsets.get(index), self.offsets.get(index + 1)) {
+            (Some(&start), Some(&end)) => start as usize..end as usize,
+            _ => 0..0,
+        }
+    }
+
+    /// Indices of a province's neighbors
+    pub fn neighbors(&self, index: usize) -> &[u32] {
+        &self.neighbors[self.range(index)]
+    }
+
+    /// A province's neighbors with the distance to each
+    pub fn edges(&self, index: usize) -> impl Iterator<Item = (usize, Fixed32)> + '_ {
+        let range = self.range(index);
+        self.neighbors[range.clone()]
+            .iter()
+            .zip(&self.lengths[range])
+            .map(|(&neighbor, &length)| (neighbor as usize, length))
+    }
+
+    pub fn degree(&self, index: usize) -> usize {
+        self.range(index).len()
+    }
+
+    pub fn is_adjacent(&self, a: usize, b: usize) -> bool {
+        self.neighbors(a).contains(&(b as u32))
+    }
+
+    /// Provinces within `max_hops` steps of `from`, with their distance in steps, nearest first
+    ///
+    /// Only provinces that `passable` accepts are entered; `from` always is.
+    pub fn within(&self, from: usize, max_hops: u32, passable: impl Fn(usize) -> bool) -> Vec<(usize, u32)> {
+        if from >= self.len() {
+            return Vec::new();
+        }
+        let mut reached = vec![(from, 0)];
+        let mut seen = vec![false; self.len()];
+        seen[from] = true;
+        let mut frontier = VecDeque::from([(from, 0)]);
+        while let Some((current, hops)) = frontier.pop_front() {
+            if hops == max_hops {
+                continue;
+            }
+            for &next in self.neighbors(current) {
+                let next = next as usize;
+                if seen[next] || !passable(next) {
+                    continue;
+                }
+                seen[next] = true;
+                reached.push((next, hops + 1));
+                frontier.push_back((next, hops + 1));
+            }
+        }
+        reached
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::ProvinceId;
+
+    #[test]
+    fn neighbor_lists_match_provinces_and_survive_serialization() {
+        // A zigzag row of four provinces, each touching the next
+        let provinces: Vec<Province> = (0..4u32)
+            .map(|index| {
+                let mut province = Province {
+                    id: ProvinceId::new(index),
+                    position: Vec2::new(index as f32 * 3.0, 4.0 * (index % 2) as f32),
+                    ..Province::default()
+                };
+                province.neighbors[1] = (index < 3).then(|| ProvinceId::new(index + 1));
+                province.neighbors[4] = index.checked_sub(1).map(ProvinceId::new);
+                province
+            })
+            .collect();
+
+        let graph = ProvinceGraph::build(&provinces);
+        assert_eq!(graph.len(), 4);
+        assert!(graph.matches(&provinces));
+        assert_eq!(graph.neighbors(1), &[2, 0]);
+        assert_eq!(graph.degree(3), 1);
+        assert!(graph.is_adjacent(2, 3) && !graph.is_adjacent(0, 2));
+        assert_eq!(graph.neighbors(9), &[] as &[u32]);
+        // 3 across and 4 up or down between every pair
+        assert!(graph.edges(0).all(|(_, length)| length == Fixed32::from_int(5)));
+
+        assert_eq!(graph.within(0, 2, |_| true), vec![(0, 0), (1, 1), (2, 2)]);
+        assert_eq!(graph.within(0, 5, |index| index != 2), vec![(0, 0), (1, 1)]);
+
+        let json = serde_json::to_string(&graph).unwrap_or_default();
+        let restored: Option<ProvinceGraph> = serde_json::from_str(&json).ok();
+        assert_eq!(restored, Some(graph));
+    }
+}
@@ -1,6 +1,7 @@
 //! Provinces feature module gateway
 //!
-//! Core province system with spatial indexing
+//! Core province system with spatial indexing, adjacency, and the region
+//! hierarchy above the provinces
 
 // PRIVATE MODULES
 mod agriculture;
@@ -8,7 +9,9 @@ mod coastal;
 mod elevation;
 mod events;
 mod generation;  // Now points to the new generation/ subfolder
+mod graph;
 mod naval_range;
+mod regions;
 mod spatial;
 mod types;
 
@@ -21,11 +24,15 @@ pub use types::{
     ProvinceMarker, ProvinceData, ProvinceNeighbors, ProvinceBundle, ProvinceEntityOrder,
 };
 
-// Spatial indexing
+// Spatial indexing and adjacency
 pub use spatial::{ProvincesSpatialIndex, WorldBounds};
+pub use graph::ProvinceGraph;
+pub use regions::{build_region_hierarchy, Area, Continent, Region, RegionHierarchy};
 
 // Generation and processing
-pub use agriculture::calculate as calculate_agriculture_values;
+pub use agriculture::{
+    calculate as calculate_agriculture_values, climate_shift, crop_yield, irrigation_reach, YieldConditions,
+};
 pub use generation::{
     ProvinceBuilder, calculate_ocean_depths, precompute_neighbor_indices,
     provinces_to_bundles, set_neighbor_entities,
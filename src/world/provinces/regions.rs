@@ -0,0 +1,456 @@
+//! Region hierarchy - continents, regions, and areas above the provinces
+//!
+//! Provinces are too fine a grain for much of what the game reasons about.
+//! At generation every large landmass becomes a continent, with the small
+//! islands joining the nearest one; each continent is divided into regions of
+//! a few hundred provinces and each region into areas of a few dozen, all
+//! named by the name generator. AI strategy weighs threats region by region,
+//! the province panel and follow breadcrumbs say where things are
+//! ("Northern Vales, Asteria"), every region has weather of its own, and the
+//! stress test simulates load far from the regions in view more coarsely.
+//!
+//! The division follows from the provinces and the world seed alone, so it is
+//! rebuilt on load rather than saved.
+
+use bevy::prelude::*;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+use super::graph::ProvinceGraph;
+use super::spatial::ProvincesSpatialIndex;
+use super::types::Province;
+use crate::math::HEX_SIZE;
+use crate::name_generator::{self, Bearing, NameGenerator, NameType};
+use crate::world::TerrainType;
+
+/// Fewest provinces a landmass needs to be a continent rather than an island
+const MIN_CONTINENT_PROVINCES: usize = 150;
+/// Provinces in a region, roughly
+const REGION_PROVINCES: usize = 300;
+/// Provinces in an area, roughly
+const AREA_PROVINCES: usize = 40;
+/// How far from its continent's center a region must lie, as a share of the
+/// continent's reach, to be named for its bearing
+const OFF_CENTER_SHARE: f32 = 0.35;
+
+/// A large landmass and the islands around it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Continent {
+    pub name: String,
+}
+
+/// A broad division of a continent
+#[derive(Debug, Clone, PartialEq)]
+pub struct Region {
+    pub name: String,
+    pub continent: u32,
+    /// Regions sharing a land border with this one
+    pub neighbors: Vec<u32>,
+}
+
+/// A handful of neighboring provinces within a region
+#[derive(Debug, Clone, PartialEq)]
+pub struct Area {
+    pub name: String,
+    pub region: u32,
+}
+
+/// Continents, regions, and areas of the world, and the area of each province
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct RegionHierarchy {
+    continents: Vec<Continent>,
+    regions: Vec<Region>,
+    areas: Vec<Area>,
+    /// Area of each province by index, none at sea
+    province_areas: Vec<Option<u32>>,
+}
+
+impl RegionHierarchy {
+    /// Divide the land of generated provinces, naming everything from the world seed
+    pub fn build(provinces: &[Province], graph: &ProvinceGraph, seed: u32) -> Self {
+        let landmasses = landmasses(provinces, graph);
+
+        // Continents are the big landmasses, or the biggest if none is big
+        let biggest = landmasses.iter().map(Vec::len).max().unwrap_or(0);
+        let (mainlands, islands): (Vec<Vec<usize>>, Vec<Vec<usize>>) = landmasses
+            .into_iter()
+            .partition(|landmass| landmass.len() >= MIN_CONTINENT_PROVINCES.min(biggest));
+        let mainland_centers: Vec<Vec2> = mainlands.iter().map(|mainland| centroid(mainland, provinces)).collect();
+        let mut outlying = vec![Vec::new(); mainlands.len()];
+        for island in islands {
+            let center = centroid(&island, provinces);
+            let nearest = mainland_centers
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| a.distance_squared(center).total_cmp(&b.distance_squared(center)))
+                .map(|(index, _)| index);
+            if let Some(nearest) = nearest {
+                outlying[nearest].extend(island);
+            }
+        }
+
+        let mut names = NameGenerator::with_seed(seed as u64);
+        let mut hierarchy = Self {
+            province_areas: vec![None; provinces.len()],
+            ..Self::default()
+        };
+        for (mainland, outlying) in mainlands.into_iter().zip(outlying) {
+            let continent_index = hierarchy.continents.len() as u32;
+            let members: Vec<usize> = mainland.iter().chain(&outlying).copied().collect();
+            let center = centroid(&members, provinces);
+            let reach = members
+                .iter()
+                .map(|&index| provinces[index].position.distance(center))
+                .fold(0.0, f32::max);
+            hierarchy.continents.push(Continent {
+                name: names.generate(NameType::Continent),
+            });
+
+            let groups = partition(&mainland, REGION_PROVINCES, graph, provinces)
+                .into_iter()
+                .map(|group| (group, false))
+                .chain(
+                    partition(&outlying, REGION_PROVINCES, graph, provinces)
+                        .into_iter()
+                        .map(|group| (group, true)),
+                );
+            for (members, islands) in groups {
+                let region_index = hierarchy.regions.len() as u32;
+                let landform_of = |members: &[usize]| {
+                    if islands {
+                        name_generator::Region::Island
+                    } else {
+                        dominant_landform(members, provinces)
+                    }
+                };
+                let region_center = centroid(&members, provinces);
+                let offset = region_center - center;
+                let bearing =
+                    (offset.length() > reach * OFF_CENTER_SHARE).then(|| Bearing::from_angle(offset.y.atan2(offset.x)));
+                hierarchy.regions.push(Region {
+                    name: names.generate(NameType::Region {
+                        region: landform_of(&members),
+                        bearing,
+                    }),
+                    continent: continent_index,
+                    neighbors: Vec::new(),
+                });
+                for members in partition(&members, AREA_PROVINCES, graph, provinces) {
+                    let area_index = hierarchy.areas.len() as u32;
+                    for &index in &members {
+                        hierarchy.province_areas[index] = Some(area_index);
+                    }
+                    hierarchy.areas.push(Area {
+                        name: names.generate(NameType::Area {
+                            region: landform_of(&members),
+                        }),
+                        region: region_index,
+                    });
+                }
+            }
+        }
+
+        let mut borders = BTreeSet::new();
+        for province in 0..provinces.len() {
+            let Some(region) = hierarchy.region_of(province) else {
+                continue;
+            };
+            for &neighbor in graph.neighbors(province) {
+                if let Some(other) = hierarchy.region_of(neighbor as usize) {
+                    if other != region {
+                        borders.insert((region, other));
+                    }
+                }
+            }
+        }
+        for (region, other) in borders {
+            hierarchy.regions[region].neighbors.push(other as u32);
+        }
+        hierarchy
+    }
+
+    pub fn continents(&self) -> &[Continent] {
+        &self.continents
+    }
+
+    pub fn regions(&self) -> &[Region] {
+        &self.regions
+    }
+
+    pub fn areas(&self) -> &[Area] {
+        &self.areas
+    }
+
+    pub fn area_of(&self, province: usize) -> Option<usize> {
+        self.province_areas
+            .get(province)
+            .copied()
+            .flatten()
+            .map(|area| area as usize)
+    }
+
+    pub fn region_of(&self, province: usize) -> Option<usize> {
+        let area = self.areas.get(self.area_of(province)?)?;
+        Some(area.region as usize)
+    }
+
+    /// Region under a point on the map, if it is over land
+    pub fn region_at(&self, spatial: &ProvincesSpatialIndex, position: Vec2) -> Option<usize> {
+        let (province, _) = spatial.pick_province_at_position(position, HEX_SIZE)?;
+        self.region_of(province.value() as usize)
+    }
+
+    /// A region with its continent, e.g. "Northern Vales, Asteria"
+    pub fn describe_region(&self, region: usize) -> Option<String> {
+        let region = self.regions.get(region)?;
+        let continent = self.continents.get(region.continent as usize)?;
+        Some(format!("{}, {}", region.name, continent.name))
+    }
+
+    /// Where a province lies, by its region and continent
+    pub fn describe(&self, province: usize) -> Option<String> {
+        self.describe_region(self.region_of(province)?)
+    }
+}
+
+/// Connected stretches of land, each in the order it was reached
+fn landmasses(provinces: &[Province], graph: &ProvinceGraph) -> Vec<Vec<usize>> {
+    let is_land = |index: usize| {
+        provinces
+            .get(index)
+            .is_some_and(|province| province.terrain != TerrainType::Ocean)
+    };
+    let mut seen = vec![false; provinces.len()];
+    let mut landmasses = Vec::new();
+    for start in 0..provinces.len() {
+        if seen[start] || !is_land(start) {
+            continue;
+        }
+        seen[start] = true;
+        let mut landmass = vec![start];
+        let mut frontier = VecDeque::from([start]);
+        while let Some(current) = frontier.pop_front() {
+            for &next in graph.neighbors(current) {
+                let next = next as usize;
+                if is_land(next) && !seen[next] {
+                    seen[next] = true;
+                    landmass.push(next);
+                    frontier.push_back(next);
+                }
+            }
+        }
+        landmasses.push(landmass);
+    }
+    landmasses
+}
+
+fn centroid(members: &[usize], provinces: &[Province]) -> Vec2 {
+    if members.is_empty() {
+        return Vec2::ZERO;
+    }
+    members.iter().map(|&index| provinces[index].position).sum::<Vec2>() / members.len() as f32
+}
+
+/// Divide provinces into groups of about `size` neighbors
+///
+/// Seeds are spread out farthest-first and grow over the graph together;
+/// whatever they cannot reach, such as other islands, joins the nearest seed.
+fn partition(members: &[usize], size: usize, graph: &ProvinceGraph, provinces: &[Province]) -> Vec<Vec<usize>> {
+    let position_of = |index: usize| provinces[index].position;
+    let center = centroid(members, provinces);
+    let Some(&first) = members.iter().max_by(|&&a, &&b| {
+        let (a, b) = (
+            position_of(a).distance_squared(center),
+            position_of(b).distance_squared(center),
+        );
+        a.total_cmp(&b)
+    }) else {
+        return Vec::new();
+    };
+
+    let count = members.len().div_ceil(size);
+    let mut seeds = vec![first];
+    let mut gaps: Vec<f32> = members
+        .iter()
+        .map(|&member| position_of(member).distance_squared(position_of(first)))
+        .collect();
+    while seeds.len() < count {
+        let Some((slot, _)) = gaps.iter().enumerate().max_by(|(_, a), (_, b)| a.total_cmp(b)) else {
+            break;
+        };
+        let seed = members[slot];
+        seeds.push(seed);
+        for (gap, &member) in gaps.iter_mut().zip(members) {
+            *gap = gap.min(position_of(member).distance_squared(position_of(seed)));
+        }
+    }
+
+    let slot_of: HashMap<usize, usize> = members
+        .iter()
+        .enumerate()
+        .map(|(slot, &member)| (member, slot))
+        .collect();
+    let mut labels: Vec<Option<usize>> = vec![None; members.len()];
+    let mut frontier = VecDeque::new();
+    for (label, &seed) in seeds.iter().enumerate() {
+        if let Some(&slot) = slot_of.get(&seed) {
+            if labels[slot].is_none() {
+                labels[slot] = Some(label);
+                frontier.push_back((seed, label));
+            }
+        }
+    }
+    while let Some((current, label)) = frontier.pop_front() {
+        for &next in graph.neighbors(current) {
+            if let Some(&slot) = slot_of.get(&(next as usize)) {
+                if labels[slot].is_none() {
+                    labels[slot] = Some(label);
+                    frontier.push_back((next as usize, label));
+                }
+            }
+        }
+    }
+
+    let nearest_seed = |member: usize| {
+        seeds
+            .iter()
+            .enumerate()
+            .min_by(|(_, &a), (_, &b)| {
+                let (a, b) = (
+                    position_of(a).distance_squared(position_of(member)),
+                    position_of(b).distance_squared(position_of(member)),
+                );
+                a.total_cmp(&b)
+            })
+            .map_or(0, |(label, _)| label)
+    };
+    let mut groups = vec![Vec::new(); seeds.len()];
+    for (slot, &member) in members.iter().enumerate() {
+        let label = labels[slot].unwrap_or_else(|| nearest_seed(member));
+        groups[label].push(member);
+    }
+    groups.retain(|group| !group.is_empty());
+    groups
+}
+
+/// The lie of the land a terrain gives a place name
+fn landform(terrain: TerrainType) -> name_generator::Region {
+    use name_generator::Region as Landform;
+    match terrain {
+        TerrainType::Ocean | TerrainType::Beach => Landform::Coastal,
+        TerrainType::River | TerrainType::Wetlands => Landform::River,
+        TerrainType::PolarDesert | TerrainType::Tundra => Landform::Arctic,
+        TerrainType::Alpine => Landform::Mountain,
+        TerrainType::Taiga
+        | TerrainType::BorealForest
+        | TerrainType::TemperateRainforest
+        | TerrainType::TemperateDeciduousForest
+        | TerrainType::MediterraneanForest => Landform::Forest,
+        TerrainType::TemperateGrassland | TerrainType::Savanna => Landform::Plains,
+        TerrainType::Chaparral => Landform::Valley,
+        TerrainType::ColdDesert | TerrainType::SubtropicalDesert | TerrainType::TropicalDesert => Landform::Desert,
+        TerrainType::TropicalRainforest | TerrainType::TropicalSeasonalForest | TerrainType::Mangrove => {
+            Landform::Tropical
+        }
+    }
+}
+
+/// The most common lie of the land among some provinces
+fn dominant_landform(members: &[usize], provinces: &[Province]) -> name_generator::Region {
+    let mut counts: Vec<(name_generator::Region, usize)> = Vec::new();
+    for &index in members {
+        let landform = landform(provinces[index].terrain);
+        match counts.iter_mut().find(|(kind, _)| *kind == landform) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((landform, 1)),
+        }
+    }
+    counts
+        .into_iter()
+        .max_by_key(|&(_, count)| count)
+        .map_or(name_generator::Region::Plains, |(landform, _)| landform)
+}
+
+/// Divide a freshly generated or loaded world into continents, regions, and areas
+pub fn build_region_hierarchy(provinces: &[Province], graph: &ProvinceGraph, seed: u32, commands: &mut Commands) {
+    let hierarchy = RegionHierarchy::build(provinces, graph, seed);
+    debug!(
+        "Region hierarchy built: {} continents, {} regions, {} areas",
+        hierarchy.continents().len(),
+        hierarchy.regions().len(),
+        hierarchy.areas().len()
+    );
+    commands.insert_resource(hierarchy);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::ProvinceId;
+
+    #[test]
+    fn land_divides_into_named_continents_regions_and_areas() {
+        // A 32 by 20 grid: two landmasses split by a strait, and a small island off the second
+        let (width, height) = (32u32, 20u32);
+        let is_land = |column: u32, row: u32| column < 16 || (17..26).contains(&column) || (column == 30 && row < 3);
+        let provinces: Vec<Province> = (0..width * height)
+            .map(|index| {
+                let (column, row) = (index % width, index / width);
+                let mut province = Province {
+                    id: ProvinceId::new(index),
+                    position: Vec2::new(column as f32 * 10.0, row as f32 * 10.0),
+                    ..Province::default()
+                };
+                if is_land(column, row) {
+                    province.terrain = TerrainType::TemperateGrassland;
+                }
+                province.neighbors[0] = (column + 1 < width).then(|| ProvinceId::new(index + 1));
+                province.neighbors[1] = (row + 1 < height).then(|| ProvinceId::new(index + width));
+                province.neighbors[2] = (column > 0).then(|| ProvinceId::new(index - 1));
+                province.neighbors[3] = (row > 0).then(|| ProvinceId::new(index - width));
+                province
+            })
+            .collect();
+        let graph = ProvinceGraph::build(&provinces);
+        let hierarchy = RegionHierarchy::build(&provinces, &graph, 7);
+
+        assert_eq!(hierarchy.continents().len(), 2);
+        let continent_of = |province: usize| {
+            let region = hierarchy.region_of(province)?;
+            Some(hierarchy.regions()[region].continent)
+        };
+        let (west, east, island, sea) = (0, 20, 30, 16);
+        assert_ne!(continent_of(west), continent_of(east));
+        assert_eq!(continent_of(island), continent_of(east));
+        assert_eq!(hierarchy.area_of(sea), None);
+        assert_eq!(hierarchy.describe(sea), None);
+
+        // Every land province is in an area of a fitting size
+        let mut sizes = vec![0; hierarchy.areas().len()];
+        for (index, province) in provinces.iter().enumerate() {
+            let area = hierarchy.area_of(index);
+            assert_eq!(area.is_some(), province.terrain != TerrainType::Ocean);
+            if let Some(area) = area {
+                sizes[area] += 1;
+            }
+        }
+        assert!(sizes.iter().all(|&size| size > 0 && size <= AREA_PROVINCES * 2));
+
+        // The western landmass is two regions across a shared border; the island is a region of its own
+        let western: Vec<usize> = (0..hierarchy.regions().len())
+            .filter(|&region| Some(hierarchy.regions()[region].continent) == continent_of(west))
+            .collect();
+        let [a, b] = western[..] else {
+            panic!("expected two western regions, found {:?}", western);
+        };
+        assert_eq!(hierarchy.regions()[a].neighbors, vec![b as u32]);
+        assert_eq!(hierarchy.regions()[b].neighbors, vec![a as u32]);
+        assert_ne!(hierarchy.region_of(island), hierarchy.region_of(east));
+        let eastern = &hierarchy.continents()[continent_of(east).unwrap_or_default() as usize];
+        assert!(hierarchy.describe(island).unwrap_or_default().ends_with(&eastern.name));
+
+        // The same world and seed always divide and name the same way
+        assert_eq!(RegionHierarchy::build(&provinces, &graph, 7), hierarchy);
+        assert_eq!(Bearing::from_angle(std::f32::consts::FRAC_PI_2), Bearing::North);
+        assert_eq!(Bearing::from_angle(-2.4), Bearing::SouthWest);
+    }
+}
@@ -16,7 +16,8 @@ use std::fmt;
 
 /// Province entity marker component
 /// Links an entity to its data in ProvinceStorage
-#[derive(Component, Debug, Clone, Copy)]
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
 pub struct ProvinceEntity {
     /// Index into ProvinceStorage.provinces array
     pub storage_index: usize,
@@ -264,6 +265,10 @@ pub struct Province {
     pub position: Vec2,
 
     /// Nation entity that owns/controls this province
+    ///
+    /// Not serialized: entities are only valid for one session, so saves record
+    /// the owner's `NationId` alongside the provinces instead.
+    #[serde(skip)]
     pub owner_entity: Option<Entity>,
 
     /// Cultural identity of this province (assigned based on geography)
@@ -444,6 +449,7 @@ impl Province {
 /// Used to identify province entities in queries without loading data.
 /// All province entities must have this component.
 #[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
 pub struct ProvinceMarker;
 
 /// ALL province data in a single component (cache-optimal for 3M entities)
@@ -452,6 +458,7 @@ pub struct ProvinceMarker;
 /// archetype fragmentation and maximize cache efficiency during iteration.
 /// Minerals are embedded directly rather than as separate components.
 #[derive(Component, Debug, Clone, Reflect, Serialize, Deserialize)]
+#[reflect(Component)]
 pub struct ProvinceData {
     /// Unique identifier for this province
     pub id: ProvinceId,
@@ -600,6 +607,7 @@ impl Default for ProvinceData {
 /// Stores direct Entity references to neighboring provinces for O(1) lookups.
 /// Uses the standard hexagonal direction order: NE, E, SE, SW, W, NW.
 #[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 pub struct ProvinceNeighbors {
     /// Entity references to the 6 neighboring provinces
     /// Order: NE, E, SE, SW, W, NW (matches HexDirection enum)
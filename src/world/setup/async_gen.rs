@@ -34,6 +34,7 @@ pub async fn generate_world_async(
             world_data: None,
             error_message: None,
             generation_metrics: None,
+            preview: None,
         });
         if let Err(e) = result {
             error!("Failed to send progress update: {:?}", e);
@@ -51,10 +52,20 @@ pub async fn generate_world_async(
             world_data: None,
             error_message: Some(e.to_string()),
             generation_metrics: None,
+            preview: None,
         });
         return;
     }
 
+    if settings.generation_version != crate::world::GENERATION_VERSION {
+        warn!(
+            "Settings target generation v{} but this build generates v{}; seed {} will not reproduce the original world",
+            settings.generation_version,
+            crate::world::GENERATION_VERSION,
+            settings.seed
+        );
+    }
+
     // Generate world data with progress reporting
     let start_time = std::time::Instant::now();
 
@@ -62,6 +73,12 @@ pub async fn generate_world_async(
     let progress_callback = |step: &str, progress: f32| {
         send_progress(step, progress);
     };
+    // And one that streams each stage's map to the loading screen
+    let preview_callback = |preview: crate::world::GenerationPreview| {
+        if let Err(e) = progress_sender.try_send(GenerationProgress::preview(preview)) {
+            error!("Failed to send generation preview: {:?}", e);
+        }
+    };
 
     // Choose between GPU-accelerated and CPU-only generation
     let world_result = if let Some(gpu_res) = gpu_resources.as_ref() {
@@ -82,7 +99,8 @@ pub async fn generate_world_async(
                 settings.river_density,
                 settings.climate_type,
             )
-            .build_with_progress(Some(progress_callback))
+            .with_erosion(settings.erosion_iterations, settings.erosion_droplets.multiplier())
+            .build_with_preview(Some(progress_callback), Some(preview_callback))
         }
     } else {
         info!("GPU not available - using CPU generation");
@@ -94,7 +112,8 @@ pub async fn generate_world_async(
             settings.river_density,
             settings.climate_type,
         )
-        .build_with_progress(Some(progress_callback))
+        .with_erosion(settings.erosion_iterations, settings.erosion_droplets.multiplier())
+        .build_with_preview(Some(progress_callback), Some(preview_callback))
     };
 
     let generation_time = start_time.elapsed().as_millis() as f32;
@@ -111,6 +130,7 @@ pub async fn generate_world_async(
                 world_data: Some(world),
                 error_message: None,
                 generation_metrics: None,
+                preview: None,
             });
         }
         Err(e) => {
@@ -122,6 +142,7 @@ pub async fn generate_world_async(
                 world_data: None,
                 error_message: Some(e.to_string()),
                 generation_metrics: None,
+                preview: None,
             });
             return;
         }
@@ -1,13 +1,17 @@
 //! GPU-accelerated world generation
 //!
 //! Provides hybrid GPU/CPU world generation for improved performance.
+//! Elevation, temperature, and moisture run as compute passes when GPU
+//! generation is turned on and the render device is available; the rest of
+//! the pipeline stays on the CPU. It is off by default, since the passes are
+//! not bit-exact across hardware.
 
 use async_channel::Sender;
-use bevy::log::{error, info};
+use bevy::log::{error, info, warn};
 use rand::{rngs::StdRng, SeedableRng};
 
 use super::progress::GenerationProgress;
-use super::super::WorldGenerationSettings;
+use super::super::{GenerationPreview, WorldGenerationSettings};
 use crate::resources::MapDimensions;
 use crate::world::gpu::GpuProvinceBuilder;
 
@@ -32,11 +36,24 @@ pub fn generate_world_with_gpu_acceleration(
             world_data: None,
             error_message: None,
             generation_metrics: None,
+            preview: None,
         });
         if let Err(e) = result {
             error!("Failed to send progress update: {:?}", e);
         }
     };
+    let send_preview = |preview: GenerationPreview| {
+        if let Err(e) = progress_sender.try_send(GenerationProgress::preview(preview)) {
+            error!("Failed to send generation preview: {:?}", e);
+        }
+    };
+
+    if gpu_resources.fields.is_some() {
+        warn!(
+            "GPU field passes are not bit-exact across hardware; seed {} may not reproduce this world elsewhere",
+            settings.seed
+        );
+    }
 
     // Step 1: Generate provinces with GPU acceleration
     send_progress("Generating provinces with GPU acceleration...", 0.1);
@@ -56,6 +73,7 @@ pub fn generate_world_with_gpu_acceleration(
         fallback_on_failure: true,
         timeout_seconds: 30.0,
         max_retries: 3,
+        fields: gpu_resources.fields.clone(),
     };
 
     let mut gpu_state = crate::world::gpu::GpuGenerationState::default();
@@ -81,17 +99,13 @@ pub fn generate_world_with_gpu_acceleration(
 
     // Step 2: Apply erosion simulation for realistic terrain
     send_progress("Applying erosion simulation...", 0.2);
-    let erosion_iterations = match dimensions.provinces_per_row * dimensions.provinces_per_col {
-        n if n < 400_000 => 3_000,
-        n if n < 700_000 => 5_000,
-        _ => 8_000,
-    };
-    crate::world::apply_erosion_to_provinces(
-        &mut provinces,
-        dimensions,
-        &mut rng,
-        erosion_iterations,
+    let erosion = crate::world::ErosionQuality::for_world(
+        dimensions.provinces_per_row * dimensions.provinces_per_col,
+        settings.erosion_iterations,
+        settings.erosion_droplets.multiplier(),
     );
+    crate::world::apply_erosion_to_provinces(&mut provinces, dimensions, &mut rng, erosion);
+    send_preview(GenerationPreview::elevation(&provinces, dimensions));
 
     // Step 3: Calculate ocean depths
     send_progress("Calculating ocean depths...", 0.3);
@@ -103,7 +117,9 @@ pub fn generate_world_with_gpu_acceleration(
         &mut provinces,
         dimensions,
         settings.climate_type,
+        gpu_resources.fields.as_ref(),
     );
+    send_preview(GenerationPreview::climate(&provinces, dimensions, settings.seed));
 
     // Step 5: Generate river systems
     send_progress("Creating river systems...", 0.5);
@@ -114,6 +130,7 @@ pub fn generate_world_with_gpu_acceleration(
             error_message: format!("Failed to generate rivers: {}", e),
             error_type: crate::world::generation::WorldGenerationErrorType::GenerationFailed,
         })?;
+    send_preview(GenerationPreview::rivers(&provinces, dimensions, settings.seed, &river_system.river_tiles));
 
     // Step 6: Calculate agriculture values
     send_progress("Calculating agriculture values...", 0.55);
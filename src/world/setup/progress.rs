@@ -2,7 +2,7 @@
 //!
 //! Provides progress reporting structures and constants for world generation.
 
-use super::super::World;
+use super::super::{GenerationPreview, World};
 
 /// Loading progress milestones - more granular for better user feedback
 pub const PROGRESS_START: f32 = 0.0;
@@ -28,4 +28,20 @@ pub struct GenerationProgress {
     pub world_data: Option<World>, // Only present when completed
     pub error_message: Option<String>, // Error message if generation failed
     pub generation_metrics: Option<crate::diagnostics::GenerationMetrics>, // Metrics for error context
+    pub preview: Option<GenerationPreview>, // Map snapshot after a finished stage
+}
+
+impl GenerationProgress {
+    /// A map snapshot, leaving the step and progress bar as they are
+    pub fn preview(preview: GenerationPreview) -> Self {
+        Self {
+            step: String::new(),
+            progress: 0.0,
+            completed: false,
+            world_data: None,
+            error_message: None,
+            generation_metrics: None,
+            preview: Some(preview),
+        }
+    }
 }
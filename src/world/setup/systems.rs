@@ -11,11 +11,12 @@ use super::types::{AsyncWorldGeneration, WorldGenerationTransitionDelay, Pending
 use crate::world::provinces::ProvinceNeighbors;
 use super::validation::count_cultures;
 use super::super::{
-    build_world_mesh, ProvinceStorage, ProvincesSpatialIndex, WorldGenerationSettings, WorldMeshHandle,
-    provinces_to_bundles, set_neighbor_entities, ProvinceEntityOrder,
+    build_world_mesh, GenerationPreview, MapDimensions, ProvinceStorage, ProvincesSpatialIndex, WorldGenerationSettings,
+    WorldMeshHandle, provinces_to_bundles, set_neighbor_entities, ProvinceEntityOrder, ProvinceGraph,
+    build_region_hierarchy,
 };
 use crate::relationships::ControlledBy;
-use crate::loading::{set_loading_progress, LoadingState};
+use crate::loading::{set_loading_preview, set_loading_progress, LoadingState};
 use crate::states::{GameState, RequestStateTransition};
 
 /// Exclusive system to spawn province entities using World::spawn_batch
@@ -182,6 +183,8 @@ pub fn start_async_world_generation(
     _gpu_metrics: Option<ResMut<crate::world::gpu::GpuPerformanceMetrics>>,
     validation_config: Option<Res<crate::world::gpu::ValidationConfig>>,
     mut gpu_request: Option<ResMut<crate::world::gpu::GpuGenerationRequest>>,
+    render_device: Option<Res<bevy::render::renderer::RenderDevice>>,
+    render_queue: Option<Res<bevy::render::renderer::RenderQueue>>,
 ) {
     info!("Starting async world generation");
 
@@ -203,6 +206,9 @@ pub fn start_async_world_generation(
                 use_gpu: config.use_gpu,
                 timeout_ms: (config.timeout_seconds * 1000.0) as u64,
                 validation_enabled: validation_config.is_some(),
+                fields: render_device.zip(render_queue).map(|(device, queue)| {
+                    crate::world::gpu::GpuFieldContext::new((*device).clone(), (*queue).clone())
+                }),
             })
         } else {
             None
@@ -299,7 +305,7 @@ fn spawn_nation_entities(
             .spawn((
                 crate::nations::NationBundle {
                     nation: nation.clone(),
-                    economy: crate::nations::Economy::default(),
+                    economy: crate::nations::EconomicFocus::default().economy(),
                     transform: Transform::default(),
                     visibility: Visibility::default(),
                     pressure_vector: crate::simulation::PressureVector::default(),
@@ -312,20 +318,10 @@ fn spawn_nation_entities(
                     laws: crate::nations::NationLaws::default(),
                 },
                 crate::nations::OwnsTerritory::default(),
-                crate::nations::Governance {
-                    government_type,
-                    stability: 0.75,
-                    reform_pressure: 0.0,
-                    tradition_strength: government_type.mechanics().reform_resistance,
-                    institution_strength: 1.0,
-                    last_transition: None,
-                    days_in_power: 0,
-                    legitimacy: 0.75,
-                    legitimacy_trend: 0.0,
-                    legitimacy_factors: crate::nations::LegitimacyFactors::for_government_type(government_type),
-                },
+                crate::nations::Governance::founded(government_type),
                 crate::nations::PoliticalPressure::default(),
                 crate::nations::GovernmentHistory::new(government_type),
+                crate::nations::EconomicFocus::default(),
                 *nation_id,
             ))
             .id();
@@ -410,14 +406,19 @@ fn spawn_territory_entities(
     );
 }
 
-/// Spawn house entities
+/// Spawn house entities, each ruling the nation it was created with
 fn spawn_house_entities(
     houses: Vec<crate::nations::House>,
+    nations: &[(crate::nations::NationId, crate::nations::Nation)],
+    nation_entities: &std::collections::HashMap<crate::nations::NationId, Entity>,
     commands: &mut Commands,
 ) {
     info!("Spawning {} house entities...", houses.len());
-    for house in houses {
-        commands.spawn(house);
+    for (house, (nation_id, _)) in houses.into_iter().zip(nations) {
+        let mut house_entity = commands.spawn(house);
+        if let Some(&nation_entity) = nation_entities.get(nation_id) {
+            house_entity.insert(crate::relationships::RulesOver(nation_entity));
+        }
     }
     info!("House entities spawned successfully");
 }
@@ -525,10 +526,30 @@ fn handle_task_termination(
     });
 }
 
+/// Replace the loading screen's map preview with a newer stage
+fn show_generation_preview(
+    preview: &GenerationPreview,
+    images: &mut Assets<Image>,
+    loading_state: &mut LoadingState,
+) {
+    let image = preview.to_image();
+    // Reuse the previous stage's image so the preview node keeps its handle
+    if let Some(handle) = loading_state.details.preview.clone() {
+        if let Some(existing) = images.get_mut(&handle) {
+            *existing = image;
+            set_loading_preview(loading_state, handle, preview.stage.label());
+            return;
+        }
+    }
+    let handle = images.add(image);
+    set_loading_preview(loading_state, handle, preview.stage.label());
+}
+
 /// Poll async world generation progress and handle completion
 pub fn poll_async_world_generation(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
+    mut images: ResMut<Assets<Image>>,
     mut loading_state: ResMut<LoadingState>,
     mut state_events: MessageWriter<RequestStateTransition>,
     async_generation: Option<ResMut<AsyncWorldGeneration>>,
@@ -539,6 +560,10 @@ pub fn poll_async_world_generation(
 
     // Check for progress updates (non-blocking)
     while let Ok(progress) = generation.progress_receiver.try_recv() {
+        if let Some(preview) = progress.preview {
+            show_generation_preview(&preview, &mut images, &mut loading_state);
+            continue;
+        }
         if progress.completed {
             if let Some(mut world) = progress.world_data {
                 info!("Async world generation completed, processing...");
@@ -566,7 +591,10 @@ pub fn poll_async_world_generation(
                 );
 
                 // Phase 6: Spawn nations with ruling houses and governance
-                let nation_settings = crate::nations::NationGenerationSettings::default();
+                let nation_settings = crate::nations::NationGenerationSettings {
+                    custom_nations: generation.settings.custom_nations.clone(),
+                    ..Default::default()
+                };
                 info!("About to call spawn_nations...");
                 let (nations, houses, governments, province_ownership) = crate::nations::spawn_nations(
                     &nation_settings,
@@ -576,6 +604,22 @@ pub fn poll_async_world_generation(
                 info!("spawn_nations completed! Got {} nations, {} houses, {} governments, and {} ownership entries",
                       nations.len(), houses.len(), governments.len(), province_ownership.len());
 
+                // Reveal the nations on the preview while the rest is spawned
+                let nation_colors: std::collections::HashMap<_, _> =
+                    nations.iter().map(|(id, nation)| (*id, nation.color)).collect();
+                let province_colors = province_ownership
+                    .iter()
+                    .filter_map(|(id, provinces)| Some((nation_colors.get(id)?, provinces)))
+                    .flat_map(|(color, provinces)| provinces.iter().map(|&province| (province, *color)))
+                    .collect();
+                let preview = GenerationPreview::nations(
+                    &province_storage.provinces,
+                    MapDimensions::from_world_size(&generation.settings.world_size),
+                    world.seed,
+                    &province_colors,
+                );
+                show_generation_preview(&preview, &mut images, &mut loading_state);
+
                 // Phase 7: Build territories from provinces
                 info!("Building territories from {} provinces...", province_storage.provinces.len());
                 let territories_by_nation = crate::nations::build_territories_from_provinces(
@@ -629,10 +673,13 @@ pub fn poll_async_world_generation(
                 );
 
                 // Phase 13: Spawn house entities
-                spawn_house_entities(houses, &mut commands);
+                spawn_house_entities(houses, &nations, &nation_entities, &mut commands);
 
-                // Phase 14: Initialize coastal cache
+                // Phase 14: Initialize coastal cache, province graph, and region hierarchy
                 initialize_coastal_cache(&province_storage, &mut commands);
+                let graph = ProvinceGraph::build(&province_storage.provinces);
+                build_region_hierarchy(&province_storage.provinces, &graph, world.seed, &mut commands);
+                commands.insert_resource(graph);
 
                 // Phase 15: Insert province storage
                 info!("Inserting province storage with {} provinces...", province_storage.provinces.len());
@@ -0,0 +1,101 @@
+//! Terrain attrition profiles - how each terrain wears down armies
+//!
+//! Deserts drain an army's water, cold country kills soldiers without winter
+//! gear when the winter bites, and jungles and swamps spread fever. Every
+//! terrain has a built-in profile; the base game's `config/base/attrition.ron`
+//! and active mods (through their own `config/attrition.ron`) can override
+//! the profile of any terrain.
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use super::types::TerrainType;
+use crate::modding::ModManager;
+
+/// Annual mean temperature (Celsius) at which winter starts to threaten armies
+const WINTER_ONSET_TEMPERATURE: f32 = 5.0;
+/// Degrees below the onset at which winter is at its most severe
+const WINTER_SEVERITY_RANGE: f32 = 25.0;
+
+/// Yearly attrition an army campaigning in a terrain faces
+#[derive(Debug, Clone, Copy, PartialEq, Default, Reflect, serde::Serialize, serde::Deserialize)]
+pub struct AttritionProfile {
+    /// Water needed per point of strength; unmet need becomes losses
+    #[serde(default)]
+    pub water: f32,
+    /// Share of strength lost in a severe winter without winter gear
+    #[serde(default)]
+    pub cold: f32,
+    /// Share of strength lost to disease
+    #[serde(default)]
+    pub disease: f32,
+}
+
+impl TerrainType {
+    /// Built-in attrition profile of the terrain
+    pub fn attrition_profile(&self) -> AttritionProfile {
+        let (water, cold, disease) = match self {
+            TerrainType::SubtropicalDesert | TerrainType::TropicalDesert => (0.3, 0.0, 0.02),
+            TerrainType::ColdDesert => (0.2, 0.15, 0.0),
+            TerrainType::PolarDesert => (0.1, 0.4, 0.0),
+            TerrainType::Tundra => (0.0, 0.35, 0.0),
+            TerrainType::Alpine => (0.0, 0.25, 0.0),
+            TerrainType::Taiga | TerrainType::BorealForest => (0.0, 0.2, 0.0),
+            TerrainType::Chaparral => (0.05, 0.0, 0.0),
+            TerrainType::Savanna => (0.05, 0.0, 0.05),
+            TerrainType::TropicalRainforest => (0.0, 0.0, 0.2),
+            TerrainType::Wetlands | TerrainType::Mangrove => (0.0, 0.0, 0.15),
+            TerrainType::TropicalSeasonalForest => (0.0, 0.0, 0.1),
+            _ => (0.0, 0.0, 0.0),
+        };
+        AttritionProfile { water, cold, disease }
+    }
+}
+
+/// How hard winter is in a province, from its annual mean temperature (0.0-1.0)
+pub fn winter_severity(temperature: f32) -> f32 {
+    ((WINTER_ONSET_TEMPERATURE - temperature) / WINTER_SEVERITY_RANGE).clamp(0.0, 1.0)
+}
+
+/// Attrition profile of each terrain, built-in values overridden by configuration
+#[derive(Resource, Debug, Clone, Default)]
+pub struct AttritionProfiles {
+    pub overrides: HashMap<TerrainType, AttritionProfile>,
+}
+
+impl AttritionProfiles {
+    pub fn get(&self, terrain: TerrainType) -> AttritionProfile {
+        self.overrides
+            .get(&terrain)
+            .copied()
+            .unwrap_or_else(|| terrain.attrition_profile())
+    }
+}
+
+/// Rebuild the profiles from the merged mod configuration
+pub fn rebuild_attrition_profiles(mut profiles: ResMut<AttritionProfiles>, mods: Option<Res<ModManager>>) {
+    if !profiles.is_added() && !mods.as_ref().is_some_and(|mods| mods.is_changed()) {
+        return;
+    }
+
+    profiles.overrides = mods.map(|mods| mods.get_config().attrition.clone()).unwrap_or_default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn configured_profiles_override_the_builtin_ones() {
+        let mut profiles = AttritionProfiles::default();
+        assert!(profiles.get(TerrainType::TropicalDesert).water > 0.0);
+        assert!(profiles.get(TerrainType::TropicalRainforest).disease > 0.0);
+
+        profiles
+            .overrides
+            .insert(TerrainType::TropicalDesert, AttritionProfile::default());
+        assert_eq!(profiles.get(TerrainType::TropicalDesert).water, 0.0);
+        assert_eq!(winter_severity(20.0), 0.0);
+        assert_eq!(winter_severity(-30.0), 1.0);
+    }
+}
@@ -7,9 +7,10 @@
 use super::super::provinces::Province;
 use super::types::TerrainType;
 use crate::world::ClimateType;
+use crate::world::gpu::{ClimateFieldParams, GpuFieldContext};
 use crate::math::{exponential_smooth, lerp};
 use crate::parallel::{parallel_map, parallel_zip_mutate, parallel_enumerate};
-use bevy::log::{debug, info};
+use bevy::log::{debug, info, warn};
 use bevy::prelude::Vec2;
 use std::collections::{HashMap, VecDeque};
 use std::f32::consts::PI;
@@ -49,6 +50,12 @@ const DESERT_RAINFALL: f32 = 250.0;
 /// Maximum distance for ocean influence (km)
 const OCEAN_INFLUENCE_DISTANCE: f32 = 500.0;
 
+/// Stand-in for "no ocean reachable" on the GPU, where infinities aren't safe (km)
+const GPU_NO_OCEAN_DISTANCE: f32 = 1.0e9;
+
+/// Rounds of moisture propagation between neighbors
+const MOISTURE_ITERATIONS: usize = 2;
+
 /// Elevation threshold for alpine climate (meters)
 const ALPINE_ELEVATION: f32 = 3000.0;
 
@@ -135,6 +142,8 @@ pub struct ClimateSystem {
     dimensions: crate::resources::MapDimensions,
     /// Climate type for temperature calculations
     climate_type: ClimateType,
+    /// GPU for the temperature and moisture passes, if generation is GPU-accelerated
+    gpu: Option<GpuFieldContext>,
 }
 
 impl ClimateSystem {
@@ -143,9 +152,15 @@ impl ClimateSystem {
             climates: vec![Climate::default(); province_count],
             dimensions,
             climate_type,
+            gpu: None,
         }
     }
 
+    pub fn with_gpu(mut self, gpu: Option<GpuFieldContext>) -> Self {
+        self.gpu = gpu;
+        self
+    }
+
     /// Run full climate simulation with unified passes for memory efficiency
     pub fn simulate(&mut self, provinces: &[Province]) {
         info!("Starting climate simulation with unified passes...");
@@ -154,10 +169,14 @@ impl ClimateSystem {
         self.calculate_ocean_distances(provinces);
 
         // Step 2 & 3 UNIFIED: Calculate temperatures AND winds in single pass
-        self.calculate_temperatures_and_winds(provinces);
+        if !self.gpu_temperatures_and_winds(provinces) {
+            self.calculate_temperatures_and_winds(provinces);
+        }
 
         // Step 4: Simulate moisture propagation
-        self.propagate_moisture(provinces);
+        if !self.gpu_propagate_moisture(provinces) {
+            self.propagate_moisture(provinces);
+        }
 
         // Step 5: Apply rain shadows
         self.apply_rain_shadows(provinces);
@@ -290,9 +309,6 @@ impl ClimateSystem {
                 .push(idx);
         }
 
-        // Reduced iterations for better performance
-        const MOISTURE_ITERATIONS: usize = 2; // Further reduced from 3
-
         for iteration in 0..MOISTURE_ITERATIONS {
             debug!(
                 "      Moisture propagation iteration {}/{}",
@@ -361,6 +377,92 @@ impl ClimateSystem {
         info!("      Moisture propagation complete");
     }
 
+    /// Per-province inputs to the GPU passes: latitude position, elevation,
+    /// ocean distance, and whether it is ocean
+    fn gpu_field_inputs(&self, provinces: &[Province]) -> Vec<[f32; 4]> {
+        provinces
+            .iter()
+            .zip(&self.climates)
+            .map(|(province, climate)| {
+                [
+                    province.position.y,
+                    province.elevation.value(),
+                    climate.ocean_distance.min(GPU_NO_OCEAN_DISTANCE),
+                    if province.terrain == TerrainType::Ocean { 1.0 } else { 0.0 },
+                ]
+            })
+            .collect()
+    }
+
+    /// Temperatures and winds on the GPU; false if the CPU should do it instead
+    fn gpu_temperatures_and_winds(&mut self, provinces: &[Province]) -> bool {
+        let Some(gpu) = self.gpu.as_ref() else {
+            return false;
+        };
+        let (equator_temp, pole_temp) = get_climate_temperatures(self.climate_type);
+        let params = ClimateFieldParams {
+            count: provinces.len() as u32,
+            equator_temp,
+            pole_temp,
+            y_min: self.dimensions.bounds.y_min,
+            y_max: self.dimensions.bounds.y_max,
+            _padding: [0.0; 3],
+        };
+
+        match gpu.temperatures(&self.gpu_field_inputs(provinces), params) {
+            Ok(fields) => {
+                for (climate, [temperature, wind_direction, wind_strength, _]) in self.climates.iter_mut().zip(fields) {
+                    climate.temperature = temperature;
+                    climate.wind_direction = wind_direction;
+                    climate.wind_strength = wind_strength;
+                }
+                debug!("Calculated temperatures and winds on the GPU");
+                true
+            }
+            Err(e) => {
+                warn!("GPU temperature pass failed, using CPU: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Moisture propagation on the GPU; false if the CPU should do it instead
+    fn gpu_propagate_moisture(&mut self, provinces: &[Province]) -> bool {
+        let Some(gpu) = self.gpu.as_ref() else {
+            return false;
+        };
+        let winds: Vec<[f32; 4]> = self
+            .climates
+            .iter()
+            .map(|climate| [climate.temperature, climate.wind_direction, climate.wind_strength, 0.0])
+            .collect();
+        let neighbors: Vec<i32> = provinces
+            .iter()
+            .flat_map(|province| province.neighbor_indices.map(|neighbor| neighbor.map_or(-1, |idx| idx as i32)))
+            .collect();
+        let rainfall: Vec<f32> = self.climates.iter().map(|climate| climate.rainfall).collect();
+
+        match gpu.diffuse_moisture(
+            &self.gpu_field_inputs(provinces),
+            &winds,
+            &neighbors,
+            &rainfall,
+            MOISTURE_ITERATIONS as u32,
+        ) {
+            Ok(rainfall) => {
+                for (climate, rainfall) in self.climates.iter_mut().zip(rainfall) {
+                    climate.rainfall = rainfall;
+                }
+                debug!("Propagated moisture on the GPU");
+                true
+            }
+            Err(e) => {
+                warn!("GPU moisture pass failed, using CPU: {}", e);
+                false
+            }
+        }
+    }
+
     /// Apply rain shadow effects from mountains (PARALLELIZED)
     fn apply_rain_shadows(&mut self, provinces: &[Province]) {
         info!("    Applying rain shadow effects...");
@@ -563,6 +665,7 @@ pub fn apply_climate_to_provinces(
     provinces: &mut [crate::world::Province],
     dimensions: crate::resources::MapDimensions,
     climate_type: ClimateType,
+    gpu: Option<&GpuFieldContext>,
 ) -> super::storage::ClimateStorage {
     // Count how many provinces actually need climate calculations
     let land_provinces = provinces
@@ -576,7 +679,8 @@ pub fn apply_climate_to_provinces(
         climate_type
     );
 
-    let mut climate_system = ClimateSystem::new(dimensions, provinces.len(), climate_type);
+    let mut climate_system =
+        ClimateSystem::new(dimensions, provinces.len(), climate_type).with_gpu(gpu.cloned());
     climate_system.simulate(provinces);
 
     // Create climate storage for runtime visualization
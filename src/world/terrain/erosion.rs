@@ -55,6 +55,12 @@ const SMOOTHING_RADIUS: usize = 2;
 /// Inertia - how much previous direction affects new direction
 const INERTIA: f32 = 0.3;
 
+/// Droplets traced against one snapshot of the heightmap
+const DROPLET_BATCH: usize = 2048;
+
+/// Rows of cells in each region tile
+const TILE_ROWS: usize = 32;
+
 /// A water droplet for hydraulic erosion simulation
 #[derive(Debug, Clone)]
 struct WaterDroplet {
@@ -157,23 +163,103 @@ impl HeightMap {
 
     /// Deposit or erode at position
     pub fn modify_at(&mut self, pos: Vec2, amount: f32) {
+        let mut changes = Vec::with_capacity(4);
+        self.spread(pos, amount, &mut changes);
+        for (cell, change) in changes {
+            self.data[cell] = (self.data[cell] + change).clamp(0.0, 1.0);
+        }
+    }
+
+    /// Split a change at a position between its neighboring cells by proximity
+    fn spread(&self, pos: Vec2, amount: f32, changes: &mut Vec<(usize, f32)>) {
         let x = (pos.x / self.cell_size) as usize;
         let y = (pos.y / self.cell_size) as usize;
 
-        // Distribute change to neighboring cells based on proximity
         for dx in 0..=1 {
             for dy in 0..=1 {
                 let nx = (x + dx).min(self.width - 1);
                 let ny = (y + dy).min(self.height - 1);
 
                 let cell_pos = Vec2::new(nx as f32 * self.cell_size, ny as f32 * self.cell_size);
+                let weight = linear_falloff(pos.distance(cell_pos), self.cell_size);
+                changes.push((ny * self.width + nx, amount * weight));
+            }
+        }
+    }
 
-                let distance = pos.distance(cell_pos);
-                let weight = linear_falloff(distance, self.cell_size);
-
-                let current = self.get(nx, ny);
-                self.set(nx, ny, current + amount * weight);
+    /// Follow one droplet downhill, returning the per-cell height changes it
+    /// would make, in order
+    fn trace_droplet(&self, seed: u64) -> Vec<(usize, f32)> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let start_x = rng.gen_range(0.0..self.width as f32) * self.cell_size;
+        let start_y = rng.gen_range(0.0..self.height as f32) * self.cell_size;
+        let mut droplet = WaterDroplet::new(Vec2::new(start_x, start_y));
+        let max_x = (self.width - 1) as f32 * self.cell_size;
+        let max_y = (self.height - 1) as f32 * self.cell_size;
+
+        let mut changes = Vec::new();
+        while droplet.lifetime < MAX_DROPLET_LIFETIME && droplet.water > 0.001 {
+            let gradient = self.get_gradient(droplet.position);
+            let flow_dir = -gradient.normalize_or_zero();
+
+            droplet.velocity = droplet.velocity * INERTIA + flow_dir * (1.0 - INERTIA);
+            droplet.velocity = droplet.velocity.normalize_or_zero() * droplet.velocity.length().min(1.0);
+
+            // Move droplet, keeping it within bounds
+            let old_pos = droplet.position;
+            droplet.position += droplet.velocity * self.cell_size;
+            droplet.position.x = droplet.position.x.clamp(0.0, max_x);
+            droplet.position.y = droplet.position.y.clamp(0.0, max_y);
+
+            let height_diff = self.get_interpolated(droplet.position) - self.get_interpolated(old_pos);
+            let slope = gradient.length().max(MIN_SLOPE);
+            let capacity = slope * droplet.water * droplet.velocity.length() * SEDIMENT_CAPACITY;
+
+            if height_diff > 0.0 || droplet.sediment > capacity {
+                // Deposit sediment
+                let amount_to_deposit = if height_diff > 0.0 {
+                    height_diff.min(droplet.sediment)
+                } else {
+                    (droplet.sediment - capacity) * DEPOSITION_RATE
+                };
+                droplet.sediment -= amount_to_deposit;
+                self.spread(old_pos, amount_to_deposit, &mut changes);
+            } else {
+                // Erode terrain
+                let amount_to_erode = ((capacity - droplet.sediment) * EROSION_RATE).min(-height_diff);
+                droplet.sediment += amount_to_erode;
+                self.spread(old_pos, -amount_to_erode, &mut changes);
             }
+
+            droplet.water *= 1.0 - EVAPORATION_RATE;
+            droplet.lifetime += 1;
+        }
+
+        changes
+    }
+}
+
+/// How much erosion world generation runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErosionQuality {
+    /// Rounds of hydraulic erosion, thermal erosion, and smoothing
+    pub iterations: usize,
+    /// Droplets simulated in each round
+    pub droplets: usize,
+}
+
+impl ErosionQuality {
+    /// Quality for a world of `province_count` provinces, with the droplet
+    /// count scaled from the size's baseline by `droplet_multiplier`
+    pub fn for_world(province_count: u32, iterations: u32, droplet_multiplier: f32) -> Self {
+        let baseline = match province_count {
+            n if n < 400_000 => 3_000.0,
+            n if n < 700_000 => 5_000.0,
+            _ => 8_000.0,
+        };
+        Self {
+            iterations: iterations.max(1) as usize,
+            droplets: (baseline * droplet_multiplier.max(0.0)) as usize,
         }
     }
 }
@@ -189,10 +275,10 @@ impl ErosionSystem {
     }
 
     /// Run full erosion simulation
-    pub fn erode(&mut self, rng: &mut StdRng, iterations: usize) {
+    pub fn erode(&mut self, rng: &mut StdRng, quality: ErosionQuality) {
         info!(
-            "  Starting erosion simulation ({} iterations)...",
-            iterations
+            "  Starting erosion simulation ({} iterations of {} droplets)...",
+            quality.iterations, quality.droplets
         );
         info!(
             "  Heightmap dimensions: {}x{} ({} total cells)",
@@ -201,18 +287,11 @@ impl ErosionSystem {
             self.heightmap.width * self.heightmap.height
         );
 
-        // Reduced passes for better performance (was 3)
-        let num_passes = if self.heightmap.width * self.heightmap.height > 100_000 {
-            1
-        } else {
-            2
-        };
-
-        for pass in 0..num_passes {
-            info!("    Erosion pass {}/{}", pass + 1, num_passes);
+        for pass in 0..quality.iterations {
+            info!("    Erosion pass {}/{}", pass + 1, quality.iterations);
 
             // Hydraulic erosion - water carving channels
-            self.hydraulic_erosion(rng, iterations / num_passes);
+            self.hydraulic_erosion(rng, quality.droplets);
 
             // Thermal erosion - material sliding down slopes
             info!(
@@ -232,225 +311,50 @@ impl ErosionSystem {
     }
 
     /// Hydraulic erosion - water flowing and carving terrain (PARALLELIZED)
+    ///
+    /// Droplets run in batches, each traced in parallel against the heightmap
+    /// as it stood when the batch began. Their changes are bucketed into region
+    /// tiles - bands of rows that each own a disjoint slice of the heightmap -
+    /// and every tile applies its bucket in droplet order on its own thread.
+    /// Each droplet's RNG is seeded from its index, so a seed gives the same
+    /// terrain however many threads run it.
     fn hydraulic_erosion(&mut self, rng: &mut StdRng, droplets: usize) {
-        let batch_size = 500; // Process 500 droplets at a time
-        let num_batches = (droplets + batch_size - 1) / batch_size;
-
         let base_seed = rng.r#gen::<u64>();
+        let tile_cells = TILE_ROWS * self.heightmap.width;
+        let mut tiles: Vec<Vec<(usize, f32)>> = vec![Vec::new(); self.heightmap.data.len().div_ceil(tile_cells)];
+
+        for batch_start in (0..droplets).step_by(DROPLET_BATCH) {
+            let batch_end = (batch_start + DROPLET_BATCH).min(droplets);
+            print!(
+                "\r      Hydraulic erosion: {}%",
+                (batch_start as f32 / droplets as f32 * 100.0) as u32
+            );
 
-        for batch in 0..num_batches {
-            let batch_start = batch * batch_size;
-            let batch_end = (batch_start + batch_size).min(droplets);
-            let batch_droplets = batch_end - batch_start;
-
-            // Progress reporting
-            let progress = (batch_start as f32 / droplets as f32 * 100.0) as u32;
-            print!("\r      Hydraulic erosion: {}%", progress);
-
-            // Shared heightmap data for read-only access
-            let heightmap_width = self.heightmap.width;
-            let heightmap_height = self.heightmap.height;
-            let heightmap_cell_size = self.heightmap.cell_size;
-            let heightmap_data = &self.heightmap.data;
-
-            let erosion_changes: Vec<(Vec2, f32)> = (0..batch_droplets)
+            let heightmap = &self.heightmap;
+            let paths: Vec<Vec<(usize, f32)>> = (batch_start..batch_end)
                 .into_par_iter()
-                .flat_map(|i| {
-                    // Each thread gets its own RNG with unique seed
-                    let mut thread_rng =
-                        StdRng::seed_from_u64(base_seed + (batch_start + i) as u64);
-
-                    // Random starting position
-                    let start_x =
-                        thread_rng.gen_range(0.0..heightmap_width as f32) * heightmap_cell_size;
-                    let start_y =
-                        thread_rng.gen_range(0.0..heightmap_height as f32) * heightmap_cell_size;
-                    let mut droplet = WaterDroplet::new(Vec2::new(start_x, start_y));
-
-                    // Track changes this droplet wants to make
-                    let mut droplet_changes = Vec::new();
-
-                    // Simulate droplet flowing downhill
-                    while droplet.lifetime < MAX_DROPLET_LIFETIME && droplet.water > 0.001 {
-                        let gradient = Self::calculate_gradient_static(
-                            droplet.position,
-                            heightmap_data,
-                            heightmap_width,
-                            heightmap_height,
-                            heightmap_cell_size,
-                        );
-
-                        let flow_dir = -gradient.normalize_or_zero();
-
-                        droplet.velocity = droplet.velocity * INERTIA + flow_dir * (1.0 - INERTIA);
-                        droplet.velocity = droplet.velocity.normalize_or_zero()
-                            * droplet.velocity.length().min(1.0);
-
-                        // Move droplet
-                        let old_pos = droplet.position;
-                        droplet.position += droplet.velocity * heightmap_cell_size;
-
-                        // Keep within bounds
-                        droplet.position.x = droplet
-                            .position
-                            .x
-                            .clamp(0.0, (heightmap_width - 1) as f32 * heightmap_cell_size);
-                        droplet.position.y = droplet
-                            .position
-                            .y
-                            .clamp(0.0, (heightmap_height - 1) as f32 * heightmap_cell_size);
-
-                        let old_height = Self::get_interpolated_static(
-                            old_pos,
-                            heightmap_data,
-                            heightmap_width,
-                            heightmap_height,
-                            heightmap_cell_size,
-                        );
-                        let new_height = Self::get_interpolated_static(
-                            droplet.position,
-                            heightmap_data,
-                            heightmap_width,
-                            heightmap_height,
-                            heightmap_cell_size,
-                        );
-                        let height_diff = new_height - old_height;
-
-                        let slope = gradient.length().max(MIN_SLOPE);
-                        let capacity =
-                            slope * droplet.water * droplet.velocity.length() * SEDIMENT_CAPACITY;
-
-                        if height_diff > 0.0 || droplet.sediment > capacity {
-                            // Deposit sediment
-                            let amount_to_deposit = if height_diff > 0.0 {
-                                height_diff.min(droplet.sediment)
-                            } else {
-                                (droplet.sediment - capacity) * DEPOSITION_RATE
-                            };
-
-                            droplet.sediment -= amount_to_deposit;
-                            droplet_changes.push((old_pos, amount_to_deposit));
-                        } else {
-                            // Erode terrain
-                            let amount_to_erode =
-                                ((capacity - droplet.sediment) * EROSION_RATE).min(-height_diff);
-
-                            droplet.sediment += amount_to_erode;
-                            droplet_changes.push((old_pos, -amount_to_erode));
-                        }
-
-                        // Evaporate water
-                        droplet.water *= 1.0 - EVAPORATION_RATE;
-                        droplet.lifetime += 1;
-                    }
-
-                    droplet_changes
-                })
+                .map(|index| heightmap.trace_droplet(base_seed.wrapping_add(index as u64)))
                 .collect();
 
-            // Apply all erosion changes from this batch
-            for (pos, amount) in erosion_changes {
-                self.heightmap.modify_at(pos, amount);
+            for (cell, amount) in paths.into_iter().flatten() {
+                tiles[cell / tile_cells].push((cell, amount));
             }
-        }
 
-        info!("\r      Hydraulic erosion: 100%");
-    }
-
-    /// Thread-safe static version of get_interpolated
-    fn get_interpolated_static(
-        pos: Vec2,
-        data: &[f32],
-        width: usize,
-        height: usize,
-        cell_size: f32,
-    ) -> f32 {
-        // Clamp position to valid range first
-        let x = (pos.x / cell_size).clamp(0.0, (width - 1) as f32);
-        let y = (pos.y / cell_size).clamp(0.0, (height - 1) as f32);
-
-        let x0 = (x.floor() as usize).min(width - 1);
-        let y0 = (y.floor() as usize).min(height - 1);
-        let x1 = (x0 + 1).min(width - 1);
-        let y1 = (y0 + 1).min(height - 1);
-
-        let fx = x - x0 as f32;
-        let fy = y - y0 as f32;
-
-        // Ensure indices are valid before access
-        // Correct bilinear interpolation indexing:
-        // (x0,y0) -> top-left,    (x1,y0) -> top-right
-        // (x0,y1) -> bottom-left, (x1,y1) -> bottom-right
-        let idx_top_left = y0 * width + x0; // (x0, y0)
-        let idx_top_right = y0 * width + x1; // (x1, y0)
-        let idx_bottom_left = y1 * width + x0; // (x0, y1)
-        let idx_bottom_right = y1 * width + x1; // (x1, y1)
-
-        // Extra safety check
-        let max_idx = width * height - 1;
-        if idx_top_left > max_idx
-            || idx_top_right > max_idx
-            || idx_bottom_left > max_idx
-            || idx_bottom_right > max_idx
-        {
-            // Fallback to nearest valid cell
-            return data[y0.min(height - 1) * width + x0.min(width - 1)];
+            self.heightmap
+                .data
+                .par_chunks_mut(tile_cells)
+                .zip(tiles.par_iter_mut())
+                .enumerate()
+                .for_each(|(tile, (cells, changes))| {
+                    let offset = tile * tile_cells;
+                    for (cell, amount) in changes.drain(..) {
+                        let height = &mut cells[cell - offset];
+                        *height = (*height + amount).clamp(0.0, 1.0);
+                    }
+                });
         }
 
-        let h_top_left = data[idx_top_left];
-        let h_top_right = data[idx_top_right];
-        let h_bottom_left = data[idx_bottom_left];
-        let h_bottom_right = data[idx_bottom_right];
-
-        // Bilinear interpolation: interpolate horizontally, then vertically
-        let h_top = lerp(h_top_left, h_top_right, fx); // Top edge interpolation
-        let h_bottom = lerp(h_bottom_left, h_bottom_right, fx); // Bottom edge interpolation
-        lerp(h_top, h_bottom, fy) // Final vertical interpolation
-    }
-
-    /// Thread-safe static version of calculate_gradient
-    fn calculate_gradient_static(
-        pos: Vec2,
-        data: &[f32],
-        width: usize,
-        height: usize,
-        cell_size: f32,
-    ) -> Vec2 {
-        let epsilon = cell_size * 0.5;
-
-        let h_left = Self::get_interpolated_static(
-            pos - Vec2::new(epsilon, 0.0),
-            data,
-            width,
-            height,
-            cell_size,
-        );
-        let h_right = Self::get_interpolated_static(
-            pos + Vec2::new(epsilon, 0.0),
-            data,
-            width,
-            height,
-            cell_size,
-        );
-        let h_down = Self::get_interpolated_static(
-            pos - Vec2::new(0.0, epsilon),
-            data,
-            width,
-            height,
-            cell_size,
-        );
-        let h_up = Self::get_interpolated_static(
-            pos + Vec2::new(0.0, epsilon),
-            data,
-            width,
-            height,
-            cell_size,
-        );
-
-        Vec2::new(
-            (h_left - h_right) / (2.0 * epsilon),
-            (h_down - h_up) / (2.0 * epsilon),
-        )
+        info!("\r      Hydraulic erosion: 100%");
     }
 
     /// Thermal erosion - material sliding down steep slopes (PARALLELIZED)
@@ -585,7 +489,7 @@ pub fn apply_erosion_to_provinces(
     provinces: &mut [Province],
     dimensions: crate::resources::MapDimensions,
     rng: &mut StdRng,
-    iterations: usize,
+    quality: ErosionQuality,
 ) {
     info!("  Applying erosion simulation to terrain...");
 
@@ -626,7 +530,7 @@ pub fn apply_erosion_to_provinces(
     }
 
     let mut erosion = ErosionSystem::new(heightmap);
-    erosion.erode(rng, iterations);
+    erosion.erode(rng, quality);
     let eroded = erosion.get_heightmap();
 
     // Apply eroded elevations back to provinces
@@ -641,3 +545,32 @@ pub fn apply_erosion_to_provinces(
 
     info!("  Erosion complete - terrain now has realistic valleys and drainage");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sloped_heightmap() -> HeightMap {
+        let mut heightmap = HeightMap::new(40, 75, 10.0);
+        for y in 0..heightmap.height {
+            for x in 0..heightmap.width {
+                heightmap.set(x, y, 0.2 + 0.6 * ((x * 7 + y * 3) % 40) as f32 / 40.0);
+            }
+        }
+        heightmap
+    }
+
+    fn eroded_with_threads(threads: usize) -> Vec<f32> {
+        let mut erosion = ErosionSystem::new(sloped_heightmap());
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+        pool.install(|| erosion.hydraulic_erosion(&mut StdRng::seed_from_u64(7), DROPLET_BATCH + 100));
+        erosion.get_heightmap().data
+    }
+
+    #[test]
+    fn hydraulic_erosion_is_independent_of_thread_count() {
+        let single = eroded_with_threads(1);
+        assert_eq!(single, eroded_with_threads(4));
+        assert_ne!(single, sloped_heightmap().data);
+    }
+}
@@ -4,10 +4,12 @@
 //! - Terrain types and classification
 //! - Climate zones and biomes
 //! - Terrain marker components
+//! - Terrain attrition profiles for campaigning armies
 //!
 //! Following gateway architecture - all submodules are private.
 
 // PRIVATE MODULES
+mod attrition;
 mod climate;
 mod erosion;
 mod storage;
@@ -20,12 +22,15 @@ pub use types::{
     classify_terrain_with_climate, ClimateZone, TerrainEntity, TerrainPlugin, TerrainType,
 };
 
+// Attrition profiles for armies in the field
+pub use attrition::{winter_severity, AttritionProfile, AttritionProfiles};
+
 // Climate types
 pub use climate::Biome;
 
 // Generation functions (these modules use direct functions, not builders)
 pub use climate::apply_climate_to_provinces;
-pub use erosion::apply_erosion_to_provinces;
+pub use erosion::{apply_erosion_to_provinces, ErosionQuality};
 
 // Climate storage for runtime visualization
 pub use storage::{ClimateStorage, ClimateZone as StoredClimateZone};
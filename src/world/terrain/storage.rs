@@ -6,10 +6,11 @@
 
 use bevy::prelude::*;
 use crate::world::ProvinceId;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Simplified climate data for runtime visualization
-#[derive(Debug, Clone, Copy, Reflect)]
+#[derive(Debug, Clone, Copy, Reflect, Serialize, Deserialize)]
 pub struct ProvinceClimate {
     /// Average annual temperature in Celsius
     pub temperature: f32,
@@ -22,7 +23,7 @@ pub struct ProvinceClimate {
 }
 
 /// Climate zone classification for visualization
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Serialize, Deserialize)]
 pub enum ClimateZone {
     Arctic,      // < -10°C
     Subarctic,   // -10°C to 0°C
@@ -58,8 +59,8 @@ impl ClimateZone {
     }
 }
 
-/// Resource storing climate data for all provinces
-#[derive(Resource, Default, Debug, Clone, Reflect)]
+/// Resource storing climate data for all provinces, saved with the world
+#[derive(Resource, Default, Debug, Clone, Reflect, Serialize, Deserialize)]
 pub struct ClimateStorage {
     /// Climate data indexed by province ID
     pub climates: HashMap<ProvinceId, ProvinceClimate>,
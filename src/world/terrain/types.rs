@@ -499,5 +499,7 @@ impl TerrainType {
 use bevy_plugin_builder::define_plugin;
 
 define_plugin!(TerrainPlugin {
-    // Empty plugin - currently only provides types and functions
+    resources: [super::attrition::AttritionProfiles],
+
+    update: [super::attrition::rebuild_attrition_profiles]
 });
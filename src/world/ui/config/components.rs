@@ -5,6 +5,8 @@
 
 use super::types::*;
 use crate::resources::WorldSize;
+use crate::simulation::DirectorMode;
+use crate::world::SeaLevelDrift;
 use bevy::prelude::*;
 
 // Root markers
@@ -65,12 +67,61 @@ pub struct ClimateButton(pub ClimateType);
 #[derive(Component)]
 pub struct IslandButton(pub IslandFrequency);
 
+#[derive(Component)]
+pub struct ErosionIterationsButton(pub u32);
+
+#[derive(Component)]
+pub struct ErosionDropletsButton(pub ErosionDroplets);
+
 #[derive(Component)]
 pub struct AggressionButton(pub AggressionLevel);
 
 #[derive(Component)]
 pub struct ResourceButton(pub ResourceAbundance);
 
+#[derive(Component)]
+pub struct DirectorButton(pub DirectorMode);
+
+#[derive(Component)]
+pub struct SeaLevelButton(pub SeaLevelDrift);
+
+#[derive(Component)]
+pub struct BehaviorPackButton(pub String); // Behavior pack ID
+
+#[derive(Component)]
+pub struct NationEditorButton(pub bool);
+
+// Nation designer markers
+#[derive(Component)]
+pub struct NationDesignerList;
+
+#[derive(Component)]
+pub struct AddCustomNationButton;
+
+#[derive(Component)]
+pub struct SaveNationDesignsButton;
+
+#[derive(Component)]
+pub struct LoadNationDesignsButton;
+
+#[derive(Component)]
+pub struct CustomNationNameInput(pub usize);
+
+#[derive(Component)]
+pub struct CustomNationCultureButton(pub usize);
+
+#[derive(Component)]
+pub struct CustomNationColorButton(pub usize);
+
+#[derive(Component)]
+pub struct CustomNationGovernmentButton(pub usize);
+
+#[derive(Component)]
+pub struct CustomNationRegionButton(pub usize);
+
+#[derive(Component)]
+pub struct RemoveCustomNationButton(pub usize);
+
 // Display text markers
 #[derive(Component)]
 pub struct WorldPreviewText;
@@ -157,6 +208,20 @@ impl SelectionComponent for IslandButton {
     }
 }
 
+impl SelectionComponent for ErosionIterationsButton {
+    type Value = u32;
+    fn value(&self) -> Self::Value {
+        self.0
+    }
+}
+
+impl SelectionComponent for ErosionDropletsButton {
+    type Value = ErosionDroplets;
+    fn value(&self) -> Self::Value {
+        self.0
+    }
+}
+
 impl SelectionComponent for AggressionButton {
     type Value = AggressionLevel;
     fn value(&self) -> Self::Value {
@@ -171,6 +236,34 @@ impl SelectionComponent for ResourceButton {
     }
 }
 
+impl SelectionComponent for DirectorButton {
+    type Value = DirectorMode;
+    fn value(&self) -> Self::Value {
+        self.0
+    }
+}
+
+impl SelectionComponent for SeaLevelButton {
+    type Value = SeaLevelDrift;
+    fn value(&self) -> Self::Value {
+        self.0
+    }
+}
+
+impl SelectionComponent for BehaviorPackButton {
+    type Value = String;
+    fn value(&self) -> Self::Value {
+        self.0.clone()
+    }
+}
+
+impl SelectionComponent for NationEditorButton {
+    type Value = bool;
+    fn value(&self) -> Self::Value {
+        self.0
+    }
+}
+
 impl SelectionComponent for PresetButton {
     type Value = WorldPreset;
     fn value(&self) -> Self::Value {
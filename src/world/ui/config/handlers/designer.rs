@@ -0,0 +1,164 @@
+//! Nation designer handlers
+//!
+//! This module edits the designer-defined nations in the settings, rebuilds
+//! their rows, and shares designs through `NATION_DESIGNS_FILE`.
+
+use super::super::components::*;
+use super::super::layout::spawn_custom_nation_rows;
+use super::super::types::{
+    NationDesignPreset, NationDesignsChanged, WorldGenerationSettings, NATION_DESIGNS_FILE,
+};
+use crate::nations::{
+    CustomNationSpec, RegionPreference, DESIGNER_COLORS, DESIGNER_CULTURES, EDITABLE_GOVERNMENTS,
+    MAX_CUSTOM_NATIONS,
+};
+use crate::ui::{NotificationPosition, NotificationType, ShowNotification, TextBuffer};
+use bevy::prelude::*;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// Next item after `current` in `options`, wrapping around
+fn cycle<T: Copy + PartialEq>(options: &[T], current: T) -> T {
+    let index = options.iter().position(|option| *option == current).unwrap_or(0);
+    options[(index + 1) % options.len()]
+}
+
+fn save_designs(specs: &[CustomNationSpec]) -> Result<(), String> {
+    let preset = NationDesignPreset {
+        nations: specs.to_vec(),
+    };
+    let text = ron::ser::to_string_pretty(&preset, ron::ser::PrettyConfig::default())
+        .map_err(|e| format!("Failed to serialize nation designs: {}", e))?;
+    if let Some(dir) = Path::new(NATION_DESIGNS_FILE).parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("Failed to create {:?}: {}", dir, e))?;
+    }
+    fs::write(NATION_DESIGNS_FILE, text).map_err(|e| format!("Failed to write {}: {}", NATION_DESIGNS_FILE, e))
+}
+
+fn load_designs() -> Result<Vec<CustomNationSpec>, String> {
+    let text = fs::read_to_string(NATION_DESIGNS_FILE)
+        .map_err(|e| format!("Failed to read {}: {}", NATION_DESIGNS_FILE, e))?;
+    let preset: NationDesignPreset =
+        ron::from_str(&text).map_err(|e| format!("Failed to parse {}: {}", NATION_DESIGNS_FILE, e))?;
+    Ok(preset.nations.into_iter().take(MAX_CUSTOM_NATIONS).collect())
+}
+
+fn notify(notifications: &mut MessageWriter<ShowNotification>, message: String, notification_type: NotificationType) {
+    notifications.write(ShowNotification {
+        message,
+        notification_type,
+        duration: Some(Duration::from_secs(3)),
+        position: NotificationPosition::BottomRight,
+    });
+}
+
+pub fn handle_nation_designer_buttons(
+    add: Query<&Interaction, (Changed<Interaction>, With<AddCustomNationButton>)>,
+    save: Query<&Interaction, (Changed<Interaction>, With<SaveNationDesignsButton>)>,
+    load: Query<&Interaction, (Changed<Interaction>, With<LoadNationDesignsButton>)>,
+    cultures: Query<(&Interaction, &CustomNationCultureButton), Changed<Interaction>>,
+    colors: Query<(&Interaction, &CustomNationColorButton), Changed<Interaction>>,
+    governments: Query<(&Interaction, &CustomNationGovernmentButton), Changed<Interaction>>,
+    regions: Query<(&Interaction, &CustomNationRegionButton), Changed<Interaction>>,
+    removes: Query<(&Interaction, &RemoveCustomNationButton), Changed<Interaction>>,
+    mut settings: ResMut<WorldGenerationSettings>,
+    mut changed: MessageWriter<NationDesignsChanged>,
+    mut notifications: MessageWriter<ShowNotification>,
+) {
+    let pressed = |interaction: &Interaction| *interaction == Interaction::Pressed;
+    let before = settings.custom_nations.clone();
+    // Only mark the settings changed when a design actually changed
+    let designs = &mut settings.bypass_change_detection().custom_nations;
+
+    if add.iter().any(pressed) && designs.len() < MAX_CUSTOM_NATIONS {
+        designs.push(CustomNationSpec::new(designs.len()));
+    }
+
+    for (_, button) in cultures.iter().filter(|(i, _)| pressed(i)) {
+        if let Some(spec) = designs.get_mut(button.0) {
+            spec.culture = cycle(&DESIGNER_CULTURES, spec.culture);
+        }
+    }
+    for (_, button) in colors.iter().filter(|(i, _)| pressed(i)) {
+        if let Some(spec) = designs.get_mut(button.0) {
+            spec.color = cycle(&DESIGNER_COLORS, spec.color);
+        }
+    }
+    for (_, button) in governments.iter().filter(|(i, _)| pressed(i)) {
+        if let Some(spec) = designs.get_mut(button.0) {
+            spec.government = cycle(&EDITABLE_GOVERNMENTS, spec.government);
+        }
+    }
+    for (_, button) in regions.iter().filter(|(i, _)| pressed(i)) {
+        if let Some(spec) = designs.get_mut(button.0) {
+            spec.region = cycle(&RegionPreference::ALL, spec.region);
+        }
+    }
+    if let Some((_, button)) = removes.iter().find(|(i, _)| pressed(i)) {
+        if button.0 < designs.len() {
+            designs.remove(button.0);
+        }
+    }
+
+    if save.iter().any(pressed) {
+        match save_designs(designs) {
+            Ok(()) => notify(
+                &mut notifications,
+                format!("Saved {} nation designs to {}", designs.len(), NATION_DESIGNS_FILE),
+                NotificationType::Success,
+            ),
+            Err(e) => notify(&mut notifications, e, NotificationType::Error),
+        }
+    }
+
+    if load.iter().any(pressed) {
+        match load_designs() {
+            Ok(loaded) => {
+                notify(
+                    &mut notifications,
+                    format!("Loaded {} nation designs", loaded.len()),
+                    NotificationType::Info,
+                );
+                *designs = loaded;
+            }
+            Err(e) => notify(&mut notifications, e, NotificationType::Error),
+        }
+    }
+
+    if settings.custom_nations != before {
+        settings.set_changed();
+        changed.write(NationDesignsChanged);
+    }
+}
+
+pub fn handle_nation_designer_names(
+    inputs: Query<(&TextBuffer, &CustomNationNameInput), Changed<TextBuffer>>,
+    mut settings: ResMut<WorldGenerationSettings>,
+) {
+    for (buffer, input) in &inputs {
+        if let Some(spec) = settings.custom_nations.get_mut(input.0) {
+            if spec.name != buffer.content {
+                spec.name = buffer.content.clone();
+            }
+        }
+    }
+}
+
+pub fn rebuild_nation_designer_list(
+    mut commands: Commands,
+    mut changed: MessageReader<NationDesignsChanged>,
+    lists: Query<Entity, With<NationDesignerList>>,
+    settings: Res<WorldGenerationSettings>,
+) {
+    if changed.read().count() == 0 {
+        return;
+    }
+
+    for list in &lists {
+        commands.entity(list).despawn_related::<Children>();
+        commands
+            .entity(list)
+            .with_children(|list| spawn_custom_nation_rows(list, &settings.custom_nations));
+    }
+}
@@ -8,8 +8,11 @@ use crate::states::{GameState, RequestStateTransition};
 use crate::ui::define_marker_interactions;
 use bevy::prelude::*;
 
-pub fn init_default_settings(mut commands: Commands) {
-    commands.insert_resource(WorldGenerationSettings::default());
+pub fn init_default_settings(mut commands: Commands, device: Res<crate::settings::ActiveDeviceProfile>) {
+    commands.insert_resource(WorldGenerationSettings {
+        world_size: device.0.world_size,
+        ..default()
+    });
     debug!("Initialized default world generation settings");
 }
 
@@ -80,6 +80,26 @@ pub fn handle_island_selection(
     }
 }
 
+pub fn handle_erosion_selection(
+    mut selection_events: EventReader<SelectionChanged>,
+    iteration_buttons: Query<&ErosionIterationsButton>,
+    droplet_buttons: Query<&ErosionDropletsButton>,
+    mut settings: ResMut<WorldGenerationSettings>,
+) {
+    for event in selection_events.read() {
+        if event.selected {
+            if let Ok(iteration_button) = iteration_buttons.get(event.entity) {
+                settings.erosion_iterations = iteration_button.0;
+                debug!("Selected erosion iterations: {}", iteration_button.0);
+            }
+            if let Ok(droplet_button) = droplet_buttons.get(event.entity) {
+                settings.erosion_droplets = droplet_button.0;
+                debug!("Selected erosion droplets: {:?}", droplet_button.0);
+            }
+        }
+    }
+}
+
 pub fn handle_aggression_selection(
     mut selection_events: EventReader<SelectionChanged>,
     aggression_buttons: Query<&AggressionButton>,
@@ -110,6 +130,66 @@ pub fn handle_resource_selection(
     }
 }
 
+pub fn handle_director_selection(
+    mut selection_events: EventReader<SelectionChanged>,
+    director_buttons: Query<&DirectorButton>,
+    mut settings: ResMut<WorldGenerationSettings>,
+) {
+    for event in selection_events.read() {
+        if event.selected {
+            if let Ok(director_button) = director_buttons.get(event.entity) {
+                settings.director_mode = director_button.0;
+                debug!("Selected director mode: {:?}", director_button.0);
+            }
+        }
+    }
+}
+
+pub fn handle_sea_level_selection(
+    mut selection_events: EventReader<SelectionChanged>,
+    sea_level_buttons: Query<&SeaLevelButton>,
+    mut settings: ResMut<WorldGenerationSettings>,
+) {
+    for event in selection_events.read() {
+        if event.selected {
+            if let Ok(sea_level_button) = sea_level_buttons.get(event.entity) {
+                settings.sea_level_drift = sea_level_button.0;
+                debug!("Selected sea level drift: {:?}", sea_level_button.0);
+            }
+        }
+    }
+}
+
+pub fn handle_behavior_pack_selection(
+    mut selection_events: EventReader<SelectionChanged>,
+    pack_buttons: Query<&BehaviorPackButton>,
+    mut settings: ResMut<WorldGenerationSettings>,
+) {
+    for event in selection_events.read() {
+        if event.selected {
+            if let Ok(pack_button) = pack_buttons.get(event.entity) {
+                settings.behavior_pack = pack_button.0.clone();
+                debug!("Selected AI behavior pack: {}", pack_button.0);
+            }
+        }
+    }
+}
+
+pub fn handle_nation_editor_selection(
+    mut selection_events: EventReader<SelectionChanged>,
+    editor_buttons: Query<&NationEditorButton>,
+    mut settings: ResMut<WorldGenerationSettings>,
+) {
+    for event in selection_events.read() {
+        if event.selected {
+            if let Ok(editor_button) = editor_buttons.get(event.entity) {
+                settings.customize_nations = editor_button.0;
+                debug!("Customize nations before starting: {}", editor_button.0);
+            }
+        }
+    }
+}
+
 pub fn handle_calendar_selection(
     mut selection_events: EventReader<SelectionChanged>,
     calendar_buttons: Query<&CalendarButton>,
@@ -4,12 +4,15 @@
 
 use super::super::components::*;
 use super::super::types::*;
+use crate::ai::BehaviorPacks;
+use crate::simulation::DirectorMode;
+use crate::world::SeaLevelDrift;
 use crate::ui::colors;
 use crate::ui::{SliderBuilder, ValueFormat};
 use crate::ui::{ButtonBuilder, ButtonSize, PanelBuilder, PanelStyle};
 use bevy::prelude::*;
 
-pub fn spawn_advanced_panel(parent: &mut ChildSpawnerCommands) {
+pub fn spawn_advanced_panel(parent: &mut ChildSpawnerCommands, behavior_pack: &str, behavior_packs: &BehaviorPacks) {
     // Use PanelBuilder for the advanced panel
     let panel_entity = PanelBuilder::new()
         .style(PanelStyle::Light)
@@ -58,8 +61,11 @@ pub fn spawn_advanced_panel(parent: &mut ChildSpawnerCommands) {
                     spawn_geography_column(columns);
 
                     // Right column: Civilizations & Resources
-                    spawn_civilizations_column(columns);
+                    spawn_civilizations_column(columns, behavior_pack, behavior_packs);
                 });
+
+            // Full width: designer-defined nations
+            super::spawn_nation_designer_section(panel, &[]);
         });
 
     // Add the AdvancedPanel marker to the panel entity
@@ -170,10 +176,69 @@ fn spawn_geography_column(parent: &mut ChildSpawnerCommands) {
                 IslandFrequency::Moderate,
                 |freq| IslandButton(freq),
             );
+
+            // Erosion quality
+            spawn_selection_row(
+                column,
+                "Erosion Iterations",
+                vec![("1", 1), ("2", 2), ("3", 3), ("4", 4)],
+                1,
+                |iterations| ErosionIterationsButton(iterations),
+            );
+            spawn_selection_row(
+                column,
+                "Erosion Droplets",
+                vec![
+                    ("Light", ErosionDroplets::Light),
+                    ("Normal", ErosionDroplets::Normal),
+                    ("Heavy", ErosionDroplets::Heavy),
+                    ("Extreme", ErosionDroplets::Extreme),
+                ],
+                ErosionDroplets::Normal,
+                |droplets| ErosionDropletsButton(droplets),
+            );
+            column.spawn((
+                Text::new("More erosion carves deeper valleys and drainage, but slows generation on large worlds."),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(colors::TEXT_MUTED),
+                Node {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+
+            // Sea Level Selection
+            spawn_selection_row(
+                column,
+                "Sea Level",
+                vec![
+                    ("Stable", SeaLevelDrift::Stable),
+                    ("Rising", SeaLevelDrift::Rising),
+                    ("Falling", SeaLevelDrift::Falling),
+                    ("Great Floods", SeaLevelDrift::GreatFloods),
+                ],
+                SeaLevelDrift::Stable,
+                |drift| SeaLevelButton(drift),
+            );
+            column.spawn((
+                Text::new("Over centuries the sea can drown low coasts or leave new shore. For very long runs."),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(colors::TEXT_MUTED),
+                Node {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
         });
 }
 
-fn spawn_civilizations_column(parent: &mut ChildSpawnerCommands) {
+fn spawn_civilizations_column(parent: &mut ChildSpawnerCommands, behavior_pack: &str, behavior_packs: &BehaviorPacks) {
     parent
         .spawn((Node {
             width: Val::Percent(50.0),
@@ -266,6 +331,77 @@ fn spawn_civilizations_column(parent: &mut ChildSpawnerCommands) {
                 ResourceAbundance::Normal,
                 |res| ResourceButton(res),
             );
+
+            // World Director Selection
+            spawn_selection_row(
+                column,
+                "World Director",
+                vec![
+                    ("Off", DirectorMode::Off),
+                    ("Gentle", DirectorMode::Gentle),
+                    ("Active", DirectorMode::Active),
+                ],
+                DirectorMode::Gentle,
+                |mode| DirectorButton(mode),
+            );
+            column.spawn((
+                Text::new("Nudges a stagnant world with plagues, crises, and discoveries. Off for purist runs."),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(colors::TEXT_MUTED),
+                Node {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+
+            // AI Behavior Pack Selection (built-in and mod-provided packs)
+            spawn_selection_row(
+                column,
+                "AI Behavior",
+                behavior_packs
+                    .packs
+                    .iter()
+                    .map(|(id, pack)| (pack.name.as_str(), id.clone()))
+                    .collect(),
+                behavior_pack.to_string(),
+                |id| BehaviorPackButton(id),
+            );
+            column.spawn((
+                Text::new("How every nation weighs war, expansion, alliances, and trade. Saved with the world."),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(colors::TEXT_MUTED),
+                Node {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+
+            // Nation personality editor
+            spawn_selection_row(
+                column,
+                "Nation Personalities",
+                vec![("Generated", false), ("Customize", true)],
+                false,
+                |customize| NationEditorButton(customize),
+            );
+            column.spawn((
+                Text::new("Customize lets you tune each nation's AI, government, and economy before time starts."),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(colors::TEXT_MUTED),
+                Node {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
         });
 }
 
@@ -234,7 +234,10 @@ pub fn spawn_seed_section(parent: &mut ChildSpawnerCommands, seed: u32) {
 
         // Help text
         section.spawn((
-            Text::new("Same seed = same world generation. Share seeds with friends for identical worlds."),
+            Text::new(format!(
+                "Same seed = same world generation (generation v{}). Share seeds with friends for identical worlds.",
+                crate::world::GENERATION_VERSION
+            )),
             TextFont {
                 font_size: 14.0,
                 ..default()
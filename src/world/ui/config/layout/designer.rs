@@ -0,0 +1,149 @@
+//! Nation designer layout
+//!
+//! This module creates the list of designer-defined nations shown at the
+//! bottom of the advanced settings panel.
+
+use super::super::components::*;
+use crate::nations::{get_structure_name, CustomNationSpec, MAX_CUSTOM_NATIONS};
+use crate::ui::colors;
+use crate::ui::{text_input, FocusGroupId};
+use crate::ui::{ButtonBuilder, ButtonSize, ButtonStyle};
+use bevy::prelude::*;
+
+pub fn spawn_nation_designer_section(parent: &mut ChildSpawnerCommands, specs: &[CustomNationSpec]) {
+    parent
+        .spawn((Node {
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(8.0),
+            margin: UiRect::top(Val::Px(20.0)),
+            ..default()
+        },))
+        .with_children(|section| {
+            section.spawn((
+                Text::new("Nation Designer"),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(colors::TEXT_PRIMARY),
+            ));
+            section.spawn((
+                Text::new(format!(
+                    "Define up to {} nations of your own. Each gets a capital matching its region and a reserved core; the rest of the world is generated around them.",
+                    MAX_CUSTOM_NATIONS
+                )),
+                TextFont {
+                    font_size: 13.0,
+                    ..default()
+                },
+                TextColor(colors::TEXT_MUTED),
+            ));
+
+            section
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(6.0),
+                        ..default()
+                    },
+                    NationDesignerList,
+                ))
+                .with_children(|list| spawn_custom_nation_rows(list, specs));
+
+            section
+                .spawn((Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                },))
+                .with_children(|row| {
+                    let button = ButtonBuilder::new("Add Nation")
+                        .style(ButtonStyle::Primary)
+                        .size(ButtonSize::Small)
+                        .build(row);
+                    row.commands().entity(button).insert(AddCustomNationButton);
+
+                    let button = ButtonBuilder::new("Save Designs")
+                        .style(ButtonStyle::Secondary)
+                        .size(ButtonSize::Small)
+                        .build(row);
+                    row.commands().entity(button).insert(SaveNationDesignsButton);
+
+                    let button = ButtonBuilder::new("Load Designs")
+                        .style(ButtonStyle::Secondary)
+                        .size(ButtonSize::Small)
+                        .build(row);
+                    row.commands().entity(button).insert(LoadNationDesignsButton);
+                });
+        });
+}
+
+/// One editable row per design, or a hint when there are none
+pub fn spawn_custom_nation_rows(list: &mut ChildSpawnerCommands, specs: &[CustomNationSpec]) {
+    if specs.is_empty() {
+        list.spawn((
+            Text::new("No custom nations - every nation will be generated."),
+            TextFont {
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(colors::TEXT_MUTED),
+        ));
+        return;
+    }
+
+    for (index, spec) in specs.iter().enumerate() {
+        list.spawn((Node {
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(6.0),
+            ..default()
+        },))
+            .with_children(|row| {
+                row.spawn((
+                    Node {
+                        width: Val::Px(18.0),
+                        height: Val::Px(18.0),
+                        ..default()
+                    },
+                    BackgroundColor(spec.color),
+                ));
+
+                text_input()
+                    .with_value(&spec.name)
+                    .with_font_size(14.0)
+                    .with_width(Val::Px(200.0))
+                    .with_padding(UiRect::horizontal(Val::Px(8.0)))
+                    .with_max_length(30)
+                    .with_focus_group(FocusGroupId::WorldConfig)
+                    .inactive()
+                    .with_marker(CustomNationNameInput(index))
+                    .build(row);
+
+                let button = ButtonBuilder::new(format!("{:?}", spec.culture))
+                    .size(ButtonSize::Small)
+                    .build(row);
+                row.commands().entity(button).insert(CustomNationCultureButton(index));
+
+                let button = ButtonBuilder::new("Color").size(ButtonSize::Small).build(row);
+                row.commands().entity(button).insert(CustomNationColorButton(index));
+
+                let button = ButtonBuilder::new(get_structure_name(&spec.government))
+                    .size(ButtonSize::Small)
+                    .build(row);
+                row.commands().entity(button).insert(CustomNationGovernmentButton(index));
+
+                let button = ButtonBuilder::new(spec.region.label()).size(ButtonSize::Small).build(row);
+                row.commands().entity(button).insert(CustomNationRegionButton(index));
+
+                let button = ButtonBuilder::new("Remove")
+                    .style(ButtonStyle::Danger)
+                    .size(ButtonSize::Small)
+                    .build(row);
+                row.commands().entity(button).insert(RemoveCustomNationButton(index));
+            });
+    }
+}
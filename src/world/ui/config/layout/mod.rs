@@ -11,6 +11,7 @@
 // PRIVATE MODULES - UI layout implementations
 mod advanced;
 mod basic;
+mod designer;
 mod presets;
 mod root;
 
@@ -19,6 +20,7 @@ pub use root::spawn_world_config_ui;
 
 // INTERNAL EXPORTS - For use by sibling modules ONLY through this gateway
 pub(super) use advanced::spawn_advanced_panel;
+pub(super) use designer::{spawn_custom_nation_rows, spawn_nation_designer_section};
 pub(super) use basic::{
     spawn_calendar_selection_section, spawn_seed_section, spawn_starting_year_section,
     spawn_world_name_section, spawn_world_size_section,
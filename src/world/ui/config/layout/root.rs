@@ -6,6 +6,7 @@ use super::super::components::{
     AdvancedToggle, BackButton, GenerateButton,
 };
 use super::super::types::WorldGenerationSettings;
+use crate::ai::BehaviorPacks;
 use crate::simulation::CalendarRegistry;
 use crate::states::GameState;
 use crate::ui::colors;
@@ -16,6 +17,7 @@ pub fn spawn_world_config_ui(
     mut commands: Commands,
     settings: Res<WorldGenerationSettings>,
     calendar_registry: Res<CalendarRegistry>,
+    behavior_packs: Res<BehaviorPacks>,
 ) {
     debug!(
         "Spawning world configuration UI with seed: {}",
@@ -121,7 +123,7 @@ pub fn spawn_world_config_ui(
                     content.commands().entity(button).insert(AdvancedToggle);
 
                     // Advanced Settings Panel
-                    super::spawn_advanced_panel(content);
+                    super::spawn_advanced_panel(content, &settings.behavior_pack, &behavior_packs);
 
                     // Generation time estimate - using PanelBuilder
                     PanelBuilder::new()
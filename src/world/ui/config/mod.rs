@@ -31,7 +31,7 @@ pub use plugin::WorldConfigPlugin;
 
 // Types - configuration data structures
 pub use types::{
-    AggressionLevel, ClimateType, IslandFrequency, MineralDistribution, MountainDensity,
+    AggressionLevel, ClimateType, ErosionDroplets, IslandFrequency, MineralDistribution, MountainDensity,
     ResourceAbundance, TradePropensity, WorldGenerationSettings, WorldPreset,
 };
 
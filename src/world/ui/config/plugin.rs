@@ -5,7 +5,7 @@
 
 use super::handlers;
 use super::layout;
-use super::types::WorldGenerationSettings;
+use super::types::{NationDesignsChanged, WorldGenerationSettings};
 use crate::states::GameState;
 use bevy::prelude::*;
 use bevy_plugin_builder::define_plugin;
@@ -16,6 +16,8 @@ use bevy_plugin_builder::define_plugin;
 define_plugin!(WorldConfigPlugin {
     resources: [WorldGenerationSettings],
 
+    messages: [NationDesignsChanged],
+
     update: [
         // All world config systems beautifully organized!
         (// Input handlers
@@ -26,8 +28,13 @@ define_plugin!(WorldConfigPlugin {
          handlers::handle_size_selection,
          handlers::handle_climate_selection,
          handlers::handle_island_selection,
+         handlers::handle_erosion_selection,
          handlers::handle_aggression_selection,
          handlers::handle_resource_selection,
+         (handlers::handle_director_selection,
+          handlers::handle_behavior_pack_selection,
+          handlers::handle_sea_level_selection),
+         handlers::handle_nation_editor_selection,
          handlers::handle_calendar_selection,
          // UI interactions
          handlers::handle_preset_hover,
@@ -38,7 +45,11 @@ define_plugin!(WorldConfigPlugin {
          handlers::update_slider_displays,
          // Navigation
          handlers::handle_generate_button,
-         handlers::handle_back_button).run_if(in_state(GameState::WorldConfiguration))
+         handlers::handle_back_button).run_if(in_state(GameState::WorldConfiguration)),
+        // Nation designer
+        (handlers::handle_nation_designer_buttons,
+         handlers::handle_nation_designer_names,
+         handlers::rebuild_nation_designer_list).chain().run_if(in_state(GameState::WorldConfiguration))
     ],
 
     on_enter: {
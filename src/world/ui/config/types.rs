@@ -4,7 +4,10 @@
 //! including the main settings struct and all configuration enums.
 
 use crate::name_generator::{NameGenerator, NameType};
+use crate::nations::CustomNationSpec;
 use crate::resources::WorldSize;
+use crate::simulation::DirectorMode;
+use crate::world::SeaLevelDrift;
 use rand::Rng;
 
 /// Complete world generation settings
@@ -15,6 +18,8 @@ pub struct WorldGenerationSettings {
     pub custom_dimensions: Option<(u32, u32)>,
     pub seed: u32,
     pub preset: WorldPreset,
+    /// Generator revision these settings reproduce (see `GENERATION_VERSION`)
+    pub generation_version: u32,
 
     // Time Settings
     pub calendar_id: String,
@@ -27,6 +32,12 @@ pub struct WorldGenerationSettings {
     pub climate_type: ClimateType,
     pub mountain_density: MountainDensity,
     pub river_density: f32,
+    /// How the sea changes over the run
+    pub sea_level_drift: SeaLevelDrift,
+    /// Rounds of erosion run over the terrain
+    pub erosion_iterations: u32,
+    /// Droplets simulated in each erosion round
+    pub erosion_droplets: ErosionDroplets,
 
     // Advanced - Civilizations
     pub starting_nations: u32,
@@ -34,6 +45,14 @@ pub struct WorldGenerationSettings {
     pub tech_progression_speed: f32,
     pub empire_stability: f32,
     pub trade_propensity: TradePropensity,
+    /// Whether the world director may nudge a stagnant simulation
+    pub director_mode: DirectorMode,
+    /// Id of the AI behavior pack the world runs under
+    pub behavior_pack: String,
+    /// Open the AI personality editor before the simulation starts
+    pub customize_nations: bool,
+    /// Nations defined in the designer, placed before the generated ones
+    pub custom_nations: Vec<CustomNationSpec>,
 
     // Advanced - Resources
     pub resource_abundance: ResourceAbundance,
@@ -50,6 +69,7 @@ impl Default for WorldGenerationSettings {
             custom_dimensions: None,
             seed: rand::thread_rng().r#gen(),
             preset: WorldPreset::Balanced,
+            generation_version: crate::world::GENERATION_VERSION,
 
             calendar_id: "gregorian".to_string(),
             starting_year: 1000,
@@ -60,12 +80,19 @@ impl Default for WorldGenerationSettings {
             climate_type: ClimateType::Mixed,
             mountain_density: MountainDensity::Normal,
             river_density: 1.0,
+            sea_level_drift: SeaLevelDrift::Stable,
+            erosion_iterations: 1,
+            erosion_droplets: ErosionDroplets::Normal,
 
             starting_nations: 8,
             aggression_level: AggressionLevel::Balanced,
             tech_progression_speed: 1.0,
             empire_stability: 0.5,
             trade_propensity: TradePropensity::Normal,
+            director_mode: DirectorMode::Gentle,
+            behavior_pack: crate::ai::DEFAULT_BEHAVIOR_PACK.to_string(),
+            customize_nations: false,
+            custom_nations: Vec::new(),
 
             resource_abundance: ResourceAbundance::Normal,
             mineral_distribution: MineralDistribution::Clustered,
@@ -108,6 +135,26 @@ pub enum MountainDensity {
     Many,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ErosionDroplets {
+    Light,
+    Normal,
+    Heavy,
+    Extreme,
+}
+
+impl ErosionDroplets {
+    /// Droplet count relative to the world size's baseline
+    pub fn multiplier(self) -> f32 {
+        match self {
+            ErosionDroplets::Light => 0.5,
+            ErosionDroplets::Normal => 1.0,
+            ErosionDroplets::Heavy => 2.0,
+            ErosionDroplets::Extreme => 4.0,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum AggressionLevel {
     Peaceful,
@@ -179,5 +226,19 @@ impl WorldGenerationSettings {
     }
 }
 
+/// Shareable file of nation designs, read and written by the designer
+pub const NATION_DESIGNS_FILE: &str = "presets/nations.ron";
+
+/// Nation designs as stored in `NATION_DESIGNS_FILE`
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct NationDesignPreset {
+    pub nations: Vec<CustomNationSpec>,
+}
+
+/// The designer's list needs rebuilding from the settings
+#[derive(Message, Clone, Debug, Default)]
+pub struct NationDesignsChanged;
+
 // Add Resource derive
-use bevy::prelude::Resource;
+use bevy::prelude::{Message, Resource};
+use serde::{Deserialize, Serialize};
@@ -0,0 +1,16 @@
+//! Natural wonders feature module gateway
+//!
+//! Great waterfalls, canyons, ancient forests, and sacred peaks placed from
+//! the world's terrain, each named, marked on the map, and worth a little
+//! treasury and stability to whoever holds it.
+
+// PRIVATE MODULES
+mod placement;
+mod plugin;
+mod systems;
+mod types;
+
+// PUBLIC EXPORTS
+pub use placement::place_natural_wonders;
+pub use plugin::NaturalWondersPlugin;
+pub use types::{NaturalWonder, NaturalWonders, WonderKind};
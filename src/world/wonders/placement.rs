@@ -0,0 +1,217 @@
+//! Choosing where natural wonders stand
+//!
+//! Each kind of wonder scores the provinces whose geography suits it - a
+//! waterfall where a river drops sharply, a canyon cut below dry highlands,
+//! an ancient forest deep inside unbroken woodland, a sacred peak above its
+//! range - and the best-scoring sites are taken, kept well apart. Placement
+//! depends only on the terrain and seed, so a loaded world finds the same
+//! wonders it was generated with.
+
+use crate::math::HEX_SIZE;
+use crate::name_generator::NameGenerator;
+use crate::world::{Province, TerrainType};
+
+use super::types::{NaturalWonder, WonderKind};
+
+/// Land provinces per wonder of each kind
+const LAND_PROVINCES_PER_WONDER: usize = 100_000;
+/// Most wonders of one kind in any world
+const MAX_WONDERS_PER_KIND: usize = 5;
+/// Closest two wonders may stand, in world units
+const MIN_WONDER_SPACING: f32 = HEX_SIZE * 60.0;
+/// Smallest drop from a river province to water below it that makes a waterfall
+const MIN_WATERFALL_DROP: f32 = 0.02;
+/// Weight of the per-province tiebreak, small against real score differences
+const JITTER_WEIGHT: f32 = 0.001;
+/// Keeps wonder names independent of other names drawn from the seed
+const WONDER_NAME_SALT: u64 = 0x5745_4E44_4552;
+
+/// Pick the world's natural wonders and name them
+pub fn place_natural_wonders(provinces: &[Province], seed: u32) -> Vec<NaturalWonder> {
+    let land = provinces.iter().filter(|province| !is_water(province.terrain)).count();
+    let quota = (land / LAND_PROVINCES_PER_WONDER).clamp(1, MAX_WONDERS_PER_KIND);
+    let mut names = NameGenerator::with_seed(seed as u64 ^ WONDER_NAME_SALT);
+    let mut wonders: Vec<NaturalWonder> = Vec::new();
+
+    for kind in WonderKind::ALL {
+        let mut candidates: Vec<(f32, usize)> = provinces
+            .iter()
+            .enumerate()
+            .filter_map(|(index, province)| {
+                let score = site_score(kind, provinces, province)?;
+                Some((score + jitter(seed, index) * JITTER_WEIGHT, index))
+            })
+            .collect();
+        candidates.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)));
+
+        let mut placed = 0;
+        for (_, index) in candidates {
+            if placed == quota {
+                break;
+            }
+            let position = provinces[index].position;
+            let crowded = wonders
+                .iter()
+                .any(|wonder| provinces[wonder.index].position.distance(position) < MIN_WONDER_SPACING);
+            if crowded {
+                continue;
+            }
+            wonders.push(NaturalWonder {
+                kind,
+                name: names.generate(kind.name_type()),
+                province: provinces[index].id,
+                index,
+            });
+            placed += 1;
+        }
+    }
+
+    wonders
+}
+
+/// How well a province suits a wonder, or `None` if it can't hold one
+fn site_score(kind: WonderKind, provinces: &[Province], province: &Province) -> Option<f32> {
+    let elevation = province.elevation.value();
+    let neighbors = || {
+        province
+            .neighbor_indices
+            .iter()
+            .flatten()
+            .filter_map(|&index| provinces.get(index))
+    };
+
+    match kind {
+        WonderKind::GreatWaterfall => {
+            if province.terrain != TerrainType::River {
+                return None;
+            }
+            let below = neighbors()
+                .filter(|neighbor| is_water(neighbor.terrain))
+                .map(|neighbor| neighbor.elevation.value())
+                .min_by(f32::total_cmp)?;
+            let drop = elevation - below;
+            (drop >= MIN_WATERFALL_DROP).then_some(drop)
+        }
+        WonderKind::Canyon => {
+            if !is_dry(province.terrain) {
+                return None;
+            }
+            let higher = neighbors()
+                .filter(|neighbor| neighbor.elevation.value() > elevation)
+                .count();
+            if higher < 4 {
+                return None;
+            }
+            let (sum, count) = neighbors().fold((0.0, 0), |(sum, count), neighbor| {
+                (sum + neighbor.elevation.value(), count + 1)
+            });
+            Some(sum / count as f32 - elevation)
+        }
+        WonderKind::AncientForest => {
+            if !is_forest(province.terrain) {
+                return None;
+            }
+            // Deep inside the woods: every neighbor and their neighbors forested
+            let mut forested = 0;
+            for neighbor in neighbors() {
+                if !is_forest(neighbor.terrain) {
+                    return None;
+                }
+                forested += neighbor
+                    .neighbor_indices
+                    .iter()
+                    .flatten()
+                    .filter_map(|&index| provinces.get(index))
+                    .filter(|outer| is_forest(outer.terrain))
+                    .count();
+            }
+            (forested > 0).then_some(forested as f32)
+        }
+        WonderKind::SacredPeak => {
+            if province.terrain != TerrainType::Alpine {
+                return None;
+            }
+            let summit = neighbors().all(|neighbor| neighbor.elevation.value() < elevation);
+            summit.then_some(elevation)
+        }
+    }
+}
+
+fn is_water(terrain: TerrainType) -> bool {
+    matches!(terrain, TerrainType::Ocean | TerrainType::River)
+}
+
+fn is_dry(terrain: TerrainType) -> bool {
+    matches!(
+        terrain,
+        TerrainType::ColdDesert
+            | TerrainType::SubtropicalDesert
+            | TerrainType::TropicalDesert
+            | TerrainType::Chaparral
+            | TerrainType::TemperateGrassland
+            | TerrainType::Savanna
+    )
+}
+
+fn is_forest(terrain: TerrainType) -> bool {
+    matches!(
+        terrain,
+        TerrainType::Taiga
+            | TerrainType::BorealForest
+            | TerrainType::TemperateRainforest
+            | TerrainType::TemperateDeciduousForest
+            | TerrainType::MediterraneanForest
+            | TerrainType::TropicalRainforest
+            | TerrainType::TropicalSeasonalForest
+    )
+}
+
+/// Per-province value in [0, 1) that breaks ties between equal sites
+fn jitter(seed: u32, index: usize) -> f32 {
+    let mut h = ((seed as u64) << 32) ^ index as u64;
+    h = (h ^ (h >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    h = (h ^ (h >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    h ^= h >> 31;
+    (h >> 40) as f32 / (1u64 << 24) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::{Elevation, ProvinceId};
+    use bevy::prelude::Vec2;
+
+    fn province(index: usize, terrain: TerrainType, elevation: f32) -> Province {
+        Province {
+            id: ProvinceId::new(index as u32),
+            position: Vec2::new(index as f32 * HEX_SIZE, 0.0),
+            terrain,
+            elevation: Elevation::new(elevation),
+            ..Province::default()
+        }
+    }
+
+    #[test]
+    fn waterfall_forms_where_a_river_drops_to_the_sea() {
+        let mut provinces = vec![
+            province(0, TerrainType::TemperateGrassland, 0.6),
+            province(1, TerrainType::River, 0.5),
+            province(2, TerrainType::River, 0.45),
+            province(3, TerrainType::Ocean, 0.0),
+        ];
+        let count = provinces.len();
+        for (index, province) in provinces.iter_mut().enumerate() {
+            province.neighbor_indices[1] = (index + 1 < count).then_some(index + 1);
+            province.neighbor_indices[4] = index.checked_sub(1);
+        }
+
+        let wonders = place_natural_wonders(&provinces, 7);
+        let falls: Vec<usize> = wonders
+            .iter()
+            .filter(|wonder| wonder.kind == WonderKind::GreatWaterfall)
+            .map(|wonder| wonder.index)
+            .collect();
+        assert_eq!(falls, [2]);
+        assert_eq!(wonders, place_natural_wonders(&provinces, 7));
+    }
+}
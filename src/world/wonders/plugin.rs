@@ -0,0 +1,23 @@
+//! Natural wonders plugin
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+use super::types::NaturalWonders;
+use crate::states::GameState;
+
+define_plugin!(NaturalWondersPlugin {
+    resources: [NaturalWonders],
+
+    update: [
+        super::systems::apply_wonder_effects.run_if(in_state(GameState::InGame))
+    ],
+
+    on_exit: {
+        GameState::LoadingWorld => [super::systems::clear_natural_wonders]
+    },
+
+    on_enter: {
+        GameState::InGame => [super::systems::initialize_natural_wonders]
+    }
+});
@@ -0,0 +1,74 @@
+//! Natural wonder lifecycle and yearly effects
+
+use bevy::prelude::*;
+
+use super::placement::place_natural_wonders;
+use super::types::NaturalWonders;
+use crate::nations::Nation;
+use crate::simulation::NewYearEvent;
+use crate::world::{ProvinceStorage, Wonder, WorldSeed};
+
+/// Forget the previous world's wonders once a new world has loaded
+pub fn clear_natural_wonders(
+    mut commands: Commands,
+    mut wonders: ResMut<NaturalWonders>,
+    markers: Query<Entity, With<Wonder>>,
+) {
+    wonders.clear();
+    for entity in &markers {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Place the world's wonders and mark them on the map
+pub fn initialize_natural_wonders(
+    mut commands: Commands,
+    mut wonders: ResMut<NaturalWonders>,
+    province_storage: Res<ProvinceStorage>,
+    world_seed: Option<Res<WorldSeed>>,
+) {
+    if !wonders.is_empty() {
+        return;
+    }
+
+    let seed = world_seed.map_or(0, |seed| seed.0);
+    wonders.wonders = place_natural_wonders(&province_storage.provinces, seed);
+    for wonder in &wonders.wonders {
+        commands.spawn((
+            Name::new(wonder.name.clone()),
+            Wonder {
+                name: wonder.name.clone(),
+                province: wonder.province,
+            },
+        ));
+    }
+
+    info!("Placed {} natural wonders", wonders.wonders.len());
+}
+
+/// Each year, owners of wonders gain a little treasury and stability
+pub fn apply_wonder_effects(
+    mut year_events: MessageReader<NewYearEvent>,
+    wonders: Res<NaturalWonders>,
+    province_storage: Res<ProvinceStorage>,
+    mut nations: Query<&mut Nation>,
+) {
+    if year_events.read().last().is_none() {
+        return;
+    }
+
+    for wonder in wonders.iter() {
+        let Some(owner) = province_storage
+            .provinces
+            .get(wonder.index)
+            .and_then(|province| province.owner_entity)
+        else {
+            continue;
+        };
+        let Ok(mut nation) = nations.get_mut(owner) else {
+            continue;
+        };
+        nation.treasury += wonder.kind.yearly_income();
+        nation.stability = (nation.stability + wonder.kind.yearly_stability()).min(1.0);
+    }
+}
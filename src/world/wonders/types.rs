@@ -0,0 +1,103 @@
+//! Natural wonder types and their effects
+
+use bevy::prelude::*;
+
+use crate::math::Money;
+use crate::name_generator::NameType;
+use crate::world::ProvinceId;
+
+/// Kind of natural landmark
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum WonderKind {
+    /// A river dropping off a steep escarpment
+    GreatWaterfall,
+    /// A valley carved deep below dry highlands
+    Canyon,
+    /// The heart of a large unbroken forest
+    AncientForest,
+    /// A summit towering over its range
+    SacredPeak,
+}
+
+impl WonderKind {
+    pub const ALL: [WonderKind; 4] = [
+        WonderKind::GreatWaterfall,
+        WonderKind::Canyon,
+        WonderKind::AncientForest,
+        WonderKind::SacredPeak,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            WonderKind::GreatWaterfall => "Great Waterfall",
+            WonderKind::Canyon => "Canyon",
+            WonderKind::AncientForest => "Ancient Forest",
+            WonderKind::SacredPeak => "Sacred Peak",
+        }
+    }
+
+    pub(super) fn name_type(self) -> NameType {
+        match self {
+            WonderKind::GreatWaterfall => NameType::Waterfall,
+            WonderKind::Canyon => NameType::Canyon,
+            WonderKind::AncientForest => NameType::AncientForest,
+            WonderKind::SacredPeak => NameType::Mountain,
+        }
+    }
+
+    /// Treasury the owner gains each year from travellers and trade
+    pub fn yearly_income(self) -> Money {
+        match self {
+            WonderKind::GreatWaterfall => Money::from_int(150),
+            WonderKind::Canyon => Money::from_int(100),
+            WonderKind::AncientForest => Money::from_int(120),
+            WonderKind::SacredPeak => Money::from_int(60),
+        }
+    }
+
+    /// Stability the owner gains each year from pride in the landmark
+    pub fn yearly_stability(self) -> f32 {
+        match self {
+            WonderKind::GreatWaterfall => 0.005,
+            WonderKind::Canyon => 0.005,
+            WonderKind::AncientForest => 0.008,
+            WonderKind::SacredPeak => 0.015,
+        }
+    }
+}
+
+/// A landmark placed at world generation
+#[derive(Debug, Clone, PartialEq)]
+pub struct NaturalWonder {
+    pub kind: WonderKind,
+    pub name: String,
+    pub province: ProvinceId,
+    /// Index into province storage
+    pub index: usize,
+}
+
+/// The world's natural wonders, derived from its terrain and seed
+#[derive(Resource, Debug, Default)]
+pub struct NaturalWonders {
+    pub(super) wonders: Vec<NaturalWonder>,
+}
+
+impl NaturalWonders {
+    pub fn iter(&self) -> impl Iterator<Item = &NaturalWonder> {
+        self.wonders.iter()
+    }
+
+    /// Wonder in a province, if it has one
+    pub fn in_province(&self, index: usize) -> Option<&NaturalWonder> {
+        self.wonders.iter().find(|wonder| wonder.index == index)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.wonders.is_empty()
+    }
+
+    /// Drop all wonders (new world or loaded save)
+    pub fn clear(&mut self) {
+        self.wonders.clear();
+    }
+}
@@ -0,0 +1,15 @@
+//! Crop yields feature module gateway
+//!
+//! Every year each province's agriculture is worked out afresh from its
+//! terrain, water, climate, irrigation, and its owner's technology (see the
+//! formula in the provinces module). The sea serves as the world's
+//! thermometer: as it rises the world warms and climate zones creep
+//! poleward, so over centuries the breadbaskets move with the climate and
+//! with the spread of irrigation and better tools.
+
+// PRIVATE MODULES
+mod plugin;
+mod systems;
+
+// PUBLIC EXPORTS
+pub use plugin::CropYieldPlugin;
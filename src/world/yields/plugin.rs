@@ -0,0 +1,12 @@
+//! Crop yield plugin
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+use crate::states::GameState;
+
+define_plugin!(CropYieldPlugin {
+    update: [
+        super::systems::update_crop_yields.run_if(in_state(GameState::InGame))
+    ]
+});
@@ -0,0 +1,112 @@
+//! Working out each province's yield at the turn of the year
+
+use bevy::prelude::*;
+
+use super::super::provinces::{
+    climate_shift, crop_yield, irrigation_reach, ProvinceAgricultureChanged, YieldConditions,
+};
+use crate::nations::Nation;
+use crate::simulation::NewYearEvent;
+use crate::world::generation::population_capacity;
+use crate::world::{
+    CachedOverlayColors, ClimateStorage, InfrastructureStorage, MapMode, ProvinceData, ProvinceEntityOrder,
+    ProvinceStorage, SeaLevel,
+};
+
+/// Degrees the world warms for each unit the sea has risen since generation
+///
+/// Steady drift moves the sea 0.004 a century, about 0.8 degrees; a great
+/// flood warms the world by 2 degrees at once.
+const WARMING_PER_SEA_LEVEL: f32 = 200.0;
+
+/// Recompute every province's agriculture from the yield formula
+///
+/// Provinces whose yield moved have their capacity recomputed and every
+/// copy of their data brought up to date; the agriculture map is redrawn if
+/// it is showing. Provinces without a climate record (saves from before
+/// climate was kept) keep the climate they were generated with.
+pub fn update_crop_yields(
+    mut year_events: MessageReader<NewYearEvent>,
+    province_storage: Option<ResMut<ProvinceStorage>>,
+    climate: Option<Res<ClimateStorage>>,
+    infrastructure: Option<Res<InfrastructureStorage>>,
+    sea_level: Res<SeaLevel>,
+    nations: Query<&Nation>,
+    entity_order: Option<Res<ProvinceEntityOrder>>,
+    mut province_data: Query<&mut ProvinceData>,
+    (mut overlay_colors, mut map_mode): (ResMut<CachedOverlayColors>, ResMut<MapMode>),
+    mut changed: MessageWriter<ProvinceAgricultureChanged>,
+) {
+    if year_events.read().last().is_none() {
+        return;
+    }
+    let Some(mut storage) = province_storage else {
+        return;
+    };
+    let warming = sea_level.offset * WARMING_PER_SEA_LEVEL;
+
+    let mut moved = false;
+    let mut gained_fertility = 0;
+    let mut lost_fertility = 0;
+    for (index, province) in storage.provinces.iter_mut().enumerate() {
+        let technology_level = province
+            .owner_entity
+            .and_then(|owner| nations.get(owner).ok())
+            .map_or(1, |nation| nation.technology_level);
+        let development_level = infrastructure
+            .as_ref()
+            .and_then(|infrastructure| infrastructure.get(province.id))
+            .map_or(0, |infra| infra.development_level);
+        let conditions = YieldConditions {
+            climate: climate
+                .as_ref()
+                .and_then(|climate| climate.get(province.id))
+                .map_or(1.0, |record| {
+                    climate_shift(record.temperature, record.rainfall, province.elevation.value(), warming)
+                }),
+            irrigation_reach: irrigation_reach(development_level, technology_level),
+            technology_level,
+        };
+
+        let agriculture = crop_yield(province.terrain, province.fresh_water_distance.value(), &conditions);
+        if agriculture == province.agriculture {
+            continue;
+        }
+        let event = ProvinceAgricultureChanged {
+            province_id: province.id,
+            old_agriculture: province.agriculture,
+            new_agriculture: agriculture,
+        };
+        moved = true;
+        match (event.old_agriculture.is_fertile(), agriculture.is_fertile()) {
+            (false, true) => gained_fertility += 1,
+            (true, false) => lost_fertility += 1,
+            _ => {}
+        }
+
+        province.agriculture = agriculture;
+        province.max_population = population_capacity(province);
+        province.population = province.population.min(province.max_population);
+        province.mark_dirty();
+        if let Some(entity) = entity_order.as_ref().and_then(|order| order.get(index)) {
+            if let Ok(mut data) = province_data.get_mut(entity) {
+                *data = ProvinceData::from_province(province);
+            }
+        }
+        changed.write(event);
+    }
+
+    if !moved {
+        return;
+    }
+    if gained_fertility + lost_fertility > 0 {
+        info!(
+            "Harvests shifted (world {:+.1} degrees): {} provinces became fertile, {} lost their fertility",
+            warming, gained_fertility, lost_fertility
+        );
+    }
+    overlay_colors.clear_cache();
+    if *map_mode == MapMode::Agriculture {
+        map_mode.set_changed();
+    }
+}
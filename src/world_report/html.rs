@@ -0,0 +1,216 @@
+//! Standalone HTML export of a world report
+//!
+//! The file has no external assets: styles are inline and charts are SVG, so
+//! it can be shared or archived as a single document.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use super::report::{ReportSeries, WorldReport};
+
+/// Directory exported reports are written to
+pub const REPORT_DIRECTORY: &str = "reports";
+
+const CHART_WIDTH: f64 = 720.0;
+const CHART_HEIGHT: f64 = 220.0;
+
+/// Escape text for use in HTML content and attributes
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn css_color([r, g, b]: [f32; 3]) -> String {
+    let channel = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!("#{:02x}{:02x}{:02x}", channel(r), channel(g), channel(b))
+}
+
+/// Inline SVG line chart of one or more series sharing a scale
+fn line_chart(series: &[&ReportSeries], first_year: u32, final_year: u32) -> String {
+    let max = WorldReport::series_max(series).max(1.0);
+    let span = f64::from(final_year.saturating_sub(first_year).max(1));
+    let mut svg = format!(
+        "<svg viewBox=\"0 0 {CHART_WIDTH} {CHART_HEIGHT}\" class=\"chart\">\
+         <rect width=\"100%\" height=\"100%\" fill=\"#1b1b22\"/>"
+    );
+    for s in series {
+        let points: Vec<String> = s
+            .points
+            .iter()
+            .map(|&(year, value)| {
+                let x = f64::from(year.saturating_sub(first_year)) / span * CHART_WIDTH;
+                let y = CHART_HEIGHT - value / max * (CHART_HEIGHT - 10.0);
+                format!("{x:.1},{y:.1}")
+            })
+            .collect();
+        let _ = write!(
+            svg,
+            "<polyline fill=\"none\" stroke=\"{}\" stroke-width=\"2\" points=\"{}\"><title>{}</title></polyline>",
+            css_color(s.color),
+            points.join(" "),
+            escape(&s.label)
+        );
+    }
+    svg.push_str("</svg><div class=\"legend\">");
+    for s in series {
+        let _ = write!(
+            svg,
+            "<span><i style=\"background:{}\"></i>{}</span>",
+            css_color(s.color),
+            escape(&s.label)
+        );
+    }
+    svg.push_str("</div>");
+    svg
+}
+
+/// Render the whole report as a self-contained HTML document
+pub fn render_html(report: &WorldReport) -> String {
+    let mut html = String::new();
+    let title = escape(&report.world_name);
+    let _ = write!(
+        html,
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>{title} - World Report</title>\
+         <style>body{{background:#121218;color:#ddd;font-family:Georgia,serif;max-width:800px;margin:auto;padding:2em}}\
+         h1,h2{{color:#e6c97a}}table{{width:100%;border-collapse:collapse}}td,th{{padding:4px 8px;border-bottom:1px solid #333;text-align:left}}\
+         .chart{{width:100%}}.legend span{{margin-right:1em}}.legend i{{display:inline-block;width:10px;height:10px;margin-right:4px}}</style>\
+         </head><body><h1>{title}</h1><p>Years {} to {}</p>",
+        report.first_year, report.final_year
+    );
+
+    html.push_str("<h2>Greatest Empires</h2><table><tr><th>#</th><th>Nation</th><th>Peak provinces</th><th>Peak year</th><th>Lifetime</th></tr>");
+    for (rank, empire) in report.empires.iter().enumerate() {
+        let _ = write!(
+            html,
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}-{} ({} years)</td></tr>",
+            rank + 1,
+            escape(&empire.name),
+            empire.peak_provinces,
+            empire.peak_year,
+            empire.first_year,
+            empire.last_year,
+            empire.years()
+        );
+    }
+    html.push_str("</table>");
+    let territory: Vec<&ReportSeries> = report.territory.iter().collect();
+    html.push_str(&line_chart(
+        &territory,
+        report.first_year,
+        report.final_year,
+    ));
+
+    html.push_str("<h2>Notable Rulers</h2><table><tr><th>Ruler</th><th>House</th><th>Realm</th><th>Reign</th></tr>");
+    for reign in &report.rulers {
+        let _ = write!(
+            html,
+            "<tr><td>{} {}</td><td>{}</td><td>{}</td><td>{}-{} ({} years)</td></tr>",
+            escape(&reign.title),
+            escape(&reign.ruler),
+            escape(&reign.house),
+            escape(&reign.nation),
+            reign.start_year,
+            reign.end_year,
+            reign.years()
+        );
+    }
+    html.push_str("</table>");
+
+    html.push_str("<h2>Bloodiest Wars</h2><table><tr><th>Belligerents</th><th>Years</th><th>Battles</th><th>Casualties</th></tr>");
+    for war in &report.wars {
+        let end = war
+            .end_year
+            .map_or_else(|| "ongoing".to_string(), |year| year.to_string());
+        let _ = write!(
+            html,
+            "<tr><td>{}</td><td>{}-{}</td><td>{}</td><td>{:.0}</td></tr>",
+            escape(&war.belligerents.join(", ")),
+            war.start_year,
+            end,
+            war.battles,
+            war.casualties
+        );
+    }
+    html.push_str("</table>");
+
+    html.push_str("<h2>Economic Winners</h2><table><tr><th>Nation</th><th>Peak treasury</th></tr>");
+    for economy in &report.economies {
+        let _ = write!(
+            html,
+            "<tr><td>{}</td><td>{:.0}</td></tr>",
+            escape(&economy.name),
+            economy.peak_treasury
+        );
+    }
+    html.push_str("</table>");
+
+    if !report.trade_agreements.is_empty() {
+        html.push_str(
+            "<h2>Trade Agreements</h2><table><tr><th>Signatories</th><th>Years</th>\
+             <th>Trade before</th><th>Trade under agreement</th><th>Gains</th></tr>",
+        );
+        for agreement in &report.trade_agreements {
+            let end = agreement
+                .end_year
+                .map_or_else(|| "standing".to_string(), |year| year.to_string());
+            let _ = write!(
+                html,
+                "<tr><td>{} &amp; {}</td><td>{}-{}</td><td>{:.0}</td>\
+                 <td>{:.0} ({:+.0}%)</td><td>{:.0} / {:.0}</td></tr>",
+                escape(&agreement.names[0]),
+                escape(&agreement.names[1]),
+                agreement.signed_year,
+                end,
+                agreement.volume_before,
+                agreement.volume,
+                agreement.trade_growth() * 100.0,
+                agreement.gains[0],
+                agreement.gains[1]
+            );
+        }
+        html.push_str("</table>");
+    }
+
+    html.push_str("<h2>Population</h2>");
+    html.push_str(&line_chart(
+        &[&report.population],
+        report.first_year,
+        report.final_year,
+    ));
+
+    if !report.milestones.is_empty() {
+        html.push_str("<h2>Milestones</h2><ul>");
+        for (year, text) in &report.milestones {
+            let _ = write!(html, "<li><b>{year}</b> {}</li>", escape(text));
+        }
+        html.push_str("</ul>");
+    }
+
+    html.push_str("</body></html>");
+    html
+}
+
+/// Write the report to [`REPORT_DIRECTORY`] and return where it went
+pub fn export_html(report: &WorldReport) -> io::Result<PathBuf> {
+    fs::create_dir_all(REPORT_DIRECTORY)?;
+    let stem: String = report
+        .world_name
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    let path = PathBuf::from(REPORT_DIRECTORY).join(format!("{}_{}.html", stem, report.final_year));
+    fs::write(&path, render_html(report))?;
+    Ok(path)
+}
@@ -0,0 +1,439 @@
+//! Market prices for the statistics record
+//!
+//! The great cities are the world's markets. Each draws on the provinces
+//! around it: their fields and mines supply the goods, their people demand
+//! them, and a market's price settles where the two balance. Prices wander
+//! with the seasons and chase that balance, while merchants buy where goods
+//! are cheap and sell where they are dear. Once two markets have traded for
+//! a while, and their cities keep their ports open, the gap between them
+//! narrows to what it costs to carry goods from one to the other.
+//!
+//! Every year each market's prices are kept as a candle - open, high, low,
+//! and close over the seasons - so the report can chart them.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use super::statistics::{SAMPLE_INTERVAL_YEARS, WorldStatistics};
+use crate::math::HEX_SIZE;
+use crate::nations::{CityNames, ContactPolicy, ContactStance};
+use crate::simulation::NewYearEvent;
+use crate::world::{InfrastructureStorage, Province, ProvinceStorage, TerrainType, WorldSeed};
+
+/// Most markets tracked in one world
+pub const MAX_MARKETS: usize = 12;
+/// Years of candles kept per good per market
+pub const MAX_PRICE_YEARS: usize = 500;
+/// People a city needs before it holds a market
+const MARKET_POPULATION: u32 = 5_000;
+/// Closest two markets may stand
+const MIN_MARKET_SPACING: f32 = HEX_SIZE * 40.0;
+/// Farthest a province sells into its market
+const MARKET_REACH: f32 = HEX_SIZE * 30.0;
+/// Farthest merchants carry goods between markets
+const TRADE_REACH: f32 = HEX_SIZE * 120.0;
+/// Carrying cost at the edge of trade reach, as a share of the price
+const MAX_TRANSPORT_COST: f32 = 0.3;
+/// Seasons a year's candle is drawn from
+const SEASONS: usize = 4;
+/// Share of the gap to the balancing price closed each season
+const PRICE_ADJUSTMENT: f32 = 0.3;
+/// How sharply price answers to shortage or glut
+const PRICE_ELASTICITY: f32 = 0.6;
+/// Largest seasonal swing in price, either way
+const SEASONAL_NOISE: f32 = 0.08;
+/// Share of an arbitrage gap a fully integrated pair of markets closes each season
+const ARBITRAGE_RATE: f32 = 0.5;
+/// Years of trade before two markets' merchants are fully established
+const INTEGRATION_YEARS: f32 = 150.0;
+/// Prices never stray further than this multiple from a good's base price
+const PRICE_RANGE: f32 = 10.0;
+
+/// Goods traded in every market
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Good {
+    #[default]
+    Grain,
+    Iron,
+    Copper,
+    Tin,
+    Gold,
+    Gems,
+}
+
+impl Good {
+    pub const ALL: [Good; 6] = [Good::Grain, Good::Iron, Good::Copper, Good::Tin, Good::Gold, Good::Gems];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Good::Grain => "Grain",
+            Good::Iron => "Iron",
+            Good::Copper => "Copper",
+            Good::Tin => "Tin",
+            Good::Gold => "Gold",
+            Good::Gems => "Gems",
+        }
+    }
+
+    /// Price where supply meets demand in a typical market
+    pub fn base_price(self) -> f32 {
+        match self {
+            Good::Grain => 1.0,
+            Good::Iron => 4.0,
+            Good::Copper => 6.0,
+            Good::Tin => 12.0,
+            Good::Gold => 80.0,
+            Good::Gems => 150.0,
+        }
+    }
+
+    /// Output per person in a typical province, which is what people demand
+    fn typical_yield(self) -> f32 {
+        match self {
+            Good::Grain => 1.5,
+            Good::Iron => 0.3,
+            Good::Copper => 0.25,
+            Good::Tin => 0.1,
+            Good::Gold => 0.05,
+            Good::Gems => 0.03,
+        }
+    }
+
+    /// Output per person of a province
+    fn yield_of(self, province: &Province) -> f32 {
+        match self {
+            Good::Grain => province.agriculture.value(),
+            Good::Iron => province.iron.normalized(),
+            Good::Copper => province.copper.normalized(),
+            Good::Tin => province.tin.normalized(),
+            Good::Gold => province.gold.normalized(),
+            Good::Gems => province.gems.normalized(),
+        }
+    }
+}
+
+/// One year of a good's price in one market
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PriceCandle {
+    pub year: u32,
+    pub open: f32,
+    pub high: f32,
+    pub low: f32,
+    pub close: f32,
+}
+
+/// A market's name and price history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarketRecord {
+    /// City name, kept current through renames
+    pub name: String,
+    /// Year the market opened
+    pub opened: u32,
+    /// Yearly candles per good, oldest first, capped at [`MAX_PRICE_YEARS`]
+    pub prices: BTreeMap<Good, Vec<PriceCandle>>,
+}
+
+impl MarketRecord {
+    /// Latest closing price of a good, if the market has traded it
+    pub fn last_close(&self, good: Good) -> Option<f32> {
+        self.prices.get(&good)?.last().map(|candle| candle.close)
+    }
+}
+
+/// Price where a market's supply and demand balance
+pub fn balancing_price(good: Good, supply: f32, demand: f32) -> f32 {
+    let base = good.base_price();
+    if demand <= 0.0 {
+        return base;
+    }
+    let shortage = demand / supply.max(f32::EPSILON);
+    (base * shortage.powf(PRICE_ELASTICITY)).clamp(base / PRICE_RANGE, base * PRICE_RANGE)
+}
+
+/// Merchants carry goods from the cheaper market to the dearer one
+///
+/// Only the part of the gap beyond the carrying cost is worth trading, and
+/// `integration` (0 to 1) is how much of it established merchants close.
+pub fn arbitrage(dear: &mut f32, cheap: &mut f32, transport_cost: f32, integration: f32) {
+    let landed = *cheap * (1.0 + transport_cost);
+    if *dear <= landed {
+        return;
+    }
+    let shift = (*dear - landed) * ARBITRAGE_RATE * integration.clamp(0.0, 1.0) / 2.0;
+    *dear -= shift;
+    *cheap += shift;
+}
+
+/// Spread between the dearest and cheapest of `markets` for each year they all traded `good`,
+/// as a share of their mean closing price
+pub fn price_spread<'a>(markets: impl IntoIterator<Item = &'a MarketRecord>, good: Good) -> Vec<(u32, f32)> {
+    let mut closes: BTreeMap<u32, Vec<f32>> = BTreeMap::new();
+    let mut count = 0;
+    for market in markets {
+        count += 1;
+        for candle in market.prices.get(&good).into_iter().flatten() {
+            closes.entry(candle.year).or_default().push(candle.close);
+        }
+    }
+    closes
+        .into_iter()
+        .filter(|(_, prices)| count > 1 && prices.len() == count)
+        .map(|(year, prices)| {
+            let max = prices.iter().copied().fold(f32::MIN, f32::max);
+            let min = prices.iter().copied().fold(f32::MAX, f32::min);
+            let mean = prices.iter().sum::<f32>() / prices.len() as f32;
+            (year, (max - min) / mean.max(f32::EPSILON))
+        })
+        .collect()
+}
+
+/// Open markets in great cities with room around them
+fn open_markets(statistics: &mut WorldStatistics, provinces: &[Province], city_names: &CityNames, year: u32) {
+    if statistics.markets.len() >= MAX_MARKETS {
+        return;
+    }
+    let mut cities: Vec<(u32, usize)> = provinces
+        .iter()
+        .enumerate()
+        .filter(|(_, province)| province.population >= MARKET_POPULATION && province.owner_entity.is_some())
+        .map(|(index, province)| (province.population, index))
+        .collect();
+    cities.sort_by(|a, b| b.cmp(a));
+
+    for (_, index) in cities {
+        if statistics.markets.len() >= MAX_MARKETS {
+            break;
+        }
+        let position = provinces[index].position;
+        let crowded = statistics.markets.keys().any(|&market| {
+            provinces
+                .get(market as usize)
+                .is_some_and(|other| other.position.distance(position) < MIN_MARKET_SPACING)
+        });
+        if crowded {
+            continue;
+        }
+        let Some(city) = city_names.get(index) else {
+            continue;
+        };
+        statistics.markets.insert(
+            index as u32,
+            MarketRecord {
+                name: city.name.clone(),
+                opened: year,
+                prices: BTreeMap::new(),
+            },
+        );
+    }
+}
+
+/// Record a year of prices in every market
+pub fn record_market_prices(
+    mut year_events: MessageReader<NewYearEvent>,
+    mut statistics: ResMut<WorldStatistics>,
+    province_storage: Option<Res<ProvinceStorage>>,
+    infrastructure: Option<Res<InfrastructureStorage>>,
+    world_seed: Option<Res<WorldSeed>>,
+    city_names: Res<CityNames>,
+    stances_query: Query<&ContactStance>,
+) {
+    let Some(year) = year_events.read().last().map(|event| event.year) else {
+        return;
+    };
+    let Some(storage) = province_storage else {
+        return;
+    };
+    let provinces = &storage.provinces;
+    let statistics = statistics.as_mut();
+
+    if statistics.markets.is_empty() || year % SAMPLE_INTERVAL_YEARS == 0 {
+        open_markets(statistics, provinces, &city_names, year);
+    }
+    if statistics.markets.is_empty() {
+        return;
+    }
+    let markets: Vec<usize> = statistics.markets.keys().map(|&index| index as usize).collect();
+
+    // Each settled province sells into its nearest market within reach
+    let mut supply = vec![[0.0f32; Good::ALL.len()]; markets.len()];
+    let mut demand = vec![[0.0f32; Good::ALL.len()]; markets.len()];
+    for province in provinces {
+        if province.population == 0 || province.terrain == TerrainType::Ocean {
+            continue;
+        }
+        let nearest = markets
+            .iter()
+            .enumerate()
+            .filter_map(|(slot, &market)| {
+                let distance = provinces.get(market)?.position.distance(province.position);
+                (distance <= MARKET_REACH).then_some((distance, slot))
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0));
+        let Some((_, slot)) = nearest else {
+            continue;
+        };
+        let people = province.population as f32;
+        for (g, good) in Good::ALL.into_iter().enumerate() {
+            supply[slot][g] += people * good.yield_of(province);
+            demand[slot][g] += people * good.typical_yield();
+        }
+    }
+
+    // Merchants trade between pairs in reach whose cities keep their ports open
+    let open = |index: usize| {
+        provinces
+            .get(index)
+            .and_then(|province| province.owner_entity)
+            .is_none_or(|owner| stances_query.get(owner).is_ok_and(|s| s.policy == ContactPolicy::Open))
+    };
+    let connectivity = |index: usize| {
+        let (Some(infrastructure), Some(province)) = (infrastructure.as_ref(), provinces.get(index)) else {
+            return 0.5;
+        };
+        infrastructure
+            .get(province.id)
+            .map_or(0.0, |infra| infrastructure.normalized_connectivity(infra.connectivity))
+    };
+    let mut routes = Vec::new();
+    for a in 0..markets.len() {
+        for b in a + 1..markets.len() {
+            let (Some(from), Some(to)) = (provinces.get(markets[a]), provinces.get(markets[b])) else {
+                continue;
+            };
+            let distance = from.position.distance(to.position);
+            if distance > TRADE_REACH || !open(markets[a]) || !open(markets[b]) {
+                continue;
+            }
+            let opened = [markets[a], markets[b]]
+                .iter()
+                .filter_map(|&index| statistics.markets.get(&(index as u32)))
+                .map(|market| market.opened)
+                .max()
+                .unwrap_or(year);
+            let established = (year.saturating_sub(opened) as f32 / INTEGRATION_YEARS).min(1.0);
+            let integration = established * (connectivity(markets[a]) + connectivity(markets[b])) / 2.0;
+            routes.push((a, b, MAX_TRANSPORT_COST * distance / TRADE_REACH, integration));
+        }
+    }
+
+    // Seeded by world and year so identical runs chart identical prices
+    let seed = world_seed.map_or(0, |seed| seed.0) as u64;
+    let mut rng = StdRng::seed_from_u64((seed << 32) ^ year as u64 ^ 0x6d61_726b_6574);
+
+    for (g, good) in Good::ALL.into_iter().enumerate() {
+        let targets: Vec<f32> = (0..markets.len())
+            .map(|slot| balancing_price(good, supply[slot][g], demand[slot][g]))
+            .collect();
+        let mut prices: Vec<f32> = markets
+            .iter()
+            .zip(&targets)
+            .map(|(&index, &target)| {
+                statistics
+                    .markets
+                    .get(&(index as u32))
+                    .and_then(|market| market.last_close(good))
+                    .unwrap_or(target)
+            })
+            .collect();
+        let mut candles: Vec<PriceCandle> = prices
+            .iter()
+            .map(|&price| PriceCandle {
+                year,
+                open: price,
+                high: price,
+                low: price,
+                close: price,
+            })
+            .collect();
+
+        for _ in 0..SEASONS {
+            for (price, &target) in prices.iter_mut().zip(&targets) {
+                let swing = 1.0 + rng.gen_range(-SEASONAL_NOISE..SEASONAL_NOISE);
+                *price += (target * swing - *price) * PRICE_ADJUSTMENT;
+            }
+            for &(a, b, cost, integration) in &routes {
+                let (low, high) = prices.split_at_mut(b);
+                let (first, second) = (&mut low[a], &mut high[0]);
+                if *first > *second {
+                    arbitrage(first, second, cost, integration);
+                } else {
+                    arbitrage(second, first, cost, integration);
+                }
+            }
+            for (candle, &price) in candles.iter_mut().zip(&prices) {
+                candle.high = candle.high.max(price);
+                candle.low = candle.low.min(price);
+                candle.close = price;
+            }
+        }
+
+        for (&index, candle) in markets.iter().zip(candles) {
+            if let Some(market) = statistics.markets.get_mut(&(index as u32)) {
+                let history = market.prices.entry(good).or_default();
+                history.push(candle);
+                if history.len() > MAX_PRICE_YEARS {
+                    history.remove(0);
+                }
+            }
+        }
+    }
+
+    for (&index, market) in statistics.markets.iter_mut() {
+        if let Some(city) = city_names.get(index as usize) {
+            if city.name != market.name {
+                market.name = city.name.clone();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn market(closes: &[f32]) -> MarketRecord {
+        let candles = closes
+            .iter()
+            .enumerate()
+            .map(|(year, &close)| PriceCandle {
+                year: year as u32,
+                open: close,
+                high: close,
+                low: close,
+                close,
+            })
+            .collect();
+        MarketRecord {
+            name: String::new(),
+            opened: 0,
+            prices: BTreeMap::from([(Good::Iron, candles)]),
+        }
+    }
+
+    #[test]
+    fn merchants_close_gaps_down_to_carrying_cost() {
+        assert!(balancing_price(Good::Grain, 50.0, 100.0) > Good::Grain.base_price());
+        assert!(balancing_price(Good::Grain, 200.0, 100.0) < Good::Grain.base_price());
+        assert_eq!(
+            balancing_price(Good::Gold, 0.0, 100.0),
+            Good::Gold.base_price() * PRICE_RANGE
+        );
+
+        let (mut dear, mut cheap) = (20.0, 10.0);
+        for _ in 0..50 {
+            arbitrage(&mut dear, &mut cheap, 0.1, 1.0);
+        }
+        assert!((dear - cheap * 1.1).abs() < 0.01);
+
+        let (mut dear, mut cheap) = (20.0, 10.0);
+        arbitrage(&mut dear, &mut cheap, 0.1, 0.0);
+        assert_eq!((dear, cheap), (20.0, 10.0));
+
+        let spread = price_spread([&market(&[10.0, 12.0]), &market(&[30.0, 12.0])], Good::Iron);
+        assert_eq!(spread, vec![(0, 1.0), (1, 0.0)]);
+    }
+}
@@ -0,0 +1,35 @@
+//! End-of-world summary report gateway
+//!
+//! Gathers statistics as a world runs and turns them, with the chronicle, into
+//! a ranked summary: greatest empires, notable rulers, bloodiest wars, economic
+//! winners, and population and territory graphs. The report opens in game
+//! (R, or at a scheduled year) and is exported as standalone HTML, including
+//! automatically when the observer stops the world.
+//!
+//! Alongside the report, each great city's market prices are recorded every
+//! year, and K opens candle charts of any good across chosen cities with the
+//! spread between them.
+//!
+//! # Gateway Pattern
+//!
+//! This is a PURE gateway - no implementations, only module declarations
+//! and controlled exports.
+
+// PRIVATE MODULES
+mod html;
+mod markets;
+mod plugin;
+mod price_chart;
+mod report;
+mod statistics;
+mod systems;
+mod types;
+mod ui;
+
+// CONTROLLED EXPORTS
+pub use html::{REPORT_DIRECTORY, render_html};
+pub use markets::{record_market_prices, Good, MarketRecord, PriceCandle};
+pub use plugin::WorldReportPlugin;
+pub use report::{WorldReport, build_world_report};
+pub use statistics::WorldStatistics;
+pub use types::OpenWorldReport;
@@ -0,0 +1,45 @@
+//! World report plugin
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+use super::markets::record_market_prices;
+use super::statistics::{
+    WorldStatistics, collect_world_statistics, record_trade_agreements, record_war_endings,
+    reset_world_statistics,
+};
+use super::systems::{
+    check_report_schedule, export_report_on_stop, handle_price_chart_buttons, handle_price_chart_shortcut,
+    handle_report_buttons, handle_report_shortcut, open_world_report, refresh_price_chart,
+};
+use super::types::{DisplayedWorldReport, OpenWorldReport, PriceChartSelection, ReportSchedule};
+use crate::states::GameState;
+
+define_plugin!(WorldReportPlugin {
+    resources: [WorldStatistics, ReportSchedule, DisplayedWorldReport, PriceChartSelection],
+
+    messages: [OpenWorldReport],
+
+    update: [
+        (
+            collect_world_statistics,
+            record_market_prices,
+            record_trade_agreements,
+            record_war_endings,
+            check_report_schedule,
+            handle_report_shortcut,
+            open_world_report,
+            handle_report_buttons,
+            handle_price_chart_shortcut,
+            handle_price_chart_buttons,
+            refresh_price_chart
+        )
+            .chain()
+            .run_if(in_state(GameState::InGame))
+    ],
+
+    on_enter: {
+        GameState::LoadingWorld => [reset_world_statistics],
+        GameState::MainMenu => [export_report_on_stop]
+    }
+});
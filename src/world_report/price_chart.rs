@@ -0,0 +1,264 @@
+//! Market price charts - candles per city and the spread between them
+
+use bevy::prelude::*;
+
+use super::markets::{Good, MarketRecord, PriceCandle, price_spread};
+use super::statistics::WorldStatistics;
+use super::types::{ClosePriceChartButton, PriceChartGoodButton, PriceChartMarketButton, PriceChartPanel};
+use crate::ui::*;
+
+/// Years of candles shown
+const CHART_YEARS: usize = 80;
+/// Height of each market's candle chart
+const CHART_HEIGHT: f32 = 120.0;
+/// Height of the spread strip
+const SPREAD_HEIGHT: f32 = 60.0;
+/// Markets charted at once, one color each
+pub const MAX_CHARTED_MARKETS: usize = 4;
+const MARKET_COLORS: [Color; MAX_CHARTED_MARKETS] = [
+    Color::srgb(0.95, 0.75, 0.3),
+    Color::srgb(0.4, 0.75, 0.95),
+    Color::srgb(0.85, 0.45, 0.85),
+    Color::srgb(0.55, 0.9, 0.5),
+];
+const RISING: Color = Color::srgb(0.3, 0.75, 0.35);
+const FALLING: Color = Color::srgb(0.85, 0.3, 0.3);
+
+fn line(parent: &mut ChildBuilder, text: String, color: Color) {
+    parent.spawn((
+        Text::new(text),
+        TextFont {
+            font_size: TEXT_SIZE_NORMAL,
+            ..default()
+        },
+        TextColor(color),
+    ));
+}
+
+fn button_row(parent: &mut ChildBuilder, build: impl FnOnce(&mut ChildBuilder)) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            flex_wrap: FlexWrap::Wrap,
+            column_gap: Val::Px(6.0),
+            row_gap: Val::Px(6.0),
+            ..default()
+        })
+        .with_children(build);
+}
+
+/// The last [`CHART_YEARS`] candles of a market's good
+fn recent(market: &MarketRecord, good: Good) -> &[PriceCandle] {
+    let candles = market.prices.get(&good).map_or(&[][..], |candles| candles.as_slice());
+    &candles[candles.len().saturating_sub(CHART_YEARS)..]
+}
+
+/// One candle per year: a thin wick from low to high behind a body from open to close
+fn candle_chart(parent: &mut ChildBuilder, candles: &[PriceCandle], low: f32, high: f32) {
+    let range = (high - low).max(f32::EPSILON);
+    let from_top = |price: f32| (high - price) / range * 100.0;
+    parent
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Px(CHART_HEIGHT),
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(1.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            for candle in candles {
+                let color = if candle.close >= candle.open { RISING } else { FALLING };
+                let body_top = candle.open.max(candle.close);
+                let body_bottom = candle.open.min(candle.close);
+                parent
+                    .spawn(Node {
+                        flex_grow: 1.0,
+                        height: Val::Percent(100.0),
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        parent.spawn((
+                            Node {
+                                position_type: PositionType::Absolute,
+                                left: Val::Percent(40.0),
+                                width: Val::Percent(20.0),
+                                top: Val::Percent(from_top(candle.high)),
+                                height: Val::Percent((candle.high - candle.low) / range * 100.0),
+                                ..default()
+                            },
+                            BackgroundColor(color),
+                        ));
+                        parent.spawn((
+                            Node {
+                                position_type: PositionType::Absolute,
+                                left: Val::Percent(10.0),
+                                width: Val::Percent(80.0),
+                                top: Val::Percent(from_top(body_top)),
+                                height: Val::Percent((body_top - body_bottom) / range * 100.0),
+                                min_height: Val::Px(1.0),
+                                ..default()
+                            },
+                            BackgroundColor(color),
+                        ));
+                    });
+            }
+        });
+}
+
+/// Column chart of the spread between charted markets, one column per year
+fn spread_strip(parent: &mut ChildBuilder, spread: &[(u32, f32)]) {
+    let max = spread.iter().map(|&(_, value)| value).fold(f32::EPSILON, f32::max);
+    parent
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Px(SPREAD_HEIGHT),
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::FlexEnd,
+            column_gap: Val::Px(1.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            for &(_, value) in spread {
+                parent.spawn((
+                    Node {
+                        flex_grow: 1.0,
+                        height: Val::Percent(value / max * 100.0),
+                        ..default()
+                    },
+                    BackgroundColor(colors::WARNING),
+                ));
+            }
+        });
+}
+
+/// Spawn the price chart for `good` across the `charted` markets
+pub fn spawn_price_chart_panel(commands: &mut Commands, statistics: &WorldStatistics, good: Good, charted: &[u32]) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(15.0),
+                top: Val::Px(60.0),
+                width: Val::Percent(70.0),
+                height: Val::Percent(85.0),
+                padding: UiRect::all(Val::Px(20.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(UI_BACKGROUND_COLOR),
+            BorderColor::all(UI_BORDER_COLOR),
+            GlobalZIndex(layers::MODAL_OVERLAY),
+            PriceChartPanel,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!("MARKET PRICES - {}", good.label().to_uppercase())),
+                TextFont {
+                    font_size: TEXT_SIZE_TITLE,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR_HEADER),
+            ));
+
+            button_row(parent, |parent| {
+                for candidate in Good::ALL {
+                    let style = if candidate == good {
+                        ButtonStyle::Primary
+                    } else {
+                        ButtonStyle::Secondary
+                    };
+                    ButtonBuilder::new(candidate.label())
+                        .size(ButtonSize::Small)
+                        .style(style)
+                        .with_marker(PriceChartGoodButton(candidate))
+                        .build(parent);
+                }
+                ButtonBuilder::new("Close")
+                    .size(ButtonSize::Small)
+                    .style(ButtonStyle::Ghost)
+                    .with_marker(ClosePriceChartButton)
+                    .build(parent);
+            });
+
+            button_row(parent, |parent| {
+                for (&index, market) in &statistics.markets {
+                    let style = if charted.contains(&index) {
+                        ButtonStyle::Primary
+                    } else {
+                        ButtonStyle::Secondary
+                    };
+                    ButtonBuilder::new(market.name.as_str())
+                        .size(ButtonSize::Small)
+                        .style(style)
+                        .with_marker(PriceChartMarketButton(index))
+                        .build(parent);
+                }
+            });
+
+            parent
+                .spawn(Node {
+                    width: Val::Percent(100.0),
+                    flex_grow: 1.0,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(6.0),
+                    overflow: Overflow::scroll_y(),
+                    ..default()
+                })
+                .with_children(|parent| spawn_charts(parent, statistics, good, charted));
+        });
+}
+
+fn spawn_charts(parent: &mut ChildBuilder, statistics: &WorldStatistics, good: Good, charted: &[u32]) {
+    let markets: Vec<&MarketRecord> = charted
+        .iter()
+        .filter_map(|index| statistics.markets.get(index))
+        .collect();
+    if markets.is_empty() {
+        line(
+            parent,
+            "Pick up to four markets to chart".to_string(),
+            TEXT_COLOR_SECONDARY,
+        );
+        return;
+    }
+
+    // One price scale for every chart, so the gaps between markets show
+    let (low, high) = markets
+        .iter()
+        .flat_map(|market| recent(market, good))
+        .fold((f32::MAX, f32::MIN), |(low, high), candle| {
+            (low.min(candle.low), high.max(candle.high))
+        });
+
+    for (market, color) in markets.iter().zip(MARKET_COLORS) {
+        let candles = recent(market, good);
+        let summary = match (candles.first(), candles.last()) {
+            (Some(first), Some(last)) => format!(
+                "{} - {:.2} ({} to {}, from {:.2})",
+                market.name, last.close, first.year, last.year, first.open
+            ),
+            _ => format!("{} - no trade yet", market.name),
+        };
+        line(parent, summary, color);
+        candle_chart(parent, candles, low, high);
+    }
+
+    let spread = price_spread(markets.iter().copied(), good);
+    let spread = &spread[spread.len().saturating_sub(CHART_YEARS)..];
+    if let (Some(&(first_year, first)), Some(&(last_year, last))) = (spread.first(), spread.last()) {
+        line(
+            parent,
+            format!(
+                "Spread between markets - {:.0}% in {}, {:.0}% in {}",
+                first * 100.0,
+                first_year,
+                last * 100.0,
+                last_year
+            ),
+            TEXT_COLOR_PRIMARY,
+        );
+        spread_strip(parent, spread);
+    }
+}
@@ -0,0 +1,198 @@
+//! Turning gathered statistics into a ranked end-of-world report
+
+use super::statistics::{NationRecord, ReignRecord, TradeAgreementRecord, WarRecord, WorldStatistics};
+use crate::chronicle::{ChronicleCategory, WorldChronicle};
+
+/// Entries shown in each ranked section
+pub const REPORT_SECTION_SIZE: usize = 10;
+
+/// Empires charted in the territory graph
+pub const CHARTED_EMPIRES: usize = 5;
+
+/// A named series of (year, value) points for a line chart
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportSeries {
+    pub label: String,
+    pub color: [f32; 3],
+    pub points: Vec<(u32, f64)>,
+}
+
+/// Finished summary of a world, ready to show or export
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorldReport {
+    pub world_name: String,
+    pub first_year: u32,
+    pub final_year: u32,
+    /// Ranked by peak size, then by how long they lasted
+    pub empires: Vec<NationRecord>,
+    /// Longest reigns, including ones still running at the report date
+    pub rulers: Vec<ReignRecord>,
+    /// Ranked by casualties
+    pub wars: Vec<WarRecord>,
+    /// Ranked by peak treasury
+    pub economies: Vec<NationRecord>,
+    /// Ranked by the trade they added
+    pub trade_agreements: Vec<TradeAgreementRecord>,
+    pub population: ReportSeries,
+    pub territory: Vec<ReportSeries>,
+    /// Milestones from the chronicle, as (year, text)
+    pub milestones: Vec<(u32, String)>,
+}
+
+/// Build the report for `world_name` as of `final_year`
+pub fn build_world_report(
+    statistics: &WorldStatistics,
+    chronicle: &WorldChronicle,
+    world_name: &str,
+    final_year: u32,
+) -> WorldReport {
+    let mut empires: Vec<(u32, NationRecord)> = statistics
+        .nations
+        .iter()
+        .map(|(&id, record)| (id, record.clone()))
+        .collect();
+    empires.sort_by(|(_, a), (_, b)| {
+        b.peak_provinces
+            .cmp(&a.peak_provinces)
+            .then(b.years().cmp(&a.years()))
+            .then(a.name.cmp(&b.name))
+    });
+
+    let territory = empires
+        .iter()
+        .take(CHARTED_EMPIRES)
+        .map(|(id, record)| ReportSeries {
+            label: record.name.clone(),
+            color: record.color,
+            points: statistics
+                .samples
+                .iter()
+                .map(|sample| {
+                    (
+                        sample.year,
+                        f64::from(sample.provinces.get(id).copied().unwrap_or(0)),
+                    )
+                })
+                .collect(),
+        })
+        .collect();
+
+    let mut economies: Vec<NationRecord> = statistics.nations.values().cloned().collect();
+    economies.sort_by(|a, b| b.peak_treasury.total_cmp(&a.peak_treasury));
+    economies.truncate(REPORT_SECTION_SIZE);
+
+    let mut rulers = statistics.all_reigns(final_year);
+    rulers.sort_by(|a, b| {
+        b.years()
+            .cmp(&a.years())
+            .then(a.start_year.cmp(&b.start_year))
+    });
+    rulers.truncate(REPORT_SECTION_SIZE);
+
+    let mut wars: Vec<WarRecord> = statistics.wars.values().cloned().collect();
+    wars.sort_by(|a, b| {
+        b.casualties
+            .total_cmp(&a.casualties)
+            .then(a.war_id.cmp(&b.war_id))
+    });
+    wars.truncate(REPORT_SECTION_SIZE);
+
+    let mut trade_agreements = statistics.trade_agreements.clone();
+    trade_agreements.sort_by(|a, b| {
+        (b.volume - b.volume_before)
+            .total_cmp(&(a.volume - a.volume_before))
+            .then(a.signed_year.cmp(&b.signed_year))
+    });
+    trade_agreements.truncate(REPORT_SECTION_SIZE);
+
+    let population = ReportSeries {
+        label: "World population".to_string(),
+        color: [0.9, 0.8, 0.5],
+        points: statistics
+            .samples
+            .iter()
+            .map(|sample| (sample.year, sample.population as f64))
+            .collect(),
+    };
+
+    let milestones = chronicle
+        .entries()
+        .iter()
+        .filter(|entry| entry.category == ChronicleCategory::Milestone)
+        .map(|entry| (entry.year, entry.text.clone()))
+        .collect();
+
+    let first_year = statistics
+        .nations
+        .values()
+        .map(|record| record.first_year)
+        .chain(statistics.samples.first().map(|sample| sample.year))
+        .min()
+        .unwrap_or(final_year);
+
+    WorldReport {
+        world_name: world_name.to_string(),
+        first_year,
+        final_year,
+        empires: empires
+            .into_iter()
+            .take(REPORT_SECTION_SIZE)
+            .map(|(_, record)| record)
+            .collect(),
+        rulers,
+        wars,
+        economies,
+        trade_agreements,
+        population,
+        territory,
+        milestones,
+    }
+}
+
+impl WorldReport {
+    /// Largest value across a set of series, for scaling charts
+    pub fn series_max(series: &[&ReportSeries]) -> f64 {
+        series
+            .iter()
+            .flat_map(|s| s.points.iter().map(|&(_, value)| value))
+            .fold(0.0, f64::max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nation(name: &str, peak: u32, first: u32, last: u32, treasury: f32) -> NationRecord {
+        NationRecord {
+            name: name.to_string(),
+            color: [1.0, 0.0, 0.0],
+            first_year: first,
+            last_year: last,
+            peak_provinces: peak,
+            peak_year: first,
+            peak_treasury: treasury,
+        }
+    }
+
+    #[test]
+    fn empires_rank_by_peak_then_duration() {
+        let mut statistics = WorldStatistics::default();
+        statistics
+            .nations
+            .insert(1, nation("Short", 40, 1000, 1100, 50.0));
+        statistics
+            .nations
+            .insert(2, nation("Long", 40, 1000, 1400, 10.0));
+        statistics
+            .nations
+            .insert(3, nation("Vast", 90, 1200, 1250, 5.0));
+
+        let report = build_world_report(&statistics, &WorldChronicle::default(), "Test", 1500);
+
+        let order: Vec<&str> = report.empires.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(order, ["Vast", "Long", "Short"]);
+        assert_eq!(report.economies[0].name, "Short");
+        assert_eq!(report.first_year, 1000);
+    }
+}
@@ -0,0 +1,341 @@
+//! Long-running world statistics the end-of-world report is built from
+//!
+//! Live ECS state only describes the present; the report needs peaks, finished
+//! reigns, and wars that ended centuries ago. This resource keeps those facts
+//! as the simulation runs and travels with the save.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::markets::MarketRecord;
+use crate::nations::{
+    House, Nation, NationId, NationIndex, TradeFlow, Treaty, War, WarEndEvent, WarParticipants,
+};
+use crate::relationships::{Controls, RulesOver};
+use crate::simulation::NewYearEvent;
+use crate::world::ProvinceStorage;
+
+/// Years between population and territory samples
+pub const SAMPLE_INTERVAL_YEARS: u32 = 10;
+
+/// Finished reigns kept for the report, longest first
+pub const MAX_RECORDED_REIGNS: usize = 20;
+
+/// Everything remembered about one nation across its lifetime
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NationRecord {
+    pub name: String,
+    /// Map colour as linear RGB, for report charts
+    pub color: [f32; 3],
+    pub first_year: u32,
+    pub last_year: u32,
+    pub peak_provinces: u32,
+    pub peak_year: u32,
+    pub peak_treasury: f32,
+}
+
+impl NationRecord {
+    /// Years the nation held at least one province
+    pub fn years(&self) -> u32 {
+        self.last_year.saturating_sub(self.first_year)
+    }
+}
+
+/// One ruler's time on a throne
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReignRecord {
+    pub ruler: String,
+    pub title: String,
+    pub house: String,
+    pub nation: String,
+    pub start_year: u32,
+    pub end_year: u32,
+}
+
+impl ReignRecord {
+    pub fn years(&self) -> u32 {
+        self.end_year.saturating_sub(self.start_year)
+    }
+}
+
+/// One war from declaration to peace
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WarRecord {
+    pub war_id: u32,
+    /// Names of every nation that fought in it
+    pub belligerents: Vec<String>,
+    pub start_year: u32,
+    /// `None` while the war is still being fought
+    pub end_year: Option<u32>,
+    pub battles: u32,
+    pub casualties: f32,
+}
+
+/// One trade agreement and the trade it made
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TradeAgreementRecord {
+    /// Signatory nation ids, in signing order
+    pub signatories: [u32; 2],
+    pub names: [String; 2],
+    pub signed_year: u32,
+    /// `None` while the agreement still stands
+    pub end_year: Option<u32>,
+    /// Yearly trade the pair would have had without the agreement, when it was signed
+    pub volume_before: f32,
+    /// Latest yearly trade under the agreement
+    pub volume: f32,
+    /// Treasury each signatory has gained from it, in signing order
+    pub gains: [f32; 2],
+}
+
+impl TradeAgreementRecord {
+    /// Trade under the agreement against trade before it, as a share (0.5 = 50% more)
+    pub fn trade_growth(&self) -> f32 {
+        self.volume / self.volume_before.max(f32::EPSILON) - 1.0
+    }
+}
+
+/// World population and each nation's territory at one point in time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatisticsSample {
+    pub year: u32,
+    pub population: u64,
+    /// Provinces held, keyed by nation id
+    pub provinces: BTreeMap<u32, u32>,
+}
+
+/// Statistics gathered over the life of the current world
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorldStatistics {
+    pub nations: BTreeMap<u32, NationRecord>,
+    /// Longest finished reigns, capped at [`MAX_RECORDED_REIGNS`]
+    pub reigns: Vec<ReignRecord>,
+    /// Reign in progress for each nation, keyed by nation id
+    pub current_reigns: BTreeMap<u32, ReignRecord>,
+    pub wars: BTreeMap<u32, WarRecord>,
+    pub samples: Vec<StatisticsSample>,
+    /// Every trade agreement signed, in signing order
+    #[serde(default)]
+    pub trade_agreements: Vec<TradeAgreementRecord>,
+    /// Price history of each market, keyed by its city's province index
+    #[serde(default)]
+    pub markets: BTreeMap<u32, MarketRecord>,
+}
+
+impl WorldStatistics {
+    pub fn is_empty(&self) -> bool {
+        self.nations.is_empty() && self.samples.is_empty()
+    }
+
+    /// Keep a finished reign if it is among the longest seen
+    pub fn finish_reign(&mut self, reign: ReignRecord) {
+        self.reigns.push(reign);
+        self.reigns.sort_by(|a, b| b.years().cmp(&a.years()));
+        self.reigns.truncate(MAX_RECORDED_REIGNS);
+    }
+
+    /// Finished reigns plus the ones still running, as of `year`
+    pub fn all_reigns(&self, year: u32) -> Vec<ReignRecord> {
+        self.reigns
+            .iter()
+            .cloned()
+            .chain(self.current_reigns.values().map(|reign| ReignRecord {
+                end_year: year,
+                ..reign.clone()
+            }))
+            .collect()
+    }
+}
+
+/// Update nation peaks, reigns, wars, and periodic samples once per year
+pub fn collect_world_statistics(
+    mut year_events: MessageReader<NewYearEvent>,
+    mut statistics: ResMut<WorldStatistics>,
+    nation_index: Res<NationIndex>,
+    province_storage: Option<Res<ProvinceStorage>>,
+    nations_query: Query<(&Nation, &NationId, Option<&Controls>)>,
+    houses_query: Query<(&House, &RulesOver)>,
+    wars_query: Query<(&War, Option<&WarParticipants>)>,
+) {
+    let Some(year) = year_events.read().last().map(|event| event.year) else {
+        return;
+    };
+    let statistics = statistics.as_mut();
+
+    for (nation, id, controls) in &nations_query {
+        let provinces = controls.map_or(0, |c| c.province_count() as u32);
+        if provinces == 0 {
+            continue;
+        }
+        let color = nation.color.to_linear();
+        let record = statistics
+            .nations
+            .entry(id.value())
+            .or_insert_with(|| NationRecord {
+                name: nation.name.clone(),
+                color: [color.red, color.green, color.blue],
+                first_year: year,
+                last_year: year,
+                peak_provinces: 0,
+                peak_year: year,
+                peak_treasury: 0.0,
+            });
+        record.name = nation.name.clone();
+        record.last_year = year;
+        record.peak_treasury = record.peak_treasury.max(nation.treasury.to_f32());
+        if provinces > record.peak_provinces {
+            record.peak_provinces = provinces;
+            record.peak_year = year;
+        }
+    }
+
+    for (house, rules_over) in &houses_query {
+        let Ok((nation, id, _)) = nations_query.get(rules_over.0) else {
+            continue;
+        };
+        let current = ReignRecord {
+            ruler: house.ruler.name.clone(),
+            title: house.ruler.title.clone(),
+            house: house.name.clone(),
+            nation: nation.name.clone(),
+            start_year: year.saturating_sub(house.ruler.years_ruling),
+            end_year: year,
+        };
+        let previous = statistics
+            .current_reigns
+            .insert(id.value(), current.clone());
+        if let Some(previous) = previous {
+            if previous.ruler != current.ruler || previous.house != current.house {
+                statistics.finish_reign(previous);
+            }
+        }
+    }
+
+    for (war, participants) in &wars_query {
+        let belligerents = participants
+            .map(|p| {
+                p.participants()
+                    .iter()
+                    .filter_map(|&entity| nations_query.get(entity).ok())
+                    .map(|(nation, _, _)| nation.name.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let record = statistics
+            .wars
+            .entry(war.war_id)
+            .or_insert_with(|| WarRecord {
+                war_id: war.war_id,
+                belligerents: Vec::new(),
+                start_year: war.start_year,
+                end_year: None,
+                battles: 0,
+                casualties: 0.0,
+            });
+        if record.end_year.is_some() {
+            continue;
+        }
+        if !belligerents.is_empty() {
+            record.belligerents = belligerents;
+        }
+        record.battles = war.battles_fought;
+        record.casualties = war.casualties;
+    }
+
+    if year % SAMPLE_INTERVAL_YEARS == 0 {
+        let mut sample = StatisticsSample {
+            year,
+            population: 0,
+            provinces: BTreeMap::new(),
+        };
+        if let Some(storage) = province_storage {
+            for province in &storage.provinces {
+                sample.population += u64::from(province.population);
+                if let Some(id) = province
+                    .owner_entity
+                    .and_then(|owner| nation_index.id(owner))
+                {
+                    *sample.provinces.entry(id.value()).or_insert(0) += 1;
+                }
+            }
+        }
+        statistics.samples.push(sample);
+    }
+}
+
+/// Follow the trade under every standing trade agreement, and close the records of lapsed ones
+pub fn record_trade_agreements(
+    mut year_events: MessageReader<NewYearEvent>,
+    mut statistics: ResMut<WorldStatistics>,
+    nation_index: Res<NationIndex>,
+    nations_query: Query<&Nation>,
+    treaties_query: Query<(&Treaty, &TradeFlow)>,
+) {
+    let Some(year) = year_events.read().last().map(|event| event.year) else {
+        return;
+    };
+    let records = &mut statistics.trade_agreements;
+    let mut standing = Vec::new();
+
+    for (treaty, flow) in &treaties_query {
+        let [a, b] = treaty.signatories;
+        let (Some(id_a), Some(id_b)) = (nation_index.id(a), nation_index.id(b)) else {
+            continue;
+        };
+        let key = ([id_a.value(), id_b.value()], treaty.signed_year);
+        standing.push(key);
+
+        let position = records
+            .iter()
+            .position(|record| record.end_year.is_none() && (record.signatories, record.signed_year) == key);
+        let record = match position {
+            Some(position) => &mut records[position],
+            None => {
+                let name = |nation| nations_query.get(nation).map_or_else(|_| String::new(), |n| n.name.clone());
+                records.push(TradeAgreementRecord {
+                    signatories: key.0,
+                    names: [name(a), name(b)],
+                    signed_year: treaty.signed_year,
+                    end_year: None,
+                    volume_before: flow.first_baseline,
+                    volume: flow.volume,
+                    gains: [0.0; 2],
+                });
+                let last = records.len() - 1;
+                &mut records[last]
+            }
+        };
+        record.volume = flow.volume;
+        record.gains[0] += flow.gains[0];
+        record.gains[1] += flow.gains[1];
+    }
+
+    for record in records.iter_mut().filter(|record| record.end_year.is_none()) {
+        if !standing.contains(&(record.signatories, record.signed_year)) {
+            record.end_year = Some(year);
+        }
+    }
+}
+
+/// Close war records when peace is made
+pub fn record_war_endings(
+    mut war_end_events: MessageReader<WarEndEvent>,
+    mut statistics: ResMut<WorldStatistics>,
+    game_time: Option<Res<crate::simulation::GameTime>>,
+) {
+    let year = game_time.as_ref().map_or(0, |time| time.current_year());
+    for event in war_end_events.read() {
+        if let Some(record) = statistics.wars.get_mut(&event.war_id) {
+            record.end_year.get_or_insert(year);
+        }
+    }
+}
+
+/// A freshly generated world starts with no statistics
+///
+/// Loading a save re-inserts the saved statistics after this runs.
+pub fn reset_world_statistics(mut statistics: ResMut<WorldStatistics>) {
+    *statistics = WorldStatistics::default();
+}
@@ -0,0 +1,231 @@
+//! Opening, exporting, scheduling, and closing the world report
+
+use bevy::prelude::*;
+
+use super::html::export_html;
+use super::price_chart::{MAX_CHARTED_MARKETS, spawn_price_chart_panel};
+use super::report::build_world_report;
+use super::statistics::WorldStatistics;
+use super::types::{
+    ClosePriceChartButton, CloseReportButton, DisplayedWorldReport, ExportReportButton, OpenWorldReport,
+    PriceChartGoodButton, PriceChartMarketButton, PriceChartPanel, PriceChartSelection, ReportSchedule,
+    ScheduleReportButton, WorldReportPanel,
+};
+use super::ui::spawn_world_report_panel;
+use crate::chronicle::WorldChronicle;
+use crate::simulation::{GameTime, NewYearEvent};
+use crate::ui::{NotificationPosition, NotificationType, ShowNotification};
+use crate::world::WorldName;
+
+/// Years between a report and the one scheduled from its panel
+const SCHEDULE_INTERVAL_YEARS: u32 = 100;
+
+fn world_name(name: Option<&WorldName>) -> &str {
+    name.map_or("Unnamed World", |name| name.0.as_str())
+}
+
+fn notify(
+    notifications: &mut MessageWriter<ShowNotification>,
+    message: String,
+    notification_type: NotificationType,
+) {
+    notifications.write(ShowNotification {
+        message,
+        notification_type,
+        duration: None,
+        position: NotificationPosition::TopCenter,
+    });
+}
+
+/// R opens the report for the current date
+pub fn handle_report_shortcut(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut open_events: MessageWriter<OpenWorldReport>,
+) {
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    if keys.just_pressed(KeyCode::KeyR) && !shift {
+        open_events.write(OpenWorldReport);
+    }
+}
+
+/// K opens the market price chart, or closes it
+pub fn handle_price_chart_shortcut(
+    keys: Res<ButtonInput<KeyCode>>,
+    statistics: Res<WorldStatistics>,
+    mut selection: ResMut<PriceChartSelection>,
+) {
+    if !keys.just_pressed(KeyCode::KeyK) {
+        return;
+    }
+    selection.open = !selection.open;
+    selection.markets.retain(|index| statistics.markets.contains_key(index));
+    if selection.open && selection.markets.is_empty() {
+        selection.markets = statistics.markets.keys().take(MAX_CHARTED_MARKETS).copied().collect();
+    }
+}
+
+/// Good, market, and close buttons on the price chart
+pub fn handle_price_chart_buttons(
+    good_buttons: Query<(&Interaction, &PriceChartGoodButton), Changed<Interaction>>,
+    market_buttons: Query<(&Interaction, &PriceChartMarketButton), Changed<Interaction>>,
+    close_buttons: Query<&Interaction, (Changed<Interaction>, With<ClosePriceChartButton>)>,
+    mut selection: ResMut<PriceChartSelection>,
+) {
+    for (interaction, button) in &good_buttons {
+        if *interaction == Interaction::Pressed {
+            selection.good = button.0;
+        }
+    }
+    for (interaction, button) in &market_buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Some(position) = selection.markets.iter().position(|&index| index == button.0) {
+            selection.markets.remove(position);
+        } else if selection.markets.len() < MAX_CHARTED_MARKETS {
+            selection.markets.push(button.0);
+        }
+    }
+    if close_buttons.iter().any(|i| *i == Interaction::Pressed) {
+        selection.open = false;
+    }
+}
+
+/// Redraw the price chart when its selection changes, and yearly while it is open
+pub fn refresh_price_chart(
+    mut commands: Commands,
+    mut year_events: MessageReader<NewYearEvent>,
+    selection: Res<PriceChartSelection>,
+    statistics: Res<WorldStatistics>,
+    panels: Query<Entity, With<PriceChartPanel>>,
+) {
+    let new_year = year_events.read().last().is_some();
+    if !selection.is_changed() && !(new_year && selection.open) {
+        return;
+    }
+    for panel in &panels {
+        commands.entity(panel).despawn();
+    }
+    if selection.open {
+        spawn_price_chart_panel(&mut commands, &statistics, selection.good, &selection.markets);
+    }
+}
+
+/// Open the report when the scheduled year arrives
+pub fn check_report_schedule(
+    mut year_events: MessageReader<NewYearEvent>,
+    mut schedule: ResMut<ReportSchedule>,
+    mut open_events: MessageWriter<OpenWorldReport>,
+) {
+    let Some(year) = year_events.read().last().map(|event| event.year) else {
+        return;
+    };
+    if schedule.at_year.is_some_and(|at_year| year >= at_year) {
+        schedule.at_year = None;
+        open_events.write(OpenWorldReport);
+    }
+}
+
+/// Build a fresh report and replace any panel already on screen
+pub fn open_world_report(
+    mut commands: Commands,
+    mut open_events: MessageReader<OpenWorldReport>,
+    mut displayed: ResMut<DisplayedWorldReport>,
+    statistics: Res<WorldStatistics>,
+    chronicle: Res<WorldChronicle>,
+    game_time: Option<Res<GameTime>>,
+    name: Option<Res<WorldName>>,
+    panels: Query<Entity, With<WorldReportPanel>>,
+) {
+    if open_events.read().last().is_none() {
+        return;
+    }
+    for panel in &panels {
+        commands.entity(panel).despawn();
+    }
+    let year = game_time.as_ref().map_or(0, |time| time.current_year());
+    let report = build_world_report(&statistics, &chronicle, world_name(name.as_deref()), year);
+    spawn_world_report_panel(&mut commands, &report);
+    displayed.0 = Some(report);
+}
+
+/// Export, schedule, and close buttons on the report panel
+pub fn handle_report_buttons(
+    mut commands: Commands,
+    export_buttons: Query<&Interaction, (Changed<Interaction>, With<ExportReportButton>)>,
+    schedule_buttons: Query<&Interaction, (Changed<Interaction>, With<ScheduleReportButton>)>,
+    close_buttons: Query<&Interaction, (Changed<Interaction>, With<CloseReportButton>)>,
+    mut displayed: ResMut<DisplayedWorldReport>,
+    mut schedule: ResMut<ReportSchedule>,
+    mut notifications: MessageWriter<ShowNotification>,
+    panels: Query<Entity, With<WorldReportPanel>>,
+) {
+    if export_buttons.iter().any(|i| *i == Interaction::Pressed) {
+        if let Some(report) = &displayed.0 {
+            match export_html(report) {
+                Ok(path) => notify(
+                    &mut notifications,
+                    format!("Report exported to {}", path.display()),
+                    NotificationType::Success,
+                ),
+                Err(e) => notify(
+                    &mut notifications,
+                    format!("Could not export report: {e}"),
+                    NotificationType::Error,
+                ),
+            }
+        }
+    }
+    if schedule_buttons.iter().any(|i| *i == Interaction::Pressed) {
+        if let Some(report) = &displayed.0 {
+            let at_year = report.final_year + SCHEDULE_INTERVAL_YEARS;
+            schedule.at_year = Some(at_year);
+            notify(
+                &mut notifications,
+                format!("The next world report will open in the year {at_year}"),
+                NotificationType::Info,
+            );
+        }
+    }
+    if close_buttons.iter().any(|i| *i == Interaction::Pressed) {
+        for panel in &panels {
+            commands.entity(panel).despawn();
+        }
+        displayed.0 = None;
+    }
+}
+
+/// When the observer stops a world, archive its report and close the panel
+///
+/// Statistics are cleared afterwards so returning to the menu again (from
+/// settings, say) does not export the same world twice.
+pub fn export_report_on_stop(
+    mut commands: Commands,
+    mut statistics: ResMut<WorldStatistics>,
+    mut displayed: ResMut<DisplayedWorldReport>,
+    mut schedule: ResMut<ReportSchedule>,
+    chronicle: Res<WorldChronicle>,
+    game_time: Option<Res<GameTime>>,
+    name: Option<Res<WorldName>>,
+    mut price_chart: ResMut<PriceChartSelection>,
+    panels: Query<Entity, With<WorldReportPanel>>,
+    price_chart_panels: Query<Entity, With<PriceChartPanel>>,
+) {
+    for panel in panels.iter().chain(&price_chart_panels) {
+        commands.entity(panel).despawn();
+    }
+    displayed.0 = None;
+    schedule.at_year = None;
+    *price_chart = PriceChartSelection::default();
+
+    if statistics.is_empty() {
+        return;
+    }
+    let year = game_time.as_ref().map_or(0, |time| time.current_year());
+    let report = build_world_report(&statistics, &chronicle, world_name(name.as_deref()), year);
+    match export_html(&report) {
+        Ok(path) => info!("World report saved to {}", path.display()),
+        Err(e) => warn!("Failed to save world report: {}", e),
+    }
+    *statistics = WorldStatistics::default();
+}
@@ -0,0 +1,58 @@
+//! Messages, resources, and UI markers for the world report
+
+use bevy::prelude::*;
+
+use super::markets::Good;
+use super::report::WorldReport;
+
+/// Request to build and show the world report as of the current date
+#[derive(Message, Debug, Clone, Default)]
+pub struct OpenWorldReport;
+
+/// A report the observer asked for at a future date
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ReportSchedule {
+    pub at_year: Option<u32>,
+}
+
+/// The report currently on screen, kept so it can be exported as shown
+#[derive(Resource, Debug, Clone, Default)]
+pub struct DisplayedWorldReport(pub Option<WorldReport>);
+
+/// Marker for the report panel root
+#[derive(Component)]
+pub struct WorldReportPanel;
+
+#[derive(Component)]
+pub struct ExportReportButton;
+
+/// Schedules another report a century after the current one
+#[derive(Component)]
+pub struct ScheduleReportButton;
+
+#[derive(Component)]
+pub struct CloseReportButton;
+
+/// Good and markets the price chart shows, and whether it is open
+#[derive(Resource, Debug, Clone, Default)]
+pub struct PriceChartSelection {
+    pub open: bool,
+    pub good: Good,
+    /// Charted markets by province index, in the order they were picked
+    pub markets: Vec<u32>,
+}
+
+/// Marker for the price chart panel root
+#[derive(Component)]
+pub struct PriceChartPanel;
+
+/// Charts this good
+#[derive(Component)]
+pub struct PriceChartGoodButton(pub Good);
+
+/// Adds this market to the chart, or takes it off
+#[derive(Component)]
+pub struct PriceChartMarketButton(pub u32);
+
+#[derive(Component)]
+pub struct ClosePriceChartButton;
@@ -0,0 +1,287 @@
+//! Scrollable in-game view of a world report
+
+use bevy::prelude::*;
+
+use super::report::{ReportSeries, WorldReport};
+use super::types::{CloseReportButton, ExportReportButton, ScheduleReportButton, WorldReportPanel};
+use crate::ui::*;
+
+/// Height of the bars in the population strip
+const STRIP_HEIGHT: f32 = 60.0;
+
+fn section_title(parent: &mut ChildBuilder, title: &str) {
+    parent.spawn((
+        Text::new(title),
+        TextFont {
+            font_size: TEXT_SIZE_LARGE,
+            ..default()
+        },
+        TextColor(TEXT_COLOR_HEADER),
+        Node {
+            margin: UiRect::top(Val::Px(12.0)),
+            ..default()
+        },
+    ));
+}
+
+fn line(parent: &mut ChildBuilder, text: String, color: Color) {
+    parent.spawn((
+        Text::new(text),
+        TextFont {
+            font_size: TEXT_SIZE_NORMAL,
+            ..default()
+        },
+        TextColor(color),
+    ));
+}
+
+/// A labelled horizontal bar, `fraction` of the full width
+fn bar(parent: &mut ChildBuilder, label: String, fraction: f32, color: Color) {
+    parent
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(2.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            line(parent, label, TEXT_COLOR_PRIMARY);
+            parent.spawn((
+                Node {
+                    width: Val::Percent(fraction.clamp(0.01, 1.0) * 100.0),
+                    height: Val::Px(8.0),
+                    ..default()
+                },
+                BackgroundColor(color),
+            ));
+        });
+}
+
+/// Column chart of one series, one column per sample
+fn column_strip(parent: &mut ChildBuilder, series: &ReportSeries) {
+    let max = WorldReport::series_max(&[series]).max(1.0);
+    let [r, g, b] = series.color;
+    parent
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Px(STRIP_HEIGHT),
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::FlexEnd,
+            column_gap: Val::Px(1.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            for &(_, value) in &series.points {
+                parent.spawn((
+                    Node {
+                        flex_grow: 1.0,
+                        height: Val::Percent((value / max * 100.0) as f32),
+                        ..default()
+                    },
+                    BackgroundColor(Color::linear_rgb(r, g, b)),
+                ));
+            }
+        });
+}
+
+/// Spawn the report panel showing `report`
+pub fn spawn_world_report_panel(commands: &mut Commands, report: &WorldReport) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(20.0),
+                top: Val::Px(60.0),
+                width: Val::Percent(60.0),
+                height: Val::Percent(85.0),
+                padding: UiRect::all(Val::Px(20.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(UI_BACKGROUND_COLOR),
+            BorderColor::all(UI_BORDER_COLOR),
+            ThemedAccent::Border(UI_BORDER_COLOR),
+            GlobalZIndex(layers::MODAL_OVERLAY),
+            WorldReportPanel,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!(
+                    "{} - {} to {}",
+                    report.world_name.to_uppercase(),
+                    report.first_year,
+                    report.final_year
+                )),
+                TextFont {
+                    font_size: TEXT_SIZE_TITLE,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR_HEADER),
+            ));
+
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    ButtonBuilder::new("Export HTML")
+                        .size(ButtonSize::Small)
+                        .with_marker(ExportReportButton)
+                        .build(parent);
+                    ButtonBuilder::new("Report Again in 100 Years")
+                        .size(ButtonSize::Small)
+                        .with_marker(ScheduleReportButton)
+                        .build(parent);
+                    ButtonBuilder::new("Close")
+                        .size(ButtonSize::Small)
+                        .style(ButtonStyle::Secondary)
+                        .with_marker(CloseReportButton)
+                        .build(parent);
+                });
+
+            parent
+                .spawn(Node {
+                    width: Val::Percent(100.0),
+                    flex_grow: 1.0,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(6.0),
+                    overflow: Overflow::scroll_y(),
+                    ..default()
+                })
+                .with_children(|parent| spawn_report_sections(parent, report));
+        });
+}
+
+fn spawn_report_sections(parent: &mut ChildBuilder, report: &WorldReport) {
+    section_title(parent, "Greatest Empires");
+    let largest = report
+        .empires
+        .first()
+        .map_or(1, |e| e.peak_provinces.max(1));
+    for (rank, empire) in report.empires.iter().enumerate() {
+        let [r, g, b] = empire.color;
+        bar(
+            parent,
+            format!(
+                "{}. {} - {} provinces at peak ({}), {} years",
+                rank + 1,
+                empire.name,
+                empire.peak_provinces,
+                empire.peak_year,
+                empire.years()
+            ),
+            empire.peak_provinces as f32 / largest as f32,
+            Color::linear_rgb(r, g, b),
+        );
+    }
+
+    section_title(parent, "Notable Rulers");
+    for reign in &report.rulers {
+        line(
+            parent,
+            format!(
+                "{} {} of House {}, {} - {} years ({}-{})",
+                reign.title,
+                reign.ruler,
+                reign.house,
+                reign.nation,
+                reign.years(),
+                reign.start_year,
+                reign.end_year
+            ),
+            TEXT_COLOR_PRIMARY,
+        );
+    }
+
+    section_title(parent, "Bloodiest Wars");
+    let bloodiest = report.wars.first().map_or(1.0, |w| w.casualties.max(1.0));
+    for war in &report.wars {
+        let end = war
+            .end_year
+            .map_or_else(|| "ongoing".to_string(), |year| year.to_string());
+        bar(
+            parent,
+            format!(
+                "{} ({}-{}) - {} battles, {:.0} casualties",
+                war.belligerents.join(" vs "),
+                war.start_year,
+                end,
+                war.battles,
+                war.casualties
+            ),
+            war.casualties / bloodiest,
+            colors::DANGER,
+        );
+    }
+
+    section_title(parent, "Economic Winners");
+    let richest = report
+        .economies
+        .first()
+        .map_or(1.0, |e| e.peak_treasury.max(1.0));
+    for economy in &report.economies {
+        bar(
+            parent,
+            format!(
+                "{} - peak treasury {:.0}",
+                economy.name, economy.peak_treasury
+            ),
+            economy.peak_treasury / richest,
+            colors::SUCCESS,
+        );
+    }
+
+    if !report.trade_agreements.is_empty() {
+        section_title(parent, "Trade Agreements");
+        let largest = report
+            .trade_agreements
+            .iter()
+            .map(|agreement| agreement.trade_growth())
+            .fold(f32::EPSILON, f32::max);
+        for agreement in &report.trade_agreements {
+            let years = agreement
+                .end_year
+                .map_or_else(|| format!("since {}", agreement.signed_year), |end| {
+                    format!("{}-{}", agreement.signed_year, end)
+                });
+            bar(
+                parent,
+                format!(
+                    "{} & {} ({}) - trade {:.0} to {:.0} a year ({:+.0}%), gains {:.0} / {:.0}",
+                    agreement.names[0],
+                    agreement.names[1],
+                    years,
+                    agreement.volume_before,
+                    agreement.volume,
+                    agreement.trade_growth() * 100.0,
+                    agreement.gains[0],
+                    agreement.gains[1]
+                ),
+                agreement.trade_growth() / largest,
+                colors::PRIMARY,
+            );
+        }
+    }
+
+    section_title(parent, "World Population");
+    column_strip(parent, &report.population);
+    for series in &report.territory {
+        line(
+            parent,
+            format!("{} territory", series.label),
+            TEXT_COLOR_SECONDARY,
+        );
+        column_strip(parent, series);
+    }
+
+    if !report.milestones.is_empty() {
+        section_title(parent, "Milestones");
+        for (year, text) in &report.milestones {
+            line(parent, format!("{year}: {text}"), TEXT_COLOR_PRIMARY);
+        }
+    }
+}
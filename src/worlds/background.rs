@@ -0,0 +1,159 @@
+//! Worlds simulating off screen, each in its own headless app
+
+use bevy::ecs::message::Messages;
+use bevy::prelude::*;
+use std::path::PathBuf;
+use std::time::Instant;
+
+use super::types::WorldSlotId;
+use crate::app::build_headless_app;
+use crate::save_load::{LoadCompleteEvent, LoadGameEvent, SaveCompleteEvent, SaveGameEvent};
+use crate::simulation::GameTime;
+use crate::states::GameState;
+
+/// What happened to a background world during its last step
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StepOutcome {
+    /// Finished loading and began simulating
+    Loaded,
+    /// Could not be loaded, with the reason
+    LoadFailed(String),
+    /// Wrote its save slot
+    Saved(PathBuf),
+    /// Failed to write its save slot, with the reason
+    SaveFailed(String),
+}
+
+/// One world running headless
+pub struct BackgroundWorld {
+    pub id: WorldSlotId,
+    app: App,
+    /// Saved to its slot before it is dropped
+    pub closing: bool,
+}
+
+impl BackgroundWorld {
+    /// Start loading a saved world into a fresh headless app
+    pub fn open(id: WorldSlotId, save_path: PathBuf) -> Self {
+        let mut app = build_headless_app();
+        app.world_mut().write_message(LoadGameEvent { save_path });
+        Self {
+            id,
+            app,
+            closing: false,
+        }
+    }
+
+    fn state(&self) -> Option<GameState> {
+        self.app
+            .world()
+            .get_resource::<State<GameState>>()
+            .map(|state| *state.get())
+    }
+
+    /// Whether the world finished loading and is simulating
+    pub fn is_running(&self) -> bool {
+        self.state() == Some(GameState::InGame)
+    }
+
+    pub fn year(&self) -> Option<u32> {
+        self.app
+            .world()
+            .get_resource::<GameTime>()
+            .map(|time| time.current_year())
+    }
+
+    /// Hold the world's clock paused or let it run
+    pub fn set_paused(&mut self, paused: bool) {
+        if let Some(mut time) = self.app.world_mut().get_resource_mut::<GameTime>() {
+            if time.is_paused() != paused {
+                if paused {
+                    time.pause();
+                } else {
+                    time.resume();
+                }
+            }
+        }
+    }
+
+    /// Ask the world to write itself to its save slot
+    pub fn request_save(&mut self) {
+        let slot_name = self.id.save_slot();
+        self.app.world_mut().write_message(SaveGameEvent { slot_name });
+    }
+
+    /// Run one frame of the world's app, returning how long it took and what came of it
+    pub fn step(&mut self) -> (f32, Vec<StepOutcome>) {
+        let was_running = self.is_running();
+        let started = Instant::now();
+        self.app.update();
+        let elapsed_ms = started.elapsed().as_secs_f32() * 1000.0;
+
+        let mut outcomes = Vec::new();
+        if !was_running && self.is_running() {
+            outcomes.push(StepOutcome::Loaded);
+        }
+        let world = self.app.world_mut();
+        if let Some(mut loads) = world.get_resource_mut::<Messages<LoadCompleteEvent>>() {
+            outcomes.extend(
+                loads
+                    .drain()
+                    .filter(|load| !load.success)
+                    .map(|load| StepOutcome::LoadFailed(load.message)),
+            );
+        }
+        if let Some(mut saves) = world.get_resource_mut::<Messages<SaveCompleteEvent>>() {
+            let slot_name = self.id.save_slot();
+            outcomes.extend(
+                saves
+                    .drain()
+                    .filter(|save| save.slot_name == slot_name)
+                    .map(|save| match save.path {
+                        Some(path) if save.success => StepOutcome::Saved(path),
+                        _ => StepOutcome::SaveFailed(save.message),
+                    }),
+            );
+        }
+        (elapsed_ms, outcomes)
+    }
+}
+
+/// Every background world, kept outside the ECS since apps can't cross threads
+#[derive(Default)]
+pub struct BackgroundWorlds {
+    pub worlds: Vec<BackgroundWorld>,
+    /// Where the next frame's round of steps begins, so no world starves
+    next: usize,
+}
+
+impl BackgroundWorlds {
+    pub fn get_mut(&mut self, id: WorldSlotId) -> Option<&mut BackgroundWorld> {
+        self.worlds.iter_mut().find(|world| world.id == id)
+    }
+
+    /// Stop simulating a world, dropping its app
+    pub fn remove(&mut self, id: WorldSlotId) {
+        self.worlds.retain(|world| world.id != id);
+    }
+
+    /// Step worlds in turn until the frame budget is spent
+    ///
+    /// Each world gets at most one step a frame. When the budget runs out
+    /// the round stops, and the next frame picks up where it left off.
+    pub fn step_within(&mut self, budget_ms: f32) -> Vec<(WorldSlotId, f32, Vec<StepOutcome>)> {
+        let count = self.worlds.len();
+        let started = Instant::now();
+        let mut results = Vec::new();
+        for _ in 0..count {
+            if started.elapsed().as_secs_f32() * 1000.0 >= budget_ms {
+                break;
+            }
+            let index = self.next % count;
+            self.next = index + 1;
+            let world = &mut self.worlds[index];
+            let (elapsed_ms, outcomes) = world.step();
+            results.push((world.id, elapsed_ms, outcomes));
+        }
+        results
+    }
+}
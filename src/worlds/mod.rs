@@ -0,0 +1,27 @@
+//! Multi-world management gateway
+//!
+//! Several worlds can be open at once. One is on screen and simulated as
+//! usual; the rest run headless, each in its own app, stepped in turn within
+//! a per-frame budget so the world being watched stays smooth. Switching
+//! parks the on-screen world in a save slot, loads the arriving world from
+//! its slot, and reopens the parked one in the background. Each world keeps
+//! its own pause.
+//!
+//! # Gateway Pattern
+//!
+//! This is a PURE gateway - no implementations, only module declarations
+//! and controlled exports.
+
+// PRIVATE MODULES
+mod background;
+mod plugin;
+mod systems;
+mod types;
+mod ui;
+
+// CONTROLLED EXPORTS
+pub use plugin::WorldsPlugin;
+pub use types::{
+    CloseWorld, OpenWorld, PauseWorld, SwitchWorld, ToggleWorldSwitcher, WorldBudget, WorldRoster, WorldSlot,
+    WorldSlotId, WorldStatus,
+};
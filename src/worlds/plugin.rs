@@ -0,0 +1,45 @@
+//! Multi-world plugin
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+use super::background::BackgroundWorlds;
+use super::systems::{
+    close_worlds, collect_active_saves, complete_world_switch, open_worlds, pause_worlds, register_active_world,
+    release_active_world, step_background_worlds, switch_worlds, track_active_world,
+};
+use super::types::{CloseWorld, OpenWorld, PauseWorld, SwitchWorld, ToggleWorldSwitcher, WorldBudget, WorldRoster};
+use super::ui::{close_world_switcher, handle_world_switcher_buttons, refresh_world_switcher, toggle_world_switcher};
+use crate::states::GameState;
+
+define_plugin!(WorldsPlugin {
+    resources: [WorldRoster, WorldBudget],
+
+    messages: [OpenWorld, SwitchWorld, CloseWorld, PauseWorld, ToggleWorldSwitcher],
+
+    update: [
+        (
+            open_worlds,
+            switch_worlds,
+            collect_active_saves,
+            close_worlds,
+            pause_worlds,
+            step_background_worlds,
+            complete_world_switch
+        )
+            .chain(),
+        track_active_world.run_if(in_state(GameState::InGame)),
+        (toggle_world_switcher, handle_world_switcher_buttons, refresh_world_switcher).chain()
+    ],
+
+    on_enter: {
+        GameState::MainMenu => [release_active_world],
+        GameState::LoadingWorld => [release_active_world, close_world_switcher],
+        GameState::InGame => [register_active_world]
+    },
+
+    custom_init: |app: &mut bevy::app::App| {
+        // Headless apps aren't Send, so the worlds live outside the resource map
+        app.insert_non_send_resource(BackgroundWorlds::default());
+    }
+});
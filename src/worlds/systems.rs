@@ -0,0 +1,313 @@
+//! Opening, switching, pausing, and closing worlds, and stepping those in the background
+
+use bevy::prelude::*;
+
+use super::background::{BackgroundWorld, BackgroundWorlds, StepOutcome};
+use super::types::{
+    CloseWorld, OpenWorld, PauseWorld, PendingSwitch, SwitchWorld, WorldBudget, WorldRoster, WorldStatus,
+};
+use crate::resources::WorldName;
+use crate::save_load::{LoadGameEvent, SaveCompleteEvent, SaveGameEvent};
+use crate::simulation::{GameTime, SimulationSpeedChanged};
+use crate::states::GameState;
+use crate::ui::ShowNotification;
+
+/// Put the world that just came on screen into the roster
+///
+/// A world arriving through a switch is already listed and gets back the
+/// pause it had; any other world reaching the screen is listed as new.
+pub fn register_active_world(
+    mut roster: ResMut<WorldRoster>,
+    world_name: Option<Res<WorldName>>,
+    game_time: Option<ResMut<GameTime>>,
+) {
+    let arriving = roster
+        .active
+        .and_then(|id| roster.get_mut(id))
+        .filter(|slot| slot.status == WorldStatus::Loading);
+    if let Some(slot) = arriving {
+        slot.status = WorldStatus::Active;
+        if let Some(mut time) = game_time {
+            if slot.paused && !time.is_paused() {
+                time.pause();
+            }
+        }
+        return;
+    }
+
+    if roster.active.is_none() {
+        let name = world_name.map_or_else(|| "Unnamed World".to_string(), |name| name.0.clone());
+        let id = roster.add(name, WorldStatus::Active);
+        roster.active = Some(id);
+    }
+}
+
+/// Forget the on-screen world when something other than a switch replaces it
+pub fn release_active_world(mut roster: ResMut<WorldRoster>) {
+    let Some(active) = roster.active else {
+        return;
+    };
+    let arriving = roster
+        .get(active)
+        .is_some_and(|slot| slot.status == WorldStatus::Loading);
+    if !arriving {
+        roster.remove(active);
+    }
+}
+
+/// Keep the on-screen world's year and pause current in the roster
+pub fn track_active_world(mut roster: ResMut<WorldRoster>, game_time: Res<GameTime>) {
+    let Some(active) = roster.active else {
+        return;
+    };
+    let (year, paused) = (game_time.current_year(), game_time.is_paused());
+    if let Some(slot) = roster.get_mut(active) {
+        if slot.status == WorldStatus::Active && (slot.year != year || slot.paused != paused) {
+            slot.year = year;
+            slot.paused = paused;
+        }
+    }
+}
+
+/// Start loading requested worlds in the background, up to the budget's limit
+pub fn open_worlds(
+    mut open_events: MessageReader<OpenWorld>,
+    mut roster: ResMut<WorldRoster>,
+    budget: Res<WorldBudget>,
+    mut background: NonSendMut<BackgroundWorlds>,
+    mut notifications: MessageWriter<ShowNotification>,
+) {
+    for event in open_events.read() {
+        if roster.background_count() >= budget.max_background {
+            notifications.write(ShowNotification::error(format!(
+                "At most {} worlds can run in the background",
+                budget.max_background
+            )));
+            continue;
+        }
+        info!("Opening {:?} in the background", event.save_path);
+        let id = roster.add(event.name.clone(), WorldStatus::Loading);
+        background
+            .worlds
+            .push(BackgroundWorld::open(id, event.save_path.clone()));
+    }
+}
+
+/// Begin switching worlds by saving both the leaving and the arriving world
+pub fn switch_worlds(
+    mut switch_events: MessageReader<SwitchWorld>,
+    mut roster: ResMut<WorldRoster>,
+    mut background: NonSendMut<BackgroundWorlds>,
+    state: Res<State<GameState>>,
+    mut save_events: MessageWriter<SaveGameEvent>,
+    mut notifications: MessageWriter<ShowNotification>,
+) {
+    let Some(SwitchWorld(target)) = switch_events.read().last().copied() else {
+        return;
+    };
+    if roster.switching.is_some() {
+        notifications.write(ShowNotification::warning("A world switch is already under way"));
+        return;
+    }
+    let ready = roster
+        .get(target)
+        .is_some_and(|slot| slot.status == WorldStatus::Background);
+    let Some(target_world) = background.get_mut(target).filter(|_| ready) else {
+        notifications.write(ShowNotification::warning("That world is not ready to switch to yet"));
+        return;
+    };
+    target_world.request_save();
+    roster.set_status(target, WorldStatus::Saving);
+
+    // Only a world in play is saved; from the menus the target simply loads
+    let previous = roster
+        .active
+        .filter(|_| matches!(state.get(), GameState::InGame | GameState::Paused));
+    if let Some(previous) = previous {
+        save_events.write(SaveGameEvent {
+            slot_name: previous.save_slot(),
+        });
+        roster.set_status(previous, WorldStatus::Saving);
+    }
+    info!("Switching to world {:?}", target);
+    roster.switching = Some(PendingSwitch {
+        target,
+        previous,
+        previous_save: None,
+        target_save: None,
+    });
+}
+
+/// Note when the on-screen world has been saved for a switch
+pub fn collect_active_saves(
+    mut save_events: MessageReader<SaveCompleteEvent>,
+    mut roster: ResMut<WorldRoster>,
+    mut notifications: MessageWriter<ShowNotification>,
+) {
+    for event in save_events.read() {
+        let Some(switch) = roster.switching.as_mut() else {
+            continue;
+        };
+        let Some(previous) = switch.previous.filter(|id| id.save_slot() == event.slot_name) else {
+            continue;
+        };
+        if let Some(path) = event.path.clone().filter(|_| event.success) {
+            switch.previous_save = Some(path);
+            continue;
+        }
+
+        // The world stays on screen; the target goes on simulating where it was
+        let target = switch.target;
+        roster.switching = None;
+        roster.set_status(previous, WorldStatus::Active);
+        roster.set_status(target, WorldStatus::Background);
+        notifications.write(ShowNotification::error(format!(
+            "Could not switch worlds: {}",
+            event.message
+        )));
+    }
+}
+
+/// Step background worlds within the frame budget and act on what they report
+pub fn step_background_worlds(
+    mut roster: ResMut<WorldRoster>,
+    budget: Res<WorldBudget>,
+    mut background: NonSendMut<BackgroundWorlds>,
+    mut notifications: MessageWriter<ShowNotification>,
+) {
+    if background.worlds.is_empty() {
+        return;
+    }
+    for (id, step_ms, outcomes) in background.step_within(budget.frame_ms) {
+        let name = roster.get(id).map_or_else(String::new, |slot| slot.name.clone());
+        for outcome in outcomes {
+            match outcome {
+                StepOutcome::Loaded => {
+                    roster.set_status(id, WorldStatus::Background);
+                    notifications.write(ShowNotification::info(format!("{} is running in the background", name)));
+                }
+                StepOutcome::LoadFailed(message) => {
+                    roster.remove(id);
+                    background.remove(id);
+                    notifications.write(ShowNotification::error(format!("Could not open {}: {}", name, message)));
+                }
+                StepOutcome::Saved(path) => {
+                    if let Some(switch) = roster.switching.as_mut().filter(|switch| switch.target == id) {
+                        switch.target_save = Some(path);
+                    } else if background.get_mut(id).is_some_and(|world| world.closing) {
+                        roster.remove(id);
+                        background.remove(id);
+                        notifications.write(ShowNotification::info(format!("{} saved and closed", name)));
+                    }
+                }
+                StepOutcome::SaveFailed(message) => {
+                    // The switch or close is abandoned and the world keeps running
+                    if roster.switching.as_ref().is_some_and(|switch| switch.target == id) {
+                        if let Some(previous) = roster.switching.take().and_then(|switch| switch.previous) {
+                            roster.set_status(previous, WorldStatus::Active);
+                        }
+                    }
+                    if let Some(world) = background.get_mut(id) {
+                        world.closing = false;
+                    }
+                    roster.set_status(id, WorldStatus::Background);
+                    notifications.write(ShowNotification::error(format!("Could not save {}: {}", name, message)));
+                }
+            }
+        }
+
+        let Some(world) = background.get_mut(id) else {
+            continue;
+        };
+        if let Some(slot) = roster.get_mut(id) {
+            world.set_paused(slot.paused);
+            slot.year = world.year().unwrap_or(slot.year);
+            slot.step_ms = step_ms;
+        }
+    }
+}
+
+/// Finish a switch once both saves are written: load the target on screen
+/// and reopen the world it replaced in the background
+pub fn complete_world_switch(
+    mut roster: ResMut<WorldRoster>,
+    mut background: NonSendMut<BackgroundWorlds>,
+    mut load_events: MessageWriter<LoadGameEvent>,
+) {
+    if !roster.switching.as_ref().is_some_and(PendingSwitch::is_ready) {
+        return;
+    }
+    let Some(switch) = roster.switching.take() else {
+        return;
+    };
+    let Some(target_save) = switch.target_save else {
+        return;
+    };
+
+    background.remove(switch.target);
+    if let (Some(previous), Some(previous_save)) = (switch.previous, switch.previous_save) {
+        roster.set_status(previous, WorldStatus::Loading);
+        background.worlds.push(BackgroundWorld::open(previous, previous_save));
+    }
+    roster.set_status(switch.target, WorldStatus::Loading);
+    roster.active = Some(switch.target);
+    load_events.write(LoadGameEvent { save_path: target_save });
+}
+
+/// Save a background world to its slot, then stop simulating it
+pub fn close_worlds(
+    mut close_events: MessageReader<CloseWorld>,
+    mut roster: ResMut<WorldRoster>,
+    mut background: NonSendMut<BackgroundWorlds>,
+) {
+    for CloseWorld(id) in close_events.read().copied() {
+        let switching = roster.switching.as_ref().is_some_and(|switch| switch.target == id);
+        if roster.active == Some(id) || switching {
+            continue;
+        }
+        let Some(world) = background.get_mut(id) else {
+            continue;
+        };
+        if world.is_running() {
+            world.closing = true;
+            world.request_save();
+            roster.set_status(id, WorldStatus::Saving);
+        } else {
+            // Still loading, so there is nothing new to keep
+            background.remove(id);
+            roster.remove(id);
+        }
+    }
+}
+
+/// Pause or resume single worlds; background worlds pick it up on their next step
+pub fn pause_worlds(
+    mut pause_events: MessageReader<PauseWorld>,
+    mut roster: ResMut<WorldRoster>,
+    mut game_time: Option<ResMut<GameTime>>,
+    mut speed_events: MessageWriter<SimulationSpeedChanged>,
+) {
+    for event in pause_events.read() {
+        let active = roster.active == Some(event.world);
+        let Some(slot) = roster.get_mut(event.world) else {
+            continue;
+        };
+        slot.paused = event.paused;
+        if !active {
+            continue;
+        }
+        if let Some(time) = game_time.as_mut() {
+            if time.is_paused() != event.paused {
+                if event.paused {
+                    time.pause();
+                } else {
+                    time.resume();
+                }
+                speed_events.write(SimulationSpeedChanged {
+                    new_speed: time.get_speed().multiplier(),
+                    is_paused: time.is_paused(),
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,231 @@
+//! The roster of open worlds, the background budget, and world messages
+
+use bevy::prelude::*;
+use std::path::PathBuf;
+
+/// Prefix of the save slots worlds are parked in
+pub const WORLD_SLOT_PREFIX: &str = "world-";
+
+/// Identifies an open world for the rest of the session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WorldSlotId(pub u32);
+
+impl WorldSlotId {
+    /// Save slot a world is parked in while another takes the screen
+    pub fn save_slot(self) -> String {
+        format!("{}{}", WORLD_SLOT_PREFIX, self.0)
+    }
+}
+
+/// What an open world is doing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WorldStatus {
+    /// Being read from its save
+    #[default]
+    Loading,
+    /// On screen, simulated at full speed
+    Active,
+    /// Simulated headless within the background budget
+    Background,
+    /// Writing itself to its save slot before a switch or close
+    Saving,
+}
+
+impl WorldStatus {
+    pub fn label(self) -> &'static str {
+        match self {
+            WorldStatus::Loading => "Loading",
+            WorldStatus::Active => "Active",
+            WorldStatus::Background => "Background",
+            WorldStatus::Saving => "Saving",
+        }
+    }
+}
+
+/// One open world
+#[derive(Debug, Clone)]
+pub struct WorldSlot {
+    pub id: WorldSlotId,
+    pub name: String,
+    pub status: WorldStatus,
+    /// Whether the observer paused this world; kept across switches
+    pub paused: bool,
+    pub year: u32,
+    /// Milliseconds its last background step took
+    pub step_ms: f32,
+}
+
+/// A switch waiting on saves before the target world can take the screen
+#[derive(Debug, Clone)]
+pub struct PendingSwitch {
+    pub target: WorldSlotId,
+    /// World leaving the screen, if one was on it
+    pub previous: Option<WorldSlotId>,
+    /// Save of the world leaving the screen, once written
+    pub previous_save: Option<PathBuf>,
+    /// Save of the target world, once written
+    pub target_save: Option<PathBuf>,
+}
+
+impl PendingSwitch {
+    /// Whether every save the switch waits on has been written
+    pub fn is_ready(&self) -> bool {
+        self.target_save.is_some() && (self.previous.is_none() || self.previous_save.is_some())
+    }
+}
+
+/// Every world open this session, and which one is on screen
+#[derive(Resource, Debug, Default)]
+pub struct WorldRoster {
+    pub slots: Vec<WorldSlot>,
+    pub active: Option<WorldSlotId>,
+    pub switching: Option<PendingSwitch>,
+    next_id: u32,
+}
+
+impl WorldRoster {
+    /// Add a world to the roster, returning its id
+    pub fn add(&mut self, name: impl Into<String>, status: WorldStatus) -> WorldSlotId {
+        let id = WorldSlotId(self.next_id);
+        self.next_id += 1;
+        self.slots.push(WorldSlot {
+            id,
+            name: name.into(),
+            status,
+            paused: false,
+            year: 0,
+            step_ms: 0.0,
+        });
+        id
+    }
+
+    pub fn get(&self, id: WorldSlotId) -> Option<&WorldSlot> {
+        self.slots.iter().find(|slot| slot.id == id)
+    }
+
+    pub fn get_mut(&mut self, id: WorldSlotId) -> Option<&mut WorldSlot> {
+        self.slots.iter_mut().find(|slot| slot.id == id)
+    }
+
+    pub fn remove(&mut self, id: WorldSlotId) {
+        self.slots.retain(|slot| slot.id != id);
+        if self.active == Some(id) {
+            self.active = None;
+        }
+    }
+
+    pub fn set_status(&mut self, id: WorldSlotId, status: WorldStatus) {
+        if let Some(slot) = self.get_mut(id) {
+            slot.status = status;
+        }
+    }
+
+    /// Worlds simulating off screen, loading ones included
+    pub fn background_count(&self) -> usize {
+        self.slots.iter().filter(|slot| Some(slot.id) != self.active).count()
+    }
+}
+
+/// How much of each frame background worlds may use
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct WorldBudget {
+    /// Milliseconds per frame shared by all background worlds
+    pub frame_ms: f32,
+    /// Worlds that may simulate off screen at once
+    pub max_background: usize,
+}
+
+impl WorldBudget {
+    pub const MIN_FRAME_MS: f32 = 1.0;
+    pub const MAX_FRAME_MS: f32 = 16.0;
+
+    /// Change the frame share, keeping it within bounds
+    pub fn adjust(&mut self, delta_ms: f32) {
+        self.frame_ms = (self.frame_ms + delta_ms).clamp(Self::MIN_FRAME_MS, Self::MAX_FRAME_MS);
+    }
+}
+
+impl Default for WorldBudget {
+    fn default() -> Self {
+        Self {
+            frame_ms: 4.0,
+            max_background: 3,
+        }
+    }
+}
+
+/// Open a saved world to simulate in the background
+#[derive(Message, Debug, Clone)]
+pub struct OpenWorld {
+    pub save_path: PathBuf,
+    pub name: String,
+}
+
+/// Bring a background world on screen, sending the current one to the background
+#[derive(Message, Debug, Clone, Copy)]
+pub struct SwitchWorld(pub WorldSlotId);
+
+/// Save a background world to its slot and stop simulating it
+#[derive(Message, Debug, Clone, Copy)]
+pub struct CloseWorld(pub WorldSlotId);
+
+/// Pause or resume one world without touching the others
+#[derive(Message, Debug, Clone, Copy)]
+pub struct PauseWorld {
+    pub world: WorldSlotId,
+    pub paused: bool,
+}
+
+/// Open or close the world switcher panel
+#[derive(Message, Debug, Clone, Copy)]
+pub struct ToggleWorldSwitcher;
+
+/// Root of the world switcher panel
+#[derive(Component)]
+pub struct WorldSwitcherPanel;
+
+/// Buttons on the world switcher panel
+#[derive(Component, Debug, Clone)]
+pub enum WorldSwitcherButton {
+    Switch(WorldSlotId),
+    TogglePause(WorldSlotId),
+    Close(WorldSlotId),
+    Open(PathBuf, String),
+    MoreBudget,
+    LessBudget,
+    Dismiss,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roster_tracks_worlds_and_switches_wait_for_both_saves() {
+        let mut roster = WorldRoster::default();
+        let home = roster.add("Home", WorldStatus::Active);
+        let away = roster.add("Away", WorldStatus::Background);
+        roster.active = Some(home);
+        assert_ne!(home, away);
+        assert_eq!(away.save_slot(), "world-1");
+        assert_eq!(roster.background_count(), 1);
+
+        let mut switch = PendingSwitch {
+            target: away,
+            previous: Some(home),
+            previous_save: None,
+            target_save: Some(PathBuf::from("saves/world-1.lws")),
+        };
+        assert!(!switch.is_ready());
+        switch.previous_save = Some(PathBuf::from("saves/world-0.lws"));
+        assert!(switch.is_ready());
+
+        roster.remove(home);
+        assert_eq!(roster.active, None);
+        assert!(roster.get(home).is_none());
+
+        let mut budget = WorldBudget::default();
+        budget.adjust(100.0);
+        assert_eq!(budget.frame_ms, WorldBudget::MAX_FRAME_MS);
+    }
+}
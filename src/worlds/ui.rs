@@ -0,0 +1,254 @@
+//! World switcher panel, opened with F2 or from the main menu
+
+use bevy::prelude::*;
+
+use super::types::{
+    CloseWorld, OpenWorld, PauseWorld, SwitchWorld, ToggleWorldSwitcher, WorldBudget, WorldRoster, WorldStatus,
+    WorldSwitcherButton, WorldSwitcherPanel, WORLD_SLOT_PREFIX,
+};
+use crate::save_load::{scan_save_files_internal, SaveGameInfo, SaveGameList};
+use crate::ui::*;
+
+/// Saves offered for opening at once
+const LISTED_SAVES: usize = 6;
+/// Seconds between refreshes of an open panel, for years and step times
+const REFRESH_SECS: f32 = 1.0;
+/// Change in the background budget per button press
+const BUDGET_STEP_MS: f32 = 1.0;
+
+fn spawn_line(parent: &mut ChildBuilder, text: String, size: f32, color: Color) {
+    parent.spawn((
+        Text::new(text),
+        TextFont {
+            font_size: size,
+            ..default()
+        },
+        TextColor(color),
+    ));
+}
+
+fn spawn_row(parent: &mut ChildBuilder, build: impl FnOnce(&mut ChildBuilder)) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(8.0),
+            ..default()
+        })
+        .with_children(build);
+}
+
+fn spawn_button(parent: &mut ChildBuilder, label: &str, button: WorldSwitcherButton) {
+    ButtonBuilder::new(label)
+        .size(ButtonSize::Small)
+        .with_marker(button)
+        .build(parent);
+}
+
+/// Spawn the panel listing open worlds and saves that can join them
+fn spawn_world_switcher(commands: &mut Commands, roster: &WorldRoster, budget: &WorldBudget, saves: &[SaveGameInfo]) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(25.0),
+                top: Val::Px(80.0),
+                width: Val::Percent(50.0),
+                max_height: Val::Percent(80.0),
+                padding: UiRect::all(Val::Px(20.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                border: UiRect::all(Val::Px(2.0)),
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+            BackgroundColor(UI_BACKGROUND_COLOR),
+            BorderColor::all(UI_BORDER_COLOR),
+            GlobalZIndex(layers::MODAL_OVERLAY),
+            WorldSwitcherPanel,
+        ))
+        .with_children(|parent| {
+            spawn_line(parent, "Worlds".to_string(), TEXT_SIZE_TITLE, TEXT_COLOR_HEADER);
+            spawn_row(parent, |row| {
+                spawn_line(
+                    row,
+                    format!(
+                        "Background budget: {:.0} ms per frame, up to {} worlds",
+                        budget.frame_ms, budget.max_background
+                    ),
+                    TEXT_SIZE_NORMAL,
+                    TEXT_COLOR_SECONDARY,
+                );
+                spawn_button(row, "-", WorldSwitcherButton::LessBudget);
+                spawn_button(row, "+", WorldSwitcherButton::MoreBudget);
+            });
+
+            if roster.slots.is_empty() {
+                spawn_line(
+                    parent,
+                    "No worlds are open.".to_string(),
+                    TEXT_SIZE_NORMAL,
+                    TEXT_COLOR_SECONDARY,
+                );
+            }
+            for slot in &roster.slots {
+                spawn_row(parent, |row| {
+                    let mut line = format!("{} - year {} - {}", slot.name, slot.year, slot.status.label());
+                    if slot.paused {
+                        line.push_str(", paused");
+                    }
+                    if slot.status == WorldStatus::Background {
+                        line.push_str(&format!(" ({:.1} ms)", slot.step_ms));
+                    }
+                    spawn_line(row, line, TEXT_SIZE_NORMAL, TEXT_COLOR_PRIMARY);
+
+                    let settled = matches!(slot.status, WorldStatus::Active | WorldStatus::Background);
+                    if slot.status == WorldStatus::Background && roster.switching.is_none() {
+                        spawn_button(row, "Switch", WorldSwitcherButton::Switch(slot.id));
+                    }
+                    if settled {
+                        let label = if slot.paused { "Resume" } else { "Pause" };
+                        spawn_button(row, label, WorldSwitcherButton::TogglePause(slot.id));
+                    }
+                    if slot.status == WorldStatus::Background {
+                        spawn_button(row, "Close", WorldSwitcherButton::Close(slot.id));
+                    }
+                });
+            }
+
+            spawn_line(
+                parent,
+                "Open a saved world in the background".to_string(),
+                TEXT_SIZE_LARGE,
+                TEXT_COLOR_HEADER,
+            );
+            for save in saves.iter().take(LISTED_SAVES) {
+                let label = format!("{} ({})", save.world_name, save.name);
+                spawn_button(
+                    parent,
+                    &label,
+                    WorldSwitcherButton::Open(save.path.clone(), save.world_name.clone()),
+                );
+            }
+            spawn_button(parent, "Close", WorldSwitcherButton::Dismiss);
+        });
+}
+
+/// Saves that can be opened, leaving out the slots parked worlds are written to
+fn openable_saves(save_list: &SaveGameList) -> Vec<SaveGameInfo> {
+    save_list
+        .saves
+        .iter()
+        .filter(|save| !save.name.starts_with(WORLD_SLOT_PREFIX))
+        .cloned()
+        .collect()
+}
+
+/// F2 or the main menu's Worlds button opens and closes the switcher
+pub fn toggle_world_switcher(
+    mut commands: Commands,
+    mut shortcuts: MessageReader<ShortcutEvent>,
+    mut toggles: MessageReader<ToggleWorldSwitcher>,
+    roster: Res<WorldRoster>,
+    budget: Res<WorldBudget>,
+    mut save_list: ResMut<SaveGameList>,
+    panels: Query<Entity, With<WorldSwitcherPanel>>,
+) {
+    let pressed = shortcuts
+        .read()
+        .any(|event| event.shortcut_id == ShortcutId::ToggleWorlds);
+    if !pressed && toggles.read().last().is_none() {
+        return;
+    }
+
+    if panels.is_empty() {
+        scan_save_files_internal(&mut save_list);
+        spawn_world_switcher(&mut commands, &roster, &budget, &openable_saves(&save_list));
+    } else {
+        for panel in &panels {
+            commands.entity(panel).despawn();
+        }
+    }
+}
+
+/// Act on the switcher's buttons
+pub fn handle_world_switcher_buttons(
+    mut commands: Commands,
+    buttons: Query<(&Interaction, &WorldSwitcherButton), Changed<Interaction>>,
+    roster: Res<WorldRoster>,
+    mut budget: ResMut<WorldBudget>,
+    panels: Query<Entity, With<WorldSwitcherPanel>>,
+    mut open_events: MessageWriter<OpenWorld>,
+    mut switch_events: MessageWriter<SwitchWorld>,
+    mut close_events: MessageWriter<CloseWorld>,
+    mut pause_events: MessageWriter<PauseWorld>,
+) {
+    for (interaction, button) in &buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            WorldSwitcherButton::Switch(id) => {
+                switch_events.write(SwitchWorld(*id));
+            }
+            WorldSwitcherButton::TogglePause(id) => {
+                let paused = roster.get(*id).is_some_and(|slot| slot.paused);
+                pause_events.write(PauseWorld {
+                    world: *id,
+                    paused: !paused,
+                });
+            }
+            WorldSwitcherButton::Close(id) => {
+                close_events.write(CloseWorld(*id));
+            }
+            WorldSwitcherButton::Open(path, name) => {
+                open_events.write(OpenWorld {
+                    save_path: path.clone(),
+                    name: name.clone(),
+                });
+            }
+            WorldSwitcherButton::MoreBudget => budget.adjust(BUDGET_STEP_MS),
+            WorldSwitcherButton::LessBudget => budget.adjust(-BUDGET_STEP_MS),
+            WorldSwitcherButton::Dismiss => {
+                for panel in &panels {
+                    commands.entity(panel).despawn();
+                }
+            }
+        }
+    }
+}
+
+/// Redraw an open switcher as worlds change, at most once a second
+pub fn refresh_world_switcher(
+    mut commands: Commands,
+    roster: Res<WorldRoster>,
+    budget: Res<WorldBudget>,
+    save_list: Res<SaveGameList>,
+    time: Res<Time<Real>>,
+    mut since_refresh: Local<f32>,
+    mut stale: Local<bool>,
+    panels: Query<Entity, With<WorldSwitcherPanel>>,
+) {
+    if panels.is_empty() {
+        return;
+    }
+    *since_refresh += time.delta_secs();
+    *stale |= roster.is_changed();
+    // Budget presses show at once; world progress waits for the next refresh
+    if !budget.is_changed() && !(*stale && *since_refresh >= REFRESH_SECS) {
+        return;
+    }
+    *since_refresh = 0.0;
+    *stale = false;
+    for panel in &panels {
+        commands.entity(panel).despawn();
+    }
+    spawn_world_switcher(&mut commands, &roster, &budget, &openable_saves(&save_list));
+}
+
+/// The switcher closes while a world loads
+pub fn close_world_switcher(mut commands: Commands, panels: Query<Entity, With<WorldSwitcherPanel>>) {
+    for panel in &panels {
+        commands.entity(panel).despawn();
+    }
+}